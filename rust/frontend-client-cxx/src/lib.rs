@@ -1,9 +1,9 @@
 //! Frontend-client library for rust.
-///
-/// Rust to C++ Grpc frontend.proto for Windows, linux and mac.
-///
-/// This can be replaced with grpcio native implementation when the
-/// Windows build works.
+//!
+//! Rust to C++ Grpc frontend.proto for Windows, linux and mac.
+//!
+//! This can be replaced with grpcio native implementation when the
+//! Windows build works.
 
 /// Wrapper struct for application defined ClientResponseReader
 pub struct ClientResponseReader {
@@ -39,9 +39,29 @@ pub mod ffi {
         PatchDevice,
         GetDevices,
         Reset,
+        RestartChip,
         ListCapture,
         PatchCapture,
         GetCapture,
+        Advertise,
+        ReplayFuzzInput,
+        GetChipTransportStats,
+        CreateBugReport,
+        PauseDelivery,
+        ResumeDelivery,
+        StepDelivery,
+        GetDeliveryState,
+        ReplayCapture,
+        SetDeviceMotion,
+        SetLinkImpairment,
+        PatchGroup,
+        LoadScenario,
+        SaveState,
+        RestoreState,
+        ListEvents,
+        SubscribeEvents,
+        StreamCapture,
+        PatchSelection,
     }
 
     extern "Rust" {
@@ -69,6 +89,30 @@ pub mod ffi {
             client_reader: &ClientResponseReader,
         ) -> UniquePtr<ClientResult>;
 
+        #[allow(dead_code)]
+        #[rust_name = "stream_capture"]
+        pub fn StreamCapture(
+            self: &FrontendClient,
+            request: &Vec<u8>,
+            client_reader: &ClientResponseReader,
+        ) -> UniquePtr<ClientResult>;
+
+        #[allow(dead_code)]
+        #[rust_name = "advertise"]
+        pub fn Advertise(
+            self: &FrontendClient,
+            request: &Vec<u8>,
+            client_reader: &ClientResponseReader,
+        ) -> UniquePtr<ClientResult>;
+
+        #[allow(dead_code)]
+        #[rust_name = "subscribe_events"]
+        pub fn SubscribeEvents(
+            self: &FrontendClient,
+            request: &Vec<u8>,
+            client_reader: &ClientResponseReader,
+        ) -> UniquePtr<ClientResult>;
+
         #[allow(dead_code)]
         #[rust_name = "send_grpc"]
         pub fn SendGrpc(
@@ -26,45 +26,73 @@
 const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.VersionResponse)
-pub struct VersionResponse {
+// @@protoc_insertion_point(message:netsim.frontend.ComputePathLossRequest)
+pub struct ComputePathLossRequest {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.VersionResponse.version)
-    pub version: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ComputePathLossRequest.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.frontend.ComputePathLossRequest.sender_id)
+    pub sender_id: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.ComputePathLossRequest.receiver_id)
+    pub receiver_id: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.ComputePathLossRequest.sender_position)
+    pub sender_position: ::protobuf::MessageField<super::model::Position>,
+    // @@protoc_insertion_point(field:netsim.frontend.ComputePathLossRequest.receiver_position)
+    pub receiver_position: ::protobuf::MessageField<super::model::Position>,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.VersionResponse.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.ComputePathLossRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a VersionResponse {
-    fn default() -> &'a VersionResponse {
-        <VersionResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ComputePathLossRequest {
+    fn default() -> &'a ComputePathLossRequest {
+        <ComputePathLossRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl VersionResponse {
-    pub fn new() -> VersionResponse {
+impl ComputePathLossRequest {
+    pub fn new() -> ComputePathLossRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "version",
-            |m: &VersionResponse| { &m.version },
-            |m: &mut VersionResponse| { &mut m.version },
+            "chip_kind",
+            |m: &ComputePathLossRequest| { &m.chip_kind },
+            |m: &mut ComputePathLossRequest| { &mut m.chip_kind },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<VersionResponse>(
-            "VersionResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sender_id",
+            |m: &ComputePathLossRequest| { &m.sender_id },
+            |m: &mut ComputePathLossRequest| { &mut m.sender_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "receiver_id",
+            |m: &ComputePathLossRequest| { &m.receiver_id },
+            |m: &mut ComputePathLossRequest| { &mut m.receiver_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Position>(
+            "sender_position",
+            |m: &ComputePathLossRequest| { &m.sender_position },
+            |m: &mut ComputePathLossRequest| { &mut m.sender_position },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Position>(
+            "receiver_position",
+            |m: &ComputePathLossRequest| { &m.receiver_position },
+            |m: &mut ComputePathLossRequest| { &mut m.receiver_position },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ComputePathLossRequest>(
+            "ComputePathLossRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for VersionResponse {
-    const NAME: &'static str = "VersionResponse";
+impl ::protobuf::Message for ComputePathLossRequest {
+    const NAME: &'static str = "ComputePathLossRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -73,8 +101,20 @@ impl ::protobuf::Message for VersionResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.version = is.read_string()?;
+                8 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.sender_id = is.read_uint32()?;
+                },
+                24 => {
+                    self.receiver_id = is.read_uint32()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sender_position)?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.receiver_position)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -88,8 +128,22 @@ impl ::protobuf::Message for VersionResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.version.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.version);
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(1, self.chip_kind.value());
+        }
+        if self.sender_id != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.sender_id);
+        }
+        if self.receiver_id != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.receiver_id);
+        }
+        if let Some(v) = self.sender_position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.receiver_position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -97,8 +151,20 @@ impl ::protobuf::Message for VersionResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.version.is_empty() {
-            os.write_string(1, &self.version)?;
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        if self.sender_id != 0 {
+            os.write_uint32(2, self.sender_id)?;
+        }
+        if self.receiver_id != 0 {
+            os.write_uint32(3, self.receiver_id)?;
+        }
+        if let Some(v) = self.sender_position.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.receiver_position.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -112,102 +178,89 @@ impl ::protobuf::Message for VersionResponse {
         &mut self.special_fields
     }
 
-    fn new() -> VersionResponse {
-        VersionResponse::new()
+    fn new() -> ComputePathLossRequest {
+        ComputePathLossRequest::new()
     }
 
     fn clear(&mut self) {
-        self.version.clear();
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.sender_id = 0;
+        self.receiver_id = 0;
+        self.sender_position.clear();
+        self.receiver_position.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static VersionResponse {
-        static instance: VersionResponse = VersionResponse {
-            version: ::std::string::String::new(),
+    fn default_instance() -> &'static ComputePathLossRequest {
+        static instance: ComputePathLossRequest = ComputePathLossRequest {
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            sender_id: 0,
+            receiver_id: 0,
+            sender_position: ::protobuf::MessageField::none(),
+            receiver_position: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for VersionResponse {
+impl ::protobuf::MessageFull for ComputePathLossRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("VersionResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ComputePathLossRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for VersionResponse {
+impl ::std::fmt::Display for ComputePathLossRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for VersionResponse {
+impl ::protobuf::reflect::ProtobufValue for ComputePathLossRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.SetLinkLossRequest)
-pub struct SetLinkLossRequest {
+// @@protoc_insertion_point(message:netsim.frontend.ComputePathLossResponse)
+pub struct ComputePathLossResponse {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.SetLinkLossRequest.device_name)
-    pub device_name: ::std::string::String,
-    // @@protoc_insertion_point(field:netsim.frontend.SetLinkLossRequest.other_device)
-    pub other_device: ::std::string::String,
-    // @@protoc_insertion_point(field:netsim.frontend.SetLinkLossRequest.radio)
-    pub radio: ::protobuf::EnumOrUnknown<super::model::PhyKind>,
-    // @@protoc_insertion_point(field:netsim.frontend.SetLinkLossRequest.link_loss)
-    pub link_loss: f32,
+    // @@protoc_insertion_point(field:netsim.frontend.ComputePathLossResponse.path_loss)
+    pub path_loss: f32,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.SetLinkLossRequest.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.ComputePathLossResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a SetLinkLossRequest {
-    fn default() -> &'a SetLinkLossRequest {
-        <SetLinkLossRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ComputePathLossResponse {
+    fn default() -> &'a ComputePathLossResponse {
+        <ComputePathLossResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SetLinkLossRequest {
-    pub fn new() -> SetLinkLossRequest {
+impl ComputePathLossResponse {
+    pub fn new() -> ComputePathLossResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "device_name",
-            |m: &SetLinkLossRequest| { &m.device_name },
-            |m: &mut SetLinkLossRequest| { &mut m.device_name },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "other_device",
-            |m: &SetLinkLossRequest| { &m.other_device },
-            |m: &mut SetLinkLossRequest| { &mut m.other_device },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "radio",
-            |m: &SetLinkLossRequest| { &m.radio },
-            |m: &mut SetLinkLossRequest| { &mut m.radio },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "link_loss",
-            |m: &SetLinkLossRequest| { &m.link_loss },
-            |m: &mut SetLinkLossRequest| { &mut m.link_loss },
+            "path_loss",
+            |m: &ComputePathLossResponse| { &m.path_loss },
+            |m: &mut ComputePathLossResponse| { &mut m.path_loss },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetLinkLossRequest>(
-            "SetLinkLossRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ComputePathLossResponse>(
+            "ComputePathLossResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for SetLinkLossRequest {
-    const NAME: &'static str = "SetLinkLossRequest";
+impl ::protobuf::Message for ComputePathLossResponse {
+    const NAME: &'static str = "ComputePathLossResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -216,17 +269,8 @@ impl ::protobuf::Message for SetLinkLossRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.device_name = is.read_string()?;
-                },
-                18 => {
-                    self.other_device = is.read_string()?;
-                },
-                24 => {
-                    self.radio = is.read_enum_or_unknown()?;
-                },
-                37 => {
-                    self.link_loss = is.read_float()?;
+                13 => {
+                    self.path_loss = is.read_float()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -240,16 +284,7 @@ impl ::protobuf::Message for SetLinkLossRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.device_name.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.device_name);
-        }
-        if !self.other_device.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.other_device);
-        }
-        if self.radio != ::protobuf::EnumOrUnknown::new(super::model::PhyKind::NONE) {
-            my_size += ::protobuf::rt::int32_size(3, self.radio.value());
-        }
-        if self.link_loss != 0. {
+        if self.path_loss != 0. {
             my_size += 1 + 4;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
@@ -258,17 +293,8 @@ impl ::protobuf::Message for SetLinkLossRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.device_name.is_empty() {
-            os.write_string(1, &self.device_name)?;
-        }
-        if !self.other_device.is_empty() {
-            os.write_string(2, &self.other_device)?;
-        }
-        if self.radio != ::protobuf::EnumOrUnknown::new(super::model::PhyKind::NONE) {
-            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.radio))?;
-        }
-        if self.link_loss != 0. {
-            os.write_float(4, self.link_loss)?;
+        if self.path_loss != 0. {
+            os.write_float(1, self.path_loss)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -282,87 +308,102 @@ impl ::protobuf::Message for SetLinkLossRequest {
         &mut self.special_fields
     }
 
-    fn new() -> SetLinkLossRequest {
-        SetLinkLossRequest::new()
+    fn new() -> ComputePathLossResponse {
+        ComputePathLossResponse::new()
     }
 
     fn clear(&mut self) {
-        self.device_name.clear();
-        self.other_device.clear();
-        self.radio = ::protobuf::EnumOrUnknown::new(super::model::PhyKind::NONE);
-        self.link_loss = 0.;
+        self.path_loss = 0.;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static SetLinkLossRequest {
-        static instance: SetLinkLossRequest = SetLinkLossRequest {
-            device_name: ::std::string::String::new(),
-            other_device: ::std::string::String::new(),
-            radio: ::protobuf::EnumOrUnknown::from_i32(0),
-            link_loss: 0.,
+    fn default_instance() -> &'static ComputePathLossResponse {
+        static instance: ComputePathLossResponse = ComputePathLossResponse {
+            path_loss: 0.,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for SetLinkLossRequest {
+impl ::protobuf::MessageFull for ComputePathLossResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("SetLinkLossRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ComputePathLossResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for SetLinkLossRequest {
+impl ::std::fmt::Display for ComputePathLossResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SetLinkLossRequest {
+impl ::protobuf::reflect::ProtobufValue for ComputePathLossResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.PatchDeviceRequest)
-pub struct PatchDeviceRequest {
+// @@protoc_insertion_point(message:netsim.frontend.VersionResponse)
+pub struct VersionResponse {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.PatchDeviceRequest.device)
-    pub device: ::protobuf::MessageField<super::model::Device>,
+    // @@protoc_insertion_point(field:netsim.frontend.VersionResponse.version)
+    pub version: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.VersionResponse.chip_kinds)
+    pub chip_kinds: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:netsim.frontend.VersionResponse.capture_formats)
+    pub capture_formats: ::std::vec::Vec<::protobuf::EnumOrUnknown<super::model::capture::Format>>,
+    // @@protoc_insertion_point(field:netsim.frontend.VersionResponse.features)
+    pub features: ::std::vec::Vec<::std::string::String>,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.PatchDeviceRequest.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.VersionResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a PatchDeviceRequest {
-    fn default() -> &'a PatchDeviceRequest {
-        <PatchDeviceRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a VersionResponse {
+    fn default() -> &'a VersionResponse {
+        <VersionResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl PatchDeviceRequest {
-    pub fn new() -> PatchDeviceRequest {
+impl VersionResponse {
+    pub fn new() -> VersionResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Device>(
-            "device",
-            |m: &PatchDeviceRequest| { &m.device },
-            |m: &mut PatchDeviceRequest| { &mut m.device },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &VersionResponse| { &m.version },
+            |m: &mut VersionResponse| { &mut m.version },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchDeviceRequest>(
-            "PatchDeviceRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "chip_kinds",
+            |m: &VersionResponse| { &m.chip_kinds },
+            |m: &mut VersionResponse| { &mut m.chip_kinds },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "capture_formats",
+            |m: &VersionResponse| { &m.capture_formats },
+            |m: &mut VersionResponse| { &mut m.capture_formats },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "features",
+            |m: &VersionResponse| { &m.features },
+            |m: &mut VersionResponse| { &mut m.features },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<VersionResponse>(
+            "VersionResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for PatchDeviceRequest {
-    const NAME: &'static str = "PatchDeviceRequest";
+impl ::protobuf::Message for VersionResponse {
+    const NAME: &'static str = "VersionResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -371,8 +412,20 @@ impl ::protobuf::Message for PatchDeviceRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
+                10 => {
+                    self.version = is.read_string()?;
+                },
                 18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.device)?;
+                    self.chip_kinds.push(is.read_string()?);
+                },
+                24 => {
+                    self.capture_formats.push(is.read_enum_or_unknown()?);
+                },
+                26 => {
+                    ::protobuf::rt::read_repeated_packed_enum_or_unknown_into(is, &mut self.capture_formats)?
+                },
+                34 => {
+                    self.features.push(is.read_string()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -386,19 +439,36 @@ impl ::protobuf::Message for PatchDeviceRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.device.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.version);
         }
+        for value in &self.chip_kinds {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.capture_formats {
+            my_size += ::protobuf::rt::int32_size(3, value.value());
+        };
+        for value in &self.features {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.device.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        if !self.version.is_empty() {
+            os.write_string(1, &self.version)?;
         }
+        for v in &self.chip_kinds {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.capture_formats {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(v))?;
+        };
+        for v in &self.features {
+            os.write_string(4, &v)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -411,81 +481,108 @@ impl ::protobuf::Message for PatchDeviceRequest {
         &mut self.special_fields
     }
 
-    fn new() -> PatchDeviceRequest {
-        PatchDeviceRequest::new()
+    fn new() -> VersionResponse {
+        VersionResponse::new()
     }
 
     fn clear(&mut self) {
-        self.device.clear();
+        self.version.clear();
+        self.chip_kinds.clear();
+        self.capture_formats.clear();
+        self.features.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static PatchDeviceRequest {
-        static instance: PatchDeviceRequest = PatchDeviceRequest {
-            device: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static VersionResponse {
+        static instance: VersionResponse = VersionResponse {
+            version: ::std::string::String::new(),
+            chip_kinds: ::std::vec::Vec::new(),
+            capture_formats: ::std::vec::Vec::new(),
+            features: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for PatchDeviceRequest {
+impl ::protobuf::MessageFull for VersionResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("PatchDeviceRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("VersionResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for PatchDeviceRequest {
+impl ::std::fmt::Display for VersionResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PatchDeviceRequest {
+impl ::protobuf::reflect::ProtobufValue for VersionResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.Event)
-pub struct Event {
+// @@protoc_insertion_point(message:netsim.frontend.SetDeviceMotionRequest)
+pub struct SetDeviceMotionRequest {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.Event.devices)
-    pub devices: ::std::vec::Vec<super::model::Device>,
+    // @@protoc_insertion_point(field:netsim.frontend.SetDeviceMotionRequest.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.SetDeviceMotionRequest.waypoints)
+    pub waypoints: ::std::vec::Vec<super::model::Position>,
+    // @@protoc_insertion_point(field:netsim.frontend.SetDeviceMotionRequest.speed)
+    pub speed: f32,
+    // @@protoc_insertion_point(field:netsim.frontend.SetDeviceMotionRequest.repeat)
+    pub repeat: bool,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.Event.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.SetDeviceMotionRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Event {
-    fn default() -> &'a Event {
-        <Event as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SetDeviceMotionRequest {
+    fn default() -> &'a SetDeviceMotionRequest {
+        <SetDeviceMotionRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Event {
-    pub fn new() -> Event {
+impl SetDeviceMotionRequest {
+    pub fn new() -> SetDeviceMotionRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &SetDeviceMotionRequest| { &m.device_name },
+            |m: &mut SetDeviceMotionRequest| { &mut m.device_name },
+        ));
         fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "devices",
-            |m: &Event| { &m.devices },
-            |m: &mut Event| { &mut m.devices },
+            "waypoints",
+            |m: &SetDeviceMotionRequest| { &m.waypoints },
+            |m: &mut SetDeviceMotionRequest| { &mut m.waypoints },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Event>(
-            "Event",
-            fields,
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "speed",
+            |m: &SetDeviceMotionRequest| { &m.speed },
+            |m: &mut SetDeviceMotionRequest| { &mut m.speed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "repeat",
+            |m: &SetDeviceMotionRequest| { &m.repeat },
+            |m: &mut SetDeviceMotionRequest| { &mut m.repeat },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetDeviceMotionRequest>(
+            "SetDeviceMotionRequest",
+            fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Event {
-    const NAME: &'static str = "Event";
+impl ::protobuf::Message for SetDeviceMotionRequest {
+    const NAME: &'static str = "SetDeviceMotionRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -495,7 +592,16 @@ impl ::protobuf::Message for Event {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.devices.push(is.read_message()?);
+                    self.device_name = is.read_string()?;
+                },
+                18 => {
+                    self.waypoints.push(is.read_message()?);
+                },
+                29 => {
+                    self.speed = is.read_float()?;
+                },
+                32 => {
+                    self.repeat = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -509,19 +615,37 @@ impl ::protobuf::Message for Event {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.devices {
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device_name);
+        }
+        for value in &self.waypoints {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         };
+        if self.speed != 0. {
+            my_size += 1 + 4;
+        }
+        if self.repeat != false {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.devices {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        if !self.device_name.is_empty() {
+            os.write_string(1, &self.device_name)?;
+        }
+        for v in &self.waypoints {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         };
+        if self.speed != 0. {
+            os.write_float(3, self.speed)?;
+        }
+        if self.repeat != false {
+            os.write_bool(4, self.repeat)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -534,81 +658,108 @@ impl ::protobuf::Message for Event {
         &mut self.special_fields
     }
 
-    fn new() -> Event {
-        Event::new()
+    fn new() -> SetDeviceMotionRequest {
+        SetDeviceMotionRequest::new()
     }
 
     fn clear(&mut self) {
-        self.devices.clear();
+        self.device_name.clear();
+        self.waypoints.clear();
+        self.speed = 0.;
+        self.repeat = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Event {
-        static instance: Event = Event {
-            devices: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static SetDeviceMotionRequest {
+        static instance: SetDeviceMotionRequest = SetDeviceMotionRequest {
+            device_name: ::std::string::String::new(),
+            waypoints: ::std::vec::Vec::new(),
+            speed: 0.,
+            repeat: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Event {
+impl ::protobuf::MessageFull for SetDeviceMotionRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Event").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SetDeviceMotionRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Event {
+impl ::std::fmt::Display for SetDeviceMotionRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Event {
+impl ::protobuf::reflect::ProtobufValue for SetDeviceMotionRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.GetDevicesResponse)
-pub struct GetDevicesResponse {
+// @@protoc_insertion_point(message:netsim.frontend.SetLinkLossRequest)
+pub struct SetLinkLossRequest {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.GetDevicesResponse.devices)
-    pub devices: ::std::vec::Vec<super::model::Device>,
+    // @@protoc_insertion_point(field:netsim.frontend.SetLinkLossRequest.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.SetLinkLossRequest.other_device)
+    pub other_device: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.SetLinkLossRequest.radio)
+    pub radio: ::protobuf::EnumOrUnknown<super::model::PhyKind>,
+    // @@protoc_insertion_point(field:netsim.frontend.SetLinkLossRequest.link_loss)
+    pub link_loss: f32,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.GetDevicesResponse.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.SetLinkLossRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetDevicesResponse {
-    fn default() -> &'a GetDevicesResponse {
-        <GetDevicesResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SetLinkLossRequest {
+    fn default() -> &'a SetLinkLossRequest {
+        <SetLinkLossRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetDevicesResponse {
-    pub fn new() -> GetDevicesResponse {
+impl SetLinkLossRequest {
+    pub fn new() -> SetLinkLossRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "devices",
-            |m: &GetDevicesResponse| { &m.devices },
-            |m: &mut GetDevicesResponse| { &mut m.devices },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &SetLinkLossRequest| { &m.device_name },
+            |m: &mut SetLinkLossRequest| { &mut m.device_name },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetDevicesResponse>(
-            "GetDevicesResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "other_device",
+            |m: &SetLinkLossRequest| { &m.other_device },
+            |m: &mut SetLinkLossRequest| { &mut m.other_device },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "radio",
+            |m: &SetLinkLossRequest| { &m.radio },
+            |m: &mut SetLinkLossRequest| { &mut m.radio },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "link_loss",
+            |m: &SetLinkLossRequest| { &m.link_loss },
+            |m: &mut SetLinkLossRequest| { &mut m.link_loss },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetLinkLossRequest>(
+            "SetLinkLossRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetDevicesResponse {
-    const NAME: &'static str = "GetDevicesResponse";
+impl ::protobuf::Message for SetLinkLossRequest {
+    const NAME: &'static str = "SetLinkLossRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -618,7 +769,16 @@ impl ::protobuf::Message for GetDevicesResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.devices.push(is.read_message()?);
+                    self.device_name = is.read_string()?;
+                },
+                18 => {
+                    self.other_device = is.read_string()?;
+                },
+                24 => {
+                    self.radio = is.read_enum_or_unknown()?;
+                },
+                37 => {
+                    self.link_loss = is.read_float()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -632,19 +792,36 @@ impl ::protobuf::Message for GetDevicesResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.devices {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device_name);
+        }
+        if !self.other_device.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.other_device);
+        }
+        if self.radio != ::protobuf::EnumOrUnknown::new(super::model::PhyKind::NONE) {
+            my_size += ::protobuf::rt::int32_size(3, self.radio.value());
+        }
+        if self.link_loss != 0. {
+            my_size += 1 + 4;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.devices {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
+        if !self.device_name.is_empty() {
+            os.write_string(1, &self.device_name)?;
+        }
+        if !self.other_device.is_empty() {
+            os.write_string(2, &self.other_device)?;
+        }
+        if self.radio != ::protobuf::EnumOrUnknown::new(super::model::PhyKind::NONE) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.radio))?;
+        }
+        if self.link_loss != 0. {
+            os.write_float(4, self.link_loss)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -657,81 +834,129 @@ impl ::protobuf::Message for GetDevicesResponse {
         &mut self.special_fields
     }
 
-    fn new() -> GetDevicesResponse {
-        GetDevicesResponse::new()
+    fn new() -> SetLinkLossRequest {
+        SetLinkLossRequest::new()
     }
 
     fn clear(&mut self) {
-        self.devices.clear();
+        self.device_name.clear();
+        self.other_device.clear();
+        self.radio = ::protobuf::EnumOrUnknown::new(super::model::PhyKind::NONE);
+        self.link_loss = 0.;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetDevicesResponse {
-        static instance: GetDevicesResponse = GetDevicesResponse {
-            devices: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static SetLinkLossRequest {
+        static instance: SetLinkLossRequest = SetLinkLossRequest {
+            device_name: ::std::string::String::new(),
+            other_device: ::std::string::String::new(),
+            radio: ::protobuf::EnumOrUnknown::from_i32(0),
+            link_loss: 0.,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetDevicesResponse {
+impl ::protobuf::MessageFull for SetLinkLossRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetDevicesResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SetLinkLossRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetDevicesResponse {
+impl ::std::fmt::Display for SetLinkLossRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetDevicesResponse {
+impl ::protobuf::reflect::ProtobufValue for SetLinkLossRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.NetCatStream)
-pub struct NetCatStream {
+// @@protoc_insertion_point(message:netsim.frontend.SetLinkImpairmentRequest)
+pub struct SetLinkImpairmentRequest {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.NetCatStream.result)
-    pub result: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:netsim.frontend.SetLinkImpairmentRequest.chip_id)
+    pub chip_id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.SetLinkImpairmentRequest.other_chip_id)
+    pub other_chip_id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.SetLinkImpairmentRequest.drop_percent)
+    pub drop_percent: f32,
+    // @@protoc_insertion_point(field:netsim.frontend.SetLinkImpairmentRequest.seed)
+    pub seed: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.SetLinkImpairmentRequest.latency_ms)
+    pub latency_ms: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.SetLinkImpairmentRequest.jitter_ms)
+    pub jitter_ms: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.SetLinkImpairmentRequest.jitter_distribution)
+    pub jitter_distribution: ::protobuf::EnumOrUnknown<set_link_impairment_request::JitterDistribution>,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.NetCatStream.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.SetLinkImpairmentRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a NetCatStream {
-    fn default() -> &'a NetCatStream {
-        <NetCatStream as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SetLinkImpairmentRequest {
+    fn default() -> &'a SetLinkImpairmentRequest {
+        <SetLinkImpairmentRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl NetCatStream {
-    pub fn new() -> NetCatStream {
+impl SetLinkImpairmentRequest {
+    pub fn new() -> SetLinkImpairmentRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(7);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "result",
-            |m: &NetCatStream| { &m.result },
-            |m: &mut NetCatStream| { &mut m.result },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_id",
+            |m: &SetLinkImpairmentRequest| { &m.chip_id },
+            |m: &mut SetLinkImpairmentRequest| { &mut m.chip_id },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NetCatStream>(
-            "NetCatStream",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "other_chip_id",
+            |m: &SetLinkImpairmentRequest| { &m.other_chip_id },
+            |m: &mut SetLinkImpairmentRequest| { &mut m.other_chip_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "drop_percent",
+            |m: &SetLinkImpairmentRequest| { &m.drop_percent },
+            |m: &mut SetLinkImpairmentRequest| { &mut m.drop_percent },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "seed",
+            |m: &SetLinkImpairmentRequest| { &m.seed },
+            |m: &mut SetLinkImpairmentRequest| { &mut m.seed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "latency_ms",
+            |m: &SetLinkImpairmentRequest| { &m.latency_ms },
+            |m: &mut SetLinkImpairmentRequest| { &mut m.latency_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "jitter_ms",
+            |m: &SetLinkImpairmentRequest| { &m.jitter_ms },
+            |m: &mut SetLinkImpairmentRequest| { &mut m.jitter_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "jitter_distribution",
+            |m: &SetLinkImpairmentRequest| { &m.jitter_distribution },
+            |m: &mut SetLinkImpairmentRequest| { &mut m.jitter_distribution },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetLinkImpairmentRequest>(
+            "SetLinkImpairmentRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for NetCatStream {
-    const NAME: &'static str = "NetCatStream";
+impl ::protobuf::Message for SetLinkImpairmentRequest {
+    const NAME: &'static str = "SetLinkImpairmentRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -740,8 +965,26 @@ impl ::protobuf::Message for NetCatStream {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.result.push(is.read_string()?);
+                8 => {
+                    self.chip_id = is.read_int32()?;
+                },
+                16 => {
+                    self.other_chip_id = is.read_int32()?;
+                },
+                29 => {
+                    self.drop_percent = is.read_float()?;
+                },
+                32 => {
+                    self.seed = is.read_uint64()?;
+                },
+                40 => {
+                    self.latency_ms = is.read_uint32()?;
+                },
+                48 => {
+                    self.jitter_ms = is.read_uint32()?;
+                },
+                56 => {
+                    self.jitter_distribution = is.read_enum_or_unknown()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -755,18 +998,54 @@ impl ::protobuf::Message for NetCatStream {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.result {
-            my_size += ::protobuf::rt::string_size(1, &value);
-        };
+        if self.chip_id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.chip_id);
+        }
+        if self.other_chip_id != 0 {
+            my_size += ::protobuf::rt::int32_size(2, self.other_chip_id);
+        }
+        if self.drop_percent != 0. {
+            my_size += 1 + 4;
+        }
+        if self.seed != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.seed);
+        }
+        if self.latency_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.latency_ms);
+        }
+        if self.jitter_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.jitter_ms);
+        }
+        if self.jitter_distribution != ::protobuf::EnumOrUnknown::new(set_link_impairment_request::JitterDistribution::UNIFORM) {
+            my_size += ::protobuf::rt::int32_size(7, self.jitter_distribution.value());
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.result {
-            os.write_string(1, &v)?;
-        };
+        if self.chip_id != 0 {
+            os.write_int32(1, self.chip_id)?;
+        }
+        if self.other_chip_id != 0 {
+            os.write_int32(2, self.other_chip_id)?;
+        }
+        if self.drop_percent != 0. {
+            os.write_float(3, self.drop_percent)?;
+        }
+        if self.seed != 0 {
+            os.write_uint64(4, self.seed)?;
+        }
+        if self.latency_ms != 0 {
+            os.write_uint32(5, self.latency_ms)?;
+        }
+        if self.jitter_ms != 0 {
+            os.write_uint32(6, self.jitter_ms)?;
+        }
+        if self.jitter_distribution != ::protobuf::EnumOrUnknown::new(set_link_impairment_request::JitterDistribution::UNIFORM) {
+            os.write_enum(7, ::protobuf::EnumOrUnknown::value(&self.jitter_distribution))?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -779,88 +1058,157 @@ impl ::protobuf::Message for NetCatStream {
         &mut self.special_fields
     }
 
-    fn new() -> NetCatStream {
-        NetCatStream::new()
+    fn new() -> SetLinkImpairmentRequest {
+        SetLinkImpairmentRequest::new()
     }
 
     fn clear(&mut self) {
-        self.result.clear();
+        self.chip_id = 0;
+        self.other_chip_id = 0;
+        self.drop_percent = 0.;
+        self.seed = 0;
+        self.latency_ms = 0;
+        self.jitter_ms = 0;
+        self.jitter_distribution = ::protobuf::EnumOrUnknown::new(set_link_impairment_request::JitterDistribution::UNIFORM);
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static NetCatStream {
-        static instance: NetCatStream = NetCatStream {
-            result: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static SetLinkImpairmentRequest {
+        static instance: SetLinkImpairmentRequest = SetLinkImpairmentRequest {
+            chip_id: 0,
+            other_chip_id: 0,
+            drop_percent: 0.,
+            seed: 0,
+            latency_ms: 0,
+            jitter_ms: 0,
+            jitter_distribution: ::protobuf::EnumOrUnknown::from_i32(0),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for NetCatStream {
+impl ::protobuf::MessageFull for SetLinkImpairmentRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("NetCatStream").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SetLinkImpairmentRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for NetCatStream {
+impl ::std::fmt::Display for SetLinkImpairmentRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for NetCatStream {
+impl ::protobuf::reflect::ProtobufValue for SetLinkImpairmentRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-#[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.SetPacketCaptureRequest)
-pub struct SetPacketCaptureRequest {
-    // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.SetPacketCaptureRequest.capture)
-    pub capture: bool,
-    // @@protoc_insertion_point(field:netsim.frontend.SetPacketCaptureRequest.device_serial)
-    pub device_serial: ::std::string::String,
-    // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.SetPacketCaptureRequest.special_fields)
-    pub special_fields: ::protobuf::SpecialFields,
-}
-
-impl<'a> ::std::default::Default for &'a SetPacketCaptureRequest {
-    fn default() -> &'a SetPacketCaptureRequest {
-        <SetPacketCaptureRequest as ::protobuf::Message>::default_instance()
+/// Nested message and enums of message `SetLinkImpairmentRequest`
+pub mod set_link_impairment_request {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.SetLinkImpairmentRequest.JitterDistribution)
+    pub enum JitterDistribution {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.SetLinkImpairmentRequest.JitterDistribution.UNIFORM)
+        UNIFORM = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.SetLinkImpairmentRequest.JitterDistribution.NORMAL)
+        NORMAL = 1,
+    }
+
+    impl ::protobuf::Enum for JitterDistribution {
+        const NAME: &'static str = "JitterDistribution";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<JitterDistribution> {
+            match value {
+                0 => ::std::option::Option::Some(JitterDistribution::UNIFORM),
+                1 => ::std::option::Option::Some(JitterDistribution::NORMAL),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [JitterDistribution] = &[
+            JitterDistribution::UNIFORM,
+            JitterDistribution::NORMAL,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for JitterDistribution {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("SetLinkImpairmentRequest.JitterDistribution").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for JitterDistribution {
+        fn default() -> Self {
+            JitterDistribution::UNIFORM
+        }
+    }
+
+    impl JitterDistribution {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<JitterDistribution>("SetLinkImpairmentRequest.JitterDistribution")
+        }
     }
 }
 
-impl SetPacketCaptureRequest {
-    pub fn new() -> SetPacketCaptureRequest {
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.PatchDeviceRequest)
+pub struct PatchDeviceRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.PatchDeviceRequest.device)
+    pub device: ::protobuf::MessageField<super::model::Device>,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchDeviceRequest.update_mask)
+    pub update_mask: ::protobuf::MessageField<::protobuf::well_known_types::field_mask::FieldMask>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.PatchDeviceRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PatchDeviceRequest {
+    fn default() -> &'a PatchDeviceRequest {
+        <PatchDeviceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PatchDeviceRequest {
+    pub fn new() -> PatchDeviceRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "capture",
-            |m: &SetPacketCaptureRequest| { &m.capture },
-            |m: &mut SetPacketCaptureRequest| { &mut m.capture },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Device>(
+            "device",
+            |m: &PatchDeviceRequest| { &m.device },
+            |m: &mut PatchDeviceRequest| { &mut m.device },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "device_serial",
-            |m: &SetPacketCaptureRequest| { &m.device_serial },
-            |m: &mut SetPacketCaptureRequest| { &mut m.device_serial },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::field_mask::FieldMask>(
+            "update_mask",
+            |m: &PatchDeviceRequest| { &m.update_mask },
+            |m: &mut PatchDeviceRequest| { &mut m.update_mask },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetPacketCaptureRequest>(
-            "SetPacketCaptureRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchDeviceRequest>(
+            "PatchDeviceRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for SetPacketCaptureRequest {
-    const NAME: &'static str = "SetPacketCaptureRequest";
+impl ::protobuf::Message for PatchDeviceRequest {
+    const NAME: &'static str = "PatchDeviceRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -869,11 +1217,11 @@ impl ::protobuf::Message for SetPacketCaptureRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.capture = is.read_bool()?;
-                },
                 18 => {
-                    self.device_serial = is.read_string()?;
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.device)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.update_mask)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -887,11 +1235,13 @@ impl ::protobuf::Message for SetPacketCaptureRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.capture != false {
-            my_size += 1 + 1;
+        if let Some(v) = self.device.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
-        if !self.device_serial.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.device_serial);
+        if let Some(v) = self.update_mask.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -899,11 +1249,11 @@ impl ::protobuf::Message for SetPacketCaptureRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.capture != false {
-            os.write_bool(1, self.capture)?;
+        if let Some(v) = self.device.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
-        if !self.device_serial.is_empty() {
-            os.write_string(2, &self.device_serial)?;
+        if let Some(v) = self.update_mask.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -917,90 +1267,118 @@ impl ::protobuf::Message for SetPacketCaptureRequest {
         &mut self.special_fields
     }
 
-    fn new() -> SetPacketCaptureRequest {
-        SetPacketCaptureRequest::new()
+    fn new() -> PatchDeviceRequest {
+        PatchDeviceRequest::new()
     }
 
     fn clear(&mut self) {
-        self.capture = false;
-        self.device_serial.clear();
+        self.device.clear();
+        self.update_mask.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static SetPacketCaptureRequest {
-        static instance: SetPacketCaptureRequest = SetPacketCaptureRequest {
-            capture: false,
-            device_serial: ::std::string::String::new(),
+    fn default_instance() -> &'static PatchDeviceRequest {
+        static instance: PatchDeviceRequest = PatchDeviceRequest {
+            device: ::protobuf::MessageField::none(),
+            update_mask: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for SetPacketCaptureRequest {
+impl ::protobuf::MessageFull for PatchDeviceRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("SetPacketCaptureRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PatchDeviceRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for SetPacketCaptureRequest {
+impl ::std::fmt::Display for PatchDeviceRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SetPacketCaptureRequest {
+impl ::protobuf::reflect::ProtobufValue for PatchDeviceRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.PatchCaptureRequest)
-pub struct PatchCaptureRequest {
+// @@protoc_insertion_point(message:netsim.frontend.PatchGroupRequest)
+pub struct PatchGroupRequest {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.id)
-    pub id: i32,
-    // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.patch)
-    pub patch: ::protobuf::MessageField<patch_capture_request::PatchCapture>,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchGroupRequest.group)
+    pub group: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchGroupRequest.join_device_names)
+    pub join_device_names: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchGroupRequest.leave_device_names)
+    pub leave_device_names: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchGroupRequest.device)
+    pub device: ::protobuf::MessageField<super::model::Device>,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchGroupRequest.relative)
+    pub relative: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchGroupRequest.capture)
+    pub capture: ::protobuf::EnumOrUnknown<super::model::State>,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.PatchCaptureRequest.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.PatchGroupRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a PatchCaptureRequest {
-    fn default() -> &'a PatchCaptureRequest {
-        <PatchCaptureRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a PatchGroupRequest {
+    fn default() -> &'a PatchGroupRequest {
+        <PatchGroupRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl PatchCaptureRequest {
-    pub fn new() -> PatchCaptureRequest {
+impl PatchGroupRequest {
+    pub fn new() -> PatchGroupRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(6);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "id",
-            |m: &PatchCaptureRequest| { &m.id },
-            |m: &mut PatchCaptureRequest| { &mut m.id },
+            "group",
+            |m: &PatchGroupRequest| { &m.group },
+            |m: &mut PatchGroupRequest| { &mut m.group },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, patch_capture_request::PatchCapture>(
-            "patch",
-            |m: &PatchCaptureRequest| { &m.patch },
-            |m: &mut PatchCaptureRequest| { &mut m.patch },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "join_device_names",
+            |m: &PatchGroupRequest| { &m.join_device_names },
+            |m: &mut PatchGroupRequest| { &mut m.join_device_names },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchCaptureRequest>(
-            "PatchCaptureRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "leave_device_names",
+            |m: &PatchGroupRequest| { &m.leave_device_names },
+            |m: &mut PatchGroupRequest| { &mut m.leave_device_names },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Device>(
+            "device",
+            |m: &PatchGroupRequest| { &m.device },
+            |m: &mut PatchGroupRequest| { &mut m.device },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "relative",
+            |m: &PatchGroupRequest| { &m.relative },
+            |m: &mut PatchGroupRequest| { &mut m.relative },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "capture",
+            |m: &PatchGroupRequest| { &m.capture },
+            |m: &mut PatchGroupRequest| { &mut m.capture },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchGroupRequest>(
+            "PatchGroupRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for PatchCaptureRequest {
-    const NAME: &'static str = "PatchCaptureRequest";
+impl ::protobuf::Message for PatchGroupRequest {
+    const NAME: &'static str = "PatchGroupRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1009,11 +1387,23 @@ impl ::protobuf::Message for PatchCaptureRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.id = is.read_int32()?;
+                10 => {
+                    self.group = is.read_string()?;
                 },
                 18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.patch)?;
+                    self.join_device_names.push(is.read_string()?);
+                },
+                26 => {
+                    self.leave_device_names.push(is.read_string()?);
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.device)?;
+                },
+                40 => {
+                    self.relative = is.read_bool()?;
+                },
+                48 => {
+                    self.capture = is.read_enum_or_unknown()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1027,24 +1417,48 @@ impl ::protobuf::Message for PatchCaptureRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.id != 0 {
-            my_size += ::protobuf::rt::int32_size(1, self.id);
+        if !self.group.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.group);
         }
-        if let Some(v) = self.patch.as_ref() {
+        for value in &self.join_device_names {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.leave_device_names {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if let Some(v) = self.device.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if self.relative != false {
+            my_size += 1 + 1;
+        }
+        if self.capture != ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(6, self.capture.value());
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.id != 0 {
-            os.write_int32(1, self.id)?;
+        if !self.group.is_empty() {
+            os.write_string(1, &self.group)?;
         }
-        if let Some(v) = self.patch.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        for v in &self.join_device_names {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.leave_device_names {
+            os.write_string(3, &v)?;
+        };
+        if let Some(v) = self.device.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if self.relative != false {
+            os.write_bool(5, self.relative)?;
+        }
+        if self.capture != ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN) {
+            os.write_enum(6, ::protobuf::EnumOrUnknown::value(&self.capture))?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1058,96 +1472,15612 @@ impl ::protobuf::Message for PatchCaptureRequest {
         &mut self.special_fields
     }
 
-    fn new() -> PatchCaptureRequest {
-        PatchCaptureRequest::new()
+    fn new() -> PatchGroupRequest {
+        PatchGroupRequest::new()
     }
 
     fn clear(&mut self) {
-        self.id = 0;
-        self.patch.clear();
+        self.group.clear();
+        self.join_device_names.clear();
+        self.leave_device_names.clear();
+        self.device.clear();
+        self.relative = false;
+        self.capture = ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN);
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static PatchCaptureRequest {
-        static instance: PatchCaptureRequest = PatchCaptureRequest {
-            id: 0,
-            patch: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static PatchGroupRequest {
+        static instance: PatchGroupRequest = PatchGroupRequest {
+            group: ::std::string::String::new(),
+            join_device_names: ::std::vec::Vec::new(),
+            leave_device_names: ::std::vec::Vec::new(),
+            device: ::protobuf::MessageField::none(),
+            relative: false,
+            capture: ::protobuf::EnumOrUnknown::from_i32(0),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for PatchCaptureRequest {
+impl ::protobuf::MessageFull for PatchGroupRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("PatchCaptureRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PatchGroupRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for PatchCaptureRequest {
+impl ::std::fmt::Display for PatchGroupRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PatchCaptureRequest {
+impl ::protobuf::reflect::ProtobufValue for PatchGroupRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-/// Nested message and enums of message `PatchCaptureRequest`
-pub mod patch_capture_request {
-    #[derive(PartialEq,Clone,Default,Debug)]
-    // @@protoc_insertion_point(message:netsim.frontend.PatchCaptureRequest.PatchCapture)
-    pub struct PatchCapture {
-        // message fields
-        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.state)
-        pub state: ::protobuf::EnumOrUnknown<super::super::model::State>,
-        // special fields
-        // @@protoc_insertion_point(special_field:netsim.frontend.PatchCaptureRequest.PatchCapture.special_fields)
-        pub special_fields: ::protobuf::SpecialFields,
-    }
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.PatchGroupResponse)
+pub struct PatchGroupResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.PatchGroupResponse.device_names)
+    pub device_names: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.PatchGroupResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
 
-    impl<'a> ::std::default::Default for &'a PatchCapture {
-        fn default() -> &'a PatchCapture {
-            <PatchCapture as ::protobuf::Message>::default_instance()
-        }
+impl<'a> ::std::default::Default for &'a PatchGroupResponse {
+    fn default() -> &'a PatchGroupResponse {
+        <PatchGroupResponse as ::protobuf::Message>::default_instance()
     }
+}
 
-    impl PatchCapture {
-        pub fn new() -> PatchCapture {
-            ::std::default::Default::default()
-        }
+impl PatchGroupResponse {
+    pub fn new() -> PatchGroupResponse {
+        ::std::default::Default::default()
+    }
 
-        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-            let mut fields = ::std::vec::Vec::with_capacity(1);
-            let mut oneofs = ::std::vec::Vec::with_capacity(0);
-            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-                "state",
-                |m: &PatchCapture| { &m.state },
-                |m: &mut PatchCapture| { &mut m.state },
-            ));
-            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchCapture>(
-                "PatchCaptureRequest.PatchCapture",
-                fields,
-                oneofs,
-            )
-        }
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "device_names",
+            |m: &PatchGroupResponse| { &m.device_names },
+            |m: &mut PatchGroupResponse| { &mut m.device_names },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchGroupResponse>(
+            "PatchGroupResponse",
+            fields,
+            oneofs,
+        )
     }
+}
 
-    impl ::protobuf::Message for PatchCapture {
-        const NAME: &'static str = "PatchCapture";
+impl ::protobuf::Message for PatchGroupResponse {
+    const NAME: &'static str = "PatchGroupResponse";
 
-        fn is_initialized(&self) -> bool {
-            true
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.device_names.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
+    }
 
-        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
-            while let Some(tag) = is.read_raw_tag_or_eof()? {
-                match tag {
-                    8 => {
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.device_names {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.device_names {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PatchGroupResponse {
+        PatchGroupResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.device_names.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PatchGroupResponse {
+        static instance: PatchGroupResponse = PatchGroupResponse {
+            device_names: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PatchGroupResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PatchGroupResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PatchGroupResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PatchGroupResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.Selector)
+pub struct Selector {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.Selector.device)
+    pub device: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.Selector.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.Selector.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Selector {
+    fn default() -> &'a Selector {
+        <Selector as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Selector {
+    pub fn new() -> Selector {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device",
+            |m: &Selector| { &m.device },
+            |m: &mut Selector| { &mut m.device },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &Selector| { &m.chip_kind },
+            |m: &mut Selector| { &mut m.chip_kind },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Selector>(
+            "Selector",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Selector {
+    const NAME: &'static str = "Selector";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.device = is.read_string()?;
+                },
+                16 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.device.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(2, self.chip_kind.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.device.is_empty() {
+            os.write_string(1, &self.device)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Selector {
+        Selector::new()
+    }
+
+    fn clear(&mut self) {
+        self.device.clear();
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Selector {
+        static instance: Selector = Selector {
+            device: ::std::string::String::new(),
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Selector {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Selector").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Selector {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Selector {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.PatchSelectionRequest)
+pub struct PatchSelectionRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.PatchSelectionRequest.selector)
+    pub selector: ::protobuf::MessageField<Selector>,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchSelectionRequest.chip)
+    pub chip: ::protobuf::MessageField<super::model::Chip>,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchSelectionRequest.capture)
+    pub capture: ::protobuf::EnumOrUnknown<super::model::State>,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchSelectionRequest.format)
+    pub format: ::protobuf::EnumOrUnknown<super::model::capture::Format>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.PatchSelectionRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PatchSelectionRequest {
+    fn default() -> &'a PatchSelectionRequest {
+        <PatchSelectionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PatchSelectionRequest {
+    pub fn new() -> PatchSelectionRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Selector>(
+            "selector",
+            |m: &PatchSelectionRequest| { &m.selector },
+            |m: &mut PatchSelectionRequest| { &mut m.selector },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Chip>(
+            "chip",
+            |m: &PatchSelectionRequest| { &m.chip },
+            |m: &mut PatchSelectionRequest| { &mut m.chip },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "capture",
+            |m: &PatchSelectionRequest| { &m.capture },
+            |m: &mut PatchSelectionRequest| { &mut m.capture },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "format",
+            |m: &PatchSelectionRequest| { &m.format },
+            |m: &mut PatchSelectionRequest| { &mut m.format },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchSelectionRequest>(
+            "PatchSelectionRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PatchSelectionRequest {
+    const NAME: &'static str = "PatchSelectionRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.selector)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.chip)?;
+                },
+                24 => {
+                    self.capture = is.read_enum_or_unknown()?;
+                },
+                32 => {
+                    self.format = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.selector.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.chip.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.capture != ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(3, self.capture.value());
+        }
+        if self.format != ::protobuf::EnumOrUnknown::new(super::model::capture::Format::PCAP) {
+            my_size += ::protobuf::rt::int32_size(4, self.format.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.selector.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.chip.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.capture != ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.capture))?;
+        }
+        if self.format != ::protobuf::EnumOrUnknown::new(super::model::capture::Format::PCAP) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.format))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PatchSelectionRequest {
+        PatchSelectionRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.selector.clear();
+        self.chip.clear();
+        self.capture = ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN);
+        self.format = ::protobuf::EnumOrUnknown::new(super::model::capture::Format::PCAP);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PatchSelectionRequest {
+        static instance: PatchSelectionRequest = PatchSelectionRequest {
+            selector: ::protobuf::MessageField::none(),
+            chip: ::protobuf::MessageField::none(),
+            capture: ::protobuf::EnumOrUnknown::from_i32(0),
+            format: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PatchSelectionRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PatchSelectionRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PatchSelectionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PatchSelectionRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.PatchSelectionResponse)
+pub struct PatchSelectionResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.PatchSelectionResponse.results)
+    pub results: ::std::vec::Vec<patch_selection_response::Result>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.PatchSelectionResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PatchSelectionResponse {
+    fn default() -> &'a PatchSelectionResponse {
+        <PatchSelectionResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PatchSelectionResponse {
+    pub fn new() -> PatchSelectionResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "results",
+            |m: &PatchSelectionResponse| { &m.results },
+            |m: &mut PatchSelectionResponse| { &mut m.results },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchSelectionResponse>(
+            "PatchSelectionResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PatchSelectionResponse {
+    const NAME: &'static str = "PatchSelectionResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.results.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.results {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.results {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PatchSelectionResponse {
+        PatchSelectionResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.results.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PatchSelectionResponse {
+        static instance: PatchSelectionResponse = PatchSelectionResponse {
+            results: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PatchSelectionResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PatchSelectionResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PatchSelectionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PatchSelectionResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `PatchSelectionResponse`
+pub mod patch_selection_response {
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.PatchSelectionResponse.Result)
+    pub struct Result {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.PatchSelectionResponse.Result.device_name)
+        pub device_name: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchSelectionResponse.Result.chip_id)
+        pub chip_id: i32,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchSelectionResponse.Result.chip_kind)
+        pub chip_kind: ::protobuf::EnumOrUnknown<super::super::common::ChipKind>,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchSelectionResponse.Result.error)
+        pub error: ::std::string::String,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.PatchSelectionResponse.Result.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Result {
+        fn default() -> &'a Result {
+            <Result as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Result {
+        pub fn new() -> Result {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(4);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "device_name",
+                |m: &Result| { &m.device_name },
+                |m: &mut Result| { &mut m.device_name },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "chip_id",
+                |m: &Result| { &m.chip_id },
+                |m: &mut Result| { &mut m.chip_id },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "chip_kind",
+                |m: &Result| { &m.chip_kind },
+                |m: &mut Result| { &mut m.chip_kind },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "error",
+                |m: &Result| { &m.error },
+                |m: &mut Result| { &mut m.error },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Result>(
+                "PatchSelectionResponse.Result",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Result {
+        const NAME: &'static str = "Result";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.device_name = is.read_string()?;
+                    },
+                    16 => {
+                        self.chip_id = is.read_int32()?;
+                    },
+                    24 => {
+                        self.chip_kind = is.read_enum_or_unknown()?;
+                    },
+                    34 => {
+                        self.error = is.read_string()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.device_name.is_empty() {
+                my_size += ::protobuf::rt::string_size(1, &self.device_name);
+            }
+            if self.chip_id != 0 {
+                my_size += ::protobuf::rt::int32_size(2, self.chip_id);
+            }
+            if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::super::common::ChipKind::UNSPECIFIED) {
+                my_size += ::protobuf::rt::int32_size(3, self.chip_kind.value());
+            }
+            if !self.error.is_empty() {
+                my_size += ::protobuf::rt::string_size(4, &self.error);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if !self.device_name.is_empty() {
+                os.write_string(1, &self.device_name)?;
+            }
+            if self.chip_id != 0 {
+                os.write_int32(2, self.chip_id)?;
+            }
+            if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::super::common::ChipKind::UNSPECIFIED) {
+                os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+            }
+            if !self.error.is_empty() {
+                os.write_string(4, &self.error)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Result {
+            Result::new()
+        }
+
+        fn clear(&mut self) {
+            self.device_name.clear();
+            self.chip_id = 0;
+            self.chip_kind = ::protobuf::EnumOrUnknown::new(super::super::common::ChipKind::UNSPECIFIED);
+            self.error.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Result {
+            static instance: Result = Result {
+                device_name: ::std::string::String::new(),
+                chip_id: 0,
+                chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+                error: ::std::string::String::new(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Result {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("PatchSelectionResponse.Result").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Result {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Result {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.RestartChipRequest)
+pub struct RestartChipRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.RestartChipRequest.chip_id)
+    pub chip_id: i32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.RestartChipRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RestartChipRequest {
+    fn default() -> &'a RestartChipRequest {
+        <RestartChipRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RestartChipRequest {
+    pub fn new() -> RestartChipRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_id",
+            |m: &RestartChipRequest| { &m.chip_id },
+            |m: &mut RestartChipRequest| { &mut m.chip_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RestartChipRequest>(
+            "RestartChipRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RestartChipRequest {
+    const NAME: &'static str = "RestartChipRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.chip_id = is.read_int32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.chip_id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.chip_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.chip_id != 0 {
+            os.write_int32(1, self.chip_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RestartChipRequest {
+        RestartChipRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.chip_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RestartChipRequest {
+        static instance: RestartChipRequest = RestartChipRequest {
+            chip_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RestartChipRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RestartChipRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RestartChipRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RestartChipRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.RestartChipResponse)
+pub struct RestartChipResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.RestartChipResponse.queued)
+    pub queued: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.RestartChipResponse.rejected)
+    pub rejected: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.RestartChipResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RestartChipResponse {
+    fn default() -> &'a RestartChipResponse {
+        <RestartChipResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RestartChipResponse {
+    pub fn new() -> RestartChipResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "queued",
+            |m: &RestartChipResponse| { &m.queued },
+            |m: &mut RestartChipResponse| { &mut m.queued },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "rejected",
+            |m: &RestartChipResponse| { &m.rejected },
+            |m: &mut RestartChipResponse| { &mut m.rejected },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RestartChipResponse>(
+            "RestartChipResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RestartChipResponse {
+    const NAME: &'static str = "RestartChipResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.queued = is.read_uint32()?;
+                },
+                16 => {
+                    self.rejected = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.queued != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.queued);
+        }
+        if self.rejected != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.rejected);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.queued != 0 {
+            os.write_uint32(1, self.queued)?;
+        }
+        if self.rejected != 0 {
+            os.write_uint32(2, self.rejected)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RestartChipResponse {
+        RestartChipResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.queued = 0;
+        self.rejected = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RestartChipResponse {
+        static instance: RestartChipResponse = RestartChipResponse {
+            queued: 0,
+            rejected: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RestartChipResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RestartChipResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RestartChipResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RestartChipResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.AdvertiseRequest)
+pub struct AdvertiseRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.AdvertiseRequest.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.AdvertiseRequest.interval_ms)
+    pub interval_ms: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.AdvertiseRequest.payload)
+    pub payload: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.AdvertiseRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AdvertiseRequest {
+    fn default() -> &'a AdvertiseRequest {
+        <AdvertiseRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AdvertiseRequest {
+    pub fn new() -> AdvertiseRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &AdvertiseRequest| { &m.name },
+            |m: &mut AdvertiseRequest| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "interval_ms",
+            |m: &AdvertiseRequest| { &m.interval_ms },
+            |m: &mut AdvertiseRequest| { &mut m.interval_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "payload",
+            |m: &AdvertiseRequest| { &m.payload },
+            |m: &mut AdvertiseRequest| { &mut m.payload },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AdvertiseRequest>(
+            "AdvertiseRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AdvertiseRequest {
+    const NAME: &'static str = "AdvertiseRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.interval_ms = is.read_uint32()?;
+                },
+                26 => {
+                    self.payload = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.interval_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.interval_ms);
+        }
+        if !self.payload.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.payload);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.interval_ms != 0 {
+            os.write_uint32(2, self.interval_ms)?;
+        }
+        if !self.payload.is_empty() {
+            os.write_bytes(3, &self.payload)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AdvertiseRequest {
+        AdvertiseRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.interval_ms = 0;
+        self.payload.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AdvertiseRequest {
+        static instance: AdvertiseRequest = AdvertiseRequest {
+            name: ::std::string::String::new(),
+            interval_ms: 0,
+            payload: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AdvertiseRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AdvertiseRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AdvertiseRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AdvertiseRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.Beacon)
+pub struct Beacon {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.Beacon.device_id)
+    pub device_id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.Beacon.chip_id)
+    pub chip_id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.Beacon.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.Beacon.interval_ms)
+    pub interval_ms: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.Beacon.advertising_data)
+    pub advertising_data: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.Beacon.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Beacon {
+    fn default() -> &'a Beacon {
+        <Beacon as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Beacon {
+    pub fn new() -> Beacon {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_id",
+            |m: &Beacon| { &m.device_id },
+            |m: &mut Beacon| { &mut m.device_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_id",
+            |m: &Beacon| { &m.chip_id },
+            |m: &mut Beacon| { &mut m.chip_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &Beacon| { &m.name },
+            |m: &mut Beacon| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "interval_ms",
+            |m: &Beacon| { &m.interval_ms },
+            |m: &mut Beacon| { &mut m.interval_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "advertising_data",
+            |m: &Beacon| { &m.advertising_data },
+            |m: &mut Beacon| { &mut m.advertising_data },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Beacon>(
+            "Beacon",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Beacon {
+    const NAME: &'static str = "Beacon";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.device_id = is.read_int32()?;
+                },
+                16 => {
+                    self.chip_id = is.read_int32()?;
+                },
+                26 => {
+                    self.name = is.read_string()?;
+                },
+                32 => {
+                    self.interval_ms = is.read_uint32()?;
+                },
+                42 => {
+                    self.advertising_data = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.device_id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.device_id);
+        }
+        if self.chip_id != 0 {
+            my_size += ::protobuf::rt::int32_size(2, self.chip_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.name);
+        }
+        if self.interval_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.interval_ms);
+        }
+        if !self.advertising_data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.advertising_data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.device_id != 0 {
+            os.write_int32(1, self.device_id)?;
+        }
+        if self.chip_id != 0 {
+            os.write_int32(2, self.chip_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(3, &self.name)?;
+        }
+        if self.interval_ms != 0 {
+            os.write_uint32(4, self.interval_ms)?;
+        }
+        if !self.advertising_data.is_empty() {
+            os.write_bytes(5, &self.advertising_data)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Beacon {
+        Beacon::new()
+    }
+
+    fn clear(&mut self) {
+        self.device_id = 0;
+        self.chip_id = 0;
+        self.name.clear();
+        self.interval_ms = 0;
+        self.advertising_data.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Beacon {
+        static instance: Beacon = Beacon {
+            device_id: 0,
+            chip_id: 0,
+            name: ::std::string::String::new(),
+            interval_ms: 0,
+            advertising_data: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Beacon {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Beacon").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Beacon {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Beacon {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ListBeaconsResponse)
+pub struct ListBeaconsResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ListBeaconsResponse.beacons)
+    pub beacons: ::std::vec::Vec<Beacon>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ListBeaconsResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListBeaconsResponse {
+    fn default() -> &'a ListBeaconsResponse {
+        <ListBeaconsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListBeaconsResponse {
+    pub fn new() -> ListBeaconsResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "beacons",
+            |m: &ListBeaconsResponse| { &m.beacons },
+            |m: &mut ListBeaconsResponse| { &mut m.beacons },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListBeaconsResponse>(
+            "ListBeaconsResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListBeaconsResponse {
+    const NAME: &'static str = "ListBeaconsResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.beacons.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.beacons {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.beacons {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListBeaconsResponse {
+        ListBeaconsResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.beacons.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListBeaconsResponse {
+        static instance: ListBeaconsResponse = ListBeaconsResponse {
+            beacons: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListBeaconsResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListBeaconsResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListBeaconsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListBeaconsResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.Event)
+pub struct Event {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.Event.devices)
+    pub devices: ::std::vec::Vec<super::model::Device>,
+    // @@protoc_insertion_point(field:netsim.frontend.Event.id)
+    pub id: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.Event.timestamp)
+    pub timestamp: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:netsim.frontend.Event.type)
+    pub type_: ::protobuf::EnumOrUnknown<event::Type>,
+    // @@protoc_insertion_point(field:netsim.frontend.Event.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.Event.chip_id)
+    pub chip_id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.Event.packet)
+    pub packet: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:netsim.frontend.Event.breakpoint_id)
+    pub breakpoint_id: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.Event.transport_stats)
+    pub transport_stats: ::protobuf::MessageField<ChipTransportStats>,
+    // @@protoc_insertion_point(field:netsim.frontend.Event.capture)
+    pub capture: ::protobuf::MessageField<super::model::Capture>,
+    // @@protoc_insertion_point(field:netsim.frontend.Event.error)
+    pub error: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.Event.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Event {
+    fn default() -> &'a Event {
+        <Event as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Event {
+    pub fn new() -> Event {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(11);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "devices",
+            |m: &Event| { &m.devices },
+            |m: &mut Event| { &mut m.devices },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &Event| { &m.id },
+            |m: &mut Event| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "timestamp",
+            |m: &Event| { &m.timestamp },
+            |m: &mut Event| { &mut m.timestamp },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "type",
+            |m: &Event| { &m.type_ },
+            |m: &mut Event| { &mut m.type_ },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &Event| { &m.device_name },
+            |m: &mut Event| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_id",
+            |m: &Event| { &m.chip_id },
+            |m: &mut Event| { &mut m.chip_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "packet",
+            |m: &Event| { &m.packet },
+            |m: &mut Event| { &mut m.packet },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "breakpoint_id",
+            |m: &Event| { &m.breakpoint_id },
+            |m: &mut Event| { &mut m.breakpoint_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ChipTransportStats>(
+            "transport_stats",
+            |m: &Event| { &m.transport_stats },
+            |m: &mut Event| { &mut m.transport_stats },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Capture>(
+            "capture",
+            |m: &Event| { &m.capture },
+            |m: &mut Event| { &mut m.capture },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &Event| { &m.error },
+            |m: &mut Event| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Event>(
+            "Event",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Event {
+    const NAME: &'static str = "Event";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.devices.push(is.read_message()?);
+                },
+                16 => {
+                    self.id = is.read_uint64()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.timestamp)?;
+                },
+                32 => {
+                    self.type_ = is.read_enum_or_unknown()?;
+                },
+                42 => {
+                    self.device_name = is.read_string()?;
+                },
+                48 => {
+                    self.chip_id = is.read_int32()?;
+                },
+                58 => {
+                    self.packet = is.read_bytes()?;
+                },
+                64 => {
+                    self.breakpoint_id = is.read_uint32()?;
+                },
+                74 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.transport_stats)?;
+                },
+                82 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.capture)?;
+                },
+                90 => {
+                    self.error = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.devices {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.id);
+        }
+        if let Some(v) = self.timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(event::Type::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(4, self.type_.value());
+        }
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.device_name);
+        }
+        if self.chip_id != 0 {
+            my_size += ::protobuf::rt::int32_size(6, self.chip_id);
+        }
+        if !self.packet.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.packet);
+        }
+        if self.breakpoint_id != 0 {
+            my_size += ::protobuf::rt::uint32_size(8, self.breakpoint_id);
+        }
+        if let Some(v) = self.transport_stats.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.capture.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(11, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.devices {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.id != 0 {
+            os.write_uint64(2, self.id)?;
+        }
+        if let Some(v) = self.timestamp.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(event::Type::UNSPECIFIED) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.type_))?;
+        }
+        if !self.device_name.is_empty() {
+            os.write_string(5, &self.device_name)?;
+        }
+        if self.chip_id != 0 {
+            os.write_int32(6, self.chip_id)?;
+        }
+        if !self.packet.is_empty() {
+            os.write_bytes(7, &self.packet)?;
+        }
+        if self.breakpoint_id != 0 {
+            os.write_uint32(8, self.breakpoint_id)?;
+        }
+        if let Some(v) = self.transport_stats.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
+        }
+        if let Some(v) = self.capture.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(11, &self.error)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Event {
+        Event::new()
+    }
+
+    fn clear(&mut self) {
+        self.devices.clear();
+        self.id = 0;
+        self.timestamp.clear();
+        self.type_ = ::protobuf::EnumOrUnknown::new(event::Type::UNSPECIFIED);
+        self.device_name.clear();
+        self.chip_id = 0;
+        self.packet.clear();
+        self.breakpoint_id = 0;
+        self.transport_stats.clear();
+        self.capture.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Event {
+        static instance: Event = Event {
+            devices: ::std::vec::Vec::new(),
+            id: 0,
+            timestamp: ::protobuf::MessageField::none(),
+            type_: ::protobuf::EnumOrUnknown::from_i32(0),
+            device_name: ::std::string::String::new(),
+            chip_id: 0,
+            packet: ::std::vec::Vec::new(),
+            breakpoint_id: 0,
+            transport_stats: ::protobuf::MessageField::none(),
+            capture: ::protobuf::MessageField::none(),
+            error: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Event {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Event").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Event {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Event {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Event`
+pub mod event {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.Event.Type)
+    pub enum Type {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.UNSPECIFIED)
+        UNSPECIFIED = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.DEVICE_ADDED)
+        DEVICE_ADDED = 1,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.DEVICE_REMOVED)
+        DEVICE_REMOVED = 2,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.DEVICE_PATCHED)
+        DEVICE_PATCHED = 3,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CHIP_ADDED)
+        CHIP_ADDED = 4,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CHIP_REMOVED)
+        CHIP_REMOVED = 5,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.RESET)
+        RESET = 6,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.RATE_LIMITED)
+        RATE_LIMITED = 7,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CAPTURE_WATERMARK)
+        CAPTURE_WATERMARK = 8,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CAPTURE_FULL)
+        CAPTURE_FULL = 9,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.BREAKPOINT_HIT)
+        BREAKPOINT_HIT = 10,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CHIP_RESTARTED)
+        CHIP_RESTARTED = 11,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CHIP_STALLED)
+        CHIP_STALLED = 12,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CAPTURE_STARTED)
+        CAPTURE_STARTED = 13,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CAPTURE_STOPPED)
+        CAPTURE_STOPPED = 14,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CAPTURE_ROTATED)
+        CAPTURE_ROTATED = 15,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CAPTURE_REMOVED)
+        CAPTURE_REMOVED = 16,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CAPTURE_ERROR)
+        CAPTURE_ERROR = 17,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CAPTURE_EVICTED)
+        CAPTURE_EVICTED = 18,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Event.Type.CHIP_STATE_CHANGED)
+        CHIP_STATE_CHANGED = 19,
+    }
+
+    impl ::protobuf::Enum for Type {
+        const NAME: &'static str = "Type";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Type> {
+            match value {
+                0 => ::std::option::Option::Some(Type::UNSPECIFIED),
+                1 => ::std::option::Option::Some(Type::DEVICE_ADDED),
+                2 => ::std::option::Option::Some(Type::DEVICE_REMOVED),
+                3 => ::std::option::Option::Some(Type::DEVICE_PATCHED),
+                4 => ::std::option::Option::Some(Type::CHIP_ADDED),
+                5 => ::std::option::Option::Some(Type::CHIP_REMOVED),
+                6 => ::std::option::Option::Some(Type::RESET),
+                7 => ::std::option::Option::Some(Type::RATE_LIMITED),
+                8 => ::std::option::Option::Some(Type::CAPTURE_WATERMARK),
+                9 => ::std::option::Option::Some(Type::CAPTURE_FULL),
+                10 => ::std::option::Option::Some(Type::BREAKPOINT_HIT),
+                11 => ::std::option::Option::Some(Type::CHIP_RESTARTED),
+                12 => ::std::option::Option::Some(Type::CHIP_STALLED),
+                13 => ::std::option::Option::Some(Type::CAPTURE_STARTED),
+                14 => ::std::option::Option::Some(Type::CAPTURE_STOPPED),
+                15 => ::std::option::Option::Some(Type::CAPTURE_ROTATED),
+                16 => ::std::option::Option::Some(Type::CAPTURE_REMOVED),
+                17 => ::std::option::Option::Some(Type::CAPTURE_ERROR),
+                18 => ::std::option::Option::Some(Type::CAPTURE_EVICTED),
+                19 => ::std::option::Option::Some(Type::CHIP_STATE_CHANGED),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Type] = &[
+            Type::UNSPECIFIED,
+            Type::DEVICE_ADDED,
+            Type::DEVICE_REMOVED,
+            Type::DEVICE_PATCHED,
+            Type::CHIP_ADDED,
+            Type::CHIP_REMOVED,
+            Type::RESET,
+            Type::RATE_LIMITED,
+            Type::CAPTURE_WATERMARK,
+            Type::CAPTURE_FULL,
+            Type::BREAKPOINT_HIT,
+            Type::CHIP_RESTARTED,
+            Type::CHIP_STALLED,
+            Type::CAPTURE_STARTED,
+            Type::CAPTURE_STOPPED,
+            Type::CAPTURE_ROTATED,
+            Type::CAPTURE_REMOVED,
+            Type::CAPTURE_ERROR,
+            Type::CAPTURE_EVICTED,
+            Type::CHIP_STATE_CHANGED,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Type {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Event.Type").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Type {
+        fn default() -> Self {
+            Type::UNSPECIFIED
+        }
+    }
+
+    impl Type {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Type>("Event.Type")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetDevicesRequest)
+pub struct GetDevicesRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetDevicesRequest.name_filter)
+    pub name_filter: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.GetDevicesRequest.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.frontend.GetDevicesRequest.page_size)
+    pub page_size: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.GetDevicesRequest.page_token)
+    pub page_token: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetDevicesRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetDevicesRequest {
+    fn default() -> &'a GetDevicesRequest {
+        <GetDevicesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetDevicesRequest {
+    pub fn new() -> GetDevicesRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name_filter",
+            |m: &GetDevicesRequest| { &m.name_filter },
+            |m: &mut GetDevicesRequest| { &mut m.name_filter },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &GetDevicesRequest| { &m.chip_kind },
+            |m: &mut GetDevicesRequest| { &mut m.chip_kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "page_size",
+            |m: &GetDevicesRequest| { &m.page_size },
+            |m: &mut GetDevicesRequest| { &mut m.page_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "page_token",
+            |m: &GetDevicesRequest| { &m.page_token },
+            |m: &mut GetDevicesRequest| { &mut m.page_token },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetDevicesRequest>(
+            "GetDevicesRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetDevicesRequest {
+    const NAME: &'static str = "GetDevicesRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name_filter = is.read_string()?;
+                },
+                16 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                24 => {
+                    self.page_size = is.read_uint32()?;
+                },
+                34 => {
+                    self.page_token = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name_filter.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name_filter);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(2, self.chip_kind.value());
+        }
+        if self.page_size != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.page_size);
+        }
+        if !self.page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.page_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name_filter.is_empty() {
+            os.write_string(1, &self.name_filter)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        if self.page_size != 0 {
+            os.write_uint32(3, self.page_size)?;
+        }
+        if !self.page_token.is_empty() {
+            os.write_string(4, &self.page_token)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetDevicesRequest {
+        GetDevicesRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.name_filter.clear();
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.page_size = 0;
+        self.page_token.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetDevicesRequest {
+        static instance: GetDevicesRequest = GetDevicesRequest {
+            name_filter: ::std::string::String::new(),
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            page_size: 0,
+            page_token: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetDevicesRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetDevicesRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetDevicesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetDevicesRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetDevicesResponse)
+pub struct GetDevicesResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetDevicesResponse.devices)
+    pub devices: ::std::vec::Vec<super::model::Device>,
+    // @@protoc_insertion_point(field:netsim.frontend.GetDevicesResponse.next_page_token)
+    pub next_page_token: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetDevicesResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetDevicesResponse {
+    fn default() -> &'a GetDevicesResponse {
+        <GetDevicesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetDevicesResponse {
+    pub fn new() -> GetDevicesResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "devices",
+            |m: &GetDevicesResponse| { &m.devices },
+            |m: &mut GetDevicesResponse| { &mut m.devices },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "next_page_token",
+            |m: &GetDevicesResponse| { &m.next_page_token },
+            |m: &mut GetDevicesResponse| { &mut m.next_page_token },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetDevicesResponse>(
+            "GetDevicesResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetDevicesResponse {
+    const NAME: &'static str = "GetDevicesResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.devices.push(is.read_message()?);
+                },
+                18 => {
+                    self.next_page_token = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.devices {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.next_page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.next_page_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.devices {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if !self.next_page_token.is_empty() {
+            os.write_string(2, &self.next_page_token)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetDevicesResponse {
+        GetDevicesResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.devices.clear();
+        self.next_page_token.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetDevicesResponse {
+        static instance: GetDevicesResponse = GetDevicesResponse {
+            devices: ::std::vec::Vec::new(),
+            next_page_token: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetDevicesResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetDevicesResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetDevicesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetDevicesResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.NetCatStream)
+pub struct NetCatStream {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.NetCatStream.result)
+    pub result: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.NetCatStream.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a NetCatStream {
+    fn default() -> &'a NetCatStream {
+        <NetCatStream as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NetCatStream {
+    pub fn new() -> NetCatStream {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "result",
+            |m: &NetCatStream| { &m.result },
+            |m: &mut NetCatStream| { &mut m.result },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NetCatStream>(
+            "NetCatStream",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for NetCatStream {
+    const NAME: &'static str = "NetCatStream";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.result.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.result {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.result {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> NetCatStream {
+        NetCatStream::new()
+    }
+
+    fn clear(&mut self) {
+        self.result.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static NetCatStream {
+        static instance: NetCatStream = NetCatStream {
+            result: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for NetCatStream {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("NetCatStream").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for NetCatStream {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NetCatStream {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.SetPacketCaptureRequest)
+pub struct SetPacketCaptureRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.SetPacketCaptureRequest.capture)
+    pub capture: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.SetPacketCaptureRequest.device_serial)
+    pub device_serial: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.SetPacketCaptureRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SetPacketCaptureRequest {
+    fn default() -> &'a SetPacketCaptureRequest {
+        <SetPacketCaptureRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetPacketCaptureRequest {
+    pub fn new() -> SetPacketCaptureRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "capture",
+            |m: &SetPacketCaptureRequest| { &m.capture },
+            |m: &mut SetPacketCaptureRequest| { &mut m.capture },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_serial",
+            |m: &SetPacketCaptureRequest| { &m.device_serial },
+            |m: &mut SetPacketCaptureRequest| { &mut m.device_serial },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetPacketCaptureRequest>(
+            "SetPacketCaptureRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SetPacketCaptureRequest {
+    const NAME: &'static str = "SetPacketCaptureRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.capture = is.read_bool()?;
+                },
+                18 => {
+                    self.device_serial = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.capture != false {
+            my_size += 1 + 1;
+        }
+        if !self.device_serial.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.device_serial);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.capture != false {
+            os.write_bool(1, self.capture)?;
+        }
+        if !self.device_serial.is_empty() {
+            os.write_string(2, &self.device_serial)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SetPacketCaptureRequest {
+        SetPacketCaptureRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.capture = false;
+        self.device_serial.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SetPacketCaptureRequest {
+        static instance: SetPacketCaptureRequest = SetPacketCaptureRequest {
+            capture: false,
+            device_serial: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SetPacketCaptureRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SetPacketCaptureRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SetPacketCaptureRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetPacketCaptureRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.PatchCaptureRequest)
+pub struct PatchCaptureRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.id)
+    pub id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.patch)
+    pub patch: ::protobuf::MessageField<patch_capture_request::PatchCapture>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.PatchCaptureRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PatchCaptureRequest {
+    fn default() -> &'a PatchCaptureRequest {
+        <PatchCaptureRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PatchCaptureRequest {
+    pub fn new() -> PatchCaptureRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &PatchCaptureRequest| { &m.id },
+            |m: &mut PatchCaptureRequest| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, patch_capture_request::PatchCapture>(
+            "patch",
+            |m: &PatchCaptureRequest| { &m.patch },
+            |m: &mut PatchCaptureRequest| { &mut m.patch },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchCaptureRequest>(
+            "PatchCaptureRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PatchCaptureRequest {
+    const NAME: &'static str = "PatchCaptureRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_int32()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.patch)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.id);
+        }
+        if let Some(v) = self.patch.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_int32(1, self.id)?;
+        }
+        if let Some(v) = self.patch.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PatchCaptureRequest {
+        PatchCaptureRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.patch.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PatchCaptureRequest {
+        static instance: PatchCaptureRequest = PatchCaptureRequest {
+            id: 0,
+            patch: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PatchCaptureRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PatchCaptureRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PatchCaptureRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PatchCaptureRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `PatchCaptureRequest`
+pub mod patch_capture_request {
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.PatchCaptureRequest.PatchCapture)
+    pub struct PatchCapture {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.state)
+        pub state: ::protobuf::EnumOrUnknown<super::super::model::State>,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.sampling)
+        pub sampling: ::protobuf::MessageField<super::super::model::capture::Sampling>,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.format)
+        pub format: ::protobuf::EnumOrUnknown<super::super::model::capture::Format>,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.filter)
+        pub filter: ::protobuf::MessageField<super::super::model::capture::Filter>,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.mode)
+        pub mode: ::protobuf::EnumOrUnknown<super::super::model::capture::Mode>,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.dump)
+        pub dump: bool,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.snaplen)
+        pub snaplen: u32,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.live_path)
+        pub live_path: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.append)
+        pub append: bool,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.update_mask)
+        pub update_mask: ::protobuf::MessageField<::protobuf::well_known_types::field_mask::FieldMask>,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.PatchCaptureRequest.PatchCapture.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a PatchCapture {
+        fn default() -> &'a PatchCapture {
+            <PatchCapture as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl PatchCapture {
+        pub fn new() -> PatchCapture {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(10);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "state",
+                |m: &PatchCapture| { &m.state },
+                |m: &mut PatchCapture| { &mut m.state },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::super::model::capture::Sampling>(
+                "sampling",
+                |m: &PatchCapture| { &m.sampling },
+                |m: &mut PatchCapture| { &mut m.sampling },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "format",
+                |m: &PatchCapture| { &m.format },
+                |m: &mut PatchCapture| { &mut m.format },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::super::model::capture::Filter>(
+                "filter",
+                |m: &PatchCapture| { &m.filter },
+                |m: &mut PatchCapture| { &mut m.filter },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "mode",
+                |m: &PatchCapture| { &m.mode },
+                |m: &mut PatchCapture| { &mut m.mode },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "dump",
+                |m: &PatchCapture| { &m.dump },
+                |m: &mut PatchCapture| { &mut m.dump },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "snaplen",
+                |m: &PatchCapture| { &m.snaplen },
+                |m: &mut PatchCapture| { &mut m.snaplen },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "live_path",
+                |m: &PatchCapture| { &m.live_path },
+                |m: &mut PatchCapture| { &mut m.live_path },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "append",
+                |m: &PatchCapture| { &m.append },
+                |m: &mut PatchCapture| { &mut m.append },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::field_mask::FieldMask>(
+                "update_mask",
+                |m: &PatchCapture| { &m.update_mask },
+                |m: &mut PatchCapture| { &mut m.update_mask },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchCapture>(
+                "PatchCaptureRequest.PatchCapture",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for PatchCapture {
+        const NAME: &'static str = "PatchCapture";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
                         self.state = is.read_enum_or_unknown()?;
                     },
+                    18 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.sampling)?;
+                    },
+                    24 => {
+                        self.format = is.read_enum_or_unknown()?;
+                    },
+                    34 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.filter)?;
+                    },
+                    40 => {
+                        self.mode = is.read_enum_or_unknown()?;
+                    },
+                    48 => {
+                        self.dump = is.read_bool()?;
+                    },
+                    56 => {
+                        self.snaplen = is.read_uint32()?;
+                    },
+                    66 => {
+                        self.live_path = is.read_string()?;
+                    },
+                    72 => {
+                        self.append = is.read_bool()?;
+                    },
+                    82 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.update_mask)?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.state != ::protobuf::EnumOrUnknown::new(super::super::model::State::UNKNOWN) {
+                my_size += ::protobuf::rt::int32_size(1, self.state.value());
+            }
+            if let Some(v) = self.sampling.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
+            if self.format != ::protobuf::EnumOrUnknown::new(super::super::model::capture::Format::PCAP) {
+                my_size += ::protobuf::rt::int32_size(3, self.format.value());
+            }
+            if let Some(v) = self.filter.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
+            if self.mode != ::protobuf::EnumOrUnknown::new(super::super::model::capture::Mode::FILE) {
+                my_size += ::protobuf::rt::int32_size(5, self.mode.value());
+            }
+            if self.dump != false {
+                my_size += 1 + 1;
+            }
+            if self.snaplen != 0 {
+                my_size += ::protobuf::rt::uint32_size(7, self.snaplen);
+            }
+            if !self.live_path.is_empty() {
+                my_size += ::protobuf::rt::string_size(8, &self.live_path);
+            }
+            if self.append != false {
+                my_size += 1 + 1;
+            }
+            if let Some(v) = self.update_mask.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.state != ::protobuf::EnumOrUnknown::new(super::super::model::State::UNKNOWN) {
+                os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.state))?;
+            }
+            if let Some(v) = self.sampling.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+            }
+            if self.format != ::protobuf::EnumOrUnknown::new(super::super::model::capture::Format::PCAP) {
+                os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.format))?;
+            }
+            if let Some(v) = self.filter.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+            }
+            if self.mode != ::protobuf::EnumOrUnknown::new(super::super::model::capture::Mode::FILE) {
+                os.write_enum(5, ::protobuf::EnumOrUnknown::value(&self.mode))?;
+            }
+            if self.dump != false {
+                os.write_bool(6, self.dump)?;
+            }
+            if self.snaplen != 0 {
+                os.write_uint32(7, self.snaplen)?;
+            }
+            if !self.live_path.is_empty() {
+                os.write_string(8, &self.live_path)?;
+            }
+            if self.append != false {
+                os.write_bool(9, self.append)?;
+            }
+            if let Some(v) = self.update_mask.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> PatchCapture {
+            PatchCapture::new()
+        }
+
+        fn clear(&mut self) {
+            self.state = ::protobuf::EnumOrUnknown::new(super::super::model::State::UNKNOWN);
+            self.sampling.clear();
+            self.format = ::protobuf::EnumOrUnknown::new(super::super::model::capture::Format::PCAP);
+            self.filter.clear();
+            self.mode = ::protobuf::EnumOrUnknown::new(super::super::model::capture::Mode::FILE);
+            self.dump = false;
+            self.snaplen = 0;
+            self.live_path.clear();
+            self.append = false;
+            self.update_mask.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static PatchCapture {
+            static instance: PatchCapture = PatchCapture {
+                state: ::protobuf::EnumOrUnknown::from_i32(0),
+                sampling: ::protobuf::MessageField::none(),
+                format: ::protobuf::EnumOrUnknown::from_i32(0),
+                filter: ::protobuf::MessageField::none(),
+                mode: ::protobuf::EnumOrUnknown::from_i32(0),
+                dump: false,
+                snaplen: 0,
+                live_path: ::std::string::String::new(),
+                append: false,
+                update_mask: ::protobuf::MessageField::none(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for PatchCapture {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("PatchCaptureRequest.PatchCapture").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for PatchCapture {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for PatchCapture {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ListCaptureRequest)
+pub struct ListCaptureRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ListCaptureRequest.device_name_filter)
+    pub device_name_filter: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ListCaptureRequest.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.frontend.ListCaptureRequest.state)
+    pub state: ::protobuf::EnumOrUnknown<super::model::State>,
+    // @@protoc_insertion_point(field:netsim.frontend.ListCaptureRequest.page_size)
+    pub page_size: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.ListCaptureRequest.page_token)
+    pub page_token: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ListCaptureRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListCaptureRequest {
+    fn default() -> &'a ListCaptureRequest {
+        <ListCaptureRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListCaptureRequest {
+    pub fn new() -> ListCaptureRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name_filter",
+            |m: &ListCaptureRequest| { &m.device_name_filter },
+            |m: &mut ListCaptureRequest| { &mut m.device_name_filter },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &ListCaptureRequest| { &m.chip_kind },
+            |m: &mut ListCaptureRequest| { &mut m.chip_kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "state",
+            |m: &ListCaptureRequest| { &m.state },
+            |m: &mut ListCaptureRequest| { &mut m.state },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "page_size",
+            |m: &ListCaptureRequest| { &m.page_size },
+            |m: &mut ListCaptureRequest| { &mut m.page_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "page_token",
+            |m: &ListCaptureRequest| { &m.page_token },
+            |m: &mut ListCaptureRequest| { &mut m.page_token },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListCaptureRequest>(
+            "ListCaptureRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListCaptureRequest {
+    const NAME: &'static str = "ListCaptureRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.device_name_filter = is.read_string()?;
+                },
+                16 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                24 => {
+                    self.state = is.read_enum_or_unknown()?;
+                },
+                32 => {
+                    self.page_size = is.read_uint32()?;
+                },
+                42 => {
+                    self.page_token = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.device_name_filter.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device_name_filter);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(2, self.chip_kind.value());
+        }
+        if self.state != ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(3, self.state.value());
+        }
+        if self.page_size != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.page_size);
+        }
+        if !self.page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.page_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.device_name_filter.is_empty() {
+            os.write_string(1, &self.device_name_filter)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        if self.state != ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.state))?;
+        }
+        if self.page_size != 0 {
+            os.write_uint32(4, self.page_size)?;
+        }
+        if !self.page_token.is_empty() {
+            os.write_string(5, &self.page_token)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListCaptureRequest {
+        ListCaptureRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.device_name_filter.clear();
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.state = ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN);
+        self.page_size = 0;
+        self.page_token.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListCaptureRequest {
+        static instance: ListCaptureRequest = ListCaptureRequest {
+            device_name_filter: ::std::string::String::new(),
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            state: ::protobuf::EnumOrUnknown::from_i32(0),
+            page_size: 0,
+            page_token: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListCaptureRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListCaptureRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListCaptureRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListCaptureRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ListCaptureResponse)
+pub struct ListCaptureResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ListCaptureResponse.captures)
+    pub captures: ::std::vec::Vec<super::model::Capture>,
+    // @@protoc_insertion_point(field:netsim.frontend.ListCaptureResponse.next_page_token)
+    pub next_page_token: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ListCaptureResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListCaptureResponse {
+    fn default() -> &'a ListCaptureResponse {
+        <ListCaptureResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListCaptureResponse {
+    pub fn new() -> ListCaptureResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "captures",
+            |m: &ListCaptureResponse| { &m.captures },
+            |m: &mut ListCaptureResponse| { &mut m.captures },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "next_page_token",
+            |m: &ListCaptureResponse| { &m.next_page_token },
+            |m: &mut ListCaptureResponse| { &mut m.next_page_token },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListCaptureResponse>(
+            "ListCaptureResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListCaptureResponse {
+    const NAME: &'static str = "ListCaptureResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.captures.push(is.read_message()?);
+                },
+                18 => {
+                    self.next_page_token = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.captures {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.next_page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.next_page_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.captures {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if !self.next_page_token.is_empty() {
+            os.write_string(2, &self.next_page_token)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListCaptureResponse {
+        ListCaptureResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.captures.clear();
+        self.next_page_token.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListCaptureResponse {
+        static instance: ListCaptureResponse = ListCaptureResponse {
+            captures: ::std::vec::Vec::new(),
+            next_page_token: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListCaptureResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListCaptureResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListCaptureResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListCaptureResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetCaptureRequest)
+pub struct GetCaptureRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetCaptureRequest.id)
+    pub id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.GetCaptureRequest.segment)
+    pub segment: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.GetCaptureRequest.offset)
+    pub offset: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.GetCaptureRequest.length)
+    pub length: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetCaptureRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetCaptureRequest {
+    fn default() -> &'a GetCaptureRequest {
+        <GetCaptureRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetCaptureRequest {
+    pub fn new() -> GetCaptureRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &GetCaptureRequest| { &m.id },
+            |m: &mut GetCaptureRequest| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "segment",
+            |m: &GetCaptureRequest| { &m.segment },
+            |m: &mut GetCaptureRequest| { &mut m.segment },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &GetCaptureRequest| { &m.offset },
+            |m: &mut GetCaptureRequest| { &mut m.offset },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "length",
+            |m: &GetCaptureRequest| { &m.length },
+            |m: &mut GetCaptureRequest| { &mut m.length },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetCaptureRequest>(
+            "GetCaptureRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetCaptureRequest {
+    const NAME: &'static str = "GetCaptureRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_int32()?;
+                },
+                16 => {
+                    self.segment = is.read_uint32()?;
+                },
+                24 => {
+                    self.offset = is.read_uint64()?;
+                },
+                32 => {
+                    self.length = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.id);
+        }
+        if self.segment != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.segment);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.offset);
+        }
+        if self.length != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.length);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_int32(1, self.id)?;
+        }
+        if self.segment != 0 {
+            os.write_uint32(2, self.segment)?;
+        }
+        if self.offset != 0 {
+            os.write_uint64(3, self.offset)?;
+        }
+        if self.length != 0 {
+            os.write_uint64(4, self.length)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetCaptureRequest {
+        GetCaptureRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.segment = 0;
+        self.offset = 0;
+        self.length = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetCaptureRequest {
+        static instance: GetCaptureRequest = GetCaptureRequest {
+            id: 0,
+            segment: 0,
+            offset: 0,
+            length: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetCaptureRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetCaptureRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetCaptureRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetCaptureRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetCaptureResponse)
+pub struct GetCaptureResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetCaptureResponse.capture_stream)
+    pub capture_stream: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetCaptureResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetCaptureResponse {
+    fn default() -> &'a GetCaptureResponse {
+        <GetCaptureResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetCaptureResponse {
+    pub fn new() -> GetCaptureResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "capture_stream",
+            |m: &GetCaptureResponse| { &m.capture_stream },
+            |m: &mut GetCaptureResponse| { &mut m.capture_stream },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetCaptureResponse>(
+            "GetCaptureResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetCaptureResponse {
+    const NAME: &'static str = "GetCaptureResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.capture_stream = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.capture_stream.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.capture_stream);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.capture_stream.is_empty() {
+            os.write_bytes(1, &self.capture_stream)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetCaptureResponse {
+        GetCaptureResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.capture_stream.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetCaptureResponse {
+        static instance: GetCaptureResponse = GetCaptureResponse {
+            capture_stream: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetCaptureResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetCaptureResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetCaptureResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetCaptureResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.StreamCaptureRequest)
+pub struct StreamCaptureRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.StreamCaptureRequest.chip_id)
+    pub chip_id: i32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.StreamCaptureRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a StreamCaptureRequest {
+    fn default() -> &'a StreamCaptureRequest {
+        <StreamCaptureRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StreamCaptureRequest {
+    pub fn new() -> StreamCaptureRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_id",
+            |m: &StreamCaptureRequest| { &m.chip_id },
+            |m: &mut StreamCaptureRequest| { &mut m.chip_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<StreamCaptureRequest>(
+            "StreamCaptureRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for StreamCaptureRequest {
+    const NAME: &'static str = "StreamCaptureRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.chip_id = is.read_int32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.chip_id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.chip_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.chip_id != 0 {
+            os.write_int32(1, self.chip_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> StreamCaptureRequest {
+        StreamCaptureRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.chip_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static StreamCaptureRequest {
+        static instance: StreamCaptureRequest = StreamCaptureRequest {
+            chip_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for StreamCaptureRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("StreamCaptureRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for StreamCaptureRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StreamCaptureRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.CaptureAll)
+pub struct CaptureAll {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureAll.enabled)
+    pub enabled: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureAll.format)
+    pub format: ::protobuf::EnumOrUnknown<super::model::capture::Format>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.CaptureAll.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CaptureAll {
+    fn default() -> &'a CaptureAll {
+        <CaptureAll as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CaptureAll {
+    pub fn new() -> CaptureAll {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "enabled",
+            |m: &CaptureAll| { &m.enabled },
+            |m: &mut CaptureAll| { &mut m.enabled },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "format",
+            |m: &CaptureAll| { &m.format },
+            |m: &mut CaptureAll| { &mut m.format },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CaptureAll>(
+            "CaptureAll",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CaptureAll {
+    const NAME: &'static str = "CaptureAll";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.enabled = is.read_bool()?;
+                },
+                16 => {
+                    self.format = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.enabled != false {
+            my_size += 1 + 1;
+        }
+        if self.format != ::protobuf::EnumOrUnknown::new(super::model::capture::Format::PCAP) {
+            my_size += ::protobuf::rt::int32_size(2, self.format.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.enabled != false {
+            os.write_bool(1, self.enabled)?;
+        }
+        if self.format != ::protobuf::EnumOrUnknown::new(super::model::capture::Format::PCAP) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.format))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CaptureAll {
+        CaptureAll::new()
+    }
+
+    fn clear(&mut self) {
+        self.enabled = false;
+        self.format = ::protobuf::EnumOrUnknown::new(super::model::capture::Format::PCAP);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CaptureAll {
+        static instance: CaptureAll = CaptureAll {
+            enabled: false,
+            format: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CaptureAll {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CaptureAll").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CaptureAll {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CaptureAll {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.AggregateCapture)
+pub struct AggregateCapture {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.AggregateCapture.state)
+    pub state: ::protobuf::EnumOrUnknown<super::model::State>,
+    // @@protoc_insertion_point(field:netsim.frontend.AggregateCapture.path)
+    pub path: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.AggregateCapture.size)
+    pub size: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.AggregateCapture.records)
+    pub records: i32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.AggregateCapture.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AggregateCapture {
+    fn default() -> &'a AggregateCapture {
+        <AggregateCapture as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AggregateCapture {
+    pub fn new() -> AggregateCapture {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "state",
+            |m: &AggregateCapture| { &m.state },
+            |m: &mut AggregateCapture| { &mut m.state },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "path",
+            |m: &AggregateCapture| { &m.path },
+            |m: &mut AggregateCapture| { &mut m.path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &AggregateCapture| { &m.size },
+            |m: &mut AggregateCapture| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "records",
+            |m: &AggregateCapture| { &m.records },
+            |m: &mut AggregateCapture| { &mut m.records },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AggregateCapture>(
+            "AggregateCapture",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AggregateCapture {
+    const NAME: &'static str = "AggregateCapture";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.state = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.path = is.read_string()?;
+                },
+                24 => {
+                    self.size = is.read_int32()?;
+                },
+                32 => {
+                    self.records = is.read_int32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.state != ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.state.value());
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.path);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::int32_size(3, self.size);
+        }
+        if self.records != 0 {
+            my_size += ::protobuf::rt::int32_size(4, self.records);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.state != ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.state))?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(2, &self.path)?;
+        }
+        if self.size != 0 {
+            os.write_int32(3, self.size)?;
+        }
+        if self.records != 0 {
+            os.write_int32(4, self.records)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AggregateCapture {
+        AggregateCapture::new()
+    }
+
+    fn clear(&mut self) {
+        self.state = ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN);
+        self.path.clear();
+        self.size = 0;
+        self.records = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AggregateCapture {
+        static instance: AggregateCapture = AggregateCapture {
+            state: ::protobuf::EnumOrUnknown::from_i32(0),
+            path: ::std::string::String::new(),
+            size: 0,
+            records: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AggregateCapture {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AggregateCapture").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AggregateCapture {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AggregateCapture {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.CaptureStats)
+pub struct CaptureStats {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureStats.id)
+    pub id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureStats.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureStats.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureStats.state)
+    pub state: ::protobuf::EnumOrUnknown<super::model::State>,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureStats.bytes_per_second)
+    pub bytes_per_second: f64,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureStats.packets_per_second)
+    pub packets_per_second: f64,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureStats.size)
+    pub size: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureStats.records)
+    pub records: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureStats.last_record)
+    pub last_record: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureStats.dropped)
+    pub dropped: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.CaptureStats.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CaptureStats {
+    fn default() -> &'a CaptureStats {
+        <CaptureStats as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CaptureStats {
+    pub fn new() -> CaptureStats {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(10);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &CaptureStats| { &m.id },
+            |m: &mut CaptureStats| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &CaptureStats| { &m.device_name },
+            |m: &mut CaptureStats| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &CaptureStats| { &m.chip_kind },
+            |m: &mut CaptureStats| { &mut m.chip_kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "state",
+            |m: &CaptureStats| { &m.state },
+            |m: &mut CaptureStats| { &mut m.state },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bytes_per_second",
+            |m: &CaptureStats| { &m.bytes_per_second },
+            |m: &mut CaptureStats| { &mut m.bytes_per_second },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "packets_per_second",
+            |m: &CaptureStats| { &m.packets_per_second },
+            |m: &mut CaptureStats| { &mut m.packets_per_second },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &CaptureStats| { &m.size },
+            |m: &mut CaptureStats| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "records",
+            |m: &CaptureStats| { &m.records },
+            |m: &mut CaptureStats| { &mut m.records },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "last_record",
+            |m: &CaptureStats| { &m.last_record },
+            |m: &mut CaptureStats| { &mut m.last_record },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "dropped",
+            |m: &CaptureStats| { &m.dropped },
+            |m: &mut CaptureStats| { &mut m.dropped },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CaptureStats>(
+            "CaptureStats",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CaptureStats {
+    const NAME: &'static str = "CaptureStats";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_int32()?;
+                },
+                18 => {
+                    self.device_name = is.read_string()?;
+                },
+                24 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                32 => {
+                    self.state = is.read_enum_or_unknown()?;
+                },
+                41 => {
+                    self.bytes_per_second = is.read_double()?;
+                },
+                49 => {
+                    self.packets_per_second = is.read_double()?;
+                },
+                56 => {
+                    self.size = is.read_int32()?;
+                },
+                64 => {
+                    self.records = is.read_int32()?;
+                },
+                74 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.last_record)?;
+                },
+                80 => {
+                    self.dropped = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.id);
+        }
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.device_name);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(3, self.chip_kind.value());
+        }
+        if self.state != ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(4, self.state.value());
+        }
+        if self.bytes_per_second != 0. {
+            my_size += 1 + 8;
+        }
+        if self.packets_per_second != 0. {
+            my_size += 1 + 8;
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::int32_size(7, self.size);
+        }
+        if self.records != 0 {
+            my_size += ::protobuf::rt::int32_size(8, self.records);
+        }
+        if let Some(v) = self.last_record.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.dropped != 0 {
+            my_size += ::protobuf::rt::uint64_size(10, self.dropped);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_int32(1, self.id)?;
+        }
+        if !self.device_name.is_empty() {
+            os.write_string(2, &self.device_name)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        if self.state != ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.state))?;
+        }
+        if self.bytes_per_second != 0. {
+            os.write_double(5, self.bytes_per_second)?;
+        }
+        if self.packets_per_second != 0. {
+            os.write_double(6, self.packets_per_second)?;
+        }
+        if self.size != 0 {
+            os.write_int32(7, self.size)?;
+        }
+        if self.records != 0 {
+            os.write_int32(8, self.records)?;
+        }
+        if let Some(v) = self.last_record.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
+        }
+        if self.dropped != 0 {
+            os.write_uint64(10, self.dropped)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CaptureStats {
+        CaptureStats::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.device_name.clear();
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.state = ::protobuf::EnumOrUnknown::new(super::model::State::UNKNOWN);
+        self.bytes_per_second = 0.;
+        self.packets_per_second = 0.;
+        self.size = 0;
+        self.records = 0;
+        self.last_record.clear();
+        self.dropped = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CaptureStats {
+        static instance: CaptureStats = CaptureStats {
+            id: 0,
+            device_name: ::std::string::String::new(),
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            state: ::protobuf::EnumOrUnknown::from_i32(0),
+            bytes_per_second: 0.,
+            packets_per_second: 0.,
+            size: 0,
+            records: 0,
+            last_record: ::protobuf::MessageField::none(),
+            dropped: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CaptureStats {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CaptureStats").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CaptureStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CaptureStats {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetCaptureStatsResponse)
+pub struct GetCaptureStatsResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetCaptureStatsResponse.captures)
+    pub captures: ::std::vec::Vec<CaptureStats>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetCaptureStatsResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetCaptureStatsResponse {
+    fn default() -> &'a GetCaptureStatsResponse {
+        <GetCaptureStatsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetCaptureStatsResponse {
+    pub fn new() -> GetCaptureStatsResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "captures",
+            |m: &GetCaptureStatsResponse| { &m.captures },
+            |m: &mut GetCaptureStatsResponse| { &mut m.captures },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetCaptureStatsResponse>(
+            "GetCaptureStatsResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetCaptureStatsResponse {
+    const NAME: &'static str = "GetCaptureStatsResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.captures.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.captures {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.captures {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetCaptureStatsResponse {
+        GetCaptureStatsResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.captures.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetCaptureStatsResponse {
+        static instance: GetCaptureStatsResponse = GetCaptureStatsResponse {
+            captures: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetCaptureStatsResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetCaptureStatsResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetCaptureStatsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetCaptureStatsResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ReplayCaptureRequest)
+pub struct ReplayCaptureRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayCaptureRequest.chip_id)
+    pub chip_id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayCaptureRequest.path)
+    pub path: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayCaptureRequest.speed)
+    pub speed: f64,
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayCaptureRequest.burst)
+    pub burst: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ReplayCaptureRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReplayCaptureRequest {
+    fn default() -> &'a ReplayCaptureRequest {
+        <ReplayCaptureRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReplayCaptureRequest {
+    pub fn new() -> ReplayCaptureRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_id",
+            |m: &ReplayCaptureRequest| { &m.chip_id },
+            |m: &mut ReplayCaptureRequest| { &mut m.chip_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "path",
+            |m: &ReplayCaptureRequest| { &m.path },
+            |m: &mut ReplayCaptureRequest| { &mut m.path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "speed",
+            |m: &ReplayCaptureRequest| { &m.speed },
+            |m: &mut ReplayCaptureRequest| { &mut m.speed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "burst",
+            |m: &ReplayCaptureRequest| { &m.burst },
+            |m: &mut ReplayCaptureRequest| { &mut m.burst },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReplayCaptureRequest>(
+            "ReplayCaptureRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReplayCaptureRequest {
+    const NAME: &'static str = "ReplayCaptureRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.chip_id = is.read_int32()?;
+                },
+                18 => {
+                    self.path = is.read_string()?;
+                },
+                25 => {
+                    self.speed = is.read_double()?;
+                },
+                32 => {
+                    self.burst = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.chip_id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.chip_id);
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.path);
+        }
+        if self.speed != 0. {
+            my_size += 1 + 8;
+        }
+        if self.burst != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.chip_id != 0 {
+            os.write_int32(1, self.chip_id)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(2, &self.path)?;
+        }
+        if self.speed != 0. {
+            os.write_double(3, self.speed)?;
+        }
+        if self.burst != false {
+            os.write_bool(4, self.burst)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReplayCaptureRequest {
+        ReplayCaptureRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.chip_id = 0;
+        self.path.clear();
+        self.speed = 0.;
+        self.burst = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReplayCaptureRequest {
+        static instance: ReplayCaptureRequest = ReplayCaptureRequest {
+            chip_id: 0,
+            path: ::std::string::String::new(),
+            speed: 0.,
+            burst: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReplayCaptureRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReplayCaptureRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReplayCaptureRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReplayCaptureRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ReplayCaptureResponse)
+pub struct ReplayCaptureResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayCaptureResponse.injected)
+    pub injected: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayCaptureResponse.skipped)
+    pub skipped: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ReplayCaptureResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReplayCaptureResponse {
+    fn default() -> &'a ReplayCaptureResponse {
+        <ReplayCaptureResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReplayCaptureResponse {
+    pub fn new() -> ReplayCaptureResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "injected",
+            |m: &ReplayCaptureResponse| { &m.injected },
+            |m: &mut ReplayCaptureResponse| { &mut m.injected },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "skipped",
+            |m: &ReplayCaptureResponse| { &m.skipped },
+            |m: &mut ReplayCaptureResponse| { &mut m.skipped },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReplayCaptureResponse>(
+            "ReplayCaptureResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReplayCaptureResponse {
+    const NAME: &'static str = "ReplayCaptureResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.injected = is.read_uint64()?;
+                },
+                16 => {
+                    self.skipped = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.injected != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.injected);
+        }
+        if self.skipped != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.skipped);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.injected != 0 {
+            os.write_uint64(1, self.injected)?;
+        }
+        if self.skipped != 0 {
+            os.write_uint64(2, self.skipped)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReplayCaptureResponse {
+        ReplayCaptureResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.injected = 0;
+        self.skipped = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReplayCaptureResponse {
+        static instance: ReplayCaptureResponse = ReplayCaptureResponse {
+            injected: 0,
+            skipped: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReplayCaptureResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReplayCaptureResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReplayCaptureResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReplayCaptureResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.DeleteCaptureRequest)
+pub struct DeleteCaptureRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.DeleteCaptureRequest.id)
+    pub id: i32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.DeleteCaptureRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteCaptureRequest {
+    fn default() -> &'a DeleteCaptureRequest {
+        <DeleteCaptureRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteCaptureRequest {
+    pub fn new() -> DeleteCaptureRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &DeleteCaptureRequest| { &m.id },
+            |m: &mut DeleteCaptureRequest| { &mut m.id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteCaptureRequest>(
+            "DeleteCaptureRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeleteCaptureRequest {
+    const NAME: &'static str = "DeleteCaptureRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_int32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_int32(1, self.id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeleteCaptureRequest {
+        DeleteCaptureRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeleteCaptureRequest {
+        static instance: DeleteCaptureRequest = DeleteCaptureRequest {
+            id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeleteCaptureRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteCaptureRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeleteCaptureRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteCaptureRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ScenarioAssertion)
+pub struct ScenarioAssertion {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ScenarioAssertion.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ScenarioAssertion.timeout_ms)
+    pub timeout_ms: u32,
+    // message oneof groups
+    pub assertion: ::std::option::Option<scenario_assertion::Assertion>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ScenarioAssertion.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ScenarioAssertion {
+    fn default() -> &'a ScenarioAssertion {
+        <ScenarioAssertion as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScenarioAssertion {
+    pub fn new() -> ScenarioAssertion {
+        ::std::default::Default::default()
+    }
+
+    // .netsim.frontend.ScenarioAssertion.PacketSeen packet_seen = 3;
+
+    pub fn packet_seen(&self) -> &scenario_assertion::PacketSeen {
+        match self.assertion {
+            ::std::option::Option::Some(scenario_assertion::Assertion::PacketSeen(ref v)) => v,
+            _ => <scenario_assertion::PacketSeen as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_packet_seen(&mut self) {
+        self.assertion = ::std::option::Option::None;
+    }
+
+    pub fn has_packet_seen(&self) -> bool {
+        match self.assertion {
+            ::std::option::Option::Some(scenario_assertion::Assertion::PacketSeen(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_packet_seen(&mut self, v: scenario_assertion::PacketSeen) {
+        self.assertion = ::std::option::Option::Some(scenario_assertion::Assertion::PacketSeen(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_packet_seen(&mut self) -> &mut scenario_assertion::PacketSeen {
+        if let ::std::option::Option::Some(scenario_assertion::Assertion::PacketSeen(_)) = self.assertion {
+        } else {
+            self.assertion = ::std::option::Option::Some(scenario_assertion::Assertion::PacketSeen(scenario_assertion::PacketSeen::new()));
+        }
+        match self.assertion {
+            ::std::option::Option::Some(scenario_assertion::Assertion::PacketSeen(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_packet_seen(&mut self) -> scenario_assertion::PacketSeen {
+        if self.has_packet_seen() {
+            match self.assertion.take() {
+                ::std::option::Option::Some(scenario_assertion::Assertion::PacketSeen(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            scenario_assertion::PacketSeen::new()
+        }
+    }
+
+    // .netsim.frontend.ScenarioAssertion.DeviceState device_state = 4;
+
+    pub fn device_state(&self) -> &scenario_assertion::DeviceState {
+        match self.assertion {
+            ::std::option::Option::Some(scenario_assertion::Assertion::DeviceState(ref v)) => v,
+            _ => <scenario_assertion::DeviceState as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_device_state(&mut self) {
+        self.assertion = ::std::option::Option::None;
+    }
+
+    pub fn has_device_state(&self) -> bool {
+        match self.assertion {
+            ::std::option::Option::Some(scenario_assertion::Assertion::DeviceState(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_state(&mut self, v: scenario_assertion::DeviceState) {
+        self.assertion = ::std::option::Option::Some(scenario_assertion::Assertion::DeviceState(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_device_state(&mut self) -> &mut scenario_assertion::DeviceState {
+        if let ::std::option::Option::Some(scenario_assertion::Assertion::DeviceState(_)) = self.assertion {
+        } else {
+            self.assertion = ::std::option::Option::Some(scenario_assertion::Assertion::DeviceState(scenario_assertion::DeviceState::new()));
+        }
+        match self.assertion {
+            ::std::option::Option::Some(scenario_assertion::Assertion::DeviceState(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_device_state(&mut self) -> scenario_assertion::DeviceState {
+        if self.has_device_state() {
+            match self.assertion.take() {
+                ::std::option::Option::Some(scenario_assertion::Assertion::DeviceState(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            scenario_assertion::DeviceState::new()
+        }
+    }
+
+    // .netsim.frontend.ScenarioAssertion.CounterExceeded counter_exceeded = 5;
+
+    pub fn counter_exceeded(&self) -> &scenario_assertion::CounterExceeded {
+        match self.assertion {
+            ::std::option::Option::Some(scenario_assertion::Assertion::CounterExceeded(ref v)) => v,
+            _ => <scenario_assertion::CounterExceeded as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_counter_exceeded(&mut self) {
+        self.assertion = ::std::option::Option::None;
+    }
+
+    pub fn has_counter_exceeded(&self) -> bool {
+        match self.assertion {
+            ::std::option::Option::Some(scenario_assertion::Assertion::CounterExceeded(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counter_exceeded(&mut self, v: scenario_assertion::CounterExceeded) {
+        self.assertion = ::std::option::Option::Some(scenario_assertion::Assertion::CounterExceeded(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_counter_exceeded(&mut self) -> &mut scenario_assertion::CounterExceeded {
+        if let ::std::option::Option::Some(scenario_assertion::Assertion::CounterExceeded(_)) = self.assertion {
+        } else {
+            self.assertion = ::std::option::Option::Some(scenario_assertion::Assertion::CounterExceeded(scenario_assertion::CounterExceeded::new()));
+        }
+        match self.assertion {
+            ::std::option::Option::Some(scenario_assertion::Assertion::CounterExceeded(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_counter_exceeded(&mut self) -> scenario_assertion::CounterExceeded {
+        if self.has_counter_exceeded() {
+            match self.assertion.take() {
+                ::std::option::Option::Some(scenario_assertion::Assertion::CounterExceeded(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            scenario_assertion::CounterExceeded::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &ScenarioAssertion| { &m.name },
+            |m: &mut ScenarioAssertion| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timeout_ms",
+            |m: &ScenarioAssertion| { &m.timeout_ms },
+            |m: &mut ScenarioAssertion| { &mut m.timeout_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, scenario_assertion::PacketSeen>(
+            "packet_seen",
+            ScenarioAssertion::has_packet_seen,
+            ScenarioAssertion::packet_seen,
+            ScenarioAssertion::mut_packet_seen,
+            ScenarioAssertion::set_packet_seen,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, scenario_assertion::DeviceState>(
+            "device_state",
+            ScenarioAssertion::has_device_state,
+            ScenarioAssertion::device_state,
+            ScenarioAssertion::mut_device_state,
+            ScenarioAssertion::set_device_state,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, scenario_assertion::CounterExceeded>(
+            "counter_exceeded",
+            ScenarioAssertion::has_counter_exceeded,
+            ScenarioAssertion::counter_exceeded,
+            ScenarioAssertion::mut_counter_exceeded,
+            ScenarioAssertion::set_counter_exceeded,
+        ));
+        oneofs.push(scenario_assertion::Assertion::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ScenarioAssertion>(
+            "ScenarioAssertion",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ScenarioAssertion {
+    const NAME: &'static str = "ScenarioAssertion";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.timeout_ms = is.read_uint32()?;
+                },
+                26 => {
+                    self.assertion = ::std::option::Option::Some(scenario_assertion::Assertion::PacketSeen(is.read_message()?));
+                },
+                34 => {
+                    self.assertion = ::std::option::Option::Some(scenario_assertion::Assertion::DeviceState(is.read_message()?));
+                },
+                42 => {
+                    self.assertion = ::std::option::Option::Some(scenario_assertion::Assertion::CounterExceeded(is.read_message()?));
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.timeout_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.timeout_ms);
+        }
+        if let ::std::option::Option::Some(ref v) = self.assertion {
+            match v {
+                &scenario_assertion::Assertion::PacketSeen(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &scenario_assertion::Assertion::DeviceState(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &scenario_assertion::Assertion::CounterExceeded(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.timeout_ms != 0 {
+            os.write_uint32(2, self.timeout_ms)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.assertion {
+            match v {
+                &scenario_assertion::Assertion::PacketSeen(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+                },
+                &scenario_assertion::Assertion::DeviceState(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &scenario_assertion::Assertion::CounterExceeded(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ScenarioAssertion {
+        ScenarioAssertion::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.timeout_ms = 0;
+        self.assertion = ::std::option::Option::None;
+        self.assertion = ::std::option::Option::None;
+        self.assertion = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ScenarioAssertion {
+        static instance: ScenarioAssertion = ScenarioAssertion {
+            name: ::std::string::String::new(),
+            timeout_ms: 0,
+            assertion: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ScenarioAssertion {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ScenarioAssertion").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ScenarioAssertion {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScenarioAssertion {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ScenarioAssertion`
+pub mod scenario_assertion {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:netsim.frontend.ScenarioAssertion.assertion)
+    pub enum Assertion {
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.ScenarioAssertion.packet_seen)
+        PacketSeen(PacketSeen),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.ScenarioAssertion.device_state)
+        DeviceState(DeviceState),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.ScenarioAssertion.counter_exceeded)
+        CounterExceeded(CounterExceeded),
+    }
+
+    impl ::protobuf::Oneof for Assertion {
+    }
+
+    impl ::protobuf::OneofFull for Assertion {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::ScenarioAssertion as ::protobuf::MessageFull>::descriptor().oneof_by_name("assertion").unwrap()).clone()
+        }
+    }
+
+    impl Assertion {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Assertion>("assertion")
+        }
+    }
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.ScenarioAssertion.PacketSeen)
+    pub struct PacketSeen {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.ScenarioAssertion.PacketSeen.device_name)
+        pub device_name: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.ScenarioAssertion.PacketSeen.chip_kind)
+        pub chip_kind: ::protobuf::EnumOrUnknown<super::super::common::ChipKind>,
+        // @@protoc_insertion_point(field:netsim.frontend.ScenarioAssertion.PacketSeen.pattern)
+        pub pattern: ::std::string::String,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.ScenarioAssertion.PacketSeen.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a PacketSeen {
+        fn default() -> &'a PacketSeen {
+            <PacketSeen as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl PacketSeen {
+        pub fn new() -> PacketSeen {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(3);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "device_name",
+                |m: &PacketSeen| { &m.device_name },
+                |m: &mut PacketSeen| { &mut m.device_name },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "chip_kind",
+                |m: &PacketSeen| { &m.chip_kind },
+                |m: &mut PacketSeen| { &mut m.chip_kind },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "pattern",
+                |m: &PacketSeen| { &m.pattern },
+                |m: &mut PacketSeen| { &mut m.pattern },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PacketSeen>(
+                "ScenarioAssertion.PacketSeen",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for PacketSeen {
+        const NAME: &'static str = "PacketSeen";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.device_name = is.read_string()?;
+                    },
+                    16 => {
+                        self.chip_kind = is.read_enum_or_unknown()?;
+                    },
+                    26 => {
+                        self.pattern = is.read_string()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.device_name.is_empty() {
+                my_size += ::protobuf::rt::string_size(1, &self.device_name);
+            }
+            if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::super::common::ChipKind::UNSPECIFIED) {
+                my_size += ::protobuf::rt::int32_size(2, self.chip_kind.value());
+            }
+            if !self.pattern.is_empty() {
+                my_size += ::protobuf::rt::string_size(3, &self.pattern);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if !self.device_name.is_empty() {
+                os.write_string(1, &self.device_name)?;
+            }
+            if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::super::common::ChipKind::UNSPECIFIED) {
+                os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+            }
+            if !self.pattern.is_empty() {
+                os.write_string(3, &self.pattern)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> PacketSeen {
+            PacketSeen::new()
+        }
+
+        fn clear(&mut self) {
+            self.device_name.clear();
+            self.chip_kind = ::protobuf::EnumOrUnknown::new(super::super::common::ChipKind::UNSPECIFIED);
+            self.pattern.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static PacketSeen {
+            static instance: PacketSeen = PacketSeen {
+                device_name: ::std::string::String::new(),
+                chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+                pattern: ::std::string::String::new(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for PacketSeen {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("ScenarioAssertion.PacketSeen").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for PacketSeen {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for PacketSeen {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.ScenarioAssertion.DeviceState)
+    pub struct DeviceState {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.ScenarioAssertion.DeviceState.device)
+        pub device: ::protobuf::MessageField<super::super::model::Device>,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.ScenarioAssertion.DeviceState.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a DeviceState {
+        fn default() -> &'a DeviceState {
+            <DeviceState as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl DeviceState {
+        pub fn new() -> DeviceState {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(1);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::super::model::Device>(
+                "device",
+                |m: &DeviceState| { &m.device },
+                |m: &mut DeviceState| { &mut m.device },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeviceState>(
+                "ScenarioAssertion.DeviceState",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for DeviceState {
+        const NAME: &'static str = "DeviceState";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.device)?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if let Some(v) = self.device.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if let Some(v) = self.device.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> DeviceState {
+            DeviceState::new()
+        }
+
+        fn clear(&mut self) {
+            self.device.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static DeviceState {
+            static instance: DeviceState = DeviceState {
+                device: ::protobuf::MessageField::none(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for DeviceState {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("ScenarioAssertion.DeviceState").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for DeviceState {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for DeviceState {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.ScenarioAssertion.CounterExceeded)
+    pub struct CounterExceeded {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.ScenarioAssertion.CounterExceeded.metric)
+        pub metric: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.ScenarioAssertion.CounterExceeded.labels)
+        pub labels: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+        // @@protoc_insertion_point(field:netsim.frontend.ScenarioAssertion.CounterExceeded.threshold)
+        pub threshold: f64,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.ScenarioAssertion.CounterExceeded.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a CounterExceeded {
+        fn default() -> &'a CounterExceeded {
+            <CounterExceeded as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl CounterExceeded {
+        pub fn new() -> CounterExceeded {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(3);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "metric",
+                |m: &CounterExceeded| { &m.metric },
+                |m: &mut CounterExceeded| { &mut m.metric },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+                "labels",
+                |m: &CounterExceeded| { &m.labels },
+                |m: &mut CounterExceeded| { &mut m.labels },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "threshold",
+                |m: &CounterExceeded| { &m.threshold },
+                |m: &mut CounterExceeded| { &mut m.threshold },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CounterExceeded>(
+                "ScenarioAssertion.CounterExceeded",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for CounterExceeded {
+        const NAME: &'static str = "CounterExceeded";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.metric = is.read_string()?;
+                    },
+                    18 => {
+                        let len = is.read_raw_varint32()?;
+                        let old_limit = is.push_limit(len as u64)?;
+                        let mut key = ::std::default::Default::default();
+                        let mut value = ::std::default::Default::default();
+                        while let Some(tag) = is.read_raw_tag_or_eof()? {
+                            match tag {
+                                10 => key = is.read_string()?,
+                                18 => value = is.read_string()?,
+                                _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                            };
+                        }
+                        is.pop_limit(old_limit);
+                        self.labels.insert(key, value);
+                    },
+                    25 => {
+                        self.threshold = is.read_double()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.metric.is_empty() {
+                my_size += ::protobuf::rt::string_size(1, &self.metric);
+            }
+            for (k, v) in &self.labels {
+                let mut entry_size = 0;
+                entry_size += ::protobuf::rt::string_size(1, &k);
+                entry_size += ::protobuf::rt::string_size(2, &v);
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+            };
+            if self.threshold != 0. {
+                my_size += 1 + 8;
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if !self.metric.is_empty() {
+                os.write_string(1, &self.metric)?;
+            }
+            for (k, v) in &self.labels {
+                let mut entry_size = 0;
+                entry_size += ::protobuf::rt::string_size(1, &k);
+                entry_size += ::protobuf::rt::string_size(2, &v);
+                os.write_raw_varint32(18)?; // Tag.
+                os.write_raw_varint32(entry_size as u32)?;
+                os.write_string(1, &k)?;
+                os.write_string(2, &v)?;
+            };
+            if self.threshold != 0. {
+                os.write_double(3, self.threshold)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> CounterExceeded {
+            CounterExceeded::new()
+        }
+
+        fn clear(&mut self) {
+            self.metric.clear();
+            self.labels.clear();
+            self.threshold = 0.;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static CounterExceeded {
+            static instance: ::protobuf::rt::Lazy<CounterExceeded> = ::protobuf::rt::Lazy::new();
+            instance.get(CounterExceeded::new)
+        }
+    }
+
+    impl ::protobuf::MessageFull for CounterExceeded {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("ScenarioAssertion.CounterExceeded").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for CounterExceeded {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for CounterExceeded {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.RunScenarioRequest)
+pub struct RunScenarioRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.RunScenarioRequest.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.RunScenarioRequest.setup)
+    pub setup: ::std::vec::Vec<super::model::Device>,
+    // @@protoc_insertion_point(field:netsim.frontend.RunScenarioRequest.assertions)
+    pub assertions: ::std::vec::Vec<ScenarioAssertion>,
+    // @@protoc_insertion_point(field:netsim.frontend.RunScenarioRequest.golden_captures)
+    pub golden_captures: ::std::vec::Vec<GoldenCapture>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.RunScenarioRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RunScenarioRequest {
+    fn default() -> &'a RunScenarioRequest {
+        <RunScenarioRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RunScenarioRequest {
+    pub fn new() -> RunScenarioRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &RunScenarioRequest| { &m.name },
+            |m: &mut RunScenarioRequest| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "setup",
+            |m: &RunScenarioRequest| { &m.setup },
+            |m: &mut RunScenarioRequest| { &mut m.setup },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "assertions",
+            |m: &RunScenarioRequest| { &m.assertions },
+            |m: &mut RunScenarioRequest| { &mut m.assertions },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "golden_captures",
+            |m: &RunScenarioRequest| { &m.golden_captures },
+            |m: &mut RunScenarioRequest| { &mut m.golden_captures },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RunScenarioRequest>(
+            "RunScenarioRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RunScenarioRequest {
+    const NAME: &'static str = "RunScenarioRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                18 => {
+                    self.setup.push(is.read_message()?);
+                },
+                26 => {
+                    self.assertions.push(is.read_message()?);
+                },
+                34 => {
+                    self.golden_captures.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.setup {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.assertions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.golden_captures {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.setup {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        for v in &self.assertions {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        for v in &self.golden_captures {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RunScenarioRequest {
+        RunScenarioRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.setup.clear();
+        self.assertions.clear();
+        self.golden_captures.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RunScenarioRequest {
+        static instance: RunScenarioRequest = RunScenarioRequest {
+            name: ::std::string::String::new(),
+            setup: ::std::vec::Vec::new(),
+            assertions: ::std::vec::Vec::new(),
+            golden_captures: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RunScenarioRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RunScenarioRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RunScenarioRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RunScenarioRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.CaptureNormalization)
+pub struct CaptureNormalization {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureNormalization.compare_timestamps)
+    pub compare_timestamps: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureNormalization.timestamp_tolerance_ms)
+    pub timestamp_tolerance_ms: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureNormalization.masks)
+    pub masks: ::std::vec::Vec<capture_normalization::Mask>,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureNormalization.address_patterns)
+    pub address_patterns: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.CaptureNormalization.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CaptureNormalization {
+    fn default() -> &'a CaptureNormalization {
+        <CaptureNormalization as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CaptureNormalization {
+    pub fn new() -> CaptureNormalization {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "compare_timestamps",
+            |m: &CaptureNormalization| { &m.compare_timestamps },
+            |m: &mut CaptureNormalization| { &mut m.compare_timestamps },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timestamp_tolerance_ms",
+            |m: &CaptureNormalization| { &m.timestamp_tolerance_ms },
+            |m: &mut CaptureNormalization| { &mut m.timestamp_tolerance_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "masks",
+            |m: &CaptureNormalization| { &m.masks },
+            |m: &mut CaptureNormalization| { &mut m.masks },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "address_patterns",
+            |m: &CaptureNormalization| { &m.address_patterns },
+            |m: &mut CaptureNormalization| { &mut m.address_patterns },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CaptureNormalization>(
+            "CaptureNormalization",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CaptureNormalization {
+    const NAME: &'static str = "CaptureNormalization";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.compare_timestamps = is.read_bool()?;
+                },
+                16 => {
+                    self.timestamp_tolerance_ms = is.read_uint32()?;
+                },
+                26 => {
+                    self.masks.push(is.read_message()?);
+                },
+                34 => {
+                    self.address_patterns.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.compare_timestamps != false {
+            my_size += 1 + 1;
+        }
+        if self.timestamp_tolerance_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.timestamp_tolerance_ms);
+        }
+        for value in &self.masks {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.address_patterns {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.compare_timestamps != false {
+            os.write_bool(1, self.compare_timestamps)?;
+        }
+        if self.timestamp_tolerance_ms != 0 {
+            os.write_uint32(2, self.timestamp_tolerance_ms)?;
+        }
+        for v in &self.masks {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        for v in &self.address_patterns {
+            os.write_string(4, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CaptureNormalization {
+        CaptureNormalization::new()
+    }
+
+    fn clear(&mut self) {
+        self.compare_timestamps = false;
+        self.timestamp_tolerance_ms = 0;
+        self.masks.clear();
+        self.address_patterns.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CaptureNormalization {
+        static instance: CaptureNormalization = CaptureNormalization {
+            compare_timestamps: false,
+            timestamp_tolerance_ms: 0,
+            masks: ::std::vec::Vec::new(),
+            address_patterns: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CaptureNormalization {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CaptureNormalization").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CaptureNormalization {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CaptureNormalization {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `CaptureNormalization`
+pub mod capture_normalization {
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.CaptureNormalization.Mask)
+    pub struct Mask {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.CaptureNormalization.Mask.offset)
+        pub offset: u32,
+        // @@protoc_insertion_point(field:netsim.frontend.CaptureNormalization.Mask.length)
+        pub length: u32,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.CaptureNormalization.Mask.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Mask {
+        fn default() -> &'a Mask {
+            <Mask as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Mask {
+        pub fn new() -> Mask {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "offset",
+                |m: &Mask| { &m.offset },
+                |m: &mut Mask| { &mut m.offset },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "length",
+                |m: &Mask| { &m.length },
+                |m: &mut Mask| { &mut m.length },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Mask>(
+                "CaptureNormalization.Mask",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Mask {
+        const NAME: &'static str = "Mask";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.offset = is.read_uint32()?;
+                    },
+                    16 => {
+                        self.length = is.read_uint32()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.offset != 0 {
+                my_size += ::protobuf::rt::uint32_size(1, self.offset);
+            }
+            if self.length != 0 {
+                my_size += ::protobuf::rt::uint32_size(2, self.length);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.offset != 0 {
+                os.write_uint32(1, self.offset)?;
+            }
+            if self.length != 0 {
+                os.write_uint32(2, self.length)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Mask {
+            Mask::new()
+        }
+
+        fn clear(&mut self) {
+            self.offset = 0;
+            self.length = 0;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Mask {
+            static instance: Mask = Mask {
+                offset: 0,
+                length: 0,
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Mask {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("CaptureNormalization.Mask").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Mask {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Mask {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GoldenCapture)
+pub struct GoldenCapture {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GoldenCapture.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.GoldenCapture.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.GoldenCapture.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.frontend.GoldenCapture.golden_path)
+    pub golden_path: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.GoldenCapture.timeout_ms)
+    pub timeout_ms: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.GoldenCapture.actual_path)
+    pub actual_path: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.GoldenCapture.normalization)
+    pub normalization: ::protobuf::MessageField<CaptureNormalization>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GoldenCapture.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GoldenCapture {
+    fn default() -> &'a GoldenCapture {
+        <GoldenCapture as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GoldenCapture {
+    pub fn new() -> GoldenCapture {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &GoldenCapture| { &m.name },
+            |m: &mut GoldenCapture| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &GoldenCapture| { &m.device_name },
+            |m: &mut GoldenCapture| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &GoldenCapture| { &m.chip_kind },
+            |m: &mut GoldenCapture| { &mut m.chip_kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "golden_path",
+            |m: &GoldenCapture| { &m.golden_path },
+            |m: &mut GoldenCapture| { &mut m.golden_path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timeout_ms",
+            |m: &GoldenCapture| { &m.timeout_ms },
+            |m: &mut GoldenCapture| { &mut m.timeout_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "actual_path",
+            |m: &GoldenCapture| { &m.actual_path },
+            |m: &mut GoldenCapture| { &mut m.actual_path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CaptureNormalization>(
+            "normalization",
+            |m: &GoldenCapture| { &m.normalization },
+            |m: &mut GoldenCapture| { &mut m.normalization },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GoldenCapture>(
+            "GoldenCapture",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GoldenCapture {
+    const NAME: &'static str = "GoldenCapture";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                18 => {
+                    self.device_name = is.read_string()?;
+                },
+                24 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                34 => {
+                    self.golden_path = is.read_string()?;
+                },
+                40 => {
+                    self.timeout_ms = is.read_uint32()?;
+                },
+                50 => {
+                    self.actual_path = is.read_string()?;
+                },
+                58 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.normalization)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.device_name);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(3, self.chip_kind.value());
+        }
+        if !self.golden_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.golden_path);
+        }
+        if self.timeout_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.timeout_ms);
+        }
+        if !self.actual_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.actual_path);
+        }
+        if let Some(v) = self.normalization.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.device_name.is_empty() {
+            os.write_string(2, &self.device_name)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        if !self.golden_path.is_empty() {
+            os.write_string(4, &self.golden_path)?;
+        }
+        if self.timeout_ms != 0 {
+            os.write_uint32(5, self.timeout_ms)?;
+        }
+        if !self.actual_path.is_empty() {
+            os.write_string(6, &self.actual_path)?;
+        }
+        if let Some(v) = self.normalization.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GoldenCapture {
+        GoldenCapture::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.device_name.clear();
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.golden_path.clear();
+        self.timeout_ms = 0;
+        self.actual_path.clear();
+        self.normalization.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GoldenCapture {
+        static instance: GoldenCapture = GoldenCapture {
+            name: ::std::string::String::new(),
+            device_name: ::std::string::String::new(),
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            golden_path: ::std::string::String::new(),
+            timeout_ms: 0,
+            actual_path: ::std::string::String::new(),
+            normalization: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GoldenCapture {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GoldenCapture").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GoldenCapture {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GoldenCapture {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.RecordDiff)
+pub struct RecordDiff {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.RecordDiff.kind)
+    pub kind: ::protobuf::EnumOrUnknown<record_diff::Kind>,
+    // @@protoc_insertion_point(field:netsim.frontend.RecordDiff.index)
+    pub index: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.RecordDiff.offset)
+    pub offset: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.RecordDiff.expected)
+    pub expected: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:netsim.frontend.RecordDiff.actual)
+    pub actual: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:netsim.frontend.RecordDiff.expected_time_us)
+    pub expected_time_us: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.RecordDiff.actual_time_us)
+    pub actual_time_us: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.RecordDiff.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RecordDiff {
+    fn default() -> &'a RecordDiff {
+        <RecordDiff as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RecordDiff {
+    pub fn new() -> RecordDiff {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "kind",
+            |m: &RecordDiff| { &m.kind },
+            |m: &mut RecordDiff| { &mut m.kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "index",
+            |m: &RecordDiff| { &m.index },
+            |m: &mut RecordDiff| { &mut m.index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &RecordDiff| { &m.offset },
+            |m: &mut RecordDiff| { &mut m.offset },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "expected",
+            |m: &RecordDiff| { &m.expected },
+            |m: &mut RecordDiff| { &mut m.expected },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "actual",
+            |m: &RecordDiff| { &m.actual },
+            |m: &mut RecordDiff| { &mut m.actual },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "expected_time_us",
+            |m: &RecordDiff| { &m.expected_time_us },
+            |m: &mut RecordDiff| { &mut m.expected_time_us },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "actual_time_us",
+            |m: &RecordDiff| { &m.actual_time_us },
+            |m: &mut RecordDiff| { &mut m.actual_time_us },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RecordDiff>(
+            "RecordDiff",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RecordDiff {
+    const NAME: &'static str = "RecordDiff";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.kind = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.index = is.read_uint32()?;
+                },
+                24 => {
+                    self.offset = is.read_uint32()?;
+                },
+                34 => {
+                    self.expected = is.read_bytes()?;
+                },
+                42 => {
+                    self.actual = is.read_bytes()?;
+                },
+                48 => {
+                    self.expected_time_us = is.read_uint64()?;
+                },
+                56 => {
+                    self.actual_time_us = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.kind != ::protobuf::EnumOrUnknown::new(record_diff::Kind::CHANGED) {
+            my_size += ::protobuf::rt::int32_size(1, self.kind.value());
+        }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.index);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.offset);
+        }
+        if !self.expected.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.expected);
+        }
+        if !self.actual.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.actual);
+        }
+        if self.expected_time_us != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.expected_time_us);
+        }
+        if self.actual_time_us != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.actual_time_us);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.kind != ::protobuf::EnumOrUnknown::new(record_diff::Kind::CHANGED) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.kind))?;
+        }
+        if self.index != 0 {
+            os.write_uint32(2, self.index)?;
+        }
+        if self.offset != 0 {
+            os.write_uint32(3, self.offset)?;
+        }
+        if !self.expected.is_empty() {
+            os.write_bytes(4, &self.expected)?;
+        }
+        if !self.actual.is_empty() {
+            os.write_bytes(5, &self.actual)?;
+        }
+        if self.expected_time_us != 0 {
+            os.write_uint64(6, self.expected_time_us)?;
+        }
+        if self.actual_time_us != 0 {
+            os.write_uint64(7, self.actual_time_us)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RecordDiff {
+        RecordDiff::new()
+    }
+
+    fn clear(&mut self) {
+        self.kind = ::protobuf::EnumOrUnknown::new(record_diff::Kind::CHANGED);
+        self.index = 0;
+        self.offset = 0;
+        self.expected.clear();
+        self.actual.clear();
+        self.expected_time_us = 0;
+        self.actual_time_us = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RecordDiff {
+        static instance: RecordDiff = RecordDiff {
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            index: 0,
+            offset: 0,
+            expected: ::std::vec::Vec::new(),
+            actual: ::std::vec::Vec::new(),
+            expected_time_us: 0,
+            actual_time_us: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RecordDiff {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RecordDiff").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RecordDiff {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RecordDiff {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `RecordDiff`
+pub mod record_diff {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.RecordDiff.Kind)
+    pub enum Kind {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.RecordDiff.Kind.CHANGED)
+        CHANGED = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.RecordDiff.Kind.MISSING)
+        MISSING = 1,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.RecordDiff.Kind.EXTRA)
+        EXTRA = 2,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.RecordDiff.Kind.TIMESTAMP)
+        TIMESTAMP = 3,
+    }
+
+    impl ::protobuf::Enum for Kind {
+        const NAME: &'static str = "Kind";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Kind> {
+            match value {
+                0 => ::std::option::Option::Some(Kind::CHANGED),
+                1 => ::std::option::Option::Some(Kind::MISSING),
+                2 => ::std::option::Option::Some(Kind::EXTRA),
+                3 => ::std::option::Option::Some(Kind::TIMESTAMP),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Kind] = &[
+            Kind::CHANGED,
+            Kind::MISSING,
+            Kind::EXTRA,
+            Kind::TIMESTAMP,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Kind {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("RecordDiff.Kind").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Kind {
+        fn default() -> Self {
+            Kind::CHANGED
+        }
+    }
+
+    impl Kind {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Kind>("RecordDiff.Kind")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.CaptureComparison)
+pub struct CaptureComparison {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureComparison.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureComparison.passed)
+    pub passed: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureComparison.message)
+    pub message: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureComparison.expected_records)
+    pub expected_records: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureComparison.actual_records)
+    pub actual_records: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.CaptureComparison.diffs)
+    pub diffs: ::std::vec::Vec<RecordDiff>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.CaptureComparison.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CaptureComparison {
+    fn default() -> &'a CaptureComparison {
+        <CaptureComparison as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CaptureComparison {
+    pub fn new() -> CaptureComparison {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &CaptureComparison| { &m.name },
+            |m: &mut CaptureComparison| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "passed",
+            |m: &CaptureComparison| { &m.passed },
+            |m: &mut CaptureComparison| { &mut m.passed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &CaptureComparison| { &m.message },
+            |m: &mut CaptureComparison| { &mut m.message },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "expected_records",
+            |m: &CaptureComparison| { &m.expected_records },
+            |m: &mut CaptureComparison| { &mut m.expected_records },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "actual_records",
+            |m: &CaptureComparison| { &m.actual_records },
+            |m: &mut CaptureComparison| { &mut m.actual_records },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "diffs",
+            |m: &CaptureComparison| { &m.diffs },
+            |m: &mut CaptureComparison| { &mut m.diffs },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CaptureComparison>(
+            "CaptureComparison",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CaptureComparison {
+    const NAME: &'static str = "CaptureComparison";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.passed = is.read_bool()?;
+                },
+                26 => {
+                    self.message = is.read_string()?;
+                },
+                32 => {
+                    self.expected_records = is.read_uint32()?;
+                },
+                40 => {
+                    self.actual_records = is.read_uint32()?;
+                },
+                50 => {
+                    self.diffs.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.passed != false {
+            my_size += 1 + 1;
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.message);
+        }
+        if self.expected_records != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.expected_records);
+        }
+        if self.actual_records != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.actual_records);
+        }
+        for value in &self.diffs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.passed != false {
+            os.write_bool(2, self.passed)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(3, &self.message)?;
+        }
+        if self.expected_records != 0 {
+            os.write_uint32(4, self.expected_records)?;
+        }
+        if self.actual_records != 0 {
+            os.write_uint32(5, self.actual_records)?;
+        }
+        for v in &self.diffs {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CaptureComparison {
+        CaptureComparison::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.passed = false;
+        self.message.clear();
+        self.expected_records = 0;
+        self.actual_records = 0;
+        self.diffs.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CaptureComparison {
+        static instance: CaptureComparison = CaptureComparison {
+            name: ::std::string::String::new(),
+            passed: false,
+            message: ::std::string::String::new(),
+            expected_records: 0,
+            actual_records: 0,
+            diffs: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CaptureComparison {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CaptureComparison").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CaptureComparison {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CaptureComparison {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.AssertionResult)
+pub struct AssertionResult {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.AssertionResult.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.AssertionResult.passed)
+    pub passed: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.AssertionResult.message)
+    pub message: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.AssertionResult.elapsed_ms)
+    pub elapsed_ms: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.AssertionResult.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AssertionResult {
+    fn default() -> &'a AssertionResult {
+        <AssertionResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AssertionResult {
+    pub fn new() -> AssertionResult {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &AssertionResult| { &m.name },
+            |m: &mut AssertionResult| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "passed",
+            |m: &AssertionResult| { &m.passed },
+            |m: &mut AssertionResult| { &mut m.passed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &AssertionResult| { &m.message },
+            |m: &mut AssertionResult| { &mut m.message },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "elapsed_ms",
+            |m: &AssertionResult| { &m.elapsed_ms },
+            |m: &mut AssertionResult| { &mut m.elapsed_ms },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AssertionResult>(
+            "AssertionResult",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AssertionResult {
+    const NAME: &'static str = "AssertionResult";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.passed = is.read_bool()?;
+                },
+                26 => {
+                    self.message = is.read_string()?;
+                },
+                32 => {
+                    self.elapsed_ms = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.passed != false {
+            my_size += 1 + 1;
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.message);
+        }
+        if self.elapsed_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.elapsed_ms);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.passed != false {
+            os.write_bool(2, self.passed)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(3, &self.message)?;
+        }
+        if self.elapsed_ms != 0 {
+            os.write_uint32(4, self.elapsed_ms)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AssertionResult {
+        AssertionResult::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.passed = false;
+        self.message.clear();
+        self.elapsed_ms = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AssertionResult {
+        static instance: AssertionResult = AssertionResult {
+            name: ::std::string::String::new(),
+            passed: false,
+            message: ::std::string::String::new(),
+            elapsed_ms: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AssertionResult {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AssertionResult").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AssertionResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AssertionResult {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.RunScenarioResponse)
+pub struct RunScenarioResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.RunScenarioResponse.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.RunScenarioResponse.passed)
+    pub passed: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.RunScenarioResponse.results)
+    pub results: ::std::vec::Vec<AssertionResult>,
+    // @@protoc_insertion_point(field:netsim.frontend.RunScenarioResponse.captures)
+    pub captures: ::std::vec::Vec<CaptureComparison>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.RunScenarioResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RunScenarioResponse {
+    fn default() -> &'a RunScenarioResponse {
+        <RunScenarioResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RunScenarioResponse {
+    pub fn new() -> RunScenarioResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &RunScenarioResponse| { &m.name },
+            |m: &mut RunScenarioResponse| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "passed",
+            |m: &RunScenarioResponse| { &m.passed },
+            |m: &mut RunScenarioResponse| { &mut m.passed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "results",
+            |m: &RunScenarioResponse| { &m.results },
+            |m: &mut RunScenarioResponse| { &mut m.results },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "captures",
+            |m: &RunScenarioResponse| { &m.captures },
+            |m: &mut RunScenarioResponse| { &mut m.captures },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RunScenarioResponse>(
+            "RunScenarioResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RunScenarioResponse {
+    const NAME: &'static str = "RunScenarioResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.passed = is.read_bool()?;
+                },
+                26 => {
+                    self.results.push(is.read_message()?);
+                },
+                34 => {
+                    self.captures.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.passed != false {
+            my_size += 1 + 1;
+        }
+        for value in &self.results {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.captures {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.passed != false {
+            os.write_bool(2, self.passed)?;
+        }
+        for v in &self.results {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        for v in &self.captures {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RunScenarioResponse {
+        RunScenarioResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.passed = false;
+        self.results.clear();
+        self.captures.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RunScenarioResponse {
+        static instance: RunScenarioResponse = RunScenarioResponse {
+            name: ::std::string::String::new(),
+            passed: false,
+            results: ::std::vec::Vec::new(),
+            captures: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RunScenarioResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RunScenarioResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RunScenarioResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RunScenarioResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ControlClockRequest)
+pub struct ControlClockRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ControlClockRequest.action)
+    pub action: ::protobuf::EnumOrUnknown<control_clock_request::Action>,
+    // @@protoc_insertion_point(field:netsim.frontend.ControlClockRequest.advance_ms)
+    pub advance_ms: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ControlClockRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ControlClockRequest {
+    fn default() -> &'a ControlClockRequest {
+        <ControlClockRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ControlClockRequest {
+    pub fn new() -> ControlClockRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "action",
+            |m: &ControlClockRequest| { &m.action },
+            |m: &mut ControlClockRequest| { &mut m.action },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "advance_ms",
+            |m: &ControlClockRequest| { &m.advance_ms },
+            |m: &mut ControlClockRequest| { &mut m.advance_ms },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ControlClockRequest>(
+            "ControlClockRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ControlClockRequest {
+    const NAME: &'static str = "ControlClockRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.action = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.advance_ms = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.action != ::protobuf::EnumOrUnknown::new(control_clock_request::Action::GET) {
+            my_size += ::protobuf::rt::int32_size(1, self.action.value());
+        }
+        if self.advance_ms != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.advance_ms);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.action != ::protobuf::EnumOrUnknown::new(control_clock_request::Action::GET) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.action))?;
+        }
+        if self.advance_ms != 0 {
+            os.write_uint64(2, self.advance_ms)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ControlClockRequest {
+        ControlClockRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.action = ::protobuf::EnumOrUnknown::new(control_clock_request::Action::GET);
+        self.advance_ms = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ControlClockRequest {
+        static instance: ControlClockRequest = ControlClockRequest {
+            action: ::protobuf::EnumOrUnknown::from_i32(0),
+            advance_ms: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ControlClockRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ControlClockRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ControlClockRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ControlClockRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ControlClockRequest`
+pub mod control_clock_request {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.ControlClockRequest.Action)
+    pub enum Action {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ControlClockRequest.Action.GET)
+        GET = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ControlClockRequest.Action.PAUSE)
+        PAUSE = 1,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ControlClockRequest.Action.RESUME)
+        RESUME = 2,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ControlClockRequest.Action.ADVANCE)
+        ADVANCE = 3,
+    }
+
+    impl ::protobuf::Enum for Action {
+        const NAME: &'static str = "Action";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Action> {
+            match value {
+                0 => ::std::option::Option::Some(Action::GET),
+                1 => ::std::option::Option::Some(Action::PAUSE),
+                2 => ::std::option::Option::Some(Action::RESUME),
+                3 => ::std::option::Option::Some(Action::ADVANCE),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Action] = &[
+            Action::GET,
+            Action::PAUSE,
+            Action::RESUME,
+            Action::ADVANCE,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Action {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("ControlClockRequest.Action").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Action {
+        fn default() -> Self {
+            Action::GET
+        }
+    }
+
+    impl Action {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Action>("ControlClockRequest.Action")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ControlClockResponse)
+pub struct ControlClockResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ControlClockResponse.now)
+    pub now: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:netsim.frontend.ControlClockResponse.paused)
+    pub paused: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ControlClockResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ControlClockResponse {
+    fn default() -> &'a ControlClockResponse {
+        <ControlClockResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ControlClockResponse {
+    pub fn new() -> ControlClockResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "now",
+            |m: &ControlClockResponse| { &m.now },
+            |m: &mut ControlClockResponse| { &mut m.now },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "paused",
+            |m: &ControlClockResponse| { &m.paused },
+            |m: &mut ControlClockResponse| { &mut m.paused },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ControlClockResponse>(
+            "ControlClockResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ControlClockResponse {
+    const NAME: &'static str = "ControlClockResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.now)?;
+                },
+                16 => {
+                    self.paused = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.now.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.paused != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.now.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if self.paused != false {
+            os.write_bool(2, self.paused)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ControlClockResponse {
+        ControlClockResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.now.clear();
+        self.paused = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ControlClockResponse {
+        static instance: ControlClockResponse = ControlClockResponse {
+            now: ::protobuf::MessageField::none(),
+            paused: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ControlClockResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ControlClockResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ControlClockResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ControlClockResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ControlSessionRequest)
+pub struct ControlSessionRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ControlSessionRequest.action)
+    pub action: ::protobuf::EnumOrUnknown<control_session_request::Action>,
+    // @@protoc_insertion_point(field:netsim.frontend.ControlSessionRequest.path)
+    pub path: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ControlSessionRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ControlSessionRequest {
+    fn default() -> &'a ControlSessionRequest {
+        <ControlSessionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ControlSessionRequest {
+    pub fn new() -> ControlSessionRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "action",
+            |m: &ControlSessionRequest| { &m.action },
+            |m: &mut ControlSessionRequest| { &mut m.action },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "path",
+            |m: &ControlSessionRequest| { &m.path },
+            |m: &mut ControlSessionRequest| { &mut m.path },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ControlSessionRequest>(
+            "ControlSessionRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ControlSessionRequest {
+    const NAME: &'static str = "ControlSessionRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.action = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.path = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.action != ::protobuf::EnumOrUnknown::new(control_session_request::Action::GET) {
+            my_size += ::protobuf::rt::int32_size(1, self.action.value());
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.path);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.action != ::protobuf::EnumOrUnknown::new(control_session_request::Action::GET) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.action))?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(2, &self.path)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ControlSessionRequest {
+        ControlSessionRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.action = ::protobuf::EnumOrUnknown::new(control_session_request::Action::GET);
+        self.path.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ControlSessionRequest {
+        static instance: ControlSessionRequest = ControlSessionRequest {
+            action: ::protobuf::EnumOrUnknown::from_i32(0),
+            path: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ControlSessionRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ControlSessionRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ControlSessionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ControlSessionRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ControlSessionRequest`
+pub mod control_session_request {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.ControlSessionRequest.Action)
+    pub enum Action {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ControlSessionRequest.Action.GET)
+        GET = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ControlSessionRequest.Action.RECORD)
+        RECORD = 1,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ControlSessionRequest.Action.STOP)
+        STOP = 2,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ControlSessionRequest.Action.REPLAY)
+        REPLAY = 3,
+    }
+
+    impl ::protobuf::Enum for Action {
+        const NAME: &'static str = "Action";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Action> {
+            match value {
+                0 => ::std::option::Option::Some(Action::GET),
+                1 => ::std::option::Option::Some(Action::RECORD),
+                2 => ::std::option::Option::Some(Action::STOP),
+                3 => ::std::option::Option::Some(Action::REPLAY),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Action] = &[
+            Action::GET,
+            Action::RECORD,
+            Action::STOP,
+            Action::REPLAY,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Action {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("ControlSessionRequest.Action").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Action {
+        fn default() -> Self {
+            Action::GET
+        }
+    }
+
+    impl Action {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Action>("ControlSessionRequest.Action")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ControlSessionResponse)
+pub struct ControlSessionResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ControlSessionResponse.recording)
+    pub recording: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.ControlSessionResponse.path)
+    pub path: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ControlSessionResponse.records)
+    pub records: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ControlSessionResponse.replayed)
+    pub replayed: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ControlSessionResponse.warnings)
+    pub warnings: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ControlSessionResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ControlSessionResponse {
+    fn default() -> &'a ControlSessionResponse {
+        <ControlSessionResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ControlSessionResponse {
+    pub fn new() -> ControlSessionResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "recording",
+            |m: &ControlSessionResponse| { &m.recording },
+            |m: &mut ControlSessionResponse| { &mut m.recording },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "path",
+            |m: &ControlSessionResponse| { &m.path },
+            |m: &mut ControlSessionResponse| { &mut m.path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "records",
+            |m: &ControlSessionResponse| { &m.records },
+            |m: &mut ControlSessionResponse| { &mut m.records },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "replayed",
+            |m: &ControlSessionResponse| { &m.replayed },
+            |m: &mut ControlSessionResponse| { &mut m.replayed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "warnings",
+            |m: &ControlSessionResponse| { &m.warnings },
+            |m: &mut ControlSessionResponse| { &mut m.warnings },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ControlSessionResponse>(
+            "ControlSessionResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ControlSessionResponse {
+    const NAME: &'static str = "ControlSessionResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.recording = is.read_bool()?;
+                },
+                18 => {
+                    self.path = is.read_string()?;
+                },
+                24 => {
+                    self.records = is.read_uint64()?;
+                },
+                32 => {
+                    self.replayed = is.read_uint64()?;
+                },
+                42 => {
+                    self.warnings.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.recording != false {
+            my_size += 1 + 1;
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.path);
+        }
+        if self.records != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.records);
+        }
+        if self.replayed != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.replayed);
+        }
+        for value in &self.warnings {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.recording != false {
+            os.write_bool(1, self.recording)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(2, &self.path)?;
+        }
+        if self.records != 0 {
+            os.write_uint64(3, self.records)?;
+        }
+        if self.replayed != 0 {
+            os.write_uint64(4, self.replayed)?;
+        }
+        for v in &self.warnings {
+            os.write_string(5, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ControlSessionResponse {
+        ControlSessionResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.recording = false;
+        self.path.clear();
+        self.records = 0;
+        self.replayed = 0;
+        self.warnings.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ControlSessionResponse {
+        static instance: ControlSessionResponse = ControlSessionResponse {
+            recording: false,
+            path: ::std::string::String::new(),
+            records: 0,
+            replayed: 0,
+            warnings: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ControlSessionResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ControlSessionResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ControlSessionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ControlSessionResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.SessionRecord)
+pub struct SessionRecord {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.time_us)
+    pub time_us: u64,
+    // message oneof groups
+    pub record: ::std::option::Option<session_record::Record>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.SessionRecord.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SessionRecord {
+    fn default() -> &'a SessionRecord {
+        <SessionRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SessionRecord {
+    pub fn new() -> SessionRecord {
+        ::std::default::Default::default()
+    }
+
+    // .netsim.frontend.SessionRecord.Header header = 2;
+
+    pub fn header(&self) -> &session_record::Header {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::Header(ref v)) => v,
+            _ => <session_record::Header as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_header(&mut self) {
+        self.record = ::std::option::Option::None;
+    }
+
+    pub fn has_header(&self) -> bool {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::Header(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: session_record::Header) {
+        self.record = ::std::option::Option::Some(session_record::Record::Header(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_header(&mut self) -> &mut session_record::Header {
+        if let ::std::option::Option::Some(session_record::Record::Header(_)) = self.record {
+        } else {
+            self.record = ::std::option::Option::Some(session_record::Record::Header(session_record::Header::new()));
+        }
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::Header(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> session_record::Header {
+        if self.has_header() {
+            match self.record.take() {
+                ::std::option::Option::Some(session_record::Record::Header(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            session_record::Header::new()
+        }
+    }
+
+    // .netsim.frontend.SessionRecord.ChipAdded chip_added = 3;
+
+    pub fn chip_added(&self) -> &session_record::ChipAdded {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::ChipAdded(ref v)) => v,
+            _ => <session_record::ChipAdded as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_chip_added(&mut self) {
+        self.record = ::std::option::Option::None;
+    }
+
+    pub fn has_chip_added(&self) -> bool {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::ChipAdded(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chip_added(&mut self, v: session_record::ChipAdded) {
+        self.record = ::std::option::Option::Some(session_record::Record::ChipAdded(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chip_added(&mut self) -> &mut session_record::ChipAdded {
+        if let ::std::option::Option::Some(session_record::Record::ChipAdded(_)) = self.record {
+        } else {
+            self.record = ::std::option::Option::Some(session_record::Record::ChipAdded(session_record::ChipAdded::new()));
+        }
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::ChipAdded(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_chip_added(&mut self) -> session_record::ChipAdded {
+        if self.has_chip_added() {
+            match self.record.take() {
+                ::std::option::Option::Some(session_record::Record::ChipAdded(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            session_record::ChipAdded::new()
+        }
+    }
+
+    // .netsim.frontend.SessionRecord.ChipRemoved chip_removed = 4;
+
+    pub fn chip_removed(&self) -> &session_record::ChipRemoved {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::ChipRemoved(ref v)) => v,
+            _ => <session_record::ChipRemoved as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_chip_removed(&mut self) {
+        self.record = ::std::option::Option::None;
+    }
+
+    pub fn has_chip_removed(&self) -> bool {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::ChipRemoved(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chip_removed(&mut self, v: session_record::ChipRemoved) {
+        self.record = ::std::option::Option::Some(session_record::Record::ChipRemoved(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chip_removed(&mut self) -> &mut session_record::ChipRemoved {
+        if let ::std::option::Option::Some(session_record::Record::ChipRemoved(_)) = self.record {
+        } else {
+            self.record = ::std::option::Option::Some(session_record::Record::ChipRemoved(session_record::ChipRemoved::new()));
+        }
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::ChipRemoved(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_chip_removed(&mut self) -> session_record::ChipRemoved {
+        if self.has_chip_removed() {
+            match self.record.take() {
+                ::std::option::Option::Some(session_record::Record::ChipRemoved(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            session_record::ChipRemoved::new()
+        }
+    }
+
+    // .netsim.model.Device device_patched = 5;
+
+    pub fn device_patched(&self) -> &super::model::Device {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::DevicePatched(ref v)) => v,
+            _ => <super::model::Device as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_device_patched(&mut self) {
+        self.record = ::std::option::Option::None;
+    }
+
+    pub fn has_device_patched(&self) -> bool {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::DevicePatched(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_patched(&mut self, v: super::model::Device) {
+        self.record = ::std::option::Option::Some(session_record::Record::DevicePatched(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_device_patched(&mut self) -> &mut super::model::Device {
+        if let ::std::option::Option::Some(session_record::Record::DevicePatched(_)) = self.record {
+        } else {
+            self.record = ::std::option::Option::Some(session_record::Record::DevicePatched(super::model::Device::new()));
+        }
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::DevicePatched(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_device_patched(&mut self) -> super::model::Device {
+        if self.has_device_patched() {
+            match self.record.take() {
+                ::std::option::Option::Some(session_record::Record::DevicePatched(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::model::Device::new()
+        }
+    }
+
+    // .google.protobuf.Empty reset = 6;
+
+    pub fn reset(&self) -> &::protobuf::well_known_types::empty::Empty {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::Reset(ref v)) => v,
+            _ => <::protobuf::well_known_types::empty::Empty as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_reset(&mut self) {
+        self.record = ::std::option::Option::None;
+    }
+
+    pub fn has_reset(&self) -> bool {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::Reset(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reset(&mut self, v: ::protobuf::well_known_types::empty::Empty) {
+        self.record = ::std::option::Option::Some(session_record::Record::Reset(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_reset(&mut self) -> &mut ::protobuf::well_known_types::empty::Empty {
+        if let ::std::option::Option::Some(session_record::Record::Reset(_)) = self.record {
+        } else {
+            self.record = ::std::option::Option::Some(session_record::Record::Reset(::protobuf::well_known_types::empty::Empty::new()));
+        }
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::Reset(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_reset(&mut self) -> ::protobuf::well_known_types::empty::Empty {
+        if self.has_reset() {
+            match self.record.take() {
+                ::std::option::Option::Some(session_record::Record::Reset(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::protobuf::well_known_types::empty::Empty::new()
+        }
+    }
+
+    // .netsim.frontend.ChipKindInfo chip_kind_registered = 7;
+
+    pub fn chip_kind_registered(&self) -> &ChipKindInfo {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::ChipKindRegistered(ref v)) => v,
+            _ => <ChipKindInfo as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_chip_kind_registered(&mut self) {
+        self.record = ::std::option::Option::None;
+    }
+
+    pub fn has_chip_kind_registered(&self) -> bool {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::ChipKindRegistered(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chip_kind_registered(&mut self, v: ChipKindInfo) {
+        self.record = ::std::option::Option::Some(session_record::Record::ChipKindRegistered(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chip_kind_registered(&mut self) -> &mut ChipKindInfo {
+        if let ::std::option::Option::Some(session_record::Record::ChipKindRegistered(_)) = self.record {
+        } else {
+            self.record = ::std::option::Option::Some(session_record::Record::ChipKindRegistered(ChipKindInfo::new()));
+        }
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::ChipKindRegistered(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_chip_kind_registered(&mut self) -> ChipKindInfo {
+        if self.has_chip_kind_registered() {
+            match self.record.take() {
+                ::std::option::Option::Some(session_record::Record::ChipKindRegistered(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ChipKindInfo::new()
+        }
+    }
+
+    // .netsim.frontend.SessionRecord.Packet packet = 8;
+
+    pub fn packet(&self) -> &session_record::Packet {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::Packet(ref v)) => v,
+            _ => <session_record::Packet as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_packet(&mut self) {
+        self.record = ::std::option::Option::None;
+    }
+
+    pub fn has_packet(&self) -> bool {
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::Packet(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_packet(&mut self, v: session_record::Packet) {
+        self.record = ::std::option::Option::Some(session_record::Record::Packet(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_packet(&mut self) -> &mut session_record::Packet {
+        if let ::std::option::Option::Some(session_record::Record::Packet(_)) = self.record {
+        } else {
+            self.record = ::std::option::Option::Some(session_record::Record::Packet(session_record::Packet::new()));
+        }
+        match self.record {
+            ::std::option::Option::Some(session_record::Record::Packet(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_packet(&mut self) -> session_record::Packet {
+        if self.has_packet() {
+            match self.record.take() {
+                ::std::option::Option::Some(session_record::Record::Packet(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            session_record::Packet::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "time_us",
+            |m: &SessionRecord| { &m.time_us },
+            |m: &mut SessionRecord| { &mut m.time_us },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, session_record::Header>(
+            "header",
+            SessionRecord::has_header,
+            SessionRecord::header,
+            SessionRecord::mut_header,
+            SessionRecord::set_header,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, session_record::ChipAdded>(
+            "chip_added",
+            SessionRecord::has_chip_added,
+            SessionRecord::chip_added,
+            SessionRecord::mut_chip_added,
+            SessionRecord::set_chip_added,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, session_record::ChipRemoved>(
+            "chip_removed",
+            SessionRecord::has_chip_removed,
+            SessionRecord::chip_removed,
+            SessionRecord::mut_chip_removed,
+            SessionRecord::set_chip_removed,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, super::model::Device>(
+            "device_patched",
+            SessionRecord::has_device_patched,
+            SessionRecord::device_patched,
+            SessionRecord::mut_device_patched,
+            SessionRecord::set_device_patched,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ::protobuf::well_known_types::empty::Empty>(
+            "reset",
+            SessionRecord::has_reset,
+            SessionRecord::reset,
+            SessionRecord::mut_reset,
+            SessionRecord::set_reset,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ChipKindInfo>(
+            "chip_kind_registered",
+            SessionRecord::has_chip_kind_registered,
+            SessionRecord::chip_kind_registered,
+            SessionRecord::mut_chip_kind_registered,
+            SessionRecord::set_chip_kind_registered,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, session_record::Packet>(
+            "packet",
+            SessionRecord::has_packet,
+            SessionRecord::packet,
+            SessionRecord::mut_packet,
+            SessionRecord::set_packet,
+        ));
+        oneofs.push(session_record::Record::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SessionRecord>(
+            "SessionRecord",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SessionRecord {
+    const NAME: &'static str = "SessionRecord";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.time_us = is.read_uint64()?;
+                },
+                18 => {
+                    self.record = ::std::option::Option::Some(session_record::Record::Header(is.read_message()?));
+                },
+                26 => {
+                    self.record = ::std::option::Option::Some(session_record::Record::ChipAdded(is.read_message()?));
+                },
+                34 => {
+                    self.record = ::std::option::Option::Some(session_record::Record::ChipRemoved(is.read_message()?));
+                },
+                42 => {
+                    self.record = ::std::option::Option::Some(session_record::Record::DevicePatched(is.read_message()?));
+                },
+                50 => {
+                    self.record = ::std::option::Option::Some(session_record::Record::Reset(is.read_message()?));
+                },
+                58 => {
+                    self.record = ::std::option::Option::Some(session_record::Record::ChipKindRegistered(is.read_message()?));
+                },
+                66 => {
+                    self.record = ::std::option::Option::Some(session_record::Record::Packet(is.read_message()?));
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.time_us != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.time_us);
+        }
+        if let ::std::option::Option::Some(ref v) = self.record {
+            match v {
+                &session_record::Record::Header(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &session_record::Record::ChipAdded(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &session_record::Record::ChipRemoved(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &session_record::Record::DevicePatched(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &session_record::Record::Reset(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &session_record::Record::ChipKindRegistered(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &session_record::Record::Packet(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.time_us != 0 {
+            os.write_uint64(1, self.time_us)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.record {
+            match v {
+                &session_record::Record::Header(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &session_record::Record::ChipAdded(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+                },
+                &session_record::Record::ChipRemoved(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &session_record::Record::DevicePatched(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+                &session_record::Record::Reset(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+                },
+                &session_record::Record::ChipKindRegistered(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+                },
+                &session_record::Record::Packet(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SessionRecord {
+        SessionRecord::new()
+    }
+
+    fn clear(&mut self) {
+        self.time_us = 0;
+        self.record = ::std::option::Option::None;
+        self.record = ::std::option::Option::None;
+        self.record = ::std::option::Option::None;
+        self.record = ::std::option::Option::None;
+        self.record = ::std::option::Option::None;
+        self.record = ::std::option::Option::None;
+        self.record = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SessionRecord {
+        static instance: SessionRecord = SessionRecord {
+            time_us: 0,
+            record: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SessionRecord {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SessionRecord").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SessionRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SessionRecord {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `SessionRecord`
+pub mod session_record {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:netsim.frontend.SessionRecord.record)
+    pub enum Record {
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.SessionRecord.header)
+        Header(Header),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.SessionRecord.chip_added)
+        ChipAdded(ChipAdded),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.SessionRecord.chip_removed)
+        ChipRemoved(ChipRemoved),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.SessionRecord.device_patched)
+        DevicePatched(super::super::model::Device),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.SessionRecord.reset)
+        Reset(::protobuf::well_known_types::empty::Empty),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.SessionRecord.chip_kind_registered)
+        ChipKindRegistered(super::ChipKindInfo),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.SessionRecord.packet)
+        Packet(Packet),
+    }
+
+    impl ::protobuf::Oneof for Record {
+    }
+
+    impl ::protobuf::OneofFull for Record {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::SessionRecord as ::protobuf::MessageFull>::descriptor().oneof_by_name("record").unwrap()).clone()
+        }
+    }
+
+    impl Record {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Record>("record")
+        }
+    }
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.SessionRecord.Header)
+    pub struct Header {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.Header.version)
+        pub version: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.Header.seed)
+        pub seed: u64,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.Header.settings)
+        pub settings: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.SessionRecord.Header.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Header {
+        fn default() -> &'a Header {
+            <Header as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Header {
+        pub fn new() -> Header {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(3);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "version",
+                |m: &Header| { &m.version },
+                |m: &mut Header| { &mut m.version },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "seed",
+                |m: &Header| { &m.seed },
+                |m: &mut Header| { &mut m.seed },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+                "settings",
+                |m: &Header| { &m.settings },
+                |m: &mut Header| { &mut m.settings },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Header>(
+                "SessionRecord.Header",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Header {
+        const NAME: &'static str = "Header";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.version = is.read_string()?;
+                    },
+                    16 => {
+                        self.seed = is.read_uint64()?;
+                    },
+                    26 => {
+                        let len = is.read_raw_varint32()?;
+                        let old_limit = is.push_limit(len as u64)?;
+                        let mut key = ::std::default::Default::default();
+                        let mut value = ::std::default::Default::default();
+                        while let Some(tag) = is.read_raw_tag_or_eof()? {
+                            match tag {
+                                10 => key = is.read_string()?,
+                                18 => value = is.read_string()?,
+                                _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                            };
+                        }
+                        is.pop_limit(old_limit);
+                        self.settings.insert(key, value);
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.version.is_empty() {
+                my_size += ::protobuf::rt::string_size(1, &self.version);
+            }
+            if self.seed != 0 {
+                my_size += ::protobuf::rt::uint64_size(2, self.seed);
+            }
+            for (k, v) in &self.settings {
+                let mut entry_size = 0;
+                entry_size += ::protobuf::rt::string_size(1, &k);
+                entry_size += ::protobuf::rt::string_size(2, &v);
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+            };
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if !self.version.is_empty() {
+                os.write_string(1, &self.version)?;
+            }
+            if self.seed != 0 {
+                os.write_uint64(2, self.seed)?;
+            }
+            for (k, v) in &self.settings {
+                let mut entry_size = 0;
+                entry_size += ::protobuf::rt::string_size(1, &k);
+                entry_size += ::protobuf::rt::string_size(2, &v);
+                os.write_raw_varint32(26)?; // Tag.
+                os.write_raw_varint32(entry_size as u32)?;
+                os.write_string(1, &k)?;
+                os.write_string(2, &v)?;
+            };
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Header {
+            Header::new()
+        }
+
+        fn clear(&mut self) {
+            self.version.clear();
+            self.seed = 0;
+            self.settings.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Header {
+            static instance: ::protobuf::rt::Lazy<Header> = ::protobuf::rt::Lazy::new();
+            instance.get(Header::new)
+        }
+    }
+
+    impl ::protobuf::MessageFull for Header {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("SessionRecord.Header").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Header {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Header {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.SessionRecord.ChipAdded)
+    pub struct ChipAdded {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.ChipAdded.guid)
+        pub guid: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.ChipAdded.device_name)
+        pub device_name: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.ChipAdded.kind)
+        pub kind: u32,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.ChipAdded.chip_name)
+        pub chip_name: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.ChipAdded.manufacturer)
+        pub manufacturer: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.ChipAdded.product_name)
+        pub product_name: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.ChipAdded.chip_id)
+        pub chip_id: u32,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.ChipAdded.facade_id)
+        pub facade_id: u32,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.SessionRecord.ChipAdded.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a ChipAdded {
+        fn default() -> &'a ChipAdded {
+            <ChipAdded as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl ChipAdded {
+        pub fn new() -> ChipAdded {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(8);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "guid",
+                |m: &ChipAdded| { &m.guid },
+                |m: &mut ChipAdded| { &mut m.guid },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "device_name",
+                |m: &ChipAdded| { &m.device_name },
+                |m: &mut ChipAdded| { &mut m.device_name },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "kind",
+                |m: &ChipAdded| { &m.kind },
+                |m: &mut ChipAdded| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "chip_name",
+                |m: &ChipAdded| { &m.chip_name },
+                |m: &mut ChipAdded| { &mut m.chip_name },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "manufacturer",
+                |m: &ChipAdded| { &m.manufacturer },
+                |m: &mut ChipAdded| { &mut m.manufacturer },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "product_name",
+                |m: &ChipAdded| { &m.product_name },
+                |m: &mut ChipAdded| { &mut m.product_name },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "chip_id",
+                |m: &ChipAdded| { &m.chip_id },
+                |m: &mut ChipAdded| { &mut m.chip_id },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "facade_id",
+                |m: &ChipAdded| { &m.facade_id },
+                |m: &mut ChipAdded| { &mut m.facade_id },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ChipAdded>(
+                "SessionRecord.ChipAdded",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for ChipAdded {
+        const NAME: &'static str = "ChipAdded";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.guid = is.read_string()?;
+                    },
+                    18 => {
+                        self.device_name = is.read_string()?;
+                    },
+                    24 => {
+                        self.kind = is.read_uint32()?;
+                    },
+                    34 => {
+                        self.chip_name = is.read_string()?;
+                    },
+                    42 => {
+                        self.manufacturer = is.read_string()?;
+                    },
+                    50 => {
+                        self.product_name = is.read_string()?;
+                    },
+                    56 => {
+                        self.chip_id = is.read_uint32()?;
+                    },
+                    64 => {
+                        self.facade_id = is.read_uint32()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.guid.is_empty() {
+                my_size += ::protobuf::rt::string_size(1, &self.guid);
+            }
+            if !self.device_name.is_empty() {
+                my_size += ::protobuf::rt::string_size(2, &self.device_name);
+            }
+            if self.kind != 0 {
+                my_size += ::protobuf::rt::uint32_size(3, self.kind);
+            }
+            if !self.chip_name.is_empty() {
+                my_size += ::protobuf::rt::string_size(4, &self.chip_name);
+            }
+            if !self.manufacturer.is_empty() {
+                my_size += ::protobuf::rt::string_size(5, &self.manufacturer);
+            }
+            if !self.product_name.is_empty() {
+                my_size += ::protobuf::rt::string_size(6, &self.product_name);
+            }
+            if self.chip_id != 0 {
+                my_size += ::protobuf::rt::uint32_size(7, self.chip_id);
+            }
+            if self.facade_id != 0 {
+                my_size += ::protobuf::rt::uint32_size(8, self.facade_id);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if !self.guid.is_empty() {
+                os.write_string(1, &self.guid)?;
+            }
+            if !self.device_name.is_empty() {
+                os.write_string(2, &self.device_name)?;
+            }
+            if self.kind != 0 {
+                os.write_uint32(3, self.kind)?;
+            }
+            if !self.chip_name.is_empty() {
+                os.write_string(4, &self.chip_name)?;
+            }
+            if !self.manufacturer.is_empty() {
+                os.write_string(5, &self.manufacturer)?;
+            }
+            if !self.product_name.is_empty() {
+                os.write_string(6, &self.product_name)?;
+            }
+            if self.chip_id != 0 {
+                os.write_uint32(7, self.chip_id)?;
+            }
+            if self.facade_id != 0 {
+                os.write_uint32(8, self.facade_id)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> ChipAdded {
+            ChipAdded::new()
+        }
+
+        fn clear(&mut self) {
+            self.guid.clear();
+            self.device_name.clear();
+            self.kind = 0;
+            self.chip_name.clear();
+            self.manufacturer.clear();
+            self.product_name.clear();
+            self.chip_id = 0;
+            self.facade_id = 0;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static ChipAdded {
+            static instance: ChipAdded = ChipAdded {
+                guid: ::std::string::String::new(),
+                device_name: ::std::string::String::new(),
+                kind: 0,
+                chip_name: ::std::string::String::new(),
+                manufacturer: ::std::string::String::new(),
+                product_name: ::std::string::String::new(),
+                chip_id: 0,
+                facade_id: 0,
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for ChipAdded {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("SessionRecord.ChipAdded").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for ChipAdded {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for ChipAdded {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.SessionRecord.ChipRemoved)
+    pub struct ChipRemoved {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.ChipRemoved.chip_id)
+        pub chip_id: u32,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.SessionRecord.ChipRemoved.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a ChipRemoved {
+        fn default() -> &'a ChipRemoved {
+            <ChipRemoved as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl ChipRemoved {
+        pub fn new() -> ChipRemoved {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(1);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "chip_id",
+                |m: &ChipRemoved| { &m.chip_id },
+                |m: &mut ChipRemoved| { &mut m.chip_id },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ChipRemoved>(
+                "SessionRecord.ChipRemoved",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for ChipRemoved {
+        const NAME: &'static str = "ChipRemoved";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.chip_id = is.read_uint32()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.chip_id != 0 {
+                my_size += ::protobuf::rt::uint32_size(1, self.chip_id);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.chip_id != 0 {
+                os.write_uint32(1, self.chip_id)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> ChipRemoved {
+            ChipRemoved::new()
+        }
+
+        fn clear(&mut self) {
+            self.chip_id = 0;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static ChipRemoved {
+            static instance: ChipRemoved = ChipRemoved {
+                chip_id: 0,
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for ChipRemoved {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("SessionRecord.ChipRemoved").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for ChipRemoved {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for ChipRemoved {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.SessionRecord.Packet)
+    pub struct Packet {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.Packet.kind)
+        pub kind: u32,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.Packet.facade_id)
+        pub facade_id: u32,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.Packet.packet_type)
+        pub packet_type: u32,
+        // @@protoc_insertion_point(field:netsim.frontend.SessionRecord.Packet.packet)
+        pub packet: ::std::vec::Vec<u8>,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.SessionRecord.Packet.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Packet {
+        fn default() -> &'a Packet {
+            <Packet as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Packet {
+        pub fn new() -> Packet {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(4);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "kind",
+                |m: &Packet| { &m.kind },
+                |m: &mut Packet| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "facade_id",
+                |m: &Packet| { &m.facade_id },
+                |m: &mut Packet| { &mut m.facade_id },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "packet_type",
+                |m: &Packet| { &m.packet_type },
+                |m: &mut Packet| { &mut m.packet_type },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "packet",
+                |m: &Packet| { &m.packet },
+                |m: &mut Packet| { &mut m.packet },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Packet>(
+                "SessionRecord.Packet",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Packet {
+        const NAME: &'static str = "Packet";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.kind = is.read_uint32()?;
+                    },
+                    16 => {
+                        self.facade_id = is.read_uint32()?;
+                    },
+                    24 => {
+                        self.packet_type = is.read_uint32()?;
+                    },
+                    34 => {
+                        self.packet = is.read_bytes()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.kind != 0 {
+                my_size += ::protobuf::rt::uint32_size(1, self.kind);
+            }
+            if self.facade_id != 0 {
+                my_size += ::protobuf::rt::uint32_size(2, self.facade_id);
+            }
+            if self.packet_type != 0 {
+                my_size += ::protobuf::rt::uint32_size(3, self.packet_type);
+            }
+            if !self.packet.is_empty() {
+                my_size += ::protobuf::rt::bytes_size(4, &self.packet);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.kind != 0 {
+                os.write_uint32(1, self.kind)?;
+            }
+            if self.facade_id != 0 {
+                os.write_uint32(2, self.facade_id)?;
+            }
+            if self.packet_type != 0 {
+                os.write_uint32(3, self.packet_type)?;
+            }
+            if !self.packet.is_empty() {
+                os.write_bytes(4, &self.packet)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Packet {
+            Packet::new()
+        }
+
+        fn clear(&mut self) {
+            self.kind = 0;
+            self.facade_id = 0;
+            self.packet_type = 0;
+            self.packet.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Packet {
+            static instance: Packet = Packet {
+                kind: 0,
+                facade_id: 0,
+                packet_type: 0,
+                packet: ::std::vec::Vec::new(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Packet {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("SessionRecord.Packet").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Packet {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Packet {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ReplayFuzzInputRequest)
+pub struct ReplayFuzzInputRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayFuzzInputRequest.target)
+    pub target: ::protobuf::EnumOrUnknown<replay_fuzz_input_request::Target>,
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayFuzzInputRequest.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayFuzzInputRequest.input)
+    pub input: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ReplayFuzzInputRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReplayFuzzInputRequest {
+    fn default() -> &'a ReplayFuzzInputRequest {
+        <ReplayFuzzInputRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReplayFuzzInputRequest {
+    pub fn new() -> ReplayFuzzInputRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "target",
+            |m: &ReplayFuzzInputRequest| { &m.target },
+            |m: &mut ReplayFuzzInputRequest| { &mut m.target },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &ReplayFuzzInputRequest| { &m.name },
+            |m: &mut ReplayFuzzInputRequest| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "input",
+            |m: &ReplayFuzzInputRequest| { &m.input },
+            |m: &mut ReplayFuzzInputRequest| { &mut m.input },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReplayFuzzInputRequest>(
+            "ReplayFuzzInputRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReplayFuzzInputRequest {
+    const NAME: &'static str = "ReplayFuzzInputRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.target = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.name = is.read_string()?;
+                },
+                26 => {
+                    self.input = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.target != ::protobuf::EnumOrUnknown::new(replay_fuzz_input_request::Target::H4) {
+            my_size += ::protobuf::rt::int32_size(1, self.target.value());
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if !self.input.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.input);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.target != ::protobuf::EnumOrUnknown::new(replay_fuzz_input_request::Target::H4) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.target))?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if !self.input.is_empty() {
+            os.write_bytes(3, &self.input)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReplayFuzzInputRequest {
+        ReplayFuzzInputRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.target = ::protobuf::EnumOrUnknown::new(replay_fuzz_input_request::Target::H4);
+        self.name.clear();
+        self.input.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReplayFuzzInputRequest {
+        static instance: ReplayFuzzInputRequest = ReplayFuzzInputRequest {
+            target: ::protobuf::EnumOrUnknown::from_i32(0),
+            name: ::std::string::String::new(),
+            input: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReplayFuzzInputRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReplayFuzzInputRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReplayFuzzInputRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReplayFuzzInputRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ReplayFuzzInputRequest`
+pub mod replay_fuzz_input_request {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.ReplayFuzzInputRequest.Target)
+    pub enum Target {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ReplayFuzzInputRequest.Target.H4)
+        H4 = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ReplayFuzzInputRequest.Target.UCI)
+        UCI = 1,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ReplayFuzzInputRequest.Target.PCAP)
+        PCAP = 2,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ReplayFuzzInputRequest.Target.HTTP_REQUEST)
+        HTTP_REQUEST = 3,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ReplayFuzzInputRequest.Target.FILTER)
+        FILTER = 4,
+    }
+
+    impl ::protobuf::Enum for Target {
+        const NAME: &'static str = "Target";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Target> {
+            match value {
+                0 => ::std::option::Option::Some(Target::H4),
+                1 => ::std::option::Option::Some(Target::UCI),
+                2 => ::std::option::Option::Some(Target::PCAP),
+                3 => ::std::option::Option::Some(Target::HTTP_REQUEST),
+                4 => ::std::option::Option::Some(Target::FILTER),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Target] = &[
+            Target::H4,
+            Target::UCI,
+            Target::PCAP,
+            Target::HTTP_REQUEST,
+            Target::FILTER,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Target {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("ReplayFuzzInputRequest.Target").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Target {
+        fn default() -> Self {
+            Target::H4
+        }
+    }
+
+    impl Target {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Target>("ReplayFuzzInputRequest.Target")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ReplayFuzzInputResponse)
+pub struct ReplayFuzzInputResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayFuzzInputResponse.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayFuzzInputResponse.accepted)
+    pub accepted: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayFuzzInputResponse.crashed)
+    pub crashed: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.ReplayFuzzInputResponse.error)
+    pub error: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ReplayFuzzInputResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReplayFuzzInputResponse {
+    fn default() -> &'a ReplayFuzzInputResponse {
+        <ReplayFuzzInputResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReplayFuzzInputResponse {
+    pub fn new() -> ReplayFuzzInputResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &ReplayFuzzInputResponse| { &m.name },
+            |m: &mut ReplayFuzzInputResponse| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "accepted",
+            |m: &ReplayFuzzInputResponse| { &m.accepted },
+            |m: &mut ReplayFuzzInputResponse| { &mut m.accepted },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "crashed",
+            |m: &ReplayFuzzInputResponse| { &m.crashed },
+            |m: &mut ReplayFuzzInputResponse| { &mut m.crashed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &ReplayFuzzInputResponse| { &m.error },
+            |m: &mut ReplayFuzzInputResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReplayFuzzInputResponse>(
+            "ReplayFuzzInputResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReplayFuzzInputResponse {
+    const NAME: &'static str = "ReplayFuzzInputResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.accepted = is.read_bool()?;
+                },
+                24 => {
+                    self.crashed = is.read_bool()?;
+                },
+                34 => {
+                    self.error = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.accepted != false {
+            my_size += 1 + 1;
+        }
+        if self.crashed != false {
+            my_size += 1 + 1;
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.accepted != false {
+            os.write_bool(2, self.accepted)?;
+        }
+        if self.crashed != false {
+            os.write_bool(3, self.crashed)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(4, &self.error)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReplayFuzzInputResponse {
+        ReplayFuzzInputResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.accepted = false;
+        self.crashed = false;
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReplayFuzzInputResponse {
+        static instance: ReplayFuzzInputResponse = ReplayFuzzInputResponse {
+            name: ::std::string::String::new(),
+            accepted: false,
+            crashed: false,
+            error: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReplayFuzzInputResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReplayFuzzInputResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReplayFuzzInputResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReplayFuzzInputResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ChipTransportStats)
+pub struct ChipTransportStats {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ChipTransportStats.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipTransportStats.chip_id)
+    pub chip_id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipTransportStats.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipTransportStats.packets_in)
+    pub packets_in: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipTransportStats.bytes_in)
+    pub bytes_in: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipTransportStats.packets_out)
+    pub packets_out: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipTransportStats.bytes_out)
+    pub bytes_out: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipTransportStats.malformed_packets)
+    pub malformed_packets: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipTransportStats.queue_depth)
+    pub queue_depth: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipTransportStats.last_activity)
+    pub last_activity: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ChipTransportStats.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ChipTransportStats {
+    fn default() -> &'a ChipTransportStats {
+        <ChipTransportStats as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChipTransportStats {
+    pub fn new() -> ChipTransportStats {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(10);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &ChipTransportStats| { &m.device_name },
+            |m: &mut ChipTransportStats| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_id",
+            |m: &ChipTransportStats| { &m.chip_id },
+            |m: &mut ChipTransportStats| { &mut m.chip_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &ChipTransportStats| { &m.chip_kind },
+            |m: &mut ChipTransportStats| { &mut m.chip_kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "packets_in",
+            |m: &ChipTransportStats| { &m.packets_in },
+            |m: &mut ChipTransportStats| { &mut m.packets_in },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bytes_in",
+            |m: &ChipTransportStats| { &m.bytes_in },
+            |m: &mut ChipTransportStats| { &mut m.bytes_in },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "packets_out",
+            |m: &ChipTransportStats| { &m.packets_out },
+            |m: &mut ChipTransportStats| { &mut m.packets_out },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bytes_out",
+            |m: &ChipTransportStats| { &m.bytes_out },
+            |m: &mut ChipTransportStats| { &mut m.bytes_out },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "malformed_packets",
+            |m: &ChipTransportStats| { &m.malformed_packets },
+            |m: &mut ChipTransportStats| { &mut m.malformed_packets },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "queue_depth",
+            |m: &ChipTransportStats| { &m.queue_depth },
+            |m: &mut ChipTransportStats| { &mut m.queue_depth },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "last_activity",
+            |m: &ChipTransportStats| { &m.last_activity },
+            |m: &mut ChipTransportStats| { &mut m.last_activity },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ChipTransportStats>(
+            "ChipTransportStats",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ChipTransportStats {
+    const NAME: &'static str = "ChipTransportStats";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.device_name = is.read_string()?;
+                },
+                16 => {
+                    self.chip_id = is.read_int32()?;
+                },
+                24 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                32 => {
+                    self.packets_in = is.read_uint64()?;
+                },
+                40 => {
+                    self.bytes_in = is.read_uint64()?;
+                },
+                48 => {
+                    self.packets_out = is.read_uint64()?;
+                },
+                56 => {
+                    self.bytes_out = is.read_uint64()?;
+                },
+                64 => {
+                    self.malformed_packets = is.read_uint64()?;
+                },
+                72 => {
+                    self.queue_depth = is.read_uint64()?;
+                },
+                82 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.last_activity)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device_name);
+        }
+        if self.chip_id != 0 {
+            my_size += ::protobuf::rt::int32_size(2, self.chip_id);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(3, self.chip_kind.value());
+        }
+        if self.packets_in != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.packets_in);
+        }
+        if self.bytes_in != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.bytes_in);
+        }
+        if self.packets_out != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.packets_out);
+        }
+        if self.bytes_out != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.bytes_out);
+        }
+        if self.malformed_packets != 0 {
+            my_size += ::protobuf::rt::uint64_size(8, self.malformed_packets);
+        }
+        if self.queue_depth != 0 {
+            my_size += ::protobuf::rt::uint64_size(9, self.queue_depth);
+        }
+        if let Some(v) = self.last_activity.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.device_name.is_empty() {
+            os.write_string(1, &self.device_name)?;
+        }
+        if self.chip_id != 0 {
+            os.write_int32(2, self.chip_id)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        if self.packets_in != 0 {
+            os.write_uint64(4, self.packets_in)?;
+        }
+        if self.bytes_in != 0 {
+            os.write_uint64(5, self.bytes_in)?;
+        }
+        if self.packets_out != 0 {
+            os.write_uint64(6, self.packets_out)?;
+        }
+        if self.bytes_out != 0 {
+            os.write_uint64(7, self.bytes_out)?;
+        }
+        if self.malformed_packets != 0 {
+            os.write_uint64(8, self.malformed_packets)?;
+        }
+        if self.queue_depth != 0 {
+            os.write_uint64(9, self.queue_depth)?;
+        }
+        if let Some(v) = self.last_activity.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ChipTransportStats {
+        ChipTransportStats::new()
+    }
+
+    fn clear(&mut self) {
+        self.device_name.clear();
+        self.chip_id = 0;
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.packets_in = 0;
+        self.bytes_in = 0;
+        self.packets_out = 0;
+        self.bytes_out = 0;
+        self.malformed_packets = 0;
+        self.queue_depth = 0;
+        self.last_activity.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ChipTransportStats {
+        static instance: ChipTransportStats = ChipTransportStats {
+            device_name: ::std::string::String::new(),
+            chip_id: 0,
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            packets_in: 0,
+            bytes_in: 0,
+            packets_out: 0,
+            bytes_out: 0,
+            malformed_packets: 0,
+            queue_depth: 0,
+            last_activity: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ChipTransportStats {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ChipTransportStats").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ChipTransportStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChipTransportStats {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetChipTransportStatsResponse)
+pub struct GetChipTransportStatsResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetChipTransportStatsResponse.chips)
+    pub chips: ::std::vec::Vec<ChipTransportStats>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetChipTransportStatsResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetChipTransportStatsResponse {
+    fn default() -> &'a GetChipTransportStatsResponse {
+        <GetChipTransportStatsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetChipTransportStatsResponse {
+    pub fn new() -> GetChipTransportStatsResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "chips",
+            |m: &GetChipTransportStatsResponse| { &m.chips },
+            |m: &mut GetChipTransportStatsResponse| { &mut m.chips },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetChipTransportStatsResponse>(
+            "GetChipTransportStatsResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetChipTransportStatsResponse {
+    const NAME: &'static str = "GetChipTransportStatsResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.chips.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.chips {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.chips {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetChipTransportStatsResponse {
+        GetChipTransportStatsResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.chips.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetChipTransportStatsResponse {
+        static instance: GetChipTransportStatsResponse = GetChipTransportStatsResponse {
+            chips: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetChipTransportStatsResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetChipTransportStatsResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetChipTransportStatsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetChipTransportStatsResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.Capacity)
+pub struct Capacity {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.Capacity.resource)
+    pub resource: ::protobuf::EnumOrUnknown<capacity::Resource>,
+    // @@protoc_insertion_point(field:netsim.frontend.Capacity.used)
+    pub used: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.Capacity.limit)
+    pub limit: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.Capacity.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Capacity {
+    fn default() -> &'a Capacity {
+        <Capacity as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Capacity {
+    pub fn new() -> Capacity {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "resource",
+            |m: &Capacity| { &m.resource },
+            |m: &mut Capacity| { &mut m.resource },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "used",
+            |m: &Capacity| { &m.used },
+            |m: &mut Capacity| { &mut m.used },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &Capacity| { &m.limit },
+            |m: &mut Capacity| { &mut m.limit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Capacity>(
+            "Capacity",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Capacity {
+    const NAME: &'static str = "Capacity";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.resource = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.used = is.read_uint64()?;
+                },
+                24 => {
+                    self.limit = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.resource != ::protobuf::EnumOrUnknown::new(capacity::Resource::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(1, self.resource.value());
+        }
+        if self.used != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.used);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.limit);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.resource != ::protobuf::EnumOrUnknown::new(capacity::Resource::UNSPECIFIED) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.resource))?;
+        }
+        if self.used != 0 {
+            os.write_uint64(2, self.used)?;
+        }
+        if self.limit != 0 {
+            os.write_uint64(3, self.limit)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Capacity {
+        Capacity::new()
+    }
+
+    fn clear(&mut self) {
+        self.resource = ::protobuf::EnumOrUnknown::new(capacity::Resource::UNSPECIFIED);
+        self.used = 0;
+        self.limit = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Capacity {
+        static instance: Capacity = Capacity {
+            resource: ::protobuf::EnumOrUnknown::from_i32(0),
+            used: 0,
+            limit: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Capacity {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Capacity").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Capacity {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Capacity {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Capacity`
+pub mod capacity {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.Capacity.Resource)
+    pub enum Resource {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Capacity.Resource.UNSPECIFIED)
+        UNSPECIFIED = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Capacity.Resource.DEVICES)
+        DEVICES = 1,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Capacity.Resource.CHIPS)
+        CHIPS = 2,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Capacity.Resource.CAPTURES)
+        CAPTURES = 3,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Capacity.Resource.DISK)
+        DISK = 4,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Capacity.Resource.MEMORY)
+        MEMORY = 5,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Capacity.Resource.EVENTS)
+        EVENTS = 6,
+    }
+
+    impl ::protobuf::Enum for Resource {
+        const NAME: &'static str = "Resource";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Resource> {
+            match value {
+                0 => ::std::option::Option::Some(Resource::UNSPECIFIED),
+                1 => ::std::option::Option::Some(Resource::DEVICES),
+                2 => ::std::option::Option::Some(Resource::CHIPS),
+                3 => ::std::option::Option::Some(Resource::CAPTURES),
+                4 => ::std::option::Option::Some(Resource::DISK),
+                5 => ::std::option::Option::Some(Resource::MEMORY),
+                6 => ::std::option::Option::Some(Resource::EVENTS),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Resource] = &[
+            Resource::UNSPECIFIED,
+            Resource::DEVICES,
+            Resource::CHIPS,
+            Resource::CAPTURES,
+            Resource::DISK,
+            Resource::MEMORY,
+            Resource::EVENTS,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Resource {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Capacity.Resource").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Resource {
+        fn default() -> Self {
+            Resource::UNSPECIFIED
+        }
+    }
+
+    impl Resource {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Resource>("Capacity.Resource")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetCapacityResponse)
+pub struct GetCapacityResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetCapacityResponse.resources)
+    pub resources: ::std::vec::Vec<Capacity>,
+    // @@protoc_insertion_point(field:netsim.frontend.GetCapacityResponse.available)
+    pub available: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetCapacityResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetCapacityResponse {
+    fn default() -> &'a GetCapacityResponse {
+        <GetCapacityResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetCapacityResponse {
+    pub fn new() -> GetCapacityResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "resources",
+            |m: &GetCapacityResponse| { &m.resources },
+            |m: &mut GetCapacityResponse| { &mut m.resources },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "available",
+            |m: &GetCapacityResponse| { &m.available },
+            |m: &mut GetCapacityResponse| { &mut m.available },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetCapacityResponse>(
+            "GetCapacityResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetCapacityResponse {
+    const NAME: &'static str = "GetCapacityResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.resources.push(is.read_message()?);
+                },
+                16 => {
+                    self.available = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.resources {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.available != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.resources {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.available != false {
+            os.write_bool(2, self.available)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetCapacityResponse {
+        GetCapacityResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.resources.clear();
+        self.available = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetCapacityResponse {
+        static instance: GetCapacityResponse = GetCapacityResponse {
+            resources: ::std::vec::Vec::new(),
+            available: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetCapacityResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetCapacityResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetCapacityResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetCapacityResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ApiStats)
+pub struct ApiStats {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ApiStats.api)
+    pub api: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ApiStats.calls)
+    pub calls: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ApiStats.errors)
+    pub errors: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ApiStats.cancelled)
+    pub cancelled: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ApiStats.total_latency_us)
+    pub total_latency_us: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ApiStats.max_latency_us)
+    pub max_latency_us: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ApiStats.p50_latency_us)
+    pub p50_latency_us: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ApiStats.p99_latency_us)
+    pub p99_latency_us: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ApiStats.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ApiStats {
+    fn default() -> &'a ApiStats {
+        <ApiStats as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ApiStats {
+    pub fn new() -> ApiStats {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "api",
+            |m: &ApiStats| { &m.api },
+            |m: &mut ApiStats| { &mut m.api },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "calls",
+            |m: &ApiStats| { &m.calls },
+            |m: &mut ApiStats| { &mut m.calls },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "errors",
+            |m: &ApiStats| { &m.errors },
+            |m: &mut ApiStats| { &mut m.errors },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "cancelled",
+            |m: &ApiStats| { &m.cancelled },
+            |m: &mut ApiStats| { &mut m.cancelled },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total_latency_us",
+            |m: &ApiStats| { &m.total_latency_us },
+            |m: &mut ApiStats| { &mut m.total_latency_us },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_latency_us",
+            |m: &ApiStats| { &m.max_latency_us },
+            |m: &mut ApiStats| { &mut m.max_latency_us },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "p50_latency_us",
+            |m: &ApiStats| { &m.p50_latency_us },
+            |m: &mut ApiStats| { &mut m.p50_latency_us },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "p99_latency_us",
+            |m: &ApiStats| { &m.p99_latency_us },
+            |m: &mut ApiStats| { &mut m.p99_latency_us },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ApiStats>(
+            "ApiStats",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ApiStats {
+    const NAME: &'static str = "ApiStats";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.api = is.read_string()?;
+                },
+                16 => {
+                    self.calls = is.read_uint64()?;
+                },
+                24 => {
+                    self.errors = is.read_uint64()?;
+                },
+                64 => {
+                    self.cancelled = is.read_uint64()?;
+                },
+                32 => {
+                    self.total_latency_us = is.read_uint64()?;
+                },
+                40 => {
+                    self.max_latency_us = is.read_uint64()?;
+                },
+                48 => {
+                    self.p50_latency_us = is.read_uint64()?;
+                },
+                56 => {
+                    self.p99_latency_us = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.api.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.api);
+        }
+        if self.calls != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.calls);
+        }
+        if self.errors != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.errors);
+        }
+        if self.cancelled != 0 {
+            my_size += ::protobuf::rt::uint64_size(8, self.cancelled);
+        }
+        if self.total_latency_us != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.total_latency_us);
+        }
+        if self.max_latency_us != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.max_latency_us);
+        }
+        if self.p50_latency_us != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.p50_latency_us);
+        }
+        if self.p99_latency_us != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.p99_latency_us);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.api.is_empty() {
+            os.write_string(1, &self.api)?;
+        }
+        if self.calls != 0 {
+            os.write_uint64(2, self.calls)?;
+        }
+        if self.errors != 0 {
+            os.write_uint64(3, self.errors)?;
+        }
+        if self.cancelled != 0 {
+            os.write_uint64(8, self.cancelled)?;
+        }
+        if self.total_latency_us != 0 {
+            os.write_uint64(4, self.total_latency_us)?;
+        }
+        if self.max_latency_us != 0 {
+            os.write_uint64(5, self.max_latency_us)?;
+        }
+        if self.p50_latency_us != 0 {
+            os.write_uint64(6, self.p50_latency_us)?;
+        }
+        if self.p99_latency_us != 0 {
+            os.write_uint64(7, self.p99_latency_us)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ApiStats {
+        ApiStats::new()
+    }
+
+    fn clear(&mut self) {
+        self.api.clear();
+        self.calls = 0;
+        self.errors = 0;
+        self.cancelled = 0;
+        self.total_latency_us = 0;
+        self.max_latency_us = 0;
+        self.p50_latency_us = 0;
+        self.p99_latency_us = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ApiStats {
+        static instance: ApiStats = ApiStats {
+            api: ::std::string::String::new(),
+            calls: 0,
+            errors: 0,
+            cancelled: 0,
+            total_latency_us: 0,
+            max_latency_us: 0,
+            p50_latency_us: 0,
+            p99_latency_us: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ApiStats {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ApiStats").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ApiStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ApiStats {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetApiStatsResponse)
+pub struct GetApiStatsResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetApiStatsResponse.apis)
+    pub apis: ::std::vec::Vec<ApiStats>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetApiStatsResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetApiStatsResponse {
+    fn default() -> &'a GetApiStatsResponse {
+        <GetApiStatsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetApiStatsResponse {
+    pub fn new() -> GetApiStatsResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "apis",
+            |m: &GetApiStatsResponse| { &m.apis },
+            |m: &mut GetApiStatsResponse| { &mut m.apis },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetApiStatsResponse>(
+            "GetApiStatsResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetApiStatsResponse {
+    const NAME: &'static str = "GetApiStatsResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.apis.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.apis {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.apis {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetApiStatsResponse {
+        GetApiStatsResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.apis.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetApiStatsResponse {
+        static instance: GetApiStatsResponse = GetApiStatsResponse {
+            apis: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetApiStatsResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetApiStatsResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetApiStatsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetApiStatsResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetLinkRequest)
+pub struct GetLinkRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetLinkRequest.device_a)
+    pub device_a: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.GetLinkRequest.device_b)
+    pub device_b: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.GetLinkRequest.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.frontend.GetLinkRequest.tx_power)
+    pub tx_power: i32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetLinkRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetLinkRequest {
+    fn default() -> &'a GetLinkRequest {
+        <GetLinkRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetLinkRequest {
+    pub fn new() -> GetLinkRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_a",
+            |m: &GetLinkRequest| { &m.device_a },
+            |m: &mut GetLinkRequest| { &mut m.device_a },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_b",
+            |m: &GetLinkRequest| { &m.device_b },
+            |m: &mut GetLinkRequest| { &mut m.device_b },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &GetLinkRequest| { &m.chip_kind },
+            |m: &mut GetLinkRequest| { &mut m.chip_kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tx_power",
+            |m: &GetLinkRequest| { &m.tx_power },
+            |m: &mut GetLinkRequest| { &mut m.tx_power },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetLinkRequest>(
+            "GetLinkRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetLinkRequest {
+    const NAME: &'static str = "GetLinkRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.device_a = is.read_string()?;
+                },
+                18 => {
+                    self.device_b = is.read_string()?;
+                },
+                24 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                32 => {
+                    self.tx_power = is.read_int32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.device_a.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device_a);
+        }
+        if !self.device_b.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.device_b);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(3, self.chip_kind.value());
+        }
+        if self.tx_power != 0 {
+            my_size += ::protobuf::rt::int32_size(4, self.tx_power);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.device_a.is_empty() {
+            os.write_string(1, &self.device_a)?;
+        }
+        if !self.device_b.is_empty() {
+            os.write_string(2, &self.device_b)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        if self.tx_power != 0 {
+            os.write_int32(4, self.tx_power)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetLinkRequest {
+        GetLinkRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.device_a.clear();
+        self.device_b.clear();
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.tx_power = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetLinkRequest {
+        static instance: GetLinkRequest = GetLinkRequest {
+            device_a: ::std::string::String::new(),
+            device_b: ::std::string::String::new(),
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            tx_power: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetLinkRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetLinkRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetLinkRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetLinkRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.LinkImpairment)
+pub struct LinkImpairment {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.LinkImpairment.kind)
+    pub kind: ::protobuf::EnumOrUnknown<link_impairment::Kind>,
+    // @@protoc_insertion_point(field:netsim.frontend.LinkImpairment.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.LinkImpairment.description)
+    pub description: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.LinkImpairment.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LinkImpairment {
+    fn default() -> &'a LinkImpairment {
+        <LinkImpairment as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LinkImpairment {
+    pub fn new() -> LinkImpairment {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "kind",
+            |m: &LinkImpairment| { &m.kind },
+            |m: &mut LinkImpairment| { &mut m.kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &LinkImpairment| { &m.device_name },
+            |m: &mut LinkImpairment| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "description",
+            |m: &LinkImpairment| { &m.description },
+            |m: &mut LinkImpairment| { &mut m.description },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LinkImpairment>(
+            "LinkImpairment",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LinkImpairment {
+    const NAME: &'static str = "LinkImpairment";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.kind = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.device_name = is.read_string()?;
+                },
+                26 => {
+                    self.description = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.kind != ::protobuf::EnumOrUnknown::new(link_impairment::Kind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(1, self.kind.value());
+        }
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.device_name);
+        }
+        if !self.description.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.description);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.kind != ::protobuf::EnumOrUnknown::new(link_impairment::Kind::UNSPECIFIED) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.kind))?;
+        }
+        if !self.device_name.is_empty() {
+            os.write_string(2, &self.device_name)?;
+        }
+        if !self.description.is_empty() {
+            os.write_string(3, &self.description)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LinkImpairment {
+        LinkImpairment::new()
+    }
+
+    fn clear(&mut self) {
+        self.kind = ::protobuf::EnumOrUnknown::new(link_impairment::Kind::UNSPECIFIED);
+        self.device_name.clear();
+        self.description.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LinkImpairment {
+        static instance: LinkImpairment = LinkImpairment {
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            device_name: ::std::string::String::new(),
+            description: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LinkImpairment {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LinkImpairment").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LinkImpairment {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LinkImpairment {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `LinkImpairment`
+pub mod link_impairment {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.LinkImpairment.Kind)
+    pub enum Kind {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.LinkImpairment.Kind.UNSPECIFIED)
+        UNSPECIFIED = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.LinkImpairment.Kind.RADIO_OFF)
+        RADIO_OFF = 1,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.LinkImpairment.Kind.PACKET_PROCESSOR)
+        PACKET_PROCESSOR = 2,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.LinkImpairment.Kind.PACKET_DROP)
+        PACKET_DROP = 3,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.LinkImpairment.Kind.PACKET_DELAY)
+        PACKET_DELAY = 4,
+    }
+
+    impl ::protobuf::Enum for Kind {
+        const NAME: &'static str = "Kind";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Kind> {
+            match value {
+                0 => ::std::option::Option::Some(Kind::UNSPECIFIED),
+                1 => ::std::option::Option::Some(Kind::RADIO_OFF),
+                2 => ::std::option::Option::Some(Kind::PACKET_PROCESSOR),
+                3 => ::std::option::Option::Some(Kind::PACKET_DROP),
+                4 => ::std::option::Option::Some(Kind::PACKET_DELAY),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Kind] = &[
+            Kind::UNSPECIFIED,
+            Kind::RADIO_OFF,
+            Kind::PACKET_PROCESSOR,
+            Kind::PACKET_DROP,
+            Kind::PACKET_DELAY,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Kind {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("LinkImpairment.Kind").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Kind {
+        fn default() -> Self {
+            Kind::UNSPECIFIED
+        }
+    }
+
+    impl Kind {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Kind>("LinkImpairment.Kind")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetLinkResponse)
+pub struct GetLinkResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetLinkResponse.distance)
+    pub distance: f32,
+    // @@protoc_insertion_point(field:netsim.frontend.GetLinkResponse.path_loss)
+    pub path_loss: f32,
+    // @@protoc_insertion_point(field:netsim.frontend.GetLinkResponse.rssi)
+    pub rssi: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.GetLinkResponse.impairments)
+    pub impairments: ::std::vec::Vec<LinkImpairment>,
+    // @@protoc_insertion_point(field:netsim.frontend.GetLinkResponse.delivery_probability)
+    pub delivery_probability: f32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetLinkResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetLinkResponse {
+    fn default() -> &'a GetLinkResponse {
+        <GetLinkResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetLinkResponse {
+    pub fn new() -> GetLinkResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "distance",
+            |m: &GetLinkResponse| { &m.distance },
+            |m: &mut GetLinkResponse| { &mut m.distance },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "path_loss",
+            |m: &GetLinkResponse| { &m.path_loss },
+            |m: &mut GetLinkResponse| { &mut m.path_loss },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "rssi",
+            |m: &GetLinkResponse| { &m.rssi },
+            |m: &mut GetLinkResponse| { &mut m.rssi },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "impairments",
+            |m: &GetLinkResponse| { &m.impairments },
+            |m: &mut GetLinkResponse| { &mut m.impairments },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "delivery_probability",
+            |m: &GetLinkResponse| { &m.delivery_probability },
+            |m: &mut GetLinkResponse| { &mut m.delivery_probability },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetLinkResponse>(
+            "GetLinkResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetLinkResponse {
+    const NAME: &'static str = "GetLinkResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                13 => {
+                    self.distance = is.read_float()?;
+                },
+                21 => {
+                    self.path_loss = is.read_float()?;
+                },
+                24 => {
+                    self.rssi = is.read_int32()?;
+                },
+                34 => {
+                    self.impairments.push(is.read_message()?);
+                },
+                45 => {
+                    self.delivery_probability = is.read_float()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.distance != 0. {
+            my_size += 1 + 4;
+        }
+        if self.path_loss != 0. {
+            my_size += 1 + 4;
+        }
+        if self.rssi != 0 {
+            my_size += ::protobuf::rt::int32_size(3, self.rssi);
+        }
+        for value in &self.impairments {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.delivery_probability != 0. {
+            my_size += 1 + 4;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.distance != 0. {
+            os.write_float(1, self.distance)?;
+        }
+        if self.path_loss != 0. {
+            os.write_float(2, self.path_loss)?;
+        }
+        if self.rssi != 0 {
+            os.write_int32(3, self.rssi)?;
+        }
+        for v in &self.impairments {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        if self.delivery_probability != 0. {
+            os.write_float(5, self.delivery_probability)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetLinkResponse {
+        GetLinkResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.distance = 0.;
+        self.path_loss = 0.;
+        self.rssi = 0;
+        self.impairments.clear();
+        self.delivery_probability = 0.;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetLinkResponse {
+        static instance: GetLinkResponse = GetLinkResponse {
+            distance: 0.,
+            path_loss: 0.,
+            rssi: 0,
+            impairments: ::std::vec::Vec::new(),
+            delivery_probability: 0.,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetLinkResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetLinkResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetLinkResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetLinkResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ListEventsRequest)
+pub struct ListEventsRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ListEventsRequest.types)
+    pub types: ::std::vec::Vec<::protobuf::EnumOrUnknown<event::Type>>,
+    // @@protoc_insertion_point(field:netsim.frontend.ListEventsRequest.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ListEventsRequest.since_id)
+    pub since_id: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.ListEventsRequest.since_time)
+    pub since_time: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:netsim.frontend.ListEventsRequest.limit)
+    pub limit: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ListEventsRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListEventsRequest {
+    fn default() -> &'a ListEventsRequest {
+        <ListEventsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListEventsRequest {
+    pub fn new() -> ListEventsRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "types",
+            |m: &ListEventsRequest| { &m.types },
+            |m: &mut ListEventsRequest| { &mut m.types },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &ListEventsRequest| { &m.device_name },
+            |m: &mut ListEventsRequest| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "since_id",
+            |m: &ListEventsRequest| { &m.since_id },
+            |m: &mut ListEventsRequest| { &mut m.since_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "since_time",
+            |m: &ListEventsRequest| { &m.since_time },
+            |m: &mut ListEventsRequest| { &mut m.since_time },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &ListEventsRequest| { &m.limit },
+            |m: &mut ListEventsRequest| { &mut m.limit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListEventsRequest>(
+            "ListEventsRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListEventsRequest {
+    const NAME: &'static str = "ListEventsRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.types.push(is.read_enum_or_unknown()?);
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_packed_enum_or_unknown_into(is, &mut self.types)?
+                },
+                18 => {
+                    self.device_name = is.read_string()?;
+                },
+                24 => {
+                    self.since_id = is.read_uint64()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.since_time)?;
+                },
+                40 => {
+                    self.limit = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.types {
+            my_size += ::protobuf::rt::int32_size(1, value.value());
+        };
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.device_name);
+        }
+        if self.since_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.since_id);
+        }
+        if let Some(v) = self.since_time.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.limit);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.types {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(v))?;
+        };
+        if !self.device_name.is_empty() {
+            os.write_string(2, &self.device_name)?;
+        }
+        if self.since_id != 0 {
+            os.write_uint64(3, self.since_id)?;
+        }
+        if let Some(v) = self.since_time.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(5, self.limit)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListEventsRequest {
+        ListEventsRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.types.clear();
+        self.device_name.clear();
+        self.since_id = 0;
+        self.since_time.clear();
+        self.limit = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListEventsRequest {
+        static instance: ListEventsRequest = ListEventsRequest {
+            types: ::std::vec::Vec::new(),
+            device_name: ::std::string::String::new(),
+            since_id: 0,
+            since_time: ::protobuf::MessageField::none(),
+            limit: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListEventsRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListEventsRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListEventsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListEventsRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.SubscribeEventsRequest)
+pub struct SubscribeEventsRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.SubscribeEventsRequest.types)
+    pub types: ::std::vec::Vec<::protobuf::EnumOrUnknown<event::Type>>,
+    // @@protoc_insertion_point(field:netsim.frontend.SubscribeEventsRequest.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.SubscribeEventsRequest.since_id)
+    pub since_id: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.SubscribeEventsRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SubscribeEventsRequest {
+    fn default() -> &'a SubscribeEventsRequest {
+        <SubscribeEventsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SubscribeEventsRequest {
+    pub fn new() -> SubscribeEventsRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "types",
+            |m: &SubscribeEventsRequest| { &m.types },
+            |m: &mut SubscribeEventsRequest| { &mut m.types },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &SubscribeEventsRequest| { &m.device_name },
+            |m: &mut SubscribeEventsRequest| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "since_id",
+            |m: &SubscribeEventsRequest| { &m.since_id },
+            |m: &mut SubscribeEventsRequest| { &mut m.since_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SubscribeEventsRequest>(
+            "SubscribeEventsRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SubscribeEventsRequest {
+    const NAME: &'static str = "SubscribeEventsRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.types.push(is.read_enum_or_unknown()?);
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_packed_enum_or_unknown_into(is, &mut self.types)?
+                },
+                18 => {
+                    self.device_name = is.read_string()?;
+                },
+                24 => {
+                    self.since_id = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.types {
+            my_size += ::protobuf::rt::int32_size(1, value.value());
+        };
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.device_name);
+        }
+        if self.since_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.since_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.types {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(v))?;
+        };
+        if !self.device_name.is_empty() {
+            os.write_string(2, &self.device_name)?;
+        }
+        if self.since_id != 0 {
+            os.write_uint64(3, self.since_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SubscribeEventsRequest {
+        SubscribeEventsRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.types.clear();
+        self.device_name.clear();
+        self.since_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SubscribeEventsRequest {
+        static instance: SubscribeEventsRequest = SubscribeEventsRequest {
+            types: ::std::vec::Vec::new(),
+            device_name: ::std::string::String::new(),
+            since_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SubscribeEventsRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SubscribeEventsRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SubscribeEventsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SubscribeEventsRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ListEventsResponse)
+pub struct ListEventsResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ListEventsResponse.events)
+    pub events: ::std::vec::Vec<Event>,
+    // @@protoc_insertion_point(field:netsim.frontend.ListEventsResponse.truncated)
+    pub truncated: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.ListEventsResponse.last_id)
+    pub last_id: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ListEventsResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListEventsResponse {
+    fn default() -> &'a ListEventsResponse {
+        <ListEventsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListEventsResponse {
+    pub fn new() -> ListEventsResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &ListEventsResponse| { &m.events },
+            |m: &mut ListEventsResponse| { &mut m.events },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "truncated",
+            |m: &ListEventsResponse| { &m.truncated },
+            |m: &mut ListEventsResponse| { &mut m.truncated },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_id",
+            |m: &ListEventsResponse| { &m.last_id },
+            |m: &mut ListEventsResponse| { &mut m.last_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListEventsResponse>(
+            "ListEventsResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListEventsResponse {
+    const NAME: &'static str = "ListEventsResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.events.push(is.read_message()?);
+                },
+                16 => {
+                    self.truncated = is.read_bool()?;
+                },
+                24 => {
+                    self.last_id = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.truncated != false {
+            my_size += 1 + 1;
+        }
+        if self.last_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.last_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.truncated != false {
+            os.write_bool(2, self.truncated)?;
+        }
+        if self.last_id != 0 {
+            os.write_uint64(3, self.last_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListEventsResponse {
+        ListEventsResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.events.clear();
+        self.truncated = false;
+        self.last_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListEventsResponse {
+        static instance: ListEventsResponse = ListEventsResponse {
+            events: ::std::vec::Vec::new(),
+            truncated: false,
+            last_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListEventsResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListEventsResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListEventsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListEventsResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ErrorResponse)
+pub struct ErrorResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ErrorResponse.code)
+    pub code: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.ErrorResponse.error_message)
+    pub error_message: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ErrorResponse.status)
+    pub status: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ErrorResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ErrorResponse {
+    fn default() -> &'a ErrorResponse {
+        <ErrorResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ErrorResponse {
+    pub fn new() -> ErrorResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &ErrorResponse| { &m.code },
+            |m: &mut ErrorResponse| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error_message",
+            |m: &ErrorResponse| { &m.error_message },
+            |m: &mut ErrorResponse| { &mut m.error_message },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &ErrorResponse| { &m.status },
+            |m: &mut ErrorResponse| { &mut m.status },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ErrorResponse>(
+            "ErrorResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ErrorResponse {
+    const NAME: &'static str = "ErrorResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.code = is.read_int32()?;
+                },
+                18 => {
+                    self.error_message = is.read_string()?;
+                },
+                26 => {
+                    self.status = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.code);
+        }
+        if !self.error_message.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error_message);
+        }
+        if !self.status.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.status);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != 0 {
+            os.write_int32(1, self.code)?;
+        }
+        if !self.error_message.is_empty() {
+            os.write_string(2, &self.error_message)?;
+        }
+        if !self.status.is_empty() {
+            os.write_string(3, &self.status)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ErrorResponse {
+        ErrorResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = 0;
+        self.error_message.clear();
+        self.status.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ErrorResponse {
+        static instance: ErrorResponse = ErrorResponse {
+            code: 0,
+            error_message: ::std::string::String::new(),
+            status: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ErrorResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ErrorResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ErrorResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ErrorResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.CreateBugReportResponse)
+pub struct CreateBugReportResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.CreateBugReportResponse.path)
+    pub path: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.CreateBugReportResponse.files)
+    pub files: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.CreateBugReportResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CreateBugReportResponse {
+    fn default() -> &'a CreateBugReportResponse {
+        <CreateBugReportResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateBugReportResponse {
+    pub fn new() -> CreateBugReportResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "path",
+            |m: &CreateBugReportResponse| { &m.path },
+            |m: &mut CreateBugReportResponse| { &mut m.path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "files",
+            |m: &CreateBugReportResponse| { &m.files },
+            |m: &mut CreateBugReportResponse| { &mut m.files },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CreateBugReportResponse>(
+            "CreateBugReportResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CreateBugReportResponse {
+    const NAME: &'static str = "CreateBugReportResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.path = is.read_string()?;
+                },
+                18 => {
+                    self.files.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        for value in &self.files {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        for v in &self.files {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CreateBugReportResponse {
+        CreateBugReportResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.path.clear();
+        self.files.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CreateBugReportResponse {
+        static instance: CreateBugReportResponse = CreateBugReportResponse {
+            path: ::std::string::String::new(),
+            files: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CreateBugReportResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CreateBugReportResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CreateBugReportResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateBugReportResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ChipKindInfo)
+pub struct ChipKindInfo {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ChipKindInfo.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipKindInfo.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipKindInfo.linktype)
+    pub linktype: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipKindInfo.routing)
+    pub routing: ::protobuf::EnumOrUnknown<chip_kind_info::Routing>,
+    // @@protoc_insertion_point(field:netsim.frontend.ChipKindInfo.builtin)
+    pub builtin: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ChipKindInfo.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ChipKindInfo {
+    fn default() -> &'a ChipKindInfo {
+        <ChipKindInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChipKindInfo {
+    pub fn new() -> ChipKindInfo {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &ChipKindInfo| { &m.id },
+            |m: &mut ChipKindInfo| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &ChipKindInfo| { &m.name },
+            |m: &mut ChipKindInfo| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "linktype",
+            |m: &ChipKindInfo| { &m.linktype },
+            |m: &mut ChipKindInfo| { &mut m.linktype },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "routing",
+            |m: &ChipKindInfo| { &m.routing },
+            |m: &mut ChipKindInfo| { &mut m.routing },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "builtin",
+            |m: &ChipKindInfo| { &m.builtin },
+            |m: &mut ChipKindInfo| { &mut m.builtin },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ChipKindInfo>(
+            "ChipKindInfo",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ChipKindInfo {
+    const NAME: &'static str = "ChipKindInfo";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                18 => {
+                    self.name = is.read_string()?;
+                },
+                24 => {
+                    self.linktype = is.read_uint32()?;
+                },
+                32 => {
+                    self.routing = is.read_enum_or_unknown()?;
+                },
+                40 => {
+                    self.builtin = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.linktype != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.linktype);
+        }
+        if self.routing != ::protobuf::EnumOrUnknown::new(chip_kind_info::Routing::BROADCAST) {
+            my_size += ::protobuf::rt::int32_size(4, self.routing.value());
+        }
+        if self.builtin != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.linktype != 0 {
+            os.write_uint32(3, self.linktype)?;
+        }
+        if self.routing != ::protobuf::EnumOrUnknown::new(chip_kind_info::Routing::BROADCAST) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.routing))?;
+        }
+        if self.builtin != false {
+            os.write_bool(5, self.builtin)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ChipKindInfo {
+        ChipKindInfo::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.name.clear();
+        self.linktype = 0;
+        self.routing = ::protobuf::EnumOrUnknown::new(chip_kind_info::Routing::BROADCAST);
+        self.builtin = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ChipKindInfo {
+        static instance: ChipKindInfo = ChipKindInfo {
+            id: 0,
+            name: ::std::string::String::new(),
+            linktype: 0,
+            routing: ::protobuf::EnumOrUnknown::from_i32(0),
+            builtin: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ChipKindInfo {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ChipKindInfo").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ChipKindInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChipKindInfo {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ChipKindInfo`
+pub mod chip_kind_info {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.ChipKindInfo.Routing)
+    pub enum Routing {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ChipKindInfo.Routing.BROADCAST)
+        BROADCAST = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.ChipKindInfo.Routing.POINT_TO_POINT)
+        POINT_TO_POINT = 1,
+    }
+
+    impl ::protobuf::Enum for Routing {
+        const NAME: &'static str = "Routing";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Routing> {
+            match value {
+                0 => ::std::option::Option::Some(Routing::BROADCAST),
+                1 => ::std::option::Option::Some(Routing::POINT_TO_POINT),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Routing] = &[
+            Routing::BROADCAST,
+            Routing::POINT_TO_POINT,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Routing {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("ChipKindInfo.Routing").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Routing {
+        fn default() -> Self {
+            Routing::BROADCAST
+        }
+    }
+
+    impl Routing {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Routing>("ChipKindInfo.Routing")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ListChipKindsResponse)
+pub struct ListChipKindsResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ListChipKindsResponse.kinds)
+    pub kinds: ::std::vec::Vec<ChipKindInfo>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ListChipKindsResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListChipKindsResponse {
+    fn default() -> &'a ListChipKindsResponse {
+        <ListChipKindsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListChipKindsResponse {
+    pub fn new() -> ListChipKindsResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "kinds",
+            |m: &ListChipKindsResponse| { &m.kinds },
+            |m: &mut ListChipKindsResponse| { &mut m.kinds },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListChipKindsResponse>(
+            "ListChipKindsResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListChipKindsResponse {
+    const NAME: &'static str = "ListChipKindsResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.kinds.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.kinds {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.kinds {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListChipKindsResponse {
+        ListChipKindsResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.kinds.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListChipKindsResponse {
+        static instance: ListChipKindsResponse = ListChipKindsResponse {
+            kinds: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListChipKindsResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListChipKindsResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListChipKindsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListChipKindsResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.LinkInstanceRequest)
+pub struct LinkInstanceRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.LinkInstanceRequest.address)
+    pub address: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.LinkInstanceRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LinkInstanceRequest {
+    fn default() -> &'a LinkInstanceRequest {
+        <LinkInstanceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LinkInstanceRequest {
+    pub fn new() -> LinkInstanceRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "address",
+            |m: &LinkInstanceRequest| { &m.address },
+            |m: &mut LinkInstanceRequest| { &mut m.address },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LinkInstanceRequest>(
+            "LinkInstanceRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LinkInstanceRequest {
+    const NAME: &'static str = "LinkInstanceRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.address = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.address);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.address.is_empty() {
+            os.write_string(1, &self.address)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LinkInstanceRequest {
+        LinkInstanceRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.address.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LinkInstanceRequest {
+        static instance: LinkInstanceRequest = LinkInstanceRequest {
+            address: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LinkInstanceRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LinkInstanceRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LinkInstanceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LinkInstanceRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.UnlinkInstanceRequest)
+pub struct UnlinkInstanceRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.UnlinkInstanceRequest.id)
+    pub id: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.UnlinkInstanceRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a UnlinkInstanceRequest {
+    fn default() -> &'a UnlinkInstanceRequest {
+        <UnlinkInstanceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UnlinkInstanceRequest {
+    pub fn new() -> UnlinkInstanceRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &UnlinkInstanceRequest| { &m.id },
+            |m: &mut UnlinkInstanceRequest| { &mut m.id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UnlinkInstanceRequest>(
+            "UnlinkInstanceRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for UnlinkInstanceRequest {
+    const NAME: &'static str = "UnlinkInstanceRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> UnlinkInstanceRequest {
+        UnlinkInstanceRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static UnlinkInstanceRequest {
+        static instance: UnlinkInstanceRequest = UnlinkInstanceRequest {
+            id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for UnlinkInstanceRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UnlinkInstanceRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for UnlinkInstanceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UnlinkInstanceRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.Trunk)
+pub struct Trunk {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.Trunk.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.Trunk.address)
+    pub address: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.Trunk.peer)
+    pub peer: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.Trunk.role)
+    pub role: ::protobuf::EnumOrUnknown<trunk::Role>,
+    // @@protoc_insertion_point(field:netsim.frontend.Trunk.chips)
+    pub chips: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.Trunk.packets_sent)
+    pub packets_sent: u64,
+    // @@protoc_insertion_point(field:netsim.frontend.Trunk.packets_received)
+    pub packets_received: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.Trunk.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Trunk {
+    fn default() -> &'a Trunk {
+        <Trunk as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Trunk {
+    pub fn new() -> Trunk {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &Trunk| { &m.id },
+            |m: &mut Trunk| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "address",
+            |m: &Trunk| { &m.address },
+            |m: &mut Trunk| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "peer",
+            |m: &Trunk| { &m.peer },
+            |m: &mut Trunk| { &mut m.peer },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "role",
+            |m: &Trunk| { &m.role },
+            |m: &mut Trunk| { &mut m.role },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chips",
+            |m: &Trunk| { &m.chips },
+            |m: &mut Trunk| { &mut m.chips },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "packets_sent",
+            |m: &Trunk| { &m.packets_sent },
+            |m: &mut Trunk| { &mut m.packets_sent },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "packets_received",
+            |m: &Trunk| { &m.packets_received },
+            |m: &mut Trunk| { &mut m.packets_received },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Trunk>(
+            "Trunk",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Trunk {
+    const NAME: &'static str = "Trunk";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                18 => {
+                    self.address = is.read_string()?;
+                },
+                26 => {
+                    self.peer = is.read_string()?;
+                },
+                32 => {
+                    self.role = is.read_enum_or_unknown()?;
+                },
+                40 => {
+                    self.chips = is.read_uint32()?;
+                },
+                48 => {
+                    self.packets_sent = is.read_uint64()?;
+                },
+                56 => {
+                    self.packets_received = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.address);
+        }
+        if !self.peer.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.peer);
+        }
+        if self.role != ::protobuf::EnumOrUnknown::new(trunk::Role::LEAF) {
+            my_size += ::protobuf::rt::int32_size(4, self.role.value());
+        }
+        if self.chips != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.chips);
+        }
+        if self.packets_sent != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.packets_sent);
+        }
+        if self.packets_received != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.packets_received);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        if !self.address.is_empty() {
+            os.write_string(2, &self.address)?;
+        }
+        if !self.peer.is_empty() {
+            os.write_string(3, &self.peer)?;
+        }
+        if self.role != ::protobuf::EnumOrUnknown::new(trunk::Role::LEAF) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.role))?;
+        }
+        if self.chips != 0 {
+            os.write_uint32(5, self.chips)?;
+        }
+        if self.packets_sent != 0 {
+            os.write_uint64(6, self.packets_sent)?;
+        }
+        if self.packets_received != 0 {
+            os.write_uint64(7, self.packets_received)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Trunk {
+        Trunk::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.address.clear();
+        self.peer.clear();
+        self.role = ::protobuf::EnumOrUnknown::new(trunk::Role::LEAF);
+        self.chips = 0;
+        self.packets_sent = 0;
+        self.packets_received = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Trunk {
+        static instance: Trunk = Trunk {
+            id: 0,
+            address: ::std::string::String::new(),
+            peer: ::std::string::String::new(),
+            role: ::protobuf::EnumOrUnknown::from_i32(0),
+            chips: 0,
+            packets_sent: 0,
+            packets_received: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Trunk {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Trunk").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Trunk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Trunk {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Trunk`
+pub mod trunk {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.Trunk.Role)
+    pub enum Role {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Trunk.Role.LEAF)
+        LEAF = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Trunk.Role.HUB)
+        HUB = 1,
+    }
+
+    impl ::protobuf::Enum for Role {
+        const NAME: &'static str = "Role";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Role> {
+            match value {
+                0 => ::std::option::Option::Some(Role::LEAF),
+                1 => ::std::option::Option::Some(Role::HUB),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Role] = &[
+            Role::LEAF,
+            Role::HUB,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Role {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Trunk.Role").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Role {
+        fn default() -> Self {
+            Role::LEAF
+        }
+    }
+
+    impl Role {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Role>("Trunk.Role")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ListTrunksResponse)
+pub struct ListTrunksResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ListTrunksResponse.trunks)
+    pub trunks: ::std::vec::Vec<Trunk>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ListTrunksResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListTrunksResponse {
+    fn default() -> &'a ListTrunksResponse {
+        <ListTrunksResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListTrunksResponse {
+    pub fn new() -> ListTrunksResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "trunks",
+            |m: &ListTrunksResponse| { &m.trunks },
+            |m: &mut ListTrunksResponse| { &mut m.trunks },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListTrunksResponse>(
+            "ListTrunksResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListTrunksResponse {
+    const NAME: &'static str = "ListTrunksResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.trunks.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.trunks {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.trunks {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListTrunksResponse {
+        ListTrunksResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.trunks.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListTrunksResponse {
+        static instance: ListTrunksResponse = ListTrunksResponse {
+            trunks: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListTrunksResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListTrunksResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListTrunksResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListTrunksResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.TrunkMessage)
+pub struct TrunkMessage {
+    // message oneof groups
+    pub message: ::std::option::Option<trunk_message::Message>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.TrunkMessage.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TrunkMessage {
+    fn default() -> &'a TrunkMessage {
+        <TrunkMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TrunkMessage {
+    pub fn new() -> TrunkMessage {
+        ::std::default::Default::default()
+    }
+
+    // .netsim.frontend.TrunkMessage.Hello hello = 1;
+
+    pub fn hello(&self) -> &trunk_message::Hello {
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::Hello(ref v)) => v,
+            _ => <trunk_message::Hello as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_hello(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_hello(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::Hello(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hello(&mut self, v: trunk_message::Hello) {
+        self.message = ::std::option::Option::Some(trunk_message::Message::Hello(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_hello(&mut self) -> &mut trunk_message::Hello {
+        if let ::std::option::Option::Some(trunk_message::Message::Hello(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(trunk_message::Message::Hello(trunk_message::Hello::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::Hello(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_hello(&mut self) -> trunk_message::Hello {
+        if self.has_hello() {
+            match self.message.take() {
+                ::std::option::Option::Some(trunk_message::Message::Hello(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            trunk_message::Hello::new()
+        }
+    }
+
+    // .netsim.frontend.TrunkMessage.AddChip add_chip = 2;
+
+    pub fn add_chip(&self) -> &trunk_message::AddChip {
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::AddChip(ref v)) => v,
+            _ => <trunk_message::AddChip as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_add_chip(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_add_chip(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::AddChip(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_add_chip(&mut self, v: trunk_message::AddChip) {
+        self.message = ::std::option::Option::Some(trunk_message::Message::AddChip(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_add_chip(&mut self) -> &mut trunk_message::AddChip {
+        if let ::std::option::Option::Some(trunk_message::Message::AddChip(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(trunk_message::Message::AddChip(trunk_message::AddChip::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::AddChip(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_add_chip(&mut self) -> trunk_message::AddChip {
+        if self.has_add_chip() {
+            match self.message.take() {
+                ::std::option::Option::Some(trunk_message::Message::AddChip(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            trunk_message::AddChip::new()
+        }
+    }
+
+    // uint32 remove_chip = 3;
+
+    pub fn remove_chip(&self) -> u32 {
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::RemoveChip(v)) => v,
+            _ => 0,
+        }
+    }
+
+    pub fn clear_remove_chip(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_remove_chip(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::RemoveChip(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remove_chip(&mut self, v: u32) {
+        self.message = ::std::option::Option::Some(trunk_message::Message::RemoveChip(v))
+    }
+
+    // .netsim.frontend.TrunkMessage.Packet packet = 4;
+
+    pub fn packet(&self) -> &trunk_message::Packet {
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::Packet(ref v)) => v,
+            _ => <trunk_message::Packet as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_packet(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_packet(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::Packet(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_packet(&mut self, v: trunk_message::Packet) {
+        self.message = ::std::option::Option::Some(trunk_message::Message::Packet(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_packet(&mut self) -> &mut trunk_message::Packet {
+        if let ::std::option::Option::Some(trunk_message::Message::Packet(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(trunk_message::Message::Packet(trunk_message::Packet::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::Packet(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_packet(&mut self) -> trunk_message::Packet {
+        if self.has_packet() {
+            match self.message.take() {
+                ::std::option::Option::Some(trunk_message::Message::Packet(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            trunk_message::Packet::new()
+        }
+    }
+
+    // .netsim.model.Device move_device = 5;
+
+    pub fn move_device(&self) -> &super::model::Device {
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::MoveDevice(ref v)) => v,
+            _ => <super::model::Device as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_move_device(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_move_device(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::MoveDevice(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_move_device(&mut self, v: super::model::Device) {
+        self.message = ::std::option::Option::Some(trunk_message::Message::MoveDevice(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_move_device(&mut self) -> &mut super::model::Device {
+        if let ::std::option::Option::Some(trunk_message::Message::MoveDevice(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(trunk_message::Message::MoveDevice(super::model::Device::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(trunk_message::Message::MoveDevice(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_move_device(&mut self) -> super::model::Device {
+        if self.has_move_device() {
+            match self.message.take() {
+                ::std::option::Option::Some(trunk_message::Message::MoveDevice(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::model::Device::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, trunk_message::Hello>(
+            "hello",
+            TrunkMessage::has_hello,
+            TrunkMessage::hello,
+            TrunkMessage::mut_hello,
+            TrunkMessage::set_hello,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, trunk_message::AddChip>(
+            "add_chip",
+            TrunkMessage::has_add_chip,
+            TrunkMessage::add_chip,
+            TrunkMessage::mut_add_chip,
+            TrunkMessage::set_add_chip,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "remove_chip",
+            TrunkMessage::has_remove_chip,
+            TrunkMessage::remove_chip,
+            TrunkMessage::set_remove_chip,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, trunk_message::Packet>(
+            "packet",
+            TrunkMessage::has_packet,
+            TrunkMessage::packet,
+            TrunkMessage::mut_packet,
+            TrunkMessage::set_packet,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, super::model::Device>(
+            "move_device",
+            TrunkMessage::has_move_device,
+            TrunkMessage::move_device,
+            TrunkMessage::mut_move_device,
+            TrunkMessage::set_move_device,
+        ));
+        oneofs.push(trunk_message::Message::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TrunkMessage>(
+            "TrunkMessage",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TrunkMessage {
+    const NAME: &'static str = "TrunkMessage";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.message = ::std::option::Option::Some(trunk_message::Message::Hello(is.read_message()?));
+                },
+                18 => {
+                    self.message = ::std::option::Option::Some(trunk_message::Message::AddChip(is.read_message()?));
+                },
+                24 => {
+                    self.message = ::std::option::Option::Some(trunk_message::Message::RemoveChip(is.read_uint32()?));
+                },
+                34 => {
+                    self.message = ::std::option::Option::Some(trunk_message::Message::Packet(is.read_message()?));
+                },
+                42 => {
+                    self.message = ::std::option::Option::Some(trunk_message::Message::MoveDevice(is.read_message()?));
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.message {
+            match v {
+                &trunk_message::Message::Hello(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &trunk_message::Message::AddChip(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &trunk_message::Message::RemoveChip(v) => {
+                    my_size += ::protobuf::rt::uint32_size(3, v);
+                },
+                &trunk_message::Message::Packet(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &trunk_message::Message::MoveDevice(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let ::std::option::Option::Some(ref v) = self.message {
+            match v {
+                &trunk_message::Message::Hello(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &trunk_message::Message::AddChip(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &trunk_message::Message::RemoveChip(v) => {
+                    os.write_uint32(3, v)?;
+                },
+                &trunk_message::Message::Packet(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &trunk_message::Message::MoveDevice(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TrunkMessage {
+        TrunkMessage::new()
+    }
+
+    fn clear(&mut self) {
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TrunkMessage {
+        static instance: TrunkMessage = TrunkMessage {
+            message: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TrunkMessage {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TrunkMessage").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TrunkMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TrunkMessage {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `TrunkMessage`
+pub mod trunk_message {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:netsim.frontend.TrunkMessage.message)
+    pub enum Message {
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.TrunkMessage.hello)
+        Hello(Hello),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.TrunkMessage.add_chip)
+        AddChip(AddChip),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.TrunkMessage.remove_chip)
+        RemoveChip(u32),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.TrunkMessage.packet)
+        Packet(Packet),
+        // @@protoc_insertion_point(oneof_field:netsim.frontend.TrunkMessage.move_device)
+        MoveDevice(super::super::model::Device),
+    }
+
+    impl ::protobuf::Oneof for Message {
+    }
+
+    impl ::protobuf::OneofFull for Message {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::TrunkMessage as ::protobuf::MessageFull>::descriptor().oneof_by_name("message").unwrap()).clone()
+        }
+    }
+
+    impl Message {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Message>("message")
+        }
+    }
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.TrunkMessage.Hello)
+    pub struct Hello {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.Hello.name)
+        pub name: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.Hello.version)
+        pub version: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.Hello.nonce)
+        pub nonce: ::std::vec::Vec<u8>,
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.Hello.proof)
+        pub proof: ::std::vec::Vec<u8>,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.TrunkMessage.Hello.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Hello {
+        fn default() -> &'a Hello {
+            <Hello as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Hello {
+        pub fn new() -> Hello {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(4);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "name",
+                |m: &Hello| { &m.name },
+                |m: &mut Hello| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "version",
+                |m: &Hello| { &m.version },
+                |m: &mut Hello| { &mut m.version },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "nonce",
+                |m: &Hello| { &m.nonce },
+                |m: &mut Hello| { &mut m.nonce },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "proof",
+                |m: &Hello| { &m.proof },
+                |m: &mut Hello| { &mut m.proof },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Hello>(
+                "TrunkMessage.Hello",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Hello {
+        const NAME: &'static str = "Hello";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.name = is.read_string()?;
+                    },
+                    18 => {
+                        self.version = is.read_string()?;
+                    },
+                    34 => {
+                        self.nonce = is.read_bytes()?;
+                    },
+                    42 => {
+                        self.proof = is.read_bytes()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.name.is_empty() {
+                my_size += ::protobuf::rt::string_size(1, &self.name);
+            }
+            if !self.version.is_empty() {
+                my_size += ::protobuf::rt::string_size(2, &self.version);
+            }
+            if !self.nonce.is_empty() {
+                my_size += ::protobuf::rt::bytes_size(4, &self.nonce);
+            }
+            if !self.proof.is_empty() {
+                my_size += ::protobuf::rt::bytes_size(5, &self.proof);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if !self.name.is_empty() {
+                os.write_string(1, &self.name)?;
+            }
+            if !self.version.is_empty() {
+                os.write_string(2, &self.version)?;
+            }
+            if !self.nonce.is_empty() {
+                os.write_bytes(4, &self.nonce)?;
+            }
+            if !self.proof.is_empty() {
+                os.write_bytes(5, &self.proof)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Hello {
+            Hello::new()
+        }
+
+        fn clear(&mut self) {
+            self.name.clear();
+            self.version.clear();
+            self.nonce.clear();
+            self.proof.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Hello {
+            static instance: Hello = Hello {
+                name: ::std::string::String::new(),
+                version: ::std::string::String::new(),
+                nonce: ::std::vec::Vec::new(),
+                proof: ::std::vec::Vec::new(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Hello {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("TrunkMessage.Hello").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Hello {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Hello {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.TrunkMessage.AddChip)
+    pub struct AddChip {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.AddChip.chip_id)
+        pub chip_id: u32,
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.AddChip.device_name)
+        pub device_name: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.AddChip.kind)
+        pub kind: ::protobuf::EnumOrUnknown<super::super::common::ChipKind>,
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.AddChip.chip_name)
+        pub chip_name: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.AddChip.manufacturer)
+        pub manufacturer: ::std::string::String,
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.AddChip.product_name)
+        pub product_name: ::std::string::String,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.TrunkMessage.AddChip.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a AddChip {
+        fn default() -> &'a AddChip {
+            <AddChip as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl AddChip {
+        pub fn new() -> AddChip {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(6);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "chip_id",
+                |m: &AddChip| { &m.chip_id },
+                |m: &mut AddChip| { &mut m.chip_id },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "device_name",
+                |m: &AddChip| { &m.device_name },
+                |m: &mut AddChip| { &mut m.device_name },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "kind",
+                |m: &AddChip| { &m.kind },
+                |m: &mut AddChip| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "chip_name",
+                |m: &AddChip| { &m.chip_name },
+                |m: &mut AddChip| { &mut m.chip_name },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "manufacturer",
+                |m: &AddChip| { &m.manufacturer },
+                |m: &mut AddChip| { &mut m.manufacturer },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "product_name",
+                |m: &AddChip| { &m.product_name },
+                |m: &mut AddChip| { &mut m.product_name },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AddChip>(
+                "TrunkMessage.AddChip",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for AddChip {
+        const NAME: &'static str = "AddChip";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.chip_id = is.read_uint32()?;
+                    },
+                    18 => {
+                        self.device_name = is.read_string()?;
+                    },
+                    24 => {
+                        self.kind = is.read_enum_or_unknown()?;
+                    },
+                    34 => {
+                        self.chip_name = is.read_string()?;
+                    },
+                    42 => {
+                        self.manufacturer = is.read_string()?;
+                    },
+                    50 => {
+                        self.product_name = is.read_string()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.chip_id != 0 {
+                my_size += ::protobuf::rt::uint32_size(1, self.chip_id);
+            }
+            if !self.device_name.is_empty() {
+                my_size += ::protobuf::rt::string_size(2, &self.device_name);
+            }
+            if self.kind != ::protobuf::EnumOrUnknown::new(super::super::common::ChipKind::UNSPECIFIED) {
+                my_size += ::protobuf::rt::int32_size(3, self.kind.value());
+            }
+            if !self.chip_name.is_empty() {
+                my_size += ::protobuf::rt::string_size(4, &self.chip_name);
+            }
+            if !self.manufacturer.is_empty() {
+                my_size += ::protobuf::rt::string_size(5, &self.manufacturer);
+            }
+            if !self.product_name.is_empty() {
+                my_size += ::protobuf::rt::string_size(6, &self.product_name);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.chip_id != 0 {
+                os.write_uint32(1, self.chip_id)?;
+            }
+            if !self.device_name.is_empty() {
+                os.write_string(2, &self.device_name)?;
+            }
+            if self.kind != ::protobuf::EnumOrUnknown::new(super::super::common::ChipKind::UNSPECIFIED) {
+                os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.kind))?;
+            }
+            if !self.chip_name.is_empty() {
+                os.write_string(4, &self.chip_name)?;
+            }
+            if !self.manufacturer.is_empty() {
+                os.write_string(5, &self.manufacturer)?;
+            }
+            if !self.product_name.is_empty() {
+                os.write_string(6, &self.product_name)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> AddChip {
+            AddChip::new()
+        }
+
+        fn clear(&mut self) {
+            self.chip_id = 0;
+            self.device_name.clear();
+            self.kind = ::protobuf::EnumOrUnknown::new(super::super::common::ChipKind::UNSPECIFIED);
+            self.chip_name.clear();
+            self.manufacturer.clear();
+            self.product_name.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static AddChip {
+            static instance: AddChip = AddChip {
+                chip_id: 0,
+                device_name: ::std::string::String::new(),
+                kind: ::protobuf::EnumOrUnknown::from_i32(0),
+                chip_name: ::std::string::String::new(),
+                manufacturer: ::std::string::String::new(),
+                product_name: ::std::string::String::new(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for AddChip {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("TrunkMessage.AddChip").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for AddChip {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for AddChip {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.TrunkMessage.Packet)
+    pub struct Packet {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.Packet.chip_id)
+        pub chip_id: u32,
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.Packet.packet_type)
+        pub packet_type: u32,
+        // @@protoc_insertion_point(field:netsim.frontend.TrunkMessage.Packet.packet)
+        pub packet: ::std::vec::Vec<u8>,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.TrunkMessage.Packet.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Packet {
+        fn default() -> &'a Packet {
+            <Packet as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Packet {
+        pub fn new() -> Packet {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(3);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "chip_id",
+                |m: &Packet| { &m.chip_id },
+                |m: &mut Packet| { &mut m.chip_id },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "packet_type",
+                |m: &Packet| { &m.packet_type },
+                |m: &mut Packet| { &mut m.packet_type },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "packet",
+                |m: &Packet| { &m.packet },
+                |m: &mut Packet| { &mut m.packet },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Packet>(
+                "TrunkMessage.Packet",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Packet {
+        const NAME: &'static str = "Packet";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.chip_id = is.read_uint32()?;
+                    },
+                    16 => {
+                        self.packet_type = is.read_uint32()?;
+                    },
+                    26 => {
+                        self.packet = is.read_bytes()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.chip_id != 0 {
+                my_size += ::protobuf::rt::uint32_size(1, self.chip_id);
+            }
+            if self.packet_type != 0 {
+                my_size += ::protobuf::rt::uint32_size(2, self.packet_type);
+            }
+            if !self.packet.is_empty() {
+                my_size += ::protobuf::rt::bytes_size(3, &self.packet);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.chip_id != 0 {
+                os.write_uint32(1, self.chip_id)?;
+            }
+            if self.packet_type != 0 {
+                os.write_uint32(2, self.packet_type)?;
+            }
+            if !self.packet.is_empty() {
+                os.write_bytes(3, &self.packet)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Packet {
+            Packet::new()
+        }
+
+        fn clear(&mut self) {
+            self.chip_id = 0;
+            self.packet_type = 0;
+            self.packet.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Packet {
+            static instance: Packet = Packet {
+                chip_id: 0,
+                packet_type: 0,
+                packet: ::std::vec::Vec::new(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Packet {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("TrunkMessage.Packet").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Packet {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Packet {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.Breakpoint)
+pub struct Breakpoint {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.Breakpoint.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.Breakpoint.chip_id)
+    pub chip_id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.Breakpoint.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.frontend.Breakpoint.direction)
+    pub direction: ::protobuf::EnumOrUnknown<breakpoint::Direction>,
+    // @@protoc_insertion_point(field:netsim.frontend.Breakpoint.pattern)
+    pub pattern: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:netsim.frontend.Breakpoint.mask)
+    pub mask: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:netsim.frontend.Breakpoint.offset)
+    pub offset: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.Breakpoint.scope)
+    pub scope: ::protobuf::EnumOrUnknown<breakpoint::Scope>,
+    // @@protoc_insertion_point(field:netsim.frontend.Breakpoint.hits)
+    pub hits: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.Breakpoint.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Breakpoint {
+    fn default() -> &'a Breakpoint {
+        <Breakpoint as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Breakpoint {
+    pub fn new() -> Breakpoint {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &Breakpoint| { &m.id },
+            |m: &mut Breakpoint| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_id",
+            |m: &Breakpoint| { &m.chip_id },
+            |m: &mut Breakpoint| { &mut m.chip_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &Breakpoint| { &m.chip_kind },
+            |m: &mut Breakpoint| { &mut m.chip_kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "direction",
+            |m: &Breakpoint| { &m.direction },
+            |m: &mut Breakpoint| { &mut m.direction },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "pattern",
+            |m: &Breakpoint| { &m.pattern },
+            |m: &mut Breakpoint| { &mut m.pattern },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mask",
+            |m: &Breakpoint| { &m.mask },
+            |m: &mut Breakpoint| { &mut m.mask },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &Breakpoint| { &m.offset },
+            |m: &mut Breakpoint| { &mut m.offset },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "scope",
+            |m: &Breakpoint| { &m.scope },
+            |m: &mut Breakpoint| { &mut m.scope },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hits",
+            |m: &Breakpoint| { &m.hits },
+            |m: &mut Breakpoint| { &mut m.hits },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Breakpoint>(
+            "Breakpoint",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Breakpoint {
+    const NAME: &'static str = "Breakpoint";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                16 => {
+                    self.chip_id = is.read_int32()?;
+                },
+                24 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                32 => {
+                    self.direction = is.read_enum_or_unknown()?;
+                },
+                42 => {
+                    self.pattern = is.read_bytes()?;
+                },
+                50 => {
+                    self.mask = is.read_bytes()?;
+                },
+                56 => {
+                    self.offset = is.read_uint32()?;
+                },
+                64 => {
+                    self.scope = is.read_enum_or_unknown()?;
+                },
+                72 => {
+                    self.hits = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        if self.chip_id != 0 {
+            my_size += ::protobuf::rt::int32_size(2, self.chip_id);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(3, self.chip_kind.value());
+        }
+        if self.direction != ::protobuf::EnumOrUnknown::new(breakpoint::Direction::ANY) {
+            my_size += ::protobuf::rt::int32_size(4, self.direction.value());
+        }
+        if !self.pattern.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.pattern);
+        }
+        if !self.mask.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.mask);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::uint32_size(7, self.offset);
+        }
+        if self.scope != ::protobuf::EnumOrUnknown::new(breakpoint::Scope::CHIP) {
+            my_size += ::protobuf::rt::int32_size(8, self.scope.value());
+        }
+        if self.hits != 0 {
+            my_size += ::protobuf::rt::uint32_size(9, self.hits);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        if self.chip_id != 0 {
+            os.write_int32(2, self.chip_id)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        if self.direction != ::protobuf::EnumOrUnknown::new(breakpoint::Direction::ANY) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.direction))?;
+        }
+        if !self.pattern.is_empty() {
+            os.write_bytes(5, &self.pattern)?;
+        }
+        if !self.mask.is_empty() {
+            os.write_bytes(6, &self.mask)?;
+        }
+        if self.offset != 0 {
+            os.write_uint32(7, self.offset)?;
+        }
+        if self.scope != ::protobuf::EnumOrUnknown::new(breakpoint::Scope::CHIP) {
+            os.write_enum(8, ::protobuf::EnumOrUnknown::value(&self.scope))?;
+        }
+        if self.hits != 0 {
+            os.write_uint32(9, self.hits)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Breakpoint {
+        Breakpoint::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.chip_id = 0;
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.direction = ::protobuf::EnumOrUnknown::new(breakpoint::Direction::ANY);
+        self.pattern.clear();
+        self.mask.clear();
+        self.offset = 0;
+        self.scope = ::protobuf::EnumOrUnknown::new(breakpoint::Scope::CHIP);
+        self.hits = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Breakpoint {
+        static instance: Breakpoint = Breakpoint {
+            id: 0,
+            chip_id: 0,
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            direction: ::protobuf::EnumOrUnknown::from_i32(0),
+            pattern: ::std::vec::Vec::new(),
+            mask: ::std::vec::Vec::new(),
+            offset: 0,
+            scope: ::protobuf::EnumOrUnknown::from_i32(0),
+            hits: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Breakpoint {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Breakpoint").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Breakpoint {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Breakpoint`
+pub mod breakpoint {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.Breakpoint.Direction)
+    pub enum Direction {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Breakpoint.Direction.ANY)
+        ANY = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Breakpoint.Direction.HOST_TO_CONTROLLER)
+        HOST_TO_CONTROLLER = 1,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Breakpoint.Direction.CONTROLLER_TO_HOST)
+        CONTROLLER_TO_HOST = 2,
+    }
+
+    impl ::protobuf::Enum for Direction {
+        const NAME: &'static str = "Direction";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Direction> {
+            match value {
+                0 => ::std::option::Option::Some(Direction::ANY),
+                1 => ::std::option::Option::Some(Direction::HOST_TO_CONTROLLER),
+                2 => ::std::option::Option::Some(Direction::CONTROLLER_TO_HOST),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Direction] = &[
+            Direction::ANY,
+            Direction::HOST_TO_CONTROLLER,
+            Direction::CONTROLLER_TO_HOST,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Direction {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Breakpoint.Direction").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Direction {
+        fn default() -> Self {
+            Direction::ANY
+        }
+    }
+
+    impl Direction {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Direction>("Breakpoint.Direction")
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.frontend.Breakpoint.Scope)
+    pub enum Scope {
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Breakpoint.Scope.CHIP)
+        CHIP = 0,
+        // @@protoc_insertion_point(enum_value:netsim.frontend.Breakpoint.Scope.SCENE)
+        SCENE = 1,
+    }
+
+    impl ::protobuf::Enum for Scope {
+        const NAME: &'static str = "Scope";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Scope> {
+            match value {
+                0 => ::std::option::Option::Some(Scope::CHIP),
+                1 => ::std::option::Option::Some(Scope::SCENE),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Scope] = &[
+            Scope::CHIP,
+            Scope::SCENE,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Scope {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Breakpoint.Scope").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Scope {
+        fn default() -> Self {
+            Scope::CHIP
+        }
+    }
+
+    impl Scope {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Scope>("Breakpoint.Scope")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ListBreakpointsResponse)
+pub struct ListBreakpointsResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ListBreakpointsResponse.breakpoints)
+    pub breakpoints: ::std::vec::Vec<Breakpoint>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ListBreakpointsResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListBreakpointsResponse {
+    fn default() -> &'a ListBreakpointsResponse {
+        <ListBreakpointsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListBreakpointsResponse {
+    pub fn new() -> ListBreakpointsResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "breakpoints",
+            |m: &ListBreakpointsResponse| { &m.breakpoints },
+            |m: &mut ListBreakpointsResponse| { &mut m.breakpoints },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListBreakpointsResponse>(
+            "ListBreakpointsResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListBreakpointsResponse {
+    const NAME: &'static str = "ListBreakpointsResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.breakpoints.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.breakpoints {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.breakpoints {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListBreakpointsResponse {
+        ListBreakpointsResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.breakpoints.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListBreakpointsResponse {
+        static instance: ListBreakpointsResponse = ListBreakpointsResponse {
+            breakpoints: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListBreakpointsResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListBreakpointsResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListBreakpointsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListBreakpointsResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.DeleteBreakpointRequest)
+pub struct DeleteBreakpointRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.DeleteBreakpointRequest.id)
+    pub id: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.DeleteBreakpointRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteBreakpointRequest {
+    fn default() -> &'a DeleteBreakpointRequest {
+        <DeleteBreakpointRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteBreakpointRequest {
+    pub fn new() -> DeleteBreakpointRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &DeleteBreakpointRequest| { &m.id },
+            |m: &mut DeleteBreakpointRequest| { &mut m.id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteBreakpointRequest>(
+            "DeleteBreakpointRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeleteBreakpointRequest {
+    const NAME: &'static str = "DeleteBreakpointRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeleteBreakpointRequest {
+        DeleteBreakpointRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeleteBreakpointRequest {
+        static instance: DeleteBreakpointRequest = DeleteBreakpointRequest {
+            id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeleteBreakpointRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteBreakpointRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeleteBreakpointRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteBreakpointRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.DeliveryState)
+pub struct DeliveryState {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.DeliveryState.paused)
+    pub paused: bool,
+    // @@protoc_insertion_point(field:netsim.frontend.DeliveryState.paused_chip_ids)
+    pub paused_chip_ids: ::std::vec::Vec<i32>,
+    // @@protoc_insertion_point(field:netsim.frontend.DeliveryState.held)
+    pub held: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.DeliveryState.delivered)
+    pub delivered: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.DeliveryState.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeliveryState {
+    fn default() -> &'a DeliveryState {
+        <DeliveryState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeliveryState {
+    pub fn new() -> DeliveryState {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "paused",
+            |m: &DeliveryState| { &m.paused },
+            |m: &mut DeliveryState| { &mut m.paused },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "paused_chip_ids",
+            |m: &DeliveryState| { &m.paused_chip_ids },
+            |m: &mut DeliveryState| { &mut m.paused_chip_ids },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "held",
+            |m: &DeliveryState| { &m.held },
+            |m: &mut DeliveryState| { &mut m.held },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "delivered",
+            |m: &DeliveryState| { &m.delivered },
+            |m: &mut DeliveryState| { &mut m.delivered },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeliveryState>(
+            "DeliveryState",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeliveryState {
+    const NAME: &'static str = "DeliveryState";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.paused = is.read_bool()?;
+                },
+                18 => {
+                    is.read_repeated_packed_int32_into(&mut self.paused_chip_ids)?;
+                },
+                16 => {
+                    self.paused_chip_ids.push(is.read_int32()?);
+                },
+                24 => {
+                    self.held = is.read_uint32()?;
+                },
+                32 => {
+                    self.delivered = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.paused != false {
+            my_size += 1 + 1;
+        }
+        for value in &self.paused_chip_ids {
+            my_size += ::protobuf::rt::int32_size(2, *value);
+        };
+        if self.held != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.held);
+        }
+        if self.delivered != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.delivered);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.paused != false {
+            os.write_bool(1, self.paused)?;
+        }
+        for v in &self.paused_chip_ids {
+            os.write_int32(2, *v)?;
+        };
+        if self.held != 0 {
+            os.write_uint32(3, self.held)?;
+        }
+        if self.delivered != 0 {
+            os.write_uint32(4, self.delivered)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeliveryState {
+        DeliveryState::new()
+    }
+
+    fn clear(&mut self) {
+        self.paused = false;
+        self.paused_chip_ids.clear();
+        self.held = 0;
+        self.delivered = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeliveryState {
+        static instance: DeliveryState = DeliveryState {
+            paused: false,
+            paused_chip_ids: ::std::vec::Vec::new(),
+            held: 0,
+            delivered: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeliveryState {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeliveryState").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeliveryState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeliveryState {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.LogLevel)
+pub struct LogLevel {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.LogLevel.level)
+    pub level: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.LogLevel.json)
+    pub json: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.LogLevel.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LogLevel {
+    fn default() -> &'a LogLevel {
+        <LogLevel as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LogLevel {
+    pub fn new() -> LogLevel {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "level",
+            |m: &LogLevel| { &m.level },
+            |m: &mut LogLevel| { &mut m.level },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "json",
+            |m: &LogLevel| { &m.json },
+            |m: &mut LogLevel| { &mut m.json },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LogLevel>(
+            "LogLevel",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LogLevel {
+    const NAME: &'static str = "LogLevel";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.level = is.read_string()?;
+                },
+                16 => {
+                    self.json = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.level.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.level);
+        }
+        if self.json != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.level.is_empty() {
+            os.write_string(1, &self.level)?;
+        }
+        if self.json != false {
+            os.write_bool(2, self.json)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LogLevel {
+        LogLevel::new()
+    }
+
+    fn clear(&mut self) {
+        self.level.clear();
+        self.json = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LogLevel {
+        static instance: LogLevel = LogLevel {
+            level: ::std::string::String::new(),
+            json: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LogLevel {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LogLevel").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LogLevel {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.Propagation)
+pub struct Propagation {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.Propagation.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.Propagation.exponent)
+    pub exponent: f32,
+    // @@protoc_insertion_point(field:netsim.frontend.Propagation.walls)
+    pub walls: ::std::vec::Vec<propagation::Wall>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.Propagation.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Propagation {
+    fn default() -> &'a Propagation {
+        <Propagation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Propagation {
+    pub fn new() -> Propagation {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &Propagation| { &m.name },
+            |m: &mut Propagation| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "exponent",
+            |m: &Propagation| { &m.exponent },
+            |m: &mut Propagation| { &mut m.exponent },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "walls",
+            |m: &Propagation| { &m.walls },
+            |m: &mut Propagation| { &mut m.walls },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Propagation>(
+            "Propagation",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Propagation {
+    const NAME: &'static str = "Propagation";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                21 => {
+                    self.exponent = is.read_float()?;
+                },
+                26 => {
+                    self.walls.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.exponent != 0. {
+            my_size += 1 + 4;
+        }
+        for value in &self.walls {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.exponent != 0. {
+            os.write_float(2, self.exponent)?;
+        }
+        for v in &self.walls {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Propagation {
+        Propagation::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.exponent = 0.;
+        self.walls.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Propagation {
+        static instance: Propagation = Propagation {
+            name: ::std::string::String::new(),
+            exponent: 0.,
+            walls: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Propagation {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Propagation").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Propagation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Propagation {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Propagation`
+pub mod propagation {
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.Propagation.Wall)
+    pub struct Wall {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.Propagation.Wall.start)
+        pub start: ::protobuf::MessageField<super::super::model::Position>,
+        // @@protoc_insertion_point(field:netsim.frontend.Propagation.Wall.end)
+        pub end: ::protobuf::MessageField<super::super::model::Position>,
+        // @@protoc_insertion_point(field:netsim.frontend.Propagation.Wall.loss)
+        pub loss: f32,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.Propagation.Wall.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Wall {
+        fn default() -> &'a Wall {
+            <Wall as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Wall {
+        pub fn new() -> Wall {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(3);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::super::model::Position>(
+                "start",
+                |m: &Wall| { &m.start },
+                |m: &mut Wall| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::super::model::Position>(
+                "end",
+                |m: &Wall| { &m.end },
+                |m: &mut Wall| { &mut m.end },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "loss",
+                |m: &Wall| { &m.loss },
+                |m: &mut Wall| { &mut m.loss },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Wall>(
+                "Propagation.Wall",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Wall {
+        const NAME: &'static str = "Wall";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.start)?;
+                    },
+                    18 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.end)?;
+                    },
+                    29 => {
+                        self.loss = is.read_float()?;
+                    },
                     tag => {
                         ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                     },
@@ -1156,110 +17086,973 @@ pub mod patch_capture_request {
             ::std::result::Result::Ok(())
         }
 
-        // Compute sizes of nested messages
-        #[allow(unused_variables)]
-        fn compute_size(&self) -> u64 {
-            let mut my_size = 0;
-            if self.state != ::protobuf::EnumOrUnknown::new(super::super::model::State::UNKNOWN) {
-                my_size += ::protobuf::rt::int32_size(1, self.state.value());
-            }
-            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
-            self.special_fields.cached_size().set(my_size as u32);
-            my_size
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if let Some(v) = self.start.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
+            if let Some(v) = self.end.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
+            if self.loss != 0. {
+                my_size += 1 + 4;
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if let Some(v) = self.start.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+            }
+            if let Some(v) = self.end.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+            }
+            if self.loss != 0. {
+                os.write_float(3, self.loss)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Wall {
+            Wall::new()
+        }
+
+        fn clear(&mut self) {
+            self.start.clear();
+            self.end.clear();
+            self.loss = 0.;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Wall {
+            static instance: Wall = Wall {
+                start: ::protobuf::MessageField::none(),
+                end: ::protobuf::MessageField::none(),
+                loss: 0.,
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Wall {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Propagation.Wall").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Wall {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Wall {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.StepDeliveryRequest)
+pub struct StepDeliveryRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.StepDeliveryRequest.count)
+    pub count: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.StepDeliveryRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a StepDeliveryRequest {
+    fn default() -> &'a StepDeliveryRequest {
+        <StepDeliveryRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StepDeliveryRequest {
+    pub fn new() -> StepDeliveryRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &StepDeliveryRequest| { &m.count },
+            |m: &mut StepDeliveryRequest| { &mut m.count },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<StepDeliveryRequest>(
+            "StepDeliveryRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for StepDeliveryRequest {
+    const NAME: &'static str = "StepDeliveryRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.count = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.count != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.count);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.count != 0 {
+            os.write_uint32(1, self.count)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> StepDeliveryRequest {
+        StepDeliveryRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.count = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static StepDeliveryRequest {
+        static instance: StepDeliveryRequest = StepDeliveryRequest {
+            count: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for StepDeliveryRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("StepDeliveryRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for StepDeliveryRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StepDeliveryRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.SceneImpairment)
+pub struct SceneImpairment {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.SceneImpairment.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.SceneImpairment.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.frontend.SceneImpairment.other_device_name)
+    pub other_device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.SceneImpairment.impairment)
+    pub impairment: ::protobuf::MessageField<SetLinkImpairmentRequest>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.SceneImpairment.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SceneImpairment {
+    fn default() -> &'a SceneImpairment {
+        <SceneImpairment as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SceneImpairment {
+    pub fn new() -> SceneImpairment {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &SceneImpairment| { &m.device_name },
+            |m: &mut SceneImpairment| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &SceneImpairment| { &m.chip_kind },
+            |m: &mut SceneImpairment| { &mut m.chip_kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "other_device_name",
+            |m: &SceneImpairment| { &m.other_device_name },
+            |m: &mut SceneImpairment| { &mut m.other_device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, SetLinkImpairmentRequest>(
+            "impairment",
+            |m: &SceneImpairment| { &m.impairment },
+            |m: &mut SceneImpairment| { &mut m.impairment },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SceneImpairment>(
+            "SceneImpairment",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SceneImpairment {
+    const NAME: &'static str = "SceneImpairment";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.device_name = is.read_string()?;
+                },
+                16 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    self.other_device_name = is.read_string()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.impairment)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device_name);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(2, self.chip_kind.value());
+        }
+        if !self.other_device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.other_device_name);
+        }
+        if let Some(v) = self.impairment.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.device_name.is_empty() {
+            os.write_string(1, &self.device_name)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        if !self.other_device_name.is_empty() {
+            os.write_string(3, &self.other_device_name)?;
+        }
+        if let Some(v) = self.impairment.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SceneImpairment {
+        SceneImpairment::new()
+    }
+
+    fn clear(&mut self) {
+        self.device_name.clear();
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.other_device_name.clear();
+        self.impairment.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SceneImpairment {
+        static instance: SceneImpairment = SceneImpairment {
+            device_name: ::std::string::String::new(),
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            other_device_name: ::std::string::String::new(),
+            impairment: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SceneImpairment {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SceneImpairment").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SceneImpairment {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SceneImpairment {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.SceneCapture)
+pub struct SceneCapture {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.SceneCapture.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.SceneCapture.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.frontend.SceneCapture.format)
+    pub format: ::protobuf::EnumOrUnknown<super::model::capture::Format>,
+    // @@protoc_insertion_point(field:netsim.frontend.SceneCapture.mode)
+    pub mode: ::protobuf::EnumOrUnknown<super::model::capture::Mode>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.SceneCapture.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SceneCapture {
+    fn default() -> &'a SceneCapture {
+        <SceneCapture as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SceneCapture {
+    pub fn new() -> SceneCapture {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &SceneCapture| { &m.device_name },
+            |m: &mut SceneCapture| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &SceneCapture| { &m.chip_kind },
+            |m: &mut SceneCapture| { &mut m.chip_kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "format",
+            |m: &SceneCapture| { &m.format },
+            |m: &mut SceneCapture| { &mut m.format },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mode",
+            |m: &SceneCapture| { &m.mode },
+            |m: &mut SceneCapture| { &mut m.mode },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SceneCapture>(
+            "SceneCapture",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SceneCapture {
+    const NAME: &'static str = "SceneCapture";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.device_name = is.read_string()?;
+                },
+                16 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                24 => {
+                    self.format = is.read_enum_or_unknown()?;
+                },
+                32 => {
+                    self.mode = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device_name);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(2, self.chip_kind.value());
+        }
+        if self.format != ::protobuf::EnumOrUnknown::new(super::model::capture::Format::PCAP) {
+            my_size += ::protobuf::rt::int32_size(3, self.format.value());
+        }
+        if self.mode != ::protobuf::EnumOrUnknown::new(super::model::capture::Mode::FILE) {
+            my_size += ::protobuf::rt::int32_size(4, self.mode.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.device_name.is_empty() {
+            os.write_string(1, &self.device_name)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
         }
+        if self.format != ::protobuf::EnumOrUnknown::new(super::model::capture::Format::PCAP) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.format))?;
+        }
+        if self.mode != ::protobuf::EnumOrUnknown::new(super::model::capture::Mode::FILE) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.mode))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
 
-        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-            if self.state != ::protobuf::EnumOrUnknown::new(super::super::model::State::UNKNOWN) {
-                os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.state))?;
-            }
-            os.write_unknown_fields(self.special_fields.unknown_fields())?;
-            ::std::result::Result::Ok(())
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SceneCapture {
+        SceneCapture::new()
+    }
+
+    fn clear(&mut self) {
+        self.device_name.clear();
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.format = ::protobuf::EnumOrUnknown::new(super::model::capture::Format::PCAP);
+        self.mode = ::protobuf::EnumOrUnknown::new(super::model::capture::Mode::FILE);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SceneCapture {
+        static instance: SceneCapture = SceneCapture {
+            device_name: ::std::string::String::new(),
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            format: ::protobuf::EnumOrUnknown::from_i32(0),
+            mode: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SceneCapture {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SceneCapture").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SceneCapture {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SceneCapture {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.SceneFile)
+pub struct SceneFile {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.SceneFile.scene)
+    pub scene: ::protobuf::MessageField<super::model::Scene>,
+    // @@protoc_insertion_point(field:netsim.frontend.SceneFile.beacons)
+    pub beacons: ::std::vec::Vec<AdvertiseRequest>,
+    // @@protoc_insertion_point(field:netsim.frontend.SceneFile.settings)
+    pub settings: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // @@protoc_insertion_point(field:netsim.frontend.SceneFile.impairments)
+    pub impairments: ::std::vec::Vec<SceneImpairment>,
+    // @@protoc_insertion_point(field:netsim.frontend.SceneFile.captures)
+    pub captures: ::std::vec::Vec<SceneCapture>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.SceneFile.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SceneFile {
+    fn default() -> &'a SceneFile {
+        <SceneFile as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SceneFile {
+    pub fn new() -> SceneFile {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Scene>(
+            "scene",
+            |m: &SceneFile| { &m.scene },
+            |m: &mut SceneFile| { &mut m.scene },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "beacons",
+            |m: &SceneFile| { &m.beacons },
+            |m: &mut SceneFile| { &mut m.beacons },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "settings",
+            |m: &SceneFile| { &m.settings },
+            |m: &mut SceneFile| { &mut m.settings },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "impairments",
+            |m: &SceneFile| { &m.impairments },
+            |m: &mut SceneFile| { &mut m.impairments },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "captures",
+            |m: &SceneFile| { &m.captures },
+            |m: &mut SceneFile| { &mut m.captures },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SceneFile>(
+            "SceneFile",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SceneFile {
+    const NAME: &'static str = "SceneFile";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.scene)?;
+                },
+                18 => {
+                    self.beacons.push(is.read_message()?);
+                },
+                26 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.settings.insert(key, value);
+                },
+                34 => {
+                    self.impairments.push(is.read_message()?);
+                },
+                42 => {
+                    self.captures.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.scene.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.beacons {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for (k, v) in &self.settings {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        for value in &self.impairments {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.captures {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.scene.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        for v in &self.beacons {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        for (k, v) in &self.settings {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(26)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        for v in &self.impairments {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        for v in &self.captures {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SceneFile {
+        SceneFile::new()
+    }
+
+    fn clear(&mut self) {
+        self.scene.clear();
+        self.beacons.clear();
+        self.settings.clear();
+        self.impairments.clear();
+        self.captures.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SceneFile {
+        static instance: ::protobuf::rt::Lazy<SceneFile> = ::protobuf::rt::Lazy::new();
+        instance.get(SceneFile::new)
+    }
+}
+
+impl ::protobuf::MessageFull for SceneFile {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SceneFile").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SceneFile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SceneFile {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.LoadScenarioRequest)
+pub struct LoadScenarioRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.LoadScenarioRequest.scene_file)
+    pub scene_file: ::protobuf::MessageField<SceneFile>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.LoadScenarioRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LoadScenarioRequest {
+    fn default() -> &'a LoadScenarioRequest {
+        <LoadScenarioRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LoadScenarioRequest {
+    pub fn new() -> LoadScenarioRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, SceneFile>(
+            "scene_file",
+            |m: &LoadScenarioRequest| { &m.scene_file },
+            |m: &mut LoadScenarioRequest| { &mut m.scene_file },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LoadScenarioRequest>(
+            "LoadScenarioRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LoadScenarioRequest {
+    const NAME: &'static str = "LoadScenarioRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.scene_file)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.scene_file.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
 
-        fn special_fields(&self) -> &::protobuf::SpecialFields {
-            &self.special_fields
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.scene_file.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
 
-        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
-            &mut self.special_fields
-        }
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
 
-        fn new() -> PatchCapture {
-            PatchCapture::new()
-        }
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
 
-        fn clear(&mut self) {
-            self.state = ::protobuf::EnumOrUnknown::new(super::super::model::State::UNKNOWN);
-            self.special_fields.clear();
-        }
+    fn new() -> LoadScenarioRequest {
+        LoadScenarioRequest::new()
+    }
 
-        fn default_instance() -> &'static PatchCapture {
-            static instance: PatchCapture = PatchCapture {
-                state: ::protobuf::EnumOrUnknown::from_i32(0),
-                special_fields: ::protobuf::SpecialFields::new(),
-            };
-            &instance
-        }
+    fn clear(&mut self) {
+        self.scene_file.clear();
+        self.special_fields.clear();
     }
 
-    impl ::protobuf::MessageFull for PatchCapture {
-        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
-            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("PatchCaptureRequest.PatchCapture").unwrap()).clone()
-        }
+    fn default_instance() -> &'static LoadScenarioRequest {
+        static instance: LoadScenarioRequest = LoadScenarioRequest {
+            scene_file: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
     }
+}
 
-    impl ::std::fmt::Display for PatchCapture {
-        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-            ::protobuf::text_format::fmt(self, f)
-        }
+impl ::protobuf::MessageFull for LoadScenarioRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LoadScenarioRequest").unwrap()).clone()
     }
+}
 
-    impl ::protobuf::reflect::ProtobufValue for PatchCapture {
-        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+impl ::std::fmt::Display for LoadScenarioRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
 }
 
+impl ::protobuf::reflect::ProtobufValue for LoadScenarioRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.ListCaptureResponse)
-pub struct ListCaptureResponse {
+// @@protoc_insertion_point(message:netsim.frontend.LoadScenarioResponse)
+pub struct LoadScenarioResponse {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.ListCaptureResponse.captures)
-    pub captures: ::std::vec::Vec<super::model::Capture>,
+    // @@protoc_insertion_point(field:netsim.frontend.LoadScenarioResponse.device_names)
+    pub device_names: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:netsim.frontend.LoadScenarioResponse.beacons)
+    pub beacons: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.LoadScenarioResponse.impairments)
+    pub impairments: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.LoadScenarioResponse.pending_impairments)
+    pub pending_impairments: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.LoadScenarioResponse.captures)
+    pub captures: u32,
+    // @@protoc_insertion_point(field:netsim.frontend.LoadScenarioResponse.pending_captures)
+    pub pending_captures: u32,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.ListCaptureResponse.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.LoadScenarioResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ListCaptureResponse {
-    fn default() -> &'a ListCaptureResponse {
-        <ListCaptureResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a LoadScenarioResponse {
+    fn default() -> &'a LoadScenarioResponse {
+        <LoadScenarioResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ListCaptureResponse {
-    pub fn new() -> ListCaptureResponse {
+impl LoadScenarioResponse {
+    pub fn new() -> LoadScenarioResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(6);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "device_names",
+            |m: &LoadScenarioResponse| { &m.device_names },
+            |m: &mut LoadScenarioResponse| { &mut m.device_names },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "beacons",
+            |m: &LoadScenarioResponse| { &m.beacons },
+            |m: &mut LoadScenarioResponse| { &mut m.beacons },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "impairments",
+            |m: &LoadScenarioResponse| { &m.impairments },
+            |m: &mut LoadScenarioResponse| { &mut m.impairments },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "pending_impairments",
+            |m: &LoadScenarioResponse| { &m.pending_impairments },
+            |m: &mut LoadScenarioResponse| { &mut m.pending_impairments },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "captures",
-            |m: &ListCaptureResponse| { &m.captures },
-            |m: &mut ListCaptureResponse| { &mut m.captures },
+            |m: &LoadScenarioResponse| { &m.captures },
+            |m: &mut LoadScenarioResponse| { &mut m.captures },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListCaptureResponse>(
-            "ListCaptureResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "pending_captures",
+            |m: &LoadScenarioResponse| { &m.pending_captures },
+            |m: &mut LoadScenarioResponse| { &mut m.pending_captures },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LoadScenarioResponse>(
+            "LoadScenarioResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ListCaptureResponse {
-    const NAME: &'static str = "ListCaptureResponse";
+impl ::protobuf::Message for LoadScenarioResponse {
+    const NAME: &'static str = "LoadScenarioResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1269,7 +18062,22 @@ impl ::protobuf::Message for ListCaptureResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.captures.push(is.read_message()?);
+                    self.device_names.push(is.read_string()?);
+                },
+                16 => {
+                    self.beacons = is.read_uint32()?;
+                },
+                24 => {
+                    self.impairments = is.read_uint32()?;
+                },
+                32 => {
+                    self.pending_impairments = is.read_uint32()?;
+                },
+                40 => {
+                    self.captures = is.read_uint32()?;
+                },
+                48 => {
+                    self.pending_captures = is.read_uint32()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1283,19 +18091,48 @@ impl ::protobuf::Message for ListCaptureResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.captures {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        for value in &self.device_names {
+            my_size += ::protobuf::rt::string_size(1, &value);
         };
+        if self.beacons != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.beacons);
+        }
+        if self.impairments != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.impairments);
+        }
+        if self.pending_impairments != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.pending_impairments);
+        }
+        if self.captures != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.captures);
+        }
+        if self.pending_captures != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.pending_captures);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.captures {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        for v in &self.device_names {
+            os.write_string(1, &v)?;
         };
+        if self.beacons != 0 {
+            os.write_uint32(2, self.beacons)?;
+        }
+        if self.impairments != 0 {
+            os.write_uint32(3, self.impairments)?;
+        }
+        if self.pending_impairments != 0 {
+            os.write_uint32(4, self.pending_impairments)?;
+        }
+        if self.captures != 0 {
+            os.write_uint32(5, self.captures)?;
+        }
+        if self.pending_captures != 0 {
+            os.write_uint32(6, self.pending_captures)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1308,60 +18145,70 @@ impl ::protobuf::Message for ListCaptureResponse {
         &mut self.special_fields
     }
 
-    fn new() -> ListCaptureResponse {
-        ListCaptureResponse::new()
+    fn new() -> LoadScenarioResponse {
+        LoadScenarioResponse::new()
     }
 
     fn clear(&mut self) {
-        self.captures.clear();
+        self.device_names.clear();
+        self.beacons = 0;
+        self.impairments = 0;
+        self.pending_impairments = 0;
+        self.captures = 0;
+        self.pending_captures = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ListCaptureResponse {
-        static instance: ListCaptureResponse = ListCaptureResponse {
-            captures: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static LoadScenarioResponse {
+        static instance: LoadScenarioResponse = LoadScenarioResponse {
+            device_names: ::std::vec::Vec::new(),
+            beacons: 0,
+            impairments: 0,
+            pending_impairments: 0,
+            captures: 0,
+            pending_captures: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ListCaptureResponse {
+impl ::protobuf::MessageFull for LoadScenarioResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListCaptureResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LoadScenarioResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ListCaptureResponse {
+impl ::std::fmt::Display for LoadScenarioResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ListCaptureResponse {
+impl ::protobuf::reflect::ProtobufValue for LoadScenarioResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.GetCaptureRequest)
-pub struct GetCaptureRequest {
+// @@protoc_insertion_point(message:netsim.frontend.SaveStateRequest)
+pub struct SaveStateRequest {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.GetCaptureRequest.id)
-    pub id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.SaveStateRequest.path)
+    pub path: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.GetCaptureRequest.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.SaveStateRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetCaptureRequest {
-    fn default() -> &'a GetCaptureRequest {
-        <GetCaptureRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SaveStateRequest {
+    fn default() -> &'a SaveStateRequest {
+        <SaveStateRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetCaptureRequest {
-    pub fn new() -> GetCaptureRequest {
+impl SaveStateRequest {
+    pub fn new() -> SaveStateRequest {
         ::std::default::Default::default()
     }
 
@@ -1369,20 +18216,20 @@ impl GetCaptureRequest {
         let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "id",
-            |m: &GetCaptureRequest| { &m.id },
-            |m: &mut GetCaptureRequest| { &mut m.id },
+            "path",
+            |m: &SaveStateRequest| { &m.path },
+            |m: &mut SaveStateRequest| { &mut m.path },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetCaptureRequest>(
-            "GetCaptureRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SaveStateRequest>(
+            "SaveStateRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetCaptureRequest {
-    const NAME: &'static str = "GetCaptureRequest";
+impl ::protobuf::Message for SaveStateRequest {
+    const NAME: &'static str = "SaveStateRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1391,8 +18238,8 @@ impl ::protobuf::Message for GetCaptureRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.id = is.read_int32()?;
+                10 => {
+                    self.path = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1406,8 +18253,8 @@ impl ::protobuf::Message for GetCaptureRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.id != 0 {
-            my_size += ::protobuf::rt::int32_size(1, self.id);
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1415,8 +18262,8 @@ impl ::protobuf::Message for GetCaptureRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.id != 0 {
-            os.write_int32(1, self.id)?;
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1430,60 +18277,60 @@ impl ::protobuf::Message for GetCaptureRequest {
         &mut self.special_fields
     }
 
-    fn new() -> GetCaptureRequest {
-        GetCaptureRequest::new()
+    fn new() -> SaveStateRequest {
+        SaveStateRequest::new()
     }
 
     fn clear(&mut self) {
-        self.id = 0;
+        self.path.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetCaptureRequest {
-        static instance: GetCaptureRequest = GetCaptureRequest {
-            id: 0,
+    fn default_instance() -> &'static SaveStateRequest {
+        static instance: SaveStateRequest = SaveStateRequest {
+            path: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetCaptureRequest {
+impl ::protobuf::MessageFull for SaveStateRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetCaptureRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SaveStateRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetCaptureRequest {
+impl ::std::fmt::Display for SaveStateRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetCaptureRequest {
+impl ::protobuf::reflect::ProtobufValue for SaveStateRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.GetCaptureResponse)
-pub struct GetCaptureResponse {
+// @@protoc_insertion_point(message:netsim.frontend.SaveStateResponse)
+pub struct SaveStateResponse {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.GetCaptureResponse.capture_stream)
-    pub capture_stream: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:netsim.frontend.SaveStateResponse.path)
+    pub path: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.GetCaptureResponse.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.SaveStateResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetCaptureResponse {
-    fn default() -> &'a GetCaptureResponse {
-        <GetCaptureResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SaveStateResponse {
+    fn default() -> &'a SaveStateResponse {
+        <SaveStateResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetCaptureResponse {
-    pub fn new() -> GetCaptureResponse {
+impl SaveStateResponse {
+    pub fn new() -> SaveStateResponse {
         ::std::default::Default::default()
     }
 
@@ -1491,20 +18338,20 @@ impl GetCaptureResponse {
         let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "capture_stream",
-            |m: &GetCaptureResponse| { &m.capture_stream },
-            |m: &mut GetCaptureResponse| { &mut m.capture_stream },
+            "path",
+            |m: &SaveStateResponse| { &m.path },
+            |m: &mut SaveStateResponse| { &mut m.path },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetCaptureResponse>(
-            "GetCaptureResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SaveStateResponse>(
+            "SaveStateResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetCaptureResponse {
-    const NAME: &'static str = "GetCaptureResponse";
+impl ::protobuf::Message for SaveStateResponse {
+    const NAME: &'static str = "SaveStateResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1514,7 +18361,7 @@ impl ::protobuf::Message for GetCaptureResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.capture_stream = is.read_bytes()?;
+                    self.path = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1528,8 +18375,8 @@ impl ::protobuf::Message for GetCaptureResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.capture_stream.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.capture_stream);
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1537,8 +18384,8 @@ impl ::protobuf::Message for GetCaptureResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.capture_stream.is_empty() {
-            os.write_bytes(1, &self.capture_stream)?;
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1552,95 +18399,81 @@ impl ::protobuf::Message for GetCaptureResponse {
         &mut self.special_fields
     }
 
-    fn new() -> GetCaptureResponse {
-        GetCaptureResponse::new()
+    fn new() -> SaveStateResponse {
+        SaveStateResponse::new()
     }
 
     fn clear(&mut self) {
-        self.capture_stream.clear();
+        self.path.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetCaptureResponse {
-        static instance: GetCaptureResponse = GetCaptureResponse {
-            capture_stream: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static SaveStateResponse {
+        static instance: SaveStateResponse = SaveStateResponse {
+            path: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetCaptureResponse {
+impl ::protobuf::MessageFull for SaveStateResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetCaptureResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SaveStateResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetCaptureResponse {
+impl ::std::fmt::Display for SaveStateResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetCaptureResponse {
+impl ::protobuf::reflect::ProtobufValue for SaveStateResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.frontend.ErrorResponse)
-pub struct ErrorResponse {
+// @@protoc_insertion_point(message:netsim.frontend.RestoreStateRequest)
+pub struct RestoreStateRequest {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.ErrorResponse.code)
-    pub code: i32,
-    // @@protoc_insertion_point(field:netsim.frontend.ErrorResponse.error_message)
-    pub error_message: ::std::string::String,
-    // @@protoc_insertion_point(field:netsim.frontend.ErrorResponse.status)
-    pub status: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.RestoreStateRequest.path)
+    pub path: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.ErrorResponse.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.RestoreStateRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ErrorResponse {
-    fn default() -> &'a ErrorResponse {
-        <ErrorResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RestoreStateRequest {
+    fn default() -> &'a RestoreStateRequest {
+        <RestoreStateRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ErrorResponse {
-    pub fn new() -> ErrorResponse {
+impl RestoreStateRequest {
+    pub fn new() -> RestoreStateRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "code",
-            |m: &ErrorResponse| { &m.code },
-            |m: &mut ErrorResponse| { &mut m.code },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "error_message",
-            |m: &ErrorResponse| { &m.error_message },
-            |m: &mut ErrorResponse| { &mut m.error_message },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "status",
-            |m: &ErrorResponse| { &m.status },
-            |m: &mut ErrorResponse| { &mut m.status },
+            "path",
+            |m: &RestoreStateRequest| { &m.path },
+            |m: &mut RestoreStateRequest| { &mut m.path },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ErrorResponse>(
-            "ErrorResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RestoreStateRequest>(
+            "RestoreStateRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ErrorResponse {
-    const NAME: &'static str = "ErrorResponse";
+impl ::protobuf::Message for RestoreStateRequest {
+    const NAME: &'static str = "RestoreStateRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1649,14 +18482,8 @@ impl ::protobuf::Message for ErrorResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.code = is.read_int32()?;
-                },
-                18 => {
-                    self.error_message = is.read_string()?;
-                },
-                26 => {
-                    self.status = is.read_string()?;
+                10 => {
+                    self.path = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1670,14 +18497,8 @@ impl ::protobuf::Message for ErrorResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.code != 0 {
-            my_size += ::protobuf::rt::int32_size(1, self.code);
-        }
-        if !self.error_message.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.error_message);
-        }
-        if !self.status.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.status);
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1685,14 +18506,8 @@ impl ::protobuf::Message for ErrorResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.code != 0 {
-            os.write_int32(1, self.code)?;
-        }
-        if !self.error_message.is_empty() {
-            os.write_string(2, &self.error_message)?;
-        }
-        if !self.status.is_empty() {
-            os.write_string(3, &self.status)?;
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1706,83 +18521,507 @@ impl ::protobuf::Message for ErrorResponse {
         &mut self.special_fields
     }
 
-    fn new() -> ErrorResponse {
-        ErrorResponse::new()
+    fn new() -> RestoreStateRequest {
+        RestoreStateRequest::new()
     }
 
     fn clear(&mut self) {
-        self.code = 0;
-        self.error_message.clear();
-        self.status.clear();
+        self.path.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ErrorResponse {
-        static instance: ErrorResponse = ErrorResponse {
-            code: 0,
-            error_message: ::std::string::String::new(),
-            status: ::std::string::String::new(),
+    fn default_instance() -> &'static RestoreStateRequest {
+        static instance: RestoreStateRequest = RestoreStateRequest {
+            path: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ErrorResponse {
+impl ::protobuf::MessageFull for RestoreStateRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ErrorResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RestoreStateRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ErrorResponse {
+impl ::std::fmt::Display for RestoreStateRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ErrorResponse {
+impl ::protobuf::reflect::ProtobufValue for RestoreStateRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0efrontend.proto\x12\x0fnetsim.frontend\x1a\x1bgoogle/protobuf/empty\
-    .proto\x1a\x0bmodel.proto\"+\n\x0fVersionResponse\x12\x18\n\x07version\
-    \x18\x01\x20\x01(\tR\x07version\"\xa2\x01\n\x12SetLinkLossRequest\x12\
-    \x1f\n\x0bdevice_name\x18\x01\x20\x01(\tR\ndeviceName\x12!\n\x0cother_de\
-    vice\x18\x02\x20\x01(\tR\x0botherDevice\x12+\n\x05radio\x18\x03\x20\x01(\
-    \x0e2\x15.netsim.model.PhyKindR\x05radio\x12\x1b\n\tlink_loss\x18\x04\
-    \x20\x01(\x02R\x08linkLoss\"B\n\x12PatchDeviceRequest\x12,\n\x06device\
-    \x18\x02\x20\x01(\x0b2\x14.netsim.model.DeviceR\x06device\"7\n\x05Event\
-    \x12.\n\x07devices\x18\x01\x20\x03(\x0b2\x14.netsim.model.DeviceR\x07dev\
-    ices\"D\n\x12GetDevicesResponse\x12.\n\x07devices\x18\x01\x20\x03(\x0b2\
-    \x14.netsim.model.DeviceR\x07devices\"&\n\x0cNetCatStream\x12\x16\n\x06r\
-    esult\x18\x01\x20\x03(\tR\x06result\"X\n\x17SetPacketCaptureRequest\x12\
-    \x18\n\x07capture\x18\x01\x20\x01(\x08R\x07capture\x12#\n\rdevice_serial\
-    \x18\x02\x20\x01(\tR\x0cdeviceSerial\"\xa9\x01\n\x13PatchCaptureRequest\
-    \x12\x0e\n\x02id\x18\x01\x20\x01(\x05R\x02id\x12G\n\x05patch\x18\x02\x20\
-    \x01(\x0b21.netsim.frontend.PatchCaptureRequest.PatchCaptureR\x05patch\
-    \x1a9\n\x0cPatchCapture\x12)\n\x05state\x18\x01\x20\x01(\x0e2\x13.netsim\
-    .model.StateR\x05state\"H\n\x13ListCaptureResponse\x121\n\x08captures\
-    \x18\x01\x20\x03(\x0b2\x15.netsim.model.CaptureR\x08captures\"#\n\x11Get\
-    CaptureRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\x05R\x02id\";\n\x12GetCa\
-    ptureResponse\x12%\n\x0ecapture_stream\x18\x01\x20\x01(\x0cR\rcaptureStr\
-    eam\"`\n\rErrorResponse\x12\x12\n\x04code\x18\x01\x20\x01(\x05R\x04code\
-    \x12#\n\rerror_message\x18\x02\x20\x01(\tR\x0cerrorMessage\x12\x16\n\x06\
-    status\x18\x03\x20\x01(\tR\x06status2\xf0\x05\n\x0fFrontendService\x12F\
-    \n\nGetVersion\x12\x16.google.protobuf.Empty\x1a\x20.netsim.frontend.Ver\
-    sionResponse\x12B\n\x0eRegisterEvents\x12\x16.google.protobuf.Empty\x1a\
-    \x16.netsim.frontend.Event0\x01\x12I\n\nGetDevices\x12\x16.google.protob\
-    uf.Empty\x1a#.netsim.frontend.GetDevicesResponse\x12J\n\x0bPatchDevice\
-    \x12#.netsim.frontend.PatchDeviceRequest\x1a\x16.google.protobuf.Empty\
-    \x127\n\x05Reset\x12\x16.google.protobuf.Empty\x1a\x16.google.protobuf.E\
-    mpty\x12J\n\x0bSetLinkLoss\x12#.netsim.frontend.SetLinkLossRequest\x1a\
-    \x16.google.protobuf.Empty\x12A\n\x06NetCat\x12\x16.google.protobuf.Empt\
-    y\x1a\x1d.netsim.frontend.NetCatStream0\x01\x12L\n\x0cPatchCapture\x12$.\
-    netsim.frontend.PatchCaptureRequest\x1a\x16.google.protobuf.Empty\x12K\n\
-    \x0bListCapture\x12\x16.google.protobuf.Empty\x1a$.netsim.frontend.ListC\
-    aptureResponse\x12W\n\nGetCapture\x12\".netsim.frontend.GetCaptureReques\
-    t\x1a#.netsim.frontend.GetCaptureResponse0\x01b\x06proto3\
+    .proto\x1a\x20google/protobuf/field_mask.proto\x1a\x1fgoogle/protobuf/ti\
+    mestamp.proto\x1a\x0ccommon.proto\x1a\x0bmodel.proto\"\x92\x02\n\x16Comp\
+    utePathLossRequest\x124\n\tchip_kind\x18\x01\x20\x01(\x0e2\x17.netsim.co\
+    mmon.ChipKindR\x08chipKind\x12\x1b\n\tsender_id\x18\x02\x20\x01(\rR\x08s\
+    enderId\x12\x1f\n\x0breceiver_id\x18\x03\x20\x01(\rR\nreceiverId\x12?\n\
+    \x0fsender_position\x18\x04\x20\x01(\x0b2\x16.netsim.model.PositionR\x0e\
+    senderPosition\x12C\n\x11receiver_position\x18\x05\x20\x01(\x0b2\x16.net\
+    sim.model.PositionR\x10receiverPosition\"6\n\x17ComputePathLossResponse\
+    \x12\x1b\n\tpath_loss\x18\x01\x20\x01(\x02R\x08pathLoss\"\xad\x01\n\x0fV\
+    ersionResponse\x12\x18\n\x07version\x18\x01\x20\x01(\tR\x07version\x12\
+    \x1d\n\nchip_kinds\x18\x02\x20\x03(\tR\tchipKinds\x12E\n\x0fcapture_form\
+    ats\x18\x03\x20\x03(\x0e2\x1c.netsim.model.Capture.FormatR\x0ecaptureFor\
+    mats\x12\x1a\n\x08features\x18\x04\x20\x03(\tR\x08features\"\x9d\x01\n\
+    \x16SetDeviceMotionRequest\x12\x1f\n\x0bdevice_name\x18\x01\x20\x01(\tR\
+    \ndeviceName\x124\n\twaypoints\x18\x02\x20\x03(\x0b2\x16.netsim.model.Po\
+    sitionR\twaypoints\x12\x14\n\x05speed\x18\x03\x20\x01(\x02R\x05speed\x12\
+    \x16\n\x06repeat\x18\x04\x20\x01(\x08R\x06repeat\"\xa2\x01\n\x12SetLinkL\
+    ossRequest\x12\x1f\n\x0bdevice_name\x18\x01\x20\x01(\tR\ndeviceName\x12!\
+    \n\x0cother_device\x18\x02\x20\x01(\tR\x0botherDevice\x12+\n\x05radio\
+    \x18\x03\x20\x01(\x0e2\x15.netsim.model.PhyKindR\x05radio\x12\x1b\n\tlin\
+    k_loss\x18\x04\x20\x01(\x02R\x08linkLoss\"\xe8\x02\n\x18SetLinkImpairmen\
+    tRequest\x12\x17\n\x07chip_id\x18\x01\x20\x01(\x05R\x06chipId\x12\"\n\ro\
+    ther_chip_id\x18\x02\x20\x01(\x05R\x0botherChipId\x12!\n\x0cdrop_percent\
+    \x18\x03\x20\x01(\x02R\x0bdropPercent\x12\x12\n\x04seed\x18\x04\x20\x01(\
+    \x04R\x04seed\x12\x1d\n\nlatency_ms\x18\x05\x20\x01(\rR\tlatencyMs\x12\
+    \x1b\n\tjitter_ms\x18\x06\x20\x01(\rR\x08jitterMs\x12m\n\x13jitter_distr\
+    ibution\x18\x07\x20\x01(\x0e2<.netsim.frontend.SetLinkImpairmentRequest.\
+    JitterDistributionR\x12jitterDistribution\"-\n\x12JitterDistribution\x12\
+    \x0b\n\x07UNIFORM\x10\0\x12\n\n\x06NORMAL\x10\x01\"\x7f\n\x12PatchDevice\
+    Request\x12,\n\x06device\x18\x02\x20\x01(\x0b2\x14.netsim.model.DeviceR\
+    \x06device\x12;\n\x0bupdate_mask\x18\x03\x20\x01(\x0b2\x1a.google.protob\
+    uf.FieldMaskR\nupdateMask\"\xfc\x01\n\x11PatchGroupRequest\x12\x14\n\x05\
+    group\x18\x01\x20\x01(\tR\x05group\x12*\n\x11join_device_names\x18\x02\
+    \x20\x03(\tR\x0fjoinDeviceNames\x12,\n\x12leave_device_names\x18\x03\x20\
+    \x03(\tR\x10leaveDeviceNames\x12,\n\x06device\x18\x04\x20\x01(\x0b2\x14.\
+    netsim.model.DeviceR\x06device\x12\x1a\n\x08relative\x18\x05\x20\x01(\
+    \x08R\x08relative\x12-\n\x07capture\x18\x06\x20\x01(\x0e2\x13.netsim.mod\
+    el.StateR\x07capture\"7\n\x12PatchGroupResponse\x12!\n\x0cdevice_names\
+    \x18\x01\x20\x03(\tR\x0bdeviceNames\"X\n\x08Selector\x12\x16\n\x06device\
+    \x18\x01\x20\x01(\tR\x06device\x124\n\tchip_kind\x18\x02\x20\x01(\x0e2\
+    \x17.netsim.common.ChipKindR\x08chipKind\"\xdb\x01\n\x15PatchSelectionRe\
+    quest\x125\n\x08selector\x18\x01\x20\x01(\x0b2\x19.netsim.frontend.Selec\
+    torR\x08selector\x12&\n\x04chip\x18\x02\x20\x01(\x0b2\x12.netsim.model.C\
+    hipR\x04chip\x12-\n\x07capture\x18\x03\x20\x01(\x0e2\x13.netsim.model.St\
+    ateR\x07capture\x124\n\x06format\x18\x04\x20\x01(\x0e2\x1c.netsim.model.\
+    Capture.FormatR\x06format\"\xf3\x01\n\x16PatchSelectionResponse\x12H\n\
+    \x07results\x18\x01\x20\x03(\x0b2..netsim.frontend.PatchSelectionRespons\
+    e.ResultR\x07results\x1a\x8e\x01\n\x06Result\x12\x1f\n\x0bdevice_name\
+    \x18\x01\x20\x01(\tR\ndeviceName\x12\x17\n\x07chip_id\x18\x02\x20\x01(\
+    \x05R\x06chipId\x124\n\tchip_kind\x18\x03\x20\x01(\x0e2\x17.netsim.commo\
+    n.ChipKindR\x08chipKind\x12\x14\n\x05error\x18\x04\x20\x01(\tR\x05error\
+    \"-\n\x12RestartChipRequest\x12\x17\n\x07chip_id\x18\x01\x20\x01(\x05R\
+    \x06chipId\"I\n\x13RestartChipResponse\x12\x16\n\x06queued\x18\x01\x20\
+    \x01(\rR\x06queued\x12\x1a\n\x08rejected\x18\x02\x20\x01(\rR\x08rejected\
+    \"a\n\x10AdvertiseRequest\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\
+    \x12\x1f\n\x0binterval_ms\x18\x02\x20\x01(\rR\nintervalMs\x12\x18\n\x07p\
+    ayload\x18\x03\x20\x01(\x0cR\x07payload\"\x9e\x01\n\x06Beacon\x12\x1b\n\
+    \tdevice_id\x18\x01\x20\x01(\x05R\x08deviceId\x12\x17\n\x07chip_id\x18\
+    \x02\x20\x01(\x05R\x06chipId\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04na\
+    me\x12\x1f\n\x0binterval_ms\x18\x04\x20\x01(\rR\nintervalMs\x12)\n\x10ad\
+    vertising_data\x18\x05\x20\x01(\x0cR\x0fadvertisingData\"H\n\x13ListBeac\
+    onsResponse\x121\n\x07beacons\x18\x01\x20\x03(\x0b2\x17.netsim.frontend.\
+    BeaconR\x07beacons\"\xc8\x06\n\x05Event\x12.\n\x07devices\x18\x01\x20\
+    \x03(\x0b2\x14.netsim.model.DeviceR\x07devices\x12\x0e\n\x02id\x18\x02\
+    \x20\x01(\x04R\x02id\x128\n\ttimestamp\x18\x03\x20\x01(\x0b2\x1a.google.\
+    protobuf.TimestampR\ttimestamp\x12/\n\x04type\x18\x04\x20\x01(\x0e2\x1b.\
+    netsim.frontend.Event.TypeR\x04type\x12\x1f\n\x0bdevice_name\x18\x05\x20\
+    \x01(\tR\ndeviceName\x12\x17\n\x07chip_id\x18\x06\x20\x01(\x05R\x06chipI\
+    d\x12\x16\n\x06packet\x18\x07\x20\x01(\x0cR\x06packet\x12#\n\rbreakpoint\
+    _id\x18\x08\x20\x01(\rR\x0cbreakpointId\x12L\n\x0ftransport_stats\x18\t\
+    \x20\x01(\x0b2#.netsim.frontend.ChipTransportStatsR\x0etransportStats\
+    \x12/\n\x07capture\x18\n\x20\x01(\x0b2\x15.netsim.model.CaptureR\x07capt\
+    ure\x12\x14\n\x05error\x18\x0b\x20\x01(\tR\x05error\"\x87\x03\n\x04Type\
+    \x12\x0f\n\x0bUNSPECIFIED\x10\0\x12\x10\n\x0cDEVICE_ADDED\x10\x01\x12\
+    \x12\n\x0eDEVICE_REMOVED\x10\x02\x12\x12\n\x0eDEVICE_PATCHED\x10\x03\x12\
+    \x0e\n\nCHIP_ADDED\x10\x04\x12\x10\n\x0cCHIP_REMOVED\x10\x05\x12\t\n\x05\
+    RESET\x10\x06\x12\x10\n\x0cRATE_LIMITED\x10\x07\x12\x15\n\x11CAPTURE_WAT\
+    ERMARK\x10\x08\x12\x10\n\x0cCAPTURE_FULL\x10\t\x12\x12\n\x0eBREAKPOINT_H\
+    IT\x10\n\x12\x12\n\x0eCHIP_RESTARTED\x10\x0b\x12\x10\n\x0cCHIP_STALLED\
+    \x10\x0c\x12\x13\n\x0fCAPTURE_STARTED\x10\r\x12\x13\n\x0fCAPTURE_STOPPED\
+    \x10\x0e\x12\x13\n\x0fCAPTURE_ROTATED\x10\x0f\x12\x13\n\x0fCAPTURE_REMOV\
+    ED\x10\x10\x12\x11\n\rCAPTURE_ERROR\x10\x11\x12\x13\n\x0fCAPTURE_EVICTED\
+    \x10\x12\x12\x16\n\x12CHIP_STATE_CHANGED\x10\x13\"\xa6\x01\n\x11GetDevic\
+    esRequest\x12\x1f\n\x0bname_filter\x18\x01\x20\x01(\tR\nnameFilter\x124\
+    \n\tchip_kind\x18\x02\x20\x01(\x0e2\x17.netsim.common.ChipKindR\x08chipK\
+    ind\x12\x1b\n\tpage_size\x18\x03\x20\x01(\rR\x08pageSize\x12\x1d\n\npage\
+    _token\x18\x04\x20\x01(\tR\tpageToken\"l\n\x12GetDevicesResponse\x12.\n\
+    \x07devices\x18\x01\x20\x03(\x0b2\x14.netsim.model.DeviceR\x07devices\
+    \x12&\n\x0fnext_page_token\x18\x02\x20\x01(\tR\rnextPageToken\"&\n\x0cNe\
+    tCatStream\x12\x16\n\x06result\x18\x01\x20\x03(\tR\x06result\"X\n\x17Set\
+    PacketCaptureRequest\x12\x18\n\x07capture\x18\x01\x20\x01(\x08R\x07captu\
+    re\x12#\n\rdevice_serial\x18\x02\x20\x01(\tR\x0cdeviceSerial\"\xa2\x04\n\
+    \x13PatchCaptureRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\x05R\x02id\x12G\
+    \n\x05patch\x18\x02\x20\x01(\x0b21.netsim.frontend.PatchCaptureRequest.P\
+    atchCaptureR\x05patch\x1a\xb1\x03\n\x0cPatchCapture\x12)\n\x05state\x18\
+    \x01\x20\x01(\x0e2\x13.netsim.model.StateR\x05state\x12:\n\x08sampling\
+    \x18\x02\x20\x01(\x0b2\x1e.netsim.model.Capture.SamplingR\x08sampling\
+    \x124\n\x06format\x18\x03\x20\x01(\x0e2\x1c.netsim.model.Capture.FormatR\
+    \x06format\x124\n\x06filter\x18\x04\x20\x01(\x0b2\x1c.netsim.model.Captu\
+    re.FilterR\x06filter\x12.\n\x04mode\x18\x05\x20\x01(\x0e2\x1a.netsim.mod\
+    el.Capture.ModeR\x04mode\x12\x12\n\x04dump\x18\x06\x20\x01(\x08R\x04dump\
+    \x12\x18\n\x07snaplen\x18\x07\x20\x01(\rR\x07snaplen\x12\x1b\n\tlive_pat\
+    h\x18\x08\x20\x01(\tR\x08livePath\x12\x16\n\x06append\x18\t\x20\x01(\x08\
+    R\x06append\x12;\n\x0bupdate_mask\x18\n\x20\x01(\x0b2\x1a.google.protobu\
+    f.FieldMaskR\nupdateMask\"\xdf\x01\n\x12ListCaptureRequest\x12,\n\x12dev\
+    ice_name_filter\x18\x01\x20\x01(\tR\x10deviceNameFilter\x124\n\tchip_kin\
+    d\x18\x02\x20\x01(\x0e2\x17.netsim.common.ChipKindR\x08chipKind\x12)\n\
+    \x05state\x18\x03\x20\x01(\x0e2\x13.netsim.model.StateR\x05state\x12\x1b\
+    \n\tpage_size\x18\x04\x20\x01(\rR\x08pageSize\x12\x1d\n\npage_token\x18\
+    \x05\x20\x01(\tR\tpageToken\"p\n\x13ListCaptureResponse\x121\n\x08captur\
+    es\x18\x01\x20\x03(\x0b2\x15.netsim.model.CaptureR\x08captures\x12&\n\
+    \x0fnext_page_token\x18\x02\x20\x01(\tR\rnextPageToken\"m\n\x11GetCaptur\
+    eRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\x05R\x02id\x12\x18\n\x07segmen\
+    t\x18\x02\x20\x01(\rR\x07segment\x12\x16\n\x06offset\x18\x03\x20\x01(\
+    \x04R\x06offset\x12\x16\n\x06length\x18\x04\x20\x01(\x04R\x06length\";\n\
+    \x12GetCaptureResponse\x12%\n\x0ecapture_stream\x18\x01\x20\x01(\x0cR\rc\
+    aptureStream\"/\n\x14StreamCaptureRequest\x12\x17\n\x07chip_id\x18\x01\
+    \x20\x01(\x05R\x06chipId\"\\\n\nCaptureAll\x12\x18\n\x07enabled\x18\x01\
+    \x20\x01(\x08R\x07enabled\x124\n\x06format\x18\x02\x20\x01(\x0e2\x1c.net\
+    sim.model.Capture.FormatR\x06format\"\x7f\n\x10AggregateCapture\x12)\n\
+    \x05state\x18\x01\x20\x01(\x0e2\x13.netsim.model.StateR\x05state\x12\x12\
+    \n\x04path\x18\x02\x20\x01(\tR\x04path\x12\x12\n\x04size\x18\x03\x20\x01\
+    (\x05R\x04size\x12\x18\n\x07records\x18\x04\x20\x01(\x05R\x07records\"\
+    \xfd\x02\n\x0cCaptureStats\x12\x0e\n\x02id\x18\x01\x20\x01(\x05R\x02id\
+    \x12\x1f\n\x0bdevice_name\x18\x02\x20\x01(\tR\ndeviceName\x124\n\tchip_k\
+    ind\x18\x03\x20\x01(\x0e2\x17.netsim.common.ChipKindR\x08chipKind\x12)\n\
+    \x05state\x18\x04\x20\x01(\x0e2\x13.netsim.model.StateR\x05state\x12(\n\
+    \x10bytes_per_second\x18\x05\x20\x01(\x01R\x0ebytesPerSecond\x12,\n\x12p\
+    ackets_per_second\x18\x06\x20\x01(\x01R\x10packetsPerSecond\x12\x12\n\
+    \x04size\x18\x07\x20\x01(\x05R\x04size\x12\x18\n\x07records\x18\x08\x20\
+    \x01(\x05R\x07records\x12;\n\x0blast_record\x18\t\x20\x01(\x0b2\x1a.goog\
+    le.protobuf.TimestampR\nlastRecord\x12\x18\n\x07dropped\x18\n\x20\x01(\
+    \x04R\x07dropped\"T\n\x17GetCaptureStatsResponse\x129\n\x08captures\x18\
+    \x01\x20\x03(\x0b2\x1d.netsim.frontend.CaptureStatsR\x08captures\"o\n\
+    \x14ReplayCaptureRequest\x12\x17\n\x07chip_id\x18\x01\x20\x01(\x05R\x06c\
+    hipId\x12\x12\n\x04path\x18\x02\x20\x01(\tR\x04path\x12\x14\n\x05speed\
+    \x18\x03\x20\x01(\x01R\x05speed\x12\x14\n\x05burst\x18\x04\x20\x01(\x08R\
+    \x05burst\"M\n\x15ReplayCaptureResponse\x12\x1a\n\x08injected\x18\x01\
+    \x20\x01(\x04R\x08injected\x12\x18\n\x07skipped\x18\x02\x20\x01(\x04R\
+    \x07skipped\"&\n\x14DeleteCaptureRequest\x12\x0e\n\x02id\x18\x01\x20\x01\
+    (\x05R\x02id\"\xf4\x05\n\x11ScenarioAssertion\x12\x12\n\x04name\x18\x01\
+    \x20\x01(\tR\x04name\x12\x1d\n\ntimeout_ms\x18\x02\x20\x01(\rR\ttimeoutM\
+    s\x12P\n\x0bpacket_seen\x18\x03\x20\x01(\x0b2-.netsim.frontend.ScenarioA\
+    ssertion.PacketSeenH\0R\npacketSeen\x12S\n\x0cdevice_state\x18\x04\x20\
+    \x01(\x0b2..netsim.frontend.ScenarioAssertion.DeviceStateH\0R\x0bdeviceS\
+    tate\x12_\n\x10counter_exceeded\x18\x05\x20\x01(\x0b22.netsim.frontend.S\
+    cenarioAssertion.CounterExceededH\0R\x0fcounterExceeded\x1a}\n\nPacketSe\
+    en\x12\x1f\n\x0bdevice_name\x18\x01\x20\x01(\tR\ndeviceName\x124\n\tchip\
+    _kind\x18\x02\x20\x01(\x0e2\x17.netsim.common.ChipKindR\x08chipKind\x12\
+    \x18\n\x07pattern\x18\x03\x20\x01(\tR\x07pattern\x1a;\n\x0bDeviceState\
+    \x12,\n\x06device\x18\x01\x20\x01(\x0b2\x14.netsim.model.DeviceR\x06devi\
+    ce\x1a\xda\x01\n\x0fCounterExceeded\x12\x16\n\x06metric\x18\x01\x20\x01(\
+    \tR\x06metric\x12V\n\x06labels\x18\x02\x20\x03(\x0b2>.netsim.frontend.Sc\
+    enarioAssertion.CounterExceeded.LabelsEntryR\x06labels\x12\x1c\n\tthresh\
+    old\x18\x03\x20\x01(\x01R\tthreshold\x1a9\n\x0bLabelsEntry\x12\x10\n\x03\
+    key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\
+    \x05value:\x028\x01B\x0b\n\tassertion\"\xe1\x01\n\x12RunScenarioRequest\
+    \x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12*\n\x05setup\x18\x02\
+    \x20\x03(\x0b2\x14.netsim.model.DeviceR\x05setup\x12B\n\nassertions\x18\
+    \x03\x20\x03(\x0b2\".netsim.frontend.ScenarioAssertionR\nassertions\x12G\
+    \n\x0fgolden_captures\x18\x04\x20\x03(\x0b2\x1e.netsim.frontend.GoldenCa\
+    ptureR\x0egoldenCaptures\"\xa0\x02\n\x14CaptureNormalization\x12-\n\x12c\
+    ompare_timestamps\x18\x01\x20\x01(\x08R\x11compareTimestamps\x124\n\x16t\
+    imestamp_tolerance_ms\x18\x02\x20\x01(\rR\x14timestampToleranceMs\x12@\n\
+    \x05masks\x18\x03\x20\x03(\x0b2*.netsim.frontend.CaptureNormalization.Ma\
+    skR\x05masks\x12)\n\x10address_patterns\x18\x04\x20\x03(\tR\x0faddressPa\
+    tterns\x1a6\n\x04Mask\x12\x16\n\x06offset\x18\x01\x20\x01(\rR\x06offset\
+    \x12\x16\n\x06length\x18\x02\x20\x01(\rR\x06length\"\xa8\x02\n\rGoldenCa\
+    pture\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x1f\n\x0bdevice_\
+    name\x18\x02\x20\x01(\tR\ndeviceName\x124\n\tchip_kind\x18\x03\x20\x01(\
+    \x0e2\x17.netsim.common.ChipKindR\x08chipKind\x12\x1f\n\x0bgolden_path\
+    \x18\x04\x20\x01(\tR\ngoldenPath\x12\x1d\n\ntimeout_ms\x18\x05\x20\x01(\
+    \rR\ttimeoutMs\x12\x1f\n\x0bactual_path\x18\x06\x20\x01(\tR\nactualPath\
+    \x12K\n\rnormalization\x18\x07\x20\x01(\x0b2%.netsim.frontend.CaptureNor\
+    malizationR\rnormalization\"\xb0\x02\n\nRecordDiff\x124\n\x04kind\x18\
+    \x01\x20\x01(\x0e2\x20.netsim.frontend.RecordDiff.KindR\x04kind\x12\x14\
+    \n\x05index\x18\x02\x20\x01(\rR\x05index\x12\x16\n\x06offset\x18\x03\x20\
+    \x01(\rR\x06offset\x12\x1a\n\x08expected\x18\x04\x20\x01(\x0cR\x08expect\
+    ed\x12\x16\n\x06actual\x18\x05\x20\x01(\x0cR\x06actual\x12(\n\x10expecte\
+    d_time_us\x18\x06\x20\x01(\x04R\x0eexpectedTimeUs\x12$\n\x0eactual_time_\
+    us\x18\x07\x20\x01(\x04R\x0cactualTimeUs\":\n\x04Kind\x12\x0b\n\x07CHANG\
+    ED\x10\0\x12\x0b\n\x07MISSING\x10\x01\x12\t\n\x05EXTRA\x10\x02\x12\r\n\t\
+    TIMESTAMP\x10\x03\"\xde\x01\n\x11CaptureComparison\x12\x12\n\x04name\x18\
+    \x01\x20\x01(\tR\x04name\x12\x16\n\x06passed\x18\x02\x20\x01(\x08R\x06pa\
+    ssed\x12\x18\n\x07message\x18\x03\x20\x01(\tR\x07message\x12)\n\x10expec\
+    ted_records\x18\x04\x20\x01(\rR\x0fexpectedRecords\x12%\n\x0eactual_reco\
+    rds\x18\x05\x20\x01(\rR\ractualRecords\x121\n\x05diffs\x18\x06\x20\x03(\
+    \x0b2\x1b.netsim.frontend.RecordDiffR\x05diffs\"v\n\x0fAssertionResult\
+    \x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06passed\x18\
+    \x02\x20\x01(\x08R\x06passed\x12\x18\n\x07message\x18\x03\x20\x01(\tR\
+    \x07message\x12\x1d\n\nelapsed_ms\x18\x04\x20\x01(\rR\telapsedMs\"\xbd\
+    \x01\n\x13RunScenarioResponse\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04n\
+    ame\x12\x16\n\x06passed\x18\x02\x20\x01(\x08R\x06passed\x12:\n\x07result\
+    s\x18\x03\x20\x03(\x0b2\x20.netsim.frontend.AssertionResultR\x07results\
+    \x12>\n\x08captures\x18\x04\x20\x03(\x0b2\".netsim.frontend.CaptureCompa\
+    risonR\x08captures\"\xb0\x01\n\x13ControlClockRequest\x12C\n\x06action\
+    \x18\x01\x20\x01(\x0e2+.netsim.frontend.ControlClockRequest.ActionR\x06a\
+    ction\x12\x1d\n\nadvance_ms\x18\x02\x20\x01(\x04R\tadvanceMs\"5\n\x06Act\
+    ion\x12\x07\n\x03GET\x10\0\x12\t\n\x05PAUSE\x10\x01\x12\n\n\x06RESUME\
+    \x10\x02\x12\x0b\n\x07ADVANCE\x10\x03\"\\\n\x14ControlClockResponse\x12,\
+    \n\x03now\x18\x01\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\x03now\
+    \x12\x16\n\x06paused\x18\x02\x20\x01(\x08R\x06paused\"\xa7\x01\n\x15Cont\
+    rolSessionRequest\x12E\n\x06action\x18\x01\x20\x01(\x0e2-.netsim.fronten\
+    d.ControlSessionRequest.ActionR\x06action\x12\x12\n\x04path\x18\x02\x20\
+    \x01(\tR\x04path\"3\n\x06Action\x12\x07\n\x03GET\x10\0\x12\n\n\x06RECORD\
+    \x10\x01\x12\x08\n\x04STOP\x10\x02\x12\n\n\x06REPLAY\x10\x03\"\x9c\x01\n\
+    \x16ControlSessionResponse\x12\x1c\n\trecording\x18\x01\x20\x01(\x08R\tr\
+    ecording\x12\x12\n\x04path\x18\x02\x20\x01(\tR\x04path\x12\x18\n\x07reco\
+    rds\x18\x03\x20\x01(\x04R\x07records\x12\x1a\n\x08replayed\x18\x04\x20\
+    \x01(\x04R\x08replayed\x12\x1a\n\x08warnings\x18\x05\x20\x03(\tR\x08warn\
+    ings\"\xe6\x08\n\rSessionRecord\x12\x17\n\x07time_us\x18\x01\x20\x01(\
+    \x04R\x06timeUs\x12?\n\x06header\x18\x02\x20\x01(\x0b2%.netsim.frontend.\
+    SessionRecord.HeaderH\0R\x06header\x12I\n\nchip_added\x18\x03\x20\x01(\
+    \x0b2(.netsim.frontend.SessionRecord.ChipAddedH\0R\tchipAdded\x12O\n\x0c\
+    chip_removed\x18\x04\x20\x01(\x0b2*.netsim.frontend.SessionRecord.ChipRe\
+    movedH\0R\x0bchipRemoved\x12=\n\x0edevice_patched\x18\x05\x20\x01(\x0b2\
+    \x14.netsim.model.DeviceH\0R\rdevicePatched\x12.\n\x05reset\x18\x06\x20\
+    \x01(\x0b2\x16.google.protobuf.EmptyH\0R\x05reset\x12Q\n\x14chip_kind_re\
+    gistered\x18\x07\x20\x01(\x0b2\x1d.netsim.frontend.ChipKindInfoH\0R\x12c\
+    hipKindRegistered\x12?\n\x06packet\x18\x08\x20\x01(\x0b2%.netsim.fronten\
+    d.SessionRecord.PacketH\0R\x06packet\x1a\xc4\x01\n\x06Header\x12\x18\n\
+    \x07version\x18\x01\x20\x01(\tR\x07version\x12\x12\n\x04seed\x18\x02\x20\
+    \x01(\x04R\x04seed\x12O\n\x08settings\x18\x03\x20\x03(\x0b23.netsim.fron\
+    tend.SessionRecord.Header.SettingsEntryR\x08settings\x1a;\n\rSettingsEnt\
+    ry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\
+    \x02\x20\x01(\tR\x05value:\x028\x01\x1a\xee\x01\n\tChipAdded\x12\x12\n\
+    \x04guid\x18\x01\x20\x01(\tR\x04guid\x12\x1f\n\x0bdevice_name\x18\x02\
+    \x20\x01(\tR\ndeviceName\x12\x12\n\x04kind\x18\x03\x20\x01(\rR\x04kind\
+    \x12\x1b\n\tchip_name\x18\x04\x20\x01(\tR\x08chipName\x12\"\n\x0cmanufac\
+    turer\x18\x05\x20\x01(\tR\x0cmanufacturer\x12!\n\x0cproduct_name\x18\x06\
+    \x20\x01(\tR\x0bproductName\x12\x17\n\x07chip_id\x18\x07\x20\x01(\rR\x06\
+    chipId\x12\x1b\n\tfacade_id\x18\x08\x20\x01(\rR\x08facadeId\x1a&\n\x0bCh\
+    ipRemoved\x12\x17\n\x07chip_id\x18\x01\x20\x01(\rR\x06chipId\x1ar\n\x06P\
+    acket\x12\x12\n\x04kind\x18\x01\x20\x01(\rR\x04kind\x12\x1b\n\tfacade_id\
+    \x18\x02\x20\x01(\rR\x08facadeId\x12\x1f\n\x0bpacket_type\x18\x03\x20\
+    \x01(\rR\npacketType\x12\x16\n\x06packet\x18\x04\x20\x01(\x0cR\x06packet\
+    B\x08\n\x06record\"\xcd\x01\n\x16ReplayFuzzInputRequest\x12F\n\x06target\
+    \x18\x01\x20\x01(\x0e2..netsim.frontend.ReplayFuzzInputRequest.TargetR\
+    \x06target\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x14\n\x05in\
+    put\x18\x03\x20\x01(\x0cR\x05input\"A\n\x06Target\x12\x06\n\x02H4\x10\0\
+    \x12\x07\n\x03UCI\x10\x01\x12\x08\n\x04PCAP\x10\x02\x12\x10\n\x0cHTTP_RE\
+    QUEST\x10\x03\x12\n\n\x06FILTER\x10\x04\"y\n\x17ReplayFuzzInputResponse\
+    \x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x1a\n\x08accepted\x18\
+    \x02\x20\x01(\x08R\x08accepted\x12\x18\n\x07crashed\x18\x03\x20\x01(\x08\
+    R\x07crashed\x12\x14\n\x05error\x18\x04\x20\x01(\tR\x05error\"\x8b\x03\n\
+    \x12ChipTransportStats\x12\x1f\n\x0bdevice_name\x18\x01\x20\x01(\tR\ndev\
+    iceName\x12\x17\n\x07chip_id\x18\x02\x20\x01(\x05R\x06chipId\x124\n\tchi\
+    p_kind\x18\x03\x20\x01(\x0e2\x17.netsim.common.ChipKindR\x08chipKind\x12\
+    \x1d\n\npackets_in\x18\x04\x20\x01(\x04R\tpacketsIn\x12\x19\n\x08bytes_i\
+    n\x18\x05\x20\x01(\x04R\x07bytesIn\x12\x1f\n\x0bpackets_out\x18\x06\x20\
+    \x01(\x04R\npacketsOut\x12\x1b\n\tbytes_out\x18\x07\x20\x01(\x04R\x08byt\
+    esOut\x12+\n\x11malformed_packets\x18\x08\x20\x01(\x04R\x10malformedPack\
+    ets\x12\x1f\n\x0bqueue_depth\x18\t\x20\x01(\x04R\nqueueDepth\x12?\n\rlas\
+    t_activity\x18\n\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\x0clastAct\
+    ivity\"Z\n\x1dGetChipTransportStatsResponse\x129\n\x05chips\x18\x01\x20\
+    \x03(\x0b2#.netsim.frontend.ChipTransportStatsR\x05chips\"\xd9\x01\n\x08\
+    Capacity\x12>\n\x08resource\x18\x01\x20\x01(\x0e2\".netsim.frontend.Capa\
+    city.ResourceR\x08resource\x12\x12\n\x04used\x18\x02\x20\x01(\x04R\x04us\
+    ed\x12\x14\n\x05limit\x18\x03\x20\x01(\x04R\x05limit\"c\n\x08Resource\
+    \x12\x0f\n\x0bUNSPECIFIED\x10\0\x12\x0b\n\x07DEVICES\x10\x01\x12\t\n\x05\
+    CHIPS\x10\x02\x12\x0c\n\x08CAPTURES\x10\x03\x12\x08\n\x04DISK\x10\x04\
+    \x12\n\n\x06MEMORY\x10\x05\x12\n\n\x06EVENTS\x10\x06\"l\n\x13GetCapacity\
+    Response\x127\n\tresources\x18\x01\x20\x03(\x0b2\x19.netsim.frontend.Cap\
+    acityR\tresources\x12\x1c\n\tavailable\x18\x02\x20\x01(\x08R\tavailable\
+    \"\x84\x02\n\x08ApiStats\x12\x10\n\x03api\x18\x01\x20\x01(\tR\x03api\x12\
+    \x14\n\x05calls\x18\x02\x20\x01(\x04R\x05calls\x12\x16\n\x06errors\x18\
+    \x03\x20\x01(\x04R\x06errors\x12\x1c\n\tcancelled\x18\x08\x20\x01(\x04R\
+    \tcancelled\x12(\n\x10total_latency_us\x18\x04\x20\x01(\x04R\x0etotalLat\
+    encyUs\x12$\n\x0emax_latency_us\x18\x05\x20\x01(\x04R\x0cmaxLatencyUs\
+    \x12$\n\x0ep50_latency_us\x18\x06\x20\x01(\x04R\x0cp50LatencyUs\x12$\n\
+    \x0ep99_latency_us\x18\x07\x20\x01(\x04R\x0cp99LatencyUs\"D\n\x13GetApiS\
+    tatsResponse\x12-\n\x04apis\x18\x01\x20\x03(\x0b2\x19.netsim.frontend.Ap\
+    iStatsR\x04apis\"\x97\x01\n\x0eGetLinkRequest\x12\x19\n\x08device_a\x18\
+    \x01\x20\x01(\tR\x07deviceA\x12\x19\n\x08device_b\x18\x02\x20\x01(\tR\
+    \x07deviceB\x124\n\tchip_kind\x18\x03\x20\x01(\x0e2\x17.netsim.common.Ch\
+    ipKindR\x08chipKind\x12\x19\n\x08tx_power\x18\x04\x20\x01(\x05R\x07txPow\
+    er\"\xee\x01\n\x0eLinkImpairment\x128\n\x04kind\x18\x01\x20\x01(\x0e2$.n\
+    etsim.frontend.LinkImpairment.KindR\x04kind\x12\x1f\n\x0bdevice_name\x18\
+    \x02\x20\x01(\tR\ndeviceName\x12\x20\n\x0bdescription\x18\x03\x20\x01(\t\
+    R\x0bdescription\"_\n\x04Kind\x12\x0f\n\x0bUNSPECIFIED\x10\0\x12\r\n\tRA\
+    DIO_OFF\x10\x01\x12\x14\n\x10PACKET_PROCESSOR\x10\x02\x12\x0f\n\x0bPACKE\
+    T_DROP\x10\x03\x12\x10\n\x0cPACKET_DELAY\x10\x04\"\xd4\x01\n\x0fGetLinkR\
+    esponse\x12\x1a\n\x08distance\x18\x01\x20\x01(\x02R\x08distance\x12\x1b\
+    \n\tpath_loss\x18\x02\x20\x01(\x02R\x08pathLoss\x12\x12\n\x04rssi\x18\
+    \x03\x20\x01(\x05R\x04rssi\x12A\n\x0bimpairments\x18\x04\x20\x03(\x0b2\
+    \x1f.netsim.frontend.LinkImpairmentR\x0bimpairments\x121\n\x14delivery_p\
+    robability\x18\x05\x20\x01(\x02R\x13deliveryProbability\"\xd3\x01\n\x11L\
+    istEventsRequest\x121\n\x05types\x18\x01\x20\x03(\x0e2\x1b.netsim.fronte\
+    nd.Event.TypeR\x05types\x12\x1f\n\x0bdevice_name\x18\x02\x20\x01(\tR\nde\
+    viceName\x12\x19\n\x08since_id\x18\x03\x20\x01(\x04R\x07sinceId\x129\n\n\
+    since_time\x18\x04\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\tsinceTi\
+    me\x12\x14\n\x05limit\x18\x05\x20\x01(\rR\x05limit\"\x87\x01\n\x16Subscr\
+    ibeEventsRequest\x121\n\x05types\x18\x01\x20\x03(\x0e2\x1b.netsim.fronte\
+    nd.Event.TypeR\x05types\x12\x1f\n\x0bdevice_name\x18\x02\x20\x01(\tR\nde\
+    viceName\x12\x19\n\x08since_id\x18\x03\x20\x01(\x04R\x07sinceId\"{\n\x12\
+    ListEventsResponse\x12.\n\x06events\x18\x01\x20\x03(\x0b2\x16.netsim.fro\
+    ntend.EventR\x06events\x12\x1c\n\ttruncated\x18\x02\x20\x01(\x08R\ttrunc\
+    ated\x12\x17\n\x07last_id\x18\x03\x20\x01(\x04R\x06lastId\"`\n\rErrorRes\
+    ponse\x12\x12\n\x04code\x18\x01\x20\x01(\x05R\x04code\x12#\n\rerror_mess\
+    age\x18\x02\x20\x01(\tR\x0cerrorMessage\x12\x16\n\x06status\x18\x03\x20\
+    \x01(\tR\x06status\"C\n\x17CreateBugReportResponse\x12\x12\n\x04path\x18\
+    \x01\x20\x01(\tR\x04path\x12\x14\n\x05files\x18\x02\x20\x03(\tR\x05files\
+    \"\xd7\x01\n\x0cChipKindInfo\x12\x0e\n\x02id\x18\x01\x20\x01(\rR\x02id\
+    \x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x1a\n\x08linktype\x18\
+    \x03\x20\x01(\rR\x08linktype\x12?\n\x07routing\x18\x04\x20\x01(\x0e2%.ne\
+    tsim.frontend.ChipKindInfo.RoutingR\x07routing\x12\x18\n\x07builtin\x18\
+    \x05\x20\x01(\x08R\x07builtin\",\n\x07Routing\x12\r\n\tBROADCAST\x10\0\
+    \x12\x12\n\x0ePOINT_TO_POINT\x10\x01\"L\n\x15ListChipKindsResponse\x123\
+    \n\x05kinds\x18\x01\x20\x03(\x0b2\x1d.netsim.frontend.ChipKindInfoR\x05k\
+    inds\"/\n\x13LinkInstanceRequest\x12\x18\n\x07address\x18\x01\x20\x01(\t\
+    R\x07address\"'\n\x15UnlinkInstanceRequest\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\rR\x02id\"\xf5\x01\n\x05Trunk\x12\x0e\n\x02id\x18\x01\x20\x01(\rR\
+    \x02id\x12\x18\n\x07address\x18\x02\x20\x01(\tR\x07address\x12\x12\n\x04\
+    peer\x18\x03\x20\x01(\tR\x04peer\x12/\n\x04role\x18\x04\x20\x01(\x0e2\
+    \x1b.netsim.frontend.Trunk.RoleR\x04role\x12\x14\n\x05chips\x18\x05\x20\
+    \x01(\rR\x05chips\x12!\n\x0cpackets_sent\x18\x06\x20\x01(\x04R\x0bpacket\
+    sSent\x12)\n\x10packets_received\x18\x07\x20\x01(\x04R\x0fpacketsReceive\
+    d\"\x19\n\x04Role\x12\x08\n\x04LEAF\x10\0\x12\x07\n\x03HUB\x10\x01\"D\n\
+    \x12ListTrunksResponse\x12.\n\x06trunks\x18\x01\x20\x03(\x0b2\x16.netsim\
+    .frontend.TrunkR\x06trunks\"\xd2\x05\n\x0cTrunkMessage\x12;\n\x05hello\
+    \x18\x01\x20\x01(\x0b2#.netsim.frontend.TrunkMessage.HelloH\0R\x05hello\
+    \x12B\n\x08add_chip\x18\x02\x20\x01(\x0b2%.netsim.frontend.TrunkMessage.\
+    AddChipH\0R\x07addChip\x12!\n\x0bremove_chip\x18\x03\x20\x01(\rH\0R\nrem\
+    oveChip\x12>\n\x06packet\x18\x04\x20\x01(\x0b2$.netsim.frontend.TrunkMes\
+    sage.PacketH\0R\x06packet\x127\n\x0bmove_device\x18\x05\x20\x01(\x0b2\
+    \x14.netsim.model.DeviceH\0R\nmoveDevice\x1ag\n\x05Hello\x12\x12\n\x04na\
+    me\x18\x01\x20\x01(\tR\x04name\x12\x18\n\x07version\x18\x02\x20\x01(\tR\
+    \x07version\x12\x14\n\x05nonce\x18\x04\x20\x01(\x0cR\x05nonce\x12\x14\n\
+    \x05proof\x18\x05\x20\x01(\x0cR\x05proofJ\x04\x08\x03\x10\x04\x1a\xd4\
+    \x01\n\x07AddChip\x12\x17\n\x07chip_id\x18\x01\x20\x01(\rR\x06chipId\x12\
+    \x1f\n\x0bdevice_name\x18\x02\x20\x01(\tR\ndeviceName\x12+\n\x04kind\x18\
+    \x03\x20\x01(\x0e2\x17.netsim.common.ChipKindR\x04kind\x12\x1b\n\tchip_n\
+    ame\x18\x04\x20\x01(\tR\x08chipName\x12\"\n\x0cmanufacturer\x18\x05\x20\
+    \x01(\tR\x0cmanufacturer\x12!\n\x0cproduct_name\x18\x06\x20\x01(\tR\x0bp\
+    roductName\x1aZ\n\x06Packet\x12\x17\n\x07chip_id\x18\x01\x20\x01(\rR\x06\
+    chipId\x12\x1f\n\x0bpacket_type\x18\x02\x20\x01(\rR\npacketType\x12\x16\
+    \n\x06packet\x18\x03\x20\x01(\x0cR\x06packetB\t\n\x07message\"\xa7\x03\n\
+    \nBreakpoint\x12\x0e\n\x02id\x18\x01\x20\x01(\rR\x02id\x12\x17\n\x07chip\
+    _id\x18\x02\x20\x01(\x05R\x06chipId\x124\n\tchip_kind\x18\x03\x20\x01(\
+    \x0e2\x17.netsim.common.ChipKindR\x08chipKind\x12C\n\tdirection\x18\x04\
+    \x20\x01(\x0e2%.netsim.frontend.Breakpoint.DirectionR\tdirection\x12\x18\
+    \n\x07pattern\x18\x05\x20\x01(\x0cR\x07pattern\x12\x12\n\x04mask\x18\x06\
+    \x20\x01(\x0cR\x04mask\x12\x16\n\x06offset\x18\x07\x20\x01(\rR\x06offset\
+    \x127\n\x05scope\x18\x08\x20\x01(\x0e2!.netsim.frontend.Breakpoint.Scope\
+    R\x05scope\x12\x12\n\x04hits\x18\t\x20\x01(\rR\x04hits\"D\n\tDirection\
+    \x12\x07\n\x03ANY\x10\0\x12\x16\n\x12HOST_TO_CONTROLLER\x10\x01\x12\x16\
+    \n\x12CONTROLLER_TO_HOST\x10\x02\"\x1c\n\x05Scope\x12\x08\n\x04CHIP\x10\
+    \0\x12\t\n\x05SCENE\x10\x01\"X\n\x17ListBreakpointsResponse\x12=\n\x0bbr\
+    eakpoints\x18\x01\x20\x03(\x0b2\x1b.netsim.frontend.BreakpointR\x0bbreak\
+    points\")\n\x17DeleteBreakpointRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\
+    \rR\x02id\"\x81\x01\n\rDeliveryState\x12\x16\n\x06paused\x18\x01\x20\x01\
+    (\x08R\x06paused\x12&\n\x0fpaused_chip_ids\x18\x02\x20\x03(\x05R\rpaused\
+    ChipIds\x12\x12\n\x04held\x18\x03\x20\x01(\rR\x04held\x12\x1c\n\tdeliver\
+    ed\x18\x04\x20\x01(\rR\tdelivered\"4\n\x08LogLevel\x12\x14\n\x05level\
+    \x18\x01\x20\x01(\tR\x05level\x12\x12\n\x04json\x18\x02\x20\x01(\x08R\
+    \x04json\"\xea\x01\n\x0bPropagation\x12\x12\n\x04name\x18\x01\x20\x01(\t\
+    R\x04name\x12\x1a\n\x08exponent\x18\x02\x20\x01(\x02R\x08exponent\x127\n\
+    \x05walls\x18\x03\x20\x03(\x0b2!.netsim.frontend.Propagation.WallR\x05wa\
+    lls\x1ar\n\x04Wall\x12,\n\x05start\x18\x01\x20\x01(\x0b2\x16.netsim.mode\
+    l.PositionR\x05start\x12(\n\x03end\x18\x02\x20\x01(\x0b2\x16.netsim.mode\
+    l.PositionR\x03end\x12\x12\n\x04loss\x18\x03\x20\x01(\x02R\x04loss\"+\n\
+    \x13StepDeliveryRequest\x12\x14\n\x05count\x18\x01\x20\x01(\rR\x05count\
+    \"\xdf\x01\n\x0fSceneImpairment\x12\x1f\n\x0bdevice_name\x18\x01\x20\x01\
+    (\tR\ndeviceName\x124\n\tchip_kind\x18\x02\x20\x01(\x0e2\x17.netsim.comm\
+    on.ChipKindR\x08chipKind\x12*\n\x11other_device_name\x18\x03\x20\x01(\tR\
+    \x0fotherDeviceName\x12I\n\nimpairment\x18\x04\x20\x01(\x0b2).netsim.fro\
+    ntend.SetLinkImpairmentRequestR\nimpairment\"\xcb\x01\n\x0cSceneCapture\
+    \x12\x1f\n\x0bdevice_name\x18\x01\x20\x01(\tR\ndeviceName\x124\n\tchip_k\
+    ind\x18\x02\x20\x01(\x0e2\x17.netsim.common.ChipKindR\x08chipKind\x124\n\
+    \x06format\x18\x03\x20\x01(\x0e2\x1c.netsim.model.Capture.FormatR\x06for\
+    mat\x12.\n\x04mode\x18\x04\x20\x01(\x0e2\x1a.netsim.model.Capture.ModeR\
+    \x04mode\"\xf5\x02\n\tSceneFile\x12)\n\x05scene\x18\x01\x20\x01(\x0b2\
+    \x13.netsim.model.SceneR\x05scene\x12;\n\x07beacons\x18\x02\x20\x03(\x0b\
+    2!.netsim.frontend.AdvertiseRequestR\x07beacons\x12D\n\x08settings\x18\
+    \x03\x20\x03(\x0b2(.netsim.frontend.SceneFile.SettingsEntryR\x08settings\
+    \x12B\n\x0bimpairments\x18\x04\x20\x03(\x0b2\x20.netsim.frontend.SceneIm\
+    pairmentR\x0bimpairments\x129\n\x08captures\x18\x05\x20\x03(\x0b2\x1d.ne\
+    tsim.frontend.SceneCaptureR\x08captures\x1a;\n\rSettingsEntry\x12\x10\n\
+    \x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\t\
+    R\x05value:\x028\x01\"P\n\x13LoadScenarioRequest\x129\n\nscene_file\x18\
+    \x01\x20\x01(\x0b2\x1a.netsim.frontend.SceneFileR\tsceneFile\"\xed\x01\n\
+    \x14LoadScenarioResponse\x12!\n\x0cdevice_names\x18\x01\x20\x03(\tR\x0bd\
+    eviceNames\x12\x18\n\x07beacons\x18\x02\x20\x01(\rR\x07beacons\x12\x20\n\
+    \x0bimpairments\x18\x03\x20\x01(\rR\x0bimpairments\x12/\n\x13pending_imp\
+    airments\x18\x04\x20\x01(\rR\x12pendingImpairments\x12\x1a\n\x08captures\
+    \x18\x05\x20\x01(\rR\x08captures\x12)\n\x10pending_captures\x18\x06\x20\
+    \x01(\rR\x0fpendingCaptures\"&\n\x10SaveStateRequest\x12\x12\n\x04path\
+    \x18\x01\x20\x01(\tR\x04path\"'\n\x11SaveStateResponse\x12\x12\n\x04path\
+    \x18\x01\x20\x01(\tR\x04path\")\n\x13RestoreStateRequest\x12\x12\n\x04pa\
+    th\x18\x01\x20\x01(\tR\x04path2\xe9!\n\x0fFrontendService\x12F\n\nGetVer\
+    sion\x12\x16.google.protobuf.Empty\x1a\x20.netsim.frontend.VersionRespon\
+    se\x12B\n\x0eRegisterEvents\x12\x16.google.protobuf.Empty\x1a\x16.netsim\
+    .frontend.Event0\x01\x12T\n\x0fSubscribeEvents\x12'.netsim.frontend.Subs\
+    cribeEventsRequest\x1a\x16.netsim.frontend.Event0\x01\x12U\n\nGetDevices\
+    \x12\".netsim.frontend.GetDevicesRequest\x1a#.netsim.frontend.GetDevices\
+    Response\x12N\n\x10ListDeviceStream\x12\".netsim.frontend.GetDevicesRequ\
+    est\x1a\x14.netsim.model.Device0\x01\x12J\n\x0bPatchDevice\x12#.netsim.f\
+    rontend.PatchDeviceRequest\x1a\x16.google.protobuf.Empty\x12R\n\x0fSetDe\
+    viceMotion\x12'.netsim.frontend.SetDeviceMotionRequest\x1a\x16.google.pr\
+    otobuf.Empty\x12U\n\nPatchGroup\x12\".netsim.frontend.PatchGroupRequest\
+    \x1a#.netsim.frontend.PatchGroupResponse\x12a\n\x0ePatchSelection\x12&.n\
+    etsim.frontend.PatchSelectionRequest\x1a'.netsim.frontend.PatchSelection\
+    Response\x127\n\x05Reset\x12\x16.google.protobuf.Empty\x1a\x16.google.pr\
+    otobuf.Empty\x12X\n\x0bRestartChip\x12#.netsim.frontend.RestartChipReque\
+    st\x1a$.netsim.frontend.RestartChipResponse\x12I\n\tAdvertise\x12!.netsi\
+    m.frontend.AdvertiseRequest\x1a\x17.netsim.frontend.Beacon0\x01\x12V\n\
+    \x11SetLinkImpairment\x12).netsim.frontend.SetLinkImpairmentRequest\x1a\
+    \x16.google.protobuf.Empty\x12[\n\x0cLoadScenario\x12$.netsim.frontend.L\
+    oadScenarioRequest\x1a%.netsim.frontend.LoadScenarioResponse\x12R\n\tSav\
+    eState\x12!.netsim.frontend.SaveStateRequest\x1a\".netsim.frontend.SaveS\
+    tateResponse\x12[\n\x0cRestoreState\x12$.netsim.frontend.RestoreStateReq\
+    uest\x1a%.netsim.frontend.LoadScenarioResponse\x12J\n\x0bSetLinkLoss\x12\
+    #.netsim.frontend.SetLinkLossRequest\x1a\x16.google.protobuf.Empty\x12A\
+    \n\x06NetCat\x12\x16.google.protobuf.Empty\x1a\x1d.netsim.frontend.NetCa\
+    tStream0\x01\x12L\n\x0cPatchCapture\x12$.netsim.frontend.PatchCaptureReq\
+    uest\x1a\x16.google.protobuf.Empty\x12X\n\x0bListCapture\x12#.netsim.fro\
+    ntend.ListCaptureRequest\x1a$.netsim.frontend.ListCaptureResponse\x12Q\n\
+    \x11ListCaptureStream\x12#.netsim.frontend.ListCaptureRequest\x1a\x15.ne\
+    tsim.model.Capture0\x01\x12W\n\nGetCapture\x12\".netsim.frontend.GetCapt\
+    ureRequest\x1a#.netsim.frontend.GetCaptureResponse0\x01\x12]\n\rStreamCa\
+    pture\x12%.netsim.frontend.StreamCaptureRequest\x1a#.netsim.frontend.Get\
+    CaptureResponse0\x01\x12N\n\rDeleteCapture\x12%.netsim.frontend.DeleteCa\
+    ptureRequest\x1a\x16.google.protobuf.Empty\x12D\n\rSetCaptureAll\x12\x1b\
+    .netsim.frontend.CaptureAll\x1a\x16.google.protobuf.Empty\x12]\n\x15Patc\
+    hAggregateCapture\x12!.netsim.frontend.AggregateCapture\x1a!.netsim.fron\
+    tend.AggregateCapture\x12S\n\x0fGetCaptureStats\x12\x16.google.protobuf.\
+    Empty\x1a(.netsim.frontend.GetCaptureStatsResponse\x12^\n\rReplayCapture\
+    \x12%.netsim.frontend.ReplayCaptureRequest\x1a&.netsim.frontend.ReplayCa\
+    ptureResponse\x12X\n\x0bRunScenario\x12#.netsim.frontend.RunScenarioRequ\
+    est\x1a$.netsim.frontend.RunScenarioResponse\x12[\n\x0cControlClock\x12$\
+    .netsim.frontend.ControlClockRequest\x1a%.netsim.frontend.ControlClockRe\
+    sponse\x12a\n\x0eControlSession\x12&.netsim.frontend.ControlSessionReque\
+    st\x1a'.netsim.frontend.ControlSessionResponse\x12d\n\x0fReplayFuzzInput\
+    \x12'.netsim.frontend.ReplayFuzzInputRequest\x1a(.netsim.frontend.Replay\
+    FuzzInputResponse\x12_\n\x15GetChipTransportStats\x12\x16.google.protobu\
+    f.Empty\x1a..netsim.frontend.GetChipTransportStatsResponse\x12K\n\x0bGet\
+    Capacity\x12\x16.google.protobuf.Empty\x1a$.netsim.frontend.GetCapacityR\
+    esponse\x12K\n\x0bGetApiStats\x12\x16.google.protobuf.Empty\x1a$.netsim.\
+    frontend.GetApiStatsResponse\x12L\n\x07GetLink\x12\x1f.netsim.frontend.G\
+    etLinkRequest\x1a\x20.netsim.frontend.GetLinkResponse\x12U\n\nListEvents\
+    \x12\".netsim.frontend.ListEventsRequest\x1a#.netsim.frontend.ListEvents\
+    Response\x12S\n\x0fCreateBugReport\x12\x16.google.protobuf.Empty\x1a(.ne\
+    tsim.frontend.CreateBugReportResponse\x12P\n\x10RegisterChipKind\x12\x1d\
+    .netsim.frontend.ChipKindInfo\x1a\x1d.netsim.frontend.ChipKindInfo\x12O\
+    \n\rListChipKinds\x12\x16.google.protobuf.Empty\x1a&.netsim.frontend.Lis\
+    tChipKindsResponse\x12L\n\x0cLinkInstance\x12$.netsim.frontend.LinkInsta\
+    nceRequest\x1a\x16.netsim.frontend.Trunk\x12I\n\nListTrunks\x12\x16.goog\
+    le.protobuf.Empty\x1a#.netsim.frontend.ListTrunksResponse\x12P\n\x0eUnli\
+    nkInstance\x12&.netsim.frontend.UnlinkInstanceRequest\x1a\x16.google.pro\
+    tobuf.Empty\x12I\n\rSetBreakpoint\x12\x1b.netsim.frontend.Breakpoint\x1a\
+    \x1b.netsim.frontend.Breakpoint\x12S\n\x0fListBreakpoints\x12\x16.google\
+    .protobuf.Empty\x1a(.netsim.frontend.ListBreakpointsResponse\x12T\n\x10D\
+    eleteBreakpoint\x12(.netsim.frontend.DeleteBreakpointRequest\x1a\x16.goo\
+    gle.protobuf.Empty\x12H\n\x0eResumeDelivery\x12\x16.google.protobuf.Empt\
+    y\x1a\x1e.netsim.frontend.DeliveryState\x12G\n\rPauseDelivery\x12\x16.go\
+    ogle.protobuf.Empty\x1a\x1e.netsim.frontend.DeliveryState\x12T\n\x0cStep\
+    Delivery\x12$.netsim.frontend.StepDeliveryRequest\x1a\x1e.netsim.fronten\
+    d.DeliveryState\x12J\n\x10GetDeliveryState\x12\x16.google.protobuf.Empty\
+    \x1a\x1e.netsim.frontend.DeliveryState\x12E\n\rPatchLogLevel\x12\x19.net\
+    sim.frontend.LogLevel\x1a\x19.netsim.frontend.LogLevel\x12N\n\x10PatchPr\
+    opagation\x12\x1c.netsim.frontend.Propagation\x1a\x1c.netsim.frontend.Pr\
+    opagation2\x7f\n\x17PropagationModelService\x12d\n\x0fComputePathLoss\
+    \x12'.netsim.frontend.ComputePathLossRequest\x1a(.netsim.frontend.Comput\
+    ePathLossResponseb\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -1799,24 +19038,126 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
-            let mut deps = ::std::vec::Vec::with_capacity(2);
+            let mut deps = ::std::vec::Vec::with_capacity(5);
             deps.push(::protobuf::well_known_types::empty::file_descriptor().clone());
+            deps.push(::protobuf::well_known_types::field_mask::file_descriptor().clone());
+            deps.push(::protobuf::well_known_types::timestamp::file_descriptor().clone());
+            deps.push(super::common::file_descriptor().clone());
             deps.push(super::model::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(13);
+            let mut messages = ::std::vec::Vec::with_capacity(100);
+            messages.push(ComputePathLossRequest::generated_message_descriptor_data());
+            messages.push(ComputePathLossResponse::generated_message_descriptor_data());
             messages.push(VersionResponse::generated_message_descriptor_data());
+            messages.push(SetDeviceMotionRequest::generated_message_descriptor_data());
             messages.push(SetLinkLossRequest::generated_message_descriptor_data());
+            messages.push(SetLinkImpairmentRequest::generated_message_descriptor_data());
             messages.push(PatchDeviceRequest::generated_message_descriptor_data());
+            messages.push(PatchGroupRequest::generated_message_descriptor_data());
+            messages.push(PatchGroupResponse::generated_message_descriptor_data());
+            messages.push(Selector::generated_message_descriptor_data());
+            messages.push(PatchSelectionRequest::generated_message_descriptor_data());
+            messages.push(PatchSelectionResponse::generated_message_descriptor_data());
+            messages.push(RestartChipRequest::generated_message_descriptor_data());
+            messages.push(RestartChipResponse::generated_message_descriptor_data());
+            messages.push(AdvertiseRequest::generated_message_descriptor_data());
+            messages.push(Beacon::generated_message_descriptor_data());
+            messages.push(ListBeaconsResponse::generated_message_descriptor_data());
             messages.push(Event::generated_message_descriptor_data());
+            messages.push(GetDevicesRequest::generated_message_descriptor_data());
             messages.push(GetDevicesResponse::generated_message_descriptor_data());
             messages.push(NetCatStream::generated_message_descriptor_data());
             messages.push(SetPacketCaptureRequest::generated_message_descriptor_data());
             messages.push(PatchCaptureRequest::generated_message_descriptor_data());
+            messages.push(ListCaptureRequest::generated_message_descriptor_data());
             messages.push(ListCaptureResponse::generated_message_descriptor_data());
             messages.push(GetCaptureRequest::generated_message_descriptor_data());
             messages.push(GetCaptureResponse::generated_message_descriptor_data());
+            messages.push(StreamCaptureRequest::generated_message_descriptor_data());
+            messages.push(CaptureAll::generated_message_descriptor_data());
+            messages.push(AggregateCapture::generated_message_descriptor_data());
+            messages.push(CaptureStats::generated_message_descriptor_data());
+            messages.push(GetCaptureStatsResponse::generated_message_descriptor_data());
+            messages.push(ReplayCaptureRequest::generated_message_descriptor_data());
+            messages.push(ReplayCaptureResponse::generated_message_descriptor_data());
+            messages.push(DeleteCaptureRequest::generated_message_descriptor_data());
+            messages.push(ScenarioAssertion::generated_message_descriptor_data());
+            messages.push(RunScenarioRequest::generated_message_descriptor_data());
+            messages.push(CaptureNormalization::generated_message_descriptor_data());
+            messages.push(GoldenCapture::generated_message_descriptor_data());
+            messages.push(RecordDiff::generated_message_descriptor_data());
+            messages.push(CaptureComparison::generated_message_descriptor_data());
+            messages.push(AssertionResult::generated_message_descriptor_data());
+            messages.push(RunScenarioResponse::generated_message_descriptor_data());
+            messages.push(ControlClockRequest::generated_message_descriptor_data());
+            messages.push(ControlClockResponse::generated_message_descriptor_data());
+            messages.push(ControlSessionRequest::generated_message_descriptor_data());
+            messages.push(ControlSessionResponse::generated_message_descriptor_data());
+            messages.push(SessionRecord::generated_message_descriptor_data());
+            messages.push(ReplayFuzzInputRequest::generated_message_descriptor_data());
+            messages.push(ReplayFuzzInputResponse::generated_message_descriptor_data());
+            messages.push(ChipTransportStats::generated_message_descriptor_data());
+            messages.push(GetChipTransportStatsResponse::generated_message_descriptor_data());
+            messages.push(Capacity::generated_message_descriptor_data());
+            messages.push(GetCapacityResponse::generated_message_descriptor_data());
+            messages.push(ApiStats::generated_message_descriptor_data());
+            messages.push(GetApiStatsResponse::generated_message_descriptor_data());
+            messages.push(GetLinkRequest::generated_message_descriptor_data());
+            messages.push(LinkImpairment::generated_message_descriptor_data());
+            messages.push(GetLinkResponse::generated_message_descriptor_data());
+            messages.push(ListEventsRequest::generated_message_descriptor_data());
+            messages.push(SubscribeEventsRequest::generated_message_descriptor_data());
+            messages.push(ListEventsResponse::generated_message_descriptor_data());
             messages.push(ErrorResponse::generated_message_descriptor_data());
+            messages.push(CreateBugReportResponse::generated_message_descriptor_data());
+            messages.push(ChipKindInfo::generated_message_descriptor_data());
+            messages.push(ListChipKindsResponse::generated_message_descriptor_data());
+            messages.push(LinkInstanceRequest::generated_message_descriptor_data());
+            messages.push(UnlinkInstanceRequest::generated_message_descriptor_data());
+            messages.push(Trunk::generated_message_descriptor_data());
+            messages.push(ListTrunksResponse::generated_message_descriptor_data());
+            messages.push(TrunkMessage::generated_message_descriptor_data());
+            messages.push(Breakpoint::generated_message_descriptor_data());
+            messages.push(ListBreakpointsResponse::generated_message_descriptor_data());
+            messages.push(DeleteBreakpointRequest::generated_message_descriptor_data());
+            messages.push(DeliveryState::generated_message_descriptor_data());
+            messages.push(LogLevel::generated_message_descriptor_data());
+            messages.push(Propagation::generated_message_descriptor_data());
+            messages.push(StepDeliveryRequest::generated_message_descriptor_data());
+            messages.push(SceneImpairment::generated_message_descriptor_data());
+            messages.push(SceneCapture::generated_message_descriptor_data());
+            messages.push(SceneFile::generated_message_descriptor_data());
+            messages.push(LoadScenarioRequest::generated_message_descriptor_data());
+            messages.push(LoadScenarioResponse::generated_message_descriptor_data());
+            messages.push(SaveStateRequest::generated_message_descriptor_data());
+            messages.push(SaveStateResponse::generated_message_descriptor_data());
+            messages.push(RestoreStateRequest::generated_message_descriptor_data());
+            messages.push(patch_selection_response::Result::generated_message_descriptor_data());
             messages.push(patch_capture_request::PatchCapture::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(0);
+            messages.push(scenario_assertion::PacketSeen::generated_message_descriptor_data());
+            messages.push(scenario_assertion::DeviceState::generated_message_descriptor_data());
+            messages.push(scenario_assertion::CounterExceeded::generated_message_descriptor_data());
+            messages.push(capture_normalization::Mask::generated_message_descriptor_data());
+            messages.push(session_record::Header::generated_message_descriptor_data());
+            messages.push(session_record::ChipAdded::generated_message_descriptor_data());
+            messages.push(session_record::ChipRemoved::generated_message_descriptor_data());
+            messages.push(session_record::Packet::generated_message_descriptor_data());
+            messages.push(trunk_message::Hello::generated_message_descriptor_data());
+            messages.push(trunk_message::AddChip::generated_message_descriptor_data());
+            messages.push(trunk_message::Packet::generated_message_descriptor_data());
+            messages.push(propagation::Wall::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(12);
+            enums.push(set_link_impairment_request::JitterDistribution::generated_enum_descriptor_data());
+            enums.push(event::Type::generated_enum_descriptor_data());
+            enums.push(record_diff::Kind::generated_enum_descriptor_data());
+            enums.push(control_clock_request::Action::generated_enum_descriptor_data());
+            enums.push(control_session_request::Action::generated_enum_descriptor_data());
+            enums.push(replay_fuzz_input_request::Target::generated_enum_descriptor_data());
+            enums.push(capacity::Resource::generated_enum_descriptor_data());
+            enums.push(link_impairment::Kind::generated_enum_descriptor_data());
+            enums.push(chip_kind_info::Routing::generated_enum_descriptor_data());
+            enums.push(trunk::Role::generated_enum_descriptor_data());
+            enums.push(breakpoint::Direction::generated_enum_descriptor_data());
+            enums.push(breakpoint::Scope::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
@@ -15,6 +15,9 @@
 
 //! frontend protobuf library for netsim
 
+// The generated files allow lints that newer toolchains have removed.
+#![allow(renamed_and_removed_lints)]
+
 pub mod common;
 pub mod frontend;
 pub mod model;
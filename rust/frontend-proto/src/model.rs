@@ -357,6 +357,8 @@ pub struct Chip {
     pub product_name: ::std::string::String,
     // @@protoc_insertion_point(field:netsim.model.Chip.capture)
     pub capture: ::protobuf::EnumOrUnknown<State>,
+    // @@protoc_insertion_point(field:netsim.model.Chip.bandwidth)
+    pub bandwidth: ::protobuf::MessageField<chip::Bandwidth>,
     // message oneof groups
     pub chip: ::std::option::Option<chip::Chip>,
     // special fields
@@ -522,8 +524,57 @@ impl Chip {
         }
     }
 
+    // .netsim.model.Chip.Nfc nfc = 11;
+
+    pub fn nfc(&self) -> &chip::Nfc {
+        match self.chip {
+            ::std::option::Option::Some(chip::Chip::Nfc(ref v)) => v,
+            _ => <chip::Nfc as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_nfc(&mut self) {
+        self.chip = ::std::option::Option::None;
+    }
+
+    pub fn has_nfc(&self) -> bool {
+        match self.chip {
+            ::std::option::Option::Some(chip::Chip::Nfc(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nfc(&mut self, v: chip::Nfc) {
+        self.chip = ::std::option::Option::Some(chip::Chip::Nfc(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nfc(&mut self) -> &mut chip::Nfc {
+        if let ::std::option::Option::Some(chip::Chip::Nfc(_)) = self.chip {
+        } else {
+            self.chip = ::std::option::Option::Some(chip::Chip::Nfc(chip::Nfc::new()));
+        }
+        match self.chip {
+            ::std::option::Option::Some(chip::Chip::Nfc(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_nfc(&mut self) -> chip::Nfc {
+        if self.has_nfc() {
+            match self.chip.take() {
+                ::std::option::Option::Some(chip::Chip::Nfc(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            chip::Nfc::new()
+        }
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut fields = ::std::vec::Vec::with_capacity(11);
         let mut oneofs = ::std::vec::Vec::with_capacity(1);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "kind",
@@ -576,6 +627,18 @@ impl Chip {
             Chip::mut_wifi,
             Chip::set_wifi,
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, chip::Nfc>(
+            "nfc",
+            Chip::has_nfc,
+            Chip::nfc,
+            Chip::mut_nfc,
+            Chip::set_nfc,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, chip::Bandwidth>(
+            "bandwidth",
+            |m: &Chip| { &m.bandwidth },
+            |m: &mut Chip| { &mut m.bandwidth },
+        ));
         oneofs.push(chip::Chip::generated_oneof_descriptor_data());
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Chip>(
             "Chip",
@@ -622,6 +685,12 @@ impl ::protobuf::Message for Chip {
                 74 => {
                     self.chip = ::std::option::Option::Some(chip::Chip::Wifi(is.read_message()?));
                 },
+                90 => {
+                    self.chip = ::std::option::Option::Some(chip::Chip::Nfc(is.read_message()?));
+                },
+                82 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.bandwidth)?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -652,6 +721,10 @@ impl ::protobuf::Message for Chip {
         if self.capture != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
             my_size += ::protobuf::rt::int32_size(6, self.capture.value());
         }
+        if let Some(v) = self.bandwidth.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
         if let ::std::option::Option::Some(ref v) = self.chip {
             match v {
                 &chip::Chip::Bt(ref v) => {
@@ -666,6 +739,10 @@ impl ::protobuf::Message for Chip {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
                 },
+                &chip::Chip::Nfc(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
@@ -692,6 +769,9 @@ impl ::protobuf::Message for Chip {
         if self.capture != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
             os.write_enum(6, ::protobuf::EnumOrUnknown::value(&self.capture))?;
         }
+        if let Some(v) = self.bandwidth.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
         if let ::std::option::Option::Some(ref v) = self.chip {
             match v {
                 &chip::Chip::Bt(ref v) => {
@@ -703,6 +783,9 @@ impl ::protobuf::Message for Chip {
                 &chip::Chip::Wifi(ref v) => {
                     ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
                 },
+                &chip::Chip::Nfc(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
+                },
             };
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
@@ -731,6 +814,8 @@ impl ::protobuf::Message for Chip {
         self.chip = ::std::option::Option::None;
         self.chip = ::std::option::Option::None;
         self.chip = ::std::option::Option::None;
+        self.chip = ::std::option::Option::None;
+        self.bandwidth.clear();
         self.special_fields.clear();
     }
 
@@ -742,6 +827,7 @@ impl ::protobuf::Message for Chip {
             manufacturer: ::std::string::String::new(),
             product_name: ::std::string::String::new(),
             capture: ::protobuf::EnumOrUnknown::from_i32(0),
+            bandwidth: ::protobuf::MessageField::none(),
             chip: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -779,6 +865,8 @@ pub mod chip {
         Uwb(Radio),
         // @@protoc_insertion_point(oneof_field:netsim.model.Chip.wifi)
         Wifi(Radio),
+        // @@protoc_insertion_point(oneof_field:netsim.model.Chip.nfc)
+        Nfc(Nfc),
     }
 
     impl ::protobuf::Oneof for Chip {
@@ -1113,263 +1201,479 @@ pub mod chip {
     impl ::protobuf::reflect::ProtobufValue for Bluetooth {
         type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
     }
-}
-
-#[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.model.Device)
-pub struct Device {
-    // message fields
-    // @@protoc_insertion_point(field:netsim.model.Device.id)
-    pub id: i32,
-    // @@protoc_insertion_point(field:netsim.model.Device.name)
-    pub name: ::std::string::String,
-    // @@protoc_insertion_point(field:netsim.model.Device.visible)
-    pub visible: bool,
-    // @@protoc_insertion_point(field:netsim.model.Device.position)
-    pub position: ::protobuf::MessageField<Position>,
-    // @@protoc_insertion_point(field:netsim.model.Device.orientation)
-    pub orientation: ::protobuf::MessageField<Orientation>,
-    // @@protoc_insertion_point(field:netsim.model.Device.chips)
-    pub chips: ::std::vec::Vec<Chip>,
-    // special fields
-    // @@protoc_insertion_point(special_field:netsim.model.Device.special_fields)
-    pub special_fields: ::protobuf::SpecialFields,
-}
-
-impl<'a> ::std::default::Default for &'a Device {
-    fn default() -> &'a Device {
-        <Device as ::protobuf::Message>::default_instance()
-    }
-}
 
-impl Device {
-    pub fn new() -> Device {
-        ::std::default::Default::default()
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.model.Chip.Nfc)
+    pub struct Nfc {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.model.Chip.Nfc.radio)
+        pub radio: ::protobuf::MessageField<Radio>,
+        // @@protoc_insertion_point(field:netsim.model.Chip.Nfc.tag)
+        pub tag: ::protobuf::MessageField<nfc::Tag>,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.model.Chip.Nfc.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
     }
 
-    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(6);
-        let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "id",
-            |m: &Device| { &m.id },
-            |m: &mut Device| { &mut m.id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "name",
-            |m: &Device| { &m.name },
-            |m: &mut Device| { &mut m.name },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "visible",
-            |m: &Device| { &m.visible },
-            |m: &mut Device| { &mut m.visible },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Position>(
-            "position",
-            |m: &Device| { &m.position },
-            |m: &mut Device| { &mut m.position },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Orientation>(
-            "orientation",
-            |m: &Device| { &m.orientation },
-            |m: &mut Device| { &mut m.orientation },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "chips",
-            |m: &Device| { &m.chips },
-            |m: &mut Device| { &mut m.chips },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Device>(
-            "Device",
-            fields,
-            oneofs,
-        )
+    impl<'a> ::std::default::Default for &'a Nfc {
+        fn default() -> &'a Nfc {
+            <Nfc as ::protobuf::Message>::default_instance()
+        }
     }
-}
-
-impl ::protobuf::Message for Device {
-    const NAME: &'static str = "Device";
 
-    fn is_initialized(&self) -> bool {
-        true
-    }
+    impl Nfc {
+        pub fn new() -> Nfc {
+            ::std::default::Default::default()
+        }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
-        while let Some(tag) = is.read_raw_tag_or_eof()? {
-            match tag {
-                8 => {
-                    self.id = is.read_int32()?;
-                },
-                18 => {
-                    self.name = is.read_string()?;
-                },
-                24 => {
-                    self.visible = is.read_bool()?;
-                },
-                34 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.position)?;
-                },
-                42 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.orientation)?;
-                },
-                50 => {
-                    self.chips.push(is.read_message()?);
-                },
-                tag => {
-                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
-                },
-            };
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Radio>(
+                "radio",
+                |m: &Nfc| { &m.radio },
+                |m: &mut Nfc| { &mut m.radio },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, nfc::Tag>(
+                "tag",
+                |m: &Nfc| { &m.tag },
+                |m: &mut Nfc| { &mut m.tag },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Nfc>(
+                "Chip.Nfc",
+                fields,
+                oneofs,
+            )
         }
-        ::std::result::Result::Ok(())
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u64 {
-        let mut my_size = 0;
-        if self.id != 0 {
-            my_size += ::protobuf::rt::int32_size(1, self.id);
-        }
-        if !self.name.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.name);
+    impl ::protobuf::Message for Nfc {
+        const NAME: &'static str = "Nfc";
+
+        fn is_initialized(&self) -> bool {
+            true
         }
-        if self.visible != false {
-            my_size += 1 + 1;
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.radio)?;
+                    },
+                    18 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.tag)?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
         }
-        if let Some(v) = self.position.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if let Some(v) = self.radio.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
+            if let Some(v) = self.tag.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
         }
-        if let Some(v) = self.orientation.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if let Some(v) = self.radio.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+            }
+            if let Some(v) = self.tag.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
         }
-        for value in &self.chips {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
-        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
-        self.special_fields.cached_size().set(my_size as u32);
-        my_size
-    }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.id != 0 {
-            os.write_int32(1, self.id)?;
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
         }
-        if !self.name.is_empty() {
-            os.write_string(2, &self.name)?;
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
         }
-        if self.visible != false {
-            os.write_bool(3, self.visible)?;
+
+        fn new() -> Nfc {
+            Nfc::new()
         }
-        if let Some(v) = self.position.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+
+        fn clear(&mut self) {
+            self.radio.clear();
+            self.tag.clear();
+            self.special_fields.clear();
         }
-        if let Some(v) = self.orientation.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+
+        fn default_instance() -> &'static Nfc {
+            static instance: Nfc = Nfc {
+                radio: ::protobuf::MessageField::none(),
+                tag: ::protobuf::MessageField::none(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
         }
-        for v in &self.chips {
-            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
-        };
-        os.write_unknown_fields(self.special_fields.unknown_fields())?;
-        ::std::result::Result::Ok(())
     }
 
-    fn special_fields(&self) -> &::protobuf::SpecialFields {
-        &self.special_fields
+    impl ::protobuf::MessageFull for Nfc {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Chip.Nfc").unwrap()).clone()
+        }
     }
 
-    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
-        &mut self.special_fields
+    impl ::std::fmt::Display for Nfc {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
     }
 
-    fn new() -> Device {
-        Device::new()
+    impl ::protobuf::reflect::ProtobufValue for Nfc {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
     }
 
-    fn clear(&mut self) {
-        self.id = 0;
-        self.name.clear();
-        self.visible = false;
-        self.position.clear();
-        self.orientation.clear();
-        self.chips.clear();
-        self.special_fields.clear();
+    /// Nested message and enums of message `Nfc`
+    pub mod nfc {
+        #[derive(PartialEq,Clone,Default,Debug)]
+        // @@protoc_insertion_point(message:netsim.model.Chip.Nfc.Tag)
+        pub struct Tag {
+            // message fields
+            // @@protoc_insertion_point(field:netsim.model.Chip.Nfc.Tag.ndef)
+            pub ndef: ::std::vec::Vec<u8>,
+            // special fields
+            // @@protoc_insertion_point(special_field:netsim.model.Chip.Nfc.Tag.special_fields)
+            pub special_fields: ::protobuf::SpecialFields,
+        }
+
+        impl<'a> ::std::default::Default for &'a Tag {
+            fn default() -> &'a Tag {
+                <Tag as ::protobuf::Message>::default_instance()
+            }
+        }
+
+        impl Tag {
+            pub fn new() -> Tag {
+                ::std::default::Default::default()
+            }
+
+            pub(in super::super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+                let mut fields = ::std::vec::Vec::with_capacity(1);
+                let mut oneofs = ::std::vec::Vec::with_capacity(0);
+                fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                    "ndef",
+                    |m: &Tag| { &m.ndef },
+                    |m: &mut Tag| { &mut m.ndef },
+                ));
+                ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Tag>(
+                    "Chip.Nfc.Tag",
+                    fields,
+                    oneofs,
+                )
+            }
+        }
+
+        impl ::protobuf::Message for Tag {
+            const NAME: &'static str = "Tag";
+
+            fn is_initialized(&self) -> bool {
+                true
+            }
+
+            fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+                while let Some(tag) = is.read_raw_tag_or_eof()? {
+                    match tag {
+                        10 => {
+                            self.ndef = is.read_bytes()?;
+                        },
+                        tag => {
+                            ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                        },
+                    };
+                }
+                ::std::result::Result::Ok(())
+            }
+
+            // Compute sizes of nested messages
+            #[allow(unused_variables)]
+            fn compute_size(&self) -> u64 {
+                let mut my_size = 0;
+                if !self.ndef.is_empty() {
+                    my_size += ::protobuf::rt::bytes_size(1, &self.ndef);
+                }
+                my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+                self.special_fields.cached_size().set(my_size as u32);
+                my_size
+            }
+
+            fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+                if !self.ndef.is_empty() {
+                    os.write_bytes(1, &self.ndef)?;
+                }
+                os.write_unknown_fields(self.special_fields.unknown_fields())?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn special_fields(&self) -> &::protobuf::SpecialFields {
+                &self.special_fields
+            }
+
+            fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+                &mut self.special_fields
+            }
+
+            fn new() -> Tag {
+                Tag::new()
+            }
+
+            fn clear(&mut self) {
+                self.ndef.clear();
+                self.special_fields.clear();
+            }
+
+            fn default_instance() -> &'static Tag {
+                static instance: Tag = Tag {
+                    ndef: ::std::vec::Vec::new(),
+                    special_fields: ::protobuf::SpecialFields::new(),
+                };
+                &instance
+            }
+        }
+
+        impl ::protobuf::MessageFull for Tag {
+            fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+                static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+                descriptor.get(|| super::super::file_descriptor().message_by_package_relative_name("Chip.Nfc.Tag").unwrap()).clone()
+            }
+        }
+
+        impl ::std::fmt::Display for Tag {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::protobuf::text_format::fmt(self, f)
+            }
+        }
+
+        impl ::protobuf::reflect::ProtobufValue for Tag {
+            type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+        }
     }
 
-    fn default_instance() -> &'static Device {
-        static instance: Device = Device {
-            id: 0,
-            name: ::std::string::String::new(),
-            visible: false,
-            position: ::protobuf::MessageField::none(),
-            orientation: ::protobuf::MessageField::none(),
-            chips: ::std::vec::Vec::new(),
-            special_fields: ::protobuf::SpecialFields::new(),
-        };
-        &instance
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.model.Chip.Bandwidth)
+    pub struct Bandwidth {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.model.Chip.Bandwidth.bits_per_second)
+        pub bits_per_second: u64,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.model.Chip.Bandwidth.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
     }
-}
 
-impl ::protobuf::MessageFull for Device {
-    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
-        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Device").unwrap()).clone()
+    impl<'a> ::std::default::Default for &'a Bandwidth {
+        fn default() -> &'a Bandwidth {
+            <Bandwidth as ::protobuf::Message>::default_instance()
+        }
     }
-}
 
-impl ::std::fmt::Display for Device {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    impl Bandwidth {
+        pub fn new() -> Bandwidth {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(1);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "bits_per_second",
+                |m: &Bandwidth| { &m.bits_per_second },
+                |m: &mut Bandwidth| { &mut m.bits_per_second },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Bandwidth>(
+                "Chip.Bandwidth",
+                fields,
+                oneofs,
+            )
+        }
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for Device {
-    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    impl ::protobuf::Message for Bandwidth {
+        const NAME: &'static str = "Bandwidth";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.bits_per_second = is.read_uint64()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.bits_per_second != 0 {
+                my_size += ::protobuf::rt::uint64_size(1, self.bits_per_second);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.bits_per_second != 0 {
+                os.write_uint64(1, self.bits_per_second)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Bandwidth {
+            Bandwidth::new()
+        }
+
+        fn clear(&mut self) {
+            self.bits_per_second = 0;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Bandwidth {
+            static instance: Bandwidth = Bandwidth {
+                bits_per_second: 0,
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Bandwidth {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Chip.Bandwidth").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Bandwidth {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Bandwidth {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.model.Scene)
-pub struct Scene {
+// @@protoc_insertion_point(message:netsim.model.Device)
+pub struct Device {
     // message fields
-    // @@protoc_insertion_point(field:netsim.model.Scene.devices)
-    pub devices: ::std::vec::Vec<Device>,
+    // @@protoc_insertion_point(field:netsim.model.Device.id)
+    pub id: i32,
+    // @@protoc_insertion_point(field:netsim.model.Device.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.model.Device.visible)
+    pub visible: bool,
+    // @@protoc_insertion_point(field:netsim.model.Device.position)
+    pub position: ::protobuf::MessageField<Position>,
+    // @@protoc_insertion_point(field:netsim.model.Device.orientation)
+    pub orientation: ::protobuf::MessageField<Orientation>,
+    // @@protoc_insertion_point(field:netsim.model.Device.chips)
+    pub chips: ::std::vec::Vec<Chip>,
+    // @@protoc_insertion_point(field:netsim.model.Device.group)
+    pub group: ::std::option::Option<::std::string::String>,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.model.Scene.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.model.Device.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Scene {
-    fn default() -> &'a Scene {
-        <Scene as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Device {
+    fn default() -> &'a Device {
+        <Device as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Scene {
-    pub fn new() -> Scene {
+impl Device {
+    pub fn new() -> Device {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(7);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &Device| { &m.id },
+            |m: &mut Device| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &Device| { &m.name },
+            |m: &mut Device| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "visible",
+            |m: &Device| { &m.visible },
+            |m: &mut Device| { &mut m.visible },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Position>(
+            "position",
+            |m: &Device| { &m.position },
+            |m: &mut Device| { &mut m.position },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Orientation>(
+            "orientation",
+            |m: &Device| { &m.orientation },
+            |m: &mut Device| { &mut m.orientation },
+        ));
         fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "devices",
-            |m: &Scene| { &m.devices },
-            |m: &mut Scene| { &mut m.devices },
+            "chips",
+            |m: &Device| { &m.chips },
+            |m: &mut Device| { &mut m.chips },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Scene>(
-            "Scene",
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "group",
+            |m: &Device| { &m.group },
+            |m: &mut Device| { &mut m.group },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Device>(
+            "Device",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Scene {
-    const NAME: &'static str = "Scene";
+impl ::protobuf::Message for Device {
+    const NAME: &'static str = "Device";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1378,8 +1682,26 @@ impl ::protobuf::Message for Scene {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.devices.push(is.read_message()?);
+                8 => {
+                    self.id = is.read_int32()?;
+                },
+                18 => {
+                    self.name = is.read_string()?;
+                },
+                24 => {
+                    self.visible = is.read_bool()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.position)?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.orientation)?;
+                },
+                50 => {
+                    self.chips.push(is.read_message()?);
+                },
+                58 => {
+                    self.group = ::std::option::Option::Some(is.read_string()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1393,19 +1715,57 @@ impl ::protobuf::Message for Scene {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.devices {
+        if self.id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.visible != false {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.orientation.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.chips {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         };
+        if let Some(v) = self.group.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.devices {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        if self.id != 0 {
+            os.write_int32(1, self.id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.visible != false {
+            os.write_bool(3, self.visible)?;
+        }
+        if let Some(v) = self.position.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.orientation.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        for v in &self.chips {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
         };
+        if let Some(v) = self.group.as_ref() {
+            os.write_string(7, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1418,130 +1778,93 @@ impl ::protobuf::Message for Scene {
         &mut self.special_fields
     }
 
-    fn new() -> Scene {
-        Scene::new()
+    fn new() -> Device {
+        Device::new()
     }
 
     fn clear(&mut self) {
-        self.devices.clear();
+        self.id = 0;
+        self.name.clear();
+        self.visible = false;
+        self.position.clear();
+        self.orientation.clear();
+        self.chips.clear();
+        self.group = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Scene {
-        static instance: Scene = Scene {
-            devices: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static Device {
+        static instance: Device = Device {
+            id: 0,
+            name: ::std::string::String::new(),
+            visible: false,
+            position: ::protobuf::MessageField::none(),
+            orientation: ::protobuf::MessageField::none(),
+            chips: ::std::vec::Vec::new(),
+            group: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Scene {
+impl ::protobuf::MessageFull for Device {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Scene").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Device").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Scene {
+impl ::std::fmt::Display for Device {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Scene {
+impl ::protobuf::reflect::ProtobufValue for Device {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.model.Capture)
-pub struct Capture {
+// @@protoc_insertion_point(message:netsim.model.Scene)
+pub struct Scene {
     // message fields
-    // @@protoc_insertion_point(field:netsim.model.Capture.id)
-    pub id: i32,
-    // @@protoc_insertion_point(field:netsim.model.Capture.chip_kind)
-    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
-    // @@protoc_insertion_point(field:netsim.model.Capture.device_name)
-    pub device_name: ::std::string::String,
-    // @@protoc_insertion_point(field:netsim.model.Capture.state)
-    pub state: ::protobuf::EnumOrUnknown<State>,
-    // @@protoc_insertion_point(field:netsim.model.Capture.size)
-    pub size: i32,
-    // @@protoc_insertion_point(field:netsim.model.Capture.records)
-    pub records: i32,
-    // @@protoc_insertion_point(field:netsim.model.Capture.timestamp)
-    pub timestamp: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
-    // @@protoc_insertion_point(field:netsim.model.Capture.valid)
-    pub valid: bool,
+    // @@protoc_insertion_point(field:netsim.model.Scene.devices)
+    pub devices: ::std::vec::Vec<Device>,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.model.Capture.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.model.Scene.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Capture {
-    fn default() -> &'a Capture {
-        <Capture as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Scene {
+    fn default() -> &'a Scene {
+        <Scene as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Capture {
-    pub fn new() -> Capture {
+impl Scene {
+    pub fn new() -> Scene {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "id",
-            |m: &Capture| { &m.id },
-            |m: &mut Capture| { &mut m.id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "chip_kind",
-            |m: &Capture| { &m.chip_kind },
-            |m: &mut Capture| { &mut m.chip_kind },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "device_name",
-            |m: &Capture| { &m.device_name },
-            |m: &mut Capture| { &mut m.device_name },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "state",
-            |m: &Capture| { &m.state },
-            |m: &mut Capture| { &mut m.state },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "size",
-            |m: &Capture| { &m.size },
-            |m: &mut Capture| { &mut m.size },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "records",
-            |m: &Capture| { &m.records },
-            |m: &mut Capture| { &mut m.records },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
-            "timestamp",
-            |m: &Capture| { &m.timestamp },
-            |m: &mut Capture| { &mut m.timestamp },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "valid",
-            |m: &Capture| { &m.valid },
-            |m: &mut Capture| { &mut m.valid },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "devices",
+            |m: &Scene| { &m.devices },
+            |m: &mut Scene| { &mut m.devices },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Capture>(
-            "Capture",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Scene>(
+            "Scene",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Capture {
-    const NAME: &'static str = "Capture";
+impl ::protobuf::Message for Scene {
+    const NAME: &'static str = "Scene";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1550,29 +1873,8 @@ impl ::protobuf::Message for Capture {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.id = is.read_int32()?;
-                },
-                16 => {
-                    self.chip_kind = is.read_enum_or_unknown()?;
-                },
-                26 => {
-                    self.device_name = is.read_string()?;
-                },
-                32 => {
-                    self.state = is.read_enum_or_unknown()?;
-                },
-                40 => {
-                    self.size = is.read_int32()?;
-                },
-                48 => {
-                    self.records = is.read_int32()?;
-                },
-                58 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.timestamp)?;
-                },
-                64 => {
-                    self.valid = is.read_bool()?;
+                10 => {
+                    self.devices.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1586,61 +1888,19 @@ impl ::protobuf::Message for Capture {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.id != 0 {
-            my_size += ::protobuf::rt::int32_size(1, self.id);
-        }
-        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
-            my_size += ::protobuf::rt::int32_size(2, self.chip_kind.value());
-        }
-        if !self.device_name.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.device_name);
-        }
-        if self.state != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
-            my_size += ::protobuf::rt::int32_size(4, self.state.value());
-        }
-        if self.size != 0 {
-            my_size += ::protobuf::rt::int32_size(5, self.size);
-        }
-        if self.records != 0 {
-            my_size += ::protobuf::rt::int32_size(6, self.records);
-        }
-        if let Some(v) = self.timestamp.as_ref() {
-            let len = v.compute_size();
+        for value in &self.devices {
+            let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
-        if self.valid != false {
-            my_size += 1 + 1;
-        }
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.id != 0 {
-            os.write_int32(1, self.id)?;
-        }
-        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
-            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
-        }
-        if !self.device_name.is_empty() {
-            os.write_string(3, &self.device_name)?;
-        }
-        if self.state != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
-            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.state))?;
-        }
-        if self.size != 0 {
-            os.write_int32(5, self.size)?;
-        }
-        if self.records != 0 {
-            os.write_int32(6, self.records)?;
-        }
-        if let Some(v) = self.timestamp.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
-        }
-        if self.valid != false {
-            os.write_bool(8, self.valid)?;
-        }
+        for v in &self.devices {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1653,55 +1913,1542 @@ impl ::protobuf::Message for Capture {
         &mut self.special_fields
     }
 
-    fn new() -> Capture {
-        Capture::new()
+    fn new() -> Scene {
+        Scene::new()
     }
 
     fn clear(&mut self) {
-        self.id = 0;
-        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
-        self.device_name.clear();
-        self.state = ::protobuf::EnumOrUnknown::new(State::UNKNOWN);
-        self.size = 0;
-        self.records = 0;
-        self.timestamp.clear();
-        self.valid = false;
+        self.devices.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Capture {
-        static instance: Capture = Capture {
-            id: 0,
-            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
-            device_name: ::std::string::String::new(),
-            state: ::protobuf::EnumOrUnknown::from_i32(0),
-            size: 0,
-            records: 0,
-            timestamp: ::protobuf::MessageField::none(),
-            valid: false,
+    fn default_instance() -> &'static Scene {
+        static instance: Scene = Scene {
+            devices: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Capture {
+impl ::protobuf::MessageFull for Scene {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Capture").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Scene").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Capture {
+impl ::std::fmt::Display for Scene {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Capture {
+impl ::protobuf::reflect::ProtobufValue for Scene {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.model.SavedState)
+pub struct SavedState {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.model.SavedState.scene)
+    pub scene: ::protobuf::MessageField<Scene>,
+    // @@protoc_insertion_point(field:netsim.model.SavedState.settings)
+    pub settings: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.model.SavedState.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SavedState {
+    fn default() -> &'a SavedState {
+        <SavedState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SavedState {
+    pub fn new() -> SavedState {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Scene>(
+            "scene",
+            |m: &SavedState| { &m.scene },
+            |m: &mut SavedState| { &mut m.scene },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "settings",
+            |m: &SavedState| { &m.settings },
+            |m: &mut SavedState| { &mut m.settings },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SavedState>(
+            "SavedState",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SavedState {
+    const NAME: &'static str = "SavedState";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.scene)?;
+                },
+                18 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.settings.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.scene.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for (k, v) in &self.settings {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.scene.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        for (k, v) in &self.settings {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(18)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SavedState {
+        SavedState::new()
+    }
+
+    fn clear(&mut self) {
+        self.scene.clear();
+        self.settings.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SavedState {
+        static instance: ::protobuf::rt::Lazy<SavedState> = ::protobuf::rt::Lazy::new();
+        instance.get(SavedState::new)
+    }
+}
+
+impl ::protobuf::MessageFull for SavedState {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SavedState").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SavedState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SavedState {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.model.Capture)
+pub struct Capture {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.model.Capture.id)
+    pub id: i32,
+    // @@protoc_insertion_point(field:netsim.model.Capture.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.model.Capture.state)
+    pub state: ::protobuf::EnumOrUnknown<State>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.size)
+    pub size: i32,
+    // @@protoc_insertion_point(field:netsim.model.Capture.records)
+    pub records: i32,
+    // @@protoc_insertion_point(field:netsim.model.Capture.timestamp)
+    pub timestamp: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.valid)
+    pub valid: bool,
+    // @@protoc_insertion_point(field:netsim.model.Capture.sampling)
+    pub sampling: ::protobuf::MessageField<capture::Sampling>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.packets)
+    pub packets: i32,
+    // @@protoc_insertion_point(field:netsim.model.Capture.quota_status)
+    pub quota_status: ::protobuf::EnumOrUnknown<capture::QuotaStatus>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.sha256)
+    pub sha256: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.model.Capture.format)
+    pub format: ::protobuf::EnumOrUnknown<capture::Format>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.segments)
+    pub segments: ::std::vec::Vec<capture::Segment>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.filter)
+    pub filter: ::protobuf::MessageField<capture::Filter>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.mode)
+    pub mode: ::protobuf::EnumOrUnknown<capture::Mode>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.compressed)
+    pub compressed: bool,
+    // @@protoc_insertion_point(field:netsim.model.Capture.snaplen)
+    pub snaplen: u32,
+    // @@protoc_insertion_point(field:netsim.model.Capture.bytes_per_second)
+    pub bytes_per_second: f64,
+    // @@protoc_insertion_point(field:netsim.model.Capture.packets_per_second)
+    pub packets_per_second: f64,
+    // @@protoc_insertion_point(field:netsim.model.Capture.last_record)
+    pub last_record: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.error)
+    pub error: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.model.Capture.live_path)
+    pub live_path: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.model.Capture.live_reader)
+    pub live_reader: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.model.Capture.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Capture {
+    fn default() -> &'a Capture {
+        <Capture as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Capture {
+    pub fn new() -> Capture {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(24);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &Capture| { &m.id },
+            |m: &mut Capture| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &Capture| { &m.chip_kind },
+            |m: &mut Capture| { &mut m.chip_kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &Capture| { &m.device_name },
+            |m: &mut Capture| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "state",
+            |m: &Capture| { &m.state },
+            |m: &mut Capture| { &mut m.state },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &Capture| { &m.size },
+            |m: &mut Capture| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "records",
+            |m: &Capture| { &m.records },
+            |m: &mut Capture| { &mut m.records },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "timestamp",
+            |m: &Capture| { &m.timestamp },
+            |m: &mut Capture| { &mut m.timestamp },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "valid",
+            |m: &Capture| { &m.valid },
+            |m: &mut Capture| { &mut m.valid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, capture::Sampling>(
+            "sampling",
+            |m: &Capture| { &m.sampling },
+            |m: &mut Capture| { &mut m.sampling },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "packets",
+            |m: &Capture| { &m.packets },
+            |m: &mut Capture| { &mut m.packets },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "quota_status",
+            |m: &Capture| { &m.quota_status },
+            |m: &mut Capture| { &mut m.quota_status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sha256",
+            |m: &Capture| { &m.sha256 },
+            |m: &mut Capture| { &mut m.sha256 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "format",
+            |m: &Capture| { &m.format },
+            |m: &mut Capture| { &mut m.format },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "segments",
+            |m: &Capture| { &m.segments },
+            |m: &mut Capture| { &mut m.segments },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, capture::Filter>(
+            "filter",
+            |m: &Capture| { &m.filter },
+            |m: &mut Capture| { &mut m.filter },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mode",
+            |m: &Capture| { &m.mode },
+            |m: &mut Capture| { &mut m.mode },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "compressed",
+            |m: &Capture| { &m.compressed },
+            |m: &mut Capture| { &mut m.compressed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "snaplen",
+            |m: &Capture| { &m.snaplen },
+            |m: &mut Capture| { &mut m.snaplen },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bytes_per_second",
+            |m: &Capture| { &m.bytes_per_second },
+            |m: &mut Capture| { &mut m.bytes_per_second },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "packets_per_second",
+            |m: &Capture| { &m.packets_per_second },
+            |m: &mut Capture| { &mut m.packets_per_second },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "last_record",
+            |m: &Capture| { &m.last_record },
+            |m: &mut Capture| { &mut m.last_record },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &Capture| { &m.error },
+            |m: &mut Capture| { &mut m.error },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "live_path",
+            |m: &Capture| { &m.live_path },
+            |m: &mut Capture| { &mut m.live_path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "live_reader",
+            |m: &Capture| { &m.live_reader },
+            |m: &mut Capture| { &mut m.live_reader },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Capture>(
+            "Capture",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Capture {
+    const NAME: &'static str = "Capture";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_int32()?;
+                },
+                16 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    self.device_name = is.read_string()?;
+                },
+                32 => {
+                    self.state = is.read_enum_or_unknown()?;
+                },
+                40 => {
+                    self.size = is.read_int32()?;
+                },
+                48 => {
+                    self.records = is.read_int32()?;
+                },
+                58 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.timestamp)?;
+                },
+                64 => {
+                    self.valid = is.read_bool()?;
+                },
+                74 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sampling)?;
+                },
+                80 => {
+                    self.packets = is.read_int32()?;
+                },
+                88 => {
+                    self.quota_status = is.read_enum_or_unknown()?;
+                },
+                98 => {
+                    self.sha256 = is.read_string()?;
+                },
+                104 => {
+                    self.format = is.read_enum_or_unknown()?;
+                },
+                114 => {
+                    self.segments.push(is.read_message()?);
+                },
+                122 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.filter)?;
+                },
+                128 => {
+                    self.mode = is.read_enum_or_unknown()?;
+                },
+                136 => {
+                    self.compressed = is.read_bool()?;
+                },
+                144 => {
+                    self.snaplen = is.read_uint32()?;
+                },
+                153 => {
+                    self.bytes_per_second = is.read_double()?;
+                },
+                161 => {
+                    self.packets_per_second = is.read_double()?;
+                },
+                170 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.last_record)?;
+                },
+                178 => {
+                    self.error = is.read_string()?;
+                },
+                186 => {
+                    self.live_path = is.read_string()?;
+                },
+                192 => {
+                    self.live_reader = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.id);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(2, self.chip_kind.value());
+        }
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.device_name);
+        }
+        if self.state != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(4, self.state.value());
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::int32_size(5, self.size);
+        }
+        if self.records != 0 {
+            my_size += ::protobuf::rt::int32_size(6, self.records);
+        }
+        if let Some(v) = self.timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.valid != false {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.sampling.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.packets != 0 {
+            my_size += ::protobuf::rt::int32_size(10, self.packets);
+        }
+        if self.quota_status != ::protobuf::EnumOrUnknown::new(capture::QuotaStatus::BELOW_WATERMARK) {
+            my_size += ::protobuf::rt::int32_size(11, self.quota_status.value());
+        }
+        if !self.sha256.is_empty() {
+            my_size += ::protobuf::rt::string_size(12, &self.sha256);
+        }
+        if self.format != ::protobuf::EnumOrUnknown::new(capture::Format::PCAP) {
+            my_size += ::protobuf::rt::int32_size(13, self.format.value());
+        }
+        for value in &self.segments {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.filter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.mode != ::protobuf::EnumOrUnknown::new(capture::Mode::FILE) {
+            my_size += ::protobuf::rt::int32_size(16, self.mode.value());
+        }
+        if self.compressed != false {
+            my_size += 2 + 1;
+        }
+        if self.snaplen != 0 {
+            my_size += ::protobuf::rt::uint32_size(18, self.snaplen);
+        }
+        if self.bytes_per_second != 0. {
+            my_size += 2 + 8;
+        }
+        if self.packets_per_second != 0. {
+            my_size += 2 + 8;
+        }
+        if let Some(v) = self.last_record.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(22, &self.error);
+        }
+        if !self.live_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(23, &self.live_path);
+        }
+        if self.live_reader != false {
+            my_size += 2 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_int32(1, self.id)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        if !self.device_name.is_empty() {
+            os.write_string(3, &self.device_name)?;
+        }
+        if self.state != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.state))?;
+        }
+        if self.size != 0 {
+            os.write_int32(5, self.size)?;
+        }
+        if self.records != 0 {
+            os.write_int32(6, self.records)?;
+        }
+        if let Some(v) = self.timestamp.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
+        if self.valid != false {
+            os.write_bool(8, self.valid)?;
+        }
+        if let Some(v) = self.sampling.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
+        }
+        if self.packets != 0 {
+            os.write_int32(10, self.packets)?;
+        }
+        if self.quota_status != ::protobuf::EnumOrUnknown::new(capture::QuotaStatus::BELOW_WATERMARK) {
+            os.write_enum(11, ::protobuf::EnumOrUnknown::value(&self.quota_status))?;
+        }
+        if !self.sha256.is_empty() {
+            os.write_string(12, &self.sha256)?;
+        }
+        if self.format != ::protobuf::EnumOrUnknown::new(capture::Format::PCAP) {
+            os.write_enum(13, ::protobuf::EnumOrUnknown::value(&self.format))?;
+        }
+        for v in &self.segments {
+            ::protobuf::rt::write_message_field_with_cached_size(14, v, os)?;
+        };
+        if let Some(v) = self.filter.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(15, v, os)?;
+        }
+        if self.mode != ::protobuf::EnumOrUnknown::new(capture::Mode::FILE) {
+            os.write_enum(16, ::protobuf::EnumOrUnknown::value(&self.mode))?;
+        }
+        if self.compressed != false {
+            os.write_bool(17, self.compressed)?;
+        }
+        if self.snaplen != 0 {
+            os.write_uint32(18, self.snaplen)?;
+        }
+        if self.bytes_per_second != 0. {
+            os.write_double(19, self.bytes_per_second)?;
+        }
+        if self.packets_per_second != 0. {
+            os.write_double(20, self.packets_per_second)?;
+        }
+        if let Some(v) = self.last_record.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(21, v, os)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(22, &self.error)?;
+        }
+        if !self.live_path.is_empty() {
+            os.write_string(23, &self.live_path)?;
+        }
+        if self.live_reader != false {
+            os.write_bool(24, self.live_reader)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Capture {
+        Capture::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.device_name.clear();
+        self.state = ::protobuf::EnumOrUnknown::new(State::UNKNOWN);
+        self.size = 0;
+        self.records = 0;
+        self.timestamp.clear();
+        self.valid = false;
+        self.sampling.clear();
+        self.packets = 0;
+        self.quota_status = ::protobuf::EnumOrUnknown::new(capture::QuotaStatus::BELOW_WATERMARK);
+        self.sha256.clear();
+        self.format = ::protobuf::EnumOrUnknown::new(capture::Format::PCAP);
+        self.segments.clear();
+        self.filter.clear();
+        self.mode = ::protobuf::EnumOrUnknown::new(capture::Mode::FILE);
+        self.compressed = false;
+        self.snaplen = 0;
+        self.bytes_per_second = 0.;
+        self.packets_per_second = 0.;
+        self.last_record.clear();
+        self.error.clear();
+        self.live_path.clear();
+        self.live_reader = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Capture {
+        static instance: Capture = Capture {
+            id: 0,
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            device_name: ::std::string::String::new(),
+            state: ::protobuf::EnumOrUnknown::from_i32(0),
+            size: 0,
+            records: 0,
+            timestamp: ::protobuf::MessageField::none(),
+            valid: false,
+            sampling: ::protobuf::MessageField::none(),
+            packets: 0,
+            quota_status: ::protobuf::EnumOrUnknown::from_i32(0),
+            sha256: ::std::string::String::new(),
+            format: ::protobuf::EnumOrUnknown::from_i32(0),
+            segments: ::std::vec::Vec::new(),
+            filter: ::protobuf::MessageField::none(),
+            mode: ::protobuf::EnumOrUnknown::from_i32(0),
+            compressed: false,
+            snaplen: 0,
+            bytes_per_second: 0.,
+            packets_per_second: 0.,
+            last_record: ::protobuf::MessageField::none(),
+            error: ::std::string::String::new(),
+            live_path: ::std::string::String::new(),
+            live_reader: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Capture {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Capture").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Capture {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Capture {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Capture`
+pub mod capture {
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.model.Capture.Sampling)
+    pub struct Sampling {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.model.Capture.Sampling.one_in)
+        pub one_in: u32,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Sampling.max_rate)
+        pub max_rate: u32,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.model.Capture.Sampling.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Sampling {
+        fn default() -> &'a Sampling {
+            <Sampling as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Sampling {
+        pub fn new() -> Sampling {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "one_in",
+                |m: &Sampling| { &m.one_in },
+                |m: &mut Sampling| { &mut m.one_in },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "max_rate",
+                |m: &Sampling| { &m.max_rate },
+                |m: &mut Sampling| { &mut m.max_rate },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Sampling>(
+                "Capture.Sampling",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Sampling {
+        const NAME: &'static str = "Sampling";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.one_in = is.read_uint32()?;
+                    },
+                    16 => {
+                        self.max_rate = is.read_uint32()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.one_in != 0 {
+                my_size += ::protobuf::rt::uint32_size(1, self.one_in);
+            }
+            if self.max_rate != 0 {
+                my_size += ::protobuf::rt::uint32_size(2, self.max_rate);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.one_in != 0 {
+                os.write_uint32(1, self.one_in)?;
+            }
+            if self.max_rate != 0 {
+                os.write_uint32(2, self.max_rate)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Sampling {
+            Sampling::new()
+        }
+
+        fn clear(&mut self) {
+            self.one_in = 0;
+            self.max_rate = 0;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Sampling {
+            static instance: Sampling = Sampling {
+                one_in: 0,
+                max_rate: 0,
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Sampling {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Capture.Sampling").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Sampling {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Sampling {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.model.Capture.Segment)
+    pub struct Segment {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.model.Capture.Segment.index)
+        pub index: u32,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Segment.size)
+        pub size: i32,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Segment.records)
+        pub records: i32,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Segment.timestamp)
+        pub timestamp: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Segment.sha256)
+        pub sha256: ::std::string::String,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.model.Capture.Segment.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Segment {
+        fn default() -> &'a Segment {
+            <Segment as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Segment {
+        pub fn new() -> Segment {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(5);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "index",
+                |m: &Segment| { &m.index },
+                |m: &mut Segment| { &mut m.index },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "size",
+                |m: &Segment| { &m.size },
+                |m: &mut Segment| { &mut m.size },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "records",
+                |m: &Segment| { &m.records },
+                |m: &mut Segment| { &mut m.records },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+                "timestamp",
+                |m: &Segment| { &m.timestamp },
+                |m: &mut Segment| { &mut m.timestamp },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "sha256",
+                |m: &Segment| { &m.sha256 },
+                |m: &mut Segment| { &mut m.sha256 },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Segment>(
+                "Capture.Segment",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Segment {
+        const NAME: &'static str = "Segment";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.index = is.read_uint32()?;
+                    },
+                    16 => {
+                        self.size = is.read_int32()?;
+                    },
+                    24 => {
+                        self.records = is.read_int32()?;
+                    },
+                    34 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.timestamp)?;
+                    },
+                    42 => {
+                        self.sha256 = is.read_string()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.index != 0 {
+                my_size += ::protobuf::rt::uint32_size(1, self.index);
+            }
+            if self.size != 0 {
+                my_size += ::protobuf::rt::int32_size(2, self.size);
+            }
+            if self.records != 0 {
+                my_size += ::protobuf::rt::int32_size(3, self.records);
+            }
+            if let Some(v) = self.timestamp.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
+            if !self.sha256.is_empty() {
+                my_size += ::protobuf::rt::string_size(5, &self.sha256);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.index != 0 {
+                os.write_uint32(1, self.index)?;
+            }
+            if self.size != 0 {
+                os.write_int32(2, self.size)?;
+            }
+            if self.records != 0 {
+                os.write_int32(3, self.records)?;
+            }
+            if let Some(v) = self.timestamp.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+            }
+            if !self.sha256.is_empty() {
+                os.write_string(5, &self.sha256)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Segment {
+            Segment::new()
+        }
+
+        fn clear(&mut self) {
+            self.index = 0;
+            self.size = 0;
+            self.records = 0;
+            self.timestamp.clear();
+            self.sha256.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Segment {
+            static instance: Segment = Segment {
+                index: 0,
+                size: 0,
+                records: 0,
+                timestamp: ::protobuf::MessageField::none(),
+                sha256: ::std::string::String::new(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Segment {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Capture.Segment").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Segment {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Segment {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.model.Capture.Filter)
+    pub struct Filter {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.model.Capture.Filter.packet_types)
+        pub packet_types: ::std::vec::Vec<u32>,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Filter.direction)
+        pub direction: ::protobuf::EnumOrUnknown<filter::Direction>,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Filter.min_length)
+        pub min_length: u32,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Filter.max_length)
+        pub max_length: u32,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Filter.pattern)
+        pub pattern: ::std::vec::Vec<u8>,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Filter.offset)
+        pub offset: u32,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Filter.anywhere)
+        pub anywhere: bool,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.model.Capture.Filter.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Filter {
+        fn default() -> &'a Filter {
+            <Filter as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Filter {
+        pub fn new() -> Filter {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(7);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+                "packet_types",
+                |m: &Filter| { &m.packet_types },
+                |m: &mut Filter| { &mut m.packet_types },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "direction",
+                |m: &Filter| { &m.direction },
+                |m: &mut Filter| { &mut m.direction },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "min_length",
+                |m: &Filter| { &m.min_length },
+                |m: &mut Filter| { &mut m.min_length },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "max_length",
+                |m: &Filter| { &m.max_length },
+                |m: &mut Filter| { &mut m.max_length },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "pattern",
+                |m: &Filter| { &m.pattern },
+                |m: &mut Filter| { &mut m.pattern },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "offset",
+                |m: &Filter| { &m.offset },
+                |m: &mut Filter| { &mut m.offset },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "anywhere",
+                |m: &Filter| { &m.anywhere },
+                |m: &mut Filter| { &mut m.anywhere },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Filter>(
+                "Capture.Filter",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Filter {
+        const NAME: &'static str = "Filter";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        is.read_repeated_packed_uint32_into(&mut self.packet_types)?;
+                    },
+                    8 => {
+                        self.packet_types.push(is.read_uint32()?);
+                    },
+                    16 => {
+                        self.direction = is.read_enum_or_unknown()?;
+                    },
+                    24 => {
+                        self.min_length = is.read_uint32()?;
+                    },
+                    32 => {
+                        self.max_length = is.read_uint32()?;
+                    },
+                    42 => {
+                        self.pattern = is.read_bytes()?;
+                    },
+                    48 => {
+                        self.offset = is.read_uint32()?;
+                    },
+                    56 => {
+                        self.anywhere = is.read_bool()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            for value in &self.packet_types {
+                my_size += ::protobuf::rt::uint32_size(1, *value);
+            };
+            if self.direction != ::protobuf::EnumOrUnknown::new(filter::Direction::ANY) {
+                my_size += ::protobuf::rt::int32_size(2, self.direction.value());
+            }
+            if self.min_length != 0 {
+                my_size += ::protobuf::rt::uint32_size(3, self.min_length);
+            }
+            if self.max_length != 0 {
+                my_size += ::protobuf::rt::uint32_size(4, self.max_length);
+            }
+            if !self.pattern.is_empty() {
+                my_size += ::protobuf::rt::bytes_size(5, &self.pattern);
+            }
+            if self.offset != 0 {
+                my_size += ::protobuf::rt::uint32_size(6, self.offset);
+            }
+            if self.anywhere != false {
+                my_size += 1 + 1;
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            for v in &self.packet_types {
+                os.write_uint32(1, *v)?;
+            };
+            if self.direction != ::protobuf::EnumOrUnknown::new(filter::Direction::ANY) {
+                os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.direction))?;
+            }
+            if self.min_length != 0 {
+                os.write_uint32(3, self.min_length)?;
+            }
+            if self.max_length != 0 {
+                os.write_uint32(4, self.max_length)?;
+            }
+            if !self.pattern.is_empty() {
+                os.write_bytes(5, &self.pattern)?;
+            }
+            if self.offset != 0 {
+                os.write_uint32(6, self.offset)?;
+            }
+            if self.anywhere != false {
+                os.write_bool(7, self.anywhere)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Filter {
+            Filter::new()
+        }
+
+        fn clear(&mut self) {
+            self.packet_types.clear();
+            self.direction = ::protobuf::EnumOrUnknown::new(filter::Direction::ANY);
+            self.min_length = 0;
+            self.max_length = 0;
+            self.pattern.clear();
+            self.offset = 0;
+            self.anywhere = false;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Filter {
+            static instance: Filter = Filter {
+                packet_types: ::std::vec::Vec::new(),
+                direction: ::protobuf::EnumOrUnknown::from_i32(0),
+                min_length: 0,
+                max_length: 0,
+                pattern: ::std::vec::Vec::new(),
+                offset: 0,
+                anywhere: false,
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Filter {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Capture.Filter").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Filter {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Filter {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    /// Nested message and enums of message `Filter`
+    pub mod filter {
+        #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+        // @@protoc_insertion_point(enum:netsim.model.Capture.Filter.Direction)
+        pub enum Direction {
+            // @@protoc_insertion_point(enum_value:netsim.model.Capture.Filter.Direction.ANY)
+            ANY = 0,
+            // @@protoc_insertion_point(enum_value:netsim.model.Capture.Filter.Direction.HOST_TO_CONTROLLER)
+            HOST_TO_CONTROLLER = 1,
+            // @@protoc_insertion_point(enum_value:netsim.model.Capture.Filter.Direction.CONTROLLER_TO_HOST)
+            CONTROLLER_TO_HOST = 2,
+        }
+
+        impl ::protobuf::Enum for Direction {
+            const NAME: &'static str = "Direction";
+
+            fn value(&self) -> i32 {
+                *self as i32
+            }
+
+            fn from_i32(value: i32) -> ::std::option::Option<Direction> {
+                match value {
+                    0 => ::std::option::Option::Some(Direction::ANY),
+                    1 => ::std::option::Option::Some(Direction::HOST_TO_CONTROLLER),
+                    2 => ::std::option::Option::Some(Direction::CONTROLLER_TO_HOST),
+                    _ => ::std::option::Option::None
+                }
+            }
+
+            const VALUES: &'static [Direction] = &[
+                Direction::ANY,
+                Direction::HOST_TO_CONTROLLER,
+                Direction::CONTROLLER_TO_HOST,
+            ];
+        }
+
+        impl ::protobuf::EnumFull for Direction {
+            fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+                static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+                descriptor.get(|| super::super::file_descriptor().enum_by_package_relative_name("Capture.Filter.Direction").unwrap()).clone()
+            }
+
+            fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+                let index = *self as usize;
+                Self::enum_descriptor().value_by_index(index)
+            }
+        }
+
+        impl ::std::default::Default for Direction {
+            fn default() -> Self {
+                Direction::ANY
+            }
+        }
+
+        impl Direction {
+            pub(in super::super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+                ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Direction>("Capture.Filter.Direction")
+            }
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.model.Capture.QuotaStatus)
+    pub enum QuotaStatus {
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.QuotaStatus.BELOW_WATERMARK)
+        BELOW_WATERMARK = 0,
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.QuotaStatus.ABOVE_WATERMARK)
+        ABOVE_WATERMARK = 1,
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.QuotaStatus.FULL)
+        FULL = 2,
+    }
+
+    impl ::protobuf::Enum for QuotaStatus {
+        const NAME: &'static str = "QuotaStatus";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<QuotaStatus> {
+            match value {
+                0 => ::std::option::Option::Some(QuotaStatus::BELOW_WATERMARK),
+                1 => ::std::option::Option::Some(QuotaStatus::ABOVE_WATERMARK),
+                2 => ::std::option::Option::Some(QuotaStatus::FULL),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [QuotaStatus] = &[
+            QuotaStatus::BELOW_WATERMARK,
+            QuotaStatus::ABOVE_WATERMARK,
+            QuotaStatus::FULL,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for QuotaStatus {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Capture.QuotaStatus").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for QuotaStatus {
+        fn default() -> Self {
+            QuotaStatus::BELOW_WATERMARK
+        }
+    }
+
+    impl QuotaStatus {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<QuotaStatus>("Capture.QuotaStatus")
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.model.Capture.Format)
+    pub enum Format {
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.Format.PCAP)
+        PCAP = 0,
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.Format.PCAPNG)
+        PCAPNG = 1,
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.Format.BTSNOOP)
+        BTSNOOP = 2,
+    }
+
+    impl ::protobuf::Enum for Format {
+        const NAME: &'static str = "Format";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Format> {
+            match value {
+                0 => ::std::option::Option::Some(Format::PCAP),
+                1 => ::std::option::Option::Some(Format::PCAPNG),
+                2 => ::std::option::Option::Some(Format::BTSNOOP),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Format] = &[
+            Format::PCAP,
+            Format::PCAPNG,
+            Format::BTSNOOP,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Format {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Capture.Format").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Format {
+        fn default() -> Self {
+            Format::PCAP
+        }
+    }
+
+    impl Format {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Format>("Capture.Format")
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.model.Capture.Mode)
+    pub enum Mode {
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.Mode.FILE)
+        FILE = 0,
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.Mode.RING)
+        RING = 1,
+    }
+
+    impl ::protobuf::Enum for Mode {
+        const NAME: &'static str = "Mode";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Mode> {
+            match value {
+                0 => ::std::option::Option::Some(Mode::FILE),
+                1 => ::std::option::Option::Some(Mode::RING),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Mode] = &[
+            Mode::FILE,
+            Mode::RING,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Mode {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Capture.Mode").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Mode {
+        fn default() -> Self {
+            Mode::FILE
+        }
+    }
+
+    impl Mode {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Mode>("Capture.Mode")
+        }
+    }
+}
+
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 // @@protoc_insertion_point(enum:netsim.model.PhyKind)
 pub enum PhyKind {
@@ -1836,7 +3583,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     (\x02R\x01x\x12\x0c\n\x01y\x18\x02\x20\x01(\x02R\x01y\x12\x0c\n\x01z\x18\
     \x03\x20\x01(\x02R\x01z\"I\n\x0bOrientation\x12\x10\n\x03yaw\x18\x01\x20\
     \x01(\x02R\x03yaw\x12\x14\n\x05pitch\x18\x02\x20\x01(\x02R\x05pitch\x12\
-    \x12\n\x04roll\x18\x03\x20\x01(\x02R\x04roll\"\xdd\x04\n\x04Chip\x12+\n\
+    \x12\n\x04roll\x18\x03\x20\x01(\x02R\x04roll\"\xfa\x06\n\x04Chip\x12+\n\
     \x04kind\x18\x01\x20\x01(\x0e2\x17.netsim.common.ChipKindR\x04kind\x12\
     \x0e\n\x02id\x18\x02\x20\x01(\x05R\x02id\x12\x12\n\x04name\x18\x03\x20\
     \x01(\tR\x04name\x12\"\n\x0cmanufacturer\x18\x04\x20\x01(\tR\x0cmanufact\
@@ -1845,31 +3592,76 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     .\n\x02bt\x18\x07\x20\x01(\x0b2\x1c.netsim.model.Chip.BluetoothH\0R\x02b\
     t\x12,\n\x03uwb\x18\x08\x20\x01(\x0b2\x18.netsim.model.Chip.RadioH\0R\
     \x03uwb\x12.\n\x04wifi\x18\t\x20\x01(\x0b2\x18.netsim.model.Chip.RadioH\
-    \0R\x04wifi\x1a~\n\x05Radio\x12)\n\x05state\x18\x01\x20\x01(\x0e2\x13.ne\
-    tsim.model.StateR\x05state\x12\x14\n\x05range\x18\x02\x20\x01(\x02R\x05r\
-    ange\x12\x19\n\x08tx_count\x18\x03\x20\x01(\x05R\x07txCount\x12\x19\n\
-    \x08rx_count\x18\x04\x20\x01(\x05R\x07rxCount\x1ax\n\tBluetooth\x127\n\n\
-    low_energy\x18\x01\x20\x01(\x0b2\x18.netsim.model.Chip.RadioR\tlowEnergy\
-    \x122\n\x07classic\x18\x02\x20\x01(\x0b2\x18.netsim.model.Chip.RadioR\
-    \x07classicB\x06\n\x04chip\"\xe1\x01\n\x06Device\x12\x0e\n\x02id\x18\x01\
-    \x20\x01(\x05R\x02id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\
-    \x18\n\x07visible\x18\x03\x20\x01(\x08R\x07visible\x122\n\x08position\
-    \x18\x04\x20\x01(\x0b2\x16.netsim.model.PositionR\x08position\x12;\n\x0b\
-    orientation\x18\x05\x20\x01(\x0b2\x19.netsim.model.OrientationR\x0borien\
-    tation\x12(\n\x05chips\x18\x06\x20\x03(\x0b2\x12.netsim.model.ChipR\x05c\
-    hips\"7\n\x05Scene\x12.\n\x07devices\x18\x01\x20\x03(\x0b2\x14.netsim.mo\
-    del.DeviceR\x07devices\"\x99\x02\n\x07Capture\x12\x0e\n\x02id\x18\x01\
-    \x20\x01(\x05R\x02id\x124\n\tchip_kind\x18\x02\x20\x01(\x0e2\x17.netsim.\
-    common.ChipKindR\x08chipKind\x12\x1f\n\x0bdevice_name\x18\x03\x20\x01(\t\
-    R\ndeviceName\x12)\n\x05state\x18\x04\x20\x01(\x0e2\x13.netsim.model.Sta\
-    teR\x05state\x12\x12\n\x04size\x18\x05\x20\x01(\x05R\x04size\x12\x18\n\
-    \x07records\x18\x06\x20\x01(\x05R\x07records\x128\n\ttimestamp\x18\x07\
-    \x20\x01(\x0b2\x1a.google.protobuf.TimestampR\ttimestamp\x12\x14\n\x05va\
-    lid\x18\x08\x20\x01(\x08R\x05valid*e\n\x07PhyKind\x12\x08\n\x04NONE\x10\
-    \0\x12\x15\n\x11BLUETOOTH_CLASSIC\x10\x01\x12\x18\n\x14BLUETOOTH_LOW_ENE\
-    RGY\x10\x02\x12\x08\n\x04WIFI\x10\x03\x12\x07\n\x03UWB\x10\x04\x12\x0c\n\
-    \x08WIFI_RTT\x10\x05*%\n\x05State\x12\x0b\n\x07UNKNOWN\x10\0\x12\x06\n\
-    \x02ON\x10\x01\x12\x07\n\x03OFF\x10\x02b\x06proto3\
+    \0R\x04wifi\x12*\n\x03nfc\x18\x0b\x20\x01(\x0b2\x16.netsim.model.Chip.Nf\
+    cH\0R\x03nfc\x12:\n\tbandwidth\x18\n\x20\x01(\x0b2\x1c.netsim.model.Chip\
+    .BandwidthR\tbandwidth\x1a~\n\x05Radio\x12)\n\x05state\x18\x01\x20\x01(\
+    \x0e2\x13.netsim.model.StateR\x05state\x12\x14\n\x05range\x18\x02\x20\
+    \x01(\x02R\x05range\x12\x19\n\x08tx_count\x18\x03\x20\x01(\x05R\x07txCou\
+    nt\x12\x19\n\x08rx_count\x18\x04\x20\x01(\x05R\x07rxCount\x1ax\n\tBlueto\
+    oth\x127\n\nlow_energy\x18\x01\x20\x01(\x0b2\x18.netsim.model.Chip.Radio\
+    R\tlowEnergy\x122\n\x07classic\x18\x02\x20\x01(\x0b2\x18.netsim.model.Ch\
+    ip.RadioR\x07classic\x1a~\n\x03Nfc\x12.\n\x05radio\x18\x01\x20\x01(\x0b2\
+    \x18.netsim.model.Chip.RadioR\x05radio\x12,\n\x03tag\x18\x02\x20\x01(\
+    \x0b2\x1a.netsim.model.Chip.Nfc.TagR\x03tag\x1a\x19\n\x03Tag\x12\x12\n\
+    \x04ndef\x18\x01\x20\x01(\x0cR\x04ndef\x1a3\n\tBandwidth\x12&\n\x0fbits_\
+    per_second\x18\x01\x20\x01(\x04R\rbitsPerSecondB\x06\n\x04chip\"\x86\x02\
+    \n\x06Device\x12\x0e\n\x02id\x18\x01\x20\x01(\x05R\x02id\x12\x12\n\x04na\
+    me\x18\x02\x20\x01(\tR\x04name\x12\x18\n\x07visible\x18\x03\x20\x01(\x08\
+    R\x07visible\x122\n\x08position\x18\x04\x20\x01(\x0b2\x16.netsim.model.P\
+    ositionR\x08position\x12;\n\x0borientation\x18\x05\x20\x01(\x0b2\x19.net\
+    sim.model.OrientationR\x0borientation\x12(\n\x05chips\x18\x06\x20\x03(\
+    \x0b2\x12.netsim.model.ChipR\x05chips\x12\x19\n\x05group\x18\x07\x20\x01\
+    (\tH\0R\x05group\x88\x01\x01B\x08\n\x06_group\"7\n\x05Scene\x12.\n\x07de\
+    vices\x18\x01\x20\x03(\x0b2\x14.netsim.model.DeviceR\x07devices\"\xb8\
+    \x01\n\nSavedState\x12)\n\x05scene\x18\x01\x20\x01(\x0b2\x13.netsim.mode\
+    l.SceneR\x05scene\x12B\n\x08settings\x18\x02\x20\x03(\x0b2&.netsim.model\
+    .SavedState.SettingsEntryR\x08settings\x1a;\n\rSettingsEntry\x12\x10\n\
+    \x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\t\
+    R\x05value:\x028\x01\"\xf9\x0c\n\x07Capture\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\x05R\x02id\x124\n\tchip_kind\x18\x02\x20\x01(\x0e2\x17.netsim.comm\
+    on.ChipKindR\x08chipKind\x12\x1f\n\x0bdevice_name\x18\x03\x20\x01(\tR\nd\
+    eviceName\x12)\n\x05state\x18\x04\x20\x01(\x0e2\x13.netsim.model.StateR\
+    \x05state\x12\x12\n\x04size\x18\x05\x20\x01(\x05R\x04size\x12\x18\n\x07r\
+    ecords\x18\x06\x20\x01(\x05R\x07records\x128\n\ttimestamp\x18\x07\x20\
+    \x01(\x0b2\x1a.google.protobuf.TimestampR\ttimestamp\x12\x14\n\x05valid\
+    \x18\x08\x20\x01(\x08R\x05valid\x12:\n\x08sampling\x18\t\x20\x01(\x0b2\
+    \x1e.netsim.model.Capture.SamplingR\x08sampling\x12\x18\n\x07packets\x18\
+    \n\x20\x01(\x05R\x07packets\x12D\n\x0cquota_status\x18\x0b\x20\x01(\x0e2\
+    !.netsim.model.Capture.QuotaStatusR\x0bquotaStatus\x12\x16\n\x06sha256\
+    \x18\x0c\x20\x01(\tR\x06sha256\x124\n\x06format\x18\r\x20\x01(\x0e2\x1c.\
+    netsim.model.Capture.FormatR\x06format\x129\n\x08segments\x18\x0e\x20\
+    \x03(\x0b2\x1d.netsim.model.Capture.SegmentR\x08segments\x124\n\x06filte\
+    r\x18\x0f\x20\x01(\x0b2\x1c.netsim.model.Capture.FilterR\x06filter\x12.\
+    \n\x04mode\x18\x10\x20\x01(\x0e2\x1a.netsim.model.Capture.ModeR\x04mode\
+    \x12\x1e\n\ncompressed\x18\x11\x20\x01(\x08R\ncompressed\x12\x18\n\x07sn\
+    aplen\x18\x12\x20\x01(\rR\x07snaplen\x12(\n\x10bytes_per_second\x18\x13\
+    \x20\x01(\x01R\x0ebytesPerSecond\x12,\n\x12packets_per_second\x18\x14\
+    \x20\x01(\x01R\x10packetsPerSecond\x12;\n\x0blast_record\x18\x15\x20\x01\
+    (\x0b2\x1a.google.protobuf.TimestampR\nlastRecord\x12\x14\n\x05error\x18\
+    \x16\x20\x01(\tR\x05error\x12\x1b\n\tlive_path\x18\x17\x20\x01(\tR\x08li\
+    vePath\x12\x1f\n\x0blive_reader\x18\x18\x20\x01(\x08R\nliveReader\x1a<\n\
+    \x08Sampling\x12\x15\n\x06one_in\x18\x01\x20\x01(\rR\x05oneIn\x12\x19\n\
+    \x08max_rate\x18\x02\x20\x01(\rR\x07maxRate\x1a\x9f\x01\n\x07Segment\x12\
+    \x14\n\x05index\x18\x01\x20\x01(\rR\x05index\x12\x12\n\x04size\x18\x02\
+    \x20\x01(\x05R\x04size\x12\x18\n\x07records\x18\x03\x20\x01(\x05R\x07rec\
+    ords\x128\n\ttimestamp\x18\x04\x20\x01(\x0b2\x1a.google.protobuf.Timesta\
+    mpR\ttimestamp\x12\x16\n\x06sha256\x18\x05\x20\x01(\tR\x06sha256\x1a\xc3\
+    \x02\n\x06Filter\x12!\n\x0cpacket_types\x18\x01\x20\x03(\rR\x0bpacketTyp\
+    es\x12D\n\tdirection\x18\x02\x20\x01(\x0e2&.netsim.model.Capture.Filter.\
+    DirectionR\tdirection\x12\x1d\n\nmin_length\x18\x03\x20\x01(\rR\tminLeng\
+    th\x12\x1d\n\nmax_length\x18\x04\x20\x01(\rR\tmaxLength\x12\x18\n\x07pat\
+    tern\x18\x05\x20\x01(\x0cR\x07pattern\x12\x16\n\x06offset\x18\x06\x20\
+    \x01(\rR\x06offset\x12\x1a\n\x08anywhere\x18\x07\x20\x01(\x08R\x08anywhe\
+    re\"D\n\tDirection\x12\x07\n\x03ANY\x10\0\x12\x16\n\x12HOST_TO_CONTROLLE\
+    R\x10\x01\x12\x16\n\x12CONTROLLER_TO_HOST\x10\x02\"A\n\x0bQuotaStatus\
+    \x12\x13\n\x0fBELOW_WATERMARK\x10\0\x12\x13\n\x0fABOVE_WATERMARK\x10\x01\
+    \x12\x08\n\x04FULL\x10\x02\"+\n\x06Format\x12\x08\n\x04PCAP\x10\0\x12\n\
+    \n\x06PCAPNG\x10\x01\x12\x0b\n\x07BTSNOOP\x10\x02\"\x1a\n\x04Mode\x12\
+    \x08\n\x04FILE\x10\0\x12\x08\n\x04RING\x10\x01*e\n\x07PhyKind\x12\x08\n\
+    \x04NONE\x10\0\x12\x15\n\x11BLUETOOTH_CLASSIC\x10\x01\x12\x18\n\x14BLUET\
+    OOTH_LOW_ENERGY\x10\x02\x12\x08\n\x04WIFI\x10\x03\x12\x07\n\x03UWB\x10\
+    \x04\x12\x0c\n\x08WIFI_RTT\x10\x05*%\n\x05State\x12\x0b\n\x07UNKNOWN\x10\
+    \0\x12\x06\n\x02ON\x10\x01\x12\x07\n\x03OFF\x10\x02b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -1889,18 +3681,29 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             let mut deps = ::std::vec::Vec::with_capacity(2);
             deps.push(super::common::file_descriptor().clone());
             deps.push(::protobuf::well_known_types::timestamp::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(8);
+            let mut messages = ::std::vec::Vec::with_capacity(15);
             messages.push(Position::generated_message_descriptor_data());
             messages.push(Orientation::generated_message_descriptor_data());
             messages.push(Chip::generated_message_descriptor_data());
             messages.push(Device::generated_message_descriptor_data());
             messages.push(Scene::generated_message_descriptor_data());
+            messages.push(SavedState::generated_message_descriptor_data());
             messages.push(Capture::generated_message_descriptor_data());
             messages.push(chip::Radio::generated_message_descriptor_data());
             messages.push(chip::Bluetooth::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(2);
+            messages.push(chip::Nfc::generated_message_descriptor_data());
+            messages.push(chip::Bandwidth::generated_message_descriptor_data());
+            messages.push(chip::nfc::Tag::generated_message_descriptor_data());
+            messages.push(capture::Sampling::generated_message_descriptor_data());
+            messages.push(capture::Segment::generated_message_descriptor_data());
+            messages.push(capture::Filter::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(6);
             enums.push(PhyKind::generated_enum_descriptor_data());
             enums.push(State::generated_enum_descriptor_data());
+            enums.push(capture::QuotaStatus::generated_enum_descriptor_data());
+            enums.push(capture::Format::generated_enum_descriptor_data());
+            enums.push(capture::Mode::generated_enum_descriptor_data());
+            enums.push(capture::filter::Direction::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
@@ -17,13 +17,23 @@ use frontend_client_cxx::ffi::{FrontendClient, GrpcMethod};
 use frontend_proto::common::ChipKind;
 use frontend_proto::frontend;
 use frontend_proto::frontend::patch_capture_request::PatchCapture as PatchCaptureProto;
+use frontend_proto::frontend::replay_fuzz_input_request::Target;
+use frontend_proto::frontend::set_link_impairment_request::JitterDistribution;
 use frontend_proto::model;
-use frontend_proto::model::chip::{Bluetooth as Chip_Bluetooth, Radio as Chip_Radio};
+use frontend_proto::model::capture::filter::Direction as FilterDirection;
+use frontend_proto::model::capture::{Filter, Format, Mode, Sampling};
+use frontend_proto::model::chip::nfc::Tag as Chip_Tag;
+use frontend_proto::model::chip::{
+    Bluetooth as Chip_Bluetooth, Nfc as Chip_Nfc, Radio as Chip_Radio,
+};
 use frontend_proto::model::{Chip, State};
 use frontend_proto::model::{Device, Position};
 use netsim_common::util::time_display::TimeDisplay;
-use protobuf::Message;
+use protobuf::well_known_types::field_mask::FieldMask;
+use protobuf::{Message, MessageField};
 use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
 
 pub type BinaryProtobuf = Vec<u8>;
 
@@ -34,6 +44,9 @@ pub struct NetsimArgs {
     /// Set verbose mode
     #[arg(short, long)]
     pub verbose: bool,
+    /// Name of the netsimd instance to connect to
+    #[arg(short, long, global = true)]
+    pub instance: Option<String>,
 }
 
 #[derive(Debug, Subcommand)]
@@ -45,15 +58,55 @@ pub enum Command {
     Radio(Radio),
     /// Set the device location
     Move(Move),
+    /// Move a device along a path of waypoints, or stop it without waypoints
+    Motion(Motion),
+    /// Cap the throughput of a chip of a device
+    Bandwidth(Bandwidth),
+    /// Emulate an NFC tag holding a URI on a device, or stop emulating without one
+    Tag(Tag),
+    /// Patch the devices of a group at once, after devices join or leave the group
+    Group(Group),
     /// Display device(s) information
     Devices(Devices),
     /// Reset Netsim device scene
     Reset,
+    /// Restart the facade of a chip in place, keeping its emulator connected
+    Restart(Restart),
+    /// Drop or delay the packets of a chip, or of the link between two chips
+    Impair(Impair),
+    /// Load a scene file of devices, beacons, impairments and settings into the running scene
+    Load(Load),
+    /// Save the simulation to a scene file
+    Save(Save),
+    /// Restore the simulation saved to a scene file
+    Restore(Restore),
     /// Open netsim Web UI
     Gui,
-    /// Control the packet capture functionalities with commands: list, patch, get
-    #[command(subcommand)]
+    /// Control the packet capture functionalities with commands: list, start, stop, patch, get, open, tail, replay
+    #[command(subcommand, visible_alias = "capture")]
     Pcap(Pcap),
+    /// Inspect the netsimd instances on this host with commands: list
+    #[command(subcommand)]
+    Instances(Instances),
+    /// Replay fuzzer corpus files through the netsimd parsers
+    FuzzReplay(FuzzReplay),
+    /// Display live packet rates of the chips and captures
+    Stats(Stats),
+    /// Stream the events of the devices, their chips and the captures until interrupted
+    Events(Events),
+    /// Bundle the captures, scene, events, settings and log of netsimd into an archive for a bug
+    Bugreport(Bugreport),
+    /// List the emulators running on this host and whether they are connected to netsim
+    Discover,
+    /// Pause the packet delivery of the whole simulation, holding the packets
+    Pause,
+    /// Resume the paused packet delivery, delivering the held packets in order
+    Resume,
+    /// Deliver the next held packets while the packet delivery is paused
+    Step(Step),
+    /// Control builtin Bluetooth devices with commands: advertise
+    #[command(subcommand)]
+    Bluetooth(Bluetooth),
 }
 
 impl Command {
@@ -64,38 +117,27 @@ impl Command {
         match self {
             Command::Version => Vec::new(),
             Command::Radio(cmd) => {
-                let mut chip = Chip { ..Default::default() };
-                let chip_state = match cmd.status {
-                    UpDownStatus::Up => State::ON,
-                    UpDownStatus::Down => State::OFF,
-                };
-                if cmd.radio_type == RadioType::Wifi {
-                    let mut wifi_chip = Chip_Radio::new();
-                    wifi_chip.state = chip_state.into();
-                    chip.set_wifi(wifi_chip);
-                    chip.kind = ChipKind::WIFI.into();
-                } else if cmd.radio_type == RadioType::Uwb {
-                    let mut uwb_chip = Chip_Radio::new();
-                    uwb_chip.state = chip_state.into();
-                    chip.set_uwb(uwb_chip);
-                    chip.kind = ChipKind::UWB.into();
-                } else {
-                    let mut bt_chip = Chip_Bluetooth::new();
-                    let mut bt_chip_radio = Chip_Radio::new();
-                    bt_chip_radio.state = chip_state.into();
-                    if cmd.radio_type == RadioType::Ble {
-                        bt_chip.low_energy = Some(bt_chip_radio).into();
-                    } else {
-                        bt_chip.classic = Some(bt_chip_radio).into();
+                let chip = radio_chip(cmd.radio_type, cmd.status);
+                // The radios of the devices matching a pattern
+                if let Some(pattern) = &cmd.device {
+                    return frontend::PatchSelectionRequest {
+                        selector: Some(frontend::Selector {
+                            device: pattern.clone(),
+                            ..Default::default()
+                        })
+                        .into(),
+                        chip: Some(chip).into(),
+                        ..Default::default()
                     }
-                    chip.kind = ChipKind::BLUETOOTH.into();
-                    chip.set_bt(bt_chip);
+                    .write_to_bytes()
+                    .unwrap();
                 }
                 let mut result = frontend::PatchDeviceRequest::new();
                 let mut device = Device::new();
-                device.name = cmd.name.to_owned();
+                device.name = cmd.name.clone().unwrap_or_default();
                 device.chips.push(chip);
                 result.device = Some(device).into();
+                result.update_mask = update_mask(&["chips"]);
                 result.write_to_bytes().unwrap()
             }
             Command::Move(cmd) => {
@@ -110,21 +152,221 @@ impl Command {
                 device.name = cmd.name.to_owned();
                 device.position = Some(position).into();
                 result.device = Some(device).into();
+                result.update_mask = update_mask(&["position"]);
                 result.write_to_bytes().unwrap()
             }
-            Command::Devices(_) => Vec::new(),
+            Command::Motion(cmd) => frontend::SetDeviceMotionRequest {
+                device_name: cmd.name.clone(),
+                waypoints: cmd.waypoints.clone(),
+                speed: cmd.speed,
+                repeat: cmd.repeat,
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap(),
+            Command::Bandwidth(cmd) => {
+                let chip = Chip {
+                    kind: match cmd.radio_type {
+                        RadioType::Ble | RadioType::Classic => ChipKind::BLUETOOTH,
+                        RadioType::Wifi => ChipKind::WIFI,
+                        RadioType::Uwb => ChipKind::UWB,
+                        RadioType::Nfc => ChipKind::NFC,
+                    }
+                    .into(),
+                    bandwidth: Some(model::chip::Bandwidth {
+                        bits_per_second: cmd.bits_per_second,
+                        ..Default::default()
+                    })
+                    .into(),
+                    ..Default::default()
+                };
+                let mut device = Device::new();
+                device.name = cmd.name.to_owned();
+                device.chips.push(chip);
+                frontend::PatchDeviceRequest {
+                    device: Some(device).into(),
+                    update_mask: update_mask(&["chips"]),
+                    ..Default::default()
+                }
+                .write_to_bytes()
+                .unwrap()
+            }
+            Command::Tag(cmd) => {
+                let mut nfc_chip = Chip_Nfc::new();
+                nfc_chip.tag = Some(Chip_Tag {
+                    ndef: cmd.uri.as_deref().map(ndef_uri).unwrap_or_default(),
+                    ..Default::default()
+                })
+                .into();
+                let mut chip = Chip { kind: ChipKind::NFC.into(), ..Default::default() };
+                chip.set_nfc(nfc_chip);
+                let mut device = Device::new();
+                device.name = cmd.name.to_owned();
+                device.chips.push(chip);
+                frontend::PatchDeviceRequest {
+                    device: Some(device).into(),
+                    update_mask: update_mask(&["chips"]),
+                    ..Default::default()
+                }
+                .write_to_bytes()
+                .unwrap()
+            }
+            Command::Group(cmd) => {
+                let device = Device {
+                    chips: cmd
+                        .radios
+                        .iter()
+                        .map(|&(radio_type, status)| radio_chip(radio_type, status))
+                        .collect(),
+                    position: cmd.position.clone().into(),
+                    ..Default::default()
+                };
+                frontend::PatchGroupRequest {
+                    group: cmd.group.clone(),
+                    join_device_names: cmd.join.clone(),
+                    leave_device_names: cmd.leave.clone(),
+                    device: Some(device).into(),
+                    relative: cmd.relative,
+                    capture: match cmd.capture {
+                        Some(OnOffState::On) => State::ON,
+                        Some(OnOffState::Off) => State::OFF,
+                        None => State::UNKNOWN,
+                    }
+                    .into(),
+                    ..Default::default()
+                }
+                .write_to_bytes()
+                .unwrap()
+            }
+            Command::Devices(cmd) => frontend::GetDevicesRequest {
+                name_filter: cmd.name.clone().unwrap_or_default(),
+                chip_kind: cmd.kind.map(ChipType::chip_kind).unwrap_or_default().into(),
+                page_size: cmd.page_size,
+                page_token: cmd.page_token.clone().unwrap_or_default(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap(),
             Command::Reset => Vec::new(),
+            Command::Restart(cmd) => {
+                frontend::RestartChipRequest { chip_id: cmd.chip_id, ..Default::default() }
+                    .write_to_bytes()
+                    .unwrap()
+            }
+            Command::Impair(cmd) => frontend::SetLinkImpairmentRequest {
+                chip_id: cmd.chip_id,
+                other_chip_id: cmd.peer.unwrap_or_default(),
+                drop_percent: cmd.drop_percent,
+                seed: cmd.seed.unwrap_or_default(),
+                latency_ms: cmd.latency,
+                jitter_ms: cmd.jitter,
+                jitter_distribution: match cmd.distribution {
+                    Jitter::Uniform => JitterDistribution::UNIFORM,
+                    Jitter::Normal => JitterDistribution::NORMAL,
+                }
+                .into(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap(),
+            Command::Load(cmd) => frontend::LoadScenarioRequest {
+                scene_file: Some(cmd.scene_file.clone()).into(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap(),
+            Command::Save(cmd) => frontend::SaveStateRequest {
+                path: cmd.path.clone().unwrap_or_default(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap(),
+            Command::Restore(cmd) => {
+                frontend::RestoreStateRequest { path: cmd.path.clone(), ..Default::default() }
+                    .write_to_bytes()
+                    .unwrap()
+            }
+            Command::Events(cmd) => frontend::SubscribeEventsRequest {
+                types: cmd.types.iter().map(|&event_type| event_type.into()).collect(),
+                device_name: cmd.device.clone().unwrap_or_default(),
+                since_id: cmd.since,
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap(),
+            Command::Stats(_) => Vec::new(),
+            Command::Bugreport(_) => Vec::new(),
+            Command::Discover => Vec::new(),
+            Command::Pause => Vec::new(),
+            Command::Resume => Vec::new(),
+            Command::Step(cmd) => {
+                frontend::StepDeliveryRequest { count: cmd.count, ..Default::default() }
+                    .write_to_bytes()
+                    .unwrap()
+            }
+            Command::Bluetooth(Bluetooth::Advertise(cmd)) => frontend::AdvertiseRequest {
+                name: cmd.name.clone().unwrap_or_default(),
+                interval_ms: cmd.interval.as_millis() as u32,
+                payload: cmd.payload.clone(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap(),
             Command::Gui => {
                 unimplemented!("get_request_bytes is not implemented for Gui Command.");
             }
+            Command::Instances(_) => {
+                unimplemented!("get_request_bytes is not implemented for Instances Command.");
+            }
+            Command::FuzzReplay(_) => {
+                unimplemented!("get_request_bytes not implemented for FuzzReplay command. Use get_requests instead.")
+            }
             Command::Pcap(pcap_cmd) => match pcap_cmd {
-                Pcap::List(_) => Vec::new(),
+                Pcap::List(cmd) => frontend::ListCaptureRequest {
+                    device_name_filter: cmd.device.clone().unwrap_or_default(),
+                    chip_kind: cmd.kind.map(ChipType::chip_kind).unwrap_or_default().into(),
+                    state: match cmd.state {
+                        Some(OnOffState::On) => State::ON,
+                        Some(OnOffState::Off) => State::OFF,
+                        None => State::UNKNOWN,
+                    }
+                    .into(),
+                    page_size: cmd.page_size,
+                    page_token: cmd.page_token.clone().unwrap_or_default(),
+                    ..Default::default()
+                }
+                .write_to_bytes()
+                .unwrap(),
                 Pcap::Get(_) => {
                     unimplemented!("get_request_bytes not implemented for Pcap Get command. Use get_requests instead.")
                 }
-                Pcap::Patch(_) => {
-                    unimplemented!("get_request_bytes not implemented for Pcap Patch command. Use get_requests instead.")
+                Pcap::Patch(_) | Pcap::Start(_) | Pcap::Stop(_) => {
+                    unimplemented!("get_request_bytes not implemented for Pcap Patch, Start and Stop commands. Use get_requests instead.")
+                }
+                Pcap::Tail(cmd) => {
+                    frontend::StreamCaptureRequest { chip_id: cmd.chip_id, ..Default::default() }
+                        .write_to_bytes()
+                        .unwrap()
+                }
+                Pcap::Open(_) => {
+                    unimplemented!("get_request_bytes not implemented for Pcap Open command.")
+                }
+                Pcap::Dump(_) => {
+                    unimplemented!("get_request_bytes not implemented for Pcap Dump command. Use get_requests instead.")
+                }
+                Pcap::Replay(cmd) => frontend::ReplayCaptureRequest {
+                    chip_id: cmd.chip_id,
+                    // netsimd opens the file, from its own working directory.
+                    path: std::fs::canonicalize(&cmd.file)
+                        .unwrap_or_else(|_| cmd.file.clone())
+                        .to_string_lossy()
+                        .into_owned(),
+                    speed: cmd.speed.unwrap_or_default(),
+                    burst: cmd.burst,
+                    ..Default::default()
                 }
+                .write_to_bytes()
+                .unwrap(),
             },
         }
     }
@@ -148,11 +390,107 @@ impl Command {
                     };
                     let mut patch_capture = PatchCaptureProto::new();
                     patch_capture.state = capture_state.into();
+                    patch_capture.format = cmd.format.format().into();
+                    if cmd.sample.is_some() || cmd.max_rate.is_some() {
+                        patch_capture.sampling = Some(Sampling {
+                            one_in: cmd.sample.unwrap_or_default(),
+                            max_rate: cmd.max_rate.unwrap_or_default(),
+                            ..Default::default()
+                        })
+                        .into();
+                    }
+                    patch_capture.filter = cmd.filter().into();
+                    if cmd.ring {
+                        patch_capture.mode = Mode::RING.into();
+                    }
+                    patch_capture.snaplen = cmd.snaplen.unwrap_or_default();
+                    patch_capture.append = cmd.append;
+                    // netsimd opens the pipe, from its own working directory.
+                    if let Some(live) = cmd.live.as_ref() {
+                        patch_capture.live_path = std::path::absolute(live)
+                            .unwrap_or_else(|_| live.clone())
+                            .to_string_lossy()
+                            .into_owned();
+                    }
                     result.patch = Some(patch_capture).into();
                     reqs.push(result.write_to_bytes().unwrap())
                 }
                 reqs
             }
+            Command::Pcap(Pcap::Start(cmd)) if cmd.selector.is_set() => {
+                let request = frontend::PatchSelectionRequest {
+                    selector: Some(cmd.selector.selector()).into(),
+                    capture: State::ON.into(),
+                    format: cmd.format.map(CaptureFormat::format).unwrap_or_default().into(),
+                    ..Default::default()
+                };
+                vec![request.write_to_bytes().unwrap()]
+            }
+            Command::Pcap(Pcap::Stop(cmd)) if cmd.selector.is_set() => {
+                let request = frontend::PatchSelectionRequest {
+                    selector: Some(cmd.selector.selector()).into(),
+                    capture: State::OFF.into(),
+                    ..Default::default()
+                };
+                vec![request.write_to_bytes().unwrap()]
+            }
+            Command::Pcap(Pcap::Start(cmd)) => {
+                let filtered_captures = Self::get_filtered_captures(client, &cmd.patterns);
+                let (patch_capture, mask) = match cmd.format {
+                    Some(format) => (
+                        PatchCaptureProto {
+                            state: State::ON.into(),
+                            format: format.format().into(),
+                            ..Default::default()
+                        },
+                        update_mask(&["state", "format"]),
+                    ),
+                    None => (
+                        PatchCaptureProto { state: State::ON.into(), ..Default::default() },
+                        update_mask(&["state"]),
+                    ),
+                };
+                filtered_captures
+                    .iter()
+                    .map(|capture| Self::masked_capture_request(capture.id, &patch_capture, &mask))
+                    .collect()
+            }
+            Command::Pcap(Pcap::Stop(cmd)) => {
+                let filtered_captures = Self::get_filtered_captures(client, &cmd.patterns);
+                let patch_capture =
+                    PatchCaptureProto { state: State::OFF.into(), ..Default::default() };
+                let mask = update_mask(&["state"]);
+                filtered_captures
+                    .iter()
+                    .map(|capture| Self::masked_capture_request(capture.id, &patch_capture, &mask))
+                    .collect()
+            }
+            Command::Pcap(Pcap::Dump(cmd)) => {
+                let filtered_captures = Self::get_filtered_captures(client, &cmd.patterns);
+                // The running ring captures keep their options.
+                filtered_captures
+                    .iter()
+                    .filter(|capture| {
+                        capture.state.enum_value_or_default() == State::ON
+                            && capture.mode.enum_value_or_default() == Mode::RING
+                    })
+                    .map(|capture| {
+                        let patch_capture = PatchCaptureProto {
+                            state: State::ON.into(),
+                            format: capture.format,
+                            mode: capture.mode,
+                            dump: true,
+                            ..Default::default()
+                        };
+                        let request = frontend::PatchCaptureRequest {
+                            id: capture.id,
+                            patch: Some(patch_capture).into(),
+                            ..Default::default()
+                        };
+                        request.write_to_bytes().unwrap()
+                    })
+                    .collect()
+            }
             Command::Pcap(Pcap::Get(cmd)) => {
                 let mut reqs = Vec::new();
                 let filtered_captures = Self::get_filtered_captures(client, &cmd.patterns);
@@ -160,21 +498,40 @@ impl Command {
                 for capture in &filtered_captures {
                     let mut result = frontend::GetCaptureRequest::new();
                     result.id = capture.id;
-                    reqs.push(result.write_to_bytes().unwrap());
+                    result.segment = cmd.segment.unwrap_or_default();
+                    result.offset = cmd.offset.unwrap_or_default();
+                    result.length = cmd.length.unwrap_or_default();
                     let time_display = TimeDisplay::new(
                         capture.timestamp.get_or_default().seconds,
                         capture.timestamp.get_or_default().nanos as u32,
                     );
-                    cmd.filenames.push(format!(
-                        "{:?}-{}-{}-{}",
+                    let extension = match capture.format.enum_value_or_default() {
+                        Format::PCAP => "pcap",
+                        Format::PCAPNG => "pcapng",
+                        Format::BTSNOOP => "btsnoop",
+                    };
+                    let suffix = cmd.segment.map(|index| format!(".{index}")).unwrap_or_default();
+                    // The file of a stopped capture may be compressed, not its segments.
+                    let gzip = if capture.compressed && result.segment == 0 { ".gz" } else { "" };
+                    let filename = format!(
+                        "{:?}-{}-{}-{}{suffix}.{extension}{gzip}",
                         capture.id,
                         capture.device_name.to_owned().replace(' ', "_"),
                         Self::chip_kind_to_string(capture.chip_kind.enum_value_or_default()),
                         time_display.utc_display()
-                    ));
+                    );
+                    // A resumed download gets the bytes after those of its file.
+                    if cmd.resume {
+                        if let Ok(metadata) = std::fs::metadata(cmd.dir().join(&filename)) {
+                            result.offset = metadata.len();
+                        }
+                    }
+                    reqs.push(result.write_to_bytes().unwrap());
+                    cmd.filenames.push(filename);
                 }
                 reqs
             }
+            Command::FuzzReplay(cmd) => cmd.get_requests(),
             _ => {
                 unimplemented!(
                     "get_requests not implemented for this command. Use get_request_bytes instead."
@@ -183,9 +540,26 @@ impl Command {
         }
     }
 
+    /// Returns the PatchCaptureRequest patching the fields of the mask of a
+    /// capture.
+    pub fn masked_capture_request(
+        id: i32,
+        patch_capture: &PatchCaptureProto,
+        mask: &MessageField<FieldMask>,
+    ) -> BinaryProtobuf {
+        let mut patch_capture = patch_capture.clone();
+        patch_capture.update_mask = mask.clone();
+        let request = frontend::PatchCaptureRequest {
+            id,
+            patch: Some(patch_capture).into(),
+            ..Default::default()
+        };
+        request.write_to_bytes().unwrap()
+    }
+
     fn get_filtered_captures(
         client: &cxx::UniquePtr<FrontendClient>,
-        patterns: &Vec<String>,
+        patterns: &[String],
     ) -> Vec<model::Capture> {
         // Get list of captures
         let result = client.send_grpc(&GrpcMethod::ListCapture, &Vec::new());
@@ -212,7 +586,11 @@ pub struct Radio {
     #[arg(value_enum, ignore_case = true)]
     pub status: UpDownStatus,
     /// Device name
-    pub name: String,
+    #[arg(required_unless_present = "device")]
+    pub name: Option<String>,
+    /// Pattern of the names of the devices instead, where * matches any characters and ? a single one
+    #[arg(long, conflicts_with = "name")]
+    pub device: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -221,6 +599,7 @@ pub enum RadioType {
     Classic,
     Wifi,
     Uwb,
+    Nfc,
 }
 
 impl fmt::Display for RadioType {
@@ -241,6 +620,45 @@ impl fmt::Display for UpDownStatus {
     }
 }
 
+/// Returns the chip patching the state of a radio.
+fn radio_chip(radio_type: RadioType, status: UpDownStatus) -> Chip {
+    let mut chip = Chip { ..Default::default() };
+    let chip_state = match status {
+        UpDownStatus::Up => State::ON,
+        UpDownStatus::Down => State::OFF,
+    };
+    if radio_type == RadioType::Wifi {
+        let mut wifi_chip = Chip_Radio::new();
+        wifi_chip.state = chip_state.into();
+        chip.set_wifi(wifi_chip);
+        chip.kind = ChipKind::WIFI.into();
+    } else if radio_type == RadioType::Uwb {
+        let mut uwb_chip = Chip_Radio::new();
+        uwb_chip.state = chip_state.into();
+        chip.set_uwb(uwb_chip);
+        chip.kind = ChipKind::UWB.into();
+    } else if radio_type == RadioType::Nfc {
+        let mut nfc_chip_radio = Chip_Radio::new();
+        nfc_chip_radio.state = chip_state.into();
+        let mut nfc_chip = Chip_Nfc::new();
+        nfc_chip.radio = Some(nfc_chip_radio).into();
+        chip.set_nfc(nfc_chip);
+        chip.kind = ChipKind::NFC.into();
+    } else {
+        let mut bt_chip = Chip_Bluetooth::new();
+        let mut bt_chip_radio = Chip_Radio::new();
+        bt_chip_radio.state = chip_state.into();
+        if radio_type == RadioType::Ble {
+            bt_chip.low_energy = Some(bt_chip_radio).into();
+        } else {
+            bt_chip.classic = Some(bt_chip_radio).into();
+        }
+        chip.kind = ChipKind::BLUETOOTH.into();
+        chip.set_bt(bt_chip);
+    }
+    chip
+}
+
 #[derive(Debug, Args)]
 pub struct Move {
     /// Device name
@@ -253,11 +671,162 @@ pub struct Move {
     pub z: Option<f32>,
 }
 
+#[derive(Debug, Args)]
+pub struct Motion {
+    /// Device name
+    pub name: String,
+    /// Waypoints of the path, like 1,2 or 1,2,3 with a z position
+    #[arg(value_parser = parse_waypoint)]
+    pub waypoints: Vec<Position>,
+    /// Speed along the path in meters per second
+    #[arg(long, default_value_t = 1.0)]
+    pub speed: f32,
+    /// Loop back to the first waypoint at the end of the path
+    #[arg(long)]
+    pub repeat: bool,
+}
+
+/// Parses a waypoint of comma separated x, y and optional z positions.
+fn parse_waypoint(value: &str) -> Result<Position, String> {
+    let coordinates: Vec<f32> = value
+        .split(',')
+        .map(|coordinate| coordinate.trim().parse::<f32>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| format!("invalid waypoint: {value}"))?;
+    match coordinates[..] {
+        [x, y] => Ok(Position { x, y, ..Default::default() }),
+        [x, y, z] => Ok(Position { x, y, z, ..Default::default() }),
+        _ => Err(format!("invalid waypoint {value}, expected x,y or x,y,z")),
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct Bandwidth {
+    /// Radio type of the chip
+    #[arg(value_enum, ignore_case = true)]
+    pub radio_type: RadioType,
+    /// Device name
+    pub name: String,
+    /// Bits per second like 1M or 500k, 0 removes the cap
+    #[arg(value_parser = parse_bits_per_second)]
+    pub bits_per_second: u64,
+}
+
+#[derive(Debug, Args)]
+pub struct Tag {
+    /// Device name
+    pub name: String,
+    /// URI read from the tag, like https://example.com
+    pub uri: Option<String>,
+}
+
+/// Returns an NDEF message with a single URI record.
+pub fn ndef_uri(uri: &str) -> Vec<u8> {
+    // The payload starts with the identifier code 0, no URI prefix.
+    let payload = [&[0], uri.as_bytes()].concat();
+    // A message begin, message end, well-known type record, short with a
+    // payload below 256 bytes.
+    let mut ndef = match u8::try_from(payload.len()) {
+        Ok(len) => vec![0xd1, 1, len],
+        Err(_) => [&[0xc1, 1][..], &(payload.len() as u32).to_be_bytes()].concat(),
+    };
+    ndef.push(b'U');
+    ndef.extend(payload);
+    ndef
+}
+
+/// Parses a rate in bits per second with an optional k, M or G suffix.
+fn parse_bits_per_second(value: &str) -> Result<u64, String> {
+    let rate = value.trim_end_matches("bps");
+    let (number, multiplier) = match rate.char_indices().last() {
+        Some((i, 'k' | 'K')) => (&rate[..i], 1e3),
+        Some((i, 'M')) => (&rate[..i], 1e6),
+        Some((i, 'G')) => (&rate[..i], 1e9),
+        _ => (rate, 1.0),
+    };
+    match number.parse::<f64>() {
+        Ok(number) if number >= 0.0 && (number * multiplier).is_finite() => {
+            Ok((number * multiplier).round() as u64)
+        }
+        _ => Err(format!("invalid rate {value}, expected bits per second like 1M or 500k")),
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct Group {
+    /// Group name
+    pub group: String,
+    /// Device joining the group, repeated for each device
+    #[arg(long)]
+    pub join: Vec<String>,
+    /// Device leaving the group, repeated for each device
+    #[arg(long)]
+    pub leave: Vec<String>,
+    /// Radio status of the devices, like ble=down, repeated for each radio type
+    #[arg(long = "radio", value_parser = parse_radio)]
+    pub radios: Vec<(RadioType, UpDownStatus)>,
+    /// Position of the devices, like 1,2 or 1,2,3 with a z position
+    #[arg(long = "move", value_parser = parse_waypoint)]
+    pub position: Option<Position>,
+    /// Move the devices by the position, keeping the layout of the group
+    #[arg(long, requires = "position")]
+    pub relative: bool,
+    /// Start or stop the captures of the chips of the devices
+    #[arg(long, value_enum, ignore_case = true)]
+    pub capture: Option<OnOffState>,
+}
+
+/// Returns the update mask of a patch request patching only the fields of
+/// the paths, so the other fields, e.g. the visibility of a device, keep
+/// their values.
+fn update_mask(paths: &[&str]) -> MessageField<FieldMask> {
+    let paths = paths.iter().map(|path| path.to_string()).collect();
+    Some(FieldMask { paths, ..Default::default() }).into()
+}
+
+/// Parses a radio status like ble=down.
+fn parse_radio(value: &str) -> Result<(RadioType, UpDownStatus), String> {
+    let (radio_type, status) =
+        value.split_once('=').ok_or(format!("invalid radio {value}, expected like ble=down"))?;
+    Ok((RadioType::from_str(radio_type, true)?, UpDownStatus::from_str(status, true)?))
+}
+
 #[derive(Debug, Args)]
 pub struct Devices {
-    /// Continuously print device(s) information every second
+    /// Continuously print device(s) information on each change of the devices
     #[arg(short, long)]
     pub continuous: bool,
+    /// List only the devices with a name containing this substring
+    #[arg(long, conflicts_with = "continuous")]
+    pub name: Option<String>,
+    /// List only the devices with a chip of this kind
+    #[arg(long, value_enum, ignore_case = true, conflicts_with = "continuous")]
+    pub kind: Option<ChipType>,
+    /// List at most this many devices, with the token of the next page
+    #[arg(long, default_value_t = 0, conflicts_with = "continuous")]
+    pub page_size: u32,
+    /// List the page of this token, printed with the previous page
+    #[arg(long, conflicts_with = "continuous")]
+    pub page_token: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ChipType {
+    Bluetooth,
+    Wifi,
+    Uwb,
+    Nfc,
+}
+
+impl ChipType {
+    fn chip_kind(self) -> ChipKind {
+        match self {
+            ChipType::Bluetooth => ChipKind::BLUETOOTH,
+            ChipType::Wifi => ChipKind::WIFI,
+            ChipType::Uwb => ChipKind::UWB,
+            ChipType::Nfc => ChipKind::NFC,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -266,20 +835,71 @@ pub enum OnOffState {
     Off,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CaptureFormat {
+    Pcap,
+    Pcapng,
+    Btsnoop,
+}
+
+impl CaptureFormat {
+    /// Returns the Format of the Capture proto.
+    pub fn format(self) -> Format {
+        match self {
+            CaptureFormat::Pcap => Format::PCAP,
+            CaptureFormat::Pcapng => Format::PCAPNG,
+            CaptureFormat::Btsnoop => Format::BTSNOOP,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CaptureDirection {
+    HostToController,
+    ControllerToHost,
+}
+
 #[derive(Debug, Subcommand)]
 pub enum Pcap {
     /// List currently available Captures (packet captures)
     List(ListCapture),
+    /// Start the packet captures, keeping their other options
+    Start(StartCapture),
+    /// Stop the packet captures
+    Stop(StopCapture),
     /// Patch a Capture source to turn packet capture on/off
     Patch(PatchCapture),
     /// Download the packet capture content
     Get(GetCapture),
+    /// Download a packet capture and open it in Wireshark
+    Open(OpenCapture),
+    /// Stream the packets of a running capture to stdout as they are recorded, e.g. for `tshark -i -`
+    Tail(TailCapture),
+    /// Write the ring buffer of running ring captures to their files
+    Dump(DumpCapture),
+    /// Replay the host packets of a Bluetooth pcap or btsnoop file to a chip
+    Replay(ReplayCapture),
 }
 
 #[derive(Debug, Args)]
 pub struct ListCapture {
     /// Optional strings of pattern for captures to list. Possible filter fields include Capture ID, Device Name, and Chip Kind
     pub patterns: Vec<String>,
+    /// List only the captures of the devices with a name containing this substring
+    #[arg(long)]
+    pub device: Option<String>,
+    /// List only the captures of the chips of this kind
+    #[arg(long, value_enum, ignore_case = true)]
+    pub kind: Option<ChipType>,
+    /// List only the captures in this state
+    #[arg(long, value_enum, ignore_case = true)]
+    pub state: Option<OnOffState>,
+    /// List at most this many captures, with the token of the next page
+    #[arg(long, default_value_t = 0)]
+    pub page_size: u32,
+    /// List the page of this token, printed with the previous page
+    #[arg(long)]
+    pub page_token: Option<String>,
 }
 
 #[derive(Debug, Args)]
@@ -289,6 +909,139 @@ pub struct PatchCapture {
     pub state: OnOffState,
     /// Optional strings of pattern for captures to patch. Possible filter fields include Capture ID, Device Name, and Chip Kind
     pub patterns: Vec<String>,
+    /// Record only 1 in N packets
+    #[arg(long, value_name = "N")]
+    pub sample: Option<u32>,
+    /// Record at most this many packets per second
+    #[arg(long)]
+    pub max_rate: Option<u32>,
+    /// File format of a started capture
+    #[arg(long, value_enum, ignore_case = true, default_value = "pcap")]
+    pub format: CaptureFormat,
+    /// Record only the packets of this H4 packet type, repeatable
+    #[arg(long = "packet-type", value_name = "TYPE")]
+    pub packet_types: Vec<u32>,
+    /// Record only the packets sent in this direction
+    #[arg(long, value_enum, ignore_case = true)]
+    pub direction: Option<CaptureDirection>,
+    /// Record only the packets of at least this many bytes
+    #[arg(long)]
+    pub min_length: Option<u32>,
+    /// Record only the packets of at most this many bytes
+    #[arg(long)]
+    pub max_length: Option<u32>,
+    /// Record only the packets holding these bytes, in hex
+    #[arg(long, value_parser = parse_hex)]
+    pub pattern: Option<HexBytes>,
+    /// Offset of the pattern in the packets
+    #[arg(long, requires = "pattern", conflicts_with = "anywhere")]
+    pub offset: Option<u32>,
+    /// Match the pattern anywhere in the packets
+    #[arg(long, requires = "pattern")]
+    pub anywhere: bool,
+    /// Record the latest packets in a ring buffer in memory, written to the
+    /// capture file by `pcap dump`
+    #[arg(long)]
+    pub ring: bool,
+    /// Record only the first N bytes of each packet, e.g. to keep the
+    /// headers without the payloads
+    #[arg(long, value_name = "N")]
+    pub snaplen: Option<u32>,
+    /// Also write a started capture to this named pipe, created if missing,
+    /// to watch it live with `wireshark -k -i <FIFO>`
+    #[arg(long, value_name = "FIFO", conflicts_with = "ring")]
+    pub live: Option<PathBuf>,
+    /// Continue the file of the stopped capture instead of replacing it
+    #[arg(long, conflicts_with_all = ["ring", "snaplen"])]
+    pub append: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct StartCapture {
+    /// Optional strings of pattern for captures to start. Possible filter fields include Capture ID, Device Name, and Chip Kind
+    pub patterns: Vec<String>,
+    /// File format of the started captures [default: the format of the latest capture]
+    #[arg(long, value_enum, ignore_case = true)]
+    pub format: Option<CaptureFormat>,
+    #[command(flatten)]
+    pub selector: SelectorArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct StopCapture {
+    /// Optional strings of pattern for captures to stop. Possible filter fields include Capture ID, Device Name, and Chip Kind
+    pub patterns: Vec<String>,
+    #[command(flatten)]
+    pub selector: SelectorArgs,
+}
+
+/// The chips selected by netsimd instead of the patterns
+#[derive(Debug, Args)]
+pub struct SelectorArgs {
+    /// Select the chips of the devices with a name matching this pattern, where * matches any characters and ? a single one
+    #[arg(long, conflicts_with = "patterns")]
+    pub device: Option<String>,
+    /// Select the chips of this kind
+    #[arg(long, value_enum, ignore_case = true, conflicts_with = "patterns")]
+    pub chip_kind: Option<ChipType>,
+}
+
+impl SelectorArgs {
+    /// Whether the chips are selected by netsimd.
+    pub fn is_set(&self) -> bool {
+        self.device.is_some() || self.chip_kind.is_some()
+    }
+
+    /// Returns the Selector of the options.
+    pub fn selector(&self) -> frontend::Selector {
+        frontend::Selector {
+            device: self.device.clone().unwrap_or_default(),
+            chip_kind: self.chip_kind.map(ChipType::chip_kind).unwrap_or_default().into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct DumpCapture {
+    /// Optional strings of pattern for captures to dump. Possible filter fields include Capture ID, Device Name, and Chip Kind
+    pub patterns: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct ReplayCapture {
+    /// Chip ID of the Bluetooth chip receiving the packets
+    pub chip_id: i32,
+    /// Path of the pcap or btsnoop file
+    pub file: PathBuf,
+    /// Factor of the recorded pace of the packets, e.g. 2 replays twice as fast
+    #[arg(long, conflicts_with = "burst", value_parser = parse_speed)]
+    pub speed: Option<f64>,
+    /// Send the packets without waiting between them
+    #[arg(long)]
+    pub burst: bool,
+}
+
+impl PatchCapture {
+    /// Returns the filter of the filter options, None without any.
+    pub fn filter(&self) -> Option<Filter> {
+        let filter = Filter {
+            packet_types: self.packet_types.clone(),
+            direction: match self.direction {
+                None => FilterDirection::ANY,
+                Some(CaptureDirection::HostToController) => FilterDirection::HOST_TO_CONTROLLER,
+                Some(CaptureDirection::ControllerToHost) => FilterDirection::CONTROLLER_TO_HOST,
+            }
+            .into(),
+            min_length: self.min_length.unwrap_or_default(),
+            max_length: self.max_length.unwrap_or_default(),
+            pattern: self.pattern.clone().unwrap_or_default(),
+            offset: self.offset.unwrap_or_default(),
+            anywhere: self.anywhere,
+            ..Default::default()
+        };
+        (filter != Filter::default()).then_some(filter)
+    }
 }
 
 #[derive(Debug, Args)]
@@ -296,8 +1049,317 @@ pub struct GetCapture {
     /// Optional strings of pattern for captures to get. Possible filter fields include Capture ID, Device Name, and Chip Kind
     pub patterns: Vec<String>,
     /// Directory to store downloaded capture(s)
-    #[arg(short = 'o', long)]
+    #[arg(short = 'o', long, visible_alias = "output")]
     pub location: Option<String>,
+    /// Index of a rotated segment to download instead of the current file
+    #[arg(long)]
+    pub segment: Option<u32>,
+    /// Offset of the first byte to download, e.g. to tail a large capture
+    #[arg(long, conflicts_with = "resume")]
+    pub offset: Option<u64>,
+    /// Number of bytes to download from the offset [default: the rest of the file]
+    #[arg(long)]
+    pub length: Option<u64>,
+    /// Resume an interrupted download, appending to the downloaded file
+    #[arg(long)]
+    pub resume: bool,
     #[arg(skip)]
     pub filenames: Vec<String>,
 }
+
+impl GetCapture {
+    /// Returns the directory of the downloaded captures.
+    pub fn dir(&self) -> PathBuf {
+        match &self.location {
+            Some(location) => PathBuf::from(location),
+            None => std::env::current_dir().unwrap(),
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct TailCapture {
+    /// Chip ID of the running capture
+    pub chip_id: i32,
+}
+
+#[derive(Debug, Args)]
+pub struct OpenCapture {
+    /// Capture ID
+    pub id: i32,
+    /// Print the capture with tshark instead of opening Wireshark
+    #[arg(long)]
+    pub tshark: bool,
+    /// File of Wi-Fi keys to decrypt WPA traffic, one `<type>,<key>` per line
+    /// [default: $NETSIM_WIFI_KEYLOG]
+    #[arg(long)]
+    pub keylog: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct Bugreport {
+    /// Directory to store the bug report archive
+    #[arg(short = 'o', long)]
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct Restart {
+    /// Id of the chip, as listed by the devices command
+    pub chip_id: i32,
+}
+
+#[derive(Debug, Args)]
+pub struct Impair {
+    /// Id of the chip, as listed by the devices command
+    pub chip_id: i32,
+    /// Percent of the packets dropped
+    #[arg(value_parser = parse_percent)]
+    pub drop_percent: f32,
+    /// Id of the other chip of a Bluetooth link, all the packets of the chip if unset
+    #[arg(long)]
+    pub peer: Option<i32>,
+    /// Seed of the drops and the jitter, from the debug.seed setting and the chips if unset
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Fixed delay of the packets in milliseconds
+    #[arg(long, default_value_t = 0)]
+    pub latency: u32,
+    /// Variable delay added to the latency in milliseconds
+    #[arg(long, default_value_t = 0)]
+    pub jitter: u32,
+    /// Distribution of the jitter
+    #[arg(long, value_enum, default_value_t = Jitter::Uniform)]
+    pub distribution: Jitter,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Jitter {
+    Uniform,
+    Normal,
+}
+
+impl fmt::Display for Jitter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Parses a percent between 0 and 100.
+fn parse_percent(value: &str) -> Result<f32, String> {
+    match value.parse::<f32>() {
+        Ok(percent) if (0.0..=100.0).contains(&percent) => Ok(percent),
+        _ => Err(format!("invalid percent {value}, expected 0 to 100")),
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct Load {
+    /// Scene file in the JSON format of the SceneFile protobuf
+    #[arg(value_parser = parse_scene_file)]
+    pub scene_file: frontend::SceneFile,
+}
+
+#[derive(Debug, Args)]
+pub struct Save {
+    /// Scene file written by netsimd [default: a timestamped snapshot in the snapshot directory]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct Restore {
+    /// Scene file read by netsimd
+    pub path: String,
+}
+
+/// Reads and parses a scene file.
+fn parse_scene_file(path: &str) -> Result<frontend::SceneFile, String> {
+    let json = std::fs::read_to_string(path).map_err(|e| format!("unable to read {path}: {e}"))?;
+    protobuf_json_mapping::parse_from_str(&json).map_err(|e| format!("invalid scene file: {e}"))
+}
+
+#[derive(Debug, Args)]
+pub struct Step {
+    /// Number of held packets to deliver
+    #[arg(default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub count: u32,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Bluetooth {
+    /// Advertise from a temporary builtin beacon until interrupted with Ctrl-C
+    Advertise(Advertise),
+}
+
+/// Bytes given as a single hex value; a Vec<u8> field would take one value per byte.
+pub type HexBytes = Vec<u8>;
+
+#[derive(Debug, Args)]
+pub struct Advertise {
+    /// Name of the beacon, advertised as its complete local name
+    #[arg(long)]
+    pub name: Option<String>,
+    /// Advertising interval, like 100ms or 1s
+    #[arg(long, default_value = "100ms", value_parser = parse_interval)]
+    pub interval: Duration,
+    /// Advertising data structures in hex, advertised after the name
+    #[arg(long, default_value = "", value_parser = parse_hex)]
+    pub payload: HexBytes,
+}
+
+/// Parses an even number of hex digits.
+fn parse_hex(value: &str) -> Result<HexBytes, String> {
+    if !value.len().is_multiple_of(2) {
+        return Err(format!("odd number of hex digits: {value}"));
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|i| {
+            value
+                .get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or(format!("invalid hex: {value}"))
+        })
+        .collect()
+}
+
+/// Parses a positive factor of the replay pace.
+fn parse_speed(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(speed) if speed.is_finite() && speed > 0.0 => Ok(speed),
+        _ => Err(format!("invalid speed {value}, expected a positive number")),
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Instances {
+    /// List the netsimd instances with a discovery file
+    List,
+}
+
+#[derive(Debug, Args)]
+pub struct FuzzReplay {
+    /// Parser receiving the inputs
+    #[arg(value_enum, ignore_case = true)]
+    pub target: FuzzTarget,
+    /// Corpus directory or input file
+    pub corpus: String,
+    #[arg(skip)]
+    pub inputs: Vec<String>,
+}
+
+impl FuzzReplay {
+    /// Create a ReplayFuzzInputRequest for each corpus file.
+    pub fn get_requests(&mut self) -> Vec<BinaryProtobuf> {
+        let target = match self.target {
+            FuzzTarget::H4 => Target::H4,
+            FuzzTarget::Uci => Target::UCI,
+            FuzzTarget::Pcap => Target::PCAP,
+            FuzzTarget::HttpRequest => Target::HTTP_REQUEST,
+            FuzzTarget::Filter => Target::FILTER,
+        };
+        let mut reqs = Vec::new();
+        for path in Self::get_corpus_files(&self.corpus) {
+            let input = match std::fs::read(&path) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Unable to read {}: {e}", path.display());
+                    continue;
+                }
+            };
+            let name = path.display().to_string();
+            let result = frontend::ReplayFuzzInputRequest {
+                target: target.into(),
+                name: name.clone(),
+                input,
+                ..Default::default()
+            };
+            reqs.push(result.write_to_bytes().unwrap());
+            self.inputs.push(name);
+        }
+        reqs
+    }
+
+    // Returns the files of a corpus directory in name order, or the corpus
+    // itself if it is a file.
+    fn get_corpus_files(corpus: &str) -> Vec<PathBuf> {
+        let path = PathBuf::from(corpus);
+        if !path.is_dir() {
+            return vec![path];
+        }
+        let mut files: Vec<PathBuf> = match std::fs::read_dir(&path) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file())
+                .collect(),
+            Err(e) => {
+                eprintln!("Unable to read {corpus}: {e}");
+                Vec::new()
+            }
+        };
+        files.sort();
+        files
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct Stats {
+    /// Refresh interval, like 500ms, 1s or 1m
+    #[arg(long, default_value = "1s", value_parser = parse_interval)]
+    pub interval: Duration,
+    /// Print a JSON object per interval instead of a table
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct Events {
+    /// Event type to stream, like chip-state-changed, all types if unset
+    #[arg(long = "type", value_parser = parse_event_type)]
+    pub types: Vec<frontend::event::Type>,
+    /// Name of the device to stream the events of, all devices if unset
+    #[arg(long)]
+    pub device: Option<String>,
+    /// Id of an event to stream the recorded events after, only new events if unset
+    #[arg(long, default_value_t = 0)]
+    pub since: u64,
+}
+
+/// Parses an event type, case insensitive with dashes or underscores.
+fn parse_event_type(value: &str) -> Result<frontend::event::Type, String> {
+    use protobuf::{Enum, EnumFull};
+    frontend::event::Type::enum_descriptor()
+        .value_by_name(&value.to_uppercase().replace('-', "_"))
+        .and_then(|value| frontend::event::Type::from_i32(value.value()))
+        .filter(|&event_type| event_type != frontend::event::Type::UNSPECIFIED)
+        .ok_or(format!("invalid event type: {value}"))
+}
+
+/// Parses a positive interval with a ms, s or m unit; a bare number is in seconds.
+fn parse_interval(value: &str) -> Result<Duration, String> {
+    let (number, unit) = match value.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(index) => value.split_at(index),
+        None => (value, "s"),
+    };
+    let number: f64 = number.parse().map_err(|_| format!("invalid interval: {value}"))?;
+    let seconds = match unit {
+        "ms" => number / 1000.0,
+        "s" => number,
+        "m" => number * 60.0,
+        _ => return Err(format!("invalid interval unit: {unit}")),
+    };
+    match Duration::try_from_secs_f64(seconds) {
+        Ok(interval) if !interval.is_zero() => Ok(interval),
+        _ => Err(format!("interval must be positive: {value}")),
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum FuzzTarget {
+    H4,
+    Uci,
+    Pcap,
+    HttpRequest,
+    Filter,
+}
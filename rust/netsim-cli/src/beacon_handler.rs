@@ -0,0 +1,36 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use frontend_client_cxx::ClientResponseReadable;
+use frontend_proto::frontend::Beacon;
+use protobuf::Message;
+
+/// Implements handler for the beacon of bluetooth advertise
+pub struct BeaconHandler;
+
+impl ClientResponseReadable for BeaconHandler {
+    // function to handle the beacon sent once created
+    fn handle_chunk(&self, chunk: &[u8]) {
+        match Beacon::parse_from_bytes(chunk) {
+            Ok(beacon) => println!(
+                "Advertising as {} (chip {}) every {} ms, press Ctrl-C to stop.",
+                beacon.name, beacon.chip_id, beacon.interval_ms
+            ),
+            Err(e) => eprintln!("Unable to parse the beacon: {e}"),
+        }
+    }
+    // function to handle error response
+    fn handle_error(&self, error_code: u32, error_message: &str) {
+        eprintln!("Handling error code: {error_code}, msg: {error_message}");
+    }
+}
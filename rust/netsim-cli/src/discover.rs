@@ -0,0 +1,207 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Discovery of the emulators running on this host.
+//
+// Emulators are found through the discovery files the emulator writes for
+// its gRPC console, and through adb. Each emulator is reported as
+// connected when a device of this netsimd instance carries its serial or
+// AVD name.
+
+use frontend_client_cxx::ffi::{FrontendClient, GrpcMethod};
+use frontend_proto::frontend::GetDevicesResponse;
+use netsim_common::util::ini_file::IniFile;
+use netsim_common::util::os_utils::get_discovery_directory;
+use protobuf::Message;
+use std::process::Command;
+
+/// An emulator running on this host.
+#[derive(Debug, Default, PartialEq)]
+struct Emulator {
+    serial: String,
+    avd: Option<String>,
+    grpc_port: Option<String>,
+    // State of the emulator in adb, None when adb doesn't list it.
+    adb_state: Option<String>,
+}
+
+impl Emulator {
+    fn is_connected(&self, device_names: &[String]) -> bool {
+        device_names
+            .iter()
+            .any(|name| *name == self.serial || self.avd.as_ref().is_some_and(|avd| avd == name))
+    }
+}
+
+// Reads the discovery files of the running emulators, named pid_<pid>.ini.
+fn read_discovery_files() -> Vec<Emulator> {
+    let mut path = get_discovery_directory();
+    path.push("avd");
+    path.push("running");
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return Vec::new();
+    };
+    let mut emulators = Vec::new();
+    for entry in entries.flatten() {
+        let filename = entry.file_name().to_string_lossy().into_owned();
+        if !filename.starts_with("pid_") || !filename.ends_with(".ini") {
+            continue;
+        }
+        let mut ini_file = IniFile::new(entry.path().to_string_lossy().into_owned());
+        if ini_file.read().is_err() {
+            continue;
+        }
+        if let Some(port) = ini_file.get("port.serial") {
+            emulators.push(Emulator {
+                serial: format!("emulator-{port}"),
+                avd: ini_file.get("avd.name").map(String::from),
+                grpc_port: ini_file.get("grpc.port").map(String::from),
+                adb_state: None,
+            });
+        }
+    }
+    emulators
+}
+
+// Parses the output of `adb devices` into serials and states.
+fn parse_adb_devices(output: &str) -> Vec<(String, String)> {
+    output
+        .lines()
+        .skip_while(|line| !line.starts_with("List of devices attached"))
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some((fields.next()?.to_string(), fields.next()?.to_string()))
+        })
+        .collect()
+}
+
+// Merges the adb devices into the emulators of the discovery files.
+fn merge_adb_devices(emulators: &mut Vec<Emulator>, devices: Vec<(String, String)>) {
+    for (serial, state) in devices {
+        match emulators.iter_mut().find(|emulator| emulator.serial == serial) {
+            Some(emulator) => emulator.adb_state = Some(state),
+            None if serial.starts_with("emulator-") => {
+                emulators.push(Emulator { serial, adb_state: Some(state), ..Default::default() })
+            }
+            None => {}
+        }
+    }
+}
+
+fn running_emulators(verbose: bool) -> Vec<Emulator> {
+    let mut emulators = read_discovery_files();
+    match Command::new("adb").arg("devices").output() {
+        Ok(output) => merge_adb_devices(
+            &mut emulators,
+            parse_adb_devices(&String::from_utf8_lossy(&output.stdout)),
+        ),
+        Err(e) if verbose => println!("Unable to run adb: {e}"),
+        Err(_) => {}
+    }
+    emulators.sort_by(|a, b| a.serial.cmp(&b.serial));
+    emulators
+}
+
+/// Prints the emulators running on this host and whether they are
+/// connected to netsimd.
+pub fn discover(client: &cxx::UniquePtr<FrontendClient>, verbose: bool) -> Result<(), String> {
+    let result = client.send_grpc(&GrpcMethod::GetDevices, &Vec::new());
+    if !result.is_ok() {
+        return Err(format!("Grpc call error: {}", result.err()));
+    }
+    let devices = GetDevicesResponse::parse_from_bytes(result.byte_vec().as_slice())
+        .map_err(|e| e.to_string())?
+        .devices;
+    let device_names: Vec<String> = devices.into_iter().map(|device| device.name).collect();
+    let emulators = running_emulators(verbose);
+    if emulators.is_empty() {
+        println!("No running emulators found.");
+        return Ok(());
+    }
+    let serial_width = 16;
+    let avd_width = 24;
+    let port_width = 8;
+    let state_width = 14;
+    println!(
+        "{:serial_width$}{:avd_width$}{:port_width$}{:state_width$}NETSIM",
+        "SERIAL", "AVD", "GRPC", "ADB"
+    );
+    let mut disconnected = 0;
+    for emulator in emulators.iter() {
+        let connected = emulator.is_connected(&device_names);
+        disconnected += !connected as usize;
+        println!(
+            "{:serial_width$}{:avd_width$}{:port_width$}{:state_width$}{}",
+            emulator.serial,
+            emulator.avd.as_deref().unwrap_or("-"),
+            emulator.grpc_port.as_deref().unwrap_or("-"),
+            emulator.adb_state.as_deref().unwrap_or("-"),
+            if connected { "connected" } else { "not connected" }
+        );
+    }
+    if disconnected > 0 {
+        println!(
+            "{disconnected} emulator(s) not connected to this netsimd instance. Check that they \
+             were started with netsim enabled and the same instance, or try `netsim instances list`."
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_adb_devices() {
+        let output = "* daemon started successfully\n\
+                      List of devices attached\n\
+                      emulator-5554\tdevice\n\
+                      emulator-5556\toffline\n\
+                      R58M1234\tunauthorized\n\n";
+        assert_eq!(
+            parse_adb_devices(output),
+            [
+                ("emulator-5554".to_string(), "device".to_string()),
+                ("emulator-5556".to_string(), "offline".to_string()),
+                ("R58M1234".to_string(), "unauthorized".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_and_connect() {
+        let mut emulators = vec![Emulator {
+            serial: "emulator-5554".to_string(),
+            avd: Some("Pixel_7".to_string()),
+            ..Default::default()
+        }];
+        merge_adb_devices(
+            &mut emulators,
+            vec![
+                ("emulator-5554".to_string(), "device".to_string()),
+                ("emulator-5556".to_string(), "device".to_string()),
+                ("R58M1234".to_string(), "device".to_string()),
+            ],
+        );
+        // Physical devices are not emulators.
+        assert_eq!(emulators.len(), 2);
+        assert_eq!(emulators[0].adb_state.as_deref(), Some("device"));
+        let device_names = ["Pixel_7".to_string()];
+        assert!(emulators[0].is_connected(&device_names));
+        assert!(!emulators[1].is_connected(&device_names));
+        assert!(emulators[1].is_connected(&["emulator-5556".to_string()]));
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Mutex;
+
+use cxx::UniquePtr;
+use frontend_client_cxx::ffi::{FrontendClient, GrpcMethod};
+use frontend_client_cxx::{ClientResponseReadable, ClientResponseReader};
+use frontend_proto::frontend::event::Type;
+use frontend_proto::frontend::{
+    Event, GetDevicesResponse, ListEventsRequest, ListEventsResponse, SubscribeEventsRequest,
+};
+use frontend_proto::model::Device;
+use protobuf::Message;
+
+use crate::args::Command;
+
+/// The events changing the devices or the radio states of their chips.
+const DEVICE_EVENT_TYPES: [Type; 7] = [
+    Type::DEVICE_ADDED,
+    Type::DEVICE_REMOVED,
+    Type::DEVICE_PATCHED,
+    Type::CHIP_ADDED,
+    Type::CHIP_REMOVED,
+    Type::CHIP_STATE_CHANGED,
+    Type::RESET,
+];
+
+/// Implements handler for the events of a subscription
+pub struct EventHandler {
+    pub verbose: bool,
+}
+
+impl ClientResponseReadable for EventHandler {
+    // function to print each event as it is recorded
+    fn handle_chunk(&self, chunk: &[u8]) {
+        let event = match Event::parse_from_bytes(chunk) {
+            Ok(event) => event,
+            Err(e) => {
+                eprintln!("Unable to parse the event: {e}");
+                return;
+            }
+        };
+        if self.verbose {
+            println!("{}", protobuf_json_mapping::print_to_string(&event).unwrap_or_default());
+            return;
+        }
+        let mut line = format!("{} {:?}", event.id, event.type_.enum_value_or_default());
+        if !event.device_name.is_empty() {
+            line += &format!(" {}", event.device_name);
+        }
+        if event.chip_id != 0 {
+            line += &format!(" chip {}", event.chip_id);
+        }
+        println!("{line}");
+    }
+    // function to handle error response
+    fn handle_error(&self, error_code: u32, error_message: &str) {
+        eprintln!("Handling error code: {error_code}, msg: {error_message}");
+    }
+}
+
+/// Applies an event to the devices, with the state of the devices of the
+/// event.
+fn apply_event(devices: &mut Vec<Device>, event: &Event) {
+    match event.type_.enum_value_or_default() {
+        Type::RESET => *devices = event.devices.clone(),
+        Type::DEVICE_REMOVED => devices.retain(|device| device.name != event.device_name),
+        _ => {
+            for device in event.devices.iter() {
+                match devices.iter_mut().find(|d| d.name == device.name) {
+                    Some(d) => *d = device.clone(),
+                    None => devices.push(device.clone()),
+                }
+            }
+        }
+    }
+}
+
+/// Implements handler printing the devices on each of their events
+struct DeviceWatcher {
+    devices: Mutex<Vec<Device>>,
+    verbose: bool,
+}
+
+impl ClientResponseReadable for DeviceWatcher {
+    // function to print the devices changed by each event
+    fn handle_chunk(&self, chunk: &[u8]) {
+        let Ok(event) = Event::parse_from_bytes(chunk) else {
+            return;
+        };
+        let mut devices = self.devices.lock().unwrap();
+        apply_event(&mut devices, &event);
+        Command::print_device_response(
+            GetDevicesResponse { devices: devices.clone(), ..Default::default() },
+            self.verbose,
+        );
+    }
+    // function to handle error response
+    fn handle_error(&self, error_code: u32, error_message: &str) {
+        eprintln!("Handling error code: {error_code}, msg: {error_message}");
+    }
+}
+
+/// Prints the devices, then again on each change of the devices or the
+/// radio states of their chips until the stream ends.
+pub fn watch_devices(client: &UniquePtr<FrontendClient>, verbose: bool) -> Result<(), String> {
+    // The events after the last one recorded before GetDevices.
+    let request = ListEventsRequest { since_id: u64::MAX, ..Default::default() };
+    let result = client.send_grpc(&GrpcMethod::ListEvents, &request.write_to_bytes().unwrap());
+    if !result.is_ok() {
+        return Err(format!("Grpc call error: {}", result.err()));
+    }
+    let last_id = ListEventsResponse::parse_from_bytes(result.byte_vec().as_slice())
+        .map_err(|e| e.to_string())?
+        .last_id;
+    let result = client.send_grpc(&GrpcMethod::GetDevices, &Vec::new());
+    if !result.is_ok() {
+        return Err(format!("Grpc call error: {}", result.err()));
+    }
+    let response = GetDevicesResponse::parse_from_bytes(result.byte_vec().as_slice())
+        .map_err(|e| e.to_string())?;
+    let devices = response.devices.clone();
+    Command::print_device_response(response, verbose);
+    let request = SubscribeEventsRequest {
+        types: DEVICE_EVENT_TYPES.iter().map(|&event_type| event_type.into()).collect(),
+        since_id: last_id,
+        ..Default::default()
+    };
+    let result = client.subscribe_events(
+        &request.write_to_bytes().unwrap(),
+        &ClientResponseReader {
+            handler: Box::new(DeviceWatcher { devices: Mutex::new(devices), verbose }),
+        },
+    );
+    if !result.is_ok() {
+        return Err(format!("Grpc call error: {}", result.err()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(event_type: Type, device_name: &str, devices: &[&str]) -> Event {
+        Event {
+            type_: event_type.into(),
+            device_name: device_name.to_string(),
+            devices: devices
+                .iter()
+                .map(|name| Device { name: name.to_string(), visible: true, ..Default::default() })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_apply_event() {
+        let mut devices = Vec::new();
+        apply_event(&mut devices, &event(Type::DEVICE_ADDED, "a", &["a"]));
+        apply_event(&mut devices, &event(Type::DEVICE_ADDED, "b", &["b"]));
+        let names = |devices: &[Device]| devices.iter().map(|d| d.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&devices), ["a", "b"]);
+        // The state of the device of the event replaces the previous one.
+        devices[0].visible = false;
+        apply_event(&mut devices, &event(Type::CHIP_STATE_CHANGED, "a", &["a"]));
+        assert!(devices[0].visible);
+        apply_event(&mut devices, &event(Type::DEVICE_REMOVED, "a", &["a"]));
+        assert_eq!(names(&devices), ["b"]);
+        apply_event(&mut devices, &event(Type::RESET, "", &["c", "d"]));
+        assert_eq!(names(&devices), ["c", "d"]);
+    }
+}
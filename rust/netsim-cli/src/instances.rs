@@ -0,0 +1,95 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Discovery of the netsimd instances running on this host.
+
+use netsim_common::util::ini_file::IniFile;
+use netsim_common::util::os_utils::{get_netsim_ini_filepath, list_instances};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Addresses recorded in the discovery file of an instance.
+struct InstanceInfo {
+    name: String,
+    grpc_address: Option<String>,
+    http_address: Option<String>,
+}
+
+impl InstanceInfo {
+    fn read(name: &str) -> InstanceInfo {
+        let filepath = get_netsim_ini_filepath(name);
+        let mut ini_file = IniFile::new(filepath.to_string_lossy().into_owned());
+        let mut info =
+            InstanceInfo { name: name.to_string(), grpc_address: None, http_address: None };
+        if ini_file.read().is_ok() {
+            info.grpc_address = Self::get_address(&ini_file, "grpc");
+            info.http_address = Self::get_address(&ini_file, "http");
+        }
+        info
+    }
+
+    // Discovery files written by older netsimd only have the port.
+    fn get_address(ini_file: &IniFile, server: &str) -> Option<String> {
+        match ini_file.get(&format!("{server}.address")) {
+            Some(address) => Some(address.to_string()),
+            None => ini_file.get(&format!("{server}.port")).map(|port| format!("localhost:{port}")),
+        }
+    }
+
+    /// A discovery file outlives its netsimd, so probe the grpc port.
+    fn is_running(&self) -> bool {
+        let addrs = match self.grpc_address.as_ref().map(|address| address.to_socket_addrs()) {
+            Some(Ok(addrs)) => addrs,
+            _ => return false,
+        };
+        addrs.into_iter().any(|addr| TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT).is_ok())
+    }
+}
+
+/// Returns the frontend http address of an instance.
+pub fn get_http_address(instance: &str) -> Option<String> {
+    InstanceInfo::read(instance).http_address
+}
+
+/// Prints the instances found in the discovery directory.
+pub fn print_instances(verbose: bool) {
+    let instances = list_instances();
+    if instances.is_empty() {
+        println!("No netsimd instances found.");
+        return;
+    }
+    let name_width = 16;
+    let address_width = 24;
+    let status_width = 12;
+    println!(
+        "{:name_width$}{:address_width$}{:address_width$}{:status_width$}",
+        "NAME", "GRPC", "HTTP", "STATUS"
+    );
+    for name in instances {
+        let info = InstanceInfo::read(&name);
+        let running = info.is_running();
+        if !running && !verbose {
+            continue;
+        }
+        println!(
+            "{:name_width$}{:address_width$}{:address_width$}{:status_width$}",
+            if info.name.is_empty() { "(default)" } else { &info.name },
+            info.grpc_address.as_deref().unwrap_or("-"),
+            info.http_address.as_deref().unwrap_or("-"),
+            if running { "running" } else { "stopped" }
+        );
+    }
+}
@@ -15,21 +15,29 @@
 //! Command Line Interface for Netsim
 
 mod args;
+mod beacon_handler;
 mod browser;
+mod discover;
+mod event_handler;
+mod instances;
 mod pcap_handler;
 mod requests;
 mod response;
+mod stats;
+mod viewer;
 
 use std::env;
-use std::fs::File;
-use std::path::PathBuf;
+use std::fs::{File, OpenOptions};
 
 use args::{BinaryProtobuf, GetCapture, NetsimArgs};
+use beacon_handler::BeaconHandler;
 use clap::Parser;
 use cxx::UniquePtr;
+use event_handler::EventHandler;
 use frontend_client_cxx::ffi::{new_frontend_client, ClientResult, FrontendClient, GrpcMethod};
 use frontend_client_cxx::ClientResponseReader;
-use pcap_handler::CaptureHandler;
+use netsim_common::util::os_utils::{is_valid_instance_name, INSTANCE_ENV};
+use pcap_handler::{CaptureHandler, TailHandler};
 
 // helper function to process streaming Grpc request
 fn perform_streaming_request(
@@ -38,17 +46,26 @@ fn perform_streaming_request(
     req: &BinaryProtobuf,
     filename: &str,
 ) -> UniquePtr<ClientResult> {
-    let dir = if cmd.location.is_some() {
-        PathBuf::from(cmd.location.to_owned().unwrap())
-    } else {
-        env::current_dir().unwrap()
-    };
+    let dir = cmd.dir();
+    let mut output_file = dir.join(filename);
+    // A resumed download appends to its file, from the offset of the request
+    if cmd.resume {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&output_file)
+            .unwrap_or_else(|_| panic!("Failed to open file: {}", &output_file.display()));
+        return client.get_capture(
+            req,
+            &ClientResponseReader { handler: Box::new(CaptureHandler { file, path: output_file }) },
+        );
+    }
     // Find next available file name
-    let mut output_file = dir.join(filename.to_string() + ".pcap");
+    let (stem, extension) = filename.rsplit_once('.').unwrap_or((filename, "pcap"));
     let mut idx = 0;
     while output_file.exists() {
         idx += 1;
-        output_file = dir.join(format!("{}_{}.pcap", filename, idx));
+        output_file = dir.join(format!("{}_{}.{}", stem, idx, extension));
     }
     client.get_capture(
         req,
@@ -70,29 +87,71 @@ fn perform_command(
     grpc_method: GrpcMethod,
     verbose: bool,
 ) -> Result<(), String> {
+    // Stats polls several gRPC calls every interval
+    if let args::Command::Stats(ref cmd) = command {
+        return stats::watch_stats(&client, cmd);
+    }
+    // Discover matches the devices with the emulators found on the host
+    if let args::Command::Discover = command {
+        return discover::discover(&client, verbose);
+    }
+    // Continuous devices reprints the devices on each of their events
+    if let args::Command::Devices(ref cmd) = command {
+        if cmd.continuous {
+            return event_handler::watch_devices(&client, verbose);
+        }
+    }
+    // Open downloads the capture and starts the viewer on it
+    if let args::Command::Pcap(args::Pcap::Open(ref cmd)) = command {
+        return viewer::open_capture(&client, cmd);
+    }
     // Get command's gRPC request(s)
     let requests = match command {
-        args::Command::Pcap(args::Pcap::Patch(_) | args::Pcap::Get(_)) => {
-            command.get_requests(&client)
-        }
+        args::Command::Pcap(
+            args::Pcap::Patch(_)
+            | args::Pcap::Start(_)
+            | args::Pcap::Stop(_)
+            | args::Pcap::Get(_)
+            | args::Pcap::Dump(_),
+        )
+        | args::Command::FuzzReplay(_) => command.get_requests(&client),
         _ => vec![command.get_request_bytes()],
     };
 
     // Process each request
     for (i, req) in requests.iter().enumerate() {
-        let result = match command {
-            // Continuous option sends the gRPC call every second
-            args::Command::Devices(ref cmd) if cmd.continuous => loop {
-                process_result(command, client.send_grpc(&grpc_method, req), verbose)?;
-                std::thread::sleep(std::time::Duration::from_secs(1));
-            },
-            // Get Pcap use streaming gRPC reader request
-            args::Command::Pcap(args::Pcap::Get(ref cmd)) => {
-                perform_streaming_request(&client, cmd, req, &cmd.filenames[i])
-            }
-            // All other commands use a single gRPC call
-            _ => client.send_grpc(&grpc_method, req),
-        };
+        let result =
+            match command {
+                // Events stream until the command is interrupted
+                args::Command::Events(_) => client.subscribe_events(
+                    req,
+                    &ClientResponseReader { handler: Box::new(EventHandler { verbose }) },
+                ),
+                // Bluetooth advertise streams until the command is interrupted
+                args::Command::Bluetooth(args::Bluetooth::Advertise(_)) => client
+                    .advertise(req, &ClientResponseReader { handler: Box::new(BeaconHandler) }),
+                // Get Pcap use streaming gRPC reader request
+                args::Command::Pcap(args::Pcap::Get(ref cmd)) => {
+                    perform_streaming_request(&client, cmd, req, &cmd.filenames[i])
+                }
+                // Tail streams the capture to stdout until interrupted
+                args::Command::Pcap(args::Pcap::Tail(_)) => client
+                    .stream_capture(req, &ClientResponseReader { handler: Box::new(TailHandler) }),
+                // Fuzz replay names the input netsimd failed on
+                args::Command::FuzzReplay(ref cmd) => {
+                    let result = client.send_grpc(&grpc_method, req);
+                    if !result.is_ok() {
+                        return Err(format!(
+                            "Grpc call error on input {}: {}",
+                            cmd.inputs[i],
+                            result.err()
+                        ));
+                    }
+                    result
+                }
+                // All other commands use a single gRPC call
+                _ => client.send_grpc(&grpc_method, req),
+            };
         process_result(command, result, verbose)?;
     }
     Ok(())
@@ -115,9 +174,27 @@ fn process_result(
 /// main Rust netsim CLI function to be called by C wrapper netsim.cc
 pub extern "C" fn rust_main() {
     let mut args = NetsimArgs::parse();
-    if matches!(args.command, args::Command::Gui) {
-        browser::open("http://localhost:7681/");
-        return;
+    if let Some(instance) = &args.instance {
+        if !is_valid_instance_name(instance) {
+            eprintln!("Invalid instance name: {instance}");
+            return;
+        }
+        // The frontend client finds the instance's discovery file through the environment.
+        env::set_var(INSTANCE_ENV, instance);
+    }
+    let instance = env::var(INSTANCE_ENV).unwrap_or_default();
+    match args.command {
+        args::Command::Gui => {
+            let address = instances::get_http_address(&instance)
+                .unwrap_or_else(|| "localhost:7681".to_string());
+            browser::open(format!("http://{address}/"));
+            return;
+        }
+        args::Command::Instances(args::Instances::List) => {
+            instances::print_instances(args.verbose);
+            return;
+        }
+        _ => {}
     }
     let grpc_method = args.command.grpc_method();
     let client = new_frontend_client();
@@ -17,6 +17,7 @@ use std::fs::File;
 /// Implements handler for pcap operations
 use std::io::Write;
 use std::path::PathBuf;
+use std::process;
 
 pub struct CaptureHandler {
     pub file: File,
@@ -40,3 +41,21 @@ impl ClientResponseReadable for CaptureHandler {
         );
     }
 }
+
+/// Writes a streamed capture to stdout, e.g. for `tshark -i -`
+pub struct TailHandler;
+
+impl ClientResponseReadable for TailHandler {
+    // function to write each chunk as it arrives
+    fn handle_chunk(&self, chunk: &[u8]) {
+        let mut stdout = std::io::stdout().lock();
+        // The reader of the pipe is gone, e.g. tshark was closed.
+        if stdout.write_all(chunk).and_then(|_| stdout.flush()).is_err() {
+            process::exit(0);
+        }
+    }
+    // function to handle error response
+    fn handle_error(&self, error_code: u32, error_message: &str) {
+        eprintln!("Handling error code: {}, msg: {}, on capture stream", error_code, error_message);
+    }
+}
@@ -20,18 +20,48 @@ impl args::Command {
     pub fn grpc_method(&self) -> GrpcMethod {
         match self {
             Command::Version => GrpcMethod::GetVersion,
+            Command::Radio(cmd) if cmd.device.is_some() => GrpcMethod::PatchSelection,
             Command::Radio(_) => GrpcMethod::PatchDevice,
             Command::Move(_) => GrpcMethod::PatchDevice,
+            Command::Bandwidth(_) => GrpcMethod::PatchDevice,
+            Command::Tag(_) => GrpcMethod::PatchDevice,
+            Command::Motion(_) => GrpcMethod::SetDeviceMotion,
             Command::Devices(_) => GrpcMethod::GetDevices,
             Command::Reset => GrpcMethod::Reset,
+            Command::Restart(_) => GrpcMethod::RestartChip,
+            Command::Impair(_) => GrpcMethod::SetLinkImpairment,
+            Command::Group(_) => GrpcMethod::PatchGroup,
+            Command::Load(_) => GrpcMethod::LoadScenario,
+            Command::Save(_) => GrpcMethod::SaveState,
+            Command::Restore(_) => GrpcMethod::RestoreState,
             Command::Pcap(cmd) => match cmd {
                 args::Pcap::List(_) => GrpcMethod::ListCapture,
                 args::Pcap::Get(_) => GrpcMethod::GetCapture,
                 args::Pcap::Patch(_) => GrpcMethod::PatchCapture,
+                args::Pcap::Start(cmd) if cmd.selector.is_set() => GrpcMethod::PatchSelection,
+                args::Pcap::Stop(cmd) if cmd.selector.is_set() => GrpcMethod::PatchSelection,
+                args::Pcap::Start(_) => GrpcMethod::PatchCapture,
+                args::Pcap::Stop(_) => GrpcMethod::PatchCapture,
+                args::Pcap::Dump(_) => GrpcMethod::PatchCapture,
+                args::Pcap::Open(_) => GrpcMethod::GetCapture,
+                args::Pcap::Tail(_) => GrpcMethod::StreamCapture,
+                args::Pcap::Replay(_) => GrpcMethod::ReplayCapture,
             },
             Command::Gui => {
                 panic!("No GrpcMethod for Ui Command.");
             }
+            Command::Instances(_) => {
+                panic!("No GrpcMethod for Instances Command.");
+            }
+            Command::FuzzReplay(_) => GrpcMethod::ReplayFuzzInput,
+            Command::Stats(_) => GrpcMethod::GetChipTransportStats,
+            Command::Events(_) => GrpcMethod::SubscribeEvents,
+            Command::Bugreport(_) => GrpcMethod::CreateBugReport,
+            Command::Discover => GrpcMethod::GetDevices,
+            Command::Pause => GrpcMethod::PauseDelivery,
+            Command::Resume => GrpcMethod::ResumeDelivery,
+            Command::Step(_) => GrpcMethod::StepDelivery,
+            Command::Bluetooth(_) => GrpcMethod::Advertise,
         }
     }
 }
@@ -39,19 +69,29 @@ impl args::Command {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use args::{BinaryProtobuf, NetsimArgs};
+    use args::{BinaryProtobuf, CaptureFormat, NetsimArgs};
     use clap::Parser;
+    use frontend::patch_capture_request::PatchCapture as PatchCaptureProto;
     use frontend_proto::{
         common::ChipKind,
-        frontend,
+        frontend::{self, set_link_impairment_request::JitterDistribution},
         model::{
             self,
-            chip::{Bluetooth as Chip_Bluetooth, Radio as Chip_Radio},
+            capture::filter::Direction as FilterDirection,
+            chip::{
+                nfc::Tag as Chip_Tag, Bluetooth as Chip_Bluetooth, Nfc as Chip_Nfc,
+                Radio as Chip_Radio,
+            },
             Device, Position, State,
         },
     };
+    use protobuf::well_known_types::field_mask::FieldMask;
     use protobuf::Message;
 
+    fn mask(path: &str) -> FieldMask {
+        FieldMask { paths: vec![path.to_string()], ..Default::default() }
+    }
+
     fn test_command(
         command: &str,
         expected_grpc_method: GrpcMethod,
@@ -101,6 +141,7 @@ mod tests {
         device.name = name.to_owned();
         device.chips.push(chip);
         result.device = Some(device).into();
+        result.update_mask = Some(mask("chips")).into();
         result.write_to_bytes().unwrap()
     }
 
@@ -118,6 +159,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_radio_selection() {
+        let patch_device =
+            frontend::PatchDeviceRequest::parse_from_bytes(&get_expected_radio("", "ble", "down"))
+                .unwrap();
+        let request = frontend::PatchSelectionRequest {
+            selector: Some(frontend::Selector {
+                device: "pixel*".to_string(),
+                ..Default::default()
+            })
+            .into(),
+            chip: Some(patch_device.device.chips[0].clone()).into(),
+            ..Default::default()
+        };
+        test_command(
+            "netsim-cli radio ble down --device pixel*",
+            GrpcMethod::PatchSelection,
+            request.write_to_bytes().unwrap(),
+        );
+        assert!(NetsimArgs::try_parse_from("netsim-cli radio ble down".split_whitespace()).is_err());
+        assert!(NetsimArgs::try_parse_from(
+            "netsim-cli radio ble down phone --device pixel*".split_whitespace()
+        )
+        .is_err());
+    }
+
     #[test]
     fn test_radio_ble_aliases() {
         test_command(
@@ -184,6 +251,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tag() {
+        let request = |name: &str, ndef: Vec<u8>| {
+            let mut nfc_chip = Chip_Nfc::new();
+            nfc_chip.tag = Some(Chip_Tag { ndef, ..Default::default() }).into();
+            let mut chip = model::Chip { kind: ChipKind::NFC.into(), ..Default::default() };
+            chip.set_nfc(nfc_chip);
+            let device = Device { name: name.to_string(), chips: vec![chip], ..Default::default() };
+            frontend::PatchDeviceRequest {
+                device: Some(device).into(),
+                update_mask: Some(mask("chips")).into(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap()
+        };
+        test_command(
+            "netsim-cli tag a https://a.io",
+            GrpcMethod::PatchDevice,
+            request("a", [&[0xd1, 0x01, 0x0d, b'U', 0x00][..], b"https://a.io"].concat()),
+        );
+        test_command("netsim-cli tag b", GrpcMethod::PatchDevice, request("b", Vec::new()));
+        let long = args::ndef_uri(&"a".repeat(300));
+        assert_eq!(long[..7], [0xc1, 0x01, 0x00, 0x00, 0x01, 0x2d, b'U']);
+    }
+
     fn get_expected_move(name: &str, x: f32, y: f32, z: Option<f32>) -> BinaryProtobuf {
         let mut result = frontend::PatchDeviceRequest::new();
         let mut device = Device::new();
@@ -191,6 +284,7 @@ mod tests {
         device.name = name.to_owned();
         device.position = Some(position).into();
         result.device = Some(device).into();
+        result.update_mask = Some(mask("position")).into();
         result.write_to_bytes().unwrap()
     }
 
@@ -230,19 +324,654 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_motion() {
+        let request = |waypoints: Vec<(f32, f32, f32)>, speed, repeat| {
+            frontend::SetDeviceMotionRequest {
+                device_name: "phone".to_string(),
+                waypoints: waypoints
+                    .into_iter()
+                    .map(|(x, y, z)| Position { x, y, z, ..Default::default() })
+                    .collect(),
+                speed,
+                repeat,
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap()
+        };
+        test_command(
+            "netsim-cli motion phone 0,0 10,0,1.5",
+            GrpcMethod::SetDeviceMotion,
+            request(vec![(0.0, 0.0, 0.0), (10.0, 0.0, 1.5)], 1.0, false),
+        );
+        test_command(
+            "netsim-cli motion phone 0,0 1,1 --speed 2.5 --repeat",
+            GrpcMethod::SetDeviceMotion,
+            request(vec![(0.0, 0.0, 0.0), (1.0, 1.0, 0.0)], 2.5, true),
+        );
+        // Without waypoints the device stops.
+        test_command(
+            "netsim-cli motion phone",
+            GrpcMethod::SetDeviceMotion,
+            request(vec![], 1.0, false),
+        );
+        for waypoint in ["1", "1,2,3,4", "1,x"] {
+            let command = format!("netsim-cli motion phone {waypoint}");
+            assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+        }
+    }
+
     #[test]
     fn test_devices() {
         test_command("netsim-cli devices", GrpcMethod::GetDevices, Vec::new())
     }
 
+    #[test]
+    fn test_devices_page() {
+        let request = frontend::GetDevicesRequest {
+            name_filter: "pixel".to_string(),
+            chip_kind: ChipKind::BLUETOOTH.into(),
+            page_size: 10,
+            page_token: "3".to_string(),
+            ..Default::default()
+        };
+        test_command(
+            "netsim-cli devices --name pixel --kind bluetooth --page-size 10 --page-token 3",
+            GrpcMethod::GetDevices,
+            request.write_to_bytes().unwrap(),
+        );
+        let command = "netsim-cli devices --continuous --page-size 10";
+        assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+    }
+
     #[test]
     fn test_reset() {
         test_command("netsim-cli reset", GrpcMethod::Reset, Vec::new())
     }
 
+    #[test]
+    fn test_restart() {
+        let request =
+            frontend::RestartChipRequest { chip_id: 3, ..Default::default() }.write_to_bytes();
+        test_command("netsim-cli restart 3", GrpcMethod::RestartChip, request.unwrap());
+        assert!(NetsimArgs::try_parse_from("netsim-cli restart".split_whitespace()).is_err());
+    }
+
+    #[test]
+    fn test_bluetooth_advertise() {
+        let request = |name: &str, interval_ms, payload: Vec<u8>| {
+            frontend::AdvertiseRequest {
+                name: name.to_string(),
+                interval_ms,
+                payload,
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap()
+        };
+        test_command(
+            "netsim-cli bluetooth advertise",
+            GrpcMethod::Advertise,
+            request("", 100, vec![]),
+        );
+        test_command(
+            "netsim-cli bluetooth advertise --name Foo --interval 1s --payload 020106",
+            GrpcMethod::Advertise,
+            request("Foo", 1000, vec![2, 1, 6]),
+        );
+        for args in ["--payload 02010", "--payload 0g", "--interval 0ms"] {
+            let command = format!("netsim-cli bluetooth advertise {args}");
+            assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_bugreport() {
+        test_command("netsim-cli bugreport", GrpcMethod::CreateBugReport, Vec::new());
+        let command =
+            NetsimArgs::parse_from("netsim-cli bugreport -o /tmp".split_whitespace()).command;
+        let Command::Bugreport(cmd) = command else { panic!("unexpected command") };
+        assert_eq!(cmd.location.as_deref(), Some("/tmp"));
+    }
+
+    #[test]
+    fn test_bandwidth() {
+        let request = |kind: ChipKind, bits_per_second| {
+            let chip = model::Chip {
+                kind: kind.into(),
+                bandwidth: Some(model::chip::Bandwidth { bits_per_second, ..Default::default() })
+                    .into(),
+                ..Default::default()
+            };
+            let device =
+                Device { name: "1000".to_string(), chips: vec![chip], ..Default::default() };
+            frontend::PatchDeviceRequest {
+                device: Some(device).into(),
+                update_mask: Some(mask("chips")).into(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap()
+        };
+        test_command(
+            "netsim-cli bandwidth wifi 1000 1M",
+            GrpcMethod::PatchDevice,
+            request(ChipKind::WIFI, 1_000_000),
+        );
+        test_command(
+            "netsim-cli bandwidth ble 1000 2.5kbps",
+            GrpcMethod::PatchDevice,
+            request(ChipKind::BLUETOOTH, 2500),
+        );
+        test_command(
+            "netsim-cli bandwidth wifi 1000 0",
+            GrpcMethod::PatchDevice,
+            request(ChipKind::WIFI, 0),
+        );
+        for rate in ["-1", "1X", "M"] {
+            let command = format!("netsim-cli bandwidth wifi 1000 {rate}");
+            assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_group() {
+        let request = |device: Device, relative, capture: State| {
+            frontend::PatchGroupRequest {
+                group: "room".to_string(),
+                join_device_names: vec!["a".to_string(), "b".to_string()],
+                device: Some(device).into(),
+                relative,
+                capture: capture.into(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap()
+        };
+        test_command(
+            "netsim-cli group room --join a --join b",
+            GrpcMethod::PatchGroup,
+            request(Device::new(), false, State::UNKNOWN),
+        );
+        let mut wifi = Chip_Radio::new();
+        wifi.state = State::OFF.into();
+        let mut chip = model::Chip { kind: ChipKind::WIFI.into(), ..Default::default() };
+        chip.set_wifi(wifi);
+        let device = Device {
+            chips: vec![chip],
+            position: Some(Position { x: 1.0, y: 2.0, ..Default::default() }).into(),
+            ..Default::default()
+        };
+        test_command(
+            "netsim-cli group room --join a --join b --radio wifi=down --move 1,2 --relative --capture on",
+            GrpcMethod::PatchGroup,
+            request(device, true, State::ON),
+        );
+        for options in ["--radio wifi", "--radio fm=up", "--relative"] {
+            let command = format!("netsim-cli group room {options}");
+            assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_impair() {
+        let request = |other_chip_id, drop_percent, seed| {
+            frontend::SetLinkImpairmentRequest {
+                chip_id: 3,
+                other_chip_id,
+                drop_percent,
+                seed,
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap()
+        };
+        test_command(
+            "netsim-cli impair 3 12.5",
+            GrpcMethod::SetLinkImpairment,
+            request(0, 12.5, 0),
+        );
+        test_command(
+            "netsim-cli impair 3 50 --peer 4 --seed 42",
+            GrpcMethod::SetLinkImpairment,
+            request(4, 50.0, 42),
+        );
+        test_command(
+            "netsim-cli impair 3 0 --latency 100 --jitter 20 --distribution normal",
+            GrpcMethod::SetLinkImpairment,
+            frontend::SetLinkImpairmentRequest {
+                chip_id: 3,
+                latency_ms: 100,
+                jitter_ms: 20,
+                jitter_distribution: JitterDistribution::NORMAL.into(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap(),
+        );
+        for percent in ["-1", "101", "x"] {
+            let command = format!("netsim-cli impair 3 {percent}");
+            assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_pause_and_resume() {
+        test_command("netsim-cli pause", GrpcMethod::PauseDelivery, Vec::new());
+        test_command("netsim-cli resume", GrpcMethod::ResumeDelivery, Vec::new());
+    }
+
+    #[test]
+    fn test_load() {
+        let path =
+            std::env::temp_dir().join(format!("netsim-cli-scene-{}.json", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"{"scene": {"devices": [{"name": "phone"}]},
+                "impairments": [{"deviceName": "phone", "impairment": {"dropPercent": 5}}]}"#,
+        )
+        .unwrap();
+        let scene_file = frontend::SceneFile {
+            scene: Some(model::Scene {
+                devices: vec![Device { name: "phone".to_string(), ..Default::default() }],
+                ..Default::default()
+            })
+            .into(),
+            impairments: vec![frontend::SceneImpairment {
+                device_name: "phone".to_string(),
+                impairment: Some(frontend::SetLinkImpairmentRequest {
+                    drop_percent: 5.0,
+                    ..Default::default()
+                })
+                .into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        test_command(
+            &format!("netsim-cli load {}", path.display()),
+            GrpcMethod::LoadScenario,
+            frontend::LoadScenarioRequest {
+                scene_file: Some(scene_file).into(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap(),
+        );
+        std::fs::write(&path, "{").unwrap();
+        let command = format!("netsim-cli load {}", path.display());
+        assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+        std::fs::remove_file(&path).unwrap();
+        assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+    }
+
+    #[test]
+    fn test_save_and_restore() {
+        let save = |path: &str| {
+            frontend::SaveStateRequest { path: path.to_string(), ..Default::default() }
+                .write_to_bytes()
+                .unwrap()
+        };
+        test_command("netsim-cli save", GrpcMethod::SaveState, save(""));
+        test_command(
+            "netsim-cli save /tmp/scene.json",
+            GrpcMethod::SaveState,
+            save("/tmp/scene.json"),
+        );
+        test_command(
+            "netsim-cli restore /tmp/scene.json",
+            GrpcMethod::RestoreState,
+            frontend::RestoreStateRequest {
+                path: "/tmp/scene.json".to_string(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap(),
+        );
+        assert!(NetsimArgs::try_parse_from(["netsim-cli", "restore"]).is_err());
+    }
+
+    #[test]
+    fn test_events() {
+        use frontend::event::Type;
+        let request = |types: Vec<Type>, device_name: &str, since_id| {
+            frontend::SubscribeEventsRequest {
+                types: types.into_iter().map(|event_type| event_type.into()).collect(),
+                device_name: device_name.to_string(),
+                since_id,
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap()
+        };
+        test_command("netsim-cli events", GrpcMethod::SubscribeEvents, request(vec![], "", 0));
+        test_command(
+            "netsim-cli events --type chip-state-changed --type DEVICE_ADDED --device phone --since 7",
+            GrpcMethod::SubscribeEvents,
+            request(vec![Type::CHIP_STATE_CHANGED, Type::DEVICE_ADDED], "phone", 7),
+        );
+        for args in ["--type moved", "--type unspecified", "--since -1"] {
+            let command = format!("netsim-cli events {args}");
+            assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        let request = |count| {
+            frontend::StepDeliveryRequest { count, ..Default::default() }.write_to_bytes().unwrap()
+        };
+        test_command("netsim-cli step", GrpcMethod::StepDelivery, request(1));
+        test_command("netsim-cli step 5", GrpcMethod::StepDelivery, request(5));
+        assert!(NetsimArgs::try_parse_from("netsim-cli step 0".split_whitespace()).is_err());
+    }
+
+    #[test]
+    fn test_discover() {
+        test_command("netsim-cli discover", GrpcMethod::GetDevices, Vec::new())
+    }
+
+    #[test]
+    fn test_stats() {
+        test_command("netsim-cli stats", GrpcMethod::GetChipTransportStats, Vec::new());
+        let command =
+            NetsimArgs::parse_from("netsim-cli stats --interval 500ms --json".split_whitespace())
+                .command;
+        let Command::Stats(cmd) = command else { panic!("unexpected command") };
+        assert_eq!(cmd.interval, std::time::Duration::from_millis(500));
+        assert!(cmd.json);
+        assert!(NetsimArgs::try_parse_from("netsim-cli stats --interval 0s".split_whitespace())
+            .is_err());
+    }
+
     #[test]
     fn test_pcap_list() {
-        test_command("netsim-cli pcap list", GrpcMethod::ListCapture, Vec::new())
+        test_command("netsim-cli pcap list", GrpcMethod::ListCapture, Vec::new());
+        let request = frontend::ListCaptureRequest {
+            device_name_filter: "watch".to_string(),
+            chip_kind: ChipKind::WIFI.into(),
+            state: State::ON.into(),
+            page_size: 5,
+            ..Default::default()
+        };
+        test_command(
+            "netsim-cli pcap list --device watch --kind wifi --state on --page-size 5",
+            GrpcMethod::ListCapture,
+            request.write_to_bytes().unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_pcap_open() {
+        let command = NetsimArgs::parse_from(
+            "netsim-cli capture open 3 --tshark --keylog keys.txt".split_whitespace(),
+        )
+        .command;
+        assert_eq!(GrpcMethod::GetCapture, command.grpc_method());
+        let Command::Pcap(args::Pcap::Open(cmd)) = command else {
+            panic!("expected pcap open");
+        };
+        assert_eq!((cmd.id, cmd.tshark, cmd.keylog.as_deref()), (3, true, Some("keys.txt")));
+    }
+
+    #[test]
+    fn test_capture_start_stop() {
+        let command = NetsimArgs::parse_from(
+            "netsim-cli capture start phone --format pcapng".split_whitespace(),
+        )
+        .command;
+        assert_eq!(GrpcMethod::PatchCapture, command.grpc_method());
+        let Command::Pcap(args::Pcap::Start(cmd)) = command else {
+            panic!("expected capture start");
+        };
+        assert_eq!(
+            (cmd.patterns, cmd.format),
+            (vec!["phone".to_string()], Some(CaptureFormat::Pcapng))
+        );
+        let command = NetsimArgs::parse_from("netsim-cli capture stop".split_whitespace()).command;
+        assert_eq!(GrpcMethod::PatchCapture, command.grpc_method());
+        let Command::Pcap(args::Pcap::Stop(cmd)) = command else {
+            panic!("expected capture stop");
+        };
+        assert!(cmd.patterns.is_empty());
+        // Only the state is patched, keeping the other options of the capture.
+        let patch_capture = PatchCaptureProto { state: State::OFF.into(), ..Default::default() };
+        let request = frontend::PatchCaptureRequest {
+            id: 2,
+            patch: Some(PatchCaptureProto {
+                update_mask: Some(mask("state")).into(),
+                ..patch_capture.clone()
+            })
+            .into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            args::Command::masked_capture_request(2, &patch_capture, &Some(mask("state")).into()),
+            request.write_to_bytes().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_capture_selection() {
+        let selector = frontend::Selector {
+            device: "pixel?".to_string(),
+            chip_kind: ChipKind::BLUETOOTH.into(),
+            ..Default::default()
+        };
+        let command = NetsimArgs::parse_from(
+            "netsim-cli capture start --device pixel? --chip-kind bluetooth --format pcapng"
+                .split_whitespace(),
+        )
+        .command;
+        assert_eq!(GrpcMethod::PatchSelection, command.grpc_method());
+        let Command::Pcap(args::Pcap::Start(cmd)) = &command else {
+            panic!("expected capture start");
+        };
+        assert_eq!((cmd.selector.selector(), cmd.format), (selector, Some(CaptureFormat::Pcapng)));
+        let command =
+            NetsimArgs::parse_from("netsim-cli capture stop --chip-kind wifi".split_whitespace())
+                .command;
+        assert_eq!(GrpcMethod::PatchSelection, command.grpc_method());
+        assert!(NetsimArgs::try_parse_from(
+            "netsim-cli capture stop phone --chip-kind wifi".split_whitespace()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_capture_get_output() {
+        let command = NetsimArgs::parse_from(
+            "netsim-cli capture get phone --output /tmp/captures".split_whitespace(),
+        )
+        .command;
+        let Command::Pcap(args::Pcap::Get(cmd)) = command else {
+            panic!("expected capture get");
+        };
+        assert_eq!(cmd.location.as_deref(), Some("/tmp/captures"));
+    }
+
+    #[test]
+    fn test_pcap_get_range() {
+        let command = NetsimArgs::parse_from(
+            "netsim-cli pcap get 3 --offset 1024 --length 64".split_whitespace(),
+        )
+        .command;
+        assert_eq!(GrpcMethod::GetCapture, command.grpc_method());
+        let Command::Pcap(args::Pcap::Get(cmd)) = command else {
+            panic!("expected pcap get");
+        };
+        assert_eq!((cmd.offset, cmd.length, cmd.resume), (Some(1024), Some(64), false));
+        // A resumed download gets the offset from its file.
+        assert!(NetsimArgs::try_parse_from(
+            "netsim-cli pcap get 3 --resume --offset 1024".split_whitespace()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_pcap_patch_filter() {
+        let command = NetsimArgs::parse_from(
+            "netsim-cli pcap patch on phone --packet-type 2 --packet-type 4 \
+             --direction controller-to-host --max-length 64 --pattern 0e04 --anywhere"
+                .split_whitespace(),
+        )
+        .command;
+        assert_eq!(GrpcMethod::PatchCapture, command.grpc_method());
+        let Command::Pcap(args::Pcap::Patch(cmd)) = command else {
+            panic!("expected pcap patch");
+        };
+        let filter = cmd.filter().unwrap();
+        assert_eq!(filter.packet_types, [2, 4]);
+        assert_eq!(filter.direction.enum_value_or_default(), FilterDirection::CONTROLLER_TO_HOST);
+        assert_eq!((filter.min_length, filter.max_length), (0, 64));
+        assert_eq!((filter.pattern, filter.anywhere), (vec![0x0e, 0x04], true));
+        let command =
+            NetsimArgs::parse_from("netsim-cli pcap patch off".split_whitespace()).command;
+        let Command::Pcap(args::Pcap::Patch(cmd)) = command else {
+            panic!("expected pcap patch");
+        };
+        assert_eq!(cmd.filter(), None);
+    }
+
+    #[test]
+    fn test_pcap_snaplen() {
+        let command = NetsimArgs::parse_from(
+            "netsim-cli pcap patch on phone --snaplen 32".split_whitespace(),
+        )
+        .command;
+        let Command::Pcap(args::Pcap::Patch(cmd)) = command else {
+            panic!("expected pcap patch");
+        };
+        assert_eq!(cmd.snaplen, Some(32));
+    }
+
+    #[test]
+    fn test_pcap_live() {
+        let command = NetsimArgs::parse_from(
+            "netsim-cli pcap patch on phone --live /tmp/netsim.fifo".split_whitespace(),
+        )
+        .command;
+        let Command::Pcap(args::Pcap::Patch(cmd)) = command else {
+            panic!("expected pcap patch");
+        };
+        assert_eq!(cmd.live.as_deref(), Some(std::path::Path::new("/tmp/netsim.fifo")));
+        let command = "netsim-cli pcap patch on phone --live /tmp/netsim.fifo --ring";
+        assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+    }
+
+    #[test]
+    fn test_pcap_append() {
+        let command =
+            NetsimArgs::parse_from("netsim-cli pcap patch on phone --append".split_whitespace())
+                .command;
+        let Command::Pcap(args::Pcap::Patch(cmd)) = command else {
+            panic!("expected pcap patch");
+        };
+        assert!(cmd.append);
+        for args in ["--append --ring", "--append --snaplen 32"] {
+            let command = format!("netsim-cli pcap patch on phone {args}");
+            assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_pcap_ring() {
+        let command =
+            NetsimArgs::parse_from("netsim-cli pcap patch on phone --ring".split_whitespace())
+                .command;
+        let Command::Pcap(args::Pcap::Patch(cmd)) = command else {
+            panic!("expected pcap patch");
+        };
+        assert!(cmd.ring);
+        let command =
+            NetsimArgs::parse_from("netsim-cli pcap dump phone".split_whitespace()).command;
+        assert_eq!(GrpcMethod::PatchCapture, command.grpc_method());
+        let Command::Pcap(args::Pcap::Dump(cmd)) = command else {
+            panic!("expected pcap dump");
+        };
+        assert_eq!(cmd.patterns, ["phone"]);
+    }
+
+    #[test]
+    fn test_capture_tail() {
+        let request = frontend::StreamCaptureRequest { chip_id: 4, ..Default::default() };
+        test_command(
+            "netsim-cli capture tail 4",
+            GrpcMethod::StreamCapture,
+            request.write_to_bytes().unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_pcap_replay() {
+        let request = |speed, burst| {
+            frontend::ReplayCaptureRequest {
+                chip_id: 3,
+                path: "/no/such/trace.btsnoop".to_string(),
+                speed,
+                burst,
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap()
+        };
+        test_command(
+            "netsim-cli pcap replay 3 /no/such/trace.btsnoop",
+            GrpcMethod::ReplayCapture,
+            request(0.0, false),
+        );
+        test_command(
+            "netsim-cli pcap replay 3 /no/such/trace.btsnoop --speed 2.5",
+            GrpcMethod::ReplayCapture,
+            request(2.5, false),
+        );
+        test_command(
+            "netsim-cli pcap replay 3 /no/such/trace.btsnoop --burst",
+            GrpcMethod::ReplayCapture,
+            request(0.0, true),
+        );
+        for args in ["3 trace --speed 0", "3 trace --speed 2 --burst"] {
+            let command = format!("netsim-cli pcap replay {args}");
+            assert!(NetsimArgs::try_parse_from(command.split_whitespace()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_instance_arg() {
+        let args =
+            NetsimArgs::parse_from("netsim-cli devices --instance shard1".split_whitespace());
+        assert_eq!(args.instance.as_deref(), Some("shard1"));
+        assert_eq!(GrpcMethod::GetDevices, args.command.grpc_method());
+        let args = NetsimArgs::parse_from("netsim-cli -i shard1 version".split_whitespace());
+        assert_eq!(args.instance.as_deref(), Some("shard1"));
+        let args = NetsimArgs::parse_from("netsim-cli version".split_whitespace());
+        assert_eq!(args.instance, None);
+    }
+
+    #[test]
+    fn test_instances_list() {
+        let command =
+            NetsimArgs::parse_from("netsim-cli instances list".split_whitespace()).command;
+        assert!(matches!(command, Command::Instances(args::Instances::List)));
+    }
+
+    #[test]
+    fn test_fuzz_replay() {
+        let corpus = std::env::temp_dir().join("netsim-cli-test-corpus");
+        std::fs::create_dir_all(&corpus).unwrap();
+        std::fs::write(corpus.join("b"), [4, 0x0e, 0]).unwrap();
+        std::fs::write(corpus.join("a"), [1]).unwrap();
+        let command_line = format!("netsim-cli fuzz-replay h4 {}", corpus.display());
+        let mut command = NetsimArgs::parse_from(command_line.split_whitespace()).command;
+        assert_eq!(GrpcMethod::ReplayFuzzInput, command.grpc_method());
+        let Command::FuzzReplay(ref mut cmd) = command else { panic!("unexpected command") };
+        let requests = cmd.get_requests();
+        std::fs::remove_dir_all(&corpus).unwrap();
+        assert_eq!(requests.len(), 2);
+        let request = frontend::ReplayFuzzInputRequest::parse_from_bytes(&requests[1]).unwrap();
+        assert_eq!(request.input, [4, 0x0e, 0]);
+        assert!(cmd.inputs[0].ends_with('a') && cmd.inputs[1].ends_with('b'));
     }
 
     //TODO: Add pcap patch and get tests once able to run tests with cxx definitions
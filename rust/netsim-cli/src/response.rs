@@ -13,11 +13,18 @@
 // limitations under the License.
 
 use std::cmp::max;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
 
 use crate::args::{self, Command, OnOffState, Pcap};
 use frontend_proto::{
     common::ChipKind,
-    frontend::{GetDevicesResponse, ListCaptureResponse, VersionResponse},
+    frontend::{
+        CreateBugReportResponse, DeliveryState, GetDevicesResponse, ListCaptureResponse,
+        LoadScenarioResponse, PatchGroupResponse, PatchSelectionResponse, ReplayCaptureResponse,
+        ReplayFuzzInputResponse, RestartChipResponse, SaveStateResponse, VersionResponse,
+    },
     model::{self, chip::Chip as Chip_oneof_chip, State},
 };
 use protobuf::Message;
@@ -29,13 +36,18 @@ impl args::Command {
             Command::Version => {
                 Self::print_version_response(VersionResponse::parse_from_bytes(response).unwrap());
             }
+            Command::Radio(cmd) if cmd.device.is_some() => {
+                Self::print_patch_selection_response(
+                    PatchSelectionResponse::parse_from_bytes(response).unwrap(),
+                );
+            }
             Command::Radio(cmd) => {
                 if verbose {
                     println!(
                         "Radio {} is {} for {}",
                         cmd.radio_type,
                         cmd.status,
-                        cmd.name.to_owned()
+                        cmd.name.clone().unwrap_or_default()
                     );
                 }
             }
@@ -50,22 +62,111 @@ impl args::Command {
                     )
                 }
             }
+            Command::Bandwidth(cmd) => {
+                if verbose {
+                    match cmd.bits_per_second {
+                        0 => {
+                            println!("Removed the {} bandwidth cap of {}", cmd.radio_type, cmd.name)
+                        }
+                        rate => println!(
+                            "Capped the {} bandwidth of {} to {rate} bps",
+                            cmd.radio_type, cmd.name
+                        ),
+                    }
+                }
+            }
+            Command::Tag(cmd) => {
+                if verbose {
+                    match &cmd.uri {
+                        Some(uri) => println!("Emulating a tag of {uri} on device:{}", cmd.name),
+                        None => println!("Stopped the tag of device:{}", cmd.name),
+                    }
+                }
+            }
+            Command::Motion(cmd) => {
+                if verbose {
+                    match cmd.waypoints.len() {
+                        0 => println!("Stopped device:{}", cmd.name),
+                        n => println!(
+                            "Moving device:{} along {n} waypoint(s) at {:.2} m/s{}",
+                            cmd.name,
+                            cmd.speed,
+                            if cmd.repeat { ", repeated" } else { "" }
+                        ),
+                    }
+                }
+            }
+            Command::Group(cmd) => {
+                let response = PatchGroupResponse::parse_from_bytes(response).unwrap();
+                match response.device_names.is_empty() {
+                    true => println!("Group {} has no devices.", cmd.group),
+                    false => {
+                        println!("Group {}: {}", cmd.group, response.device_names.join(", "))
+                    }
+                }
+            }
             Command::Devices(_) => {
-                Self::print_device_response(
-                    GetDevicesResponse::parse_from_bytes(response).unwrap(),
-                    verbose,
-                );
+                let response = GetDevicesResponse::parse_from_bytes(response).unwrap();
+                let next_page_token = response.next_page_token.clone();
+                Self::print_device_response(response, verbose);
+                Self::print_next_page_token(&next_page_token);
             }
             Command::Reset => {
                 if verbose {
                     println!("All devices have been reset.");
                 }
             }
-            Command::Pcap(Pcap::List(cmd)) => Self::print_list_capture_response(
-                ListCaptureResponse::parse_from_bytes(response).unwrap(),
-                verbose,
-                cmd.patterns.to_owned(),
-            ),
+            Command::Load(_) | Command::Restore(_) => {
+                let response = LoadScenarioResponse::parse_from_bytes(response).unwrap();
+                println!(
+                    "Loaded the scene file: {} devices patched, {} beacons added, {} impairments set, {} captures started.",
+                    response.device_names.len(),
+                    response.beacons,
+                    response.impairments,
+                    response.captures
+                );
+                if response.pending_impairments > 0 {
+                    println!(
+                        "{} impairments are set once their chips are added.",
+                        response.pending_impairments
+                    );
+                }
+                if response.pending_captures > 0 {
+                    println!(
+                        "{} captures are started once their chips are added.",
+                        response.pending_captures
+                    );
+                }
+            }
+            Command::Save(_) => {
+                let response = SaveStateResponse::parse_from_bytes(response).unwrap();
+                println!("Saved the simulation to {}.", response.path);
+            }
+            Command::Restart(cmd) => {
+                let response = RestartChipResponse::parse_from_bytes(response).unwrap();
+                println!(
+                    "Restarted chip {}: {} packets queued, {} rejected.",
+                    cmd.chip_id, response.queued, response.rejected
+                );
+            }
+            Command::Impair(cmd) => {
+                if verbose {
+                    let link = match cmd.peer {
+                        Some(peer) => format!(" to and from chip {peer}"),
+                        None => String::new(),
+                    };
+                    println!(
+                        "Dropping {}% of the packets of chip {}{link}, delayed by {} ms with {} ms of {} jitter",
+                        cmd.drop_percent, cmd.chip_id, cmd.latency, cmd.jitter, cmd.distribution
+                    );
+                }
+            }
+            Command::Pcap(Pcap::List(cmd)) => {
+                let response = ListCaptureResponse::parse_from_bytes(response).unwrap();
+                let next_page_token = response.next_page_token.clone();
+                Self::print_list_capture_response(response, verbose, cmd.patterns.to_owned());
+                Self::print_next_page_token(&next_page_token);
+            }
             Command::Pcap(Pcap::Patch(cmd)) => {
                 if verbose {
                     println!(
@@ -74,19 +175,133 @@ impl args::Command {
                     );
                 }
             }
+            Command::Pcap(Pcap::Start(args::StartCapture { selector, .. }))
+            | Command::Pcap(Pcap::Stop(args::StopCapture { selector, .. }))
+                if selector.is_set() =>
+            {
+                Self::print_patch_selection_response(
+                    PatchSelectionResponse::parse_from_bytes(response).unwrap(),
+                );
+            }
+            Command::Pcap(Pcap::Start(_)) => {
+                if verbose {
+                    println!("Started the capture.");
+                }
+            }
+            Command::Pcap(Pcap::Stop(_)) => {
+                if verbose {
+                    println!("Stopped the capture.");
+                }
+            }
+            Command::Bluetooth(_) => {
+                if verbose {
+                    println!("The beacon has stopped advertising.");
+                }
+            }
+            Command::Pcap(Pcap::Dump(_)) => {
+                if verbose {
+                    println!("Dumped the ring buffer of the capture.");
+                }
+            }
             Command::Pcap(Pcap::Get(_)) => {
                 if verbose {
                     println!("Successfully downloaded Pcap.");
                 }
             }
+            // The capture went to stdout, which the response leaves alone.
+            Command::Pcap(Pcap::Tail(_)) => {}
+            Command::Pcap(Pcap::Open(_)) => {
+                unimplemented!("No Grpc Response for Pcap Open Command.");
+            }
+            Command::Pcap(Pcap::Replay(cmd)) => {
+                let response = ReplayCaptureResponse::parse_from_bytes(response).unwrap();
+                println!(
+                    "Replayed {} packet(s) to chip {}, {} skipped",
+                    response.injected, cmd.chip_id, response.skipped
+                );
+            }
             Command::Gui => {
                 unimplemented!("No Grpc Response for Gui Command.");
             }
+            Command::Instances(_) => {
+                unimplemented!("No Grpc Response for Instances Command.");
+            }
+            Command::Stats(_) => {
+                unimplemented!("Stats Command prints the responses of several Grpc calls.");
+            }
+            Command::Events(_) => {
+                if verbose {
+                    println!("The event stream has ended.");
+                }
+            }
+            Command::Discover => {
+                unimplemented!("Discover Command prints its own response.");
+            }
+            Command::Bugreport(cmd) => Self::save_bugreport(
+                CreateBugReportResponse::parse_from_bytes(response).unwrap(),
+                cmd.location.as_deref(),
+                verbose,
+            ),
+            Command::Pause | Command::Resume | Command::Step(_) => println!(
+                "{}",
+                Self::format_delivery_state(&DeliveryState::parse_from_bytes(response).unwrap())
+            ),
+            Command::FuzzReplay(_) => Self::print_fuzz_replay_response(
+                ReplayFuzzInputResponse::parse_from_bytes(response).unwrap(),
+                verbose,
+            ),
+        }
+    }
+
+    /// Helper function to copy the bug report archive to `location`
+    fn save_bugreport(response: CreateBugReportResponse, location: Option<&str>, verbose: bool) {
+        let source = PathBuf::from(&response.path);
+        let dir = location.map(PathBuf::from).unwrap_or_else(|| env::current_dir().unwrap());
+        let destination = dir.join(source.file_name().unwrap_or_default());
+        if let Err(e) = fs::copy(&source, &destination) {
+            eprintln!("Failed to copy bug report {} to {}: {e}", source.display(), dir.display());
+            return;
+        }
+        if verbose {
+            for file in response.files.iter() {
+                println!("  {file}");
+            }
+        }
+        println!("Bug report saved to {}", destination.display());
+    }
+
+    /// Helper function to format a DeliveryState as a status line
+    pub fn format_delivery_state(state: &DeliveryState) -> String {
+        let mut status = if state.paused {
+            "Packet delivery paused".to_string()
+        } else if !state.paused_chip_ids.is_empty() {
+            let chip_ids: Vec<String> = state.paused_chip_ids.iter().map(i32::to_string).collect();
+            format!("Packet delivery paused for chip(s) {}", chip_ids.join(", "))
+        } else {
+            "Packet delivery running".to_string()
+        };
+        if state.delivered > 0 {
+            status += &format!(", {} packet(s) delivered", state.delivered);
+        }
+        if state.held > 0 {
+            status += &format!(", {} packet(s) held", state.held);
+        }
+        status
+    }
+
+    /// Helper function to format and print ReplayFuzzInputResponse
+    fn print_fuzz_replay_response(response: ReplayFuzzInputResponse, verbose: bool) {
+        if response.crashed {
+            println!("{}: CRASHED: {}", response.name, response.error);
+        } else if verbose && response.accepted {
+            println!("{}: accepted", response.name);
+        } else if verbose {
+            println!("{}: rejected: {}", response.name, response.error);
         }
     }
 
     /// Helper function to format and print GetDevicesResponse
-    fn print_device_response(response: GetDevicesResponse, verbose: bool) {
+    pub(crate) fn print_device_response(response: GetDevicesResponse, verbose: bool) {
         let pos_prec = 2;
         let name_width = 16;
         let state_width = 5;
@@ -105,6 +320,9 @@ impl args::Command {
                     "{:name_width$}  position: {:.pos_prec$}, {:.pos_prec$}, {:.pos_prec$}",
                     device.name, pos.x, pos.y, pos.z
                 );
+                if let Some(group) = device.group.as_deref().filter(|group| !group.is_empty()) {
+                    println!("{:chip_indent$}{:radio_width$}{group}", "", "group:");
+                }
                 for chip in &device.chips {
                     match &chip.chip {
                         Some(Chip_oneof_chip::Bt(bt)) => {
@@ -155,8 +373,34 @@ impl args::Command {
                                 Self::capture_state_to_string(chip.capture.enum_value_or_default())
                             );
                         }
+                        Some(Chip_oneof_chip::Nfc(nfc_chip)) => {
+                            let nfc_chip_radio = &nfc_chip.radio;
+                            println!(
+                                "{:chip_indent$}{:radio_width$}{:state_width$}| rx_count: {:cnt_width$?} | tx_count: {:cnt_width$?} | capture: {}",
+                                "",
+                                "nfc:",
+                                Self::chip_state_to_string(nfc_chip_radio.state.enum_value_or_default()),
+                                nfc_chip_radio.rx_count,
+                                nfc_chip_radio.tx_count,
+                                Self::capture_state_to_string(chip.capture.enum_value_or_default())
+                            );
+                            if let Some(tag) = nfc_chip.tag.as_ref() {
+                                println!(
+                                    "{:chip_indent$}{:radio_width$}{} bytes",
+                                    "",
+                                    "tag:",
+                                    tag.ndef.len()
+                                );
+                            }
+                        }
                         _ => println!("{:chip_indent$}Unknown chip: down  ", ""),
                     }
+                    if let Some(bandwidth) = chip.bandwidth.as_ref() {
+                        println!(
+                            "{:chip_indent$}{:radio_width$}{} bps",
+                            "", "bandwidth:", bandwidth.bits_per_second
+                        );
+                    }
                 }
             }
         } else {
@@ -169,6 +413,9 @@ impl args::Command {
                         pos.x, pos.y, pos.z
                     );
                 }
+                if let Some(group) = device.group.as_deref().filter(|group| !group.is_empty()) {
+                    print!("{:chip_indent$}group: {group}", "");
+                }
                 for chip in &device.chips {
                     match &chip.chip {
                         Some(Chip_oneof_chip::Bt(bt)) => {
@@ -199,29 +446,37 @@ impl args::Command {
                                 }
                             }
                         }
-                        Some(Chip_oneof_chip::Wifi(wifi_chip)) => {
-                            if wifi_chip.state.enum_value_or_default() == State::OFF {
-                                print!(
-                                    "{:chip_indent$}{:radio_width$}{:state_width$}",
-                                    "",
-                                    "wifi:",
-                                    Self::chip_state_to_string(
-                                        wifi_chip.state.enum_value_or_default()
-                                    )
-                                );
-                            }
+                        Some(Chip_oneof_chip::Wifi(wifi_chip))
+                            if wifi_chip.state.enum_value_or_default() == State::OFF =>
+                        {
+                            print!(
+                                "{:chip_indent$}{:radio_width$}{:state_width$}",
+                                "",
+                                "wifi:",
+                                Self::chip_state_to_string(wifi_chip.state.enum_value_or_default())
+                            );
                         }
-                        Some(Chip_oneof_chip::Uwb(uwb_chip)) => {
-                            if uwb_chip.state.enum_value_or_default() == State::OFF {
-                                print!(
-                                    "{:chip_indent$}{:radio_width$}{:state_width$}",
-                                    "",
-                                    "uwb:",
-                                    Self::chip_state_to_string(
-                                        uwb_chip.state.enum_value_or_default()
-                                    )
-                                );
-                            }
+                        Some(Chip_oneof_chip::Uwb(uwb_chip))
+                            if uwb_chip.state.enum_value_or_default() == State::OFF =>
+                        {
+                            print!(
+                                "{:chip_indent$}{:radio_width$}{:state_width$}",
+                                "",
+                                "uwb:",
+                                Self::chip_state_to_string(uwb_chip.state.enum_value_or_default())
+                            );
+                        }
+                        Some(Chip_oneof_chip::Nfc(nfc_chip))
+                            if nfc_chip.radio.state.enum_value_or_default() == State::OFF =>
+                        {
+                            print!(
+                                "{:chip_indent$}{:radio_width$}{:state_width$}",
+                                "",
+                                "nfc:",
+                                Self::chip_state_to_string(
+                                    nfc_chip.radio.state.enum_value_or_default()
+                                )
+                            );
                         }
                         _ => {}
                     }
@@ -261,6 +516,44 @@ impl args::Command {
     /// Helper function to format and print VersionResponse
     fn print_version_response(response: VersionResponse) {
         println!("Netsim version: {}", response.version);
+        // Older netsimd only report their version.
+        if !response.chip_kinds.is_empty() {
+            println!("Chip kinds: {}", response.chip_kinds.join(", "));
+        }
+        if !response.capture_formats.is_empty() {
+            let formats: Vec<String> = response
+                .capture_formats
+                .iter()
+                .map(|format| format!("{:?}", format.enum_value_or_default()))
+                .collect();
+            println!("Capture formats: {}", formats.join(", "));
+        }
+        if !response.features.is_empty() {
+            println!("Features: {}", response.features.join(", "));
+        }
+    }
+
+    /// Helper function to print the token of the next page of a list
+    fn print_next_page_token(next_page_token: &str) {
+        if !next_page_token.is_empty() {
+            println!("Next page: --page-token {next_page_token}");
+        }
+    }
+
+    /// Helper function to print the result of each chip of a PatchSelectionResponse
+    fn print_patch_selection_response(response: PatchSelectionResponse) {
+        for result in response.results {
+            let chip = format!(
+                "{} chip {} of {}",
+                Self::chip_kind_to_string(result.chip_kind.enum_value_or_default()),
+                result.chip_id,
+                result.device_name
+            );
+            match result.error.is_empty() {
+                true => println!("{chip}: ok"),
+                false => println!("{chip}: {}", result.error),
+            }
+        }
     }
 
     /// Helper function to format and print ListCaptureResponse
@@ -361,6 +654,7 @@ impl args::Command {
             ChipKind::BLUETOOTH => "BLUETOOTH".to_string(),
             ChipKind::WIFI => "WIFI".to_string(),
             ChipKind::UWB => "UWB".to_string(),
+            ChipKind::NFC => "NFC".to_string(),
         }
     }
 
@@ -0,0 +1,423 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Live packet rates of the chips and captures of netsimd.
+//
+// The table view also shows the state of the packet delivery, and reads
+// commands from stdin pausing, stepping and resuming it.
+
+use crate::args::{Command, Stats};
+use frontend_client_cxx::ffi::{FrontendClient, GrpcMethod};
+use frontend_proto::frontend::{
+    ChipTransportStats, DeliveryState, GetChipTransportStatsResponse, ListCaptureResponse,
+    StepDeliveryRequest,
+};
+use frontend_proto::model::Capture;
+use protobuf::Message;
+use serde_json::json;
+use std::cmp::max;
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::thread::{sleep, spawn};
+use std::time::Instant;
+
+// Escape sequence clearing the terminal and moving the cursor home.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+const CONTROLS_HINT: &str =
+    "Enter p to pause, r to resume or s [N] to step N packets of the delivery";
+
+/// A packet delivery command of the table view.
+#[derive(Debug, PartialEq)]
+enum Control {
+    Pause,
+    Resume,
+    Step(u32),
+}
+
+fn parse_control(line: &str) -> Result<Control, String> {
+    let mut words = line.split_whitespace();
+    let control = match words.next() {
+        Some("p" | "pause") => Control::Pause,
+        Some("r" | "resume") => Control::Resume,
+        Some("s" | "step") => match words.next().map(str::parse::<u32>) {
+            None => Control::Step(1),
+            Some(Ok(count)) if count > 0 => Control::Step(count),
+            Some(_) => return Err(format!("Invalid step count in '{}'", line.trim())),
+        },
+        _ => return Err(format!("Unknown command '{}'", line.trim())),
+    };
+    match words.next() {
+        Some(_) => Err(format!("Unknown command '{}'", line.trim())),
+        None => Ok(control),
+    }
+}
+
+/// The statistics of netsimd at a point in time.
+pub struct Snapshot {
+    pub time: Instant,
+    pub chips: Vec<ChipTransportStats>,
+    pub captures: Vec<Capture>,
+}
+
+/// Rates of a chip between two snapshots, per second.
+#[derive(Debug, PartialEq)]
+pub struct ChipRates {
+    pub device_name: String,
+    pub chip_kind: String,
+    pub packets_in: f64,
+    pub bytes_in: f64,
+    pub packets_out: f64,
+    pub bytes_out: f64,
+    pub malformed_packets: u64,
+    pub queue_depth: u64,
+}
+
+/// Rates of a capture between two snapshots, per second.
+#[derive(Debug, PartialEq)]
+pub struct CaptureRates {
+    pub id: i32,
+    pub device_name: String,
+    pub chip_kind: String,
+    pub records: f64,
+    pub bytes: f64,
+}
+
+// Returns the increase of a counter per second; counters of a new chip
+// count from zero.
+fn rate(current: u64, previous: Option<u64>, seconds: f64) -> f64 {
+    match seconds > 0.0 {
+        true => current.saturating_sub(previous.unwrap_or(0)) as f64 / seconds,
+        false => 0.0,
+    }
+}
+
+impl Snapshot {
+    fn seconds_since(&self, previous: &Snapshot) -> f64 {
+        self.time.saturating_duration_since(previous.time).as_secs_f64()
+    }
+
+    /// Returns the chip rates since `previous`, the busiest chips first.
+    pub fn chip_rates(&self, previous: &Snapshot) -> Vec<ChipRates> {
+        let seconds = self.seconds_since(previous);
+        let before: HashMap<i32, &ChipTransportStats> =
+            previous.chips.iter().map(|chip| (chip.chip_id, chip)).collect();
+        let mut rates: Vec<ChipRates> = self
+            .chips
+            .iter()
+            .map(|chip| {
+                let old = before.get(&chip.chip_id);
+                ChipRates {
+                    device_name: chip.device_name.clone(),
+                    chip_kind: Command::chip_kind_to_string(chip.chip_kind.enum_value_or_default()),
+                    packets_in: rate(chip.packets_in, old.map(|o| o.packets_in), seconds),
+                    bytes_in: rate(chip.bytes_in, old.map(|o| o.bytes_in), seconds),
+                    packets_out: rate(chip.packets_out, old.map(|o| o.packets_out), seconds),
+                    bytes_out: rate(chip.bytes_out, old.map(|o| o.bytes_out), seconds),
+                    malformed_packets: chip.malformed_packets,
+                    queue_depth: chip.queue_depth,
+                }
+            })
+            .collect();
+        rates.sort_by(|a, b| {
+            (b.packets_in + b.packets_out).total_cmp(&(a.packets_in + a.packets_out))
+        });
+        rates
+    }
+
+    /// Returns the capture rates since `previous`, the busiest captures first.
+    pub fn capture_rates(&self, previous: &Snapshot) -> Vec<CaptureRates> {
+        let seconds = self.seconds_since(previous);
+        let before: HashMap<i32, &Capture> =
+            previous.captures.iter().map(|capture| (capture.id, capture)).collect();
+        let mut rates: Vec<CaptureRates> = self
+            .captures
+            .iter()
+            .map(|capture| {
+                let old = before.get(&capture.id);
+                CaptureRates {
+                    id: capture.id,
+                    device_name: capture.device_name.clone(),
+                    chip_kind: Command::chip_kind_to_string(
+                        capture.chip_kind.enum_value_or_default(),
+                    ),
+                    records: rate(
+                        capture.records.max(0) as u64,
+                        old.map(|o| o.records.max(0) as u64),
+                        seconds,
+                    ),
+                    bytes: rate(
+                        capture.size.max(0) as u64,
+                        old.map(|o| o.size.max(0) as u64),
+                        seconds,
+                    ),
+                }
+            })
+            .collect();
+        rates.sort_by(|a, b| b.records.total_cmp(&a.records));
+        rates
+    }
+}
+
+/// Formats the rates as tables.
+pub fn format_table(chips: &[ChipRates], captures: &[CaptureRates]) -> String {
+    let name_hdr = "Device Name";
+    let name_width = max(
+        chips.iter().map(|chip| chip.device_name.len()).max().unwrap_or_default(),
+        name_hdr.len(),
+    );
+    let kind_width = 11; // ChipKind width 11 for 'UNSPECIFIED'
+    let rate_width = 10;
+    let mut table = format!(
+        "{:name_width$} | {:kind_width$} | {:>rate_width$} | {:>rate_width$} | {:>rate_width$} | {:>rate_width$} | {:>9} | {:>5} |\n",
+        name_hdr, "Chip Kind", "Pkts In/s", "Bytes In/s", "Pkts Out/s", "Bytes Out/s", "Malformed", "Queue"
+    );
+    for chip in chips {
+        table += &format!(
+            "{:name_width$} | {:kind_width$} | {:>rate_width$.1} | {:>rate_width$.1} | {:>rate_width$.1} | {:>rate_width$.1} | {:>9} | {:>5} |\n",
+            chip.device_name,
+            chip.chip_kind,
+            chip.packets_in,
+            chip.bytes_in,
+            chip.packets_out,
+            chip.bytes_out,
+            chip.malformed_packets,
+            chip.queue_depth,
+        );
+    }
+    if captures.is_empty() {
+        return table;
+    }
+    let name_width = max(
+        captures.iter().map(|capture| capture.device_name.len()).max().unwrap_or_default(),
+        name_hdr.len(),
+    );
+    let id_width = 4; // ID width of 4 since capture id (=chip_id) starts at 1000
+    table += &format!(
+        "\n{:id_width$} | {:name_width$} | {:kind_width$} | {:>rate_width$} | {:>rate_width$} |\n",
+        "ID", name_hdr, "Chip Kind", "Records/s", "Bytes/s"
+    );
+    for capture in captures {
+        table += &format!(
+            "{:id_width$} | {:name_width$} | {:kind_width$} | {:>rate_width$.1} | {:>rate_width$.1} |\n",
+            capture.id, capture.device_name, capture.chip_kind, capture.records, capture.bytes,
+        );
+    }
+    table
+}
+
+/// Formats the rates as a single line JSON object.
+pub fn format_json(chips: &[ChipRates], captures: &[CaptureRates]) -> String {
+    let chips: Vec<_> = chips
+        .iter()
+        .map(|chip| {
+            json!({
+                "deviceName": chip.device_name,
+                "chipKind": chip.chip_kind,
+                "packetsInPerSec": chip.packets_in,
+                "bytesInPerSec": chip.bytes_in,
+                "packetsOutPerSec": chip.packets_out,
+                "bytesOutPerSec": chip.bytes_out,
+                "malformedPackets": chip.malformed_packets,
+                "queueDepth": chip.queue_depth,
+            })
+        })
+        .collect();
+    let captures: Vec<_> = captures
+        .iter()
+        .map(|capture| {
+            json!({
+                "id": capture.id,
+                "deviceName": capture.device_name,
+                "chipKind": capture.chip_kind,
+                "recordsPerSec": capture.records,
+                "bytesPerSec": capture.bytes,
+            })
+        })
+        .collect();
+    json!({ "chips": chips, "captures": captures }).to_string()
+}
+
+// Returns the statistics of netsimd.
+fn take_snapshot(client: &cxx::UniquePtr<FrontendClient>) -> Result<Snapshot, String> {
+    let result = client.send_grpc(&GrpcMethod::GetChipTransportStats, &Vec::new());
+    if !result.is_ok() {
+        return Err(format!("Grpc call error: {}", result.err()));
+    }
+    let chips = GetChipTransportStatsResponse::parse_from_bytes(result.byte_vec().as_slice())
+        .map_err(|e| e.to_string())?
+        .chips;
+    let result = client.send_grpc(&GrpcMethod::ListCapture, &Vec::new());
+    if !result.is_ok() {
+        return Err(format!("Grpc call error: {}", result.err()));
+    }
+    let captures = ListCaptureResponse::parse_from_bytes(result.byte_vec().as_slice())
+        .map_err(|e| e.to_string())?
+        .captures;
+    Ok(Snapshot { time: Instant::now(), chips, captures })
+}
+
+// Sends a delivery request and returns the resulting delivery state.
+fn send_delivery_request(
+    client: &cxx::UniquePtr<FrontendClient>,
+    grpc_method: GrpcMethod,
+    request: Vec<u8>,
+) -> Result<DeliveryState, String> {
+    let result = client.send_grpc(&grpc_method, &request);
+    if !result.is_ok() {
+        return Err(format!("Grpc call error: {}", result.err()));
+    }
+    DeliveryState::parse_from_bytes(result.byte_vec().as_slice()).map_err(|e| e.to_string())
+}
+
+// Runs a command line of the table view and returns the message to show.
+fn run_control(client: &cxx::UniquePtr<FrontendClient>, line: &str) -> String {
+    let result = parse_control(line).and_then(|control| match control {
+        Control::Pause => send_delivery_request(client, GrpcMethod::PauseDelivery, Vec::new()),
+        Control::Resume => send_delivery_request(client, GrpcMethod::ResumeDelivery, Vec::new()),
+        Control::Step(count) => {
+            let request = StepDeliveryRequest { count, ..Default::default() };
+            send_delivery_request(
+                client,
+                GrpcMethod::StepDelivery,
+                request.write_to_bytes().unwrap(),
+            )
+        }
+    });
+    match result {
+        Ok(state) if state.delivered > 0 => format!("{} packet(s) delivered", state.delivered),
+        Ok(_) => String::new(),
+        Err(e) => e,
+    }
+}
+
+// Returns the lines read from stdin.
+fn read_lines() -> Receiver<String> {
+    let (sender, receiver) = channel();
+    spawn(move || {
+        for line in std::io::stdin().lock().lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+/// Prints the rates every interval until netsimd stops responding.
+pub fn watch_stats(client: &cxx::UniquePtr<FrontendClient>, cmd: &Stats) -> Result<(), String> {
+    let mut previous = take_snapshot(client)?;
+    let mut lines = (!cmd.json).then(read_lines);
+    let mut message = String::new();
+    loop {
+        // A command refreshes the table right away.
+        match lines.as_ref().map(|lines| lines.recv_timeout(cmd.interval)) {
+            Some(Ok(line)) => message = run_control(client, &line),
+            Some(Err(RecvTimeoutError::Timeout)) => {}
+            Some(Err(RecvTimeoutError::Disconnected)) => {
+                lines = None;
+                sleep(cmd.interval);
+            }
+            None => sleep(cmd.interval),
+        }
+        let current = take_snapshot(client)?;
+        let chips = current.chip_rates(&previous);
+        let captures = current.capture_rates(&previous);
+        if cmd.json {
+            println!("{}", format_json(&chips, &captures));
+        } else {
+            let state = send_delivery_request(client, GrpcMethod::GetDeliveryState, Vec::new())?;
+            print!(
+                "{CLEAR_SCREEN}{}\n{}\n{message}\n{CONTROLS_HINT}\n",
+                format_table(&chips, &captures),
+                Command::format_delivery_state(&state)
+            );
+        }
+        previous = current;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::common::ChipKind;
+    use std::time::Duration;
+
+    fn chip(name: &str, chip_id: i32, packets_in: u64, bytes_in: u64) -> ChipTransportStats {
+        ChipTransportStats {
+            device_name: name.to_string(),
+            chip_id,
+            chip_kind: ChipKind::BLUETOOTH.into(),
+            packets_in,
+            bytes_in,
+            ..Default::default()
+        }
+    }
+
+    fn snapshot(time: Instant, chips: Vec<ChipTransportStats>) -> Snapshot {
+        Snapshot { time, chips, captures: Vec::new() }
+    }
+
+    #[test]
+    fn test_chip_rates() {
+        let start = Instant::now();
+        let previous = snapshot(start, vec![chip("quiet", 1, 10, 100), chip("busy", 2, 10, 100)]);
+        let current = snapshot(
+            start + Duration::from_secs(2),
+            vec![chip("quiet", 1, 12, 120), chip("busy", 2, 110, 1100), chip("new", 3, 4, 40)],
+        );
+        let rates = current.chip_rates(&previous);
+        let names: Vec<&str> = rates.iter().map(|rate| rate.device_name.as_str()).collect();
+        assert_eq!(names, ["busy", "new", "quiet"]);
+        assert_eq!((rates[0].packets_in, rates[0].bytes_in), (50.0, 500.0));
+        assert_eq!(rates[1].packets_in, 2.0);
+        assert_eq!(rates[2].chip_kind, "BLUETOOTH");
+        assert!(format_table(&rates, &[]).lines().nth(1).unwrap().starts_with("busy"));
+    }
+
+    #[test]
+    fn test_parse_control() {
+        assert_eq!(parse_control("p"), Ok(Control::Pause));
+        assert_eq!(parse_control(" resume "), Ok(Control::Resume));
+        assert_eq!(parse_control("s"), Ok(Control::Step(1)));
+        assert_eq!(parse_control("step 3"), Ok(Control::Step(3)));
+        assert!(parse_control("s 0").is_err());
+        assert!(parse_control("s 1 2").is_err());
+        assert!(parse_control("q").is_err());
+    }
+
+    #[test]
+    fn test_capture_rates() {
+        let start = Instant::now();
+        let capture = |records, size| Capture {
+            id: 1000,
+            device_name: "phone".to_string(),
+            records,
+            size,
+            ..Default::default()
+        };
+        let previous = Snapshot { time: start, chips: Vec::new(), captures: vec![capture(5, 50)] };
+        let current = Snapshot {
+            time: start + Duration::from_millis(500),
+            chips: Vec::new(),
+            captures: vec![capture(10, 150)],
+        };
+        let rates = current.capture_rates(&previous);
+        assert_eq!((rates[0].records, rates[0].bytes), (10.0, 200.0));
+        let json: serde_json::Value = serde_json::from_str(&format_json(&[], &rates)).unwrap();
+        assert_eq!(json["captures"][0]["recordsPerSec"], 10.0);
+        assert_eq!(json["chips"].as_array().unwrap().len(), 0);
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Opening a capture in Wireshark.
+//
+// The capture is downloaded to a temporary file and opened in Wireshark,
+// or printed by tshark. The Wi-Fi keys of a keylog file are passed to the
+// viewer so the WPA traffic is decrypted. The keylog holds a key per line
+// as in the Wireshark 802.11 keys table, e.g. `wpa-pwd,password:ssid` or
+// `wpa-psk,<hex>`.
+
+use frontend_client_cxx::ffi::FrontendClient;
+use frontend_client_cxx::ClientResponseReader;
+use frontend_proto::frontend::GetCaptureRequest;
+use protobuf::Message;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::args::OpenCapture;
+use crate::pcap_handler::CaptureHandler;
+
+/// Environment variable naming the keylog file when --keylog isn't given.
+const KEYLOG_ENV: &str = "NETSIM_WIFI_KEYLOG";
+
+const KEY_TYPES: [&str; 3] = ["wep", "wpa-pwd", "wpa-psk"];
+
+// Returns the (type, key) entries of a keylog, skipping blank and # lines.
+fn parse_keylog(text: &str) -> Result<Vec<(String, String)>, String> {
+    let mut keys = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line.split_once(',') {
+            Some((key_type, key)) if KEY_TYPES.contains(&key_type.trim()) => {
+                keys.push((key_type.trim().to_string(), key.trim().to_string()))
+            }
+            _ => return Err(format!("invalid key on line {} of the keylog: {line}", i + 1)),
+        }
+    }
+    Ok(keys)
+}
+
+// Returns the viewer and its arguments to open a capture.
+fn viewer_command(
+    tshark: bool,
+    capture: &Path,
+    keys: &[(String, String)],
+) -> (&'static str, Vec<String>) {
+    let mut args = vec!["-r".to_string(), capture.display().to_string()];
+    if !keys.is_empty() {
+        args.extend(["-o".to_string(), "wlan.enable_decryption:TRUE".to_string()]);
+    }
+    for (key_type, key) in keys {
+        args.extend(["-o".to_string(), format!("uat:80211_keys:\"{key_type}\",\"{key}\"")]);
+    }
+    (if tshark { "tshark" } else { "wireshark" }, args)
+}
+
+// Reads the keys of the keylog named by --keylog or the environment.
+fn read_keys(cmd: &OpenCapture) -> Result<Vec<(String, String)>, String> {
+    let Some(keylog) = cmd.keylog.clone().or_else(|| std::env::var(KEYLOG_ENV).ok()) else {
+        return Ok(Vec::new());
+    };
+    let text = std::fs::read_to_string(&keylog)
+        .map_err(|e| format!("Unable to read keylog {keylog}: {e}"))?;
+    parse_keylog(&text)
+}
+
+/// Downloads a capture and opens it in Wireshark, or tshark.
+pub fn open_capture(
+    client: &cxx::UniquePtr<FrontendClient>,
+    cmd: &OpenCapture,
+) -> Result<(), String> {
+    let keys = read_keys(cmd)?;
+    let path: PathBuf = std::env::temp_dir().join(format!("netsim-capture-{}.pcap", cmd.id));
+    let file = File::create(&path)
+        .map_err(|e| format!("Failed to create file {}: {e}", path.display()))?;
+    let request = GetCaptureRequest { id: cmd.id, ..Default::default() };
+    let result = client.get_capture(
+        &request.write_to_bytes().unwrap(),
+        &ClientResponseReader { handler: Box::new(CaptureHandler { file, path: path.clone() }) },
+    );
+    if !result.is_ok() {
+        return Err(format!("Grpc call error: {}", result.err()));
+    }
+    let (viewer, args) = viewer_command(cmd.tshark, &path, &keys);
+    let mut command = Command::new(viewer);
+    command.args(&args);
+    // Wireshark keeps running on its own, tshark prints to this terminal.
+    let started =
+        if cmd.tshark { command.status().map(|_| ()) } else { command.spawn().map(|_| ()) };
+    started.map_err(|e| {
+        format!("Unable to run {viewer}: {e}. The capture is downloaded to {}", path.display())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keylog() {
+        let keylog = "# lab network\n\nwpa-pwd,secret:AndroidWifi\n wpa-psk , 0011 \n";
+        assert_eq!(
+            parse_keylog(keylog).unwrap(),
+            [
+                ("wpa-pwd".to_string(), "secret:AndroidWifi".to_string()),
+                ("wpa-psk".to_string(), "0011".to_string())
+            ]
+        );
+        assert!(parse_keylog("tls,secret").is_err());
+        assert!(parse_keylog("secret").is_err());
+    }
+
+    #[test]
+    fn test_viewer_command() {
+        let capture = Path::new("/tmp/netsim-capture-3.pcap");
+        assert_eq!(
+            viewer_command(true, capture, &[]),
+            ("tshark", vec!["-r".to_string(), "/tmp/netsim-capture-3.pcap".to_string()])
+        );
+        let keys = [("wpa-pwd".to_string(), "secret:AndroidWifi".to_string())];
+        let (viewer, args) = viewer_command(false, capture, &keys);
+        assert_eq!(viewer, "wireshark");
+        assert_eq!(
+            args[2..],
+            [
+                "-o",
+                "wlan.enable_decryption:TRUE",
+                "-o",
+                "uat:80211_keys:\"wpa-pwd\",\"secret:AndroidWifi\""
+            ]
+        );
+    }
+}
@@ -0,0 +1,353 @@
+//
+//  Copyright 2023 Google, Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at:
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! # gzip compression
+//!
+//! A streaming gzip (RFC 1952) writer. The data is compressed by DEFLATE
+//! (RFC 1951) with LZ77 matches coded by the fixed Huffman codes, which
+//! is simple and compresses the repetitive records of captures well.
+
+use std::io::{Result, Write};
+
+/// Bytes compressed as a block, after the window of the previous block.
+const BLOCK_LEN: usize = 1 << 16;
+/// Longest distance of a match.
+const WINDOW_LEN: usize = 1 << 15;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+/// Candidates tried for each match.
+const MAX_CHAIN: usize = 64;
+const HASH_BITS: u32 = 15;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] =
+    [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Returns the CRC-32 (ISO 3309) of data, continuing from `crc`.
+pub fn crc32(crc: u32, data: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+// Writes codes least significant bit first.
+struct BitWriter<W: Write> {
+    output: W,
+    bits: u64,
+    count: u32,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> BitWriter<W> {
+    fn put(&mut self, value: u32, count: u32) {
+        self.bits |= (value as u64) << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.buffer.push(self.bits as u8);
+            self.bits >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    // Writes a Huffman code, which is defined most significant bit first.
+    fn put_code(&mut self, code: u32, count: u32) {
+        self.put(code.reverse_bits() >> (32 - count), count);
+    }
+
+    fn align(&mut self) {
+        if self.count > 0 {
+            self.put(0, 8 - self.count);
+        }
+    }
+
+    fn flush_bytes(&mut self) -> Result<()> {
+        self.output.write_all(&self.buffer)?;
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+/// A writer compressing the data written to it to a gzip stream.
+pub struct GzipWriter<W: Write> {
+    output: BitWriter<W>,
+    // The window of the previous block, then the data of the next block.
+    data: Vec<u8>,
+    window: usize,
+    crc: u32,
+    len: u32,
+}
+
+impl<W: Write> GzipWriter<W> {
+    /// Writes the gzip header to output.
+    pub fn new(mut output: W) -> Result<Self> {
+        // Deflate, no flags, no time, no extra flags, unknown OS.
+        output.write_all(&[0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 255])?;
+        Ok(GzipWriter {
+            output: BitWriter { output, bits: 0, count: 0, buffer: Vec::new() },
+            data: Vec::new(),
+            window: 0,
+            crc: 0,
+            len: 0,
+        })
+    }
+
+    /// Compresses the data written so far, ends the stream and returns
+    /// the output.
+    pub fn finish(mut self) -> Result<W> {
+        self.compress_block()?;
+        // An empty final block with the fixed codes.
+        self.output.put(0b011, 3);
+        self.output.put_code(0, 7);
+        self.output.align();
+        self.output.buffer.extend(self.crc.to_le_bytes());
+        self.output.buffer.extend(self.len.to_le_bytes());
+        self.output.flush_bytes()?;
+        self.output.output.flush()?;
+        Ok(self.output.output)
+    }
+
+    fn put_literal(&mut self, byte: u8) {
+        match byte {
+            0..=143 => self.output.put_code(0x30 + byte as u32, 8),
+            _ => self.output.put_code(0x190 + byte as u32 - 144, 9),
+        }
+    }
+
+    fn put_match(&mut self, length: usize, distance: usize) {
+        let code = LENGTH_BASE.iter().rposition(|&base| base as usize <= length).unwrap();
+        let symbol = 257 + code as u32;
+        match symbol {
+            257..=279 => self.output.put_code(symbol - 256, 7),
+            _ => self.output.put_code(0xc0 + symbol - 280, 8),
+        }
+        let extra = LENGTH_EXTRA[code] as u32;
+        self.output.put((length - LENGTH_BASE[code] as usize) as u32, extra);
+        let code = DISTANCE_BASE.iter().rposition(|&base| base as usize <= distance).unwrap();
+        self.output.put_code(code as u32, 5);
+        let extra = DISTANCE_EXTRA[code] as u32;
+        self.output.put((distance - DISTANCE_BASE[code] as usize) as u32, extra);
+    }
+
+    // Compresses the pending data as a block with the fixed codes,
+    // matching the window of the previous block.
+    fn compress_block(&mut self) -> Result<()> {
+        if self.data.len() == self.window {
+            return Ok(());
+        }
+        self.output.put(0b010, 3);
+        let data = std::mem::take(&mut self.data);
+        let hash = |i: usize| {
+            let key = (data[i] as u32) << 16 | (data[i + 1] as u32) << 8 | data[i + 2] as u32;
+            (key.wrapping_mul(0x9e3779b1) >> (32 - HASH_BITS)) as usize
+        };
+        // The latest position of each hash and the previous position of
+        // the same hash.
+        let mut head = vec![usize::MAX; 1 << HASH_BITS];
+        let mut prev = vec![usize::MAX; data.len()];
+        let insert = |i: usize, head: &mut [usize], prev: &mut [usize]| {
+            if i + MIN_MATCH <= data.len() {
+                let h = hash(i);
+                prev[i] = head[h];
+                head[h] = i;
+            }
+        };
+        for i in 0..self.window {
+            insert(i, &mut head, &mut prev);
+        }
+        let mut i = self.window;
+        while i < data.len() {
+            let (mut best_len, mut best_distance) = (0, 0);
+            if i + MIN_MATCH <= data.len() {
+                let max_len = MAX_MATCH.min(data.len() - i);
+                let mut candidate = head[hash(i)];
+                let mut chain = 0;
+                while candidate != usize::MAX && i - candidate <= WINDOW_LEN && chain < MAX_CHAIN {
+                    let len = data[candidate..]
+                        .iter()
+                        .zip(&data[i..i + max_len])
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    if len > best_len {
+                        (best_len, best_distance) = (len, i - candidate);
+                        if len == max_len {
+                            break;
+                        }
+                    }
+                    candidate = prev[candidate];
+                    chain += 1;
+                }
+            }
+            if best_len >= MIN_MATCH {
+                self.put_match(best_len, best_distance);
+                for j in i..i + best_len {
+                    insert(j, &mut head, &mut prev);
+                }
+                i += best_len;
+            } else {
+                self.put_literal(data[i]);
+                insert(i, &mut head, &mut prev);
+                i += 1;
+            }
+        }
+        // End of block.
+        self.output.put_code(0, 7);
+        self.output.flush_bytes()?;
+        // Keep the end of the data as the window of the next block.
+        let start = data.len().saturating_sub(WINDOW_LEN);
+        self.data = data[start..].to_vec();
+        self.window = self.data.len();
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for GzipWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.crc = crc32(self.crc, buf);
+        self.len = self.len.wrapping_add(buf.len() as u32);
+        for chunk in buf.chunks(BLOCK_LEN) {
+            let room = self.window + BLOCK_LEN - self.data.len();
+            let (head, tail) = chunk.split_at(room.min(chunk.len()));
+            self.data.extend_from_slice(head);
+            if self.data.len() == self.window + BLOCK_LEN {
+                self.compress_block()?;
+            }
+            self.data.extend_from_slice(tail);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.output.output.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Inflates the fixed Huffman blocks of a deflate stream.
+    fn inflate(data: &[u8]) -> Vec<u8> {
+        let mut bit = 0;
+        let mut get = |count: u32| {
+            let mut value = 0;
+            for i in 0..count {
+                value |= ((data[bit / 8] >> (bit % 8)) as u32 & 1) << i;
+                bit += 1;
+            }
+            value
+        };
+        let mut output: Vec<u8> = Vec::new();
+        loop {
+            let last = get(1);
+            assert_eq!(get(2), 1, "not a fixed Huffman block");
+            loop {
+                // Read the code most significant bit first.
+                let mut code = 0;
+                for _ in 0..7 {
+                    code = code << 1 | get(1);
+                }
+                let symbol = match code {
+                    0..=0x17 => code + 256,
+                    _ => {
+                        code = code << 1 | get(1);
+                        match code {
+                            0x30..=0xbf => code - 0x30,
+                            0xc0..=0xc7 => code - 0xc0 + 280,
+                            _ => (code << 1 | get(1)) - 0x190 + 144,
+                        }
+                    }
+                };
+                match symbol {
+                    0..=255 => output.push(symbol as u8),
+                    256 => break,
+                    _ => {
+                        let code = (symbol - 257) as usize;
+                        let length =
+                            LENGTH_BASE[code] as usize + get(LENGTH_EXTRA[code] as u32) as usize;
+                        let mut code = 0;
+                        for _ in 0..5 {
+                            code = code << 1 | get(1);
+                        }
+                        let code = code as usize;
+                        let distance = DISTANCE_BASE[code] as usize
+                            + get(DISTANCE_EXTRA[code] as u32) as usize;
+                        for _ in 0..length {
+                            output.push(output[output.len() - distance]);
+                        }
+                    }
+                }
+            }
+            if last == 1 {
+                return output;
+            }
+        }
+    }
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut writer = GzipWriter::new(Vec::new()).unwrap();
+        // Odd writes cross the blocks.
+        for chunk in data.chunks(10_007) {
+            writer.write_all(chunk).unwrap();
+        }
+        writer.finish().unwrap()
+    }
+
+    fn gunzip(gz: &[u8]) -> Vec<u8> {
+        assert_eq!(gz[..4], [0x1f, 0x8b, 8, 0]);
+        let data = inflate(&gz[10..gz.len() - 8]);
+        let trailer = &gz[gz.len() - 8..];
+        assert_eq!(trailer[..4], crc32(0, &data).to_le_bytes());
+        assert_eq!(trailer[4..], (data.len() as u32).to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(0, b"123456789"), 0xcbf43926);
+        assert_eq!(crc32(crc32(0, b"1234"), b"56789"), 0xcbf43926);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(gunzip(&gzip(b"")), b"");
+        assert_eq!(gunzip(&gzip(b"a")), b"a");
+        // Records repeating with small differences, as in a capture.
+        let data: Vec<u8> = (0..200_000u32)
+            .flat_map(|i| [0, 0, 0, 1, 4, 0x0e, 4, 1, (i % 7) as u8, 32, 0, (i % 256) as u8])
+            .collect();
+        let gz = gzip(&data);
+        assert!(gz.len() * 10 < data.len(), "compressed to {} bytes", gz.len());
+        assert_eq!(gunzip(&gz), data);
+        let random: Vec<u8> =
+            (0..100_000u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect();
+        assert_eq!(gunzip(&gzip(&random)), random);
+    }
+}
@@ -15,5 +15,9 @@
 
 //! # netsim utilities.
 
+pub mod gzip;
 pub mod ini_file;
+pub mod os_utils;
+pub mod sha1;
+pub mod sha256;
 pub mod time_display;
@@ -0,0 +1,181 @@
+//
+//  Copyright 2023 Google, Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at:
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! # os utility functions
+//!
+//! Based on src/util/os_utils.cc.
+
+use std::path::PathBuf;
+
+/// Environment variable selecting the netsimd instance.
+pub const INSTANCE_ENV: &str = "NETSIM_INSTANCE";
+
+const DISCOVERY_FILE_PREFIX: &str = "netsim";
+const DISCOVERY_FILE_EXTENSION: &str = ".ini";
+const TOKEN_FILE_EXTENSION: &str = ".token";
+
+#[cfg(target_os = "windows")]
+const DISCOVERY: (&str, &str) = ("LOCALAPPDATA", "Temp");
+#[cfg(target_os = "linux")]
+const DISCOVERY: (&str, &str) = ("XDG_RUNTIME_DIR", "");
+#[cfg(target_os = "macos")]
+const DISCOVERY: (&str, &str) = ("HOME", "Library/Caches/TemporaryItems");
+
+/// Returns the path containing runtime user files.
+pub fn get_discovery_directory() -> PathBuf {
+    // $TMPDIR is the temp directory on buildbots.
+    if let Ok(test_env_p) = std::env::var("TMPDIR") {
+        if !test_env_p.is_empty() {
+            return PathBuf::from(test_env_p);
+        }
+    }
+    let (root_env, subdir) = DISCOVERY;
+    let mut path = match std::env::var(root_env) {
+        Ok(env_p) => PathBuf::from(env_p),
+        Err(_) => PathBuf::from("/tmp"),
+    };
+    if !subdir.is_empty() {
+        path.push(subdir);
+    }
+    path
+}
+
+/// Returns true if `instance` can be used as a netsimd instance name.
+///
+/// The empty name selects the default instance.
+pub fn is_valid_instance_name(instance: &str) -> bool {
+    instance.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Returns the file name of the netsim ini file of an instance.
+pub fn get_netsim_ini_filename(instance: &str) -> String {
+    match instance {
+        "" => format!("{DISCOVERY_FILE_PREFIX}{DISCOVERY_FILE_EXTENSION}"),
+        _ => format!("{DISCOVERY_FILE_PREFIX}_{instance}{DISCOVERY_FILE_EXTENSION}"),
+    }
+}
+
+/// Returns the path of the netsim ini file of an instance.
+pub fn get_netsim_ini_filepath(instance: &str) -> PathBuf {
+    get_discovery_directory().join(get_netsim_ini_filename(instance))
+}
+
+/// Returns the path of the frontend token file of an instance.
+pub fn get_netsim_token_filepath(instance: &str) -> PathBuf {
+    let filename = match instance {
+        "" => format!("{DISCOVERY_FILE_PREFIX}{TOKEN_FILE_EXTENSION}"),
+        _ => format!("{DISCOVERY_FILE_PREFIX}_{instance}{TOKEN_FILE_EXTENSION}"),
+    };
+    get_discovery_directory().join(filename)
+}
+
+/// Returns the instance name for a netsim ini file name, or None if the
+/// file isn't a netsim ini file.
+pub fn get_instance_name(filename: &str) -> Option<&str> {
+    let stem =
+        filename.strip_prefix(DISCOVERY_FILE_PREFIX)?.strip_suffix(DISCOVERY_FILE_EXTENSION)?;
+    match stem {
+        "" => Some(""),
+        _ => stem
+            .strip_prefix('_')
+            .filter(|instance| !instance.is_empty() && is_valid_instance_name(instance)),
+    }
+}
+
+/// Returns the names of all instances with a netsim ini file, sorted.
+pub fn list_instances() -> Vec<String> {
+    let mut instances: Vec<String> = match std::fs::read_dir(get_discovery_directory()) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .filter_map(|entry| {
+                entry.file_name().to_str().and_then(get_instance_name).map(String::from)
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    instances.sort();
+    instances
+}
+
+/// Returns "host:port", enclosing IPv6 hosts in brackets like "[::1]:8554".
+pub fn format_address(host: &str, port: u16) -> String {
+    if host.contains(':') && !host.starts_with('[') {
+        format!("[{host}]:{port}")
+    } else {
+        format!("{host}:{port}")
+    }
+}
+
+/// Returns the host clients use to reach a server listening on `listen_host`.
+///
+/// Wildcard hosts like "0.0.0.0" and "::" are reached through "localhost".
+pub fn get_connect_host(listen_host: &str) -> &str {
+    match listen_host {
+        "" | "0.0.0.0" | "::" | "[::]" => "localhost",
+        _ => listen_host,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_instance_name() {
+        assert!(is_valid_instance_name(""));
+        assert!(is_valid_instance_name("shard-1_a"));
+        assert!(!is_valid_instance_name("shard/1"));
+        assert!(!is_valid_instance_name("shard 1"));
+    }
+
+    #[test]
+    fn test_get_netsim_ini_filename() {
+        assert_eq!(get_netsim_ini_filename(""), "netsim.ini");
+        assert_eq!(get_netsim_ini_filename("shard1"), "netsim_shard1.ini");
+    }
+
+    #[test]
+    fn test_get_netsim_token_filepath() {
+        assert!(get_netsim_token_filepath("").ends_with("netsim.token"));
+        assert!(get_netsim_token_filepath("shard1").ends_with("netsim_shard1.token"));
+        assert_eq!(get_instance_name("netsim_shard1.token"), None);
+    }
+
+    #[test]
+    fn test_get_instance_name() {
+        assert_eq!(get_instance_name("netsim.ini"), Some(""));
+        assert_eq!(get_instance_name("netsim_shard1.ini"), Some("shard1"));
+        assert_eq!(get_instance_name("netsim_.ini"), None);
+        assert_eq!(get_instance_name("netsimd.ini"), None);
+        assert_eq!(get_instance_name("netsim_shard1.txt"), None);
+        assert_eq!(get_instance_name("other.ini"), None);
+    }
+
+    #[test]
+    fn test_format_address() {
+        assert_eq!(format_address("127.0.0.1", 7681), "127.0.0.1:7681");
+        assert_eq!(format_address("::1", 7681), "[::1]:7681");
+        assert_eq!(format_address("[::1]", 7681), "[::1]:7681");
+        assert_eq!(format_address("localhost", 7681), "localhost:7681");
+    }
+
+    #[test]
+    fn test_get_connect_host() {
+        assert_eq!(get_connect_host("0.0.0.0"), "localhost");
+        assert_eq!(get_connect_host("::"), "localhost");
+        assert_eq!(get_connect_host("::1"), "::1");
+    }
+}
@@ -0,0 +1,146 @@
+//
+//  Copyright 2023 Google, Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at:
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! # SHA-1 digests
+//!
+//! A streaming SHA-1 (FIPS 180-4), only for protocols requiring it like the
+//! WebSocket handshake; SHA-1 is not collision resistant.
+
+const H0: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+/// A SHA-1 digest of the data passed to `update`.
+#[derive(Clone, Debug)]
+pub struct Sha1 {
+    state: [u32; 5],
+    block: [u8; 64],
+    /// Bytes in `block`.
+    block_len: usize,
+    /// Bytes hashed so far.
+    len: u64,
+}
+
+impl Default for Sha1 {
+    fn default() -> Self {
+        Sha1 { state: H0, block: [0; 64], block_len: 0, len: 0 }
+    }
+}
+
+impl Sha1 {
+    /// Creates an empty digest
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hashes the next bytes of the data
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.len += data.len() as u64;
+        while !data.is_empty() {
+            let n = (64 - self.block_len).min(data.len());
+            self.block[self.block_len..self.block_len + n].copy_from_slice(&data[..n]);
+            self.block_len += n;
+            data = &data[n..];
+            if self.block_len == 64 {
+                self.compress();
+                self.block_len = 0;
+            }
+        }
+    }
+
+    /// Returns the digest of the data hashed so far, leaving the digest
+    /// open for more data.
+    pub fn finalize(&self) -> [u8; 20] {
+        let mut sha1 = self.clone();
+        let bits = self.len.wrapping_mul(8);
+        sha1.update(&[0x80]);
+        while sha1.block_len != 56 {
+            sha1.update(&[0]);
+        }
+        sha1.update(&bits.to_be_bytes());
+        let mut digest = [0u8; 20];
+        for (bytes, word) in digest.chunks_exact_mut(4).zip(sha1.state) {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+
+    /// Returns the digest as lowercase hex digits
+    pub fn hex(&self) -> String {
+        self.finalize().iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn compress(&mut self) {
+        let mut w = [0u32; 80];
+        for (i, word) in self.block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes(word.try_into().unwrap());
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = self.state;
+        for (i, word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5a827999),
+                20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+            let t = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = t;
+        }
+        for (state, value) in self.state.iter_mut().zip([a, b, c, d, e]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sha1;
+
+    fn hex(data: &[u8]) -> String {
+        let mut sha1 = Sha1::new();
+        sha1.update(data);
+        sha1.hex()
+    }
+
+    #[test]
+    fn test_known_digests() {
+        assert_eq!(hex(b""), "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(hex(b"abc"), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(
+            hex(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "84983e441c3bd26ebaae4aa1f95129e5e54670f1"
+        );
+    }
+
+    #[test]
+    fn test_rolling_updates() {
+        let data: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+        let mut sha1 = Sha1::new();
+        for chunk in data.chunks(37) {
+            sha1.update(chunk);
+        }
+        assert_eq!(sha1.hex(), hex(&data));
+    }
+}
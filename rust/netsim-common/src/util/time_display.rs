@@ -15,7 +15,7 @@
 
 //! # Time Display class
 
-use chrono::{DateTime, Datelike, NaiveDateTime, Timelike, Utc};
+use chrono::{Datelike, TimeZone, Timelike, Utc};
 
 /// A simple class that contains information required to display time
 pub struct TimeDisplay {
@@ -42,8 +42,7 @@ impl TimeDisplay {
     ///
     /// `Ok(String)` if the display was successful, `Error` otherwise.
     pub fn utc_display(&self) -> String {
-        if let Some(datetime) = NaiveDateTime::from_timestamp_opt(self.secs, self.nsecs) {
-            let current_datetime = DateTime::<Utc>::from_utc(datetime, Utc);
+        if let Some(current_datetime) = Utc.timestamp_opt(self.secs, self.nsecs).single() {
             return format!(
                 "{}-{:02}-{:02}-{:02}-{:02}-{:02}",
                 current_datetime.year(),
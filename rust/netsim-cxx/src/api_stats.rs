@@ -0,0 +1,276 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Statistics of the frontend APIs.
+//!
+//! Every frontend RPC and http route records its calls with their latency,
+//! from the start of the call to its status, and whether it failed. The
+//! statistics are exported as the `netsim_api_*` metrics and returned by
+//! the GetApiStats RPC, so a slow test can tell the time spent in netsimd
+//! from the time spent in its own harness. Streaming RPCs count their
+//! whole stream as latency. Calls abandoned by their client, or past their
+//! deadline, are also counted as cancelled.
+//!
+//! /v1/stats/api --> handle_api_stats
+
+use frontend_proto::frontend::{ApiStats, GetApiStatsResponse};
+use lazy_static::lazy_static;
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::collections::BTreeMap;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::ffi::CxxServerResponseWriter;
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::metrics::{register_collector, MetricKind, Sample};
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+/// Upper bounds of the latency histogram buckets in microseconds, followed
+/// by an unbounded bucket.
+const BUCKETS_US: [u64; 11] =
+    [100, 500, 1_000, 5_000, 10_000, 50_000, 100_000, 500_000, 1_000_000, 5_000_000, 10_000_000];
+
+/// The calls of an API.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Calls {
+    pub calls: u64,
+    pub errors: u64,
+    pub cancelled: u64,
+    pub total_us: u64,
+    pub max_us: u64,
+    /// Calls by latency bucket of BUCKETS_US.
+    pub buckets: [u64; BUCKETS_US.len() + 1],
+}
+
+impl Calls {
+    fn record(&mut self, latency_us: u64, error: bool, cancelled: bool) {
+        self.calls += 1;
+        self.errors += error as u64;
+        self.cancelled += cancelled as u64;
+        self.total_us += latency_us;
+        self.max_us = self.max_us.max(latency_us);
+        let bucket = BUCKETS_US.iter().position(|bound| latency_us <= *bound);
+        self.buckets[bucket.unwrap_or(BUCKETS_US.len())] += 1;
+    }
+
+    /// Returns the upper bound of the bucket holding the quantile `q`,
+    /// within the maximum latency.
+    pub fn quantile_us(&self, q: f64) -> u64 {
+        let rank = ((self.calls as f64 * q).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (bucket, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return BUCKETS_US
+                    .get(bucket)
+                    .map_or(self.max_us, |bound| *bound.min(&self.max_us));
+            }
+        }
+        self.max_us
+    }
+}
+
+/// The calls of the frontend APIs by name.
+#[derive(Default)]
+pub struct ApiCalls {
+    apis: Mutex<BTreeMap<String, Calls>>,
+}
+
+impl ApiCalls {
+    pub fn record(&self, api: &str, latency: Duration, error: bool, cancelled: bool) {
+        let mut apis = self.apis.lock().unwrap();
+        let latency_us = latency.as_micros() as u64;
+        match apis.get_mut(api) {
+            Some(calls) => calls.record(latency_us, error, cancelled),
+            None => apis.entry(api.to_string()).or_default().record(latency_us, error, cancelled),
+        }
+    }
+
+    /// Returns the calls of every API called so far.
+    pub fn snapshot(&self) -> Vec<(String, Calls)> {
+        let apis = self.apis.lock().unwrap();
+        apis.iter().map(|(api, calls)| (api.clone(), calls.clone())).collect()
+    }
+}
+
+lazy_static! {
+    static ref API_CALLS: ApiCalls = {
+        register_collector(collect_metrics);
+        ApiCalls::default()
+    };
+}
+
+/// Returns the netsimd API statistics.
+pub fn api_calls() -> &'static ApiCalls {
+    &API_CALLS
+}
+
+fn collect_metrics() -> Vec<Sample> {
+    let mut samples = Vec::new();
+    for (api, calls) in api_calls().snapshot() {
+        let labels = vec![("api", api)];
+        let mut sample = |name, help, kind, labels, value: f64| {
+            samples.push(Sample { name, help, kind, labels, value })
+        };
+        sample(
+            "netsim_api_calls_total",
+            "Calls of the frontend API.",
+            MetricKind::Counter,
+            labels.clone(),
+            calls.calls as f64,
+        );
+        sample(
+            "netsim_api_errors_total",
+            "Calls of the frontend API ending with an error.",
+            MetricKind::Counter,
+            labels.clone(),
+            calls.errors as f64,
+        );
+        sample(
+            "netsim_api_cancelled_total",
+            "Calls of the frontend API cancelled by the client or past their deadline.",
+            MetricKind::Counter,
+            labels.clone(),
+            calls.cancelled as f64,
+        );
+        let help = "Latency of the frontend API calls in seconds.";
+        let mut cumulative = 0;
+        for (bucket, count) in calls.buckets.iter().enumerate() {
+            cumulative += count;
+            let le = match BUCKETS_US.get(bucket) {
+                Some(bound) => (*bound as f64 / 1e6).to_string(),
+                None => "+Inf".to_string(),
+            };
+            let mut labels = labels.clone();
+            labels.push(("le", le));
+            let name = "netsim_api_latency_seconds_bucket";
+            sample(name, help, MetricKind::Histogram, labels, cumulative as f64);
+        }
+        let (sum, count) = (calls.total_us as f64 / 1e6, calls.calls as f64);
+        sample("netsim_api_latency_seconds_sum", help, MetricKind::Histogram, labels.clone(), sum);
+        sample("netsim_api_latency_seconds_count", help, MetricKind::Histogram, labels, count);
+    }
+    samples
+}
+
+/// Returns the statistics of the frontend APIs.
+pub fn get_api_stats() -> GetApiStatsResponse {
+    let apis = api_calls()
+        .snapshot()
+        .into_iter()
+        .map(|(api, calls)| ApiStats {
+            api,
+            calls: calls.calls,
+            errors: calls.errors,
+            cancelled: calls.cancelled,
+            total_latency_us: calls.total_us,
+            max_latency_us: calls.max_us,
+            p50_latency_us: calls.quantile_us(0.5),
+            p99_latency_us: calls.quantile_us(0.99),
+            ..Default::default()
+        })
+        .collect();
+    GetApiStatsResponse { apis, ..Default::default() }
+}
+
+/// The Rust API statistics handler used directly by Http frontend for GET
+pub fn handle_api_stats(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "GET" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    if let Ok(json_response) = print_to_string_with_options(&get_api_stats(), &JSON_PRINT_OPTION) {
+        writer.put_ok("text/json", &json_response, &[])
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure")
+    }
+}
+
+/// API statistics handle cxx for grpc server to call
+pub fn handle_api_stats_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/stats/api".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: Vec::new(),
+    };
+    handle_api_stats(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+// Cxx Method for the frontend server to invoke
+
+/// Records a call of a frontend RPC.
+pub fn record_api_call(api: &str, latency_us: u64, error: bool, cancelled: bool) {
+    api_calls().record(api, Duration::from_micros(latency_us), error, cancelled);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let api_calls = ApiCalls::default();
+        for latency_us in [50, 80, 700, 20_000_000] {
+            api_calls.record("GetDevices", Duration::from_micros(latency_us), false, false);
+        }
+        api_calls.record("GET /v1/devices", Duration::from_millis(3), true, false);
+        api_calls.record("GetCapture", Duration::from_secs(2), true, true);
+        let snapshot = api_calls.snapshot();
+        assert_eq!(snapshot.len(), 3);
+        let (api, calls) = &snapshot[2];
+        assert_eq!(api, "GetDevices");
+        assert_eq!((calls.calls, calls.errors, calls.cancelled), (4, 0, 0));
+        assert_eq!((calls.total_us, calls.max_us), (20_000_830, 20_000_000));
+        assert_eq!(calls.buckets[..3], [2, 0, 1]);
+        assert_eq!(calls.buckets[BUCKETS_US.len()], 1);
+        assert_eq!(snapshot[0].1.errors, 1);
+        assert_eq!((snapshot[1].0.as_str(), snapshot[1].1.cancelled), ("GetCapture", 1));
+    }
+
+    #[test]
+    fn test_quantile() {
+        let mut calls = Calls::default();
+        assert_eq!(calls.quantile_us(0.5), 0);
+        for _ in 0..99 {
+            calls.record(300, false, false);
+        }
+        calls.record(7_000, false, false);
+        assert_eq!(calls.quantile_us(0.5), 500);
+        assert_eq!(calls.quantile_us(0.99), 500);
+        assert_eq!(calls.quantile_us(1.0), 7_000);
+        // The percentiles stay within the maximum latency.
+        let mut calls = Calls::default();
+        calls.record(20, false, false);
+        assert_eq!(calls.quantile_us(0.5), 20);
+    }
+}
@@ -0,0 +1,294 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scoped authorization of the frontend.
+//!
+//! Auth is enabled by the `auth.tokens` setting, a comma separated list of
+//! `<token>=<scope>` entries. Every frontend RPC and http endpoint then
+//! requires a token with a scope granting its access:
+//!
+//! * `read-only` reads the scene, the statistics and the capture list,
+//! * `capture-download` lists and downloads the captures,
+//! * `admin` has full access, including the bug reports.
+//!
+//! The events carry the packets of the breakpoint hits and the stalled
+//! chips; like the captures they require the download scope, so the events
+//! of the other tokens have their packets cleared.
+//!
+//! Clients present the token as `Authorization: Bearer <token>`, in the
+//! http header or the gRPC metadata; the CLI sends the token of the
+//! `NETSIM_TOKEN` environment variable. The web UI files and the version
+//! stay public. The packet streamer of the emulators is not affected.
+//!
+//! On shared hosts the `auth.generate_token` setting enables the auth with
+//! an admin token generated at startup, and written to the token file of
+//! the instance with permissions for its user only. The CLI of that user
+//! sends the token of the file when `NETSIM_TOKEN` is not set, while the
+//! other users of the host are refused.
+
+use lazy_static::lazy_static;
+use netsim_common::util::os_utils::get_netsim_token_filepath;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::RwLock;
+
+use crate::config::{get_setting, get_setting_parsed};
+use crate::ffi::Access;
+use crate::http_server::http_request::HttpRequest;
+use crate::logger::{info, warn};
+
+const GENERATED_TOKEN_LEN: usize = 32;
+
+lazy_static! {
+    // The admin token generated at startup, empty without one.
+    static ref GENERATED_TOKEN: RwLock<String> = RwLock::new(String::new());
+}
+
+/// Access granted to a token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scope {
+    ReadOnly,
+    CaptureDownload,
+    Admin,
+}
+
+impl Scope {
+    fn parse(value: &str) -> Option<Scope> {
+        match value {
+            "read-only" => Some(Scope::ReadOnly),
+            "capture-download" => Some(Scope::CaptureDownload),
+            "admin" => Some(Scope::Admin),
+            _ => None,
+        }
+    }
+
+    fn allows(&self, access: Access) -> bool {
+        match self {
+            Scope::Admin => true,
+            Scope::ReadOnly => matches!(access, Access::Read | Access::ListCaptures),
+            Scope::CaptureDownload => {
+                matches!(access, Access::ListCaptures | Access::DownloadCaptures)
+            }
+        }
+    }
+}
+
+/// Why a request is refused.
+#[derive(Debug, PartialEq, Eq)]
+pub enum AuthError {
+    /// The request has no token or an unknown one.
+    Unauthenticated,
+    /// The scope of the token does not grant the access.
+    PermissionDenied,
+}
+
+/// Compares secrets in a time independent of the position of the first
+/// difference.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+// Returns the scope of a token among `<token>=<scope>` entries; invalid
+// entries grant nothing.
+fn token_scope(tokens: &str, token: &str) -> Option<Scope> {
+    tokens.split(',').filter_map(|entry| entry.trim().rsplit_once('=')).find_map(
+        |(known, scope)| match constant_time_eq(known.as_bytes(), token.as_bytes()) {
+            true => Scope::parse(scope),
+            false => None,
+        },
+    )
+}
+
+// Checks an authorization header value against `<token>=<scope>` entries.
+fn authorize_with(tokens: &str, authorization: &str, access: Access) -> Result<(), AuthError> {
+    if tokens.trim().is_empty() {
+        return Ok(());
+    }
+    let token = authorization.strip_prefix("Bearer ").ok_or(AuthError::Unauthenticated)?;
+    match token_scope(tokens, token.trim()) {
+        Some(scope) if scope.allows(access) => Ok(()),
+        Some(_) => Err(AuthError::PermissionDenied),
+        None => Err(AuthError::Unauthenticated),
+    }
+}
+
+/// Whether the frontend requires tokens.
+pub fn is_enabled() -> bool {
+    !get_setting("auth.tokens").trim().is_empty() || !GENERATED_TOKEN.read().unwrap().is_empty()
+}
+
+/// Checks an authorization header value for an access.
+pub fn authorize(authorization: &str, access: Access) -> Result<(), AuthError> {
+    let tokens = get_setting("auth.tokens");
+    let generated = GENERATED_TOKEN.read().unwrap();
+    match generated.is_empty() {
+        true => authorize_with(&tokens, authorization, access),
+        false => authorize_with(&format!("{generated}=admin,{tokens}"), authorization, access),
+    }
+}
+
+/// Whether an authorization header value may read the packets of the
+/// events.
+pub fn may_read_packets(authorization: &str) -> bool {
+    authorize(authorization, Access::DownloadCaptures).is_ok()
+}
+
+/// Creates a file with permissions for its owner only, for the files
+/// holding secrets: the token file, the state file and the bug reports.
+pub fn create_private_file(path: &Path) -> std::io::Result<File> {
+    // A file left by a previous run may have other permissions.
+    let _ = std::fs::remove_file(path);
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)
+}
+
+// Writes a token to a file created with permissions for its owner only.
+fn write_token_file(path: &Path, token: &str) -> std::io::Result<()> {
+    create_private_file(path)?.write_all(token.as_bytes())
+}
+
+/// Generates the admin token of the `auth.generate_token` setting and
+/// writes it to the token file of the instance.
+pub fn init_token() {
+    if !get_setting_parsed::<bool>("auth.generate_token") {
+        return;
+    }
+    let token: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(GENERATED_TOKEN_LEN)
+        .map(char::from)
+        .collect();
+    let path = get_netsim_token_filepath(&get_setting("instance"));
+    // The auth stays enabled with a token nobody knows rather than falling
+    // back to no auth.
+    match write_token_file(&path, &token) {
+        Ok(()) => info!("Frontend token written to {}", path.display()),
+        Err(e) => warn!("Unable to write the frontend token to {}: {e}", path.display()),
+    }
+    *GENERATED_TOKEN.write().unwrap() = token;
+}
+
+/// Returns the access an http request requires, None for public paths.
+pub fn http_access(method: &str, path: &str) -> Option<Access> {
+    let path = path.split('?').next().unwrap_or_default();
+    let captures = path.strip_prefix("/v1/captures");
+    if path.starts_with("/pcap/") {
+        return Some(Access::DownloadCaptures);
+    }
+    if path == "/metrics" {
+        return Some(Access::Read);
+    }
+    if !path.starts_with("/v1/") {
+        return None;
+    }
+    let read = method == "GET";
+    Some(match captures {
+        Some("" | "/") if read => Access::ListCaptures,
+        Some(_) if read => Access::DownloadCaptures,
+        // Bug reports bundle the captures.
+        _ if path.starts_with("/v1/bugreport") => Access::Admin,
+        _ if read => Access::Read,
+        _ => Access::Admin,
+    })
+}
+
+/// Checks the bearer token of an http request, returning the http status
+/// of a refused request.
+pub fn authorize_http(request: &HttpRequest) -> Result<(), (u16, &'static str)> {
+    let Some(access) = http_access(&request.method, &request.uri) else {
+        return Ok(());
+    };
+    let authorization = request.headers.get("Authorization").unwrap_or_default();
+    match authorize(&authorization, access) {
+        Ok(()) => Ok(()),
+        Err(AuthError::Unauthenticated) => Err((401, "Unauthorized")),
+        Err(AuthError::PermissionDenied) => Err((403, "Forbidden")),
+    }
+}
+
+// Cxx Method for the frontend server to invoke
+
+/// Returns 0 for an authorized call, 401 without a valid token and 403
+/// when the scope of the token does not grant the access.
+pub fn check_access(authorization: &str, access: Access) -> u32 {
+    match authorize(authorization, access) {
+        Ok(()) => 0,
+        Err(AuthError::Unauthenticated) => 401,
+        Err(AuthError::PermissionDenied) => 403,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKENS: &str = "dash=read-only, pcap=capture-download,root=admin,bad=owner";
+
+    #[test]
+    fn test_authorize() {
+        let check = |authorization, access| authorize_with(TOKENS, authorization, access);
+        assert_eq!(check("Bearer dash", Access::Read), Ok(()));
+        assert_eq!(check("Bearer dash", Access::ListCaptures), Ok(()));
+        assert_eq!(
+            check("Bearer dash", Access::DownloadCaptures),
+            Err(AuthError::PermissionDenied)
+        );
+        assert_eq!(check("Bearer pcap", Access::DownloadCaptures), Ok(()));
+        assert_eq!(check("Bearer pcap", Access::Read), Err(AuthError::PermissionDenied));
+        assert_eq!(check("Bearer root", Access::Admin), Ok(()));
+        assert_eq!(check("Bearer bad", Access::Read), Err(AuthError::Unauthenticated));
+        assert_eq!(check("Bearer roo", Access::Read), Err(AuthError::Unauthenticated));
+        assert_eq!(check("root", Access::Read), Err(AuthError::Unauthenticated));
+        assert_eq!(check("", Access::Read), Err(AuthError::Unauthenticated));
+        // Without tokens auth is disabled.
+        assert_eq!(authorize_with("", "", Access::Admin), Ok(()));
+    }
+
+    #[test]
+    fn test_write_token_file() {
+        let path = std::env::temp_dir().join(format!("netsim-token-{}", std::process::id()));
+        std::fs::write(&path, "previous token").unwrap();
+        write_token_file(&path, "token").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "token");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_http_access() {
+        assert_eq!(http_access("GET", "/"), None);
+        assert_eq!(http_access("GET", "/version"), None);
+        assert_eq!(http_access("GET", "/dev/index.html"), None);
+        assert_eq!(http_access("GET", "/v1/devices"), Some(Access::Read));
+        assert_eq!(http_access("PATCH", "/v1/devices"), Some(Access::Admin));
+        assert_eq!(http_access("GET", "/v1/captures"), Some(Access::ListCaptures));
+        assert_eq!(http_access("GET", "/v1/captures/3"), Some(Access::DownloadCaptures));
+        assert_eq!(http_access("PATCH", "/v1/captures/3"), Some(Access::Admin));
+        assert_eq!(http_access("GET", "/pcap/3"), Some(Access::DownloadCaptures));
+        assert_eq!(http_access("GET", "/v1/bugreport"), Some(Access::Admin));
+        assert_eq!(http_access("GET", "/metrics"), Some(Access::Read));
+        assert_eq!(http_access("GET", "/v1/events?limit=3"), Some(Access::Read));
+    }
+}
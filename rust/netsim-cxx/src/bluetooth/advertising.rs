@@ -0,0 +1,235 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Advertising timing.
+//!
+//! Rootcanal sends the advertisements of a controller at its configured
+//! advertising interval, and the LE phy layer used to deliver them to all
+//! scanners at once. With the `bluetooth.advertising_timing` setting the
+//! phy layer instead delays each advertising event by the random advDelay
+//! of the core specification, and a scanner only receives the events
+//! arriving within its scan window. Discovery latencies measured against
+//! netsim then depend on the advertising and scan parameters the way they
+//! do over the air.
+//!
+//! The scan parameters of each controller are observed from the HCI
+//! commands of its host.
+
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::clock::now;
+use crate::config::get_setting_parsed;
+use crate::random::next_u64;
+
+const LE_SET_SCAN_PARAMETERS: u16 = 0x200b;
+const LE_SET_SCAN_ENABLE: u16 = 0x200c;
+const LE_SET_EXTENDED_SCAN_PARAMETERS: u16 = 0x2041;
+const LE_SET_EXTENDED_SCAN_ENABLE: u16 = 0x2042;
+/// Length of the command header: opcode, then parameter length.
+const COMMAND_HEADER_LEN: usize = 3;
+/// Unit of the scan interval and window.
+const SCAN_TIME_UNIT: Duration = Duration::from_micros(625);
+/// Upper bound of the random advDelay added to each advertising event.
+pub const MAX_ADV_DELAY: Duration = Duration::from_millis(10);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Scanner {
+    interval: Duration,
+    window: Duration,
+    // Start of the first scan window while scanning.
+    enabled_at: Option<Duration>,
+}
+
+impl Default for Scanner {
+    // The default scan parameters of the controller.
+    fn default() -> Scanner {
+        Scanner { interval: SCAN_TIME_UNIT * 16, window: SCAN_TIME_UNIT * 16, enabled_at: None }
+    }
+}
+
+impl Scanner {
+    /// Whether the scanner listens at `time`. A scanner that is not
+    /// scanning listens for the initiator of a connection, which is not
+    /// modelled.
+    fn is_listening(&self, time: Duration) -> bool {
+        let Some(enabled_at) = self.enabled_at else {
+            return true;
+        };
+        if self.window >= self.interval {
+            return true;
+        }
+        let elapsed = time.saturating_sub(enabled_at).as_micros();
+        elapsed % self.interval.as_micros() < self.window.as_micros()
+    }
+}
+
+/// The scan parameters of the controllers, by facade id.
+#[derive(Default)]
+pub struct Scanners {
+    scanners: Mutex<HashMap<u32, Scanner>>,
+}
+
+fn scan_time(bytes: &[u8]) -> Duration {
+    SCAN_TIME_UNIT * u16::from_le_bytes([bytes[0], bytes[1]]) as u32
+}
+
+impl Scanners {
+    /// Updates the scanner of a controller from an HCI command of its host
+    /// sent at `time`.
+    pub fn observe_command(&self, facade_id: u32, command: &[u8], time: Duration) {
+        let Some(header) = command.get(..COMMAND_HEADER_LEN) else {
+            return;
+        };
+        let opcode = u16::from_le_bytes([header[0], header[1]]);
+        let parameters = &command[COMMAND_HEADER_LEN..];
+        let mut scanners = self.scanners.lock().unwrap();
+        match opcode {
+            // Scan type, interval, window, own address type, filter policy.
+            LE_SET_SCAN_PARAMETERS if parameters.len() >= 5 => {
+                let scanner = scanners.entry(facade_id).or_default();
+                scanner.interval = scan_time(&parameters[1..3]);
+                scanner.window = scan_time(&parameters[3..5]);
+            }
+            // Own address type, filter policy, phys, then the scan type,
+            // interval and window of each phy; the first phy is used.
+            LE_SET_EXTENDED_SCAN_PARAMETERS if parameters.len() >= 8 => {
+                let scanner = scanners.entry(facade_id).or_default();
+                scanner.interval = scan_time(&parameters[4..6]);
+                scanner.window = scan_time(&parameters[6..8]);
+            }
+            LE_SET_SCAN_ENABLE | LE_SET_EXTENDED_SCAN_ENABLE if !parameters.is_empty() => {
+                let scanner = scanners.entry(facade_id).or_default();
+                scanner.enabled_at = match parameters[0] {
+                    0 => None,
+                    // Enabling again does not restart the scan.
+                    _ => scanner.enabled_at.or(Some(time)),
+                };
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether the controller of `facade_id` receives an advertising event
+    /// arriving at `time`.
+    pub fn is_listening(&self, facade_id: u32, time: Duration) -> bool {
+        let scanners = self.scanners.lock().unwrap();
+        scanners.get(&facade_id).is_none_or(|scanner| scanner.is_listening(time))
+    }
+
+    pub fn remove(&self, facade_id: u32) {
+        self.scanners.lock().unwrap().remove(&facade_id);
+    }
+}
+
+lazy_static! {
+    static ref ENABLED: bool = get_setting_parsed("bluetooth.advertising_timing");
+    static ref SCANNERS: Scanners = Scanners::default();
+}
+
+/// Returns a pseudo random advDelay between 0 and MAX_ADV_DELAY.
+fn random_adv_delay() -> Duration {
+    Duration::from_millis(next_u64() % (MAX_ADV_DELAY.as_millis() as u64 + 1))
+}
+
+// Cxx Methods for the Bluetooth facade to invoke
+
+pub fn observe_command(facade_id: u32, command: &[u8]) {
+    if *ENABLED {
+        SCANNERS.observe_command(facade_id, command, now());
+    }
+}
+
+pub fn remove_scanner(facade_id: u32) {
+    SCANNERS.remove(facade_id);
+}
+
+/// Returns the delay in milliseconds of the next advertising event.
+pub fn advertising_delay() -> u32 {
+    match *ENABLED {
+        true => random_adv_delay().as_millis() as u32,
+        false => 0,
+    }
+}
+
+/// Whether a controller receives an advertising event delayed by `delay`
+/// milliseconds.
+pub fn is_listening(facade_id: u32, delay: u32) -> bool {
+    !*ENABLED || SCANNERS.is_listening(facade_id, now() + Duration::from_millis(delay as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // LE Set Scan Parameters of a passive scan, in units of 0.625ms.
+    fn scan_parameters(interval: u16, window: u16) -> Vec<u8> {
+        let mut command = vec![0x0b, 0x20, 7, 0];
+        command.extend_from_slice(&interval.to_le_bytes());
+        command.extend_from_slice(&window.to_le_bytes());
+        command.extend_from_slice(&[0, 0]);
+        command
+    }
+
+    fn scan_enable(enable: u8) -> Vec<u8> {
+        vec![0x0c, 0x20, 2, enable, 0]
+    }
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_scan_window() {
+        let scanners = Scanners::default();
+        let start = Duration::from_secs(100);
+        // 100ms interval with a 25ms window.
+        scanners.observe_command(1, &scan_parameters(160, 40), start);
+        // Not scanning yet.
+        assert!(scanners.is_listening(1, start + ms(50)));
+        scanners.observe_command(1, &scan_enable(1), start);
+        assert!(scanners.is_listening(1, start + ms(10)));
+        assert!(!scanners.is_listening(1, start + ms(50)));
+        assert!(scanners.is_listening(1, start + ms(120)));
+        // Enabling again keeps the phase of the scan.
+        scanners.observe_command(1, &scan_enable(1), start + ms(40));
+        assert!(!scanners.is_listening(1, start + ms(50)));
+        scanners.observe_command(1, &scan_enable(0), start + ms(40));
+        assert!(scanners.is_listening(1, start + ms(50)));
+        // Unknown controllers always listen.
+        assert!(scanners.is_listening(2, start + ms(50)));
+    }
+
+    #[test]
+    fn test_extended_scan() {
+        let scanners = Scanners::default();
+        let start = Duration::from_secs(100);
+        // 1M phy, active scan with a 50ms interval and a 10ms window.
+        let parameters = [0x41, 0x20, 8, 0, 0, 1, 1, 80, 0, 16, 0];
+        scanners.observe_command(1, &parameters, start);
+        scanners.observe_command(1, &[0x42, 0x20, 6, 1, 0, 0, 0, 0, 0], start);
+        assert!(scanners.is_listening(1, start + ms(55)));
+        assert!(!scanners.is_listening(1, start + ms(65)));
+        // A continuous scan always listens.
+        scanners.observe_command(1, &scan_parameters(160, 160), start);
+        assert!(scanners.is_listening(1, start + ms(65)));
+    }
+
+    #[test]
+    fn test_random_adv_delay() {
+        assert!((0..100).all(|_| random_adv_delay() <= MAX_ADV_DELAY));
+    }
+}
@@ -0,0 +1,272 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builtin beacons.
+//!
+//! A beacon is a Bluetooth device without an emulator: netsimd adds its
+//! chip to the scene and drives the controller with the HCI commands of a
+//! non-connectable advertiser, advertising its name followed by the given
+//! advertising data. Beacons make manual scanner testing possible without
+//! authoring a scene.
+//!
+//! /v1/beacons      --> handle_beacons (GET, POST)
+//! /v1/beacons/{id} --> handle_beacons (DELETE)
+
+use cxx::let_cxx_string;
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::{AdvertiseRequest, Beacon, ListBeaconsResponse};
+use lazy_static::lazy_static;
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::collections::BTreeMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+
+use crate::ffi::{add_chip_cxx, handle_request_cxx, remove_chip, CxxServerResponseWriter};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::info;
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+const HCI_COMMAND: u8 = 1;
+const RESET: u16 = 0x0c03;
+const LE_SET_ADVERTISING_PARAMETERS: u16 = 0x2006;
+const LE_SET_ADVERTISING_DATA: u16 = 0x2008;
+const LE_SET_ADVERTISING_ENABLE: u16 = 0x200a;
+/// Non-connectable undirected advertising, ADV_NONCONN_IND.
+const ADV_NONCONN_IND: u8 = 0x03;
+/// All three primary advertising channels.
+const ADVERTISING_CHANNELS: u8 = 0x07;
+/// Advertising data type of the complete local name.
+const COMPLETE_LOCAL_NAME: u8 = 0x09;
+const MAX_ADVERTISING_DATA_LEN: usize = 31;
+/// Range of the advertising interval, in milliseconds.
+const MIN_INTERVAL_MS: u32 = 20;
+const MAX_INTERVAL_MS: u32 = 10240;
+
+lazy_static! {
+    // Beacons by chip id.
+    static ref BEACONS: Mutex<BTreeMap<i32, Beacon>> = Mutex::new(BTreeMap::new());
+    static ref NEXT_BEACON: AtomicU32 = AtomicU32::new(1);
+}
+
+// Returns the name advertising structure followed by the payload.
+fn advertising_data(name: &str, payload: &[u8]) -> Result<Vec<u8>, String> {
+    let mut data = vec![(name.len() + 1) as u8, COMPLETE_LOCAL_NAME];
+    data.extend_from_slice(name.as_bytes());
+    data.extend_from_slice(payload);
+    if data.len() > MAX_ADVERTISING_DATA_LEN {
+        return Err(format!(
+            "Advertising data of {} bytes exceeds {MAX_ADVERTISING_DATA_LEN} bytes",
+            data.len()
+        ));
+    }
+    Ok(data)
+}
+
+// Returns the advertising interval in units of 0.625 ms.
+fn advertising_interval(interval_ms: u32) -> Result<u16, String> {
+    if !(MIN_INTERVAL_MS..=MAX_INTERVAL_MS).contains(&interval_ms) {
+        return Err(format!(
+            "Advertising interval {interval_ms} ms is not within {MIN_INTERVAL_MS} and \
+             {MAX_INTERVAL_MS} ms"
+        ));
+    }
+    Ok((interval_ms * 8 / 5) as u16)
+}
+
+fn command(opcode: u16, parameters: &[u8]) -> Vec<u8> {
+    [&opcode.to_le_bytes()[..], &[parameters.len() as u8], parameters].concat()
+}
+
+// Returns the HCI commands starting the advertising of a beacon.
+fn advertising_commands(interval: u16, data: &[u8]) -> Vec<Vec<u8>> {
+    let mut parameters = [0u8; 15];
+    parameters[0..2].copy_from_slice(&interval.to_le_bytes());
+    parameters[2..4].copy_from_slice(&interval.to_le_bytes());
+    parameters[4] = ADV_NONCONN_IND;
+    parameters[13] = ADVERTISING_CHANNELS;
+    let mut advertising_data = [0u8; MAX_ADVERTISING_DATA_LEN + 1];
+    advertising_data[0] = data.len() as u8;
+    advertising_data[1..=data.len()].copy_from_slice(data);
+    vec![
+        command(RESET, &[]),
+        command(LE_SET_ADVERTISING_PARAMETERS, &parameters),
+        command(LE_SET_ADVERTISING_DATA, &advertising_data),
+        command(LE_SET_ADVERTISING_ENABLE, &[1]),
+    ]
+}
+
+/// Adds a beacon to the scene and starts its advertising.
+pub fn create_beacon(request: &AdvertiseRequest) -> Result<Beacon, String> {
+    let number = NEXT_BEACON.fetch_add(1, Ordering::Relaxed);
+    let name = match request.name.is_empty() {
+        true => format!("beacon-{number}"),
+        false => request.name.clone(),
+    };
+    let data = advertising_data(&name, &request.payload)?;
+    let interval = advertising_interval(request.interval_ms)?;
+    let_cxx_string!(guid = format!("beacon-{number}"));
+    let_cxx_string!(device_name = name.clone());
+    let_cxx_string!(chip_name = "beacon");
+    let_cxx_string!(manufacturer = "netsim");
+    let_cxx_string!(product_name = "beacon");
+    let kind = ChipKind::BLUETOOTH as u32;
+    let result = add_chip_cxx(&guid, &device_name, kind, &chip_name, &manufacturer, &product_name);
+    for packet in advertising_commands(interval, &data) {
+        handle_request_cxx(kind, result.get_facade_id(), &packet, HCI_COMMAND);
+    }
+    let beacon = Beacon {
+        device_id: result.get_device_id() as i32,
+        chip_id: result.get_chip_id() as i32,
+        name,
+        interval_ms: request.interval_ms,
+        advertising_data: data,
+        ..Default::default()
+    };
+    info!(
+        "beacon {} advertising every {} ms as chip {}",
+        beacon.name, beacon.interval_ms, beacon.chip_id
+    );
+    BEACONS.lock().unwrap().insert(beacon.chip_id, beacon.clone());
+    Ok(beacon)
+}
+
+/// Returns the requests creating the current beacons again.
+pub fn beacon_requests() -> Vec<AdvertiseRequest> {
+    let beacons = BEACONS.lock().unwrap();
+    beacons
+        .values()
+        .map(|beacon| AdvertiseRequest {
+            name: beacon.name.clone(),
+            interval_ms: beacon.interval_ms,
+            // The advertising data starts with the name structure.
+            payload: beacon.advertising_data[beacon.name.len() + 2..].to_vec(),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Removes a beacon from the scene. Returns false for unknown beacons.
+pub fn remove_beacon(chip_id: i32) -> bool {
+    let Some(beacon) = BEACONS.lock().unwrap().remove(&chip_id) else {
+        return false;
+    };
+    remove_chip(beacon.device_id as u32, beacon.chip_id as u32);
+    info!("beacon {} removed", beacon.name);
+    true
+}
+
+fn put_json<M: protobuf::MessageFull>(writer: ResponseWritable, message: &M) {
+    if let Ok(json_response) = print_to_string_with_options(message, &JSON_PRINT_OPTION) {
+        writer.put_ok("text/json", &json_response, &[])
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure")
+    }
+}
+
+/// The Rust beacons handler used directly by Http frontend for GET, POST
+/// and DELETE
+pub fn handle_beacons(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    match request.method.as_str() {
+        "GET" => {
+            let beacons = BEACONS.lock().unwrap().values().cloned().collect();
+            put_json(writer, &ListBeaconsResponse { beacons, ..Default::default() })
+        }
+        "POST" => {
+            let body = String::from_utf8_lossy(&request.body);
+            let advertise_request =
+                match protobuf_json_mapping::parse_from_str::<AdvertiseRequest>(&body) {
+                    Ok(advertise_request) => advertise_request,
+                    Err(e) => {
+                        writer.put_error(400, format!("Invalid advertise request: {e}").as_str());
+                        return;
+                    }
+                };
+            match create_beacon(&advertise_request) {
+                Ok(beacon) => put_json(writer, &beacon),
+                Err(e) => writer.put_error(400, e.as_str()),
+            }
+        }
+        "DELETE" => match param.parse::<i32>() {
+            Ok(chip_id) if remove_beacon(chip_id) => writer.put_ok("text/plain", "", &[]),
+            _ => writer.put_error(404, format!("Beacon {param} not found").as_str()),
+        },
+        _ => writer.put_error(404, "Not found."),
+    }
+}
+
+/// beacons handle cxx for grpc server to call
+pub fn handle_beacons_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: match param.is_empty() {
+            true => "/v1/beacons".to_string(),
+            false => format!("/v1/beacons/{param}"),
+        },
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_beacons(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advertising_data() {
+        assert_eq!(
+            advertising_data("Foo", &[2, 1, 6]),
+            Ok(vec![4, 0x09, b'F', b'o', b'o', 2, 1, 6])
+        );
+        assert!(advertising_data("Foo", &[0; 26]).is_ok());
+        assert!(advertising_data("Foo", &[0; 27]).is_err());
+    }
+
+    #[test]
+    fn test_advertising_commands() {
+        assert_eq!(advertising_interval(100), Ok(160));
+        assert_eq!(advertising_interval(20), Ok(0x20));
+        assert_eq!(advertising_interval(10240), Ok(0x4000));
+        assert!(advertising_interval(19).is_err());
+        assert!(advertising_interval(10241).is_err());
+        let commands = advertising_commands(160, &[2, 1, 6]);
+        assert_eq!(commands[0], [0x03, 0x0c, 0]);
+        assert_eq!(
+            commands[1],
+            [0x06, 0x20, 15, 160, 0, 160, 0, ADV_NONCONN_IND, 0, 0, 0, 0, 0, 0, 0, 0, 0x07, 0]
+        );
+        assert_eq!(commands[2].len(), 3 + 32);
+        assert_eq!(commands[2][..7], [0x08, 0x20, 32, 3, 2, 1, 6]);
+        assert_eq!(commands[3], [0x0a, 0x20, 1, 1]);
+    }
+}
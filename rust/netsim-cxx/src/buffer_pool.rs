@@ -0,0 +1,255 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pool of packet buffers.
+//!
+//! The packets read by the transports and the records of the captures are
+//! taken from a pool of buffers rather than allocated and freed for each
+//! packet: a PooledBuffer returns its Vec to the pool as it is dropped,
+//! with its capacity, for the next packet. The pool holds at most
+//! `buffer_pool.buffers` free buffers of at most
+//! `buffer_pool.max_buffer_bytes`. A buffer is allocated on the heap when
+//! the pool is empty, and the buffers beyond the pool or its buffer size
+//! are freed as they are dropped.
+
+use crate::config::get_setting_parsed;
+use crate::metrics::{register_collector, MetricKind, Sample};
+use lazy_static::lazy_static;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Occupancy of a buffer pool.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Buffers in the pool, ready to be taken.
+    pub free: usize,
+    /// Buffers taken from the pool or the heap and not dropped yet.
+    pub in_use: usize,
+    /// Buffers taken from the pool.
+    pub reused: u64,
+    /// Buffers allocated on the heap because the pool was empty.
+    pub allocated: u64,
+    /// Buffers freed rather than returned to a full pool, or too large.
+    pub freed: u64,
+}
+
+/// A pool of byte buffers reused from one packet to the next.
+#[derive(Debug)]
+pub struct BufferPool {
+    free: Mutex<Vec<Vec<u8>>>,
+    max_buffers: usize,
+    max_buffer_bytes: usize,
+    in_use: AtomicUsize,
+    reused: AtomicU64,
+    allocated: AtomicU64,
+    freed: AtomicU64,
+}
+
+lazy_static! {
+    static ref BUFFER_POOL: Arc<BufferPool> = {
+        register_collector(collect_metrics);
+        Arc::new(BufferPool::new(
+            get_setting_parsed("buffer_pool.buffers"),
+            get_setting_parsed("buffer_pool.max_buffer_bytes"),
+        ))
+    };
+}
+
+/// Returns the buffer pool of the netsimd process.
+pub fn buffer_pool() -> &'static Arc<BufferPool> {
+    &BUFFER_POOL
+}
+
+impl BufferPool {
+    /// Creates a pool of at most `max_buffers` free buffers, keeping the
+    /// buffers of at most `max_buffer_bytes`.
+    pub fn new(max_buffers: usize, max_buffer_bytes: usize) -> BufferPool {
+        BufferPool {
+            free: Mutex::new(Vec::new()),
+            max_buffers,
+            max_buffer_bytes,
+            in_use: AtomicUsize::new(0),
+            reused: AtomicU64::new(0),
+            allocated: AtomicU64::new(0),
+            freed: AtomicU64::new(0),
+        }
+    }
+
+    /// Takes an empty buffer with room for `capacity` bytes, from the pool
+    /// or else from the heap.
+    pub fn take(self: &Arc<Self>, capacity: usize) -> PooledBuffer {
+        let data = match self.free.lock().unwrap().pop() {
+            Some(mut data) => {
+                self.reused.fetch_add(1, Ordering::Relaxed);
+                data.reserve(capacity);
+                data
+            }
+            None => {
+                self.allocated.fetch_add(1, Ordering::Relaxed);
+                Vec::with_capacity(capacity)
+            }
+        };
+        self.in_use.fetch_add(1, Ordering::Relaxed);
+        PooledBuffer { data, pool: Some(self.clone()) }
+    }
+
+    // Returns a dropped buffer to the pool, or frees it.
+    fn put(&self, mut data: Vec<u8>) {
+        self.in_use.fetch_sub(1, Ordering::Relaxed);
+        if data.capacity() <= self.max_buffer_bytes {
+            let mut free = self.free.lock().unwrap();
+            if free.len() < self.max_buffers {
+                data.clear();
+                free.push(data);
+                return;
+            }
+        }
+        self.freed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> PoolStats {
+        PoolStats {
+            free: self.free.lock().unwrap().len(),
+            in_use: self.in_use.load(Ordering::Relaxed),
+            reused: self.reused.load(Ordering::Relaxed),
+            allocated: self.allocated.load(Ordering::Relaxed),
+            freed: self.freed.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A buffer of a pool, returned to the pool as it is dropped. A buffer
+/// made from a Vec belongs to no pool and is freed.
+#[derive(Debug, Default)]
+pub struct PooledBuffer {
+    data: Vec<u8>,
+    pool: Option<Arc<BufferPool>>,
+}
+
+impl From<Vec<u8>> for PooledBuffer {
+    fn from(data: Vec<u8>) -> Self {
+        PooledBuffer { data, pool: None }
+    }
+}
+
+impl Deref for PooledBuffer {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Vec<u8> {
+        &self.data
+    }
+}
+
+impl DerefMut for PooledBuffer {
+    fn deref_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.data
+    }
+}
+
+impl PartialEq for PooledBuffer {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl Drop for PooledBuffer {
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool.take() {
+            pool.put(std::mem::take(&mut self.data));
+        }
+    }
+}
+
+fn collect_metrics() -> Vec<Sample> {
+    let stats = buffer_pool().stats();
+    let sample = |name, help, kind, value: f64| Sample { name, help, kind, labels: vec![], value };
+    vec![
+        sample(
+            "netsim_buffer_pool_free_buffers",
+            "Packet buffers in the pool, ready to be reused.",
+            MetricKind::Gauge,
+            stats.free as f64,
+        ),
+        sample(
+            "netsim_buffer_pool_in_use_buffers",
+            "Packet buffers taken and not dropped yet.",
+            MetricKind::Gauge,
+            stats.in_use as f64,
+        ),
+        sample(
+            "netsim_buffer_pool_reused_total",
+            "Packet buffers taken from the pool.",
+            MetricKind::Counter,
+            stats.reused as f64,
+        ),
+        sample(
+            "netsim_buffer_pool_allocated_total",
+            "Packet buffers allocated on the heap because the pool was empty.",
+            MetricKind::Counter,
+            stats.allocated as f64,
+        ),
+        sample(
+            "netsim_buffer_pool_freed_total",
+            "Packet buffers freed rather than returned to the pool.",
+            MetricKind::Counter,
+            stats.freed as f64,
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reuse() {
+        let pool = Arc::new(BufferPool::new(2, 64));
+        let mut first = pool.take(16);
+        first.extend_from_slice(&[1, 2, 3]);
+        let address = first.as_ptr();
+        assert_eq!(pool.stats(), PoolStats { in_use: 1, allocated: 1, ..Default::default() });
+        drop(first);
+        assert_eq!(pool.stats(), PoolStats { free: 1, allocated: 1, ..Default::default() });
+        // The buffer is reused empty, with its allocation.
+        let second = pool.take(8);
+        assert!(second.is_empty());
+        assert_eq!(second.as_ptr(), address);
+        assert_eq!(
+            pool.stats(),
+            PoolStats { in_use: 1, reused: 1, allocated: 1, ..Default::default() }
+        );
+    }
+
+    #[test]
+    fn test_heap_fallback() {
+        let pool = Arc::new(BufferPool::new(1, 64));
+        // The pool is empty: the buffers are allocated on the heap, and
+        // those beyond the pool are freed.
+        let buffers: Vec<_> = (0..3).map(|_| pool.take(8)).collect();
+        assert_eq!(pool.stats().allocated, 3);
+        drop(buffers);
+        let stats = pool.stats();
+        assert_eq!((stats.free, stats.in_use, stats.freed), (1, 0, 2));
+        // A buffer grown beyond the buffer size is freed.
+        let mut large = pool.take(8);
+        large.resize(128, 0);
+        drop(large);
+        let stats = pool.stats();
+        assert_eq!((stats.free, stats.freed), (0, 3));
+        // A buffer of no pool is freed.
+        drop(PooledBuffer::from(vec![1, 2]));
+        assert_eq!(pool.stats().freed, 3);
+    }
+}
@@ -0,0 +1,306 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Diagnostic bug report.
+//!
+//! Bundles the capture files, the scene, the event history, the settings
+//! and the log of netsimd into a single tar archive in the temp directory,
+//! ready to attach to a bug.
+//!
+//! /v1/bugreport --> handle_bugreport (POST)
+
+use frontend_proto::frontend::{CreateBugReportResponse, ListEventsRequest};
+use frontend_proto::model::Scene;
+use netsim_common::util::time_display::TimeDisplay;
+use protobuf::Message;
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::fs::File;
+use std::io::{BufWriter, Read, Result, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+use crate::auth::create_private_file;
+use crate::captures::capture::pcap_dir;
+use crate::captures::handlers::flush_captures;
+use crate::clock::now;
+use crate::config::{get_setting, redacted_settings};
+use crate::events::history;
+use crate::ffi::{get_devices_bytes, CxxServerResponseWriter};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::warn;
+use crate::version::get_version;
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+const BLOCK_LEN: usize = 512;
+/// Longest file name of a ustar header without the prefix field.
+const MAX_NAME_LEN: usize = 99;
+/// Log bytes kept in the archive, from the end of the log.
+const MAX_LOG_LEN: u64 = 16 * 1024 * 1024;
+
+/// A minimal writer of ustar archives of regular files.
+pub struct TarWriter<W: Write> {
+    out: W,
+}
+
+// Writes `value` as a NUL terminated octal number filling `field`.
+fn put_octal(field: &mut [u8], value: u64) {
+    let len = field.len() - 1;
+    let digits = format!("{:0len$o}", value);
+    field[..len].copy_from_slice(digits.as_bytes());
+    field[len] = 0;
+}
+
+impl<W: Write> TarWriter<W> {
+    pub fn new(out: W) -> Self {
+        TarWriter { out }
+    }
+
+    /// Appends a file of `data` modified at `mtime` seconds since the epoch.
+    /// Names longer than the ustar limit are truncated.
+    pub fn append(&mut self, name: &str, data: &[u8], mtime: u64) -> Result<()> {
+        self.append_reader(name, data, data.len() as u64, mtime)
+    }
+
+    /// Appends a file of the `len` first bytes of `reader`, copied without
+    /// buffering the whole file. A reader ending early is padded with zeros.
+    pub fn append_reader(
+        &mut self,
+        name: &str,
+        reader: impl Read,
+        len: u64,
+        mtime: u64,
+    ) -> Result<()> {
+        let mut header = [0u8; BLOCK_LEN];
+        let name = &name.as_bytes()[..name.len().min(MAX_NAME_LEN)];
+        header[..name.len()].copy_from_slice(name);
+        put_octal(&mut header[100..108], 0o644);
+        put_octal(&mut header[108..116], 0);
+        put_octal(&mut header[116..124], 0);
+        put_octal(&mut header[124..136], len);
+        put_octal(&mut header[136..148], mtime);
+        header[156] = b'0';
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+        // The checksum is computed with its own field filled with spaces.
+        header[148..156].fill(b' ');
+        let checksum: u64 = header.iter().map(|&byte| byte as u64).sum();
+        put_octal(&mut header[148..155], checksum);
+        self.out.write_all(&header)?;
+        let copied = std::io::copy(&mut reader.take(len), &mut self.out)?;
+        // The file was truncated since its length was read.
+        std::io::copy(&mut std::io::repeat(0).take(len - copied), &mut self.out)?;
+        let padding = (BLOCK_LEN - (len % BLOCK_LEN as u64) as usize) % BLOCK_LEN;
+        self.out.write_all(&[0u8; BLOCK_LEN][..padding])
+    }
+
+    /// Writes the end of archive marker and returns the output.
+    pub fn finish(mut self) -> Result<W> {
+        self.out.write_all(&[0u8; 2 * BLOCK_LEN])?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+// Returns the file netsimd logs to, when its stderr is redirected to one.
+fn log_file() -> Option<PathBuf> {
+    let path = std::fs::read_link("/proc/self/fd/2").ok()?;
+    std::fs::metadata(&path).ok()?.is_file().then_some(path)
+}
+
+/// The content of a file of the bug report.
+enum Content {
+    Data(Vec<u8>),
+    /// The `len` bytes of an open file from its position, streamed into
+    /// the archive.
+    File(File, u64),
+}
+
+// Opens the last `max_len` bytes of a file, at most.
+fn open_tail(path: &Path, max_len: u64) -> Result<Content> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    let offset = len.saturating_sub(max_len);
+    file.seek(SeekFrom::Start(offset))?;
+    Ok(Content::File(file, len - offset))
+}
+
+// Returns the files of the bug report, by name in the archive.
+fn collect_files() -> Vec<(String, Content)> {
+    let mut files = vec![("version.txt".to_string(), Content::Data(get_version().into_bytes()))];
+    let settings: String =
+        redacted_settings().iter().map(|(key, value)| format!("{key}={value}\n")).collect();
+    files.push(("settings.ini".to_string(), Content::Data(settings.into_bytes())));
+    let mut vec = Vec::<u8>::new();
+    if get_devices_bytes(&mut vec) {
+        match Scene::parse_from_bytes(&vec).map_err(|e| e.to_string()).and_then(|scene| {
+            print_to_string_with_options(&scene, &JSON_PRINT_OPTION).map_err(|e| e.to_string())
+        }) {
+            Ok(json) => files.push(("scene.json".to_string(), Content::Data(json.into_bytes()))),
+            Err(e) => warn!("unable to add the scene to the bug report: {e}"),
+        }
+    }
+    let events = history().list(&ListEventsRequest::new());
+    if let Ok(json) = print_to_string_with_options(&events, &JSON_PRINT_OPTION) {
+        files.push(("events.json".to_string(), Content::Data(json.into_bytes())));
+    }
+    flush_captures();
+    if let Ok(entries) = std::fs::read_dir(pcap_dir()) {
+        for entry in entries.flatten().filter(|entry| entry.path().is_file()) {
+            if let Ok(content) = open_tail(&entry.path(), u64::MAX) {
+                let name = format!("captures/{}", entry.file_name().to_string_lossy());
+                files.push((name, content));
+            }
+        }
+    }
+    if let Some(Ok(content)) = log_file().map(|path| open_tail(&path, MAX_LOG_LEN)) {
+        files.push(("netsimd.log".to_string(), content));
+    }
+    files
+}
+
+// Appends a file of the bug report to the archive.
+fn append_content<W: Write>(
+    tar: &mut TarWriter<W>,
+    name: &str,
+    content: Content,
+    mtime: u64,
+) -> Result<()> {
+    match content {
+        Content::Data(data) => tar.append(name, &data, mtime),
+        Content::File(file, len) => tar.append_reader(name, file, len, mtime),
+    }
+}
+
+/// Writes a bug report archive into the temp directory.
+pub fn create_bugreport() -> Result<CreateBugReportResponse> {
+    let time = now();
+    let time_display = TimeDisplay::new(time.as_secs() as i64, 0).utc_display();
+    let mut path = std::env::temp_dir();
+    match get_setting("instance") {
+        instance if instance.is_empty() => {
+            path.push(format!("netsim-bugreport-{time_display}.tar"))
+        }
+        instance => path.push(format!("netsim-bugreport-{instance}-{time_display}.tar")),
+    }
+    // The archive holds the captures and the log, for its owner only.
+    let mut tar = TarWriter::new(BufWriter::new(create_private_file(&path)?));
+    let mut response = CreateBugReportResponse::new();
+    for (name, content) in collect_files() {
+        append_content(&mut tar, &name, content, time.as_secs())?;
+        response.files.push(name);
+    }
+    tar.finish()?;
+    response.path = path.to_string_lossy().into_owned();
+    Ok(response)
+}
+
+/// The Rust bug report handler used directly by Http frontend for POST
+pub fn handle_bugreport(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "POST" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    match create_bugreport() {
+        Ok(response) => {
+            if let Ok(json_response) = print_to_string_with_options(&response, &JSON_PRINT_OPTION) {
+                writer.put_ok("text/json", &json_response, &[])
+            } else {
+                writer.put_error(404, "proto to JSON mapping failure")
+            }
+        }
+        Err(e) => writer.put_error(500, format!("Failed to create bug report: {e}").as_str()),
+    }
+}
+
+/// bug report handle cxx for grpc server to call
+pub fn handle_bugreport_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/bugreport".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: Vec::new(),
+    };
+    handle_bugreport(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn octal(field: &[u8]) -> u64 {
+        let digits = std::str::from_utf8(field).unwrap().trim_end_matches(['\0', ' ']);
+        u64::from_str_radix(digits, 8).unwrap()
+    }
+
+    #[test]
+    fn test_tar_writer() {
+        let mut tar = TarWriter::new(Vec::new());
+        tar.append("dir/file.txt", b"hello", 1000).unwrap();
+        let archive = tar.finish().unwrap();
+        // A header, a data block and the end of archive marker.
+        assert_eq!(archive.len(), 4 * BLOCK_LEN);
+        let header = &archive[..BLOCK_LEN];
+        assert!(header.starts_with(b"dir/file.txt\0"));
+        assert_eq!(octal(&header[124..136]), 5);
+        assert_eq!(octal(&header[136..148]), 1000);
+        assert_eq!(&header[257..262], b"ustar");
+        let mut unsigned = header.to_vec();
+        unsigned[148..156].fill(b' ');
+        let checksum: u64 = unsigned.iter().map(|&byte| byte as u64).sum();
+        assert_eq!(octal(&header[148..156]), checksum);
+        assert_eq!(&archive[BLOCK_LEN..BLOCK_LEN + 5], b"hello");
+        assert!(archive[BLOCK_LEN + 5..].iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn test_tar_writer_reader() {
+        let mut tar = TarWriter::new(Vec::new());
+        tar.append_reader("file", &b"hello world"[..], 5, 0).unwrap();
+        // A reader ending early is padded to its length.
+        tar.append_reader("short", &b"hi"[..], 4, 0).unwrap();
+        let archive = tar.finish().unwrap();
+        assert_eq!(archive.len(), 6 * BLOCK_LEN);
+        assert_eq!(octal(&archive[124..136]), 5);
+        assert_eq!(&archive[BLOCK_LEN..BLOCK_LEN + 6], b"hello\0");
+        assert_eq!(octal(&archive[2 * BLOCK_LEN + 124..2 * BLOCK_LEN + 136]), 4);
+        assert_eq!(&archive[3 * BLOCK_LEN..3 * BLOCK_LEN + 5], b"hi\0\0\0");
+    }
+
+    #[test]
+    fn test_tar_writer_long_name() {
+        let mut tar = TarWriter::new(Vec::new());
+        tar.append(&"a".repeat(200), &[1; BLOCK_LEN], 0).unwrap();
+        let archive = tar.finish().unwrap();
+        assert_eq!(archive.len(), 4 * BLOCK_LEN);
+        assert_eq!(archive[MAX_NAME_LEN - 1], b'a');
+        assert_eq!(archive[MAX_NAME_LEN], 0);
+    }
+}
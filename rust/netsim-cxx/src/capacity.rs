@@ -0,0 +1,160 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Capacity reporting.
+//!
+//! Reports the usage of the resources of netsimd against their limits, so
+//! fleet schedulers can decide whether to place another emulator on this
+//! instance. The devices, chips, captures and disk are limited by the
+//! `capacity.*` settings, which netsimd only reports; the memory and the
+//! event history are limited by the `memory.*` settings.
+//!
+//! /v1/capacity --> handle_capacity
+
+use frontend_proto::frontend::capacity::Resource;
+use frontend_proto::frontend::{Capacity, GetCapacityResponse, GetDevicesResponse};
+use protobuf::Message;
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::path::Path;
+use std::pin::Pin;
+
+use crate::captures::capture::pcap_dir;
+use crate::captures::handlers::recording_captures;
+use crate::config::get_setting_parsed;
+use crate::ffi::{get_devices_bytes, CxxServerResponseWriter};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::memory::{accountant, Pool};
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+fn capacity(resource: Resource, used: u64, limit: u64) -> Capacity {
+    Capacity { resource: resource.into(), used, limit, ..Default::default() }
+}
+
+// Returns the bytes of the files in a directory.
+fn disk_usage(dir: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries.flatten().filter_map(|entry| entry.metadata().ok()).map(|m| m.len()).sum()
+}
+
+// Whether every resource but the event history is below its limit.
+fn is_available(resources: &[Capacity]) -> bool {
+    resources
+        .iter()
+        .filter(|c| c.resource.enum_value_or_default() != Resource::EVENTS)
+        .all(|c| c.limit == 0 || c.used < c.limit)
+}
+
+/// Returns the usage of the resources against their limits.
+pub fn get_capacity() -> GetCapacityResponse {
+    let mut vec = Vec::<u8>::new();
+    let devices = match get_devices_bytes(&mut vec) {
+        true => GetDevicesResponse::parse_from_bytes(&vec).map(|r| r.devices).unwrap_or_default(),
+        false => Vec::new(),
+    };
+    let chips = devices.iter().map(|device| device.chips.len()).sum::<usize>();
+    let accountant = accountant();
+    let events = accountant.usage(Pool::Events);
+    let resources = vec![
+        capacity(
+            Resource::DEVICES,
+            devices.len() as u64,
+            get_setting_parsed("capacity.max_devices"),
+        ),
+        capacity(Resource::CHIPS, chips as u64, get_setting_parsed("capacity.max_chips")),
+        capacity(
+            Resource::CAPTURES,
+            recording_captures() as u64,
+            get_setting_parsed("capacity.max_captures"),
+        ),
+        capacity(
+            Resource::DISK,
+            disk_usage(&pcap_dir()),
+            get_setting_parsed("capacity.max_disk_bytes"),
+        ),
+        capacity(Resource::MEMORY, accountant.used_bytes() as u64, accountant.limit_bytes() as u64),
+        capacity(Resource::EVENTS, events.used_bytes as u64, events.limit_bytes as u64),
+    ];
+    GetCapacityResponse { available: is_available(&resources), resources, ..Default::default() }
+}
+
+/// The Rust capacity handler used directly by Http frontend for GET
+pub fn handle_capacity(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "GET" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    if let Ok(json_response) = print_to_string_with_options(&get_capacity(), &JSON_PRINT_OPTION) {
+        writer.put_ok("text/json", &json_response, &[])
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure")
+    }
+}
+
+/// capacity handle cxx for grpc server to call
+pub fn handle_capacity_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/capacity".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: Vec::new(),
+    };
+    handle_capacity(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_available() {
+        let mut resources = vec![
+            capacity(Resource::DEVICES, 3, 4),
+            capacity(Resource::CHIPS, 100, 0),
+            capacity(Resource::EVENTS, 16, 16),
+        ];
+        assert!(is_available(&resources));
+        resources.push(capacity(Resource::MEMORY, 8, 8));
+        assert!(!is_available(&resources));
+    }
+
+    #[test]
+    fn test_disk_usage() {
+        let dir = std::env::temp_dir().join(format!("netsim-capacity-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.pcap"), [0; 10]).unwrap();
+        std::fs::write(dir.join("b.pcap"), [0; 32]).unwrap();
+        assert_eq!(disk_usage(&dir), 42);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(disk_usage(&dir), 0);
+    }
+}
@@ -20,36 +20,723 @@
 
 use std::collections::btree_map::{Iter, Values};
 use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
 use std::fs::{File, OpenOptions};
-use std::io::Result;
-use std::sync::{Arc, Mutex};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::io::{Error, Read, Result, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
 
 use frontend_proto::{
     common::ChipKind,
-    model::{Capture as ProtoCapture, State},
+    frontend::{event::Type, patch_capture_request::PatchCapture, CaptureStats, Event},
+    model::{
+        capture::{Filter, Format, Mode, QuotaStatus, Sampling, Segment as ProtoSegment},
+        Capture as ProtoCapture, State,
+    },
 };
+use lazy_static::lazy_static;
+use netsim_common::util::gzip::GzipWriter;
+use netsim_common::util::sha256::Sha256;
+use netsim_common::util::time_display::TimeDisplay;
 use protobuf::well_known_types::timestamp::Timestamp;
+use protobuf::EnumOrUnknown;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::mpsc::{channel, Receiver, Sender};
 
-use crate::ffi::get_facade_id;
+use crate::buffer_pool::buffer_pool;
+use crate::chip_kinds::{chip_kind_name, linktype};
+use crate::chip_registry::facade_id;
+use crate::clock::{now, to_timestamp};
+use crate::config::{get_setting, get_setting_parsed};
+use crate::events::history;
+use crate::memory::{accountant, Accountant, BoundedBuffer, Pool};
 
-use super::pcap_util::write_pcap_header;
+use super::error::CaptureError;
+use super::filter::validate;
+use super::handlers::{int_to_chip_kind, link_data};
+use super::live::LivePipe;
+use super::pcap_util::{
+    write_btsnoop_header, write_btsnoop_record, write_pcap_header, write_pcapng_header,
+    write_pcapng_interface, write_pcapng_packet, write_pcapng_section, write_pcapng_statistics,
+    write_record_parts, PacketDirection, LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR,
+};
+use super::writer::{CaptureWriter, Record};
+use super::{BTSNOOP_MIME_TYPE, PCAPNG_MIME_TYPE, PCAP_MIME_TYPE};
+use crate::logger::{info, warn};
 
 pub type ChipId = i32;
 pub type FacadeId = i32;
 
+/// Records queued for a subscriber of a capture; the records beyond it are
+/// dropped until the subscriber catches up.
+const SUBSCRIBER_QUEUE_LEN: usize = 1024;
+/// Characters replaced in capture file names: path separators and the
+/// characters invalid in file names on Windows.
+const INVALID_FILE_NAME_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+/// File of the aggregate capture in the directory of the captures.
+const AGGREGATE_FILE_NAME: &str = "netsim-aggregate.pcapng";
+/// Seconds of the rolling window of the throughput of a capture.
+const THROUGHPUT_WINDOW_SECS: u64 = 10;
+/// Bytes a record adds to its data at most: the framing of an enhanced
+/// packet block of pcapng with its padding and flags.
+const RECORD_OVERHEAD: usize = 48;
+
+lazy_static! {
+    // The directory set by Captures::set_dir, overriding the capture.dir
+    // setting.
+    static ref CAPTURE_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+    // The capture.dir setting and the directory it resolved to, once
+    // checked writable.
+    static ref SETTING_DIR: Mutex<Option<(String, PathBuf)>> = Mutex::new(None);
+}
+
+// Returns the default directory of the pcap files of this netsimd instance.
+fn default_pcap_dir() -> PathBuf {
+    let mut path = std::env::temp_dir();
+    match get_setting("instance") {
+        instance if instance.is_empty() => path.push("netsim-pcaps"),
+        instance => path.push(format!("netsim-pcaps-{instance}")),
+    }
+    path
+}
+
+/// Returns the directory holding the pcap files of this netsimd instance:
+/// the directory set by `Captures::set_dir`, or the capture.dir setting,
+/// or netsim-pcaps in the temp directory.
+pub fn pcap_dir() -> PathBuf {
+    if let Some(dir) = CAPTURE_DIR.read().unwrap().as_ref() {
+        return dir.clone();
+    }
+    let setting = get_setting("capture.dir");
+    if setting.is_empty() {
+        return default_pcap_dir();
+    }
+    // The directory of a new setting is checked once, a directory that
+    // isn't writable falling back to the temp directory.
+    let mut checked = SETTING_DIR.lock().unwrap();
+    match checked.as_ref() {
+        Some((value, dir)) if *value == setting => dir.clone(),
+        _ => {
+            let dir = match check_writable_dir(Path::new(&setting)) {
+                Ok(()) => PathBuf::from(&setting),
+                Err(e) => {
+                    warn!("{e}, using the temp directory");
+                    default_pcap_dir()
+                }
+            };
+            *checked = Some((setting, dir.clone()));
+            dir
+        }
+    }
+}
+
+/// Returns true when the pcap files are in the default temp directory,
+/// which netsimd owns and clears on startup.
+pub fn is_default_pcap_dir() -> bool {
+    pcap_dir() == default_pcap_dir()
+}
+
+/// Creates a directory if needed and checks that files can be written in it.
+pub fn check_writable_dir(dir: &Path) -> std::result::Result<(), CaptureError> {
+    let probe = dir.join(format!(".netsim-probe-{}", std::process::id()));
+    std::fs::create_dir_all(dir)
+        .and_then(|_| File::create(&probe))
+        .and_then(|_| std::fs::remove_file(&probe))
+        .map_err(|e| {
+            CaptureError::DirectoryNotWritable(format!(
+                "capture directory {} is not writable: {e}",
+                dir.display()
+            ))
+        })
+}
+
+/// Sends the records written to a capture to its subscribers. The
+/// receivers are tokio channels: the http streams await the records as
+/// tasks of the runtime, the gRPC streams block on them.
+#[derive(Default)]
+pub struct Broadcast {
+    senders: Vec<Sender<Record>>,
+}
+
+impl Broadcast {
+    /// Returns the receiver of the records sent from now on.
+    pub fn subscribe(&mut self) -> Receiver<Record> {
+        let (sender, receiver) = channel(SUBSCRIBER_QUEUE_LEN);
+        self.senders.push(sender);
+        receiver
+    }
+
+    /// Sends a record to the subscribers, forgetting those that are gone.
+    pub fn send(&mut self, record: &Record) {
+        self.senders.retain(|sender| match sender.try_send(record.clone()) {
+            Ok(()) | Err(TrySendError::Full(_)) => true,
+            Err(TrySendError::Closed(_)) => false,
+        });
+    }
+
+    /// Returns true when the capture has no subscriber.
+    pub fn is_empty(&self) -> bool {
+        self.senders.is_empty()
+    }
+
+    /// Ends the streams of the subscribers.
+    pub fn close(&mut self) {
+        self.senders.clear();
+    }
+}
+
+/// The latest records of a capture recording in memory, charged to the
+/// captures memory pool.
+pub struct Ring {
+    // Records of at most the capacity in bytes.
+    records: BoundedBuffer<Record>,
+    capacity: usize,
+}
+
+impl Ring {
+    pub fn new(capacity: usize) -> Self {
+        Ring::with_accountant(capacity, accountant())
+    }
+
+    pub fn with_accountant(capacity: usize, accountant: Arc<Accountant>) -> Self {
+        Ring { records: BoundedBuffer::with_accountant(Pool::Captures, accountant), capacity }
+    }
+
+    /// Adds a record, discarding the oldest records beyond the capacity.
+    /// The pool out of budget drops records per its drop policy.
+    pub fn push(&mut self, record: Record) {
+        let size = record.len();
+        while !self.records.is_empty() && self.records.bytes() + size > self.capacity {
+            self.records.pop_front();
+        }
+        if size <= self.capacity {
+            self.records.push(record, size);
+        }
+    }
+}
+
+/// A subscription to the records of a capture.
+pub struct Subscription {
+    pub mime_type: &'static str,
+    // Header of the capture format, sent before the records.
+    pub header: Vec<u8>,
+    pub records: Receiver<Record>,
+}
+
+/// Selects the packets recorded by a sampled capture.
+#[derive(Default)]
+pub struct Sampler {
+    sampling: Sampling,
+    // Packets seen since the start of the capture.
+    packets: u64,
+    window_start: Duration,
+    window_records: u32,
+}
+
+impl Sampler {
+    pub fn new(sampling: Sampling) -> Self {
+        Sampler { sampling, ..Default::default() }
+    }
+
+    /// Counts a packet seen at `time` and returns whether it is recorded.
+    pub fn sample(&mut self, time: Duration) -> bool {
+        self.packets += 1;
+        let one_in = self.sampling.one_in.max(1) as u64;
+        if !(self.packets - 1).is_multiple_of(one_in) {
+            return false;
+        }
+        let max_rate = self.sampling.max_rate;
+        if max_rate > 0 {
+            if time.saturating_sub(self.window_start) >= Duration::from_secs(1) {
+                self.window_start = time;
+                self.window_records = 0;
+            }
+            if self.window_records >= max_rate {
+                return false;
+            }
+            self.window_records += 1;
+        }
+        true
+    }
+}
+
+/// The rolling throughput of the records of a capture, over the last
+/// THROUGHPUT_WINDOW_SECS seconds in buckets of a second.
+#[derive(Default)]
+pub struct Throughput {
+    // The second, bytes and records of each bucket, indexed by the second
+    // modulo the window.
+    buckets: [(u64, u64, u64); THROUGHPUT_WINDOW_SECS as usize],
+    // Time of the latest record.
+    pub last_record: Option<Duration>,
+}
+
+impl Throughput {
+    /// Counts a record of `bytes` at `time`.
+    pub fn record(&mut self, time: Duration, bytes: usize) {
+        let second = time.as_secs();
+        let bucket = &mut self.buckets[(second % THROUGHPUT_WINDOW_SECS) as usize];
+        if bucket.0 != second {
+            *bucket = (second, 0, 0);
+        }
+        bucket.1 += bytes as u64;
+        bucket.2 += 1;
+        self.last_record = Some(time);
+    }
+
+    /// Returns the bytes and records per second of the window ending at
+    /// `time`.
+    pub fn rates(&self, time: Duration) -> (f64, f64) {
+        let second = time.as_secs();
+        let (bytes, records) = self
+            .buckets
+            .iter()
+            .filter(|(start, _, _)| *start <= second && second - start < THROUGHPUT_WINDOW_SECS)
+            .fold((0, 0), |(bytes, records), (_, b, r)| (bytes + b, records + r));
+        let window = THROUGHPUT_WINDOW_SECS as f64;
+        (bytes as f64 / window, records as f64 / window)
+    }
+}
+
+/// The size and record quotas of each capture; 0 is unlimited.
+pub struct Quota {
+    pub max_size: usize,
+    pub max_records: i32,
+    /// Percentage of a quota crossing the watermark.
+    pub watermark: f64,
+}
+
+impl Quota {
+    pub fn from_settings() -> Self {
+        Quota {
+            max_size: get_setting_parsed("capture.max_size"),
+            max_records: get_setting_parsed("capture.max_records"),
+            watermark: get_setting_parsed("capture.watermark"),
+        }
+    }
+
+    /// Returns the status of a capture of `size` bytes and `records`.
+    pub fn status(&self, size: usize, records: i32) -> QuotaStatus {
+        let usage = |value: f64, quota: f64| if quota > 0.0 { 100.0 * value / quota } else { 0.0 };
+        let usage = usage(size as f64, self.max_size as f64)
+            .max(usage(records as f64, self.max_records as f64));
+        if usage >= 100.0 {
+            QuotaStatus::FULL
+        } else if usage >= self.watermark {
+            QuotaStatus::ABOVE_WATERMARK
+        } else {
+            QuotaStatus::BELOW_WATERMARK
+        }
+    }
+}
+
+/// What happens when the capture directory exceeds its quota.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DirQuotaPolicy {
+    /// Stops the capture writing past the quota.
+    Stop,
+    /// Deletes the files of the oldest stopped captures, and stops the
+    /// capture writing past the quota when they aren't enough.
+    Evict,
+}
+
+impl DirQuotaPolicy {
+    pub fn parse(value: &str) -> Option<DirQuotaPolicy> {
+        match value {
+            "stop" => Some(DirQuotaPolicy::Stop),
+            "evict" => Some(DirQuotaPolicy::Evict),
+            _ => None,
+        }
+    }
+}
+
+/// The quota of the files in the capture directory; 0 is unlimited.
+pub struct DirQuota {
+    pub max_size: u64,
+    pub policy: DirQuotaPolicy,
+}
+
+impl DirQuota {
+    pub fn from_settings() -> Self {
+        DirQuota {
+            max_size: get_setting_parsed("capture.dir_max_size"),
+            policy: DirQuotaPolicy::parse(&get_setting("capture.dir_quota_policy"))
+                .unwrap_or(DirQuotaPolicy::Stop),
+        }
+    }
+}
+
+/// Returns the size of the files in `dir`, 0 without the directory.
+pub fn dir_size(dir: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .filter_map(|entry| entry.ok()?.metadata().ok())
+        .filter(|metadata| metadata.is_file())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// What happens to the captures of removed chips.
+pub struct Retention {
+    pub keep_removed: bool,
+    /// Time a removed capture is kept; zero keeps it until deleted.
+    pub expiry: Duration,
+}
+
+impl Retention {
+    pub fn from_settings() -> Self {
+        Retention {
+            keep_removed: get_setting_parsed("capture.keep_removed"),
+            expiry: Duration::from_secs(get_setting_parsed("capture.expire_secs")),
+        }
+    }
+
+    /// Whether a capture removed at `removed_at` expired at `time`.
+    pub fn is_expired(&self, removed_at: Duration, time: Duration) -> bool {
+        !self.expiry.is_zero() && time.saturating_sub(removed_at) >= self.expiry
+    }
+}
+
+/// The rotation of the capture files and the retention of the segments.
+pub struct Rotation {
+    /// Size of a file closing it for a new one; 0 never rotates.
+    pub max_size: usize,
+    /// Age of a file closing it for a new one; zero never rotates.
+    pub interval: Duration,
+    /// Age of a segment deleting it; zero keeps the segments.
+    pub max_age: Duration,
+}
+
+impl Rotation {
+    pub fn from_settings() -> Self {
+        let minutes = |key| Duration::from_secs(60 * get_setting_parsed::<u64>(key));
+        Rotation {
+            max_size: get_setting_parsed("capture.rotate_size"),
+            interval: minutes("capture.rotate_interval_mins"),
+            max_age: minutes("capture.segment_retention_mins"),
+        }
+    }
+
+    /// Whether a file of `size` bytes opened `age` ago is rotated.
+    pub fn is_due(&self, size: usize, age: Duration) -> bool {
+        (self.max_size > 0 && size >= self.max_size)
+            || (!self.interval.is_zero() && age >= self.interval)
+    }
+
+    /// Whether a segment closed at `end` expired at `time`.
+    pub fn is_expired(&self, end: Duration, time: Duration) -> bool {
+        !self.max_age.is_zero() && time.saturating_sub(end) >= self.max_age
+    }
+}
+
+/// A file of a capture closed by rotation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Segment {
+    /// Sequence number of the segment, from 1.
+    pub index: u32,
+    pub path: PathBuf,
+    pub size: usize,
+    pub records: i32,
+    /// Times of the first and last packets the segment could hold.
+    pub start: Duration,
+    pub end: Duration,
+    pub sha256: String,
+}
+
+impl Segment {
+    fn to_proto(&self) -> ProtoSegment {
+        ProtoSegment {
+            index: self.index,
+            size: self.size as i32,
+            records: self.records,
+            timestamp: Some(to_timestamp(self.start)).into(),
+            sha256: self.sha256.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Writes to the file of a capture, hashing the written bytes.
+pub struct HashingWriter<'a> {
+    pub file: &'a mut File,
+    pub sha256: &'a mut Sha256,
+}
+
+impl Write for HashingWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let len = self.file.write(buf)?;
+        self.sha256.update(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Replaces the {placeholders} of a template with their values, keeping
+/// the unknown placeholders as they are. The values aren't expanded again.
+pub fn render_template(template: &str, value: impl Fn(&str) -> Option<String>) -> String {
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        rendered.push_str(&rest[..open]);
+        let placeholder = &rest[open..];
+        match placeholder.find('}').and_then(|close| Some((close, value(&placeholder[1..close])?)))
+        {
+            Some((close, value)) => {
+                rendered.push_str(&value);
+                rest = &placeholder[close + 1..];
+            }
+            None => {
+                rendered.push('{');
+                rest = &placeholder[1..];
+            }
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+/// Returns a file name valid on the host filesystem, with the path
+/// separators, the invalid characters and the control characters replaced
+/// by '_', and without the trailing dots and spaces Windows drops.
+pub fn sanitize_file_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_control() || INVALID_FILE_NAME_CHARS.contains(&c) { '_' } else { c })
+        .collect();
+    let name = name.trim_end_matches(['.', ' ']);
+    match name {
+        "" => "_".to_string(),
+        name => name.to_string(),
+    }
+}
+
+/// A capture of the packets of every chip in a single pcapng file, with an
+/// interface per chip, so the radios are analyzed on a single timeline.
+pub struct Aggregate {
+    file: File,
+    pub path: PathBuf,
+    // Interface id of each chip, in the order of their first packets.
+    interfaces: HashMap<ChipId, u32>,
+    pub size: usize,
+    pub records: i32,
+}
+
+impl Aggregate {
+    /// Creates the file of an aggregate capture in the directory of the
+    /// captures, replacing the file of the previous one.
+    pub fn start() -> Result<Self> {
+        let dir = pcap_dir();
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(AGGREGATE_FILE_NAME);
+        let mut file = File::create(&path)?;
+        let size = write_pcapng_section(&mut file)?;
+        Ok(Aggregate { file, path, interfaces: HashMap::new(), size, records: 0 })
+    }
+
+    /// Records the data of a packet of the chip of a capture, in the link
+    /// type of the chip, after the description of the chip on its first
+    /// packet.
+    pub fn record(
+        &mut self,
+        capture: &CaptureInfo,
+        timestamp: Duration,
+        direction: PacketDirection,
+        parts: &[&[u8]],
+    ) -> Result<()> {
+        let interface = match self.interfaces.get(&capture.id) {
+            Some(&interface) => interface,
+            None => {
+                let interface = self.interfaces.len() as u32;
+                self.size += write_pcapng_interface(
+                    &mut self.file,
+                    capture.linktype(),
+                    &capture.if_name(),
+                    0,
+                )?;
+                self.interfaces.insert(capture.id, interface);
+                interface
+            }
+        };
+        let mut record = Vec::new();
+        write_pcapng_packet(timestamp, &mut record, interface, direction, parts, 0)?;
+        self.file.write_all(&record)?;
+        self.size += record.len();
+        self.records += 1;
+        Ok(())
+    }
+
+    /// Syncs the file of the aggregate capture to the disk.
+    pub fn sync(&self) -> Result<()> {
+        self.file.sync_all()
+    }
+}
+
+/// A reader of a range of bytes of a capture file, read in chunks, so a
+/// download resumes or tails the file without reading it from byte zero.
+pub struct ChunkReader {
+    file: File,
+    /// Length of the range, once clamped to the file.
+    pub length: u64,
+    remaining: u64,
+}
+
+impl ChunkReader {
+    /// Opens the `length` bytes from `offset` of a file of `size` bytes. A
+    /// length of 0, or past the end of the file, reads to the end.
+    pub fn open(path: &Path, size: u64, offset: u64, length: u64) -> Result<Self> {
+        if offset > size {
+            return Err(Error::other(format!(
+                "offset {offset} past the size {size} of the capture"
+            )));
+        }
+        let mut file = File::open(path)?;
+        file.seek(SeekFrom::Start(offset))?;
+        let available = size - offset;
+        let length = if length == 0 { available } else { length.min(available) };
+        Ok(ChunkReader { file, length, remaining: length })
+    }
+
+    /// Returns the file, positioned at the rest of the range, and the
+    /// length of the rest.
+    pub fn into_parts(self) -> (File, u64) {
+        (self.file, self.remaining)
+    }
+
+    /// Reads the next chunk of the range into the buffer, returning its
+    /// length, 0 at the end of the range.
+    pub fn read_chunk(&mut self, buffer: &mut [u8]) -> Result<usize> {
+        let len = self.remaining.min(buffer.len() as u64) as usize;
+        if len == 0 {
+            return Ok(0);
+        }
+        let read = self.file.read(&mut buffer[..len])?;
+        if read == 0 {
+            return Err(Error::other("capture file shorter than its size"));
+        }
+        self.remaining -= read as u64;
+        Ok(read)
+    }
+}
+
+/// The compression of the file of a stopped capture, which runs without
+/// the lock of the capture.
+pub struct Compression {
+    pub id: ChipId,
+    path: PathBuf,
+    file_start: Duration,
+}
+
+impl Compression {
+    // The compressed file, until it replaces the file of the capture.
+    fn temp_path(&self) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".gz.tmp");
+        PathBuf::from(path)
+    }
+
+    /// Compresses the file to a temporary file. Returns the size and the
+    /// digest of the compressed file.
+    pub fn run(&self) -> Result<(usize, Sha256)> {
+        let mut input = File::open(&self.path)?;
+        let mut file = File::create(self.temp_path())?;
+        let mut sha256 = Sha256::new();
+        let mut output = GzipWriter::new(HashingWriter { file: &mut file, sha256: &mut sha256 })?;
+        std::io::copy(&mut input, &mut output)?;
+        output.finish()?;
+        Ok((file.metadata()?.len() as usize, sha256))
+    }
+
+    /// Removes the temporary file of a compression that isn't used.
+    pub fn discard(&self) {
+        let _ = std::fs::remove_file(self.temp_path());
+    }
+}
+
 pub struct CaptureInfo {
     facade_id: FacadeId,
-    pub file: Option<File>,
+    // Writer of the file of a running capture, on a background thread.
+    file: Option<CaptureWriter>,
     // Following items will be returned as ProtoCapture. (state: file.is_some())
     id: ChipId,
     pub chip_kind: ChipKind,
+    // Value of the chip kind, beyond ChipKind for custom chip kinds.
+    pub kind: u32,
     pub device_name: String,
     pub size: usize,
     pub records: i32,
     pub seconds: i64,
     pub nanos: i32,
     pub valid: bool,
+    pub sampler: Sampler,
+    // Filter of the recorded packets.
+    pub filter: Filter,
+    pub quota_status: QuotaStatus,
+    // Time the chip of the capture was removed.
+    pub removed_at: Option<Duration>,
+    // Rolling digest of the bytes written to the file.
+    pub sha256: Sha256,
+    // File format of the latest capture.
+    pub format: Format,
+    // Files closed by rotation, oldest first, and the start of the file.
+    pub segments: Vec<Segment>,
+    file_start: Duration,
+    // Directory of the files, from the start of the latest capture.
+    dir: PathBuf,
+    // Subscribers streaming the records of the capture.
+    pub broadcast: Broadcast,
+    // Recording of the latest capture, and the ring buffer of a running
+    // capture in the RING mode.
+    pub mode: Mode,
+    pub ring: Option<Ring>,
+    // The file of the stopped capture is replaced by its gzip file.
+    pub compressed: bool,
+    // Snapshot length of the records, 0 records the whole packets.
+    pub snaplen: u32,
+    // Name of the capture file without its extension, from the
+    // capture.filename template as the capture starts.
+    file_stem: String,
+    // Throughput of the records of the latest capture.
+    pub throughput: Throughput,
+    // Reason the capture stopped itself, until it starts again.
+    pub error: String,
+    // Named pipe of the latest capture, empty without one, and the pipe
+    // while the capture runs.
+    pub live_path: String,
+    live: Option<Arc<LivePipe>>,
+    // Packets to or from the chip dropped by the link impairments.
+    pub dropped: u64,
+}
+
+/// The fields of a PatchCapture named by the paths of its update mask.
+const PATCH_CAPTURE_FIELDS: [&str; 9] =
+    ["state", "sampling", "format", "filter", "mode", "dump", "snaplen", "live_path", "append"];
+
+/// Returns the fields of the paths of the update mask of a PatchCapture,
+/// in snake_case for the lowerCamelCase paths of the JSON mapping, or the
+/// path naming no field.
+pub fn update_mask_fields(paths: &[String]) -> std::result::Result<Vec<String>, String> {
+    paths
+        .iter()
+        .map(|path| {
+            let mut field = String::new();
+            for c in path.trim().chars() {
+                if c.is_ascii_uppercase() {
+                    field.push('_');
+                }
+                field.push(c.to_ascii_lowercase());
+            }
+            match PATCH_CAPTURE_FIELDS.contains(&field.as_str()) {
+                true => Ok(field),
+                false => Err(format!("Invalid update_mask path: {path}")),
+            }
+        })
+        .collect()
 }
 
 // Captures contains a recent copy of all chips and their ChipKind, chip_id,
@@ -62,14 +749,20 @@ pub struct Captures {
     // BTreeMap is used for chip_id_to_capture, so that the CaptureInfo can always be
     // ordered by ChipId. ListCaptureResponse will produce a ordered list of CaptureInfos.
     pub chip_id_to_capture: BTreeMap<ChipId, Arc<Mutex<CaptureInfo>>>,
+    // Format of the captures started as their chips are inserted, None
+    // leaves the captures of new chips stopped.
+    pub capture_all: Option<Format>,
+    // The aggregate capture of the packets of every chip, while it runs.
+    pub aggregate: Mutex<Option<Aggregate>>,
 }
 
 impl CaptureInfo {
-    pub fn new(chip_kind: ChipKind, chip_id: ChipId, device_name: String) -> Self {
+    pub fn new(kind: u32, chip_id: ChipId, device_name: String) -> Self {
         CaptureInfo {
-            facade_id: get_facade_id(chip_id),
+            facade_id: facade_id(chip_id).unwrap_or(-1),
             id: chip_id,
-            chip_kind,
+            chip_kind: int_to_chip_kind(kind),
+            kind,
             device_name,
             size: 0,
             records: 0,
@@ -77,36 +770,616 @@ impl CaptureInfo {
             nanos: 0,
             valid: true,
             file: None,
+            sampler: Sampler::default(),
+            filter: Filter::default(),
+            quota_status: QuotaStatus::BELOW_WATERMARK,
+            removed_at: None,
+            sha256: Sha256::new(),
+            format: Format::PCAP,
+            segments: Vec::new(),
+            file_start: Duration::ZERO,
+            dir: pcap_dir(),
+            broadcast: Broadcast::default(),
+            mode: Mode::FILE,
+            ring: None,
+            compressed: false,
+            snaplen: 0,
+            file_stem: String::new(),
+            throughput: Throughput::default(),
+            error: String::new(),
+            live_path: String::new(),
+            live: None,
+            dropped: 0,
         }
     }
 
-    // Creates a pcap file with headers and store it under temp directory
+    fn kind_name(&self) -> String {
+        chip_kind_name(self.kind).unwrap_or_else(|| format!("{:?}", self.chip_kind))
+    }
+
+    // The link type of the chip kind, so the records are dissected without
+    // a "Decode As".
+    fn linktype(&self) -> u32 {
+        linktype(self.kind).unwrap_or(LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR)
+    }
+
+    // The interface of a pcapng file is named after the chip.
+    fn if_name(&self) -> String {
+        format!("{}-{}", self.device_name, self.kind_name())
+    }
+
+    // Returns the name of the capture file without its extension, from the
+    // capture.filename template.
+    fn render_file_stem(&self, start: Duration) -> String {
+        let timestamp = TimeDisplay::new(start.as_secs() as i64, start.subsec_nanos());
+        let stem =
+            render_template(&get_setting("capture.filename"), |placeholder| match placeholder {
+                "chip_id" => Some(self.id.to_string()),
+                "device_name" => Some(self.device_name.clone()),
+                "chip_kind" => Some(self.kind_name()),
+                "timestamp" => Some(timestamp.utc_display()),
+                "session" => Some(std::process::id().to_string()),
+                _ => None,
+            });
+        sanitize_file_name(&stem)
+    }
+
+    // Format: /tmp/netsim-pcaps/{capture.filename}.{pcap,pcapng}, by default
+    // /tmp/netsim-pcaps/{chip_id}-{device_name}-{chip_kind}.{pcap,pcapng}
+    pub fn file_path(&self) -> PathBuf {
+        let mut filename = self.dir.clone();
+        let stem = match self.file_stem.as_str() {
+            "" => sanitize_file_name(&format!(
+                "{:?}-{}-{}",
+                self.id,
+                self.device_name,
+                self.kind_name()
+            )),
+            stem => stem.to_string(),
+        };
+        filename.push(format!("{stem}.{}", self.extension()));
+        filename
+    }
+
+    /// Returns the path of the file downloaded, the gzip file of a
+    /// compressed capture.
+    pub fn download_path(&self) -> PathBuf {
+        match self.compressed {
+            true => self.gzip_path(),
+            false => self.file_path(),
+        }
+    }
+
+    // Format: /tmp/netsim-pcaps/{chip_id}-{device_name}-{chip_kind}.{pcap,pcapng}.gz
+    fn gzip_path(&self) -> PathBuf {
+        self.file_path().with_extension(format!("{}.gz", self.extension()))
+    }
+
+    // Format: /tmp/netsim-pcaps/{chip_id}-{device_name}-{chip_kind}.{index}.{pcap,pcapng}
+    fn segment_path(&self, index: u32) -> PathBuf {
+        let mut path = self.file_path();
+        path.set_extension(format!("{index}.{}", self.extension()));
+        path
+    }
+
+    /// Returns the size of the capture with its segments.
+    pub fn total_size(&self) -> usize {
+        self.size + self.segments.iter().map(|segment| segment.size).sum::<usize>()
+    }
+
+    /// Returns the records of the capture with its segments.
+    pub fn total_records(&self) -> i32 {
+        self.records + self.segments.iter().map(|segment| segment.records).sum::<i32>()
+    }
+
+    /// Returns the time since the current file was opened.
+    pub fn file_age(&self, time: Duration) -> Duration {
+        time.saturating_sub(self.file_start)
+    }
+
+    /// Removes the segments expired at `time` with their files.
+    pub fn prune_segments(&mut self, rotation: &Rotation, time: Duration) {
+        let (expired, kept) =
+            self.segments.drain(..).partition(|segment| rotation.is_expired(segment.end, time));
+        self.segments = kept;
+        for segment in expired {
+            if let Err(err) = std::fs::remove_file(&segment.path) {
+                info!("failed to delete {}: {err}", segment.path.display());
+            }
+        }
+    }
+
+    /// Removes the files of the segments.
+    pub fn delete_segments(&mut self) {
+        for segment in self.segments.drain(..) {
+            if let Err(err) = std::fs::remove_file(&segment.path) {
+                info!("failed to delete {}: {err}", segment.path.display());
+            }
+        }
+    }
+
+    /// Returns the file extension of the capture format.
+    pub fn extension(&self) -> &'static str {
+        match self.format {
+            Format::PCAP => "pcap",
+            Format::PCAPNG => "pcapng",
+            Format::BTSNOOP => "btsnoop",
+        }
+    }
+
+    /// Returns the MIME type of the capture format.
+    pub fn mime_type(&self) -> &'static str {
+        match self.format {
+            Format::PCAP => PCAP_MIME_TYPE,
+            Format::PCAPNG => PCAPNG_MIME_TYPE,
+            Format::BTSNOOP => BTSNOOP_MIME_TYPE,
+        }
+    }
+
+    /// Returns true while the capture records packets, in its file or in
+    /// its ring buffer.
+    pub fn is_recording(&self) -> bool {
+        self.file.is_some() || self.ring.is_some()
+    }
+
+    // Creates a capture file in `format` with headers and store it under
+    // pcap_dir(), or a ring buffer in the RING mode. A running capture
+    // keeps its format, mode and directory.
     // The lifecycle of the file is NOT tied to the lifecycle of the struct
-    // Format: /tmp/netsim-pcaps/{chip_id}-{device_name}-{chip_kind}.pcap
-    pub fn start_capture(&mut self) -> Result<()> {
-        if self.file.is_some() {
+    pub fn start_capture(
+        &mut self,
+        format: Format,
+        mode: Mode,
+    ) -> std::result::Result<(), CaptureError> {
+        if self.is_recording() {
             return Ok(());
         }
-        let mut filename = std::env::temp_dir();
-        filename.push("netsim-pcaps");
-        std::fs::create_dir_all(&filename)?;
-        filename.push(format!("{:?}-{:}-{:?}.pcap", self.id, self.device_name, self.chip_kind));
-        let mut file = OpenOptions::new().write(true).truncate(true).create(true).open(filename)?;
-        let size = write_pcap_header(&mut file)?;
-        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards");
-        self.size = size;
-        self.records = 0;
+        if format == Format::BTSNOOP && self.chip_kind != ChipKind::BLUETOOTH {
+            return Err(CaptureError::InvalidArgument(
+                "btsnoop captures are only for Bluetooth chips".to_string(),
+            ));
+        }
+        let dir = pcap_dir();
+        std::fs::create_dir_all(&dir)?;
+        // The files of the previous capture are replaced by the new ones.
+        self.delete_segments();
+        if self.compressed {
+            let _ = std::fs::remove_file(self.download_path());
+            self.compressed = false;
+        }
+        // The file is named as the capture starts, e.g. after its timestamp.
+        let file_stem = self.render_file_stem(now());
+        if format != self.format || dir != self.dir || file_stem != self.file_stem {
+            let _ = std::fs::remove_file(self.file_path());
+            self.file_stem = file_stem;
+            // The streams are in the format of their header.
+            if format != self.format {
+                self.broadcast.close();
+            }
+            self.format = format;
+            self.dir = dir;
+        }
+        self.mode = mode;
+        self.open_live()?;
+        match mode {
+            Mode::FILE => self.open_file()?,
+            // The file is written when the ring buffer is dumped.
+            Mode::RING => {
+                let _ = std::fs::remove_file(self.file_path());
+                self.sha256 = Sha256::new();
+                self.size = 0;
+                self.records = 0;
+                self.file_start = now();
+                self.ring = Some(Ring::new(get_setting_parsed("capture.ring_size")));
+            }
+        }
+        self.started();
+        Ok(())
+    }
+
+    /// Continues the file of the stopped capture instead of replacing it:
+    /// the header is not written again and the records and the size of the
+    /// file carry on. The capture keeps the format and the snapshot length
+    /// of its file.
+    pub fn resume_capture(&mut self, format: Format) -> std::result::Result<(), CaptureError> {
+        if self.is_recording() {
+            return Ok(());
+        }
+        if self.compressed || self.size == 0 {
+            return Err(CaptureError::NotFound("no capture file to continue".to_string()));
+        }
+        if format != self.format {
+            return Err(CaptureError::InvalidArgument(format!(
+                "the capture file is in the {:?} format",
+                self.format
+            )));
+        }
+        let file = OpenOptions::new().append(true).open(self.file_path())?;
+        if file.metadata()?.len() != self.size as u64 {
+            return Err(CaptureError::InvalidState(
+                "the capture file changed since the capture stopped".to_string(),
+            ));
+        }
+        self.mode = Mode::FILE;
+        self.open_live()?;
+        self.file = Some(CaptureWriter::new(file, self.live.clone())?);
+        self.started();
+        Ok(())
+    }
+
+    // Creates the named pipe of a live capture, which records to a file.
+    fn open_live(&mut self) -> std::result::Result<(), CaptureError> {
+        if self.live_path.is_empty() {
+            return Ok(());
+        }
+        if self.mode == Mode::RING {
+            return Err(CaptureError::InvalidArgument(
+                "live captures are not recorded in a ring buffer".to_string(),
+            ));
+        }
+        let wait = Duration::from_secs(get_setting_parsed("capture.live_wait_secs"));
+        self.live =
+            Some(Arc::new(LivePipe::new(Path::new(&self.live_path), self.header()?, wait)?));
+        Ok(())
+    }
+
+    // Resets the state of the recording of a started capture.
+    fn started(&mut self) {
+        let timestamp = self.file_start;
+        self.sampler = Sampler::new(self.sampler.sampling.clone());
+        self.throughput = Throughput::default();
+        self.error.clear();
+        // A capture stopped by the quota of the directory is valid again,
+        // unlike the capture of a removed chip.
+        if self.removed_at.is_none() {
+            self.valid = true;
+        }
+        self.quota_status = QuotaStatus::BELOW_WATERMARK;
         self.seconds = timestamp.as_secs() as i64;
         self.nanos = timestamp.subsec_nanos() as i32;
-        self.file = Some(file);
+        self.record_event(Type::CAPTURE_STARTED, String::new());
+    }
+
+    /// Closes the file of a running capture as the next segment and
+    /// continues the capture in a new file.
+    pub fn rotate(&mut self) -> std::result::Result<(), CaptureError> {
+        if self.file.take().is_none() {
+            return Ok(());
+        }
+        let index = self.segments.last().map_or(1, |segment| segment.index + 1);
+        let path = self.segment_path(index);
+        std::fs::rename(self.file_path(), &path)?;
+        self.segments.push(Segment {
+            index,
+            path,
+            size: self.size,
+            records: self.records,
+            start: self.file_start,
+            end: now(),
+            sha256: self.sha256.hex(),
+        });
+        self.open_file()?;
+        self.record_event(Type::CAPTURE_ROTATED, String::new());
         Ok(())
     }
 
+    // Returns the header of a file in the capture format.
+    fn header(&self) -> Result<Vec<u8>> {
+        let linktype = self.linktype();
+        let if_name = self.if_name();
+        let mut header = Vec::new();
+        match self.format {
+            Format::PCAP => write_pcap_header(&mut header, linktype, self.snaplen)?,
+            Format::PCAPNG => write_pcapng_header(&mut header, linktype, &if_name, self.snaplen)?,
+            Format::BTSNOOP => write_btsnoop_header(&mut header)?,
+        };
+        Ok(header)
+    }
+
+    // Creates the file of the capture with its header. A previous file is
+    // removed rather than truncated, so the downloads still reading it
+    // complete.
+    fn create_file(&mut self) -> Result<File> {
+        let _ = std::fs::remove_file(self.file_path());
+        let mut file =
+            OpenOptions::new().write(true).truncate(true).create(true).open(self.file_path())?;
+        let header = self.header()?;
+        self.sha256 = Sha256::new();
+        HashingWriter { file: &mut file, sha256: &mut self.sha256 }.write_all(&header)?;
+        self.size = header.len();
+        self.records = 0;
+        self.file_start = now();
+        Ok(file)
+    }
+
+    // Creates the file of the capture, written by a CaptureWriter, and to
+    // the named pipe of a live capture.
+    fn open_file(&mut self) -> Result<()> {
+        let file = self.create_file()?;
+        self.file = Some(CaptureWriter::new(file, self.live.clone())?);
+        Ok(())
+    }
+
+    /// Records a packet of the chip: the packet is framed in the link type
+    /// of the chip kind and the format of the capture, sent to the
+    /// subscribers and queued for the file, or pushed to the ring buffer. A
+    /// capture failing to write its file stops, invalid. Returns whether
+    /// the record was queued for the file, for the caller to apply the
+    /// quotas.
+    pub fn write_packet(
+        &mut self,
+        packet_type: u32,
+        packet: &[u8],
+        direction: PacketDirection,
+        timestamp: Duration,
+    ) -> bool {
+        let Some(data) = link_data(self.kind, direction, packet_type, packet) else {
+            return false;
+        };
+        let parts = data.parts();
+        // The packet is copied once, into a record of the buffer pool sized
+        // for it and shared by the subscribers, the ring buffer and the
+        // writer.
+        let mut record = buffer_pool().take(RECORD_OVERHEAD + data.header.len() + packet.len());
+        let result = match self.format {
+            Format::PCAP => write_record_parts(timestamp, &mut *record, &parts, self.snaplen),
+            Format::PCAPNG => {
+                write_pcapng_packet(timestamp, &mut *record, 0, direction, &parts, self.snaplen)
+            }
+            // The H4 records of Bluetooth, the only chips captured in btsnoop.
+            Format::BTSNOOP => write_btsnoop_record(
+                timestamp,
+                &mut *record,
+                direction,
+                packet_type,
+                packet,
+                self.snaplen,
+            ),
+        };
+        if let Err(err) = result {
+            info!("{err:?}");
+            return false;
+        }
+        let record = Record::new(record);
+        self.broadcast.send(&record);
+        self.throughput.record(timestamp, record.len());
+        if let Some(ring) = self.ring.as_mut() {
+            ring.push(record);
+            return false;
+        }
+        // The record is written by the writer thread of the capture, so a
+        // slow disk doesn't stall the packets.
+        match self.write_record(record) {
+            Ok(()) => self.file.is_some(),
+            // A capture failing to write its file stops, rather than
+            // reporting the failure of every packet.
+            Err(err) => {
+                self.fail(format!("failed to write the capture file: {err}"));
+                false
+            }
+        }
+    }
+
+    // Queues a record for the file of a running capture, hashed and
+    // counted as it is queued.
+    fn write_record(&mut self, record: Record) -> Result<()> {
+        let Some(file) = self.file.as_ref() else {
+            return Ok(());
+        };
+        let len = record.len();
+        self.sha256.update(&record);
+        file.write(record)?;
+        self.size += len;
+        self.records += 1;
+        Ok(())
+    }
+
+    /// Waits for the queued records of a running capture to be in its file.
+    pub fn flush(&self) -> Result<()> {
+        match self.file.as_ref() {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// Stops a running capture as netsimd exits, with its records on the
+    /// disk: the ring buffer of a RING capture is written to its file, and
+    /// a pcapng file ends with the statistics of its interface.
+    pub fn shutdown(&mut self) -> std::result::Result<(), CaptureError> {
+        if !self.is_recording() {
+            return Ok(());
+        }
+        let result = self.close_file();
+        self.stop_capture();
+        result
+    }
+
+    // Writes the end of the file of a running capture and syncs it.
+    fn close_file(&mut self) -> std::result::Result<(), CaptureError> {
+        if self.ring.is_some() {
+            return self.dump();
+        }
+        let Some(file) = self.file.as_ref() else {
+            return Ok(());
+        };
+        if self.format == Format::PCAPNG {
+            let mut block = Vec::new();
+            write_pcapng_statistics(now(), &mut block, 0, self.file_start, self.sampler.packets)?;
+            // The statistics block is not a record of the capture.
+            self.sha256.update(&block);
+            self.size += block.len();
+            file.write(Record::new(block.into()))?;
+        }
+        Ok(file.sync()?)
+    }
+
     // Closes file by removing ownership of self.file
     // Capture info will still retain the size and record count
     // So it can be downloaded easily when GetCapture is invoked.
     pub fn stop_capture(&mut self) {
+        if !self.is_recording() {
+            return;
+        }
         self.file = None;
+        self.ring = None;
+        self.live = None;
+        self.record_event(Type::CAPTURE_STOPPED, String::new());
+    }
+
+    /// Records a lifecycle event of the capture in the event history, with
+    /// the state of the capture, for the subscribers of the events.
+    pub fn record_event(&self, event_type: Type, error: String) {
+        history().record(Event {
+            type_: event_type.into(),
+            timestamp: Some(to_timestamp(now())).into(),
+            device_name: self.device_name.clone(),
+            chip_id: self.id,
+            capture: Some(self.get_capture_proto()).into(),
+            error,
+            ..Default::default()
+        });
+    }
+
+    /// Records a CAPTURE_ERROR event of a failure of the capture.
+    pub fn record_error(&self, error: &impl Display) {
+        info!("capture of {}: {error}", self.device_name);
+        self.record_event(Type::CAPTURE_ERROR, error.to_string());
+    }
+
+    /// Stops the capture for `reason`, marking it invalid with the reason.
+    pub fn fail(&mut self, reason: String) {
+        self.stop_capture();
+        self.valid = false;
+        self.error = reason.clone();
+        warn!(chip_id: self.id; "capture of {} stopped: {reason}", self.device_name);
+        self.record_event(Type::CAPTURE_ERROR, reason);
+    }
+
+    /// Deletes the files of a stopped capture, keeping the capture listed,
+    /// and returns the bytes freed.
+    pub fn evict(&mut self) -> u64 {
+        if self.is_recording() {
+            return 0;
+        }
+        let freed = self.total_size() as u64;
+        self.delete_segments();
+        let path = self.download_path();
+        if let Err(err) = std::fs::remove_file(&path) {
+            info!("failed to delete {}: {err}", path.display());
+        }
+        self.size = 0;
+        self.records = 0;
+        self.sha256 = Sha256::new();
+        self.compressed = false;
+        self.record_event(Type::CAPTURE_EVICTED, String::new());
+        freed
+    }
+
+    /// Returns the compression of the file of a stopped capture, None while
+    /// the capture records, without a file or when the file is compressed.
+    pub fn compression(&self) -> Option<Compression> {
+        if self.is_recording() || self.compressed || self.size == 0 {
+            return None;
+        }
+        Some(Compression { id: self.id, path: self.file_path(), file_start: self.file_start })
+    }
+
+    /// Replaces the file by its compressed file, unless the capture started
+    /// again during the compression.
+    pub fn finish_compression(
+        &mut self,
+        compression: &Compression,
+        size: usize,
+        sha256: Sha256,
+    ) -> Result<()> {
+        if self.compression().is_none()
+            || self.file_start != compression.file_start
+            || self.file_path() != compression.path
+        {
+            compression.discard();
+            return Ok(());
+        }
+        std::fs::rename(compression.temp_path(), self.gzip_path())?;
+        std::fs::remove_file(&compression.path)?;
+        self.size = size;
+        self.sha256 = sha256;
+        self.compressed = true;
+        Ok(())
+    }
+
+    /// Writes the records of the ring buffer to the file of the capture,
+    /// replacing its content. The capture keeps recording in the ring.
+    pub fn dump(&mut self) -> std::result::Result<(), CaptureError> {
+        let Some(ring) = self.ring.take() else {
+            return Err(CaptureError::InvalidState(
+                "the capture isn't recording in a ring buffer".to_string(),
+            ));
+        };
+        let result = self.write_ring(&ring);
+        self.ring = Some(ring);
+        Ok(result?)
+    }
+
+    /// Applies the fields of a patch named by its update mask, without
+    /// starting or stopping the capture: the sampling and the filter at
+    /// once, the format, mode, snaplen and live_path of a stopped capture
+    /// for its next start, and the dump of a ring capture.
+    pub fn update(
+        &mut self,
+        patch: &PatchCapture,
+        fields: &[String],
+    ) -> std::result::Result<(), CaptureError> {
+        let has = |name: &str| fields.iter().any(|field| field == name);
+        let starts = ["format", "mode", "snaplen", "live_path"];
+        if self.is_recording() && starts.iter().any(|name| has(name)) {
+            return Err(CaptureError::InvalidState(
+                "the format, mode, snaplen and live_path of a running capture are not patched"
+                    .to_string(),
+            ));
+        }
+        let filter = patch.filter.clone().unwrap_or_default();
+        if has("filter") {
+            validate(&filter).map_err(|err| {
+                CaptureError::InvalidArgument(format!("Invalid capture filter: {err}"))
+            })?;
+        }
+        if has("sampling") {
+            self.sampler = Sampler::new(patch.sampling.clone().unwrap_or_default());
+        }
+        if has("filter") {
+            self.filter = filter;
+        }
+        if has("format") {
+            self.format = patch.format.enum_value_or_default();
+        }
+        if has("mode") {
+            self.mode = patch.mode.enum_value_or_default();
+        }
+        if has("snaplen") {
+            self.snaplen = patch.snaplen;
+        }
+        if has("live_path") {
+            self.live_path = patch.live_path.clone();
+        }
+        if has("dump") && patch.dump {
+            self.dump()?;
+        }
+        Ok(())
+    }
+
+    // Writes a file with the header and the records of a ring buffer.
+    fn write_ring(&mut self, ring: &Ring) -> Result<()> {
+        let start = self.file_start;
+        let mut file = self.create_file()?;
+        self.file_start = start;
+        let mut output = HashingWriter { file: &mut file, sha256: &mut self.sha256 };
+        for record in ring.records.iter() {
+            output.write_all(record)?;
+            self.size += record.len();
+            self.records += 1;
+        }
+        output.flush()?;
+        file.sync_all()
     }
 
     pub fn new_facade_key(kind: ChipKind, facade_id: FacadeId) -> (ChipKind, FacadeId) {
@@ -117,14 +1390,19 @@ impl CaptureInfo {
         CaptureInfo::new_facade_key(self.chip_kind, self.facade_id)
     }
 
+    pub fn chip_id(&self) -> ChipId {
+        self.id
+    }
+
     pub fn get_capture_proto(&self) -> ProtoCapture {
         let timestamp =
             Timestamp { seconds: self.seconds, nanos: self.nanos, ..Default::default() };
+        let (bytes_per_second, packets_per_second) = self.throughput.rates(now());
         ProtoCapture {
             id: self.id,
-            chip_kind: self.chip_kind.into(),
+            chip_kind: EnumOrUnknown::from_i32(self.kind as i32),
             device_name: self.device_name.clone(),
-            state: match self.file.is_some() {
+            state: match self.is_recording() {
                 true => State::ON.into(),
                 false => State::OFF.into(),
             },
@@ -132,6 +1410,45 @@ impl CaptureInfo {
             records: self.records,
             timestamp: Some(timestamp).into(),
             valid: self.valid,
+            sampling: Some(self.sampler.sampling.clone()).into(),
+            packets: self.sampler.packets as i32,
+            quota_status: self.quota_status.into(),
+            sha256: match self.size {
+                0 => String::new(),
+                _ => self.sha256.hex(),
+            },
+            format: self.format.into(),
+            segments: self.segments.iter().map(Segment::to_proto).collect(),
+            filter: Some(self.filter.clone()).into(),
+            mode: self.mode.into(),
+            compressed: self.compressed,
+            snaplen: self.snaplen,
+            bytes_per_second,
+            packets_per_second,
+            last_record: self.throughput.last_record.map(to_timestamp).into(),
+            error: self.error.clone(),
+            live_path: self.live_path.clone(),
+            live_reader: self.live.as_ref().is_some_and(|live| live.is_attached()),
+            ..Default::default()
+        }
+    }
+
+    pub fn get_capture_stats(&self, time: Duration) -> CaptureStats {
+        let (bytes_per_second, packets_per_second) = self.throughput.rates(time);
+        CaptureStats {
+            id: self.id,
+            device_name: self.device_name.clone(),
+            chip_kind: EnumOrUnknown::from_i32(self.kind as i32),
+            state: match self.is_recording() {
+                true => State::ON.into(),
+                false => State::OFF.into(),
+            },
+            bytes_per_second,
+            packets_per_second,
+            size: self.size as i32,
+            records: self.records,
+            last_record: self.throughput.last_record.map(to_timestamp).into(),
+            dropped: self.dropped,
             ..Default::default()
         }
     }
@@ -142,18 +1459,73 @@ impl Captures {
         Captures {
             facade_key_to_capture: HashMap::<(ChipKind, FacadeId), Arc<Mutex<CaptureInfo>>>::new(),
             chip_id_to_capture: BTreeMap::<ChipId, Arc<Mutex<CaptureInfo>>>::new(),
+            capture_all: None,
+            aggregate: Mutex::new(None),
+        }
+    }
+
+    /// Sets the directory of the capture files, replacing the capture.dir
+    /// setting. The directory is created if needed and must be writable.
+    /// The files of the captures already started stay in the previous
+    /// directory until the captures are started again.
+    pub fn set_dir(dir: &Path) -> std::result::Result<(), CaptureError> {
+        check_writable_dir(dir)?;
+        *CAPTURE_DIR.write().unwrap() = Some(dir.to_path_buf());
+        Ok(())
+    }
+
+    /// Subscribes to the records of a capture as they are written, None
+    /// when the chip of the capture is gone.
+    pub fn subscribe(&self, key: ChipId) -> Option<Subscription> {
+        let mut capture = self.chip_id_to_capture.get(&key)?.lock().unwrap();
+        if !capture.valid {
+            return None;
         }
+        let header = capture.header().ok()?;
+        Some(Subscription {
+            mime_type: capture.mime_type(),
+            header,
+            records: capture.broadcast.subscribe(),
+        })
     }
 
     pub fn contains(&self, key: ChipId) -> bool {
         self.chip_id_to_capture.contains_key(&key)
     }
 
-    pub fn get(&mut self, key: ChipId) -> Option<&mut Arc<Mutex<CaptureInfo>>> {
-        self.chip_id_to_capture.get_mut(&key)
+    pub fn get(&self, key: ChipId) -> Option<&Arc<Mutex<CaptureInfo>>> {
+        self.chip_id_to_capture.get(&key)
+    }
+
+    /// Moves the capture of a chip to the new facade id of the chip, e.g.
+    /// after its facade was rebuilt, so the packets of the new facade are
+    /// recorded by the running capture. Returns whether the capture moved.
+    pub fn rekey(&mut self, key: ChipId, facade_id: FacadeId) -> bool {
+        let Some(arc_capture) = self.chip_id_to_capture.get(&key) else {
+            return false;
+        };
+        let mut capture = arc_capture.lock().unwrap();
+        // The capture of a removed chip has no facade anymore.
+        if capture.facade_id == facade_id || capture.removed_at.is_some() {
+            return false;
+        }
+        if let Some(previous) = self.facade_key_to_capture.get(&capture.get_facade_key()) {
+            if Arc::ptr_eq(previous, arc_capture) {
+                self.facade_key_to_capture.remove(&capture.get_facade_key());
+            }
+        }
+        capture.facade_id = facade_id;
+        self.facade_key_to_capture.insert(capture.get_facade_key(), arc_capture.clone());
+        true
     }
 
-    pub fn insert(&mut self, capture: CaptureInfo) {
+    /// Inserts the capture of a chip, started with the capture_all format.
+    pub fn insert(&mut self, mut capture: CaptureInfo) {
+        if let Some(format) = self.capture_all {
+            if let Err(err) = capture.start_capture(format, Mode::FILE) {
+                capture.record_error(&err);
+            }
+        }
         let chip_id = capture.id;
         let facade_key = capture.get_facade_key();
         let arc_capture = Arc::new(Mutex::new(capture));
@@ -165,25 +1537,804 @@ impl Captures {
         self.chip_id_to_capture.is_empty()
     }
 
-    pub fn iter(&self) -> Iter<ChipId, Arc<Mutex<CaptureInfo>>> {
+    pub fn iter(&self) -> Iter<'_, ChipId, Arc<Mutex<CaptureInfo>>> {
         self.chip_id_to_capture.iter()
     }
 
     // When Capture is removed, remove from each map and also invoke closing of files.
-    pub fn remove(&mut self, key: &ChipId) {
+    pub fn remove(&mut self, key: &ChipId) -> std::result::Result<(), CaptureError> {
+        let Some(arc_capture) = self.chip_id_to_capture.remove(key) else {
+            return Err(CaptureError::NotFound(format!("no capture of chip {key}")));
+        };
+        let mut capture = arc_capture.lock().unwrap();
+        self.facade_key_to_capture.remove(&capture.get_facade_key());
+        capture.stop_capture();
+        capture.broadcast.close();
+        capture.record_event(Type::CAPTURE_REMOVED, String::new());
+        Ok(())
+    }
+
+    // Keeps the capture of a removed chip with its file closed, so it can
+    // still be downloaded. The ring buffer of a RING capture is written to
+    // the file first, as the last records of the chip.
+    pub fn finalize(&mut self, key: &ChipId, time: Duration) {
         if let Some(arc_capture) = self.chip_id_to_capture.get(key) {
-            if let Ok(mut capture) = arc_capture.lock() {
-                self.facade_key_to_capture.remove(&capture.get_facade_key());
-                capture.stop_capture();
+            let mut capture = arc_capture.lock().unwrap();
+            self.facade_key_to_capture.remove(&capture.get_facade_key());
+            if capture.ring.is_some() {
+                if let Err(err) = capture.dump() {
+                    capture.record_error(&err);
+                }
             }
-        } else {
-            println!("key does not exist in Captures");
-            return;
+            capture.stop_capture();
+            capture.broadcast.close();
+            capture.valid = false;
+            capture.removed_at = Some(time);
         }
-        self.chip_id_to_capture.remove(key);
     }
 
-    pub fn values(&self) -> Values<ChipId, Arc<Mutex<CaptureInfo>>> {
+    // Removes a capture and deletes its files.
+    pub fn delete(&mut self, key: &ChipId) -> std::result::Result<(), CaptureError> {
+        let Some(arc_capture) = self.chip_id_to_capture.get(key) else {
+            return Err(CaptureError::NotFound(format!("no capture of chip {key}")));
+        };
+        let mut capture = arc_capture.lock().unwrap();
+        capture.delete_segments();
+        let path = capture.download_path();
+        drop(capture);
+        self.remove(key)?;
+        if let Err(err) = std::fs::remove_file(&path) {
+            info!("failed to delete {}: {err}", path.display());
+        }
+        Ok(())
+    }
+
+    /// Evicts the files of the oldest stopped captures, but `keep`, until
+    /// `usage` bytes are at most `max_size`, and returns the usage left.
+    /// The captures locked by their packets are skipped, as a packet
+    /// handler evicts while holding the lock of its own capture.
+    pub fn evict_oldest(&self, keep: ChipId, mut usage: u64, max_size: u64) -> u64 {
+        let mut stopped: Vec<_> = self
+            .chip_id_to_capture
+            .iter()
+            .filter(|(id, _)| **id != keep)
+            .filter_map(|(_, capture)| capture.try_lock().ok())
+            .filter(|capture| !capture.is_recording() && capture.total_size() > 0)
+            .collect();
+        stopped.sort_by_key(|capture| (capture.seconds, capture.nanos));
+        for mut capture in stopped {
+            if usage <= max_size {
+                break;
+            }
+            usage = usage.saturating_sub(capture.evict());
+        }
+        usage
+    }
+
+    pub fn values(&self) -> Values<'_, ChipId, Arc<Mutex<CaptureInfo>>> {
         self.chip_id_to_capture.values()
     }
+
+    /// Starts the file captures of the chips of `keys` in `format`, or stops
+    /// them, e.g. for the devices of a group. The running captures keep
+    /// running. Returns the errors of the captures that failed to start,
+    /// recorded in the captures, or of the missing captures.
+    pub fn set_recording(
+        &self,
+        keys: &[ChipId],
+        state: bool,
+        format: Format,
+    ) -> Vec<(ChipId, CaptureError)> {
+        let mut errors = Vec::new();
+        for &key in keys {
+            let Some(arc_capture) = self.chip_id_to_capture.get(&key) else {
+                errors.push((key, CaptureError::NotFound(format!("no capture of chip {key}"))));
+                continue;
+            };
+            let mut capture = arc_capture.lock().unwrap();
+            if !state {
+                capture.stop_capture();
+            } else if let Err(err) = capture.start_capture(format, Mode::FILE) {
+                capture.record_error(&err);
+                errors.push((key, err));
+            }
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DropPolicy;
+
+    fn sampled(sampler: &mut Sampler, times: &[u64]) -> Vec<bool> {
+        times.iter().map(|&millis| sampler.sample(Duration::from_millis(millis))).collect()
+    }
+
+    #[test]
+    fn test_sampler() {
+        let mut sampler = Sampler::default();
+        assert_eq!(sampled(&mut sampler, &[0, 0, 0]), [true, true, true]);
+        let mut sampler = Sampler::new(Sampling { one_in: 3, ..Default::default() });
+        assert_eq!(sampled(&mut sampler, &[0, 0, 0, 0, 0]), [true, false, false, true, false]);
+        assert_eq!(sampler.packets, 5);
+    }
+
+    #[test]
+    fn test_sampler_max_rate() {
+        let mut sampler = Sampler::new(Sampling { max_rate: 2, ..Default::default() });
+        assert_eq!(
+            sampled(&mut sampler, &[1000, 1100, 1200, 1900, 2000, 2100]),
+            [true, true, false, false, true, true]
+        );
+        // Both limits apply.
+        let mut sampler = Sampler::new(Sampling { one_in: 2, max_rate: 1, ..Default::default() });
+        assert_eq!(sampled(&mut sampler, &[1000, 1000, 1000, 2000]), [true, false, false, false]);
+    }
+
+    #[test]
+    fn test_throughput() {
+        let mut throughput = Throughput::default();
+        assert_eq!(throughput.rates(Duration::from_secs(100)), (0.0, 0.0));
+        throughput.record(Duration::from_millis(100_200), 300);
+        throughput.record(Duration::from_millis(100_700), 100);
+        throughput.record(Duration::from_millis(105_000), 600);
+        assert_eq!(throughput.rates(Duration::from_secs(105)), (100.0, 0.3));
+        // The records leave the window after THROUGHPUT_WINDOW_SECS.
+        assert_eq!(throughput.rates(Duration::from_secs(110)), (60.0, 0.1));
+        assert_eq!(throughput.rates(Duration::from_secs(115)), (0.0, 0.0));
+        // A bucket is reused for a later second.
+        throughput.record(Duration::from_secs(115), 50);
+        assert_eq!(throughput.rates(Duration::from_secs(115)), (5.0, 0.1));
+        assert_eq!(throughput.last_record, Some(Duration::from_secs(115)));
+    }
+
+    #[test]
+    fn test_quota_status() {
+        let quota = Quota { max_size: 1000, max_records: 10, watermark: 80.0 };
+        assert_eq!(quota.status(799, 7), QuotaStatus::BELOW_WATERMARK);
+        assert_eq!(quota.status(800, 7), QuotaStatus::ABOVE_WATERMARK);
+        assert_eq!(quota.status(100, 8), QuotaStatus::ABOVE_WATERMARK);
+        assert_eq!(quota.status(100, 10), QuotaStatus::FULL);
+        assert_eq!(quota.status(1200, 0), QuotaStatus::FULL);
+        let unlimited = Quota { max_size: 0, max_records: 0, watermark: 80.0 };
+        assert_eq!(unlimited.status(usize::MAX, i32::MAX), QuotaStatus::BELOW_WATERMARK);
+    }
+
+    #[test]
+    fn test_retention_expiry() {
+        let removed_at = Duration::from_secs(100);
+        let retention = Retention { keep_removed: true, expiry: Duration::from_secs(60) };
+        assert!(!retention.is_expired(removed_at, Duration::from_secs(159)));
+        assert!(retention.is_expired(removed_at, Duration::from_secs(160)));
+        let retention = Retention { keep_removed: true, expiry: Duration::ZERO };
+        assert!(!retention.is_expired(removed_at, Duration::from_secs(1_000_000)));
+    }
+
+    #[test]
+    fn test_broadcast() {
+        let mut broadcast = Broadcast::default();
+        let mut first = broadcast.subscribe();
+        let second = broadcast.subscribe();
+        let one = Record::new(b"one".to_vec().into());
+        broadcast.send(&one);
+        drop(second);
+        broadcast.send(&Record::new(b"two".to_vec().into()));
+        let records: Vec<_> = std::iter::from_fn(|| first.try_recv().ok()).collect();
+        assert_eq!(records, [one.clone(), Record::new(b"two".to_vec().into())]);
+        // The subscribers share the record rather than a copy.
+        assert!(Record::ptr_eq(&records[0], &one));
+        // The subscribers that are gone are forgotten.
+        assert_eq!(broadcast.senders.len(), 1);
+        // A slow subscriber misses the records beyond its queue.
+        for _ in 0..SUBSCRIBER_QUEUE_LEN + 1 {
+            broadcast.send(&Record::new(b"record".to_vec().into()));
+        }
+        assert_eq!(std::iter::from_fn(|| first.try_recv().ok()).count(), SUBSCRIBER_QUEUE_LEN);
+        broadcast.close();
+        assert!(broadcast.is_empty());
+        assert!(first.blocking_recv().is_none());
+    }
+
+    #[test]
+    fn test_check_writable_dir() {
+        let dir = std::env::temp_dir().join(format!("netsim-dir-test-{}", std::process::id()));
+        let nested = dir.join("artifacts").join("captures");
+        assert!(check_writable_dir(&nested).is_ok());
+        assert!(nested.is_dir());
+        assert_eq!(std::fs::read_dir(&nested).unwrap().count(), 0);
+        // A directory can't be created under a file.
+        let file = dir.join("file");
+        std::fs::write(&file, "").unwrap();
+        assert!(check_writable_dir(&file.join("captures")).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    // A Bluetooth capture without a facade, CaptureInfo::new needs the ffi.
+    fn test_capture(device_name: &str) -> CaptureInfo {
+        CaptureInfo {
+            facade_id: 0,
+            id: 7,
+            chip_kind: ChipKind::BLUETOOTH,
+            kind: ChipKind::BLUETOOTH as u32,
+            device_name: format!("{device_name}-{}", std::process::id()),
+            size: 0,
+            records: 0,
+            seconds: 0,
+            nanos: 0,
+            valid: true,
+            file: None,
+            sampler: Sampler::default(),
+            filter: Filter::default(),
+            quota_status: QuotaStatus::BELOW_WATERMARK,
+            removed_at: None,
+            sha256: Sha256::new(),
+            format: Format::PCAP,
+            segments: Vec::new(),
+            file_start: Duration::ZERO,
+            dir: pcap_dir(),
+            broadcast: Broadcast::default(),
+            mode: Mode::FILE,
+            ring: None,
+            compressed: false,
+            snaplen: 0,
+            file_stem: String::new(),
+            throughput: Throughput::default(),
+            error: String::new(),
+            live_path: String::new(),
+            live: None,
+            dropped: 0,
+        }
+    }
+
+    #[test]
+    fn test_render_template() {
+        let value = |placeholder: &str| match placeholder {
+            "chip_id" => Some("7".to_string()),
+            "device_name" => Some("{chip_id}".to_string()),
+            _ => None,
+        };
+        assert_eq!(render_template("{chip_id}-{device_name}", value), "7-{chip_id}");
+        assert_eq!(render_template("{unknown}-{chip_id}{", value), "{unknown}-7{");
+    }
+
+    #[test]
+    fn test_sanitize_file_name() {
+        assert_eq!(sanitize_file_name("7-../phone/a:b-BLUETOOTH"), "7-.._phone_a_b-BLUETOOTH");
+        assert_eq!(sanitize_file_name("tab\tname.. "), "tab_name");
+        assert_eq!(sanitize_file_name(".."), "_");
+    }
+
+    #[test]
+    fn test_header_linktype() {
+        let mut capture = test_capture("linktype-test");
+        assert_eq!(capture.header().unwrap()[20..24], 201u32.to_be_bytes());
+        capture.chip_kind = ChipKind::WIFI;
+        capture.kind = ChipKind::WIFI as u32;
+        assert_eq!(capture.header().unwrap()[20..24], 127u32.to_be_bytes());
+        capture.chip_kind = ChipKind::UWB;
+        capture.kind = ChipKind::UWB as u32;
+        assert_eq!(capture.header().unwrap()[20..24], 299u32.to_be_bytes());
+    }
+
+    #[test]
+    fn test_btsnoop_capture() {
+        let mut capture = test_capture("btsnoop-test");
+        capture.start_capture(Format::BTSNOOP, Mode::FILE).unwrap();
+        assert!(capture.file_path().to_str().unwrap().ends_with("-BLUETOOTH.btsnoop"));
+        assert_eq!(std::fs::read(capture.file_path()).unwrap()[..8], *b"btsnoop\0");
+        capture.stop_capture();
+        std::fs::remove_file(capture.file_path()).unwrap();
+        // The other chips have no H4 records.
+        capture.chip_kind = ChipKind::WIFI;
+        capture.kind = ChipKind::WIFI as u32;
+        assert!(capture.start_capture(Format::BTSNOOP, Mode::FILE).is_err());
+        assert!(!capture.is_recording());
+    }
+
+    #[test]
+    fn test_update_mask_fields() {
+        let paths = |paths: &[&str]| paths.iter().map(|path| path.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            update_mask_fields(&paths(&["state", "livePath", "snaplen"])).unwrap(),
+            ["state", "live_path", "snaplen"]
+        );
+        assert!(update_mask_fields(&paths(&["sampling.one_in"])).is_err());
+        assert!(update_mask_fields(&paths(&["size"])).is_err());
+    }
+
+    #[test]
+    fn test_update() {
+        let mut capture = test_capture("update-test");
+        let patch = PatchCapture {
+            sampling: Some(Sampling { one_in: 4, ..Default::default() }).into(),
+            filter: Some(Filter { min_length: 5, ..Default::default() }).into(),
+            format: Format::PCAPNG.into(),
+            snaplen: 64,
+            ..Default::default()
+        };
+        let fields = |fields: &[&str]| fields.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+        // Only the fields of the mask are patched.
+        capture.update(&patch, &fields(&["sampling", "format"])).unwrap();
+        assert_eq!(capture.sampler.sampling.one_in, 4);
+        assert_eq!(capture.format, Format::PCAPNG);
+        assert_eq!((capture.filter.min_length, capture.snaplen), (0, 0));
+        capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+        capture.update(&patch, &fields(&["filter"])).unwrap();
+        assert_eq!(capture.filter.min_length, 5);
+        // The settings of the file of a running capture are kept.
+        assert!(capture.update(&patch, &fields(&["snaplen"])).is_err());
+        let invalid = PatchCapture {
+            filter: Some(Filter { min_length: 5, max_length: 4, ..Default::default() }).into(),
+            ..Default::default()
+        };
+        assert!(capture.update(&invalid, &fields(&["filter", "sampling"])).is_err());
+        assert_eq!(capture.sampler.sampling.one_in, 4);
+        // An unset field of the mask is cleared.
+        capture.update(&PatchCapture::new(), &fields(&["sampling", "filter"])).unwrap();
+        assert_eq!((capture.sampler.sampling.one_in, capture.filter.min_length), (0, 0));
+        assert!(capture.is_recording());
+        capture.stop_capture();
+        std::fs::remove_file(capture.file_path()).unwrap();
+    }
+
+    #[test]
+    fn test_ring() {
+        let mut ring = Ring::new(10);
+        ring.push(Record::new(vec![1; 4].into()));
+        ring.push(Record::new(vec![2; 4].into()));
+        assert_eq!(ring.records.bytes(), 8);
+        // The oldest records are discarded beyond the capacity.
+        ring.push(Record::new(vec![3; 4].into()));
+        assert_eq!((ring.records.len(), ring.records.bytes()), (2, 8));
+        assert_eq!(ring.records.iter().next().unwrap()[..], [2; 4]);
+        ring.push(Record::new(vec![4; 11].into()));
+        assert_eq!((ring.records.len(), ring.records.bytes()), (0, 0));
+    }
+
+    #[test]
+    fn test_ring_memory_pool() {
+        let accountant = Arc::new(Accountant::new(
+            0,
+            [(6, DropPolicy::DropOldest), (0, DropPolicy::DropOldest), (0, DropPolicy::DropNewest)],
+        ));
+        let mut ring = Ring::with_accountant(10, accountant.clone());
+        ring.push(Record::new(vec![1; 4].into()));
+        // The captures pool is out of budget before the ring is full.
+        ring.push(Record::new(vec![2; 4].into()));
+        assert_eq!(ring.records.len(), 1);
+        let usage = accountant.usage(Pool::Captures);
+        assert_eq!((usage.used_bytes, usage.dropped_items), (4, 1));
+        drop(ring);
+        assert_eq!(accountant.usage(Pool::Captures).used_bytes, 0);
+    }
+
+    #[test]
+    fn test_dump() {
+        let mut capture = test_capture("dump-test");
+        assert!(capture.dump().is_err());
+        capture.start_capture(Format::PCAP, Mode::RING).unwrap();
+        assert!(capture.is_recording() && capture.file.is_none());
+        assert!(!capture.file_path().exists());
+        capture.ring.as_mut().unwrap().push(Record::new(vec![1; 20].into()));
+        capture.ring.as_mut().unwrap().push(Record::new(vec![2; 30].into()));
+        capture.dump().unwrap();
+        // The header and the records, and the ring keeps recording.
+        assert_eq!((capture.size, capture.records), (24 + 50, 2));
+        assert_eq!(std::fs::read(capture.file_path()).unwrap().len(), 74);
+        assert!(capture.file.is_none() && capture.ring.is_some());
+        capture.stop_capture();
+        assert!(!capture.is_recording());
+        std::fs::remove_file(capture.file_path()).unwrap();
+    }
+
+    #[test]
+    fn test_compression() {
+        let mut capture = test_capture("gzip-test");
+        capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+        assert!(capture.compression().is_none());
+        capture.stop_capture();
+        let compression = capture.compression().unwrap();
+        let (size, sha256) = compression.run().unwrap();
+        capture.finish_compression(&compression, size, sha256).unwrap();
+        assert!(capture.compressed && capture.compression().is_none());
+        assert!(!capture.file_path().exists());
+        let path = capture.download_path();
+        assert!(path.to_str().unwrap().ends_with("-BLUETOOTH.pcap.gz"));
+        assert_eq!(std::fs::read(&path).unwrap().len(), capture.size);
+        // A new capture replaces the compressed file.
+        capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+        assert!(!capture.compressed && !path.exists());
+        // A compression of a capture started again is discarded.
+        capture.stop_capture();
+        let compression = capture.compression().unwrap();
+        let (size, sha256) = compression.run().unwrap();
+        capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+        capture.finish_compression(&compression, size, sha256).unwrap();
+        assert!(!capture.compressed && capture.file_path().exists());
+        assert!(!compression.temp_path().exists());
+        std::fs::remove_file(capture.file_path()).unwrap();
+    }
+
+    #[test]
+    fn test_resume_capture() {
+        let mut capture = test_capture("resume-test");
+        assert!(capture.resume_capture(Format::PCAP).is_err());
+        capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+        capture.write_record(Record::new(vec![1; 10].into())).unwrap();
+        capture.stop_capture();
+        let sha256 = capture.sha256.clone();
+        // The resumed capture continues the file and its digest.
+        assert!(capture.resume_capture(Format::PCAPNG).is_err());
+        capture.resume_capture(Format::PCAP).unwrap();
+        capture.write_record(Record::new(vec![2; 5].into())).unwrap();
+        capture.stop_capture();
+        assert_eq!((capture.size, capture.records), (24 + 15, 2));
+        let data = std::fs::read(capture.file_path()).unwrap();
+        assert_eq!(data.len(), capture.size);
+        assert_eq!(data[24..], [[1; 10].as_slice(), &[2; 5]].concat());
+        let mut expected = sha256;
+        expected.update(&[2; 5]);
+        assert_eq!(capture.sha256.hex(), expected.hex());
+        // A file changed since the capture stopped is not continued.
+        std::fs::write(capture.file_path(), [0; 4]).unwrap();
+        assert!(capture.resume_capture(Format::PCAP).is_err());
+        std::fs::remove_file(capture.file_path()).unwrap();
+    }
+
+    #[test]
+    fn test_range_download() {
+        use crate::captures::handlers::{handle_capture_get, ByteRange};
+        use crate::http_server::server_response::ServerResponseWriter;
+        let mut capture = test_capture("range-download-test");
+        capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+        capture.write_record(Record::new(vec![1; 16].into())).unwrap();
+        capture.stop_capture();
+        let path = capture.file_path();
+        let capture = Arc::new(Mutex::new(capture));
+        let get = |offset, length, header| {
+            let mut response = Vec::new();
+            let range = ByteRange { offset, length, header };
+            handle_capture_get(
+                &mut ServerResponseWriter::new(&mut response),
+                Some(capture.clone()),
+                7,
+                0,
+                range,
+            );
+            String::from_utf8_lossy(&response).into_owned()
+        };
+        // A Range header gets a Partial Content, with the range of the file.
+        let response = get(30, 0, true);
+        assert!(response.starts_with("HTTP/1.1 206\r\n"));
+        assert!(response.contains("Content-Length: 10\r\n"));
+        assert!(response.contains("Content-Range: bytes 30-39/40\r\n"));
+        assert!(get(0, 0, true).contains("Content-Range: bytes 0-39/40\r\n"));
+        // The range of a gRPC request has no partial status.
+        assert!(get(30, 0, false).starts_with("HTTP/1.1 200\r\n"));
+        // A range from the end of the file is not satisfiable.
+        let response = get(40, 0, true);
+        assert!(response.starts_with("HTTP/1.1 416\r\n"));
+        assert!(response.contains("Content-Range: bytes */40\r\n"));
+        assert!(get(41, 0, false).starts_with("HTTP/1.1 416\r\n"));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_async_download() {
+        use crate::captures::handlers::{prepare_download, ByteRange};
+        use crate::http_server::stream::send_download;
+        use tokio::io::AsyncReadExt;
+        let mut capture = test_capture("async-download-test");
+        capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+        capture.stop_capture();
+        let path = capture.file_path();
+        let capture = Mutex::new(capture);
+        let range = ByteRange { offset: 4, length: 8, header: true };
+        let download = prepare_download(Some(&capture), 7, 0, range).ok().unwrap();
+        // The download holds the file, not the capture.
+        drop(capture.lock().unwrap());
+        let (mut client, mut server) = tokio::io::duplex(1024);
+        send_download(&mut server, &[], download).await.unwrap();
+        let mut response = Vec::new();
+        client.read_to_end(&mut response).await.unwrap();
+        let response = String::from_utf8_lossy(&response);
+        assert!(response.starts_with("HTTP/1.1 206\r\n"));
+        assert!(response.contains("Content-Range: bytes 4-11/24\r\n"));
+        let body = &std::fs::read(&path).unwrap()[4..12];
+        assert!(response.as_bytes().ends_with(body));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_shutdown() {
+        let mut capture = test_capture("shutdown-test");
+        capture.start_capture(Format::PCAPNG, Mode::FILE).unwrap();
+        capture.write_record(Record::new(vec![1; 12].into())).unwrap();
+        capture.shutdown().unwrap();
+        assert!(!capture.is_recording());
+        // The file ends with the statistics block of the interface, which
+        // is not a record.
+        let data = std::fs::read(capture.file_path()).unwrap();
+        assert_eq!((data.len(), capture.records), (capture.size, 1));
+        let block = &data[data.len() - 64..];
+        assert_eq!(block[..8], [0, 0, 0, 5, 0, 0, 0, 64]);
+        std::fs::remove_file(capture.file_path()).unwrap();
+        // The ring buffer of a RING capture is written to the file.
+        capture.start_capture(Format::PCAP, Mode::RING).unwrap();
+        capture.ring.as_mut().unwrap().push(Record::new(vec![1; 20].into()));
+        capture.shutdown().unwrap();
+        assert_eq!(std::fs::read(capture.file_path()).unwrap().len(), 24 + 20);
+        std::fs::remove_file(capture.file_path()).unwrap();
+    }
+
+    #[test]
+    fn test_write_packet() {
+        let mut capture = test_capture("write-packet-test");
+        let time = Duration::from_secs(1);
+        let direction = PacketDirection::HostToController;
+        // A stopped capture records nothing.
+        assert!(!capture.write_packet(1, &[1, 2, 3], direction, time));
+        capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+        assert!(capture.write_packet(1, &[1, 2, 3], direction, time));
+        // The H4 record: the direction, the packet type and the packet.
+        assert_eq!((capture.size, capture.records), (24 + 16 + 8, 1));
+        capture.stop_capture();
+        let data = std::fs::read(capture.file_path()).unwrap();
+        assert_eq!(data[40..], [0, 0, 0, 0, 1, 1, 2, 3]);
+        // The records of a ring capture are not written to the file.
+        capture.start_capture(Format::PCAP, Mode::RING).unwrap();
+        assert!(!capture.write_packet(1, &[1, 2, 3], direction, time));
+        assert_eq!(capture.ring.as_ref().unwrap().records.len(), 1);
+        capture.stop_capture();
+        let _ = std::fs::remove_file(capture.file_path());
+    }
+
+    #[test]
+    fn test_finalize_ring() {
+        let mut captures = Captures::new();
+        let mut capture = test_capture("finalize-ring-test");
+        capture.start_capture(Format::PCAP, Mode::RING).unwrap();
+        capture.ring.as_mut().unwrap().push(Record::new(vec![1; 20].into()));
+        captures.insert(capture);
+        captures.finalize(&7, Duration::from_secs(1));
+        // The removed capture keeps the records of its ring buffer.
+        let capture = captures.get(7).unwrap().lock().unwrap();
+        assert!(!capture.valid && !capture.is_recording());
+        assert_eq!((capture.size, capture.records), (24 + 20, 1));
+        assert_eq!(std::fs::read(capture.file_path()).unwrap().len(), 44);
+        std::fs::remove_file(capture.file_path()).unwrap();
+    }
+
+    #[test]
+    fn test_rekey() {
+        let mut captures = Captures::new();
+        captures.insert(test_capture("rekey-test"));
+        assert!(!captures.rekey(7, 0));
+        assert!(!captures.rekey(8, 3));
+        assert!(captures.rekey(7, 3));
+        // The packets of the new facade reach the capture, not the old one.
+        assert!(!captures.facade_key_to_capture.contains_key(&(ChipKind::BLUETOOTH, 0)));
+        let capture = captures.facade_key_to_capture.get(&(ChipKind::BLUETOOTH, 3)).unwrap();
+        assert_eq!(capture.lock().unwrap().id, 7);
+        // The capture of a removed chip stays with its last facade.
+        captures.finalize(&7, Duration::from_secs(1));
+        assert!(!captures.rekey(7, 4));
+        let _ = std::fs::remove_file(captures.get(7).unwrap().lock().unwrap().file_path());
+    }
+
+    #[test]
+    fn test_remove_not_found() {
+        let mut captures = Captures::new();
+        assert!(matches!(captures.remove(&7), Err(CaptureError::NotFound(_))));
+        assert!(matches!(captures.delete(&7), Err(CaptureError::NotFound(_))));
+        captures.insert(test_capture("remove-test"));
+        captures.remove(&7).unwrap();
+        assert!(captures.is_empty());
+    }
+
+    #[test]
+    fn test_capture_all() {
+        let mut captures = Captures::new();
+        captures.insert(test_capture("stopped-test"));
+        assert!(!captures.get(7).unwrap().lock().unwrap().is_recording());
+        captures.capture_all = Some(Format::PCAPNG);
+        captures.insert(test_capture("capture-all-test"));
+        let capture = captures.get(7).unwrap().lock().unwrap();
+        assert!(capture.file.is_some());
+        assert_eq!(capture.format, Format::PCAPNG);
+        std::fs::remove_file(capture.file_path()).unwrap();
+    }
+
+    #[test]
+    fn test_set_recording() {
+        let mut captures = Captures::new();
+        for id in [1, 2] {
+            let mut capture = test_capture(&format!("group-test-{id}"));
+            capture.id = id;
+            captures.chip_id_to_capture.insert(id, Arc::new(Mutex::new(capture)));
+        }
+        let errors = captures.set_recording(&[1, 2, 3], true, Format::PCAP);
+        assert!(matches!(errors[..], [(3, CaptureError::NotFound(_))]));
+        let capture = |id| captures.chip_id_to_capture[&id].lock().unwrap();
+        assert!(capture(1).is_recording() && capture(2).is_recording());
+        // The btsnoop format is for Bluetooth chips only.
+        capture(2).chip_kind = ChipKind::WIFI;
+        capture(2).stop_capture();
+        let errors = captures.set_recording(&[1, 2], true, Format::BTSNOOP);
+        assert!(matches!(errors[..], [(2, CaptureError::InvalidArgument(_))]));
+        assert_eq!(capture(1).format, Format::PCAP);
+        assert!(captures.set_recording(&[1, 2], false, Format::PCAP).is_empty());
+        assert!(!capture(1).is_recording());
+        for id in [1, 2] {
+            std::fs::remove_file(capture(id).file_path()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let bluetooth = test_capture("aggregate-test");
+        let mut wifi = test_capture("aggregate-test");
+        wifi.id = 8;
+        wifi.kind = ChipKind::WIFI as u32;
+        let mut aggregate = Aggregate::start().unwrap();
+        let direction = PacketDirection::HostToController;
+        aggregate.record(&bluetooth, Duration::ZERO, direction, &[&[1, 2]]).unwrap();
+        aggregate.record(&wifi, Duration::ZERO, direction, &[&[3]]).unwrap();
+        aggregate.record(&bluetooth, Duration::ZERO, direction, &[&[4]]).unwrap();
+        assert_eq!(aggregate.records, 3);
+        // A section header, then an interface on the first packet of each
+        // chip, before its enhanced packet blocks.
+        let bytes = std::fs::read(&aggregate.path).unwrap();
+        assert_eq!(bytes.len(), aggregate.size);
+        let mut blocks = Vec::new();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let field = |at: usize| u32::from_be_bytes(bytes[at..at + 4].try_into().unwrap());
+            let block_type = field(offset);
+            // The interface id of the enhanced packet blocks.
+            let interface = (block_type == 6).then(|| field(offset + 8));
+            blocks.push((block_type, interface));
+            offset += field(offset + 4) as usize;
+        }
+        assert_eq!(
+            blocks,
+            [(0x0a0d0d0a, None), (1, None), (6, Some(0)), (1, None), (6, Some(1)), (6, Some(0))]
+        );
+        std::fs::remove_file(&aggregate.path).unwrap();
+    }
+
+    #[test]
+    fn test_chunk_reader() {
+        let path = std::env::temp_dir().join("netsim-chunk-reader-test");
+        std::fs::write(&path, b"0123456789").unwrap();
+        let read = |offset, length, chunk_len| {
+            let mut reader = ChunkReader::open(&path, 10, offset, length).unwrap();
+            let mut buffer = vec![0u8; chunk_len];
+            let mut chunks = Vec::new();
+            loop {
+                match reader.read_chunk(&mut buffer).unwrap() {
+                    0 => break,
+                    len => chunks.push(String::from_utf8(buffer[..len].to_vec()).unwrap()),
+                }
+            }
+            (reader.length, chunks)
+        };
+        assert_eq!(read(0, 0, 4), (10, vec!["0123".into(), "4567".into(), "89".into()]));
+        assert_eq!(read(3, 4, 3), (4, vec!["345".into(), "6".into()]));
+        // The range is clamped to the file, a resumed complete file is empty.
+        assert_eq!(read(8, 5, 4), (2, vec!["89".into()]));
+        assert_eq!(read(10, 0, 4), (0, vec![]));
+        assert!(ChunkReader::open(&path, 10, 11, 0).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_dir_size() {
+        let dir = std::env::temp_dir().join(format!("netsim-dir-size-test-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("subdir")).unwrap();
+        std::fs::write(dir.join("a.pcap"), [0; 10]).unwrap();
+        std::fs::write(dir.join("b.pcap"), [0; 5]).unwrap();
+        assert_eq!(dir_size(&dir), 15);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(dir_size(&dir), 0);
+        assert_eq!(DirQuotaPolicy::parse("evict"), Some(DirQuotaPolicy::Evict));
+        assert_eq!(DirQuotaPolicy::parse("drop"), None);
+    }
+
+    #[test]
+    fn test_evict_oldest() {
+        let mut captures = Captures::new();
+        for (id, seconds) in [(1, 30), (2, 10), (3, 20)] {
+            let mut capture = test_capture(&format!("evict-test-{id}"));
+            capture.id = id;
+            capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+            // The capture 3 keeps recording.
+            if id != 3 {
+                capture.stop_capture();
+            }
+            capture.seconds = seconds;
+            captures.chip_id_to_capture.insert(id, Arc::new(Mutex::new(capture)));
+        }
+        // Each file holds the 24 bytes of the pcap header; the oldest
+        // stopped capture is evicted first.
+        assert_eq!(captures.evict_oldest(0, 72, 50), 48);
+        let capture = |id| captures.chip_id_to_capture[&id].lock().unwrap();
+        assert_eq!((capture(1).size, capture(2).size, capture(3).size), (24, 0, 24));
+        assert!(!capture(2).file_path().exists());
+        // Neither the kept capture nor the recording one are evicted.
+        assert_eq!(captures.evict_oldest(1, 48, 0), 48);
+        assert_eq!(captures.evict_oldest(0, 48, 0), 24);
+        assert!(!capture(1).file_path().exists());
+        std::fs::remove_file(capture(3).file_path()).unwrap();
+    }
+
+    #[test]
+    fn test_fail() {
+        let mut capture = test_capture("fail-test");
+        capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+        capture.fail("quota".to_string());
+        assert!(!capture.is_recording() && !capture.valid);
+        assert_eq!(capture.get_capture_proto().error, "quota");
+        // The capture is valid again when it starts again.
+        capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+        assert!(capture.valid && capture.error.is_empty());
+        std::fs::remove_file(capture.file_path()).unwrap();
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut capture = test_capture("rotate-test");
+        capture.start_capture(Format::PCAP, Mode::FILE).unwrap();
+        capture.records = 3;
+        capture.rotate().unwrap();
+        capture.rotate().unwrap();
+        assert_eq!(capture.segments.len(), 2);
+        let segment = &capture.segments[1];
+        assert_eq!((segment.index, segment.size, segment.records), (2, 24, 0));
+        assert!(segment.path.to_str().unwrap().ends_with("-BLUETOOTH.2.pcap"));
+        assert_eq!(std::fs::read(&segment.path).unwrap().len(), 24);
+        // The capture continues in a new file, counted with its segments.
+        assert_eq!((capture.size, capture.records), (24, 0));
+        assert_eq!((capture.total_size(), capture.total_records()), (72, 3));
+        let paths: Vec<PathBuf> = capture.segments.iter().map(|s| s.path.clone()).collect();
+        capture.delete_segments();
+        assert!(paths.iter().all(|path| !path.exists()));
+        std::fs::remove_file(capture.file_path()).unwrap();
+        // A stopped capture doesn't rotate.
+        capture.stop_capture();
+        capture.rotate().unwrap();
+        assert!(capture.segments.is_empty());
+    }
+
+    #[test]
+    fn test_rotation_policy() {
+        let minute = Duration::from_secs(60);
+        let by_size = Rotation { max_size: 100, interval: Duration::ZERO, max_age: Duration::ZERO };
+        assert!(by_size.is_due(100, Duration::ZERO));
+        assert!(!by_size.is_due(99, 1000 * minute));
+        assert!(!by_size.is_expired(Duration::ZERO, 1000 * minute));
+        let by_time = Rotation { max_size: 0, interval: 5 * minute, max_age: 60 * minute };
+        assert!(!by_time.is_due(1 << 30, 4 * minute));
+        assert!(by_time.is_due(0, 5 * minute));
+        assert!(!by_time.is_expired(10 * minute, 69 * minute));
+        assert!(by_time.is_expired(10 * minute, 70 * minute));
+    }
+
+    #[test]
+    fn test_hashing_writer() {
+        let path =
+            std::env::temp_dir().join(format!("netsim-hash-test-{}.pcap", std::process::id()));
+        let mut file = File::create(&path).unwrap();
+        let mut sha256 = Sha256::new();
+        let mut output = HashingWriter { file: &mut file, sha256: &mut sha256 };
+        write_pcap_header(&mut output, LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR, 0).unwrap();
+        output.write_all(&[1, 2, 3]).unwrap();
+        // The rolling digest matches the digest of the file.
+        let mut expected = Sha256::new();
+        expected.update(&std::fs::read(&path).unwrap());
+        assert_eq!(sha256.hex(), expected.hex());
+        std::fs::remove_file(&path).unwrap();
+    }
 }
@@ -0,0 +1,94 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Errors of the captures.
+//!
+//! The operations of the captures fail with a CaptureError. The handlers
+//! reply with the HTTP status of the error, which the gRPC frontend maps to
+//! the status code of the RPC.
+
+use std::fmt;
+use std::io::Error;
+
+/// Why an operation of a capture failed.
+#[derive(Debug)]
+pub enum CaptureError {
+    /// A file of the capture failed.
+    IoError(Error),
+    /// The capture directory can't be written.
+    DirectoryNotWritable(String),
+    /// The capture, or a resource it needs, is used by a running capture.
+    AlreadyRunning(String),
+    /// No capture, segment or file matches the request.
+    NotFound(String),
+    /// The capture directory exceeds its quota of bytes.
+    QuotaExceeded(u64),
+    /// The request is invalid for the capture.
+    InvalidArgument(String),
+    /// The capture is not in the state the request requires.
+    InvalidState(String),
+}
+
+impl CaptureError {
+    /// The HTTP status of the replies failing with the error.
+    pub fn status(&self) -> u16 {
+        match self {
+            CaptureError::IoError(_) => 500,
+            CaptureError::DirectoryNotWritable(_) | CaptureError::InvalidState(_) => 412,
+            CaptureError::AlreadyRunning(_) => 409,
+            CaptureError::NotFound(_) => 404,
+            CaptureError::QuotaExceeded(_) => 507,
+            CaptureError::InvalidArgument(_) => 400,
+        }
+    }
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CaptureError::IoError(err) => write!(f, "{err}"),
+            CaptureError::QuotaExceeded(max_size) => {
+                write!(f, "the capture directory exceeds its quota of {max_size} bytes")
+            }
+            CaptureError::DirectoryNotWritable(message)
+            | CaptureError::AlreadyRunning(message)
+            | CaptureError::NotFound(message)
+            | CaptureError::InvalidArgument(message)
+            | CaptureError::InvalidState(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+impl From<Error> for CaptureError {
+    fn from(err: Error) -> Self {
+        CaptureError::IoError(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_error() {
+        let err = CaptureError::from(Error::other("disk full"));
+        assert_eq!((err.status(), err.to_string().as_str()), (500, "disk full"));
+        let err = CaptureError::QuotaExceeded(1024);
+        assert_eq!(err.status(), 507);
+        assert_eq!(err.to_string(), "the capture directory exceeds its quota of 1024 bytes");
+        assert_eq!(CaptureError::NotFound("no capture".to_string()).status(), 404);
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Capture filters.
+//!
+//! The filter of a capture, set by PatchCapture, selects the packets
+//! written to its file by packet type, direction, length and a byte
+//! pattern. The filter applies before the sampling of the capture.
+
+use frontend_proto::model::capture::filter::Direction;
+use frontend_proto::model::capture::Filter;
+
+use super::pcap_util::PacketDirection;
+
+/// Checks that the conditions of a filter can match a packet.
+pub fn validate(filter: &Filter) -> Result<(), String> {
+    if filter.max_length != 0 && filter.min_length > filter.max_length {
+        return Err(format!(
+            "min_length {} is above max_length {}",
+            filter.min_length, filter.max_length
+        ));
+    }
+    if filter.anywhere && filter.pattern.is_empty() {
+        return Err("anywhere without a pattern".to_string());
+    }
+    Ok(())
+}
+
+// Returns true when the packet holds the pattern of the filter.
+fn matches_pattern(filter: &Filter, packet: &[u8]) -> bool {
+    let pattern = filter.pattern.as_slice();
+    if pattern.is_empty() {
+        return true;
+    }
+    if filter.anywhere {
+        return packet.windows(pattern.len()).any(|window| window == pattern);
+    }
+    let offset = filter.offset as usize;
+    packet.get(offset..offset.saturating_add(pattern.len())) == Some(pattern)
+}
+
+/// Returns true when a packet matches every condition of a filter.
+pub fn matches(
+    filter: &Filter,
+    direction: PacketDirection,
+    packet_type: u32,
+    packet: &[u8],
+) -> bool {
+    let direction_matches = match filter.direction.enum_value_or_default() {
+        Direction::ANY => true,
+        Direction::HOST_TO_CONTROLLER => direction == PacketDirection::HostToController,
+        Direction::CONTROLLER_TO_HOST => direction == PacketDirection::ControllerToHost,
+    };
+    let length = packet.len() as u32;
+    direction_matches
+        && (filter.packet_types.is_empty() || filter.packet_types.contains(&packet_type))
+        && length >= filter.min_length
+        && (filter.max_length == 0 || length <= filter.max_length)
+        && matches_pattern(filter, packet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOST: PacketDirection = PacketDirection::HostToController;
+    const CONTROLLER: PacketDirection = PacketDirection::ControllerToHost;
+
+    #[test]
+    fn test_empty_filter() {
+        let filter = Filter::default();
+        assert!(matches(&filter, HOST, 1, &[]));
+        assert!(matches(&filter, CONTROLLER, 4, &[0x0e, 0x04]));
+        assert!(validate(&filter).is_ok());
+    }
+
+    #[test]
+    fn test_conditions() {
+        let filter = Filter {
+            packet_types: vec![2, 4],
+            direction: Direction::CONTROLLER_TO_HOST.into(),
+            min_length: 2,
+            max_length: 4,
+            ..Default::default()
+        };
+        assert!(matches(&filter, CONTROLLER, 4, &[0x0e, 0x04]));
+        assert!(!matches(&filter, HOST, 4, &[0x0e, 0x04]));
+        assert!(!matches(&filter, CONTROLLER, 1, &[0x0e, 0x04]));
+        assert!(!matches(&filter, CONTROLLER, 2, &[0x0e]));
+        assert!(!matches(&filter, CONTROLLER, 2, &[0; 5]));
+        let filter = Filter { min_length: 5, max_length: 4, ..Default::default() };
+        assert!(validate(&filter).is_err());
+    }
+
+    #[test]
+    fn test_pattern() {
+        let at_offset = Filter { pattern: vec![0x0e, 0x04], offset: 1, ..Default::default() };
+        assert!(matches(&at_offset, HOST, 4, &[0x00, 0x0e, 0x04, 0x01]));
+        assert!(!matches(&at_offset, HOST, 4, &[0x0e, 0x04, 0x01]));
+        assert!(!matches(&at_offset, HOST, 4, &[0x00, 0x0e]));
+        let anywhere = Filter { anywhere: true, ..at_offset };
+        assert!(matches(&anywhere, HOST, 4, &[0x01, 0x02, 0x0e, 0x04]));
+        assert!(!matches(&anywhere, HOST, 4, &[0x0e, 0x01, 0x04]));
+        assert!(validate(&Filter { anywhere: true, ..Default::default() }).is_err());
+    }
+}
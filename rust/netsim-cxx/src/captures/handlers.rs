@@ -16,40 +16,162 @@
 //!
 //! This module implements a handler for GET, PATCH, LIST capture
 //!
-//! /v1/captures --> handle_capture_list
-//! /v1/captures/{id} --> handle_capture_patch, handle_capture_get
+//! /v1/captures --> handle_capture_list, with the filters and the page of
+//! a ListCaptureRequest as JSON body or query parameters, see listing.rs
+//! /v1/captures/{id} --> handle_capture_patch, handle_capture_get, handle_capture_delete
 //! handle_capture_cxx calls handle_capture, which calls handle_capture_* based on uri
 //! handle_packet_request and handle_packet_response is invoked by packet_hub
-//! to write packets to files if capture state is on.
+//! to write packets to files if capture state is on, with the nanosecond
+//! time the packets crossed packet_hub: the pcap files have the nanosecond
+//! magic number and the pcapng interfaces a nanosecond if_tsresol, for the
+//! latency analysis of HCI. btsnoop files stay in microseconds, the only
+//! resolution of the format. A capture stops when
+//! it reaches the capture.max_size or capture.max_records quota.
+//! With the capture.rotate_size and capture.rotate_interval_mins settings a
+//! capture file is closed as a segment of the capture when it reaches the
+//! size or the age, and the capture continues in a new file. Segments are
+//! deleted capture.segment_retention_mins after they are closed:
+//! /v1/captures/{id}/segments/{index} --> handle_capture_get
+//!
+//! A capture is recorded in the pcap, pcapng or btsnoop (Bluetooth chips
+//! only) format of the PatchCapture starting it, and downloaded with the
+//! MIME type of its format. The link type of the file is the link type of
+//! the chip kind: the H4 records of Bluetooth, the 802.11 frames of WiFi
+//! with a radiotap header holding their channel and the simulated signal
+//! and rate of the received frames, the UCI packets of UWB, and the packets
+//! of custom chip kinds as they are.
+//!
+//! In the RING mode of the PatchCapture, the capture records the latest
+//! packets in a ring buffer in memory, written to the capture file by a
+//! PatchCapture with dump, as a flight recorder of intermittent bugs. Each
+//! record carries the direction of its packet, host to controller or
+//! controller to host: in the H4 pseudo header of the Bluetooth records and
+//! in the epb_flags of the pcapng records. The pcap records of the other
+//! chip kinds have no room for it, their captures need pcapng to keep it.
+//! The filter of the PatchCapture selects the recorded packets.
+//!
+//! A download holds the lock of its capture only while it opens the file,
+//! then sends the file without it: as a task of the runtime for the HTTP
+//! downloads, see http_server/stream.rs, and until the client cancels it or
+//! its deadline passes for the gRPC calls. A download gets a range of the file, from the offset and the length of
+//! the GetCaptureRequest or from an HTTP Range header, read in chunks by a
+//! ChunkReader, so clients resume interrupted downloads and tail large
+//! files. The Range of an HTTP request gets a 206 Partial Content, or a
+//! 416 from the end of the file.
+//!
+//! The records are written to the files by a writer task per capture,
+//! flushed every 100 ms and before the files are read, so the packet
+//! handlers don't block on the disk. As netsimd exits, on SIGTERM or after
+//! its inactivity, shutdown_captures stops the running captures with their
+//! files synced, a pcapng file ending with its interface statistics.
+//!
+//! The pcap files are hashed as they are written; the SHA-256 is listed
+//! with the captures and sent with the downloads in the X-Netsim-Sha256
+//! header, so clients can verify a transferred file.
+//!
+//! With netsimd --capture-all or a PATCH of /v1/captures with a CaptureAll
+//! (the SetCaptureAll RPC) the capture of each chip starts as the chip is
+//! added, so the packets of the boot of a device are recorded.
+//!
+//! With the capture.compress setting the file of a capture is compressed
+//! to .gz in the background when the capture stops, and downloaded
+//! compressed once the compression completes.
+//!
+//! A PATCH of /v1/captures/aggregate with an AggregateCapture (the
+//! PatchAggregateCapture RPC) starts or stops the aggregate capture,
+//! recording the packets of every chip into a single pcapng file with an
+//! interface per chip --> handle_capture_aggregate
+//!
+//! With the capture.dir_max_size setting the files of the capture directory
+//! have a quota: a capture writing past it stops, invalid with the error,
+//! or with the capture.dir_quota_policy "evict" the files of the oldest
+//! stopped captures are deleted first.
+//!
+//! The captures record their lifecycle in the event history: started,
+//! stopped, rotated, removed, and the errors, e.g. a capture failing to
+//! write its file, which stops it.
+//!
+//! The captures count the bytes and records of the latest 10 seconds, listed
+//! as their throughput so users tell whether the traffic still flows:
+//! /v1/captures/stats --> handle_capture_stats (the GetCaptureStats RPC)
+//!
+//! A capture started with the append of its PatchCapture continues the file
+//! of its stopped capture instead of replacing it.
+//!
+//! A capture started with the live_path of its PatchCapture also writes
+//! its records to that named pipe, for Wireshark to show them live, see
+//! live.rs.
+//!
+//! The StreamCapture RPC subscribes to a capture with handle_capture_stream
+//! and receives the records as the packet handlers write them, through the
+//! broadcast of the capture. Over HTTP, a GET of /v1/captures/{id}/stream
+//! streams them as a task of the tokio runtime, see http_server/stream.rs.
+//!
+//! A POST of /v1/captures/replay with a ReplayCaptureRequest (the
+//! ReplayCapture RPC) replays the packets of the host recorded in a pcap or
+//! btsnoop file to a Bluetooth chip --> handle_capture_replay
+//!
+//! The failed requests reply with the HTTP status of their CaptureError,
+//! mapped to the status code of the gRPC calls, see error.rs.
 
 // TODO(b/274506882): Implement gRPC status proto on error responses. Also write better
 // and more descriptive error messages with proper error codes.
 
 use cxx::CxxVector;
 use frontend_proto::common::ChipKind;
-use frontend_proto::frontend::{GetDevicesResponse, ListCaptureResponse};
+use frontend_proto::frontend::event::Type;
+use frontend_proto::frontend::patch_capture_request::PatchCapture;
+use frontend_proto::frontend::{
+    AggregateCapture, CaptureAll, GetCaptureRequest, GetCaptureStatsResponse, GetDevicesResponse,
+    ListCaptureRequest, ReplayCaptureRequest,
+};
+use frontend_proto::model::capture::{Format, Mode, QuotaStatus};
+use frontend_proto::model::State;
 use lazy_static::lazy_static;
 use netsim_common::util::time_display::TimeDisplay;
 use protobuf::Message;
-use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use protobuf_json_mapping::{parse_from_str, print_to_string_with_options, PrintOptions};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{Read, Result};
+use std::io::ErrorKind;
+use std::path::Path;
 use std::pin::Pin;
-use std::sync::RwLock;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
 
-use crate::captures::capture::{Captures, ChipId};
+use crate::captures::capture::{
+    is_default_pcap_dir, pcap_dir, Aggregate, Captures, ChipId, ChunkReader, FacadeId,
+};
+use crate::chip_kinds::is_custom_chip_kind;
+use crate::clock::now;
+use crate::config::get_setting_parsed;
 use crate::ffi::{get_devices_bytes, CxxServerResponseWriter};
 use crate::http_server::http_request::{HttpHeaders, HttpRequest};
 use crate::http_server::server_response::ResponseWritable;
+use crate::listing::{list_captures, list_request};
+use crate::metrics::{register_collector, MetricKind, Sample};
+use crate::runtime::runtime;
+use crate::wifi::frame::parse_hwsim;
 use crate::CxxServerResponseWriterWrapper;
 
-use super::capture::CaptureInfo;
-use super::pcap_util::{append_record, PacketDirection};
-use super::PCAP_MIME_TYPE;
+use super::capture::{
+    dir_size, update_mask_fields, CaptureInfo, DirQuota, DirQuotaPolicy, Quota, Retention,
+    Rotation, Sampler, Subscription,
+};
+use super::error::CaptureError;
+use super::filter::{matches, validate};
+use super::live::live_dropped;
+use super::pcap_util::{h4_header, PacketDirection, Radiotap};
+use super::replay::replay_capture;
+use super::writer::bytes_written;
+use super::GZIP_MIME_TYPE;
+use crate::logger::{error, info, warn};
 
 const CHUNK_LEN: usize = 1_048_576;
+/// Interval of the checks for a cancelled capture stream.
+const STREAM_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Header of the SHA-256 of a downloaded pcap file, in hex.
+const SHA256_HEADER: &str = "X-Netsim-Sha256";
 const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
     enum_values_int: false,
     proto_field_name: false,
@@ -59,136 +181,493 @@ const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
 
 // The Capture resource is a singleton that manages all captures
 lazy_static! {
-    static ref RESOURCE: RwLock<Captures> = RwLock::new(Captures::new());
+    static ref RESOURCE: RwLock<Captures> = {
+        register_collector(collect_metrics);
+        RwLock::new(Captures::new())
+    };
+    static ref QUOTA: Quota = Quota::from_settings();
+    static ref RETENTION: Retention = Retention::from_settings();
+    static ref ROTATION: Rotation = Rotation::from_settings();
+    static ref COMPRESS: bool = get_setting_parsed("capture.compress");
+    static ref DIR_QUOTA: DirQuota = DirQuota::from_settings();
+    // Time of the latest check of the quota of the capture directory.
+    static ref DIR_CHECKED_AT: Mutex<Option<Duration>> = Mutex::new(None);
 }
 
-// Update the Captures collection to reflect the currently connected devices.
-// This function removes entries from Captures when devices/chips
-// go away and adds entries when new devices/chips connect.
-//
-// Note: if a device disconnects and there is captured data, the entry
-// remains with a flag valid = false so it can be retrieved.
-fn update_captures(captures: &mut Captures) {
+// Changes of the capture of a chip gone from the scene:
+// 1. The device had no capture, or removed captures are not kept,
+//    remove completely.
+// 2. The device had capture, finalize it and indicate by valid = false.
+// 3. The capture was finalized before, remove it with its file once expired.
+enum RemovalIndicator {
+    Gone(ChipId),    // type ChipId = i32
+    Unused(ChipId),  // type ChipId = i32
+    Expired(ChipId), // type ChipId = i32
+}
+
+// Returns the chips of the scene with their kind and the name of their
+// device, None when the scene is not available.
+fn scene_chips() -> Option<Vec<(ChipId, u32, String)>> {
     // Perform get_devices_bytes ffi to receive bytes of GetDevicesResponse
-    // Print error and return empty hashmap if GetDevicesBytes fails.
     let mut vec = Vec::<u8>::new();
     if !get_devices_bytes(&mut vec) {
-        println!("netsim error: GetDevicesBytes failed - returning an empty set of captures");
-        return;
+        error!("GetDevicesBytes failed - returning an empty set of captures");
+        return None;
     }
-
-    // Parse get_devices_response
     let device_response = GetDevicesResponse::parse_from_bytes(&vec).unwrap();
+    Some(
+        device_response
+            .devices
+            .iter()
+            .flat_map(|device| {
+                device
+                    .chips
+                    .iter()
+                    .map(|chip| (chip.id, chip.kind.value() as u32, device.name.clone()))
+            })
+            .collect(),
+    )
+}
 
-    // Adding to Captures hashmap
-    let mut chip_ids = HashSet::<ChipId>::new();
-    for device in device_response.devices {
-        for chip in device.chips {
-            chip_ids.insert(chip.id);
-            if !captures.contains(chip.id) {
-                let capture = CaptureInfo::new(
-                    chip.kind.enum_value_or_default(),
-                    chip.id,
-                    device.name.clone(),
-                );
-                captures.insert(capture);
+// Returns the changes of the captures of the chips gone from the scene.
+fn removals(
+    captures: &Captures,
+    chip_ids: &HashSet<ChipId>,
+    time: Duration,
+) -> Vec<RemovalIndicator> {
+    let mut removal = Vec::<RemovalIndicator>::new();
+    for (chip_id, capture) in captures.iter() {
+        if chip_ids.contains(chip_id) {
+            continue;
+        }
+        let lock = capture.lock().unwrap();
+        if let Some(removed_at) = lock.removed_at {
+            if RETENTION.is_expired(removed_at, time) {
+                removal.push(RemovalIndicator::Expired(chip_id.to_owned()));
             }
+        } else if (lock.size == 0 && lock.ring.is_none()) || !RETENTION.keep_removed {
+            removal.push(RemovalIndicator::Unused(chip_id.to_owned()));
+        } else {
+            removal.push(RemovalIndicator::Gone(chip_id.to_owned()))
         }
     }
+    removal
+}
 
-    // Two cases when device gets disconnected:
-    // 1. The device had no capture, remove completely.
-    // 2. The device had capture, indicate by capture.set_valid(false)
-    enum RemovalIndicator {
-        Gone(ChipId),   // type ChipId = i32
-        Unused(ChipId), // type ChipId = i32
+// Update the Captures collection to reflect the currently connected devices.
+// This function removes entries from Captures when devices/chips
+// go away and adds entries when new devices/chips connect.
+//
+// The scene is read without the lock of the captures, then the captures
+// are checked with the read lock, so the packets keep flowing; the write
+// lock is only taken when chips came or went.
+//
+// Note: if a device disconnects and there is captured data, the entry
+// remains with a flag valid = false and its file closed so it can be
+// retrieved, until it is deleted or expires (capture.keep_removed setting).
+fn update_captures() {
+    let Some(chips) = scene_chips() else {
+        return;
+    };
+    let chip_ids: HashSet<ChipId> = chips.iter().map(|(chip_id, _, _)| *chip_id).collect();
+    let time = now();
+    {
+        let captures = RESOURCE.read().unwrap();
+        // The captures of quiet chips rotate here.
+        for capture in captures.values() {
+            rotate_capture(&mut capture.lock().unwrap(), time);
+        }
+        if chip_ids.iter().all(|chip_id| captures.contains(*chip_id))
+            && removals(&captures, &chip_ids, time).is_empty()
+        {
+            return;
+        }
     }
 
-    // Check if the active_capture entry still exists in the chips.
-    let mut removal = Vec::<RemovalIndicator>::new();
-    for (chip_id, capture) in captures.iter() {
-        let lock = capture.lock().unwrap();
-        let proto_capture = lock.get_capture_proto();
-        if !chip_ids.contains(chip_id) {
-            if proto_capture.size == 0 {
-                removal.push(RemovalIndicator::Unused(chip_id.to_owned()));
-            } else {
-                removal.push(RemovalIndicator::Gone(chip_id.to_owned()))
-            }
+    let mut captures = RESOURCE.write().unwrap();
+    for (chip_id, kind, device_name) in chips {
+        if !captures.contains(chip_id) {
+            captures.insert(CaptureInfo::new(kind, chip_id, device_name));
         }
     }
-
-    // Now remove/update the captures based on the loop above
-    for indicator in removal {
+    // The captures are checked again, they may have changed without the
+    // lock.
+    for indicator in removals(&captures, &chip_ids, time) {
         match indicator {
-            RemovalIndicator::Unused(key) => captures.remove(&key),
+            RemovalIndicator::Unused(key) => {
+                if let Err(err) = captures.remove(&key) {
+                    warn!(chip_id: key; "{err}");
+                }
+            }
             RemovalIndicator::Gone(key) => {
-                for capture in captures.get(key).iter() {
-                    capture.lock().unwrap().valid = false;
+                captures.finalize(&key, time);
+                if let Some(capture) = captures.get(key) {
+                    compress_capture(&capture.lock().unwrap());
+                }
+            }
+            RemovalIndicator::Expired(key) => {
+                if let Err(err) = captures.delete(&key) {
+                    warn!(chip_id: key; "{err}");
                 }
             }
         }
     }
 }
 
-// Helper function for getting file name from the given fields.
-fn get_file(id: ChipId, device_name: String, chip_kind: ChipKind) -> Result<File> {
-    let mut filename = std::env::temp_dir();
-    filename.push("netsim-pcaps");
-    filename.push(format!("{:?}-{:}-{:?}.pcap", id, device_name, chip_kind));
-    File::open(filename)
+// Compresses the file of a stopped capture in the background with the
+// capture.compress setting. The capture is downloaded uncompressed until
+// the compressed file replaces its file.
+fn compress_capture(capture: &CaptureInfo) {
+    if !*COMPRESS {
+        return;
+    }
+    let Some(compression) = capture.compression() else {
+        return;
+    };
+    let result = thread::Builder::new().name("capture_gzip".to_string()).spawn(move || {
+        let result = compression.run().and_then(|(size, sha256)| {
+            let captures = RESOURCE.read().unwrap();
+            match captures.chip_id_to_capture.get(&compression.id) {
+                Some(capture) => {
+                    capture.lock().unwrap().finish_compression(&compression, size, sha256)
+                }
+                None => {
+                    compression.discard();
+                    Ok(())
+                }
+            }
+        });
+        if let Err(err) = result {
+            compression.discard();
+            info!("failed to compress capture {}: {err}", compression.id);
+        }
+    });
+    if let Err(err) = result {
+        info!("failed to start the compression of a capture: {err}");
+    }
 }
 
 // TODO: GetCapture should return the information of the capture. Need to reconsider
 // uri hierarchy.
+const INCORRECT_ID: &str = "Incorrect ID type for capture, ID should be i32.";
+const INCORRECT_STATE: &str = "Incorrect state for PatchCapture";
+
+fn not_found(message: impl Into<String>) -> CaptureError {
+    CaptureError::NotFound(message.into())
+}
+
+fn invalid(message: impl Into<String>) -> CaptureError {
+    CaptureError::InvalidArgument(message.into())
+}
+
+// Replies with the HTTP status and the message of a capture error.
+fn put_capture_error(writer: ResponseWritable, err: CaptureError) {
+    writer.put_error(err.status(), err.to_string().as_str());
+}
+
+/// The bytes of a capture to get, the whole file by default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ByteRange {
+    pub offset: u64,
+    /// 0 for the bytes to the end of the file.
+    pub length: u64,
+    /// Set for the Range header of an HTTP request, answered with a 206
+    /// Partial Content, or a 416 from the end of the file.
+    pub header: bool,
+}
+
+/// A download of a capture file, prepared holding the lock of the capture
+/// and sent without it: the open file keeps its bytes readable while the
+/// capture goes on, rotates or restarts.
+pub struct Download {
+    mime_type: &'static str,
+    headers: Vec<(&'static str, String)>,
+    // A Partial Content answering a Range header.
+    partial: bool,
+    pub reader: ChunkReader,
+}
+
+impl Download {
+    /// Writes the status and the headers of the response.
+    pub fn put_head(&self, writer: ResponseWritable) {
+        let headers: Vec<(&str, &str)> =
+            self.headers.iter().map(|(name, value)| (*name, value.as_str())).collect();
+        let length = self.reader.length as usize;
+        match self.partial {
+            true => writer.put_partial_with_length(self.mime_type, length, &headers),
+            false => writer.put_ok_with_length(self.mime_type, length, &headers),
+        }
+    }
+}
+
+/// Why a capture download fails.
+pub enum DownloadError {
+    Capture(CaptureError),
+    /// The range starts at the end of the file of this size, or past it.
+    RangeNotSatisfiable(u64),
+}
+
+impl DownloadError {
+    /// Replies with the HTTP status of the error.
+    pub fn put(self, writer: ResponseWritable) {
+        match self {
+            DownloadError::Capture(err) => put_capture_error(writer, err),
+            DownloadError::RangeNotSatisfiable(size) => writer.put_range_not_satisfiable(size),
+        }
+    }
+}
+
+impl From<CaptureError> for DownloadError {
+    fn from(err: CaptureError) -> Self {
+        DownloadError::Capture(err)
+    }
+}
+
+/// Prepares the download of the file of a capture, or of a rotated
+/// segment of it: flushes the queued records and opens the range of the
+/// file.
+pub fn prepare_download(
+    capture: Option<&Mutex<CaptureInfo>>,
+    id: ChipId,
+    segment: u32,
+    range: ByteRange,
+) -> Result<Download, DownloadError> {
+    let ByteRange { offset, length, header } = range;
+    let capture = capture.ok_or(not_found("Cannot access Capture Resource"))?.lock().unwrap();
+    // The current file, or a rotated segment named after its index.
+    let (path, size, sha256, suffix) = match segment {
+        0 => (capture.download_path(), capture.size, capture.sha256.hex(), String::new()),
+        index => match capture.segments.iter().find(|segment| segment.index == index) {
+            Some(segment) => {
+                (segment.path.clone(), segment.size, segment.sha256.clone(), format!(".{index}"))
+            }
+            None => return Err(not_found("Capture segment not found").into()),
+        },
+    };
+    if size == 0 {
+        return Err(not_found("Capture file not found").into());
+    }
+    // The file of a running capture holds its queued records once flushed.
+    if segment == 0 {
+        capture.flush().map_err(CaptureError::IoError)?;
+    }
+    let reader = match ChunkReader::open(&path, size as u64, offset, length) {
+        Ok(reader) => reader,
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            return Err(not_found("Cannot open Capture file").into())
+        }
+        // The range is past the end of the file.
+        Err(_) => return Err(DownloadError::RangeNotSatisfiable(size as u64)),
+    };
+    // A Range from the end of the file has no byte to send.
+    if header && reader.length == 0 {
+        return Err(DownloadError::RangeNotSatisfiable(size as u64));
+    }
+    let time_display = TimeDisplay::new(capture.seconds, capture.nanos as u32);
+    let compressed = segment == 0 && capture.compressed;
+    let disposition = format!(
+        "attachment; filename=\"{:?}-{:}-{:?}-{}{}.{}{}\"",
+        id,
+        capture.device_name.clone(),
+        capture.chip_kind,
+        time_display.utc_display(),
+        suffix,
+        capture.extension(),
+        if compressed { ".gz" } else { "" }
+    );
+    let mut headers = vec![("Content-Disposition", disposition), (SHA256_HEADER, sha256)];
+    // The range of a resumed or tailed download, within the size of the
+    // whole file.
+    if header || offset > 0 || length > 0 {
+        let content_range = match reader.length {
+            0 => format!("bytes */{size}"),
+            length => format!("bytes {offset}-{}/{size}", offset + length - 1),
+        };
+        headers.push(("Content-Range", content_range));
+    }
+    let mime_type = if compressed { GZIP_MIME_TYPE } else { capture.mime_type() };
+    Ok(Download { mime_type, headers, partial: header, reader })
+}
+
 // GET /captures/id/{id} --> Get Capture information
 // GET /captures/contents/{id} --> Download Pcap file
-pub fn handle_capture_get(writer: ResponseWritable, captures: &mut Captures, id: ChipId) {
-    // Get the most updated active captures
-    update_captures(captures);
-
-    if let Some(capture) = captures.get(id).map(|arc_capture| arc_capture.lock().unwrap()) {
-        if capture.size == 0 {
-            writer.put_error(404, "Capture file not found");
-        } else if let Ok(mut file) = get_file(id, capture.device_name.clone(), capture.chip_kind) {
-            let mut buffer = [0u8; CHUNK_LEN];
-            let time_display = TimeDisplay::new(capture.seconds, capture.nanos as u32);
-            let header_value = format!(
-                "attachment; filename=\"{:?}-{:}-{:?}-{}.pcap\"",
-                id,
-                capture.device_name.clone(),
-                capture.chip_kind,
-                time_display.utc_display()
-            );
-            writer.put_ok_with_length(
-                PCAP_MIME_TYPE,
-                capture.size,
-                &[("Content-Disposition", header_value.as_str())],
-            );
-            loop {
-                match file.read(&mut buffer) {
-                    Ok(0) => break,
-                    Ok(length) => writer.put_chunk(&buffer[..length]),
-                    Err(_) => writer.put_error(404, "Error reading pcap file"),
-                }
+// GET /captures/{id}/segments/{index} --> Download a rotated segment
+pub fn handle_capture_get(
+    writer: ResponseWritable,
+    capture: Option<Arc<Mutex<CaptureInfo>>>,
+    id: ChipId,
+    segment: u32,
+    range: ByteRange,
+) {
+    match prepare_download(capture.as_deref(), id, segment, range) {
+        Ok(download) => send_download(writer, download),
+        Err(err) => err.put(writer),
+    }
+}
+
+// Sends a download on the calling thread, for the gRPC calls.
+fn send_download(writer: ResponseWritable, mut download: Download) {
+    download.put_head(writer);
+    let mut buffer = vec![0u8; CHUNK_LEN];
+    // A cancelled download stops at the next chunk.
+    while !writer.is_cancelled() {
+        match download.reader.read_chunk(&mut buffer) {
+            Ok(0) => break,
+            Ok(length) => writer.put_chunk(&buffer[..length]),
+            Err(err) => {
+                put_capture_error(writer, CaptureError::IoError(err));
+                break;
             }
-        } else {
-            writer.put_error(404, "Cannot open Capture file");
         }
-    } else {
-        writer.put_error(404, "Cannot access Capture Resource")
     }
 }
 
-pub fn handle_capture_list(writer: ResponseWritable, captures: &mut Captures) {
+/// Prepares the download of a GET of /v1/captures/{id} or of
+/// /v1/captures/{id}/segments/{index}, `param` being the part of the path
+/// after /v1/captures/.
+pub fn prepare_capture_get(request: &HttpRequest, param: &str) -> Result<Download, DownloadError> {
+    let (id, segment) = param.split_once("/segments/").unwrap_or((param, "0"));
+    let id = id.parse::<i32>().map_err(|_| invalid(INCORRECT_ID))?;
+    let segment =
+        segment.parse::<u32>().map_err(|_| invalid("Incorrect segment index for capture."))?;
+    let range = get_range(request).map_err(invalid)?;
     // Get the most updated active captures
-    update_captures(captures);
+    update_captures();
+    let capture = RESOURCE.read().unwrap().get(id).cloned();
+    prepare_download(capture.as_deref(), id, segment, range)
+}
 
-    // Instantiate ListCaptureResponse and add Captures
-    let mut response = ListCaptureResponse::new();
-    for capture in captures.values() {
-        response.captures.push(capture.lock().unwrap().get_capture_proto());
+// Returns the offset and the length of the bytes of a capture to get: from
+// the GetCaptureRequest in the body of a gRPC call, or from the Range header
+// of an HTTP request, bytes=first-last or bytes=first-.
+fn get_range(request: &HttpRequest) -> Result<ByteRange, String> {
+    if !request.body.is_empty() {
+        let json = std::str::from_utf8(&request.body).map_err(|e| e.to_string())?;
+        let get = parse_from_str::<GetCaptureRequest>(json).map_err(|e| e.to_string())?;
+        return Ok(ByteRange { offset: get.offset, length: get.length, header: false });
+    }
+    let Some(range) = request.headers.get("Range") else {
+        return Ok(ByteRange::default());
+    };
+    let invalid = || format!("Incorrect Range: {range}");
+    let (first, last) =
+        range.trim().strip_prefix("bytes=").and_then(|r| r.split_once('-')).ok_or_else(invalid)?;
+    let first = first.trim().parse::<u64>().map_err(|_| invalid())?;
+    let length = match last.trim() {
+        "" => 0,
+        last => match last.parse::<u64>() {
+            Ok(last) if last >= first => last - first + 1,
+            _ => return Err(invalid()),
+        },
+    };
+    Ok(ByteRange { offset: first, length, header: true })
+}
+
+/// Subscribes to the records of a capture as they are written.
+pub fn subscribe_capture(id: ChipId) -> Result<Subscription, CaptureError> {
+    update_captures();
+    let subscription = RESOURCE.read().unwrap().subscribe(id);
+    subscription.ok_or_else(|| not_found("Cannot access Capture Resource"))
+}
+
+/// Streams the records of a capture as they are written, after the header
+/// of its format, until the client cancels the stream or the capture ends.
+/// The gRPC threads block on the tokio channel of the subscription.
+pub fn handle_capture_stream(writer: ResponseWritable, id: ChipId) {
+    let mut subscription = match subscribe_capture(id) {
+        Ok(subscription) => subscription,
+        Err(err) => {
+            put_capture_error(writer, err);
+            return;
+        }
+    };
+    writer.put_ok_with_length(subscription.mime_type, 0, &[]);
+    writer.put_chunk(&subscription.header);
+    while !writer.is_cancelled() {
+        let next = tokio::time::timeout(STREAM_POLL_INTERVAL, subscription.records.recv());
+        match runtime().block_on(next) {
+            Ok(Some(record)) => writer.put_chunk(&record),
+            Ok(None) => break,
+            Err(_) => continue,
+        }
     }
+}
+
+/// Returns the number of captures recording packets.
+pub fn recording_captures() -> usize {
+    let captures = RESOURCE.read().unwrap();
+    captures.values().filter(|capture| capture.lock().unwrap().is_recording()).count()
+}
+
+fn collect_metrics() -> Vec<Sample> {
+    vec![
+        Sample {
+            name: "netsim_capture_bytes_written_total",
+            help: "Bytes written to the capture files.",
+            kind: MetricKind::Counter,
+            labels: vec![],
+            value: bytes_written() as f64,
+        },
+        Sample {
+            name: "netsim_capture_live_dropped_total",
+            help: "Records of the live captures dropped on a full named pipe.",
+            kind: MetricKind::Counter,
+            labels: vec![],
+            value: live_dropped() as f64,
+        },
+        Sample {
+            name: "netsim_captures_active",
+            help: "Captures recording packets.",
+            kind: MetricKind::Gauge,
+            labels: vec![],
+            value: recording_captures() as f64,
+        },
+    ]
+}
+
+/// Returns the format and the mode of the captures recording packets, by
+/// chip id.
+pub fn recording_capture_modes() -> Vec<(ChipId, Format, Mode)> {
+    let captures = RESOURCE.read().unwrap();
+    captures
+        .iter()
+        .filter_map(|(&id, capture)| {
+            let capture = capture.lock().unwrap();
+            capture.is_recording().then_some((id, capture.format, capture.mode))
+        })
+        .collect()
+}
+
+/// Starts the capture of a chip in `format` and `mode`, e.g. a capture
+/// restored from a scene file.
+pub fn start_chip_capture(chip_id: ChipId, format: Format, mode: Mode) -> Result<(), String> {
+    update_captures();
+    let captures = RESOURCE.read().unwrap();
+    let Some(capture) = captures.get(chip_id) else {
+        return Err(format!("no capture of chip {chip_id}"));
+    };
+    let mut capture = capture.lock().unwrap();
+    capture.start_capture(format, mode).map_err(|err| {
+        capture.record_error(&err);
+        err.to_string()
+    })
+}
+
+pub fn handle_capture_list(
+    writer: ResponseWritable,
+    captures: &Captures,
+    request: &ListCaptureRequest,
+) {
+    let captures =
+        captures.values().map(|capture| capture.lock().unwrap().get_capture_proto()).collect();
+    // Keep the captures selected by the request
+    let response = match list_captures(captures, request) {
+        Ok(response) => response,
+        Err(err) => {
+            writer.put_error(400, err.as_str());
+            return;
+        }
+    };
 
     // Perform protobuf-json-mapping with the given protobuf
     if let Ok(json_response) = print_to_string_with_options(&response, &JSON_PRINT_OPTION) {
@@ -198,24 +677,139 @@ pub fn handle_capture_list(writer: ResponseWritable, captures: &mut Captures) {
     }
 }
 
+pub fn handle_capture_stats(writer: ResponseWritable, captures: &Captures) {
+    let time = now();
+    let mut response = GetCaptureStatsResponse::new();
+    for capture in captures.values() {
+        response.captures.push(capture.lock().unwrap().get_capture_stats(time));
+    }
+    if let Ok(json_response) = print_to_string_with_options(&response, &JSON_PRINT_OPTION) {
+        writer.put_ok("text/json", &json_response, &[])
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure")
+    }
+}
+
+// Replays a capture file to a Bluetooth chip. The captures are only
+// locked to find the chip, the packets of the replay are captured.
+pub fn handle_capture_replay(writer: ResponseWritable, body: &[u8]) {
+    let request = match std::str::from_utf8(body)
+        .map_err(|e| e.to_string())
+        .and_then(|json| parse_from_str::<ReplayCaptureRequest>(json).map_err(|e| e.to_string()))
+    {
+        Ok(request) => request,
+        Err(err) => {
+            put_capture_error(writer, invalid(format!("Incorrect ReplayCaptureRequest: {err}")));
+            return;
+        }
+    };
+    if !request.speed.is_finite() || request.speed < 0.0 {
+        put_capture_error(writer, invalid(format!("Incorrect replay speed {}", request.speed)));
+        return;
+    }
+    let chip = RESOURCE.read().unwrap().get(request.chip_id).map(|capture| {
+        let capture = capture.lock().unwrap();
+        (capture.chip_kind, capture.kind, capture.get_facade_key().1 as u32)
+    });
+    let (kind, facade_id) = match chip {
+        Some((ChipKind::BLUETOOTH, kind, facade_id)) => (kind, facade_id),
+        Some(_) => {
+            put_capture_error(writer, invalid("Only Bluetooth chips replay captures."));
+            return;
+        }
+        None => {
+            put_capture_error(writer, not_found(format!("Chip {} not found.", request.chip_id)));
+            return;
+        }
+    };
+    match replay_capture(&request, kind, facade_id) {
+        Ok(response) => match print_to_string_with_options(&response, &JSON_PRINT_OPTION) {
+            Ok(json_response) => writer.put_ok("text/json", &json_response, &[]),
+            Err(_) => writer.put_error(404, "proto to JSON mapping failure"),
+        },
+        Err(err) => {
+            put_capture_error(writer, invalid(format!("Failed to replay {}: {err}", request.path)))
+        }
+    }
+}
+
+// Patches the state of a capture, with the options of a PatchCapture. A
+// capture patched without a PatchCapture keeps its options.
 pub fn handle_capture_patch(
     writer: ResponseWritable,
-    captures: &mut Captures,
+    captures: &Captures,
     id: ChipId,
     state: bool,
+    patch: Option<PatchCapture>,
 ) {
-    // Get the most updated active captures
-    update_captures(captures);
+    // The records of two captures would interleave in a named pipe.
+    let live_path = patch.as_ref().map_or("", |patch| patch.live_path.as_str());
+    if state
+        && !live_path.is_empty()
+        && captures.chip_id_to_capture.iter().any(|(&other_id, other)| {
+            let other = other.lock().unwrap();
+            other_id != id && other.is_recording() && other.live_path == live_path
+        })
+    {
+        let err = CaptureError::AlreadyRunning("The named pipe is used by another capture".into());
+        put_capture_error(writer, err);
+        return;
+    }
 
     if let Some(mut capture) = captures.get(id).map(|arc_capture| arc_capture.lock().unwrap()) {
-        match state {
-            true => {
-                if let Err(err) = capture.start_capture() {
-                    writer.put_error(404, err.to_string().as_str());
-                    return;
-                }
+        let patch = patch.unwrap_or_else(|| PatchCapture {
+            format: capture.format.into(),
+            mode: capture.mode.into(),
+            snaplen: capture.snaplen,
+            live_path: capture.live_path.clone(),
+            ..Default::default()
+        });
+        if patch.dump && capture.ring.is_none() {
+            let err =
+                CaptureError::InvalidState("Capture is not recording in a ring buffer".into());
+            put_capture_error(writer, err);
+            return;
+        }
+        if let Some(sampling) = patch.sampling.into_option() {
+            capture.sampler = Sampler::new(sampling);
+        }
+        if let Some(filter) = patch.filter.into_option() {
+            if let Err(err) = validate(&filter) {
+                put_capture_error(writer, invalid(format!("Invalid capture filter: {err}")));
+                return;
             }
-            false => capture.stop_capture(),
+            capture.filter = filter;
+        }
+        // A running capture keeps the snapshot length of its header and
+        // its named pipe.
+        if state && !capture.is_recording() {
+            // A resumed capture keeps the snapshot length of its file.
+            if !patch.append {
+                capture.snaplen = patch.snaplen;
+            }
+            capture.live_path = patch.live_path.clone();
+        }
+        let result = match state {
+            true if patch.append => match patch.mode.enum_value_or_default() {
+                Mode::RING => Err(invalid("ring captures are not resumed")),
+                Mode::FILE => capture.resume_capture(patch.format.enum_value_or_default()),
+            },
+            true => capture
+                .start_capture(
+                    patch.format.enum_value_or_default(),
+                    patch.mode.enum_value_or_default(),
+                )
+                .and_then(|_| if patch.dump { capture.dump() } else { Ok(()) }),
+            false => {
+                capture.stop_capture();
+                compress_capture(&capture);
+                Ok(())
+            }
+        };
+        if let Err(err) = result {
+            capture.record_error(&err);
+            put_capture_error(writer, err);
+            return;
         }
 
         // Perform protobuf-json-mapping with the given protobuf
@@ -226,54 +820,274 @@ pub fn handle_capture_patch(
         } else {
             writer.put_error(404, "proto to JSON mapping failure");
         }
+    } else {
+        put_capture_error(writer, not_found("Cannot access Capture Resource"));
+    }
+}
+
+// Returns the patch starting or stopping a capture with the fields of the
+// update mask of a patch, and the settings of the capture for the others.
+fn masked_patch(capture: &CaptureInfo, patch: &PatchCapture, fields: &[String]) -> PatchCapture {
+    let mut masked = PatchCapture {
+        state: patch.state,
+        format: capture.format.into(),
+        mode: capture.mode.into(),
+        snaplen: capture.snaplen,
+        live_path: capture.live_path.clone(),
+        ..Default::default()
+    };
+    for field in fields {
+        match field.as_str() {
+            // An unset sampling or filter in the mask records every packet.
+            "sampling" => masked.sampling = Some(patch.sampling.clone().unwrap_or_default()).into(),
+            "filter" => masked.filter = Some(patch.filter.clone().unwrap_or_default()).into(),
+            "format" => masked.format = patch.format,
+            "mode" => masked.mode = patch.mode,
+            "dump" => masked.dump = patch.dump,
+            "snaplen" => masked.snaplen = patch.snaplen,
+            "live_path" => masked.live_path = patch.live_path.clone(),
+            "append" => masked.append = patch.append,
+            _ => {}
+        }
+    }
+    masked
+}
+
+// PATCH /captures/{id} with a PatchCapture with an update mask --> patch
+// only the fields of the mask, starting or stopping the capture with
+// "state" only
+pub fn handle_capture_update(
+    writer: ResponseWritable,
+    captures: &Captures,
+    id: ChipId,
+    patch: PatchCapture,
+) {
+    let fields = match update_mask_fields(&patch.update_mask.paths) {
+        Ok(fields) => fields,
+        Err(err) => {
+            put_capture_error(writer, invalid(err));
+            return;
+        }
+    };
+    let Some(capture) = captures.get(id) else {
+        put_capture_error(writer, not_found("Cannot access Capture Resource"));
+        return;
+    };
+    if fields.iter().any(|field| field == "state") {
+        let state = match patch.state.enum_value_or_default() {
+            State::ON => true,
+            State::OFF => false,
+            _ => {
+                put_capture_error(writer, invalid(INCORRECT_STATE));
+                return;
+            }
+        };
+        let masked = masked_patch(&capture.lock().unwrap(), &patch, &fields);
+        handle_capture_patch(writer, captures, id, state, Some(masked));
+        return;
+    }
+    let mut capture = capture.lock().unwrap();
+    if let Err(err) = capture.update(&patch, &fields) {
+        put_capture_error(writer, err);
+        return;
+    }
+    if let Ok(json_response) =
+        print_to_string_with_options(&capture.get_capture_proto(), &JSON_PRINT_OPTION)
+    {
+        writer.put_ok("text/json", &json_response, &[]);
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure");
+    }
+}
+
+// Starts the captures of the chips added from now on, or stops starting
+// them, with a CaptureAll.
+pub fn handle_capture_all(writer: ResponseWritable, captures: &mut Captures, body: &[u8]) {
+    let capture_all = match std::str::from_utf8(body)
+        .map_err(|e| e.to_string())
+        .and_then(|json| parse_from_str::<CaptureAll>(json).map_err(|e| e.to_string()))
+    {
+        Ok(capture_all) => capture_all,
+        Err(err) => {
+            put_capture_error(writer, invalid(format!("Incorrect CaptureAll: {err}")));
+            return;
+        }
+    };
+    captures.capture_all = capture_all.enabled.then(|| capture_all.format.enum_value_or_default());
+    writer.put_ok("text/plain", "", &[]);
+}
+
+// Starts or stops the aggregate capture with an AggregateCapture, replying
+// with the file, the size and the records of the aggregate capture.
+pub fn handle_capture_aggregate(writer: ResponseWritable, captures: &Captures, body: &[u8]) {
+    let patch = match std::str::from_utf8(body)
+        .map_err(|e| e.to_string())
+        .and_then(|json| parse_from_str::<AggregateCapture>(json).map_err(|e| e.to_string()))
+    {
+        Ok(patch) => patch,
+        Err(err) => {
+            put_capture_error(writer, invalid(format!("Incorrect AggregateCapture: {err}")));
+            return;
+        }
+    };
+    let mut aggregate = captures.aggregate.lock().unwrap();
+    let mut response = AggregateCapture::new();
+    let stopped = match patch.state.enum_value_or_default() {
+        State::ON => {
+            if aggregate.is_none() {
+                match Aggregate::start() {
+                    Ok(started) => *aggregate = Some(started),
+                    Err(err) => {
+                        put_capture_error(writer, CaptureError::IoError(err));
+                        return;
+                    }
+                }
+            }
+            response.state = State::ON.into();
+            None
+        }
+        // The file of a stopped aggregate capture is closed and kept.
+        _ => {
+            response.state = State::OFF.into();
+            aggregate.take()
+        }
+    };
+    if let Some(aggregate) = aggregate.as_ref().or(stopped.as_ref()) {
+        response.path = aggregate.path.to_string_lossy().into_owned();
+        response.size = aggregate.size as i32;
+        response.records = aggregate.records;
+    }
+    if let Ok(json_response) = print_to_string_with_options(&response, &JSON_PRINT_OPTION) {
+        writer.put_ok("text/json", &json_response, &[]);
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure");
+    }
+}
+
+pub fn handle_capture_delete(writer: ResponseWritable, captures: &mut Captures, id: ChipId) {
+    let removed = match captures.get(id) {
+        Some(arc_capture) => arc_capture.lock().unwrap().removed_at.is_some(),
+        None => {
+            put_capture_error(writer, not_found("Cannot access Capture Resource"));
+            return;
+        }
+    };
+    if !removed {
+        let err =
+            CaptureError::InvalidState("Cannot delete the Capture of a connected chip".into());
+        put_capture_error(writer, err);
+        return;
+    }
+    match captures.delete(&id) {
+        Ok(()) => writer.put_ok("text/plain", "", &[]),
+        Err(err) => put_capture_error(writer, err),
     }
 }
 
-/// The Rust capture handler used directly by Http frontend for LIST, GET, and PATCH
+/// The Rust capture handler used directly by Http frontend for LIST, GET, PATCH, and DELETE
 pub fn handle_capture(request: &HttpRequest, param: &str, writer: ResponseWritable) {
-    if request.uri.as_str() == "/v1/captures" {
+    if request.path() == "/v1/captures" {
         match request.method.as_str() {
             "GET" => {
+                let list_request = match list_request::<ListCaptureRequest>(request) {
+                    Ok(list_request) => list_request,
+                    Err(err) => {
+                        writer.put_error(400, err.as_str());
+                        return;
+                    }
+                };
+                // Get the most updated active captures
+                update_captures();
+                handle_capture_list(writer, &RESOURCE.read().unwrap(), &list_request);
+            }
+            "PATCH" => {
                 let mut captures = RESOURCE.write().unwrap();
-                handle_capture_list(writer, &mut captures);
+                handle_capture_all(writer, &mut captures, &request.body);
             }
             _ => writer.put_error(404, "Not found."),
         }
-    } else {
+    } else if param == "stats" {
         match request.method.as_str() {
             "GET" => {
-                let mut captures = RESOURCE.write().unwrap();
+                update_captures();
+                handle_capture_stats(writer, &RESOURCE.read().unwrap());
+            }
+            _ => writer.put_error(404, "Not found."),
+        }
+    } else if param == "replay" {
+        match request.method.as_str() {
+            "POST" => handle_capture_replay(writer, &request.body),
+            _ => writer.put_error(404, "Not found."),
+        }
+    } else if param == "aggregate" {
+        match request.method.as_str() {
+            "PATCH" => {
+                let captures = RESOURCE.read().unwrap();
+                handle_capture_aggregate(writer, &captures, &request.body);
+            }
+            _ => writer.put_error(404, "Not found."),
+        }
+    } else {
+        match request.method.as_str() {
+            "GET" => match prepare_capture_get(request, param) {
+                Ok(download) => send_download(writer, download),
+                Err(err) => err.put(writer),
+            },
+            "PATCH" => {
+                update_captures();
+                let captures = RESOURCE.read().unwrap();
                 let id = match param.parse::<i32>() {
                     Ok(num) => num,
                     Err(_) => {
-                        writer.put_error(404, "Incorrect ID type for capture, ID should be i32.");
+                        put_capture_error(writer, invalid(INCORRECT_ID));
                         return;
                     }
                 };
-                handle_capture_get(writer, &mut captures, id);
+                let body = &request.body;
+                let state = String::from_utf8(body.to_vec()).unwrap();
+                match state.as_str() {
+                    "1" => handle_capture_patch(writer, &captures, id, true, None),
+                    "2" => handle_capture_patch(writer, &captures, id, false, None),
+                    // A PatchCapture with the options of the capture.
+                    _ => match parse_from_str::<PatchCapture>(&state) {
+                        Ok(patch) if !patch.update_mask.paths.is_empty() => {
+                            handle_capture_update(writer, &captures, id, patch)
+                        }
+                        Ok(patch) => match patch.state.enum_value_or_default() {
+                            State::ON => {
+                                handle_capture_patch(writer, &captures, id, true, Some(patch))
+                            }
+                            State::OFF => {
+                                handle_capture_patch(writer, &captures, id, false, Some(patch))
+                            }
+                            _ => put_capture_error(writer, invalid(INCORRECT_STATE)),
+                        },
+                        Err(_) => put_capture_error(writer, invalid(INCORRECT_STATE)),
+                    },
+                }
             }
-            "PATCH" => {
+            "DELETE" => {
+                update_captures();
                 let mut captures = RESOURCE.write().unwrap();
                 let id = match param.parse::<i32>() {
                     Ok(num) => num,
                     Err(_) => {
-                        writer.put_error(404, "Incorrect ID type for capture, ID should be i32.");
+                        put_capture_error(writer, invalid(INCORRECT_ID));
                         return;
                     }
                 };
-                let body = &request.body;
-                let state = String::from_utf8(body.to_vec()).unwrap();
-                match state.as_str() {
-                    "1" => handle_capture_patch(writer, &mut captures, id, true),
-                    "2" => handle_capture_patch(writer, &mut captures, id, false),
-                    _ => writer.put_error(404, "Incorrect state for PatchCapture"),
-                }
+                handle_capture_delete(writer, &mut captures, id);
             }
             _ => writer.put_error(404, "Not found."),
         }
     }
 }
 
+/// capture stream handle cxx for grpc server to call
+pub fn handle_capture_stream_cxx(responder: Pin<&mut CxxServerResponseWriter>, id: i32) {
+    handle_capture_stream(&mut CxxServerResponseWriterWrapper { writer: responder }, id);
+}
+
 /// capture handle cxx for grpc server to call
 pub fn handle_capture_cxx(
     responder: Pin<&mut CxxServerResponseWriter>,
@@ -301,22 +1115,136 @@ pub fn handle_capture_cxx(
 }
 
 // Helper function for translating u32 representation of ChipKind
-fn int_to_chip_kind(kind: u32) -> ChipKind {
+pub fn int_to_chip_kind(kind: u32) -> ChipKind {
     match kind {
         1 => ChipKind::BLUETOOTH,
         2 => ChipKind::WIFI,
         3 => ChipKind::UWB,
+        4 => ChipKind::NFC,
         _ => ChipKind::UNSPECIFIED,
     }
 }
 
-// A common code for handle_request and handle_response cxx mehtods
+// Updates the quota status of a capture after a record. Records an event
+// when the capture crosses the watermark and stops it when it is full.
+fn update_quota_status(capture: &mut CaptureInfo) {
+    let status = QUOTA.status(capture.total_size(), capture.total_records());
+    if status == capture.quota_status {
+        return;
+    }
+    capture.quota_status = status;
+    let event_type = match status {
+        QuotaStatus::ABOVE_WATERMARK => Type::CAPTURE_WATERMARK,
+        QuotaStatus::FULL => {
+            capture.stop_capture();
+            compress_capture(capture);
+            Type::CAPTURE_FULL
+        }
+        _ => return,
+    };
+    let proto = capture.get_capture_proto();
+    warn!("capture {} of {} is {:?}", proto.id, proto.device_name, status);
+    capture.record_event(event_type, String::new());
+}
+
+// Enforces the capture.dir_max_size quota of the capture directory after a
+// write of `capture`. The directory is listed at most once a second, so the
+// quota is exceeded by the writes of a second at most.
+fn enforce_dir_quota(captures: &Captures, capture: &mut CaptureInfo, time: Duration) {
+    if DIR_QUOTA.max_size == 0 {
+        return;
+    }
+    {
+        let mut checked_at = DIR_CHECKED_AT.lock().unwrap();
+        if checked_at.is_some_and(|at| time.saturating_sub(at) < Duration::from_secs(1)) {
+            return;
+        }
+        *checked_at = Some(time);
+    }
+    let mut usage = dir_size(&pcap_dir());
+    if usage > DIR_QUOTA.max_size && DIR_QUOTA.policy == DirQuotaPolicy::Evict {
+        let id = capture.get_capture_proto().id;
+        usage = captures.evict_oldest(id, usage, DIR_QUOTA.max_size);
+    }
+    if usage > DIR_QUOTA.max_size {
+        capture.fail(CaptureError::QuotaExceeded(DIR_QUOTA.max_size).to_string());
+        compress_capture(capture);
+    }
+}
+
+// Rotates the file of a recording capture when it is due and removes the
+// expired segments.
+fn rotate_capture(capture: &mut CaptureInfo, time: Duration) {
+    let age = capture.file_age(time);
+    if capture.is_recording() && capture.records > 0 && ROTATION.is_due(capture.size, age) {
+        if let Err(err) = capture.rotate() {
+            capture.record_error(&err);
+        }
+    }
+    capture.prune_segments(&ROTATION, time);
+}
+
+/// The data of the record of a packet in the link type of its chip kind:
+/// the pseudo header of the link type and the packet, borrowed rather than
+/// copied, so the packet is only copied into the records.
+pub struct LinkData<'a> {
+    pub header: Vec<u8>,
+    pub packet: &'a [u8],
+}
+
+impl LinkData<'_> {
+    /// Returns the parts of the data, written to the records in turn.
+    pub fn parts(&self) -> [&[u8]; 2] {
+        [&self.header, self.packet]
+    }
+}
+
+// Returns the data of the record of a packet in the link type of its chip
+// kind: H4 for Bluetooth, radiotap for WiFi, and UCI for UWB. Custom chip
+// kinds are recorded as is, in the link type they registered.
+pub fn link_data(
+    kind: u32,
+    direction: PacketDirection,
+    packet_type: u32,
+    packet: &[u8],
+) -> Option<LinkData<'_>> {
+    let (header, packet) = match int_to_chip_kind(kind) {
+        ChipKind::BLUETOOTH => (h4_header(direction, packet_type), packet),
+        // The frame of a mac80211_hwsim message with the channel, and the
+        // signal and the rate simulated for the receiver, or a bare frame.
+        ChipKind::WIFI => match parse_hwsim(packet) {
+            Some(hwsim) => (
+                Radiotap {
+                    rate: hwsim
+                        .rx_rate
+                        .and_then(|index| Radiotap::hwsim_rate(hwsim.frequency, index)),
+                    frequency: u16::try_from(hwsim.frequency)
+                        .ok()
+                        .filter(|&frequency| frequency > 0),
+                    signal: hwsim.signal.map(|signal| signal.clamp(-128, 127) as i8),
+                }
+                .header(),
+                hwsim.frame,
+            ),
+            None => (Radiotap::default().header(), packet),
+        },
+        ChipKind::UWB | ChipKind::NFC => (Vec::new(), packet),
+        _ if is_custom_chip_kind(kind) => (Vec::new(), packet),
+        _ => return None,
+    };
+    Some(LinkData { header, packet })
+}
+
+// A common code for handle_request and handle_response cxx mehtods. The
+// packet is borrowed from packet_hub for the call, and framed straight into
+// the record of each capture.
 fn handle_packet(
     kind: u32,
     facade_id: u32,
     packet: &CxxVector<u8>,
     packet_type: u32,
     direction: PacketDirection,
+    timestamp: Duration,
 ) {
     let captures = RESOURCE.read().unwrap();
     let facade_key = CaptureInfo::new_facade_key(int_to_chip_kind(kind), facade_id as i32);
@@ -325,38 +1253,194 @@ fn handle_packet(
         .get(&facade_key)
         .map(|arc_capture| arc_capture.lock().unwrap())
     {
-        if let Some(ref mut file) = capture.file {
-            if int_to_chip_kind(kind) == ChipKind::BLUETOOTH {
-                let timestamp =
-                    SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards");
-                match append_record(timestamp, file, direction, packet_type, packet.as_slice()) {
-                    Ok(size) => {
-                        capture.size += size;
-                        capture.records += 1;
-                    }
-                    Err(err) => {
-                        println!("netsimd: {err:?}");
-                    }
+        let capture = &mut *capture;
+        // The aggregate capture records the packets of every chip, whatever
+        // the state, the filter and the sampling of the capture of the chip.
+        if let Some(aggregate) = captures.aggregate.lock().unwrap().as_mut() {
+            if let Some(data) = link_data(kind, direction, packet_type, packet.as_slice()) {
+                if let Err(err) = aggregate.record(capture, timestamp, direction, &data.parts()) {
+                    info!("{err:?}");
                 }
             }
         }
+        if !capture.is_recording() {
+            return;
+        }
+        if !matches(&capture.filter, direction, packet_type, packet.as_slice()) {
+            return;
+        }
+        if !capture.sampler.sample(timestamp) {
+            return;
+        }
+        // The ring buffer is bounded, the quotas and the rotation only
+        // apply to the files.
+        if capture.write_packet(packet_type, packet.as_slice(), direction, timestamp) {
+            update_quota_status(capture);
+            rotate_capture(capture, timestamp);
+            enforce_dir_quota(&captures, capture, timestamp);
+        }
     };
 }
 
 // Cxx Method for packet_hub to invoke (Host to Controller Packet Flow)
-pub fn handle_packet_request(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) {
-    handle_packet(kind, facade_id, packet, packet_type, PacketDirection::HostToController)
+pub fn handle_packet_request(
+    kind: u32,
+    facade_id: u32,
+    packet: &CxxVector<u8>,
+    packet_type: u32,
+    timestamp_nanos: u64,
+) {
+    let timestamp = Duration::from_nanos(timestamp_nanos);
+    handle_packet(
+        kind,
+        facade_id,
+        packet,
+        packet_type,
+        PacketDirection::HostToController,
+        timestamp,
+    )
 }
 
 // Cxx Method for packet_hub to invoke (Controller to Host Packet Flow)
-pub fn handle_packet_response(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) {
-    handle_packet(kind, facade_id, packet, packet_type, PacketDirection::ControllerToHost)
+pub fn handle_packet_response(
+    kind: u32,
+    facade_id: u32,
+    packet: &CxxVector<u8>,
+    packet_type: u32,
+    timestamp_nanos: u64,
+) {
+    let timestamp = Duration::from_nanos(timestamp_nanos);
+    handle_packet(
+        kind,
+        facade_id,
+        packet,
+        packet_type,
+        PacketDirection::ControllerToHost,
+        timestamp,
+    )
 }
 
-// Cxx Method for clearing pcap files in temp directory
+/// Returns the chip of a facade, from its capture.
+pub fn facade_chip_id(chip_kind: ChipKind, facade_id: u32) -> Option<ChipId> {
+    let captures = RESOURCE.read().unwrap();
+    let facade_key = CaptureInfo::new_facade_key(chip_kind, facade_id as i32);
+    captures.facade_key_to_capture.get(&facade_key).map(|capture| capture.lock().unwrap().chip_id())
+}
+
+/// Counts a packet to or from a chip dropped by a link impairment.
+pub fn record_dropped(chip_id: ChipId) {
+    if let Some(capture) = RESOURCE.read().unwrap().get(chip_id) {
+        capture.lock().unwrap().dropped += 1;
+    }
+}
+
+/// Writes the queued records of the running captures to their files.
+pub fn flush_captures() {
+    let captures = RESOURCE.read().unwrap();
+    for capture in captures.values() {
+        let capture = capture.lock().unwrap();
+        if let Err(err) = capture.flush() {
+            capture.record_error(&err);
+        }
+    }
+}
+
+// Cxx Method for netsimd to invoke as it exits, e.g. on SIGTERM, stopping
+// the captures with their records synced to the disk.
+pub fn shutdown_captures() {
+    let captures = RESOURCE.read().unwrap();
+    for capture in captures.values() {
+        let mut capture = capture.lock().unwrap();
+        if let Err(err) = capture.shutdown() {
+            capture.record_error(&err);
+        }
+    }
+    if let Some(aggregate) = captures.aggregate.lock().unwrap().take() {
+        if let Err(err) = aggregate.sync() {
+            warn!("failed to sync the aggregate capture: {err}");
+        }
+    }
+    info!("stopped the captures for the shutdown");
+}
+
+// Cxx Method for the scene controller to add the capture of a chip as the
+// chip is added, started when all the captures are, so the first packets
+// of the chip are recorded.
+pub fn add_capture(kind: u32, chip_id: u32, device_name: &str) {
+    let mut captures = RESOURCE.write().unwrap();
+    if !captures.contains(chip_id as ChipId) {
+        captures.insert(CaptureInfo::new(kind, chip_id as ChipId, device_name.to_string()));
+    }
+}
+
+// Cxx Method for the device manager to invoke as the facade of a chip is
+// rebuilt, e.g. by a restart, so its running capture follows the facade id
+// of the chip.
+pub fn update_capture_facade(chip_id: u32, facade_id: u32) {
+    let mut captures = RESOURCE.write().unwrap();
+    if captures.rekey(chip_id as ChipId, facade_id as FacadeId) {
+        info!(chip_id: chip_id; "capture moved to facade {facade_id}");
+    }
+}
+
+/// Starts the captures of chips in a format, or stops them, compressing the
+/// stopped files. Returns the errors of the captures that failed.
+pub fn set_chip_captures(
+    chip_ids: &[ChipId],
+    state: bool,
+    format: Format,
+) -> Vec<(ChipId, CaptureError)> {
+    update_captures();
+    let captures = RESOURCE.read().unwrap();
+    let errors = captures.set_recording(chip_ids, state, format);
+    if !state {
+        for capture in chip_ids.iter().filter_map(|&id| captures.get(id)) {
+            compress_capture(&capture.lock().unwrap());
+        }
+    }
+    errors
+}
+
+/// Starts the pcap captures of the chips of a group of devices, or stops
+/// them, compressing the stopped files. Fails with the errors of the
+/// captures that failed to start.
+pub fn set_group_captures(chip_ids: &[ChipId], state: bool) -> Result<(), String> {
+    let errors = set_chip_captures(chip_ids, state, Format::PCAP);
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors
+            .iter()
+            .map(|(id, err)| format!("capture of chip {id}: {err}"))
+            .collect::<Vec<_>>()
+            .join(", ")),
+    }
+}
+
+// Cxx Method for netsimd to invoke with --capture-all, starting the
+// captures of the chips in the pcap format.
+pub fn set_capture_all() {
+    RESOURCE.write().unwrap().capture_all = Some(Format::PCAP);
+}
+
+// Cxx Method for netsimd to invoke with --capture-dir. Returns false when
+// the directory isn't writable.
+pub fn set_capture_dir(dir: &str) -> bool {
+    match Captures::set_dir(Path::new(dir)) {
+        Ok(()) => true,
+        Err(e) => {
+            warn!("{e}");
+            false
+        }
+    }
+}
+
+// Cxx Method for clearing pcap files in temp directory. A configured
+// capture directory is kept.
 pub fn clear_pcap_files() -> bool {
-    let mut path = std::env::temp_dir();
-    path.push("netsim-pcaps");
+    if !is_default_pcap_dir() {
+        return false;
+    }
+    let path = pcap_dir();
 
     // Check if the directory exists.
     if std::fs::metadata(&path).is_err() {
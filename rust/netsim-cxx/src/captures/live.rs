@@ -0,0 +1,322 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Live capture output to a named pipe.
+//!
+//! A capture started with a live path also writes its records to a named
+//! pipe, created if missing, so `wireshark -k -i <path>` shows the packets
+//! as they are simulated. The pipe is opened without blocking: until a
+//! reader opens it, the writer thread of the capture retries every
+//! RETRY_INTERVAL, for capture.live_wait_secs; then the capture falls back
+//! to its file alone. A reader attaching receives the header of the file
+//! and the records from then on; a reader closing the pipe is waited for
+//! again, so Wireshark can be restarted. The capture file is written
+//! either way.
+//!
+//! The pipe stays non-blocking, so a paused or slow reader never stalls the
+//! writer thread: the records finding the pipe full are dropped and counted
+//! for the netsim_capture_live_dropped_total metric. The rest of a record
+//! the pipe took in part is written before the next records, so the reader
+//! only gets whole records. The pipe is created readable by its owner only.
+
+use std::fs::File;
+use std::io::{ErrorKind, Result, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::logger::{info, warn};
+
+/// Interval of the attempts to open the pipe for a reader.
+const RETRY_INTERVAL: Duration = Duration::from_millis(200);
+
+// Records of all live captures dropped on a full pipe.
+static DROPPED: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the records of all live captures dropped on a full pipe.
+pub fn live_dropped() -> u64 {
+    DROPPED.load(Ordering::Relaxed)
+}
+
+struct PipeState {
+    pipe: Option<File>,
+    // The rest of a record the full pipe took in part.
+    pending: Vec<u8>,
+    // End of the wait for a reader, None once the capture fell back to its
+    // file.
+    deadline: Option<Instant>,
+    next_attempt: Instant,
+}
+
+/// The named pipe of a live capture, shared by the writers of its files.
+pub struct LivePipe {
+    path: PathBuf,
+    // The file header, sent first to each reader.
+    header: Vec<u8>,
+    wait: Duration,
+    // Read without waiting for a write blocked on a slow reader.
+    attached: AtomicBool,
+    // Records dropped on a full pipe.
+    dropped: AtomicU64,
+    state: Mutex<PipeState>,
+}
+
+impl LivePipe {
+    /// Creates the named pipe `path` unless it exists, waiting up to `wait`
+    /// for a reader.
+    pub fn new(path: &Path, header: Vec<u8>, wait: Duration) -> Result<Self> {
+        create_fifo(path)?;
+        let now = Instant::now();
+        Ok(LivePipe {
+            path: path.to_path_buf(),
+            header,
+            wait,
+            attached: AtomicBool::new(false),
+            dropped: AtomicU64::new(0),
+            state: Mutex::new(PipeState {
+                pipe: None,
+                pending: Vec::new(),
+                deadline: Some(now + wait),
+                next_attempt: now,
+            }),
+        })
+    }
+
+    /// A reader has the pipe open.
+    pub fn is_attached(&self) -> bool {
+        self.attached.load(Ordering::Relaxed)
+    }
+
+    /// Returns the records dropped on a full pipe.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Attaches a reader that opened the pipe, while the capture waits for
+    /// one, or writes the rest of a record to the attached reader.
+    pub fn poll(&self) {
+        let mut state = self.state.lock().unwrap();
+        self.attach(&mut state);
+        self.write_record(&mut state, &[]);
+    }
+
+    fn attach(&self, state: &mut PipeState) {
+        let now = Instant::now();
+        let Some(deadline) = state.deadline else {
+            return;
+        };
+        if state.pipe.is_some() || now < state.next_attempt {
+            return;
+        }
+        let path = self.path.display();
+        if now >= deadline {
+            warn!("no reader of {path} in {:?}, capturing to the file only", self.wait);
+            state.deadline = None;
+            return;
+        }
+        state.next_attempt = now + RETRY_INTERVAL;
+        match open_fifo(&self.path) {
+            Ok(Some(mut pipe)) => {
+                if let Ok(written) = write_some(&mut pipe, &self.header) {
+                    info!("live capture reader attached to {path}");
+                    state.pending = self.header[written..].to_vec();
+                    state.pipe = Some(pipe);
+                    self.attached.store(true, Ordering::Relaxed);
+                }
+            }
+            Ok(None) => {}
+            Err(err) => {
+                warn!("failed to open {path}: {err}, capturing to the file only");
+                state.deadline = None;
+            }
+        }
+    }
+
+    /// Writes a record to the reader of the pipe, or drops it when the pipe
+    /// is full. A reader closing the pipe is waited for again.
+    pub fn write(&self, record: &[u8]) {
+        let mut state = self.state.lock().unwrap();
+        self.attach(&mut state);
+        self.write_record(&mut state, record);
+    }
+
+    // Writes the rest of the last record and then the record, dropping the
+    // record unless the pipe takes some of it.
+    fn write_record(&self, state: &mut PipeState, record: &[u8]) {
+        let PipeState { pipe: Some(pipe), pending, .. } = state else {
+            return;
+        };
+        let result = write_some(pipe, pending).and_then(|written| {
+            pending.drain(..written);
+            match pending.is_empty() {
+                true => write_some(pipe, record),
+                false => Ok(0),
+            }
+        });
+        match result {
+            Ok(0) if !record.is_empty() => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(written) => pending.extend_from_slice(&record[written..]),
+            Err(err) => {
+                info!("live capture reader of {} detached: {err}", self.path.display());
+                state.pipe = None;
+                state.pending.clear();
+                state.deadline = Some(Instant::now() + self.wait);
+                self.attached.store(false, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+// Writes the bytes the pipe takes without blocking, returning their count.
+fn write_some(pipe: &mut File, mut bytes: &[u8]) -> Result<usize> {
+    let len = bytes.len();
+    while !bytes.is_empty() {
+        match pipe.write(bytes) {
+            Ok(0) => break,
+            Ok(written) => bytes = &bytes[written..],
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(len - bytes.len())
+}
+
+/// Creates the named pipe `path`, unless it exists.
+#[cfg(target_os = "linux")]
+fn create_fifo(path: &Path) -> Result<()> {
+    use std::ffi::CString;
+    use std::io::Error;
+    use std::os::unix::ffi::OsStrExt;
+    use std::os::unix::fs::FileTypeExt;
+
+    match std::fs::metadata(path) {
+        Ok(metadata) if metadata.file_type().is_fifo() => return Ok(()),
+        Ok(_) => return Err(Error::new(ErrorKind::AlreadyExists, "not a named pipe")),
+        Err(err) if err.kind() != ErrorKind::NotFound => return Err(err),
+        Err(_) => {}
+    }
+    let path = CString::new(path.as_os_str().as_bytes()).map_err(Error::other)?;
+    // SAFETY: path is a NUL terminated string outliving the call.
+    if unsafe { libc::mkfifo(path.as_ptr(), 0o600) } < 0 {
+        return Err(Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Opens the named pipe `path` for non-blocking writes, or None without a
+/// reader. netsimd ignores SIGPIPE, so a reader closing the pipe fails the
+/// writes with EPIPE.
+#[cfg(target_os = "linux")]
+fn open_fifo(path: &Path) -> Result<Option<File>> {
+    use std::fs::OpenOptions;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    match OpenOptions::new().write(true).custom_flags(libc::O_NONBLOCK).open(path) {
+        Ok(pipe) => Ok(Some(pipe)),
+        Err(err) if err.raw_os_error() == Some(libc::ENXIO) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn create_fifo(_path: &Path) -> Result<()> {
+    Err(std::io::Error::new(ErrorKind::Unsupported, "live captures require Linux"))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn open_fifo(_path: &Path) -> Result<Option<File>> {
+    Err(std::io::Error::new(ErrorKind::Unsupported, "live captures require Linux"))
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("netsim-{name}-{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_live_pipe() {
+        let path = temp_path("live-test");
+        let live = LivePipe::new(&path, vec![1, 2], Duration::from_secs(10)).unwrap();
+        // Without a reader the records are not written.
+        live.write(&[3]);
+        assert!(!live.is_attached());
+        let mut reader = std::fs::OpenOptions::new()
+            .read(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open(&path)
+            .unwrap();
+        live.state.lock().unwrap().next_attempt = Instant::now();
+        live.write(&[4, 5]);
+        assert!(live.is_attached());
+        let mut bytes = [0u8; 8];
+        assert_eq!(reader.read(&mut bytes).unwrap(), 4);
+        assert_eq!(bytes[..4], [1, 2, 4, 5]);
+        // A reader not reading drops the records finding the pipe full,
+        // without blocking, and gets whole records once it reads again.
+        let record = vec![7; 5000];
+        while live.dropped() == 0 {
+            live.write(&record);
+        }
+        let pending = live.state.lock().unwrap().pending.len();
+        let mut bytes = vec![0u8; 1 << 20];
+        let mut read = 0;
+        while let Ok(len @ 1..) = reader.read(&mut bytes[read..]) {
+            read += len;
+        }
+        live.poll();
+        while let Ok(len @ 1..) = reader.read(&mut bytes[read..]) {
+            read += len;
+        }
+        assert!(pending > 0 && read % record.len() == 0);
+        assert!(live.is_attached());
+        assert!(live_dropped() >= 1);
+        // The reader closing the pipe detaches it.
+        drop(reader);
+        live.write(&[6]);
+        assert!(!live.is_attached());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_fifo_mode() {
+        use std::os::unix::fs::PermissionsExt;
+        let path = temp_path("live-mode-test");
+        create_fifo(&path).unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o077, 0);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_live_pipe_fallback() {
+        let path = temp_path("live-fallback-test");
+        let live = LivePipe::new(&path, Vec::new(), Duration::ZERO).unwrap();
+        live.poll();
+        assert_eq!(live.state.lock().unwrap().deadline, None);
+        std::fs::remove_file(&path).unwrap();
+        // A regular file is not a named pipe.
+        std::fs::write(&path, []).unwrap();
+        assert!(LivePipe::new(&path, Vec::new(), Duration::ZERO).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
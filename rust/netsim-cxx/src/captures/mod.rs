@@ -13,7 +13,15 @@
 // limitations under the License.
 
 pub mod capture;
+pub mod error;
+pub mod filter;
 pub mod handlers;
+pub mod live;
 pub mod pcap_util;
+pub mod replay;
+pub mod writer;
 
 pub const PCAP_MIME_TYPE: &str = "application/vnd.tcpdump.pcap";
+pub const PCAPNG_MIME_TYPE: &str = "application/x-pcapng";
+pub const BTSNOOP_MIME_TYPE: &str = "application/octet-stream";
+pub const GZIP_MIME_TYPE: &str = "application/gzip";
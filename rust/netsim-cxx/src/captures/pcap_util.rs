@@ -13,8 +13,7 @@
 // limitations under the License.
 
 use std::{
-    fs::File,
-    io::{Result, Write},
+    io::{Error, ErrorKind, Read, Result, Write},
     time::Duration,
 };
 macro_rules! be_vec {
@@ -25,22 +24,71 @@ macro_rules! be_vec {
        };
     }
 
+/// Direction of a captured packet, passed by packet_hub with each packet.
+/// It is recorded in the pseudo header of the Bluetooth H4 records, as 0
+/// for sent and 1 for received, and in the epb_flags of the pcapng records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PacketDirection {
     HostToController = 0,
     ControllerToHost = 1,
 }
 
-pub fn write_pcap_header(output: &mut File) -> Result<usize> {
-    let linktype: u32 = 201; // LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR
+pub const LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR: u32 = 201;
+pub const LINKTYPE_IEEE802_11_RADIOTAP: u32 = 127;
+pub const LINKTYPE_FIRA_UCI: u32 = 299;
+/// No link type is assigned to NCI, the NFC captures use LINKTYPE_USER0.
+pub const LINKTYPE_NFC_NCI: u32 = 147;
 
+// Radiotap fields, and the channel flags of the 2.4 and 5 GHz bands.
+const RADIOTAP_RATE: u32 = 1 << 2;
+const RADIOTAP_CHANNEL: u32 = 1 << 3;
+const RADIOTAP_DBM_ANTSIGNAL: u32 = 1 << 5;
+const RADIOTAP_CHANNEL_2GHZ: u16 = 0x0080;
+const RADIOTAP_CHANNEL_5GHZ: u16 = 0x0100;
+/// Data rates of mac80211_hwsim in 500 kbps units: 1 to 11 Mbps CCK, then
+/// 6 to 54 Mbps OFDM.
+const HWSIM_RATES: [u8; 12] = [2, 4, 11, 22, 12, 18, 24, 36, 48, 72, 96, 108];
+
+// Returns the parts of the data recorded with a snapshot length, 0 records
+// every byte. The data of a record is written in parts, like a pseudo
+// header and the packet, rather than copied into one buffer first.
+fn snapshot<'a>(parts: &[&'a [u8]], snaplen: u32) -> Vec<&'a [u8]> {
+    let mut remaining = match snaplen {
+        0 => usize::MAX,
+        snaplen => snaplen as usize,
+    };
+    parts
+        .iter()
+        .map(|part| {
+            let part = &part[..part.len().min(remaining)];
+            remaining -= part.len();
+            part
+        })
+        .collect()
+}
+
+fn parts_len(parts: &[&[u8]]) -> usize {
+    parts.iter().map(|part| part.len()).sum()
+}
+
+fn write_parts(output: &mut impl Write, parts: &[&[u8]]) -> Result<()> {
+    parts.iter().try_for_each(|part| output.write_all(part))
+}
+
+/// Writes the header of a pcap file with nanosecond timestamps. The records
+/// are truncated to the snapshot length, 0 is unlimited.
+pub fn write_pcap_header(output: &mut impl Write, linktype: u32, snaplen: u32) -> Result<usize> {
     // https://tools.ietf.org/id/draft-gharris-opsawg-pcap-00.html#name-file-header
     let header: Vec<u8> = be_vec![
-        0xa1b2c3d4u32, // magic number
+        0xa1b23c4du32, // magic number of nanosecond timestamps
         2u16,          // major version
         4u16,          // minor version
         0u32,          // reserved 1
         0u32,          // reserved 2
-        u32::MAX,      // snaplen
+        match snaplen {
+            0 => u32::MAX,
+            snaplen => snaplen,
+        }, // snaplen
         linktype
     ];
 
@@ -50,38 +98,424 @@ pub fn write_pcap_header(output: &mut File) -> Result<usize> {
 
 pub fn append_record(
     timestamp: Duration,
-    output: &mut File,
+    output: &mut impl Write,
     packet_direction: PacketDirection,
     packet_type: u32,
     packet: &[u8],
+    snaplen: u32,
 ) -> Result<usize> {
-    // Record (direciton, type, packet)
-    let record: Vec<u8> = be_vec![packet_direction as u32, packet_type as u8];
+    let header = h4_header(packet_direction, packet_type);
+    write_record_parts(timestamp, output, &[&header, packet], snaplen)
+}
+
+/// Returns a packet with the pseudo header of LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR.
+pub fn h4_record(packet_direction: PacketDirection, packet_type: u32, packet: &[u8]) -> Vec<u8> {
+    [h4_header(packet_direction, packet_type).as_slice(), packet].concat()
+}
+
+/// Returns the pseudo header of LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR.
+pub fn h4_header(packet_direction: PacketDirection, packet_type: u32) -> Vec<u8> {
+    // Record (direction, type, packet)
+    be_vec![packet_direction as u32, packet_type as u8]
+}
+
+/// The radiotap header of LINKTYPE_IEEE802_11_RADIOTAP, with the fields
+/// known of a frame.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Radiotap {
+    /// Data rate in 500 kbps units.
+    pub rate: Option<u8>,
+    /// Channel frequency in MHz.
+    pub frequency: Option<u16>,
+    /// Signal strength in dBm.
+    pub signal: Option<i8>,
+}
+
+impl Radiotap {
+    /// Returns the data rate of a mac80211_hwsim rate index in a band,
+    /// which starts at the OFDM rates in the 5 GHz band.
+    pub fn hwsim_rate(frequency: u32, index: u32) -> Option<u8> {
+        let offset = if frequency > 3000 { 4 } else { 0 };
+        HWSIM_RATES.get(index as usize + offset).copied()
+    }
+
+    /// Returns an 802.11 frame with the radiotap header.
+    pub fn record(&self, frame: &[u8]) -> Vec<u8> {
+        [self.header().as_slice(), frame].concat()
+    }
 
+    /// Returns the radiotap header of a frame.
+    pub fn header(&self) -> Vec<u8> {
+        // The fields in the order of their bits, aligned to their size.
+        let mut present = 0u32;
+        let mut fields = Vec::new();
+        if let Some(rate) = self.rate {
+            present |= RADIOTAP_RATE;
+            fields.push(rate);
+        }
+        if let Some(frequency) = self.frequency {
+            present |= RADIOTAP_CHANNEL;
+            let flags = match frequency {
+                ..=3000 => RADIOTAP_CHANNEL_2GHZ,
+                _ => RADIOTAP_CHANNEL_5GHZ,
+            };
+            fields.resize(fields.len().next_multiple_of(2), 0);
+            fields.extend(frequency.to_le_bytes());
+            fields.extend(flags.to_le_bytes());
+        }
+        if let Some(signal) = self.signal {
+            present |= RADIOTAP_DBM_ANTSIGNAL;
+            fields.push(signal as u8);
+        }
+        // Version, padding, little-endian length and bitmap of the fields.
+        let mut data = vec![0u8, 0];
+        data.extend((8 + fields.len() as u16).to_le_bytes());
+        data.extend(present.to_le_bytes());
+        data.extend(fields);
+        data
+    }
+}
+
+/// Writes a packet record of already encoded data, truncated to the
+/// snapshot length with the original length of the data.
+pub fn write_record(
+    timestamp: Duration,
+    output: &mut impl Write,
+    data: &[u8],
+    snaplen: u32,
+) -> Result<usize> {
+    write_record_parts(timestamp, output, &[data], snaplen)
+}
+
+/// Writes a packet record of data encoded in parts, like the pseudo header
+/// of the link type and the packet.
+pub fn write_record_parts(
+    timestamp: Duration,
+    output: &mut impl Write,
+    parts: &[&[u8]],
+    snaplen: u32,
+) -> Result<usize> {
     // https://tools.ietf.org/id/draft-gharris-opsawg-pcap-00.html#name-packet-record
-    let length = record.len() + packet.len();
+    let original_length = parts_len(parts);
+    let parts = snapshot(parts, snaplen);
+    let length = parts_len(&parts);
     let header: Vec<u8> = be_vec![
         timestamp.as_secs() as u32, // seconds
-        timestamp.subsec_micros(),  // microseconds
+        timestamp.subsec_nanos(),   // nanoseconds
         length as u32,              // Captured Packet Length
-        length as u32               // Original Packet Length
+        original_length as u32      // Original Packet Length
     ];
-    let mut bytes = Vec::<u8>::with_capacity(header.len() + length);
-    bytes.extend(&header);
-    bytes.extend(&record);
-    bytes.extend(packet);
-    output.write_all(&bytes)?;
+    output.write_all(&header)?;
+    write_parts(output, &parts)?;
     output.flush()?;
     Ok(header.len() + length)
 }
 
+// https://www.ietf.org/archive/id/draft-ietf-opsawg-pcapng-01.html
+const PCAPNG_SECTION_HEADER: u32 = 0x0a0d0d0a;
+const PCAPNG_INTERFACE_DESCRIPTION: u32 = 1;
+const PCAPNG_ENHANCED_PACKET: u32 = 6;
+const PCAPNG_INTERFACE_STATISTICS: u32 = 5;
+const PCAPNG_IF_NAME: u16 = 2;
+const PCAPNG_IF_TSRESOL: u16 = 9;
+const PCAPNG_EPB_FLAGS: u16 = 2;
+const PCAPNG_ISB_STARTTIME: u16 = 2;
+const PCAPNG_ISB_ENDTIME: u16 = 3;
+const PCAPNG_ISB_IFRECV: u16 = 4;
+
+// Appends an option, padded to 32 bits.
+fn pcapng_option(options: &mut Vec<u8>, code: u16, value: &[u8]) {
+    options.extend(code.to_be_bytes());
+    options.extend((value.len() as u16).to_be_bytes());
+    options.extend(value);
+    options.resize(options.len().next_multiple_of(4), 0);
+}
+
+// Writes a block with a body, given in parts, padded to 32 bits and its
+// options.
+fn write_pcapng_block(
+    output: &mut impl Write,
+    block_type: u32,
+    body: &[&[u8]],
+    options: &[u8],
+) -> Result<usize> {
+    let body_len = parts_len(body);
+    let padded = body_len.next_multiple_of(4);
+    // The options end with opt_endofopt.
+    let options_len = if options.is_empty() { 0 } else { options.len() + 4 };
+    let length = (12 + padded + options_len) as u32;
+    let header: Vec<u8> = be_vec![block_type, length];
+    output.write_all(&header)?;
+    write_parts(output, body)?;
+    output.write_all(&[0u8; 3][..padded - body_len])?;
+    if !options.is_empty() {
+        output.write_all(options)?;
+        output.write_all(&[0u8; 4])?;
+    }
+    output.write_all(&length.to_be_bytes())?;
+    Ok(length as usize)
+}
+
+/// Writes the section header of a pcapng file and the description of its
+/// interface, the chip of the capture named `if_name`. The records are
+/// truncated to the snapshot length, 0 is unlimited.
+pub fn write_pcapng_header(
+    output: &mut impl Write,
+    linktype: u32,
+    if_name: &str,
+    snaplen: u32,
+) -> Result<usize> {
+    let size = write_pcapng_section(output)?;
+    Ok(size + write_pcapng_interface(output, linktype, if_name, snaplen)?)
+}
+
+/// Writes the section header of a pcapng file, before its interfaces.
+pub fn write_pcapng_section(output: &mut impl Write) -> Result<usize> {
+    let section: Vec<u8> = be_vec![
+        0x1a2b3c4du32, // byte-order magic
+        1u16,          // major version
+        0u16,          // minor version
+        u64::MAX       // section length, unspecified
+    ];
+    let size = write_pcapng_block(output, PCAPNG_SECTION_HEADER, &[&section], &[])?;
+    output.flush()?;
+    Ok(size)
+}
+
+/// Writes the description of the next interface of a pcapng file, whose
+/// id is the number of interfaces before it. The timestamps of the
+/// interface are in nanoseconds.
+pub fn write_pcapng_interface(
+    output: &mut impl Write,
+    linktype: u32,
+    if_name: &str,
+    snaplen: u32,
+) -> Result<usize> {
+    let interface: Vec<u8> = be_vec![
+        linktype as u16, // link type
+        0u16,            // reserved
+        snaplen          // snaplen, 0 is unlimited
+    ];
+    let mut options = Vec::new();
+    pcapng_option(&mut options, PCAPNG_IF_NAME, if_name.as_bytes());
+    // 10^-9 seconds
+    pcapng_option(&mut options, PCAPNG_IF_TSRESOL, &[9]);
+    let size = write_pcapng_block(output, PCAPNG_INTERFACE_DESCRIPTION, &[&interface], &options)?;
+    output.flush()?;
+    Ok(size)
+}
+
+/// Writes an enhanced packet block of already encoded data, flagged with
+/// the direction of the packet: inbound to the host from the controller.
+/// The data is truncated to the snapshot length of the interface.
+pub fn write_pcapng_record(
+    timestamp: Duration,
+    output: &mut impl Write,
+    packet_direction: PacketDirection,
+    data: &[u8],
+    snaplen: u32,
+) -> Result<usize> {
+    write_pcapng_packet(timestamp, output, 0, packet_direction, &[data], snaplen)
+}
+
+/// Writes an enhanced packet block of an interface of a pcapng file with
+/// several interfaces, of data encoded in parts.
+pub fn write_pcapng_packet(
+    timestamp: Duration,
+    output: &mut impl Write,
+    interface: u32,
+    packet_direction: PacketDirection,
+    parts: &[&[u8]],
+    snaplen: u32,
+) -> Result<usize> {
+    // The timestamps are in nanoseconds, the if_tsresol of the interfaces.
+    let nanos = timestamp.as_nanos() as u64;
+    let original_length = parts_len(parts) as u32;
+    let parts = snapshot(parts, snaplen);
+    let fields: Vec<u8> = be_vec![
+        interface,                // interface id
+        (nanos >> 32) as u32,     // timestamp (high)
+        nanos as u32,             // timestamp (low)
+        parts_len(&parts) as u32, // captured packet length
+        original_length           // original packet length
+    ];
+    let body: Vec<&[u8]> = [fields.as_slice()].into_iter().chain(parts).collect();
+    let flags: u32 = match packet_direction {
+        PacketDirection::HostToController => 2,
+        PacketDirection::ControllerToHost => 1,
+    };
+    let mut options = Vec::new();
+    pcapng_option(&mut options, PCAPNG_EPB_FLAGS, &flags.to_be_bytes());
+    let size = write_pcapng_block(output, PCAPNG_ENHANCED_PACKET, &body, &options)?;
+    output.flush()?;
+    Ok(size)
+}
+
+// A timestamp in nanoseconds, the if_tsresol of the interfaces, as its
+// high and low 32 bits.
+fn pcapng_timestamp(timestamp: Duration) -> Vec<u8> {
+    let nanos = timestamp.as_nanos() as u64;
+    be_vec![(nanos >> 32) as u32, nanos as u32]
+}
+
+/// Writes the statistics block of an interface of a pcapng file, ending a
+/// capture at `timestamp`: the start of the capture and the packets the
+/// interface received, recorded or not.
+pub fn write_pcapng_statistics(
+    timestamp: Duration,
+    output: &mut impl Write,
+    interface: u32,
+    start: Duration,
+    received: u64,
+) -> Result<usize> {
+    let mut body = interface.to_be_bytes().to_vec();
+    body.extend(pcapng_timestamp(timestamp));
+    let mut options = Vec::new();
+    pcapng_option(&mut options, PCAPNG_ISB_STARTTIME, &pcapng_timestamp(start));
+    pcapng_option(&mut options, PCAPNG_ISB_ENDTIME, &pcapng_timestamp(timestamp));
+    pcapng_option(&mut options, PCAPNG_ISB_IFRECV, &received.to_be_bytes());
+    let size = write_pcapng_block(output, PCAPNG_INTERFACE_STATISTICS, &[&body], &options)?;
+    output.flush()?;
+    Ok(size)
+}
+
+/// Datalink of the btsnoop files of H4 records.
+pub const BTSNOOP_H4: u32 = 1002;
+/// Microseconds from the btsnoop epoch, year 0, to the Unix epoch.
+pub const BTSNOOP_EPOCH_DELTA: u64 = 0x00dc_ddb3_0f2f_8000;
+/// H4 packet types of the commands and the events.
+const H4_COMMAND: u32 = 1;
+const H4_EVENT: u32 = 4;
+
+/// Writes the header of a btsnoop file of H4 records.
+pub fn write_btsnoop_header(output: &mut impl Write) -> Result<usize> {
+    let fields: Vec<u8> = be_vec![
+        1u32,       // version
+        BTSNOOP_H4  // datalink
+    ];
+    let header = [b"btsnoop\0".as_slice(), &fields].concat();
+    output.write_all(&header)?;
+    Ok(header.len())
+}
+
+/// Writes a btsnoop record of a Bluetooth packet with its H4 packet type,
+/// truncated to the snapshot length.
+pub fn write_btsnoop_record(
+    timestamp: Duration,
+    output: &mut impl Write,
+    packet_direction: PacketDirection,
+    packet_type: u32,
+    packet: &[u8],
+    snaplen: u32,
+) -> Result<usize> {
+    let parts = [&[packet_type as u8][..], packet];
+    let original_length = parts_len(&parts) as u32;
+    let parts = snapshot(&parts, snaplen);
+    let length = parts_len(&parts);
+    // Bit 0 is set for the received packets, bit 1 for the commands and
+    // the events.
+    let mut flags = packet_direction as u32;
+    if packet_type == H4_COMMAND || packet_type == H4_EVENT {
+        flags |= 2;
+    }
+    let micros = timestamp.as_micros() as u64 + BTSNOOP_EPOCH_DELTA;
+    let header: Vec<u8> = be_vec![
+        original_length, // original length
+        length as u32,   // included length
+        flags,           // packet flags
+        0u32,            // cumulative drops
+        micros           // timestamp
+    ];
+    output.write_all(&header)?;
+    write_parts(output, &parts)?;
+    output.flush()?;
+    Ok(header.len() + length)
+}
+
+/// Largest record accepted by `read_record`.
+const MAX_RECORD_LEN: u32 = 16 << 20;
+
+/// The file header of a pcap file.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PcapHeader {
+    pub linktype: u32,
+    pub snaplen: u32,
+    big_endian: bool,
+    nanos: bool,
+}
+
+/// A packet record of a pcap file.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PcapRecord {
+    pub timestamp: Duration,
+    pub original_len: u32,
+    pub data: Vec<u8>,
+}
+
+fn invalid_data(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+impl PcapHeader {
+    fn u32(&self, bytes: &[u8]) -> u32 {
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        match self.big_endian {
+            true => u32::from_be_bytes(bytes),
+            false => u32::from_le_bytes(bytes),
+        }
+    }
+}
+
+/// Reads a pcap file header in either byte order.
+pub fn read_pcap_header<R: Read>(input: &mut R) -> Result<PcapHeader> {
+    let mut bytes = [0u8; 24];
+    input.read_exact(&mut bytes)?;
+    let (big_endian, nanos) = match bytes[0..4] {
+        [0xa1, 0xb2, 0xc3, 0xd4] => (true, false),
+        [0xd4, 0xc3, 0xb2, 0xa1] => (false, false),
+        [0xa1, 0xb2, 0x3c, 0x4d] => (true, true),
+        [0x4d, 0x3c, 0xb2, 0xa1] => (false, true),
+        _ => return Err(invalid_data("invalid pcap magic number")),
+    };
+    let mut header = PcapHeader { linktype: 0, snaplen: 0, big_endian, nanos };
+    header.snaplen = header.u32(&bytes[16..20]);
+    header.linktype = header.u32(&bytes[20..24]);
+    Ok(header)
+}
+
+/// Reads the next packet record, or None at the end of the file.
+pub fn read_record<R: Read>(input: &mut R, header: &PcapHeader) -> Result<Option<PcapRecord>> {
+    let mut bytes = [0u8; 16];
+    match input.read(&mut bytes[..1])? {
+        0 => return Ok(None),
+        _ => input.read_exact(&mut bytes[1..])?,
+    }
+    let seconds = header.u32(&bytes[0..4]);
+    let fraction = header.u32(&bytes[4..8]);
+    let captured_len = header.u32(&bytes[8..12]);
+    let original_len = header.u32(&bytes[12..16]);
+    if captured_len > MAX_RECORD_LEN {
+        return Err(invalid_data("pcap record too large"));
+    }
+    let nanos = match header.nanos {
+        true => fraction,
+        false => fraction.saturating_mul(1000),
+    };
+    if nanos >= 1_000_000_000 {
+        return Err(invalid_data("invalid pcap record timestamp"));
+    }
+    let mut data = vec![0u8; captured_len as usize];
+    input.read_exact(&mut data)?;
+    Ok(Some(PcapRecord { timestamp: Duration::new(seconds.into(), nanos), original_len, data }))
+}
+
 #[cfg(test)]
 mod tests {
     use std::{fs::File, io::Read, time::Duration};
 
     use crate::captures::pcap_util::{append_record, PacketDirection};
 
-    use super::write_pcap_header;
+    use super::*;
 
     static EXPECTED: &[u8; 76] = include_bytes!("sample.pcap");
 
@@ -93,13 +527,14 @@ mod tests {
         let mut temp_dir = std::env::temp_dir();
         temp_dir.push("test.pcap");
         if let Ok(mut file) = File::create(temp_dir.clone()) {
-            write_pcap_header(&mut file).unwrap();
+            write_pcap_header(&mut file, LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR, 0).unwrap();
             append_record(
                 Duration::from_secs(0),
                 &mut file,
                 PacketDirection::HostToController,
                 4u32,
                 &[14, 4, 1, 10, 32, 0],
+                0,
             )
             .unwrap();
             append_record(
@@ -108,6 +543,7 @@ mod tests {
                 PacketDirection::ControllerToHost,
                 1u32,
                 &[10, 32, 1, 0],
+                0,
             )
             .unwrap();
         } else {
@@ -124,4 +560,247 @@ mod tests {
             panic!("Cannot create temp file")
         }
     }
+
+    #[test]
+    fn test_pcapng_file() {
+        let mut output = Vec::new();
+        let size = write_pcapng_header(&mut output, 201, "1-phone-BLUETOOTH", 0).unwrap();
+        // The section header, then the interface description, its name and
+        // its nanosecond if_tsresol.
+        assert_eq!(size, 28 + 56);
+        assert_eq!(output[..4], [0x0a, 0x0d, 0x0d, 0x0a]);
+        assert_eq!(output[8..12], [0x1a, 0x2b, 0x3c, 0x4d]);
+        assert_eq!(output[28..32], [0, 0, 0, 1]);
+        assert_eq!(output[36..38], [0, 201]);
+        assert_eq!(output[44..48], [0, 2, 0, 17]);
+        assert_eq!(&output[48..65], b"1-phone-BLUETOOTH");
+        assert_eq!(output[68..76], [0, 9, 0, 1, 9, 0, 0, 0]);
+        assert_eq!(output[size - 4..], output[28 + 4..28 + 8]);
+        let packet = [1, 2, 3, 4, 5];
+        let record = write_pcapng_record(
+            Duration::from_millis(1500),
+            &mut output,
+            PacketDirection::ControllerToHost,
+            &packet,
+            0,
+        )
+        .unwrap();
+        assert_eq!(record, 28 + 8 + 12 + 4);
+        let block = &output[size..];
+        assert_eq!(block.len(), record);
+        assert_eq!(block[..8], [0, 0, 0, 6, 0, 0, 0, record as u8]);
+        // The timestamp in nanoseconds, then the lengths.
+        assert_eq!(block[12..20], 1_500_000_000u64.to_be_bytes());
+        assert_eq!(block[20..28], [0, 0, 0, 5, 0, 0, 0, 5]);
+        assert_eq!(block[28..36], [1, 2, 3, 4, 5, 0, 0, 0]);
+        // Inbound epb_flags and the end of the options.
+        assert_eq!(block[36..48], [0, 2, 0, 4, 0, 0, 0, 1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_pcapng_statistics() {
+        let mut output = Vec::new();
+        let size = write_pcapng_statistics(
+            Duration::from_secs(2),
+            &mut output,
+            0,
+            Duration::from_secs(1),
+            7,
+        )
+        .unwrap();
+        // The interface id and the timestamp, then the start time, the end
+        // time and the received packets options.
+        assert_eq!(size, 12 + 12 + 3 * 12 + 4);
+        assert_eq!(output.len(), size);
+        assert_eq!(output[..8], [0, 0, 0, 5, 0, 0, 0, size as u8]);
+        assert_eq!(output[8..20], [0, 0, 0, 0, 0, 0, 0, 0, 0x77, 0x35, 0x94, 0]);
+        assert_eq!(output[20..24], [0, 2, 0, 8]);
+        assert_eq!(output[24..32], 1_000_000_000u64.to_be_bytes());
+        assert_eq!(output[44..48], [0, 4, 0, 8]);
+        assert_eq!(output[48..56], 7u64.to_be_bytes());
+    }
+
+    #[test]
+    fn test_direction() {
+        // The pseudo header holds the direction as a big-endian u32.
+        assert_eq!(h4_record(PacketDirection::HostToController, 1, &[3]), [0, 0, 0, 0, 1, 3]);
+        assert_eq!(h4_record(PacketDirection::ControllerToHost, 4, &[3]), [0, 0, 0, 1, 4, 3]);
+        // Outbound epb_flags for the packets sent by the host.
+        let mut output = Vec::new();
+        write_pcapng_record(
+            Duration::ZERO,
+            &mut output,
+            PacketDirection::HostToController,
+            &[1],
+            0,
+        )
+        .unwrap();
+        assert_eq!(output[32..40], [0, 2, 0, 4, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn test_radiotap() {
+        let frame = [0x08, 0x00, 0x00, 0x00];
+        assert_eq!(Radiotap::default().record(&frame), [0, 0, 8, 0, 0, 0, 0, 0, 8, 0, 0, 0]);
+        // The rate, the channel of 2437 MHz after a padding byte, and the
+        // signal.
+        let radiotap = Radiotap { rate: Some(2), frequency: Some(2437), signal: Some(-50) };
+        assert_eq!(
+            radiotap.record(&frame)[..15],
+            [0, 0, 15, 0, 0x2c, 0, 0, 0, 2, 0, 0x85, 0x09, 0x80, 0x00, 0xce]
+        );
+        assert_eq!(radiotap.record(&frame)[15..], frame);
+        let channel = Radiotap { frequency: Some(5180), ..Default::default() };
+        assert_eq!(channel.record(&frame)[8..12], [0x3c, 0x14, 0x00, 0x01]);
+        // The 5 GHz band starts at 6 Mbps.
+        assert_eq!(Radiotap::hwsim_rate(2437, 1), Some(4));
+        assert_eq!(Radiotap::hwsim_rate(5180, 0), Some(12));
+        assert_eq!(Radiotap::hwsim_rate(5180, 8), None);
+    }
+
+    #[test]
+    fn test_btsnoop() {
+        let mut output = Vec::new();
+        assert_eq!(write_btsnoop_header(&mut output).unwrap(), 16);
+        assert_eq!(output, b"btsnoop\0\0\0\0\x01\0\0\x03\xea");
+        let size = write_btsnoop_record(
+            Duration::from_secs(1),
+            &mut output,
+            PacketDirection::ControllerToHost,
+            4,
+            &[0x0e, 0x04],
+            0,
+        )
+        .unwrap();
+        let record = &output[16..];
+        assert_eq!(size, 24 + 3);
+        // Lengths, flags of a received event and no drops.
+        assert_eq!(record[..16], [0, 0, 0, 3, 0, 0, 0, 3, 0, 0, 0, 3, 0, 0, 0, 0]);
+        assert_eq!(record[16..24], (BTSNOOP_EPOCH_DELTA + 1_000_000).to_be_bytes());
+        assert_eq!(record[24..], [4, 0x0e, 0x04]);
+        // A sent ACL packet truncated to the snapshot length.
+        let mut output = Vec::new();
+        write_btsnoop_record(
+            Duration::ZERO,
+            &mut output,
+            PacketDirection::HostToController,
+            2,
+            &[1; 10],
+            4,
+        )
+        .unwrap();
+        assert_eq!(output[..12], [0, 0, 0, 11, 0, 0, 0, 4, 0, 0, 0, 0]);
+        assert_eq!(output.len(), 24 + 4);
+    }
+
+    #[test]
+    fn test_snaplen() {
+        let mut output = Vec::new();
+        write_pcap_header(&mut output, LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR, 8).unwrap();
+        append_record(
+            Duration::ZERO,
+            &mut output,
+            PacketDirection::HostToController,
+            2,
+            &[7; 10],
+            8,
+        )
+        .unwrap();
+        append_record(
+            Duration::ZERO,
+            &mut output,
+            PacketDirection::HostToController,
+            2,
+            &[7; 2],
+            8,
+        )
+        .unwrap();
+        // The records hold the first bytes of the H4 records, with their
+        // original lengths.
+        let mut input = &output[..];
+        let header = read_pcap_header(&mut input).unwrap();
+        assert_eq!(header.snaplen, 8);
+        let first = read_record(&mut input, &header).unwrap().unwrap();
+        assert_eq!((first.data, first.original_len), (vec![0, 0, 0, 0, 2, 7, 7, 7], 15));
+        let second = read_record(&mut input, &header).unwrap().unwrap();
+        assert_eq!((second.data.len(), second.original_len), (7, 7));
+        // The pcapng interface holds the snapshot length, the enhanced
+        // packet block both lengths.
+        let mut output = Vec::new();
+        let size = write_pcapng_header(&mut output, 201, "1-phone-BLUETOOTH", 4).unwrap();
+        assert_eq!(output[40..44], [0, 0, 0, 4]);
+        write_pcapng_record(
+            Duration::ZERO,
+            &mut output,
+            PacketDirection::HostToController,
+            &[1; 6],
+            4,
+        )
+        .unwrap();
+        assert_eq!(output[size + 20..size + 28], [0, 0, 0, 4, 0, 0, 0, 6]);
+        assert_eq!(output[size + 28..size + 32], [1; 4]);
+        assert_eq!(output.len() - size, 28 + 4 + 12 + 4);
+    }
+
+    #[test]
+    fn test_record_parts() {
+        // The records of data in parts are those of the data joined, the
+        // snapshot length cutting into the header or into the packet.
+        let (header, packet) = ([1u8, 2, 3], [4u8; 6]);
+        let joined = [&header[..], &packet].concat();
+        for snaplen in [0, 2, 5, 20] {
+            let (mut parts_output, mut joined_output) = (Vec::new(), Vec::new());
+            let size =
+                write_record_parts(Duration::ZERO, &mut parts_output, &[&header, &packet], snaplen)
+                    .unwrap();
+            write_record(Duration::ZERO, &mut joined_output, &joined, snaplen).unwrap();
+            assert_eq!(parts_output, joined_output);
+            assert_eq!(size, parts_output.len());
+            let (mut parts_output, mut joined_output) = (Vec::new(), Vec::new());
+            let direction = PacketDirection::ControllerToHost;
+            let parts = [&header[..], &packet];
+            let size = write_pcapng_packet(
+                Duration::ZERO,
+                &mut parts_output,
+                1,
+                direction,
+                &parts,
+                snaplen,
+            )
+            .unwrap();
+            write_pcapng_packet(
+                Duration::ZERO,
+                &mut joined_output,
+                1,
+                direction,
+                &[&joined],
+                snaplen,
+            )
+            .unwrap();
+            assert_eq!(parts_output, joined_output);
+            assert_eq!(size, parts_output.len());
+        }
+    }
+
+    #[test]
+    fn test_read_pcap() {
+        let mut input = &EXPECTED[..];
+        let header = read_pcap_header(&mut input).unwrap();
+        assert_eq!(header.linktype, 201);
+        let first = read_record(&mut input, &header).unwrap().unwrap();
+        assert_eq!(first.timestamp, Duration::from_secs(0));
+        assert_eq!(first.data, [0, 0, 0, 0, 4, 14, 4, 1, 10, 32, 0]);
+        let second = read_record(&mut input, &header).unwrap().unwrap();
+        assert_eq!(second.timestamp, Duration::from_millis(250));
+        assert_eq!(second.original_len, 9);
+        assert!(read_record(&mut input, &header).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_read_pcap_invalid() {
+        assert!(read_pcap_header(&mut &[0u8; 24][..]).is_err());
+        let mut input = &EXPECTED[..30];
+        let header = read_pcap_header(&mut input).unwrap();
+        assert!(read_record(&mut input, &header).is_err());
+    }
 }
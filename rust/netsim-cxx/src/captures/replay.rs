@@ -0,0 +1,271 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replay of recorded Bluetooth captures.
+//!
+//! A replay reads a pcap file of H4 records with the direction pseudo
+//! header, e.g. a capture of netsimd, or a btsnoop file, e.g. the HCI snoop
+//! log of a phone, and sends the packets of the host to a Bluetooth chip
+//! through packet_hub as if its emulator sent them, at their recorded pace
+//! scaled by the speed of the request. The packets of the controller are
+//! skipped: the chip answers the replayed packets itself. With the
+//! `debug.clock=manual` setting the replay advances the clock to each packet
+//! instead of sleeping, like the replay of a session.
+//!
+//! /v1/captures/replay --> handle_capture_replay (POST a ReplayCaptureRequest)
+
+use frontend_proto::frontend::{ReplayCaptureRequest, ReplayCaptureResponse};
+use std::fs::File;
+use std::io::{BufReader, Error, ErrorKind, Read, Result};
+use std::time::Duration;
+
+use crate::clock::{clock, manual_clock};
+use crate::ffi::handle_request_cxx;
+
+use super::pcap_util::{
+    read_pcap_header, read_record, PacketDirection, BTSNOOP_EPOCH_DELTA, BTSNOOP_H4,
+    LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR,
+};
+
+/// Largest btsnoop record accepted by the replay.
+const MAX_RECORD_LEN: u32 = 16 << 20;
+
+/// A packet of a recorded capture.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReplayPacket {
+    pub timestamp: Duration,
+    pub direction: PacketDirection,
+    pub packet_type: u8,
+    pub packet: Vec<u8>,
+    // Truncated by the snapshot length of the recording.
+    pub truncated: bool,
+}
+
+fn invalid_data(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+fn direction(received: bool) -> PacketDirection {
+    match received {
+        true => PacketDirection::ControllerToHost,
+        false => PacketDirection::HostToController,
+    }
+}
+
+// Splits the H4 packet type from the packet.
+fn h4_packet(
+    timestamp: Duration,
+    direction: PacketDirection,
+    data: &[u8],
+    truncated: bool,
+) -> Result<ReplayPacket> {
+    let (&packet_type, packet) = data.split_first().ok_or(invalid_data("empty H4 record"))?;
+    Ok(ReplayPacket { timestamp, direction, packet_type, packet: packet.to_vec(), truncated })
+}
+
+fn read_pcap(input: &mut impl Read) -> Result<Vec<ReplayPacket>> {
+    let header = read_pcap_header(input)?;
+    if header.linktype != LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR {
+        return Err(invalid_data("only pcap files of Bluetooth H4 records are replayed"));
+    }
+    let mut packets = Vec::new();
+    while let Some(record) = read_record(input, &header)? {
+        // The direction pseudo header is in network byte order.
+        let phdr = record.data.get(..4).ok_or(invalid_data("record without pseudo header"))?;
+        let received = u32::from_be_bytes([phdr[0], phdr[1], phdr[2], phdr[3]]) != 0;
+        let truncated = record.original_len as usize > record.data.len();
+        packets.push(h4_packet(
+            record.timestamp,
+            direction(received),
+            &record.data[4..],
+            truncated,
+        )?);
+    }
+    Ok(packets)
+}
+
+fn read_btsnoop(input: &mut impl Read) -> Result<Vec<ReplayPacket>> {
+    let mut header = [0u8; 8];
+    input.read_exact(&mut header)?;
+    if u32::from_be_bytes([header[4], header[5], header[6], header[7]]) != BTSNOOP_H4 {
+        return Err(invalid_data("only btsnoop files of H4 records are replayed"));
+    }
+    let mut packets = Vec::new();
+    loop {
+        let mut bytes = [0u8; 24];
+        match input.read(&mut bytes[..1])? {
+            0 => return Ok(packets),
+            _ => input.read_exact(&mut bytes[1..])?,
+        }
+        let field =
+            |i: usize| u32::from_be_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+        let (original_len, included_len, flags) = (field(0), field(4), field(8));
+        if included_len > MAX_RECORD_LEN {
+            return Err(invalid_data("btsnoop record too large"));
+        }
+        let micros = u64::from_be_bytes(bytes[16..24].try_into().unwrap());
+        let timestamp = Duration::from_micros(micros.saturating_sub(BTSNOOP_EPOCH_DELTA));
+        let mut data = vec![0u8; included_len as usize];
+        input.read_exact(&mut data)?;
+        let truncated = original_len > included_len;
+        packets.push(h4_packet(timestamp, direction(flags & 1 != 0), &data, truncated)?);
+    }
+}
+
+/// Reads the packets of a pcap or btsnoop file of H4 records.
+pub fn read_capture(input: &mut impl Read) -> Result<Vec<ReplayPacket>> {
+    let mut magic = [0u8; 8];
+    input.read_exact(&mut magic)?;
+    match &magic {
+        b"btsnoop\0" => read_btsnoop(input),
+        _ => read_pcap(&mut magic.as_slice().chain(input)),
+    }
+}
+
+/// Sends the packets of the host to `inject`, waiting with `wait_until`
+/// for the time of each packet since the first one, scaled by `speed`; a
+/// burst replay doesn't wait. Returns the counts of the injected and the
+/// skipped packets.
+pub fn replay(
+    packets: &[ReplayPacket],
+    speed: f64,
+    burst: bool,
+    mut wait_until: impl FnMut(Duration),
+    mut inject: impl FnMut(&ReplayPacket),
+) -> (u64, u64) {
+    let (mut injected, mut skipped) = (0, 0);
+    let mut start = None;
+    for packet in packets {
+        if packet.direction != PacketDirection::HostToController || packet.truncated {
+            skipped += 1;
+            continue;
+        }
+        let start = *start.get_or_insert(packet.timestamp);
+        if !burst {
+            wait_until(packet.timestamp.saturating_sub(start).div_f64(speed));
+        }
+        inject(packet);
+        injected += 1;
+    }
+    (injected, skipped)
+}
+
+/// Replays a capture file to the Bluetooth chip of `facade_id`.
+pub fn replay_capture(
+    request: &ReplayCaptureRequest,
+    kind: u32,
+    facade_id: u32,
+) -> Result<ReplayCaptureResponse> {
+    let packets = read_capture(&mut BufReader::new(File::open(&request.path)?))?;
+    // A speed of 0 keeps the recorded pace.
+    let speed = if request.speed == 0.0 { 1.0 } else { request.speed };
+    let start = clock().monotonic();
+    let (injected, skipped) = replay(
+        &packets,
+        speed,
+        request.burst,
+        |time| {
+            let elapsed = clock().monotonic().saturating_sub(start);
+            if time > elapsed {
+                match manual_clock() {
+                    Some(clock) => clock.advance(time - elapsed),
+                    None => clock().sleep(time - elapsed),
+                }
+            }
+        },
+        |packet| handle_request_cxx(kind, facade_id, &packet.packet, packet.packet_type),
+    );
+    Ok(ReplayCaptureResponse { injected, skipped, ..Default::default() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::captures::pcap_util::{
+        append_record, write_btsnoop_header, write_btsnoop_record, write_pcap_header,
+    };
+
+    fn packet(millis: u64, direction: PacketDirection, packet_type: u8) -> ReplayPacket {
+        ReplayPacket {
+            timestamp: Duration::from_millis(millis),
+            direction,
+            packet_type,
+            packet: vec![1, 2, 3],
+            truncated: false,
+        }
+    }
+
+    #[test]
+    fn test_read_pcap() {
+        let mut file = Vec::new();
+        write_pcap_header(&mut file, LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR, 0).unwrap();
+        let time = Duration::from_millis(5);
+        append_record(time, &mut file, PacketDirection::HostToController, 1, &[1, 2, 3], 0)
+            .unwrap();
+        append_record(time, &mut file, PacketDirection::ControllerToHost, 4, &[4, 5, 6], 0)
+            .unwrap();
+        let packets = read_capture(&mut file.as_slice()).unwrap();
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0], packet(5, PacketDirection::HostToController, 1));
+        assert_eq!(packets[1].direction, PacketDirection::ControllerToHost);
+        assert_eq!(packets[1].packet, [4, 5, 6]);
+    }
+
+    #[test]
+    fn test_read_btsnoop() {
+        let mut file = Vec::new();
+        write_btsnoop_header(&mut file).unwrap();
+        let time = Duration::from_millis(5);
+        write_btsnoop_record(time, &mut file, PacketDirection::HostToController, 1, &[1, 2, 3], 0)
+            .unwrap();
+        // Truncated by the snapshot length.
+        write_btsnoop_record(time, &mut file, PacketDirection::HostToController, 2, &[1, 2, 3], 2)
+            .unwrap();
+        let packets = read_capture(&mut file.as_slice()).unwrap();
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0], packet(5, PacketDirection::HostToController, 1));
+        assert!(packets[1].truncated);
+    }
+
+    #[test]
+    fn test_read_capture_invalid() {
+        let mut file = Vec::new();
+        write_pcap_header(&mut file, 1, 0).unwrap();
+        assert!(read_capture(&mut file.as_slice()).is_err());
+        assert!(read_capture(&mut [0u8; 4].as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut packets = vec![
+            packet(100, PacketDirection::HostToController, 1),
+            packet(150, PacketDirection::ControllerToHost, 4),
+            packet(300, PacketDirection::HostToController, 2),
+        ];
+        let mut waits = Vec::new();
+        let mut types = Vec::new();
+        let counts =
+            replay(&packets, 2.0, false, |time| waits.push(time), |p| types.push(p.packet_type));
+        assert_eq!(counts, (2, 1));
+        // The times since the first packet, at twice the recorded pace.
+        assert_eq!(waits, [Duration::ZERO, Duration::from_millis(100)]);
+        assert_eq!(types, [1, 2]);
+        // A burst replay doesn't wait, and truncated packets are skipped.
+        packets[2].truncated = true;
+        waits.clear();
+        let counts = replay(&packets, 1.0, true, |time| waits.push(time), |_| {});
+        assert_eq!(counts, (1, 2));
+        assert!(waits.is_empty());
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Background writer of the capture files.
+//!
+//! The packet handlers record a packet holding the lock of its capture, so
+//! writing the file on their thread stalls the forwarding of the packets
+//! whenever the disk is slow. A CaptureWriter queues the records for a
+//! writer task of the netsimd runtime instead, so the captures don't hold
+//! a thread each. The task writes the records through a BufWriter flushed
+//! every FLUSH_INTERVAL. The packet handlers only block while the queue is
+//! full. The writer of a live capture also writes the records to its named
+//! pipe. The bytes written to the files of all captures are counted for
+//! the netsim_capture_bytes_written_total metric.
+
+use std::fs::File;
+use std::io::{Error, Result};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{sync_channel, Receiver as ReplyReceiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+use tokio::time::{timeout_at, Instant};
+
+use super::live::LivePipe;
+use crate::buffer_pool::PooledBuffer;
+use crate::runtime::runtime;
+
+/// Records queued for the writer task of a capture.
+const WRITE_QUEUE_LEN: usize = 4096;
+/// Longest time a written record stays in the buffer of the writer.
+const FLUSH_INTERVAL: Duration = Duration::from_millis(100);
+
+// Bytes written by the writers of all captures.
+static BYTES_WRITTEN: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the bytes written by the writers of all captures.
+pub fn bytes_written() -> u64 {
+    BYTES_WRITTEN.load(Ordering::Relaxed)
+}
+
+/// A record of a capture, framed once and shared by the writer task, the
+/// ring buffer and the subscribers of the capture rather than copied. Its
+/// buffer returns to the buffer pool once they all dropped it.
+pub type Record = Arc<PooledBuffer>;
+
+enum Command {
+    Record(Record),
+    // Flushes the queued records and replies with the result.
+    Flush(SyncSender<Result<()>>),
+    // Flushes the queued records, syncs the file to the disk and replies
+    // with the result.
+    Sync(SyncSender<Result<()>>),
+}
+
+/// Writes the records of a capture to its file on a writer task. The file
+/// is flushed and closed when the writer is dropped.
+///
+/// The methods block the calling thread, so they are called by the packet
+/// handlers and the blocking http handlers, never by a task of the runtime.
+pub struct CaptureWriter {
+    sender: Option<Sender<Command>>,
+    // Disconnected once the task wrote the queued records and exited.
+    done: ReplyReceiver<()>,
+    // The first failure of the writer task, returned by the next write.
+    error: Arc<Mutex<Option<Error>>>,
+}
+
+impl CaptureWriter {
+    pub fn new(file: File, live: Option<Arc<LivePipe>>) -> Result<Self> {
+        let (sender, receiver) = channel(WRITE_QUEUE_LEN);
+        let (done_sender, done) = sync_channel(0);
+        let error = Arc::new(Mutex::new(None));
+        let task_error = error.clone();
+        let file = tokio::fs::File::from_std(file);
+        runtime().spawn(async move {
+            run(file, live, receiver, task_error).await;
+            drop(done_sender);
+        });
+        Ok(CaptureWriter { sender: Some(sender), done, error })
+    }
+
+    fn send(&self, command: Command) -> Result<()> {
+        match self.sender.as_ref().map(|sender| sender.blocking_send(command)) {
+            Some(Ok(())) => Ok(()),
+            _ => Err(Error::other("the capture writer stopped")),
+        }
+    }
+
+    /// Queues a record, or returns the failure of an earlier write.
+    pub fn write(&self, record: Record) -> Result<()> {
+        if let Some(err) = self.error.lock().unwrap().take() {
+            return Err(err);
+        }
+        self.send(Command::Record(record))
+    }
+
+    /// Waits for the queued records to be written and flushed to the file,
+    /// e.g. before the file is read.
+    pub fn flush(&self) -> Result<()> {
+        let (sender, receiver) = sync_channel(1);
+        self.send(Command::Flush(sender))?;
+        receiver.recv().map_err(|_| Error::other("the capture writer stopped"))?
+    }
+
+    /// Waits for the queued records to be written, flushed and synced to
+    /// the disk, e.g. before netsimd exits.
+    pub fn sync(&self) -> Result<()> {
+        let (sender, receiver) = sync_channel(1);
+        self.send(Command::Sync(sender))?;
+        receiver.recv().map_err(|_| Error::other("the capture writer stopped"))?
+    }
+}
+
+impl Drop for CaptureWriter {
+    fn drop(&mut self) {
+        // The task writes the queued records and exits as the channel
+        // closes.
+        self.sender = None;
+        let _ = self.done.recv();
+    }
+}
+
+async fn run(
+    file: tokio::fs::File,
+    live: Option<Arc<LivePipe>>,
+    mut receiver: Receiver<Command>,
+    error: Arc<Mutex<Option<Error>>>,
+) {
+    let mut output = BufWriter::new(file);
+    let mut flushed_at = Instant::now();
+    let report = |result: Result<()>| {
+        if let Err(err) = result {
+            error.lock().unwrap().get_or_insert(err);
+        }
+    };
+    loop {
+        match timeout_at(flushed_at + FLUSH_INTERVAL, receiver.recv()).await {
+            Ok(Some(Command::Record(record))) => {
+                let result = output.write_all(&record).await;
+                if result.is_ok() {
+                    BYTES_WRITTEN.fetch_add(record.len() as u64, Ordering::Relaxed);
+                }
+                report(result);
+                // The pipe is non-blocking.
+                if let Some(live) = live.as_ref() {
+                    live.write(&record);
+                }
+            }
+            Ok(Some(Command::Flush(reply))) => {
+                flushed_at = Instant::now();
+                let _ = reply.send(output.flush().await);
+                continue;
+            }
+            Ok(Some(Command::Sync(reply))) => {
+                flushed_at = Instant::now();
+                let result = match output.flush().await {
+                    Ok(()) => output.get_ref().sync_all().await,
+                    Err(err) => Err(err),
+                };
+                let _ = reply.send(result);
+                continue;
+            }
+            // A reader of the pipe is attached while the capture is idle.
+            Err(_) => {
+                if let Some(live) = live.as_ref() {
+                    live.poll();
+                }
+            }
+            Ok(None) => {
+                report(output.flush().await);
+                return;
+            }
+        }
+        if flushed_at.elapsed() >= FLUSH_INTERVAL {
+            flushed_at = Instant::now();
+            report(output.flush().await);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("netsim-{name}-{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_capture_writer() {
+        let path = temp_file("writer-test");
+        let written = bytes_written();
+        let writer = CaptureWriter::new(File::create(&path).unwrap(), None).unwrap();
+        writer.write(Record::new(vec![1, 2, 3].into())).unwrap();
+        writer.write(Record::new(vec![4].into())).unwrap();
+        writer.flush().unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), [1, 2, 3, 4]);
+        writer.write(Record::new(vec![5, 6].into())).unwrap();
+        writer.sync().unwrap();
+        assert_eq!(std::fs::read(&path).unwrap().len(), 6);
+        // The queued records are written as the writer is dropped.
+        writer.write(Record::new(vec![5; 10].into())).unwrap();
+        drop(writer);
+        assert_eq!(std::fs::read(&path).unwrap().len(), 16);
+        // Other tests may write captures meanwhile.
+        assert!(bytes_written() >= written + 16);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_capture_writer_error() {
+        let path = temp_file("writer-error-test");
+        std::fs::write(&path, []).unwrap();
+        // A file opened read only fails the writes.
+        let writer = CaptureWriter::new(File::open(&path).unwrap(), None).unwrap();
+        writer.write(Record::new(vec![1].into())).unwrap();
+        assert!(writer.flush().is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
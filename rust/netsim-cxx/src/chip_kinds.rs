@@ -0,0 +1,385 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Custom chip kinds.
+//!
+//! Embedders register chip kinds beyond the built-in ChipKind values at
+//! runtime, with a name, the link type of their captures and their routing.
+//! A chip of a registered kind is streamed like a Wi-Fi chip and served by
+//! a generic facade: packet_hub delivers its packets to the other chips of
+//! the kind, either to all of them or to the one addressed by the packet,
+//! and the captures record them as is.
+//!
+//! /v1/chip_kinds --> handle_chip_kinds (GET to list, POST a ChipKindInfo
+//! to register)
+
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::chip_kind_info::Routing;
+use frontend_proto::frontend::{ChipKindInfo, ListChipKindsResponse};
+use lazy_static::lazy_static;
+use protobuf::Enum;
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::collections::{BTreeMap, HashMap};
+use std::pin::Pin;
+use std::sync::RwLock;
+
+use crate::captures::pcap_util::{
+    LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR, LINKTYPE_FIRA_UCI, LINKTYPE_IEEE802_11_RADIOTAP,
+    LINKTYPE_NFC_NCI,
+};
+use crate::ffi::{CxxServerResponseWriter, RoutedPacket};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::warn;
+use crate::session::record_chip_kind;
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+/// Id of the first registered chip kind, leaving room for built-in kinds.
+pub const FIRST_CUSTOM_KIND: u32 = 100;
+/// Length of the destination chip id of point-to-point packets.
+const ADDRESS_LEN: usize = 4;
+
+fn builtin(kind: ChipKind, linktype: u32) -> ChipKindInfo {
+    ChipKindInfo {
+        id: kind.value() as u32,
+        name: format!("{:?}", kind),
+        linktype,
+        routing: Routing::BROADCAST.into(),
+        builtin: true,
+        ..Default::default()
+    }
+}
+
+/// The chip kinds and the chips of the registered kinds.
+pub struct ChipKinds {
+    kinds: BTreeMap<u32, ChipKindInfo>,
+    // Chip ids by facade id, for each registered kind.
+    chips: HashMap<u32, BTreeMap<u32, u32>>,
+    next_facade_id: u32,
+}
+
+impl Default for ChipKinds {
+    fn default() -> Self {
+        let kinds = [
+            builtin(ChipKind::BLUETOOTH, LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR),
+            builtin(ChipKind::WIFI, LINKTYPE_IEEE802_11_RADIOTAP),
+            builtin(ChipKind::UWB, LINKTYPE_FIRA_UCI),
+            builtin(ChipKind::NFC, LINKTYPE_NFC_NCI),
+        ];
+        ChipKinds {
+            kinds: kinds.into_iter().map(|kind| (kind.id, kind)).collect(),
+            chips: HashMap::new(),
+            next_facade_id: 1,
+        }
+    }
+}
+
+impl ChipKinds {
+    /// Registers a chip kind and returns its id. Registering a kind again
+    /// with the same parameters returns the id of the existing kind.
+    pub fn register(&mut self, mut info: ChipKindInfo) -> Result<ChipKindInfo, String> {
+        if info.name.is_empty() {
+            return Err("chip kind name is empty".to_string());
+        }
+        if let Some(existing) =
+            self.kinds.values().find(|kind| kind.name.eq_ignore_ascii_case(&info.name))
+        {
+            if existing.builtin
+                || existing.linktype != info.linktype
+                || existing.routing != info.routing
+            {
+                return Err(format!("chip kind {} is already registered", info.name));
+            }
+            return Ok(existing.clone());
+        }
+        info.id = self
+            .kinds
+            .keys()
+            .next_back()
+            .map_or(FIRST_CUSTOM_KIND, |id| (*id + 1).max(FIRST_CUSTOM_KIND));
+        info.builtin = false;
+        self.kinds.insert(info.id, info.clone());
+        self.chips.insert(info.id, BTreeMap::new());
+        Ok(info)
+    }
+
+    pub fn get(&self, kind: u32) -> Option<&ChipKindInfo> {
+        self.kinds.get(&kind)
+    }
+
+    /// Whether `kind` is a registered kind served by the generic facade.
+    pub fn is_custom(&self, kind: u32) -> bool {
+        self.chips.contains_key(&kind)
+    }
+
+    pub fn list(&self) -> ListChipKindsResponse {
+        ListChipKindsResponse {
+            kinds: self.kinds.values().cloned().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a chip of a registered kind and returns its facade id.
+    pub fn add_chip(&mut self, kind: u32, chip_id: u32) -> Option<u32> {
+        let chips = self.chips.get_mut(&kind)?;
+        let facade_id = self.next_facade_id;
+        self.next_facade_id += 1;
+        chips.insert(facade_id, chip_id);
+        Some(facade_id)
+    }
+
+    pub fn remove_chip(&mut self, kind: u32, facade_id: u32) {
+        if let Some(chips) = self.chips.get_mut(&kind) {
+            chips.remove(&facade_id);
+        }
+    }
+
+    /// Returns the packets to deliver for a packet of the chip of
+    /// `facade_id`, by facade id of the receiving chips.
+    pub fn route(&self, kind: u32, facade_id: u32, packet: &[u8]) -> Vec<(u32, Vec<u8>)> {
+        let (Some(info), Some(chips)) = (self.kinds.get(&kind), self.chips.get(&kind)) else {
+            return Vec::new();
+        };
+        let Some(source) = chips.get(&facade_id) else {
+            return Vec::new();
+        };
+        match info.routing.enum_value_or_default() {
+            Routing::BROADCAST => chips
+                .keys()
+                .filter(|id| **id != facade_id)
+                .map(|id| (*id, packet.to_vec()))
+                .collect(),
+            Routing::POINT_TO_POINT => {
+                let Some(address) = packet.get(..ADDRESS_LEN) else {
+                    return Vec::new();
+                };
+                let destination = u32::from_le_bytes(address.try_into().unwrap());
+                let Some((id, _)) = chips
+                    .iter()
+                    .find(|(id, chip_id)| **chip_id == destination && **id != facade_id)
+                else {
+                    return Vec::new();
+                };
+                let mut delivered = source.to_le_bytes().to_vec();
+                delivered.extend_from_slice(&packet[ADDRESS_LEN..]);
+                vec![(*id, delivered)]
+            }
+        }
+    }
+}
+
+lazy_static! {
+    static ref CHIP_KINDS: RwLock<ChipKinds> = RwLock::new(ChipKinds::default());
+}
+
+/// Returns the capture link type of a chip kind, None for unknown kinds.
+pub fn linktype(kind: u32) -> Option<u32> {
+    CHIP_KINDS.read().unwrap().get(kind).map(|info| info.linktype)
+}
+
+/// Returns the name of a chip kind, None for unknown kinds.
+pub fn chip_kind_name(kind: u32) -> Option<String> {
+    CHIP_KINDS.read().unwrap().get(kind).map(|info| info.name.clone())
+}
+
+/// Returns the names of the chip kinds, the built-in kinds first.
+pub fn chip_kind_names() -> Vec<String> {
+    CHIP_KINDS.read().unwrap().kinds.values().map(|info| info.name.clone()).collect()
+}
+
+/// Whether `kind` is a registered kind served by the generic facade.
+pub fn is_custom_chip_kind(kind: u32) -> bool {
+    CHIP_KINDS.read().unwrap().is_custom(kind)
+}
+
+/// Registers a chip kind, recording it in the session log.
+pub fn register(info: ChipKindInfo) -> Result<ChipKindInfo, String> {
+    let info = CHIP_KINDS.write().unwrap().register(info)?;
+    record_chip_kind(&info);
+    Ok(info)
+}
+
+/// Registers a chip kind with the routing given as its ChipKindInfo.Routing
+/// value. Returns the id of the kind, or 0 when it can't be registered.
+pub fn register_chip_kind(name: &str, linktype: u32, routing: u32) -> u32 {
+    let Some(routing) = Routing::from_i32(routing as i32) else {
+        warn!("unknown routing {routing} of chip kind {name}");
+        return 0;
+    };
+    let info = ChipKindInfo {
+        name: name.to_string(),
+        linktype,
+        routing: routing.into(),
+        ..Default::default()
+    };
+    match register(info) {
+        Ok(info) => info.id,
+        Err(e) => {
+            warn!("{e}");
+            0
+        }
+    }
+}
+
+/// The Rust chip kinds handler used directly by Http frontend for GET and
+/// POST
+pub fn handle_chip_kinds(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    let json_response = match request.method.as_str() {
+        "GET" => {
+            print_to_string_with_options(&CHIP_KINDS.read().unwrap().list(), &JSON_PRINT_OPTION)
+        }
+        "POST" => {
+            let body = String::from_utf8_lossy(&request.body);
+            let info = match protobuf_json_mapping::parse_from_str::<ChipKindInfo>(&body) {
+                Ok(info) => info,
+                Err(e) => {
+                    writer.put_error(400, format!("Invalid chip kind: {e}").as_str());
+                    return;
+                }
+            };
+            match register(info) {
+                Ok(info) => print_to_string_with_options(&info, &JSON_PRINT_OPTION),
+                Err(e) => {
+                    writer.put_error(400, e.as_str());
+                    return;
+                }
+            }
+        }
+        _ => {
+            writer.put_error(404, "Not found.");
+            return;
+        }
+    };
+    if let Ok(json_response) = json_response {
+        writer.put_ok("text/json", &json_response, &[])
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure")
+    }
+}
+
+/// chip kinds handle cxx for grpc server to call
+pub fn handle_chip_kinds_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/chip_kinds".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_chip_kinds(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+// Cxx Methods for the chips and packet_hub to invoke
+
+/// Returns the facade id of a new chip of a registered kind, 0 for other
+/// kinds.
+pub fn add_generic_chip(kind: u32, chip_id: u32) -> u32 {
+    CHIP_KINDS.write().unwrap().add_chip(kind, chip_id).unwrap_or(0)
+}
+
+pub fn remove_generic_chip(kind: u32, facade_id: u32) {
+    CHIP_KINDS.write().unwrap().remove_chip(kind, facade_id);
+}
+
+pub fn route_generic_request(kind: u32, facade_id: u32, packet: &[u8]) -> Vec<RoutedPacket> {
+    CHIP_KINDS
+        .read()
+        .unwrap()
+        .route(kind, facade_id, packet)
+        .into_iter()
+        .map(|(facade_id, packet)| RoutedPacket { facade_id, packet })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(name: &str, routing: Routing) -> ChipKindInfo {
+        ChipKindInfo {
+            name: name.to_string(),
+            linktype: 147,
+            routing: routing.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        let mut kinds = ChipKinds::default();
+        let lora = kinds.register(info("LORA", Routing::BROADCAST)).unwrap();
+        assert_eq!(lora.id, FIRST_CUSTOM_KIND);
+        assert!(kinds.is_custom(lora.id));
+        assert!(!kinds.is_custom(ChipKind::BLUETOOTH.value() as u32));
+        // Registering again is idempotent, unless the parameters differ.
+        assert_eq!(kinds.register(info("lora", Routing::BROADCAST)).unwrap().id, lora.id);
+        assert!(kinds.register(info("LORA", Routing::POINT_TO_POINT)).is_err());
+        assert!(kinds.register(info("WIFI", Routing::BROADCAST)).is_err());
+        assert!(kinds.register(info("", Routing::BROADCAST)).is_err());
+        assert!(kinds.register(info("NFC", Routing::POINT_TO_POINT)).is_err());
+        let zigbee = kinds.register(info("ZIGBEE", Routing::POINT_TO_POINT)).unwrap();
+        assert_eq!(zigbee.id, FIRST_CUSTOM_KIND + 1);
+        assert_eq!(kinds.list().kinds.len(), 6);
+        assert_eq!(kinds.get(zigbee.id).unwrap().linktype, 147);
+    }
+
+    #[test]
+    fn test_route_broadcast() {
+        let mut kinds = ChipKinds::default();
+        let kind = kinds.register(info("LORA", Routing::BROADCAST)).unwrap().id;
+        let a = kinds.add_chip(kind, 1000).unwrap();
+        let b = kinds.add_chip(kind, 1001).unwrap();
+        let c = kinds.add_chip(kind, 1002).unwrap();
+        assert_eq!(kinds.route(kind, a, &[1, 2]), [(b, vec![1, 2]), (c, vec![1, 2])]);
+        kinds.remove_chip(kind, c);
+        assert_eq!(kinds.route(kind, a, &[1, 2]), [(b, vec![1, 2])]);
+        // Chips of other kinds are not routed.
+        assert!(kinds.add_chip(ChipKind::WIFI.value() as u32, 1003).is_none());
+        assert!(kinds.route(ChipKind::WIFI.value() as u32, a, &[1, 2]).is_empty());
+    }
+
+    #[test]
+    fn test_route_point_to_point() {
+        let mut kinds = ChipKinds::default();
+        let kind = kinds.register(info("ZIGBEE", Routing::POINT_TO_POINT)).unwrap().id;
+        let a = kinds.add_chip(kind, 1000).unwrap();
+        let b = kinds.add_chip(kind, 1001).unwrap();
+        kinds.add_chip(kind, 1002).unwrap();
+        let mut packet = 1001u32.to_le_bytes().to_vec();
+        packet.push(7);
+        let mut delivered = 1000u32.to_le_bytes().to_vec();
+        delivered.push(7);
+        assert_eq!(kinds.route(kind, a, &packet), [(b, delivered)]);
+        // Unknown destinations, the source itself and short packets.
+        assert!(kinds.route(kind, a, &[0xff, 0xff, 0, 0, 7]).is_empty());
+        assert!(kinds.route(kind, b, &packet).is_empty());
+        assert!(kinds.route(kind, a, &[1, 2]).is_empty());
+    }
+}
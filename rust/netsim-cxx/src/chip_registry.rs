@@ -0,0 +1,148 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Registry of the chips of the scene and their facades.
+//!
+//! The device manager registers each chip as it is added with the id of its
+//! facade, registers it again as a restart rebuilds its facade, and
+//! unregisters it as it is removed. The Rust modules resolve the facade of
+//! a chip, and the chip of a facade, from the registry rather than calling
+//! into the device manager.
+
+use frontend_proto::frontend::GetDevicesResponse;
+use frontend_proto::model::Device;
+use lazy_static::lazy_static;
+use protobuf::Message;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use crate::captures::capture::{ChipId, FacadeId};
+use crate::ffi::get_devices_bytes;
+
+/// A registered chip: its kind and the id of its facade.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChipEntry {
+    pub kind: u32,
+    pub facade_id: FacadeId,
+}
+
+/// The chips by id, and the chips of the facades by kind and facade id.
+#[derive(Default)]
+pub struct ChipRegistry {
+    chips: HashMap<ChipId, ChipEntry>,
+    facades: HashMap<(u32, FacadeId), ChipId>,
+}
+
+lazy_static! {
+    static ref REGISTRY: RwLock<ChipRegistry> = RwLock::new(ChipRegistry::default());
+}
+
+impl ChipRegistry {
+    /// Registers a chip, or the new facade of a registered chip.
+    pub fn register(&mut self, chip_id: ChipId, kind: u32, facade_id: FacadeId) {
+        self.unregister(chip_id);
+        self.chips.insert(chip_id, ChipEntry { kind, facade_id });
+        self.facades.insert((kind, facade_id), chip_id);
+    }
+
+    pub fn unregister(&mut self, chip_id: ChipId) {
+        if let Some(entry) = self.chips.remove(&chip_id) {
+            let key = (entry.kind, entry.facade_id);
+            // The facade may already be registered to a newer chip.
+            if self.facades.get(&key) == Some(&chip_id) {
+                self.facades.remove(&key);
+            }
+        }
+    }
+
+    pub fn get(&self, chip_id: ChipId) -> Option<ChipEntry> {
+        self.chips.get(&chip_id).copied()
+    }
+
+    /// Returns the chip of the facade of a chip kind.
+    pub fn chip_id(&self, kind: u32, facade_id: FacadeId) -> Option<ChipId> {
+        self.facades.get(&(kind, facade_id)).copied()
+    }
+}
+
+/// Returns the kind and facade id of a chip, None for a chip not in the
+/// scene.
+pub fn chip(chip_id: ChipId) -> Option<ChipEntry> {
+    REGISTRY.read().unwrap().get(chip_id)
+}
+
+/// Returns the facade id of a chip, None for a chip not in the scene.
+pub fn facade_id(chip_id: ChipId) -> Option<FacadeId> {
+    chip(chip_id).map(|entry| entry.facade_id)
+}
+
+/// Returns the chip of the facade of a chip kind.
+pub fn chip_id(kind: u32, facade_id: FacadeId) -> Option<ChipId> {
+    REGISTRY.read().unwrap().chip_id(kind, facade_id)
+}
+
+/// Returns the device and chip id of a chip by kind and facade id.
+pub fn find_chip(kind: u32, facade_id: u32) -> Option<(Device, ChipId)> {
+    let chip_id = chip_id(kind, facade_id as FacadeId)?;
+    let mut vec = Vec::<u8>::new();
+    if !get_devices_bytes(&mut vec) {
+        return None;
+    }
+    let devices = GetDevicesResponse::parse_from_bytes(&vec).ok()?.devices;
+    devices
+        .into_iter()
+        .find(|device| device.chips.iter().any(|chip| chip.id == chip_id))
+        .map(|device| (device, chip_id))
+}
+
+// Cxx Method for the device manager to invoke as a chip is added or its
+// facade is rebuilt.
+pub fn register_chip(chip_id: u32, kind: u32, facade_id: u32) {
+    // A chip failing to be added has no id.
+    if (chip_id as ChipId) < 0 {
+        return;
+    }
+    REGISTRY.write().unwrap().register(chip_id as ChipId, kind, facade_id as FacadeId);
+}
+
+// Cxx Method for the device manager to invoke as a chip is removed.
+pub fn unregister_chip(chip_id: u32) {
+    REGISTRY.write().unwrap().unregister(chip_id as ChipId);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        let mut registry = ChipRegistry::default();
+        registry.register(1000, 1, 0);
+        registry.register(1001, 2, 0);
+        assert_eq!(registry.get(1000), Some(ChipEntry { kind: 1, facade_id: 0 }));
+        assert_eq!(registry.chip_id(2, 0), Some(1001));
+        // A restart rebuilding the facade of a chip.
+        registry.register(1000, 1, 3);
+        assert_eq!(registry.get(1000).map(|entry| entry.facade_id), Some(3));
+        assert_eq!(registry.chip_id(1, 0), None);
+        assert_eq!(registry.chip_id(1, 3), Some(1000));
+        registry.unregister(1000);
+        assert_eq!(registry.get(1000), None);
+        assert_eq!(registry.chip_id(1, 3), None);
+        // The facade of a removed chip reused by a newer chip.
+        registry.register(1002, 2, 0);
+        registry.unregister(1001);
+        assert_eq!(registry.chip_id(2, 0), Some(1002));
+    }
+}
@@ -0,0 +1,365 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Clock abstraction for netsimd.
+//!
+//! Time dependent code reads the time through a `Clock` so tests can
+//! control it. netsimd uses the system clock, or a `ManualClock` when the
+//! `debug.clock` setting is "manual". The manual clock starts paused and is
+//! driven with the ControlClock debug RPC.
+//!
+//! /v1/debug/clock --> handle_clock (POST a ControlClockRequest)
+
+use frontend_proto::frontend::control_clock_request::Action;
+use frontend_proto::frontend::{ControlClockRequest, ControlClockResponse};
+use lazy_static::lazy_static;
+use protobuf::well_known_types::timestamp::Timestamp;
+use protobuf::MessageField;
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::pin::Pin;
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::config::get_setting;
+use crate::ffi::CxxServerResponseWriter;
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+/// A source of time.
+pub trait Clock: Send + Sync {
+    /// Returns the wall clock time since UNIX_EPOCH.
+    fn now(&self) -> Duration;
+
+    /// Returns the time on a monotonic timeline, for measuring intervals.
+    fn monotonic(&self) -> Duration;
+
+    /// Blocks the thread until `duration` has passed on this clock.
+    fn sleep(&self, duration: Duration);
+}
+
+/// The operating system clock.
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        SystemClock { start: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards")
+    }
+
+    fn monotonic(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration)
+    }
+}
+
+struct ManualState {
+    // Clock times at `anchor`.
+    now: Duration,
+    monotonic: Duration,
+    anchor: Instant,
+    paused: bool,
+}
+
+impl ManualState {
+    fn running(&self) -> Duration {
+        match self.paused {
+            true => Duration::ZERO,
+            false => self.anchor.elapsed(),
+        }
+    }
+}
+
+/// A clock that can be paused and advanced.
+///
+/// While running, the clock moves at the rate of the system clock.
+pub struct ManualClock {
+    state: Mutex<ManualState>,
+    advanced: Condvar,
+}
+
+impl ManualClock {
+    /// Creates a paused clock at wall clock time `now`.
+    pub fn new(now: Duration) -> ManualClock {
+        ManualClock {
+            state: Mutex::new(ManualState {
+                now,
+                monotonic: Duration::ZERO,
+                anchor: Instant::now(),
+                paused: true,
+            }),
+            advanced: Condvar::new(),
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.state.lock().unwrap().paused
+    }
+
+    /// Stops the clock at the current time.
+    pub fn pause(&self) {
+        let mut state = self.state.lock().unwrap();
+        if !state.paused {
+            let running = state.running();
+            state.now += running;
+            state.monotonic += running;
+            state.paused = true;
+        }
+    }
+
+    /// Lets the clock move with the system clock again.
+    pub fn resume(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.paused {
+            state.anchor = Instant::now();
+            state.paused = false;
+        }
+        self.advanced.notify_all();
+    }
+
+    /// Moves the clock forward, waking the sleepers that are due.
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        state.now += duration;
+        state.monotonic += duration;
+        self.advanced.notify_all();
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        let state = self.state.lock().unwrap();
+        state.now + state.running()
+    }
+
+    fn monotonic(&self) -> Duration {
+        let state = self.state.lock().unwrap();
+        state.monotonic + state.running()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        let deadline = state.monotonic + state.running() + duration;
+        loop {
+            let current = state.monotonic + state.running();
+            if current >= deadline {
+                return;
+            }
+            state = match state.paused {
+                true => self.advanced.wait(state).unwrap(),
+                false => self.advanced.wait_timeout(state, deadline - current).unwrap().0,
+            };
+        }
+    }
+}
+
+enum NetsimdClock {
+    System(SystemClock),
+    Manual(ManualClock),
+}
+
+impl NetsimdClock {
+    fn get(&self) -> &dyn Clock {
+        match self {
+            NetsimdClock::System(clock) => clock,
+            NetsimdClock::Manual(clock) => clock,
+        }
+    }
+}
+
+lazy_static! {
+    static ref CLOCK: NetsimdClock = match get_setting("debug.clock").as_str() {
+        "manual" => NetsimdClock::Manual(ManualClock::new(SystemClock::new().now())),
+        _ => NetsimdClock::System(SystemClock::new()),
+    };
+}
+
+/// Returns the netsimd clock.
+pub fn clock() -> &'static dyn Clock {
+    CLOCK.get()
+}
+
+/// Returns the netsimd clock when it is a manual clock.
+pub fn manual_clock() -> Option<&'static ManualClock> {
+    match &*CLOCK {
+        NetsimdClock::Manual(clock) => Some(clock),
+        NetsimdClock::System(_) => None,
+    }
+}
+
+/// Returns the wall clock time of the netsimd clock since UNIX_EPOCH.
+pub fn now() -> Duration {
+    clock().now()
+}
+
+// Cxx Method for packet_hub to timestamp the captured packets
+pub fn now_nanos() -> u64 {
+    now().as_nanos() as u64
+}
+
+/// Converts a wall clock time since UNIX_EPOCH to a Timestamp.
+pub fn to_timestamp(time: Duration) -> Timestamp {
+    Timestamp {
+        seconds: time.as_secs() as i64,
+        nanos: time.subsec_nanos() as i32,
+        ..Default::default()
+    }
+}
+
+/// Applies a clock control request to a manual clock.
+pub fn control_clock(
+    clock: &ManualClock,
+    request: &ControlClockRequest,
+) -> Result<ControlClockResponse, String> {
+    match request.action.enum_value() {
+        Ok(Action::GET) => {}
+        Ok(Action::PAUSE) => clock.pause(),
+        Ok(Action::RESUME) => clock.resume(),
+        Ok(Action::ADVANCE) => clock.advance(Duration::from_millis(request.advance_ms)),
+        _ => return Err("unknown clock action".to_string()),
+    }
+    Ok(ControlClockResponse {
+        now: MessageField::some(to_timestamp(clock.now())),
+        paused: clock.is_paused(),
+        ..Default::default()
+    })
+}
+
+/// The Rust clock handler used directly by Http frontend for POST
+pub fn handle_clock(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "POST" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let NetsimdClock::Manual(clock) = &*CLOCK else {
+        writer.put_error(404, "The clock is only controllable with debug.clock=manual");
+        return;
+    };
+    let body = String::from_utf8_lossy(&request.body);
+    let response = protobuf_json_mapping::parse_from_str::<ControlClockRequest>(&body)
+        .map_err(|e| format!("Invalid clock request: {e}"))
+        .and_then(|request| control_clock(clock, &request));
+    match response.map(|response| print_to_string_with_options(&response, &JSON_PRINT_OPTION)) {
+        Ok(Ok(json_response)) => writer.put_ok("text/json", &json_response, &[]),
+        Ok(Err(_)) => writer.put_error(404, "proto to JSON mapping failure"),
+        Err(e) => writer.put_error(400, &e),
+    }
+}
+
+/// clock handle cxx for grpc server to call
+pub fn handle_clock_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/debug/clock".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_clock(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    const START: Duration = Duration::from_secs(1_000);
+
+    #[test]
+    fn test_manual_clock_advance() {
+        let clock = ManualClock::new(START);
+        assert!(clock.is_paused());
+        assert_eq!(clock.now(), START);
+        clock.advance(Duration::from_millis(1500));
+        assert_eq!(clock.now(), START + Duration::from_millis(1500));
+        assert_eq!(clock.monotonic(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn test_manual_clock_resume() {
+        let clock = ManualClock::new(START);
+        clock.resume();
+        std::thread::sleep(Duration::from_millis(5));
+        clock.pause();
+        let paused = clock.now();
+        assert!(paused >= START + Duration::from_millis(5));
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(clock.now(), paused);
+    }
+
+    #[test]
+    fn test_manual_clock_sleep() {
+        let clock = Arc::new(ManualClock::new(START));
+        let sleeper = {
+            let clock = clock.clone();
+            std::thread::spawn(move || {
+                let start = clock.monotonic();
+                clock.sleep(Duration::from_secs(10));
+                clock.monotonic() - start
+            })
+        };
+        while !sleeper.is_finished() {
+            clock.advance(Duration::from_secs(1));
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert!(sleeper.join().unwrap() >= Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_control_clock() {
+        let clock = ManualClock::new(START);
+        let request = ControlClockRequest {
+            action: Action::ADVANCE.into(),
+            advance_ms: 2_000,
+            ..Default::default()
+        };
+        let response = control_clock(&clock, &request).unwrap();
+        assert_eq!(response.now.seconds, 1_002);
+        assert!(response.paused);
+        let request = ControlClockRequest { action: Action::RESUME.into(), ..Default::default() };
+        assert!(!control_clock(&clock, &request).unwrap().paused);
+    }
+}
@@ -0,0 +1,528 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Settings library for netsimd.
+//!
+//! Every setting is identified by a dotted key such as `grpc.port`. The
+//! value of a setting is resolved from, in order of precedence:
+//!
+//! 1. The command line flags of netsimd, like `--http-port` for `http.port`.
+//! 2. The environment variable `NETSIM_<KEY>`, where the key is upper-cased
+//!    and `.` is replaced with `_` (`grpc.port` -> `NETSIM_GRPC_PORT`).
+//! 3. The config file named by `NETSIM_CONFIG`, using `key=value` lines.
+//! 4. The value saved in the state file by a previous netsimd run.
+//! 5. The built-in default.
+
+use lazy_static::lazy_static;
+use netsim_common::util::ini_file::IniFile;
+use netsim_common::util::os_utils::is_valid_instance_name;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use crate::captures::capture::DirQuotaPolicy;
+use crate::logger::{init_logger, warn, Level};
+use crate::propagation::parse_walls;
+
+/// Environment variable naming the optional config file.
+pub const CONFIG_FILE_ENV: &str = "NETSIM_CONFIG";
+
+/// Known settings and their default values.
+const DEFAULTS: &[(&str, &str)] = &[
+    // Name of this netsimd instance; empty selects the default instance.
+    ("instance", ""),
+    // Level of the netsimd logs: error, warn, info or debug, also set at
+    // runtime with the PatchLogLevel RPC. With json each log line is a
+    // JSON object with the level, the module and the chip of the message.
+    ("log.level", "info"),
+    ("log.json", "false"),
+    // Fixed port for the grpc server; 0 selects a free port. A fixed port
+    // also disables the frontend http server.
+    ("grpc.port", "0"),
+    // Listening host for the grpc server. "::" listens on IPv6, and on IPv4
+    // as well where the OS supports dual-stack sockets.
+    ("grpc.host", "0.0.0.0"),
+    // Maximum size in bytes of the messages sent and received by the grpc
+    // server, 0 keeps the grpc defaults and -1 is unlimited.
+    ("grpc.max_send_message_bytes", "0"),
+    ("grpc.max_receive_message_bytes", "0"),
+    // Port for the frontend http server.
+    ("http.port", "7681"),
+    // Listening host for the frontend http server, "::1" for IPv6 loopback
+    // or "0.0.0.0" for the other machines of a trusted network.
+    ("http.host", "127.0.0.1"),
+    // Origins allowed to call the frontend http server cross-origin, as
+    // comma separated entries like http://localhost:4200, "*" for any.
+    ("http.cors_origins", ""),
+    // Tokens of the frontend as comma separated <token>=<scope> entries,
+    // where the scope is read-only, capture-download or admin. Empty
+    // disables the auth.
+    ("auth.tokens", ""),
+    // Generates an admin token at startup, written to the netsim.token file
+    // of the instance next to its discovery file and only readable by the
+    // user running netsimd, whose tools read it from there.
+    ("auth.generate_token", "false"),
+    // Path of the state file; empty selects a file in the temp directory.
+    ("state.file", ""),
+    // Total bytes of in-memory buffers across all pools; 0 is unlimited.
+    ("memory.limit", "268435456"),
+    // Per pool byte limits within memory.limit; 0 is only bounded by
+    // memory.limit. Drop policies are "oldest" or "newest".
+    ("memory.captures.limit", "0"),
+    ("memory.captures.drop_policy", "oldest"),
+    ("memory.events.limit", "16777216"),
+    ("memory.events.drop_policy", "oldest"),
+    ("memory.packets.limit", "67108864"),
+    ("memory.packets.drop_policy", "newest"),
+    // Free buffers kept by the pool of packet buffers, and the largest
+    // buffer in bytes it keeps. The packets are buffered on the heap when
+    // the pool is empty.
+    ("buffer_pool.buffers", "1024"),
+    ("buffer_pool.max_buffer_bytes", "65536"),
+    // Limits of the devices, chips, recording captures and capture bytes on
+    // disk reported by the GetCapacity RPC to the schedulers placing
+    // emulators, 0 is unlimited. netsimd does not enforce them.
+    ("capacity.max_devices", "0"),
+    ("capacity.max_chips", "0"),
+    ("capacity.max_captures", "0"),
+    ("capacity.max_disk_bytes", "0"),
+    // Ingress rate limit of each chip in packets per second, 0 is unlimited,
+    // and the burst of packets allowed above the rate. When a chip exceeds
+    // the limit the policy "queue" delays its packets, "drop" drops the
+    // excess packets and "throttle" drops all its packets for throttle_ms.
+    ("flood.rate", "0"),
+    ("flood.burst", "200"),
+    ("flood.policy", "drop"),
+    ("flood.throttle_ms", "1000"),
+    // Backpressure on the emulators when the queue of a chip reaches the
+    // high watermark of packets, 0 disables it, until it drains to the low
+    // watermark. The policy "none" keeps queueing, "block" holds the
+    // transport for at most max_wait_ms per packet and "drop" drops the
+    // packets. Chips override the policy with comma separated
+    // [<device name>/]<chip kind>=<policy> entries.
+    ("backpressure.high_watermark", "0"),
+    ("backpressure.low_watermark", "0"),
+    ("backpressure.max_wait_ms", "1000"),
+    ("backpressure.policy", "none"),
+    ("backpressure.chips", ""),
+    // Maximum payload of a packet from an emulator in bytes, 0 is
+    // unlimited: the ACL data length for Bluetooth and the frame length for
+    // Wi-Fi. The policy "fragment" splits oversized Bluetooth ACL packets
+    // into continuing fragments and "reject" drops them. Oversized Wi-Fi
+    // frames are always dropped.
+    ("mtu.bluetooth", "0"),
+    ("mtu.wifi", "0"),
+    ("mtu.policy", "fragment"),
+    // Quota of each capture in bytes and in records, 0 is unlimited. A
+    // capture records a CAPTURE_WATERMARK event when it crosses the
+    // watermark percentage of a quota, and stops with a CAPTURE_FULL event
+    // when it reaches the quota.
+    ("capture.max_size", "0"),
+    ("capture.max_records", "0"),
+    ("capture.watermark", "80"),
+    // Size in bytes of the ring buffer of a capture in the RING mode.
+    ("capture.ring_size", "16777216"),
+    // Size of a capture file in bytes, and age in minutes, closing it as a
+    // segment of the capture and continuing in a new file, 0 never rotates.
+    // The quotas apply to the capture with its segments, and the segments
+    // are deleted retention_mins after they are closed, 0 keeps them.
+    ("capture.rotate_size", "0"),
+    ("capture.rotate_interval_mins", "0"),
+    ("capture.segment_retention_mins", "0"),
+    // Directory of the capture files, empty for netsim-pcaps in the temp
+    // directory, which netsimd clears on startup. Other directories are
+    // kept, e.g. to collect the captures as CI artifacts.
+    ("capture.dir", ""),
+    // Quota of the files in the capture directory in bytes, 0 is unlimited.
+    // A capture writing past it stops and is marked invalid with the error,
+    // e.g. so CI runs don't fill their /tmp. The policy "evict" first
+    // deletes the files of the oldest stopped captures.
+    ("capture.dir_max_size", "0"),
+    ("capture.dir_quota_policy", "stop"),
+    // Seconds a live capture waits for a reader of its named pipe, then
+    // again after a reader closes it, before it writes its file only.
+    ("capture.live_wait_secs", "30"),
+    // Name of the capture files, without their extension, with the
+    // placeholders {chip_id}, {device_name}, {chip_kind}, {timestamp} of the
+    // start of the capture and {session}, the process id of netsimd. The
+    // name needs {chip_id} to be unique; the path separators and the
+    // characters invalid on the host filesystem are replaced by '_'.
+    ("capture.filename", "{chip_id}-{device_name}-{chip_kind}"),
+    // Compress the file of a capture to .gz in the background when the
+    // capture stops, e.g. for the multi-GB captures of long runs. The
+    // capture is then downloaded compressed.
+    ("capture.compress", "false"),
+    // Keep the captures of removed chips listed and downloadable until they
+    // are deleted, or for expire_secs after the removal; 0 never expires.
+    // Otherwise the captures are dropped with their chips.
+    ("capture.keep_removed", "true"),
+    ("capture.expire_secs", "0"),
+    // Packets of a chip queued while its facade restarts, delivered after
+    // the restart; the packets beyond the limit are rejected.
+    ("restart.queue_limit", "256"),
+    // Watchdog of the chip queues: reports a chip when its queue holds
+    // max_queue_depth packets, or holds packets without progress for
+    // stall_secs, and restarts its facade when restart is set. 0 disables a
+    // check.
+    ("watchdog.max_queue_depth", "0"),
+    ("watchdog.stall_secs", "0"),
+    ("watchdog.restart", "false"),
+    // Host TUN interface mirroring the IP traffic of the Wi-Fi chips, empty
+    // disables the bridge. The mode "route" also delivers the packets sent
+    // by the host through the interface to the chips.
+    ("wifi.tun", ""),
+    ("wifi.tun_mode", "mirror"),
+    // Export the scene to a timestamped scene file in dir every
+    // interval_mins, 0 disables the snapshots, keeping the latest retention
+    // files, 0 keeps all. The default dir is netsim-snapshots in the temp
+    // directory.
+    ("snapshot.interval_mins", "0"),
+    ("snapshot.retention", "24"),
+    ("snapshot.dir", ""),
+    // Radio propagation model computing the RSSI: "free_space",
+    // "log_distance" with its path loss exponent, "multi_wall" adding the
+    // loss of the walls crossed by a link, or "external" calling the
+    // PropagationModelService at address. The walls are x1,y1,x2,y2[,loss]
+    // entries separated by ';', with a loss of wall_loss dB by default.
+    ("propagation.model", "free_space"),
+    ("propagation.exponent", "2.0"),
+    ("propagation.walls", ""),
+    ("propagation.wall_loss", "5.0"),
+    ("propagation.address", ""),
+    // Delay the advertising events of Bluetooth LE by a random advDelay and
+    // deliver them only within the scan windows of the scanners.
+    ("bluetooth.advertising_timing", "true"),
+    // WebAssembly packet processors as comma separated <chip kind>=<path>
+    // entries, requires the "wasm" feature and is ignored with a warning
+    // without it. Fuel is the instruction budget
+    // of a plugin per packet.
+    ("processor.wasm_plugins", ""),
+    ("processor.wasm_fuel", "1000000"),
+    // Chips backed by external controllers instead of the built-in facades,
+    // as comma separated <device name>/<chip kind>=<host>:<port> entries.
+    ("external.controllers", ""),
+    // Port accepting the trunks of leaf netsimd instances, 0 disables the
+    // federation hub, and its listening host. Leaves must prove they know
+    // the token of the hub when linking; the hub does not listen without a
+    // token.
+    ("federation.port", "0"),
+    ("federation.host", "0.0.0.0"),
+    ("federation.token", ""),
+    // "manual" replaces the system clock with a paused clock controlled by
+    // the ControlClock debug RPC, for deterministic tests.
+    ("debug.clock", "system"),
+    // Seed of the random numbers of the simulation, 0 selects a random seed.
+    ("debug.seed", "0"),
+    // Session log recording the simulation from the start, empty records
+    // nothing. The ControlSession debug RPC replays it.
+    ("debug.session_log", ""),
+];
+
+/// Settings that are never restored from the state file.
+const NOT_SAVED: [&str; 2] = ["instance", "state.file"];
+/// Prefix of the debugging settings, which apply to a single run and are
+/// never saved.
+const DEBUG_PREFIX: &str = "debug.";
+/// Settings holding secrets, never saved and redacted from the reports.
+const SECRETS: [&str; 2] = ["auth.tokens", "federation.token"];
+const REDACTED: &str = "<redacted>";
+
+// Whether a setting is saved in the state file and restored from it.
+fn is_saved(key: &str) -> bool {
+    !NOT_SAVED.contains(&key) && !SECRETS.contains(&key) && !key.starts_with(DEBUG_PREFIX)
+}
+
+lazy_static! {
+    // The values of the command line flags, by environment variable name.
+    static ref FLAGS: RwLock<HashMap<String, String>> = RwLock::new(HashMap::new());
+    static ref SETTINGS: RwLock<Settings> = RwLock::new(Settings::from_process(None));
+}
+
+/// Resolved values for all known settings.
+pub struct Settings {
+    values: BTreeMap<&'static str, String>,
+}
+
+impl Settings {
+    /// Resolves settings from the process environment, config file and
+    /// saved values.
+    fn from_process(saved: Option<&HashMap<String, String>>) -> Settings {
+        let config_file = std::env::var(CONFIG_FILE_ENV).ok().and_then(|path| {
+            let mut ini_file = IniFile::new(path.clone());
+            match ini_file.read() {
+                Ok(()) => Some(ini_file),
+                Err(e) => {
+                    warn!("unable to read config file {path}: {e}");
+                    None
+                }
+            }
+        });
+        let flags = FLAGS.read().unwrap();
+        let env = |name: &str| flags.get(name).cloned().or_else(|| std::env::var(name).ok());
+        Settings::resolve(env, config_file.as_ref(), saved)
+    }
+
+    /// Resolves settings with the given environment lookup, config file and
+    /// saved values.
+    pub fn resolve<F>(
+        env: F,
+        config_file: Option<&IniFile>,
+        saved: Option<&HashMap<String, String>>,
+    ) -> Settings
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mut values = BTreeMap::new();
+        for &(key, default) in DEFAULTS {
+            let value = env(&env_name(key))
+                .or_else(|| config_file.and_then(|f| f.get(key)).map(String::from))
+                .or_else(|| saved.filter(|_| is_saved(key)).and_then(|s| s.get(key)).cloned())
+                .filter(|value| is_valid(key, value))
+                .unwrap_or_else(|| default.to_string());
+            values.insert(key, value);
+        }
+        Settings { values }
+    }
+
+    /// Returns the value of a setting, or None for an unknown key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|v| v.as_str())
+    }
+}
+
+fn is_valid(key: &str, value: &str) -> bool {
+    match key {
+        "instance" if !is_valid_instance_name(value) => {
+            warn!("invalid instance name {value}, using the default instance");
+            false
+        }
+        "http.port" if value.parse::<u16>().is_err() => {
+            warn!("invalid http.port {value}, using the default");
+            false
+        }
+        "log.level" if Level::parse(value).is_none() => {
+            warn!("invalid log level {value}, using info");
+            false
+        }
+        "capture.filename" if !value.contains("{chip_id}") => {
+            warn!("capture.filename {value} without {{chip_id}}, using the default");
+            false
+        }
+        "capture.dir_quota_policy" if DirQuotaPolicy::parse(value).is_none() => {
+            warn!("invalid capture.dir_quota_policy {value}, using stop");
+            false
+        }
+        "propagation.walls" if parse_walls(value, 0.0).is_none() => {
+            warn!("invalid propagation.walls {value}, using no walls");
+            false
+        }
+        // The directory is checked as the captures use it, see pcap_dir.
+        "capture.dir" if value.contains('\0') => {
+            warn!("invalid capture.dir {value:?}, using the temp directory");
+            false
+        }
+        _ => true,
+    }
+}
+
+/// Returns the environment variable that overrides the setting `key`.
+pub fn env_name(key: &str) -> String {
+    format!("NETSIM_{}", key.to_uppercase().replace('.', "_"))
+}
+
+/// Returns the value of a setting, or an empty string for an unknown key.
+pub fn get_setting(key: &str) -> String {
+    match SETTINGS.read().unwrap().get(key) {
+        Some(value) => value.to_string(),
+        None => {
+            warn!("unknown setting {key}");
+            String::new()
+        }
+    }
+}
+
+/// Sets a setting from a command line flag of netsimd, overriding the
+/// environment and the config file. Returns false for an unknown setting
+/// or an invalid value.
+pub fn set_flag(key: &str, value: &str) -> bool {
+    if !DEFAULTS.iter().any(|(known, _)| *known == key) {
+        warn!("unknown setting {key}");
+        return false;
+    }
+    if !is_valid(key, value) {
+        return false;
+    }
+    FLAGS.write().unwrap().insert(env_name(key), value.to_string());
+    *SETTINGS.write().unwrap() = Settings::from_process(None);
+    true
+}
+
+/// Re-resolves all settings, using values saved by a previous run where
+/// the environment and config file don't provide one.
+pub fn restore_settings(saved: &HashMap<String, String>) {
+    *SETTINGS.write().unwrap() = Settings::from_process(Some(saved));
+    init_logger();
+}
+
+/// Returns the resolved value of every setting, ordered by key.
+pub fn all_settings() -> Vec<(String, String)> {
+    SETTINGS
+        .read()
+        .unwrap()
+        .values
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect()
+}
+
+/// Returns the settings to save in the state file.
+pub fn saved_settings() -> HashMap<String, String> {
+    SETTINGS
+        .read()
+        .unwrap()
+        .values
+        .iter()
+        .filter(|(key, _)| is_saved(key))
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect()
+}
+
+/// Returns the resolved value of every setting, ordered by key, with the
+/// secrets that are set redacted, for the bug reports and the session logs.
+pub fn redacted_settings() -> Vec<(String, String)> {
+    all_settings()
+        .into_iter()
+        .map(|(key, value)| match SECRETS.contains(&key.as_str()) && !value.is_empty() {
+            true => (key, REDACTED.to_string()),
+            false => (key, value),
+        })
+        .collect()
+}
+
+/// Returns the parsed value of a setting, falling back to its default
+/// when the configured value doesn't parse.
+pub fn get_setting_parsed<T: FromStr + Default>(key: &str) -> T {
+    get_setting(key).parse().unwrap_or_else(|_| {
+        warn!("invalid value for setting {key}, using default");
+        DEFAULTS
+            .iter()
+            .find(|(k, _)| *k == key)
+            .and_then(|(_, v)| v.parse().ok())
+            .unwrap_or_default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_name() {
+        assert_eq!(env_name("grpc.port"), "NETSIM_GRPC_PORT");
+        assert_eq!(env_name("http.port"), "NETSIM_HTTP_PORT");
+    }
+
+    #[test]
+    fn test_defaults() {
+        let settings = Settings::resolve(|_| None, None, None);
+        assert_eq!(settings.get("grpc.port"), Some("0"));
+        assert_eq!(settings.get("http.port"), Some("7681"));
+        assert_eq!(settings.get("unknown.key"), None);
+    }
+
+    #[test]
+    fn test_invalid_instance() {
+        let env = |name: &str| (name == "NETSIM_INSTANCE").then(|| "a/b".to_string());
+        let settings = Settings::resolve(env, None, None);
+        assert_eq!(settings.get("instance"), Some(""));
+    }
+
+    #[test]
+    fn test_invalid_capture_filename() {
+        let env = |name: &str| (name == "NETSIM_CAPTURE_FILENAME").then(|| "{device_name}".into());
+        let settings = Settings::resolve(env, None, None);
+        assert_eq!(settings.get("capture.filename"), Some("{chip_id}-{device_name}-{chip_kind}"));
+    }
+
+    #[test]
+    fn test_capture_dir_not_created() {
+        let dir = std::env::temp_dir().join(format!("netsim-config-dir-{}", std::process::id()));
+        let value = dir.to_string_lossy().into_owned();
+        let env = |name: &str| (name == "NETSIM_CAPTURE_DIR").then(|| value.clone());
+        let settings = Settings::resolve(env, None, None);
+        assert_eq!(settings.get("capture.dir"), Some(value.as_str()));
+        assert!(!dir.exists());
+        let env = |name: &str| (name == "NETSIM_CAPTURE_DIR").then(|| "a\0b".to_string());
+        assert_eq!(Settings::resolve(env, None, None).get("capture.dir"), Some(""));
+    }
+
+    #[test]
+    fn test_precedence() {
+        let mut config_file = IniFile::new(String::new());
+        config_file.insert("grpc.port", "8000");
+        config_file.insert("http.port", "8001");
+        let env = |name: &str| (name == "NETSIM_HTTP_PORT").then(|| "9001".to_string());
+        let settings = Settings::resolve(env, Some(&config_file), None);
+        assert_eq!(settings.get("grpc.port"), Some("8000"));
+        assert_eq!(settings.get("http.port"), Some("9001"));
+    }
+
+    #[test]
+    fn test_saved_values() {
+        let saved = HashMap::from([
+            ("grpc.port".to_string(), "8000".to_string()),
+            ("http.port".to_string(), "8001".to_string()),
+            ("state.file".to_string(), "/tmp/other".to_string()),
+        ]);
+        let env = |name: &str| (name == "NETSIM_HTTP_PORT").then(|| "9001".to_string());
+        let settings = Settings::resolve(env, None, Some(&saved));
+        assert_eq!(settings.get("grpc.port"), Some("8000"));
+        assert_eq!(settings.get("http.port"), Some("9001"));
+        assert_eq!(settings.get("state.file"), Some(""));
+    }
+
+    #[test]
+    fn test_secrets_not_saved() {
+        let saved = HashMap::from([
+            ("auth.tokens".to_string(), "secret=admin".to_string()),
+            ("federation.token".to_string(), "secret".to_string()),
+        ]);
+        let settings = Settings::resolve(|_| None, None, Some(&saved));
+        assert_eq!(settings.get("auth.tokens"), Some(""));
+        assert_eq!(settings.get("federation.token"), Some(""));
+        assert!(SECRETS.iter().all(|key| !is_saved(key)));
+        assert!(is_saved("http.port"));
+    }
+
+    #[test]
+    fn test_debug_not_saved() {
+        let saved = HashMap::from([
+            ("debug.clock".to_string(), "manual".to_string()),
+            ("debug.seed".to_string(), "42".to_string()),
+            ("debug.session_log".to_string(), "/tmp/session".to_string()),
+        ]);
+        let settings = Settings::resolve(|_| None, None, Some(&saved));
+        assert_eq!(settings.get("debug.clock"), Some("system"));
+        assert_eq!(settings.get("debug.seed"), Some("0"));
+        assert_eq!(settings.get("debug.session_log"), Some(""));
+    }
+
+    #[test]
+    fn test_set_flag() {
+        assert!(set_flag("http.cors_origins", "http://localhost:4200"));
+        assert_eq!(get_setting("http.cors_origins"), "http://localhost:4200");
+        assert!(!set_flag("http.port", "http"));
+        assert!(!set_flag("unknown.key", "1"));
+    }
+}
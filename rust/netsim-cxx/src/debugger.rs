@@ -0,0 +1,737 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Packet breakpoints.
+//!
+//! packet_hub offers every packet to the debugger before the packet
+//! processors. A packet matching a breakpoint pauses the delivery of its
+//! chip, or of the whole scene, and records a BREAKPOINT_HIT event with the
+//! packet. The packets of paused chips are held in arrival order until
+//! delivery resumes, so the devices stay in the state of the breakpoint
+//! while the developer inspects them. The held packets are charged to the
+//! packets memory pool, whose drop policy applies when it is full.
+//!
+//! The delivery of the whole simulation can also be paused on demand, and
+//! stepped a given number of held packets at a time while paused.
+//!
+//! /v1/debug/breakpoints --> handle_breakpoints (GET to list, POST a
+//! Breakpoint to set)
+//! /v1/debug/breakpoints/{id} --> handle_breakpoints (DELETE)
+//! /v1/debug/delivery --> handle_delivery (GET the DeliveryState)
+//! /v1/debug/delivery/{action} --> handle_delivery (POST pause, resume, or
+//! step with a StepDeliveryRequest)
+
+use cxx::CxxVector;
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::breakpoint::{Direction, Scope};
+use frontend_proto::frontend::event::Type;
+use frontend_proto::frontend::{
+    Breakpoint, DeliveryState, Event, ListBreakpointsResponse, StepDeliveryRequest,
+};
+use lazy_static::lazy_static;
+use protobuf::EnumOrUnknown;
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use crate::captures::handlers::int_to_chip_kind;
+use crate::captures::pcap_util::PacketDirection;
+use crate::chip_registry::{chip, find_chip};
+use crate::clock::{now, to_timestamp};
+use crate::events::history;
+use crate::ffi::{forward_request_cxx, forward_response_cxx, CxxServerResponseWriter};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::warn;
+use crate::memory::{BoundedBuffer, Pool};
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+/// A packet held by the debugger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeldPacket {
+    pub kind: u32,
+    pub facade_id: u32,
+    pub packet: Vec<u8>,
+    pub packet_type: u32,
+    pub direction: PacketDirection,
+    // The packet already matched a breakpoint.
+    checked: bool,
+}
+
+impl HeldPacket {
+    pub fn new(
+        kind: u32,
+        facade_id: u32,
+        packet: Vec<u8>,
+        packet_type: u32,
+        direction: PacketDirection,
+    ) -> Self {
+        HeldPacket { kind, facade_id, packet, packet_type, direction, checked: false }
+    }
+}
+
+/// A breakpoint and the chip it applies to.
+struct Entry {
+    breakpoint: Breakpoint,
+    // Kind and facade id of the chip of chip_id.
+    facade: Option<(u32, u32)>,
+}
+
+impl Entry {
+    fn matches(&self, packet: &HeldPacket) -> bool {
+        let breakpoint = &self.breakpoint;
+        if self.facade.is_some_and(|facade| facade != (packet.kind, packet.facade_id)) {
+            return false;
+        }
+        let chip_kind = breakpoint.chip_kind.value() as u32;
+        if chip_kind != ChipKind::UNSPECIFIED as u32 && chip_kind != packet.kind {
+            return false;
+        }
+        match (breakpoint.direction.enum_value_or_default(), packet.direction) {
+            (Direction::HOST_TO_CONTROLLER, PacketDirection::ControllerToHost)
+            | (Direction::CONTROLLER_TO_HOST, PacketDirection::HostToController) => return false,
+            _ => {}
+        }
+        let offset = breakpoint.offset as usize;
+        let Some(bytes) = packet.packet.get(offset..offset + breakpoint.pattern.len()) else {
+            return false;
+        };
+        bytes.iter().zip(breakpoint.pattern.iter()).enumerate().all(|(i, (byte, pattern))| {
+            let mask = breakpoint.mask.get(i).copied().unwrap_or(0xff);
+            byte & mask == pattern & mask
+        })
+    }
+}
+
+/// A breakpoint hit by a packet.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hit {
+    pub breakpoint_id: u32,
+    pub kind: u32,
+    pub facade_id: u32,
+    pub packet: Vec<u8>,
+}
+
+/// What the debugger does with a packet.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Hold {
+    /// Deliver the packet.
+    Pass,
+    /// The packet waits for the delivery to resume.
+    Held,
+    /// The packet hit a breakpoint and is held.
+    Hit(Hit),
+}
+
+struct State {
+    entries: Vec<Entry>,
+    next_id: u32,
+    // Packet delivery of all chips is paused.
+    scene_paused: bool,
+    // Kind and facade id of the chips with paused delivery.
+    paused_chips: HashSet<(u32, u32)>,
+    // Charged to the packets memory pool.
+    held: BoundedBuffer<HeldPacket>,
+    // Held packets are being delivered.
+    draining: bool,
+}
+
+impl State {
+    fn new(held: BoundedBuffer<HeldPacket>) -> Self {
+        State {
+            entries: Vec::new(),
+            next_id: 1,
+            scene_paused: false,
+            paused_chips: HashSet::new(),
+            held,
+            draining: false,
+        }
+    }
+}
+
+impl State {
+    fn is_paused(&self, kind: u32, facade_id: u32) -> bool {
+        self.scene_paused || self.paused_chips.contains(&(kind, facade_id))
+    }
+
+    // Checks a packet against the breakpoints, pausing the delivery on a hit.
+    fn check(&mut self, packet: &HeldPacket) -> Option<Hit> {
+        let entry = self.entries.iter_mut().find(|entry| entry.matches(packet))?;
+        entry.breakpoint.hits += 1;
+        match entry.breakpoint.scope.enum_value_or_default() {
+            Scope::CHIP => {
+                self.paused_chips.insert((packet.kind, packet.facade_id));
+            }
+            Scope::SCENE => self.scene_paused = true,
+        }
+        Some(Hit {
+            breakpoint_id: entry.breakpoint.id,
+            kind: packet.kind,
+            facade_id: packet.facade_id,
+            packet: packet.packet.clone(),
+        })
+    }
+
+    // Removes the first held packet of a chip with delivery, or the first
+    // held packet when stepping, checking it against the breakpoints if it
+    // wasn't. A packet hitting a breakpoint stays held and is returned as a
+    // hit.
+    fn next_deliverable(&mut self, stepping: bool) -> Option<Result<HeldPacket, Hit>> {
+        let index = self
+            .held
+            .iter()
+            .position(|packet| stepping || !self.is_paused(packet.kind, packet.facade_id))?;
+        let held = self.held.get_mut(index)?;
+        if !held.checked {
+            held.checked = true;
+            let packet = held.clone();
+            if let Some(hit) = self.check(&packet) {
+                return Some(Err(hit));
+            }
+        }
+        self.held.remove(index).map(Ok)
+    }
+
+    // Holds a packet, unless the packets memory pool drops it.
+    fn push_held(&mut self, packet: HeldPacket) {
+        let size = packet.packet.len();
+        if !self.held.push(packet, size) {
+            warn!("debugger dropped a held packet, the packets memory pool is full");
+        }
+    }
+}
+
+/// Breakpoints and the packets they hold.
+pub struct Debugger {
+    state: Mutex<State>,
+    // No breakpoint is set and nothing is held, the fast path of hold.
+    idle: AtomicBool,
+}
+
+impl Default for Debugger {
+    fn default() -> Self {
+        Debugger::with_held(BoundedBuffer::new(Pool::Packets))
+    }
+}
+
+impl Debugger {
+    /// Creates a debugger holding the packets in `held`.
+    pub fn with_held(held: BoundedBuffer<HeldPacket>) -> Self {
+        Debugger { state: Mutex::new(State::new(held)), idle: true.into() }
+    }
+
+    fn update_idle(&self, state: &State) {
+        let idle = state.entries.is_empty()
+            && state.held.is_empty()
+            && !state.draining
+            && !state.scene_paused
+            && state.paused_chips.is_empty();
+        self.idle.store(idle, Ordering::Release);
+    }
+
+    /// Sets a breakpoint applying to the chip of `facade`, if any, and
+    /// returns it with its id.
+    pub fn set(&self, mut breakpoint: Breakpoint, facade: Option<(u32, u32)>) -> Breakpoint {
+        let mut state = self.state.lock().unwrap();
+        breakpoint.id = state.next_id;
+        breakpoint.hits = 0;
+        state.next_id += 1;
+        state.entries.push(Entry { breakpoint: breakpoint.clone(), facade });
+        self.update_idle(&state);
+        breakpoint
+    }
+
+    pub fn delete(&self, id: u32) -> Result<(), String> {
+        let mut state = self.state.lock().unwrap();
+        let len = state.entries.len();
+        state.entries.retain(|entry| entry.breakpoint.id != id);
+        self.update_idle(&state);
+        match state.entries.len() < len {
+            true => Ok(()),
+            false => Err(format!("Breakpoint {id} not found")),
+        }
+    }
+
+    pub fn list(&self) -> ListBreakpointsResponse {
+        let state = self.state.lock().unwrap();
+        ListBreakpointsResponse {
+            breakpoints: state.entries.iter().map(|entry| entry.breakpoint.clone()).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns whether all delivery is paused, the kind and facade id of
+    /// the paused chips and the number of held packets.
+    pub fn paused(&self) -> (bool, Vec<(u32, u32)>, usize) {
+        let state = self.state.lock().unwrap();
+        let mut chips: Vec<(u32, u32)> = state.paused_chips.iter().copied().collect();
+        chips.sort();
+        (state.scene_paused, chips, state.held.len())
+    }
+
+    /// Pauses the delivery of all chips.
+    pub fn pause(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.scene_paused = true;
+        self.update_idle(&state);
+    }
+
+    /// Offers a packet to the debugger before its delivery.
+    pub fn hold(&self, packet: HeldPacket) -> Hold {
+        if self.idle.load(Ordering::Acquire) {
+            return Hold::Pass;
+        }
+        let mut state = self.state.lock().unwrap();
+        // Packets arriving while held packets are delivered queue behind
+        // them, and are checked on their turn.
+        if state.draining || state.is_paused(packet.kind, packet.facade_id) {
+            state.push_held(packet);
+            self.update_idle(&state);
+            return Hold::Held;
+        }
+        match state.check(&packet) {
+            Some(hit) => {
+                state.push_held(HeldPacket { checked: true, ..packet });
+                self.update_idle(&state);
+                Hold::Hit(hit)
+            }
+            None => Hold::Pass,
+        }
+    }
+
+    /// Resumes the paused delivery, passing the held packets in order to
+    /// `deliver` until the packets left are of chips paused again by a
+    /// breakpoint. Returns the breakpoints hit meanwhile.
+    pub fn resume(&self, mut deliver: impl FnMut(HeldPacket)) -> Vec<Hit> {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.scene_paused = false;
+            state.paused_chips.clear();
+            state.draining = true;
+        }
+        let mut hits = Vec::new();
+        loop {
+            // The lock is released while delivering, as delivery may
+            // produce packets offered to the debugger.
+            let next = {
+                let mut state = self.state.lock().unwrap();
+                let next = state.next_deliverable(false);
+                if next.is_none() {
+                    state.draining = false;
+                    self.update_idle(&state);
+                }
+                next
+            };
+            match next {
+                Some(Ok(packet)) => deliver(packet),
+                Some(Err(hit)) => hits.push(hit),
+                None => return hits,
+            }
+        }
+    }
+
+    /// Passes up to `count` held packets in order to `deliver`, whatever
+    /// chips are paused, and keeps the delivery paused. The step stops at a
+    /// packet hitting a breakpoint, which the next step delivers. Returns
+    /// the number of packets delivered and the breakpoint hit, if any.
+    pub fn step(&self, count: usize, mut deliver: impl FnMut(HeldPacket)) -> (usize, Option<Hit>) {
+        let mut delivered = 0;
+        while delivered < count {
+            let next = self.state.lock().unwrap().next_deliverable(true);
+            match next {
+                Some(Ok(packet)) => {
+                    deliver(packet);
+                    delivered += 1;
+                }
+                Some(Err(hit)) => return (delivered, Some(hit)),
+                None => break,
+            }
+        }
+        let state = self.state.lock().unwrap();
+        self.update_idle(&state);
+        (delivered, None)
+    }
+}
+
+lazy_static! {
+    static ref DEBUGGER: Debugger = Debugger::default();
+}
+
+// Records a BREAKPOINT_HIT event with the device of the chip.
+fn record_hit(hit: Hit) {
+    let mut event = Event {
+        type_: Type::BREAKPOINT_HIT.into(),
+        timestamp: Some(to_timestamp(now())).into(),
+        packet: hit.packet,
+        breakpoint_id: hit.breakpoint_id,
+        ..Default::default()
+    };
+    if let Some((device, chip_id)) = find_chip(hit.kind, hit.facade_id) {
+        event.device_name = device.name.clone();
+        event.chip_id = chip_id;
+        event.devices.push(device);
+    }
+    warn!(
+        "{:?} chip of {} hit breakpoint {}, packet delivery paused",
+        int_to_chip_kind(hit.kind),
+        event.device_name,
+        hit.breakpoint_id
+    );
+    history().record(event);
+}
+
+// Resolves the chip of a breakpoint to its kind and facade id.
+fn resolve(breakpoint: &mut Breakpoint) -> Result<Option<(u32, u32)>, String> {
+    if breakpoint.chip_id == 0 {
+        return Ok(None);
+    }
+    let entry = chip(breakpoint.chip_id).ok_or(format!("Chip {} not found", breakpoint.chip_id))?;
+    breakpoint.chip_kind = EnumOrUnknown::from_i32(entry.kind as i32);
+    Ok(Some((entry.kind, entry.facade_id as u32)))
+}
+
+fn delivery_state(delivered: usize) -> DeliveryState {
+    let (paused, chips, held) = DEBUGGER.paused();
+    DeliveryState {
+        paused,
+        paused_chip_ids: chips
+            .into_iter()
+            .filter_map(|(kind, facade_id)| find_chip(kind, facade_id))
+            .map(|(_, chip_id)| chip_id)
+            .collect(),
+        held: held as u32,
+        delivered: delivered as u32,
+        ..Default::default()
+    }
+}
+
+fn deliver(packet: HeldPacket) {
+    match packet.direction {
+        PacketDirection::HostToController => forward_request_cxx(
+            packet.kind,
+            packet.facade_id,
+            &packet.packet,
+            packet.packet_type as u8,
+        ),
+        PacketDirection::ControllerToHost => forward_response_cxx(
+            packet.kind,
+            packet.facade_id,
+            &packet.packet,
+            packet.packet_type as u8,
+        ),
+    }
+}
+
+fn put_json<M: protobuf::MessageFull>(writer: ResponseWritable, message: &M) {
+    if let Ok(json_response) = print_to_string_with_options(message, &JSON_PRINT_OPTION) {
+        writer.put_ok("text/json", &json_response, &[])
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure")
+    }
+}
+
+/// The Rust breakpoints handler used directly by Http frontend for GET,
+/// POST and DELETE
+pub fn handle_breakpoints(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    match request.method.as_str() {
+        "GET" => put_json(writer, &DEBUGGER.list()),
+        "POST" => {
+            let body = String::from_utf8_lossy(&request.body);
+            let mut breakpoint = match protobuf_json_mapping::parse_from_str::<Breakpoint>(&body) {
+                Ok(breakpoint) => breakpoint,
+                Err(e) => {
+                    writer.put_error(400, format!("Invalid breakpoint: {e}").as_str());
+                    return;
+                }
+            };
+            match resolve(&mut breakpoint) {
+                Ok(facade) => put_json(writer, &DEBUGGER.set(breakpoint, facade)),
+                Err(e) => writer.put_error(404, e.as_str()),
+            }
+        }
+        "DELETE" => match param.parse::<u32>().map_err(|e| e.to_string()) {
+            Ok(id) => match DEBUGGER.delete(id) {
+                Ok(()) => writer.put_ok("text/plain", "", &[]),
+                Err(e) => writer.put_error(404, e.as_str()),
+            },
+            Err(e) => writer.put_error(404, format!("Invalid breakpoint id: {e}").as_str()),
+        },
+        _ => writer.put_error(404, "Not found."),
+    }
+}
+
+/// The Rust delivery handler used directly by Http frontend for GET and
+/// POST
+pub fn handle_delivery(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    let delivered = match (request.method.as_str(), param) {
+        ("GET", "") => 0,
+        ("POST", "pause") => {
+            DEBUGGER.pause();
+            0
+        }
+        ("POST", "resume") => {
+            let mut delivered = 0;
+            for hit in DEBUGGER.resume(|packet| {
+                deliver(packet);
+                delivered += 1;
+            }) {
+                record_hit(hit);
+            }
+            delivered
+        }
+        ("POST", "step") => {
+            let body = String::from_utf8_lossy(&request.body);
+            let step = match body.trim() {
+                "" => StepDeliveryRequest::new(),
+                body => match protobuf_json_mapping::parse_from_str::<StepDeliveryRequest>(body) {
+                    Ok(step) => step,
+                    Err(e) => {
+                        writer.put_error(400, format!("Invalid step request: {e}").as_str());
+                        return;
+                    }
+                },
+            };
+            let (delivered, hit) = DEBUGGER.step(step.count.max(1) as usize, deliver);
+            if let Some(hit) = hit {
+                record_hit(hit);
+            }
+            delivered
+        }
+        _ => {
+            writer.put_error(404, "Not found.");
+            return;
+        }
+    };
+    put_json(writer, &delivery_state(delivered));
+}
+
+/// breakpoints handle cxx for grpc server to call
+pub fn handle_breakpoints_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: match param.is_empty() {
+            true => "/v1/debug/breakpoints".to_string(),
+            false => format!("/v1/debug/breakpoints/{param}"),
+        },
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_breakpoints(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+/// delivery handle cxx for grpc server to call
+pub fn handle_delivery_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: match param.is_empty() {
+            true => "/v1/debug/delivery".to_string(),
+            false => format!("/v1/debug/delivery/{param}"),
+        },
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_delivery(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+// Cxx Methods for packet_hub to invoke
+
+fn hold(
+    kind: u32,
+    facade_id: u32,
+    packet: &CxxVector<u8>,
+    packet_type: u32,
+    direction: PacketDirection,
+) -> bool {
+    let packet =
+        HeldPacket::new(kind, facade_id, packet.as_slice().to_vec(), packet_type, direction);
+    match DEBUGGER.hold(packet) {
+        Hold::Pass => false,
+        Hold::Held => true,
+        Hold::Hit(hit) => {
+            record_hit(hit);
+            true
+        }
+    }
+}
+
+/// Returns true when the debugger holds a packet from the emulator.
+pub fn hold_request(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) -> bool {
+    hold(kind, facade_id, packet, packet_type, PacketDirection::HostToController)
+}
+
+/// Returns true when the debugger holds a packet to the emulator.
+pub fn hold_response(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) -> bool {
+    hold(kind, facade_id, packet, packet_type, PacketDirection::ControllerToHost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::memory::{Accountant, DropPolicy};
+    use std::sync::Arc;
+
+    const BT: u32 = 1;
+
+    fn request(facade_id: u32, packet: &[u8]) -> HeldPacket {
+        HeldPacket::new(BT, facade_id, packet.to_vec(), 1, PacketDirection::HostToController)
+    }
+
+    fn response(facade_id: u32, packet: &[u8]) -> HeldPacket {
+        HeldPacket::new(BT, facade_id, packet.to_vec(), 4, PacketDirection::ControllerToHost)
+    }
+
+    fn breakpoint(pattern: &[u8], mask: &[u8], scope: Scope) -> Breakpoint {
+        Breakpoint {
+            pattern: pattern.to_vec(),
+            mask: mask.to_vec(),
+            scope: scope.into(),
+            ..Default::default()
+        }
+    }
+
+    fn resume(debugger: &Debugger) -> (Vec<Vec<u8>>, Vec<u32>) {
+        let mut delivered = Vec::new();
+        let hits = debugger.resume(|packet| delivered.push(packet.packet));
+        (delivered, hits.iter().map(|hit| hit.breakpoint_id).collect())
+    }
+
+    #[test]
+    fn test_match() {
+        let mut entry =
+            Entry { breakpoint: breakpoint(&[0x0c, 0x20], &[], Scope::CHIP), facade: None };
+        assert!(entry.matches(&request(1, &[0x0c, 0x20, 2])));
+        assert!(!entry.matches(&request(1, &[0x0b, 0x20, 2])));
+        assert!(!entry.matches(&request(1, &[0x0c])));
+        entry.breakpoint.offset = 1;
+        entry.breakpoint.pattern = vec![0x20];
+        entry.breakpoint.mask = vec![0xf0];
+        assert!(entry.matches(&request(1, &[0x0c, 0x2f])));
+        entry.breakpoint.direction = Direction::CONTROLLER_TO_HOST.into();
+        assert!(!entry.matches(&request(1, &[0x0c, 0x2f])));
+        assert!(entry.matches(&response(1, &[0x0c, 0x2f])));
+        entry.facade = Some((BT, 2));
+        assert!(!entry.matches(&response(1, &[0x0c, 0x2f])));
+        entry.breakpoint.chip_kind = ChipKind::WIFI.into();
+        entry.facade = None;
+        assert!(!entry.matches(&response(1, &[0x0c, 0x2f])));
+    }
+
+    #[test]
+    fn test_chip_breakpoint() {
+        let debugger = Debugger::default();
+        assert_eq!(debugger.hold(request(1, &[1])), Hold::Pass);
+        let id = debugger.set(breakpoint(&[2], &[], Scope::CHIP), None).id;
+        assert_eq!(debugger.hold(request(1, &[1])), Hold::Pass);
+        assert!(
+            matches!(debugger.hold(request(1, &[2, 0])), Hold::Hit(hit) if hit.breakpoint_id == id)
+        );
+        // The chip is paused, other chips are not.
+        assert_eq!(debugger.hold(response(1, &[3])), Hold::Held);
+        assert_eq!(debugger.hold(request(2, &[4])), Hold::Pass);
+        assert_eq!(debugger.paused(), (false, vec![(BT, 1)], 2));
+        debugger.delete(id).unwrap();
+        assert_eq!(resume(&debugger), (vec![vec![2, 0], vec![3]], vec![]));
+        assert_eq!(debugger.paused(), (false, vec![], 0));
+        assert_eq!(debugger.hold(request(1, &[2])), Hold::Pass);
+        assert!(debugger.delete(id).is_err());
+    }
+
+    #[test]
+    fn test_scene_breakpoint() {
+        let debugger = Debugger::default();
+        let id = debugger.set(breakpoint(&[2], &[], Scope::SCENE), None).id;
+        assert!(matches!(debugger.hold(request(1, &[2])), Hold::Hit(_)));
+        assert_eq!(debugger.hold(request(2, &[5])), Hold::Held);
+        // The held packet of chip 2 hits the breakpoint again on resume,
+        // after the hit packet is delivered.
+        assert_eq!(debugger.hold(request(2, &[2])), Hold::Held);
+        assert_eq!(debugger.hold(request(1, &[6])), Hold::Held);
+        assert_eq!(resume(&debugger), (vec![vec![2], vec![5]], vec![id]));
+        assert_eq!(debugger.paused(), (true, vec![], 2));
+        assert_eq!(resume(&debugger), (vec![vec![2], vec![6]], vec![]));
+        assert_eq!(debugger.list().breakpoints[0].hits, 2);
+    }
+
+    #[test]
+    fn test_held_memory_pool() {
+        let accountant = Arc::new(Accountant::new(
+            0,
+            [(0, DropPolicy::DropOldest), (0, DropPolicy::DropOldest), (2, DropPolicy::DropNewest)],
+        ));
+        let held = BoundedBuffer::with_accountant(Pool::Packets, accountant.clone());
+        let debugger = Debugger::with_held(held);
+        debugger.pause();
+        for packet in [[1], [2], [3]] {
+            assert_eq!(debugger.hold(request(1, &packet)), Hold::Held);
+        }
+        // The packets pool holds two packets of a byte.
+        assert_eq!(debugger.paused(), (true, vec![], 2));
+        assert_eq!(accountant.usage(Pool::Packets).dropped_items, 1);
+        assert_eq!(resume(&debugger).0, [vec![1], vec![2]]);
+        assert_eq!(accountant.usage(Pool::Packets).used_bytes, 0);
+    }
+
+    #[test]
+    fn test_pause_and_step() {
+        let debugger = Debugger::default();
+        debugger.pause();
+        assert_eq!(debugger.hold(request(1, &[1])), Hold::Held);
+        assert_eq!(debugger.hold(response(2, &[2])), Hold::Held);
+        assert_eq!(debugger.hold(request(1, &[3])), Hold::Held);
+        let mut delivered = Vec::new();
+        assert_eq!(debugger.step(2, |packet| delivered.push(packet.packet)), (2, None));
+        assert_eq!(delivered, [vec![1], vec![2]]);
+        assert_eq!(debugger.paused(), (true, vec![], 1));
+        // A breakpoint stops the step before its packet, delivered next.
+        let id = debugger.set(breakpoint(&[3], &[], Scope::CHIP), None).id;
+        assert_eq!(debugger.hold(request(2, &[4])), Hold::Held);
+        let (count, hit) = debugger.step(2, |packet| delivered.push(packet.packet));
+        assert_eq!((count, hit.map(|hit| hit.breakpoint_id)), (0, Some(id)));
+        assert_eq!(debugger.step(5, |packet| delivered.push(packet.packet)), (2, None));
+        assert_eq!(delivered, [vec![1], vec![2], vec![3], vec![4]]);
+        assert_eq!(debugger.paused(), (true, vec![(BT, 1)], 0));
+        assert_eq!(resume(&debugger), (vec![], vec![]));
+        assert_eq!(debugger.hold(request(1, &[5])), Hold::Pass);
+    }
+}
@@ -20,6 +20,7 @@
 use crate::devices::facades::FacadeIdentifier;
 use crate::devices::facades::*;
 use crate::devices::id_factory::IdFactory;
+use crate::processor::bandwidth::{get_bandwidth, set_bandwidth};
 use frontend_proto::common::ChipKind as ProtoChipKind;
 use frontend_proto::model::Chip as ProtoChip;
 use frontend_proto::model::State as ProtoState;
@@ -93,6 +94,10 @@ impl Chip {
                 eprint!("Unknown chip kind: {:?}", chip.kind);
             }
         }
+        let bits_per_second = get_bandwidth(self.kind as u32, self.facade_id as u32);
+        if bits_per_second > 0 {
+            chip.bandwidth.mut_or_insert_default().bits_per_second = bits_per_second;
+        }
         chip
     }
 
@@ -110,6 +115,9 @@ impl Chip {
         if !patch.product_name.is_empty() {
             self.product_name = patch.product_name.clone();
         }
+        if let Some(bandwidth) = patch.bandwidth.as_ref() {
+            set_bandwidth(self.kind as u32, self.facade_id as u32, bandwidth.bits_per_second);
+        }
         // Check both ChipKind and RadioKind fields, they should be consistent
         if self.kind == ProtoChipKind::BLUETOOTH && patch.has_bt() {
             facades::hci_patch(self.facade_id, patch.bt());
@@ -121,6 +129,7 @@ impl Chip {
     }
 
     pub fn remove(&mut self) {
+        set_bandwidth(self.kind as u32, self.facade_id as u32, 0);
         match self.kind {
             ProtoChipKind::BLUETOOTH => {
                 hci_remove(self.facade_id);
@@ -135,6 +144,7 @@ impl Chip {
     }
 
     pub fn reset(&mut self) {
+        set_bandwidth(self.kind as u32, self.facade_id as u32, 0);
         match self.kind {
             ProtoChipKind::BLUETOOTH => {
                 hci_reset(self.facade_id);
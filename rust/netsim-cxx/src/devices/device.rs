@@ -20,6 +20,7 @@ use crate::devices::chip;
 use crate::devices::chip::Chip;
 use crate::devices::chip::ChipIdentifier;
 use crate::devices::facades::FacadeIdentifier;
+use crate::logger::warn;
 use frontend_proto::common::ChipKind as ProtoChipKind;
 use frontend_proto::model::Device as ProtoDevice;
 use frontend_proto::model::Orientation as ProtoOrientation;
@@ -101,7 +102,7 @@ impl Device {
         if let Some(chip) = self.chips.get_mut(&chip_id) {
             chip.remove();
         } else {
-            eprintln!("RemoveChip id {chip_id} not found");
+            warn!(chip_id: chip_id; "RemoveChip id {chip_id} not found");
         }
         self.chips.remove(&chip_id);
     }
@@ -116,7 +117,7 @@ impl Device {
     ) -> Option<AddChipResult> {
         for chip in self.chips.values() {
             if chip.kind == chip_kind && chip.name == chip_name {
-                eprintln!("Device::AddChip - duplicate at id {}, skipping.", chip.id);
+                warn!("Device::AddChip - duplicate at id {}, skipping.", chip.id);
                 return None;
             }
         }
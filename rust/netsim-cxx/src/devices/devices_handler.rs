@@ -25,8 +25,10 @@
 use super::chip::ChipIdentifier;
 use super::device::DeviceIdentifier;
 use super::id_factory::IdFactory;
+use crate::clock::clock;
 use crate::devices::device::AddChipResult;
 use crate::devices::device::Device;
+use crate::logger::warn;
 use frontend_proto::common::ChipKind as ProtoChipKind;
 use frontend_proto::model::Device as ProtoDevice;
 use frontend_proto::model::Position as ProtoPosition;
@@ -38,7 +40,6 @@ use std::collections::HashMap;
 use std::sync::RwLock;
 use std::sync::RwLockWriteGuard;
 use std::time::Duration;
-use std::time::Instant;
 
 lazy_static! {
     static ref DEVICES: RwLock<Devices> = RwLock::new(Devices::new());
@@ -49,7 +50,8 @@ static IDLE_SECS_FOR_SHUTDOWN: u64 = 120;
 struct Devices {
     devices: HashMap<DeviceIdentifier, Device>,
     id_factory: IdFactory<DeviceIdentifier>,
-    pub idle_since: Option<Instant>,
+    // Monotonic clock time when the last device went away.
+    pub idle_since: Option<Duration>,
 }
 
 impl Devices {
@@ -86,7 +88,7 @@ pub fn add_chip(
         .unwrap()
         .add_chip(device_name, chip_kind, chip_name, chip_manufacturer, chip_product_name)
         .unwrap_or_else(|| {
-            eprintln!("Error adding chip to device {}", device_id);
+            warn!("Error adding chip to device {}", device_id);
             AddChipResult { device_id: 0, chip_id: 0, facade_id: 0 }
         })
 }
@@ -114,11 +116,11 @@ fn get_or_create_device(
 /// Called when the last chip for the device is removed.
 fn remove_device(resource: &mut RwLockWriteGuard<Devices>, id: DeviceIdentifier) {
     resource.devices.remove(&id).or_else(|| {
-        eprintln!("Error removing device id {id}");
+        warn!("Error removing device id {id}");
         None
     });
     if resource.devices.is_empty() {
-        resource.idle_since = Some(Instant::now());
+        resource.idle_since = Some(clock().monotonic());
     }
 }
 
@@ -137,9 +139,6 @@ pub fn remove_chip(device_id: DeviceIdentifier, chip_id: ChipIdentifier) {
         remove_device(&mut resource, device_id);
     }
 }
-/// MATCH
-///
-///
 
 // lock the devices, find the id and call the patch function
 #[allow(dead_code)]
@@ -153,11 +152,11 @@ fn patch_device(id: DeviceIdentifier, patch_json: &str) {
             .get_mut(&id)
             .map(|device_ref| device_ref.patch(&proto_device))
             .or_else(|| {
-                eprintln!("No such device with id {id}");
+                warn!("No such device with id {id}");
                 None
             });
     } else {
-        eprintln!("Error parsing device {id} patch json {}", patch_json);
+        warn!("Error parsing device {id} patch json {}", patch_json);
     }
 }
 
@@ -170,11 +169,11 @@ pub fn get_distance(id: DeviceIdentifier, other_id: DeviceIdentifier) -> f32 {
     print!("get_distance({:?}, {:?}) = ", id, other_id);
     let devices = &DEVICES.read().unwrap().devices;
     let a = devices.get(&id).map(|device_ref| device_ref.position.clone()).or_else(|| {
-        eprintln!("No such device with id {id}");
+        warn!("No such device with id {id}");
         None
     });
     let b = devices.get(&other_id).map(|device_ref| device_ref.position.clone()).or_else(|| {
-        eprintln!("No such device with id {id}");
+        warn!("No such device with id {id}");
         None
     });
     match (a, b) {
@@ -191,7 +190,7 @@ pub fn get_devices() -> String {
         scene.devices.push(device.get());
     });
     print_to_string(&scene).unwrap_or_else(|e| -> String {
-        eprintln!("Error converting scene {:?}", e);
+        warn!("Error converting scene {:?}", e);
         String::new()
     })
 }
@@ -200,7 +199,7 @@ pub fn get_devices() -> String {
 pub fn reset(id: DeviceIdentifier) {
     DEVICES.write().unwrap().devices.get_mut(&id).map(|device_ref| device_ref.reset()).or_else(
         || {
-            eprintln!("No such device with id {id}");
+            warn!("No such device with id {id}");
             None
         },
     );
@@ -209,8 +208,9 @@ pub fn reset(id: DeviceIdentifier) {
 #[allow(dead_code)]
 fn get_secs_until_idle_shutdown() -> Option<u32> {
     if let Some(idle_since) = DEVICES.read().unwrap().idle_since {
-        let remaining_secs = idle_since
-            .elapsed()
+        let remaining_secs = clock()
+            .monotonic()
+            .saturating_sub(idle_since)
             .saturating_sub(Duration::from_secs(IDLE_SECS_FOR_SHUTDOWN))
             .as_secs();
         Some(remaining_secs.try_into().unwrap())
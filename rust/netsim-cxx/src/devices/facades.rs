@@ -16,49 +16,50 @@ use frontend_proto::model::chip::Bluetooth as ProtoBluetoothChip;
 use frontend_proto::model::chip::Radio as ProtoRadioChip;
 
 use super::device::DeviceIdentifier;
+use crate::logger::debug;
 
 pub type FacadeIdentifier = i32;
 
 pub fn hci_get(facade_id: FacadeIdentifier) -> ProtoBluetoothChip {
-    println!("hci_get({})", facade_id);
+    debug!("hci_get({})", facade_id);
     ProtoBluetoothChip::new()
 }
 
 pub fn wifi_get(facade_id: FacadeIdentifier) -> ProtoRadioChip {
-    println!("wifi_get({})", facade_id);
+    debug!("wifi_get({})", facade_id);
     ProtoRadioChip::new()
 }
 
 pub fn hci_remove(facade_id: FacadeIdentifier) {
-    println!("hci_remove({})", facade_id);
+    debug!("hci_remove({})", facade_id);
 }
 
 pub fn wifi_remove(facade_id: FacadeIdentifier) {
-    println!("wifi_remove({})", facade_id);
+    debug!("wifi_remove({})", facade_id);
 }
 
 pub fn hci_add(_device_id: DeviceIdentifier) -> FacadeIdentifier {
-    println!("hci_add()");
+    debug!("hci_add()");
     0
 }
 
 pub fn wifi_add(_device_id: DeviceIdentifier) -> FacadeIdentifier {
-    println!("wifi_add()");
+    debug!("wifi_add()");
     0
 }
 
 pub fn hci_patch(_facade_id: FacadeIdentifier, _patch: &ProtoBluetoothChip) {
-    println!("hci_patch()");
+    debug!("hci_patch()");
 }
 
 pub fn wifi_patch(_facade_id: FacadeIdentifier, _patch: &ProtoRadioChip) {
-    println!("wifi_patch()");
+    debug!("wifi_patch()");
 }
 
 pub fn hci_reset(_facade_id: FacadeIdentifier) {
-    println!("bt_reset()");
+    debug!("bt_reset()");
 }
 
 pub fn wifi_reset(_facade_id: FacadeIdentifier) {
-    println!("wifi_reset()");
+    debug!("wifi_reset()");
 }
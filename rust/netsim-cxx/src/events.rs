@@ -0,0 +1,506 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Event history.
+//!
+//! The scene controller records an event with the state of the affected
+//! devices on every change of the simulation, the flood protection when a
+//! chip exceeds its rate limit, the captures when they approach and
+//! reach their quota, and the debugger when a packet hits a breakpoint. The
+//! history keeps the most recent events within the budget of the events
+//! memory pool, so a client that connects late can list what happened
+//! before it subscribed.
+//!
+//! The captures record their lifecycle: started, stopped, rotated, removed
+//! and failed. The subscribers of the history, e.g. the RegisterEvents RPC
+//! with handle_events_stream, receive the events as they are recorded
+//! instead of polling. The SubscribeEvents RPC selects the events streamed
+//! like a listing, and resumes after the last event a client received by
+//! streaming the events of the history after it first.
+//!
+//! The packets of the events are cleared for the clients without the
+//! capture download scope, see auth::may_read_packets.
+//!
+//! /v1/events --> handle_events (POST a ListEventsRequest)
+
+use frontend_proto::frontend::event::Type;
+use frontend_proto::frontend::{
+    Event, ListEventsRequest, ListEventsResponse, SubscribeEventsRequest,
+};
+use frontend_proto::model::Scene;
+use lazy_static::lazy_static;
+use protobuf::well_known_types::timestamp::Timestamp;
+use protobuf::{EnumOrUnknown, Message};
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::pin::Pin;
+use std::sync::mpsc::{sync_channel, Receiver, RecvTimeoutError, SyncSender, TrySendError};
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use crate::auth::may_read_packets;
+use crate::clock::{now, to_timestamp};
+use crate::ffi::{CxxServerResponseWriter, EventType};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::warn;
+use crate::memory::{BoundedBuffer, Pool};
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+/// Events queued for a subscriber; the events beyond it are dropped until
+/// the subscriber catches up.
+const SUBSCRIBER_QUEUE_LEN: usize = 1024;
+/// Interval of the checks of a cancelled stream without events.
+const STREAM_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// A subscriber of the history, a blocking handler or a task.
+enum Subscriber {
+    Blocking(SyncSender<Event>),
+    Task(mpsc::Sender<Event>),
+}
+
+impl Subscriber {
+    // Queues an event, dropping it when the queue is full. Returns false
+    // once the receiver is gone.
+    fn send(&self, event: &Event) -> bool {
+        match self {
+            Subscriber::Blocking(sender) => {
+                !matches!(sender.try_send(event.clone()), Err(TrySendError::Disconnected(_)))
+            }
+            Subscriber::Task(sender) => {
+                !matches!(sender.try_send(event.clone()), Err(mpsc::error::TrySendError::Closed(_)))
+            }
+        }
+    }
+}
+
+struct Events {
+    buffer: BoundedBuffer<Event>,
+    next_id: u64,
+    // Highest id of the events dropped from the buffer.
+    last_dropped_id: u64,
+    subscribers: Vec<Subscriber>,
+}
+
+impl Events {
+    // Returns the events after `since_id`, none if 0.
+    fn since(&self, since_id: u64) -> Vec<Event> {
+        match since_id {
+            0 => Vec::new(),
+            since_id => {
+                let start = self.buffer.partition_point(|event| event.id <= since_id);
+                self.buffer.iter_from(start).cloned().collect()
+            }
+        }
+    }
+}
+
+/// A bounded history of events ordered by id.
+pub struct History {
+    events: Mutex<Events>,
+}
+
+fn to_duration(timestamp: &Timestamp) -> Duration {
+    Duration::new(timestamp.seconds.max(0) as u64, timestamp.nanos.max(0) as u32)
+}
+
+// Returns true when an event has one of the types, all if empty, and is of
+// the device, any if empty.
+fn selects(types: &[EnumOrUnknown<Type>], device_name: &str, event: &Event) -> bool {
+    (types.is_empty() || types.contains(&event.type_))
+        && (device_name.is_empty() || event.device_name == device_name)
+}
+
+impl History {
+    pub fn new(buffer: BoundedBuffer<Event>) -> History {
+        History {
+            events: Mutex::new(Events {
+                buffer,
+                next_id: 1,
+                last_dropped_id: 0,
+                subscribers: Vec::new(),
+            }),
+        }
+    }
+
+    /// Returns the receiver of the events recorded from now on.
+    pub fn subscribe(&self) -> Receiver<Event> {
+        self.subscribe_since(0).1
+    }
+
+    /// Returns the events of the history after `since_id`, none if 0, and
+    /// the receiver of the events recorded from now on, so no event is
+    /// missed or received twice in between.
+    pub fn subscribe_since(&self, since_id: u64) -> (Vec<Event>, Receiver<Event>) {
+        let (sender, receiver) = sync_channel(SUBSCRIBER_QUEUE_LEN);
+        let mut events = self.events.lock().unwrap();
+        events.subscribers.push(Subscriber::Blocking(sender));
+        (events.since(since_id), receiver)
+    }
+
+    /// Like subscribe_since, with a receiver for the tasks of the runtime.
+    pub fn subscribe_task_since(&self, since_id: u64) -> (Vec<Event>, mpsc::Receiver<Event>) {
+        let (sender, receiver) = mpsc::channel(SUBSCRIBER_QUEUE_LEN);
+        let mut events = self.events.lock().unwrap();
+        events.subscribers.push(Subscriber::Task(sender));
+        (events.since(since_id), receiver)
+    }
+
+    /// Assigns the next id to an event, sends it to the subscribers and
+    /// appends it to the history.
+    pub fn record(&self, mut event: Event) -> u64 {
+        let events = &mut *self.events.lock().unwrap();
+        let id = events.next_id;
+        events.next_id += 1;
+        event.id = id;
+        events.subscribers.retain(|subscriber| subscriber.send(&event));
+        let size = event.compute_size() as usize;
+        if !events.buffer.push(event, size) {
+            events.last_dropped_id = id;
+        } else if let Some(oldest) = events.buffer.iter().next() {
+            // The drop policy may have evicted older events.
+            events.last_dropped_id = events.last_dropped_id.max(oldest.id - 1);
+        }
+        id
+    }
+
+    /// Returns the events selected by `request`, oldest first.
+    pub fn list(&self, request: &ListEventsRequest) -> ListEventsResponse {
+        let events = self.events.lock().unwrap();
+        let start = events.buffer.partition_point(|event| event.id <= request.since_id);
+        let since_time = request.since_time.as_ref().map(to_duration);
+        let limit = match request.limit {
+            0 => usize::MAX,
+            limit => limit as usize,
+        };
+        let selected = events
+            .buffer
+            .iter_from(start)
+            .filter(|event| selects(&request.types, &request.device_name, event))
+            .filter(|event| since_time.is_none_or(|time| to_duration(&event.timestamp) > time))
+            .take(limit)
+            .cloned()
+            .collect();
+        ListEventsResponse {
+            events: selected,
+            truncated: request.since_id < events.last_dropped_id,
+            last_id: events.next_id - 1,
+            ..Default::default()
+        }
+    }
+}
+
+lazy_static! {
+    static ref HISTORY: History = History::new(BoundedBuffer::new(Pool::Events));
+}
+
+/// Returns the netsimd event history.
+pub fn history() -> &'static History {
+    &HISTORY
+}
+
+// Clears the packet of an event for a client that may not read packets.
+fn redact(event: &mut Event, packets: bool) {
+    if !packets {
+        event.packet.clear();
+    }
+}
+
+/// Returns the JSON of an event streamed to a client, with its packet if
+/// `packets`.
+pub fn event_json(mut event: Event, packets: bool) -> Option<String> {
+    redact(&mut event, packets);
+    print_to_string_with_options(&event, &JSON_PRINT_OPTION).ok()
+}
+
+fn to_event_type(event_type: EventType) -> Type {
+    match event_type {
+        EventType::DeviceAdded => Type::DEVICE_ADDED,
+        EventType::DeviceRemoved => Type::DEVICE_REMOVED,
+        EventType::DevicePatched => Type::DEVICE_PATCHED,
+        EventType::ChipAdded => Type::CHIP_ADDED,
+        EventType::ChipRemoved => Type::CHIP_REMOVED,
+        EventType::Reset => Type::RESET,
+        EventType::ChipStateChanged => Type::CHIP_STATE_CHANGED,
+        _ => Type::UNSPECIFIED,
+    }
+}
+
+/// The Rust events handler used directly by Http frontend for POST
+pub fn handle_events(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "POST" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let body = String::from_utf8_lossy(&request.body);
+    let list_request = match protobuf_json_mapping::parse_from_str::<ListEventsRequest>(&body) {
+        Ok(list_request) => list_request,
+        Err(e) => {
+            writer.put_error(400, format!("Invalid events request: {e}").as_str());
+            return;
+        }
+    };
+    let packets = may_read_packets(&request.headers.get("Authorization").unwrap_or_default());
+    let mut response = history().list(&list_request);
+    response.events.iter_mut().for_each(|event| redact(event, packets));
+    if let Ok(json_response) = print_to_string_with_options(&response, &JSON_PRINT_OPTION) {
+        writer.put_ok("text/json", &json_response, &[])
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure")
+    }
+}
+
+/// Streams the events recorded from now on, one JSON Event per chunk, until
+/// the client cancels the stream.
+pub fn handle_events_stream(packets: bool, writer: ResponseWritable) {
+    handle_subscribe_events(&SubscribeEventsRequest::new(), packets, writer);
+}
+
+/// Streams the events selected by `request`, one JSON Event per chunk,
+/// until the client cancels the stream. The events have their packets
+/// cleared unless `packets`.
+pub fn handle_subscribe_events(
+    request: &SubscribeEventsRequest,
+    packets: bool,
+    writer: ResponseWritable,
+) {
+    let (missed, events) = history().subscribe_since(request.since_id);
+    writer.put_ok_with_length("text/json", 0, &[]);
+    let put_event = |writer: ResponseWritable, event: Event| {
+        if !selects(&request.types, &request.device_name, &event) {
+            return;
+        }
+        if let Some(json) = event_json(event, packets) {
+            writer.put_chunk(json.as_bytes());
+        }
+    };
+    for event in missed {
+        put_event(writer, event);
+    }
+    while !writer.is_cancelled() {
+        match events.recv_timeout(STREAM_POLL_INTERVAL) {
+            Ok(event) => put_event(writer, event),
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+/// events stream handle cxx for the RegisterEvents RPC
+pub fn handle_events_stream_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    authorization: String,
+) {
+    handle_events_stream(
+        may_read_packets(&authorization),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+/// events stream handle cxx for the SubscribeEvents RPC, with the
+/// SubscribeEventsRequest given as JSON body
+pub fn handle_subscribe_events_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    body: String,
+    authorization: String,
+) {
+    let writer: ResponseWritable = &mut CxxServerResponseWriterWrapper { writer: responder };
+    let packets = may_read_packets(&authorization);
+    match protobuf_json_mapping::parse_from_str::<SubscribeEventsRequest>(&body) {
+        Ok(request) => handle_subscribe_events(&request, packets, writer),
+        Err(e) => writer.put_error(400, format!("Invalid subscribe request: {e}").as_str()),
+    }
+}
+
+/// events handle cxx for grpc server to call
+pub fn handle_events_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+    authorization: String,
+) {
+    let mut headers = HttpHeaders::new();
+    headers.add_header("Authorization", &authorization);
+    let request = HttpRequest {
+        method,
+        uri: "/v1/events".to_string(),
+        headers,
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_events(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+// Cxx Method for the scene controller to invoke
+
+pub fn record_event(event_type: EventType, device_name: &str, chip_id: u32, scene: &[u8]) {
+    let devices = match Scene::parse_from_bytes(scene) {
+        Ok(scene) => scene.devices,
+        Err(e) => {
+            warn!("invalid scene of {device_name} event: {e}");
+            Vec::new()
+        }
+    };
+    history().record(Event {
+        devices,
+        timestamp: Some(to_timestamp(now())).into(),
+        type_: to_event_type(event_type).into(),
+        device_name: device_name.to_string(),
+        chip_id: chip_id as i32,
+        ..Default::default()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::{Accountant, DropPolicy};
+    use std::sync::Arc;
+
+    fn history(pool_limit: usize) -> History {
+        let pool = (pool_limit, DropPolicy::DropOldest);
+        let accountant = Arc::new(Accountant::new(0, [pool; 3]));
+        History::new(BoundedBuffer::with_accountant(Pool::Events, accountant))
+    }
+
+    fn event(event_type: Type, device_name: &str, seconds: i64) -> Event {
+        Event {
+            type_: event_type.into(),
+            device_name: device_name.to_string(),
+            timestamp: Some(Timestamp { seconds, ..Default::default() }).into(),
+            ..Default::default()
+        }
+    }
+
+    fn ids(response: &ListEventsResponse) -> Vec<u64> {
+        response.events.iter().map(|event| event.id).collect()
+    }
+
+    #[test]
+    fn test_list() {
+        let history = history(0);
+        history.record(event(Type::DEVICE_ADDED, "a", 1));
+        history.record(event(Type::DEVICE_ADDED, "b", 2));
+        history.record(event(Type::DEVICE_PATCHED, "a", 3));
+        history.record(event(Type::RESET, "", 4));
+        let response = history.list(&ListEventsRequest::new());
+        assert_eq!(ids(&response), [1, 2, 3, 4]);
+        assert_eq!(response.last_id, 4);
+        assert!(!response.truncated);
+        let request = ListEventsRequest { device_name: "a".to_string(), ..Default::default() };
+        assert_eq!(ids(&history.list(&request)), [1, 3]);
+        let request =
+            ListEventsRequest { types: vec![Type::DEVICE_ADDED.into()], ..Default::default() };
+        assert_eq!(ids(&history.list(&request)), [1, 2]);
+        let request = ListEventsRequest { since_id: 1, limit: 2, ..Default::default() };
+        assert_eq!(ids(&history.list(&request)), [2, 3]);
+        let request = ListEventsRequest {
+            since_time: Some(Timestamp { seconds: 2, ..Default::default() }).into(),
+            ..Default::default()
+        };
+        assert_eq!(ids(&history.list(&request)), [3, 4]);
+    }
+
+    #[test]
+    fn test_subscribe() {
+        let history = history(0);
+        history.record(event(Type::DEVICE_ADDED, "a", 1));
+        let events = history.subscribe();
+        history.record(event(Type::CAPTURE_STARTED, "a", 2));
+        history.record(event(Type::CAPTURE_STOPPED, "a", 3));
+        let received: Vec<(u64, Type)> =
+            events.try_iter().map(|event| (event.id, event.type_.unwrap())).collect();
+        assert_eq!(received, [(2, Type::CAPTURE_STARTED), (3, Type::CAPTURE_STOPPED)]);
+        // A gone subscriber is forgotten.
+        drop(events);
+        history.record(event(Type::RESET, "", 4));
+        assert!(history.events.lock().unwrap().subscribers.is_empty());
+    }
+
+    #[test]
+    fn test_subscribe_task() {
+        let history = history(0);
+        history.record(event(Type::DEVICE_ADDED, "a", 1));
+        let (missed, mut events) = history.subscribe_task_since(1);
+        assert!(missed.is_empty());
+        history.record(event(Type::RESET, "", 2));
+        assert_eq!(events.try_recv().map(|event| event.id), Ok(2));
+        drop(events);
+        history.record(event(Type::RESET, "", 3));
+        assert!(history.events.lock().unwrap().subscribers.is_empty());
+    }
+
+    #[test]
+    fn test_subscribe_since() {
+        let history = history(0);
+        history.record(event(Type::DEVICE_ADDED, "a", 1));
+        history.record(event(Type::CHIP_STATE_CHANGED, "a", 2));
+        let (missed, events) = history.subscribe_since(1);
+        history.record(event(Type::DEVICE_REMOVED, "a", 3));
+        let received: Vec<u64> = missed
+            .iter()
+            .chain(&events.try_iter().collect::<Vec<_>>())
+            .map(|event| event.id)
+            .collect();
+        assert_eq!(received, [2, 3]);
+        let (missed, _) = history.subscribe_since(0);
+        assert!(missed.is_empty());
+        let types = [Type::CHIP_STATE_CHANGED.into()];
+        let selected: Vec<bool> = history
+            .list(&ListEventsRequest::new())
+            .events
+            .iter()
+            .map(|event| selects(&types, "a", event))
+            .collect();
+        assert_eq!(selected, [false, true, false]);
+        assert!(!selects(&[], "b", &event(Type::DEVICE_ADDED, "a", 1)));
+    }
+
+    #[test]
+    fn test_truncated() {
+        let size = event(Type::DEVICE_ADDED, "a", 1).compute_size() as usize + 2;
+        let history = history(2 * size);
+        for seconds in 1..=4 {
+            history.record(event(Type::DEVICE_ADDED, "a", seconds));
+        }
+        let response = history.list(&ListEventsRequest::new());
+        assert_eq!(ids(&response), [3, 4]);
+        assert!(response.truncated);
+        let request = ListEventsRequest { since_id: 2, ..Default::default() };
+        assert!(!history.list(&request).truncated);
+    }
+
+    #[test]
+    fn test_redact() {
+        let hit = Event { packet: vec![1, 0x03, 0x0c, 0], ..event(Type::BREAKPOINT_HIT, "a", 1) };
+        let mut redacted = hit.clone();
+        redact(&mut redacted, true);
+        assert_eq!(redacted, hit);
+        redact(&mut redacted, false);
+        assert!(redacted.packet.is_empty());
+        assert_eq!(redacted.breakpoint_id, hit.breakpoint_id);
+    }
+}
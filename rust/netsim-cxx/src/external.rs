@@ -0,0 +1,221 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! External controllers.
+//!
+//! A chip can be backed by a controller implementation running outside
+//! netsimd, such as a separately running rootcanal or a vendor simulator,
+//! instead of the built-in facade. Controllers are configured with the
+//! `external.controllers` setting as a comma separated list of
+//! `<device name>/<chip kind>=<host>:<port>`, where the chip kind is
+//! `bluetooth`, exchanging H4 packets, `uwb`, exchanging UCI packets, or
+//! `nfc`, exchanging NCI packets.
+//!
+//! netsimd connects to the controller when a configured chip is added. The
+//! packets of the chip reach the controller after the flood protection, the
+//! processors and the MTU, and are captured as usual; the packets of the
+//! controller go through the debugger, the processors and the captures
+//! like the packets of a facade. The chip falls back to its built-in facade
+//! when the controller is unreachable or disconnects.
+
+use cxx::CxxVector;
+use frontend_proto::common::ChipKind;
+use lazy_static::lazy_static;
+use protobuf::Enum;
+use std::collections::HashMap;
+use std::io::{BufReader, Write};
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+use crate::config::get_setting;
+use crate::ffi::handle_response_cxx;
+use crate::logger::{info, warn};
+use crate::transport::{h4, nci, stats, uci};
+
+/// Time allowed to connect to a controller.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A connection to the external controller of a chip.
+struct Controller {
+    address: String,
+    chip_id: u32,
+    writer: Mutex<TcpStream>,
+}
+
+lazy_static! {
+    // Controllers by the kind and facade id of their chip.
+    static ref CONTROLLERS: RwLock<HashMap<(u32, u32), Arc<Controller>>> =
+        RwLock::new(HashMap::new());
+}
+
+// Parses a `<device name>/<chip kind>=<host>:<port>` entry.
+fn parse_controller(entry: &str) -> Option<(&str, ChipKind, &str)> {
+    let (chip, address) = entry.split_once('=')?;
+    let (device_name, kind) = chip.trim().rsplit_once('/')?;
+    let chip_kind = match kind.to_lowercase().as_str() {
+        "bluetooth" => ChipKind::BLUETOOTH,
+        "uwb" => ChipKind::UWB,
+        "nfc" => ChipKind::NFC,
+        _ => return None,
+    };
+    Some((device_name, chip_kind, address.trim()))
+}
+
+// Returns the address of the controller configured for a chip.
+fn controller_address(device_name: &str, chip_kind: ChipKind) -> Option<String> {
+    get_setting("external.controllers").split(',').filter(|e| !e.trim().is_empty()).find_map(
+        |entry| match parse_controller(entry) {
+            Some((name, kind, address)) if name == device_name && kind == chip_kind => {
+                Some(address.to_string())
+            }
+            Some(_) => None,
+            None => {
+                warn!("invalid external controller entry {entry}");
+                None
+            }
+        },
+    )
+}
+
+fn connect(address: &str) -> std::io::Result<TcpStream> {
+    let mut last_error = None;
+    for socket_address in address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&socket_address, CONNECT_TIMEOUT) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or(std::io::ErrorKind::AddrNotAvailable.into()))
+}
+
+// Passes the packets of a controller to the packet hub until it
+// disconnects.
+fn run_controller(stream: TcpStream, chip_kind: ChipKind, facade_id: u32) {
+    let kind = chip_kind as u32;
+    let mut reader = BufReader::new(stream);
+    let error = loop {
+        match chip_kind {
+            ChipKind::UWB => match uci::read_uci_packet(&mut reader) {
+                Ok(uci::Packet { payload }) => handle_response_cxx(kind, facade_id, &payload, 0),
+                Err(e) => break format!("{e:?}"),
+            },
+            ChipKind::NFC => match nci::read_nci_packet(&mut reader) {
+                Ok(nci::Packet { payload }) => handle_response_cxx(kind, facade_id, &payload, 0),
+                Err(e) => break format!("{e:?}"),
+            },
+            _ => match h4::read_h4_packet(&mut reader) {
+                Ok(h4::Packet { h4_type, payload }) => {
+                    handle_response_cxx(kind, facade_id, &payload, h4_type)
+                }
+                Err(e) => {
+                    if !matches!(e, h4::PacketError::IoError(_)) {
+                        stats::record_malformed(kind, facade_id);
+                    }
+                    break format!("{e:?}");
+                }
+            },
+        }
+    };
+    let mut controllers = CONTROLLERS.write().unwrap();
+    // A detached chip has already been removed.
+    if let Some(controller) = controllers.remove(&(kind, facade_id)) {
+        warn!(
+            "external controller {} of chip {} disconnected: {error}, using the \
+             built-in facade",
+            controller.address, controller.chip_id
+        );
+    }
+}
+
+// Cxx Methods for the scene controller and packet_hub to invoke
+
+/// Connects an added chip to its external controller, if configured.
+pub fn attach_chip(device_name: &str, kind: u32, chip_id: u32, facade_id: u32) {
+    let Some(chip_kind) = ChipKind::from_i32(kind as i32) else {
+        return;
+    };
+    let Some(address) = controller_address(device_name, chip_kind) else {
+        return;
+    };
+    let stream = match connect(&address).and_then(|stream| Ok((stream.try_clone()?, stream))) {
+        Ok(streams) => streams,
+        Err(e) => {
+            warn!(
+                "unable to connect chip {chip_id} of {device_name} to the external \
+                 controller {address}: {e}, using the built-in facade"
+            );
+            return;
+        }
+    };
+    info!("chip {chip_id} of {device_name} is backed by the controller {address}");
+    let (reader, writer) = stream;
+    let controller = Arc::new(Controller { address, chip_id, writer: Mutex::new(writer) });
+    CONTROLLERS.write().unwrap().insert((kind, facade_id), controller);
+    thread::Builder::new()
+        .name(format!("external_controller_{chip_id}"))
+        .spawn(move || run_controller(reader, chip_kind, facade_id))
+        .unwrap();
+}
+
+/// Disconnects a removed chip from its external controller.
+pub fn detach_chip(chip_id: u32) {
+    let mut controllers = CONTROLLERS.write().unwrap();
+    let Some(key) = controllers.iter().find(|(_, c)| c.chip_id == chip_id).map(|(key, _)| *key)
+    else {
+        return;
+    };
+    if let Some(controller) = controllers.remove(&key) {
+        let _ = controller.writer.lock().unwrap().shutdown(Shutdown::Both);
+    }
+}
+
+/// Writes a packet to the external controller of its chip. Returns false
+/// for chips backed by a built-in facade.
+pub fn handle_request(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) -> bool {
+    let controllers = CONTROLLERS.read().unwrap();
+    let Some(controller) = controllers.get(&(kind, facade_id)) else {
+        return false;
+    };
+    let mut writer = controller.writer.lock().unwrap();
+    let result = match ChipKind::from_i32(kind as i32) {
+        Some(ChipKind::UWB | ChipKind::NFC) => writer.write_all(packet.as_slice()),
+        _ => writer.write_all(&[&[packet_type as u8], packet.as_slice()].concat()),
+    };
+    if let Err(e) = result {
+        warn!("error writing to the external controller {}: {e}", controller.address);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_controller() {
+        assert_eq!(
+            parse_controller("emulator-5554/bluetooth=localhost:6402"),
+            Some(("emulator-5554", ChipKind::BLUETOOTH, "localhost:6402"))
+        );
+        assert_eq!(
+            parse_controller(" a/b/UWB = [::1]:7000"),
+            Some(("a/b", ChipKind::UWB, "[::1]:7000"))
+        );
+        assert_eq!(parse_controller("emulator-5554/wifi=localhost:6402"), None);
+        assert_eq!(parse_controller("emulator-5554=localhost:6402"), None);
+        assert_eq!(parse_controller("emulator-5554/bluetooth"), None);
+    }
+}
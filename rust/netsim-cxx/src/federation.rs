@@ -0,0 +1,860 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Federation of netsimd instances.
+//!
+//! A leaf netsimd links to a hub netsimd over a TCP trunk, so the devices
+//! of several hosts share one scene. The hub adds a proxy chip for each
+//! chip of the leaf, in a proxy device named `<leaf>/<device>`, and
+//! simulates it with its own devices: the leaf passes the packets from its
+//! emulators to the proxy chips, and the hub passes the packets of the
+//! proxy chips back to the leaf emulators. Moves of a device on either side
+//! are applied to the other, so both place the device at the same position.
+//!
+//! The hub listens for trunks on the `federation.port` setting and only
+//! accepts leaves knowing its `federation.token`; it does not listen
+//! without a token. The token never crosses the trunk: each side proves it
+//! knows the token with an HMAC-SHA256 over the random nonce of its peer,
+//! so an eavesdropper can neither read nor replay it. The rest of the
+//! trunk is plain TCP: across untrusted networks, run it through a TLS
+//! tunnel to keep the packets private.
+//!
+//! Custom chip kinds are not federated, as their ids are local to each
+//! netsimd.
+//!
+//! /v1/federation/trunks --> handle_trunks (GET to list, POST a
+//! LinkInstanceRequest to link)
+//! /v1/federation/trunks/{id} --> handle_trunks (DELETE to unlink)
+
+use cxx::{let_cxx_string, CxxVector};
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::trunk::Role;
+use frontend_proto::frontend::trunk_message::{AddChip, Hello, Message as Payload, Packet};
+use frontend_proto::frontend::{
+    GetDevicesResponse, LinkInstanceRequest, ListTrunksResponse, PatchDeviceRequest, Trunk,
+    TrunkMessage,
+};
+use frontend_proto::model::{Device, Orientation, Position};
+use lazy_static::lazy_static;
+use netsim_common::util::os_utils::format_address;
+use netsim_common::util::sha256::hmac_sha256;
+use protobuf::{CodedInputStream, Message, MessageField};
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::io::BufReader;
+use std::net::{Shutdown, TcpListener, TcpStream, ToSocketAddrs};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::mpsc::{channel, Sender};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread::spawn;
+use std::time::Duration;
+
+use crate::auth::constant_time_eq;
+use crate::captures::capture::{ChipId, FacadeId};
+use crate::chip_registry::facade_id;
+use crate::clock::clock;
+use crate::config::{get_setting, get_setting_parsed};
+use crate::ffi::{
+    add_chip_cxx, forward_response_cxx, get_devices_bytes, handle_request_cxx, patch_device,
+    remove_chip, CxxServerResponseWriter,
+};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::{info, warn};
+use crate::version::get_version;
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+/// Interval between the checks for new chips and moved devices.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Time allowed for the exchange of hellos.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Bytes of the nonces of the handshake.
+const NONCE_LEN: usize = 32;
+
+/// Chip kinds federated through trunks.
+const FEDERATED_KINDS: [ChipKind; 3] = [ChipKind::BLUETOOTH, ChipKind::WIFI, ChipKind::UWB];
+
+/// A chip simulated through a trunk.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TrunkChip {
+    kind: u32,
+    facade_id: u32,
+    // Device and chip id of the proxy chip on the hub, of the chip on the leaf.
+    device_id: u32,
+    chip_id: u32,
+}
+
+/// The chips and device placements of a trunk.
+#[derive(Default)]
+struct TrunkState {
+    // Chips by the chip id of the leaf.
+    chips: HashMap<u32, TrunkChip>,
+    // Chip ids of the leaf by the kind and facade id of this netsimd.
+    facades: HashMap<(u32, u32), u32>,
+    // Names of the leaf devices by the proxy device names on the hub.
+    devices: HashMap<String, String>,
+    // Last position and orientation exchanged of each device, by its name
+    // in this netsimd.
+    placements: HashMap<String, (Position, Orientation)>,
+}
+
+impl TrunkState {
+    fn insert(&mut self, leaf_chip_id: u32, chip: TrunkChip) {
+        self.facades.insert((chip.kind, chip.facade_id), leaf_chip_id);
+        self.chips.insert(leaf_chip_id, chip);
+    }
+
+    fn remove(&mut self, leaf_chip_id: u32) -> Option<TrunkChip> {
+        let chip = self.chips.remove(&leaf_chip_id)?;
+        self.facades.remove(&(chip.kind, chip.facade_id));
+        Some(chip)
+    }
+
+    /// Returns the messages adding the chips of `devices` new to the trunk
+    /// and removing the chips gone, on the leaf. Chips in `excluded` are
+    /// not federated.
+    fn sync_chips(
+        &mut self,
+        devices: &[Device],
+        facade_id: impl Fn(ChipId) -> Option<FacadeId>,
+        excluded: &HashSet<(u32, u32)>,
+    ) -> Vec<TrunkMessage> {
+        let mut messages = Vec::new();
+        let mut present = HashSet::new();
+        for device in devices {
+            for chip in device.chips.iter() {
+                let kind = chip.kind.enum_value_or_default();
+                if !FEDERATED_KINDS.contains(&kind) {
+                    continue;
+                }
+                let chip_id = chip.id as u32;
+                present.insert(chip_id);
+                if self.chips.contains_key(&chip_id) {
+                    continue;
+                }
+                let Some(facade_id) = facade_id(chip.id) else {
+                    continue;
+                };
+                let facade = (kind as u32, facade_id as u32);
+                if excluded.contains(&facade) {
+                    continue;
+                }
+                let trunk_chip = TrunkChip {
+                    kind: facade.0,
+                    facade_id: facade.1,
+                    device_id: device.id as u32,
+                    chip_id,
+                };
+                self.insert(chip_id, trunk_chip);
+                messages.push(message(Payload::AddChip(AddChip {
+                    chip_id,
+                    device_name: device.name.clone(),
+                    kind: kind.into(),
+                    chip_name: chip.name.clone(),
+                    manufacturer: chip.manufacturer.clone(),
+                    product_name: chip.product_name.clone(),
+                    ..Default::default()
+                })));
+            }
+        }
+        let gone: Vec<u32> =
+            self.chips.keys().filter(|chip_id| !present.contains(chip_id)).copied().collect();
+        for chip_id in gone {
+            self.remove(chip_id);
+            messages.push(message(Payload::RemoveChip(chip_id)));
+        }
+        messages
+    }
+
+    /// Returns the devices moved since the last exchange, named for the
+    /// peer. `peer_name` returns the peer name of a device, or None for
+    /// devices not shared with the peer.
+    fn sync_moves(
+        &mut self,
+        devices: &[Device],
+        peer_name: impl Fn(&str) -> Option<String>,
+    ) -> Vec<Device> {
+        let mut moved = Vec::new();
+        for device in devices {
+            let Some(name) = peer_name(&device.name) else {
+                continue;
+            };
+            let placement = (
+                device.position.clone().unwrap_or_default(),
+                device.orientation.clone().unwrap_or_default(),
+            );
+            if self.placements.get(&device.name) == Some(&placement) {
+                continue;
+            }
+            moved.push(Device {
+                name,
+                position: MessageField::some(placement.0.clone()),
+                orientation: MessageField::some(placement.1.clone()),
+                ..Default::default()
+            });
+            self.placements.insert(device.name.clone(), placement);
+        }
+        moved
+    }
+}
+
+fn message(payload: Payload) -> TrunkMessage {
+    TrunkMessage { message: Some(payload), ..Default::default() }
+}
+
+/// A trunk to another netsimd.
+struct Connection {
+    id: u32,
+    address: String,
+    peer: String,
+    role: Role,
+    writer: Mutex<TcpStream>,
+    state: Mutex<TrunkState>,
+    packets_sent: AtomicU64,
+    packets_received: AtomicU64,
+    closed: AtomicBool,
+}
+
+impl Connection {
+    fn info(&self) -> Trunk {
+        Trunk {
+            id: self.id,
+            address: self.address.clone(),
+            peer: self.peer.clone(),
+            role: self.role.into(),
+            chips: self.state.lock().unwrap().chips.len() as u32,
+            packets_sent: self.packets_sent.load(Ordering::Relaxed),
+            packets_received: self.packets_received.load(Ordering::Relaxed),
+            ..Default::default()
+        }
+    }
+
+    fn send(&self, message: &TrunkMessage) {
+        let mut writer = self.writer.lock().unwrap();
+        if let Err(e) = message.write_length_delimited_to_writer(&mut *writer) {
+            warn!("trunk {} to {} failed: {e}", self.id, self.peer);
+            let _ = writer.shutdown(Shutdown::Both);
+        }
+    }
+
+    fn send_packet(&self, leaf_chip_id: u32, packet: &[u8], packet_type: u32) {
+        self.packets_sent.fetch_add(1, Ordering::Relaxed);
+        self.send(&message(Payload::Packet(Packet {
+            chip_id: leaf_chip_id,
+            packet_type,
+            packet: packet.to_vec(),
+            ..Default::default()
+        })));
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        let _ = self.writer.lock().unwrap().shutdown(Shutdown::Both);
+    }
+
+    // Name of the proxy device of a leaf device on the hub.
+    fn proxy_name(&self, device_name: &str) -> String {
+        format!("{}/{device_name}", self.peer)
+    }
+
+    fn receive(&self, message: TrunkMessage) {
+        match (self.role, message.message) {
+            (Role::HUB, Some(Payload::AddChip(chip))) => {
+                let proxy_name = self.proxy_name(&chip.device_name);
+                let_cxx_string!(guid = format!("trunk-{}-{}", self.id, chip.device_name));
+                let_cxx_string!(device_name = proxy_name.clone());
+                let_cxx_string!(chip_name = chip.chip_name.clone());
+                let_cxx_string!(manufacturer = chip.manufacturer.clone());
+                let_cxx_string!(product_name = chip.product_name.clone());
+                let kind = chip.kind.value() as u32;
+                let result = add_chip_cxx(
+                    &guid,
+                    &device_name,
+                    kind,
+                    &chip_name,
+                    &manufacturer,
+                    &product_name,
+                );
+                let mut state = self.state.lock().unwrap();
+                state.devices.insert(proxy_name, chip.device_name);
+                state.insert(
+                    chip.chip_id,
+                    TrunkChip {
+                        kind,
+                        facade_id: result.get_facade_id(),
+                        device_id: result.get_device_id(),
+                        chip_id: result.get_chip_id(),
+                    },
+                );
+            }
+            (Role::HUB, Some(Payload::RemoveChip(leaf_chip_id))) => {
+                let chip = self.state.lock().unwrap().remove(leaf_chip_id);
+                if let Some(chip) = chip {
+                    remove_chip(chip.device_id, chip.chip_id);
+                }
+            }
+            (role, Some(Payload::Packet(packet))) => {
+                self.packets_received.fetch_add(1, Ordering::Relaxed);
+                // The lock is released before the delivery, which may send
+                // packets through the trunk.
+                let chip = self.state.lock().unwrap().chips.get(&packet.chip_id).copied();
+                let Some(chip) = chip else {
+                    return;
+                };
+                let packet_type = packet.packet_type as u8;
+                match role {
+                    Role::HUB => {
+                        handle_request_cxx(chip.kind, chip.facade_id, &packet.packet, packet_type)
+                    }
+                    Role::LEAF => {
+                        forward_response_cxx(chip.kind, chip.facade_id, &packet.packet, packet_type)
+                    }
+                }
+            }
+            (role, Some(Payload::MoveDevice(mut device))) => {
+                if role == Role::HUB {
+                    device.name = self.proxy_name(&device.name);
+                }
+                let placement = (
+                    device.position.clone().unwrap_or_default(),
+                    device.orientation.clone().unwrap_or_default(),
+                );
+                self.state.lock().unwrap().placements.insert(device.name.clone(), placement);
+                if let Err(e) = patch(device) {
+                    warn!("trunk {} unable to move a device: {e}", self.id);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Sends the changes of the scene to the peer until the trunk closes.
+    fn poll(&self) {
+        while !self.closed.load(Ordering::Acquire) {
+            let devices = scene_devices();
+            let messages = match self.role {
+                Role::LEAF => {
+                    let excluded = FEDERATION.proxy_facades();
+                    let mut state = self.state.lock().unwrap();
+                    let mut messages = state.sync_chips(&devices, facade_id, &excluded);
+                    let shared: HashSet<u32> =
+                        state.chips.values().map(|chip| chip.device_id).collect();
+                    let moved = state.sync_moves(&devices, |name| {
+                        let device = devices.iter().find(|device| device.name == name)?;
+                        shared.contains(&(device.id as u32)).then(|| name.to_string())
+                    });
+                    messages.extend(
+                        moved.into_iter().map(|device| message(Payload::MoveDevice(device))),
+                    );
+                    messages
+                }
+                Role::HUB => {
+                    let mut state = self.state.lock().unwrap();
+                    let names = state.devices.clone();
+                    // Proxy devices are placed by the leaf first.
+                    let placed: HashSet<String> = state.placements.keys().cloned().collect();
+                    let moved = state.sync_moves(&devices, |name| {
+                        placed.contains(name).then(|| names.get(name).cloned()).flatten()
+                    });
+                    moved.into_iter().map(|device| message(Payload::MoveDevice(device))).collect()
+                }
+            };
+            for message in messages.iter() {
+                self.send(message);
+            }
+            clock().sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// The trunks of this netsimd.
+#[derive(Default)]
+struct Federation {
+    connections: RwLock<Vec<Arc<Connection>>>,
+    next_id: AtomicU32,
+    // Fast path of the packet hooks without trunks.
+    linked: AtomicBool,
+}
+
+impl Federation {
+    fn add(&self, connection: Arc<Connection>) {
+        let mut connections = self.connections.write().unwrap();
+        connections.push(connection);
+        self.linked.store(true, Ordering::Release);
+    }
+
+    fn remove(&self, id: u32) {
+        let mut connections = self.connections.write().unwrap();
+        connections.retain(|connection| connection.id != id);
+        self.linked.store(!connections.is_empty(), Ordering::Release);
+    }
+
+    fn get(&self, id: u32) -> Option<Arc<Connection>> {
+        self.connections.read().unwrap().iter().find(|connection| connection.id == id).cloned()
+    }
+
+    // Returns the trunk of a chip of this netsimd in `role`, and the chip
+    // id of the leaf.
+    fn find(&self, role: Role, kind: u32, facade_id: u32) -> Option<(Arc<Connection>, u32)> {
+        if !self.linked.load(Ordering::Acquire) {
+            return None;
+        }
+        self.connections.read().unwrap().iter().filter(|c| c.role == role).find_map(|connection| {
+            let chip_id = *connection.state.lock().unwrap().facades.get(&(kind, facade_id))?;
+            Some((connection.clone(), chip_id))
+        })
+    }
+
+    // Returns the kind and facade id of the proxy chips on this hub.
+    fn proxy_facades(&self) -> HashSet<(u32, u32)> {
+        let connections = self.connections.read().unwrap();
+        connections
+            .iter()
+            .filter(|connection| connection.role == Role::HUB)
+            .flat_map(|connection| {
+                connection.state.lock().unwrap().facades.keys().copied().collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn list(&self) -> ListTrunksResponse {
+        ListTrunksResponse {
+            trunks: self.connections.read().unwrap().iter().map(|c| c.info()).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+lazy_static! {
+    static ref FEDERATION: Federation = Federation::default();
+}
+
+fn scene_devices() -> Vec<Device> {
+    let mut vec = Vec::<u8>::new();
+    if !get_devices_bytes(&mut vec) {
+        return Vec::new();
+    }
+    GetDevicesResponse::parse_from_bytes(&vec).map(|r| r.devices).unwrap_or_default()
+}
+
+fn patch(device: Device) -> Result<(), String> {
+    let request = PatchDeviceRequest { device: MessageField::some(device), ..Default::default() };
+    let json = protobuf_json_mapping::print_to_string(&request).map_err(|e| e.to_string())?;
+    let_cxx_string!(request = json);
+    let_cxx_string!(response = "");
+    let_cxx_string!(error_message = "");
+    match patch_device(&request, response.as_mut(), error_message.as_mut()) {
+        200 => Ok(()),
+        _ => Err(error_message.to_string()),
+    }
+}
+
+// Returns the name of this netsimd for its peers.
+fn instance_name() -> String {
+    match get_setting("instance") {
+        instance if instance.is_empty() => std::env::var("HOSTNAME")
+            .ok()
+            .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "netsimd".to_string()),
+        instance => instance,
+    }
+}
+
+fn hello(nonce: Vec<u8>, proof: Vec<u8>) -> TrunkMessage {
+    message(Payload::Hello(Hello {
+        name: instance_name(),
+        version: get_version(),
+        nonce,
+        proof,
+        ..Default::default()
+    }))
+}
+
+// Returns the proof of the token by the side of `role`: the HMAC of its
+// role, the nonce of its peer and its own nonce. The role keeps a side
+// from replaying the proof of its peer.
+fn proof(token: &str, role: Role, peer_nonce: &[u8], nonce: &[u8]) -> Vec<u8> {
+    let role: &[u8] = match role {
+        Role::LEAF => b"leaf",
+        Role::HUB => b"hub",
+    };
+    hmac_sha256(token.as_bytes(), &[role, peer_nonce, nonce]).to_vec()
+}
+
+fn send_message(writer: &mut TcpStream, message: TrunkMessage) -> Result<(), String> {
+    message.write_length_delimited_to_writer(writer).map_err(|e| e.to_string())
+}
+
+fn read_hello(input: &mut CodedInputStream) -> Result<Hello, String> {
+    match input.read_message::<TrunkMessage>().map(|message| message.message) {
+        Ok(Some(Payload::Hello(hello))) if hello.nonce.len() <= NONCE_LEN => Ok(hello),
+        Ok(_) => Err("not a netsimd trunk".to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+// Exchanges the hellos of a trunk, each side proving it knows `token`.
+// Returns the name of the peer.
+fn handshake(
+    role: Role,
+    token: &str,
+    writer: &mut TcpStream,
+    input: &mut CodedInputStream,
+) -> Result<String, String> {
+    let nonce: Vec<u8> = rand::thread_rng().gen::<[u8; NONCE_LEN]>().to_vec();
+    let invalid = || "invalid federation token".to_string();
+    match role {
+        Role::LEAF => {
+            send_message(writer, hello(nonce.clone(), Vec::new()))?;
+            let hub = read_hello(input)?;
+            if hub.nonce.len() != NONCE_LEN
+                || !constant_time_eq(&hub.proof, &proof(token, Role::HUB, &nonce, &hub.nonce))
+            {
+                return Err(invalid());
+            }
+            send_message(writer, hello(Vec::new(), proof(token, Role::LEAF, &hub.nonce, &nonce)))?;
+            Ok(hub.name)
+        }
+        Role::HUB => {
+            let leaf = read_hello(input)?;
+            if leaf.nonce.len() != NONCE_LEN {
+                return Err(invalid());
+            }
+            let hub_proof = proof(token, Role::HUB, &leaf.nonce, &nonce);
+            send_message(writer, hello(nonce.clone(), hub_proof))?;
+            let answer = read_hello(input)?;
+            match constant_time_eq(&answer.proof, &proof(token, Role::LEAF, &nonce, &leaf.nonce)) {
+                true => Ok(leaf.name),
+                false => Err(invalid()),
+            }
+        }
+    }
+}
+
+// Runs a trunk connection: exchanges the hellos, then passes the messages
+// of the peer to the trunk until the connection closes. The outcome of the
+// hellos is sent to `linked`.
+fn run_trunk(
+    stream: TcpStream,
+    role: Role,
+    address: String,
+    linked: Sender<Result<Trunk, String>>,
+) {
+    let mut writer = match stream
+        .try_clone()
+        .and_then(|writer| writer.set_read_timeout(Some(HANDSHAKE_TIMEOUT)).map(|()| writer))
+    {
+        Ok(writer) => writer,
+        Err(e) => {
+            let _ = linked.send(Err(e.to_string()));
+            return;
+        }
+    };
+    let mut reader = BufReader::new(stream);
+    let mut input = CodedInputStream::from_buf_read(&mut reader);
+    let token = get_setting("federation.token");
+    let peer = match handshake(role, &token, &mut writer, &mut input) {
+        Ok(peer) => peer,
+        Err(e) => {
+            let _ = linked.send(Err(format!("{address}: {e}")));
+            return;
+        }
+    };
+    let _ = writer.set_read_timeout(None);
+    let connection = Arc::new(Connection {
+        id: FEDERATION.next_id.fetch_add(1, Ordering::Relaxed) + 1,
+        address,
+        peer,
+        role,
+        writer: Mutex::new(writer),
+        state: Mutex::new(TrunkState::default()),
+        packets_sent: AtomicU64::new(0),
+        packets_received: AtomicU64::new(0),
+        closed: AtomicBool::new(false),
+    });
+    FEDERATION.add(connection.clone());
+    info!(
+        "trunk {} {} {} linked",
+        connection.id,
+        if role == Role::HUB { "from" } else { "to" },
+        connection.peer
+    );
+    let _ = linked.send(Ok(connection.info()));
+    let poller = connection.clone();
+    spawn(move || poller.poll());
+    while let Ok(message) = input.read_message::<TrunkMessage>() {
+        connection.receive(message);
+    }
+    connection.close();
+    FEDERATION.remove(connection.id);
+    if role == Role::HUB {
+        let chips: Vec<TrunkChip> =
+            connection.state.lock().unwrap().chips.values().copied().collect();
+        for chip in chips {
+            remove_chip(chip.device_id, chip.chip_id);
+        }
+    }
+    info!("trunk {} to {} closed", connection.id, connection.peer);
+}
+
+/// Links this netsimd as a leaf to the hub at `address`.
+pub fn link(address: &str) -> Result<Trunk, String> {
+    let socket_address = address
+        .to_socket_addrs()
+        .map_err(|e| format!("{address}: {e}"))?
+        .next()
+        .ok_or(format!("{address}: no address"))?;
+    let stream = TcpStream::connect_timeout(&socket_address, HANDSHAKE_TIMEOUT)
+        .map_err(|e| format!("{address}: {e}"))?;
+    let (sender, receiver) = channel();
+    let address = address.to_string();
+    spawn(move || run_trunk(stream, Role::LEAF, address, sender));
+    receiver.recv().map_err(|e| e.to_string())?
+}
+
+/// Accepts the trunks of leaves on the federation.port setting, if set.
+pub fn run_federation() {
+    let port: u16 = get_setting_parsed("federation.port");
+    if port == 0 {
+        return;
+    }
+    // Without a token any peer could link and inject chips in the scene.
+    if get_setting("federation.token").is_empty() {
+        warn!("federation hub disabled: federation.token is not set");
+        return;
+    }
+    let host = get_setting("federation.host");
+    let listener = match TcpListener::bind((host.trim_matches(['[', ']']), port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            warn!("unable to listen for trunks on port {port}: {e}");
+            return;
+        }
+    };
+    info!("Federation hub is listening on {}", format_address(&host, port));
+    for stream in listener.incoming().flatten() {
+        let address = stream.peer_addr().map(|addr| addr.to_string()).unwrap_or_default();
+        let (sender, receiver) = channel();
+        spawn(move || run_trunk(stream, Role::HUB, address, sender));
+        spawn(move || {
+            if let Ok(Err(e)) = receiver.recv() {
+                warn!("trunk rejected: {e}");
+            }
+        });
+    }
+}
+
+fn put_json<M: protobuf::MessageFull>(writer: ResponseWritable, message: &M) {
+    if let Ok(json_response) = print_to_string_with_options(message, &JSON_PRINT_OPTION) {
+        writer.put_ok("text/json", &json_response, &[])
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure")
+    }
+}
+
+/// The Rust trunks handler used directly by Http frontend for GET, POST
+/// and DELETE
+pub fn handle_trunks(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    match request.method.as_str() {
+        "GET" => put_json(writer, &FEDERATION.list()),
+        "POST" => {
+            let body = String::from_utf8_lossy(&request.body);
+            let link_request =
+                match protobuf_json_mapping::parse_from_str::<LinkInstanceRequest>(&body) {
+                    Ok(link_request) => link_request,
+                    Err(e) => {
+                        writer.put_error(400, format!("Invalid link request: {e}").as_str());
+                        return;
+                    }
+                };
+            match link(&link_request.address) {
+                Ok(trunk) => put_json(writer, &trunk),
+                Err(e) => writer.put_error(400, format!("Unable to link: {e}").as_str()),
+            }
+        }
+        "DELETE" => match param.parse::<u32>().ok().and_then(|id| FEDERATION.get(id)) {
+            Some(connection) => {
+                connection.close();
+                writer.put_ok("text/plain", "", &[])
+            }
+            None => writer.put_error(404, format!("Trunk {param} not found").as_str()),
+        },
+        _ => writer.put_error(404, "Not found."),
+    }
+}
+
+/// trunks handle cxx for grpc server to call
+pub fn handle_trunks_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: match param.is_empty() {
+            true => "/v1/federation/trunks".to_string(),
+            false => format!("/v1/federation/trunks/{param}"),
+        },
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_trunks(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+// Cxx Methods for packet_hub to invoke
+
+/// Passes a packet from an emulator of a leaf to the hub. Returns true when
+/// the chip of the packet is simulated by a hub.
+pub fn trunk_request(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) -> bool {
+    match FEDERATION.find(Role::LEAF, kind, facade_id) {
+        Some((connection, leaf_chip_id)) => {
+            connection.send_packet(leaf_chip_id, packet.as_slice(), packet_type);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Passes a packet of a proxy chip of the hub to the leaf.
+pub fn trunk_response(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) {
+    if let Some((connection, leaf_chip_id)) = FEDERATION.find(Role::HUB, kind, facade_id) {
+        connection.send_packet(leaf_chip_id, packet.as_slice(), packet_type);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::model::Chip;
+
+    fn device(id: i32, name: &str, chip_ids: &[i32], x: f32) -> Device {
+        Device {
+            id,
+            name: name.to_string(),
+            position: MessageField::some(Position { x, ..Default::default() }),
+            chips: chip_ids
+                .iter()
+                .map(|&id| Chip { id, kind: ChipKind::BLUETOOTH.into(), ..Default::default() })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn payloads(messages: Vec<TrunkMessage>) -> Vec<Payload> {
+        messages.into_iter().filter_map(|message| message.message).collect()
+    }
+
+    #[test]
+    fn test_sync_chips() {
+        let mut state = TrunkState::default();
+        let facade_id = |chip_id| Some(chip_id + 100);
+        let devices = [device(1, "phone", &[1, 2], 0.0), device(2, "watch", &[3], 0.0)];
+        let excluded = HashSet::from([(ChipKind::BLUETOOTH as u32, 103)]);
+        let added = payloads(state.sync_chips(&devices, facade_id, &excluded));
+        assert_eq!(added.len(), 2);
+        assert!(
+            matches!(&added[0], Payload::AddChip(chip) if chip.chip_id == 1 && chip.device_name == "phone")
+        );
+        assert_eq!(state.facades.get(&(ChipKind::BLUETOOTH as u32, 102)), Some(&2));
+        assert!(state.sync_chips(&devices, facade_id, &excluded).is_empty());
+        let devices = [device(1, "phone", &[2], 0.0)];
+        assert_eq!(
+            payloads(state.sync_chips(&devices, facade_id, &excluded)),
+            [Payload::RemoveChip(1)]
+        );
+        assert!(!state.facades.contains_key(&(ChipKind::BLUETOOTH as u32, 101)));
+    }
+
+    #[test]
+    fn test_sync_moves() {
+        let mut state = TrunkState::default();
+        let peer_name = |name: &str| (name != "local").then(|| format!("leaf/{name}"));
+        let devices = [device(1, "phone", &[], 1.0), device(2, "local", &[], 0.0)];
+        let moved = state.sync_moves(&devices, peer_name);
+        assert_eq!(moved.len(), 1);
+        assert_eq!(moved[0].name, "leaf/phone");
+        assert_eq!(moved[0].position.x, 1.0);
+        assert!(state.sync_moves(&devices, peer_name).is_empty());
+        // A move received from the peer is not sent back.
+        state.placements.insert(
+            "phone".to_string(),
+            (Position { x: 2.0, ..Default::default() }, Orientation::default()),
+        );
+        assert!(state.sync_moves(&[device(1, "phone", &[], 2.0)], peer_name).is_empty());
+        assert_eq!(state.sync_moves(&[device(1, "phone", &[], 3.0)], peer_name).len(), 1);
+    }
+
+    // Runs the handshake of a hub and a leaf with their tokens over a
+    // loopback connection.
+    fn handshake_with(
+        hub_token: &str,
+        leaf_token: &str,
+    ) -> (Result<String, String>, Result<String, String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let run = |role, token: &str, stream: TcpStream| {
+            stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT)).unwrap();
+            let mut writer = stream.try_clone().unwrap();
+            let mut reader = BufReader::new(stream);
+            let result = handshake(
+                role,
+                token,
+                &mut writer,
+                &mut CodedInputStream::from_buf_read(&mut reader),
+            );
+            // Unblocks the peer waiting for a rejected proof.
+            let _ = writer.shutdown(Shutdown::Both);
+            result
+        };
+        std::thread::scope(|scope| {
+            let hub = scope.spawn(|| run(Role::HUB, hub_token, listener.accept().unwrap().0));
+            let leaf = run(Role::LEAF, leaf_token, TcpStream::connect(address).unwrap());
+            (hub.join().unwrap(), leaf)
+        })
+    }
+
+    #[test]
+    fn test_handshake() {
+        let name = instance_name();
+        assert_eq!(handshake_with("secret", "secret"), (Ok(name.clone()), Ok(name)));
+        let (hub, leaf) = handshake_with("secret", "guess");
+        assert!(hub.is_err());
+        assert_eq!(leaf, Err("invalid federation token".to_string()));
+    }
+
+    #[test]
+    fn test_proof() {
+        let (leaf_nonce, hub_nonce) = ([1; NONCE_LEN], [2; NONCE_LEN]);
+        let hub = proof("secret", Role::HUB, &leaf_nonce, &hub_nonce);
+        assert_ne!(hub, proof("secret", Role::LEAF, &leaf_nonce, &hub_nonce));
+        assert_ne!(hub, proof("other", Role::HUB, &leaf_nonce, &hub_nonce));
+    }
+}
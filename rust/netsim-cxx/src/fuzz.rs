@@ -0,0 +1,307 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fuzzing entry points for the netsimd parsers.
+//!
+//! The `fuzz_*` functions are the targets of the fuzzers in
+//! rust/netsim-cxx/fuzz. netsimd runs the same functions for the
+//! ReplayFuzzInput debug RPC used by `netsim fuzz-replay`, passing parsed
+//! packets through the packet processors like guest traffic.
+//!
+//! /v1/debug/fuzz --> handle_fuzz_replay (POST a ReplayFuzzInputRequest)
+
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::replay_fuzz_input_request::Target;
+use frontend_proto::frontend::{ReplayFuzzInputRequest, ReplayFuzzInputResponse};
+use frontend_proto::model::capture::Filter;
+use protobuf::Message;
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::io::{BufReader, Cursor};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+
+use crate::captures::filter::{matches, validate};
+use crate::captures::pcap_util::{read_pcap_header, read_record, PacketDirection};
+use crate::ffi::CxxServerResponseWriter;
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::warn;
+use crate::processor::{process, PacketContext};
+use crate::transport::{h4, uci};
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+// Facade id of replayed packets, not used by any chip.
+const REPLAY_FACADE_ID: u32 = u32::MAX;
+
+// Returns true once the reader consumed all the input.
+fn at_end(cursor: &Cursor<&[u8]>) -> bool {
+    cursor.position() as usize >= cursor.get_ref().len()
+}
+
+/// Parses a stream of H4 packets, calling `sink` with the type and payload
+/// of each packet. Returns the number of packets.
+fn parse_h4(data: &[u8], mut sink: impl FnMut(u8, &[u8])) -> Result<usize, String> {
+    let mut cursor = Cursor::new(data);
+    let mut count = 0;
+    while !at_end(&cursor) {
+        let packet = h4::read_h4_packet(&mut cursor).map_err(|e| format!("{e:?}"))?;
+        sink(packet.h4_type, &packet.payload);
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Parses a stream of UCI packets, calling `sink` with each packet.
+/// Returns the number of packets.
+fn parse_uci(data: &[u8], mut sink: impl FnMut(&[u8])) -> Result<usize, String> {
+    let mut cursor = Cursor::new(data);
+    let mut count = 0;
+    while !at_end(&cursor) {
+        let packet = uci::read_uci_packet(&mut cursor).map_err(|e| format!("{e:?}"))?;
+        sink(&packet.payload);
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Parses a pcap file. Returns the number of records.
+fn parse_pcap(data: &[u8]) -> Result<usize, String> {
+    let mut input = data;
+    let header = read_pcap_header(&mut input).map_err(|e| e.to_string())?;
+    let mut count = 0;
+    while read_record(&mut input, &header).map_err(|e| e.to_string())?.is_some() {
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Parses an HTTP request.
+fn parse_http_request(data: &[u8]) -> Result<HttpRequest, String> {
+    HttpRequest::parse(&mut BufReader::new(data))
+}
+
+/// Parses a capture Filter and matches a packet with it: the big endian
+/// length of the serialized Filter and the Filter, then the direction, the
+/// packet type and the packet. Returns true when the packet matches.
+fn parse_filter(data: &[u8]) -> Result<bool, String> {
+    let (length, rest) = data.split_first_chunk::<2>().ok_or("missing filter length")?;
+    let length = u16::from_be_bytes(*length) as usize;
+    let (filter, rest) = rest.split_at_checked(length).ok_or("truncated filter")?;
+    let filter = Filter::parse_from_bytes(filter).map_err(|e| e.to_string())?;
+    validate(&filter)?;
+    let [direction, packet_type, packet @ ..] = rest else {
+        return Err("missing packet".to_string());
+    };
+    let direction = match direction & 1 {
+        0 => PacketDirection::HostToController,
+        _ => PacketDirection::ControllerToHost,
+    };
+    Ok(matches(&filter, direction, (*packet_type).into(), packet))
+}
+
+pub fn fuzz_h4(data: &[u8]) {
+    let _ = parse_h4(data, |_, _| {});
+}
+
+pub fn fuzz_uci(data: &[u8]) {
+    let _ = parse_uci(data, |_| {});
+}
+
+pub fn fuzz_pcap(data: &[u8]) {
+    let _ = parse_pcap(data);
+}
+
+pub fn fuzz_http_request(data: &[u8]) {
+    let _ = parse_http_request(data);
+}
+
+pub fn fuzz_filter(data: &[u8]) {
+    let _ = parse_filter(data);
+}
+
+fn context(chip_kind: ChipKind, packet_type: u32) -> PacketContext {
+    PacketContext {
+        chip_kind,
+        facade_id: REPLAY_FACADE_ID,
+        packet_type,
+        direction: PacketDirection::HostToController,
+    }
+}
+
+// Runs an input through a parser, and the parsed packets through the
+// packet processors.
+fn replay_input(target: Target, input: &[u8]) -> Result<(), String> {
+    match target {
+        Target::H4 => parse_h4(input, |h4_type, payload| {
+            process(&context(ChipKind::BLUETOOTH, h4_type.into()), payload);
+        })
+        .map(|_| ()),
+        Target::UCI => parse_uci(input, |payload| {
+            process(&context(ChipKind::UWB, 0), payload);
+        })
+        .map(|_| ()),
+        Target::PCAP => parse_pcap(input).map(|_| ()),
+        Target::HTTP_REQUEST => parse_http_request(input).map(|_| ()),
+        Target::FILTER => parse_filter(input).map(|_| ()),
+    }
+}
+
+/// Replays a fuzzer input, reporting parser panics instead of crashing.
+pub fn replay(request: &ReplayFuzzInputRequest) -> ReplayFuzzInputResponse {
+    let mut response = ReplayFuzzInputResponse { name: request.name.clone(), ..Default::default() };
+    let Ok(target) = request.target.enum_value() else {
+        response.error = "unknown fuzz target".to_string();
+        return response;
+    };
+    match catch_unwind(AssertUnwindSafe(|| replay_input(target, &request.input))) {
+        Ok(Ok(())) => response.accepted = true,
+        Ok(Err(e)) => response.error = e,
+        Err(panic) => {
+            response.crashed = true;
+            response.error = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "panic".to_string());
+            warn!("fuzz input {} crashed the {:?} parser", request.name, target);
+        }
+    }
+    response
+}
+
+/// The Rust fuzz replay handler used directly by Http frontend for POST
+pub(crate) fn handle_fuzz_replay(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "POST" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let body = String::from_utf8_lossy(&request.body);
+    let fuzz_request = match protobuf_json_mapping::parse_from_str::<ReplayFuzzInputRequest>(&body)
+    {
+        Ok(fuzz_request) => fuzz_request,
+        Err(e) => {
+            writer.put_error(400, format!("Invalid fuzz input: {e}").as_str());
+            return;
+        }
+    };
+    let response = replay(&fuzz_request);
+    if let Ok(json_response) = print_to_string_with_options(&response, &JSON_PRINT_OPTION) {
+        writer.put_ok("text/json", &json_response, &[])
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure")
+    }
+}
+
+/// fuzz replay handle cxx for grpc server to call
+pub(crate) fn handle_fuzz_replay_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/debug/fuzz".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_fuzz_replay(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(target: Target, input: &[u8]) -> ReplayFuzzInputRequest {
+        ReplayFuzzInputRequest {
+            target: target.into(),
+            name: "input".to_string(),
+            input: input.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_h4() {
+        let mut types = Vec::new();
+        let data = [1, 0x03, 0x0c, 0, 4, 0x0e, 1, 0xff];
+        assert_eq!(parse_h4(&data, |h4_type, _| types.push(h4_type)), Ok(2));
+        assert_eq!(types, [1, 4]);
+        assert!(parse_h4(&[9, 0, 0], |_, _| {}).is_err());
+        assert!(parse_h4(&[1, 0x03, 0x0c, 5, 0], |_, _| {}).is_err());
+    }
+
+    #[test]
+    fn test_parse_uci() {
+        assert_eq!(parse_uci(&[0x20, 0x02, 0, 1, 0xaa], |_| {}), Ok(1));
+        assert!(parse_uci(&[0x20, 0x02, 0], |_| {}).is_err());
+    }
+
+    #[test]
+    fn test_parse_filter() {
+        let filter = Filter { packet_types: vec![4], ..Default::default() };
+        let filter = filter.write_to_bytes().unwrap();
+        let mut input = (filter.len() as u16).to_be_bytes().to_vec();
+        input.extend(&filter);
+        assert!(parse_filter(&input).is_err());
+        input.extend([1, 4, 0x0e, 0x04]);
+        assert_eq!(parse_filter(&input), Ok(true));
+        let last = input.len() - 3;
+        input[last] = 1;
+        assert_eq!(parse_filter(&input), Ok(false));
+        assert!(parse_filter(&[0, 9, 0x0a]).is_err());
+    }
+
+    #[test]
+    fn test_replay() {
+        let response = replay(&request(Target::H4, &[4, 0x0e, 0]));
+        assert_eq!(response.name, "input");
+        assert!(response.accepted);
+        let response = replay(&request(Target::PCAP, &[0; 8]));
+        assert!(!response.accepted && !response.crashed);
+        assert!(!response.error.is_empty());
+        let response = replay(&request(Target::HTTP_REQUEST, b"GET / HTTP/1.1\r\n\r\n"));
+        assert!(response.accepted);
+    }
+
+    #[test]
+    fn test_fuzz_targets_survive_garbage() {
+        let mut seed: u32 = 1;
+        for len in 0..256 {
+            let data: Vec<u8> = (0..len)
+                .map(|_| {
+                    seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    (seed >> 16) as u8
+                })
+                .collect();
+            fuzz_h4(&data);
+            fuzz_uci(&data);
+            fuzz_pcap(&data);
+            fuzz_http_request(&data);
+            fuzz_filter(&data);
+        }
+    }
+}
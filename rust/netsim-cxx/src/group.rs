@@ -0,0 +1,287 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Device groups.
+//!
+//! A device belongs to the group named by the group of its Device, set by a
+//! PatchDevice or by the devices joining and leaving a group with a
+//! PatchGroup. A PatchGroup patches every device of the group with one
+//! request: the chips, e.g. their radio states, the position, the same for
+//! all the devices or an offset moving the group with its layout, and the
+//! captures of the chips of the devices.
+//!
+//! /v1/groups --> handle_group (PATCH a PatchGroupRequest)
+
+use frontend_proto::frontend::{
+    GetDevicesResponse, PatchDeviceRequest, PatchGroupRequest, PatchGroupResponse,
+};
+use frontend_proto::model::{Device, Position, State};
+use protobuf::{Message, MessageField};
+use std::pin::Pin;
+
+use crate::captures::capture::ChipId;
+use crate::captures::handlers::set_group_captures;
+use crate::ffi::{get_devices_bytes, patch_device, CxxServerResponseWriter};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::CxxServerResponseWriterWrapper;
+
+/// The patches of a PatchGroup.
+#[derive(Debug, Default, PartialEq)]
+pub struct GroupPatch {
+    /// The patches of the devices, of the group and of the devices leaving.
+    pub devices: Vec<Device>,
+    /// The names of the devices of the group once patched.
+    pub device_names: Vec<String>,
+    /// The chips of the devices of the group.
+    pub chip_ids: Vec<ChipId>,
+}
+
+fn group_of(device: &Device) -> &str {
+    device.group.as_deref().unwrap_or_default()
+}
+
+fn translate(position: &Position, offset: &Position) -> Position {
+    Position {
+        x: position.x + offset.x,
+        y: position.y + offset.y,
+        z: position.z + offset.z,
+        ..Default::default()
+    }
+}
+
+impl GroupPatch {
+    /// Plans the patches of a request for the devices of the scene. Fails
+    /// when a device joining or leaving the group is not found, or when the
+    /// group has no devices.
+    pub fn new(devices: &[Device], request: &PatchGroupRequest) -> Result<Self, String> {
+        let group = request.group.as_str();
+        for name in request.join_device_names.iter().chain(&request.leave_device_names) {
+            if !devices.iter().any(|device| &device.name == name) {
+                return Err(format!("Device {name} not found"));
+            }
+        }
+        let joins = |device: &Device| request.join_device_names.contains(&device.name);
+        let leaves = |device: &Device| {
+            request.leave_device_names.contains(&device.name) && group_of(device) == group
+        };
+        let mut patch = GroupPatch::default();
+        for device in devices {
+            // Each patch keeps the visibility of its device, which a
+            // PatchDevice sets.
+            let mut device_patch =
+                Device { name: device.name.clone(), visible: device.visible, ..Default::default() };
+            if leaves(device) && !joins(device) {
+                device_patch.group = Some(String::new());
+                patch.devices.push(device_patch);
+                continue;
+            }
+            if !joins(device) && group_of(device) != group {
+                continue;
+            }
+            if let Some(template) = request.device.as_ref() {
+                device_patch.chips = template.chips.clone();
+                device_patch.orientation = template.orientation.clone();
+                device_patch.position = match (request.relative, template.position.as_ref()) {
+                    (true, Some(offset)) => MessageField::some(translate(
+                        device.position.as_ref().unwrap_or_default(),
+                        offset,
+                    )),
+                    _ => template.position.clone(),
+                };
+            }
+            if group_of(device) != group {
+                device_patch.group = Some(group.to_string());
+            }
+            patch.device_names.push(device.name.clone());
+            patch.chip_ids.extend(device.chips.iter().map(|chip| chip.id));
+            patch.devices.push(device_patch);
+        }
+        let changes_members =
+            !(request.join_device_names.is_empty() && request.leave_device_names.is_empty());
+        if patch.device_names.is_empty() && !changes_members {
+            return Err(format!("Group {group} has no devices"));
+        }
+        Ok(patch)
+    }
+}
+
+/// Returns the devices of the scene.
+pub fn scene_devices() -> Vec<Device> {
+    let mut vec = Vec::<u8>::new();
+    if !get_devices_bytes(&mut vec) {
+        return Vec::new();
+    }
+    GetDevicesResponse::parse_from_bytes(&vec).map(|r| r.devices).unwrap_or_default()
+}
+
+/// Patches a device of the scene.
+pub fn patch(device: Device) -> Result<(), String> {
+    let request = PatchDeviceRequest { device: MessageField::some(device), ..Default::default() };
+    let json = protobuf_json_mapping::print_to_string(&request).map_err(|e| e.to_string())?;
+    cxx::let_cxx_string!(request = json);
+    cxx::let_cxx_string!(response = "");
+    cxx::let_cxx_string!(error_message = "");
+    match patch_device(&request, response.as_mut(), error_message.as_mut()) {
+        200 => Ok(()),
+        _ => Err(error_message.to_string()),
+    }
+}
+
+/// The Rust group handler used directly by Http frontend for PATCH
+pub fn handle_group(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "PATCH" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let body = String::from_utf8_lossy(&request.body);
+    let group_request = match protobuf_json_mapping::parse_from_str::<PatchGroupRequest>(&body) {
+        Ok(group_request) => group_request,
+        Err(e) => {
+            writer.put_error(400, format!("Invalid group request: {e}").as_str());
+            return;
+        }
+    };
+    if group_request.group.is_empty() {
+        writer.put_error(400, "Incorrect group, expected a group name");
+        return;
+    }
+    let group_patch = match GroupPatch::new(&scene_devices(), &group_request) {
+        Ok(group_patch) => group_patch,
+        Err(e) => {
+            writer.put_error(404, e.as_str());
+            return;
+        }
+    };
+    for device in group_patch.devices {
+        if let Err(e) = patch(device) {
+            writer.put_error(404, e.as_str());
+            return;
+        }
+    }
+    let capture = group_request.capture.enum_value_or_default();
+    if capture != State::UNKNOWN {
+        if let Err(e) = set_group_captures(&group_patch.chip_ids, capture == State::ON) {
+            writer.put_error(500, e.as_str());
+            return;
+        }
+    }
+    let response =
+        PatchGroupResponse { device_names: group_patch.device_names, ..Default::default() };
+    match protobuf_json_mapping::print_to_string(&response) {
+        Ok(json) => writer.put_ok("text/json", &json, &[]),
+        Err(e) => writer.put_error(500, e.to_string().as_str()),
+    }
+}
+
+/// group handle cxx for grpc server to call
+pub fn handle_group_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/groups".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_group(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::common::ChipKind;
+    use frontend_proto::model::Chip;
+
+    fn device(name: &str, group: &str, x: f32, chip_id: i32) -> Device {
+        Device {
+            name: name.to_string(),
+            visible: true,
+            group: (!group.is_empty()).then(|| group.to_string()),
+            position: MessageField::some(Position { x, ..Default::default() }),
+            chips: vec![Chip { id: chip_id, ..Default::default() }],
+            ..Default::default()
+        }
+    }
+
+    fn scene() -> Vec<Device> {
+        vec![device("a", "room", 1.0, 1), device("b", "room", 2.0, 2), device("c", "", 3.0, 3)]
+    }
+
+    #[test]
+    fn test_patch_group() {
+        let chip = Chip { kind: ChipKind::BLUETOOTH.into(), ..Default::default() };
+        let request = PatchGroupRequest {
+            group: "room".to_string(),
+            device: MessageField::some(Device {
+                chips: vec![chip.clone()],
+                position: MessageField::some(Position { x: 10.0, ..Default::default() }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let patch = GroupPatch::new(&scene(), &request).unwrap();
+        assert_eq!(patch.device_names, ["a", "b"]);
+        assert_eq!(patch.chip_ids, [1, 2]);
+        for device in &patch.devices {
+            assert!(device.visible);
+            assert_eq!(device.chips, vec![chip.clone()]);
+            assert_eq!(device.position.x, 10.0);
+            assert_eq!(device.group, None);
+        }
+        // Relative positions move the group with its layout.
+        let request = PatchGroupRequest { relative: true, ..request };
+        let patch = GroupPatch::new(&scene(), &request).unwrap();
+        let xs: Vec<f32> = patch.devices.iter().map(|device| device.position.x).collect();
+        assert_eq!(xs, [11.0, 12.0]);
+    }
+
+    #[test]
+    fn test_join_and_leave() {
+        let request = PatchGroupRequest {
+            group: "room".to_string(),
+            join_device_names: vec!["c".to_string()],
+            leave_device_names: vec!["a".to_string()],
+            ..Default::default()
+        };
+        let patch = GroupPatch::new(&scene(), &request).unwrap();
+        assert_eq!(patch.device_names, ["b", "c"]);
+        let groups: Vec<_> = patch.devices.iter().map(|d| (d.name.as_str(), group_of(d))).collect();
+        assert_eq!(groups, [("a", ""), ("b", ""), ("c", "room")]);
+        assert_eq!(patch.devices[1].group, None);
+        // Leaving another group keeps the device in its group.
+        let request = PatchGroupRequest {
+            group: "hall".to_string(),
+            leave_device_names: vec!["a".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(GroupPatch::new(&scene(), &request).unwrap(), GroupPatch::default());
+        let request = PatchGroupRequest {
+            group: "room".to_string(),
+            join_device_names: vec!["d".to_string()],
+            ..Default::default()
+        };
+        assert!(GroupPatch::new(&scene(), &request).is_err());
+        let request = PatchGroupRequest { group: "hall".to_string(), ..Default::default() };
+        assert!(GroupPatch::new(&scene(), &request).is_err());
+    }
+}
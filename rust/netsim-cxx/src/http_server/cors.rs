@@ -0,0 +1,112 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cross-origin requests to the http frontend.
+//!
+//! A web UI served from another origin than netsimd, like a development
+//! server, calls the REST APIs cross-origin. The `http.cors_origins`
+//! setting lists the origins allowed to, as comma separated entries like
+//! `http://localhost:4200`, or `*` for any origin. The responses to the
+//! requests of an allowed origin carry the CORS headers, and the preflight
+//! requests of the browsers are answered without a token, which the
+//! browsers don't send with them.
+
+use super::http_request::HttpRequest;
+
+const ALLOWED_METHODS: &str = "GET, POST, PUT, PATCH, DELETE, OPTIONS";
+const ALLOWED_HEADERS: &str = "Authorization, Content-Type, Range";
+const EXPOSED_HEADERS: &str = "Content-Length, Content-Range, X-Netsim-Sha256";
+// Seconds the browsers cache the result of a preflight request.
+const MAX_AGE_SECS: u32 = 600;
+
+/// Whether the comma separated `origins` allow the origin of a request.
+pub fn is_allowed_origin(origins: &str, origin: &str) -> bool {
+    !origin.is_empty()
+        && origins.split(',').map(str::trim).any(|allowed| allowed == "*" || allowed == origin)
+}
+
+/// Whether a request is the preflight of a cross-origin request.
+pub fn is_preflight(request: &HttpRequest) -> bool {
+    request.method == "OPTIONS"
+        && request.headers.get("Origin").is_some()
+        && request.headers.get("Access-Control-Request-Method").is_some()
+}
+
+/// Returns the CORS headers of the response to a request, none for a
+/// request without an allowed origin.
+pub fn cors_headers(origins: &str, request: &HttpRequest) -> Vec<(&'static str, String)> {
+    let origin = request.headers.get("Origin").unwrap_or_default();
+    if !is_allowed_origin(origins, &origin) {
+        return Vec::new();
+    }
+    let mut headers = vec![("Access-Control-Allow-Origin", origin), ("Vary", "Origin".to_string())];
+    if is_preflight(request) {
+        headers.extend([
+            ("Access-Control-Allow-Methods", ALLOWED_METHODS.to_string()),
+            ("Access-Control-Allow-Headers", ALLOWED_HEADERS.to_string()),
+            ("Access-Control-Max-Age", MAX_AGE_SECS.to_string()),
+        ]);
+    } else {
+        headers.push(("Access-Control-Expose-Headers", EXPOSED_HEADERS.to_string()));
+    }
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http_server::http_request::HttpHeaders;
+
+    fn request(method: &str, headers: &[(&str, &str)]) -> HttpRequest {
+        HttpRequest {
+            method: method.to_string(),
+            uri: "/v1/devices".to_string(),
+            headers: HttpHeaders::new_with_headers(headers),
+            version: "1.1".to_string(),
+            body: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_is_allowed_origin() {
+        let origins = "http://localhost:4200, https://ui.example.com";
+        assert!(is_allowed_origin(origins, "http://localhost:4200"));
+        assert!(is_allowed_origin(origins, "https://ui.example.com"));
+        assert!(!is_allowed_origin(origins, "http://localhost:4201"));
+        assert!(!is_allowed_origin("", "http://localhost:4200"));
+        assert!(is_allowed_origin("*", "http://10.0.0.2:8080"));
+        assert!(!is_allowed_origin("*", ""));
+    }
+
+    #[test]
+    fn test_cors_headers() {
+        let origins = "http://localhost:4200";
+        let get = request("GET", &[("Origin", "http://localhost:4200")]);
+        let headers = cors_headers(origins, &get);
+        assert!(
+            headers.contains(&("Access-Control-Allow-Origin", "http://localhost:4200".to_string()))
+        );
+        assert!(!is_preflight(&get));
+        let preflight = request(
+            "OPTIONS",
+            &[("Origin", "http://localhost:4200"), ("Access-Control-Request-Method", "PATCH")],
+        );
+        assert!(is_preflight(&preflight));
+        let headers = cors_headers(origins, &preflight);
+        assert!(headers.contains(&("Access-Control-Allow-Methods", ALLOWED_METHODS.to_string())));
+        let other = request("GET", &[("Origin", "http://evil.example.com")]);
+        assert!(cors_headers(origins, &other).is_empty());
+        assert!(cors_headers(origins, &request("GET", &[])).is_empty());
+    }
+}
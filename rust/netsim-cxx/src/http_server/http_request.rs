@@ -22,14 +22,19 @@
 //! meant to implement all aspects of RFC 5322. In particular,
 //! this library does not implement the following:
 //! * header field body with multiple lines (section 3.2.2)
-//! * limits on the lengths of the header section or header field
+//! * limits on the lengths of the header fields
 //!
 //! The main function is `HttpRequest::parse` which can be called
-//! repeatedly.
+//! repeatedly. `HttpRequest::read` reads a request from a tokio stream,
+//! with a header section of at most MAX_HEAD_LEN bytes.
 
 use std::io::BufRead;
 use std::io::BufReader;
 use std::io::Read;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
+
+/// Bound of the request line and the headers read by `HttpRequest::read`.
+const MAX_HEAD_LEN: usize = 65536;
 
 pub type StrHeaders<'a> = &'a [(&'a str, &'a str)];
 
@@ -95,20 +100,105 @@ impl HttpRequest {
     // https://github.com/rust-lang/rust-clippy/issues/9274
     #[allow(clippy::read_zero_byte_vec)]
     pub fn parse<T>(reader: &mut BufReader<T>) -> Result<HttpRequest, String>
+    where
+        T: std::io::Read,
+    {
+        let mut request = HttpRequest::parse_head(reader)?;
+        if let Some(len) = get_content_length(&request.headers) {
+            request.body.resize(len, 0);
+            reader
+                .read_exact(&mut request.body)
+                .map_err(|e| format!("Failed to read body: {e}"))?;
+        }
+        Ok(request)
+    }
+
+    // Parse the request line and the headers of an HTTP request, leaving
+    // the body empty.
+    fn parse_head<T>(reader: &mut BufReader<T>) -> Result<HttpRequest, String>
     where
         T: std::io::Read,
     {
         let (method, uri, version) = parse_request_line::<T>(reader)?;
         let headers = parse_header_section::<T>(reader)?;
-        let mut body = Vec::new();
-        if let Some(len) = get_content_length(&headers) {
-            body.resize(len, 0);
-            reader.read_exact(&mut body).map_err(|e| format!("Failed to read body: {e}"))?;
+        Ok(HttpRequest { method, uri, version, headers, body: Vec::new() })
+    }
+
+    /// Reads an HTTP request from a tokio stream, without blocking a thread
+    /// while the client sends it.
+    pub async fn read<T>(reader: &mut T) -> Result<HttpRequest, String>
+    where
+        T: AsyncBufRead + Unpin,
+    {
+        let mut head = Vec::new();
+        loop {
+            let len = head.len();
+            let mut line = (&mut *reader).take((MAX_HEAD_LEN - len) as u64);
+            line.read_until(b'\n', &mut head)
+                .await
+                .map_err(|e| format!("Failed to read request: {e}"))?;
+            match &head[len..] {
+                b"\r\n" | b"\n" | b"" => break,
+                _ if head.len() >= MAX_HEAD_LEN => return Err("Request header too large".into()),
+                _ => continue,
+            }
         }
-        Ok(HttpRequest { method, uri, version, headers, body })
+        let mut request = HttpRequest::parse_head(&mut BufReader::new(head.as_slice()))?;
+        if let Some(len) = get_content_length(&request.headers) {
+            request.body.resize(len, 0);
+            reader
+                .read_exact(&mut request.body)
+                .await
+                .map_err(|e| format!("Failed to read body: {e}"))?;
+        }
+        Ok(request)
+    }
+
+    /// Returns the path of the uri, without its query string.
+    pub fn path(&self) -> &str {
+        self.uri.split_once('?').map_or(&self.uri, |(path, _)| path)
+    }
+
+    /// Returns the decoded name and value of each parameter of the query
+    /// string of the uri, like "/v1/devices?page_size=10".
+    pub fn query_params(&self) -> Vec<(String, String)> {
+        let Some((_, query)) = self.uri.split_once('?') else {
+            return Vec::new();
+        };
+        query
+            .split('&')
+            .filter(|param| !param.is_empty())
+            .map(|param| {
+                let (name, value) = param.split_once('=').unwrap_or((param, ""));
+                (percent_decode(name), percent_decode(value))
+            })
+            .collect()
     }
 }
 
+// Decode the %XX escapes and the '+' spaces of a query string component.
+fn percent_decode(component: &str) -> String {
+    let mut bytes = Vec::with_capacity(component.len());
+    let mut rest = component.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        let escaped = tail
+            .get(..2)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (byte, escaped) {
+            (b'%', Some(escaped)) => {
+                bytes.push(escaped);
+                rest = &tail[2..];
+            }
+            _ => {
+                bytes.push(if byte == b'+' { b' ' } else { byte });
+                rest = tail;
+            }
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
 // Parse the request line of an HTTP request, which contains the method, URI, and version
 fn parse_request_line<T>(reader: &mut BufReader<T>) -> Result<(String, String, String), String>
 where
@@ -199,4 +289,43 @@ mod tests {
         assert!(http_request.headers.eq(&[("Host", "example.com")]));
         assert_eq!(http_request.body, b"");
     }
+
+    #[tokio::test]
+    async fn test_read() {
+        let request = concat!(
+            "POST /v1/captures HTTP/1.1\r\n",
+            "Host: example.com\r\nContent-Length: 5\r\n\r\n",
+            "Hello World"
+        );
+        let mut reader = request.as_bytes();
+        let http_request = HttpRequest::read(&mut reader).await.unwrap();
+        assert_eq!(http_request.method, "POST");
+        assert_eq!(http_request.uri, "/v1/captures");
+        assert!(http_request.headers.eq(&[("Host", "example.com"), ("Content-Length", "5")]));
+        assert_eq!(http_request.body, b"Hello");
+        // The header section is bounded.
+        let request = format!("GET / HTTP/1.1\r\nCookie: {}\r\n\r\n", "a".repeat(MAX_HEAD_LEN));
+        assert!(HttpRequest::read(&mut request.as_bytes()).await.is_err());
+        assert!(HttpRequest::read(&mut &b"GET / HTTP/1.1\r\nHost"[..]).await.is_err());
+    }
+
+    #[test]
+    fn test_query_params() {
+        let request = concat!(
+            "GET /v1/devices?name_filter=my+phone%21&chip_kind=BLUETOOTH&page_size=2 HTTP/1.1\r\n",
+            "Host: example.com\r\n\r\n"
+        );
+        let mut reader = BufReader::new(request.as_bytes());
+        let http_request = HttpRequest::parse::<&[u8]>(&mut reader).unwrap();
+        assert_eq!(http_request.path(), "/v1/devices");
+        let params = http_request.query_params();
+        let params: Vec<(&str, &str)> =
+            params.iter().map(|(n, v)| (n.as_str(), v.as_str())).collect();
+        assert_eq!(
+            params,
+            [("name_filter", "my phone!"), ("chip_kind", "BLUETOOTH"), ("page_size", "2")]
+        );
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%e2%9c%93"), "\u{2713}");
+    }
 }
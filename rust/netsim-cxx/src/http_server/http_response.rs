@@ -42,6 +42,27 @@ impl HttpResponse {
         }
     }
 
+    /// A response with a range of the resource, its Content-Range added
+    /// by the caller.
+    pub fn new_partial_with_length(content_type: &str, length: usize) -> HttpResponse {
+        let mut response = HttpResponse::new_ok_with_length(content_type, length);
+        response.status_code = 206;
+        response
+    }
+
+    /// A response without a length, its body streamed until the server
+    /// closes the connection.
+    pub fn new_ok_stream(content_type: &str) -> HttpResponse {
+        HttpResponse {
+            status_code: 200,
+            headers: HttpHeaders::new_with_headers(&[
+                ("Content-Type", content_type),
+                ("Connection", "close"),
+            ]),
+            body: Vec::new(),
+        }
+    }
+
     pub fn new_ok(content_type: &str, body: Vec<u8>) -> HttpResponse {
         HttpResponse {
             status_code: 200,
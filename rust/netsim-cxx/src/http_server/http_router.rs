@@ -43,9 +43,17 @@ impl Router {
         self.routes.push((route.to_owned(), handler));
     }
 
+    /// Returns the route matching the uri.
+    pub fn route(&self, uri: &str) -> Option<&str> {
+        self.routes
+            .iter()
+            .map(|(route, _)| route.as_str())
+            .find(|route| match_route(route, uri).is_some())
+    }
+
     pub fn handle_request(&self, request: &HttpRequest, writer: ResponseWritable) {
         for (route, handler) in &self.routes {
-            if let Some(param) = match_route(route, &request.uri) {
+            if let Some(param) = match_route(route, request.path()) {
                 handler(request, param, writer);
                 return;
             }
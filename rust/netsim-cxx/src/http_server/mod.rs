@@ -12,57 +12,169 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod cors;
 pub(crate) mod http_request;
 mod http_response;
 mod http_router;
 pub(crate) mod server_response;
-mod thread_pool;
+pub(crate) mod stream;
+mod websocket;
 
+use crate::api_stats::{api_calls, handle_api_stats};
+use crate::auth::authorize_http;
+use crate::bluetooth::beacon::handle_beacons;
+use crate::bugreport::handle_bugreport;
+use crate::capacity::handle_capacity;
 use crate::captures::handlers::*;
+use crate::chip_kinds::handle_chip_kinds;
+use crate::clock::handle_clock;
+use crate::config::{get_setting, get_setting_parsed};
+use crate::debugger::{handle_breakpoints, handle_delivery};
+use crate::events::handle_events;
+use crate::federation::handle_trunks;
+use crate::fuzz::handle_fuzz_replay;
+use crate::group::handle_group;
+use crate::http_server::cors::{cors_headers, is_preflight};
 use crate::http_server::http_request::HttpRequest;
 use crate::http_server::http_router::Router;
 use crate::http_server::server_response::{
     ResponseWritable, ServerResponseWritable, ServerResponseWriter,
 };
-use crate::version::VERSION;
+use crate::link::handle_link;
+use crate::listing::handle_list_devices;
+use crate::logger::{handle_log, info, warn};
+use crate::metrics::handle_metrics;
+use crate::mobility::handle_motion;
+use crate::processor::impairment::handle_impairment;
+use crate::propagation::handle_propagation;
+use crate::restart::handle_restart;
+use crate::scenario::handle_scenario;
+use crate::scene_file::handle_scene;
+use crate::selection::handle_selection;
+use crate::session::handle_session;
+use crate::transport::stats::handle_transport_stats;
+use crate::version::handle_version;
 
-use crate::http_server::thread_pool::ThreadPool;
+use crate::http_server::stream::{
+    capture_download_param, capture_stream_id, serve_capture_download, serve_capture_stream,
+};
+use crate::http_server::websocket::WEBSOCKET_URI;
+use crate::runtime::runtime;
 
-use crate::ffi::get_devices;
 use crate::ffi::patch_device;
 use cxx::let_cxx_string;
+use netsim_common::util::ini_file::IniFile;
+use netsim_common::util::os_utils::{format_address, get_connect_host, get_netsim_ini_filepath};
 use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::fs;
-use std::io::BufReader;
 use std::net::TcpListener;
 use std::net::TcpStream;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 
 const PATH_PREFIXES: [&str; 3] = ["js", "assets", "node_modules/tslib"];
 
 pub fn run_http_server() {
-    let listener = match TcpListener::bind("127.0.0.1:7681") {
-        Ok(listener) => listener,
+    let host = get_setting("http.host");
+    let listener =
+        match bind_listener(host.trim_matches(['[', ']']), get_setting_parsed("http.port")) {
+            Ok(listener) => listener,
+            Err(e) => {
+                warn!("bind error in netsimd frontend http server. {}", e);
+                return;
+            }
+        };
+    let port = listener.local_addr().map(|addr| addr.port()).unwrap_or_default();
+    let address = format_address(get_connect_host(&host), port);
+    write_http_address(port, &address);
+    info!("Frontend http server is listening on http://{address}");
+    let valid_files = Arc::new(create_filename_hash_set());
+    runtime().block_on(async move {
+        let listener = match listener
+            .set_nonblocking(true)
+            .and_then(|()| tokio::net::TcpListener::from_std(listener))
+        {
+            Ok(listener) => listener,
+            Err(e) => {
+                warn!("netsimd frontend http server error. {}", e);
+                return;
+            }
+        };
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    tokio::spawn(serve_connection(stream, valid_files.clone()));
+                }
+                Err(e) => warn!("accept error in netsimd frontend http server. {}", e),
+            }
+        }
+    });
+
+    info!("Shutting down frontend http server.");
+}
+
+// Reads a request without holding a thread. The capture streams, the
+// capture downloads and the WebSockets are served as tasks, the other
+// requests by the blocking handlers on the blocking threads of the runtime.
+async fn serve_connection(stream: tokio::net::TcpStream, valid_files: Arc<HashSet<String>>) {
+    let mut reader = tokio::io::BufReader::new(stream);
+    let request = HttpRequest::read(&mut reader).await;
+    let stream = reader.into_inner();
+    let request = match request {
+        Ok(request) => {
+            if let Some(id) = capture_stream_id(&request).map(str::to_string) {
+                return serve_capture_stream(stream, request, &id).await;
+            }
+            if let Some(param) = capture_download_param(&request).map(str::to_string) {
+                return serve_capture_download(stream, request, param).await;
+            }
+            // The refused upgrades get their status from the handlers.
+            if request.uri == WEBSOCKET_URI && authorize_http(&request).is_ok() {
+                return websocket::upgrade(stream, request).await;
+            }
+            Ok(request)
+        }
+        Err(e) => Err(e),
+    };
+    let stream = match stream.into_std().and_then(|stream| {
+        stream.set_nonblocking(false)?;
+        Ok(stream)
+    }) {
+        Ok(stream) => stream,
         Err(e) => {
-            eprintln!("netsimd: bind error in netsimd frontend http server. {}", e);
+            warn!("handle_connection error {e}");
             return;
         }
     };
-    let pool = ThreadPool::new(4);
-    println!("netsimd: Frontend http server is listening on http://localhost:7681");
-    let valid_files = Arc::new(create_filename_hash_set());
-    for stream in listener.incoming() {
-        let stream = stream.unwrap();
-        let valid_files = valid_files.clone();
-        pool.execute(move || {
-            handle_connection(stream, valid_files);
-        });
+    tokio::task::spawn_blocking(move || handle_connection(stream, request, valid_files));
+}
+
+// Binds the http port, falling back to a free port when another netsimd
+// instance already holds it.
+fn bind_listener(host: &str, port: u16) -> std::io::Result<TcpListener> {
+    match TcpListener::bind((host, port)) {
+        Err(e) if e.kind() == std::io::ErrorKind::AddrInUse && port != 0 => {
+            info!("http port {port} is in use, selecting a free port");
+            TcpListener::bind((host, 0))
+        }
+        result => result,
     }
+}
 
-    println!("netsimd: Shutting down frontend http server.");
+// Writes the http port and address to the instance's discovery file.
+fn write_http_address(port: u16, address: &str) {
+    let filepath = get_netsim_ini_filepath(&get_setting("instance"));
+    let mut ini_file = IniFile::new(filepath.to_string_lossy().into_owned());
+    // The grpc server has already written its port, keep it.
+    let _ = ini_file.read();
+    ini_file.insert("http.port", &port.to_string());
+    ini_file.insert("http.address", address);
+    if let Err(e) = ini_file.write() {
+        warn!("unable to write {}: {}", filepath.display(), e);
+    }
 }
 
 fn ui_path(suffix: &str) -> PathBuf {
@@ -84,7 +196,7 @@ fn create_filename_hash_set() -> HashSet<String> {
                 valid_files.insert(entry.path().to_str().unwrap().to_string());
             }
         } else {
-            println!("netsim-ui doesn't exist");
+            warn!("netsim-ui doesn't exist");
         }
     }
     valid_files
@@ -150,23 +262,9 @@ fn handle_static(request: &HttpRequest, path: &str, writer: ResponseWritable) {
     handle_file(&request.method, path, writer)
 }
 
-fn handle_version(_request: &HttpRequest, _param: &str, writer: ResponseWritable) {
-    let body = format!("{{\"version\": \"{}\"}}", VERSION);
-    writer.put_ok("text/plain", body.as_str(), &[]);
-}
-
 fn handle_devices(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
     if &request.method == "GET" {
-        let_cxx_string!(request = "");
-        let_cxx_string!(response = "");
-        let_cxx_string!(error_message = "");
-        let status = get_devices(&request, response.as_mut(), error_message.as_mut());
-        if status == 200 {
-            writer.put_ok("text/plain", response.to_string().as_str(), &[]);
-        } else {
-            let body = format!("404 Not found (netsim): {:?}", error_message.to_string());
-            writer.put_error(404, body.as_str());
-        }
+        handle_list_devices(request, writer);
     } else if &request.method == "PATCH" {
         let_cxx_string!(new_request = &request.body);
         let_cxx_string!(response = "");
@@ -187,14 +285,48 @@ fn handle_devices(request: &HttpRequest, _param: &str, writer: ResponseWritable)
     }
 }
 
-fn handle_connection(mut stream: TcpStream, valid_files: Arc<HashSet<String>>) {
+fn handle_connection(
+    mut stream: TcpStream,
+    request: Result<HttpRequest, String>,
+    valid_files: Arc<HashSet<String>>,
+) {
     let mut router = Router::new();
     router.add_route("/", Box::new(handle_index));
     router.add_route("/version", Box::new(handle_version));
     router.add_route("/v1/devices", Box::new(handle_devices));
+    router.add_route(r"/v1/chips/{id}/restart", Box::new(handle_restart));
+    router.add_route("/v1/beacons", Box::new(handle_beacons));
+    router.add_route(r"/v1/beacons/{id}", Box::new(handle_beacons));
     router.add_route(r"/pcap/{id}", Box::new(handle_pcap_file));
     router.add_route(r"/v1/captures", Box::new(handle_capture));
     router.add_route(r"/v1/captures/{id}", Box::new(handle_capture));
+    router.add_route("/v1/scenario", Box::new(handle_scenario));
+    router.add_route("/v1/debug/clock", Box::new(handle_clock));
+    router.add_route("/v1/debug/session", Box::new(handle_session));
+    router.add_route("/v1/debug/fuzz", Box::new(handle_fuzz_replay));
+    router.add_route("/v1/debug/breakpoints", Box::new(handle_breakpoints));
+    router.add_route(r"/v1/debug/breakpoints/{id}", Box::new(handle_breakpoints));
+    router.add_route("/v1/debug/delivery", Box::new(handle_delivery));
+    router.add_route(r"/v1/debug/delivery/{action}", Box::new(handle_delivery));
+    router.add_route("/v1/debug/log", Box::new(handle_log));
+    router.add_route("/v1/stats/transport", Box::new(handle_transport_stats));
+    router.add_route("/v1/stats/api", Box::new(handle_api_stats));
+    router.add_route("/v1/metrics", Box::new(handle_metrics));
+    router.add_route("/metrics", Box::new(handle_metrics));
+    router.add_route("/v1/capacity", Box::new(handle_capacity));
+    router.add_route("/v1/link", Box::new(handle_link));
+    router.add_route("/v1/motion", Box::new(handle_motion));
+    router.add_route("/v1/groups", Box::new(handle_group));
+    router.add_route("/v1/selection", Box::new(handle_selection));
+    router.add_route("/v1/scenes", Box::new(handle_scene));
+    router.add_route(r"/v1/scenes/{action}", Box::new(handle_scene));
+    router.add_route("/v1/impairment", Box::new(handle_impairment));
+    router.add_route("/v1/propagation", Box::new(handle_propagation));
+    router.add_route("/v1/federation/trunks", Box::new(handle_trunks));
+    router.add_route(r"/v1/federation/trunks/{id}", Box::new(handle_trunks));
+    router.add_route("/v1/events", Box::new(handle_events));
+    router.add_route("/v1/bugreport", Box::new(handle_bugreport));
+    router.add_route("/v1/chip_kinds", Box::new(handle_chip_kinds));
 
     // A closure for checking if path is a static file we wish to serve, and call handle_static
     let handle_static_wrapper =
@@ -218,9 +350,27 @@ fn handle_connection(mut stream: TcpStream, valid_files: Arc<HashSet<String>>) {
         )
     }
 
-    if let Ok(request) = HttpRequest::parse::<&TcpStream>(&mut BufReader::new(&stream)) {
+    if let Ok(request) = request {
+        let start = Instant::now();
         let mut response_writer = ServerResponseWriter::new(&mut stream);
-        router.handle_request(&request, &mut response_writer);
+        for (name, value) in cors_headers(&get_setting("http.cors_origins"), &request) {
+            response_writer.add_header(name, &value);
+        }
+        // The browsers send the preflight requests without a token.
+        if is_preflight(&request) {
+            response_writer.put_ok("text/plain", "", &[]);
+            return;
+        }
+        match authorize_http(&request) {
+            Ok(()) => router.handle_request(&request, &mut response_writer),
+            Err((status, message)) => response_writer.put_error(status, message),
+        }
+        // Unknown uris are not recorded to bound the number of APIs.
+        if let Some(route) = router.route(request.path()) {
+            let api = format!("{} {route}", request.method);
+            let cancelled = response_writer.is_cancelled();
+            api_calls().record(&api, start.elapsed(), response_writer.status() >= 400, cancelled);
+        }
     } else {
         let mut response_writer = ServerResponseWriter::new(&mut stream);
         let body = "404 not found (netsim): parse header failed";
@@ -27,6 +27,7 @@ use std::io::Write;
 use crate::http_server::http_response::HttpResponse;
 
 use super::http_request::StrHeaders;
+use crate::logger::warn;
 
 pub type ResponseWritable<'a> = &'a mut dyn ServerResponseWritable;
 
@@ -38,26 +39,55 @@ pub trait ServerResponseWritable {
     fn put_ok(&mut self, mime_type: &str, body: &str, headers: StrHeaders);
     fn put_error(&mut self, error_code: u16, error_message: &str);
     fn put_ok_with_vec(&mut self, mime_type: &str, body: Vec<u8>, headers: StrHeaders);
+    /// Starts a response with a range of the resource, the Content-Range in
+    /// the headers. The gRPC responses have no partial status.
+    fn put_partial_with_length(&mut self, mime_type: &str, length: usize, headers: StrHeaders) {
+        self.put_ok_with_length(mime_type, length, headers);
+    }
+    /// Replies to a range past the end of a resource of `size` bytes.
+    fn put_range_not_satisfiable(&mut self, size: u64) {
+        self.put_error(416, format!("Range not satisfiable, the size is {size}").as_str());
+    }
+    /// Returns true once the client is gone or its deadline has passed, so
+    /// long-running handlers can stop their work.
+    fn is_cancelled(&self) -> bool {
+        false
+    }
 }
 
 // A response writer that can contain a TCP stream or other writable.
 pub struct ServerResponseWriter<'a> {
     writer: &'a mut dyn Write,
+    status: u16,
+    // Set when a write fails, the client has closed the connection.
+    closed: bool,
+    // Headers added to the response of any handler, like the CORS headers.
+    headers: Vec<(String, String)>,
 }
 
 impl<'a> ServerResponseWriter<'a> {
-    pub fn new<W: Write>(writer: &mut W) -> ServerResponseWriter {
-        ServerResponseWriter { writer }
+    pub fn new<W: Write>(writer: &mut W) -> ServerResponseWriter<'_> {
+        ServerResponseWriter { writer, status: 0, closed: false, headers: Vec::new() }
+    }
+    /// Adds a header to the response written next.
+    pub fn add_header(&mut self, name: &str, value: &str) {
+        self.headers.push((name.to_string(), value.to_string()));
+    }
+    /// Returns the status of the response, 0 before it is written.
+    pub fn status(&self) -> u16 {
+        self.status
     }
     pub fn put_response(&mut self, response: HttpResponse) {
+        self.status = response.status_code;
         let mut buffer = format!("HTTP/1.1 {}\r\n", response.status_code).into_bytes();
-        for (name, value) in response.headers.iter() {
+        for (name, value) in response.headers.iter().chain(self.headers.iter()) {
             buffer.extend_from_slice(format!("{name}: {value}\r\n").as_bytes());
         }
         buffer.extend_from_slice(b"\r\n");
         buffer.extend_from_slice(&response.body);
         if let Err(e) = self.writer.write_all(&buffer) {
-            println!("netsim: handle_connection error {e}");
+            warn!("handle_connection error {e}");
+            self.closed = true;
         };
     }
 }
@@ -71,10 +101,10 @@ impl ServerResponseWritable for ServerResponseWriter<'_> {
         self.put_response(response);
     }
     fn put_chunk(&mut self, chunk: &[u8]) {
-        if let Err(e) = self.writer.write_all(chunk) {
-            println!("netsim: handle_connection error {e}");
+        if let Err(e) = self.writer.write_all(chunk).and_then(|_| self.writer.flush()) {
+            warn!("handle_connection error {e}");
+            self.closed = true;
         };
-        self.writer.flush().unwrap();
     }
     fn put_ok_with_length(&mut self, mime_type: &str, length: usize, headers: StrHeaders) {
         let mut response = HttpResponse::new_ok_with_length(mime_type, length);
@@ -91,6 +121,20 @@ impl ServerResponseWritable for ServerResponseWriter<'_> {
         response.add_headers(headers);
         self.put_response(response);
     }
+    fn put_partial_with_length(&mut self, mime_type: &str, length: usize, headers: StrHeaders) {
+        let mut response = HttpResponse::new_partial_with_length(mime_type, length);
+        response.add_headers(headers);
+        self.put_response(response);
+    }
+    fn put_range_not_satisfiable(&mut self, size: u64) {
+        let message = format!("Range not satisfiable, the size is {size}");
+        let mut response = HttpResponse::new_error(416, message.into_bytes());
+        response.headers.add_header("Content-Range", &format!("bytes */{size}"));
+        self.put_response(response);
+    }
+    fn is_cancelled(&self) -> bool {
+        self.closed
+    }
 }
 
 #[cfg(test)]
@@ -103,12 +147,25 @@ mod tests {
         let mut stream = Cursor::new(Vec::new());
         let mut writer = ServerResponseWriter::new(&mut stream);
         writer.put_error(404, "Hello World");
+        assert_eq!(writer.status(), 404);
         let written_bytes = stream.get_ref();
         let expected_bytes =
             b"HTTP/1.1 404\r\nContent-Type: text/plain\r\nContent-Length: 11\r\n\r\nHello World";
         assert_eq!(written_bytes, expected_bytes);
     }
 
+    #[test]
+    fn test_add_header() {
+        let mut stream = Cursor::new(Vec::new());
+        let mut writer = ServerResponseWriter::new(&mut stream);
+        writer.add_header("Vary", "Origin");
+        writer.put_error(404, "");
+        let written_bytes = stream.get_ref();
+        let expected_bytes =
+            b"HTTP/1.1 404\r\nContent-Type: text/plain\r\nContent-Length: 0\r\nVary: Origin\r\n\r\n";
+        assert_eq!(written_bytes, expected_bytes);
+    }
+
     #[test]
     fn test_put_ok() {
         let mut stream = Cursor::new(Vec::new());
@@ -119,4 +176,16 @@ mod tests {
             b"HTTP/1.1 200\r\nContent-Type: text/plain\r\nContent-Length: 11\r\n\r\nHello World";
         assert_eq!(written_bytes, expected_bytes);
     }
+
+    #[test]
+    fn test_closed_connection() {
+        let mut buffer = [0u8; 8];
+        let mut stream = Cursor::new(&mut buffer[..]);
+        let mut writer = ServerResponseWriter::new(&mut stream);
+        writer.put_chunk(b"pcap");
+        assert!(!writer.is_cancelled());
+        // The stream is full like a connection closed by the client.
+        writer.put_chunk(b"records");
+        assert!(writer.is_cancelled());
+    }
 }
@@ -0,0 +1,268 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Capture streams and downloads of the micro HTTP server.
+//!
+//! GET /v1/captures/{id}/stream sends the header of the capture format and
+//! then its records as the packet handlers write them, until the capture
+//! ends or the client closes the connection. A stream is a task of the
+//! tokio runtime awaiting the records of its subscription, so the streaming
+//! clients don't hold a thread each.
+//!
+//! GET /v1/captures/{id} and /v1/captures/{id}/segments/{index} download
+//! the file of a capture, or a range of it, the same way: the download is
+//! prepared on a blocking thread, waiting for the lock of the capture and
+//! the flush of its writer, and the file is then sent by a task.
+
+use std::io;
+use std::time::Instant;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::task::spawn_blocking;
+
+use crate::api_stats::api_calls;
+use crate::auth::authorize_http;
+use crate::captures::capture::Subscription;
+use crate::captures::error::CaptureError;
+use crate::captures::handlers::{prepare_capture_get, subscribe_capture, Download};
+use crate::config::get_setting;
+use crate::http_server::cors::cors_headers;
+use crate::http_server::http_request::HttpRequest;
+use crate::http_server::http_response::HttpResponse;
+use crate::http_server::server_response::{ServerResponseWritable, ServerResponseWriter};
+use crate::logger::warn;
+
+const STREAM_ROUTE: &str = "/v1/captures/{id}/stream";
+const DOWNLOAD_ROUTE: &str = "/v1/captures/{id}";
+
+/// Returns the capture id of a capture stream request.
+pub fn capture_stream_id(request: &HttpRequest) -> Option<&str> {
+    if request.method != "GET" {
+        return None;
+    }
+    let id = request.path().strip_prefix("/v1/captures/")?.strip_suffix("/stream")?;
+    (!id.is_empty() && !id.contains('/')).then_some(id)
+}
+
+/// Streams a capture to the client of the request.
+pub async fn serve_capture_stream<S>(mut stream: S, request: HttpRequest, id: &str)
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let start = Instant::now();
+    let headers = cors_headers(&get_setting("http.cors_origins"), &request);
+    let subscription = match authorize_http(&request) {
+        Ok(()) => match id.parse() {
+            Ok(id) => subscribe_capture(id).map_err(|err| (err.status(), err.to_string())),
+            Err(_) => Err((400, "Incorrect ID for capture".to_string())),
+        },
+        Err((status, message)) => Err((status, message.to_string())),
+    };
+    let error = subscription.is_err();
+    let result = match subscription {
+        Ok(subscription) => stream_records(&mut stream, &headers, subscription).await,
+        Err((status, message)) => {
+            let response = render(&headers, |writer| writer.put_error(status, &message));
+            stream.write_all(&response).await
+        }
+    };
+    let cancelled = result.is_err();
+    if let Err(e) = result {
+        warn!("capture stream error {e}");
+    }
+    api_calls().record(&format!("GET {STREAM_ROUTE}"), start.elapsed(), error, cancelled);
+}
+
+// Writes the header of the capture and its records as they come, until the
+// capture ends or the client closes the connection.
+async fn stream_records<S>(
+    stream: &mut S,
+    headers: &[(&str, String)],
+    mut subscription: Subscription,
+) -> io::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let response = HttpResponse::new_ok_stream(subscription.mime_type);
+    let head = render(headers, |writer| writer.put_response(response));
+    let (mut reader, mut writer) = tokio::io::split(stream);
+    writer.write_all(&head).await?;
+    writer.write_all(&subscription.header).await?;
+    writer.flush().await?;
+    let mut byte = [0; 1];
+    loop {
+        tokio::select! {
+            record = subscription.records.recv() => match record {
+                Some(record) => {
+                    writer.write_all(&record).await?;
+                    writer.flush().await?;
+                }
+                None => return writer.shutdown().await,
+            },
+            // The client sends nothing more: its read only returns as the
+            // client closes the connection.
+            read = reader.read(&mut byte) => match read {
+                Ok(0) | Err(_) => return Ok(()),
+                Ok(_) => continue,
+            },
+        }
+    }
+}
+
+/// Returns the part of the path after /v1/captures/ of a request
+/// downloading a capture or a rotated segment of it.
+pub fn capture_download_param(request: &HttpRequest) -> Option<&str> {
+    if request.method != "GET" {
+        return None;
+    }
+    let param = request.path().strip_prefix("/v1/captures/")?;
+    let id = param.split_once("/segments/").map_or(param, |(id, _)| id);
+    id.parse::<i32>().is_ok().then_some(param)
+}
+
+/// Sends the file of a capture, or the range of the request, to the client
+/// of the request.
+pub async fn serve_capture_download<S>(mut stream: S, request: HttpRequest, param: String)
+where
+    S: AsyncWrite + Unpin,
+{
+    let start = Instant::now();
+    let headers = cors_headers(&get_setting("http.cors_origins"), &request);
+    let prepared = match authorize_http(&request) {
+        Ok(()) => spawn_blocking(move || prepare_capture_get(&request, &param))
+            .await
+            .unwrap_or_else(|e| Err(CaptureError::IoError(io::Error::other(e)).into()))
+            .map_err(|err| render(&headers, |writer| err.put(writer))),
+        Err((status, message)) => Err(render(&headers, |writer| writer.put_error(status, message))),
+    };
+    let error = prepared.is_err();
+    let result = match prepared {
+        Ok(download) => send_download(&mut stream, &headers, download).await,
+        Err(response) => stream.write_all(&response).await,
+    };
+    let cancelled = result.is_err();
+    if let Err(e) = result {
+        warn!("capture download error {e}");
+    }
+    api_calls().record(&format!("GET {DOWNLOAD_ROUTE}"), start.elapsed(), error, cancelled);
+}
+
+/// Writes the head of a download and the bytes of its range, until the
+/// client closes the connection.
+pub async fn send_download<S>(
+    stream: &mut S,
+    headers: &[(&str, String)],
+    download: Download,
+) -> io::Result<()>
+where
+    S: AsyncWrite + Unpin,
+{
+    let head = render(headers, |writer| download.put_head(writer));
+    stream.write_all(&head).await?;
+    let (file, length) = download.reader.into_parts();
+    let mut file = tokio::fs::File::from_std(file).take(length);
+    if tokio::io::copy(&mut file, stream).await? < length {
+        return Err(io::Error::other("capture file shorter than its size"));
+    }
+    stream.shutdown().await
+}
+
+// Returns the bytes of a response with the headers of any response.
+fn render(headers: &[(&str, String)], put: impl FnOnce(&mut ServerResponseWriter)) -> Vec<u8> {
+    let mut response = Vec::new();
+    let mut writer = ServerResponseWriter::new(&mut response);
+    for (name, value) in headers {
+        writer.add_header(name, value);
+    }
+    put(&mut writer);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::captures::capture::Broadcast;
+    use crate::captures::writer::Record;
+    use std::io::BufReader;
+
+    fn request(method: &str, uri: &str) -> HttpRequest {
+        let request = format!("{method} {uri} HTTP/1.1\r\n\r\n");
+        HttpRequest::parse(&mut BufReader::new(request.as_bytes())).unwrap()
+    }
+
+    #[test]
+    fn test_capture_stream_id() {
+        assert_eq!(capture_stream_id(&request("GET", "/v1/captures/3/stream")), Some("3"));
+        assert_eq!(capture_stream_id(&request("GET", "/v1/captures/3/stream?x=1")), Some("3"));
+        assert_eq!(capture_stream_id(&request("PATCH", "/v1/captures/3/stream")), None);
+        assert_eq!(capture_stream_id(&request("GET", "/v1/captures/3")), None);
+        assert_eq!(capture_stream_id(&request("GET", "/v1/captures//stream")), None);
+        assert_eq!(capture_stream_id(&request("GET", "/v1/captures/3/segments/1/stream")), None);
+    }
+
+    #[test]
+    fn test_capture_download_param() {
+        let param = |method, uri| capture_download_param(&request(method, uri)).map(String::from);
+        assert_eq!(param("GET", "/v1/captures/3"), Some("3".to_string()));
+        assert_eq!(param("GET", "/v1/captures/3?x=1"), Some("3".to_string()));
+        assert_eq!(param("GET", "/v1/captures/3/segments/2"), Some("3/segments/2".to_string()));
+        assert_eq!(param("PATCH", "/v1/captures/3"), None);
+        assert_eq!(param("GET", "/v1/captures/stats"), None);
+        assert_eq!(param("GET", "/v1/captures"), None);
+    }
+
+    #[tokio::test]
+    async fn test_stream_records() {
+        let mut broadcast = Broadcast::default();
+        let subscription = Subscription {
+            mime_type: "application/vnd.tcpdump.pcap",
+            header: b"header".to_vec(),
+            records: broadcast.subscribe(),
+        };
+        let (mut client, mut server) = tokio::io::duplex(1024);
+        let task = tokio::spawn(async move {
+            stream_records(&mut server, &[("X-Test", "1".to_string())], subscription).await
+        });
+        broadcast.send(&Record::new(b"one".to_vec().into()));
+        broadcast.send(&Record::new(b"two".to_vec().into()));
+        // The stream ends with the capture.
+        broadcast.close();
+        task.await.unwrap().unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).await.unwrap();
+        assert_eq!(
+            response,
+            concat!(
+                "HTTP/1.1 200\r\nContent-Type: application/vnd.tcpdump.pcap\r\n",
+                "Connection: close\r\nX-Test: 1\r\n\r\nheaderonetwo"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_records_client_closed() {
+        let mut broadcast = Broadcast::default();
+        let subscription = Subscription {
+            mime_type: "text/plain",
+            header: vec![],
+            records: broadcast.subscribe(),
+        };
+        let (client, mut server) = tokio::io::duplex(1024);
+        drop(client);
+        // The stream of a client gone ends with no record sent.
+        assert!(stream_records(&mut server, &[], subscription).await.is_err());
+        broadcast.send(&Record::new(b"one".to_vec().into()));
+        assert!(broadcast.is_empty());
+    }
+}
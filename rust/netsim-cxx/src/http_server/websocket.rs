@@ -0,0 +1,290 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! WebSocket push channel for the web UI.
+//!
+//! A GET of /v1/websocket upgrading the connection to a WebSocket (RFC
+//! 6455) receives the events of the event history as they are recorded,
+//! one JSON Event per text message: the devices added, removed and patched
+//! with their state, the radio states of their chips and the lifecycle of
+//! the captures. The web UI refreshes on each message instead of polling
+//! the REST endpoints.
+//!
+//! The WebSockets are served by tasks of the netsimd runtime rather than
+//! threads, up to MAX_WEBSOCKETS at once. The messages of the client are
+//! ignored, but for its pings and its close.
+
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use netsim_common::util::sha1::Sha1;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio::time::timeout;
+
+use crate::auth::may_read_packets;
+use crate::events::{event_json, history};
+use crate::http_server::http_request::HttpRequest;
+use crate::http_server::server_response::{ServerResponseWritable, ServerResponseWriter};
+use crate::logger::warn;
+
+pub const WEBSOCKET_URI: &str = "/v1/websocket";
+
+// The GUID of the Sec-WebSocket-Accept key.
+const GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+const MAX_WEBSOCKETS: usize = 16;
+// A client not reading its messages for this long is disconnected.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+// The largest message accepted from the client.
+const MAX_CLIENT_PAYLOAD: u64 = 1 << 16;
+
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xa;
+
+static WEBSOCKETS: AtomicUsize = AtomicUsize::new(0);
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::new();
+    for chunk in data.chunks(3) {
+        let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(bits >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Returns the Sec-WebSocket-Accept key of a Sec-WebSocket-Key.
+pub fn accept_key(key: &str) -> String {
+    let mut sha1 = Sha1::new();
+    sha1.update(key.trim().as_bytes());
+    sha1.update(GUID.as_bytes());
+    base64(&sha1.finalize())
+}
+
+/// Returns true for a GET upgrading the connection to a WebSocket.
+pub fn is_upgrade(request: &HttpRequest) -> bool {
+    let header = |name| request.headers.get(name).unwrap_or_default().to_ascii_lowercase();
+    request.method == "GET"
+        && header("Upgrade") == "websocket"
+        && header("Connection").split(',').any(|token| token.trim() == "upgrade")
+}
+
+/// Returns an unmasked frame of the server with a whole message.
+fn frame(opcode: u8, payload: &[u8]) -> Vec<u8> {
+    let mut frame = vec![0x80 | opcode];
+    match payload.len() {
+        len @ 0..=125 => frame.push(len as u8),
+        len @ 126..=0xffff => {
+            frame.push(126);
+            frame.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        len => {
+            frame.push(127);
+            frame.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    frame.extend_from_slice(payload);
+    frame
+}
+
+/// Reads a frame of the client, returning its opcode and unmasked payload.
+async fn read_frame(reader: &mut (impl AsyncRead + Unpin)) -> io::Result<(u8, Vec<u8>)> {
+    let mut header = [0u8; 2];
+    reader.read_exact(&mut header).await?;
+    let len = match header[1] & 0x7f {
+        126 => reader.read_u16().await? as u64,
+        127 => reader.read_u64().await?,
+        len => len as u64,
+    };
+    if len > MAX_CLIENT_PAYLOAD {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "WebSocket frame too large"));
+    }
+    let mut mask = [0u8; 4];
+    if header[1] & 0x80 != 0 {
+        reader.read_exact(&mut mask).await?;
+    }
+    let mut payload = vec![0u8; len as usize];
+    reader.read_exact(&mut payload).await?;
+    for (i, byte) in payload.iter_mut().enumerate() {
+        *byte ^= mask[i % 4];
+    }
+    Ok((header[0] & 0x0f, payload))
+}
+
+// Sends a frame, returning false once the client is gone or doesn't read
+// its messages.
+async fn send(writer: &mut (impl AsyncWrite + Unpin), opcode: u8, payload: &[u8]) -> bool {
+    matches!(timeout(WRITE_TIMEOUT, writer.write_all(&frame(opcode, payload))).await, Ok(Ok(())))
+}
+
+// Pushes the events to the client until it closes the WebSocket, with
+// their packets if `packets`.
+async fn serve(stream: TcpStream, packets: bool) {
+    let (mut reader, mut writer) = stream.into_split();
+    let (_, mut events) = history().subscribe_task_since(0);
+    // The frames of the client, read by a task of their own as a frame
+    // read half way would be lost to the next event.
+    let (frame_sender, mut frames) = mpsc::channel(1);
+    let client = tokio::spawn(async move {
+        while let Ok(frame) = read_frame(&mut reader).await {
+            if frame_sender.send(frame).await.is_err() {
+                break;
+            }
+        }
+    });
+    loop {
+        tokio::select! {
+            event = events.recv() => match event.and_then(|event| event_json(event, packets)) {
+                Some(json) if send(&mut writer, OPCODE_TEXT, json.as_bytes()).await => continue,
+                _ => break,
+            },
+            frame = frames.recv() => match frame {
+                Some((OPCODE_PING, payload)) if send(&mut writer, OPCODE_PONG, &payload).await => {}
+                Some((OPCODE_CLOSE, payload)) => {
+                    send(&mut writer, OPCODE_CLOSE, &payload).await;
+                    break;
+                }
+                Some((OPCODE_PING, _)) | None => break,
+                Some(_) => continue,
+            },
+        }
+    }
+    client.abort();
+    let _ = writer.shutdown().await;
+}
+
+/// Upgrades the connection of a request to a WebSocket served by a task of
+/// its own, or fails the request.
+pub async fn upgrade(mut stream: TcpStream, request: HttpRequest) {
+    let key = match request.headers.get("Sec-WebSocket-Key") {
+        Some(key) if is_upgrade(&request) => key,
+        _ => return put_error(&mut stream, 400, "Expected a WebSocket upgrade").await,
+    };
+    if WEBSOCKETS.fetch_add(1, Ordering::SeqCst) >= MAX_WEBSOCKETS {
+        WEBSOCKETS.fetch_sub(1, Ordering::SeqCst);
+        return put_error(&mut stream, 503, "Too many WebSockets").await;
+    }
+    let handshake = format!(
+        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+        accept_key(&key)
+    );
+    let packets = may_read_packets(&request.headers.get("Authorization").unwrap_or_default());
+    match timeout(WRITE_TIMEOUT, stream.write_all(handshake.as_bytes())).await {
+        Ok(Ok(())) => serve(stream, packets).await,
+        Ok(Err(e)) => warn!("websocket error: {e}"),
+        Err(_) => warn!("websocket error: handshake timed out"),
+    }
+    WEBSOCKETS.fetch_sub(1, Ordering::SeqCst);
+}
+
+// Fails the upgrade of a connection.
+async fn put_error(stream: &mut TcpStream, status: u16, message: &str) {
+    let mut response = Vec::new();
+    ServerResponseWriter::new(&mut response).put_error(status, message);
+    let _ = stream.write_all(&response).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http_server::http_request::HttpHeaders;
+
+    #[test]
+    fn test_accept_key() {
+        // The example handshake of RFC 6455.
+        assert_eq!(accept_key("dGhlIHNhbXBsZSBub25jZQ=="), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+        assert_eq!(base64(b"ab"), "YWI=");
+        assert_eq!(base64(b"a"), "YQ==");
+    }
+
+    #[test]
+    fn test_is_upgrade() {
+        let request = |method: &str, headers| HttpRequest {
+            method: method.to_string(),
+            uri: WEBSOCKET_URI.to_string(),
+            headers: HttpHeaders::new_with_headers(headers),
+            version: "1.1".to_string(),
+            body: Vec::new(),
+        };
+        let headers = [("Upgrade", "WebSocket"), ("Connection", "keep-alive, Upgrade")];
+        assert!(is_upgrade(&request("GET", &headers)));
+        assert!(!is_upgrade(&request("POST", &headers)));
+        assert!(!is_upgrade(&request("GET", &[("Connection", "Upgrade")])));
+    }
+
+    #[tokio::test]
+    async fn test_frames() {
+        assert_eq!(frame(OPCODE_TEXT, b"{}"), [0x81, 2, b'{', b'}']);
+        let long = frame(OPCODE_TEXT, &[0; 300]);
+        assert_eq!(long[..4], [0x81, 126, 1, 44]);
+        assert_eq!(long.len(), 4 + 300);
+        // A masked ping of the client.
+        let mask = [1, 2, 3, 4];
+        let payload: Vec<u8> =
+            b"ping".iter().zip(mask.iter().cycle()).map(|(b, m)| b ^ m).collect();
+        let client_frame = [&[0x89, 0x80 | 4][..], &mask, &payload].concat();
+        assert_eq!(
+            read_frame(&mut &client_frame[..]).await.unwrap(),
+            (OPCODE_PING, b"ping".to_vec())
+        );
+        let too_large = [0x82, 127, 0, 0, 0, 0, 0, 2, 0, 0];
+        assert!(read_frame(&mut &too_large[..]).await.is_err());
+        assert!(read_frame(&mut &[0x81][..]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_upgrade() {
+        use frontend_proto::frontend::{event::Type, Event};
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).await.unwrap();
+        let (server, _) = listener.accept().await.unwrap();
+        let headers = [
+            ("Upgrade", "websocket"),
+            ("Connection", "Upgrade"),
+            ("Sec-WebSocket-Key", "dGhlIHNhbXBsZSBub25jZQ=="),
+        ];
+        let request = HttpRequest {
+            method: "GET".to_string(),
+            uri: WEBSOCKET_URI.to_string(),
+            headers: HttpHeaders::new_with_headers(&headers),
+            version: "1.1".to_string(),
+            body: Vec::new(),
+        };
+        let task = tokio::spawn(upgrade(server, request));
+        let mut handshake = vec![0u8; 129];
+        client.read_exact(&mut handshake).await.unwrap();
+        assert!(handshake.starts_with(b"HTTP/1.1 101 Switching Protocols\r\n"));
+        // The client frames are masked.
+        client.write_all(&[0x89, 0x80 | 2, 0, 0, 0, 0, b'h', b'i']).await.unwrap();
+        assert_eq!(read_frame(&mut client).await.unwrap(), (OPCODE_PONG, b"hi".to_vec()));
+        history().record(Event { type_: Type::RESET.into(), ..Default::default() });
+        let (opcode, json) = read_frame(&mut client).await.unwrap();
+        assert_eq!(opcode, OPCODE_TEXT);
+        assert!(String::from_utf8(json).unwrap().contains("RESET"));
+        client.write_all(&[0x88, 0x80, 0, 0, 0, 0]).await.unwrap();
+        assert_eq!(read_frame(&mut client).await.unwrap(), (OPCODE_CLOSE, vec![]));
+        task.await.unwrap();
+    }
+}
@@ -16,13 +16,48 @@
 
 #![allow(dead_code)]
 
+mod api_stats;
+mod auth;
+mod bluetooth;
+mod buffer_pool;
+mod bugreport;
+mod capacity;
 mod captures;
+mod chip_kinds;
+mod chip_registry;
+mod clock;
+mod config;
+mod debugger;
 mod devices;
+mod events;
+mod external;
+mod federation;
+pub mod fuzz;
+mod group;
 mod http_server;
+mod link;
+mod listing;
+mod logger;
+mod memory;
+mod metrics;
+mod mobility;
+mod nfc;
+mod processor;
+mod propagation;
+mod random;
 mod ranging;
+mod restart;
+mod runtime;
+mod scenario;
+mod scene_file;
+mod selection;
+mod session;
+mod state;
 mod transport;
 mod uwb;
 mod version;
+mod watchdog;
+mod wifi;
 
 use std::pin::Pin;
 
@@ -34,17 +69,150 @@ use http_server::server_response::ServerResponseWritable;
 use crate::transport::fd::handle_response;
 use crate::transport::fd::run_fd_transport;
 
+use crate::config::{get_setting, set_flag};
+use crate::scene_file::{add_scene_chip, handle_scene_cxx, import_scene, run_snapshots};
+use crate::state::{load_state, save_state, saved_chip_patch};
+
+use crate::api_stats::{handle_api_stats_cxx, record_api_call};
+use crate::auth::{check_access, init_token};
+use crate::bluetooth::advertising::{
+    advertising_delay, is_listening, observe_command, remove_scanner,
+};
+use crate::bluetooth::beacon::handle_beacons_cxx;
+use crate::bugreport::handle_bugreport_cxx;
+use crate::capacity::handle_capacity_cxx;
 use crate::captures::handlers::{
-    clear_pcap_files, handle_capture_cxx, handle_packet_request, handle_packet_response,
+    add_capture, clear_pcap_files, handle_capture_cxx, handle_capture_stream_cxx,
+    handle_packet_request, handle_packet_response, set_capture_all, set_capture_dir,
+    shutdown_captures, update_capture_facade,
+};
+use crate::chip_kinds::{
+    add_generic_chip, handle_chip_kinds_cxx, is_custom_chip_kind, register_chip_kind,
+    remove_generic_chip, route_generic_request,
 };
+use crate::chip_registry::{register_chip, unregister_chip};
+use crate::clock::{handle_clock_cxx, now_nanos};
+use crate::debugger::{handle_breakpoints_cxx, handle_delivery_cxx, hold_request, hold_response};
+use crate::events::{
+    handle_events_cxx, handle_events_stream_cxx, handle_subscribe_events_cxx, record_event,
+};
+use crate::external::{attach_chip, detach_chip, handle_request as handle_external_request};
+use crate::federation::{handle_trunks_cxx, run_federation, trunk_request, trunk_response};
+use crate::fuzz::handle_fuzz_replay_cxx;
+use crate::group::handle_group_cxx;
 use crate::http_server::run_http_server;
+use crate::link::handle_link_cxx;
+use crate::listing::handle_list_devices_cxx;
+use crate::logger::{handle_log_cxx, init_logger};
+use crate::mobility::{handle_motion_cxx, reset_motions, run_mobility};
+use crate::nfc::facade::*;
+use crate::processor::bandwidth::{get_bandwidth, set_bandwidth};
+use crate::processor::delay::{delay_request, delay_response, run_delays};
+use crate::processor::impairment::{
+    drop_link_packet, handle_impairment_cxx, link_delay_ms, reset_impairments,
+};
+use crate::processor::mtu::fragment_request;
+use crate::processor::{process_request, process_response};
+use crate::propagation::{compute_rssi, handle_propagation_cxx};
 use crate::ranging::*;
+use crate::restart::{handle_restart_cxx, hold_request as hold_restart_request};
+use crate::scenario::handle_scenario_cxx;
+use crate::selection::handle_selection_cxx;
+use crate::session::{
+    handle_session_cxx, record_chip_added, record_chip_removed, record_device_patched,
+    record_ingress, record_reset,
+};
+use crate::transport::backpressure::apply_backpressure;
+use crate::transport::flood::admit_request;
+use crate::transport::stats::{
+    handle_transport_stats_cxx, record_dequeued, record_malformed, record_queued, record_request,
+    record_response,
+};
 use crate::uwb::facade::*;
 use crate::version::*;
+use crate::watchdog::{record_request as record_watchdog_request, run_watchdog};
+use crate::wifi::{mirror_request, run_tun_bridge};
 
 #[cxx::bridge(namespace = "netsim")]
 mod ffi {
 
+    /// The decision of the packet processors about a packet.
+    #[namespace = "netsim::processor"]
+    enum PacketVerdict {
+        Forward,
+        Modify,
+        Drop,
+    }
+
+    /// The access a frontend RPC or http endpoint requires.
+    #[derive(Debug)]
+    #[namespace = "netsim::auth"]
+    enum Access {
+        Read,
+        ListCaptures,
+        DownloadCaptures,
+        Admin,
+    }
+
+    /// The kind of change recorded in the event history.
+    #[namespace = "netsim::events"]
+    enum EventType {
+        DeviceAdded,
+        DeviceRemoved,
+        DevicePatched,
+        ChipAdded,
+        ChipRemoved,
+        Reset,
+        ChipStateChanged,
+    }
+
+    /// A processed packet; `packet` holds the replacement for Modify.
+    #[namespace = "netsim::processor"]
+    struct ProcessedPacket {
+        verdict: PacketVerdict,
+        packet: Vec<u8>,
+    }
+
+    /// A fragment of a packet exceeding the MTU.
+    #[namespace = "netsim::processor"]
+    struct Fragment {
+        packet: Vec<u8>,
+    }
+
+    /// A packet checked against the MTU; `fragments` replace the packet
+    /// for Modify.
+    #[namespace = "netsim::processor"]
+    struct FragmentedPacket {
+        verdict: PacketVerdict,
+        fragments: Vec<Fragment>,
+    }
+
+    /// A position in meters.
+    #[namespace = "netsim::propagation"]
+    struct Point {
+        x: f32,
+        y: f32,
+        z: f32,
+    }
+
+    /// The radio link from the device of `sender_id` to the device of
+    /// `receiver_id`.
+    #[namespace = "netsim::propagation"]
+    struct RadioLink {
+        chip_kind: u32,
+        sender_id: u32,
+        receiver_id: u32,
+        sender: Point,
+        receiver: Point,
+    }
+
+    /// A packet of a custom chip kind routed to the chip of `facade_id`.
+    #[namespace = "netsim::chip_kinds"]
+    struct RoutedPacket {
+        facade_id: u32,
+        packet: Vec<u8>,
+    }
+
     extern "Rust" {
 
         #[cxx_name = "RunFdTransport"]
@@ -53,16 +221,88 @@ mod ffi {
         #[cxx_name = "RunHttpServer"]
         fn run_http_server();
 
+        #[cxx_name = "RunWatchdog"]
+        #[namespace = "netsim::watchdog"]
+        fn run_watchdog();
+
+        #[cxx_name = "RunMobility"]
+        #[namespace = "netsim::mobility"]
+        fn run_mobility();
+
+        #[cxx_name = "RunSnapshots"]
+        #[namespace = "netsim::scene_file"]
+        fn run_snapshots();
+
+        #[cxx_name = "RunTunBridge"]
+        #[namespace = "netsim::wifi"]
+        fn run_tun_bridge();
+
+        #[cxx_name = "RunFederation"]
+        #[namespace = "netsim::federation"]
+        fn run_federation();
+
+        // Settings
+
+        #[cxx_name = "GetSetting"]
+        #[namespace = "netsim::config"]
+        fn get_setting(key: &str) -> String;
+
+        #[cxx_name = "SetFlag"]
+        #[namespace = "netsim::config"]
+        fn set_flag(key: &str, value: &str) -> bool;
+
+        // Logging
+
+        #[cxx_name = "InitLogger"]
+        #[namespace = "netsim::logger"]
+        fn init_logger();
+
+        // Persistent state
+
+        #[cxx_name = "LoadState"]
+        #[namespace = "netsim::state"]
+        fn load_state(fresh: bool);
+
+        #[cxx_name = "ImportScene"]
+        #[namespace = "netsim::scene_file"]
+        fn import_scene(path: &str) -> bool;
+
+        #[cxx_name = "AddSceneChip"]
+        #[namespace = "netsim::scene_file"]
+        fn add_scene_chip();
+
+        #[cxx_name = "SaveState"]
+        #[namespace = "netsim::state"]
+        fn save_state();
+
+        #[cxx_name = "SavedChipPatch"]
+        #[namespace = "netsim::state"]
+        fn saved_chip_patch(
+            device_name: &str,
+            chip_kind: u32,
+            chip_name: &str,
+            first_chip: bool,
+        ) -> Vec<u8>;
+
         // Ranging
 
         #[cxx_name = "DistanceToRssi"]
         fn distance_to_rssi(tx_power: i8, distance: f32) -> i8;
 
+        // Propagation
+
+        #[cxx_name = "ComputeRssi"]
+        #[namespace = "netsim::propagation"]
+        fn compute_rssi(link: &RadioLink, tx_power: i8) -> i8;
+
         // Version
 
         #[cxx_name = "GetVersion"]
         fn get_version() -> String;
 
+        #[cxx_name = "GetVersionResponseBytes"]
+        fn version_response_bytes() -> Vec<u8>;
+
         // handle_capture_cxx translates each argument into an appropriate Rust type
 
         #[cxx_name = "HandleCaptureCxx"]
@@ -73,14 +313,585 @@ mod ffi {
             body: String,
         );
 
+        // handle_capture_stream_cxx streams the records of a capture until
+        // the call is cancelled
+
+        #[cxx_name = "HandleCaptureStreamCxx"]
+        fn handle_capture_stream_cxx(responder: Pin<&mut CxxServerResponseWriter>, id: i32);
+
+        // handle_scenario_cxx runs a RunScenarioRequest given as JSON body
+
+        #[cxx_name = "HandleScenarioCxx"]
+        fn handle_scenario_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_clock_cxx controls the manual clock with a ControlClockRequest
+        // given as JSON body
+
+        #[cxx_name = "HandleClockCxx"]
+        fn handle_clock_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_session_cxx records or replays the session log of a
+        // ControlSessionRequest given as JSON body
+
+        #[cxx_name = "HandleSessionCxx"]
+        fn handle_session_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_beacons_cxx lists, creates with an AdvertiseRequest given as
+        // JSON body, or removes the builtin beacons
+
+        #[cxx_name = "HandleBeaconsCxx"]
+        fn handle_beacons_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_trunks_cxx lists, links with a LinkInstanceRequest given as
+        // JSON body, or unlinks the trunks to other netsimd instances
+
+        #[cxx_name = "HandleTrunksCxx"]
+        fn handle_trunks_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_fuzz_replay_cxx runs a ReplayFuzzInputRequest given as JSON body
+
+        #[cxx_name = "HandleFuzzReplayCxx"]
+        fn handle_fuzz_replay_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_restart_cxx restarts the facade of the chip given as param
+
+        #[cxx_name = "HandleRestartCxx"]
+        fn handle_restart_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+        );
+
+        // handle_capacity_cxx returns the usage of the resources against their
+        // limits
+
+        #[cxx_name = "HandleCapacityCxx"]
+        fn handle_capacity_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+        );
+
+        // handle_api_stats_cxx returns the calls, errors and latencies of the
+        // frontend APIs
+
+        #[cxx_name = "HandleApiStatsCxx"]
+        fn handle_api_stats_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+        );
+
+        // handle_transport_stats_cxx returns the per-chip transport statistics
+
+        #[cxx_name = "HandleTransportStatsCxx"]
+        fn handle_transport_stats_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+        );
+
+        // handle_link_cxx returns the link quality for a GetLinkRequest given
+        // as JSON body
+
+        #[cxx_name = "HandleLinkCxx"]
+        fn handle_link_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_propagation_cxx gets the propagation model for GET, and
+        // selects the model of a Propagation given as JSON body for PATCH
+
+        #[cxx_name = "HandlePropagationCxx"]
+        fn handle_propagation_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_impairment_cxx sets the link impairment of a
+        // SetLinkImpairmentRequest given as JSON body
+
+        #[cxx_name = "HandleImpairmentCxx"]
+        fn handle_impairment_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_motion_cxx moves a device along the path of a
+        // SetDeviceMotionRequest given as JSON body
+
+        #[cxx_name = "HandleMotionCxx"]
+        fn handle_motion_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_scene_cxx loads the scene file of a LoadScenarioRequest,
+        // or saves or restores the simulation with the "save" or "restore"
+        // param, given as JSON body
+
+        #[cxx_name = "HandleSceneCxx"]
+        fn handle_scene_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_group_cxx patches the devices of a group with a
+        // PatchGroupRequest given as JSON body
+
+        #[cxx_name = "HandleGroupCxx"]
+        fn handle_group_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_selection_cxx patches the chips matched by the selector of
+        // a PatchSelectionRequest given as JSON body
+
+        #[cxx_name = "HandleSelectionCxx"]
+        fn handle_selection_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_bugreport_cxx writes a bug report archive and returns its path
+
+        #[cxx_name = "HandleBugReportCxx"]
+        fn handle_bugreport_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+        );
+
+        // handle_events_cxx lists the event history for a ListEventsRequest
+        // given as JSON body, clearing the packets unless the authorization
+        // of the call may read them
+
+        #[cxx_name = "HandleEventsCxx"]
+        fn handle_events_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+            authorization: String,
+        );
+
+        // handle_events_stream_cxx streams the events recorded from now on,
+        // one JSON Event per chunk, until the call is cancelled
+
+        #[cxx_name = "HandleEventsStreamCxx"]
+        fn handle_events_stream_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            authorization: String,
+        );
+
+        // handle_subscribe_events_cxx streams the events selected by a
+        // SubscribeEventsRequest given as JSON body, until the call is
+        // cancelled
+
+        #[cxx_name = "HandleSubscribeEventsCxx"]
+        fn handle_subscribe_events_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            body: String,
+            authorization: String,
+        );
+
+        // handle_list_devices_cxx lists the devices selected by a
+        // GetDevicesRequest given as JSON body
+
+        #[cxx_name = "HandleListDevicesCxx"]
+        fn handle_list_devices_cxx(responder: Pin<&mut CxxServerResponseWriter>, body: String);
+
+        // handle_chip_kinds_cxx lists the chip kinds for GET and registers
+        // a ChipKindInfo given as JSON body for POST
+
+        #[cxx_name = "HandleChipKindsCxx"]
+        fn handle_chip_kinds_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_breakpoints_cxx lists the breakpoints for GET, sets a
+        // Breakpoint given as JSON body for POST and deletes the breakpoint
+        // of param for DELETE
+
+        #[cxx_name = "HandleBreakpointsCxx"]
+        fn handle_breakpoints_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_delivery_cxx pauses, steps and resumes the packet delivery
+
+        #[cxx_name = "HandleDeliveryCxx"]
+        fn handle_delivery_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // handle_log_cxx gets the level of the logs for GET, and sets the
+        // level given as body for PATCH
+
+        #[cxx_name = "HandleLogCxx"]
+        fn handle_log_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // Facade restarts
+
+        #[cxx_name = HoldRequest]
+        #[namespace = "netsim::restart"]
+        fn hold_restart_request(
+            kind: u32,
+            facade_id: u32,
+            packet: &CxxVector<u8>,
+            packet_type: u32,
+        ) -> bool;
+
+        // Auth
+
+        #[cxx_name = CheckAccess]
+        #[namespace = "netsim::auth"]
+        fn check_access(authorization: &str, access: Access) -> u32;
+
+        #[cxx_name = InitToken]
+        #[namespace = "netsim::auth"]
+        fn init_token();
+
+        // API statistics
+
+        #[cxx_name = RecordApiCall]
+        #[namespace = "netsim::api_stats"]
+        fn record_api_call(api: &str, latency_us: u64, error: bool, cancelled: bool);
+
+        // Watchdog
+
+        #[cxx_name = RecordRequest]
+        #[namespace = "netsim::watchdog"]
+        fn record_watchdog_request(kind: u32, facade_id: u32, packet: &CxxVector<u8>);
+
+        // Wi-Fi TUN bridge
+
+        #[cxx_name = MirrorRequest]
+        #[namespace = "netsim::wifi"]
+        fn mirror_request(facade_id: u32, packet: &CxxVector<u8>);
+
+        // Packet breakpoints
+
+        #[cxx_name = HoldRequest]
+        #[namespace = "netsim::debugger"]
+        fn hold_request(
+            kind: u32,
+            facade_id: u32,
+            packet: &CxxVector<u8>,
+            packet_type: u32,
+        ) -> bool;
+
+        #[cxx_name = HoldResponse]
+        #[namespace = "netsim::debugger"]
+        fn hold_response(
+            kind: u32,
+            facade_id: u32,
+            packet: &CxxVector<u8>,
+            packet_type: u32,
+        ) -> bool;
+
+        // Custom chip kinds
+
+        #[cxx_name = RegisterChipKind]
+        #[namespace = "netsim::chip_kinds"]
+        fn register_chip_kind(name: &str, linktype: u32, routing: u32) -> u32;
+
+        #[cxx_name = IsCustomChipKind]
+        #[namespace = "netsim::chip_kinds"]
+        fn is_custom_chip_kind(kind: u32) -> bool;
+
+        #[cxx_name = AddGenericChip]
+        #[namespace = "netsim::chip_kinds"]
+        fn add_generic_chip(kind: u32, chip_id: u32) -> u32;
+
+        #[cxx_name = RemoveGenericChip]
+        #[namespace = "netsim::chip_kinds"]
+        fn remove_generic_chip(kind: u32, facade_id: u32);
+
+        #[cxx_name = RouteGenericRequest]
+        #[namespace = "netsim::chip_kinds"]
+        fn route_generic_request(kind: u32, facade_id: u32, packet: &[u8]) -> Vec<RoutedPacket>;
+
+        // Event history
+
+        #[cxx_name = RecordEvent]
+        #[namespace = "netsim::events"]
+        fn record_event(event_type: EventType, device_name: &str, chip_id: u32, scene: &[u8]);
+
+        // Session recording
+
+        // Chip registry
+
+        #[cxx_name = RegisterChip]
+        #[namespace = "netsim::chip_registry"]
+        fn register_chip(chip_id: u32, kind: u32, facade_id: u32);
+
+        #[cxx_name = UnregisterChip]
+        #[namespace = "netsim::chip_registry"]
+        fn unregister_chip(chip_id: u32);
+
+        #[cxx_name = RecordChipAdded]
+        #[namespace = "netsim::session"]
+        #[allow(clippy::too_many_arguments)]
+        fn record_chip_added(
+            guid: &str,
+            device_name: &str,
+            kind: u32,
+            chip_name: &str,
+            manufacturer: &str,
+            product_name: &str,
+            chip_id: u32,
+            facade_id: u32,
+        );
+
+        #[cxx_name = RecordChipRemoved]
+        #[namespace = "netsim::session"]
+        fn record_chip_removed(chip_id: u32);
+
+        #[cxx_name = RecordDevicePatched]
+        #[namespace = "netsim::session"]
+        fn record_device_patched(patch: &[u8]);
+
+        #[cxx_name = RecordReset]
+        #[namespace = "netsim::session"]
+        fn record_reset();
+
+        #[cxx_name = ResetMotions]
+        #[namespace = "netsim::mobility"]
+        fn reset_motions();
+
+        #[cxx_name = ResetImpairments]
+        #[namespace = "netsim::processor"]
+        fn reset_impairments();
+
+        #[cxx_name = RecordIngress]
+        #[namespace = "netsim::session"]
+        fn record_ingress(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32);
+
+        // External controllers
+
+        #[cxx_name = AttachChip]
+        #[namespace = "netsim::external"]
+        fn attach_chip(device_name: &str, kind: u32, chip_id: u32, facade_id: u32);
+
+        #[cxx_name = DetachChip]
+        #[namespace = "netsim::external"]
+        fn detach_chip(chip_id: u32);
+
+        #[cxx_name = HandleRequest]
+        #[namespace = "netsim::external"]
+        fn handle_external_request(
+            kind: u32,
+            facade_id: u32,
+            packet: &CxxVector<u8>,
+            packet_type: u32,
+        ) -> bool;
+
+        // Federation
+
+        #[cxx_name = TrunkRequest]
+        #[namespace = "netsim::federation"]
+        fn trunk_request(
+            kind: u32,
+            facade_id: u32,
+            packet: &CxxVector<u8>,
+            packet_type: u32,
+        ) -> bool;
+
+        #[cxx_name = TrunkResponse]
+        #[namespace = "netsim::federation"]
+        fn trunk_response(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32);
+
+        // Flood protection
+
+        #[cxx_name = AdmitRequest]
+        #[namespace = "netsim::transport"]
+        fn admit_request(kind: u32, facade_id: u32, size: u32) -> bool;
+
+        // Backpressure
+
+        #[cxx_name = ApplyBackpressure]
+        #[namespace = "netsim::transport"]
+        fn apply_backpressure(kind: u32, facade_id: u32, size: u32) -> bool;
+
+        // Advertising timing
+
+        #[cxx_name = ObserveCommand]
+        #[namespace = "netsim::hci"]
+        fn observe_command(facade_id: u32, command: &[u8]);
+
+        #[cxx_name = RemoveScanner]
+        #[namespace = "netsim::hci"]
+        fn remove_scanner(facade_id: u32);
+
+        #[cxx_name = AdvertisingDelay]
+        #[namespace = "netsim::hci"]
+        fn advertising_delay() -> u32;
+
+        #[cxx_name = IsListening]
+        #[namespace = "netsim::hci"]
+        fn is_listening(facade_id: u32, delay: u32) -> bool;
+
+        // Transport statistics
+
+        #[cxx_name = RecordRequest]
+        #[namespace = "netsim::transport"]
+        fn record_request(kind: u32, facade_id: u32, size: usize);
+
+        #[cxx_name = RecordResponse]
+        #[namespace = "netsim::transport"]
+        fn record_response(kind: u32, facade_id: u32, size: usize);
+
+        #[cxx_name = RecordMalformed]
+        #[namespace = "netsim::transport"]
+        fn record_malformed(kind: u32, facade_id: u32);
+
+        #[cxx_name = RecordQueued]
+        #[namespace = "netsim::transport"]
+        fn record_queued(kind: u32, facade_id: u32);
+
+        #[cxx_name = RecordDequeued]
+        #[namespace = "netsim::transport"]
+        fn record_dequeued(kind: u32, facade_id: u32);
+
         // Packet hub
 
         #[cxx_name = HandleResponse]
         #[namespace = "netsim::fd"]
         fn handle_response(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u8);
 
+        // Packet processors
+
+        #[cxx_name = ProcessRequest]
+        #[namespace = "netsim::processor"]
+        fn process_request(
+            kind: u32,
+            facade_id: u32,
+            packet: &CxxVector<u8>,
+            packet_type: u32,
+        ) -> ProcessedPacket;
+
+        #[cxx_name = ProcessResponse]
+        #[namespace = "netsim::processor"]
+        fn process_response(
+            kind: u32,
+            facade_id: u32,
+            packet: &CxxVector<u8>,
+            packet_type: u32,
+        ) -> ProcessedPacket;
+
+        #[cxx_name = DropLinkPacket]
+        #[namespace = "netsim::processor"]
+        fn drop_link_packet(sender_facade_id: u32, receiver_facade_id: u32) -> bool;
+
+        #[cxx_name = LinkDelayMs]
+        #[namespace = "netsim::processor"]
+        fn link_delay_ms(sender_facade_id: u32, receiver_facade_id: u32) -> u32;
+
+        #[cxx_name = DelayRequest]
+        #[namespace = "netsim::processor"]
+        fn delay_request(
+            kind: u32,
+            facade_id: u32,
+            packet: &CxxVector<u8>,
+            packet_type: u32,
+        ) -> bool;
+
+        #[cxx_name = DelayResponse]
+        #[namespace = "netsim::processor"]
+        fn delay_response(
+            kind: u32,
+            facade_id: u32,
+            packet: &CxxVector<u8>,
+            packet_type: u32,
+        ) -> bool;
+
+        #[cxx_name = RunDelays]
+        #[namespace = "netsim::processor"]
+        fn run_delays();
+
+        #[cxx_name = SetBandwidth]
+        #[namespace = "netsim::processor"]
+        fn set_bandwidth(kind: u32, facade_id: u32, bits_per_second: u64);
+
+        #[cxx_name = GetBandwidth]
+        #[namespace = "netsim::processor"]
+        fn get_bandwidth(kind: u32, facade_id: u32) -> u64;
+
+        #[cxx_name = FragmentRequest]
+        #[namespace = "netsim::processor"]
+        fn fragment_request(
+            kind: u32,
+            packet: &CxxVector<u8>,
+            packet_type: u32,
+        ) -> FragmentedPacket;
+
         // Capture Resource
 
+        #[cxx_name = NowNanos]
+        #[namespace = "netsim::clock"]
+        fn now_nanos() -> u64;
+
         #[cxx_name = HandleRequest]
         #[namespace = "netsim::pcap"]
         fn handle_packet_request(
@@ -88,6 +899,7 @@ mod ffi {
             facade_id: u32,
             packet: &CxxVector<u8>,
             packet_type: u32,
+            timestamp_nanos: u64,
         );
 
         #[cxx_name = HandleResponse]
@@ -97,6 +909,7 @@ mod ffi {
             facade_id: u32,
             packet: &CxxVector<u8>,
             packet_type: u32,
+            timestamp_nanos: u64,
         );
 
         // Clearing out all pcap Files in temp directory
@@ -105,12 +918,36 @@ mod ffi {
         #[namespace = "netsim::pcap"]
         fn clear_pcap_files() -> bool;
 
+        #[cxx_name = SetCaptureDir]
+        #[namespace = "netsim::pcap"]
+        fn set_capture_dir(dir: &str) -> bool;
+
+        #[cxx_name = SetCaptureAll]
+        #[namespace = "netsim::pcap"]
+        fn set_capture_all();
+
+        #[cxx_name = AddCapture]
+        #[namespace = "netsim::pcap"]
+        fn add_capture(kind: u32, chip_id: u32, device_name: &str);
+
+        #[cxx_name = ShutdownCaptures]
+        #[namespace = "netsim::pcap"]
+        fn shutdown_captures();
+
+        #[cxx_name = UpdateCaptureFacade]
+        #[namespace = "netsim::pcap"]
+        fn update_capture_facade(chip_id: u32, facade_id: u32);
+
         // Uwb Facade.
 
         #[cxx_name = HandleUwbRequestCxx]
         #[namespace = "netsim::uwb"]
         fn handle_uwb_request(facade_id: u32, packet: &[u8]);
 
+        #[cxx_name = RunRanging]
+        #[namespace = "netsim::uwb"]
+        fn run_ranging();
+
         #[cxx_name = PatchCxx]
         #[namespace = "netsim::uwb::facade"]
         pub fn uwb_patch(_facade_id: u32, _proto_bytes: &[u8]);
@@ -139,6 +976,36 @@ mod ffi {
         #[namespace = "netsim::uwb::facade"]
         pub fn uwb_stop();
 
+        // Nfc Facade.
+
+        #[cxx_name = HandleNfcRequestCxx]
+        #[namespace = "netsim::nfc"]
+        fn handle_nfc_request(facade_id: u32, packet: &[u8]);
+
+        #[cxx_name = RunField]
+        #[namespace = "netsim::nfc"]
+        fn run_field();
+
+        #[cxx_name = PatchCxx]
+        #[namespace = "netsim::nfc::facade"]
+        pub fn nfc_patch(facade_id: u32, proto_bytes: &[u8]);
+
+        #[cxx_name = GetCxx]
+        #[namespace = "netsim::nfc::facade"]
+        pub fn nfc_get(facade_id: u32) -> Vec<u8>;
+
+        #[cxx_name = Reset]
+        #[namespace = "netsim::nfc::facade"]
+        pub fn nfc_reset(facade_id: u32);
+
+        #[cxx_name = Remove]
+        #[namespace = "netsim::nfc::facade"]
+        pub fn nfc_remove(facade_id: u32);
+
+        #[cxx_name = Add]
+        #[namespace = "netsim::nfc::facade"]
+        pub fn nfc_add(device_id: u32) -> u32;
+
     }
 
     unsafe extern "C++" {
@@ -165,6 +1032,14 @@ mod ffi {
         #[namespace = "netsim::scene_controller"]
         fn RemoveChip(device_id: u32, chip_id: u32);
 
+        #[rust_name = "restart_chip_facade"]
+        #[namespace = "netsim::scene_controller"]
+        fn RestartChip(chip_id: i32) -> bool;
+
+        #[rust_name = "reset_scene"]
+        #[namespace = "netsim::scene_controller"]
+        fn Reset();
+
         #[rust_name = "get_devices"]
         #[namespace = "netsim::scene_controller"]
         fn GetDevices(
@@ -177,10 +1052,6 @@ mod ffi {
         #[namespace = "netsim::scene_controller"]
         fn GetDevicesBytes(vec: &mut Vec<u8>) -> bool;
 
-        #[rust_name = "get_facade_id"]
-        #[namespace = "netsim::scene_controller"]
-        fn GetFacadeId(chip_id: i32) -> i32;
-
         #[rust_name = "patch_device"]
         #[namespace = "netsim::scene_controller"]
         fn PatchDevice(
@@ -189,6 +1060,12 @@ mod ffi {
             error_message: Pin<&mut CxxString>,
         ) -> u32;
 
+        include!("core/propagation_client.h");
+
+        #[rust_name = "compute_external_path_loss"]
+        #[namespace = "netsim::propagation"]
+        fn ComputeExternalPathLoss(address: &str, link: &RadioLink, path_loss: &mut f32) -> bool;
+
         /// A C++ class which can be used to respond to a request.
         include!("frontend/server_response_writable.h");
 
@@ -207,12 +1084,30 @@ mod ffi {
         #[namespace = "netsim::frontend"]
         fn put_error(self: &CxxServerResponseWriter, error_code: u32, error_message: &CxxString);
 
+        #[namespace = "netsim::frontend"]
+        fn put_header(self: &CxxServerResponseWriter, name: &CxxString, value: &CxxString);
+
+        #[namespace = "netsim::frontend"]
+        fn is_cancelled(self: &CxxServerResponseWriter) -> bool;
+
         include!("packet_hub/packet_hub.h");
 
         #[rust_name = "handle_request_cxx"]
         #[namespace = "netsim::packet_hub"]
         fn HandleRequestCxx(kind: u32, facade_id: u32, packet: &Vec<u8>, packet_type: u8);
 
+        #[rust_name = "forward_request_cxx"]
+        #[namespace = "netsim::packet_hub"]
+        fn ForwardRequestCxx(kind: u32, facade_id: u32, packet: &Vec<u8>, packet_type: u8);
+
+        #[rust_name = "handle_response_cxx"]
+        #[namespace = "netsim::packet_hub"]
+        fn HandleResponseCxx(kind: u32, facade_id: u32, packet: &Vec<u8>, packet_type: u8);
+
+        #[rust_name = "forward_response_cxx"]
+        #[namespace = "netsim::packet_hub"]
+        fn ForwardResponseCxx(kind: u32, facade_id: u32, packet: &Vec<u8>, packet_type: u8);
+
     }
 }
 
@@ -223,7 +1118,12 @@ struct CxxServerResponseWriterWrapper<'a> {
 }
 
 impl ServerResponseWritable for CxxServerResponseWriterWrapper<'_> {
-    fn put_ok_with_length(&mut self, mime_type: &str, length: usize, _headers: StrHeaders) {
+    fn put_ok_with_length(&mut self, mime_type: &str, length: usize, headers: StrHeaders) {
+        for (name, value) in headers {
+            let_cxx_string!(name = name);
+            let_cxx_string!(value = value);
+            self.writer.put_header(&name, &value);
+        }
         let_cxx_string!(mime_type = mime_type);
         self.writer.put_ok_with_length(&mime_type, length);
     }
@@ -243,4 +1143,7 @@ impl ServerResponseWritable for CxxServerResponseWriterWrapper<'_> {
     fn put_ok_with_vec(&mut self, _mime_type: &str, _body: Vec<u8>, _headers: StrHeaders) {
         todo!()
     }
+    fn is_cancelled(&self) -> bool {
+        self.writer.is_cancelled()
+    }
 }
@@ -0,0 +1,372 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Link quality between two devices.
+//!
+//! Computes what the radio model applies to the packets from one device to
+//! another, so scenario authors can check the geometry and radio states
+//! before running a long test.
+//!
+//! /v1/link --> handle_link (POST a GetLinkRequest)
+
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::link_impairment::Kind;
+use frontend_proto::frontend::{
+    GetDevicesResponse, GetLinkRequest, GetLinkResponse, LinkImpairment,
+};
+use frontend_proto::model::chip::{Chip, Radio};
+use frontend_proto::model::{Device, State};
+use protobuf::Message;
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::pin::Pin;
+
+use crate::ffi::{get_devices_bytes, CxxServerResponseWriter, Point, RadioLink};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::processor::impairment::{impairments, Impairment, Impairments};
+use crate::processor::processor_names;
+use crate::propagation::{model, PropagationModel};
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+// Returns the named radios of the chips of `chip_kind` of a device.
+fn radios(device: &Device, chip_kind: ChipKind) -> Vec<(&'static str, &Radio)> {
+    let mut radios = Vec::new();
+    for chip in device.chips.iter().filter(|chip| chip.kind.enum_value_or_default() == chip_kind) {
+        match &chip.chip {
+            Some(Chip::Bt(bt)) => {
+                radios.push(("Bluetooth low energy", bt.low_energy.get_or_default()));
+                radios.push(("Bluetooth classic", bt.classic.get_or_default()));
+            }
+            Some(Chip::Wifi(radio)) => radios.push(("Wi-Fi", radio)),
+            Some(Chip::Uwb(radio)) => radios.push(("UWB", radio)),
+            Some(Chip::Nfc(nfc)) => radios.push(("NFC", nfc.radio.get_or_default())),
+            _ => {}
+        }
+    }
+    radios
+}
+
+fn point(device: &Device) -> Point {
+    let position = device.position.get_or_default();
+    Point { x: position.x, y: position.y, z: position.z }
+}
+
+/// Returns the link from `device_a` to `device_b` of the request.
+///
+/// `processors` are the names of the packet processors of the chip kind,
+/// `impairments` the link impairments and `model` the propagation model of
+/// netsimd.
+pub fn get_link(
+    request: &GetLinkRequest,
+    devices: &[Device],
+    processors: &[String],
+    impairments: &Impairments,
+    model: &dyn PropagationModel,
+) -> Result<GetLinkResponse, String> {
+    let chip_kind = request.chip_kind.enum_value_or_default();
+    let find = |name: &str| {
+        devices
+            .iter()
+            .find(|device| device.name == name)
+            .ok_or_else(|| format!("Device {name} not found"))
+    };
+    let a = find(&request.device_a)?;
+    let b = find(&request.device_b)?;
+    let mut response = GetLinkResponse::new();
+    let mut connected = true;
+    for device in [a, b] {
+        let radios = radios(device, chip_kind);
+        if radios.is_empty() {
+            return Err(format!("Device {} has no {:?} chip", device.name, chip_kind));
+        }
+        for (name, _) in
+            radios.iter().filter(|(_, radio)| radio.state.enum_value_or_default() == State::OFF)
+        {
+            response.impairments.push(LinkImpairment {
+                kind: Kind::RADIO_OFF.into(),
+                device_name: device.name.clone(),
+                description: format!("{name} radio is off"),
+                ..Default::default()
+            });
+        }
+        // The device neither sends nor receives with all its radios off.
+        connected &=
+            radios.iter().any(|(_, radio)| radio.state.enum_value_or_default() != State::OFF);
+    }
+    for name in processors {
+        response.impairments.push(LinkImpairment {
+            kind: Kind::PACKET_PROCESSOR.into(),
+            description: format!("Packet processor {name} may drop or modify packets"),
+            ..Default::default()
+        });
+    }
+    // The packets from a to b are dropped and delayed by the impairments of
+    // both chips, and by the impairment of the link between Bluetooth chips.
+    let mut delivery_probability = 1.0;
+    let mut impair = |impairment: &Impairment, device_name: &str, packets: &str| {
+        if impairment.drop_percent > 0.0 {
+            response.impairments.push(LinkImpairment {
+                kind: Kind::PACKET_DROP.into(),
+                device_name: device_name.to_string(),
+                description: format!(
+                    "Link impairment drops {}% of the {packets}",
+                    impairment.drop_percent
+                ),
+                ..Default::default()
+            });
+            delivery_probability *= 1.0 - impairment.drop_percent / 100.0;
+        }
+        if impairment.is_delayed() {
+            response.impairments.push(LinkImpairment {
+                kind: Kind::PACKET_DELAY.into(),
+                device_name: device_name.to_string(),
+                description: format!(
+                    "Link impairment delays the {packets} by {} ms with {} ms of {:?} jitter",
+                    impairment.latency.as_millis(),
+                    impairment.jitter.as_millis(),
+                    impairment.distribution
+                ),
+                ..Default::default()
+            });
+        }
+    };
+    let chip_ids = |device: &Device| -> Vec<i32> {
+        device
+            .chips
+            .iter()
+            .filter(|chip| chip.kind.enum_value_or_default() == chip_kind)
+            .map(|chip| chip.id)
+            .collect()
+    };
+    for device in [a, b] {
+        for chip_id in chip_ids(device) {
+            if let Some(impairment) = impairments.get(chip_id, 0) {
+                impair(impairment, &device.name, "packets");
+            }
+        }
+    }
+    for chip_a in chip_ids(a).into_iter().filter(|_| chip_kind == ChipKind::BLUETOOTH) {
+        for chip_b in chip_ids(b) {
+            if let Some(impairment) = impairments.get(chip_a, chip_b) {
+                impair(impairment, "", "packets between the devices");
+            }
+        }
+    }
+    let tx_power = request.tx_power.clamp(i8::MIN as i32, i8::MAX as i32) as i8;
+    let link = RadioLink {
+        chip_kind: chip_kind as u32,
+        sender_id: a.id as u32,
+        receiver_id: b.id as u32,
+        sender: point(a),
+        receiver: point(b),
+    };
+    response.distance = link.distance();
+    response.path_loss = model.path_loss(&link);
+    response.rssi = (tx_power as f32 - response.path_loss) as i8 as i32;
+    response.delivery_probability = if connected { delivery_probability } else { 0.0 };
+    Ok(response)
+}
+
+/// The Rust link handler used directly by Http frontend for POST
+pub fn handle_link(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "POST" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let body = String::from_utf8_lossy(&request.body);
+    let link_request = match protobuf_json_mapping::parse_from_str::<GetLinkRequest>(&body) {
+        Ok(link_request) => link_request,
+        Err(e) => {
+            writer.put_error(400, format!("Invalid link request: {e}").as_str());
+            return;
+        }
+    };
+    let mut vec = Vec::<u8>::new();
+    if !get_devices_bytes(&mut vec) {
+        writer.put_error(404, "Failed to get devices");
+        return;
+    }
+    let devices = GetDevicesResponse::parse_from_bytes(&vec).map(|r| r.devices).unwrap_or_default();
+    let processors = processor_names(link_request.chip_kind.enum_value_or_default());
+    let result = get_link(&link_request, &devices, &processors, &impairments(), model().as_ref());
+    match result {
+        Ok(response) => {
+            if let Ok(json_response) = print_to_string_with_options(&response, &JSON_PRINT_OPTION) {
+                writer.put_ok("text/json", &json_response, &[])
+            } else {
+                writer.put_error(404, "proto to JSON mapping failure")
+            }
+        }
+        Err(e) => writer.put_error(404, e.as_str()),
+    }
+}
+
+/// link handle cxx for grpc server to call
+pub fn handle_link_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/link".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_link(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::model::chip::Bluetooth;
+    use frontend_proto::model::{Chip as ChipProto, Position};
+    use std::time::Duration;
+
+    use crate::propagation::FreeSpace;
+    use crate::ranging::{distance_to_rssi, path_loss};
+
+    fn device(name: &str, x: f32, ble_state: State) -> Device {
+        let mut bt = Bluetooth::new();
+        bt.low_energy.mut_or_insert_default().state = ble_state.into();
+        bt.classic.mut_or_insert_default().state = State::OFF.into();
+        let mut chip = ChipProto { kind: ChipKind::BLUETOOTH.into(), ..Default::default() };
+        chip.set_bt(bt);
+        Device {
+            name: name.to_string(),
+            position: Some(Position { x, ..Default::default() }).into(),
+            chips: vec![chip],
+            ..Default::default()
+        }
+    }
+
+    fn request(chip_kind: ChipKind) -> GetLinkRequest {
+        GetLinkRequest {
+            device_a: "a".to_string(),
+            device_b: "b".to_string(),
+            chip_kind: chip_kind.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_link() {
+        let devices = [device("a", 0.0, State::ON), device("b", 10.0, State::ON)];
+        let response = get_link(
+            &request(ChipKind::BLUETOOTH),
+            &devices,
+            &[],
+            &Impairments::default(),
+            &FreeSpace,
+        )
+        .unwrap();
+        assert_eq!(response.distance, 10.0);
+        assert_eq!(response.path_loss, path_loss(10.0));
+        assert_eq!(response.rssi, distance_to_rssi(0, 10.0) as i32);
+        assert_eq!(response.delivery_probability, 1.0);
+        // The classic radios are off on both devices.
+        assert_eq!(response.impairments.len(), 2);
+        assert!(response
+            .impairments
+            .iter()
+            .all(|i| i.kind.enum_value_or_default() == Kind::RADIO_OFF));
+    }
+
+    #[test]
+    fn test_get_link_impaired() {
+        let devices = [device("a", 0.0, State::ON), device("b", 0.0, State::OFF)];
+        let response = get_link(
+            &request(ChipKind::BLUETOOTH),
+            &devices,
+            &["drop".to_string()],
+            &Impairments::default(),
+            &FreeSpace,
+        )
+        .unwrap();
+        assert_eq!((response.distance, response.path_loss, response.rssi), (0.0, 0.0, 0));
+        assert_eq!(response.delivery_probability, 0.0);
+        assert_eq!(response.impairments.len(), 4);
+        assert_eq!(response.impairments[2].device_name, "b");
+        assert_eq!(response.impairments[3].kind.enum_value_or_default(), Kind::PACKET_PROCESSOR);
+    }
+
+    #[test]
+    fn test_get_link_dropped() {
+        let mut devices = [device("a", 0.0, State::ON), device("b", 1.0, State::ON)];
+        devices[0].chips[0].id = 1;
+        devices[1].chips[0].id = 2;
+        let mut impairments = Impairments::default();
+        let dropping = |drop_percent| Impairment { drop_percent, ..Default::default() };
+        impairments.set(2, 0, dropping(50.0), 1);
+        impairments.set(1, 2, dropping(20.0), 1);
+        impairments.set(
+            1,
+            0,
+            Impairment { latency: Duration::from_millis(30), ..Default::default() },
+            1,
+        );
+        let response =
+            get_link(&request(ChipKind::BLUETOOTH), &devices, &[], &impairments, &FreeSpace)
+                .unwrap();
+        assert!((response.delivery_probability - 0.4).abs() < 1e-6);
+        let drops: Vec<&str> = response
+            .impairments
+            .iter()
+            .filter(|i| i.kind.enum_value_or_default() == Kind::PACKET_DROP)
+            .map(|i| i.device_name.as_str())
+            .collect();
+        assert_eq!(drops, ["b", ""]);
+        let delays: Vec<&str> = response
+            .impairments
+            .iter()
+            .filter(|i| i.kind.enum_value_or_default() == Kind::PACKET_DELAY)
+            .map(|i| i.device_name.as_str())
+            .collect();
+        assert_eq!(delays, ["a"]);
+    }
+
+    #[test]
+    fn test_get_link_errors() {
+        let devices = [device("a", 0.0, State::ON), device("b", 1.0, State::ON)];
+        assert!(get_link(
+            &request(ChipKind::WIFI),
+            &devices,
+            &[],
+            &Impairments::default(),
+            &FreeSpace
+        )
+        .is_err());
+        assert!(get_link(
+            &request(ChipKind::BLUETOOTH),
+            &devices[..1],
+            &[],
+            &Impairments::default(),
+            &FreeSpace
+        )
+        .is_err());
+    }
+}
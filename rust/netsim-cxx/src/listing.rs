@@ -0,0 +1,299 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Filtering and pagination of the lists of devices and captures.
+//!
+//! GetDevices and ListCapture, and GET of /v1/devices and /v1/captures,
+//! list the items selected by the filters of their request by increasing
+//! id. With a page_size the list is cut into pages: the next_page_token of
+//! a page is the id of its last item, and the page_token of the request of
+//! the next page, which lists the items with a greater id.
+//!
+//! The REST requests take the fields of the request as query parameters,
+//! like /v1/devices?name_filter=pixel&chip_kind=BLUETOOTH&page_size=10.
+//!
+//! /v1/devices --> handle_list_devices (GET a GetDevicesRequest)
+
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::{
+    GetDevicesRequest, GetDevicesResponse, ListCaptureRequest, ListCaptureResponse,
+};
+use frontend_proto::model::{Capture, Device, State};
+use protobuf::{Message, MessageFull};
+use std::pin::Pin;
+
+use crate::ffi::{get_devices_bytes, CxxServerResponseWriter};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::CxxServerResponseWriterWrapper;
+
+// Quotes a string as a JSON string.
+fn json_string(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Parses the list request of an http request, from its JSON body or else
+/// from the query parameters of its uri.
+pub fn list_request<M: MessageFull>(request: &HttpRequest) -> Result<M, String> {
+    let json = if request.body.is_empty() {
+        // Quoted values are parsed as numbers and enums as well.
+        let fields: Vec<String> = request
+            .query_params()
+            .iter()
+            .map(|(name, value)| format!("{}:{}", json_string(name), json_string(value)))
+            .collect();
+        format!("{{{}}}", fields.join(","))
+    } else {
+        String::from_utf8_lossy(&request.body).into_owned()
+    };
+    protobuf_json_mapping::parse_from_str::<M>(&json)
+        .map_err(|e| format!("Invalid list request: {e}"))
+}
+
+/// Returns the page of the items after the page token by increasing id,
+/// with the token of the next page, empty on the last page.
+pub fn paginate<T>(
+    mut items: Vec<T>,
+    id: impl Fn(&T) -> i32,
+    page_size: u32,
+    page_token: &str,
+) -> Result<(Vec<T>, String), String> {
+    let after = match page_token {
+        "" => i32::MIN,
+        token => token.parse::<i32>().map_err(|_| format!("Invalid page_token: {token}"))?,
+    };
+    items.sort_by_key(&id);
+    items.retain(|item| id(item) > after);
+    if page_size == 0 || items.len() <= page_size as usize {
+        return Ok((items, String::new()));
+    }
+    items.truncate(page_size as usize);
+    let next_page_token = items.last().map(|item| id(item).to_string()).unwrap_or_default();
+    Ok((items, next_page_token))
+}
+
+/// Lists the devices selected by a request.
+pub fn list_devices(
+    devices: Vec<Device>,
+    request: &GetDevicesRequest,
+) -> Result<GetDevicesResponse, String> {
+    let chip_kind = request.chip_kind.enum_value_or_default();
+    let devices = devices
+        .into_iter()
+        .filter(|device| {
+            device.name.contains(&request.name_filter)
+                && (chip_kind == ChipKind::UNSPECIFIED
+                    || device
+                        .chips
+                        .iter()
+                        .any(|chip| chip.kind.enum_value_or_default() == chip_kind))
+        })
+        .collect();
+    let (devices, next_page_token) =
+        paginate(devices, |device| device.id, request.page_size, &request.page_token)?;
+    Ok(GetDevicesResponse { devices, next_page_token, ..Default::default() })
+}
+
+/// Lists the captures selected by a request.
+pub fn list_captures(
+    captures: Vec<Capture>,
+    request: &ListCaptureRequest,
+) -> Result<ListCaptureResponse, String> {
+    let chip_kind = request.chip_kind.enum_value_or_default();
+    let state = request.state.enum_value_or_default();
+    let captures = captures
+        .into_iter()
+        .filter(|capture| {
+            capture.device_name.contains(&request.device_name_filter)
+                && (chip_kind == ChipKind::UNSPECIFIED
+                    || capture.chip_kind.enum_value_or_default() == chip_kind)
+                && (state == State::UNKNOWN || capture.state.enum_value_or_default() == state)
+        })
+        .collect();
+    let (captures, next_page_token) =
+        paginate(captures, |capture| capture.id, request.page_size, &request.page_token)?;
+    Ok(ListCaptureResponse { captures, next_page_token, ..Default::default() })
+}
+
+// Returns the devices of the scene.
+fn scene_devices() -> Vec<Device> {
+    let mut vec = Vec::<u8>::new();
+    if !get_devices_bytes(&mut vec) {
+        return Vec::new();
+    }
+    GetDevicesResponse::parse_from_bytes(&vec).map(|r| r.devices).unwrap_or_default()
+}
+
+/// The Rust device list handler used directly by Http frontend for GET
+pub fn handle_list_devices(request: &HttpRequest, writer: ResponseWritable) {
+    let response = list_request::<GetDevicesRequest>(request)
+        .and_then(|list_request| list_devices(scene_devices(), &list_request));
+    match response {
+        Ok(response) => match protobuf_json_mapping::print_to_string(&response) {
+            Ok(json) => writer.put_ok("text/json", &json, &[]),
+            Err(e) => writer.put_error(500, e.to_string().as_str()),
+        },
+        Err(e) => writer.put_error(400, e.as_str()),
+    }
+}
+
+/// device list handle cxx for grpc server to call
+pub fn handle_list_devices_cxx(responder: Pin<&mut CxxServerResponseWriter>, body: String) {
+    let request = HttpRequest {
+        method: "GET".to_string(),
+        uri: "/v1/devices".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_list_devices(&request, &mut CxxServerResponseWriterWrapper { writer: responder });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::model::Chip;
+
+    fn device(id: i32, name: &str, kind: ChipKind) -> Device {
+        Device {
+            id,
+            name: name.to_string(),
+            chips: vec![Chip { kind: kind.into(), ..Default::default() }],
+            ..Default::default()
+        }
+    }
+
+    fn capture(id: i32, device_name: &str, kind: ChipKind, state: State) -> Capture {
+        Capture {
+            id,
+            device_name: device_name.to_string(),
+            chip_kind: kind.into(),
+            state: state.into(),
+            ..Default::default()
+        }
+    }
+
+    fn http_request(uri: &str, body: &str) -> HttpRequest {
+        HttpRequest {
+            method: "GET".to_string(),
+            uri: uri.to_string(),
+            headers: HttpHeaders::new(),
+            version: "1.1".to_string(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_paginate() {
+        let items = vec![4, 1, 3, 2, 5];
+        let id = |item: &i32| *item;
+        assert_eq!(
+            paginate(items.clone(), id, 0, "").unwrap(),
+            (vec![1, 2, 3, 4, 5], String::new())
+        );
+        assert_eq!(paginate(items.clone(), id, 2, "").unwrap(), (vec![1, 2], "2".to_string()));
+        assert_eq!(paginate(items.clone(), id, 2, "2").unwrap(), (vec![3, 4], "4".to_string()));
+        assert_eq!(paginate(items.clone(), id, 2, "4").unwrap(), (vec![5], String::new()));
+        // The next page skips the items removed since the previous page.
+        assert_eq!(paginate(vec![1, 5], id, 2, "2").unwrap(), (vec![5], String::new()));
+        assert!(paginate(items, id, 2, "next").is_err());
+    }
+
+    #[test]
+    fn test_list_devices() {
+        let devices = vec![
+            device(3, "pixel-2", ChipKind::BLUETOOTH),
+            device(1, "pixel-1", ChipKind::WIFI),
+            device(2, "watch", ChipKind::BLUETOOTH),
+        ];
+        let names = |response: GetDevicesResponse| -> Vec<String> {
+            response.devices.into_iter().map(|device| device.name).collect()
+        };
+        let request = GetDevicesRequest::new();
+        assert_eq!(
+            names(list_devices(devices.clone(), &request).unwrap()),
+            ["pixel-1", "watch", "pixel-2"]
+        );
+        let request = GetDevicesRequest { name_filter: "pixel".to_string(), ..Default::default() };
+        assert_eq!(names(list_devices(devices.clone(), &request).unwrap()), ["pixel-1", "pixel-2"]);
+        let request = GetDevicesRequest {
+            chip_kind: ChipKind::BLUETOOTH.into(),
+            page_size: 1,
+            ..Default::default()
+        };
+        let response = list_devices(devices.clone(), &request).unwrap();
+        assert_eq!(response.next_page_token, "2");
+        assert_eq!(names(response), ["watch"]);
+        let request = GetDevicesRequest { page_token: "2".to_string(), ..request };
+        let response = list_devices(devices, &request).unwrap();
+        assert_eq!(response.next_page_token, "");
+        assert_eq!(names(response), ["pixel-2"]);
+    }
+
+    #[test]
+    fn test_list_captures() {
+        let captures = vec![
+            capture(1, "pixel", ChipKind::BLUETOOTH, State::ON),
+            capture(2, "pixel", ChipKind::WIFI, State::OFF),
+            capture(3, "watch", ChipKind::BLUETOOTH, State::OFF),
+        ];
+        let ids = |request: &ListCaptureRequest| -> Vec<i32> {
+            let response = list_captures(captures.clone(), request).unwrap();
+            response.captures.iter().map(|capture| capture.id).collect()
+        };
+        assert_eq!(ids(&ListCaptureRequest::new()), [1, 2, 3]);
+        let request = ListCaptureRequest { state: State::OFF.into(), ..Default::default() };
+        assert_eq!(ids(&request), [2, 3]);
+        let request =
+            ListCaptureRequest { chip_kind: ChipKind::BLUETOOTH.into(), ..Default::default() };
+        assert_eq!(ids(&request), [1, 3]);
+        let request =
+            ListCaptureRequest { device_name_filter: "pix".to_string(), ..Default::default() };
+        assert_eq!(ids(&request), [1, 2]);
+    }
+
+    #[test]
+    fn test_list_request() {
+        let request = http_request(
+            "/v1/devices?name_filter=my+%22phone%22&chip_kind=BLUETOOTH&page_size=10&page_token=3",
+            "",
+        );
+        let devices_request = list_request::<GetDevicesRequest>(&request).unwrap();
+        assert_eq!(devices_request.name_filter, "my \"phone\"");
+        assert_eq!(devices_request.chip_kind.enum_value_or_default(), ChipKind::BLUETOOTH);
+        assert_eq!(devices_request.page_size, 10);
+        assert_eq!(devices_request.page_token, "3");
+        let request = http_request("/v1/captures", r#"{"state": "ON", "pageSize": 2}"#);
+        let captures_request = list_request::<ListCaptureRequest>(&request).unwrap();
+        assert_eq!(captures_request.state.enum_value_or_default(), State::ON);
+        assert_eq!(captures_request.page_size, 2);
+        assert_eq!(
+            list_request::<GetDevicesRequest>(&http_request("/v1/devices", "")).unwrap(),
+            GetDevicesRequest::new()
+        );
+        assert!(list_request::<GetDevicesRequest>(&http_request("/v1/devices?size=1", "")).is_err());
+        assert!(list_request::<GetDevicesRequest>(&http_request("/v1/devices?page_size=a", ""))
+            .is_err());
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Structured logging.
+//!
+//! The modules of netsimd log with the error!, warn!, info! and debug!
+//! macros, optionally with the chip of the message:
+//!
+//! warn!(chip_id: id; "unable to restart: {e}")
+//!
+//! Each line carries the level, the module and the chip, as text or, with
+//! the log.json setting, as one JSON object per line, so the logs of
+//! netsimd are parsed on the emulator host. The lines below the log.level
+//! setting are dropped; the level is also set at runtime:
+//!
+//! /v1/debug/log --> handle_log (GET the level, PATCH a level)
+
+use std::fmt::{Arguments, Write as _};
+use std::io::Write;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::get_setting;
+use crate::ffi::CxxServerResponseWriter;
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::CxxServerResponseWriterWrapper;
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum Level {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+    Debug = 3,
+}
+
+impl Level {
+    /// Returns the level of its name in the log.level setting.
+    pub fn parse(name: &str) -> Option<Level> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "error" => Some(Level::Error),
+            "warn" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            _ => None,
+        }
+    }
+
+    fn from_u8(value: u8) -> Level {
+        match value {
+            0 => Level::Error,
+            1 => Level::Warn,
+            2 => Level::Info,
+            _ => Level::Debug,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+        }
+    }
+}
+
+// The logger doesn't read the settings as it logs, since the settings log
+// their own errors as they are resolved.
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+static JSON: AtomicBool = AtomicBool::new(false);
+
+/// Applies the log.level and log.json settings.
+pub fn init_logger() {
+    set_level(Level::parse(&get_setting("log.level")).unwrap_or(Level::Info));
+    JSON.store(get_setting("log.json") == "true", Ordering::Relaxed);
+}
+
+/// Sets the level of the logged lines.
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Returns the level of the logged lines.
+pub fn level() -> Level {
+    Level::from_u8(LEVEL.load(Ordering::Relaxed))
+}
+
+/// Returns true when the lines of `level` are logged.
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+// Appends a JSON string, with its quotes.
+fn push_json_string(line: &mut String, value: &str) {
+    line.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => line.push_str("\\\""),
+            '\\' => line.push_str("\\\\"),
+            '\n' => line.push_str("\\n"),
+            '\r' => line.push_str("\\r"),
+            '\t' => line.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(line, "\\u{:04x}", c as u32);
+            }
+            c => line.push(c),
+        }
+    }
+    line.push('"');
+}
+
+/// Formats a log line, without its newline.
+pub fn format_line(
+    level: Level,
+    module: &str,
+    chip_id: Option<i64>,
+    message: &str,
+    json: bool,
+    time: f64,
+) -> String {
+    let module = module.strip_prefix("netsim_cxx::").unwrap_or(module);
+    let mut line = String::new();
+    if json {
+        let _ = write!(line, "{{\"time\":{time:.3},\"level\":");
+        push_json_string(&mut line, level.name());
+        line.push_str(",\"module\":");
+        push_json_string(&mut line, module);
+        if let Some(chip_id) = chip_id {
+            let _ = write!(line, ",\"chip_id\":{chip_id}");
+        }
+        line.push_str(",\"message\":");
+        push_json_string(&mut line, message);
+        line.push('}');
+    } else {
+        let _ = write!(line, "netsimd: {} {module}", level.name().to_ascii_uppercase());
+        if let Some(chip_id) = chip_id {
+            let _ = write!(line, " chip_id={chip_id}");
+        }
+        let _ = write!(line, ": {message}");
+    }
+    line
+}
+
+/// Writes a log line to stderr, used by the logging macros.
+pub fn emit(level: Level, module: &str, chip_id: Option<i64>, message: Arguments) {
+    if !enabled(level) {
+        return;
+    }
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs_f64();
+    let json = JSON.load(Ordering::Relaxed);
+    let line = format_line(level, module, chip_id, &message.to_string(), json, time);
+    let _ = writeln!(std::io::stderr().lock(), "{line}");
+}
+
+macro_rules! log {
+    ($level:expr, chip_id: $chip_id:expr; $($arg:tt)+) => {
+        $crate::logger::emit($level, module_path!(), Some($chip_id as i64), format_args!($($arg)+))
+    };
+    ($level:expr, $($arg:tt)+) => {
+        $crate::logger::emit($level, module_path!(), None, format_args!($($arg)+))
+    };
+}
+
+macro_rules! log_error {
+    ($($arg:tt)+) => { $crate::logger::log!($crate::logger::Level::Error, $($arg)+) };
+}
+
+macro_rules! log_warn {
+    ($($arg:tt)+) => { $crate::logger::log!($crate::logger::Level::Warn, $($arg)+) };
+}
+
+macro_rules! log_info {
+    ($($arg:tt)+) => { $crate::logger::log!($crate::logger::Level::Info, $($arg)+) };
+}
+
+macro_rules! log_debug {
+    ($($arg:tt)+) => { $crate::logger::log!($crate::logger::Level::Debug, $($arg)+) };
+}
+
+// The macros are defined under other names since `warn` is also an attribute.
+pub(crate) use {log, log_debug as debug, log_error as error, log_info as info, log_warn as warn};
+
+/// The Rust log handler used directly by Http frontend for GET and PATCH
+pub fn handle_log(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    match request.method.as_str() {
+        "GET" => {}
+        "PATCH" => match Level::parse(&String::from_utf8_lossy(&request.body)) {
+            Some(level) => set_level(level),
+            None => {
+                writer.put_error(400, "Invalid log level, expected error, warn, info or debug");
+                return;
+            }
+        },
+        _ => {
+            writer.put_error(404, "Not found.");
+            return;
+        }
+    }
+    let body =
+        format!("{{\"level\":\"{}\",\"json\":{}}}", level().name(), JSON.load(Ordering::Relaxed));
+    writer.put_ok("text/json", &body, &[]);
+}
+
+/// log handle cxx for grpc server to call
+pub fn handle_log_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/debug/log".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_log(&request, param.as_str(), &mut CxxServerResponseWriterWrapper { writer: responder });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level() {
+        assert_eq!(Level::parse(" WARN "), Some(Level::Warn));
+        assert_eq!(Level::parse("verbose"), None);
+        assert!(Level::Error < Level::Debug);
+    }
+
+    #[test]
+    fn test_format_line() {
+        let module = "netsim_cxx::captures::capture";
+        assert_eq!(
+            format_line(Level::Warn, module, Some(7), "disk full", false, 0.0),
+            "netsimd: WARN captures::capture chip_id=7: disk full"
+        );
+        assert_eq!(
+            format_line(Level::Info, "netsim_cxx::state", None, "a \"b\"\n", true, 1.5),
+            r#"{"time":1.500,"level":"info","module":"state","message":"a \"b\"\n"}"#
+        );
+        assert_eq!(
+            format_line(Level::Error, module, Some(3), "x", true, 0.0),
+            r#"{"time":0.000,"level":"error","module":"captures::capture","chip_id":3,"message":"x"}"#
+        );
+    }
+}
@@ -0,0 +1,541 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Memory accounting for in-memory buffers.
+//!
+//! Buffers such as capture ring buffers, the event history and pending
+//! packet queues charge their bytes to a memory pool. A charge fails when it
+//! would exceed the limit of the pool or the global `memory.limit` setting;
+//! the buffer then applies the drop policy of the pool.
+
+use crate::config::{get_setting, get_setting_parsed};
+use crate::logger::warn;
+use crate::metrics::{register_collector, MetricKind, Sample};
+use lazy_static::lazy_static;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// The memory pools, one for each kind of buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pool {
+    Captures,
+    Events,
+    Packets,
+}
+
+impl Pool {
+    pub const ALL: [Pool; 3] = [Pool::Captures, Pool::Events, Pool::Packets];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Pool::Captures => "captures",
+            Pool::Events => "events",
+            Pool::Packets => "packets",
+        }
+    }
+}
+
+/// What a buffer drops when its pool is out of budget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropPolicy {
+    /// Evict the oldest buffered items to make room for the new one.
+    DropOldest,
+    /// Reject the new item and keep the buffered ones.
+    DropNewest,
+}
+
+impl DropPolicy {
+    fn from_setting(value: &str) -> DropPolicy {
+        match value {
+            "newest" => DropPolicy::DropNewest,
+            "oldest" => DropPolicy::DropOldest,
+            _ => {
+                warn!("unknown drop policy {value}, using oldest");
+                DropPolicy::DropOldest
+            }
+        }
+    }
+}
+
+/// Current usage of a pool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolUsage {
+    pub used_bytes: usize,
+    pub limit_bytes: usize,
+    pub dropped_items: u64,
+    pub dropped_bytes: u64,
+}
+
+struct PoolAccount {
+    limit: usize,
+    policy: DropPolicy,
+    used: AtomicUsize,
+    dropped_items: AtomicU64,
+    dropped_bytes: AtomicU64,
+}
+
+impl PoolAccount {
+    fn new(limit: usize, policy: DropPolicy) -> PoolAccount {
+        PoolAccount {
+            limit,
+            policy,
+            used: AtomicUsize::new(0),
+            dropped_items: AtomicU64::new(0),
+            dropped_bytes: AtomicU64::new(0),
+        }
+    }
+}
+
+/// Tracks the bytes held by each pool against the configured limits.
+pub struct Accountant {
+    limit: usize,
+    used: AtomicUsize,
+    pools: [PoolAccount; 3],
+}
+
+lazy_static! {
+    static ref ACCOUNTANT: Arc<Accountant> = {
+        register_collector(collect_metrics);
+        Arc::new(Accountant::from_settings())
+    };
+}
+
+// Adds `bytes` to `counter` unless that exceeds a non-zero `limit`.
+fn reserve(counter: &AtomicUsize, limit: usize, bytes: usize) -> bool {
+    counter
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| match used.checked_add(bytes) {
+            Some(total) if limit == 0 || total <= limit => Some(total),
+            _ => None,
+        })
+        .is_ok()
+}
+
+impl Accountant {
+    /// Creates an accountant with a global limit and per pool limits and
+    /// policies, in `Pool::ALL` order. A limit of 0 is unlimited.
+    pub fn new(limit: usize, pools: [(usize, DropPolicy); 3]) -> Accountant {
+        Accountant {
+            limit,
+            used: AtomicUsize::new(0),
+            pools: pools.map(|(limit, policy)| PoolAccount::new(limit, policy)),
+        }
+    }
+
+    fn from_settings() -> Accountant {
+        let pools = Pool::ALL.map(|pool| {
+            let name = pool.name();
+            (
+                get_setting_parsed(&format!("memory.{name}.limit")),
+                DropPolicy::from_setting(&get_setting(&format!("memory.{name}.drop_policy"))),
+            )
+        });
+        Accountant::new(get_setting_parsed("memory.limit"), pools)
+    }
+
+    fn account(&self, pool: Pool) -> &PoolAccount {
+        &self.pools[pool as usize]
+    }
+
+    /// Charges `bytes` to `pool`, returning false if that would exceed the
+    /// pool limit or the global limit.
+    pub fn try_charge(&self, pool: Pool, bytes: usize) -> bool {
+        let account = self.account(pool);
+        if !reserve(&account.used, account.limit, bytes) {
+            return false;
+        }
+        if !reserve(&self.used, self.limit, bytes) {
+            account.used.fetch_sub(bytes, Ordering::SeqCst);
+            return false;
+        }
+        true
+    }
+
+    /// Returns `bytes` previously charged to `pool`.
+    pub fn release(&self, pool: Pool, bytes: usize) {
+        self.account(pool).used.fetch_sub(bytes, Ordering::SeqCst);
+        self.used.fetch_sub(bytes, Ordering::SeqCst);
+    }
+
+    /// Records an item of `bytes` dropped from `pool`.
+    pub fn record_drop(&self, pool: Pool, bytes: usize) {
+        let account = self.account(pool);
+        account.dropped_items.fetch_add(1, Ordering::SeqCst);
+        account.dropped_bytes.fetch_add(bytes as u64, Ordering::SeqCst);
+    }
+
+    /// Charges an item of `bytes` to the buffer of a pool, applying the
+    /// drop policy of the pool when it is out of budget: `evict` removes the
+    /// oldest item of the buffer and returns its size. Returns false if the
+    /// new item is dropped.
+    pub fn charge(
+        &self,
+        pool: Pool,
+        bytes: usize,
+        mut evict: impl FnMut() -> Option<usize>,
+    ) -> bool {
+        while !self.try_charge(pool, bytes) {
+            let evicted = match self.drop_policy(pool) {
+                DropPolicy::DropOldest => evict(),
+                DropPolicy::DropNewest => None,
+            };
+            match evicted {
+                Some(oldest) => {
+                    self.release(pool, oldest);
+                    self.record_drop(pool, oldest);
+                }
+                None => {
+                    self.record_drop(pool, bytes);
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    pub fn drop_policy(&self, pool: Pool) -> DropPolicy {
+        self.account(pool).policy
+    }
+
+    pub fn usage(&self, pool: Pool) -> PoolUsage {
+        let account = self.account(pool);
+        PoolUsage {
+            used_bytes: account.used.load(Ordering::SeqCst),
+            limit_bytes: account.limit,
+            dropped_items: account.dropped_items.load(Ordering::SeqCst),
+            dropped_bytes: account.dropped_bytes.load(Ordering::SeqCst),
+        }
+    }
+
+    pub fn used_bytes(&self) -> usize {
+        self.used.load(Ordering::SeqCst)
+    }
+
+    pub fn limit_bytes(&self) -> usize {
+        self.limit
+    }
+}
+
+/// Returns the accountant of the netsimd process.
+pub fn accountant() -> Arc<Accountant> {
+    ACCOUNTANT.clone()
+}
+
+fn collect_metrics() -> Vec<Sample> {
+    let accountant = accountant();
+    let gauge = |name, help, labels, value: usize| Sample {
+        name,
+        help,
+        kind: MetricKind::Gauge,
+        labels,
+        value: value as f64,
+    };
+    let mut samples = vec![
+        gauge(
+            "netsim_memory_used_bytes",
+            "Bytes held by in-memory buffers.",
+            vec![],
+            accountant.used_bytes(),
+        ),
+        gauge(
+            "netsim_memory_limit_bytes",
+            "Limit of in-memory buffers; 0 is unlimited.",
+            vec![],
+            accountant.limit_bytes(),
+        ),
+    ];
+    for pool in Pool::ALL {
+        let usage = accountant.usage(pool);
+        let labels = vec![("pool", pool.name().to_string())];
+        samples.push(gauge(
+            "netsim_memory_pool_used_bytes",
+            "Bytes held by the buffers of a pool.",
+            labels.clone(),
+            usage.used_bytes,
+        ));
+        samples.push(gauge(
+            "netsim_memory_pool_limit_bytes",
+            "Limit of a pool; 0 is only bounded by the global limit.",
+            labels.clone(),
+            usage.limit_bytes,
+        ));
+        samples.push(Sample {
+            name: "netsim_memory_pool_dropped_items_total",
+            help: "Items dropped because a pool was out of budget.",
+            kind: MetricKind::Counter,
+            labels: labels.clone(),
+            value: usage.dropped_items as f64,
+        });
+        samples.push(Sample {
+            name: "netsim_memory_pool_dropped_bytes_total",
+            help: "Bytes dropped because a pool was out of budget.",
+            kind: MetricKind::Counter,
+            labels,
+            value: usage.dropped_bytes as f64,
+        });
+    }
+    samples
+}
+
+/// A FIFO buffer whose bytes are charged to a memory pool.
+pub struct BoundedBuffer<T> {
+    pool: Pool,
+    accountant: Arc<Accountant>,
+    items: VecDeque<(T, usize)>,
+    bytes: usize,
+}
+
+impl<T> BoundedBuffer<T> {
+    /// Creates a buffer charged to `pool` of the netsimd accountant.
+    pub fn new(pool: Pool) -> BoundedBuffer<T> {
+        BoundedBuffer::with_accountant(pool, accountant())
+    }
+
+    pub fn with_accountant(pool: Pool, accountant: Arc<Accountant>) -> BoundedBuffer<T> {
+        BoundedBuffer { pool, accountant, items: VecDeque::new(), bytes: 0 }
+    }
+
+    /// Appends an item of `size` bytes, applying the drop policy of the pool
+    /// when it is out of budget. Returns false if the new item was dropped.
+    pub fn push(&mut self, item: T, size: usize) -> bool {
+        let evict = || {
+            let (_, oldest_size) = self.items.pop_front()?;
+            self.bytes -= oldest_size;
+            Some(oldest_size)
+        };
+        if !self.accountant.charge(self.pool, size, evict) {
+            return false;
+        }
+        self.items.push_back((item, size));
+        self.bytes += size;
+        true
+    }
+
+    /// Removes and returns the oldest item.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let (item, size) = self.items.pop_front()?;
+        self.release(size);
+        Some(item)
+    }
+
+    /// Removes and returns the item at `index`.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let (item, size) = self.items.remove(index)?;
+        self.release(size);
+        Some(item)
+    }
+
+    /// Returns the item at `index`, whose size is unchanged.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.items.get_mut(index).map(|(item, _)| item)
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.items.iter().map(|(item, _)| item)
+    }
+
+    /// Returns the items from `index` on, oldest first.
+    pub fn iter_from(&self, index: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.items.range(index.min(self.items.len())..).map(|(item, _)| item)
+    }
+
+    /// Returns the index of the first item for which `pred` is false, the
+    /// items being ordered with those matching `pred` first.
+    pub fn partition_point(&self, mut pred: impl FnMut(&T) -> bool) -> usize {
+        self.items.partition_point(|(item, _)| pred(item))
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the bytes currently charged by this buffer.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    pub fn clear(&mut self) {
+        self.accountant.release(self.pool, self.bytes);
+        self.items.clear();
+        self.bytes = 0;
+    }
+
+    fn release(&mut self, size: usize) {
+        self.accountant.release(self.pool, size);
+        self.bytes -= size;
+    }
+}
+
+impl<T> Drop for BoundedBuffer<T> {
+    fn drop(&mut self) {
+        self.accountant.release(self.pool, self.bytes);
+    }
+}
+
+/// Bytes of an item held outside of a buffer, e.g. a packet holding its
+/// transport, charged to a pool until dropped.
+pub struct Charge {
+    pool: Pool,
+    accountant: Arc<Accountant>,
+    bytes: usize,
+}
+
+impl Charge {
+    /// Charges `bytes` to `pool` of the netsimd accountant, None when the
+    /// pool is out of budget.
+    pub fn new(pool: Pool, bytes: usize) -> Option<Charge> {
+        Charge::with_accountant(pool, bytes, accountant())
+    }
+
+    pub fn with_accountant(
+        pool: Pool,
+        bytes: usize,
+        accountant: Arc<Accountant>,
+    ) -> Option<Charge> {
+        // Nothing can be evicted for the item.
+        accountant.charge(pool, bytes, || None).then(|| Charge { pool, accountant, bytes })
+    }
+}
+
+impl Drop for Charge {
+    fn drop(&mut self) {
+        self.accountant.release(self.pool, self.bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_accountant(limit: usize, pool_limit: usize) -> Arc<Accountant> {
+        Arc::new(Accountant::new(
+            limit,
+            [
+                (pool_limit, DropPolicy::DropOldest),
+                (pool_limit, DropPolicy::DropOldest),
+                (pool_limit, DropPolicy::DropNewest),
+            ],
+        ))
+    }
+
+    #[test]
+    fn test_try_charge() {
+        let accountant = test_accountant(100, 60);
+        assert!(accountant.try_charge(Pool::Captures, 60));
+        assert!(!accountant.try_charge(Pool::Captures, 1));
+        assert!(accountant.try_charge(Pool::Events, 40));
+        // The global limit is reached before the pool limit.
+        assert!(!accountant.try_charge(Pool::Events, 1));
+        assert_eq!(accountant.usage(Pool::Events).used_bytes, 40);
+        accountant.release(Pool::Captures, 60);
+        assert!(accountant.try_charge(Pool::Events, 20));
+        assert_eq!(accountant.used_bytes(), 60);
+    }
+
+    #[test]
+    fn test_unlimited() {
+        let accountant = test_accountant(0, 0);
+        assert!(accountant.try_charge(Pool::Packets, usize::MAX / 2));
+        assert!(!accountant.try_charge(Pool::Packets, usize::MAX));
+    }
+
+    #[test]
+    fn test_drop_oldest() {
+        let accountant = test_accountant(0, 30);
+        let mut buffer = BoundedBuffer::with_accountant(Pool::Events, accountant.clone());
+        for i in 0..5 {
+            assert!(buffer.push(i, 10));
+        }
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!(buffer.bytes(), 30);
+        let usage = accountant.usage(Pool::Events);
+        assert_eq!(usage.used_bytes, 30);
+        assert_eq!(usage.dropped_items, 2);
+        assert_eq!(usage.dropped_bytes, 20);
+    }
+
+    #[test]
+    fn test_drop_newest() {
+        let accountant = test_accountant(0, 30);
+        let mut buffer = BoundedBuffer::with_accountant(Pool::Packets, accountant.clone());
+        for i in 0..5 {
+            assert_eq!(buffer.push(i, 10), i < 3);
+        }
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(buffer.pop_front(), Some(0));
+        assert!(buffer.push(5, 10));
+        assert_eq!(accountant.usage(Pool::Packets).dropped_items, 2);
+    }
+
+    #[test]
+    fn test_remove() {
+        let accountant = test_accountant(0, 0);
+        let mut buffer = BoundedBuffer::with_accountant(Pool::Packets, accountant.clone());
+        for i in 0..3 {
+            buffer.push(i, 10);
+        }
+        *buffer.get_mut(0).unwrap() = 5;
+        assert_eq!(buffer.remove(1), Some(1));
+        assert_eq!(buffer.remove(2), None);
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![5, 2]);
+        assert_eq!(accountant.usage(Pool::Packets).used_bytes, 20);
+    }
+
+    #[test]
+    fn test_partition_point() {
+        let mut buffer = BoundedBuffer::with_accountant(Pool::Events, test_accountant(0, 0));
+        for i in [1, 3, 5, 7] {
+            buffer.push(i, 1);
+        }
+        let index = buffer.partition_point(|i| *i <= 3);
+        assert_eq!(index, 2);
+        assert_eq!(buffer.iter_from(index).copied().collect::<Vec<_>>(), vec![5, 7]);
+        assert_eq!(buffer.iter_from(9).count(), 0);
+    }
+
+    #[test]
+    fn test_oversized_item() {
+        let accountant = test_accountant(0, 30);
+        let mut buffer = BoundedBuffer::with_accountant(Pool::Events, accountant.clone());
+        assert!(buffer.push(0, 10));
+        assert!(!buffer.push(1, 40));
+        assert!(buffer.is_empty());
+        assert_eq!(accountant.usage(Pool::Events).dropped_items, 2);
+    }
+
+    #[test]
+    fn test_release_on_drop() {
+        let accountant = test_accountant(0, 0);
+        {
+            let mut buffer = BoundedBuffer::with_accountant(Pool::Captures, accountant.clone());
+            buffer.push(vec![0u8; 16], 16);
+            assert_eq!(accountant.used_bytes(), 16);
+        }
+        assert_eq!(accountant.used_bytes(), 0);
+    }
+
+    #[test]
+    fn test_charge() {
+        let accountant = test_accountant(0, 30);
+        let charge = Charge::with_accountant(Pool::Captures, 20, accountant.clone()).unwrap();
+        assert!(Charge::with_accountant(Pool::Captures, 20, accountant.clone()).is_none());
+        assert_eq!(accountant.usage(Pool::Captures).dropped_items, 1);
+        drop(charge);
+        assert_eq!(accountant.usage(Pool::Captures).used_bytes, 0);
+    }
+}
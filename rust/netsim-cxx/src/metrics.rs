@@ -0,0 +1,178 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metrics library for netsimd.
+//!
+//! Modules keep their own counters and register a collector that reports
+//! them as samples. Exporters read the samples of all collectors through
+//! `collect`; the http frontend exports them in the Prometheus text format.
+//!
+//! /v1/metrics --> handle_metrics
+//! /metrics    --> handle_metrics, the default path of Prometheus scrapes
+
+use lazy_static::lazy_static;
+use std::fmt::Write;
+use std::sync::RwLock;
+
+use crate::http_server::http_request::HttpRequest;
+use crate::http_server::server_response::ResponseWritable;
+
+/// The type of a metric.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricKind {
+    /// A value that only increases.
+    Counter,
+    /// A value that can go up and down.
+    Gauge,
+    /// A bucket, the sum or the count of a histogram, named with the
+    /// `_bucket`, `_sum` or `_count` suffix of the histogram.
+    Histogram,
+}
+
+/// A single value of a metric.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub kind: MetricKind,
+    pub labels: Vec<(&'static str, String)>,
+    pub value: f64,
+}
+
+/// A function reporting the current samples of a module.
+pub type Collector = fn() -> Vec<Sample>;
+
+lazy_static! {
+    static ref COLLECTORS: RwLock<Vec<Collector>> = RwLock::new(Vec::new());
+}
+
+/// Registers a collector. Registering the same collector again is a no-op.
+pub fn register_collector(collector: Collector) {
+    let mut collectors = COLLECTORS.write().unwrap();
+    if !collectors.iter().any(|c| *c as usize == collector as usize) {
+        collectors.push(collector);
+    }
+}
+
+/// Returns the samples of all registered collectors.
+pub fn collect() -> Vec<Sample> {
+    let collectors = COLLECTORS.read().unwrap().clone();
+    collectors.iter().flat_map(|collector| collector()).collect()
+}
+
+// Returns the name of the metric family of a sample.
+fn family(sample: &Sample) -> &'static str {
+    match sample.kind {
+        MetricKind::Histogram => ["_bucket", "_sum", "_count"]
+            .iter()
+            .find_map(|suffix| sample.name.strip_suffix(suffix))
+            .unwrap_or(sample.name),
+        _ => sample.name,
+    }
+}
+
+/// Returns the samples in the Prometheus text exposition format.
+pub fn encode_text(samples: &[Sample]) -> String {
+    // The samples of a family are written together.
+    let mut samples: Vec<&Sample> = samples.iter().collect();
+    samples.sort_by_key(|sample| family(sample));
+    let mut text = String::new();
+    let mut last_family = "";
+    for sample in samples {
+        let family = family(sample);
+        if family != last_family {
+            let kind = match sample.kind {
+                MetricKind::Counter => "counter",
+                MetricKind::Gauge => "gauge",
+                MetricKind::Histogram => "histogram",
+            };
+            let _ = writeln!(text, "# HELP {family} {}\n# TYPE {family} {kind}", sample.help);
+            last_family = family;
+        }
+        text.push_str(sample.name);
+        let labels: Vec<String> = sample
+            .labels
+            .iter()
+            .map(|(name, value)| {
+                let value = value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+                format!("{name}=\"{value}\"")
+            })
+            .collect();
+        if !labels.is_empty() {
+            let _ = write!(text, "{{{}}}", labels.join(","));
+        }
+        let _ = writeln!(text, " {}", sample.value);
+    }
+    text
+}
+
+/// The Rust metrics handler used directly by Http frontend for GET
+pub fn handle_metrics(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    match request.method.as_str() {
+        "GET" => writer.put_ok("text/plain; version=0.0.4", &encode_text(&collect()), &[]),
+        _ => writer.put_error(404, "Not found."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_collector() -> Vec<Sample> {
+        vec![Sample {
+            name: "netsim_test_total",
+            help: "Test counter.",
+            kind: MetricKind::Counter,
+            labels: vec![("label", "value".to_string())],
+            value: 1.0,
+        }]
+    }
+
+    #[test]
+    fn test_register_collector() {
+        register_collector(test_collector);
+        register_collector(test_collector);
+        let samples: Vec<Sample> =
+            collect().into_iter().filter(|s| s.name == "netsim_test_total").collect();
+        assert_eq!(samples, test_collector());
+    }
+
+    #[test]
+    fn test_encode_text() {
+        let sample = |name, kind, labels: &[(&'static str, &str)], value| Sample {
+            name,
+            help: "Help.",
+            kind,
+            labels: labels.iter().map(|(name, value)| (*name, value.to_string())).collect(),
+            value,
+        };
+        let samples = [
+            sample("netsim_b_total", MetricKind::Counter, &[("chip", "1")], 3.0),
+            sample("netsim_a_seconds_bucket", MetricKind::Histogram, &[("le", "0.5")], 1.0),
+            sample("netsim_b_total", MetricKind::Counter, &[("chip", "a\"b")], 1.5),
+            sample("netsim_a_seconds_count", MetricKind::Histogram, &[], 1.0),
+        ];
+        assert_eq!(
+            encode_text(&samples),
+            "# HELP netsim_a_seconds Help.\n\
+             # TYPE netsim_a_seconds histogram\n\
+             netsim_a_seconds_bucket{le=\"0.5\"} 1\n\
+             netsim_a_seconds_count 1\n\
+             # HELP netsim_b_total Help.\n\
+             # TYPE netsim_b_total counter\n\
+             netsim_b_total{chip=\"1\"} 3\n\
+             netsim_b_total{chip=\"a\\\"b\"} 1.5\n"
+        );
+    }
+}
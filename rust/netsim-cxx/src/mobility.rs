@@ -0,0 +1,312 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Device mobility.
+//!
+//! A device moves along a scripted path of waypoints at a constant speed.
+//! Every UPDATE_INTERVAL the mobility thread patches the position of each
+//! moving device to the point of its path at the simulation time, so the
+//! distance and the RSSI of its radio links, and the position listed with
+//! the devices, follow the device. A motion ends at its last waypoint, or
+//! loops back to its first waypoint when repeated, until the next motion
+//! of the device replaces it. Resetting the scene stops the motions.
+//!
+//! /v1/motion --> handle_motion (POST a SetDeviceMotionRequest)
+
+use frontend_proto::frontend::{PatchDeviceRequest, SetDeviceMotionRequest};
+use frontend_proto::model::{Device, Position};
+use lazy_static::lazy_static;
+use protobuf::MessageField;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::thread::sleep;
+use std::time::Duration;
+
+use crate::clock::clock;
+use crate::ffi::{patch_device, CxxServerResponseWriter};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::info;
+use crate::CxxServerResponseWriterWrapper;
+
+/// Interval of the updates of the positions of the moving devices.
+const UPDATE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A motion of a device along a path of waypoints.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Motion {
+    waypoints: Vec<Position>,
+    // Meters per second.
+    speed: f32,
+    repeat: bool,
+    // Simulation time of the start of the motion.
+    start: Duration,
+}
+
+fn distance(a: &Position, b: &Position) -> f32 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)).sqrt()
+}
+
+fn interpolate(a: &Position, b: &Position, fraction: f32) -> Position {
+    Position {
+        x: a.x + (b.x - a.x) * fraction,
+        y: a.y + (b.y - a.y) * fraction,
+        z: a.z + (b.z - a.z) * fraction,
+        ..Default::default()
+    }
+}
+
+impl Motion {
+    /// Checks the path of a request, without waypoints for none.
+    pub fn new(request: &SetDeviceMotionRequest, start: Duration) -> Result<Option<Self>, String> {
+        let waypoints = request.waypoints.clone();
+        if waypoints.iter().any(|p| !(p.x.is_finite() && p.y.is_finite() && p.z.is_finite())) {
+            return Err("Incorrect waypoint, the coordinates must be finite".to_string());
+        }
+        if waypoints.len() > 1 && !(request.speed.is_finite() && request.speed > 0.0) {
+            return Err(format!("Incorrect speed {}, expected a positive number", request.speed));
+        }
+        Ok((!waypoints.is_empty()).then_some(Motion {
+            waypoints,
+            speed: request.speed,
+            repeat: request.repeat,
+            start,
+        }))
+    }
+
+    // The legs of the path, back to the first waypoint when repeated.
+    fn legs(&self) -> impl Iterator<Item = (&Position, &Position)> {
+        let back = match self.repeat {
+            true => self.waypoints.last().zip(self.waypoints.first()),
+            false => None,
+        };
+        self.waypoints.iter().zip(self.waypoints.iter().skip(1)).chain(back)
+    }
+
+    /// Returns the position of the path at `time`, and whether the motion
+    /// ended.
+    pub fn position(&self, time: Duration) -> (Position, bool) {
+        let last = self.waypoints.last().cloned().unwrap_or_default();
+        let length: f32 = self.legs().map(|(a, b)| distance(a, b)).sum();
+        if length == 0.0 {
+            return (last, true);
+        }
+        let mut traveled = time.saturating_sub(self.start).as_secs_f32() * self.speed;
+        if self.repeat {
+            traveled %= length;
+        } else if traveled >= length {
+            return (last, true);
+        }
+        for (a, b) in self.legs() {
+            let leg = distance(a, b);
+            if traveled < leg {
+                return (interpolate(a, b, traveled / leg), false);
+            }
+            traveled -= leg;
+        }
+        (last, !self.repeat)
+    }
+}
+
+lazy_static! {
+    // The motions of the moving devices, by device name.
+    static ref MOTIONS: Mutex<HashMap<String, Motion>> = Mutex::new(HashMap::new());
+}
+
+// Patches the position of a device in the scene.
+fn patch_position(device_name: &str, position: Position) -> Result<(), String> {
+    let device = Device {
+        name: device_name.to_string(),
+        position: MessageField::some(position),
+        ..Default::default()
+    };
+    let request = PatchDeviceRequest { device: MessageField::some(device), ..Default::default() };
+    let json = protobuf_json_mapping::print_to_string(&request).map_err(|e| e.to_string())?;
+    cxx::let_cxx_string!(request = json);
+    cxx::let_cxx_string!(response = "");
+    cxx::let_cxx_string!(error_message = "");
+    match patch_device(&request, response.as_mut(), error_message.as_mut()) {
+        200 => Ok(()),
+        _ => Err(error_message.to_string()),
+    }
+}
+
+/// Starts the motion of a device from its first waypoint, or without a
+/// motion stops the device where it is. Fails when the device is not found.
+pub fn set_device_motion(device_name: &str, motion: Option<Motion>) -> Result<(), String> {
+    let Some(motion) = motion else {
+        MOTIONS.lock().unwrap().remove(device_name);
+        return Ok(());
+    };
+    // Patched without the lock of the motions, which the scene takes as it
+    // resets.
+    let (position, ended) = motion.position(motion.start);
+    patch_position(device_name, position)?;
+    let mut motions = MOTIONS.lock().unwrap();
+    match ended {
+        true => motions.remove(device_name),
+        false => motions.insert(device_name.to_string(), motion),
+    };
+    Ok(())
+}
+
+/// Stops the motions of the devices, as the scene is reset.
+pub fn reset_motions() {
+    MOTIONS.lock().unwrap().clear();
+}
+
+/// Moves the devices along their paths.
+pub fn run_mobility() {
+    loop {
+        sleep(UPDATE_INTERVAL);
+        let time = clock().monotonic();
+        let positions: Vec<(String, Motion)> = MOTIONS
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, motion)| (name.clone(), motion.clone()))
+            .collect();
+        for (name, motion) in positions {
+            let (position, mut ended) = motion.position(time);
+            // The motion of a removed device ends.
+            if let Err(err) = patch_position(&name, position) {
+                info!("motion of {name} stopped: {err}");
+                ended = true;
+            }
+            let mut motions = MOTIONS.lock().unwrap();
+            // Unless a new motion of the device replaced it.
+            if ended && motions.get(&name) == Some(&motion) {
+                motions.remove(&name);
+            }
+        }
+    }
+}
+
+/// The Rust motion handler used directly by Http frontend for POST
+pub fn handle_motion(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "POST" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let body = String::from_utf8_lossy(&request.body);
+    let motion_request =
+        match protobuf_json_mapping::parse_from_str::<SetDeviceMotionRequest>(&body) {
+            Ok(motion_request) => motion_request,
+            Err(e) => {
+                writer.put_error(400, format!("Invalid motion request: {e}").as_str());
+                return;
+            }
+        };
+    let motion = match Motion::new(&motion_request, clock().monotonic()) {
+        Ok(motion) => motion,
+        Err(e) => {
+            writer.put_error(400, e.as_str());
+            return;
+        }
+    };
+    match set_device_motion(&motion_request.device_name, motion) {
+        Ok(()) => writer.put_ok("text/plain", "", &[]),
+        Err(e) => writer.put_error(404, e.as_str()),
+    }
+}
+
+/// motion handle cxx for grpc server to call
+pub fn handle_motion_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/motion".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_motion(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f32, y: f32) -> Position {
+        Position { x, y, ..Default::default() }
+    }
+
+    fn motion(waypoints: &[(f32, f32)], speed: f32, repeat: bool) -> Motion {
+        let request = SetDeviceMotionRequest {
+            device_name: "phone".to_string(),
+            waypoints: waypoints.iter().map(|&(x, y)| point(x, y)).collect(),
+            speed,
+            repeat,
+            ..Default::default()
+        };
+        Motion::new(&request, Duration::from_secs(10)).unwrap().unwrap()
+    }
+
+    fn at(motion: &Motion, secs: f32) -> (f32, f32, bool) {
+        let (position, ended) = motion.position(Duration::from_secs_f32(10.0 + secs));
+        (position.x, position.y, ended)
+    }
+
+    #[test]
+    fn test_motion() {
+        // 10 meters east, then 10 meters north, at 2 meters per second.
+        let motion = motion(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)], 2.0, false);
+        assert_eq!(at(&motion, 0.0), (0.0, 0.0, false));
+        assert_eq!(at(&motion, 2.5), (5.0, 0.0, false));
+        assert_eq!(at(&motion, 7.5), (10.0, 5.0, false));
+        // The device stays at the last waypoint.
+        assert_eq!(at(&motion, 10.0), (10.0, 10.0, true));
+        assert_eq!(at(&motion, 60.0), (10.0, 10.0, true));
+    }
+
+    #[test]
+    fn test_motion_repeat() {
+        // Around a triangle of 10 + 10 + 14.14 meters at 1 meter per second.
+        let motion = motion(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)], 1.0, true);
+        let (x, y, ended) = at(&motion, 20.0 + 14.142136 / 2.0);
+        assert!((x - 5.0).abs() < 0.01 && (y - 5.0).abs() < 0.01 && !ended);
+        // Back at the start after a lap.
+        let (x, y, ended) = at(&motion, 34.142136 + 5.0);
+        assert!((x - 5.0).abs() < 0.01 && y.abs() < 0.01 && !ended);
+    }
+
+    #[test]
+    fn test_motion_request() {
+        let request = |waypoints: Vec<Position>, speed| SetDeviceMotionRequest {
+            waypoints,
+            speed,
+            ..Default::default()
+        };
+        // Without waypoints the device stops.
+        assert_eq!(Motion::new(&request(vec![], 0.0), Duration::ZERO), Ok(None));
+        // A single waypoint places the device without a speed.
+        let motion = Motion::new(&request(vec![point(1.0, 2.0)], 0.0), Duration::ZERO);
+        assert_eq!(motion.unwrap().unwrap().position(Duration::ZERO), (point(1.0, 2.0), true));
+        let path = vec![point(0.0, 0.0), point(1.0, 0.0)];
+        assert!(Motion::new(&request(path.clone(), 0.0), Duration::ZERO).is_err());
+        assert!(Motion::new(&request(path, f32::NAN), Duration::ZERO).is_err());
+        assert!(
+            Motion::new(&request(vec![point(f32::INFINITY, 0.0)], 1.0), Duration::ZERO).is_err()
+        );
+    }
+}
@@ -0,0 +1,724 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The NFC facade.
+//!
+//! Each NFC chip is an NCI controller answering the core and RF management
+//! commands of the NFC stack of its emulator. The field thread activates
+//! the chips in RF discovery when their devices are within the range of
+//! both radios, DEFAULT_RANGE when unset: a polling chip activates the
+//! nearest listening chip, or the nearest chip emulating a tag, over
+//! ISO-DEP. The data packets of an activated chip then reach its peer, or
+//! are answered by the tag, until the host deactivates the chip or the
+//! devices move apart. A pair of chips is activated once per tap.
+//!
+//! A chip patched with a tag emulates an NFC Forum Type 4 tag with a read
+//! only NDEF message, instead of its controller.
+
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::GetDevicesResponse;
+use frontend_proto::model::chip::nfc::Tag as ProtoTag;
+use frontend_proto::model::chip::{Nfc as ProtoNfc, Radio as ProtoRadio};
+use frontend_proto::model::{Position, State};
+use lazy_static::lazy_static;
+use protobuf::{Message, MessageField};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Mutex;
+use std::thread::sleep;
+use std::time::Duration;
+
+use super::nci::*;
+use crate::ffi::{get_devices_bytes, handle_response_cxx};
+use crate::logger::{debug, info, warn};
+
+/// Range of the radios without a range, in meters.
+const DEFAULT_RANGE: f32 = 0.1;
+
+/// Period of the field thread.
+const FIELD_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Packets to send, by facade id.
+pub type Outbox = Vec<(u32, Vec<u8>)>;
+
+/// Status words of the tag.
+const SW_OK: [u8; 2] = [0x90, 0x00];
+const SW_WRONG_LENGTH: [u8; 2] = [0x67, 0x00];
+const SW_WRONG_OFFSET: [u8; 2] = [0x6b, 0x00];
+const SW_NOT_FOUND: [u8; 2] = [0x6a, 0x82];
+const SW_INS_NOT_SUPPORTED: [u8; 2] = [0x6d, 0x00];
+
+/// The NDEF tag application and its files.
+const NDEF_AID: [u8; 7] = [0xd2, 0x76, 0x00, 0x00, 0x85, 0x01, 0x01];
+const CC_FILE_ID: [u8; 2] = [0xe1, 0x03];
+const NDEF_FILE_ID: [u8; 2] = [0xe1, 0x04];
+
+/// Longest response data, leaving room for the status word.
+const MAX_RESPONSE_DATA: usize = MAX_PAYLOAD_LEN - 2;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum File {
+    Application,
+    CapabilityContainer,
+    Ndef,
+}
+
+/// An emulated Type 4 tag.
+#[derive(Debug)]
+struct Tag {
+    ndef: Vec<u8>,
+    selected: Option<File>,
+}
+
+impl Tag {
+    fn file(&self, file: File) -> Vec<u8> {
+        let ndef_file_len = (self.ndef.len() + 2) as u16;
+        match file {
+            File::Application => Vec::new(),
+            // The mapping version 2.0, the largest responses and commands,
+            // and the control of the read only NDEF file.
+            File::CapabilityContainer => [
+                &[0x00, 0x0f, 0x20][..],
+                &(MAX_RESPONSE_DATA as u16).to_be_bytes(),
+                &[0x00, 0xff, 0x04, 0x06],
+                &NDEF_FILE_ID,
+                &ndef_file_len.to_be_bytes(),
+                &[0x00, 0xff],
+            ]
+            .concat(),
+            File::Ndef => [&(self.ndef.len() as u16).to_be_bytes(), self.ndef.as_slice()].concat(),
+        }
+    }
+
+    /// Returns the response APDU to a command APDU.
+    fn apdu(&mut self, command: &[u8]) -> Vec<u8> {
+        match command {
+            // SELECT the application by name.
+            [0x00, 0xa4, 0x04, 0x00, len, rest @ ..] => match rest.get(..*len as usize) {
+                Some(aid) if aid == NDEF_AID => {
+                    self.selected = Some(File::Application);
+                    SW_OK.to_vec()
+                }
+                Some(_) => SW_NOT_FOUND.to_vec(),
+                None => SW_WRONG_LENGTH.to_vec(),
+            },
+            // SELECT a file of the application by id.
+            [0x00, 0xa4, 0x00, 0x0c, 0x02, id @ ..] if self.selected.is_some() => {
+                self.selected = if id.starts_with(&CC_FILE_ID) {
+                    Some(File::CapabilityContainer)
+                } else if id.starts_with(&NDEF_FILE_ID) {
+                    Some(File::Ndef)
+                } else {
+                    return SW_NOT_FOUND.to_vec();
+                };
+                SW_OK.to_vec()
+            }
+            // READ BINARY of the selected file.
+            [0x00, 0xb0, p1, p2, le @ ..] => {
+                let Some(file) = self.selected.filter(|file| *file != File::Application) else {
+                    return SW_NOT_FOUND.to_vec();
+                };
+                let contents = self.file(file);
+                let offset = u16::from_be_bytes([*p1, *p2]) as usize;
+                let Some(data) = contents.get(offset..) else {
+                    return SW_WRONG_OFFSET.to_vec();
+                };
+                let len = match le.first() {
+                    Some(0) | None => MAX_RESPONSE_DATA,
+                    Some(le) => *le as usize,
+                };
+                [&data[..data.len().min(len).min(MAX_RESPONSE_DATA)], &SW_OK].concat()
+            }
+            [0x00, 0xa4, ..] => SW_NOT_FOUND.to_vec(),
+            _ => SW_INS_NOT_SUPPORTED.to_vec(),
+        }
+    }
+}
+
+/// The RF discovery of a chip.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Discovery {
+    poll: bool,
+    listen: bool,
+}
+
+/// The activation of a chip with a peer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Activation {
+    peer: u32,
+    poll: bool,
+    // Whether the host of the chip was notified, unlike for a tag.
+    notified: bool,
+}
+
+/// An NFC chip.
+#[derive(Debug)]
+struct NfcChip {
+    device_id: u32,
+    state: State,
+    // Meters, DEFAULT_RANGE for 0.
+    range: f32,
+    tx_count: i32,
+    rx_count: i32,
+    config: BTreeMap<u8, Vec<u8>>,
+    discovery: Option<Discovery>,
+    activation: Option<Activation>,
+    tag: Option<Tag>,
+}
+
+impl NfcChip {
+    fn new(device_id: u32) -> Self {
+        NfcChip {
+            device_id,
+            state: State::ON,
+            range: 0.0,
+            tx_count: 0,
+            rx_count: 0,
+            config: BTreeMap::new(),
+            discovery: None,
+            activation: None,
+            tag: None,
+        }
+    }
+
+    fn range(&self) -> f32 {
+        if self.range > 0.0 {
+            self.range
+        } else {
+            DEFAULT_RANGE
+        }
+    }
+
+    fn polls(&self) -> bool {
+        self.state == State::ON
+            && self.tag.is_none()
+            && self.discovery.is_some_and(|discovery| discovery.poll)
+    }
+
+    fn listens(&self) -> bool {
+        self.state == State::ON
+            && (self.tag.is_some() || self.discovery.is_some_and(|discovery| discovery.listen))
+    }
+}
+
+// Returns a NFCID1 of a chip, random as the first byte says.
+fn nfcid1(facade_id: u32) -> [u8; 4] {
+    let id = facade_id.to_be_bytes();
+    [0x08, id[1], id[2], id[3]]
+}
+
+/// The NFC chips by facade id.
+#[derive(Default)]
+pub struct Nfc {
+    next_facade_id: u32,
+    chips: HashMap<u32, NfcChip>,
+    // The pairs of poller and listener activated during the current tap.
+    tapped: HashSet<(u32, u32)>,
+}
+
+impl Nfc {
+    pub fn add(&mut self, device_id: u32) -> u32 {
+        self.next_facade_id += 1;
+        self.chips.insert(self.next_facade_id, NfcChip::new(device_id));
+        self.next_facade_id
+    }
+
+    pub fn remove(&mut self, facade_id: u32) {
+        self.chips.remove(&facade_id);
+    }
+
+    /// Stops the discovery and the tag of a chip and turns its radio on.
+    pub fn reset(&mut self, facade_id: u32) {
+        if let Some(chip) = self.chips.get_mut(&facade_id) {
+            *chip = NfcChip::new(chip.device_id);
+        }
+    }
+
+    pub fn patch(&mut self, facade_id: u32, nfc: &ProtoNfc) {
+        let Some(chip) = self.chips.get_mut(&facade_id) else {
+            return;
+        };
+        if let Some(radio) = nfc.radio.as_ref() {
+            if let Ok(state) = radio.state.enum_value() {
+                if state != State::UNKNOWN {
+                    chip.state = state;
+                }
+            }
+            if radio.range > 0.0 {
+                chip.range = radio.range;
+            }
+        }
+        if let Some(tag) = nfc.tag.as_ref() {
+            chip.tag = match tag.ndef.is_empty() {
+                true => None,
+                false => Some(Tag { ndef: tag.ndef.clone(), selected: None }),
+            };
+            info!("nfc facade {facade_id} tag of {} bytes", tag.ndef.len());
+        }
+    }
+
+    pub fn get(&self, facade_id: u32) -> Option<ProtoNfc> {
+        let chip = self.chips.get(&facade_id)?;
+        let radio = ProtoRadio {
+            state: chip.state.into(),
+            range: chip.range,
+            tx_count: chip.tx_count,
+            rx_count: chip.rx_count,
+            ..Default::default()
+        };
+        let tag =
+            chip.tag.as_ref().map(|tag| ProtoTag { ndef: tag.ndef.clone(), ..Default::default() });
+        Some(ProtoNfc {
+            radio: Some(radio).into(),
+            tag: MessageField::from_option(tag),
+            ..Default::default()
+        })
+    }
+
+    // Deactivates a chip and its peer, notifying the host of the peer.
+    fn deactivate(&mut self, facade_id: u32, reason: u8, outbox: &mut Outbox) {
+        let activation = self.chips.get_mut(&facade_id).and_then(|chip| chip.activation.take());
+        let Some(Activation { peer, .. }) = activation else {
+            return;
+        };
+        let Some(chip) = self.chips.get_mut(&peer) else {
+            return;
+        };
+        if chip.activation.take().is_some_and(|activation| activation.notified) {
+            outbox.push((peer, deactivated(DEACTIVATE_DISCOVERY, reason)));
+        }
+    }
+
+    /// Returns the packets sent for a packet from the host of a chip.
+    pub fn handle_packet(&mut self, facade_id: u32, packet: &[u8]) -> Outbox {
+        let mut outbox = Outbox::new();
+        if !self.chips.contains_key(&facade_id) {
+            return outbox;
+        }
+        match parse(packet) {
+            Some(Packet::Control { message_type: MT_COMMAND, gid, oid, payload }) => {
+                for packet in self.handle_command(facade_id, gid, oid, payload, &mut outbox) {
+                    outbox.insert(0, (facade_id, packet));
+                }
+            }
+            Some(Packet::Data { conn_id: STATIC_RF_CONNECTION, payload }) => {
+                self.handle_data(facade_id, payload, &mut outbox)
+            }
+            _ => debug!("nfc facade {facade_id} ignored a packet"),
+        }
+        outbox
+    }
+
+    // Returns the response and the notifications of a command, in reverse
+    // order, adding the packets to the other chips to the outbox.
+    fn handle_command(
+        &mut self,
+        facade_id: u32,
+        gid: u8,
+        oid: u8,
+        payload: &[u8],
+        outbox: &mut Outbox,
+    ) -> Vec<Vec<u8>> {
+        let response = |payload: &[u8]| control_packet(MT_RESPONSE, gid, oid, payload);
+        match (gid, oid) {
+            (GID_CORE, CORE_RESET) => {
+                self.deactivate(facade_id, REASON_RF_LINK_LOSS, outbox);
+                let chip = self.chips.get_mut(&facade_id).unwrap();
+                chip.discovery = None;
+                // Resets the configuration unless asked to keep it.
+                let reset_config = payload.first() != Some(&0);
+                if reset_config {
+                    chip.config.clear();
+                }
+                // Triggered by the command, NCI 2.0 without manufacturer
+                // information.
+                let status = [0x02, reset_config as u8, 0x20, 0x00, 0x00];
+                vec![
+                    control_packet(MT_NOTIFICATION, GID_CORE, CORE_RESET, &status),
+                    response(&[STATUS_OK]),
+                ]
+            }
+            (GID_CORE, CORE_INIT) => {
+                // No optional features, a logical connection, no routing
+                // table, the largest control packets, no HCI connection,
+                // and the frame and ISO-DEP interfaces without extensions.
+                let init = [
+                    &[STATUS_OK, 0, 0, 0, 0, 1, 0, 0, MAX_PAYLOAD_LEN as u8, 0, 0, 0, 0][..],
+                    &[2, 0x01, 0, INTERFACE_ISO_DEP, 0],
+                ]
+                .concat();
+                vec![response(&init)]
+            }
+            (GID_CORE, CORE_SET_CONFIG) => {
+                let Some(tlvs) = payload.get(1..).and_then(parse_tlvs) else {
+                    return vec![response(&[STATUS_SYNTAX_ERROR, 0])];
+                };
+                self.chips.get_mut(&facade_id).unwrap().config.extend(tlvs);
+                vec![response(&[STATUS_OK, 0])]
+            }
+            (GID_CORE, CORE_GET_CONFIG) => {
+                let chip = &self.chips[&facade_id];
+                let ids = payload.get(1..).unwrap_or_default();
+                let found: Vec<_> = chip.config.iter().filter(|(id, _)| ids.contains(id)).collect();
+                let mut config = vec![STATUS_OK, found.len() as u8];
+                for (id, value) in found {
+                    config.extend_from_slice(&[*id, value.len() as u8]);
+                    config.extend_from_slice(value);
+                }
+                vec![response(&config)]
+            }
+            (GID_RF, RF_DISCOVER_MAP | RF_SET_LISTEN_MODE_ROUTING) => vec![response(&[STATUS_OK])],
+            (GID_RF, RF_DISCOVER) => {
+                let chip = self.chips.get_mut(&facade_id).unwrap();
+                if chip.discovery.is_some() {
+                    return vec![response(&[STATUS_SEMANTIC_ERROR])];
+                }
+                let modes: Option<Vec<u8>> = payload.split_first().and_then(|(count, rest)| {
+                    let configs = rest.get(..*count as usize * 2)?;
+                    Some(configs.chunks_exact(2).map(|config| config[0]).collect())
+                });
+                let Some(modes) = modes else {
+                    return vec![response(&[STATUS_SYNTAX_ERROR])];
+                };
+                chip.discovery = Some(Discovery {
+                    poll: modes.iter().any(|mode| mode & NFC_A_PASSIVE_LISTEN == 0),
+                    listen: modes.iter().any(|mode| mode & NFC_A_PASSIVE_LISTEN != 0),
+                });
+                vec![response(&[STATUS_OK])]
+            }
+            (GID_RF, RF_DEACTIVATE) => {
+                if self.chips[&facade_id].discovery.is_none() {
+                    return vec![response(&[STATUS_SEMANTIC_ERROR])];
+                }
+                self.deactivate(facade_id, REASON_ENDPOINT_REQUEST, outbox);
+                // Sleep is simulated as a return to the discovery.
+                let deactivation_type = match payload.first() {
+                    Some(&DEACTIVATE_IDLE) => DEACTIVATE_IDLE,
+                    _ => DEACTIVATE_DISCOVERY,
+                };
+                if deactivation_type == DEACTIVATE_IDLE {
+                    self.chips.get_mut(&facade_id).unwrap().discovery = None;
+                }
+                vec![deactivated(deactivation_type, REASON_DH_REQUEST), response(&[STATUS_OK])]
+            }
+            (GID_CORE | GID_RF, _) => vec![response(&[STATUS_UNKNOWN_OID])],
+            _ => vec![response(&[STATUS_UNKNOWN_GID])],
+        }
+    }
+
+    fn handle_data(&mut self, facade_id: u32, payload: &[u8], outbox: &mut Outbox) {
+        let chip = self.chips.get_mut(&facade_id).unwrap();
+        // The credit of the packet returns once it is sent.
+        outbox.push((facade_id, conn_credits(STATIC_RF_CONNECTION)));
+        let Some(Activation { peer: peer_id, .. }) = chip.activation else {
+            return;
+        };
+        chip.tx_count += 1;
+        let Some(peer) = self.chips.get_mut(&peer_id) else {
+            return;
+        };
+        peer.rx_count += 1;
+        match peer.tag.as_mut() {
+            Some(tag) => {
+                let response = tag.apdu(payload);
+                peer.tx_count += 1;
+                self.chips.get_mut(&facade_id).unwrap().rx_count += 1;
+                outbox.push((facade_id, data_packet(STATIC_RF_CONNECTION, &response)));
+            }
+            None => outbox.push((peer_id, data_packet(STATIC_RF_CONNECTION, payload))),
+        }
+    }
+
+    // Returns the distance between two chips within the range of both.
+    fn distance(&self, a: u32, b: u32, positions: &HashMap<u32, Position>) -> Option<f32> {
+        let (a, b) = (self.chips.get(&a)?, self.chips.get(&b)?);
+        if a.device_id == b.device_id {
+            return None;
+        }
+        let (p, q) = (positions.get(&a.device_id)?, positions.get(&b.device_id)?);
+        let distance = ((p.x - q.x).powi(2) + (p.y - q.y).powi(2) + (p.z - q.z).powi(2)).sqrt();
+        (distance <= a.range().min(b.range())).then_some(distance)
+    }
+
+    /// Whether a chip discovers or emulates a tag.
+    pub fn has_field(&self) -> bool {
+        self.chips.values().any(|chip| chip.discovery.is_some() || chip.tag.is_some())
+    }
+
+    /// Activates and deactivates the chips as their devices move to the
+    /// `positions` by device id, returning the notifications.
+    pub fn update_field(&mut self, positions: &HashMap<u32, Position>) -> Outbox {
+        let mut outbox = Outbox::new();
+        // The activated pairs break apart out of range, and with the
+        // removed chips.
+        let activations: Vec<(u32, Activation)> =
+            self.chips.iter().filter_map(|(&id, chip)| Some((id, chip.activation?))).collect();
+        for (id, activation) in activations {
+            let linked = match activation.poll {
+                true => {
+                    self.chips[&id].state == State::ON
+                        && self.chips.get(&activation.peer).is_some_and(|chip| chip.listens())
+                        && self.distance(id, activation.peer, positions).is_some()
+                }
+                false => self.chips.contains_key(&activation.peer),
+            };
+            if !linked {
+                if activation.notified {
+                    outbox.push((id, deactivated(DEACTIVATE_DISCOVERY, REASON_RF_LINK_LOSS)));
+                }
+                self.deactivate(id, REASON_RF_LINK_LOSS, &mut outbox);
+            }
+        }
+        let tapped = std::mem::take(&mut self.tapped);
+        self.tapped = tapped
+            .into_iter()
+            .filter(|(poller, listener)| self.distance(*poller, *listener, positions).is_some())
+            .collect();
+        // The idle pollers activate their nearest idle listener.
+        let mut pollers: Vec<u32> = self
+            .chips
+            .iter()
+            .filter(|(_, chip)| chip.polls() && chip.activation.is_none())
+            .map(|(&id, _)| id)
+            .collect();
+        pollers.sort();
+        for poller in pollers {
+            let nearest = self
+                .chips
+                .iter()
+                .filter(|(&id, chip)| id != poller && chip.listens() && chip.activation.is_none())
+                .filter(|(&id, _)| !self.tapped.contains(&(poller, id)))
+                .filter_map(|(&id, _)| Some((self.distance(poller, id, positions)?, id)))
+                .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+            let Some((_, listener)) = nearest else {
+                continue;
+            };
+            self.tapped.insert((poller, listener));
+            let activation = Activation { peer: listener, poll: true, notified: true };
+            self.chips.get_mut(&poller).unwrap().activation = Some(activation);
+            let chip = self.chips.get_mut(&listener).unwrap();
+            let notified = chip.tag.is_none();
+            chip.activation = Some(Activation { peer: poller, poll: false, notified });
+            match chip.tag.as_mut() {
+                Some(tag) => tag.selected = None,
+                None => outbox.push((listener, activated(false, nfcid1(poller)))),
+            }
+            outbox.push((poller, activated(true, nfcid1(listener))));
+        }
+        outbox
+    }
+}
+
+// Parses the id, length and value parameters of a configuration.
+fn parse_tlvs(mut bytes: &[u8]) -> Option<Vec<(u8, Vec<u8>)>> {
+    let mut tlvs = Vec::new();
+    while let [id, len, rest @ ..] = bytes {
+        let value = rest.get(..*len as usize)?;
+        tlvs.push((*id, value.to_vec()));
+        bytes = &rest[*len as usize..];
+    }
+    bytes.is_empty().then_some(tlvs)
+}
+
+lazy_static! {
+    static ref NFC: Mutex<Nfc> = Mutex::new(Nfc::default());
+}
+
+// Returns the positions of the devices of the scene by device id.
+fn positions() -> HashMap<u32, Position> {
+    let mut vec = Vec::<u8>::new();
+    if !get_devices_bytes(&mut vec) {
+        return HashMap::new();
+    }
+    let devices = GetDevicesResponse::parse_from_bytes(&vec).map(|r| r.devices).unwrap_or_default();
+    devices
+        .into_iter()
+        .map(|device| (device.id as u32, device.position.unwrap_or_default()))
+        .collect()
+}
+
+fn send(outbox: Outbox) {
+    for (facade_id, packet) in outbox {
+        handle_response_cxx(ChipKind::NFC as u32, facade_id, &packet, 0);
+    }
+}
+
+/// Activates the chips brought together in the scene.
+pub fn run_field() {
+    loop {
+        sleep(FIELD_INTERVAL);
+        if !NFC.lock().unwrap().has_field() {
+            continue;
+        }
+        // Read without the lock of the chips, which the scene takes.
+        let positions = positions();
+        let outbox = NFC.lock().unwrap().update_field(&positions);
+        send(outbox);
+    }
+}
+
+pub fn handle_nfc_request(facade_id: u32, packet: &[u8]) {
+    let outbox = NFC.lock().unwrap().handle_packet(facade_id, packet);
+    // Sent without the lock, packet_hub may deliver the next packet.
+    send(outbox);
+}
+
+pub fn nfc_reset(facade_id: u32) {
+    NFC.lock().unwrap().reset(facade_id);
+}
+
+pub fn nfc_remove(facade_id: u32) {
+    NFC.lock().unwrap().remove(facade_id);
+}
+
+pub fn nfc_patch(facade_id: u32, proto_bytes: &[u8]) {
+    match ProtoNfc::parse_from_bytes(proto_bytes) {
+        Ok(nfc) => NFC.lock().unwrap().patch(facade_id, &nfc),
+        Err(err) => warn!("nfc_patch: {err}"),
+    }
+}
+
+pub fn nfc_get(facade_id: u32) -> Vec<u8> {
+    let nfc = NFC.lock().unwrap().get(facade_id);
+    MessageField::from_option(nfc).unwrap_or_default().write_to_bytes().unwrap_or_default()
+}
+
+pub fn nfc_add(device_id: u32) -> u32 {
+    NFC.lock().unwrap().add(device_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(gid: u8, oid: u8, payload: &[u8]) -> Vec<u8> {
+        control_packet(MT_COMMAND, gid, oid, payload)
+    }
+
+    // Starts the discovery of a chip in the poll or the listen mode.
+    fn discover(nfc: &mut Nfc, facade_id: u32, mode: u8) {
+        let outbox = nfc.handle_packet(facade_id, &command(GID_RF, RF_DISCOVER, &[1, mode, 1]));
+        assert_eq!(outbox, [(facade_id, vec![0x41, 0x03, 0x01, STATUS_OK])]);
+    }
+
+    fn at(positions: &[(u32, f32)]) -> HashMap<u32, Position> {
+        positions.iter().map(|&(id, x)| (id, Position { x, ..Default::default() })).collect()
+    }
+
+    fn notifications(outbox: &Outbox) -> Vec<(u32, u8)> {
+        outbox
+            .iter()
+            .filter_map(|(id, packet)| match parse(packet)? {
+                Packet::Control { message_type: MT_NOTIFICATION, oid, .. } => Some((*id, oid)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_core() {
+        let mut nfc = Nfc::default();
+        let facade_id = nfc.add(1);
+        let outbox = nfc.handle_packet(facade_id, &command(GID_CORE, CORE_RESET, &[1]));
+        assert_eq!(
+            outbox,
+            [
+                (facade_id, vec![0x40, 0x00, 0x01, STATUS_OK]),
+                (facade_id, vec![0x60, 0x00, 0x05, 0x02, 0x01, 0x20, 0x00, 0x00])
+            ]
+        );
+        nfc.handle_packet(facade_id, &command(GID_CORE, CORE_SET_CONFIG, &[1, 0x32, 1, 0x60]));
+        let outbox = nfc.handle_packet(facade_id, &command(GID_CORE, CORE_GET_CONFIG, &[1, 0x32]));
+        assert_eq!(outbox[0].1, [0x40, 0x03, 0x05, STATUS_OK, 1, 0x32, 1, 0x60]);
+        // Deactivating without a discovery is an error.
+        let outbox = nfc.handle_packet(facade_id, &command(GID_RF, RF_DEACTIVATE, &[0]));
+        assert_eq!(outbox[0].1, [0x41, 0x06, 0x01, STATUS_SEMANTIC_ERROR]);
+        let outbox = nfc.handle_packet(facade_id, &command(0x0e, 0x00, &[]));
+        assert_eq!(outbox[0].1, [0x4e, 0x00, 0x01, STATUS_UNKNOWN_GID]);
+    }
+
+    #[test]
+    fn test_peers() {
+        let mut nfc = Nfc::default();
+        let reader = nfc.add(1);
+        let card = nfc.add(2);
+        discover(&mut nfc, reader, NFC_A_PASSIVE_POLL);
+        discover(&mut nfc, card, NFC_A_PASSIVE_LISTEN);
+        assert!(nfc.update_field(&at(&[(1, 0.0), (2, 1.0)])).is_empty());
+        // Tapped.
+        let outbox = nfc.update_field(&at(&[(1, 0.0), (2, 0.05)]));
+        assert_eq!(
+            notifications(&outbox),
+            [(card, RF_INTF_ACTIVATED), (reader, RF_INTF_ACTIVATED)]
+        );
+        let outbox = nfc.handle_packet(reader, &data_packet(0, &[0x00, 0xa4]));
+        assert_eq!(outbox, [(reader, conn_credits(0)), (card, data_packet(0, &[0x00, 0xa4]))]);
+        let outbox = nfc.handle_packet(card, &data_packet(0, &SW_OK));
+        assert_eq!(outbox[1], (reader, data_packet(0, &SW_OK)));
+        // Deactivated by the reader, the card is not activated again
+        // until the next tap.
+        let outbox = nfc.handle_packet(reader, &command(GID_RF, RF_DEACTIVATE, &[3]));
+        assert_eq!(
+            outbox,
+            [
+                (reader, vec![0x41, 0x06, 0x01, STATUS_OK]),
+                (reader, deactivated(DEACTIVATE_DISCOVERY, REASON_DH_REQUEST)),
+                (card, deactivated(DEACTIVATE_DISCOVERY, REASON_ENDPOINT_REQUEST))
+            ]
+        );
+        assert!(nfc.update_field(&at(&[(1, 0.0), (2, 0.05)])).is_empty());
+        nfc.update_field(&at(&[(1, 0.0), (2, 1.0)]));
+        let outbox = nfc.update_field(&at(&[(1, 0.0), (2, 0.05)]));
+        assert_eq!(notifications(&outbox).len(), 2);
+        // Moved apart.
+        let outbox = nfc.update_field(&at(&[(1, 0.0), (2, 1.0)]));
+        assert_eq!(notifications(&outbox), [(reader, RF_DEACTIVATE), (card, RF_DEACTIVATE)]);
+        assert_eq!(nfc.get(reader).unwrap().radio.tx_count, 1);
+    }
+
+    #[test]
+    fn test_tag() {
+        let mut nfc = Nfc::default();
+        let reader = nfc.add(1);
+        let tag = nfc.add(2);
+        let ndef = vec![0xd1, 0x01, 0x02, b'U', 0x00, b'a'];
+        let patch = ProtoNfc {
+            tag: Some(ProtoTag { ndef: ndef.clone(), ..Default::default() }).into(),
+            ..Default::default()
+        };
+        nfc.patch(tag, &patch);
+        discover(&mut nfc, reader, NFC_A_PASSIVE_POLL);
+        let outbox = nfc.update_field(&at(&[(1, 0.0), (2, 0.0)]));
+        assert_eq!(outbox, [(reader, activated(true, nfcid1(tag)))]);
+        let mut read =
+            |apdu: &[u8]| nfc.handle_packet(reader, &data_packet(0, apdu))[1].1[3..].to_vec();
+        assert_eq!(read(&[0x00, 0xb0, 0x00, 0x00, 0x0f]), SW_NOT_FOUND);
+        assert_eq!(
+            read(&[&[0x00, 0xa4, 0x04, 0x00, 0x07][..], &NDEF_AID, &[0x00]].concat()),
+            SW_OK
+        );
+        assert_eq!(read(&[0x00, 0xa4, 0x00, 0x0c, 0x02, 0xe1, 0x03]), SW_OK);
+        let cc = read(&[0x00, 0xb0, 0x00, 0x00, 0x0f]);
+        assert_eq!(cc[..2], [0x00, 0x0f]);
+        assert_eq!(cc[9..13], [0xe1, 0x04, 0x00, 0x08]);
+        assert_eq!(read(&[0x00, 0xa4, 0x00, 0x0c, 0x02, 0xe1, 0x04]), SW_OK);
+        assert_eq!(read(&[0x00, 0xb0, 0x00, 0x02, 0x00]), [&ndef[..], &SW_OK].concat());
+        assert_eq!(read(&[0x00, 0xb0, 0x01, 0x00, 0x01]), SW_WRONG_OFFSET);
+        // Without the tag, the reader loses the link.
+        let patch = ProtoNfc { tag: Some(ProtoTag::default()).into(), ..Default::default() };
+        nfc.patch(tag, &patch);
+        let outbox = nfc.update_field(&at(&[(1, 0.0), (2, 0.0)]));
+        assert_eq!(outbox, [(reader, deactivated(DEACTIVATE_DISCOVERY, REASON_RF_LINK_LOSS))]);
+        assert!(nfc.get(tag).unwrap().tag.is_none());
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! NFC Controller Interface 2.0 packets.
+//!
+//! A packet has a 3 byte header: the message type, the packet boundary
+//! flag and the group id of a control packet or the connection id of a
+//! data packet, the opcode id, and the payload length after a reserved
+//! byte for data packets.
+
+/// Message types.
+pub const MT_DATA: u8 = 0;
+pub const MT_COMMAND: u8 = 1;
+pub const MT_RESPONSE: u8 = 2;
+pub const MT_NOTIFICATION: u8 = 3;
+
+/// Group ids.
+pub const GID_CORE: u8 = 0x0;
+pub const GID_RF: u8 = 0x1;
+
+/// Opcode ids of the core group.
+pub const CORE_RESET: u8 = 0x00;
+pub const CORE_INIT: u8 = 0x01;
+pub const CORE_SET_CONFIG: u8 = 0x02;
+pub const CORE_GET_CONFIG: u8 = 0x03;
+pub const CORE_CONN_CREDITS: u8 = 0x06;
+
+/// Opcode ids of the RF management group.
+pub const RF_DISCOVER_MAP: u8 = 0x00;
+pub const RF_SET_LISTEN_MODE_ROUTING: u8 = 0x01;
+pub const RF_DISCOVER: u8 = 0x03;
+pub const RF_INTF_ACTIVATED: u8 = 0x05;
+pub const RF_DEACTIVATE: u8 = 0x06;
+
+/// Status codes.
+pub const STATUS_OK: u8 = 0x00;
+pub const STATUS_REJECTED: u8 = 0x01;
+pub const STATUS_SYNTAX_ERROR: u8 = 0x05;
+pub const STATUS_SEMANTIC_ERROR: u8 = 0x06;
+pub const STATUS_UNKNOWN_GID: u8 = 0x07;
+pub const STATUS_UNKNOWN_OID: u8 = 0x08;
+
+/// Deactivation types.
+pub const DEACTIVATE_IDLE: u8 = 0x00;
+pub const DEACTIVATE_DISCOVERY: u8 = 0x03;
+
+/// Deactivation reasons.
+pub const REASON_DH_REQUEST: u8 = 0x00;
+pub const REASON_ENDPOINT_REQUEST: u8 = 0x01;
+pub const REASON_RF_LINK_LOSS: u8 = 0x02;
+
+/// RF technologies and modes, the listen modes have the high bit set.
+pub const NFC_A_PASSIVE_POLL: u8 = 0x00;
+pub const NFC_A_PASSIVE_LISTEN: u8 = 0x80;
+
+/// RF interface and protocol of the activations.
+pub const INTERFACE_ISO_DEP: u8 = 0x02;
+pub const PROTOCOL_ISO_DEP: u8 = 0x04;
+
+/// Connection id of the static RF connection.
+pub const STATIC_RF_CONNECTION: u8 = 0;
+
+const HEADER_LEN: usize = 3;
+pub const MAX_PAYLOAD_LEN: usize = 255;
+
+/// A complete packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Packet<'a> {
+    Control { message_type: u8, gid: u8, oid: u8, payload: &'a [u8] },
+    Data { conn_id: u8, payload: &'a [u8] },
+}
+
+/// Parses a complete packet, None for segments.
+pub fn parse(packet: &[u8]) -> Option<Packet<'_>> {
+    let header = packet.get(..HEADER_LEN)?;
+    let message_type = header[0] >> 5;
+    let segmented = header[0] & 0x10 != 0;
+    if segmented {
+        return None;
+    }
+    let payload = packet.get(HEADER_LEN..HEADER_LEN + header[2] as usize)?;
+    Some(match message_type {
+        MT_DATA => Packet::Data { conn_id: header[0] & 0x0f, payload },
+        _ => {
+            Packet::Control { message_type, gid: header[0] & 0x0f, oid: header[1] & 0x3f, payload }
+        }
+    })
+}
+
+/// Returns a control packet with a payload of at most 255 bytes.
+pub fn control_packet(message_type: u8, gid: u8, oid: u8, payload: &[u8]) -> Vec<u8> {
+    let len = payload.len().min(MAX_PAYLOAD_LEN);
+    let mut packet = vec![message_type << 5 | gid, oid, len as u8];
+    packet.extend_from_slice(&payload[..len]);
+    packet
+}
+
+/// Returns a data packet with a payload of at most 255 bytes.
+pub fn data_packet(conn_id: u8, payload: &[u8]) -> Vec<u8> {
+    let len = payload.len().min(MAX_PAYLOAD_LEN);
+    let mut packet = vec![MT_DATA << 5 | conn_id, 0, len as u8];
+    packet.extend_from_slice(&payload[..len]);
+    packet
+}
+
+/// Returns the CORE_CONN_CREDITS notification of a credit of a connection.
+pub fn conn_credits(conn_id: u8) -> Vec<u8> {
+    control_packet(MT_NOTIFICATION, GID_CORE, CORE_CONN_CREDITS, &[1, conn_id, 1])
+}
+
+/// Returns the RF_DEACTIVATE notification.
+pub fn deactivated(deactivation_type: u8, reason: u8) -> Vec<u8> {
+    control_packet(MT_NOTIFICATION, GID_RF, RF_DEACTIVATE, &[deactivation_type, reason])
+}
+
+/// Returns the RF_INTF_ACTIVATED notification of an ISO-DEP activation
+/// over NFC-A, as the poller of a listener with `nfcid1` or as the
+/// listener.
+pub fn activated(poll: bool, nfcid1: [u8; 4]) -> Vec<u8> {
+    let mode = if poll { NFC_A_PASSIVE_POLL } else { NFC_A_PASSIVE_LISTEN };
+    // The discovery id, the interface, the protocol and the mode, with the
+    // max payload size and a credit of the static RF connection.
+    let mut payload = vec![1, INTERFACE_ISO_DEP, PROTOCOL_ISO_DEP, mode, MAX_PAYLOAD_LEN as u8, 1];
+    if poll {
+        // SENS_RES, NFCID1, SEL_RES of an ISO-DEP target and no HR.
+        let parameters = [&[0x04, 0x00, 4][..], &nfcid1, &[1, 0x20, 0]].concat();
+        payload.push(parameters.len() as u8);
+        payload.extend(parameters);
+    } else {
+        payload.push(0);
+    }
+    // The data exchange mode with its bit rates, 106 kbps.
+    payload.extend_from_slice(&[mode, 0, 0]);
+    if poll {
+        // The ATS.
+        let ats = [0x05, 0x78, 0x80, 0x70, 0x02];
+        payload.extend_from_slice(&[ats.len() as u8 + 1, ats.len() as u8]);
+        payload.extend_from_slice(&ats);
+    } else {
+        // The parameter byte of the RATS.
+        payload.extend_from_slice(&[1, 0x80]);
+    }
+    control_packet(MT_NOTIFICATION, GID_RF, RF_INTF_ACTIVATED, &payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        // CORE_RESET_CMD keeping the configuration.
+        let packet = [0x20, 0x00, 0x01, 0x00];
+        assert_eq!(
+            parse(&packet),
+            Some(Packet::Control {
+                message_type: MT_COMMAND,
+                gid: GID_CORE,
+                oid: CORE_RESET,
+                payload: &[0x00]
+            })
+        );
+        assert_eq!(
+            parse(&data_packet(STATIC_RF_CONNECTION, &[0x90, 0x00])),
+            Some(Packet::Data { conn_id: 0, payload: &[0x90, 0x00] })
+        );
+        // Truncated and segmented packets.
+        assert_eq!(parse(&packet[..3]), None);
+        assert_eq!(parse(&[0x30, 0x00, 0x00]), None);
+        assert_eq!(conn_credits(0), [0x60, 0x06, 0x03, 0x01, 0x00, 0x01]);
+    }
+
+    #[test]
+    fn test_activated() {
+        let packet = activated(true, [1, 2, 3, 4]);
+        let Some(Packet::Control { oid, payload, .. }) = parse(&packet) else {
+            panic!("not a control packet");
+        };
+        assert_eq!(oid, RF_INTF_ACTIVATED);
+        assert_eq!(payload[..4], [1, INTERFACE_ISO_DEP, PROTOCOL_ISO_DEP, NFC_A_PASSIVE_POLL]);
+        // The technology parameters hold the NFCID1.
+        assert_eq!(payload[6], 10);
+        assert_eq!(payload[10..14], [1, 2, 3, 4]);
+        let listen = activated(false, [0; 4]);
+        assert_eq!(listen[3..][3], NFC_A_PASSIVE_LISTEN);
+        assert_eq!(listen.len(), 3 + 7 + 3 + 2);
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bandwidth caps.
+//!
+//! The bandwidth of a chip, patched with the devices, caps the throughput
+//! of the packets to and from its emulator. The packets draw their bytes
+//! from a token bucket of the chip, refilled at the capped rate with a
+//! burst of BURST, and wait in the delay queue until the bucket covers
+//! them. A chip backlogged by more than BACKLOG drops its packets, as a
+//! congested radio would, and counts them in the capture stats of the chip.
+
+use frontend_proto::common::ChipKind;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::captures::handlers::{facade_chip_id, record_dropped};
+use crate::logger::info;
+
+/// Bytes sent at once after a quiet period, as a duration of the rate.
+const BURST: Duration = Duration::from_millis(10);
+
+/// Longest wait of a packet for the bucket.
+const BACKLOG: Duration = Duration::from_secs(2);
+
+/// The decision about a packet of a capped chip.
+#[derive(Debug, PartialEq)]
+pub enum Throttle {
+    /// Send the packet at the given time.
+    Send(Duration),
+    Drop,
+}
+
+/// The token bucket of a chip, in bytes.
+#[derive(Debug)]
+struct Bucket {
+    // Bytes per second.
+    rate: f64,
+    tokens: f64,
+    updated: Duration,
+}
+
+impl Bucket {
+    fn new(bits_per_second: u64) -> Self {
+        let rate = bits_per_second as f64 / 8.0;
+        Bucket { rate, tokens: Self::burst(rate), updated: Duration::ZERO }
+    }
+
+    fn burst(rate: f64) -> f64 {
+        (rate * BURST.as_secs_f64()).max(1.0)
+    }
+
+    fn throttle(&mut self, len: usize, time: Duration) -> Throttle {
+        let elapsed = time.saturating_sub(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(Self::burst(self.rate));
+        self.updated = self.updated.max(time);
+        // The missing tokens are the backlog of the chip.
+        let wait = (len as f64 - self.tokens).max(0.0) / self.rate;
+        if wait > BACKLOG.as_secs_f64() {
+            return Throttle::Drop;
+        }
+        self.tokens -= len as f64;
+        Throttle::Send(time + Duration::from_secs_f64(wait))
+    }
+}
+
+/// The buckets of the capped chips, by chip kind and facade id.
+#[derive(Default)]
+pub struct Bandwidths {
+    buckets: HashMap<(u32, u32), Bucket>,
+}
+
+impl Bandwidths {
+    /// Caps a chip to `bits_per_second`, 0 removes the cap.
+    pub fn set(&mut self, kind: u32, facade_id: u32, bits_per_second: u64) {
+        match bits_per_second {
+            0 => self.buckets.remove(&(kind, facade_id)),
+            _ => self.buckets.insert((kind, facade_id), Bucket::new(bits_per_second)),
+        };
+    }
+
+    /// Returns the cap of a chip in bits per second, 0 for none.
+    pub fn get(&self, kind: u32, facade_id: u32) -> u64 {
+        self.buckets.get(&(kind, facade_id)).map_or(0, |bucket| (bucket.rate * 8.0).round() as u64)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    /// Draws a packet of `len` bytes sent at `time` from the bucket of a
+    /// chip, or None when the chip is not capped.
+    pub fn throttle(
+        &mut self,
+        kind: u32,
+        facade_id: u32,
+        len: usize,
+        time: Duration,
+    ) -> Option<Throttle> {
+        Some(self.buckets.get_mut(&(kind, facade_id))?.throttle(len, time))
+    }
+}
+
+lazy_static! {
+    static ref BANDWIDTHS: Mutex<Bandwidths> = Mutex::new(Bandwidths::default());
+}
+
+// Skips the lookups of the packets while no chip is capped.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Draws a packet of a chip from its bucket, recording the drops.
+pub fn throttle_packet(
+    chip_kind: ChipKind,
+    facade_id: u32,
+    len: usize,
+    time: Duration,
+) -> Option<Throttle> {
+    if !ACTIVE.load(Ordering::Relaxed) {
+        return None;
+    }
+    let throttle = BANDWIDTHS.lock().unwrap().throttle(chip_kind as u32, facade_id, len, time)?;
+    if throttle == Throttle::Drop {
+        if let Some(chip_id) = facade_chip_id(chip_kind, facade_id) {
+            record_dropped(chip_id);
+        }
+    }
+    Some(throttle)
+}
+
+// Cxx Methods for the chips to invoke
+
+/// Caps the throughput of a chip, as the chip is patched.
+pub fn set_bandwidth(kind: u32, facade_id: u32, bits_per_second: u64) {
+    let mut bandwidths = BANDWIDTHS.lock().unwrap();
+    if bandwidths.get(kind, facade_id) == bits_per_second {
+        return;
+    }
+    bandwidths.set(kind, facade_id, bits_per_second);
+    ACTIVE.store(!bandwidths.is_empty(), Ordering::Relaxed);
+    info!("bandwidth of facade {facade_id} set to {bits_per_second} bps");
+}
+
+/// Returns the cap of a chip in bits per second, 0 for none.
+pub fn get_bandwidth(kind: u32, facade_id: u32) -> u64 {
+    BANDWIDTHS.lock().unwrap().get(kind, facade_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIFI: u32 = 2;
+
+    #[test]
+    fn test_throttle() {
+        let mut bandwidths = Bandwidths::default();
+        // 1 Mbps, a burst of 1250 bytes.
+        bandwidths.set(WIFI, 1, 1_000_000);
+        assert_eq!(bandwidths.get(WIFI, 1), 1_000_000);
+        let start = Duration::from_secs(100);
+        let mut sends = Vec::new();
+        for _ in 0..4 {
+            match bandwidths.throttle(WIFI, 1, 1250, start) {
+                Some(Throttle::Send(time)) => {
+                    sends.push(((time - start).as_secs_f64() * 1000.0).round())
+                }
+                throttle => panic!("{throttle:?}"),
+            }
+        }
+        // The first packet fits the burst, the next ones 10ms apart.
+        assert_eq!(sends, [0.0, 10.0, 20.0, 30.0]);
+        // The bucket refills at the rate.
+        let later = start + Duration::from_millis(100);
+        assert_eq!(bandwidths.throttle(WIFI, 1, 1250, later), Some(Throttle::Send(later)));
+        // Other chips are not capped.
+        assert_eq!(bandwidths.throttle(WIFI, 2, 1250, start), None);
+        bandwidths.set(WIFI, 1, 0);
+        assert!(bandwidths.is_empty());
+    }
+
+    #[test]
+    fn test_backlog() {
+        let mut bandwidths = Bandwidths::default();
+        // 8 kbps, a second per kilobyte.
+        bandwidths.set(WIFI, 1, 8000);
+        let start = Duration::from_secs(100);
+        let throttles: Vec<Throttle> =
+            (0..3).filter_map(|_| bandwidths.throttle(WIFI, 1, 1000, start)).collect();
+        assert!(matches!(throttles[1], Throttle::Send(time) if time < start + BACKLOG));
+        // Beyond the backlog the packets are dropped.
+        assert_eq!(throttles[2], Throttle::Drop);
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Packet delays.
+//!
+//! packet_hub hands the packets of a chip with a latency or jitter
+//! impairment, or with a bandwidth cap, to the delay queue, past the
+//! debugger and the restarts. A capped packet is sent once the bucket of
+//! the chip covers it, and the impairment delays it from then. The delay
+//! thread forwards each packet once it is due on the netsimd clock.
+//! Neither makes a packet due before the previous packet of the chip, and
+//! packets due at the same time leave in arrival order, so the delayed
+//! packets keep their order. The delayed packets are charged to the
+//! packets memory pool; a packet it drops is dropped rather than forwarded.
+
+use cxx::CxxVector;
+use lazy_static::lazy_static;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+use crate::captures::handlers::int_to_chip_kind;
+use crate::captures::pcap_util::PacketDirection;
+use crate::clock::clock;
+use crate::ffi::{forward_request_cxx, forward_response_cxx};
+use crate::logger::warn;
+use crate::memory::{accountant, Accountant, Pool};
+use crate::processor::bandwidth::{throttle_packet, Throttle};
+use crate::processor::impairment::delay_packet;
+
+/// Longest wait of the delay thread, which also follows a manual clock.
+const MAX_WAIT: Duration = Duration::from_millis(10);
+
+/// A packet waiting in the delay queue.
+#[derive(Debug, PartialEq)]
+pub struct DelayedPacket {
+    pub kind: u32,
+    pub facade_id: u32,
+    pub packet: Vec<u8>,
+    pub packet_type: u32,
+    pub direction: PacketDirection,
+}
+
+/// The delayed packets by due time and arrival.
+pub struct DelayQueue {
+    packets: BTreeMap<(Duration, u64), DelayedPacket>,
+    arrivals: u64,
+    // Charged with the bytes of the packets.
+    accountant: Arc<Accountant>,
+}
+
+impl Default for DelayQueue {
+    fn default() -> Self {
+        DelayQueue::with_accountant(accountant())
+    }
+}
+
+impl DelayQueue {
+    pub fn with_accountant(accountant: Arc<Accountant>) -> Self {
+        DelayQueue { packets: BTreeMap::new(), arrivals: 0, accountant }
+    }
+
+    /// Adds a packet due at `due`. The packets pool out of budget drops
+    /// the first due packets or the new one, per its drop policy; returns
+    /// false if the new packet was dropped.
+    pub fn push(&mut self, due: Duration, packet: DelayedPacket) -> bool {
+        let evict = || self.packets.pop_first().map(|(_, first)| first.packet.len());
+        if !self.accountant.charge(Pool::Packets, packet.packet.len(), evict) {
+            return false;
+        }
+        self.arrivals += 1;
+        self.packets.insert((due, self.arrivals), packet);
+        true
+    }
+
+    /// Removes the first packet due at `now`.
+    pub fn pop_due(&mut self, now: Duration) -> Option<DelayedPacket> {
+        let (&(due, _), _) = self.packets.first_key_value()?;
+        if due > now {
+            return None;
+        }
+        let (_, packet) = self.packets.pop_first()?;
+        self.accountant.release(Pool::Packets, packet.packet.len());
+        Some(packet)
+    }
+
+    /// Returns the due time of the first packet.
+    pub fn next_due(&self) -> Option<Duration> {
+        self.packets.keys().next().map(|&(due, _)| due)
+    }
+
+    pub fn len(&self) -> usize {
+        self.packets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packets.is_empty()
+    }
+}
+
+impl Drop for DelayQueue {
+    fn drop(&mut self) {
+        let bytes = self.packets.values().map(|packet| packet.packet.len()).sum();
+        self.accountant.release(Pool::Packets, bytes);
+    }
+}
+
+lazy_static! {
+    static ref QUEUE: (Mutex<DelayQueue>, Condvar) =
+        (Mutex::new(DelayQueue::default()), Condvar::new());
+}
+
+fn deliver(packet: DelayedPacket) {
+    match packet.direction {
+        PacketDirection::HostToController => forward_request_cxx(
+            packet.kind,
+            packet.facade_id,
+            &packet.packet,
+            packet.packet_type as u8,
+        ),
+        PacketDirection::ControllerToHost => forward_response_cxx(
+            packet.kind,
+            packet.facade_id,
+            &packet.packet,
+            packet.packet_type as u8,
+        ),
+    }
+}
+
+/// Forwards the delayed packets as they are due.
+pub fn run_delays() {
+    let (queue, condvar) = &*QUEUE;
+    loop {
+        let mut due = Vec::new();
+        {
+            let mut queue = queue.lock().unwrap();
+            let now = clock().monotonic();
+            while let Some(packet) = queue.pop_due(now) {
+                due.push(packet);
+            }
+            if due.is_empty() {
+                let wait = queue.next_due().map_or(MAX_WAIT, |next| next.saturating_sub(now));
+                let _ = condvar.wait_timeout(queue, wait.min(MAX_WAIT)).unwrap();
+                continue;
+            }
+        }
+        // Delivered without the lock, packet_hub may delay the next packets.
+        due.into_iter().for_each(deliver);
+    }
+}
+
+// Cxx Methods for packet_hub to invoke
+
+fn delay(
+    kind: u32,
+    facade_id: u32,
+    packet: &CxxVector<u8>,
+    packet_type: u32,
+    direction: PacketDirection,
+) -> bool {
+    let chip_kind = int_to_chip_kind(kind);
+    let now = clock().monotonic();
+    let sent = match throttle_packet(chip_kind, facade_id, packet.len(), now) {
+        Some(Throttle::Drop) => return true,
+        Some(Throttle::Send(sent)) => Some(sent),
+        None => None,
+    };
+    // A capped packet waits in the queue even when sent now, behind the
+    // previous packets of the chip.
+    let Some(due) = delay_packet(chip_kind, facade_id, sent.unwrap_or(now)).or(sent) else {
+        return false;
+    };
+    let packet = DelayedPacket {
+        kind,
+        facade_id,
+        packet: packet.as_slice().to_vec(),
+        packet_type,
+        direction,
+    };
+    let (queue, condvar) = &*QUEUE;
+    if !queue.lock().unwrap().push(due, packet) {
+        warn!("{chip_kind:?} chip {facade_id} packet dropped, the packets memory pool is full");
+    }
+    condvar.notify_one();
+    true
+}
+
+/// Returns true when a packet from the emulator is delayed or dropped.
+pub fn delay_request(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) -> bool {
+    delay(kind, facade_id, packet, packet_type, PacketDirection::HostToController)
+}
+
+/// Returns true when a packet to the emulator is delayed or dropped.
+pub fn delay_response(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) -> bool {
+    delay(kind, facade_id, packet, packet_type, PacketDirection::ControllerToHost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DropPolicy;
+
+    fn packet(facade_id: u32) -> DelayedPacket {
+        DelayedPacket {
+            kind: 1,
+            facade_id,
+            packet: vec![facade_id as u8],
+            packet_type: 1,
+            direction: PacketDirection::HostToController,
+        }
+    }
+
+    #[test]
+    fn test_delay_queue() {
+        let ms = Duration::from_millis;
+        let mut queue = DelayQueue::default();
+        queue.push(ms(30), packet(1));
+        queue.push(ms(10), packet(2));
+        queue.push(ms(30), packet(3));
+        queue.push(ms(20), packet(4));
+        assert_eq!(queue.next_due(), Some(ms(10)));
+        assert_eq!(queue.pop_due(ms(5)), None);
+        assert_eq!(queue.pop_due(ms(25)), Some(packet(2)));
+        assert_eq!(queue.pop_due(ms(25)), Some(packet(4)));
+        assert_eq!(queue.pop_due(ms(25)), None);
+        assert_eq!(queue.len(), 2);
+        // The packets due at the same time leave in arrival order.
+        assert_eq!(queue.pop_due(ms(30)), Some(packet(1)));
+        assert_eq!(queue.pop_due(ms(30)), Some(packet(3)));
+        assert!(queue.is_empty());
+        assert_eq!(queue.next_due(), None);
+    }
+
+    #[test]
+    fn test_delay_queue_memory_pool() {
+        let ms = Duration::from_millis;
+        let accountant = Arc::new(Accountant::new(
+            0,
+            [(0, DropPolicy::DropOldest), (0, DropPolicy::DropOldest), (2, DropPolicy::DropOldest)],
+        ));
+        let mut queue = DelayQueue::with_accountant(accountant.clone());
+        assert!(queue.push(ms(20), packet(1)));
+        assert!(queue.push(ms(10), packet(2)));
+        // The first due packet is dropped for the new one.
+        assert!(queue.push(ms(30), packet(3)));
+        assert_eq!(queue.pop_due(ms(30)), Some(packet(1)));
+        assert_eq!(accountant.usage(Pool::Packets).used_bytes, 1);
+        assert_eq!(accountant.usage(Pool::Packets).dropped_items, 1);
+        drop(queue);
+        assert_eq!(accountant.usage(Pool::Packets).used_bytes, 0);
+    }
+}
@@ -0,0 +1,517 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Link impairments.
+//!
+//! An impairment drops a percentage of the packets of a chip, to and from
+//! its emulator, in packet_hub before the packet processors, and delays
+//! them by a latency plus a jitter in the delay queue. An impairment
+//! between two Bluetooth chips drops and delays the packets of the link in
+//! the phy layer of the Bluetooth facade instead, where each receiver of a
+//! packet is known. Each impairment draws from a generator of its own,
+//! seeded by the request or by the debug.seed setting and the chips, so a
+//! scenario impairs the same packets on each run whatever the traffic of
+//! the other chips. The dropped packets are counted in the capture stats of
+//! the chip, the receiver for a link.
+//!
+//! /v1/impairment --> handle_impairment (POST a SetLinkImpairmentRequest)
+
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::set_link_impairment_request::JitterDistribution;
+use frontend_proto::frontend::SetLinkImpairmentRequest;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use crate::captures::capture::ChipId;
+use crate::captures::handlers::{facade_chip_id, record_dropped};
+use crate::chip_registry::facade_id;
+use crate::clock::clock;
+use crate::ffi::CxxServerResponseWriter;
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::info;
+use crate::random::{seed, Generator};
+use crate::CxxServerResponseWriterWrapper;
+
+/// The drops and the delays of a chip or of a link.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Impairment {
+    pub drop_percent: f32,
+    pub latency: Duration,
+    pub jitter: Duration,
+    pub distribution: JitterDistribution,
+}
+
+impl Impairment {
+    pub fn is_delayed(&self) -> bool {
+        !self.latency.is_zero() || !self.jitter.is_zero()
+    }
+}
+
+/// An impairment with its generator.
+struct Impaired {
+    impairment: Impairment,
+    // Seed of the request, the generator draws from the seed and the key.
+    seed: u64,
+    generator: Generator,
+    // Due time of the last delayed packet.
+    last_due: Duration,
+}
+
+impl Impaired {
+    // Draws a number in [0, 1).
+    fn next_f32(&mut self) -> f32 {
+        // The top 24 bits are exact in an f32.
+        (self.generator.next_u64() >> 40) as f32 / (1 << 24) as f32
+    }
+
+    // Draws whether the next packet is dropped.
+    fn draw(&mut self) -> bool {
+        self.impairment.drop_percent > 0.0 && self.next_f32() * 100.0 < self.impairment.drop_percent
+    }
+
+    // Draws the due time of the next packet sent at `now`, no earlier than
+    // the previous packet so the packets keep their order.
+    fn delay(&mut self, now: Duration) -> Option<Duration> {
+        if !self.impairment.is_delayed() {
+            return None;
+        }
+        let jitter = self.impairment.jitter.as_secs_f32();
+        let jitter = match self.impairment.distribution {
+            JitterDistribution::UNIFORM => jitter * self.next_f32(),
+            // Box-Muller transform of two uniform draws.
+            JitterDistribution::NORMAL => {
+                let (u1, u2) = (1.0 - self.next_f32() as f64, self.next_f32() as f64);
+                jitter * ((-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()).abs() as f32
+            }
+        };
+        let due = now + self.impairment.latency + Duration::from_secs_f32(jitter);
+        self.last_due = self.last_due.max(due);
+        Some(self.last_due)
+    }
+}
+
+// Key of the impairment of a chip, or of a link with the lower chip id
+// first.
+fn key(chip_id: ChipId, other_chip_id: ChipId) -> (ChipId, ChipId) {
+    match other_chip_id {
+        0 => (chip_id, 0),
+        _ => (chip_id.min(other_chip_id), chip_id.max(other_chip_id)),
+    }
+}
+
+/// The impairments of the chips and of the links.
+#[derive(Default)]
+pub struct Impairments {
+    impairments: HashMap<(ChipId, ChipId), Impaired>,
+}
+
+impl Impairments {
+    /// Impairs the packets of a chip, or of its link to `other_chip_id`
+    /// unless 0. An impairment without drops nor delays is removed.
+    pub fn set(
+        &mut self,
+        chip_id: ChipId,
+        other_chip_id: ChipId,
+        impairment: Impairment,
+        seed: u64,
+    ) {
+        let key = key(chip_id, other_chip_id);
+        if impairment.drop_percent == 0.0 && !impairment.is_delayed() {
+            self.impairments.remove(&key);
+            return;
+        }
+        // Each chip and link draws a sequence of its own from the seed.
+        let generator = Generator::new(seed ^ ((key.0 as u32 as u64) << 32 | key.1 as u32 as u64));
+        let last_due = self.impairments.get(&key).map_or(Duration::ZERO, |i| i.last_due);
+        self.impairments.insert(key, Impaired { impairment, seed, generator, last_due });
+    }
+
+    /// Returns the requests setting the impairments again, by chip id.
+    pub fn requests(&self) -> Vec<SetLinkImpairmentRequest> {
+        let mut requests: Vec<SetLinkImpairmentRequest> = self
+            .impairments
+            .iter()
+            .map(|(&(chip_id, other_chip_id), impaired)| {
+                let impairment = &impaired.impairment;
+                SetLinkImpairmentRequest {
+                    chip_id,
+                    other_chip_id,
+                    drop_percent: impairment.drop_percent,
+                    seed: impaired.seed,
+                    latency_ms: impairment.latency.as_millis() as u32,
+                    jitter_ms: impairment.jitter.as_millis() as u32,
+                    jitter_distribution: impairment.distribution.into(),
+                    ..Default::default()
+                }
+            })
+            .collect();
+        requests.sort_by_key(|request| (request.chip_id, request.other_chip_id));
+        requests
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.impairments.is_empty()
+    }
+
+    /// Returns the impairment of a chip, or of its link to `other_chip_id`
+    /// unless 0.
+    pub fn get(&self, chip_id: ChipId, other_chip_id: ChipId) -> Option<&Impairment> {
+        self.impairments.get(&key(chip_id, other_chip_id)).map(|impaired| &impaired.impairment)
+    }
+
+    /// Draws whether to drop a packet to or from a chip.
+    pub fn drop_chip_packet(&mut self, chip_id: ChipId) -> bool {
+        self.draw(key(chip_id, 0))
+    }
+
+    /// Draws whether to drop a packet between two chips.
+    pub fn drop_link_packet(&mut self, sender_id: ChipId, receiver_id: ChipId) -> bool {
+        self.draw(key(sender_id, receiver_id))
+    }
+
+    fn draw(&mut self, key: (ChipId, ChipId)) -> bool {
+        self.impairments.get_mut(&key).is_some_and(|impaired| impaired.draw())
+    }
+
+    /// Draws the due time of a packet to or from a chip sent at `now`, or
+    /// None when the packets of the chip are not delayed.
+    pub fn delay_chip_packet(&mut self, chip_id: ChipId, now: Duration) -> Option<Duration> {
+        self.impairments.get_mut(&key(chip_id, 0))?.delay(now)
+    }
+
+    /// Draws the due time of a packet between two chips sent at `now`.
+    pub fn delay_link_packet(
+        &mut self,
+        sender_id: ChipId,
+        receiver_id: ChipId,
+        now: Duration,
+    ) -> Option<Duration> {
+        self.impairments.get_mut(&key(sender_id, receiver_id))?.delay(now)
+    }
+}
+
+lazy_static! {
+    static ref IMPAIRMENTS: Mutex<Impairments> = Mutex::new(Impairments::default());
+}
+
+// Skips the lookups of the packets while no impairment is set.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Returns the impairments of netsimd.
+pub fn impairments() -> MutexGuard<'static, Impairments> {
+    IMPAIRMENTS.lock().unwrap()
+}
+
+/// Sets an impairment of the request.
+pub fn set_impairment(request: &SetLinkImpairmentRequest) -> Result<(), String> {
+    if request.chip_id == 0 || request.chip_id == request.other_chip_id {
+        return Err(format!("Incorrect chips {} and {}", request.chip_id, request.other_chip_id));
+    }
+    let drop_percent = request.drop_percent;
+    if !(0.0..=100.0).contains(&drop_percent) {
+        return Err(format!("Incorrect drop percent {drop_percent}, expected 0 to 100"));
+    }
+    let seed = match request.seed {
+        0 => seed(),
+        seed => seed,
+    };
+    let impairment = Impairment {
+        drop_percent,
+        latency: Duration::from_millis(request.latency_ms.into()),
+        jitter: Duration::from_millis(request.jitter_ms.into()),
+        distribution: request.jitter_distribution.enum_value_or_default(),
+    };
+    let mut impairments = IMPAIRMENTS.lock().unwrap();
+    impairments.set(request.chip_id, request.other_chip_id, impairment, seed);
+    ACTIVE.store(!impairments.is_empty(), Ordering::Relaxed);
+    info!(
+        chip_id: request.chip_id;
+        "impairing chip {}{}: {drop_percent}% dropped, {} ms latency, {} ms {:?} jitter",
+        request.chip_id,
+        match request.other_chip_id {
+            0 => String::new(),
+            other => format!(" to and from chip {other}"),
+        },
+        request.latency_ms,
+        request.jitter_ms,
+        impairment.distribution
+    );
+    Ok(())
+}
+
+/// Returns the requests setting the impairments of netsimd again.
+pub fn impairment_requests() -> Vec<SetLinkImpairmentRequest> {
+    IMPAIRMENTS.lock().unwrap().requests()
+}
+
+/// Removes the impairments, as the scene is reset.
+pub fn reset_impairments() {
+    *IMPAIRMENTS.lock().unwrap() = Impairments::default();
+    ACTIVE.store(false, Ordering::Relaxed);
+}
+
+/// Draws whether packet_hub drops a packet of a chip.
+pub fn drop_packet(chip_kind: ChipKind, facade_id: u32) -> bool {
+    if !ACTIVE.load(Ordering::Relaxed) {
+        return false;
+    }
+    let Some(chip_id) = facade_chip_id(chip_kind, facade_id) else {
+        return false;
+    };
+    let dropped = IMPAIRMENTS.lock().unwrap().drop_chip_packet(chip_id);
+    if dropped {
+        record_dropped(chip_id);
+    }
+    dropped
+}
+
+// Cxx Method for the Bluetooth phy layer to invoke for each receiver
+pub fn drop_link_packet(sender_facade_id: u32, receiver_facade_id: u32) -> bool {
+    if !ACTIVE.load(Ordering::Relaxed) {
+        return false;
+    }
+    let chip_id = |facade_id| facade_chip_id(ChipKind::BLUETOOTH, facade_id);
+    let (Some(sender_id), Some(receiver_id)) =
+        (chip_id(sender_facade_id), chip_id(receiver_facade_id))
+    else {
+        return false;
+    };
+    let dropped = IMPAIRMENTS.lock().unwrap().drop_link_packet(sender_id, receiver_id);
+    if dropped {
+        record_dropped(receiver_id);
+    }
+    dropped
+}
+
+/// Draws the due time of a packet of a chip that packet_hub delays from
+/// `time`.
+pub fn delay_packet(chip_kind: ChipKind, facade_id: u32, time: Duration) -> Option<Duration> {
+    if !ACTIVE.load(Ordering::Relaxed) {
+        return None;
+    }
+    let chip_id = facade_chip_id(chip_kind, facade_id)?;
+    IMPAIRMENTS.lock().unwrap().delay_chip_packet(chip_id, time)
+}
+
+// Cxx Method for the Bluetooth phy layer to invoke for each receiver
+pub fn link_delay_ms(sender_facade_id: u32, receiver_facade_id: u32) -> u32 {
+    if !ACTIVE.load(Ordering::Relaxed) {
+        return 0;
+    }
+    let chip_id = |facade_id| facade_chip_id(ChipKind::BLUETOOTH, facade_id);
+    let (Some(sender_id), Some(receiver_id)) =
+        (chip_id(sender_facade_id), chip_id(receiver_facade_id))
+    else {
+        return 0;
+    };
+    let now = clock().monotonic();
+    let Some(due) = IMPAIRMENTS.lock().unwrap().delay_link_packet(sender_id, receiver_id, now)
+    else {
+        return 0;
+    };
+    // Rounded up, the phy layer schedules in milliseconds.
+    due.saturating_sub(now).as_micros().div_ceil(1000) as u32
+}
+
+/// The Rust impairment handler used directly by Http frontend for POST
+pub fn handle_impairment(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "POST" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let body = String::from_utf8_lossy(&request.body);
+    let impairment_request =
+        match protobuf_json_mapping::parse_from_str::<SetLinkImpairmentRequest>(&body) {
+            Ok(impairment_request) => impairment_request,
+            Err(e) => {
+                writer.put_error(400, format!("Invalid impairment request: {e}").as_str());
+                return;
+            }
+        };
+    for chip_id in [impairment_request.chip_id, impairment_request.other_chip_id] {
+        if chip_id != 0 && facade_id(chip_id).is_none() {
+            writer.put_error(404, format!("Chip {chip_id} not found").as_str());
+            return;
+        }
+    }
+    match set_impairment(&impairment_request) {
+        Ok(()) => writer.put_ok("text/plain", "", &[]),
+        Err(e) => writer.put_error(400, e.as_str()),
+    }
+}
+
+/// impairment handle cxx for grpc server to call
+pub fn handle_impairment_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/impairment".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_impairment(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dropping(drop_percent: f32) -> Impairment {
+        Impairment { drop_percent, ..Default::default() }
+    }
+
+    fn delaying(latency_ms: u64, jitter_ms: u64, distribution: JitterDistribution) -> Impairment {
+        Impairment {
+            latency: Duration::from_millis(latency_ms),
+            jitter: Duration::from_millis(jitter_ms),
+            distribution,
+            ..Default::default()
+        }
+    }
+
+    fn drops(impairments: &mut Impairments, draw: impl Fn(&mut Impairments) -> bool) -> usize {
+        (0..10000).filter(|_| draw(impairments)).count()
+    }
+
+    #[test]
+    fn test_chip_impairment() {
+        let mut impairments = Impairments::default();
+        impairments.set(1, 0, dropping(25.0), 7);
+        let dropped = drops(&mut impairments, |i| i.drop_chip_packet(1));
+        assert!((2300..2700).contains(&dropped), "{dropped}");
+        assert_eq!(drops(&mut impairments, |i| i.drop_chip_packet(2)), 0);
+        // The chip impairment leaves the links of the chip.
+        assert_eq!(drops(&mut impairments, |i| i.drop_link_packet(1, 2)), 0);
+        impairments.set(1, 0, dropping(100.0), 7);
+        assert_eq!(drops(&mut impairments, |i| i.drop_chip_packet(1)), 10000);
+        impairments.set(1, 0, dropping(0.0), 7);
+        assert!(impairments.is_empty());
+    }
+
+    #[test]
+    fn test_requests() {
+        let mut impairments = Impairments::default();
+        impairments.set(2, 1, delaying(50, 40, JitterDistribution::NORMAL), 7);
+        impairments.set(3, 0, dropping(25.0), 9);
+        let requests = impairments.requests();
+        assert_eq!(
+            requests[0],
+            SetLinkImpairmentRequest {
+                chip_id: 1,
+                other_chip_id: 2,
+                seed: 7,
+                latency_ms: 50,
+                jitter_ms: 40,
+                jitter_distribution: JitterDistribution::NORMAL.into(),
+                ..Default::default()
+            }
+        );
+        assert_eq!((requests[1].chip_id, requests[1].drop_percent, requests[1].seed), (3, 25.0, 9));
+        // The impairments set again draw the same sequences.
+        let mut restored = Impairments::default();
+        for request in requests {
+            let impairment = impairments.get(request.chip_id, request.other_chip_id).unwrap();
+            restored.set(request.chip_id, request.other_chip_id, *impairment, request.seed);
+        }
+        let draws = |impairments: &mut Impairments| -> Vec<bool> {
+            (0..100).map(|_| impairments.drop_chip_packet(3)).collect()
+        };
+        assert_eq!(draws(&mut restored), draws(&mut impairments));
+    }
+
+    #[test]
+    fn test_link_impairment() {
+        let mut impairments = Impairments::default();
+        impairments.set(2, 1, dropping(50.0), 7);
+        // Both directions of the link draw from the same generator.
+        let dropped =
+            drops(&mut impairments, |i| i.drop_link_packet(1, 2) | i.drop_link_packet(2, 1));
+        assert!((7200..7800).contains(&dropped), "{dropped}");
+        assert_eq!(drops(&mut impairments, |i| i.drop_link_packet(1, 3)), 0);
+        assert_eq!(drops(&mut impairments, |i| i.drop_chip_packet(1)), 0);
+    }
+
+    #[test]
+    fn test_impairment_seed() {
+        let sequence = |seed| {
+            let mut impairments = Impairments::default();
+            impairments.set(1, 2, dropping(50.0), seed);
+            (0..64).map(|_| impairments.drop_link_packet(1, 2)).collect::<Vec<bool>>()
+        };
+        assert_eq!(sequence(1), sequence(1));
+        assert_ne!(sequence(1), sequence(2));
+    }
+
+    #[test]
+    fn test_delay_order() {
+        let mut impairments = Impairments::default();
+        impairments.set(1, 0, delaying(50, 40, JitterDistribution::UNIFORM), 7);
+        let mut last = Duration::ZERO;
+        for i in 0..1000 {
+            let now = Duration::from_millis(i);
+            let due = impairments.delay_chip_packet(1, now).unwrap();
+            assert!(due >= now + Duration::from_millis(50), "{due:?}");
+            // The packets keep their order whatever the jitter.
+            assert!(due >= last && due <= now + Duration::from_millis(90), "{due:?}");
+            last = due;
+        }
+        assert_eq!(impairments.delay_chip_packet(2, Duration::ZERO), None);
+        assert_eq!(impairments.delay_link_packet(1, 2, Duration::ZERO), None);
+        // The drops alone leave the packets on time.
+        impairments.set(1, 0, dropping(10.0), 7);
+        assert_eq!(impairments.delay_chip_packet(1, Duration::ZERO), None);
+    }
+
+    #[test]
+    fn test_delay_jitter() {
+        let jitters = |distribution| {
+            let mut impairments = Impairments::default();
+            impairments.set(1, 2, delaying(0, 10, distribution), 7);
+            // A second apart, the jitter never holds a packet behind the
+            // previous one.
+            (0..10000)
+                .map(|i| {
+                    let now = Duration::from_secs(i);
+                    let due = impairments.delay_link_packet(2, 1, now).unwrap();
+                    (due - now).as_secs_f32() * 1000.0
+                })
+                .collect::<Vec<f32>>()
+        };
+        let uniform = jitters(JitterDistribution::UNIFORM);
+        let mean = uniform.iter().sum::<f32>() / uniform.len() as f32;
+        assert!(uniform.iter().all(|&jitter| jitter < 10.0));
+        assert!((4.8..5.2).contains(&mean), "{mean}");
+        // The mean absolute normal deviation is sqrt(2 / pi) of the jitter.
+        let normal = jitters(JitterDistribution::NORMAL);
+        let mean = normal.iter().sum::<f32>() / normal.len() as f32;
+        assert!((7.8..8.2).contains(&mean), "{mean}");
+        assert!(normal.iter().any(|&jitter| jitter > 20.0));
+        assert_eq!(normal, jitters(JitterDistribution::NORMAL));
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Packet processors.
+//!
+//! packet_hub passes every packet through the processors registered for
+//! the chip kind before forwarding it. A processor observes the packet and
+//! returns a verdict to forward it, replace it with a modified packet or
+//! drop it. Processors run in registration order; a modified packet is the
+//! input of the next processor. The link impairments drop packets before
+//! the processors see them, and delay them in the delay queue with the
+//! packets of the chips over their bandwidth.
+
+pub mod bandwidth;
+pub mod delay;
+pub mod impairment;
+pub mod mtu;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+use crate::captures::handlers::int_to_chip_kind;
+use crate::captures::pcap_util::PacketDirection;
+#[cfg(not(feature = "wasm"))]
+use crate::config::get_setting;
+use crate::ffi::{PacketVerdict, ProcessedPacket};
+#[cfg(not(feature = "wasm"))]
+use crate::logger::warn;
+use cxx::CxxVector;
+use frontend_proto::common::ChipKind;
+use lazy_static::lazy_static;
+use std::sync::RwLock;
+
+/// Where a packet is in the packet hub.
+pub struct PacketContext {
+    pub chip_kind: ChipKind,
+    pub facade_id: u32,
+    pub packet_type: u32,
+    pub direction: PacketDirection,
+}
+
+/// The decision of a processor about a packet.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Forward,
+    Modify(Vec<u8>),
+    Drop,
+}
+
+/// A packet observer and manipulator.
+pub trait PacketProcessor: Send + Sync {
+    /// Name used in logs.
+    fn name(&self) -> &str;
+
+    fn process(&self, context: &PacketContext, packet: &[u8]) -> Verdict;
+}
+
+struct Registration {
+    // None applies the processor to all chip kinds.
+    chip_kind: Option<ChipKind>,
+    processor: Box<dyn PacketProcessor>,
+}
+
+/// An ordered list of processors.
+#[derive(Default)]
+pub struct Processors {
+    registrations: Vec<Registration>,
+}
+
+impl Processors {
+    /// Appends a processor for `chip_kind`, or for all chip kinds if None.
+    pub fn register(&mut self, chip_kind: Option<ChipKind>, processor: Box<dyn PacketProcessor>) {
+        self.registrations.push(Registration { chip_kind, processor });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.registrations.is_empty()
+    }
+
+    /// Returns the names of the processors applied to `chip_kind`.
+    pub fn names(&self, chip_kind: ChipKind) -> Vec<String> {
+        self.registrations
+            .iter()
+            .filter(|registration| registration.chip_kind.is_none_or(|kind| kind == chip_kind))
+            .map(|registration| registration.processor.name().to_string())
+            .collect()
+    }
+
+    /// Passes a packet through the processors of its chip kind.
+    pub fn process(&self, context: &PacketContext, packet: &[u8]) -> Verdict {
+        let mut modified: Option<Vec<u8>> = None;
+        for registration in self.registrations.iter() {
+            if registration.chip_kind.is_some_and(|kind| kind != context.chip_kind) {
+                continue;
+            }
+            let current = modified.as_deref().unwrap_or(packet);
+            match registration.processor.process(context, current) {
+                Verdict::Forward => {}
+                Verdict::Modify(packet) => modified = Some(packet),
+                Verdict::Drop => return Verdict::Drop,
+            }
+        }
+        match modified {
+            Some(packet) => Verdict::Modify(packet),
+            None => Verdict::Forward,
+        }
+    }
+}
+
+lazy_static! {
+    static ref PROCESSORS: RwLock<Processors> = RwLock::new(load_processors());
+}
+
+// Creates the processors configured in the settings.
+fn load_processors() -> Processors {
+    #[allow(unused_mut)]
+    let mut processors = Processors::default();
+    #[cfg(feature = "wasm")]
+    wasm::load_plugins(&mut processors);
+    #[cfg(not(feature = "wasm"))]
+    if !get_setting("processor.wasm_plugins").is_empty() {
+        warn!("processor.wasm_plugins is set but netsimd is built without the wasm feature");
+    }
+    processors
+}
+
+/// Appends a processor to the netsimd processors.
+pub fn register_processor(chip_kind: Option<ChipKind>, processor: Box<dyn PacketProcessor>) {
+    PROCESSORS.write().unwrap().register(chip_kind, processor);
+}
+
+/// Returns the names of the netsimd processors applied to `chip_kind`.
+pub fn processor_names(chip_kind: ChipKind) -> Vec<String> {
+    PROCESSORS.read().unwrap().names(chip_kind)
+}
+
+/// Passes a packet through the netsimd processors.
+pub fn process(context: &PacketContext, packet: &[u8]) -> Verdict {
+    PROCESSORS.read().unwrap().process(context, packet)
+}
+
+fn process_packet(
+    kind: u32,
+    facade_id: u32,
+    packet: &CxxVector<u8>,
+    packet_type: u32,
+    direction: PacketDirection,
+) -> ProcessedPacket {
+    if impairment::drop_packet(int_to_chip_kind(kind), facade_id) {
+        return ProcessedPacket { verdict: PacketVerdict::Drop, packet: Vec::new() };
+    }
+    let processors = PROCESSORS.read().unwrap();
+    if processors.is_empty() {
+        return ProcessedPacket { verdict: PacketVerdict::Forward, packet: Vec::new() };
+    }
+    let context =
+        PacketContext { chip_kind: int_to_chip_kind(kind), facade_id, packet_type, direction };
+    match processors.process(&context, packet.as_slice()) {
+        Verdict::Forward => ProcessedPacket { verdict: PacketVerdict::Forward, packet: Vec::new() },
+        Verdict::Modify(packet) => ProcessedPacket { verdict: PacketVerdict::Modify, packet },
+        Verdict::Drop => ProcessedPacket { verdict: PacketVerdict::Drop, packet: Vec::new() },
+    }
+}
+
+// Cxx Method for packet_hub to invoke (Host to Controller Packet Flow)
+pub fn process_request(
+    kind: u32,
+    facade_id: u32,
+    packet: &CxxVector<u8>,
+    packet_type: u32,
+) -> ProcessedPacket {
+    process_packet(kind, facade_id, packet, packet_type, PacketDirection::HostToController)
+}
+
+// Cxx Method for packet_hub to invoke (Controller to Host Packet Flow)
+pub fn process_response(
+    kind: u32,
+    facade_id: u32,
+    packet: &CxxVector<u8>,
+    packet_type: u32,
+) -> ProcessedPacket {
+    process_packet(kind, facade_id, packet, packet_type, PacketDirection::ControllerToHost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Append(u8);
+
+    impl PacketProcessor for Append {
+        fn name(&self) -> &str {
+            "append"
+        }
+        fn process(&self, _context: &PacketContext, packet: &[u8]) -> Verdict {
+            let mut packet = packet.to_vec();
+            packet.push(self.0);
+            Verdict::Modify(packet)
+        }
+    }
+
+    struct DropAll;
+
+    impl PacketProcessor for DropAll {
+        fn name(&self) -> &str {
+            "drop"
+        }
+        fn process(&self, _context: &PacketContext, _packet: &[u8]) -> Verdict {
+            Verdict::Drop
+        }
+    }
+
+    fn context(chip_kind: ChipKind) -> PacketContext {
+        PacketContext {
+            chip_kind,
+            facade_id: 0,
+            packet_type: 0,
+            direction: PacketDirection::HostToController,
+        }
+    }
+
+    #[test]
+    fn test_no_processors() {
+        let processors = Processors::default();
+        assert_eq!(processors.process(&context(ChipKind::WIFI), &[1]), Verdict::Forward);
+    }
+
+    #[test]
+    fn test_chained_modify() {
+        let mut processors = Processors::default();
+        processors.register(None, Box::new(Append(2)));
+        processors.register(Some(ChipKind::WIFI), Box::new(Append(3)));
+        processors.register(Some(ChipKind::BLUETOOTH), Box::new(Append(4)));
+        assert_eq!(
+            processors.process(&context(ChipKind::WIFI), &[1]),
+            Verdict::Modify(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_drop() {
+        let mut processors = Processors::default();
+        processors.register(Some(ChipKind::UWB), Box::new(DropAll));
+        processors.register(None, Box::new(Append(2)));
+        assert_eq!(processors.process(&context(ChipKind::UWB), &[1]), Verdict::Drop);
+        assert_eq!(processors.process(&context(ChipKind::WIFI), &[1]), Verdict::Modify(vec![1, 2]));
+        assert_eq!(processors.names(ChipKind::UWB), ["drop", "append"]);
+        assert_eq!(processors.names(ChipKind::WIFI), ["append"]);
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! MTU of the links.
+//!
+//! packet_hub checks every packet from an emulator against the MTU of its
+//! chip kind after the packet processors. Oversized Bluetooth ACL packets
+//! are split into fragments the way a controller with a smaller buffer
+//! does, so the receiving stacks exercise L2CAP reassembly; each fragment
+//! is routed and captured as a packet of its own.
+
+use cxx::CxxVector;
+use frontend_proto::common::ChipKind;
+use lazy_static::lazy_static;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::captures::handlers::int_to_chip_kind;
+use crate::config::{get_setting, get_setting_parsed};
+use crate::ffi::{Fragment, FragmentedPacket, PacketVerdict};
+use crate::logger::warn;
+use crate::metrics::{register_collector, MetricKind, Sample};
+
+/// HCI packet type of ACL data.
+const HCI_ACL: u32 = 2;
+/// Length of the ACL header: handle and flags, then data length.
+const ACL_HEADER_LEN: usize = 4;
+/// Packet boundary flag of a continuing fragment.
+const PB_CONTINUING: u16 = 0b01 << 12;
+const PB_MASK: u16 = 0b11 << 12;
+
+/// What happens to a packet exceeding the MTU.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MtuPolicy {
+    Fragment,
+    Reject,
+}
+
+impl MtuPolicy {
+    fn from_setting(value: &str) -> MtuPolicy {
+        match value {
+            "fragment" => MtuPolicy::Fragment,
+            "reject" => MtuPolicy::Reject,
+            _ => {
+                warn!("unknown mtu policy {value}, using fragment");
+                MtuPolicy::Fragment
+            }
+        }
+    }
+}
+
+/// The outcome of the MTU check of a packet.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fragmented {
+    Forward,
+    Fragments(Vec<Vec<u8>>),
+    Reject,
+}
+
+/// The MTU of each chip kind; 0 is unlimited.
+pub struct Mtu {
+    pub bluetooth: usize,
+    pub wifi: usize,
+    pub policy: MtuPolicy,
+}
+
+impl Mtu {
+    fn from_settings() -> Mtu {
+        Mtu {
+            bluetooth: get_setting_parsed("mtu.bluetooth"),
+            wifi: get_setting_parsed("mtu.wifi"),
+            policy: MtuPolicy::from_setting(&get_setting("mtu.policy")),
+        }
+    }
+
+    /// Checks a packet from an emulator against the MTU.
+    pub fn check(&self, chip_kind: ChipKind, packet_type: u32, packet: &[u8]) -> Fragmented {
+        match chip_kind {
+            ChipKind::BLUETOOTH if self.bluetooth > 0 && packet_type == HCI_ACL => {
+                match acl_data_len(packet) {
+                    Some(len) if len > self.bluetooth => match self.policy {
+                        MtuPolicy::Fragment => {
+                            Fragmented::Fragments(fragment_acl(packet, self.bluetooth))
+                        }
+                        MtuPolicy::Reject => Fragmented::Reject,
+                    },
+                    _ => Fragmented::Forward,
+                }
+            }
+            ChipKind::WIFI if self.wifi > 0 && packet.len() > self.wifi => Fragmented::Reject,
+            _ => Fragmented::Forward,
+        }
+    }
+}
+
+// Returns the data length of a well-formed ACL packet.
+fn acl_data_len(packet: &[u8]) -> Option<usize> {
+    let header = packet.get(..ACL_HEADER_LEN)?;
+    let len = u16::from_le_bytes([header[2], header[3]]) as usize;
+    (packet.len() == ACL_HEADER_LEN + len).then_some(len)
+}
+
+/// Splits a well-formed ACL packet into packets of at most `mtu` data
+/// bytes. The first fragment keeps the packet boundary flag of the packet
+/// and the following fragments are continuing fragments.
+pub fn fragment_acl(packet: &[u8], mtu: usize) -> Vec<Vec<u8>> {
+    let handle_and_flags = u16::from_le_bytes([packet[0], packet[1]]);
+    packet[ACL_HEADER_LEN..]
+        .chunks(mtu)
+        .enumerate()
+        .map(|(i, data)| {
+            let flags = match i {
+                0 => handle_and_flags,
+                _ => handle_and_flags & !PB_MASK | PB_CONTINUING,
+            };
+            let mut fragment = Vec::with_capacity(ACL_HEADER_LEN + data.len());
+            fragment.extend_from_slice(&flags.to_le_bytes());
+            fragment.extend_from_slice(&(data.len() as u16).to_le_bytes());
+            fragment.extend_from_slice(data);
+            fragment
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct Counters {
+    fragmented: AtomicU64,
+    fragments: AtomicU64,
+    rejected: AtomicU64,
+}
+
+lazy_static! {
+    static ref MTU: Mtu = {
+        register_collector(collect_metrics);
+        Mtu::from_settings()
+    };
+    static ref COUNTERS: Counters = Counters::default();
+}
+
+fn collect_metrics() -> Vec<Sample> {
+    let counter = |name, help, value: &AtomicU64| Sample {
+        name,
+        help,
+        kind: MetricKind::Counter,
+        labels: vec![],
+        value: value.load(Ordering::Relaxed) as f64,
+    };
+    vec![
+        counter(
+            "netsim_mtu_fragmented_packets_total",
+            "Packets split into fragments by the MTU.",
+            &COUNTERS.fragmented,
+        ),
+        counter(
+            "netsim_mtu_fragments_total",
+            "Fragments of the packets split by the MTU.",
+            &COUNTERS.fragments,
+        ),
+        counter(
+            "netsim_mtu_rejected_packets_total",
+            "Packets dropped for exceeding the MTU.",
+            &COUNTERS.rejected,
+        ),
+    ]
+}
+
+// Cxx Method for packet_hub to invoke (Host to Controller Packet Flow)
+pub fn fragment_request(kind: u32, packet: &CxxVector<u8>, packet_type: u32) -> FragmentedPacket {
+    match MTU.check(int_to_chip_kind(kind), packet_type, packet.as_slice()) {
+        Fragmented::Forward => {
+            FragmentedPacket { verdict: PacketVerdict::Forward, fragments: Vec::new() }
+        }
+        Fragmented::Fragments(fragments) => {
+            COUNTERS.fragmented.fetch_add(1, Ordering::Relaxed);
+            COUNTERS.fragments.fetch_add(fragments.len() as u64, Ordering::Relaxed);
+            FragmentedPacket {
+                verdict: PacketVerdict::Modify,
+                fragments: fragments.into_iter().map(|packet| Fragment { packet }).collect(),
+            }
+        }
+        Fragmented::Reject => {
+            COUNTERS.rejected.fetch_add(1, Ordering::Relaxed);
+            FragmentedPacket { verdict: PacketVerdict::Drop, fragments: Vec::new() }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ACL packet of handle 0x002a, first automatically flushable fragment.
+    fn acl(data: &[u8]) -> Vec<u8> {
+        let mut packet = vec![0x2a, 0x20];
+        packet.extend_from_slice(&(data.len() as u16).to_le_bytes());
+        packet.extend_from_slice(data);
+        packet
+    }
+
+    fn test_mtu(policy: MtuPolicy) -> Mtu {
+        Mtu { bluetooth: 4, wifi: 8, policy }
+    }
+
+    #[test]
+    fn test_fragment_acl() {
+        let fragments = fragment_acl(&acl(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]), 4);
+        assert_eq!(
+            fragments,
+            [
+                vec![0x2a, 0x20, 4, 0, 1, 2, 3, 4],
+                vec![0x2a, 0x10, 4, 0, 5, 6, 7, 8],
+                vec![0x2a, 0x10, 2, 0, 9, 10]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let mtu = test_mtu(MtuPolicy::Fragment);
+        let small = acl(&[1, 2, 3, 4]);
+        let large = acl(&[1, 2, 3, 4, 5]);
+        assert_eq!(mtu.check(ChipKind::BLUETOOTH, HCI_ACL, &small), Fragmented::Forward);
+        assert!(matches!(
+            mtu.check(ChipKind::BLUETOOTH, HCI_ACL, &large),
+            Fragmented::Fragments(fragments) if fragments.len() == 2
+        ));
+        // Commands and malformed ACL packets are not fragmented.
+        assert_eq!(mtu.check(ChipKind::BLUETOOTH, 1, &large), Fragmented::Forward);
+        assert_eq!(mtu.check(ChipKind::BLUETOOTH, HCI_ACL, &large[..6]), Fragmented::Forward);
+        assert_eq!(mtu.check(ChipKind::WIFI, 0, &[0; 9]), Fragmented::Reject);
+        assert_eq!(mtu.check(ChipKind::WIFI, 0, &[0; 8]), Fragmented::Forward);
+        let mtu = test_mtu(MtuPolicy::Reject);
+        assert_eq!(mtu.check(ChipKind::BLUETOOTH, HCI_ACL, &large), Fragmented::Reject);
+    }
+}
@@ -0,0 +1,309 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! WebAssembly packet processors.
+//!
+//! Plugins are WebAssembly modules run in a sandboxed interpreter with a
+//! fuel budget per packet and a memory cap. A plugin exports:
+//!
+//! * `memory`
+//! * `netsim_alloc(len: i32) -> i32`, returning a buffer for the packet.
+//! * `netsim_process(chip_kind: i32, facade_id: i32, packet_type: i32,
+//!   direction: i32, ptr: i32, len: i32) -> i32`, returning 0 to forward,
+//!   1 to drop or 2 to forward the packet passed to `netsim.set_packet`.
+//!
+//! and may import from module `netsim`:
+//!
+//! * `log(ptr: i32, len: i32)`, printing a message.
+//! * `set_packet(ptr: i32, len: i32)`, providing the modified packet.
+//!
+//! Plugins are configured with the `processor.wasm_plugins` setting as a
+//! comma separated list of `<chip kind>=<path>`, where the chip kind is
+//! `bluetooth`, `wifi`, `uwb`, `nfc` or `all`. A plugin that traps
+//! repeatedly is disabled and its packets are forwarded unchanged.
+
+use super::{PacketContext, PacketProcessor, Processors, Verdict};
+use crate::config::{get_setting, get_setting_parsed};
+use crate::logger::{info, warn};
+use frontend_proto::common::ChipKind;
+use protobuf::Enum;
+use std::sync::Mutex;
+use wasmi::{
+    Caller, Config, Engine, Linker, Memory, Module, Store, StoreLimits, StoreLimitsBuilder,
+    TypedFunc,
+};
+
+const MEMORY_LIMIT: usize = 16 << 20;
+const MAX_CONSECUTIVE_FAILURES: u32 = 10;
+
+const PROCESS_FORWARD: i32 = 0;
+const PROCESS_DROP: i32 = 1;
+const PROCESS_MODIFY: i32 = 2;
+
+struct HostState {
+    name: String,
+    limits: StoreLimits,
+    modified: Option<Vec<u8>>,
+}
+
+type ProcessFunc = TypedFunc<(i32, i32, i32, i32, i32, i32), i32>;
+
+struct Instance {
+    store: Store<HostState>,
+    memory: Memory,
+    alloc: TypedFunc<i32, i32>,
+    process: ProcessFunc,
+    failures: u32,
+}
+
+/// A packet processor backed by a WebAssembly module.
+pub struct WasmProcessor {
+    name: String,
+    fuel: u64,
+    instance: Mutex<Instance>,
+}
+
+fn read_guest(caller: &Caller<'_, HostState>, ptr: i32, len: i32) -> Option<Vec<u8>> {
+    let memory = caller.get_export("memory")?.into_memory()?;
+    let mut buffer = vec![0u8; usize::try_from(len).ok()?];
+    memory.read(caller, usize::try_from(ptr).ok()?, &mut buffer).ok()?;
+    Some(buffer)
+}
+
+impl WasmProcessor {
+    /// Instantiates a plugin from the bytes of a WebAssembly module.
+    pub fn new(name: &str, wasm: &[u8], fuel: u64) -> Result<WasmProcessor, String> {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, wasm).map_err(|e| e.to_string())?;
+        let limits = StoreLimitsBuilder::new().memory_size(MEMORY_LIMIT).build();
+        let mut store =
+            Store::new(&engine, HostState { name: name.to_string(), limits, modified: None });
+        store.limiter(|state| &mut state.limits);
+        let mut linker = Linker::<HostState>::new(&engine);
+        linker
+            .func_wrap("netsim", "log", |caller: Caller<'_, HostState>, ptr: i32, len: i32| {
+                if let Some(message) = read_guest(&caller, ptr, len) {
+                    info!("plugin {}: {}", caller.data().name, String::from_utf8_lossy(&message));
+                }
+            })
+            .map_err(|e| e.to_string())?;
+        linker
+            .func_wrap(
+                "netsim",
+                "set_packet",
+                |mut caller: Caller<'_, HostState>, ptr: i32, len: i32| {
+                    let packet = read_guest(&caller, ptr, len);
+                    caller.data_mut().modified = packet;
+                },
+            )
+            .map_err(|e| e.to_string())?;
+        store.add_fuel(fuel).map_err(|e| e.to_string())?;
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .and_then(|pre| pre.start(&mut store))
+            .map_err(|e| e.to_string())?;
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or_else(|| "missing export memory".to_string())?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&store, "netsim_alloc")
+            .map_err(|e| e.to_string())?;
+        let process = instance
+            .get_typed_func::<(i32, i32, i32, i32, i32, i32), i32>(&store, "netsim_process")
+            .map_err(|e| e.to_string())?;
+        Ok(WasmProcessor {
+            name: name.to_string(),
+            fuel,
+            instance: Mutex::new(Instance { store, memory, alloc, process, failures: 0 }),
+        })
+    }
+
+    fn call(
+        &self,
+        instance: &mut Instance,
+        context: &PacketContext,
+        packet: &[u8],
+    ) -> Result<Verdict, String> {
+        // Top up the fuel so every packet gets the same budget.
+        let remaining = instance.store.consume_fuel(0).map_err(|e| e.to_string())?;
+        if remaining < self.fuel {
+            instance.store.add_fuel(self.fuel - remaining).map_err(|e| e.to_string())?;
+        }
+        let len = i32::try_from(packet.len()).map_err(|e| e.to_string())?;
+        let ptr = instance.alloc.call(&mut instance.store, len).map_err(|e| e.to_string())?;
+        let offset = usize::try_from(ptr).map_err(|e| e.to_string())?;
+        instance.memory.write(&mut instance.store, offset, packet).map_err(|e| e.to_string())?;
+        instance.store.data_mut().modified = None;
+        let args = (
+            context.chip_kind.value(),
+            context.facade_id as i32,
+            context.packet_type as i32,
+            context.direction as i32,
+            ptr,
+            len,
+        );
+        match instance.process.call(&mut instance.store, args).map_err(|e| e.to_string())? {
+            PROCESS_FORWARD => Ok(Verdict::Forward),
+            PROCESS_DROP => Ok(Verdict::Drop),
+            PROCESS_MODIFY => match instance.store.data_mut().modified.take() {
+                Some(packet) => Ok(Verdict::Modify(packet)),
+                None => Err("modify without set_packet".to_string()),
+            },
+            result => Err(format!("unknown result {result}")),
+        }
+    }
+}
+
+impl PacketProcessor for WasmProcessor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn process(&self, context: &PacketContext, packet: &[u8]) -> Verdict {
+        let mut instance = self.instance.lock().unwrap();
+        if instance.failures >= MAX_CONSECUTIVE_FAILURES {
+            return Verdict::Forward;
+        }
+        match self.call(&mut instance, context, packet) {
+            Ok(verdict) => {
+                instance.failures = 0;
+                verdict
+            }
+            Err(e) => {
+                instance.failures += 1;
+                warn!("plugin {} failed: {}", self.name, e);
+                if instance.failures == MAX_CONSECUTIVE_FAILURES {
+                    warn!("plugin {} disabled", self.name);
+                }
+                Verdict::Forward
+            }
+        }
+    }
+}
+
+// Parses `<chip kind>=<path>`; chip kind None applies to all chips.
+fn parse_plugin(entry: &str) -> Option<(Option<ChipKind>, &str)> {
+    let (kind, path) = entry.split_once('=')?;
+    let chip_kind = match kind.trim().to_lowercase().as_str() {
+        "all" => None,
+        "bluetooth" => Some(ChipKind::BLUETOOTH),
+        "wifi" => Some(ChipKind::WIFI),
+        "uwb" => Some(ChipKind::UWB),
+        "nfc" => Some(ChipKind::NFC),
+        _ => return None,
+    };
+    Some((chip_kind, path.trim()))
+}
+
+/// Registers the plugins configured in the `processor.wasm_plugins` setting.
+pub fn load_plugins(processors: &mut Processors) {
+    let fuel = get_setting_parsed("processor.wasm_fuel");
+    for entry in get_setting("processor.wasm_plugins").split(',').filter(|e| !e.trim().is_empty()) {
+        let Some((chip_kind, path)) = parse_plugin(entry) else {
+            warn!("invalid plugin entry {entry}");
+            continue;
+        };
+        let result = std::fs::read(path)
+            .map_err(|e| e.to_string())
+            .and_then(|wasm| WasmProcessor::new(path, &wasm, fuel));
+        match result {
+            Ok(processor) => {
+                info!("loaded plugin {path}");
+                processors.register(chip_kind, Box::new(processor));
+            }
+            Err(e) => warn!("unable to load plugin {path}: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::captures::pcap_util::PacketDirection;
+
+    // Drops WIFI packets, appends 0xff to packets of type 2 and forwards the
+    // rest, compiled from:
+    //
+    // (module
+    //   (import "netsim" "set_packet" (func $set_packet (param i32 i32)))
+    //   (memory (export "memory") 1)
+    //   (func (export "netsim_alloc") (param i32) (result i32) (i32.const 16))
+    //   (func (export "netsim_process")
+    //         (param $kind i32) (param $facade i32) (param $type i32)
+    //         (param $dir i32) (param $ptr i32) (param $len i32) (result i32)
+    //     (if (i32.eq (local.get $kind) (i32.const 2)) (then (return (i32.const 1))))
+    //     (if (i32.eq (local.get $type) (i32.const 2))
+    //       (then
+    //         (i32.store8 (i32.add (local.get $ptr) (local.get $len)) (i32.const 255))
+    //         (call $set_packet (local.get $ptr) (i32.add (local.get $len) (i32.const 1)))
+    //         (return (i32.const 2))))
+    //     (i32.const 0)))
+    static PLUGIN: &[u8] = include_bytes!("test_plugin.wasm");
+
+    // Loops forever, compiled from:
+    //
+    // (module
+    //   (memory (export "memory") 1)
+    //   (func (export "netsim_alloc") (param i32) (result i32) (i32.const 0))
+    //   (func (export "netsim_process")
+    //         (param i32 i32 i32 i32 i32 i32) (result i32)
+    //     (loop (br 0))
+    //     (i32.const 0)))
+    static SPIN: &[u8] = include_bytes!("test_spin.wasm");
+
+    fn context(chip_kind: ChipKind, packet_type: u32) -> PacketContext {
+        PacketContext {
+            chip_kind,
+            facade_id: 0,
+            packet_type,
+            direction: PacketDirection::HostToController,
+        }
+    }
+
+    #[test]
+    fn test_plugin_verdicts() {
+        let processor = WasmProcessor::new("test", PLUGIN, 100_000).unwrap();
+        assert_eq!(processor.process(&context(ChipKind::WIFI, 0), &[1, 2]), Verdict::Drop);
+        assert_eq!(
+            processor.process(&context(ChipKind::BLUETOOTH, 2), &[1, 2]),
+            Verdict::Modify(vec![1, 2, 255])
+        );
+        assert_eq!(processor.process(&context(ChipKind::BLUETOOTH, 1), &[1, 2]), Verdict::Forward);
+    }
+
+    #[test]
+    fn test_plugin_out_of_fuel() {
+        let processor = WasmProcessor::new("spin", SPIN, 1_000).unwrap();
+        for _ in 0..MAX_CONSECUTIVE_FAILURES + 1 {
+            assert_eq!(processor.process(&context(ChipKind::UWB, 0), &[1]), Verdict::Forward);
+        }
+        assert_eq!(processor.instance.lock().unwrap().failures, MAX_CONSECUTIVE_FAILURES);
+    }
+
+    #[test]
+    fn test_invalid_module() {
+        assert!(WasmProcessor::new("invalid", &[0, 1, 2], 1_000).is_err());
+    }
+
+    #[test]
+    fn test_parse_plugin() {
+        assert_eq!(parse_plugin("wifi=/a.wasm"), Some((Some(ChipKind::WIFI), "/a.wasm")));
+        assert_eq!(parse_plugin(" all = /b.wasm "), Some((None, "/b.wasm")));
+        assert_eq!(parse_plugin("nfc=/c.wasm"), Some((Some(ChipKind::NFC), "/c.wasm")));
+        assert_eq!(parse_plugin("fm=/c.wasm"), None);
+        assert_eq!(parse_plugin("/d.wasm"), None);
+    }
+}
@@ -0,0 +1,468 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Radio propagation models.
+//!
+//! A propagation model computes the path loss of the radio link between
+//! two devices, which the facades subtract from the transmit power to give
+//! the RSSI at the receiver. Models implement `PropagationModel` and are
+//! registered by name with `register_model`; the `propagation.model`
+//! setting selects the model of netsimd among:
+//!
+//! * `free_space`, the free space path loss, the default;
+//! * `log_distance`, the log-distance path loss with the exponent of the
+//!   `propagation.exponent` setting;
+//! * `multi_wall`, the log-distance path loss plus the loss of each wall of
+//!   the `propagation.walls` setting crossed by the link on the floor plan,
+//!   for indoor scenes;
+//! * `external`, calling the ComputePathLoss RPC of the
+//!   PropagationModelService at `propagation.address`, so ray-tracing or
+//!   measured-data models run outside netsimd.
+//!
+//! The external path losses are cached until the devices move, and a failed
+//! call falls back to the free space path loss.
+//!
+//! The PatchPropagation RPC selects the model of the scene at runtime, with
+//! its exponent and walls, so a test can switch between signal degradation
+//! curves without restarting netsimd.
+//!
+//! /v1/propagation --> handle_propagation (GET the model, PATCH a Propagation)
+
+use frontend_proto::frontend::propagation::Wall as WallProto;
+use frontend_proto::frontend::Propagation;
+use frontend_proto::model::Position;
+use lazy_static::lazy_static;
+use protobuf::MessageField;
+use std::collections::{BTreeMap, HashMap};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::config::{get_setting, get_setting_parsed};
+use crate::ffi::{compute_external_path_loss, CxxServerResponseWriter, RadioLink};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::{info, warn};
+use crate::ranging::{log_distance_path_loss, path_loss};
+use crate::CxxServerResponseWriterWrapper;
+
+/// Path losses cached by the external model before the cache is cleared.
+const MAX_CACHED_LINKS: usize = 4096;
+
+impl RadioLink {
+    /// Returns the distance between the devices in meters.
+    pub fn distance(&self) -> f32 {
+        let (a, b) = (&self.sender, &self.receiver);
+        ((a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)).sqrt()
+    }
+}
+
+/// A radio propagation model.
+pub trait PropagationModel: Send + Sync {
+    /// Returns the path loss (dB) of a link, relative to a transmit power
+    /// calibrated to 1 meter.
+    fn path_loss(&self, link: &RadioLink) -> f32;
+}
+
+/// The free space path loss.
+pub struct FreeSpace;
+
+impl PropagationModel for FreeSpace {
+    fn path_loss(&self, link: &RadioLink) -> f32 {
+        match link.distance() {
+            d if d > 0.0 => path_loss(d),
+            _ => 0.0,
+        }
+    }
+}
+
+/// The log-distance path loss.
+pub struct LogDistance {
+    pub exponent: f32,
+}
+
+impl PropagationModel for LogDistance {
+    fn path_loss(&self, link: &RadioLink) -> f32 {
+        match link.distance() {
+            d if d > 0.0 => log_distance_path_loss(d, self.exponent),
+            _ => 0.0,
+        }
+    }
+}
+
+/// A wall of the floor plan, between two (x, y) ends in meters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Wall {
+    pub start: (f32, f32),
+    pub end: (f32, f32),
+    // Attenuation (dB) of the links crossing the wall.
+    pub loss: f32,
+}
+
+/// Parses walls separated by ';' as x1,y1,x2,y2 ends with an optional loss,
+/// `default_loss` otherwise.
+pub fn parse_walls(value: &str, default_loss: f32) -> Option<Vec<Wall>> {
+    value
+        .split(';')
+        .filter(|wall| !wall.trim().is_empty())
+        .map(|wall| {
+            let numbers: Vec<f32> =
+                wall.split(',').map(|n| n.trim().parse().ok()).collect::<Option<_>>()?;
+            if numbers.iter().any(|n| !n.is_finite()) {
+                return None;
+            }
+            match numbers[..] {
+                [x1, y1, x2, y2] => {
+                    Some(Wall { start: (x1, y1), end: (x2, y2), loss: default_loss })
+                }
+                [x1, y1, x2, y2, loss] => Some(Wall { start: (x1, y1), end: (x2, y2), loss }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+// Positive when c is left of the line from a to b.
+fn orientation(a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> f32 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+// The segments a-b and c-d cross, not counting touching ends.
+fn crosses(a: (f32, f32), b: (f32, f32), c: (f32, f32), d: (f32, f32)) -> bool {
+    orientation(a, b, c) * orientation(a, b, d) < 0.0
+        && orientation(c, d, a) * orientation(c, d, b) < 0.0
+}
+
+/// The log-distance path loss plus the loss of the walls crossed by the
+/// link, the multi-wall model of indoor propagation.
+pub struct MultiWall {
+    pub exponent: f32,
+    pub walls: Vec<Wall>,
+}
+
+impl PropagationModel for MultiWall {
+    fn path_loss(&self, link: &RadioLink) -> f32 {
+        let a = (link.sender.x, link.sender.y);
+        let b = (link.receiver.x, link.receiver.y);
+        let walls: f32 = self
+            .walls
+            .iter()
+            .filter(|wall| crosses(a, b, wall.start, wall.end))
+            .map(|wall| wall.loss)
+            .sum();
+        LogDistance { exponent: self.exponent }.path_loss(link) + walls
+    }
+}
+
+/// Calls an external model at an address, None when the call fails.
+pub type Callout = fn(&str, &RadioLink) -> Option<f32>;
+
+/// The link identity and geometry, with the coordinates as bits.
+type LinkKey = (u32, u32, u32, [u32; 6]);
+
+fn link_key(link: &RadioLink) -> LinkKey {
+    let (a, b) = (&link.sender, &link.receiver);
+    let coordinates = [a.x, a.y, a.z, b.x, b.y, b.z].map(f32::to_bits);
+    (link.chip_kind, link.sender_id, link.receiver_id, coordinates)
+}
+
+/// A model running outside netsimd.
+pub struct External {
+    address: String,
+    callout: Callout,
+    cache: Mutex<HashMap<LinkKey, f32>>,
+    // The failures are reported once until a call succeeds.
+    failing: AtomicBool,
+}
+
+impl External {
+    pub fn new(address: &str, callout: Callout) -> Self {
+        External {
+            address: address.to_string(),
+            callout,
+            cache: Mutex::new(HashMap::new()),
+            failing: AtomicBool::new(false),
+        }
+    }
+}
+
+impl PropagationModel for External {
+    fn path_loss(&self, link: &RadioLink) -> f32 {
+        let key = link_key(link);
+        if let Some(path_loss) = self.cache.lock().unwrap().get(&key) {
+            return *path_loss;
+        }
+        let Some(path_loss) = (self.callout)(&self.address, link) else {
+            if !self.failing.swap(true, Ordering::Relaxed) {
+                warn!("external propagation model at {} failed, using free space", self.address);
+            }
+            return FreeSpace.path_loss(link);
+        };
+        self.failing.store(false, Ordering::Relaxed);
+        let mut cache = self.cache.lock().unwrap();
+        if cache.len() >= MAX_CACHED_LINKS {
+            cache.clear();
+        }
+        cache.insert(key, path_loss);
+        path_loss
+    }
+}
+
+fn call_external(address: &str, link: &RadioLink) -> Option<f32> {
+    let mut path_loss = 0.0;
+    compute_external_path_loss(address, link, &mut path_loss).then_some(path_loss)
+}
+
+lazy_static! {
+    static ref MODELS: RwLock<BTreeMap<String, Arc<dyn PropagationModel>>> = {
+        let mut models: BTreeMap<String, Arc<dyn PropagationModel>> = BTreeMap::new();
+        models.insert("free_space".to_string(), Arc::new(FreeSpace));
+        let exponent = get_setting_parsed("propagation.exponent");
+        models.insert("log_distance".to_string(), Arc::new(LogDistance { exponent }));
+        let walls = setting_walls();
+        models.insert("multi_wall".to_string(), Arc::new(MultiWall { exponent, walls }));
+        let address = get_setting("propagation.address");
+        models.insert("external".to_string(), Arc::new(External::new(&address, call_external)));
+        RwLock::new(models)
+    };
+    static ref SELECTED: RwLock<Propagation> = RwLock::new(selection(
+        &get_setting("propagation.model"),
+        get_setting_parsed("propagation.exponent"),
+        &setting_walls()
+    ));
+}
+
+fn setting_walls() -> Vec<Wall> {
+    let default_loss = get_setting_parsed("propagation.wall_loss");
+    parse_walls(&get_setting("propagation.walls"), default_loss).unwrap_or_default()
+}
+
+// Describes the selected model, with the exponent and the walls of the
+// models using them.
+fn selection(name: &str, exponent: f32, walls: &[Wall]) -> Propagation {
+    let point = |(x, y): (f32, f32)| MessageField::some(Position { x, y, ..Default::default() });
+    let mut propagation = Propagation { name: name.to_string(), ..Default::default() };
+    if name == "log_distance" || name == "multi_wall" {
+        propagation.exponent = exponent;
+    }
+    if name == "multi_wall" {
+        propagation.walls = walls
+            .iter()
+            .map(|wall| WallProto {
+                start: point(wall.start),
+                end: point(wall.end),
+                loss: wall.loss,
+                ..Default::default()
+            })
+            .collect();
+    }
+    propagation
+}
+
+/// Registers a model under a name, replacing the model of that name.
+pub fn register_model(name: &str, model: Arc<dyn PropagationModel>) {
+    MODELS.write().unwrap().insert(name.to_string(), model);
+}
+
+/// Returns the model selected by the propagation.model setting or the
+/// PatchPropagation RPC, or the free space model when no model has that
+/// name.
+pub fn model() -> Arc<dyn PropagationModel> {
+    match MODELS.read().unwrap().get(SELECTED.read().unwrap().name.as_str()) {
+        Some(model) => model.clone(),
+        None => Arc::new(FreeSpace),
+    }
+}
+
+/// Selects the model of the scene, building log_distance and multi_wall
+/// with the exponent and the walls of the request.
+pub fn patch_propagation(request: &Propagation) -> Result<Propagation, String> {
+    let exponent = match request.exponent {
+        0.0 => get_setting_parsed("propagation.exponent"),
+        exponent if exponent.is_finite() && exponent > 0.0 => exponent,
+        exponent => {
+            return Err(format!("Incorrect exponent {exponent}, expected a positive number"))
+        }
+    };
+    let default_loss: f32 = get_setting_parsed("propagation.wall_loss");
+    let walls: Vec<Wall> = request
+        .walls
+        .iter()
+        .map(|wall| Wall {
+            start: (wall.start.x, wall.start.y),
+            end: (wall.end.x, wall.end.y),
+            loss: if wall.loss == 0.0 { default_loss } else { wall.loss },
+        })
+        .collect();
+    if walls
+        .iter()
+        .any(|w| ![w.start.0, w.start.1, w.end.0, w.end.1, w.loss].iter().all(|n| n.is_finite()))
+    {
+        return Err("Incorrect wall, the coordinates and the loss must be finite".to_string());
+    }
+    let name = request.name.as_str();
+    match name {
+        "log_distance" => register_model(name, Arc::new(LogDistance { exponent })),
+        "multi_wall" => {
+            register_model(name, Arc::new(MultiWall { exponent, walls: walls.clone() }))
+        }
+        _ if MODELS.read().unwrap().contains_key(name) => {}
+        _ => return Err(format!("Unknown propagation model {name}")),
+    }
+    let propagation = selection(name, exponent, &walls);
+    info!("propagation model {name} selected");
+    *SELECTED.write().unwrap() = propagation.clone();
+    Ok(propagation)
+}
+
+// Cxx Method for the facades to invoke
+
+/// Returns the RSSI (dBm) at the receiver of a link.
+pub fn compute_rssi(link: &RadioLink, tx_power: i8) -> i8 {
+    (tx_power as f32 - model().path_loss(link)) as i8
+}
+
+/// The Rust propagation handler used directly by Http frontend for GET and
+/// PATCH
+pub fn handle_propagation(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    let propagation = match request.method.as_str() {
+        "GET" => SELECTED.read().unwrap().clone(),
+        "PATCH" => {
+            let body = String::from_utf8_lossy(&request.body);
+            let result = protobuf_json_mapping::parse_from_str::<Propagation>(&body)
+                .map_err(|e| format!("Invalid propagation request: {e}"))
+                .and_then(|request| patch_propagation(&request));
+            match result {
+                Ok(propagation) => propagation,
+                Err(e) => {
+                    writer.put_error(400, e.as_str());
+                    return;
+                }
+            }
+        }
+        _ => {
+            writer.put_error(404, "Not found.");
+            return;
+        }
+    };
+    match protobuf_json_mapping::print_to_string(&propagation) {
+        Ok(body) => writer.put_ok("text/json", &body, &[]),
+        Err(e) => writer.put_error(500, format!("Failed to print the propagation: {e}").as_str()),
+    }
+}
+
+/// propagation handle cxx for grpc server to call
+pub fn handle_propagation_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/propagation".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_propagation(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::Point;
+    use std::sync::atomic::AtomicU32;
+
+    fn link(x: f32) -> RadioLink {
+        RadioLink {
+            chip_kind: 1,
+            sender_id: 1,
+            receiver_id: 2,
+            sender: Point { x: 0.0, y: 0.0, z: 0.0 },
+            receiver: Point { x, y: 0.0, z: 0.0 },
+        }
+    }
+
+    #[test]
+    fn test_builtin_models() {
+        assert_eq!(FreeSpace.path_loss(&link(0.0)), 0.0);
+        assert_eq!(FreeSpace.path_loss(&link(10.0)), path_loss(10.0));
+        let indoor = LogDistance { exponent: 3.0 };
+        assert_eq!(indoor.path_loss(&link(1.0)), FreeSpace.path_loss(&link(1.0)));
+        assert!((indoor.path_loss(&link(10.0)) - path_loss(10.0) - 10.0).abs() < 1e-4);
+    }
+
+    fn link_to(x: f32, y: f32) -> RadioLink {
+        RadioLink { receiver: Point { x, y, z: 0.0 }, ..link(0.0) }
+    }
+
+    #[test]
+    fn test_multi_wall() {
+        // A wall along x = 5, and a wall across it along y = 5.
+        let walls = parse_walls("5,-10,5,10;0,5,10,5,10", 3.0).unwrap();
+        assert_eq!(walls[0], Wall { start: (5.0, -10.0), end: (5.0, 10.0), loss: 3.0 });
+        let indoor = MultiWall { exponent: 2.0, walls };
+        let free_space = |x, y| FreeSpace.path_loss(&link_to(x, y));
+        assert_eq!(indoor.path_loss(&link_to(4.0, 0.0)), free_space(4.0, 0.0));
+        assert_eq!(indoor.path_loss(&link_to(8.0, 0.0)), free_space(8.0, 0.0) + 3.0);
+        assert_eq!(indoor.path_loss(&link_to(8.0, 8.0)), free_space(8.0, 8.0) + 13.0);
+        // A link ending on a wall doesn't cross it.
+        assert_eq!(indoor.path_loss(&link_to(5.0, 0.0)), free_space(5.0, 0.0));
+        assert_eq!(parse_walls("", 3.0), Some(Vec::new()));
+        assert_eq!(parse_walls("1,2,3", 3.0), None);
+        assert_eq!(parse_walls("1,2,3,x", 3.0), None);
+    }
+
+    #[test]
+    fn test_selection() {
+        let walls = parse_walls("0,0,0,10,8", 3.0).unwrap();
+        let propagation = selection("multi_wall", 3.0, &walls);
+        assert_eq!(propagation.exponent, 3.0);
+        assert_eq!(propagation.walls.len(), 1);
+        assert_eq!((propagation.walls[0].end.y, propagation.walls[0].loss), (10.0, 8.0));
+        // Models without an exponent or walls don't report them.
+        assert_eq!(selection("log_distance", 3.0, &walls).walls.len(), 0);
+        let propagation = selection("free_space", 3.0, &walls);
+        assert_eq!(
+            propagation,
+            Propagation { name: "free_space".to_string(), ..Default::default() }
+        );
+    }
+
+    static CALLS: AtomicU32 = AtomicU32::new(0);
+
+    fn callout(address: &str, link: &RadioLink) -> Option<f32> {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        (address == "model:1").then_some(link.distance() * 3.0)
+    }
+
+    #[test]
+    fn test_external_model() {
+        let external = External::new("model:1", callout);
+        assert_eq!(external.path_loss(&link(2.0)), 6.0);
+        assert_eq!(external.path_loss(&link(2.0)), 6.0);
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+        // Moving a device calls the model again.
+        assert_eq!(external.path_loss(&link(3.0)), 9.0);
+        assert_eq!(CALLS.load(Ordering::Relaxed), 2);
+        // Failed calls fall back to the free space path loss.
+        let unreachable = External::new("model:2", callout);
+        assert_eq!(unreachable.path_loss(&link(10.0)), path_loss(10.0));
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pseudo random numbers of the simulation.
+//!
+//! The random decisions of the simulation draw from a single generator
+//! seeded with the `debug.seed` setting, or with a random seed when it is
+//! 0. The seed is recorded in session logs, so a replay makes the same
+//! decisions as the recording.
+
+use lazy_static::lazy_static;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::sync::Mutex;
+
+use crate::config::get_setting_parsed;
+
+/// A SplitMix64 generator.
+pub struct Generator {
+    seed: u64,
+    state: u64,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        Generator { seed, state: seed }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+// Returns the seed of the debug.seed setting, or a random seed for 0.
+fn initial_seed() -> u64 {
+    match get_setting_parsed("debug.seed") {
+        0 => RandomState::new().hash_one(0),
+        seed => seed,
+    }
+}
+
+lazy_static! {
+    static ref GENERATOR: Mutex<Generator> = Mutex::new(Generator::new(initial_seed()));
+}
+
+/// Returns the seed of the simulation generator.
+pub fn seed() -> u64 {
+    GENERATOR.lock().unwrap().seed()
+}
+
+/// Restarts the simulation generator from `seed`.
+pub fn reseed(seed: u64) {
+    *GENERATOR.lock().unwrap() = Generator::new(seed);
+}
+
+/// Returns the next number of the simulation generator.
+pub fn next_u64() -> u64 {
+    GENERATOR.lock().unwrap().next_u64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generator() {
+        let numbers = |seed| {
+            let mut generator = Generator::new(seed);
+            (0..4).map(|_| generator.next_u64()).collect::<Vec<u64>>()
+        };
+        assert_eq!(numbers(1), numbers(1));
+        assert_ne!(numbers(1), numbers(2));
+        let mut generator = Generator::new(1);
+        assert_eq!(generator.seed(), 1);
+        generator.next_u64();
+        assert_eq!(generator.seed(), 1);
+    }
+}
@@ -13,13 +13,34 @@
 // limitations under the License.
 
 //! Ranging library for RSSI.
-///
-/// The Free Space Path Loss (FSPL) model is considered as the standard
-/// under the ideal scenario.
+//!
+//! The Free Space Path Loss (FSPL) model is considered as the standard
+//! under the ideal scenario.
 
 /// (dBm) PATH_LOSS at 1m for isotropic antenna transmitting BLE.
 const PATH_LOSS_AT_1M: f32 = 40.20;
 
+/// Free space path loss (dB) over a distance, relative to a transmit power
+/// calibrated to 1 meter.
+///
+/// # Parameters
+///
+/// * `distance`: distance in meters (m), greater than 0.
+pub fn path_loss(distance: f32) -> f32 {
+    log_distance_path_loss(distance, 2.0)
+}
+
+/// Log-distance path loss (dB) over a distance, the free space path loss
+/// for an exponent of 2.
+///
+/// # Parameters
+///
+/// * `distance`: distance in meters (m), greater than 0.
+/// * `exponent`: path loss exponent, 2.7 to 3.5 in buildings.
+pub fn log_distance_path_loss(distance: f32, exponent: f32) -> f32 {
+    10.0 * exponent * distance.log10() + PATH_LOSS_AT_1M
+}
+
 /// Convert distance to RSSI using the free space path loss equation.
 /// See [Free-space_path_loss][1].
 ///
@@ -38,7 +59,7 @@ pub fn distance_to_rssi(tx_power: i8, distance: f32) -> i8 {
     if distance == 0.0 {
         tx_power
     } else {
-        (tx_power as f32 - 20.0 * distance.log10() - PATH_LOSS_AT_1M) as i8
+        (tx_power as f32 - path_loss(distance)) as i8
     }
 }
 
@@ -0,0 +1,286 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Facade restarts.
+//!
+//! Restarting a chip rebuilds the simulation state of its facade in place
+//! while the transport of its emulator stays connected, so a wedged radio
+//! simulation recovers without rebooting the emulator. The packets of the
+//! chip arriving during the restart are queued and delivered once the
+//! facade is back, up to `restart.queue_limit` packets; the packets beyond
+//! the limit are rejected, and a limit of 0 rejects them all. The queued
+//! packets are charged to the packets memory pool, which drops them when
+//! out of budget. Each restart records a CHIP_RESTARTED event.
+//!
+//! /v1/chips/{id}/restart --> handle_restart (POST)
+
+use cxx::CxxVector;
+use frontend_proto::frontend::event::Type;
+use frontend_proto::frontend::{Event, GetDevicesResponse, RestartChipResponse};
+use lazy_static::lazy_static;
+use protobuf::Message;
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::chip_registry::facade_id;
+use crate::clock::{now, to_timestamp};
+use crate::config::get_setting_parsed;
+use crate::events::history;
+use crate::ffi::{
+    forward_request_cxx, get_devices_bytes, restart_chip_facade, CxxServerResponseWriter,
+};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::info;
+use crate::memory::{accountant, Accountant, BoundedBuffer, Pool};
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+/// A packet and its packet type.
+type QueuedPacket = (Vec<u8>, u32);
+
+/// The packets of a restarting chip.
+struct Restarting {
+    // Charged to the packets memory pool.
+    queue: BoundedBuffer<QueuedPacket>,
+    queued: u32,
+    rejected: u32,
+}
+
+/// The chips restarting, by kind and facade id.
+pub struct Restarts {
+    chips: Mutex<HashMap<(u32, u32), Restarting>>,
+    // Fast path of hold_request without restarts.
+    active: AtomicBool,
+    accountant: Arc<Accountant>,
+}
+
+impl Default for Restarts {
+    fn default() -> Self {
+        Restarts::with_accountant(accountant())
+    }
+}
+
+impl Restarts {
+    /// Creates the restarts charging their queues to `accountant`.
+    pub fn with_accountant(accountant: Arc<Accountant>) -> Self {
+        Restarts { chips: Mutex::default(), active: AtomicBool::default(), accountant }
+    }
+
+    fn begin(&self, key: (u32, u32)) -> bool {
+        let mut chips = self.chips.lock().unwrap();
+        if chips.contains_key(&key) {
+            return false;
+        }
+        let queue = BoundedBuffer::with_accountant(Pool::Packets, self.accountant.clone());
+        chips.insert(key, Restarting { queue, queued: 0, rejected: 0 });
+        self.active.store(true, Ordering::Release);
+        true
+    }
+
+    /// Takes the packets queued so far. Once the queue is empty, ends the
+    /// restart and returns its queued and rejected counts.
+    fn drain(&self, key: (u32, u32)) -> Result<(u32, u32), Vec<QueuedPacket>> {
+        let mut chips = self.chips.lock().unwrap();
+        let Some(restarting) = chips.get_mut(&key) else {
+            return Ok((0, 0));
+        };
+        if !restarting.queue.is_empty() {
+            return Err(std::iter::from_fn(|| restarting.queue.pop_front()).collect());
+        }
+        let counts = (restarting.queued, restarting.rejected);
+        chips.remove(&key);
+        self.active.store(!chips.is_empty(), Ordering::Release);
+        Ok(counts)
+    }
+
+    /// Returns true when the packet belongs to a restarting chip, queuing
+    /// it within `limit` packets or rejecting it.
+    fn hold(&self, key: (u32, u32), packet: &[u8], packet_type: u32, limit: usize) -> bool {
+        if !self.active.load(Ordering::Acquire) {
+            return false;
+        }
+        let mut chips = self.chips.lock().unwrap();
+        let Some(restarting) = chips.get_mut(&key) else {
+            return false;
+        };
+        if (restarting.queued as usize) < limit
+            && restarting.queue.push((packet.to_vec(), packet_type), packet.len())
+        {
+            restarting.queued += 1;
+        } else {
+            restarting.rejected += 1;
+        }
+        true
+    }
+}
+
+lazy_static! {
+    static ref RESTARTS: Restarts = Restarts::default();
+}
+
+/// Restarts the facade of a chip and delivers the packets queued meanwhile.
+pub fn restart_chip(chip_id: i32) -> Result<RestartChipResponse, String> {
+    let mut vec = Vec::<u8>::new();
+    let devices = match get_devices_bytes(&mut vec) {
+        true => GetDevicesResponse::parse_from_bytes(&vec).map(|r| r.devices).unwrap_or_default(),
+        false => Vec::new(),
+    };
+    let (device, kind) = devices
+        .iter()
+        .find_map(|device| {
+            let chip = device.chips.iter().find(|chip| chip.id == chip_id)?;
+            Some((device.clone(), chip.kind.value() as u32))
+        })
+        .ok_or(format!("Chip {chip_id} not found"))?;
+    let key = (kind, facade_id(chip_id).ok_or(format!("Chip {chip_id} not found"))? as u32);
+    if !RESTARTS.begin(key) {
+        return Err(format!("Chip {chip_id} is already restarting"));
+    }
+    let restarted = restart_chip_facade(chip_id);
+    // The queued packets go to the facade of the chip after the restart.
+    let facade_id = facade_id(chip_id).map_or(key.1, |id| id as u32);
+    // The packets arriving while the queue drains keep their order.
+    let (queued, rejected) = loop {
+        match RESTARTS.drain(key) {
+            Ok(counts) => break counts,
+            Err(queue) => {
+                for (packet, packet_type) in queue {
+                    forward_request_cxx(key.0, facade_id, &packet, packet_type as u8);
+                }
+            }
+        }
+    };
+    let response = RestartChipResponse { queued, rejected, ..Default::default() };
+    if !restarted {
+        return Err(format!("Chip {chip_id} not found"));
+    }
+    info!(
+        "restarted chip {chip_id} of {}, {} packets queued, {} rejected",
+        device.name, response.queued, response.rejected
+    );
+    history().record(Event {
+        type_: Type::CHIP_RESTARTED.into(),
+        timestamp: Some(to_timestamp(now())).into(),
+        device_name: device.name.clone(),
+        chip_id,
+        devices: vec![device],
+        ..Default::default()
+    });
+    Ok(response)
+}
+
+/// The Rust restart handler used directly by Http frontend for POST
+pub fn handle_restart(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "POST" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let Ok(chip_id) = param.parse::<i32>() else {
+        writer.put_error(400, format!("Invalid chip id {param}").as_str());
+        return;
+    };
+    match restart_chip(chip_id) {
+        Ok(response) => {
+            if let Ok(json_response) = print_to_string_with_options(&response, &JSON_PRINT_OPTION) {
+                writer.put_ok("text/json", &json_response, &[])
+            } else {
+                writer.put_error(404, "proto to JSON mapping failure")
+            }
+        }
+        Err(e) => writer.put_error(404, e.as_str()),
+    }
+}
+
+/// restart handle cxx for grpc server to call
+pub fn handle_restart_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: format!("/v1/chips/{param}/restart"),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: Vec::new(),
+    };
+    handle_restart(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+// Cxx Method for packet_hub to invoke
+
+/// Returns true when the packet belongs to a restarting chip.
+pub fn hold_request(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) -> bool {
+    let limit = match RESTARTS.active.load(Ordering::Acquire) {
+        true => get_setting_parsed("restart.queue_limit"),
+        false => return false,
+    };
+    RESTARTS.hold((kind, facade_id), packet.as_slice(), packet_type, limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DropPolicy;
+
+    const KEY: (u32, u32) = (1, 7);
+
+    #[test]
+    fn test_hold() {
+        let restarts = Restarts::default();
+        assert!(!restarts.hold(KEY, &[1], 1, 2));
+        assert!(restarts.begin(KEY));
+        assert!(!restarts.begin(KEY));
+        assert!(!restarts.hold((1, 8), &[1], 1, 2));
+        for packet in 1..=3 {
+            assert!(restarts.hold(KEY, &[packet], 2, 2));
+        }
+        assert_eq!(restarts.drain(KEY), Err(vec![(vec![1], 2), (vec![2], 2)]));
+        assert_eq!(restarts.drain(KEY), Ok((2, 1)));
+        assert!(!restarts.hold(KEY, &[4], 1, 2));
+        // A limit of 0 rejects every packet.
+        assert!(restarts.begin(KEY));
+        assert!(restarts.hold(KEY, &[1], 1, 0));
+        assert_eq!(restarts.drain(KEY), Ok((0, 1)));
+    }
+
+    #[test]
+    fn test_queue_memory_pool() {
+        let pool = (3, DropPolicy::DropNewest);
+        let accountant = Arc::new(Accountant::new(0, [pool; 3]));
+        let restarts = Restarts::with_accountant(accountant.clone());
+        assert!(restarts.begin(KEY));
+        assert!(restarts.hold(KEY, &[1, 1], 1, 10));
+        // The pool rejects the packets beyond its budget.
+        assert!(restarts.hold(KEY, &[2, 2], 1, 10));
+        assert_eq!(accountant.usage(Pool::Packets).used_bytes, 2);
+        assert_eq!(restarts.drain(KEY), Err(vec![(vec![1, 1], 1)]));
+        assert_eq!(accountant.usage(Pool::Packets).used_bytes, 0);
+        assert_eq!(restarts.drain(KEY), Ok((1, 1)));
+    }
+}
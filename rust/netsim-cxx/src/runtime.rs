@@ -0,0 +1,43 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The tokio runtime of netsimd.
+//!
+//! The http server accepts its connections and streams the captures as
+//! tasks of the runtime, and the captures write their files as tasks, so
+//! neither the streaming clients nor the captures hold a thread each.
+//! The handlers not ported to async yet run on the blocking threads of the
+//! runtime, bounded to MAX_BLOCKING_THREADS.
+
+use lazy_static::lazy_static;
+use tokio::runtime::{Builder, Runtime};
+
+const WORKER_THREADS: usize = 2;
+/// Bound of the threads running the blocking http handlers at once.
+const MAX_BLOCKING_THREADS: usize = 32;
+
+lazy_static! {
+    static ref RUNTIME: Runtime = Builder::new_multi_thread()
+        .worker_threads(WORKER_THREADS)
+        .max_blocking_threads(MAX_BLOCKING_THREADS)
+        .thread_name("netsim-async")
+        .enable_all()
+        .build()
+        .expect("failed to build the tokio runtime");
+}
+
+/// Returns the tokio runtime of the netsimd process.
+pub fn runtime() -> &'static Runtime {
+    &RUNTIME
+}
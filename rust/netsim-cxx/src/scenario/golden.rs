@@ -0,0 +1,373 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Golden capture comparisons of test scenarios.
+//!
+//! A golden capture records the packets of a chip kind while the scenario
+//! runs, in the record format of netsimd captures, and compares them with a
+//! checked-in pcap file once both are normalized.
+
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::record_diff::Kind;
+use frontend_proto::frontend::{
+    CaptureComparison, CaptureNormalization, GoldenCapture, RecordDiff,
+};
+use lazy_static::lazy_static;
+use regex::bytes::{Regex, RegexBuilder};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use super::Simulation;
+use crate::captures::pcap_util::{
+    read_pcap_header, read_record, write_pcap_header, write_record,
+    LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR,
+};
+use crate::clock::now;
+use crate::memory::{BoundedBuffer, Pool};
+use crate::processor::PacketContext;
+
+const MAX_DIFFS: usize = 100;
+
+/// A capture record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub timestamp: Duration,
+    pub data: Vec<u8>,
+}
+
+/// Packets of the chips of a kind, for a golden capture.
+struct Recording {
+    chip_kind: Option<ChipKind>,
+    packets: Mutex<BoundedBuffer<(u32, Record)>>,
+}
+
+lazy_static! {
+    static ref RECORDINGS: RwLock<Vec<Arc<Recording>>> = RwLock::new(Vec::new());
+}
+
+/// Records a packet in the recordings of its chip kind.
+pub(super) fn record(context: &PacketContext, packet: &[u8]) {
+    for recording in RECORDINGS.read().unwrap().iter() {
+        if recording.chip_kind.is_some_and(|kind| kind != context.chip_kind) {
+            continue;
+        }
+        // Same record as captures: (direction, type, packet)
+        let mut data = Vec::with_capacity(5 + packet.len());
+        data.extend((context.direction as u32).to_be_bytes());
+        data.push(context.packet_type as u8);
+        data.extend(packet);
+        let size = data.len();
+        let record = Record { timestamp: now(), data };
+        recording.packets.lock().unwrap().push((context.facade_id, record), size);
+    }
+}
+
+/// Reads the records of a pcap file.
+pub fn load_capture(path: &str) -> Result<Vec<Record>, String> {
+    let file = File::open(path).map_err(|e| format!("can't open {path}: {e}"))?;
+    let mut input = BufReader::new(file);
+    let header = read_pcap_header(&mut input).map_err(|e| format!("{path}: {e}"))?;
+    let mut records = Vec::new();
+    while let Some(record) = read_record(&mut input, &header).map_err(|e| format!("{path}: {e}"))? {
+        records.push(Record { timestamp: record.timestamp, data: record.data });
+    }
+    Ok(records)
+}
+
+/// Writes records to a pcap file.
+pub fn save_capture(path: &str, records: &[Record]) -> Result<(), String> {
+    let mut file = File::create(path).map_err(|e| format!("can't create {path}: {e}"))?;
+    write_pcap_header(&mut file, LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR, 0)
+        .map_err(|e| e.to_string())?;
+    for record in records.iter() {
+        write_record(record.timestamp, &mut file, &record.data, 0).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Removes the expected differences from captures.
+pub struct Normalizer {
+    tolerance: Option<Duration>,
+    masks: Vec<(usize, usize)>,
+    addresses: Vec<Regex>,
+}
+
+impl Normalizer {
+    pub fn new(normalization: &CaptureNormalization) -> Result<Normalizer, String> {
+        let addresses = normalization
+            .address_patterns
+            .iter()
+            .map(|pattern| {
+                // Match raw bytes, so \xff is a byte and not a code point.
+                RegexBuilder::new(pattern)
+                    .unicode(false)
+                    .build()
+                    .map_err(|e| format!("invalid address pattern: {e}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Normalizer {
+            tolerance: normalization
+                .compare_timestamps
+                .then(|| Duration::from_millis(normalization.timestamp_tolerance_ms.into())),
+            masks: normalization
+                .masks
+                .iter()
+                .map(|mask| (mask.offset as usize, mask.length as usize))
+                .collect(),
+            addresses,
+        })
+    }
+
+    /// Returns the records with timestamps relative to the first record,
+    /// addresses numbered by order of appearance and masked bytes zeroed.
+    pub fn normalize(&self, records: &[Record]) -> Vec<Record> {
+        let start = records.first().map(|record| record.timestamp).unwrap_or_default();
+        let mut ordinals: HashMap<Vec<u8>, u8> = HashMap::new();
+        records
+            .iter()
+            .map(|record| {
+                let mut data = record.data.clone();
+                for pattern in self.addresses.iter() {
+                    let ranges: Vec<_> =
+                        pattern.find_iter(&record.data).map(|found| found.range()).collect();
+                    for range in ranges {
+                        let next = ordinals.len() as u8;
+                        let ordinal =
+                            *ordinals.entry(record.data[range.clone()].to_vec()).or_insert(next);
+                        data[range].fill(ordinal);
+                    }
+                }
+                for (offset, length) in self.masks.iter() {
+                    let end = offset.saturating_add(*length).min(data.len());
+                    if *offset < end {
+                        data[*offset..end].fill(0);
+                    }
+                }
+                let timestamp = match self.tolerance {
+                    Some(_) => record.timestamp.saturating_sub(start),
+                    None => Duration::ZERO,
+                };
+                Record { timestamp, data }
+            })
+            .collect()
+    }
+
+    /// Returns the first differences between normalized captures.
+    pub fn diff(&self, expected: &[Record], actual: &[Record]) -> Vec<RecordDiff> {
+        let mut diffs = Vec::new();
+        for index in 0..expected.len().max(actual.len()) {
+            if diffs.len() >= MAX_DIFFS {
+                break;
+            }
+            let mut diff = RecordDiff { index: index as u32, ..Default::default() };
+            match (expected.get(index), actual.get(index)) {
+                (Some(e), Some(a)) if e.data != a.data => {
+                    diff.kind = Kind::CHANGED.into();
+                    diff.offset =
+                        e.data.iter().zip(a.data.iter()).take_while(|(x, y)| x == y).count() as u32;
+                    diff.expected = e.data.clone();
+                    diff.actual = a.data.clone();
+                }
+                (Some(e), Some(a)) => match self.tolerance {
+                    Some(tolerance) if e.timestamp.abs_diff(a.timestamp) > tolerance => {
+                        diff.kind = Kind::TIMESTAMP.into();
+                        diff.expected_time_us = e.timestamp.as_micros() as u64;
+                        diff.actual_time_us = a.timestamp.as_micros() as u64;
+                    }
+                    _ => continue,
+                },
+                (Some(e), None) => {
+                    diff.kind = Kind::MISSING.into();
+                    diff.expected = e.data.clone();
+                }
+                (None, Some(a)) => {
+                    diff.kind = Kind::EXTRA.into();
+                    diff.actual = a.data.clone();
+                }
+                (None, None) => unreachable!(),
+            }
+            diffs.push(diff);
+        }
+        diffs
+    }
+}
+
+/// An armed golden capture.
+pub(super) struct Golden {
+    name: String,
+    device_name: String,
+    recording: Arc<Recording>,
+    // The golden records, or why they aren't comparable.
+    expected: Result<(Vec<Record>, Normalizer), String>,
+    actual_path: String,
+}
+
+impl Golden {
+    pub(super) fn arm(capture: &GoldenCapture) -> Golden {
+        let chip_kind = match capture.chip_kind.enum_value_or_default() {
+            ChipKind::UNSPECIFIED => None,
+            kind => Some(kind),
+        };
+        let recording = Arc::new(Recording {
+            chip_kind,
+            packets: Mutex::new(BoundedBuffer::new(Pool::Captures)),
+        });
+        RECORDINGS.write().unwrap().push(recording.clone());
+        let normalization = capture.normalization.clone().unwrap_or_default();
+        let expected = Normalizer::new(&normalization).and_then(|normalizer| {
+            let records = load_capture(&capture.golden_path)?;
+            Ok((normalizer.normalize(&records), normalizer))
+        });
+        Golden {
+            name: capture.name.clone(),
+            device_name: capture.device_name.clone(),
+            recording,
+            expected,
+            actual_path: capture.actual_path.clone(),
+        }
+    }
+
+    // Returns the records of the device.
+    fn recorded(&self, simulation: &dyn Simulation) -> Vec<Record> {
+        let facade_ids: Option<HashSet<u32>> = (!self.device_name.is_empty()).then(|| {
+            simulation
+                .devices()
+                .iter()
+                .filter(|device| device.name == self.device_name)
+                .flat_map(|device| device.chips.iter())
+                .map(|chip| simulation.facade_id(chip.id))
+                .filter(|facade_id| *facade_id >= 0)
+                .map(|facade_id| facade_id as u32)
+                .collect()
+        });
+        let packets = self.recording.packets.lock().unwrap();
+        packets
+            .iter()
+            .filter(|(facade_id, _)| facade_ids.as_ref().is_none_or(|ids| ids.contains(facade_id)))
+            .map(|(_, record)| record.clone())
+            .collect()
+    }
+
+    /// Returns true once the recording has as many records as the golden
+    /// capture, or the golden capture isn't comparable.
+    pub(super) fn is_complete(&self, simulation: &dyn Simulation) -> bool {
+        match &self.expected {
+            Ok((expected, _)) => self.recorded(simulation).len() >= expected.len(),
+            Err(_) => true,
+        }
+    }
+
+    pub(super) fn compare(&self, simulation: &dyn Simulation) -> CaptureComparison {
+        let recorded = self.recorded(simulation);
+        let mut comparison = CaptureComparison {
+            name: self.name.clone(),
+            actual_records: recorded.len() as u32,
+            ..Default::default()
+        };
+        if !self.actual_path.is_empty() {
+            if let Err(e) = save_capture(&self.actual_path, &recorded) {
+                comparison.message = e;
+            }
+        }
+        match &self.expected {
+            Ok((expected, normalizer)) => {
+                comparison.expected_records = expected.len() as u32;
+                comparison.diffs = normalizer.diff(expected, &normalizer.normalize(&recorded));
+            }
+            Err(e) => comparison.message = e.clone(),
+        }
+        comparison.passed = comparison.message.is_empty() && comparison.diffs.is_empty();
+        comparison
+    }
+}
+
+impl Drop for Golden {
+    fn drop(&mut self) {
+        RECORDINGS.write().unwrap().retain(|r| !Arc::ptr_eq(r, &self.recording));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::frontend::capture_normalization::Mask;
+
+    fn records(packets: &[(u64, &[u8])]) -> Vec<Record> {
+        packets
+            .iter()
+            .map(|(millis, data)| Record {
+                timestamp: Duration::from_millis(*millis),
+                data: data.to_vec(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_normalize_addresses_and_masks() {
+        let normalization = CaptureNormalization {
+            address_patterns: vec![r"\xaa.".to_string()],
+            masks: vec![Mask { offset: 0, length: 1, ..Default::default() }],
+            ..Default::default()
+        };
+        let normalizer = Normalizer::new(&normalization).unwrap();
+        let golden =
+            normalizer.normalize(&records(&[(5, &[1, 0xaa, 1]), (9, &[2, 0xaa, 2, 0xaa, 1])]));
+        let actual =
+            normalizer.normalize(&records(&[(7, &[3, 0xaa, 7]), (8, &[4, 0xaa, 8, 0xaa, 7])]));
+        assert_eq!(golden, actual);
+        assert_eq!(golden[1].data, [0, 1, 1, 0, 0]);
+        assert_eq!(golden[1].timestamp, Duration::ZERO);
+        assert!(normalizer.diff(&golden, &actual).is_empty());
+    }
+
+    #[test]
+    fn test_diff() {
+        let normalization = CaptureNormalization {
+            compare_timestamps: true,
+            timestamp_tolerance_ms: 2,
+            ..Default::default()
+        };
+        let normalizer = Normalizer::new(&normalization).unwrap();
+        let golden = normalizer.normalize(&records(&[(0, &[1, 2]), (10, &[3]), (20, &[4])]));
+        let actual = normalizer.normalize(&records(&[(100, &[1, 5]), (115, &[3])]));
+        let diffs = normalizer.diff(&golden, &actual);
+        assert_eq!(diffs.len(), 3);
+        assert_eq!(diffs[0].kind.enum_value(), Ok(Kind::CHANGED));
+        assert_eq!(diffs[0].offset, 1);
+        assert_eq!(diffs[1].kind.enum_value(), Ok(Kind::TIMESTAMP));
+        assert_eq!((diffs[1].expected_time_us, diffs[1].actual_time_us), (10_000, 15_000));
+        assert_eq!(diffs[2].kind.enum_value(), Ok(Kind::MISSING));
+        let diffs = normalizer.diff(&actual, &golden);
+        assert_eq!(diffs[2].kind.enum_value(), Ok(Kind::EXTRA));
+        assert!(Normalizer::new(&CaptureNormalization {
+            address_patterns: vec!["(".to_string()],
+            ..Default::default()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_save_and_load_capture() {
+        let path = std::env::temp_dir().join("netsim-golden-test.pcap");
+        let path = path.to_str().unwrap();
+        let captured = records(&[(1_500, &[0, 0, 0, 1, 4, 0x0e]), (2_000, &[0, 0, 0, 0, 1])]);
+        save_capture(path, &captured).unwrap();
+        assert_eq!(load_capture(path).unwrap(), captured);
+        std::fs::remove_file(path).unwrap();
+        assert!(load_capture(path).is_err());
+    }
+}
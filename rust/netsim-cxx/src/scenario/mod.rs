@@ -0,0 +1,682 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test scenarios evaluated by netsimd.
+//!
+//! A scenario arms its assertions, applies its setup patches and then polls
+//! the assertions until each one holds or its timeout expires. Packet
+//! assertions observe the packet hub through a packet processor, so
+//! integration tests can use netsimd as the oracle. Golden captures record
+//! the packets of a chip during the scenario and compare them with a golden
+//! pcap file, so netsimd can gate changes of the controller protocols.
+//! A scenario stops polling when its client cancels it or its deadline
+//! passes. The timeouts run on the netsimd clock, so under the manual
+//! clock a scenario moves as the ControlClock RPC advances the clock.
+//!
+//! /v1/scenario --> handle_scenario (POST a RunScenarioRequest)
+
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::scenario_assertion::Assertion;
+use frontend_proto::frontend::{
+    AssertionResult, CaptureComparison, GetDevicesResponse, PatchDeviceRequest, RunScenarioRequest,
+    RunScenarioResponse, ScenarioAssertion,
+};
+use frontend_proto::model::Device;
+use lazy_static::lazy_static;
+use protobuf::{Message, MessageField};
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use regex::bytes::Regex;
+use std::collections::{HashMap, HashSet};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, Once, RwLock};
+use std::time::Duration;
+
+use crate::chip_registry::facade_id;
+use crate::clock::{clock, Clock};
+use crate::ffi::{get_devices_bytes, patch_device, CxxServerResponseWriter};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::metrics::{collect, Sample};
+use crate::processor::{register_processor, PacketContext, PacketProcessor, Verdict};
+use crate::CxxServerResponseWriterWrapper;
+
+mod golden;
+
+use golden::Golden;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+const MAX_TIMEOUT: Duration = Duration::from_secs(300);
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+/// The parts of netsimd a scenario interacts with.
+pub trait Simulation {
+    fn devices(&self) -> Vec<Device>;
+    fn facade_id(&self, chip_id: i32) -> i32;
+    fn patch_device(&self, device: &Device) -> Result<(), String>;
+    fn samples(&self) -> Vec<Sample>;
+}
+
+/// The running netsimd.
+struct Netsimd;
+
+impl Simulation for Netsimd {
+    fn devices(&self) -> Vec<Device> {
+        let mut vec = Vec::<u8>::new();
+        if !get_devices_bytes(&mut vec) {
+            return Vec::new();
+        }
+        GetDevicesResponse::parse_from_bytes(&vec).map(|r| r.devices).unwrap_or_default()
+    }
+
+    fn facade_id(&self, chip_id: i32) -> i32 {
+        facade_id(chip_id).unwrap_or(-1)
+    }
+
+    fn patch_device(&self, device: &Device) -> Result<(), String> {
+        let request =
+            PatchDeviceRequest { device: MessageField::some(device.clone()), ..Default::default() };
+        let json = protobuf_json_mapping::print_to_string(&request).map_err(|e| e.to_string())?;
+        cxx::let_cxx_string!(request = json);
+        cxx::let_cxx_string!(response = "");
+        cxx::let_cxx_string!(error_message = "");
+        match patch_device(&request, response.as_mut(), error_message.as_mut()) {
+            200 => Ok(()),
+            _ => Err(error_message.to_string()),
+        }
+    }
+
+    fn samples(&self) -> Vec<Sample> {
+        collect()
+    }
+}
+
+/// Packets of interest for a packet assertion.
+struct PacketWatch {
+    chip_kind: Option<ChipKind>,
+    pattern: Regex,
+    // Chips that sent or received a matching packet.
+    seen: Mutex<HashSet<(ChipKind, u32)>>,
+}
+
+lazy_static! {
+    static ref WATCHES: RwLock<Vec<Arc<PacketWatch>>> = RwLock::new(Vec::new());
+}
+
+static REGISTER_OBSERVER: Once = Once::new();
+
+/// Records packets of the armed packet assertions and golden captures.
+struct Observer;
+
+impl PacketProcessor for Observer {
+    fn name(&self) -> &str {
+        "scenario"
+    }
+
+    fn process(&self, context: &PacketContext, packet: &[u8]) -> Verdict {
+        for watch in WATCHES.read().unwrap().iter() {
+            if watch.chip_kind.is_none_or(|kind| kind == context.chip_kind)
+                && watch.pattern.is_match(packet)
+            {
+                watch.seen.lock().unwrap().insert((context.chip_kind, context.facade_id));
+            }
+        }
+        golden::record(context, packet);
+        Verdict::Forward
+    }
+}
+
+// Returns true if every field set in `expected` has the same value in `actual`.
+fn has_fields<M: Message + PartialEq>(expected: &M, actual: &M) -> bool {
+    let mut merged = actual.clone();
+    match expected.write_to_bytes() {
+        Ok(bytes) => merged.merge_from_bytes(&bytes).is_ok() && merged == *actual,
+        Err(_) => false,
+    }
+}
+
+/// Checks that `actual` has the fields of `expected`, matching each expected
+/// chip against the chips of `actual`.
+pub fn device_matches(expected: &Device, actual: &Device) -> Result<(), String> {
+    let mut expected = expected.clone();
+    let expected_chips = std::mem::take(&mut expected.chips);
+    let mut device = actual.clone();
+    device.chips.clear();
+    if !has_fields(&expected, &device) {
+        return Err(format!("device {} differs from the expected device", actual.name));
+    }
+    for chip in expected_chips.iter() {
+        if !actual.chips.iter().any(|actual_chip| has_fields(chip, actual_chip)) {
+            return Err(format!(
+                "device {} has no chip matching {}",
+                actual.name,
+                protobuf::text_format::print_to_string(chip)
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a sample of `metric` with `labels` exceeds `threshold`.
+pub fn counter_exceeds(
+    samples: &[Sample],
+    metric: &str,
+    labels: &HashMap<String, String>,
+    threshold: f64,
+) -> Result<(), String> {
+    let mut values = samples.iter().filter(|sample| {
+        sample.name == metric
+            && labels.iter().all(|(key, value)| {
+                sample.labels.iter().any(|(name, label)| name == key && label == value)
+            })
+    });
+    let Some(first) = values.next() else {
+        return Err(format!("no sample of {metric}"));
+    };
+    let max = values.fold(first.value, |max, sample| max.max(sample.value));
+    if max > threshold {
+        Ok(())
+    } else {
+        Err(format!("{metric} is {max}, expected more than {threshold}"))
+    }
+}
+
+/// An armed assertion.
+enum Check {
+    Invalid(String),
+    PacketSeen { device_name: String, watch: Arc<PacketWatch> },
+    DeviceState(Device),
+    CounterExceeded { metric: String, labels: HashMap<String, String>, threshold: f64 },
+}
+
+impl Check {
+    fn arm(assertion: &ScenarioAssertion) -> Check {
+        match &assertion.assertion {
+            Some(Assertion::PacketSeen(packet_seen)) => {
+                let pattern = match Regex::new(&packet_seen.pattern) {
+                    Ok(pattern) => pattern,
+                    Err(e) => return Check::Invalid(format!("invalid pattern: {e}")),
+                };
+                let chip_kind = match packet_seen.chip_kind.enum_value_or_default() {
+                    ChipKind::UNSPECIFIED => None,
+                    kind => Some(kind),
+                };
+                let watch = Arc::new(PacketWatch { chip_kind, pattern, seen: Mutex::default() });
+                WATCHES.write().unwrap().push(watch.clone());
+                Check::PacketSeen { device_name: packet_seen.device_name.clone(), watch }
+            }
+            Some(Assertion::DeviceState(device_state)) => {
+                Check::DeviceState(device_state.device.clone().unwrap_or_default())
+            }
+            Some(Assertion::CounterExceeded(counter)) => Check::CounterExceeded {
+                metric: counter.metric.clone(),
+                labels: counter.labels.clone(),
+                threshold: counter.threshold,
+            },
+            _ => Check::Invalid("missing assertion".to_string()),
+        }
+    }
+
+    fn check(&self, simulation: &dyn Simulation) -> Result<(), String> {
+        match self {
+            Check::Invalid(message) => Err(message.clone()),
+            Check::PacketSeen { device_name, watch } => {
+                let seen = watch.seen.lock().unwrap();
+                let matched = if device_name.is_empty() {
+                    !seen.is_empty()
+                } else {
+                    simulation
+                        .devices()
+                        .iter()
+                        .filter(|device| &device.name == device_name)
+                        .flat_map(|device| device.chips.iter())
+                        .any(|chip| {
+                            let facade_id = simulation.facade_id(chip.id);
+                            facade_id >= 0
+                                && seen.contains(&(
+                                    chip.kind.enum_value_or_default(),
+                                    facade_id as u32,
+                                ))
+                        })
+                };
+                match matched {
+                    true => Ok(()),
+                    false => Err("no matching packet seen".to_string()),
+                }
+            }
+            Check::DeviceState(expected) => {
+                match simulation.devices().iter().find(|device| device.name == expected.name) {
+                    Some(actual) => device_matches(expected, actual),
+                    None => Err(format!("device {} not found", expected.name)),
+                }
+            }
+            Check::CounterExceeded { metric, labels, threshold } => {
+                counter_exceeds(&simulation.samples(), metric, labels, *threshold)
+            }
+        }
+    }
+
+    // Permanent failures don't wait for the timeout.
+    fn is_invalid(&self) -> bool {
+        matches!(self, Check::Invalid(_))
+    }
+}
+
+impl Drop for Check {
+    fn drop(&mut self) {
+        if let Check::PacketSeen { watch, .. } = self {
+            WATCHES.write().unwrap().retain(|w| !Arc::ptr_eq(w, watch));
+        }
+    }
+}
+
+/// Runs a scenario and returns the result of each assertion and golden
+/// capture comparison, or no results once `cancelled` returns true.
+pub fn run_scenario(
+    request: &RunScenarioRequest,
+    simulation: &dyn Simulation,
+    cancelled: &dyn Fn() -> bool,
+) -> RunScenarioResponse {
+    run_scenario_with_clock(request, simulation, cancelled, clock())
+}
+
+// Runs a scenario with the timeouts of its assertions on a clock: under the
+// manual clock, the scenario moves as the clock is advanced.
+fn run_scenario_with_clock(
+    request: &RunScenarioRequest,
+    simulation: &dyn Simulation,
+    cancelled: &dyn Fn() -> bool,
+    clock: &dyn Clock,
+) -> RunScenarioResponse {
+    REGISTER_OBSERVER.call_once(|| register_processor(None, Box::new(Observer)));
+    let start = clock.monotonic();
+    let checks: Vec<(Check, Duration)> = request
+        .assertions
+        .iter()
+        .map(|assertion| {
+            let timeout = Duration::from_millis(assertion.timeout_ms.into()).min(MAX_TIMEOUT);
+            (Check::arm(assertion), timeout)
+        })
+        .collect();
+    let goldens: Vec<(Golden, Duration)> = request
+        .golden_captures
+        .iter()
+        .map(|capture| {
+            let timeout = Duration::from_millis(capture.timeout_ms.into()).min(MAX_TIMEOUT);
+            (Golden::arm(capture), timeout)
+        })
+        .collect();
+    let mut results: Vec<Option<AssertionResult>> = vec![None; checks.len()];
+    let mut setup_error = None;
+    for device in request.setup.iter() {
+        if let Err(e) = simulation.patch_device(device) {
+            setup_error = Some(format!("setup of device {} failed: {e}", device.name));
+            break;
+        }
+    }
+    loop {
+        if cancelled() {
+            return RunScenarioResponse { name: request.name.clone(), ..Default::default() };
+        }
+        let elapsed = clock.monotonic().saturating_sub(start);
+        for (index, (check, timeout)) in checks.iter().enumerate() {
+            if results[index].is_some() {
+                continue;
+            }
+            let outcome = match &setup_error {
+                Some(message) => Err(message.clone()),
+                None => check.check(simulation),
+            };
+            let decided = match &outcome {
+                Ok(()) => true,
+                Err(_) => elapsed >= *timeout || check.is_invalid() || setup_error.is_some(),
+            };
+            if decided {
+                results[index] = Some(AssertionResult {
+                    name: request.assertions[index].name.clone(),
+                    passed: outcome.is_ok(),
+                    message: outcome.err().unwrap_or_default(),
+                    elapsed_ms: elapsed.as_millis() as u32,
+                    ..Default::default()
+                });
+            }
+        }
+        let recorded = goldens.iter().all(|(golden, timeout)| {
+            elapsed >= *timeout || setup_error.is_some() || golden.is_complete(simulation)
+        });
+        if results.iter().all(Option::is_some) && recorded {
+            break;
+        }
+        clock.sleep(POLL_INTERVAL);
+    }
+    let results: Vec<AssertionResult> = results.into_iter().flatten().collect();
+    let captures: Vec<CaptureComparison> = goldens
+        .iter()
+        .map(|(golden, _)| {
+            let mut comparison = golden.compare(simulation);
+            if let Some(message) = &setup_error {
+                comparison.passed = false;
+                comparison.message = message.clone();
+            }
+            comparison
+        })
+        .collect();
+    RunScenarioResponse {
+        name: request.name.clone(),
+        passed: results.iter().all(|result| result.passed)
+            && captures.iter().all(|comparison| comparison.passed),
+        results,
+        captures,
+        ..Default::default()
+    }
+}
+
+/// The Rust scenario handler used directly by Http frontend for POST
+pub fn handle_scenario(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "POST" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let body = String::from_utf8_lossy(&request.body);
+    let scenario = match protobuf_json_mapping::parse_from_str::<RunScenarioRequest>(&body) {
+        Ok(scenario) => scenario,
+        Err(e) => {
+            writer.put_error(400, format!("Invalid scenario: {e}").as_str());
+            return;
+        }
+    };
+    let response = run_scenario(&scenario, &Netsimd, &|| writer.is_cancelled());
+    if writer.is_cancelled() {
+        return;
+    }
+    if let Ok(json_response) = print_to_string_with_options(&response, &JSON_PRINT_OPTION) {
+        writer.put_ok("text/json", &json_response, &[])
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure")
+    }
+}
+
+/// scenario handle cxx for grpc server to call
+pub fn handle_scenario_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/scenario".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_scenario(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::captures::pcap_util::PacketDirection;
+    use crate::metrics::MetricKind;
+    use frontend_proto::frontend::scenario_assertion::{CounterExceeded, DeviceState, PacketSeen};
+    use frontend_proto::frontend::GoldenCapture;
+    use frontend_proto::model::{Chip, State};
+
+    struct FakeSimulation {
+        devices: Mutex<Vec<Device>>,
+        samples: Vec<Sample>,
+    }
+
+    impl Simulation for FakeSimulation {
+        fn devices(&self) -> Vec<Device> {
+            self.devices.lock().unwrap().clone()
+        }
+        fn facade_id(&self, chip_id: i32) -> i32 {
+            chip_id + 100
+        }
+        fn patch_device(&self, patch: &Device) -> Result<(), String> {
+            let mut devices = self.devices.lock().unwrap();
+            let device = devices
+                .iter_mut()
+                .find(|device| device.name == patch.name)
+                .ok_or_else(|| format!("{} not found", patch.name))?;
+            device.visible = patch.visible;
+            Ok(())
+        }
+        fn samples(&self) -> Vec<Sample> {
+            self.samples.clone()
+        }
+    }
+
+    fn device(name: &str, capture: State) -> Device {
+        let chip = Chip { id: 1, capture: capture.into(), ..Default::default() };
+        let mut device = Device { name: name.to_string(), ..Default::default() };
+        device.chips.push(chip);
+        device
+    }
+
+    fn sample(value: f64) -> Sample {
+        Sample {
+            name: "netsim_test_packets_total",
+            help: "Test packets.",
+            kind: MetricKind::Counter,
+            labels: vec![("kind", "WIFI".to_string())],
+            value,
+        }
+    }
+
+    fn simulation() -> FakeSimulation {
+        FakeSimulation {
+            devices: Mutex::new(vec![device("phone", State::ON)]),
+            samples: vec![sample(5.0)],
+        }
+    }
+
+    fn assertion(name: &str, timeout_ms: u32, assertion: Assertion) -> ScenarioAssertion {
+        ScenarioAssertion {
+            name: name.to_string(),
+            timeout_ms,
+            assertion: Some(assertion),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_device_matches() {
+        let actual = device("phone", State::ON);
+        assert!(
+            device_matches(&Device { name: "phone".into(), ..Default::default() }, &actual).is_ok()
+        );
+        assert!(device_matches(&device("phone", State::ON), &actual).is_ok());
+        assert!(device_matches(&device("phone", State::OFF), &actual).is_err());
+        let visible = Device { name: "phone".into(), visible: true, ..Default::default() };
+        assert!(device_matches(&visible, &actual).is_err());
+    }
+
+    #[test]
+    fn test_counter_exceeds() {
+        let samples = vec![sample(5.0)];
+        let labels = HashMap::from([("kind".to_string(), "WIFI".to_string())]);
+        assert!(counter_exceeds(&samples, "netsim_test_packets_total", &labels, 4.0).is_ok());
+        assert!(counter_exceeds(&samples, "netsim_test_packets_total", &labels, 5.0).is_err());
+        let other = HashMap::from([("kind".to_string(), "UWB".to_string())]);
+        assert!(counter_exceeds(&samples, "netsim_test_packets_total", &other, 0.0).is_err());
+        assert!(counter_exceeds(&samples, "netsim_other", &HashMap::new(), 0.0).is_err());
+    }
+
+    #[test]
+    fn test_run_scenario() {
+        let mut request = RunScenarioRequest { name: "smoke".to_string(), ..Default::default() };
+        request.setup.push(Device { name: "phone".into(), visible: true, ..Default::default() });
+        let visible = Device { name: "phone".into(), visible: true, ..Default::default() };
+        request.assertions.push(assertion(
+            "visible",
+            0,
+            Assertion::DeviceState(DeviceState {
+                device: MessageField::some(visible),
+                ..Default::default()
+            }),
+        ));
+        request.assertions.push(assertion(
+            "counter",
+            20,
+            Assertion::CounterExceeded(CounterExceeded {
+                metric: "netsim_test_packets_total".to_string(),
+                threshold: 10.0,
+                ..Default::default()
+            }),
+        ));
+        let response = run_scenario(&request, &simulation(), &|| false);
+        assert_eq!(response.name, "smoke");
+        assert!(!response.passed);
+        assert!(response.results[0].passed);
+        assert!(!response.results[1].passed);
+        assert!(response.results[1].elapsed_ms >= 20);
+    }
+
+    #[test]
+    fn test_cancelled_scenario() {
+        let mut request =
+            RunScenarioRequest { name: "abandoned".to_string(), ..Default::default() };
+        request.assertions.push(assertion(
+            "counter",
+            60_000,
+            Assertion::CounterExceeded(CounterExceeded {
+                metric: "netsim_test_packets_total".to_string(),
+                threshold: 1e9,
+                ..Default::default()
+            }),
+        ));
+        let start = std::time::Instant::now();
+        let polls = std::cell::Cell::new(0);
+        let response = run_scenario(&request, &simulation(), &|| {
+            polls.set(polls.get() + 1);
+            polls.get() > 2
+        });
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert_eq!(response.name, "abandoned");
+        assert!(!response.passed);
+        assert!(response.results.is_empty());
+    }
+
+    #[test]
+    fn test_scenario_manual_clock() {
+        use crate::clock::ManualClock;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        let mut request = RunScenarioRequest::new();
+        request.assertions.push(assertion(
+            "counter",
+            60_000,
+            Assertion::CounterExceeded(CounterExceeded {
+                metric: "netsim_test_packets_total".to_string(),
+                threshold: 1e9,
+                ..Default::default()
+            }),
+        ));
+        // The timeout of a minute passes as the paused clock is advanced.
+        let clock = ManualClock::new(Duration::ZERO);
+        let done = AtomicBool::new(false);
+        let start = std::time::Instant::now();
+        let response = std::thread::scope(|scope| {
+            scope.spawn(|| {
+                while !done.load(Ordering::Relaxed) {
+                    clock.advance(Duration::from_secs(1));
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+            });
+            let response = run_scenario_with_clock(&request, &simulation(), &|| false, &clock);
+            done.store(true, Ordering::Relaxed);
+            response
+        });
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert!(!response.results[0].passed);
+        assert!(response.results[0].elapsed_ms >= 60_000);
+    }
+
+    #[test]
+    fn test_invalid_assertions() {
+        let mut request = RunScenarioRequest::new();
+        request.assertions.push(ScenarioAssertion { timeout_ms: 60_000, ..Default::default() });
+        request.assertions.push(assertion(
+            "pattern",
+            60_000,
+            Assertion::PacketSeen(PacketSeen { pattern: "(".to_string(), ..Default::default() }),
+        ));
+        let response = run_scenario(&request, &simulation(), &|| false);
+        assert!(response.results.iter().all(|result| !result.passed));
+        assert!(response.results.iter().all(|result| result.elapsed_ms < 60_000));
+    }
+
+    #[test]
+    fn test_golden_captures() {
+        let path = std::env::temp_dir().join("netsim-scenario-golden.pcap");
+        let path = path.to_str().unwrap().to_string();
+        golden::save_capture(&path, &[]).unwrap();
+        let mut request = RunScenarioRequest::new();
+        request.golden_captures.push(GoldenCapture {
+            name: "empty".to_string(),
+            chip_kind: ChipKind::UWB.into(),
+            golden_path: path.clone(),
+            ..Default::default()
+        });
+        request.golden_captures.push(GoldenCapture {
+            name: "missing".to_string(),
+            golden_path: format!("{path}.missing"),
+            ..Default::default()
+        });
+        let response = run_scenario(&request, &simulation(), &|| false);
+        std::fs::remove_file(&path).unwrap();
+        assert!(!response.passed);
+        assert_eq!(response.captures[0].name, "empty");
+        assert!(response.captures[0].passed);
+        assert!(!response.captures[1].passed);
+        assert!(!response.captures[1].message.is_empty());
+    }
+
+    #[test]
+    fn test_packet_seen() {
+        let packet_seen = PacketSeen {
+            device_name: "phone".to_string(),
+            chip_kind: ChipKind::UWB.into(),
+            pattern: r"^\x04\x0e".to_string(),
+            ..Default::default()
+        };
+        let check = Check::arm(&assertion("packet", 0, Assertion::PacketSeen(packet_seen)));
+        let simulation = simulation();
+        assert!(check.check(&simulation).is_err());
+        let context = PacketContext {
+            chip_kind: ChipKind::UWB,
+            facade_id: 101,
+            packet_type: 0,
+            direction: PacketDirection::ControllerToHost,
+        };
+        assert_eq!(Observer.process(&context, &[0x01, 0x04, 0x0e]), Verdict::Forward);
+        assert!(check.check(&simulation).is_err());
+        Observer.process(&context, &[0x04, 0x0e, 0x01]);
+        // The fake chip has kind UNSPECIFIED, so the packet belongs to another chip.
+        assert!(check.check(&simulation).is_err());
+        simulation.devices.lock().unwrap()[0].chips[0].kind = ChipKind::UWB.into();
+        assert!(check.check(&simulation).is_ok());
+        drop(check);
+        assert!(WATCHES.read().unwrap().iter().all(|watch| watch.chip_kind != Some(ChipKind::UWB)));
+    }
+}
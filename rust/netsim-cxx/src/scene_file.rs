@@ -0,0 +1,681 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scene files.
+//!
+//! `netsimd --scene <file>` imports a SceneFile in the JSON format of the
+//! protobuf during startup, before any emulator connects, so the scene is
+//! in place from the first packet of the first boot:
+//!
+//! * its settings are applied, taking precedence over the state file but
+//!   not over the environment and the config file;
+//! * its devices replace the saved configuration of the devices of the
+//!   same name, applied to their chips as they are added;
+//! * its beacons are added to the scene;
+//! * its impairments are set once their chips are added, named by their
+//!   device and kind.
+//!
+//! A LoadScenario loads a scene file into the running scene the same way,
+//! also patching the devices of the scene file already in the scene and
+//! setting the impairments of the chips already added, so a test setup
+//! declares its scene in one file rather than a sequence of commands.
+//!
+//! A SaveState exports the whole simulation to a scene file: the devices
+//! with their chips and positions, the beacons, the settings, the link
+//! impairments and the running captures, named by their device and kind.
+//! A RestoreState loads it back like a LoadScenario, once the emulators of
+//! the devices connect again, starting the captures as their chips are
+//! added.
+//!
+//! With the `snapshot.interval_mins` setting netsimd also exports the scene
+//! to a timestamped scene file every interval, keeping the latest
+//! `snapshot.retention` files, so the scene as of a given time can be
+//! imported again after a script misconfigured it. Unlike the state file,
+//! the snapshots are never loaded automatically.
+//!
+//! /v1/scenes --> handle_scene (POST a LoadScenarioRequest)
+//! /v1/scenes/save --> handle_scene (POST a SaveStateRequest)
+//! /v1/scenes/restore --> handle_scene (POST a RestoreStateRequest)
+
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::{
+    LoadScenarioRequest, LoadScenarioResponse, PatchDeviceRequest, RestoreStateRequest,
+    SaveStateRequest, SaveStateResponse, SceneCapture, SceneFile, SceneImpairment,
+    SetLinkImpairmentRequest,
+};
+use frontend_proto::model::capture::{Format, Mode};
+use frontend_proto::model::{Device, Scene};
+use lazy_static::lazy_static;
+use netsim_common::util::time_display::TimeDisplay;
+use protobuf::{Message, MessageField};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::bluetooth::beacon::{beacon_requests, create_beacon};
+use crate::captures::capture::ChipId;
+use crate::captures::handlers::{recording_capture_modes, start_chip_capture};
+use crate::clock::{clock, now};
+use crate::config::{
+    all_settings, get_setting, get_setting_parsed, restore_settings, saved_settings,
+};
+use crate::ffi::{get_devices_bytes, patch_device, CxxServerResponseWriter};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::{info, warn};
+use crate::processor::impairment::{impairment_requests, set_impairment};
+use crate::state::import_devices;
+use crate::CxxServerResponseWriterWrapper;
+
+const SNAPSHOT_PREFIX: &str = "netsim-scene-";
+const SNAPSHOT_SUFFIX: &str = ".json";
+
+lazy_static! {
+    // The impairments and the captures of the scene files waiting for their
+    // chips.
+    static ref PENDING_IMPAIRMENTS: Mutex<Vec<SceneImpairment>> = Mutex::new(Vec::new());
+    static ref PENDING_CAPTURES: Mutex<Vec<SceneCapture>> = Mutex::new(Vec::new());
+}
+
+/// Parses a scene file.
+pub fn parse_scene_file(json: &str) -> Result<SceneFile, String> {
+    let scene_file = protobuf_json_mapping::parse_from_str::<SceneFile>(json)
+        .map_err(|e| format!("invalid scene file: {e}"))?;
+    check_scene_file(&scene_file)?;
+    Ok(scene_file)
+}
+
+// Checks the settings and the impairments of a scene file.
+fn check_scene_file(scene_file: &SceneFile) -> Result<(), String> {
+    let known: Vec<String> = all_settings().into_iter().map(|(key, _)| key).collect();
+    if let Some(key) = scene_file.settings.keys().find(|key| !known.contains(key)) {
+        return Err(format!("unknown setting {key}"));
+    }
+    for impairment in scene_file.impairments.iter() {
+        if impairment.device_name.is_empty() {
+            return Err("impairment without a device".to_string());
+        }
+        let drop_percent = impairment.impairment.drop_percent;
+        if !(0.0..=100.0).contains(&drop_percent) {
+            return Err(format!(
+                "incorrect drop percent {drop_percent} of the impairment of {}",
+                impairment.device_name
+            ));
+        }
+    }
+    Ok(())
+}
+
+// Returns the id of the chip of a kind of a device in `devices`. Chips
+// without a kind are Bluetooth chips, as in device patches.
+fn chip_id(devices: &[Device], device_name: &str, chip_kind: ChipKind) -> Option<ChipId> {
+    let chip_kind = match chip_kind {
+        ChipKind::UNSPECIFIED => ChipKind::BLUETOOTH,
+        chip_kind => chip_kind,
+    };
+    devices
+        .iter()
+        .filter(|device| device.name == device_name)
+        .flat_map(|device| device.chips.iter())
+        .find(|chip| chip.kind.enum_value_or_default() == chip_kind)
+        .map(|chip| chip.id)
+}
+
+/// Returns the impairment request of a scene impairment with the ids of its
+/// chips in `devices`, or None while one of its chips is not added.
+pub fn resolve_impairment(
+    devices: &[Device],
+    impairment: &SceneImpairment,
+) -> Option<SetLinkImpairmentRequest> {
+    let chip_kind = impairment.chip_kind.enum_value_or_default();
+    let other_chip_id = match impairment.other_device_name.as_str() {
+        "" => 0,
+        other_device_name => chip_id(devices, other_device_name, ChipKind::BLUETOOTH)?,
+    };
+    Some(SetLinkImpairmentRequest {
+        chip_id: chip_id(devices, &impairment.device_name, chip_kind)?,
+        other_chip_id,
+        ..impairment.impairment.clone().unwrap_or_default()
+    })
+}
+
+/// Returns the impairments and the running captures of the chips of
+/// `devices` as those of a scene file, with the chips named by their device
+/// and kind.
+pub fn scene_chip_state(
+    devices: &[Device],
+    impairments: Vec<SetLinkImpairmentRequest>,
+    captures: Vec<(ChipId, Format, Mode)>,
+) -> (Vec<SceneImpairment>, Vec<SceneCapture>) {
+    let chips: HashMap<ChipId, (&str, ChipKind)> = devices
+        .iter()
+        .flat_map(|device| {
+            device
+                .chips
+                .iter()
+                .map(|chip| (chip.id, (device.name.as_str(), chip.kind.enum_value_or_default())))
+        })
+        .collect();
+    let scene_impairments = impairments
+        .into_iter()
+        .filter_map(|request| {
+            let (device_name, chip_kind) = chips.get(&request.chip_id)?;
+            let other_device_name = match request.other_chip_id {
+                0 => "",
+                other_chip_id => chips.get(&other_chip_id)?.0,
+            };
+            Some(SceneImpairment {
+                device_name: device_name.to_string(),
+                chip_kind: (*chip_kind).into(),
+                other_device_name: other_device_name.to_string(),
+                impairment: Some(SetLinkImpairmentRequest {
+                    chip_id: 0,
+                    other_chip_id: 0,
+                    ..request
+                })
+                .into(),
+                ..Default::default()
+            })
+        })
+        .collect();
+    let scene_captures = captures
+        .into_iter()
+        .filter_map(|(chip_id, format, mode)| {
+            let (device_name, chip_kind) = chips.get(&chip_id)?;
+            Some(SceneCapture {
+                device_name: device_name.to_string(),
+                chip_kind: (*chip_kind).into(),
+                format: format.into(),
+                mode: mode.into(),
+                ..Default::default()
+            })
+        })
+        .collect();
+    (scene_impairments, scene_captures)
+}
+
+// Returns the devices of the scene.
+fn scene_devices() -> Vec<Device> {
+    let mut vec = Vec::<u8>::new();
+    if !get_devices_bytes(&mut vec) {
+        return Vec::new();
+    }
+    Scene::parse_from_bytes(&vec).map(|scene| scene.devices).unwrap_or_default()
+}
+
+// Sets the pending impairments of the chips in the scene, returning how
+// many were set.
+fn set_pending_impairments() -> u32 {
+    let devices = scene_devices();
+    let mut set = 0;
+    PENDING_IMPAIRMENTS.lock().unwrap().retain(|impairment| {
+        let Some(request) = resolve_impairment(&devices, impairment) else {
+            return true;
+        };
+        match set_impairment(&request) {
+            Ok(()) => set += 1,
+            Err(e) => warn!("unable to impair {}: {e}", impairment.device_name),
+        }
+        false
+    });
+    set
+}
+
+// Starts the pending captures of the chips in the scene, returning how
+// many were started.
+fn start_pending_captures() -> u32 {
+    let devices = scene_devices();
+    let mut started = 0;
+    PENDING_CAPTURES.lock().unwrap().retain(|capture| {
+        let Some(chip_id) =
+            chip_id(&devices, &capture.device_name, capture.chip_kind.enum_value_or_default())
+        else {
+            return true;
+        };
+        let format = capture.format.enum_value_or_default();
+        match start_chip_capture(chip_id, format, capture.mode.enum_value_or_default()) {
+            Ok(()) => started += 1,
+            Err(e) => warn!("unable to start the capture of {}: {e}", capture.device_name),
+        }
+        false
+    });
+    started
+}
+
+// Patches a device of the scene with the device of a scene file.
+fn patch_scene_device(device: &Device) -> Result<(), String> {
+    let device = Device { id: 0, ..device.clone() };
+    let request = PatchDeviceRequest { device: MessageField::some(device), ..Default::default() };
+    let json = protobuf_json_mapping::print_to_string(&request).map_err(|e| e.to_string())?;
+    cxx::let_cxx_string!(request = json);
+    cxx::let_cxx_string!(response = "");
+    cxx::let_cxx_string!(error_message = "");
+    match patch_device(&request, response.as_mut(), error_message.as_mut()) {
+        200 => Ok(()),
+        _ => Err(error_message.to_string()),
+    }
+}
+
+// Applies the settings, the saved configuration of the devices, the
+// beacons and the pending impairments and captures of a scene file. The
+// beacons already in the scene are kept. Returns the beacons added.
+fn apply_scene_file(scene_file: &SceneFile, source: &str) -> u32 {
+    if !scene_file.settings.is_empty() {
+        restore_settings(&merge_settings(saved_settings(), &scene_file.settings));
+    }
+    import_devices(&scene_file.scene.devices);
+    let added: Vec<String> = beacon_requests().into_iter().map(|request| request.name).collect();
+    let mut beacons = 0;
+    for request in scene_file.beacons.iter().filter(|request| !added.contains(&request.name)) {
+        match create_beacon(request) {
+            Ok(_) => beacons += 1,
+            Err(e) => {
+                warn!("unable to add beacon {} of scene {source}: {e}", request.name)
+            }
+        }
+    }
+    PENDING_IMPAIRMENTS.lock().unwrap().extend(scene_file.impairments.iter().cloned());
+    PENDING_CAPTURES.lock().unwrap().extend(scene_file.captures.iter().cloned());
+    beacons
+}
+
+/// Loads a scene file into the running scene, patching the devices of the
+/// scene file in the scene and setting the impairments of their chips.
+pub fn load_scenario(scene_file: &SceneFile) -> LoadScenarioResponse {
+    let beacons = apply_scene_file(scene_file, "scenario");
+    let mut response = LoadScenarioResponse { beacons, ..Default::default() };
+    let devices = scene_devices();
+    for device in scene_file.scene.devices.iter() {
+        if !devices.iter().any(|added| added.name == device.name) {
+            continue;
+        }
+        match patch_scene_device(device) {
+            Ok(()) => response.device_names.push(device.name.clone()),
+            Err(e) => warn!("unable to patch device {} of scenario: {e}", device.name),
+        }
+    }
+    response.impairments = set_pending_impairments();
+    response.pending_impairments = PENDING_IMPAIRMENTS.lock().unwrap().len() as u32;
+    response.captures = start_pending_captures();
+    response.pending_captures = PENDING_CAPTURES.lock().unwrap().len() as u32;
+    info!(
+        "loaded scenario with {} devices patched, {beacons} beacons, {} impairments set and {} captures started",
+        response.device_names.len(),
+        response.impairments,
+        response.captures
+    );
+    response
+}
+
+/// Saves the simulation to a scene file at `path`, or to a timestamped
+/// snapshot in the directory of the snapshots when empty.
+pub fn save_scene(path: &str) -> Result<PathBuf, String> {
+    let scene_file = export_scene()?;
+    match path {
+        "" => write_snapshot(&snapshot_dir(), &scene_file, now().as_secs()),
+        path => write_scene_file(Path::new(path), &scene_file).map(|_| PathBuf::from(path)),
+    }
+}
+
+/// Restores the simulation saved to a scene file.
+pub fn restore_scene(path: &str) -> Result<LoadScenarioResponse, String> {
+    let scene_file = fs::read_to_string(path)
+        .map_err(|e| format!("unable to read {path}: {e}"))
+        .and_then(|json| parse_scene_file(&json))?;
+    Ok(load_scenario(&scene_file))
+}
+
+// Returns the saved settings overridden by the settings of a scene.
+fn merge_settings(
+    saved: HashMap<String, String>,
+    scene: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    let mut settings = saved;
+    settings.extend(scene.iter().map(|(key, value)| (key.clone(), value.clone())));
+    settings
+}
+
+/// Returns the current scene as a scene file.
+pub fn export_scene() -> Result<SceneFile, String> {
+    let mut vec = Vec::<u8>::new();
+    if !get_devices_bytes(&mut vec) {
+        return Err("unable to get the scene".to_string());
+    }
+    let scene = Scene::parse_from_bytes(&vec).map_err(|e| e.to_string())?;
+    let (impairments, captures) =
+        scene_chip_state(&scene.devices, impairment_requests(), recording_capture_modes());
+    Ok(SceneFile {
+        scene: Some(scene).into(),
+        beacons: beacon_requests(),
+        settings: saved_settings(),
+        impairments,
+        captures,
+        ..Default::default()
+    })
+}
+
+/// Returns the directory of the scene snapshots.
+pub fn snapshot_dir() -> PathBuf {
+    match get_setting("snapshot.dir") {
+        dir if dir.is_empty() => match get_setting("instance") {
+            instance if instance.is_empty() => std::env::temp_dir().join("netsim-snapshots"),
+            instance => std::env::temp_dir().join(format!("netsim-snapshots-{instance}")),
+        },
+        dir => PathBuf::from(dir),
+    }
+}
+
+// Writes a scene file in the JSON format.
+fn write_scene_file(path: &Path, scene_file: &SceneFile) -> Result<(), String> {
+    let json = protobuf_json_mapping::print_to_string(scene_file).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| format!("unable to write {}: {e}", path.display()))
+}
+
+// Writes a snapshot of the scene file named after the time in seconds.
+fn write_snapshot(dir: &Path, scene_file: &SceneFile, secs: u64) -> Result<PathBuf, String> {
+    fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let time_display = TimeDisplay::new(secs as i64, 0).utc_display();
+    let path = dir.join(format!("{SNAPSHOT_PREFIX}{time_display}{SNAPSHOT_SUFFIX}"));
+    write_scene_file(&path, scene_file)?;
+    Ok(path)
+}
+
+// Removes the oldest snapshots beyond the retention, 0 keeps all.
+fn prune_snapshots(dir: &Path, retention: usize) -> std::io::Result<()> {
+    if retention == 0 {
+        return Ok(());
+    }
+    let mut snapshots: Vec<PathBuf> = fs::read_dir(dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name().and_then(|name| name.to_str()).is_some_and(|name| {
+                name.starts_with(SNAPSHOT_PREFIX) && name.ends_with(SNAPSHOT_SUFFIX)
+            })
+        })
+        .collect();
+    // The UTC timestamps of the names sort by time.
+    snapshots.sort();
+    for path in snapshots.iter().take(snapshots.len().saturating_sub(retention)) {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Exports the scene every snapshot.interval_mins, if enabled.
+pub fn run_snapshots() {
+    let interval_mins: u64 = get_setting_parsed("snapshot.interval_mins");
+    if interval_mins == 0 {
+        return;
+    }
+    let retention = get_setting_parsed("snapshot.retention");
+    let dir = snapshot_dir();
+    info!("exporting the scene to {} every {interval_mins} minutes", dir.display());
+    loop {
+        clock().sleep(Duration::from_secs(interval_mins * 60));
+        let result = export_scene()
+            .and_then(|scene_file| write_snapshot(&dir, &scene_file, now().as_secs()));
+        if let Err(e) = result {
+            warn!("unable to export a scene snapshot: {e}");
+            continue;
+        }
+        if let Err(e) = prune_snapshots(&dir, retention) {
+            warn!("unable to remove old scene snapshots: {e}");
+        }
+    }
+}
+
+// Cxx Method for netsimd to invoke
+
+/// Imports a scene file. Returns false when the file can't be imported.
+pub fn import_scene(path: &str) -> bool {
+    let scene_file = match fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|json| parse_scene_file(&json))
+    {
+        Ok(scene_file) => scene_file,
+        Err(e) => {
+            warn!("unable to import scene {path}: {e}");
+            return false;
+        }
+    };
+    let beacons = apply_scene_file(&scene_file, path);
+    info!(
+        "imported scene {path} with {} devices, {beacons} beacons, {} impairments and {} settings",
+        scene_file.scene.devices.len(),
+        scene_file.impairments.len(),
+        scene_file.settings.len()
+    );
+    true
+}
+
+// Cxx Method for the scene controller to invoke as a chip is added, setting
+// the pending impairments and starting the pending captures of the chip.
+pub fn add_scene_chip() {
+    if !PENDING_IMPAIRMENTS.lock().unwrap().is_empty() {
+        set_pending_impairments();
+    }
+    if !PENDING_CAPTURES.lock().unwrap().is_empty() {
+        start_pending_captures();
+    }
+}
+
+/// The Rust scene handler used directly by Http frontend for POST
+pub fn handle_scene(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "POST" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let body = String::from_utf8_lossy(&request.body);
+    let json = match param {
+        "" => protobuf_json_mapping::parse_from_str::<LoadScenarioRequest>(&body)
+            .map_err(|e| format!("Invalid scenario request: {e}"))
+            .and_then(|request| {
+                let scene_file = request.scene_file.unwrap_or_default();
+                check_scene_file(&scene_file).map(|_| scene_file)
+            })
+            .map(|scene_file| protobuf_json_mapping::print_to_string(&load_scenario(&scene_file))),
+        "save" => protobuf_json_mapping::parse_from_str::<SaveStateRequest>(&body)
+            .map_err(|e| format!("Invalid save request: {e}"))
+            .and_then(|request| save_scene(&request.path))
+            .map(|path| {
+                let path = path.display().to_string();
+                protobuf_json_mapping::print_to_string(&SaveStateResponse {
+                    path,
+                    ..Default::default()
+                })
+            }),
+        "restore" => protobuf_json_mapping::parse_from_str::<RestoreStateRequest>(&body)
+            .map_err(|e| format!("Invalid restore request: {e}"))
+            .and_then(|request| restore_scene(&request.path))
+            .map(|response| protobuf_json_mapping::print_to_string(&response)),
+        _ => {
+            writer.put_error(404, "Not found.");
+            return;
+        }
+    };
+    match json {
+        Ok(Ok(json)) => writer.put_ok("text/json", &json, &[]),
+        Ok(Err(e)) => writer.put_error(500, e.to_string().as_str()),
+        Err(e) => writer.put_error(400, e.as_str()),
+    }
+}
+
+/// scene handle cxx for grpc server to call
+pub fn handle_scene_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: match param.as_str() {
+            "" => "/v1/scenes".to_string(),
+            _ => format!("/v1/scenes/{param}"),
+        },
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_scene(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::model::Chip;
+
+    const SCENE: &str = r#"{
+        "scene": {"devices": [{"name": "phone", "position": {"x": 2.0}}]},
+        "beacons": [{"name": "tag", "intervalMs": 100}],
+        "settings": {"flood.rate": "1000", "propagation.model": "log_distance"}
+    }"#;
+
+    #[test]
+    fn test_parse_scene_file() {
+        let scene_file = parse_scene_file(SCENE).unwrap();
+        assert_eq!(scene_file.scene.devices[0].name, "phone");
+        assert_eq!(scene_file.scene.devices[0].position.x, 2.0);
+        assert_eq!(
+            (scene_file.beacons[0].name.as_str(), scene_file.beacons[0].interval_ms),
+            ("tag", 100)
+        );
+        assert!(parse_scene_file(r#"{"settings": {"flood.speed": "1"}}"#).is_err());
+        assert!(parse_scene_file("{").is_err());
+        let impairment = |json| format!(r#"{{"impairments": [{json}]}}"#);
+        assert!(parse_scene_file(&impairment(r#"{"impairment": {"dropPercent": 5}}"#)).is_err());
+        assert!(parse_scene_file(&impairment(
+            r#"{"deviceName": "phone", "impairment": {"dropPercent": 101}}"#
+        ))
+        .is_err());
+    }
+
+    fn device(name: &str, chips: &[(ChipKind, i32)]) -> Device {
+        Device {
+            name: name.to_string(),
+            chips: chips
+                .iter()
+                .map(|&(kind, id)| Chip { kind: kind.into(), id, ..Default::default() })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_resolve_impairment() {
+        let devices = [
+            device("phone", &[(ChipKind::BLUETOOTH, 1), (ChipKind::WIFI, 2)]),
+            device("watch", &[(ChipKind::BLUETOOTH, 3)]),
+        ];
+        let json = r#"{"impairments": [
+            {"deviceName": "phone", "chipKind": "WIFI", "impairment": {"dropPercent": 5, "chipId": 9}},
+            {"deviceName": "phone", "otherDeviceName": "watch", "impairment": {"latencyMs": 20}},
+            {"deviceName": "phone", "otherDeviceName": "tablet", "impairment": {"dropPercent": 1}}
+        ]}"#;
+        let impairments = parse_scene_file(json).unwrap().impairments;
+        let resolved: Vec<_> =
+            impairments.iter().map(|impairment| resolve_impairment(&devices, impairment)).collect();
+        let wifi = resolved[0].as_ref().unwrap();
+        assert_eq!((wifi.chip_id, wifi.other_chip_id, wifi.drop_percent), (2, 0, 5.0));
+        let link = resolved[1].as_ref().unwrap();
+        assert_eq!((link.chip_id, link.other_chip_id, link.latency_ms), (1, 3, 20));
+        // Pending until the tablet is added.
+        assert_eq!(resolved[2], None);
+    }
+
+    #[test]
+    fn test_scene_chip_state() {
+        let devices = [
+            device("phone", &[(ChipKind::BLUETOOTH, 1), (ChipKind::WIFI, 2)]),
+            device("watch", &[(ChipKind::BLUETOOTH, 3)]),
+        ];
+        let impairments = vec![
+            SetLinkImpairmentRequest { chip_id: 2, drop_percent: 5.0, ..Default::default() },
+            SetLinkImpairmentRequest { chip_id: 1, other_chip_id: 3, ..Default::default() },
+            // The chip of a removed device.
+            SetLinkImpairmentRequest { chip_id: 4, ..Default::default() },
+        ];
+        let captures = vec![(3, Format::PCAP, Mode::FILE), (4, Format::PCAP, Mode::FILE)];
+        let (impairments, captures) = scene_chip_state(&devices, impairments, captures);
+        let names: Vec<_> = impairments
+            .iter()
+            .map(|i| (i.device_name.as_str(), i.other_device_name.as_str()))
+            .collect();
+        assert_eq!(names, [("phone", ""), ("phone", "watch")]);
+        assert_eq!(impairments[0].chip_kind.enum_value_or_default(), ChipKind::WIFI);
+        assert_eq!(impairments[0].impairment.chip_id, 0);
+        assert_eq!(captures.len(), 1);
+        assert_eq!(captures[0].device_name, "watch");
+        // The saved chip state resolves to the same chips.
+        let wifi = resolve_impairment(&devices, &impairments[0]).unwrap();
+        assert_eq!((wifi.chip_id, wifi.drop_percent), (2, 5.0));
+        let link = resolve_impairment(&devices, &impairments[1]).unwrap();
+        assert_eq!((link.chip_id, link.other_chip_id), (1, 3));
+        let capture = &captures[0];
+        assert_eq!(
+            chip_id(&devices, &capture.device_name, capture.chip_kind.enum_value_or_default()),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_merge_settings() {
+        let saved = HashMap::from([
+            ("flood.rate".to_string(), "0".to_string()),
+            ("mtu.wifi".to_string(), "1500".to_string()),
+        ]);
+        let scene = parse_scene_file(SCENE).unwrap().settings;
+        let settings = merge_settings(saved, &scene);
+        assert_eq!(settings.len(), 3);
+        assert_eq!(settings["flood.rate"], "1000");
+        assert_eq!(settings["mtu.wifi"], "1500");
+    }
+
+    #[test]
+    fn test_snapshots() {
+        let dir = std::env::temp_dir().join(format!("netsim-snapshot-test-{}", std::process::id()));
+        let scene_file = parse_scene_file(SCENE).unwrap();
+        // 2023-11-14-22-13-20 and the next minutes.
+        for minute in 0..4 {
+            write_snapshot(&dir, &scene_file, 1_700_000_000 + minute * 60).unwrap();
+        }
+        fs::write(dir.join("notes.txt"), "kept").unwrap();
+        prune_snapshots(&dir, 2).unwrap();
+        let mut names: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            [
+                "netsim-scene-2023-11-14-22-15-20.json",
+                "netsim-scene-2023-11-14-22-16-20.json",
+                "notes.txt"
+            ]
+        );
+        let json = fs::read_to_string(dir.join(&names[1])).unwrap();
+        assert_eq!(parse_scene_file(&json).unwrap(), scene_file);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Chip selections.
+//!
+//! A PatchSelection patches the chips matched by its Selector: the chips
+//! of the devices with a name matching a pattern, like `pixel*`, and of a
+//! chip kind. The chip of the request is patched into each selected chip,
+//! e.g. turning off the Bluetooth LE radio of every Pixel, and the capture
+//! state of the request starts or stops their captures. A chip failing
+//! doesn't stop the others: the response holds the result of each chip.
+//!
+//! /v1/selection --> handle_selection (PATCH a PatchSelectionRequest)
+
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::patch_selection_response::Result as ChipResult;
+use frontend_proto::frontend::{PatchSelectionRequest, PatchSelectionResponse};
+use frontend_proto::model::{Chip, Device, State};
+use std::pin::Pin;
+
+use crate::captures::handlers::set_chip_captures;
+use crate::ffi::CxxServerResponseWriter;
+use crate::group::{patch, scene_devices};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::CxxServerResponseWriterWrapper;
+
+/// Whether a name matches a pattern, where * matches any characters and ?
+/// a single one.
+pub fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    // The positions after the last * in the pattern and in the name, to
+    // backtrack to when the characters after the * don't match.
+    let (mut p, mut n) = (0, 0);
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p + 1, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Returns the chips of the devices selected by a request, with their
+/// device.
+pub fn select<'a>(
+    devices: &'a [Device],
+    request: &PatchSelectionRequest,
+) -> Vec<(&'a Device, &'a Chip)> {
+    let selector = request.selector.as_ref().unwrap_or_default();
+    let chip_kind = selector.chip_kind.enum_value_or_default();
+    // A patched chip without a kind is a Bluetooth chip, as in PatchDevice.
+    let patch_kind = request.chip.as_ref().map(|chip| chip.kind.enum_value_or(ChipKind::BLUETOOTH));
+    let mut selection = Vec::new();
+    for device in devices {
+        if !selector.device.is_empty() && !matches_pattern(&selector.device, &device.name) {
+            continue;
+        }
+        for chip in &device.chips {
+            let kind = chip.kind.enum_value_or_default();
+            if (chip_kind == ChipKind::UNSPECIFIED || kind == chip_kind)
+                && patch_kind.is_none_or(|patch_kind| kind == patch_kind)
+            {
+                selection.push((device, chip));
+            }
+        }
+    }
+    selection
+}
+
+// Patches the chip of the request into a selected chip.
+fn patch_chip(device: &Device, chip: &Chip, template: &Chip) -> Result<(), String> {
+    // The patch keeps the visibility of the device, which a PatchDevice
+    // sets.
+    let chip_patch = Chip { name: chip.name.clone(), kind: chip.kind, ..template.clone() };
+    patch(Device {
+        name: device.name.clone(),
+        visible: device.visible,
+        chips: vec![chip_patch],
+        ..Default::default()
+    })
+}
+
+/// The Rust selection handler used directly by Http frontend for PATCH
+pub fn handle_selection(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "PATCH" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let body = String::from_utf8_lossy(&request.body);
+    let selection_request =
+        match protobuf_json_mapping::parse_from_str::<PatchSelectionRequest>(&body) {
+            Ok(selection_request) => selection_request,
+            Err(e) => {
+                writer.put_error(400, format!("Invalid selection request: {e}").as_str());
+                return;
+            }
+        };
+    let capture = selection_request.capture.enum_value_or_default();
+    if selection_request.chip.is_none() && capture == State::UNKNOWN {
+        writer.put_error(400, "Incorrect selection, expected a chip or a capture state");
+        return;
+    }
+    let devices = scene_devices();
+    let selection = select(&devices, &selection_request);
+    if selection.is_empty() {
+        writer.put_error(404, "No chips match the selector");
+        return;
+    }
+    let mut results: Vec<ChipResult> = selection
+        .iter()
+        .map(|(device, chip)| ChipResult {
+            device_name: device.name.clone(),
+            chip_id: chip.id,
+            chip_kind: chip.kind,
+            error: match selection_request.chip.as_ref() {
+                Some(template) => patch_chip(device, chip, template).err().unwrap_or_default(),
+                None => String::new(),
+            },
+            ..Default::default()
+        })
+        .collect();
+    if capture != State::UNKNOWN {
+        let chip_ids: Vec<_> = selection.iter().map(|(_, chip)| chip.id).collect();
+        let format = selection_request.format.enum_value_or_default();
+        for (chip_id, err) in set_chip_captures(&chip_ids, capture == State::ON, format) {
+            for result in results.iter_mut().filter(|result| result.chip_id == chip_id) {
+                let error = format!("capture: {err}");
+                result.error = match result.error.is_empty() {
+                    true => error,
+                    false => format!("{}; {error}", result.error),
+                };
+            }
+        }
+    }
+    let response = PatchSelectionResponse { results, ..Default::default() };
+    match protobuf_json_mapping::print_to_string(&response) {
+        Ok(json) => writer.put_ok("text/json", &json, &[]),
+        Err(e) => writer.put_error(500, e.to_string().as_str()),
+    }
+}
+
+/// selection handle cxx for grpc server to call
+pub fn handle_selection_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/selection".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_selection(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::frontend::Selector;
+    use protobuf::MessageField;
+
+    fn device(name: &str, kinds: &[(i32, ChipKind)]) -> Device {
+        Device {
+            name: name.to_string(),
+            chips: kinds
+                .iter()
+                .map(|&(id, kind)| Chip { id, kind: kind.into(), ..Default::default() })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn scene() -> Vec<Device> {
+        vec![
+            device("pixel-1", &[(1, ChipKind::BLUETOOTH), (2, ChipKind::WIFI)]),
+            device("pixel-2", &[(3, ChipKind::BLUETOOTH)]),
+            device("watch", &[(4, ChipKind::BLUETOOTH), (5, ChipKind::UWB)]),
+        ]
+    }
+
+    fn selected(request: &PatchSelectionRequest) -> Vec<i32> {
+        select(&scene(), request).iter().map(|(_, chip)| chip.id).collect()
+    }
+
+    fn selector(device: &str, chip_kind: ChipKind) -> MessageField<Selector> {
+        MessageField::some(Selector {
+            device: device.to_string(),
+            chip_kind: chip_kind.into(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("pixel*", "pixel-1"));
+        assert!(matches_pattern("pixel*", "pixel"));
+        assert!(matches_pattern("*-?", "pixel-2"));
+        assert!(matches_pattern("p*l*2", "pixel-2"));
+        assert!(matches_pattern("*", ""));
+        assert!(matches_pattern("watch", "watch"));
+        assert!(!matches_pattern("watch", "watch-2"));
+        assert!(!matches_pattern("pixel?", "pixel"));
+        assert!(!matches_pattern("*-1", "pixel-2"));
+    }
+
+    #[test]
+    fn test_select() {
+        let request = PatchSelectionRequest::new();
+        assert_eq!(selected(&request), [1, 2, 3, 4, 5]);
+        let request = PatchSelectionRequest {
+            selector: selector("pixel*", ChipKind::UNSPECIFIED),
+            ..Default::default()
+        };
+        assert_eq!(selected(&request), [1, 2, 3]);
+        let request = PatchSelectionRequest {
+            selector: selector("", ChipKind::BLUETOOTH),
+            ..Default::default()
+        };
+        assert_eq!(selected(&request), [1, 3, 4]);
+        // The patched chip selects the chips of its kind.
+        let request = PatchSelectionRequest {
+            selector: selector("pixel*", ChipKind::UNSPECIFIED),
+            chip: MessageField::some(Chip { kind: ChipKind::WIFI.into(), ..Default::default() }),
+            ..Default::default()
+        };
+        assert_eq!(selected(&request), [2]);
+        let request = PatchSelectionRequest {
+            selector: selector("phone*", ChipKind::UNSPECIFIED),
+            ..Default::default()
+        };
+        assert!(selected(&request).is_empty());
+    }
+}
@@ -0,0 +1,650 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Session record and replay.
+//!
+//! A recording writes every packet arriving from the emulators, with its
+//! time on the netsimd clock, and every change of the configuration of the
+//! simulation into a session log. A replay re-drives a netsimd without
+//! devices from a session log: it seeds the random numbers of the
+//! simulation with the seed of the recording, then re-adds the chips,
+//! re-applies the changes and re-sends the packets at their recorded times.
+//! With the `debug.clock=manual` setting the replay advances the clock to
+//! each record instead of sleeping, so timers fire at the same points of
+//! the packet sequence on every replay.
+//!
+//! Recording starts with netsimd when the `debug.session_log` setting names
+//! a session log.
+//!
+//! /v1/debug/session --> handle_session (POST a ControlSessionRequest)
+
+use cxx::{let_cxx_string, CxxVector};
+use frontend_proto::frontend::control_session_request::Action;
+use frontend_proto::frontend::session_record::{ChipAdded, ChipRemoved, Header, Packet, Record};
+use frontend_proto::frontend::{
+    ChipKindInfo, ControlSessionRequest, ControlSessionResponse, GetDevicesResponse,
+    PatchDeviceRequest, SessionRecord,
+};
+use frontend_proto::model::Device;
+use lazy_static::lazy_static;
+use protobuf::well_known_types::empty::Empty;
+use protobuf::{CodedInputStream, Message, MessageField};
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::chip_kinds::register;
+use crate::clock::{clock, manual_clock};
+use crate::config::{get_setting, redacted_settings};
+use crate::ffi::{
+    add_chip_cxx, get_devices_bytes, handle_request_cxx, patch_device, remove_chip, reset_scene,
+    CxxServerResponseWriter,
+};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::logger::warn;
+use crate::random::{reseed, seed};
+use crate::version::get_version;
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+/// Settings that don't change the behavior of the simulation.
+const IGNORED_SETTINGS: [&str; 6] =
+    ["instance", "state.file", "grpc.port", "http.port", "debug.seed", "debug.session_log"];
+
+/// Writes the records of a session log.
+pub struct SessionWriter<W: Write> {
+    out: W,
+    // Clock time of the start of the session.
+    start: Duration,
+    records: u64,
+}
+
+impl<W: Write> SessionWriter<W> {
+    pub fn new(out: W, start: Duration) -> Self {
+        SessionWriter { out, start, records: 0 }
+    }
+
+    /// Appends a record of clock time `time`.
+    pub fn write(&mut self, time: Duration, record: Record) -> protobuf::Result<()> {
+        let record = SessionRecord {
+            time_us: time.saturating_sub(self.start).as_micros() as u64,
+            record: Some(record),
+            ..Default::default()
+        };
+        record.write_length_delimited_to_writer(&mut self.out)?;
+        self.records += 1;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// Reads the records of a session log.
+pub fn read_session(reader: &mut dyn BufRead) -> Result<Vec<SessionRecord>, String> {
+    let mut input = CodedInputStream::from_buf_read(reader);
+    let mut records = Vec::new();
+    while !input.eof().map_err(|e| e.to_string())? {
+        records.push(input.read_message::<SessionRecord>().map_err(|e| e.to_string())?);
+    }
+    Ok(records)
+}
+
+struct Recording {
+    path: String,
+    writer: SessionWriter<BufWriter<File>>,
+}
+
+/// The recording of the running simulation.
+struct Recorder {
+    recording: Mutex<Option<Recording>>,
+    // Fast path of record when nothing is recorded.
+    active: AtomicBool,
+}
+
+impl Recorder {
+    fn new() -> Recorder {
+        let recorder = Recorder { recording: Mutex::new(None), active: false.into() };
+        let path = get_setting("debug.session_log");
+        if !path.is_empty() {
+            if let Err(e) = recorder.start(&path) {
+                warn!("unable to record the session: {e}");
+            }
+        }
+        recorder
+    }
+
+    fn start(&self, path: &str) -> Result<(), String> {
+        let mut recording = self.recording.lock().unwrap();
+        if let Some(recording) = recording.as_ref() {
+            return Err(format!("Already recording into {}", recording.path));
+        }
+        let file = File::create(path).map_err(|e| format!("{path}: {e}"))?;
+        let mut writer = SessionWriter::new(BufWriter::new(file), clock().monotonic());
+        let header = Header {
+            version: get_version(),
+            seed: seed(),
+            settings: redacted_settings().into_iter().collect(),
+            ..Default::default()
+        };
+        writer.write(clock().monotonic(), Record::Header(header)).map_err(|e| e.to_string())?;
+        *recording = Some(Recording { path: path.to_string(), writer });
+        self.active.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), String> {
+        let mut recording = self.recording.lock().unwrap();
+        self.active.store(false, Ordering::Release);
+        match recording.take() {
+            Some(mut recording) => recording.writer.flush().map_err(|e| e.to_string()),
+            None => Err("Not recording".to_string()),
+        }
+    }
+
+    fn record(&self, record: impl FnOnce() -> Record) {
+        if !self.active.load(Ordering::Acquire) {
+            return;
+        }
+        let mut guard = self.recording.lock().unwrap();
+        let Some(recording) = guard.as_mut() else {
+            return;
+        };
+        let record = record();
+        // Packets are flushed with the next change of the configuration or
+        // when the recording stops.
+        let flush = !matches!(record, Record::Packet(_));
+        let result = recording.writer.write(clock().monotonic(), record).map_err(|e| e.to_string());
+        if let Err(e) = result.and_then(|_| match flush {
+            true => recording.writer.flush().map_err(|e| e.to_string()),
+            false => Ok(()),
+        }) {
+            warn!("session recording into {} stopped: {e}", recording.path);
+            *guard = None;
+            self.active.store(false, Ordering::Release);
+        }
+    }
+
+    fn status(&self) -> ControlSessionResponse {
+        let recording = self.recording.lock().unwrap();
+        match recording.as_ref() {
+            Some(recording) => ControlSessionResponse {
+                recording: true,
+                path: recording.path.clone(),
+                records: recording.writer.records,
+                ..Default::default()
+            },
+            None => ControlSessionResponse::new(),
+        }
+    }
+}
+
+lazy_static! {
+    static ref RECORDER: Recorder = Recorder::new();
+}
+
+/// The parts of netsimd a replay drives.
+pub trait Target {
+    fn device_count(&self) -> usize;
+    /// Returns the device id, chip id and facade id of the added chip.
+    fn add_chip(&mut self, chip: &ChipAdded) -> (u32, u32, u32);
+    fn remove_chip(&mut self, device_id: u32, chip_id: u32);
+    fn patch_device(&mut self, device: &Device) -> Result<(), String>;
+    fn reset(&mut self);
+    fn register_chip_kind(&mut self, info: &ChipKindInfo) -> Result<u32, String>;
+    fn handle_request(&mut self, kind: u32, facade_id: u32, packet: &[u8], packet_type: u8);
+    /// Waits until `time` since the start of the replay.
+    fn wait_until(&mut self, time: Duration);
+}
+
+/// The running netsimd.
+struct Netsimd {
+    // Clock time of the start of the replay.
+    start: Duration,
+}
+
+impl Target for Netsimd {
+    fn device_count(&self) -> usize {
+        let mut vec = Vec::<u8>::new();
+        if !get_devices_bytes(&mut vec) {
+            return 0;
+        }
+        GetDevicesResponse::parse_from_bytes(&vec).map(|r| r.devices.len()).unwrap_or_default()
+    }
+
+    fn add_chip(&mut self, chip: &ChipAdded) -> (u32, u32, u32) {
+        let_cxx_string!(guid = chip.guid.clone());
+        let_cxx_string!(device_name = chip.device_name.clone());
+        let_cxx_string!(chip_name = chip.chip_name.clone());
+        let_cxx_string!(manufacturer = chip.manufacturer.clone());
+        let_cxx_string!(product_name = chip.product_name.clone());
+        let result =
+            add_chip_cxx(&guid, &device_name, chip.kind, &chip_name, &manufacturer, &product_name);
+        (result.get_device_id(), result.get_chip_id(), result.get_facade_id())
+    }
+
+    fn remove_chip(&mut self, device_id: u32, chip_id: u32) {
+        remove_chip(device_id, chip_id);
+    }
+
+    fn patch_device(&mut self, device: &Device) -> Result<(), String> {
+        let request =
+            PatchDeviceRequest { device: MessageField::some(device.clone()), ..Default::default() };
+        let json = protobuf_json_mapping::print_to_string(&request).map_err(|e| e.to_string())?;
+        let_cxx_string!(request = json);
+        let_cxx_string!(response = "");
+        let_cxx_string!(error_message = "");
+        match patch_device(&request, response.as_mut(), error_message.as_mut()) {
+            200 => Ok(()),
+            _ => Err(error_message.to_string()),
+        }
+    }
+
+    fn reset(&mut self) {
+        reset_scene();
+    }
+
+    fn register_chip_kind(&mut self, info: &ChipKindInfo) -> Result<u32, String> {
+        register(ChipKindInfo { id: 0, ..info.clone() }).map(|info| info.id)
+    }
+
+    fn handle_request(&mut self, kind: u32, facade_id: u32, packet: &[u8], packet_type: u8) {
+        handle_request_cxx(kind, facade_id, &packet.to_vec(), packet_type);
+    }
+
+    fn wait_until(&mut self, time: Duration) {
+        let elapsed = clock().monotonic().saturating_sub(self.start);
+        if time > elapsed {
+            match manual_clock() {
+                Some(clock) => clock.advance(time - elapsed),
+                None => clock().sleep(time - elapsed),
+            }
+        }
+    }
+}
+
+// Returns the differences between the recording and the replaying netsimd.
+fn compare_header(header: &Header, version: &str, settings: &[(String, String)]) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if header.version != version {
+        warnings.push(format!("Recorded by netsimd {}, replayed by {version}", header.version));
+    }
+    for (key, value) in settings {
+        if IGNORED_SETTINGS.contains(&key.as_str()) {
+            continue;
+        }
+        match header.settings.get(key) {
+            Some(recorded) if recorded != value => {
+                warnings.push(format!("Setting {key} recorded as {recorded}, replayed as {value}"))
+            }
+            _ => {}
+        }
+    }
+    warnings
+}
+
+/// Replays the records of a session log against `target`. Returns the
+/// number of records replayed and the warnings of the replay.
+pub fn replay(
+    target: &mut dyn Target,
+    records: &[SessionRecord],
+    on_header: impl FnOnce(&Header) -> Vec<String>,
+) -> Result<(u64, Vec<String>), String> {
+    let Some(Record::Header(header)) = records.first().and_then(|record| record.record.as_ref())
+    else {
+        return Err("Not a session log".to_string());
+    };
+    if target.device_count() > 0 {
+        return Err("Replay requires a netsimd without devices".to_string());
+    }
+    let mut warnings = on_header(header);
+    // Ids of the recording to the ids of the replay.
+    let mut kinds: HashMap<u32, u32> = HashMap::new();
+    let mut chips: HashMap<u32, (u32, u32)> = HashMap::new();
+    let mut facades: HashMap<(u32, u32), u32> = HashMap::new();
+    let mut skipped = 0;
+    for record in &records[1..] {
+        target.wait_until(Duration::from_micros(record.time_us));
+        let kind = |kind: u32| kinds.get(&kind).copied().unwrap_or(kind);
+        match &record.record {
+            Some(Record::ChipAdded(chip)) => {
+                let kind = kind(chip.kind);
+                let (device_id, chip_id, facade_id) =
+                    target.add_chip(&ChipAdded { kind, ..chip.clone() });
+                chips.insert(chip.chip_id, (device_id, chip_id));
+                facades.insert((kind, chip.facade_id), facade_id);
+            }
+            Some(Record::ChipRemoved(ChipRemoved { chip_id, .. })) => {
+                if let Some((device_id, chip_id)) = chips.remove(chip_id) {
+                    target.remove_chip(device_id, chip_id);
+                }
+            }
+            Some(Record::DevicePatched(device)) => {
+                if let Err(e) = target.patch_device(device) {
+                    warnings.push(format!("Patch of device {} failed: {e}", device.name));
+                }
+            }
+            Some(Record::Reset(_)) => target.reset(),
+            Some(Record::ChipKindRegistered(info)) => match target.register_chip_kind(info) {
+                Ok(id) => {
+                    kinds.insert(info.id, id);
+                }
+                Err(e) => {
+                    warnings.push(format!("Registration of chip kind {} failed: {e}", info.name))
+                }
+            },
+            Some(Record::Packet(packet)) => {
+                let kind = kind(packet.kind);
+                match facades.get(&(kind, packet.facade_id)) {
+                    Some(&facade_id) => target.handle_request(
+                        kind,
+                        facade_id,
+                        &packet.packet,
+                        packet.packet_type as u8,
+                    ),
+                    None => skipped += 1,
+                }
+            }
+            Some(Record::Header(_)) => return Err("Session log with several headers".to_string()),
+            _ => {}
+        }
+    }
+    if skipped > 0 {
+        warnings.push(format!("{skipped} packet(s) of unknown chips skipped"));
+    }
+    Ok((records.len() as u64, warnings))
+}
+
+fn replay_file(path: &str) -> Result<ControlSessionResponse, String> {
+    let file = File::open(path).map_err(|e| format!("{path}: {e}"))?;
+    let records = read_session(&mut BufReader::new(file))?;
+    let mut target = Netsimd { start: clock().monotonic() };
+    let (replayed, warnings) = replay(&mut target, &records, |header| {
+        reseed(header.seed);
+        compare_header(header, &get_version(), &redacted_settings())
+    })?;
+    for warning in warnings.iter() {
+        warn!("replay of {path}: {warning}");
+    }
+    Ok(ControlSessionResponse { path: path.to_string(), replayed, warnings, ..RECORDER.status() })
+}
+
+/// Applies a session control request.
+pub fn control_session(request: &ControlSessionRequest) -> Result<ControlSessionResponse, String> {
+    match request.action.enum_value() {
+        Ok(Action::GET) => {}
+        Ok(Action::RECORD) if request.path.is_empty() => {
+            return Err("Missing session log path".to_string())
+        }
+        Ok(Action::RECORD) => RECORDER.start(&request.path)?,
+        Ok(Action::STOP) => RECORDER.stop()?,
+        Ok(Action::REPLAY) => return replay_file(&request.path),
+        _ => return Err("unknown session action".to_string()),
+    }
+    Ok(RECORDER.status())
+}
+
+/// The Rust session handler used directly by Http frontend for POST
+pub fn handle_session(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "POST" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let body = String::from_utf8_lossy(&request.body);
+    let response = protobuf_json_mapping::parse_from_str::<ControlSessionRequest>(&body)
+        .map_err(|e| format!("Invalid session request: {e}"))
+        .and_then(|request| control_session(&request));
+    match response.map(|response| print_to_string_with_options(&response, &JSON_PRINT_OPTION)) {
+        Ok(Ok(json_response)) => writer.put_ok("text/json", &json_response, &[]),
+        Ok(Err(_)) => writer.put_error(404, "proto to JSON mapping failure"),
+        Err(e) => writer.put_error(400, &e),
+    }
+}
+
+/// session handle cxx for grpc server to call
+pub fn handle_session_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/debug/session".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.as_bytes().to_vec(),
+    };
+    handle_session(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+/// Records the registration of a custom chip kind.
+pub fn record_chip_kind(info: &ChipKindInfo) {
+    RECORDER.record(|| Record::ChipKindRegistered(info.clone()));
+}
+
+// Cxx Methods for the scene controller and packet_hub to invoke
+
+#[allow(clippy::too_many_arguments)]
+pub fn record_chip_added(
+    guid: &str,
+    device_name: &str,
+    kind: u32,
+    chip_name: &str,
+    manufacturer: &str,
+    product_name: &str,
+    chip_id: u32,
+    facade_id: u32,
+) {
+    RECORDER.record(|| {
+        Record::ChipAdded(ChipAdded {
+            guid: guid.to_string(),
+            device_name: device_name.to_string(),
+            kind,
+            chip_name: chip_name.to_string(),
+            manufacturer: manufacturer.to_string(),
+            product_name: product_name.to_string(),
+            chip_id,
+            facade_id,
+            ..Default::default()
+        })
+    });
+}
+
+pub fn record_chip_removed(chip_id: u32) {
+    RECORDER.record(|| Record::ChipRemoved(ChipRemoved { chip_id, ..Default::default() }));
+}
+
+pub fn record_device_patched(patch: &[u8]) {
+    RECORDER.record(|| Record::DevicePatched(Device::parse_from_bytes(patch).unwrap_or_default()));
+}
+
+pub fn record_reset() {
+    RECORDER.record(|| Record::Reset(Empty::new()));
+}
+
+pub fn record_ingress(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) {
+    RECORDER.record(|| {
+        Record::Packet(Packet {
+            kind,
+            facade_id,
+            packet_type,
+            packet: packet.as_slice().to_vec(),
+            ..Default::default()
+        })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Fake {
+        calls: Vec<String>,
+        next_id: u32,
+        time: Duration,
+    }
+
+    impl Target for Fake {
+        fn device_count(&self) -> usize {
+            0
+        }
+
+        fn add_chip(&mut self, chip: &ChipAdded) -> (u32, u32, u32) {
+            self.next_id += 1;
+            self.calls.push(format!("add {} {}", chip.device_name, self.next_id));
+            (self.next_id, 10 + self.next_id, 20 + self.next_id)
+        }
+
+        fn remove_chip(&mut self, device_id: u32, chip_id: u32) {
+            self.calls.push(format!("remove {device_id} {chip_id}"));
+        }
+
+        fn patch_device(&mut self, device: &Device) -> Result<(), String> {
+            self.calls.push(format!("patch {}", device.name));
+            Ok(())
+        }
+
+        fn reset(&mut self) {
+            self.calls.push("reset".to_string());
+        }
+
+        fn register_chip_kind(&mut self, info: &ChipKindInfo) -> Result<u32, String> {
+            self.calls.push(format!("register {}", info.name));
+            Ok(info.id + 1)
+        }
+
+        fn handle_request(&mut self, kind: u32, facade_id: u32, packet: &[u8], _: u8) {
+            self.calls.push(format!("packet {kind} {facade_id} {packet:?} at {:?}", self.time));
+        }
+
+        fn wait_until(&mut self, time: Duration) {
+            self.time = self.time.max(time);
+        }
+    }
+
+    fn chip_added(device_name: &str, kind: u32, chip_id: u32, facade_id: u32) -> Record {
+        Record::ChipAdded(ChipAdded {
+            device_name: device_name.to_string(),
+            kind,
+            chip_id,
+            facade_id,
+            ..Default::default()
+        })
+    }
+
+    fn packet(kind: u32, facade_id: u32, packet: &[u8]) -> Record {
+        Record::Packet(Packet { kind, facade_id, packet: packet.to_vec(), ..Default::default() })
+    }
+
+    #[test]
+    fn test_write_and_read() {
+        let start = Duration::from_secs(5);
+        let mut writer = SessionWriter::new(Vec::new(), start);
+        writer.write(start, Record::Header(Header { seed: 7, ..Default::default() })).unwrap();
+        writer.write(start + Duration::from_millis(3), packet(1, 2, &[1, 2])).unwrap();
+        assert_eq!(writer.records, 2);
+        let records = read_session(&mut writer.out.as_slice()).unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(matches!(&records[0].record, Some(Record::Header(header)) if header.seed == 7));
+        assert_eq!(records[1].time_us, 3000);
+        assert!(read_session(&mut [0x05, 0x01].as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_replay() {
+        let record =
+            |time_us, record| SessionRecord { time_us, record: Some(record), ..Default::default() };
+        let info = ChipKindInfo { id: 100, name: "lora".to_string(), ..Default::default() };
+        let records = vec![
+            record(0, Record::Header(Header { seed: 7, ..Default::default() })),
+            record(0, Record::ChipKindRegistered(info)),
+            record(1, chip_added("phone", 1, 5, 3)),
+            record(2, chip_added("sensor", 100, 6, 3)),
+            record(10, packet(1, 3, &[1])),
+            record(20, packet(100, 3, &[2])),
+            record(30, packet(1, 4, &[3])),
+            record(
+                40,
+                Record::DevicePatched(Device { name: "phone".to_string(), ..Default::default() }),
+            ),
+            record(50, Record::ChipRemoved(ChipRemoved { chip_id: 5, ..Default::default() })),
+            record(60, Record::Reset(Empty::new())),
+        ];
+        let mut fake = Fake::default();
+        let mut seeded = 0;
+        let (replayed, warnings) = replay(&mut fake, &records, |header| {
+            seeded = header.seed;
+            Vec::new()
+        })
+        .unwrap();
+        assert_eq!(seeded, 7);
+        assert_eq!(replayed, 10);
+        assert_eq!(warnings, ["1 packet(s) of unknown chips skipped"]);
+        assert_eq!(
+            fake.calls,
+            [
+                "register lora",
+                "add phone 1",
+                "add sensor 2",
+                "packet 1 21 [1] at 10µs",
+                "packet 101 22 [2] at 20µs",
+                "patch phone",
+                "remove 1 11",
+                "reset",
+            ]
+        );
+        assert!(replay(&mut fake, &records[1..], |_| Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_compare_header() {
+        let header = Header {
+            version: "1.0".to_string(),
+            settings: HashMap::from([
+                ("flood.rate".to_string(), "100".to_string()),
+                ("grpc.port".to_string(), "8554".to_string()),
+            ]),
+            ..Default::default()
+        };
+        let settings = [
+            ("flood.rate".to_string(), "0".to_string()),
+            ("grpc.port".to_string(), "0".to_string()),
+            ("mtu.wifi".to_string(), "0".to_string()),
+        ];
+        assert_eq!(
+            compare_header(&header, "1.0", &settings),
+            ["Setting flood.rate recorded as 100, replayed as 0"]
+        );
+        assert_eq!(compare_header(&header, "1.1", &[]).len(), 1);
+    }
+}
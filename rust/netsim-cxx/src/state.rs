@@ -0,0 +1,255 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Persistent daemon state.
+//!
+//! netsimd saves the configuration of every device it has seen, including
+//! chip radio and capture states, together with the resolved settings to a
+//! state file. On startup the file is loaded and the saved configuration is
+//! applied to devices as their chips are added again.
+
+use crate::auth::create_private_file;
+use crate::config::{get_setting, restore_settings, saved_settings};
+use crate::ffi::get_devices_bytes;
+use crate::logger::{info, warn};
+use frontend_proto::model::{Chip as ProtoChip, Device as ProtoDevice, SavedState, Scene};
+use lazy_static::lazy_static;
+use protobuf::Message;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+lazy_static! {
+    // Device configurations keyed by device name.
+    static ref SAVED_DEVICES: RwLock<BTreeMap<String, ProtoDevice>> =
+        RwLock::new(BTreeMap::new());
+}
+
+/// Returns the path of the state file.
+pub fn state_file_path() -> PathBuf {
+    match get_setting("state.file") {
+        path if path.is_empty() => match get_setting("instance") {
+            instance if instance.is_empty() => std::env::temp_dir().join("netsim_state.json"),
+            instance => std::env::temp_dir().join(format!("netsim_state_{instance}.json")),
+        },
+        path => PathBuf::from(path),
+    }
+}
+
+/// Loads the state file saved by a previous run unless `fresh` is set.
+pub fn load_state(fresh: bool) {
+    let path = state_file_path();
+    if fresh {
+        info!("ignoring saved state in {}", path.display());
+        return;
+    }
+    let json = match fs::read_to_string(&path) {
+        Ok(json) => json,
+        // First run, nothing to restore.
+        Err(_) => return,
+    };
+    let state = match protobuf_json_mapping::parse_from_str::<SavedState>(&json) {
+        Ok(state) => state,
+        Err(e) => {
+            warn!("invalid state file {}: {}", path.display(), e);
+            return;
+        }
+    };
+    restore_settings(&state.settings);
+    let mut saved_devices = SAVED_DEVICES.write().unwrap();
+    for device in state.scene.devices.iter() {
+        saved_devices.insert(device.name.clone(), strip_device(device));
+    }
+    info!("restored state of {} devices from {}", saved_devices.len(), path.display());
+}
+
+/// Replaces the saved configuration of devices, such as the devices of an
+/// imported scene file.
+pub fn import_devices(devices: &[ProtoDevice]) {
+    let mut saved_devices = SAVED_DEVICES.write().unwrap();
+    for device in devices.iter() {
+        saved_devices.insert(device.name.clone(), strip_device(device));
+    }
+}
+
+/// Saves the current scene and settings to the state file.
+pub fn save_state() {
+    let mut vec = Vec::<u8>::new();
+    if !get_devices_bytes(&mut vec) {
+        warn!("unable to get the scene to save state");
+        return;
+    }
+    let scene = match Scene::parse_from_bytes(&vec) {
+        Ok(scene) => scene,
+        Err(e) => {
+            warn!("unable to parse the scene to save state: {e}");
+            return;
+        }
+    };
+    let mut saved_devices = SAVED_DEVICES.write().unwrap();
+    for device in scene.devices.iter() {
+        saved_devices.insert(device.name.clone(), strip_device(device));
+    }
+    let mut state = SavedState::new();
+    state.scene.mut_or_insert_default().devices = saved_devices.values().cloned().collect();
+    state.settings = saved_settings();
+    let json = match protobuf_json_mapping::print_to_string(&state) {
+        Ok(json) => json,
+        Err(e) => {
+            warn!("unable to serialize state: {e}");
+            return;
+        }
+    };
+    let path = state_file_path();
+    // The state file is only readable by the user running netsimd.
+    if let Err(e) = create_private_file(&path).and_then(|mut file| file.write_all(json.as_bytes()))
+    {
+        warn!("unable to write state file {}: {}", path.display(), e);
+    }
+}
+
+/// Returns the serialized model::Device patch that restores the saved
+/// configuration of a newly added chip, or an empty vector if nothing was
+/// saved. Device level fields are included only for the device's first chip.
+pub fn saved_chip_patch(
+    device_name: &str,
+    chip_kind: u32,
+    chip_name: &str,
+    first_chip: bool,
+) -> Vec<u8> {
+    match SAVED_DEVICES.read().unwrap().get(device_name) {
+        Some(saved) => {
+            chip_patch(saved, chip_kind, chip_name, first_chip).write_to_bytes().unwrap_or_default()
+        }
+        None => Vec::new(),
+    }
+}
+
+fn chip_patch(
+    saved: &ProtoDevice,
+    chip_kind: u32,
+    chip_name: &str,
+    first_chip: bool,
+) -> ProtoDevice {
+    let mut patch = ProtoDevice::new();
+    patch.name = saved.name.clone();
+    // Device patches always apply the visible flag.
+    patch.visible = saved.visible;
+    if first_chip {
+        patch.position = saved.position.clone();
+        patch.orientation = saved.orientation.clone();
+    }
+    patch.chips = saved
+        .chips
+        .iter()
+        .filter(|chip| chip.kind.value() as u32 == chip_kind && chip.name == chip_name)
+        .cloned()
+        .collect();
+    patch
+}
+
+/// Drops the runtime fields that a restart shouldn't carry over.
+fn strip_device(device: &ProtoDevice) -> ProtoDevice {
+    let mut device = device.clone();
+    device.id = 0;
+    for chip in device.chips.iter_mut() {
+        strip_chip(chip);
+    }
+    device
+}
+
+fn strip_chip(chip: &mut ProtoChip) {
+    use frontend_proto::model::chip::Chip as Chip_oneof_chip;
+    chip.id = 0;
+    let radios = match chip.chip.as_mut() {
+        Some(Chip_oneof_chip::Bt(bt)) => vec![bt.low_energy.as_mut(), bt.classic.as_mut()],
+        Some(Chip_oneof_chip::Uwb(radio)) | Some(Chip_oneof_chip::Wifi(radio)) => {
+            vec![Some(radio)]
+        }
+        Some(Chip_oneof_chip::Nfc(nfc)) => vec![nfc.radio.as_mut()],
+        _ => Vec::new(),
+    };
+    for radio in radios.into_iter().flatten() {
+        radio.tx_count = 0;
+        radio.rx_count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::common::ChipKind;
+    use frontend_proto::model::chip::{Bluetooth, Radio};
+    use frontend_proto::model::State;
+
+    fn saved_device() -> ProtoDevice {
+        let mut device = ProtoDevice::new();
+        device.id = 7;
+        device.name = "emulator-5554".to_string();
+        device.visible = true;
+        device.position.mut_or_insert_default().x = 1.0;
+        let mut bt_chip = ProtoChip::new();
+        bt_chip.id = 1000;
+        bt_chip.kind = ChipKind::BLUETOOTH.into();
+        bt_chip.capture = State::ON.into();
+        let mut low_energy = Radio::new();
+        low_energy.state = State::OFF.into();
+        low_energy.tx_count = 10;
+        let mut bt = Bluetooth::new();
+        bt.low_energy = Some(low_energy).into();
+        bt_chip.set_bt(bt);
+        let mut wifi_chip = ProtoChip::new();
+        wifi_chip.kind = ChipKind::WIFI.into();
+        wifi_chip.set_wifi(Radio::new());
+        device.chips = vec![bt_chip, wifi_chip];
+        device
+    }
+
+    #[test]
+    fn test_strip_device() {
+        let device = strip_device(&saved_device());
+        assert_eq!(device.id, 0);
+        assert_eq!(device.chips[0].id, 0);
+        assert_eq!(device.chips[0].capture.enum_value_or_default(), State::ON);
+        let low_energy = device.chips[0].bt().low_energy.as_ref().unwrap();
+        assert_eq!(low_energy.tx_count, 0);
+        assert_eq!(low_energy.state.enum_value_or_default(), State::OFF);
+    }
+
+    #[test]
+    fn test_chip_patch_first_chip() {
+        let patch = chip_patch(&saved_device(), ChipKind::BLUETOOTH as u32, "", true);
+        assert_eq!(patch.name, "emulator-5554");
+        assert!(patch.visible);
+        assert_eq!(patch.position.as_ref().map(|p| p.x), Some(1.0));
+        assert_eq!(patch.chips.len(), 1);
+        assert_eq!(patch.chips[0].kind.enum_value_or_default(), ChipKind::BLUETOOTH);
+    }
+
+    #[test]
+    fn test_chip_patch_later_chip() {
+        let patch = chip_patch(&saved_device(), ChipKind::WIFI as u32, "", false);
+        assert!(patch.position.is_none());
+        assert_eq!(patch.chips.len(), 1);
+        assert_eq!(patch.chips[0].kind.enum_value_or_default(), ChipKind::WIFI);
+    }
+
+    #[test]
+    fn test_chip_patch_unknown_chip() {
+        let patch = chip_patch(&saved_device(), ChipKind::UWB as u32, "", false);
+        assert!(patch.chips.is_empty());
+    }
+}
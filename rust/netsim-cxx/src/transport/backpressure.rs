@@ -0,0 +1,422 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-chip backpressure.
+//!
+//! The packets of an emulator wait in the queue of their chip until the
+//! facade handles them. When a facade falls behind, the queue grows without
+//! bound unless packet_hub pushes back on the transport of the chip. Once
+//! the queue of a chip holds `backpressure.high_watermark` packets, the
+//! policy of the chip applies until the queue drains to the low watermark:
+//!
+//! * `none` keeps queueing the packets, the default;
+//! * `block` holds the transport thread of the chip, so the emulator stops
+//!   sending until the facade catches up, for at most `max_wait_ms` per
+//!   packet; the held packet is charged to the packets memory pool, and
+//!   dropped when the pool is out of budget;
+//! * `drop` drops the packets of the chip.
+//!
+//! The `backpressure.chips` setting overrides the policy of chips as comma
+//! separated `<chip kind>=<policy>` or `<device name>/<chip kind>=<policy>`
+//! entries. The captures are written on the packet path, so a slow capture
+//! writer already holds the transport.
+
+use frontend_proto::common::ChipKind;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::captures::handlers::int_to_chip_kind;
+use crate::chip_registry::find_chip;
+use crate::clock::clock;
+use crate::config::{get_setting, get_setting_parsed};
+use crate::logger::warn;
+use crate::memory::{Charge, Pool};
+use crate::metrics::{register_collector, MetricKind, Sample};
+use crate::transport::stats::stats;
+
+/// Interval between two checks of a blocked queue.
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// What happens to the packets of a chip above the high watermark.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Policy {
+    None,
+    Block,
+    Drop,
+}
+
+impl Policy {
+    fn parse(value: &str) -> Option<Policy> {
+        match value.trim() {
+            "none" => Some(Policy::None),
+            "block" => Some(Policy::Block),
+            "drop" => Some(Policy::Drop),
+            _ => None,
+        }
+    }
+}
+
+/// The policy of the chips matching a `backpressure.chips` entry.
+#[derive(Clone, Debug, PartialEq)]
+struct Override {
+    device_name: Option<String>,
+    kind: ChipKind,
+    policy: Policy,
+}
+
+// Parses a `[<device name>/]<chip kind>=<policy>` entry.
+fn parse_override(entry: &str) -> Option<Override> {
+    let (chip, policy) = entry.split_once('=')?;
+    let (device_name, kind) = match chip.trim().rsplit_once('/') {
+        Some((device_name, kind)) => (Some(device_name.to_string()), kind),
+        None => (None, chip.trim()),
+    };
+    let kind = match kind.to_lowercase().as_str() {
+        "bluetooth" => ChipKind::BLUETOOTH,
+        "wifi" => ChipKind::WIFI,
+        "uwb" => ChipKind::UWB,
+        "nfc" => ChipKind::NFC,
+        _ => return None,
+    };
+    Some(Override { device_name, kind, policy: Policy::parse(policy)? })
+}
+
+/// The watermarks of the chip queues and the default policy.
+#[derive(Clone, Copy, Debug)]
+pub struct Limits {
+    /// Queued packets starting the backpressure, 0 disables it.
+    pub high_watermark: u64,
+    /// Queued packets ending the backpressure.
+    pub low_watermark: u64,
+    /// Longest time a packet holds the transport for the block policy.
+    pub max_wait: Duration,
+    pub policy: Policy,
+}
+
+/// The decision about a packet.
+#[derive(Debug, PartialEq)]
+pub enum Admission {
+    Forward,
+    Drop,
+    /// Hold the transport until the queue drains to the low watermark.
+    Block,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Chip {
+    // Resolved when the chip first reaches the high watermark.
+    policy: Option<Policy>,
+    // Whether the chip is between the high and the low watermark.
+    pressured: bool,
+    blocked: u64,
+    blocked_time: Duration,
+    timeouts: u64,
+    dropped: u64,
+    triggered: u64,
+}
+
+/// The backpressure state of all chips, by chip kind and facade id.
+pub struct Backpressure {
+    limits: Limits,
+    overrides: Vec<Override>,
+    chips: Mutex<HashMap<(u32, u32), Chip>>,
+}
+
+impl Backpressure {
+    pub fn new(limits: Limits, overrides: &str) -> Backpressure {
+        let overrides = overrides
+            .split(',')
+            .filter(|e| !e.trim().is_empty())
+            .filter_map(|entry| {
+                let parsed = parse_override(entry);
+                if parsed.is_none() {
+                    warn!("invalid backpressure entry {entry}");
+                }
+                parsed
+            })
+            .collect();
+        Backpressure { limits, overrides, chips: Mutex::new(HashMap::new()) }
+    }
+
+    fn from_settings() -> Backpressure {
+        let high_watermark = get_setting_parsed("backpressure.high_watermark");
+        let policy = get_setting("backpressure.policy");
+        Backpressure::new(
+            Limits {
+                high_watermark,
+                low_watermark: get_setting_parsed::<u64>("backpressure.low_watermark")
+                    .min(high_watermark.saturating_sub(1)),
+                max_wait: Duration::from_millis(get_setting_parsed("backpressure.max_wait_ms")),
+                policy: Policy::parse(&policy).unwrap_or_else(|| {
+                    warn!("unknown backpressure policy {policy}, using none");
+                    Policy::None
+                }),
+            },
+            &get_setting("backpressure.chips"),
+        )
+    }
+
+    // Returns the policy of a chip, the first matching entry overriding the
+    // default policy.
+    fn policy(&self, kind: u32, device_name: impl FnOnce() -> Option<String>) -> Policy {
+        let chip_kind = int_to_chip_kind(kind);
+        let mut device_name = Some(device_name);
+        let mut name = None;
+        for entry in self.overrides.iter().filter(|entry| entry.kind == chip_kind) {
+            let Some(entry_name) = &entry.device_name else {
+                return entry.policy;
+            };
+            if let Some(device_name) = device_name.take() {
+                name = device_name();
+            }
+            if name.as_ref() == Some(entry_name) {
+                return entry.policy;
+            }
+        }
+        self.limits.policy
+    }
+
+    /// Decides about a packet of a chip with `depth` queued packets.
+    /// Returns true as second value when the chip reaches the high
+    /// watermark. The device name of the chip is looked up when needed.
+    pub fn admit(
+        &self,
+        kind: u32,
+        facade_id: u32,
+        depth: u64,
+        device_name: impl FnOnce() -> Option<String>,
+    ) -> (Admission, bool) {
+        let limits = &self.limits;
+        if limits.high_watermark == 0 {
+            return (Admission::Forward, false);
+        }
+        let mut chips = self.chips.lock().unwrap();
+        let chip = chips.entry((kind, facade_id)).or_default();
+        if depth <= limits.low_watermark || (!chip.pressured && depth < limits.high_watermark) {
+            chip.pressured = false;
+            return (Admission::Forward, false);
+        }
+        let triggered = !chip.pressured;
+        chip.pressured = true;
+        chip.triggered += triggered as u64;
+        let policy = *chip.policy.get_or_insert_with(|| self.policy(kind, device_name));
+        let admission = match policy {
+            Policy::None => Admission::Forward,
+            Policy::Block => {
+                chip.blocked += 1;
+                Admission::Block
+            }
+            Policy::Drop => {
+                chip.dropped += 1;
+                Admission::Drop
+            }
+        };
+        (admission, triggered)
+    }
+
+    /// Waits until `depth` drains to the low watermark, at most for the
+    /// maximum wait. Returns false when the wait timed out.
+    pub fn wait(&self, kind: u32, facade_id: u32, depth: impl Fn() -> u64) -> bool {
+        let start = clock().monotonic();
+        let elapsed = || clock().monotonic().saturating_sub(start);
+        let mut drained = true;
+        while depth() > self.limits.low_watermark {
+            if elapsed() >= self.limits.max_wait {
+                drained = false;
+                break;
+            }
+            clock().sleep(POLL_INTERVAL);
+        }
+        let mut chips = self.chips.lock().unwrap();
+        let chip = chips.entry((kind, facade_id)).or_default();
+        chip.blocked_time += elapsed();
+        chip.timeouts += !drained as u64;
+        chip.pressured = !drained;
+        drained
+    }
+
+    fn counters(&self) -> Vec<((u32, u32), Chip)> {
+        let chips = self.chips.lock().unwrap();
+        chips.iter().map(|(key, chip)| (*key, chip.clone())).collect()
+    }
+}
+
+lazy_static! {
+    static ref BACKPRESSURE: Backpressure = {
+        register_collector(collect_metrics);
+        Backpressure::from_settings()
+    };
+}
+
+fn collect_metrics() -> Vec<Sample> {
+    let mut samples = Vec::new();
+    for ((kind, facade_id), chip) in BACKPRESSURE.counters() {
+        let labels = vec![
+            ("kind", format!("{:?}", int_to_chip_kind(kind))),
+            ("facade_id", facade_id.to_string()),
+        ];
+        let mut counter = |name, help, value: f64| {
+            samples.push(Sample {
+                name,
+                help,
+                kind: MetricKind::Counter,
+                labels: labels.clone(),
+                value,
+            })
+        };
+        counter(
+            "netsim_backpressure_triggered_total",
+            "Times the queue of the chip reached the high watermark.",
+            chip.triggered as f64,
+        );
+        counter(
+            "netsim_backpressure_blocked_packets_total",
+            "Packets holding the transport of the chip until its queue drained.",
+            chip.blocked as f64,
+        );
+        counter(
+            "netsim_backpressure_blocked_seconds_total",
+            "Time the transport of the chip was held by its queue.",
+            chip.blocked_time.as_secs_f64(),
+        );
+        counter(
+            "netsim_backpressure_timeouts_total",
+            "Packets forwarded after waiting max_wait_ms for the queue of the chip.",
+            chip.timeouts as f64,
+        );
+        counter(
+            "netsim_backpressure_dropped_packets_total",
+            "Packets dropped above the high watermark of the chip.",
+            chip.dropped as f64,
+        );
+    }
+    samples
+}
+
+// Returns the name of the device of a chip.
+fn device_name(kind: u32, facade_id: u32) -> Option<String> {
+    find_chip(kind, facade_id).map(|(device, _)| device.name)
+}
+
+// Cxx Method for packet_hub to invoke
+
+/// Applies the backpressure of a chip to a packet of `size` bytes from the
+/// emulator, holding the calling transport for the block policy. Returns
+/// false to drop the packet.
+pub fn apply_backpressure(kind: u32, facade_id: u32, size: u32) -> bool {
+    let depth = || stats().get(kind, facade_id).queue_depth;
+    let (admission, triggered) =
+        BACKPRESSURE.admit(kind, facade_id, depth(), || device_name(kind, facade_id));
+    if triggered {
+        warn!(
+            "{:?} chip {facade_id} reached the backpressure.high_watermark",
+            int_to_chip_kind(kind)
+        );
+    }
+    match admission {
+        Admission::Forward => true,
+        Admission::Drop => false,
+        Admission::Block => {
+            let Some(_charge) = Charge::new(Pool::Packets, size as usize) else {
+                return false;
+            };
+            if !BACKPRESSURE.wait(kind, facade_id, depth) {
+                warn!(
+                    "{:?} chip {facade_id} queue did not drain in \
+                     backpressure.max_wait_ms",
+                    int_to_chip_kind(kind)
+                );
+            }
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn backpressure(policy: Policy, overrides: &str) -> Backpressure {
+        Backpressure::new(
+            Limits {
+                high_watermark: 10,
+                low_watermark: 5,
+                max_wait: Duration::from_millis(20),
+                policy,
+            },
+            overrides,
+        )
+    }
+
+    fn no_name() -> Option<String> {
+        None
+    }
+
+    #[test]
+    fn test_watermarks() {
+        let backpressure = backpressure(Policy::Drop, "");
+        assert_eq!(backpressure.admit(1, 1, 9, no_name), (Admission::Forward, false));
+        assert_eq!(backpressure.admit(1, 1, 10, no_name), (Admission::Drop, true));
+        // The chip is dropping until its queue drains to the low watermark.
+        assert_eq!(backpressure.admit(1, 1, 6, no_name), (Admission::Drop, false));
+        assert_eq!(backpressure.admit(1, 2, 6, no_name), (Admission::Forward, false));
+        assert_eq!(backpressure.admit(1, 1, 5, no_name), (Admission::Forward, false));
+        assert_eq!(backpressure.admit(1, 1, 6, no_name), (Admission::Forward, false));
+        let disabled = Backpressure::new(Limits { high_watermark: 0, ..backpressure.limits }, "");
+        assert_eq!(disabled.admit(1, 1, 1000, no_name), (Admission::Forward, false));
+    }
+
+    #[test]
+    fn test_overrides() {
+        let backpressure = backpressure(Policy::None, "uwb=drop, phone/bluetooth=block");
+        assert_eq!(backpressure.admit(3, 1, 10, no_name), (Admission::Drop, true));
+        assert_eq!(backpressure.admit(2, 1, 10, no_name), (Admission::Forward, true));
+        let lookups = Cell::new(0);
+        let name = |name: &str| {
+            let name = name.to_string();
+            let lookups = &lookups;
+            move || {
+                lookups.set(lookups.get() + 1);
+                Some(name)
+            }
+        };
+        assert_eq!(backpressure.admit(1, 1, 10, name("phone")), (Admission::Block, true));
+        assert_eq!(backpressure.admit(1, 2, 10, name("watch")), (Admission::Forward, true));
+        // The policy of a chip is resolved once.
+        assert_eq!(backpressure.admit(1, 1, 10, name("watch")), (Admission::Block, false));
+        assert_eq!(lookups.get(), 2);
+        assert_eq!(parse_override("phone/radio=block"), None);
+        assert_eq!(parse_override("wifi=queue"), None);
+    }
+
+    #[test]
+    fn test_wait() {
+        let backpressure = backpressure(Policy::Block, "");
+        let depth = Cell::new(12);
+        let drain = || {
+            depth.set(depth.get() - 1);
+            depth.get()
+        };
+        assert!(backpressure.wait(1, 1, drain));
+        assert_eq!(depth.get(), 5);
+        // A stuck queue holds the transport for max_wait at most.
+        assert!(!backpressure.wait(1, 1, || 8));
+        let counters = backpressure.counters();
+        assert_eq!(counters[0].1.timeouts, 1);
+        assert!(counters[0].1.blocked_time >= Duration::from_millis(20));
+    }
+}
@@ -16,8 +16,11 @@
 /// response packets flow out of netsim
 /// packet transports read requests and write response packets over gRPC or Fds.
 use super::h4;
+use super::nci;
+use super::stats;
 use super::uci;
 use crate::ffi::{add_chip_cxx, handle_request_cxx};
+use crate::logger::{debug, error, info};
 use cxx::let_cxx_string;
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
@@ -46,6 +49,7 @@ enum ChipKindEnum {
     BLUETOOTH = 1,
     WIFI = 2,
     UWB = 3,
+    NFC = 4,
 }
 
 impl fmt::Display for ChipKindEnum {
@@ -85,11 +89,11 @@ pub fn handle_response(kind: u32, facade_id: u32, packet: &cxx::CxxVector<u8>, p
             let temp = [packet_type];
             let bufs = [IoSlice::new(&temp), IoSlice::new(packet.as_slice())];
             if let Err(e) = fd_out.write_vectored(&bufs) {
-                println!("netsimd: error writing {}", e);
+                info!("error writing {}", e);
             };
         }
         None => {
-            println!("netsimd: Error unknown key {}/{}", kind, facade_id);
+            info!("Error unknown key {}/{}", kind, facade_id);
         }
     };
 }
@@ -102,39 +106,42 @@ fn fd_reader(fd_rx: i32, kind: ChipKindEnum, facade_id: u32) -> JoinHandle<()> {
         .spawn(move || {
             let mut rx = unsafe { File::from_raw_fd(fd_rx) };
 
-            println!(
-                "netsimd: thread handling kind:{:?} facade_id:{:?} fd:{}",
-                kind, facade_id, fd_rx
-            );
+            info!("thread handling kind:{:?} facade_id:{:?} fd:{}", kind, facade_id, fd_rx);
 
             loop {
                 match kind {
                     ChipKindEnum::UWB => match uci::read_uci_packet(&mut rx) {
                         Err(e) => {
-                            println!(
-                                "netsimd: error reading uci control packet fd {} {:?}",
-                                fd_rx, e
-                            );
+                            info!("error reading uci control packet fd {} {:?}", fd_rx, e);
                             return;
                         }
                         Ok(uci::Packet { payload }) => {
                             handle_request_cxx(kind as u32, facade_id, &payload, 0);
                         }
                     },
+                    ChipKindEnum::NFC => match nci::read_nci_packet(&mut rx) {
+                        Err(e) => {
+                            info!("error reading nci packet fd {} {:?}", fd_rx, e);
+                            return;
+                        }
+                        Ok(nci::Packet { payload }) => {
+                            handle_request_cxx(kind as u32, facade_id, &payload, 0);
+                        }
+                    },
                     ChipKindEnum::BLUETOOTH => match h4::read_h4_packet(&mut rx) {
                         Ok(h4::Packet { h4_type, payload }) => {
                             handle_request_cxx(kind as u32, facade_id, &payload, h4_type);
                         }
                         Err(e) => {
-                            println!(
-                                "netsimd: error reading hci control packet fd {} {:?}",
-                                fd_rx, e
-                            );
+                            info!("error reading hci control packet fd {} {:?}", fd_rx, e);
+                            if !matches!(e, h4::PacketError::IoError(_)) {
+                                stats::record_malformed(kind as u32, facade_id);
+                            }
                             return;
                         }
                     },
                     _ => {
-                        println!("netsimd: unknown control packet kind: {:?}", kind);
+                        info!("unknown control packet kind: {:?}", kind);
                         return;
                     }
                 };
@@ -148,10 +155,10 @@ fn fd_reader(fd_rx: i32, kind: ChipKindEnum, facade_id: u32) -> JoinHandle<()> {
 /// Create threads to read and write to file descriptors
 //
 pub fn run_fd_transport(startup_json: &String) {
-    println!("netsimd: fd_transport starting with {startup_json}");
+    info!("fd_transport starting with {startup_json}");
     let startup_info = match serde_json::from_str::<StartupInfo>(startup_json.as_str()) {
         Err(e) => {
-            println!("Error parsing startup info: {:?}", e);
+            error!("Error parsing startup info: {:?}", e);
             return;
         }
         Ok(startup_info) => startup_info,
@@ -196,7 +203,7 @@ pub fn run_fd_transport(startup_json: &String) {
                 // TODO: use runtime.block_on once FIFOs are available in Tokio
                 handle.join().unwrap();
             }
-            println!("netsimd:: done with all fd handlers");
+            info!("done with all fd handlers");
         })
         .unwrap();
 }
@@ -221,7 +228,7 @@ mod tests {
     }"#;
         let startup_info = serde_json::from_str::<StartupInfo>(s).unwrap();
         for device in startup_info.devices {
-            println!("device {:?}", device);
+            debug!("device {:?}", device);
         }
     }
 }
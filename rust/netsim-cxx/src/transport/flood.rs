@@ -0,0 +1,323 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-chip flood protection.
+//!
+//! packet_hub admits every packet from an emulator through a token bucket
+//! of its chip, so a guest stack spamming packets can't starve the packet
+//! routing of the other devices. A chip exceeding the `flood.rate` setting
+//! is handled by the `flood.policy` setting, and records a RATE_LIMITED
+//! event each time it starts exceeding the limit.
+
+use frontend_proto::frontend::event::Type;
+use frontend_proto::frontend::Event;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::captures::handlers::int_to_chip_kind;
+use crate::chip_registry::find_chip;
+use crate::clock::{clock, now, to_timestamp};
+use crate::config::{get_setting, get_setting_parsed};
+use crate::events::history;
+use crate::logger::warn;
+use crate::memory::{Charge, Pool};
+use crate::metrics::{register_collector, MetricKind, Sample};
+use crate::transport::stats::{record_dequeued, record_queued};
+
+/// What happens to the packets of a chip above the rate limit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Policy {
+    /// Delay the packets until the rate allows them.
+    Queue,
+    /// Drop the excess packets.
+    Drop,
+    /// Drop all packets of the chip for the throttle period.
+    Throttle,
+}
+
+impl Policy {
+    fn from_setting(value: &str) -> Policy {
+        match value {
+            "queue" => Policy::Queue,
+            "drop" => Policy::Drop,
+            "throttle" => Policy::Throttle,
+            _ => {
+                warn!("unknown flood policy {value}, using drop");
+                Policy::Drop
+            }
+        }
+    }
+}
+
+/// The rate limit of each chip.
+#[derive(Clone, Copy, Debug)]
+pub struct Limits {
+    /// Packets per second, 0 is unlimited.
+    pub rate: f64,
+    /// Packets allowed above the rate after a quiet period.
+    pub burst: f64,
+    pub policy: Policy,
+    pub throttle: Duration,
+}
+
+/// The decision about a packet.
+#[derive(Debug, PartialEq)]
+pub enum Admission {
+    Forward,
+    Drop,
+    /// Forward the packet after a delay.
+    Delay(Duration),
+}
+
+#[derive(Clone, Debug, Default)]
+struct Bucket {
+    tokens: f64,
+    updated: Duration,
+    throttled_until: Duration,
+    // Whether the chip exceeded the limit with its last packet.
+    limited: bool,
+    dropped: u64,
+    delayed: u64,
+    triggered: u64,
+}
+
+/// The token buckets of all chips, by chip kind and facade id.
+pub struct FloodGuard {
+    limits: Limits,
+    buckets: Mutex<HashMap<(u32, u32), Bucket>>,
+}
+
+impl FloodGuard {
+    pub fn new(limits: Limits) -> FloodGuard {
+        FloodGuard { limits, buckets: Mutex::new(HashMap::new()) }
+    }
+
+    fn from_settings() -> FloodGuard {
+        FloodGuard::new(Limits {
+            rate: get_setting_parsed("flood.rate"),
+            burst: get_setting_parsed::<f64>("flood.burst").max(1.0),
+            policy: Policy::from_setting(&get_setting("flood.policy")),
+            throttle: Duration::from_millis(get_setting_parsed("flood.throttle_ms")),
+        })
+    }
+
+    /// Decides about a packet of a chip at `time`. Returns true as second
+    /// value when the chip starts exceeding the limit.
+    pub fn admit(&self, kind: u32, facade_id: u32, time: Duration) -> (Admission, bool) {
+        let limits = &self.limits;
+        if limits.rate <= 0.0 {
+            return (Admission::Forward, false);
+        }
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry((kind, facade_id)).or_insert_with(|| Bucket {
+            tokens: limits.burst,
+            updated: time,
+            ..Default::default()
+        });
+        let elapsed = time.saturating_sub(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * limits.rate).min(limits.burst);
+        bucket.updated = bucket.updated.max(time);
+        if time < bucket.throttled_until {
+            bucket.dropped += 1;
+            return (Admission::Drop, false);
+        }
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            bucket.limited = false;
+            return (Admission::Forward, false);
+        }
+        let triggered = !bucket.limited;
+        bucket.limited = true;
+        bucket.triggered += triggered as u64;
+        let admission = match limits.policy {
+            // The queue holds at most a burst of packets.
+            Policy::Queue if bucket.tokens - 1.0 >= -limits.burst => {
+                bucket.tokens -= 1.0;
+                bucket.delayed += 1;
+                Admission::Delay(Duration::from_secs_f64(-bucket.tokens / limits.rate))
+            }
+            Policy::Throttle => {
+                bucket.throttled_until = time + limits.throttle;
+                bucket.dropped += 1;
+                Admission::Drop
+            }
+            _ => {
+                bucket.dropped += 1;
+                Admission::Drop
+            }
+        };
+        (admission, triggered)
+    }
+
+    fn counters(&self) -> Vec<((u32, u32), Bucket)> {
+        let buckets = self.buckets.lock().unwrap();
+        buckets.iter().map(|(key, bucket)| (*key, bucket.clone())).collect()
+    }
+}
+
+lazy_static! {
+    static ref GUARD: FloodGuard = {
+        register_collector(collect_metrics);
+        FloodGuard::from_settings()
+    };
+}
+
+fn collect_metrics() -> Vec<Sample> {
+    let mut samples = Vec::new();
+    for ((kind, facade_id), bucket) in GUARD.counters() {
+        let labels = vec![
+            ("kind", format!("{:?}", int_to_chip_kind(kind))),
+            ("facade_id", facade_id.to_string()),
+        ];
+        let mut counter = |name, help, value: u64| {
+            samples.push(Sample {
+                name,
+                help,
+                kind: MetricKind::Counter,
+                labels: labels.clone(),
+                value: value as f64,
+            })
+        };
+        counter(
+            "netsim_flood_dropped_packets_total",
+            "Packets dropped by the rate limit of the chip.",
+            bucket.dropped,
+        );
+        counter(
+            "netsim_flood_delayed_packets_total",
+            "Packets delayed by the rate limit of the chip.",
+            bucket.delayed,
+        );
+        counter(
+            "netsim_flood_triggered_total",
+            "Times the chip started exceeding its rate limit.",
+            bucket.triggered,
+        );
+    }
+    samples
+}
+
+// Records a RATE_LIMITED event with the device of the chip.
+fn record_rate_limited(kind: u32, facade_id: u32) {
+    let mut event = Event {
+        type_: Type::RATE_LIMITED.into(),
+        timestamp: Some(to_timestamp(now())).into(),
+        ..Default::default()
+    };
+    if let Some((device, chip_id)) = find_chip(kind, facade_id) {
+        event.device_name = device.name.clone();
+        event.chip_id = chip_id;
+        event.devices.push(device);
+    }
+    warn!(
+        "{:?} chip of {} exceeded the flood.rate limit",
+        int_to_chip_kind(kind),
+        event.device_name
+    );
+    history().record(event);
+}
+
+// Cxx Method for packet_hub to invoke
+
+/// Admits a packet of `size` bytes from the emulator, delaying the calling
+/// transport for the queue policy. Returns false to drop the packet.
+pub fn admit_request(kind: u32, facade_id: u32, size: u32) -> bool {
+    // The netsimd clock, so that a manual debug.clock drives the buckets.
+    let (admission, triggered) = GUARD.admit(kind, facade_id, clock().monotonic());
+    if triggered {
+        record_rate_limited(kind, facade_id);
+    }
+    match admission {
+        Admission::Forward => true,
+        Admission::Drop => false,
+        Admission::Delay(delay) => {
+            // The delayed packet is charged like the queued ones.
+            let Some(_charge) = Charge::new(Pool::Packets, size as usize) else {
+                return false;
+            };
+            record_queued(kind, facade_id);
+            clock().sleep(delay);
+            record_dequeued(kind, facade_id);
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard(policy: Policy) -> FloodGuard {
+        FloodGuard::new(Limits { rate: 10.0, burst: 2.0, policy, throttle: Duration::from_secs(1) })
+    }
+
+    fn admit_all(guard: &FloodGuard, time: Duration, count: usize) -> Vec<(Admission, bool)> {
+        (0..count).map(|_| guard.admit(1, 1, time)).collect()
+    }
+
+    #[test]
+    fn test_drop() {
+        let guard = guard(Policy::Drop);
+        let start = Duration::from_secs(100);
+        assert_eq!(
+            admit_all(&guard, start, 4),
+            [
+                (Admission::Forward, false),
+                (Admission::Forward, false),
+                (Admission::Drop, true),
+                (Admission::Drop, false)
+            ]
+        );
+        // Other chips have their own bucket.
+        assert_eq!(guard.admit(1, 2, start), (Admission::Forward, false));
+        // 100ms refill a token at 10 packets per second.
+        let later = start + Duration::from_millis(100);
+        assert_eq!(
+            admit_all(&guard, later, 2),
+            [(Admission::Forward, false), (Admission::Drop, true)]
+        );
+    }
+
+    #[test]
+    fn test_queue() {
+        let guard = guard(Policy::Queue);
+        let start = Duration::from_secs(100);
+        let admissions = admit_all(&guard, start, 5);
+        assert_eq!(admissions[2], (Admission::Delay(Duration::from_millis(100)), true));
+        assert_eq!(admissions[3], (Admission::Delay(Duration::from_millis(200)), false));
+        // The queue is full after a burst of delayed packets.
+        assert_eq!(admissions[4], (Admission::Drop, false));
+    }
+
+    #[test]
+    fn test_throttle() {
+        let guard = guard(Policy::Throttle);
+        let start = Duration::from_secs(100);
+        assert_eq!(admit_all(&guard, start, 3)[2], (Admission::Drop, true));
+        // The bucket refilled but the chip is throttled for a second.
+        let later = start + Duration::from_millis(500);
+        assert_eq!(guard.admit(1, 1, later), (Admission::Drop, false));
+        let after = start + Duration::from_secs(1);
+        assert_eq!(guard.admit(1, 1, after), (Admission::Forward, false));
+    }
+
+    #[test]
+    fn test_unlimited() {
+        let guard = FloodGuard::new(Limits { rate: 0.0, ..guard(Policy::Drop).limits });
+        let admissions = admit_all(&guard, Duration::from_secs(1), 1000);
+        assert!(admissions.iter().all(|admission| *admission == (Admission::Forward, false)));
+    }
+}
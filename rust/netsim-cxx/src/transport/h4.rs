@@ -14,10 +14,12 @@
 
 use std::io::{Error, Read};
 
+use crate::buffer_pool::{buffer_pool, PooledBuffer};
+
 #[derive(Debug)]
 pub struct Packet {
     pub h4_type: u8,
-    pub payload: Vec<u8>,
+    pub payload: PooledBuffer,
 }
 
 #[derive(Debug)]
@@ -57,7 +59,8 @@ pub fn read_h4_packet<R: Read>(reader: &mut R) -> Result<Packet, PacketError> {
     };
 
     // Read the preamble and obtain the payload length
-    let mut packet = vec![0u8; preamble_size];
+    let mut packet = buffer_pool().take(preamble_size);
+    packet.resize(preamble_size, 0);
     reader.read_exact(&mut packet).map_err(PacketError::IoError)?;
     let payload_length: usize = match h4_type {
         H4_CMD_TYPE => {
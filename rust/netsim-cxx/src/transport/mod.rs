@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod backpressure;
 #[cfg(feature = "cuttlefish")]
 pub mod fd;
-#[cfg(feature = "cuttlefish")]
-mod h4;
-#[cfg(feature = "cuttlefish")]
-mod uci;
+pub mod flood;
+pub mod h4;
+pub mod nci;
+pub mod stats;
+pub mod uci;
 
 // This provides no-op implementations of fd transport for non-unix systems.
 #[cfg(not(feature = "cuttlefish"))]
@@ -0,0 +1,49 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{Error, Read};
+
+use crate::buffer_pool::{buffer_pool, PooledBuffer};
+
+// This module implements packet parsing for NFC.
+//
+// NFC Controller Interface (NCI) Technical Specification
+// Version 2.0
+//
+// 4.2 Format of Control Packets, 4.3 Format of Data Packets
+
+const NCI_HEADER_SIZE: usize = 3;
+const NCI_PAYLOAD_LENGTH_FIELD: usize = 2;
+
+#[derive(Debug)]
+pub struct Packet {
+    pub payload: PooledBuffer,
+}
+
+#[derive(Debug)]
+pub enum PacketError {
+    IoError(Error),
+}
+
+pub fn read_nci_packet<R: Read>(reader: &mut R) -> Result<Packet, PacketError> {
+    // Read the NCI header
+    let mut buffer = buffer_pool().take(NCI_HEADER_SIZE);
+    buffer.resize(NCI_HEADER_SIZE, 0);
+    reader.read_exact(&mut buffer[0..]).map_err(PacketError::IoError)?;
+    // Extract the control or data packet payload length and read
+    let length = buffer[NCI_PAYLOAD_LENGTH_FIELD] as usize + NCI_HEADER_SIZE;
+    buffer.resize(length, 0);
+    reader.read_exact(&mut buffer[NCI_HEADER_SIZE..]).map_err(PacketError::IoError)?;
+    Ok(Packet { payload: buffer })
+}
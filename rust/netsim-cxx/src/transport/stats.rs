@@ -0,0 +1,337 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-chip transport statistics.
+//!
+//! The transports and packet_hub count the packets of each chip where they
+//! cross the boundary between the emulator and netsimd. Comparing the
+//! counters tells whether a hang is in the guest, the transport or the
+//! netsim routing.
+//!
+//! /v1/stats/transport --> handle_transport_stats (GET)
+
+use frontend_proto::frontend::{
+    ChipTransportStats, GetChipTransportStatsResponse, GetDevicesResponse,
+};
+use frontend_proto::model::Device;
+use lazy_static::lazy_static;
+use protobuf::Message;
+use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::captures::capture::{ChipId, FacadeId};
+use crate::captures::handlers::int_to_chip_kind;
+use crate::chip_registry::facade_id;
+use crate::clock::{now, to_timestamp};
+use crate::ffi::{get_devices_bytes, CxxServerResponseWriter};
+use crate::http_server::http_request::{HttpHeaders, HttpRequest};
+use crate::http_server::server_response::ResponseWritable;
+use crate::metrics::{register_collector, MetricKind, Sample};
+use crate::CxxServerResponseWriterWrapper;
+
+const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
+    enum_values_int: false,
+    proto_field_name: false,
+    always_output_default_values: true,
+    _future_options: (),
+};
+
+/// The counters of a chip.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChipCounters {
+    /// Packets received from the emulator.
+    pub packets_in: u64,
+    pub bytes_in: u64,
+    /// Packets delivered to the emulator.
+    pub packets_out: u64,
+    pub bytes_out: u64,
+    /// Packets from the emulator rejected as malformed.
+    pub malformed: u64,
+    /// Packets received but not yet handled by the chip.
+    pub queue_depth: u64,
+    /// Packets handled by the chip after queuing.
+    pub dequeued: u64,
+    /// Wall clock time of the last packet in either direction.
+    pub last_activity: Option<Duration>,
+}
+
+/// The counters of all chips, by chip kind and facade id.
+#[derive(Default)]
+pub struct TransportStats {
+    chips: Mutex<HashMap<(u32, u32), ChipCounters>>,
+}
+
+impl TransportStats {
+    fn update(&self, kind: u32, facade_id: u32, update: impl FnOnce(&mut ChipCounters)) {
+        let mut chips = self.chips.lock().unwrap();
+        update(chips.entry((kind, facade_id)).or_default());
+    }
+
+    pub fn record_in(&self, kind: u32, facade_id: u32, bytes: usize, time: Duration) {
+        self.update(kind, facade_id, |counters| {
+            counters.packets_in += 1;
+            counters.bytes_in += bytes as u64;
+            counters.last_activity = Some(time);
+        });
+    }
+
+    pub fn record_out(&self, kind: u32, facade_id: u32, bytes: usize, time: Duration) {
+        self.update(kind, facade_id, |counters| {
+            counters.packets_out += 1;
+            counters.bytes_out += bytes as u64;
+            counters.last_activity = Some(time);
+        });
+    }
+
+    pub fn record_malformed(&self, kind: u32, facade_id: u32, time: Duration) {
+        self.update(kind, facade_id, |counters| {
+            counters.malformed += 1;
+            counters.last_activity = Some(time);
+        });
+    }
+
+    pub fn record_queued(&self, kind: u32, facade_id: u32) {
+        self.update(kind, facade_id, |counters| counters.queue_depth += 1);
+    }
+
+    pub fn record_dequeued(&self, kind: u32, facade_id: u32) {
+        self.update(kind, facade_id, |counters| {
+            counters.queue_depth = counters.queue_depth.saturating_sub(1);
+            counters.dequeued += 1;
+        });
+    }
+
+    /// Returns the counters of a chip, zero for a chip without traffic.
+    pub fn get(&self, kind: u32, facade_id: u32) -> ChipCounters {
+        self.chips.lock().unwrap().get(&(kind, facade_id)).cloned().unwrap_or_default()
+    }
+
+    /// Returns the counters of all chips with traffic.
+    pub fn snapshot(&self) -> Vec<((u32, u32), ChipCounters)> {
+        let chips = self.chips.lock().unwrap();
+        chips.iter().map(|(key, counters)| (*key, counters.clone())).collect()
+    }
+}
+
+lazy_static! {
+    static ref STATS: TransportStats = {
+        register_collector(collect_metrics);
+        TransportStats::default()
+    };
+}
+
+/// Returns the netsimd transport statistics.
+pub fn stats() -> &'static TransportStats {
+    &STATS
+}
+
+fn collect_metrics() -> Vec<Sample> {
+    let mut samples = Vec::new();
+    for ((kind, facade_id), counters) in stats().snapshot() {
+        let labels = vec![
+            ("kind", format!("{:?}", int_to_chip_kind(kind))),
+            ("facade_id", facade_id.to_string()),
+        ];
+        let mut counter = |name, help, value: u64| {
+            samples.push(Sample {
+                name,
+                help,
+                kind: MetricKind::Counter,
+                labels: labels.clone(),
+                value: value as f64,
+            })
+        };
+        counter(
+            "netsim_transport_packets_in_total",
+            "Packets received from the emulator.",
+            counters.packets_in,
+        );
+        counter(
+            "netsim_transport_bytes_in_total",
+            "Bytes received from the emulator.",
+            counters.bytes_in,
+        );
+        counter(
+            "netsim_transport_packets_out_total",
+            "Packets delivered to the emulator.",
+            counters.packets_out,
+        );
+        counter(
+            "netsim_transport_bytes_out_total",
+            "Bytes delivered to the emulator.",
+            counters.bytes_out,
+        );
+        counter(
+            "netsim_transport_malformed_packets_total",
+            "Packets from the emulator rejected as malformed.",
+            counters.malformed,
+        );
+        samples.push(Sample {
+            name: "netsim_transport_queue_depth",
+            help: "Packets received but not yet handled by the chip.",
+            kind: MetricKind::Gauge,
+            labels,
+            value: counters.queue_depth as f64,
+        });
+    }
+    samples
+}
+
+/// Returns the statistics of the chips of `devices`.
+pub fn chip_transport_stats(
+    stats: &TransportStats,
+    devices: &[Device],
+    facade_id: impl Fn(ChipId) -> Option<FacadeId>,
+) -> GetChipTransportStatsResponse {
+    let mut response = GetChipTransportStatsResponse::new();
+    for device in devices.iter() {
+        for chip in device.chips.iter() {
+            let counters = match facade_id(chip.id) {
+                Some(id) => stats.get(chip.kind.value() as u32, id as u32),
+                None => ChipCounters::default(),
+            };
+            response.chips.push(ChipTransportStats {
+                device_name: device.name.clone(),
+                chip_id: chip.id,
+                chip_kind: chip.kind,
+                packets_in: counters.packets_in,
+                bytes_in: counters.bytes_in,
+                packets_out: counters.packets_out,
+                bytes_out: counters.bytes_out,
+                malformed_packets: counters.malformed,
+                queue_depth: counters.queue_depth,
+                last_activity: counters.last_activity.map(to_timestamp).into(),
+                ..Default::default()
+            });
+        }
+    }
+    response
+}
+
+/// The Rust transport stats handler used directly by Http frontend for GET
+pub fn handle_transport_stats(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    if request.method.as_str() != "GET" {
+        writer.put_error(404, "Not found.");
+        return;
+    }
+    let mut vec = Vec::<u8>::new();
+    if !get_devices_bytes(&mut vec) {
+        writer.put_error(404, "Failed to get devices");
+        return;
+    }
+    let devices = GetDevicesResponse::parse_from_bytes(&vec).map(|r| r.devices).unwrap_or_default();
+    let response = chip_transport_stats(stats(), &devices, facade_id);
+    if let Ok(json_response) = print_to_string_with_options(&response, &JSON_PRINT_OPTION) {
+        writer.put_ok("text/json", &json_response, &[])
+    } else {
+        writer.put_error(404, "proto to JSON mapping failure")
+    }
+}
+
+/// transport stats handle cxx for grpc server to call
+pub fn handle_transport_stats_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    method: String,
+    param: String,
+) {
+    let request = HttpRequest {
+        method,
+        uri: "/v1/stats/transport".to_string(),
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: Vec::new(),
+    };
+    handle_transport_stats(
+        &request,
+        param.as_str(),
+        &mut CxxServerResponseWriterWrapper { writer: responder },
+    );
+}
+
+// Cxx Methods for the transports and packet_hub to invoke
+
+pub fn record_request(kind: u32, facade_id: u32, size: usize) {
+    stats().record_in(kind, facade_id, size, now());
+}
+
+pub fn record_response(kind: u32, facade_id: u32, size: usize) {
+    stats().record_out(kind, facade_id, size, now());
+}
+
+pub fn record_malformed(kind: u32, facade_id: u32) {
+    stats().record_malformed(kind, facade_id, now());
+}
+
+pub fn record_queued(kind: u32, facade_id: u32) {
+    stats().record_queued(kind, facade_id);
+}
+
+pub fn record_dequeued(kind: u32, facade_id: u32) {
+    stats().record_dequeued(kind, facade_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::common::ChipKind;
+    use frontend_proto::model::Chip;
+
+    const TIME: Duration = Duration::from_secs(1_000);
+
+    #[test]
+    fn test_counters() {
+        let stats = TransportStats::default();
+        stats.record_in(1, 7, 10, TIME);
+        stats.record_in(1, 7, 5, TIME);
+        stats.record_out(1, 7, 3, TIME + Duration::from_secs(1));
+        stats.record_malformed(1, 7, TIME);
+        stats.record_queued(1, 7);
+        stats.record_queued(1, 7);
+        stats.record_dequeued(1, 7);
+        let counters = stats.get(1, 7);
+        assert_eq!((counters.packets_in, counters.bytes_in), (2, 15));
+        assert_eq!((counters.packets_out, counters.bytes_out), (1, 3));
+        assert_eq!(counters.malformed, 1);
+        assert_eq!((counters.queue_depth, counters.dequeued), (1, 1));
+        assert_eq!(counters.last_activity, Some(TIME));
+        assert_eq!(stats.get(2, 7), ChipCounters::default());
+        stats.record_dequeued(2, 7);
+        assert_eq!(stats.get(2, 7).queue_depth, 0);
+    }
+
+    #[test]
+    fn test_chip_transport_stats() {
+        let stats = TransportStats::default();
+        stats.record_in(ChipKind::BLUETOOTH as u32, 11, 4, TIME);
+        let mut device = Device { name: "phone".to_string(), ..Default::default() };
+        device.chips.push(Chip { id: 1, kind: ChipKind::BLUETOOTH.into(), ..Default::default() });
+        device.chips.push(Chip { id: 2, kind: ChipKind::WIFI.into(), ..Default::default() });
+        device.chips.push(Chip { id: 3, kind: ChipKind::BLUETOOTH.into(), ..Default::default() });
+        let facade_id = |chip_id| match chip_id {
+            3 => None,
+            id => Some(id + 10),
+        };
+        let response = chip_transport_stats(&stats, &[device], facade_id);
+        assert_eq!(response.chips.len(), 3);
+        assert_eq!(response.chips[0].device_name, "phone");
+        assert_eq!(response.chips[0].packets_in, 1);
+        assert_eq!(response.chips[0].last_activity.seconds, 1_000);
+        assert_eq!(response.chips[1].packets_in, 0);
+        assert!(response.chips[1].last_activity.is_none());
+        assert_eq!(response.chips[2].packets_in, 0);
+    }
+}
@@ -14,19 +14,21 @@
 
 use std::io::{Error, Read};
 
-/// This module implements control packet parsing for UWB.
-///
-/// UWB Command Interface Specification, UCI Generic Specification
-/// Version 1.1
-///
-/// 2.3.2 Format of Control Packets
+use crate::buffer_pool::{buffer_pool, PooledBuffer};
+
+// This module implements control packet parsing for UWB.
+//
+// UWB Command Interface Specification, UCI Generic Specification
+// Version 1.1
+//
+// 2.3.2 Format of Control Packets
 
 const UCI_HEADER_SIZE: usize = 4;
 const UCI_PAYLOAD_LENGTH_FIELD: usize = 3;
 
 #[derive(Debug)]
 pub struct Packet {
-    pub payload: Vec<u8>,
+    pub payload: PooledBuffer,
 }
 
 #[derive(Debug)]
@@ -36,7 +38,8 @@ pub enum PacketError {
 
 pub fn read_uci_packet<R: Read>(reader: &mut R) -> Result<Packet, PacketError> {
     // Read the UCI header
-    let mut buffer = vec![0; UCI_HEADER_SIZE];
+    let mut buffer = buffer_pool().take(UCI_HEADER_SIZE);
+    buffer.resize(UCI_HEADER_SIZE, 0);
     reader.read_exact(&mut buffer[0..]).map_err(PacketError::IoError)?;
     // Extract the control packet payload length and read
     let length = buffer[UCI_PAYLOAD_LENGTH_FIELD] as usize + UCI_HEADER_SIZE;
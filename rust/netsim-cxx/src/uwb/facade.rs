@@ -12,36 +12,747 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-pub fn handle_uwb_request(_facade_id: u32, _packet: &[u8]) {
-    println!("netsim: handle_uwb_request");
+//! The UWB facade.
+//!
+//! Each UWB chip is a UCI controller answering the core, session config
+//! and session control commands of the UWB stack of its emulator. A
+//! session initialized, configured with the short MAC addresses of the
+//! device and of its peers, and started ranges every ranging interval: the
+//! ranging thread sends a SESSION_INFO notification with a two way ranging
+//! measurement to each peer, derived from the positions and orientations
+//! of the devices in the scene. A peer is reached when a chip of another
+//! device has its radio on and an active session with the same session id
+//! and the peer address, within the range of both radios if set. The UCI
+//! packets flow through packet_hub, and are captured as FiRa UCI.
+
+use frontend_proto::common::ChipKind;
+use frontend_proto::frontend::GetDevicesResponse;
+use frontend_proto::model::chip::Radio as ProtoRadio;
+use frontend_proto::model::{Orientation, Position, State};
+use lazy_static::lazy_static;
+use protobuf::{Message, MessageField};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::thread::sleep;
+use std::time::Duration;
+
+use super::uci::*;
+use crate::clock::clock;
+use crate::ffi::{get_devices_bytes, handle_response_cxx};
+use crate::logger::{debug, warn};
+use crate::ranging::distance_to_rssi;
+
+/// Ranging interval of the sessions without a RANGING_INTERVAL parameter.
+const DEFAULT_RANGING_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Shortest ranging interval, the period of the ranging thread.
+const MIN_RANGING_INTERVAL: Duration = Duration::from_millis(10);
+
+/// UCI, MAC, PHY and test versions reported by the chips, 1.1.
+const VERSION: [u8; 2] = [0x01, 0x10];
+
+/// Session states.
+const SESSION_STATE_INIT: u8 = 0x00;
+const SESSION_STATE_DEINIT: u8 = 0x01;
+const SESSION_STATE_ACTIVE: u8 = 0x02;
+const SESSION_STATE_IDLE: u8 = 0x03;
+
+/// A ranging session of a chip.
+#[derive(Debug, Default)]
+struct Session {
+    state: u8,
+    app_config: BTreeMap<u8, Vec<u8>>,
+    // Sequence number of the next SESSION_INFO notification.
+    sequence: u32,
+    ranging_count: u32,
+    next_ranging: Duration,
+}
+
+impl Session {
+    fn mac_address(&self) -> Option<u16> {
+        let value = self.app_config.get(&APP_DEVICE_MAC_ADDRESS)?;
+        Some(u16::from_le_bytes(value.as_slice().try_into().ok()?))
+    }
+
+    fn destinations(&self) -> Vec<u16> {
+        self.app_config.get(&APP_DST_MAC_ADDRESS).map_or_else(Vec::new, |value| {
+            value
+                .chunks_exact(2)
+                .map(|address| u16::from_le_bytes([address[0], address[1]]))
+                .collect()
+        })
+    }
+
+    fn interval(&self) -> Duration {
+        self.app_config
+            .get(&APP_RANGING_INTERVAL)
+            .and_then(|value| value.as_slice().try_into().ok())
+            .map_or(DEFAULT_RANGING_INTERVAL, |ms| {
+                Duration::from_millis(u32::from_le_bytes(ms).into())
+            })
+            .max(MIN_RANGING_INTERVAL)
+    }
+}
+
+// Checks the value of an application configuration parameter.
+fn is_valid_app_config(tag: u8, value: &[u8]) -> bool {
+    match tag {
+        APP_DEVICE_MAC_ADDRESS => value.len() == 2,
+        APP_DST_MAC_ADDRESS => value.len().is_multiple_of(2),
+        APP_RANGING_INTERVAL => value.len() == 4,
+        // Only short MAC addresses are simulated.
+        APP_MAC_ADDRESS_MODE => value == [0],
+        _ => true,
+    }
+}
+
+/// A UWB chip.
+#[derive(Debug)]
+struct UwbChip {
+    device_id: u32,
+    state: State,
+    // Meters, 0 for unlimited.
+    range: f32,
+    tx_count: i32,
+    rx_count: i32,
+    core_config: BTreeMap<u8, Vec<u8>>,
+    sessions: BTreeMap<u32, Session>,
+}
+
+impl UwbChip {
+    fn new(device_id: u32) -> Self {
+        UwbChip {
+            device_id,
+            state: State::ON,
+            range: 0.0,
+            tx_count: 0,
+            rx_count: 0,
+            core_config: BTreeMap::new(),
+            sessions: BTreeMap::new(),
+        }
+    }
+}
+
+/// A peer of a ranging round.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Peer {
+    pub device_id: u32,
+    pub range: f32,
+}
+
+/// A ranging round of an active session.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Round {
+    pub facade_id: u32,
+    pub device_id: u32,
+    pub session_id: u32,
+    pub sequence: u32,
+    pub interval: Duration,
+    pub range: f32,
+    /// The peer addresses, with the peer reached at each address.
+    pub destinations: Vec<(u16, Option<Peer>)>,
+}
+
+/// The UWB chips by facade id.
+#[derive(Default)]
+pub struct Uwb {
+    next_facade_id: u32,
+    chips: HashMap<u32, UwbChip>,
 }
 
-pub fn uwb_reset(_facade_id: u32) {
-    println!("netsim: uwb_reset");
+fn session_status(session_id: u32, state: u8) -> Vec<u8> {
+    let mut payload = session_id.to_le_bytes().to_vec();
+    // The state changed with a session management command.
+    payload.extend_from_slice(&[state, 0]);
+    control_packet(MT_NOTIFICATION, GID_SESSION_CONFIG, SESSION_STATUS, &payload)
 }
-pub fn uwb_remove(_facade_id: u32) {
-    println!("netsim: uwb_remove");
+
+fn session_id(payload: &[u8]) -> Option<u32> {
+    Some(u32::from_le_bytes(payload.get(..4)?.try_into().ok()?))
 }
 
-pub fn uwb_patch(_facade_id: u32, _proto_bytes: &[u8]) {
-    println!("netsim: uwb_patch");
+impl Uwb {
+    pub fn add(&mut self, device_id: u32) -> u32 {
+        self.next_facade_id += 1;
+        self.chips.insert(self.next_facade_id, UwbChip::new(device_id));
+        self.next_facade_id
+    }
+
+    pub fn remove(&mut self, facade_id: u32) {
+        self.chips.remove(&facade_id);
+    }
+
+    /// Stops the sessions of a chip and turns its radio on.
+    pub fn reset(&mut self, facade_id: u32) {
+        if let Some(chip) = self.chips.get_mut(&facade_id) {
+            *chip = UwbChip::new(chip.device_id);
+        }
+    }
+
+    pub fn patch(&mut self, facade_id: u32, radio: &ProtoRadio) {
+        let Some(chip) = self.chips.get_mut(&facade_id) else {
+            return;
+        };
+        if let Ok(state) = radio.state.enum_value() {
+            if state != State::UNKNOWN {
+                chip.state = state;
+            }
+        }
+        if radio.range > 0.0 {
+            chip.range = radio.range;
+        }
+    }
+
+    pub fn get(&self, facade_id: u32) -> Option<ProtoRadio> {
+        let chip = self.chips.get(&facade_id)?;
+        Some(ProtoRadio {
+            state: chip.state.into(),
+            range: chip.range,
+            tx_count: chip.tx_count,
+            rx_count: chip.rx_count,
+            ..Default::default()
+        })
+    }
+
+    /// Returns the response and the notifications of a command of a chip
+    /// received at `now`.
+    pub fn handle_command(&mut self, facade_id: u32, packet: &[u8], now: Duration) -> Vec<Vec<u8>> {
+        let Some(chip) = self.chips.get_mut(&facade_id) else {
+            return Vec::new();
+        };
+        let Some(command) = parse(packet).filter(|packet| packet.message_type == MT_COMMAND) else {
+            debug!("uwb facade {facade_id} ignored a packet");
+            return Vec::new();
+        };
+        let (gid, oid, payload) = (command.gid, command.oid, command.payload);
+        let response = |payload: &[u8]| control_packet(MT_RESPONSE, gid, oid, payload);
+        match (gid, oid) {
+            (GID_CORE, CORE_DEVICE_RESET) => {
+                chip.core_config.clear();
+                chip.sessions.clear();
+                vec![
+                    response(&[STATUS_OK]),
+                    control_packet(
+                        MT_NOTIFICATION,
+                        GID_CORE,
+                        CORE_DEVICE_STATUS,
+                        &[DEVICE_STATE_READY],
+                    ),
+                ]
+            }
+            (GID_CORE, CORE_GET_DEVICE_INFO) => {
+                let mut info = vec![STATUS_OK];
+                for _ in 0..4 {
+                    info.extend_from_slice(&VERSION);
+                }
+                // No vendor specific information.
+                info.push(0);
+                vec![response(&info)]
+            }
+            (GID_CORE, CORE_GET_CAPS_INFO) => vec![response(&[STATUS_OK, 0])],
+            (GID_CORE, CORE_SET_CONFIG) => {
+                match payload.split_first().map(|(_, tlvs)| parse_tlvs(tlvs)) {
+                    Some(Some(tlvs)) => {
+                        chip.core_config.extend(tlvs);
+                        vec![response(&[STATUS_OK, 0])]
+                    }
+                    _ => vec![response(&[STATUS_SYNTAX_ERROR, 0])],
+                }
+            }
+            (GID_CORE, CORE_GET_CONFIG) => {
+                let tags = payload.get(1..).unwrap_or_default();
+                let found: Vec<_> =
+                    chip.core_config.iter().filter(|(tag, _)| tags.contains(tag)).collect();
+                let mut config = vec![STATUS_OK, found.len() as u8];
+                write_tlvs(&mut config, found);
+                vec![response(&config)]
+            }
+            (GID_SESSION_CONFIG, SESSION_INIT) => {
+                let Some(session_id) = session_id(payload) else {
+                    return vec![response(&[STATUS_SYNTAX_ERROR])];
+                };
+                if chip.sessions.contains_key(&session_id) {
+                    return vec![response(&[STATUS_SESSION_DUPLICATE])];
+                }
+                chip.sessions.insert(session_id, Session::default());
+                vec![response(&[STATUS_OK]), session_status(session_id, SESSION_STATE_INIT)]
+            }
+            (GID_SESSION_CONFIG, SESSION_DEINIT) => {
+                let session_id = session_id(payload).unwrap_or_default();
+                match chip.sessions.remove(&session_id) {
+                    Some(_) => vec![
+                        response(&[STATUS_OK]),
+                        session_status(session_id, SESSION_STATE_DEINIT),
+                    ],
+                    None => vec![response(&[STATUS_SESSION_NOT_EXIST])],
+                }
+            }
+            (GID_SESSION_CONFIG, SESSION_SET_APP_CONFIG) => {
+                let session_id = session_id(payload).unwrap_or_default();
+                let Some(session) = chip.sessions.get_mut(&session_id) else {
+                    return vec![response(&[STATUS_SESSION_NOT_EXIST, 0])];
+                };
+                if session.state == SESSION_STATE_ACTIVE {
+                    return vec![response(&[STATUS_SESSION_ACTIVE, 0])];
+                }
+                let Some(tlvs) = payload.get(5..).and_then(parse_tlvs) else {
+                    return vec![response(&[STATUS_SYNTAX_ERROR, 0])];
+                };
+                let mut failed = Vec::new();
+                for (tag, value) in tlvs {
+                    match is_valid_app_config(tag, &value) {
+                        true => {
+                            session.app_config.insert(tag, value);
+                        }
+                        false => failed.extend_from_slice(&[tag, STATUS_INVALID_PARAM]),
+                    }
+                }
+                let status = if failed.is_empty() { STATUS_OK } else { STATUS_INVALID_PARAM };
+                let mut packets = vec![response(
+                    &[&[status, (failed.len() / 2) as u8], failed.as_slice()].concat(),
+                )];
+                // The session is configured with the address of the device.
+                if session.state == SESSION_STATE_INIT && session.mac_address().is_some() {
+                    session.state = SESSION_STATE_IDLE;
+                    packets.push(session_status(session_id, SESSION_STATE_IDLE));
+                }
+                packets
+            }
+            (GID_SESSION_CONFIG, SESSION_GET_APP_CONFIG) => {
+                let session_id = session_id(payload).unwrap_or_default();
+                let Some(session) = chip.sessions.get(&session_id) else {
+                    return vec![response(&[STATUS_SESSION_NOT_EXIST, 0])];
+                };
+                let tags = payload.get(5..).unwrap_or_default();
+                let found: Vec<_> = session
+                    .app_config
+                    .iter()
+                    .filter(|(tag, _)| tags.is_empty() || tags.contains(tag))
+                    .collect();
+                let mut config = vec![STATUS_OK, found.len() as u8];
+                write_tlvs(&mut config, found);
+                vec![response(&config)]
+            }
+            (GID_SESSION_CONFIG, SESSION_GET_COUNT) => {
+                vec![response(&[STATUS_OK, chip.sessions.len() as u8])]
+            }
+            (GID_SESSION_CONFIG, SESSION_GET_STATE) => {
+                match chip.sessions.get(&session_id(payload).unwrap_or_default()) {
+                    Some(session) => vec![response(&[STATUS_OK, session.state])],
+                    None => vec![response(&[STATUS_SESSION_NOT_EXIST, 0])],
+                }
+            }
+            (GID_SESSION_CONTROL, SESSION_START) => {
+                let session_id = session_id(payload).unwrap_or_default();
+                let Some(session) = chip.sessions.get_mut(&session_id) else {
+                    return vec![response(&[STATUS_SESSION_NOT_EXIST])];
+                };
+                match session.state {
+                    SESSION_STATE_IDLE => {
+                        session.state = SESSION_STATE_ACTIVE;
+                        session.next_ranging = now + session.interval();
+                        vec![
+                            response(&[STATUS_OK]),
+                            session_status(session_id, SESSION_STATE_ACTIVE),
+                        ]
+                    }
+                    SESSION_STATE_ACTIVE => vec![response(&[STATUS_SESSION_ACTIVE])],
+                    _ => vec![response(&[STATUS_SESSION_NOT_CONFIGURED])],
+                }
+            }
+            (GID_SESSION_CONTROL, SESSION_STOP) => {
+                let session_id = session_id(payload).unwrap_or_default();
+                let Some(session) = chip.sessions.get_mut(&session_id) else {
+                    return vec![response(&[STATUS_SESSION_NOT_EXIST])];
+                };
+                if session.state != SESSION_STATE_ACTIVE {
+                    return vec![response(&[STATUS_REJECTED])];
+                }
+                session.state = SESSION_STATE_IDLE;
+                vec![response(&[STATUS_OK]), session_status(session_id, SESSION_STATE_IDLE)]
+            }
+            (GID_SESSION_CONTROL, SESSION_GET_RANGING_COUNT) => {
+                match chip.sessions.get(&session_id(payload).unwrap_or_default()) {
+                    Some(session) => vec![response(
+                        &[&[STATUS_OK][..], &session.ranging_count.to_le_bytes()].concat(),
+                    )],
+                    None => vec![response(&[STATUS_SESSION_NOT_EXIST])],
+                }
+            }
+            (GID_CORE | GID_SESSION_CONFIG | GID_SESSION_CONTROL, _) => {
+                vec![response(&[STATUS_UNKNOWN_OID])]
+            }
+            _ => vec![response(&[STATUS_UNKNOWN_GID])],
+        }
+    }
+
+    // Returns the chip of another device with an active session reachable
+    // at `mac_address`.
+    fn peer(&self, facade_id: u32, session_id: u32, mac_address: u16) -> Option<Peer> {
+        let device_id = self.chips.get(&facade_id)?.device_id;
+        self.chips.iter().find_map(|(&id, chip)| {
+            let session = chip.sessions.get(&session_id)?;
+            (id != facade_id
+                && chip.device_id != device_id
+                && chip.state == State::ON
+                && session.state == SESSION_STATE_ACTIVE
+                && session.mac_address() == Some(mac_address))
+            .then_some(Peer { device_id: chip.device_id, range: chip.range })
+        })
+    }
+
+    /// Returns the ranging rounds of the active sessions due at `now`, and
+    /// schedules their next rounds.
+    pub fn due_rounds(&mut self, now: Duration) -> Vec<Round> {
+        let mut due = Vec::new();
+        for (&facade_id, chip) in self.chips.iter_mut() {
+            if chip.state != State::ON {
+                continue;
+            }
+            for (&session_id, session) in chip.sessions.iter_mut() {
+                if session.state != SESSION_STATE_ACTIVE || session.next_ranging > now {
+                    continue;
+                }
+                let interval = session.interval();
+                // Late rounds are skipped rather than sent in a burst.
+                session.next_ranging = (session.next_ranging + interval).max(now);
+                session.ranging_count += 1;
+                session.sequence = session.sequence.wrapping_add(1);
+                chip.tx_count += 1;
+                due.push(Round {
+                    facade_id,
+                    device_id: chip.device_id,
+                    session_id,
+                    sequence: session.sequence - 1,
+                    interval,
+                    range: chip.range,
+                    destinations: session.destinations().into_iter().map(|a| (a, None)).collect(),
+                });
+            }
+        }
+        for round in due.iter_mut() {
+            for (address, peer) in round.destinations.iter_mut() {
+                *peer = self.peer(round.facade_id, round.session_id, *address);
+            }
+        }
+        due
+    }
+
+    fn record_received(&mut self, facade_id: u32, count: i32) {
+        if let Some(chip) = self.chips.get_mut(&facade_id) {
+            chip.rx_count += count;
+        }
+    }
 }
 
-pub fn uwb_get(_facade_id: u32) -> Vec<u8> {
-    println!("netsim: uwb_get");
-    Vec::<u8>::new()
+/// The position and the orientation of a device.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Pose {
+    pub position: Position,
+    pub orientation: Orientation,
 }
 
-// Returns facade_id
-pub fn uwb_add(_chip_id: u32) -> u32 {
-    println!("netsim: uwb_get");
-    1
+// Returns the azimuth and the elevation in degrees of the direction from
+// one pose to a position. The device faces the x axis turned by its yaw,
+// tilted up by its pitch.
+fn angles(from: &Pose, to: &Position) -> (f32, f32) {
+    let (dx, dy, dz) = (to.x - from.position.x, to.y - from.position.y, to.z - from.position.z);
+    let azimuth = dy.atan2(dx).to_degrees() - from.orientation.yaw;
+    let elevation = dz.atan2(dx.hypot(dy)).to_degrees() - from.orientation.pitch;
+    // Wrapped to [-180, 180).
+    ((azimuth + 180.0).rem_euclid(360.0) - 180.0, elevation.clamp(-90.0, 90.0))
+}
+
+/// Returns the measurements of a ranging round between the devices posed
+/// in the scene.
+pub fn measure(round: &Round, poses: &HashMap<u32, Pose>) -> Vec<Measurement> {
+    round
+        .destinations
+        .iter()
+        .map(|(address, peer)| {
+            let unreached = Measurement {
+                mac_address: *address,
+                status: STATUS_RANGING_RX_TIMEOUT,
+                ..Default::default()
+            };
+            let (Some(peer), Some(own)) = (peer, poses.get(&round.device_id)) else {
+                return unreached;
+            };
+            let Some(other) = poses.get(&peer.device_id) else {
+                return unreached;
+            };
+            let (a, b) = (&own.position, &other.position);
+            let distance = ((a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)).sqrt();
+            if [round.range, peer.range].iter().any(|&range| range > 0.0 && distance > range) {
+                return unreached;
+            }
+            let (azimuth, elevation) = angles(own, b);
+            let (destination_azimuth, destination_elevation) = angles(other, a);
+            Measurement {
+                mac_address: *address,
+                status: STATUS_OK,
+                distance: (distance * 100.0).round().min(u16::MAX as f32) as u16,
+                azimuth,
+                elevation,
+                destination_azimuth,
+                destination_elevation,
+                rssi: distance_to_rssi(0, distance),
+            }
+        })
+        .collect()
+}
+
+lazy_static! {
+    static ref UWB: Mutex<Uwb> = Mutex::new(Uwb::default());
+}
+
+// Returns the poses of the devices of the scene by device id.
+fn poses() -> HashMap<u32, Pose> {
+    let mut vec = Vec::<u8>::new();
+    if !get_devices_bytes(&mut vec) {
+        return HashMap::new();
+    }
+    let devices = GetDevicesResponse::parse_from_bytes(&vec).map(|r| r.devices).unwrap_or_default();
+    devices
+        .into_iter()
+        .map(|device| {
+            let pose = Pose {
+                position: device.position.unwrap_or_default(),
+                orientation: device.orientation.unwrap_or_default(),
+            };
+            (device.id as u32, pose)
+        })
+        .collect()
+}
+
+fn send(facade_id: u32, packet: &Vec<u8>) {
+    handle_response_cxx(ChipKind::UWB as u32, facade_id, packet, 0);
+}
+
+/// Ranges the active sessions of the chips.
+pub fn run_ranging() {
+    loop {
+        sleep(MIN_RANGING_INTERVAL);
+        let rounds = UWB.lock().unwrap().due_rounds(clock().monotonic());
+        if rounds.is_empty() {
+            continue;
+        }
+        // Read without the lock of the chips, which the scene takes.
+        let poses = poses();
+        for round in rounds {
+            let measurements = measure(&round, &poses);
+            let received = measurements.iter().filter(|m| m.status == STATUS_OK).count();
+            UWB.lock().unwrap().record_received(round.facade_id, received as i32);
+            if measurements.len() > MAX_MEASUREMENTS {
+                warn!("uwb session {} ranges the first {MAX_MEASUREMENTS} peers", round.session_id);
+            }
+            let interval_ms = round.interval.as_millis() as u32;
+            send(
+                round.facade_id,
+                &session_info(round.sequence, round.session_id, interval_ms, &measurements),
+            );
+        }
+    }
+}
+
+pub fn handle_uwb_request(facade_id: u32, packet: &[u8]) {
+    let packets = UWB.lock().unwrap().handle_command(facade_id, packet, clock().monotonic());
+    // Sent without the lock, packet_hub may deliver the next command.
+    for packet in packets {
+        send(facade_id, &packet);
+    }
+}
+
+pub fn uwb_reset(facade_id: u32) {
+    UWB.lock().unwrap().reset(facade_id);
+}
+
+pub fn uwb_remove(facade_id: u32) {
+    UWB.lock().unwrap().remove(facade_id);
+}
+
+pub fn uwb_patch(facade_id: u32, proto_bytes: &[u8]) {
+    match ProtoRadio::parse_from_bytes(proto_bytes) {
+        Ok(radio) => UWB.lock().unwrap().patch(facade_id, &radio),
+        Err(err) => warn!("uwb_patch: {err}"),
+    }
+}
+
+pub fn uwb_get(facade_id: u32) -> Vec<u8> {
+    let radio = UWB.lock().unwrap().get(facade_id);
+    MessageField::from_option(radio).unwrap_or_default().write_to_bytes().unwrap_or_default()
+}
+
+pub fn uwb_add(device_id: u32) -> u32 {
+    UWB.lock().unwrap().add(device_id)
 }
 
 pub fn uwb_start() {
-    println!("netsim: uwb_start");
+    debug!("uwb_start");
 }
 
 pub fn uwb_stop() {
-    println!("netsim: uwb_stop");
+    debug!("uwb_stop");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SESSION: u32 = 0x0a0b0c0d;
+
+    fn command(gid: u8, oid: u8, payload: &[u8]) -> Vec<u8> {
+        control_packet(MT_COMMAND, gid, oid, payload)
+    }
+
+    fn app_config(mac: u16, dst: u16) -> Vec<u8> {
+        let mut payload = SESSION.to_le_bytes().to_vec();
+        payload.push(2);
+        payload.extend_from_slice(&[APP_DEVICE_MAC_ADDRESS, 2]);
+        payload.extend_from_slice(&mac.to_le_bytes());
+        payload.extend_from_slice(&[APP_DST_MAC_ADDRESS, 2]);
+        payload.extend_from_slice(&dst.to_le_bytes());
+        payload
+    }
+
+    // Starts a session of a chip ranging from `mac` to `dst`.
+    fn start(uwb: &mut Uwb, facade_id: u32, mac: u16, dst: u16) -> Vec<Vec<u8>> {
+        let session = SESSION.to_le_bytes();
+        let mut packets = Vec::new();
+        for (gid, oid, payload) in [
+            (GID_SESSION_CONFIG, SESSION_INIT, [&session[..], &[0]].concat()),
+            (GID_SESSION_CONFIG, SESSION_SET_APP_CONFIG, app_config(mac, dst)),
+            (GID_SESSION_CONTROL, SESSION_START, session.to_vec()),
+        ] {
+            packets.extend(uwb.handle_command(
+                facade_id,
+                &command(gid, oid, &payload),
+                Duration::ZERO,
+            ));
+        }
+        packets
+    }
+
+    #[test]
+    fn test_session_lifecycle() {
+        let mut uwb = Uwb::default();
+        let facade_id = uwb.add(1);
+        let packets = uwb.handle_command(
+            facade_id,
+            &command(GID_CORE, CORE_DEVICE_RESET, &[0]),
+            Duration::ZERO,
+        );
+        assert_eq!(
+            packets,
+            [
+                vec![0x40, 0x00, 0x00, 0x01, STATUS_OK],
+                vec![0x60, 0x01, 0x00, 0x01, DEVICE_STATE_READY]
+            ]
+        );
+        let packets = start(&mut uwb, facade_id, 0x1111, 0x2222);
+        let states: Vec<u8> = packets
+            .iter()
+            .filter_map(|packet| parse(packet))
+            .filter(|packet| packet.message_type == MT_NOTIFICATION)
+            .map(|packet| packet.payload[4])
+            .collect();
+        assert_eq!(states, [SESSION_STATE_INIT, SESSION_STATE_IDLE, SESSION_STATE_ACTIVE]);
+        let statuses: Vec<u8> = packets
+            .iter()
+            .filter_map(|packet| parse(packet))
+            .filter(|packet| packet.message_type == MT_RESPONSE)
+            .map(|packet| packet.payload[0])
+            .collect();
+        assert_eq!(statuses, [STATUS_OK; 3]);
+        // A second start is rejected.
+        let session = SESSION.to_le_bytes();
+        let packets = uwb.handle_command(
+            facade_id,
+            &command(GID_SESSION_CONTROL, SESSION_START, &session),
+            Duration::ZERO,
+        );
+        assert_eq!(parse(&packets[0]).unwrap().payload, [STATUS_SESSION_ACTIVE]);
+        let packets =
+            uwb.handle_command(facade_id, &command(GID_SESSION_CONFIG, 0x3f, &[]), Duration::ZERO);
+        assert_eq!(parse(&packets[0]).unwrap().payload, [STATUS_UNKNOWN_OID]);
+        let packets = uwb.handle_command(facade_id, &command(0x0e, 0x00, &[]), Duration::ZERO);
+        assert_eq!(parse(&packets[0]).unwrap().payload, [STATUS_UNKNOWN_GID]);
+    }
+
+    #[test]
+    fn test_invalid_app_config() {
+        let mut uwb = Uwb::default();
+        let facade_id = uwb.add(1);
+        let mut init = SESSION.to_le_bytes().to_vec();
+        init.push(0);
+        uwb.handle_command(
+            facade_id,
+            &command(GID_SESSION_CONFIG, SESSION_INIT, &init),
+            Duration::ZERO,
+        );
+        let mut payload = SESSION.to_le_bytes().to_vec();
+        payload.extend_from_slice(&[1, APP_MAC_ADDRESS_MODE, 1, 2]);
+        let packets = uwb.handle_command(
+            facade_id,
+            &command(GID_SESSION_CONFIG, SESSION_SET_APP_CONFIG, &payload),
+            Duration::ZERO,
+        );
+        assert_eq!(packets.len(), 1);
+        assert_eq!(
+            parse(&packets[0]).unwrap().payload,
+            [STATUS_INVALID_PARAM, 1, APP_MAC_ADDRESS_MODE, STATUS_INVALID_PARAM]
+        );
+        // Without an address the session is not configured.
+        let packets = uwb.handle_command(
+            facade_id,
+            &command(GID_SESSION_CONTROL, SESSION_START, &SESSION.to_le_bytes()),
+            Duration::ZERO,
+        );
+        assert_eq!(parse(&packets[0]).unwrap().payload, [STATUS_SESSION_NOT_CONFIGURED]);
+    }
+
+    #[test]
+    fn test_ranging() {
+        let mut uwb = Uwb::default();
+        let controller = uwb.add(1);
+        let controlee = uwb.add(2);
+        start(&mut uwb, controller, 0x1111, 0x2222);
+        assert!(uwb.due_rounds(Duration::from_millis(100)).is_empty());
+        let rounds = uwb.due_rounds(DEFAULT_RANGING_INTERVAL);
+        // The controlee has not started, it is not reached.
+        assert_eq!(rounds.len(), 1);
+        assert_eq!(rounds[0].destinations, [(0x2222, None)]);
+        start(&mut uwb, controlee, 0x2222, 0x1111);
+        let mut rounds = uwb.due_rounds(DEFAULT_RANGING_INTERVAL * 2);
+        rounds.sort_by_key(|round| round.facade_id);
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[0].sequence, 1);
+        assert_eq!(rounds[0].destinations, [(0x2222, Some(Peer { device_id: 2, range: 0.0 }))]);
+        // The controlee is 3 meters east and 4 north, facing south.
+        let poses = HashMap::from([
+            (1, Pose::default()),
+            (
+                2,
+                Pose {
+                    position: Position { x: 3.0, y: 4.0, ..Default::default() },
+                    orientation: Orientation { yaw: -90.0, ..Default::default() },
+                },
+            ),
+        ]);
+        let measurement = &measure(&rounds[0], &poses)[0];
+        assert_eq!((measurement.status, measurement.distance), (STATUS_OK, 500));
+        assert!((measurement.azimuth - 53.13).abs() < 0.01, "{measurement:?}");
+        assert!((measurement.destination_azimuth + 36.87).abs() < 0.01, "{measurement:?}");
+        // Out of the range of the radio of the controlee.
+        uwb.patch(controlee, &ProtoRadio { range: 4.0, ..Default::default() });
+        let rounds = uwb.due_rounds(DEFAULT_RANGING_INTERVAL * 3);
+        let round = rounds.iter().find(|round| round.facade_id == controller).unwrap();
+        assert_eq!(measure(round, &poses)[0].status, STATUS_RANGING_RX_TIMEOUT);
+        // Turned off, the controlee neither ranges nor is reached.
+        uwb.patch(controlee, &ProtoRadio { state: State::OFF.into(), ..Default::default() });
+        let rounds = uwb.due_rounds(DEFAULT_RANGING_INTERVAL * 4);
+        assert_eq!(rounds.len(), 1);
+        assert_eq!(rounds[0].destinations, [(0x2222, None)]);
+        assert_eq!(uwb.get(controller).unwrap().tx_count, 4);
+    }
 }
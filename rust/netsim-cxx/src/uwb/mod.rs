@@ -12,4 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! UWB chips simulating FiRa ranging sessions over UCI.
+
 pub mod facade;
+pub mod uci;
@@ -0,0 +1,275 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! FiRa UCI control packets.
+//!
+//! A control packet has a 4 byte header: the message type, the packet
+//! boundary flag and the group id, the opcode id, a reserved byte and the
+//! payload length. The multi-byte fields of the payloads are little endian.
+
+/// Message types.
+pub const MT_COMMAND: u8 = 1;
+pub const MT_RESPONSE: u8 = 2;
+pub const MT_NOTIFICATION: u8 = 3;
+
+/// Group ids.
+pub const GID_CORE: u8 = 0x0;
+pub const GID_SESSION_CONFIG: u8 = 0x1;
+pub const GID_SESSION_CONTROL: u8 = 0x2;
+
+/// Opcode ids of the core group.
+pub const CORE_DEVICE_RESET: u8 = 0x00;
+pub const CORE_DEVICE_STATUS: u8 = 0x01;
+pub const CORE_GET_DEVICE_INFO: u8 = 0x02;
+pub const CORE_GET_CAPS_INFO: u8 = 0x03;
+pub const CORE_SET_CONFIG: u8 = 0x04;
+pub const CORE_GET_CONFIG: u8 = 0x05;
+
+/// Opcode ids of the session config group.
+pub const SESSION_INIT: u8 = 0x00;
+pub const SESSION_DEINIT: u8 = 0x01;
+pub const SESSION_STATUS: u8 = 0x02;
+pub const SESSION_SET_APP_CONFIG: u8 = 0x03;
+pub const SESSION_GET_APP_CONFIG: u8 = 0x04;
+pub const SESSION_GET_COUNT: u8 = 0x05;
+pub const SESSION_GET_STATE: u8 = 0x06;
+
+/// Opcode ids of the session control group.
+pub const SESSION_START: u8 = 0x00;
+pub const SESSION_INFO: u8 = 0x00;
+pub const SESSION_STOP: u8 = 0x01;
+pub const SESSION_GET_RANGING_COUNT: u8 = 0x03;
+
+/// Status codes.
+pub const STATUS_OK: u8 = 0x00;
+pub const STATUS_REJECTED: u8 = 0x01;
+pub const STATUS_SYNTAX_ERROR: u8 = 0x03;
+pub const STATUS_INVALID_PARAM: u8 = 0x04;
+pub const STATUS_UNKNOWN_GID: u8 = 0x07;
+pub const STATUS_UNKNOWN_OID: u8 = 0x08;
+pub const STATUS_SESSION_NOT_EXIST: u8 = 0x11;
+pub const STATUS_SESSION_DUPLICATE: u8 = 0x12;
+pub const STATUS_SESSION_ACTIVE: u8 = 0x13;
+pub const STATUS_SESSION_NOT_CONFIGURED: u8 = 0x15;
+pub const STATUS_RANGING_RX_TIMEOUT: u8 = 0x21;
+
+/// Device state of the CORE_DEVICE_STATUS notification.
+pub const DEVICE_STATE_READY: u8 = 0x01;
+
+/// Application configuration parameters.
+pub const APP_DEVICE_MAC_ADDRESS: u8 = 0x06;
+pub const APP_DST_MAC_ADDRESS: u8 = 0x07;
+pub const APP_RANGING_INTERVAL: u8 = 0x09;
+pub const APP_MAC_ADDRESS_MODE: u8 = 0x26;
+
+/// Ranging measurement type of the two way ranging.
+pub const RANGING_TWO_WAY: u8 = 0x01;
+
+const HEADER_LEN: usize = 4;
+const MAX_PAYLOAD_LEN: usize = 255;
+/// Length of the session info notification before the measurements.
+const SESSION_INFO_HEADER_LEN: usize = 25;
+/// Length of a two way ranging measurement with a short address.
+const MEASUREMENT_LEN: usize = 31;
+/// Most measurements of a session info notification in a single packet.
+pub const MAX_MEASUREMENTS: usize = (MAX_PAYLOAD_LEN - SESSION_INFO_HEADER_LEN) / MEASUREMENT_LEN;
+
+/// A control packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ControlPacket<'a> {
+    pub message_type: u8,
+    pub gid: u8,
+    pub oid: u8,
+    pub payload: &'a [u8],
+}
+
+/// Parses a complete control packet, None for data packets and segments.
+pub fn parse(packet: &[u8]) -> Option<ControlPacket<'_>> {
+    let header = packet.get(..HEADER_LEN)?;
+    let message_type = header[0] >> 5;
+    let segmented = header[0] & 0x10 != 0;
+    if message_type == 0 || segmented {
+        return None;
+    }
+    let payload = packet.get(HEADER_LEN..HEADER_LEN + header[3] as usize)?;
+    Some(ControlPacket { message_type, gid: header[0] & 0x0f, oid: header[1] & 0x3f, payload })
+}
+
+/// Returns a control packet with a payload of at most 255 bytes.
+pub fn control_packet(message_type: u8, gid: u8, oid: u8, payload: &[u8]) -> Vec<u8> {
+    let len = payload.len().min(MAX_PAYLOAD_LEN);
+    let mut packet = vec![message_type << 5 | gid, oid, 0, len as u8];
+    packet.extend_from_slice(&payload[..len]);
+    packet
+}
+
+/// Parses the type, length and value parameters of a payload.
+pub fn parse_tlvs(mut bytes: &[u8]) -> Option<Vec<(u8, Vec<u8>)>> {
+    let mut tlvs = Vec::new();
+    while let [tag, len, rest @ ..] = bytes {
+        let value = rest.get(..*len as usize)?;
+        tlvs.push((*tag, value.to_vec()));
+        bytes = &rest[*len as usize..];
+    }
+    bytes.is_empty().then_some(tlvs)
+}
+
+/// Appends type, length and value parameters to a payload.
+pub fn write_tlvs<'a>(
+    payload: &mut Vec<u8>,
+    tlvs: impl IntoIterator<Item = (&'a u8, &'a Vec<u8>)>,
+) {
+    for (tag, value) in tlvs {
+        payload.push(*tag);
+        payload.push(value.len() as u8);
+        payload.extend_from_slice(value);
+    }
+}
+
+/// A two way ranging measurement to a peer.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Measurement {
+    pub mac_address: u16,
+    pub status: u8,
+    /// Centimeters.
+    pub distance: u16,
+    /// Degrees, of the peer as seen by the device.
+    pub azimuth: f32,
+    pub elevation: f32,
+    /// Degrees, of the device as seen by the peer.
+    pub destination_azimuth: f32,
+    pub destination_elevation: f32,
+    /// dBm.
+    pub rssi: i8,
+}
+
+// Encodes an angle in degrees in Q9.7.
+fn q9_7(degrees: f32) -> [u8; 2] {
+    ((degrees * 128.0).round() as i16).to_le_bytes()
+}
+
+impl Measurement {
+    fn write(&self, payload: &mut Vec<u8>) {
+        let ok = self.status == STATUS_OK;
+        // The figures of merit of the angles, 0 without a measurement.
+        let fom = if ok { 100 } else { 0 };
+        payload.extend_from_slice(&self.mac_address.to_le_bytes());
+        payload.push(self.status);
+        // Line of sight.
+        payload.push(0);
+        payload.extend_from_slice(&self.distance.to_le_bytes());
+        for angle in
+            [self.azimuth, self.elevation, self.destination_azimuth, self.destination_elevation]
+        {
+            payload.extend_from_slice(&q9_7(angle));
+            payload.push(fom);
+        }
+        // Slot index.
+        payload.push(0);
+        // The RSSI is the absolute value in Q7.1.
+        payload.push(if ok { (self.rssi as i16 * -2).clamp(0, 255) as u8 } else { 0 });
+        payload.extend_from_slice(&[0; 11]);
+    }
+}
+
+/// Returns the SESSION_INFO notification of a ranging round of a session,
+/// with at most MAX_MEASUREMENTS measurements.
+pub fn session_info(
+    sequence: u32,
+    session_id: u32,
+    interval_ms: u32,
+    measurements: &[Measurement],
+) -> Vec<u8> {
+    let measurements = &measurements[..measurements.len().min(MAX_MEASUREMENTS)];
+    let mut payload = Vec::with_capacity(MAX_PAYLOAD_LEN);
+    payload.extend_from_slice(&sequence.to_le_bytes());
+    payload.extend_from_slice(&session_id.to_le_bytes());
+    // RCR indicator.
+    payload.push(0);
+    payload.extend_from_slice(&interval_ms.to_le_bytes());
+    payload.push(RANGING_TWO_WAY);
+    payload.push(0);
+    // Short MAC addresses.
+    payload.push(0);
+    payload.extend_from_slice(&[0; 8]);
+    payload.push(measurements.len() as u8);
+    for measurement in measurements {
+        measurement.write(&mut payload);
+    }
+    control_packet(MT_NOTIFICATION, GID_SESSION_CONTROL, SESSION_INFO, &payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        // SESSION_INIT_CMD of session 0x01020304 for ranging.
+        let packet = [0x21, 0x00, 0x00, 0x05, 0x04, 0x03, 0x02, 0x01, 0x00];
+        let parsed = parse(&packet).unwrap();
+        assert_eq!(
+            parsed,
+            ControlPacket {
+                message_type: MT_COMMAND,
+                gid: GID_SESSION_CONFIG,
+                oid: SESSION_INIT,
+                payload: &packet[4..]
+            }
+        );
+        // Truncated, segmented and data packets.
+        assert_eq!(parse(&packet[..8]), None);
+        assert_eq!(parse(&[0x31, 0x00, 0x00, 0x00]), None);
+        assert_eq!(parse(&[0x01, 0x00, 0x01, 0x00, 0xff]), None);
+        assert_eq!(
+            control_packet(MT_RESPONSE, GID_SESSION_CONFIG, SESSION_INIT, &[STATUS_OK]),
+            [0x41, 0x00, 0x00, 0x01, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_tlvs() {
+        let tlvs = parse_tlvs(&[0x06, 0x02, 0x34, 0x12, 0x09, 0x00]).unwrap();
+        assert_eq!(tlvs, [(0x06, vec![0x34, 0x12]), (0x09, vec![])]);
+        let mut payload = Vec::new();
+        write_tlvs(&mut payload, tlvs.iter().map(|(tag, value)| (tag, value)));
+        assert_eq!(payload, [0x06, 0x02, 0x34, 0x12, 0x09, 0x00]);
+        assert_eq!(parse_tlvs(&[0x06, 0x02, 0x34]), None);
+    }
+
+    #[test]
+    fn test_session_info() {
+        let measurement = Measurement {
+            mac_address: 0x1234,
+            distance: 150,
+            azimuth: -45.0,
+            rssi: -60,
+            ..Default::default()
+        };
+        let packet = session_info(7, 42, 200, &vec![measurement; 10]);
+        let parsed = parse(&packet).unwrap();
+        assert_eq!((parsed.message_type, parsed.gid), (MT_NOTIFICATION, GID_SESSION_CONTROL));
+        let payload = parsed.payload;
+        assert_eq!(payload.len(), SESSION_INFO_HEADER_LEN + MAX_MEASUREMENTS * MEASUREMENT_LEN);
+        assert_eq!(payload[..4], 7u32.to_le_bytes());
+        assert_eq!(payload[4..8], 42u32.to_le_bytes());
+        assert_eq!(payload[24] as usize, MAX_MEASUREMENTS);
+        let first = &payload[SESSION_INFO_HEADER_LEN..][..MEASUREMENT_LEN];
+        assert_eq!(first[..2], [0x34, 0x12]);
+        assert_eq!(first[4..6], 150u16.to_le_bytes());
+        assert_eq!(first[6..8], (-45i16 * 128).to_le_bytes());
+        assert_eq!(first[8], 100);
+        assert_eq!(first[19], 120);
+    }
+}
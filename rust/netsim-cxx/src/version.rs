@@ -12,10 +12,92 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-/// Version library.
+//! Version library.
+//!
+//! The version of netsimd comes with its capabilities: the chip kinds, the
+//! capture formats and the features it supports, so clients adapt to older
+//! netsimd instead of failing on what they lack.
+//!
+//! /version --> handle_version
+
+use frontend_proto::frontend::VersionResponse;
+use frontend_proto::model::capture::Format;
+use protobuf::{Enum, Message};
+
+use crate::auth::is_enabled;
+use crate::chip_kinds::chip_kind_names;
+use crate::config::get_setting_parsed;
+use crate::http_server::http_request::HttpRequest;
+use crate::http_server::server_response::ResponseWritable;
 
 pub const VERSION: &str = "0.1.0";
 
+/// Features of the frontend API, by the order they were added.
+const API_FEATURES: [&str; 6] =
+    ["metrics", "scene_files", "subscribe_events", "websocket", "pagination", "update_mask"];
+
 pub fn get_version() -> String {
     VERSION.to_owned()
 }
+
+/// Returns the features of the frontend API and the features enabled in
+/// this netsimd.
+pub fn features() -> Vec<String> {
+    let mut features: Vec<String> =
+        API_FEATURES.iter().map(|feature| feature.to_string()).collect();
+    let enabled = [
+        ("auth", is_enabled()),
+        // The http server only runs on a port selected by netsimd.
+        ("http", get_setting_parsed::<u16>("grpc.port") == 0),
+        ("wasm", cfg!(feature = "wasm")),
+        ("cuttlefish", cfg!(feature = "cuttlefish")),
+    ];
+    features.extend(
+        enabled.iter().filter(|(_, enabled)| *enabled).map(|(feature, _)| feature.to_string()),
+    );
+    features
+}
+
+/// Returns the version and the capabilities of netsimd.
+pub fn version_response() -> VersionResponse {
+    VersionResponse {
+        version: get_version(),
+        chip_kinds: chip_kind_names(),
+        capture_formats: Format::VALUES.iter().map(|format| (*format).into()).collect(),
+        features: features(),
+        ..Default::default()
+    }
+}
+
+/// The Rust version handler used directly by Http frontend for GET
+pub fn handle_version(_request: &HttpRequest, _param: &str, writer: ResponseWritable) {
+    match protobuf_json_mapping::print_to_string(&version_response()) {
+        Ok(json) => writer.put_ok("text/json", &json, &[]),
+        Err(e) => writer.put_error(500, e.to_string().as_str()),
+    }
+}
+
+// Cxx Method for the frontend server to invoke
+
+/// Returns the VersionResponse of GetVersion.
+pub fn version_response_bytes() -> Vec<u8> {
+    version_response().write_to_bytes().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_response() {
+        let response = version_response();
+        assert_eq!(response.version, VERSION);
+        assert!(response.chip_kinds.iter().any(|kind| kind == "BLUETOOTH"));
+        assert!(response.capture_formats.contains(&Format::PCAPNG.into()));
+        assert!(response.features.iter().any(|feature| feature == "update_mask"));
+        assert_eq!(
+            response.features.iter().any(|feature| feature == "wasm"),
+            cfg!(feature = "wasm")
+        );
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Watchdog for stalled chips.
+//!
+//! The watchdog checks the transport statistics of the chips every second.
+//! A chip is backed up when its queue holds `watchdog.max_queue_depth`
+//! packets or more, and stalled when its queue holds packets but none left
+//! it for `watchdog.stall_secs`. The watchdog records a CHIP_STALLED event
+//! with the transport statistics and the last packet from the emulator of
+//! the chip, once until the chip recovers, and restarts the
+//! facade of the chip when `watchdog.restart` is set.
+
+use cxx::CxxVector;
+use frontend_proto::frontend::event::Type;
+use frontend_proto::frontend::Event;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::chip_registry::{facade_id, find_chip};
+use crate::clock::{clock, now, to_timestamp};
+use crate::config::get_setting_parsed;
+use crate::events::history;
+use crate::logger::warn;
+use crate::restart::restart_chip;
+use crate::transport::stats::{chip_transport_stats, stats, ChipCounters};
+
+/// Interval between the checks of the watchdog.
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Why the watchdog reports a chip.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stall {
+    BackedUp,
+    Stalled,
+}
+
+/// Queue progress of a chip.
+struct Progress {
+    dequeued: u64,
+    // Time of the last change of dequeued, or of the first check.
+    since: Duration,
+    // The chip is reported once until it recovers.
+    reported: bool,
+}
+
+/// The watchdog limits; 0 disables a check.
+#[derive(Clone, Copy)]
+pub struct Limits {
+    pub max_queue_depth: u64,
+    pub stall: Duration,
+}
+
+#[derive(Default)]
+pub struct Watchdog {
+    chips: Mutex<HashMap<(u32, u32), Progress>>,
+    // Last packet from the emulator of each chip.
+    last_packets: Mutex<HashMap<(u32, u32), Vec<u8>>>,
+    enabled: AtomicBool,
+}
+
+impl Watchdog {
+    /// Returns the chips newly backed up or stalled at `time`.
+    pub fn check(
+        &self,
+        snapshot: &[((u32, u32), ChipCounters)],
+        time: Duration,
+        limits: Limits,
+    ) -> Vec<((u32, u32), Stall)> {
+        let mut chips = self.chips.lock().unwrap();
+        let mut stalls = Vec::new();
+        for (key, counters) in snapshot {
+            let progress = chips.entry(*key).or_insert(Progress {
+                dequeued: counters.dequeued,
+                since: time,
+                reported: false,
+            });
+            let progressed = counters.dequeued != progress.dequeued || counters.queue_depth == 0;
+            if progressed {
+                progress.dequeued = counters.dequeued;
+                progress.since = time;
+            }
+            let stall =
+                if limits.max_queue_depth > 0 && counters.queue_depth >= limits.max_queue_depth {
+                    Stall::BackedUp
+                } else if !progressed
+                    && !limits.stall.is_zero()
+                    && time.saturating_sub(progress.since) >= limits.stall
+                {
+                    Stall::Stalled
+                } else {
+                    progress.reported = false;
+                    continue;
+                };
+            if !progress.reported {
+                progress.reported = true;
+                stalls.push((*key, stall));
+            }
+        }
+        stalls
+    }
+
+    // The packet is borrowed for the call: it is copied into the buffer of
+    // the chip, reused from one packet to the next.
+    fn record_request(&self, key: (u32, u32), packet: &[u8]) {
+        let mut last_packets = self.last_packets.lock().unwrap();
+        let last_packet = last_packets.entry(key).or_default();
+        last_packet.clear();
+        last_packet.extend_from_slice(packet);
+    }
+
+    fn last_packet(&self, key: (u32, u32)) -> Vec<u8> {
+        self.last_packets.lock().unwrap().get(&key).cloned().unwrap_or_default()
+    }
+}
+
+lazy_static! {
+    static ref WATCHDOG: Watchdog = Watchdog::default();
+}
+
+// Records a CHIP_STALLED event and restarts the chip when configured.
+fn report(key: (u32, u32), stall: Stall, restart: bool) {
+    let mut event = Event {
+        type_: Type::CHIP_STALLED.into(),
+        timestamp: Some(to_timestamp(now())).into(),
+        packet: WATCHDOG.last_packet(key),
+        ..Default::default()
+    };
+    if let Some((device, chip_id)) = find_chip(key.0, key.1) {
+        event.device_name = device.name.clone();
+        event.chip_id = chip_id;
+        let response = chip_transport_stats(stats(), std::slice::from_ref(&device), facade_id);
+        event.transport_stats =
+            response.chips.into_iter().find(|chip| chip.chip_id == chip_id).into();
+        event.devices.push(device);
+    }
+    warn!(
+        "watchdog: chip {} of {} is {}, {} packets queued",
+        event.chip_id,
+        event.device_name,
+        match stall {
+            Stall::BackedUp => "backed up",
+            Stall::Stalled => "stalled",
+        },
+        event.transport_stats.queue_depth
+    );
+    let chip_id = event.chip_id;
+    history().record(event);
+    if restart && chip_id != 0 {
+        if let Err(e) = restart_chip(chip_id) {
+            warn!("watchdog: unable to restart chip {chip_id}: {e}");
+        }
+    }
+}
+
+/// Runs the watchdog, if enabled by the watchdog.stall_secs or
+/// watchdog.max_queue_depth settings.
+pub fn run_watchdog() {
+    let limits = Limits {
+        max_queue_depth: get_setting_parsed("watchdog.max_queue_depth"),
+        stall: Duration::from_secs(get_setting_parsed("watchdog.stall_secs")),
+    };
+    if limits.max_queue_depth == 0 && limits.stall.is_zero() {
+        return;
+    }
+    let restart: bool = get_setting_parsed("watchdog.restart");
+    WATCHDOG.enabled.store(true, Ordering::Release);
+    loop {
+        clock().sleep(CHECK_INTERVAL);
+        for (key, stall) in WATCHDOG.check(&stats().snapshot(), now(), limits) {
+            report(key, stall, restart);
+        }
+    }
+}
+
+// Cxx Method for packet_hub to invoke
+
+/// Keeps the last packet from the emulator of a chip for the diagnostics.
+pub fn record_request(kind: u32, facade_id: u32, packet: &CxxVector<u8>) {
+    if WATCHDOG.enabled.load(Ordering::Acquire) {
+        WATCHDOG.record_request((kind, facade_id), packet.as_slice());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: (u32, u32) = (1, 7);
+    const LIMITS: Limits = Limits { max_queue_depth: 10, stall: Duration::from_secs(5) };
+
+    fn counters(queue_depth: u64, dequeued: u64) -> Vec<((u32, u32), ChipCounters)> {
+        vec![(KEY, ChipCounters { queue_depth, dequeued, ..Default::default() })]
+    }
+
+    fn at(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_stalled() {
+        let watchdog = Watchdog::default();
+        assert!(watchdog.check(&counters(2, 3), at(0), LIMITS).is_empty());
+        assert!(watchdog.check(&counters(2, 3), at(4), LIMITS).is_empty());
+        assert_eq!(watchdog.check(&counters(2, 3), at(5), LIMITS), [(KEY, Stall::Stalled)]);
+        // Reported once until the queue progresses.
+        assert!(watchdog.check(&counters(2, 3), at(6), LIMITS).is_empty());
+        assert!(watchdog.check(&counters(2, 4), at(7), LIMITS).is_empty());
+        assert_eq!(watchdog.check(&counters(1, 4), at(12), LIMITS), [(KEY, Stall::Stalled)]);
+        // An empty queue is never stalled.
+        assert!(watchdog.check(&counters(0, 4), at(20), LIMITS).is_empty());
+        assert!(watchdog.check(&counters(0, 4), at(30), LIMITS).is_empty());
+    }
+
+    #[test]
+    fn test_backed_up() {
+        let watchdog = Watchdog::default();
+        assert_eq!(watchdog.check(&counters(10, 0), at(0), LIMITS), [(KEY, Stall::BackedUp)]);
+        // A backed up queue is reported even while it progresses.
+        assert!(watchdog.check(&counters(12, 1), at(1), LIMITS).is_empty());
+        assert!(watchdog.check(&counters(3, 9), at(2), LIMITS).is_empty());
+        assert_eq!(watchdog.check(&counters(11, 10), at(3), LIMITS), [(KEY, Stall::BackedUp)]);
+        let limits = Limits { max_queue_depth: 0, stall: Duration::ZERO };
+        let watchdog = Watchdog::default();
+        assert!(watchdog.check(&counters(100, 0), at(0), limits).is_empty());
+        assert!(watchdog.check(&counters(100, 0), at(100), limits).is_empty());
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! IEEE 802.11 data frames and their mac80211_hwsim netlink messages.
+//!
+//! The Wi-Fi packets of the emulators are either bare 802.11 frames or
+//! HWSIM_CMD_FRAME generic netlink messages of mac80211_hwsim carrying the
+//! frame. Only unprotected data frames with an LLC/SNAP header, as sent on
+//! open networks, carry readable IP packets.
+
+pub type MacAddress = [u8; 6];
+
+const NLMSG_HEADER_LEN: usize = 16;
+const GENL_HEADER_LEN: usize = 4;
+const HWSIM_CMD_FRAME: u8 = 2;
+const HWSIM_ATTR_ADDR_RECEIVER: u16 = 1;
+const HWSIM_ATTR_ADDR_TRANSMITTER: u16 = 2;
+const HWSIM_ATTR_FRAME: u16 = 3;
+const HWSIM_ATTR_RX_RATE: u16 = 5;
+const HWSIM_ATTR_SIGNAL: u16 = 6;
+const HWSIM_ATTR_FREQ: u16 = 19;
+/// Signal strength of the injected frames, in dBm.
+const INJECTED_SIGNAL: i32 = -50;
+
+const DATA_HEADER_LEN: usize = 24;
+const FRAME_TYPE_DATA: u8 = 2;
+const SUBTYPE_NO_DATA: u8 = 0x4;
+const SUBTYPE_QOS: u8 = 0x8;
+const FLAG_TO_DS: u8 = 0x01;
+const FLAG_FROM_DS: u8 = 0x02;
+const FLAG_PROTECTED: u8 = 0x40;
+const FLAG_ORDER: u8 = 0x80;
+const LLC_SNAP: [u8; 6] = [0xaa, 0xaa, 0x03, 0, 0, 0];
+
+/// The mac80211_hwsim message carrying a frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hwsim<'a> {
+    /// Generic netlink family of the message.
+    pub family: u16,
+    /// Address of the radio sending the frame.
+    pub transmitter: MacAddress,
+    pub frequency: u32,
+    pub frame: &'a [u8],
+    /// Signal strength in dBm and rate index of a received frame.
+    pub signal: Option<i32>,
+    pub rx_rate: Option<u32>,
+}
+
+fn mac_address(bytes: &[u8]) -> Option<MacAddress> {
+    bytes.get(..6)?.try_into().ok()
+}
+
+/// Parses a HWSIM_CMD_FRAME message, None for bare frames.
+pub fn parse_hwsim(packet: &[u8]) -> Option<Hwsim<'_>> {
+    let len = u32::from_le_bytes(packet.get(..4)?.try_into().ok()?) as usize;
+    if len != packet.len() || packet.get(NLMSG_HEADER_LEN)? != &HWSIM_CMD_FRAME {
+        return None;
+    }
+    let family = u16::from_le_bytes(packet[4..6].try_into().ok()?);
+    let mut hwsim = Hwsim {
+        family,
+        transmitter: [0; 6],
+        frequency: 0,
+        frame: &[],
+        signal: None,
+        rx_rate: None,
+    };
+    let mut attrs = packet.get(NLMSG_HEADER_LEN + GENL_HEADER_LEN..)?;
+    while attrs.len() >= 4 {
+        let attr_len = u16::from_le_bytes([attrs[0], attrs[1]]) as usize;
+        let attr_type = u16::from_le_bytes([attrs[2], attrs[3]]);
+        let value = attrs.get(4..attr_len)?;
+        match attr_type {
+            HWSIM_ATTR_ADDR_TRANSMITTER => hwsim.transmitter = mac_address(value)?,
+            HWSIM_ATTR_FRAME => hwsim.frame = value,
+            HWSIM_ATTR_FREQ => hwsim.frequency = u32::from_le_bytes(value.try_into().ok()?),
+            HWSIM_ATTR_SIGNAL => hwsim.signal = Some(i32::from_le_bytes(value.try_into().ok()?)),
+            HWSIM_ATTR_RX_RATE => hwsim.rx_rate = Some(u32::from_le_bytes(value.try_into().ok()?)),
+            _ => {}
+        }
+        attrs = attrs.get(attr_len.next_multiple_of(4)..).unwrap_or_default();
+    }
+    Some(hwsim)
+}
+
+fn push_attr(message: &mut Vec<u8>, attr_type: u16, value: &[u8]) {
+    message.extend_from_slice(&(4 + value.len() as u16).to_le_bytes());
+    message.extend_from_slice(&attr_type.to_le_bytes());
+    message.extend_from_slice(value);
+    message.resize(message.len().next_multiple_of(4), 0);
+}
+
+/// Returns the HWSIM_CMD_FRAME message delivering a frame to a radio.
+pub fn hwsim_frame(family: u16, receiver: MacAddress, frequency: u32, frame: &[u8]) -> Vec<u8> {
+    let mut message = vec![0; NLMSG_HEADER_LEN];
+    message[4..6].copy_from_slice(&family.to_le_bytes());
+    message.extend_from_slice(&[HWSIM_CMD_FRAME, 1, 0, 0]);
+    push_attr(&mut message, HWSIM_ATTR_ADDR_RECEIVER, &receiver);
+    push_attr(&mut message, HWSIM_ATTR_FRAME, frame);
+    push_attr(&mut message, HWSIM_ATTR_RX_RATE, &1u32.to_le_bytes());
+    push_attr(&mut message, HWSIM_ATTR_SIGNAL, &INJECTED_SIGNAL.to_le_bytes());
+    push_attr(&mut message, HWSIM_ATTR_FREQ, &frequency.to_le_bytes());
+    let len = message.len() as u32;
+    message[..4].copy_from_slice(&len.to_le_bytes());
+    message
+}
+
+/// An unprotected data frame with an LLC/SNAP header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataFrame<'a> {
+    pub destination: MacAddress,
+    pub source: MacAddress,
+    pub bssid: MacAddress,
+    pub ethertype: u16,
+    pub payload: &'a [u8],
+}
+
+/// Parses an unprotected data frame; None for the other frames.
+pub fn parse_data_frame(frame: &[u8]) -> Option<DataFrame<'_>> {
+    let (control, flags) = (*frame.first()?, *frame.get(1)?);
+    let subtype = control >> 4;
+    if (control >> 2) & 0x3 != FRAME_TYPE_DATA
+        || subtype & SUBTYPE_NO_DATA != 0
+        || flags & FLAG_PROTECTED != 0
+    {
+        return None;
+    }
+    let address1 = mac_address(frame.get(4..)?)?;
+    let address2 = mac_address(frame.get(10..)?)?;
+    let address3 = mac_address(frame.get(16..)?)?;
+    let (destination, source, bssid) = match flags & (FLAG_TO_DS | FLAG_FROM_DS) {
+        0 => (address1, address2, address3),
+        FLAG_TO_DS => (address3, address2, address1),
+        FLAG_FROM_DS => (address1, address3, address2),
+        // Frames between access points are not carried.
+        _ => return None,
+    };
+    let mut header_len = DATA_HEADER_LEN;
+    if subtype & SUBTYPE_QOS != 0 {
+        header_len += 2;
+        // The order flag of QoS data frames announces an HT control field.
+        if flags & FLAG_ORDER != 0 {
+            header_len += 4;
+        }
+    }
+    let body = frame.get(header_len..)?;
+    if body.get(..LLC_SNAP.len())? != LLC_SNAP {
+        return None;
+    }
+    let ethertype = u16::from_be_bytes(body.get(6..8)?.try_into().ok()?);
+    Some(DataFrame { destination, source, bssid, ethertype, payload: &body[8..] })
+}
+
+/// Returns a data frame from the distribution system to a station.
+pub fn data_frame(data: &DataFrame) -> Vec<u8> {
+    let mut frame = vec![FRAME_TYPE_DATA << 2, FLAG_FROM_DS, 0, 0];
+    frame.extend_from_slice(&data.destination);
+    frame.extend_from_slice(&data.bssid);
+    frame.extend_from_slice(&data.source);
+    frame.extend_from_slice(&[0, 0]);
+    frame.extend_from_slice(&LLC_SNAP);
+    frame.extend_from_slice(&data.ethertype.to_be_bytes());
+    frame.extend_from_slice(data.payload);
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATION: MacAddress = [2, 0, 0, 0, 0, 1];
+    const ACCESS_POINT: MacAddress = [2, 0, 0, 0, 0, 2];
+    const HOST: MacAddress = [2, 0, 0, 0, 0, 3];
+
+    #[test]
+    fn test_data_frame() {
+        let data = DataFrame {
+            destination: STATION,
+            source: HOST,
+            bssid: ACCESS_POINT,
+            ethertype: 0x0800,
+            payload: &[0x45, 0, 0, 20],
+        };
+        let frame = data_frame(&data);
+        assert_eq!(parse_data_frame(&frame), Some(data.clone()));
+        // A QoS data frame from the station to the access point.
+        let mut qos = vec![0x88, FLAG_TO_DS, 0, 0];
+        qos.extend_from_slice(&ACCESS_POINT);
+        qos.extend_from_slice(&STATION);
+        qos.extend_from_slice(&HOST);
+        qos.extend_from_slice(&[0, 0, 0, 0]);
+        qos.extend_from_slice(&LLC_SNAP);
+        qos.extend_from_slice(&[0x86, 0xdd, 0x60]);
+        let parsed = parse_data_frame(&qos).unwrap();
+        assert_eq!(
+            (parsed.destination, parsed.source, parsed.bssid),
+            (HOST, STATION, ACCESS_POINT)
+        );
+        assert_eq!((parsed.ethertype, parsed.payload), (0x86dd, &[0x60][..]));
+        // Protected frames are not readable.
+        let mut protected = frame.clone();
+        protected[1] |= FLAG_PROTECTED;
+        assert_eq!(parse_data_frame(&protected), None);
+        // Neither are management frames.
+        assert_eq!(parse_data_frame(&[0x80, 0, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_hwsim() {
+        let frame = data_frame(&DataFrame {
+            destination: STATION,
+            source: HOST,
+            bssid: ACCESS_POINT,
+            ethertype: 0x0800,
+            payload: &[0x45],
+        });
+        let message = hwsim_frame(0x1d, STATION, 2437, &frame);
+        assert_eq!(message.len() % 4, 0);
+        let hwsim = parse_hwsim(&message).unwrap();
+        assert_eq!((hwsim.family, hwsim.frequency, hwsim.frame), (0x1d, 2437, &frame[..]));
+        assert_eq!((hwsim.signal, hwsim.rx_rate), (Some(INJECTED_SIGNAL), Some(1)));
+        assert_eq!(parse_hwsim(&frame), None);
+        assert_eq!(parse_hwsim(&message[..message.len() - 1]), None);
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Host TUN bridge of the simulated Wi-Fi network.
+//!
+//! When the `wifi.tun` setting names an interface, netsimd creates it as a
+//! TUN device and mirrors the IP packets sent by the Wi-Fi chips onto it,
+//! so host tools such as tcpdump see the simulated traffic. With
+//! `wifi.tun_mode` set to "route" the IP packets the host sends through
+//! the interface are also delivered to the chips, addressed by the source
+//! addresses learned from their packets.
+//!
+//! Creating the interface requires CAP_NET_ADMIN; bringing it up and
+//! adding its addresses and routes is left to the user. Only unprotected
+//! data frames are bridged, the traffic of encrypted networks is skipped.
+
+pub mod frame;
+pub mod tun;
+
+use cxx::CxxVector;
+use frontend_proto::common::ChipKind;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Mutex;
+
+use crate::config::get_setting;
+use crate::ffi::handle_response_cxx;
+use crate::logger::{info, warn};
+use frame::{data_frame, hwsim_frame, parse_data_frame, parse_hwsim, DataFrame, MacAddress};
+use tun::open_tun;
+
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_IPV6: u16 = 0x86dd;
+const MAX_IP_PACKET_LEN: usize = 65535;
+
+/// Returns the ethertype, source and destination of an IP packet.
+fn ip_addresses(packet: &[u8]) -> Option<(u16, IpAddr, IpAddr)> {
+    match packet.first()? >> 4 {
+        4 => {
+            let source: [u8; 4] = packet.get(12..16)?.try_into().ok()?;
+            let destination: [u8; 4] = packet.get(16..20)?.try_into().ok()?;
+            Some((
+                ETHERTYPE_IPV4,
+                Ipv4Addr::from(source).into(),
+                Ipv4Addr::from(destination).into(),
+            ))
+        }
+        6 => {
+            let source: [u8; 16] = packet.get(8..24)?.try_into().ok()?;
+            let destination: [u8; 16] = packet.get(24..40)?.try_into().ok()?;
+            Some((
+                ETHERTYPE_IPV6,
+                Ipv6Addr::from(source).into(),
+                Ipv6Addr::from(destination).into(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// The radio of a chip sending mac80211_hwsim messages.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct HwsimRadio {
+    family: u16,
+    address: MacAddress,
+    frequency: u32,
+}
+
+/// A Wi-Fi chip learned from its IP packets.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Station {
+    facade_id: u32,
+    address: MacAddress,
+    bssid: MacAddress,
+    hwsim: Option<HwsimRadio>,
+}
+
+/// The stations by IP address.
+#[derive(Default)]
+pub struct Bridge {
+    stations: Mutex<HashMap<IpAddr, Station>>,
+}
+
+impl Bridge {
+    /// Returns the IP packet of a Wi-Fi packet from a chip, learning the
+    /// chip as the station of its source address.
+    fn mirror(&self, facade_id: u32, packet: &[u8]) -> Option<Vec<u8>> {
+        let hwsim = parse_hwsim(packet);
+        let data = parse_data_frame(hwsim.as_ref().map_or(packet, |hwsim| hwsim.frame))?;
+        let (ethertype, source, _) = ip_addresses(data.payload)?;
+        if ethertype != data.ethertype {
+            return None;
+        }
+        let station = Station {
+            facade_id,
+            address: data.source,
+            bssid: data.bssid,
+            hwsim: hwsim.map(|hwsim| HwsimRadio {
+                family: hwsim.family,
+                address: hwsim.transmitter,
+                frequency: hwsim.frequency,
+            }),
+        };
+        self.stations.lock().unwrap().insert(source, station);
+        Some(data.payload.to_vec())
+    }
+
+    /// Returns the facade id and the Wi-Fi packet delivering an IP packet
+    /// from the host to its station, None for unknown destinations.
+    fn route(&self, packet: &[u8]) -> Option<(u32, Vec<u8>)> {
+        let (ethertype, _, destination) = ip_addresses(packet)?;
+        let station = *self.stations.lock().unwrap().get(&destination)?;
+        // The host sends through the distribution system of the station.
+        let frame = data_frame(&DataFrame {
+            destination: station.address,
+            source: station.bssid,
+            bssid: station.bssid,
+            ethertype,
+            payload: packet,
+        });
+        let packet = match station.hwsim {
+            Some(radio) => hwsim_frame(radio.family, radio.address, radio.frequency, &frame),
+            None => frame,
+        };
+        Some((station.facade_id, packet))
+    }
+}
+
+lazy_static! {
+    static ref BRIDGE: Bridge = Bridge::default();
+    // The TUN device receiving the mirrored packets.
+    static ref TUN: Mutex<Option<File>> = Mutex::new(None);
+}
+
+/// Runs the TUN bridge, if enabled by the wifi.tun setting.
+pub fn run_tun_bridge() {
+    let name = get_setting("wifi.tun");
+    if name.is_empty() {
+        return;
+    }
+    let tun = match open_tun(&name).and_then(|tun| Ok((tun.try_clone()?, tun))) {
+        Ok((writer, reader)) => {
+            *TUN.lock().unwrap() = Some(writer);
+            reader
+        }
+        Err(e) => {
+            warn!("unable to open TUN interface {name}: {e}");
+            return;
+        }
+    };
+    let route = get_setting("wifi.tun_mode") == "route";
+    info!(
+        "{} the Wi-Fi traffic on TUN interface {name}",
+        if route { "routing" } else { "mirroring" }
+    );
+    if !route {
+        return;
+    }
+    let mut buffer = vec![0u8; MAX_IP_PACKET_LEN];
+    loop {
+        let len = match (&tun).read(&mut buffer) {
+            Ok(len) => len,
+            Err(e) => {
+                warn!("TUN interface {name} closed: {e}");
+                return;
+            }
+        };
+        if let Some((facade_id, packet)) = BRIDGE.route(&buffer[..len]) {
+            handle_response_cxx(ChipKind::WIFI as u32, facade_id, &packet, 0);
+        }
+    }
+}
+
+// Cxx Method for packet_hub to invoke
+
+/// Mirrors the IP packet of a Wi-Fi packet from a chip on the TUN bridge.
+pub fn mirror_request(facade_id: u32, packet: &CxxVector<u8>) {
+    let mut tun = TUN.lock().unwrap();
+    let Some(tun) = tun.as_mut() else {
+        return;
+    };
+    if let Some(ip_packet) = BRIDGE.mirror(facade_id, packet.as_slice()) {
+        if let Err(e) = tun.write_all(&ip_packet) {
+            warn!("unable to mirror a Wi-Fi packet: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATION: MacAddress = [2, 0, 0, 0, 0, 1];
+    const ACCESS_POINT: MacAddress = [2, 0, 0, 0, 0, 2];
+    const RADIO: MacAddress = [2, 0, 0, 0, 1, 0];
+
+    // Returns an IPv4 header from 10.0.2.15 to 10.0.2.2.
+    fn ip_packet() -> Vec<u8> {
+        let mut packet = vec![0x45, 0, 0, 20, 0, 0, 0, 0, 64, 17, 0, 0];
+        packet.extend_from_slice(&[10, 0, 2, 15, 10, 0, 2, 2]);
+        packet
+    }
+
+    #[test]
+    fn test_bridge() {
+        let bridge = Bridge::default();
+        let ip = ip_packet();
+        let frame = data_frame(&DataFrame {
+            destination: ACCESS_POINT,
+            source: STATION,
+            bssid: ACCESS_POINT,
+            ethertype: ETHERTYPE_IPV4,
+            payload: &ip,
+        });
+        let message = hwsim_frame(0x1d, RADIO, 2437, &frame);
+        assert_eq!(bridge.mirror(7, &message), Some(ip.clone()));
+        // A reply from the host goes to the learned station.
+        let mut reply = ip.clone();
+        reply[12..20].copy_from_slice(&[10, 0, 2, 2, 10, 0, 2, 15]);
+        let (facade_id, packet) = bridge.route(&reply).unwrap();
+        assert_eq!(facade_id, 7);
+        let hwsim = parse_hwsim(&packet).unwrap();
+        assert_eq!((hwsim.family, hwsim.frequency), (0x1d, 2437));
+        let data = parse_data_frame(hwsim.frame).unwrap();
+        assert_eq!(
+            (data.destination, data.bssid, data.payload),
+            (STATION, ACCESS_POINT, &reply[..])
+        );
+        // Unknown destinations and non IP payloads are not bridged.
+        assert_eq!(bridge.route(&ip), None);
+        assert_eq!(bridge.mirror(7, &frame[..frame.len() - ip.len()]), None);
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Host TUN devices.
+
+use std::fs::File;
+use std::io;
+
+/// Creates the TUN interface `name`, or attaches to it when it exists, and
+/// returns its device carrying one IP packet per read and write.
+#[cfg(target_os = "linux")]
+pub fn open_tun(name: &str) -> io::Result<File> {
+    use std::fs::OpenOptions;
+    use std::os::fd::AsRawFd;
+
+    if name.is_empty() || name.len() >= libc::IFNAMSIZ {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid interface name"));
+    }
+    let file = OpenOptions::new().read(true).write(true).open("/dev/net/tun")?;
+    // struct ifreq: the interface name followed by the flags.
+    let mut ifreq = [0u8; 40];
+    ifreq[..name.len()].copy_from_slice(name.as_bytes());
+    let flags = (libc::IFF_TUN | libc::IFF_NO_PI) as u16;
+    ifreq[libc::IFNAMSIZ..libc::IFNAMSIZ + 2].copy_from_slice(&flags.to_ne_bytes());
+    // SAFETY: ifreq is as large as struct ifreq and outlives the call.
+    if unsafe { libc::ioctl(file.as_raw_fd(), libc::TUNSETIFF, ifreq.as_mut_ptr()) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(file)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn open_tun(_name: &str) -> io::Result<File> {
+    Err(io::Error::new(io::ErrorKind::Unsupported, "TUN interfaces require Linux"))
+}
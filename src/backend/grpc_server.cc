@@ -27,6 +27,7 @@
 #include "google/protobuf/empty.pb.h"
 #include "grpcpp/server_context.h"
 #include "grpcpp/support/status.h"
+#include "netsim-cxx/src/lib.rs.h"
 #include "packet_hub/packet_hub.h"
 #include "packet_streamer.grpc.pb.h"
 #include "packet_streamer.pb.h"
@@ -122,24 +123,26 @@ class ServiceImpl final : public packet::PacketStreamer::Service {
       if (chip_kind == common::ChipKind::BLUETOOTH) {
         if (!request.has_hci_packet()) {
           BtsLog("grpc_server: unknown packet type from %d", facade_id);
+          netsim::transport::RecordMalformed(chip_kind, facade_id);
           continue;
         }
         auto packet_type = request.hci_packet().packet_type();
         auto packet =
             ToSharedVec(request.mutable_hci_packet()->mutable_packet());
-        packet_hub::HandleRequest(chip_kind, facade_id, *packet, packet_type);
-      } else if (chip_kind == common::ChipKind::WIFI) {
+        packet_hub::HandleRequest(chip_kind, facade_id, packet, packet_type);
+      } else if (chip_kind == common::ChipKind::WIFI ||
+                 chip_kind == common::ChipKind::UWB ||
+                 chip_kind == common::ChipKind::NFC ||
+                 netsim::chip_kinds::IsCustomChipKind(chip_kind)) {
         if (!request.has_packet()) {
           BtsLog("grpc_server: unknown packet type from %d", facade_id);
+          netsim::transport::RecordMalformed(chip_kind, facade_id);
           continue;
         }
         auto packet = ToSharedVec(request.mutable_packet());
-        packet_hub::HandleRequest(chip_kind, facade_id,
-
-                                  *packet,
+        packet_hub::HandleRequest(chip_kind, facade_id, packet,
                                   packet::HCIPacket::HCI_PACKET_UNSPECIFIED);
       } else {
-        // TODO: add UWB here
         BtsLog("grpc_server: unknown chip kind");
       }
     }
@@ -45,9 +45,9 @@ std::mutex channel_mutex;
 std::shared_ptr<grpc::Channel> CreateGrpcChannel() {
   auto endpoint = custom_packet_stream_endpoint;
   if (endpoint.empty()) {
-    auto port = netsim::osutils::GetServerAddress();
-    if (!port.has_value()) return nullptr;
-    endpoint = "localhost:" + port.value();
+    auto server = netsim::osutils::GetServerAddress();
+    if (!server.has_value()) return nullptr;
+    endpoint = server.value();
   }
 
   if (endpoint.empty()) return nullptr;
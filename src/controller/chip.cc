@@ -17,6 +17,8 @@
 #include "common.pb.h"
 #include "hci/bluetooth_facade.h"
 #include "model.pb.h"
+#include "netsim-cxx/src/lib.rs.h"
+#include "nfc/nfc_facade.h"
 #include "util/log.h"
 #include "uwb/uwb_facade.h"
 #include "wifi/wifi_facade.h"
@@ -43,9 +45,18 @@ model::Chip Chip::Get() {
   } else if (kind == common::ChipKind::UWB) {
     auto radio = uwb::facade::Get(facade_id);
     model.mutable_uwb()->CopyFrom(radio);
+  } else if (kind == common::ChipKind::NFC) {
+    auto nfc = nfc::facade::Get(facade_id);
+    model.mutable_nfc()->CopyFrom(nfc);
+  } else if (chip_kinds::IsCustomChipKind(kind)) {
+    // The generic facade has no state beyond the chip.
   } else {
     BtsLog("Chip::Model - unknown chip kind");
   }
+  auto bits_per_second = processor::GetBandwidth(kind, facade_id);
+  if (bits_per_second > 0) {
+    model.mutable_bandwidth()->set_bits_per_second(bits_per_second);
+  }
   return model;
 }
 
@@ -65,6 +76,10 @@ void Chip::Patch(const model::Chip &request) {
   if (!request.product_name().empty()) {
     this->product_name = request.product_name();
   }
+  if (request.has_bandwidth()) {
+    processor::SetBandwidth(kind, facade_id,
+                            request.bandwidth().bits_per_second());
+  }
   if (kind == common::ChipKind::BLUETOOTH) {
     if (request.has_bt()) {
       hci::facade::Patch(facade_id, request.bt());
@@ -77,6 +92,12 @@ void Chip::Patch(const model::Chip &request) {
     if (request.has_uwb()) {
       uwb::facade::Patch(facade_id, request.uwb());
     }
+  } else if (kind == common::ChipKind::NFC) {
+    if (request.has_nfc()) {
+      nfc::facade::Patch(facade_id, request.nfc());
+    }
+  } else if (chip_kinds::IsCustomChipKind(kind)) {
+    // The generic facade has nothing to patch.
   } else {
     BtsLog("Chip::Patch - unknown chip kind");
   }
@@ -84,26 +105,63 @@ void Chip::Patch(const model::Chip &request) {
 
 void Chip::Remove() {
   BtsLog("Chip::Remove %d", id);
+  netsim::chip_registry::UnregisterChip(id);
+  processor::SetBandwidth(kind, facade_id, 0);
   if (kind == common::ChipKind::BLUETOOTH) {
     hci::facade::Remove(facade_id);
   } else if (kind == common::ChipKind::WIFI) {
     wifi::facade::Remove(facade_id);
   } else if (kind == common::ChipKind::UWB) {
     uwb::facade::Remove(facade_id);
+  } else if (kind == common::ChipKind::NFC) {
+    nfc::facade::Remove(facade_id);
+  } else if (chip_kinds::IsCustomChipKind(kind)) {
+    chip_kinds::RemoveGenericChip(kind, facade_id);
   } else {
     BtsLog("Chip::Remove - unknown chip kind");
   }
 }
 
+void Chip::Restart() {
+  BtsLog("Chip::Restart %d", id);
+  if (kind == common::ChipKind::BLUETOOTH) {
+    hci::facade::Restart(facade_id);
+    // The restarted controller has a new sniffer.
+    if (capture == model::State::ON) {
+      hci::facade::SetPacketCapture(facade_id, true, device_name);
+    }
+  } else if (kind == common::ChipKind::WIFI) {
+    // The Wi-Fi facade has no simulation state to rebuild.
+  } else if (kind == common::ChipKind::UWB) {
+    uwb::facade::Reset(facade_id);
+  } else if (kind == common::ChipKind::NFC) {
+    nfc::facade::Reset(facade_id);
+  } else if (chip_kinds::IsCustomChipKind(kind)) {
+    // The generic facade has no simulation state to rebuild.
+  } else {
+    BtsLog("Chip::Restart - unknown chip kind");
+  }
+  // The chip registry and the running capture of the chip follow the
+  // facade.
+  netsim::chip_registry::RegisterChip(id, static_cast<uint32_t>(kind),
+                                      facade_id);
+  netsim::pcap::UpdateCaptureFacade(id, facade_id);
+}
+
 void Chip::Reset() {
   BtsLog("Chip::Reset %d", id);
   // TODO RESET THE CHIP
+  processor::SetBandwidth(kind, facade_id, 0);
   if (kind == common::ChipKind::BLUETOOTH) {
     hci::facade::Reset(facade_id);
   } else if (kind == common::ChipKind::WIFI) {
     wifi::facade::Reset(facade_id);
   } else if (kind == common::ChipKind::UWB) {
     uwb::facade::Reset(facade_id);
+  } else if (kind == common::ChipKind::NFC) {
+    nfc::facade::Reset(facade_id);
+  } else if (chip_kinds::IsCustomChipKind(kind)) {
+    // The generic facade has nothing to reset.
   } else {
     BtsLog("Chip::Reset - unknown chip kind");
   }
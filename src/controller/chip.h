@@ -57,6 +57,12 @@ class Chip {
    */
   void Reset();
 
+  /**
+   * Rebuild the simulation state of the chip emulator in place, keeping the
+   * facade id of the chip and the transport of its emulator.
+   */
+  void Restart();
+
   /**
    * Remove resources own by the chip and remove it from the chip emulator.
    */
@@ -33,9 +33,13 @@ unsigned int PatchDevice(const std::string &request, std::string &response,
   frontend::PatchDeviceRequest request_proto;
   google::protobuf::util::JsonStringToMessage(request, &request_proto);
   google::protobuf::Empty response_proto;
+  if (!netsim::controller::IsValidDeviceMask(request_proto.update_mask())) {
+    error_message = "invalid update_mask of the device";
+    return HTTP_STATUS_BAD_REQUEST;
+  }
 
   auto status = netsim::controller::SceneController::Singleton().PatchDevice(
-      request_proto.device());
+      request_proto.device(), request_proto.update_mask());
   if (!status) {
     error_message = "device_serial not found: " + request_proto.device().name();
     return HTTP_STATUS_BAD_REQUEST;
@@ -67,16 +71,17 @@ bool GetDevicesBytes(rust::Vec<::rust::u8> &vec) {
   return true;
 }
 
-int GetFacadeId(int chip_id) {
+bool RestartChip(int chip_id) {
   for (auto &[_, device] :
        netsim::controller::SceneController::Singleton().devices_) {
     for (const auto &[_, chip] : device->chips_) {
       if (chip->id == chip_id) {
-        return chip->facade_id;
+        chip->Restart();
+        return true;
       }
     }
   }
-  return -1;
+  return false;
 }
 
 void RemoveChip(uint32_t device_id, uint32_t chip_id) {
@@ -84,6 +89,8 @@ void RemoveChip(uint32_t device_id, uint32_t chip_id) {
                                                               chip_id);
 }
 
+void Reset() { netsim::controller::SceneController::Singleton().Reset(); }
+
 std::unique_ptr<AddChipResult> AddChipCxx(const std::string &guid,
                                           const std::string &device_name,
                                           uint32_t chip_kind,
@@ -109,6 +116,11 @@ float GetDistance(uint32_t device_id, uint32_t other_device_id) {
       device_id, other_device_id);
 }
 
+model::Position GetPosition(uint32_t device_id) {
+  return netsim::controller::SceneController::Singleton().GetPosition(
+      device_id);
+}
+
 std::optional<std::chrono::seconds> GetShutdownTime() {
   return netsim::controller::SceneController::Singleton().GetShutdownTime();
 }
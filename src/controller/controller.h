@@ -19,6 +19,7 @@
 #include <string>
 
 #include "common.pb.h"
+#include "model.pb.h"
 #include "rust/cxx.h"
 
 namespace netsim::scene_controller {
@@ -34,10 +35,12 @@ unsigned int GetDevices(const std::string &request, std::string &response,
 
 bool GetDevicesBytes(rust::Vec<::rust::u8> &vec);
 
-int GetFacadeId(int chip_id);
-
 void RemoveChip(uint32_t device_id, uint32_t chip_id);
 
+bool RestartChip(int chip_id);
+
+void Reset();
+
 /// The C++ definition of AddChip response interface for CXX.
 class AddChipResult {
  public:
@@ -67,6 +70,8 @@ std::tuple<uint32_t, uint32_t, uint32_t> AddChip(
 
 float GetDistance(uint32_t, uint32_t);
 
+model::Position GetPosition(uint32_t device_id);
+
 std::optional<std::chrono::seconds> GetShutdownTime();
 
 }  // namespace netsim::scene_controller
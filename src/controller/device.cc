@@ -41,6 +41,9 @@ model::Device Device::Get() {
   model.set_visible(visible);
   model.mutable_position()->CopyFrom(position);
   model.mutable_orientation()->CopyFrom(orientation);
+  if (!group.empty()) {
+    model.set_group(group);
+  }
 
   // populate chips models
   for (auto &[_, chip] : chips_) {
@@ -59,6 +62,9 @@ void Device::Patch(const model::Device &request) {
   if (request.has_orientation()) {
     this->orientation.CopyFrom(request.orientation());
   }
+  if (request.has_group()) {
+    this->group = request.group();
+  }
   for (const auto &request_chip_model : request.chips()) {
     // TODO: use request_chip_model.kind()
     auto request_chip_kind =
@@ -109,6 +115,10 @@ std::pair<uint32_t, uint32_t> Device::AddChip(common::ChipKind chip_kind,
     facade_id = wifi::facade::Add(this->id);
   } else if (chip_kind == common::ChipKind::UWB) {
     facade_id = uwb::facade::Add(this->id);
+  } else if (chip_kind == common::ChipKind::NFC) {
+    facade_id = nfc::facade::Add(this->id);
+  } else if (chip_kinds::IsCustomChipKind(chip_kind)) {
+    facade_id = chip_kinds::AddGenericChip(chip_kind, chip_id);
   } else {
     BtsLog("Device::AdChip: unable to add chip");
     return {-1, -1};
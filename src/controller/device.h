@@ -36,6 +36,8 @@ class Device {
   bool visible;
   model::Position position;
   model::Orientation orientation;
+  // The group of the device, empty when the device is in no group.
+  std::string group;
 
   Device(uint32_t id, const std::string &guid, const std::string &name)
       : id(id), guid(guid), name(name), visible(true) {}
@@ -14,18 +14,59 @@
 
 #include "controller/scene_controller.h"
 
+#include <google/protobuf/util/field_mask_util.h>
+
 #include <chrono>
 #include <cmath>
 #include <cstddef>
 #include <optional>
+#include <string>
+#include <string_view>
+#include <vector>
 
 #include "controller/device_notify_manager.h"
+#include "netsim-cxx/src/lib.rs.h"
 #include "util/log.h"
 
 namespace netsim {
 namespace controller {
 namespace {
 constexpr std::chrono::seconds kInactiveLimitToShutdown(300);
+// Prefix of the paths of an update mask naming the fields of the chips.
+constexpr std::string_view kChipsPrefix = "chips.";
+
+// Records an event with the state of the affected devices in the event
+// history.
+void RecordEvent(events::EventType type, const std::string &device_name,
+                 uint32_t chip_id, const model::Scene &scene) {
+  std::vector<uint8_t> bytes(scene.ByteSizeLong());
+  scene.SerializeToArray(bytes.data(), bytes.size());
+  events::RecordEvent(type, device_name, chip_id,
+                      rust::Slice<const uint8_t>{bytes.data(), bytes.size()});
+}
+
+void RecordEvent(events::EventType type, Device &device,
+                 uint32_t chip_id = 0) {
+  model::Scene scene;
+  scene.add_devices()->CopyFrom(device.Get());
+  RecordEvent(type, device.name, chip_id, scene);
+}
+
+// Returns the states of the radios of a chip.
+std::vector<model::State> RadioStates(const model::Chip &chip) {
+  switch (chip.chip_case()) {
+    case model::Chip::kBt:
+      return {chip.bt().low_energy().state(), chip.bt().classic().state()};
+    case model::Chip::kUwb:
+      return {chip.uwb().state()};
+    case model::Chip::kWifi:
+      return {chip.wifi().state()};
+    case model::Chip::kNfc:
+      return {chip.nfc().radio().state()};
+    default:
+      return {};
+  }
+}
 }
 
 /* static */
@@ -50,7 +91,28 @@ std::tuple<uint32_t, uint32_t, uint32_t> SceneController::AddChip(
   // TODO: catch case of similar name chips
   auto [chip_id, facade_id] =
       device->AddChip(chip_kind, chip_name, manufacturer, product_name);
+  netsim::chip_registry::RegisterChip(chip_id, static_cast<uint32_t>(chip_kind),
+                                      facade_id);
+  session::RecordChipAdded(guid, device_name, chip_kind, chip_name,
+                           manufacturer, product_name, chip_id, facade_id);
+  external::AttachChip(device_name, chip_kind, chip_id, facade_id);
+  // The capture of the chip starts here with --capture-all, before its
+  // first packet.
+  netsim::pcap::AddCapture(static_cast<uint32_t>(chip_kind), chip_id,
+                           device->name);
   inactive_timestamp_.reset();
+  // Restore the chip configuration saved by a previous netsimd run.
+  auto patch_bytes =
+      netsim::state::SavedChipPatch(device->name, chip_kind, chip_name,
+                                    device->chips_.size() == 1);
+  model::Device patch;
+  if (!patch_bytes.empty() &&
+      patch.ParseFromArray(patch_bytes.data(), patch_bytes.size())) {
+    device->Patch(patch);
+  }
+  // Set the impairments of the scene files waiting for the chip.
+  netsim::scene_file::AddSceneChip();
+  RecordEvent(events::EventType::ChipAdded, *device, chip_id);
   return {device->id, chip_id, facade_id};
 }
 
@@ -63,6 +125,7 @@ std::shared_ptr<Device> SceneController::GetDevice(const std::string &guid,
   static uint32_t identifier = 0;
   auto device = std::make_shared<Device>(identifier, guid, name);
   devices_[identifier++] = device;
+  RecordEvent(events::EventType::DeviceAdded, *device);
   return device;
 }
 
@@ -71,6 +134,7 @@ void SceneController::RemoveDevice(uint32_t id) {
     auto device = devices_[id];
     BtsLog("SceneController::RemoveDevice - removing %s", device->name.c_str());
     device->Remove();
+    RecordEvent(events::EventType::DeviceRemoved, *device);
     devices_.erase(id);
   } else {
     BtsLog("Device not found in remove %d", id);
@@ -82,7 +146,11 @@ void SceneController::RemoveChip(uint32_t device_id, uint32_t chip_id) {
   BtsLog("Scene RemoveChip %d", chip_id);
   if (devices_.find(device_id) != devices_.end()) {
     auto device = devices_[device_id];
-    if (device->RemoveChip(chip_id)) {
+    auto no_more_chips = device->RemoveChip(chip_id);
+    session::RecordChipRemoved(chip_id);
+    external::DetachChip(chip_id);
+    RecordEvent(events::EventType::ChipRemoved, *device, chip_id);
+    if (no_more_chips) {
       BtsLog("SceneController::RemoveChip device %d, no more chips", device_id);
       this->RemoveDevice(device_id);
       if (devices_.empty())
@@ -116,16 +184,88 @@ std::shared_ptr<Device> SceneController::MatchDevice(const std::string &name) {
   return target;
 }
 
+bool IsValidDeviceMask(const google::protobuf::FieldMask &mask) {
+  using google::protobuf::util::FieldMaskUtil;
+  for (const auto &path : mask.paths()) {
+    if (path.rfind(kChipsPrefix, 0) == 0) {
+      if (!FieldMaskUtil::IsValidPath<model::Chip>(
+              path.substr(kChipsPrefix.size())))
+        return false;
+    } else if (!FieldMaskUtil::IsValidPath<model::Device>(path)) {
+      return false;
+    }
+  }
+  return true;
+}
+
+model::Device MaskDevicePatch(const model::Device &device,
+                              const model::Device &request,
+                              const google::protobuf::FieldMask &mask) {
+  using google::protobuf::util::FieldMaskUtil;
+  google::protobuf::FieldMask device_mask;
+  google::protobuf::FieldMask chip_mask;
+  bool whole_chips = false;
+  for (const auto &path : mask.paths()) {
+    if (path == "chips")
+      whole_chips = true;
+    else if (path.rfind(kChipsPrefix, 0) == 0)
+      chip_mask.add_paths(path.substr(kChipsPrefix.size()));
+    else
+      device_mask.add_paths(path);
+  }
+  // A masked message field is replaced, not merged, so the fields unset in
+  // the request are cleared like the masked scalars.
+  FieldMaskUtil::MergeOptions options;
+  options.set_replace_message_fields(true);
+  options.set_replace_repeated_fields(true);
+  model::Device patch(device);
+  patch.clear_chips();
+  FieldMaskUtil::MergeMessageTo(request, device_mask, options, &patch);
+  if (!whole_chips && chip_mask.paths().empty()) return patch;
+  for (const auto &request_chip : request.chips()) {
+    auto *chip = patch.add_chips();
+    if (whole_chips) {
+      chip->CopyFrom(request_chip);
+      continue;
+    }
+    // The chips patch only the fields set in the patch.
+    chip->set_kind(request_chip.kind());
+    chip->set_name(request_chip.name());
+    FieldMaskUtil::MergeMessageTo(request_chip, chip_mask, options, chip);
+  }
+  return patch;
+}
+
 // UI requesting a change in device info
-bool SceneController::PatchDevice(const model::Device &request) {
+bool SceneController::PatchDevice(const model::Device &request,
+                                  const google::protobuf::FieldMask &mask) {
   std::unique_lock<std::mutex> lock(this->mutex_);
   if (request.name().empty()) {
     return false;
   }
   auto device = MatchDevice(request.name());
   if (device == nullptr) return false;
-  device->Patch(request);
+  auto before = device->Get();
+  // Masked under the lock, the other fields keep the values of the
+  // concurrent patches.
+  auto patch =
+      mask.paths().empty() ? request : MaskDevicePatch(before, request, mask);
+  std::vector<uint8_t> bytes(patch.ByteSizeLong());
+  patch.SerializeToArray(bytes.data(), bytes.size());
+  session::RecordDevicePatched(
+      rust::Slice<const uint8_t>{bytes.data(), bytes.size()});
+  device->Patch(patch);
+  RecordEvent(events::EventType::DevicePatched, *device);
+  // The chips of the device are in the same order before and after.
+  auto after = device->Get();
+  for (int i = 0; i < after.chips_size() && i < before.chips_size(); i++) {
+    if (RadioStates(before.chips(i)) != RadioStates(after.chips(i)))
+      RecordEvent(events::EventType::ChipStateChanged, *device,
+                  after.chips(i).id());
+  }
   DeviceNotifyManager::Get().Notify();
+  lock.unlock();
+  netsim::state::SaveState();
   return true;
 }
 
@@ -142,12 +282,26 @@ float SceneController::GetDistance(uint32_t id, uint32_t other_id) {
       (pow(a.x() - b.x(), 2) + pow(a.y() - b.y(), 2) + pow(a.z() - b.z(), 2)));
 }
 
+model::Position SceneController::GetPosition(uint32_t device_id) {
+  if (devices_.find(device_id) == devices_.end()) {
+    BtsLog("Error in GetPosition %d", device_id);
+    return {};
+  }
+  return devices_[device_id]->position;
+}
+
 void SceneController::Reset() {
   std::unique_lock<std::mutex> lock(this->mutex_);
+  session::RecordReset();
+  mobility::ResetMotions();
+  processor::ResetImpairments();
   for (auto &[_, device] : devices_) {
     device->Reset();
   }
+  RecordEvent(events::EventType::Reset, "", 0, Get());
   DeviceNotifyManager::Get().Notify();
+  lock.unlock();
+  netsim::state::SaveState();
 }
 
 std::optional<std::chrono::seconds> SceneController::GetShutdownTime() {
@@ -22,6 +22,7 @@
 
 #include "common.pb.h"
 #include "controller/device.h"
+#include "google/protobuf/field_mask.pb.h"
 #include "model.pb.h"
 
 namespace netsim {
@@ -46,10 +47,15 @@ class SceneController {
       const std::string &manufacturer = "",
       const std::string &product_name = "");
 
-  bool PatchDevice(const model::Device &);
+  // Patches the device of the request with the fields of the mask, or as
+  // a whole without a mask.
+  bool PatchDevice(const model::Device &,
+                   const google::protobuf::FieldMask &mask = {});
 
   float GetDistance(uint32_t, uint32_t);
 
+  model::Position GetPosition(uint32_t device_id);
+
   model::Scene Get();
 
   void Reset();
@@ -76,5 +82,17 @@ class SceneController {
       inactive_timestamp_{std::chrono::system_clock::now()};
 };
 
+// Whether the paths of an update mask name fields of a Device, or fields of
+// a Chip after "chips.".
+bool IsValidDeviceMask(const google::protobuf::FieldMask &mask);
+
+// Returns the patch of a device with the fields of the mask from the
+// request and the values of the device for the others. The chips of the
+// request patch the fields of the mask after "chips.", or all their fields
+// with "chips".
+model::Device MaskDevicePatch(const model::Device &device,
+                              const model::Device &request,
+                              const google::protobuf::FieldMask &mask);
+
 }  // namespace controller
 }  // namespace netsim
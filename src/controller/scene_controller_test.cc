@@ -40,6 +40,51 @@ TEST_F(SceneControllerTest, GetTest) {
   EXPECT_EQ(size, 0);
 }
 
+TEST_F(SceneControllerTest, MaskDevicePatchTest) {
+  model::Device device;
+  device.set_name("device");
+  device.set_visible(false);
+  device.mutable_position()->set_x(1.0);
+  device.mutable_position()->set_y(2.0);
+  model::Device request;
+  request.set_name("device");
+  request.set_visible(true);
+  request.mutable_position()->set_x(10.0);
+  auto *chip = request.add_chips();
+  chip->set_name("chip");
+  chip->set_capture(model::State::ON);
+  chip->mutable_bt()->mutable_low_energy()->set_range(5.0);
+  google::protobuf::FieldMask mask;
+  mask.add_paths("position.x");
+  mask.add_paths("chips.bt.low_energy.range");
+
+  auto patch = MaskDevicePatch(device, request, mask);
+  // The fields out of the mask keep the values of the device.
+  EXPECT_FALSE(patch.visible());
+  EXPECT_EQ(patch.position().x(), 10.0);
+  EXPECT_EQ(patch.position().y(), 2.0);
+  ASSERT_EQ(patch.chips_size(), 1);
+  EXPECT_EQ(patch.chips(0).name(), "chip");
+  EXPECT_EQ(patch.chips(0).capture(), model::State::UNKNOWN);
+  EXPECT_EQ(patch.chips(0).bt().low_energy().range(), 5.0);
+
+  // A masked message is replaced as a whole.
+  mask.Clear();
+  mask.add_paths("visible");
+  mask.add_paths("position");
+  patch = MaskDevicePatch(device, request, mask);
+  EXPECT_TRUE(patch.visible());
+  EXPECT_EQ(patch.position().y(), 0.0);
+  EXPECT_EQ(patch.chips_size(), 0);
+  mask.add_paths("chips");
+  EXPECT_EQ(MaskDevicePatch(device, request, mask).chips(0).capture(),
+            model::State::ON);
+
+  EXPECT_TRUE(IsValidDeviceMask(mask));
+  mask.add_paths("chips.bt.low_energy.rangee");
+  EXPECT_FALSE(IsValidDeviceMask(mask));
+}
+
 #ifdef NETSIM_ANDROID_EMULATOR
 TEST_F(SceneControllerTest, AddChipTest) {
   auto guid = "guid-SceneControllerTest-AddChipTest";
@@ -0,0 +1,78 @@
+// Copyright 2023 The Android Open Source Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "core/propagation_client.h"
+
+#include <chrono>
+#include <memory>
+#include <mutex>
+#include <string>
+#include <unordered_map>
+
+#include "frontend.grpc.pb.h"
+#include "frontend.pb.h"
+#include "grpcpp/client_context.h"
+#include "grpcpp/create_channel.h"
+#include "grpcpp/security/credentials.h"
+#include "netsim-cxx/src/lib.rs.h"
+
+namespace netsim::propagation {
+namespace {
+
+using Stub = frontend::PropagationModelService::Stub;
+
+// Time allowed to an external model, which delays the packet of the link.
+constexpr std::chrono::milliseconds kComputeTimeout(100);
+
+std::mutex stubs_mutex;
+std::unordered_map<std::string, std::unique_ptr<Stub>> stubs;
+
+Stub *GetStub(const std::string &address) {
+  std::lock_guard<std::mutex> lock(stubs_mutex);
+  auto &stub = stubs[address];
+  if (!stub) {
+    stub = frontend::PropagationModelService::NewStub(
+        grpc::CreateChannel(address, grpc::InsecureChannelCredentials()));
+  }
+  return stub.get();
+}
+
+void SetPosition(const Point &point, model::Position *position) {
+  position->set_x(point.x);
+  position->set_y(point.y);
+  position->set_z(point.z);
+}
+
+}  // namespace
+
+bool ComputeExternalPathLoss(rust::Str address, const RadioLink &link,
+                             float &path_loss) {
+  if (address.empty()) return false;
+  frontend::ComputePathLossRequest request;
+  request.set_chip_kind(static_cast<common::ChipKind>(link.chip_kind));
+  request.set_sender_id(link.sender_id);
+  request.set_receiver_id(link.receiver_id);
+  SetPosition(link.sender, request.mutable_sender_position());
+  SetPosition(link.receiver, request.mutable_receiver_position());
+  grpc::ClientContext context;
+  context.set_deadline(std::chrono::system_clock::now() + kComputeTimeout);
+  frontend::ComputePathLossResponse response;
+  auto status = GetStub(std::string(address))
+                    ->ComputePathLoss(&context, request, &response);
+  if (!status.ok()) return false;
+  path_loss = response.path_loss();
+  return true;
+}
+
+}  // namespace netsim::propagation
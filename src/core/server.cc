@@ -19,6 +19,8 @@
 #include <optional>
 #include <string>
 #include <thread>
+#include <utility>
+#include <vector>
 
 #ifdef NETSIM_ANDROID_EMULATOR
 #include "backend/grpc_server.h"
@@ -47,24 +49,46 @@ constexpr std::chrono::seconds InactivityCheckInterval(5);
 std::unique_ptr<grpc::Server> RunGrpcServer(int netsim_grpc_port) {
   grpc::ServerBuilder builder;
   int selected_port;
-  builder.AddListeningPort("0.0.0.0:" + std::to_string(netsim_grpc_port),
+  // Setting "grpc.host" selects IPv4 ("0.0.0.0") or IPv6 ("::") listeners.
+  auto host = std::string(netsim::config::GetSetting("grpc.host"));
+  builder.AddListeningPort(osutils::FormatAddress(host, netsim_grpc_port),
                            grpc::InsecureServerCredentials(), &selected_port);
+  // The grpc defaults apply to the message sizes set to 0.
+  auto max_send_message_bytes = std::stoi(
+      std::string(netsim::config::GetSetting("grpc.max_send_message_bytes")));
+  if (max_send_message_bytes != 0) {
+    builder.SetMaxSendMessageSize(max_send_message_bytes);
+  }
+  auto max_receive_message_bytes = std::stoi(std::string(
+      netsim::config::GetSetting("grpc.max_receive_message_bytes")));
+  if (max_receive_message_bytes != 0) {
+    builder.SetMaxReceiveMessageSize(max_receive_message_bytes);
+  }
   static auto frontend_service = GetFrontendService();
   builder.RegisterService(frontend_service.get());
+  std::vector<
+      std::unique_ptr<grpc::experimental::ServerInterceptorFactoryInterface>>
+      interceptor_factories;
+  interceptor_factories.push_back(GetFrontendInterceptorFactory());
+  builder.experimental().SetInterceptorCreators(
+      std::move(interceptor_factories));
 #ifdef NETSIM_ANDROID_EMULATOR
   static auto backend_service = GetBackendService();
   builder.RegisterService(backend_service.get());
 #endif
   std::unique_ptr<grpc::Server> server(builder.BuildAndStart());
 
-  BtsLog("Grpc server listening on localhost: %s",
-         std::to_string(selected_port).c_str());
+  auto address =
+      osutils::FormatAddress(osutils::GetConnectHost(host), selected_port);
+  BtsLog("Grpc server listening on %s", address.c_str());
 
-  // Writes grpc port to ini file.
-  auto filepath = osutils::GetNetsimIniFilepath();
+  // Writes grpc port and address to ini file.
+  auto filepath = osutils::GetNetsimIniFilepath(
+      std::string(netsim::config::GetSetting("instance")));
   IniFile iniFile(filepath);
   iniFile.Read();
   iniFile.Set("grpc.port", std::to_string(selected_port));
+  iniFile.Set("grpc.address", address);
   iniFile.Write();
 
   return std::move(server);
@@ -77,22 +101,44 @@ void Run() {
     BtsLog("netsim generated pcap files in temp directory has been removed.");
   }
 
-  // Environment variable "NETSIM_GRPC_PORT" is set in google3 forge. If set:
+  // Setting "grpc.port" (environment variable "NETSIM_GRPC_PORT") is set in
+  // google3 forge. If set:
   // 1. Use the fixed port for grpc server.
   // 2. Don't start http server.
-  auto netsim_grpc_port = std::stoi(osutils::GetEnv("NETSIM_GRPC_PORT", "0"));
+  auto netsim_grpc_port =
+      std::stoi(std::string(netsim::config::GetSetting("grpc.port")));
+  // Generate the frontend token before serving the first call.
+  netsim::auth::InitToken();
   // Run frontend and backend grpc servers.
   auto grpc_server = RunGrpcServer(netsim_grpc_port);
   if (netsim_grpc_port == 0) {
     // Run frontend http server.
     std::thread(RunHttpServer).detach();
   }
+  // Watch the chips for stalled queues.
+  std::thread(netsim::watchdog::RunWatchdog).detach();
+  // Move the devices along their paths.
+  std::thread(netsim::mobility::RunMobility).detach();
+  // Forward the packets delayed by the link impairments.
+  std::thread(netsim::processor::RunDelays).detach();
+  // Range the active UWB sessions.
+  std::thread(netsim::uwb::RunRanging).detach();
+  // Activate the NFC chips brought together.
+  std::thread(netsim::nfc::RunField).detach();
+  // Export the scene snapshots.
+  std::thread(netsim::scene_file::RunSnapshots).detach();
+  // Bridge the Wi-Fi traffic to the host TUN interface.
+  std::thread(netsim::wifi::RunTunBridge).detach();
+  // Accept the trunks of other netsimd instances.
+  std::thread(netsim::federation::RunFederation).detach();
 
   while (true) {
     std::this_thread::sleep_for(InactivityCheckInterval);
     if (auto seconds_to_shutdown = netsim::scene_controller::GetShutdownTime();
         seconds_to_shutdown.has_value() &&
         seconds_to_shutdown.value() < std::chrono::seconds(0)) {
+      netsim::state::SaveState();
+      netsim::pcap::ShutdownCaptures();
       grpc_server->Shutdown();
       BtsLog("Netsim has been shutdown due to inactivity.");
       break;
@@ -21,6 +21,7 @@
 
 #include <chrono>
 #include <cstdint>
+#include <fstream>
 #include <iomanip>
 #include <iostream>
 #include <iterator>
@@ -48,13 +49,15 @@ namespace {
 const std::chrono::duration kConnectionDeadline = std::chrono::seconds(1);
 
 std::unique_ptr<frontend::FrontendService::Stub> NewFrontendStub() {
-  auto port = netsim::osutils::GetServerAddress();
-  if (!port.has_value()) {
+  auto server = netsim::osutils::GetServerAddress();
+  if (!server.has_value()) {
     return {};
   }
-  auto server = "localhost:" + port.value();
-  std::shared_ptr<grpc::Channel> channel =
-      grpc::CreateChannel(server, grpc::InsecureChannelCredentials());
+  // The responses of large scenes may exceed the default limit.
+  grpc::ChannelArguments arguments;
+  arguments.SetMaxReceiveMessageSize(-1);
+  std::shared_ptr<grpc::Channel> channel = grpc::CreateCustomChannel(
+      server.value(), grpc::InsecureChannelCredentials(), arguments);
 
   auto deadline = std::chrono::system_clock::now() + kConnectionDeadline;
   if (!channel->WaitForConnected(deadline)) {
@@ -64,6 +67,19 @@ std::unique_ptr<frontend::FrontendService::Stub> NewFrontendStub() {
   return frontend::FrontendService::NewStub(channel);
 }
 
+// Adds the token of the NETSIM_TOKEN environment variable, or else of the
+// token file of the instance, to a call for instances with auth enabled.
+void AddToken(grpc::ClientContext &context) {
+  auto token = netsim::osutils::GetEnv("NETSIM_TOKEN", "");
+  if (token.empty()) {
+    std::ifstream file(netsim::osutils::GetNetsimTokenFilepath());
+    std::getline(file, token);
+  }
+  if (!token.empty()) {
+    context.AddMetadata("authorization", "Bearer " + token);
+  }
+}
+
 // A synchronous client for the netsim frontend service.
 class FrontendClientImpl : public FrontendClient {
  public:
@@ -82,34 +98,98 @@ class FrontendClientImpl : public FrontendClient {
     return std::make_unique<ClientResult>(true, "", message_vec);
   }
 
+  // Passes the bytes of every chunk of a capture stream to the
+  // ClientResponseReader until the stream ends.
+  std::unique_ptr<ClientResult> ReadCaptureStream(
+      grpc::ClientReaderInterface<frontend::GetCaptureResponse> &reader,
+      ClientResponseReader const &client_reader) const {
+    frontend::GetCaptureResponse chunk;
+    // Read every available chunks from grpc reader
+    while (reader.Read(&chunk)) {
+      // Using a mutable protobuf here so the move iterator can move
+      // the capture stream without copying.
+      auto mut_stream = chunk.mutable_capture_stream();
+      auto bytes =
+          std::vector<uint8_t>(std::make_move_iterator(mut_stream->begin()),
+                               std::make_move_iterator(mut_stream->end()));
+      client_reader.handle_chunk(
+          rust::Slice<const uint8_t>{bytes.data(), bytes.size()});
+    }
+    auto status = reader.Finish();
+    return make_result(status, google::protobuf::Empty());
+  }
+
   // Gets the version of the network simulator service.
   std::unique_ptr<ClientResult> GetVersion() const override {
     frontend::VersionResponse response;
     grpc::ClientContext context_;
+    AddToken(context_);
     auto status = stub_->GetVersion(&context_, {}, &response);
     return make_result(status, response);
   }
 
-  // Gets the list of device information
-  std::unique_ptr<ClientResult> GetDevices() const override {
+  // Gets the list of device information, streamed one device at a time
+  // unless a page is requested
+  std::unique_ptr<ClientResult> GetDevices(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
     frontend::GetDevicesResponse response;
     grpc::ClientContext context_;
-    auto status = stub_->GetDevices(&context_, {}, &response);
+    AddToken(context_);
+    frontend::GetDevicesRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing GetDevices request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    if (request.page_size() > 0) {
+      auto status = stub_->GetDevices(&context_, request, &response);
+      return make_result(status, response);
+    }
+    auto reader = stub_->ListDeviceStream(&context_, request);
+    model::Device device;
+    while (reader->Read(&device)) response.add_devices()->Swap(&device);
+    auto status = reader->Finish();
     return make_result(status, response);
   }
 
   std::unique_ptr<ClientResult> Reset() const override {
     grpc::ClientContext context_;
+    AddToken(context_);
     google::protobuf::Empty response;
     auto status = stub_->Reset(&context_, {}, &response);
     return make_result(status, response);
   }
 
+  // Restart the facade of a chip in place
+  std::unique_ptr<ClientResult> RestartChip(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    frontend::RestartChipResponse response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::RestartChipRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing RestartChip request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->RestartChip(&context_, request, &response);
+    return make_result(status, response);
+  }
+
   // Patchs the information of the device
   std::unique_ptr<ClientResult> PatchDevice(
       rust::Vec<::rust::u8> const &request_byte_vec) const override {
     google::protobuf::Empty response;
     grpc::ClientContext context_;
+    AddToken(context_);
     frontend::PatchDeviceRequest request;
     if (!request.ParseFromArray(request_byte_vec.data(),
                                 request_byte_vec.size())) {
@@ -124,11 +204,31 @@ class FrontendClientImpl : public FrontendClient {
     return make_result(status, response);
   }
 
-  // Get the list of Capture information
-  std::unique_ptr<ClientResult> ListCapture() const override {
+  // Get the list of Capture information, streamed one capture at a time
+  // unless a page is requested
+  std::unique_ptr<ClientResult> ListCapture(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
     frontend::ListCaptureResponse response;
     grpc::ClientContext context_;
-    auto status = stub_->ListCapture(&context_, {}, &response);
+    AddToken(context_);
+    frontend::ListCaptureRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing ListCapture request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    if (request.page_size() > 0) {
+      auto status = stub_->ListCapture(&context_, request, &response);
+      return make_result(status, response);
+    }
+    auto reader = stub_->ListCaptureStream(&context_, request);
+    model::Capture capture;
+    while (reader->Read(&capture)) response.add_captures()->Swap(&capture);
+    auto status = reader->Finish();
     return make_result(status, response);
   }
 
@@ -137,6 +237,7 @@ class FrontendClientImpl : public FrontendClient {
       rust::Vec<::rust::u8> const &request_byte_vec) const override {
     google::protobuf::Empty response;
     grpc::ClientContext context_;
+    AddToken(context_);
     frontend::PatchCaptureRequest request;
     if (!request.ParseFromArray(request_byte_vec.data(),
                                 request_byte_vec.size())) {
@@ -157,6 +258,7 @@ class FrontendClientImpl : public FrontendClient {
       rust::Vec<::rust::u8> const &request_byte_vec,
       ClientResponseReader const &client_reader) const override {
     grpc::ClientContext context_;
+    AddToken(context_);
     frontend::GetCaptureRequest request;
     if (!request.ParseFromArray(request_byte_vec.data(),
                                 request_byte_vec.size())) {
@@ -168,22 +270,352 @@ class FrontendClientImpl : public FrontendClient {
           google::protobuf::Empty());
     };
     auto reader = stub_->GetCapture(&context_, request);
-    frontend::GetCaptureResponse chunk;
-    // Read every available chunks from grpc reader
-    while (reader->Read(&chunk)) {
-      // Using a mutable protobuf here so the move iterator can move
-      // the capture stream without copying.
-      auto mut_stream = chunk.mutable_capture_stream();
-      auto bytes =
-          std::vector<uint8_t>(std::make_move_iterator(mut_stream->begin()),
-                               std::make_move_iterator(mut_stream->end()));
-      client_reader.handle_chunk(
-          rust::Slice<const uint8_t>{bytes.data(), bytes.size()});
+    return ReadCaptureStream(*reader, client_reader);
+  }
+
+  // Stream the records of a running capture as they are recorded, after
+  // the header of its format, until the stream ends
+  std::unique_ptr<ClientResult> StreamCapture(
+      rust::Vec<::rust::u8> const &request_byte_vec,
+      ClientResponseReader const &client_reader) const override {
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::StreamCaptureRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing StreamCapture request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          google::protobuf::Empty());
+    };
+    auto reader = stub_->StreamCapture(&context_, request);
+    return ReadCaptureStream(*reader, client_reader);
+  }
+
+  // Advertise from a builtin beacon until the stream ends, passing the
+  // beacon to the ClientResponseReader
+  std::unique_ptr<ClientResult> Advertise(
+      rust::Vec<::rust::u8> const &request_byte_vec,
+      ClientResponseReader const &client_reader) const override {
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::AdvertiseRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing Advertise request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          google::protobuf::Empty());
+    };
+    auto reader = stub_->Advertise(&context_, request);
+    frontend::Beacon beacon;
+    while (reader->Read(&beacon)) {
+      auto bytes = beacon.SerializeAsString();
+      client_reader.handle_chunk(rust::Slice<const uint8_t>{
+          reinterpret_cast<const uint8_t *>(bytes.data()), bytes.size()});
     }
     auto status = reader->Finish();
     return make_result(status, google::protobuf::Empty());
   }
 
+  // Stream the events selected by a request until the stream ends, passing
+  // each event to the ClientResponseReader
+  std::unique_ptr<ClientResult> SubscribeEvents(
+      rust::Vec<::rust::u8> const &request_byte_vec,
+      ClientResponseReader const &client_reader) const override {
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::SubscribeEventsRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing SubscribeEvents request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          google::protobuf::Empty());
+    };
+    auto reader = stub_->SubscribeEvents(&context_, request);
+    frontend::Event event;
+    while (reader->Read(&event)) {
+      auto bytes = event.SerializeAsString();
+      client_reader.handle_chunk(rust::Slice<const uint8_t>{
+          reinterpret_cast<const uint8_t *>(bytes.data()), bytes.size()});
+    }
+    auto status = reader->Finish();
+    return make_result(status, google::protobuf::Empty());
+  }
+
+  // Run a fuzzer input through the netsimd parsers
+  std::unique_ptr<ClientResult> ReplayFuzzInput(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    frontend::ReplayFuzzInputResponse response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::ReplayFuzzInputRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing ReplayFuzzInput request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->ReplayFuzzInput(&context_, request, &response);
+    return make_result(status, response);
+  }
+
+  // Get the transport statistics of each chip
+  std::unique_ptr<ClientResult> GetChipTransportStats() const override {
+    frontend::GetChipTransportStatsResponse response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    auto status = stub_->GetChipTransportStats(&context_, {}, &response);
+    return make_result(status, response);
+  }
+
+  // Create a bug report archive on the host of netsimd
+  std::unique_ptr<ClientResult> CreateBugReport() const override {
+    frontend::CreateBugReportResponse response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    auto status = stub_->CreateBugReport(&context_, {}, &response);
+    return make_result(status, response);
+  }
+
+  // Pause the packet delivery of all chips
+  std::unique_ptr<ClientResult> PauseDelivery() const override {
+    frontend::DeliveryState response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    auto status = stub_->PauseDelivery(&context_, {}, &response);
+    return make_result(status, response);
+  }
+
+  // Resume the paused packet delivery
+  std::unique_ptr<ClientResult> ResumeDelivery() const override {
+    frontend::DeliveryState response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    auto status = stub_->ResumeDelivery(&context_, {}, &response);
+    return make_result(status, response);
+  }
+
+  // Deliver the next held packets while the delivery is paused
+  std::unique_ptr<ClientResult> StepDelivery(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    frontend::DeliveryState response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::StepDeliveryRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing StepDelivery request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->StepDelivery(&context_, request, &response);
+    return make_result(status, response);
+  }
+
+  // Get the state of the packet delivery
+  std::unique_ptr<ClientResult> GetDeliveryState() const override {
+    frontend::DeliveryState response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    auto status = stub_->GetDeliveryState(&context_, {}, &response);
+    return make_result(status, response);
+  }
+
+  // Replay a recorded capture file to a Bluetooth chip
+  std::unique_ptr<ClientResult> ReplayCapture(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    frontend::ReplayCaptureResponse response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::ReplayCaptureRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing ReplayCapture request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->ReplayCapture(&context_, request, &response);
+    return make_result(status, response);
+  }
+
+  // Move a device along a path of waypoints
+  std::unique_ptr<ClientResult> SetDeviceMotion(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    google::protobuf::Empty response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::SetDeviceMotionRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing SetDeviceMotion request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->SetDeviceMotion(&context_, request, &response);
+    return make_result(status, response);
+  }
+
+  // Drop a percentage of the packets of a chip or of a link
+  std::unique_ptr<ClientResult> SetLinkImpairment(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    google::protobuf::Empty response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::SetLinkImpairmentRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing SetLinkImpairment request protobuf. request "
+              "size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->SetLinkImpairment(&context_, request, &response);
+    return make_result(status, response);
+  }
+
+  // Patch the devices of a group at once
+  std::unique_ptr<ClientResult> PatchGroup(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    frontend::PatchGroupResponse response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::PatchGroupRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing PatchGroup request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->PatchGroup(&context_, request, &response);
+    return make_result(status, response);
+  }
+
+  // Patch the chips matched by a selector at once
+  std::unique_ptr<ClientResult> PatchSelection(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    frontend::PatchSelectionResponse response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::PatchSelectionRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing PatchSelection request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->PatchSelection(&context_, request, &response);
+    return make_result(status, response);
+  }
+
+  // Load a scene file into the running scene
+  std::unique_ptr<ClientResult> LoadScenario(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    frontend::LoadScenarioResponse response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::LoadScenarioRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing LoadScenario request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->LoadScenario(&context_, request, &response);
+    return make_result(status, response);
+  }
+
+  // Save the simulation to a scene file
+  std::unique_ptr<ClientResult> SaveState(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    frontend::SaveStateResponse response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::SaveStateRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing SaveState request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->SaveState(&context_, request, &response);
+    return make_result(status, response);
+  }
+
+  // Restore the simulation saved to a scene file
+  std::unique_ptr<ClientResult> RestoreState(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    frontend::LoadScenarioResponse response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::RestoreStateRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing RestoreState request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->RestoreState(&context_, request, &response);
+    return make_result(status, response);
+  }
+
+  // List the events of the event history
+  std::unique_ptr<ClientResult> ListEvents(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
+    frontend::ListEventsResponse response;
+    grpc::ClientContext context_;
+    AddToken(context_);
+    frontend::ListEventsRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing ListEvents request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->ListEvents(&context_, request, &response);
+    return make_result(status, response);
+  }
+
   // Helper function to redirect to the correct Grpc call
   std::unique_ptr<ClientResult> SendGrpc(
       frontend::GrpcMethod const &grpc_method,
@@ -194,13 +626,47 @@ class FrontendClientImpl : public FrontendClient {
       case frontend::GrpcMethod::PatchDevice:
         return PatchDevice(request_byte_vec);
       case frontend::GrpcMethod::GetDevices:
-        return GetDevices();
+        return GetDevices(request_byte_vec);
       case frontend::GrpcMethod::Reset:
         return Reset();
+      case frontend::GrpcMethod::RestartChip:
+        return RestartChip(request_byte_vec);
       case frontend::GrpcMethod::ListCapture:
-        return ListCapture();
+        return ListCapture(request_byte_vec);
       case frontend::GrpcMethod::PatchCapture:
         return PatchCapture(request_byte_vec);
+      case frontend::GrpcMethod::ReplayFuzzInput:
+        return ReplayFuzzInput(request_byte_vec);
+      case frontend::GrpcMethod::GetChipTransportStats:
+        return GetChipTransportStats();
+      case frontend::GrpcMethod::CreateBugReport:
+        return CreateBugReport();
+      case frontend::GrpcMethod::PauseDelivery:
+        return PauseDelivery();
+      case frontend::GrpcMethod::ResumeDelivery:
+        return ResumeDelivery();
+      case frontend::GrpcMethod::StepDelivery:
+        return StepDelivery(request_byte_vec);
+      case frontend::GrpcMethod::GetDeliveryState:
+        return GetDeliveryState();
+      case frontend::GrpcMethod::ReplayCapture:
+        return ReplayCapture(request_byte_vec);
+      case frontend::GrpcMethod::SetDeviceMotion:
+        return SetDeviceMotion(request_byte_vec);
+      case frontend::GrpcMethod::SetLinkImpairment:
+        return SetLinkImpairment(request_byte_vec);
+      case frontend::GrpcMethod::PatchGroup:
+        return PatchGroup(request_byte_vec);
+      case frontend::GrpcMethod::PatchSelection:
+        return PatchSelection(request_byte_vec);
+      case frontend::GrpcMethod::LoadScenario:
+        return LoadScenario(request_byte_vec);
+      case frontend::GrpcMethod::SaveState:
+        return SaveState(request_byte_vec);
+      case frontend::GrpcMethod::RestoreState:
+        return RestoreState(request_byte_vec);
+      case frontend::GrpcMethod::ListEvents:
+        return ListEvents(request_byte_vec);
       default:
         return make_result(grpc::Status(grpc::StatusCode::INVALID_ARGUMENT,
                                         "Unknown GrpcMethod found."),
@@ -53,16 +53,56 @@ class FrontendClient {
       frontend::GrpcMethod const &grpc_method,
       rust::Vec<rust::u8> const &request_byte_vec) const = 0;
   virtual std::unique_ptr<ClientResult> GetVersion() const = 0;
-  virtual std::unique_ptr<ClientResult> GetDevices() const = 0;
+  virtual std::unique_ptr<ClientResult> GetDevices(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
   virtual std::unique_ptr<ClientResult> PatchDevice(
       rust::Vec<rust::u8> const &request_byte_vec) const = 0;
   virtual std::unique_ptr<ClientResult> Reset() const = 0;
-  virtual std::unique_ptr<ClientResult> ListCapture() const = 0;
+  virtual std::unique_ptr<ClientResult> RestartChip(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
+  virtual std::unique_ptr<ClientResult> ListCapture(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
   virtual std::unique_ptr<ClientResult> PatchCapture(
       rust::Vec<rust::u8> const &request_byte_vec) const = 0;
   virtual std::unique_ptr<ClientResult> GetCapture(
       rust::Vec<::rust::u8> const &request_byte_vec,
       ClientResponseReader const &client_reader) const = 0;
+  virtual std::unique_ptr<ClientResult> StreamCapture(
+      rust::Vec<::rust::u8> const &request_byte_vec,
+      ClientResponseReader const &client_reader) const = 0;
+  virtual std::unique_ptr<ClientResult> Advertise(
+      rust::Vec<::rust::u8> const &request_byte_vec,
+      ClientResponseReader const &client_reader) const = 0;
+  virtual std::unique_ptr<ClientResult> SubscribeEvents(
+      rust::Vec<::rust::u8> const &request_byte_vec,
+      ClientResponseReader const &client_reader) const = 0;
+  virtual std::unique_ptr<ClientResult> ReplayFuzzInput(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
+  virtual std::unique_ptr<ClientResult> GetChipTransportStats() const = 0;
+  virtual std::unique_ptr<ClientResult> CreateBugReport() const = 0;
+  virtual std::unique_ptr<ClientResult> PauseDelivery() const = 0;
+  virtual std::unique_ptr<ClientResult> ResumeDelivery() const = 0;
+  virtual std::unique_ptr<ClientResult> StepDelivery(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
+  virtual std::unique_ptr<ClientResult> GetDeliveryState() const = 0;
+  virtual std::unique_ptr<ClientResult> ReplayCapture(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
+  virtual std::unique_ptr<ClientResult> SetDeviceMotion(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
+  virtual std::unique_ptr<ClientResult> SetLinkImpairment(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
+  virtual std::unique_ptr<ClientResult> PatchGroup(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
+  virtual std::unique_ptr<ClientResult> PatchSelection(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
+  virtual std::unique_ptr<ClientResult> LoadScenario(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
+  virtual std::unique_ptr<ClientResult> SaveState(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
+  virtual std::unique_ptr<ClientResult> RestoreState(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
+  virtual std::unique_ptr<ClientResult> ListEvents(
+      rust::Vec<rust::u8> const &request_byte_vec) const = 0;
 };
 
 std::unique_ptr<FrontendClient> NewFrontendClient();
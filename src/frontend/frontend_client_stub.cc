@@ -30,13 +30,12 @@ namespace frontend {
 const std::chrono::duration kConnectionDeadline = std::chrono::seconds(1);
 
 std::unique_ptr<frontend::FrontendService::Stub> NewFrontendClient() {
-  auto port = netsim::osutils::GetServerAddress();
-  if (!port.has_value()) {
+  auto server = netsim::osutils::GetServerAddress();
+  if (!server.has_value()) {
     return nullptr;
   }
-  auto server = "localhost:" + port.value();
   std::shared_ptr<grpc::Channel> channel =
-      grpc::CreateChannel(server, grpc::InsecureChannelCredentials());
+      grpc::CreateChannel(server.value(), grpc::InsecureChannelCredentials());
 
   auto deadline = std::chrono::system_clock::now() + kConnectionDeadline;
   if (!channel->WaitForConnected(deadline)) {
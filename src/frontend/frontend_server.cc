@@ -16,9 +16,13 @@
 
 #include <google/protobuf/util/json_util.h>
 
+#include <algorithm>
+#include <cctype>
+#include <chrono>
 #include <iostream>
 #include <memory>
 #include <string>
+#include <thread>
 #include <utility>
 
 #include "controller/scene_controller.h"
@@ -39,13 +43,27 @@ class CxxServerResponseWritable : public frontend::CxxServerResponseWriter {
  public:
   CxxServerResponseWritable()
       : grpc_writer_(nullptr), err(""), is_ok(false), body(""), length(0){};
+  explicit CxxServerResponseWritable(grpc::ServerContext *context)
+      : context_(context),
+        grpc_writer_(nullptr),
+        err(""),
+        is_ok(false),
+        body(""),
+        length(0){};
   CxxServerResponseWritable(
+      grpc::ServerContext *context,
       grpc::ServerWriter<netsim::frontend::GetCaptureResponse> *grpc_writer)
-      : grpc_writer_(grpc_writer), err(""), is_ok(false), body(""), length(0){};
+      : context_(context),
+        grpc_writer_(grpc_writer),
+        err(""),
+        is_ok(false),
+        body(""),
+        length(0){};
 
   void put_error(unsigned int error_code,
                  const std::string &response) const override {
     err = std::to_string(error_code) + ": " + response;
+    this->error_code = error_code;
     is_ok = false;
   }
 
@@ -55,10 +73,27 @@ class CxxServerResponseWritable : public frontend::CxxServerResponseWriter {
     is_ok = true;
   }
 
+  // Sends the headers as initial metadata, with lowercase keys.
+  void put_header(const std::string &name,
+                  const std::string &value) const override {
+    if (context_ == nullptr) return;
+    std::string key = name;
+    std::transform(key.begin(), key.end(), key.begin(),
+                   [](unsigned char c) { return std::tolower(c); });
+    context_->AddInitialMetadata(key, value);
+  }
+
   void put_chunk(rust::Slice<const uint8_t> chunk) const override {
     netsim::frontend::GetCaptureResponse response;
     response.set_capture_stream(std::string(chunk.begin(), chunk.end()));
     is_ok = grpc_writer_->Write(response);
+    // The stream is closed once the client is gone.
+    if (!is_ok) closed_ = true;
+  }
+
+  // The call is cancelled by the client, or by its deadline.
+  bool is_cancelled() const override {
+    return closed_ || (context_ != nullptr && context_->IsCancelled());
   }
 
   void put_ok(const std::string &mime_type,
@@ -67,47 +102,334 @@ class CxxServerResponseWritable : public frontend::CxxServerResponseWriter {
     is_ok = true;
   }
 
+  grpc::ServerContext *context_ = nullptr;
   mutable grpc::ServerWriter<netsim::frontend::GetCaptureResponse>
       *grpc_writer_;
   mutable std::string err;
+  mutable unsigned int error_code = 0;
   mutable bool is_ok;
   mutable std::string body;
   mutable std::size_t length;
+  mutable bool closed_ = false;
 };
 
+// A CxxServerResponseWriter streaming the JSON events of its chunks to the
+// stream of a RegisterEvents or SubscribeEvents call.
+class EventStreamWritable : public frontend::CxxServerResponseWriter {
+ public:
+  EventStreamWritable(grpc::ServerContext *context,
+                      grpc::ServerWriter<frontend::Event> *grpc_writer)
+      : context_(context), grpc_writer_(grpc_writer){};
+
+  void put_error(unsigned int error_code,
+                 const std::string &response) const override {
+    this->error_code = error_code;
+    this->err = response;
+  }
+
+  void put_ok_with_length(const std::string &mime_type,
+                          std::size_t length) const override {}
+
+  void put_chunk(rust::Slice<const uint8_t> chunk) const override {
+    frontend::Event event;
+    if (!google::protobuf::util::JsonStringToMessage(
+             std::string(chunk.begin(), chunk.end()), &event)
+             .ok())
+      return;
+    // The stream is closed once the client is gone.
+    if (!grpc_writer_->Write(event)) closed_ = true;
+  }
+
+  void put_ok(const std::string &mime_type,
+              const std::string &body) const override {}
+
+  bool is_cancelled() const override {
+    return closed_ || context_->IsCancelled();
+  }
+
+  mutable unsigned int error_code = 0;
+  mutable std::string err;
+
+ private:
+  grpc::ServerContext *context_;
+  grpc::ServerWriter<frontend::Event> *grpc_writer_;
+  mutable bool closed_ = false;
+};
+
+// Returns the authorization metadata of a call, empty without one.
+std::string Authorization(grpc::ServerContext *context) {
+  std::string authorization;
+  auto metadata = context->client_metadata().find("authorization");
+  if (metadata != context->client_metadata().end()) {
+    authorization.assign(metadata->second.data(), metadata->second.size());
+  }
+  return authorization;
+}
+
+// Checks the bearer token in the authorization metadata of a call for the
+// access of its method.
+grpc::Status Authorize(grpc::ServerContext *context, auth::Access access) {
+  switch (auth::CheckAccess(Authorization(context), access)) {
+    case 0:
+      return grpc::Status::OK;
+    case 403:
+      return grpc::Status(grpc::StatusCode::PERMISSION_DENIED, "Forbidden");
+    default:
+      return grpc::Status(grpc::StatusCode::UNAUTHENTICATED, "Unauthorized");
+  }
+}
+
+// Returns the status of a failed capture call from the HTTP status of its
+// CaptureError.
+grpc::Status CaptureErrorStatus(const CxxServerResponseWritable &writer) {
+  switch (writer.error_code) {
+    case 400:
+      return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+    case 404:
+      return grpc::Status(grpc::StatusCode::NOT_FOUND, writer.err);
+    case 409:
+      return grpc::Status(grpc::StatusCode::ALREADY_EXISTS, writer.err);
+    case 412:
+      return grpc::Status(grpc::StatusCode::FAILED_PRECONDITION, writer.err);
+    case 416:
+      return grpc::Status(grpc::StatusCode::OUT_OF_RANGE, writer.err);
+    case 500:
+      return grpc::Status(grpc::StatusCode::INTERNAL, writer.err);
+    case 507:
+      return grpc::Status(grpc::StatusCode::RESOURCE_EXHAUSTED, writer.err);
+    default:
+      return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+  }
+}
+
 class FrontendServer final : public frontend::FrontendService::Service {
  public:
   grpc::Status GetVersion(grpc::ServerContext *context,
                           const google::protobuf::Empty *empty,
                           frontend::VersionResponse *reply) {
-    reply->set_version(std::string(netsim::GetVersion()));
+    auto bytes = netsim::GetVersionResponseBytes();
+    if (!reply->ParseFromArray(bytes.data(), bytes.size())) {
+      reply->set_version(std::string(netsim::GetVersion()));
+    }
     return grpc::Status::OK;
   }
 
+  grpc::Status RegisterEvents(
+      grpc::ServerContext *context, const google::protobuf::Empty *empty,
+      grpc::ServerWriter<frontend::Event> *grpc_writer) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    EventStreamWritable writer(context, grpc_writer);
+    HandleEventsStreamCxx(writer, Authorization(context));
+    return grpc::Status::CANCELLED;
+  }
+
+  grpc::Status SubscribeEvents(
+      grpc::ServerContext *context,
+      const frontend::SubscribeEventsRequest *request,
+      grpc::ServerWriter<frontend::Event> *grpc_writer) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    EventStreamWritable writer(context, grpc_writer);
+    HandleSubscribeEventsCxx(writer, body, Authorization(context));
+    if (writer.error_code == 400)
+      return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+    return grpc::Status::CANCELLED;
+  }
+
   grpc::Status GetDevices(grpc::ServerContext *context,
-                          const google::protobuf::Empty *empty,
+                          const frontend::GetDevicesRequest *request,
                           frontend::GetDevicesResponse *reply) {
-    const auto scene = netsim::controller::SceneController::Singleton().Get();
-    for (const auto &device : scene.devices())
-      reply->add_devices()->CopyFrom(device);
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleListDevicesCxx(writer, body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    if (writer.error_code == 400)
+      return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+    return grpc::Status(grpc::StatusCode::INTERNAL, writer.err);
+  }
+
+  grpc::Status ListDeviceStream(
+      grpc::ServerContext *context, const frontend::GetDevicesRequest *request,
+      grpc::ServerWriter<model::Device> *grpc_writer) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    // The stream has no size limit, only the filters apply.
+    frontend::GetDevicesRequest filters(*request);
+    filters.clear_page_size();
+    filters.clear_page_token();
+    std::string body;
+    google::protobuf::util::MessageToJsonString(filters, &body);
+    CxxServerResponseWritable writer;
+    HandleListDevicesCxx(writer, body);
+    if (!writer.is_ok) {
+      if (writer.error_code == 400)
+        return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+      return grpc::Status(grpc::StatusCode::INTERNAL, writer.err);
+    }
+    frontend::GetDevicesResponse devices;
+    google::protobuf::util::JsonStringToMessage(writer.body, &devices);
+    for (const auto &device : devices.devices()) {
+      if (!grpc_writer->Write(device)) return grpc::Status::CANCELLED;
+    }
     return grpc::Status::OK;
   }
 
   grpc::Status PatchDevice(grpc::ServerContext *context,
                            const frontend::PatchDeviceRequest *request,
                            google::protobuf::Empty *response) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    if (!controller::IsValidDeviceMask(request->update_mask()))
+      return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT,
+                          "invalid update_mask of the device");
     auto status = netsim::controller::SceneController::Singleton().PatchDevice(
-        request->device());
+        request->device(), request->update_mask());
     if (!status)
       return grpc::Status(grpc::StatusCode::NOT_FOUND,
                           "device " + request->device().name() + " not found.");
     return grpc::Status::OK;
   }
 
+  grpc::Status SetDeviceMotion(grpc::ServerContext *context,
+                               const frontend::SetDeviceMotionRequest *request,
+                               google::protobuf::Empty *response) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleMotionCxx(writer, "POST", "", body);
+    if (writer.is_ok) return grpc::Status::OK;
+    if (writer.error_code == 400)
+      return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+    return grpc::Status(grpc::StatusCode::NOT_FOUND, writer.err);
+  }
+
+  grpc::Status SetLinkImpairment(
+      grpc::ServerContext *context,
+      const frontend::SetLinkImpairmentRequest *request,
+      google::protobuf::Empty *response) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleImpairmentCxx(writer, "POST", "", body);
+    if (writer.is_ok) return grpc::Status::OK;
+    if (writer.error_code == 404)
+      return grpc::Status(grpc::StatusCode::NOT_FOUND, writer.err);
+    return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+  }
+
+  grpc::Status LoadScenario(grpc::ServerContext *context,
+                            const frontend::LoadScenarioRequest *request,
+                            frontend::LoadScenarioResponse *response) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleSceneCxx(writer, "POST", "", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, response);
+      return grpc::Status::OK;
+    }
+    if (writer.error_code == 400)
+      return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+    return grpc::Status(grpc::StatusCode::INTERNAL, writer.err);
+  }
+
+  grpc::Status SaveState(grpc::ServerContext *context,
+                         const frontend::SaveStateRequest *request,
+                         frontend::SaveStateResponse *response) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleSceneCxx(writer, "POST", "save", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, response);
+      return grpc::Status::OK;
+    }
+    if (writer.error_code == 400)
+      return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+    return grpc::Status(grpc::StatusCode::INTERNAL, writer.err);
+  }
+
+  grpc::Status RestoreState(grpc::ServerContext *context,
+                            const frontend::RestoreStateRequest *request,
+                            frontend::LoadScenarioResponse *response) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleSceneCxx(writer, "POST", "restore", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, response);
+      return grpc::Status::OK;
+    }
+    if (writer.error_code == 400)
+      return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+    return grpc::Status(grpc::StatusCode::INTERNAL, writer.err);
+  }
+
+  grpc::Status PatchGroup(grpc::ServerContext *context,
+                          const frontend::PatchGroupRequest *request,
+                          frontend::PatchGroupResponse *response) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleGroupCxx(writer, "PATCH", "", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, response);
+      return grpc::Status::OK;
+    }
+    if (writer.error_code == 400)
+      return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+    if (writer.error_code == 404)
+      return grpc::Status(grpc::StatusCode::NOT_FOUND, writer.err);
+    return grpc::Status(grpc::StatusCode::INTERNAL, writer.err);
+  }
+
+  grpc::Status PatchSelection(grpc::ServerContext *context,
+                              const frontend::PatchSelectionRequest *request,
+                              frontend::PatchSelectionResponse *response) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleSelectionCxx(writer, "PATCH", "", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, response);
+      return grpc::Status::OK;
+    }
+    if (writer.error_code == 400)
+      return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+    if (writer.error_code == 404)
+      return grpc::Status(grpc::StatusCode::NOT_FOUND, writer.err);
+    return grpc::Status(grpc::StatusCode::INTERNAL, writer.err);
+  }
+
   grpc::Status SetPacketCapture(
       grpc::ServerContext *context,
       const frontend::SetPacketCaptureRequest *request,
       google::protobuf::Empty *empty) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
     model::Device device;
     model::Chip chip;
     // Turn on bt packet capture
@@ -121,44 +443,619 @@ class FrontendServer final : public frontend::FrontendService::Service {
   grpc::Status Reset(grpc::ServerContext *context,
                      const google::protobuf::Empty *request,
                      google::protobuf::Empty *empty) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
     netsim::controller::SceneController::Singleton().Reset();
     return grpc::Status::OK;
   }
 
   grpc::Status ListCapture(grpc::ServerContext *context,
-                           const google::protobuf::Empty *empty,
+                           const frontend::ListCaptureRequest *request,
                            frontend::ListCaptureResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::ListCaptures);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
     CxxServerResponseWritable writer;
-    HandleCaptureCxx(writer, "GET", "", "");
+    HandleCaptureCxx(writer, "GET", "", body);
     if (writer.is_ok) {
       google::protobuf::util::JsonStringToMessage(writer.body, reply);
       return grpc::Status::OK;
     }
-    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+    return CaptureErrorStatus(writer);
+  }
+
+  grpc::Status ListCaptureStream(
+      grpc::ServerContext *context, const frontend::ListCaptureRequest *request,
+      grpc::ServerWriter<model::Capture> *grpc_writer) {
+    auto authorized = Authorize(context, auth::Access::ListCaptures);
+    if (!authorized.ok()) return authorized;
+    // The stream has no size limit, only the filters apply.
+    frontend::ListCaptureRequest filters(*request);
+    filters.clear_page_size();
+    filters.clear_page_token();
+    std::string body;
+    google::protobuf::util::MessageToJsonString(filters, &body);
+    CxxServerResponseWritable writer;
+    HandleCaptureCxx(writer, "GET", "", body);
+    if (!writer.is_ok) {
+      return CaptureErrorStatus(writer);
+    }
+    frontend::ListCaptureResponse captures;
+    google::protobuf::util::JsonStringToMessage(writer.body, &captures);
+    for (const auto &capture : captures.captures()) {
+      if (!grpc_writer->Write(capture)) return grpc::Status::CANCELLED;
+    }
+    return grpc::Status::OK;
   }
 
   grpc::Status PatchCapture(grpc::ServerContext *context,
                             const frontend::PatchCaptureRequest *request,
                             google::protobuf::Empty *response) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(request->patch(), &body);
     CxxServerResponseWritable writer;
-    HandleCaptureCxx(writer, "PATCH", std::to_string(request->id()),
-                     std::to_string(request->patch().state()));
+    HandleCaptureCxx(writer, "PATCH", std::to_string(request->id()), body);
     if (writer.is_ok) {
       return grpc::Status::OK;
     }
-    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+    return CaptureErrorStatus(writer);
   }
   grpc::Status GetCapture(
       grpc::ServerContext *context,
       const netsim::frontend::GetCaptureRequest *request,
       grpc::ServerWriter<netsim::frontend::GetCaptureResponse> *grpc_writer) {
-    CxxServerResponseWritable writer(grpc_writer);
-    HandleCaptureCxx(writer, "GET", std::to_string(request->id()), "");
+    auto authorized = Authorize(context, auth::Access::DownloadCaptures);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer(context, grpc_writer);
+    std::string param = std::to_string(request->id());
+    if (request->segment() > 0)
+      param += "/segments/" + std::to_string(request->segment());
+    // The range of the file to get, in the body.
+    std::string body;
+    if (request->offset() > 0 || request->length() > 0)
+      google::protobuf::util::MessageToJsonString(*request, &body);
+    HandleCaptureCxx(writer, "GET", param, body);
+    if (writer.is_cancelled()) return grpc::Status::CANCELLED;
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return CaptureErrorStatus(writer);
+  }
+  grpc::Status StreamCapture(
+      grpc::ServerContext *context,
+      const netsim::frontend::StreamCaptureRequest *request,
+      grpc::ServerWriter<netsim::frontend::GetCaptureResponse> *grpc_writer) {
+    auto authorized = Authorize(context, auth::Access::DownloadCaptures);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer(context, grpc_writer);
+    HandleCaptureStreamCxx(writer, request->chip_id());
+    if (writer.is_cancelled()) return grpc::Status::CANCELLED;
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return CaptureErrorStatus(writer);
+  }
+  grpc::Status DeleteCapture(grpc::ServerContext *context,
+                             const frontend::DeleteCaptureRequest *request,
+                             google::protobuf::Empty *response) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleCaptureCxx(writer, "DELETE", std::to_string(request->id()), "");
     if (writer.is_ok) {
       return grpc::Status::OK;
     }
+    return CaptureErrorStatus(writer);
+  }
+
+  grpc::Status SetCaptureAll(grpc::ServerContext *context,
+                             const frontend::CaptureAll *request,
+                             google::protobuf::Empty *response) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleCaptureCxx(writer, "PATCH", "", body);
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return CaptureErrorStatus(writer);
+  }
+
+  grpc::Status PatchAggregateCapture(grpc::ServerContext *context,
+                                     const frontend::AggregateCapture *request,
+                                     frontend::AggregateCapture *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleCaptureCxx(writer, "PATCH", "aggregate", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return CaptureErrorStatus(writer);
+  }
+
+  grpc::Status GetCaptureStats(grpc::ServerContext *context,
+                               const google::protobuf::Empty *empty,
+                               frontend::GetCaptureStatsResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleCaptureCxx(writer, "GET", "stats", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return CaptureErrorStatus(writer);
+  }
+
+  grpc::Status ReplayCapture(grpc::ServerContext *context,
+                             const frontend::ReplayCaptureRequest *request,
+                             frontend::ReplayCaptureResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleCaptureCxx(writer, "POST", "replay", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return CaptureErrorStatus(writer);
+  }
+
+  grpc::Status RunScenario(grpc::ServerContext *context,
+                           const frontend::RunScenarioRequest *request,
+                           frontend::RunScenarioResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer(context);
+    HandleScenarioCxx(writer, "POST", "", body);
+    if (writer.is_cancelled()) return grpc::Status::CANCELLED;
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+  }
+
+  grpc::Status ControlClock(grpc::ServerContext *context,
+                            const frontend::ControlClockRequest *request,
+                            frontend::ControlClockResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleClockCxx(writer, "POST", "", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::FAILED_PRECONDITION, writer.err);
+  }
+
+  grpc::Status ControlSession(grpc::ServerContext *context,
+                              const frontend::ControlSessionRequest *request,
+                              frontend::ControlSessionResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleSessionCxx(writer, "POST", "", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::FAILED_PRECONDITION, writer.err);
+  }
+
+  grpc::Status ReplayFuzzInput(grpc::ServerContext *context,
+                               const frontend::ReplayFuzzInputRequest *request,
+                               frontend::ReplayFuzzInputResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleFuzzReplayCxx(writer, "POST", "", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+  }
+
+  grpc::Status GetChipTransportStats(
+      grpc::ServerContext *context, const google::protobuf::Empty *empty,
+      frontend::GetChipTransportStatsResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleTransportStatsCxx(writer, "GET", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
     return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
   }
+
+  grpc::Status GetCapacity(grpc::ServerContext *context,
+                           const google::protobuf::Empty *empty,
+                           frontend::GetCapacityResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleCapacityCxx(writer, "GET", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+  }
+
+  grpc::Status GetApiStats(grpc::ServerContext *context,
+                           const google::protobuf::Empty *empty,
+                           frontend::GetApiStatsResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleApiStatsCxx(writer, "GET", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+  }
+
+  grpc::Status GetLink(grpc::ServerContext *context,
+                       const frontend::GetLinkRequest *request,
+                       frontend::GetLinkResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleLinkCxx(writer, "POST", "", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::NOT_FOUND, writer.err);
+  }
+
+  grpc::Status ListEvents(grpc::ServerContext *context,
+                          const frontend::ListEventsRequest *request,
+                          frontend::ListEventsResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleEventsCxx(writer, "POST", "", body, Authorization(context));
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+  }
+
+  grpc::Status CreateBugReport(grpc::ServerContext *context,
+                               const google::protobuf::Empty *empty,
+                               frontend::CreateBugReportResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleBugReportCxx(writer, "POST", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::INTERNAL, writer.err);
+  }
+
+  grpc::Status RegisterChipKind(grpc::ServerContext *context,
+                                const frontend::ChipKindInfo *request,
+                                frontend::ChipKindInfo *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleChipKindsCxx(writer, "POST", "", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+  }
+
+  grpc::Status ListChipKinds(grpc::ServerContext *context,
+                             const google::protobuf::Empty *empty,
+                             frontend::ListChipKindsResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleChipKindsCxx(writer, "GET", "", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+  }
+
+  grpc::Status SetBreakpoint(grpc::ServerContext *context,
+                             const frontend::Breakpoint *request,
+                             frontend::Breakpoint *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleBreakpointsCxx(writer, "POST", "", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+  }
+
+  grpc::Status ListBreakpoints(grpc::ServerContext *context,
+                               const google::protobuf::Empty *empty,
+                               frontend::ListBreakpointsResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleBreakpointsCxx(writer, "GET", "", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+  }
+
+  grpc::Status DeleteBreakpoint(
+      grpc::ServerContext *context,
+      const frontend::DeleteBreakpointRequest *request,
+      google::protobuf::Empty *empty) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleBreakpointsCxx(writer, "DELETE", std::to_string(request->id()), "");
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::NOT_FOUND, writer.err);
+  }
+
+  grpc::Status RestartChip(grpc::ServerContext *context,
+                           const frontend::RestartChipRequest *request,
+                           frontend::RestartChipResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleRestartCxx(writer, "POST", std::to_string(request->chip_id()));
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::NOT_FOUND, writer.err);
+  }
+
+  grpc::Status Advertise(grpc::ServerContext *context,
+                         const frontend::AdvertiseRequest *request,
+                         grpc::ServerWriter<frontend::Beacon> *grpc_writer) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleBeaconsCxx(writer, "POST", "", body);
+    if (!writer.is_ok) {
+      return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+    }
+    frontend::Beacon beacon;
+    google::protobuf::util::JsonStringToMessage(writer.body, &beacon);
+    // The beacon advertises until the client cancels the stream.
+    if (grpc_writer->Write(beacon)) {
+      while (!context->IsCancelled()) {
+        std::this_thread::sleep_for(std::chrono::milliseconds(100));
+      }
+    }
+    CxxServerResponseWritable remover;
+    HandleBeaconsCxx(remover, "DELETE", std::to_string(beacon.chip_id()), "");
+    return grpc::Status::OK;
+  }
+
+  grpc::Status LinkInstance(grpc::ServerContext *context,
+                            const frontend::LinkInstanceRequest *request,
+                            frontend::Trunk *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleTrunksCxx(writer, "POST", "", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::UNAVAILABLE, writer.err);
+  }
+
+  grpc::Status ListTrunks(grpc::ServerContext *context,
+                          const google::protobuf::Empty *empty,
+                          frontend::ListTrunksResponse *reply) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleTrunksCxx(writer, "GET", "", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+  }
+
+  grpc::Status UnlinkInstance(grpc::ServerContext *context,
+                              const frontend::UnlinkInstanceRequest *request,
+                              google::protobuf::Empty *empty) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleTrunksCxx(writer, "DELETE", std::to_string(request->id()), "");
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::NOT_FOUND, writer.err);
+  }
+
+  grpc::Status ResumeDelivery(grpc::ServerContext *context,
+                              const google::protobuf::Empty *empty,
+                              frontend::DeliveryState *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleDeliveryCxx(writer, "POST", "resume", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+  }
+
+  grpc::Status PauseDelivery(grpc::ServerContext *context,
+                             const google::protobuf::Empty *empty,
+                             frontend::DeliveryState *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleDeliveryCxx(writer, "POST", "pause", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+  }
+
+  grpc::Status StepDelivery(grpc::ServerContext *context,
+                            const frontend::StepDeliveryRequest *request,
+                            frontend::DeliveryState *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleDeliveryCxx(writer, "POST", "step", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+  }
+
+  grpc::Status GetDeliveryState(grpc::ServerContext *context,
+                                const google::protobuf::Empty *empty,
+                                frontend::DeliveryState *reply) {
+    auto authorized = Authorize(context, auth::Access::Read);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    HandleDeliveryCxx(writer, "GET", "", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+  }
+
+  grpc::Status PatchLogLevel(grpc::ServerContext *context,
+                             const frontend::LogLevel *request,
+                             frontend::LogLevel *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    if (request->level().empty()) {
+      HandleLogCxx(writer, "GET", "", "");
+    } else {
+      HandleLogCxx(writer, "PATCH", "", request->level());
+    }
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+  }
+
+  grpc::Status PatchPropagation(grpc::ServerContext *context,
+                                const frontend::Propagation *request,
+                                frontend::Propagation *reply) {
+    auto authorized = Authorize(context, auth::Access::Admin);
+    if (!authorized.ok()) return authorized;
+    CxxServerResponseWritable writer;
+    if (request->name().empty()) {
+      HandlePropagationCxx(writer, "GET", "", "");
+    } else {
+      std::string body;
+      google::protobuf::util::MessageToJsonString(*request, &body);
+      HandlePropagationCxx(writer, "PATCH", "", body);
+    }
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, writer.err);
+  }
+};
+
+// Measures a frontend RPC from its start to its status.
+class ApiStatsInterceptor : public grpc::experimental::Interceptor {
+ public:
+  explicit ApiStatsInterceptor(std::string api)
+      : api_(std::move(api)), start_(std::chrono::steady_clock::now()) {}
+
+  void Intercept(
+      grpc::experimental::InterceptorBatchMethods *methods) override {
+    if (methods->QueryInterceptionHookPoint(
+            grpc::experimental::InterceptionHookPoints::PRE_SEND_STATUS)) {
+      auto latency = std::chrono::duration_cast<std::chrono::microseconds>(
+          std::chrono::steady_clock::now() - start_);
+      auto status = methods->GetSendStatus();
+      bool cancelled =
+          status.error_code() == grpc::StatusCode::CANCELLED ||
+          status.error_code() == grpc::StatusCode::DEADLINE_EXCEEDED;
+      api_stats::RecordApiCall(api_, latency.count(), !status.ok(),
+                               cancelled);
+    }
+    methods->Proceed();
+  }
+
+ private:
+  std::string api_;
+  std::chrono::steady_clock::time_point start_;
+};
+
+class ApiStatsInterceptorFactory
+    : public grpc::experimental::ServerInterceptorFactoryInterface {
+ public:
+  grpc::experimental::Interceptor *CreateServerInterceptor(
+      grpc::experimental::ServerRpcInfo *info) override {
+    // The methods are named "/netsim.frontend.FrontendService/<RPC>".
+    std::string method = info->method();
+    std::string prefix = "/netsim.frontend.FrontendService/";
+    if (method.rfind(prefix, 0) != 0) return nullptr;
+    return new ApiStatsInterceptor(method.substr(prefix.size()));
+  }
 };
 }  // namespace
 
@@ -166,4 +1063,9 @@ std::unique_ptr<frontend::FrontendService::Service> GetFrontendService() {
   return std::make_unique<FrontendServer>();
 }
 
+std::unique_ptr<grpc::experimental::ServerInterceptorFactoryInterface>
+GetFrontendInterceptorFactory() {
+  return std::make_unique<ApiStatsInterceptorFactory>();
+}
+
 }  // namespace netsim
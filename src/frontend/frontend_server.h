@@ -22,9 +22,15 @@
 #include <utility>
 
 #include "frontend.grpc.pb.h"
+#include "grpcpp/support/server_interceptor.h"
 
 namespace netsim {
 
 std::unique_ptr<frontend::FrontendService::Service> GetFrontendService();
 
+// Records the latency and status of every frontend RPC in the API
+// statistics.
+std::unique_ptr<grpc::experimental::ServerInterceptorFactoryInterface>
+GetFrontendInterceptorFactory();
+
 }  // namespace netsim
@@ -47,6 +47,8 @@ TEST_F(FrontendServerTest, VerifyVersion) {
   grpc::Status status = service_.GetVersion(&context_, {}, &response);
   ASSERT_TRUE(status.ok());
   EXPECT_FALSE(response.version().empty());
+  EXPECT_FALSE(response.chip_kinds().empty());
+  EXPECT_FALSE(response.capture_formats().empty());
 }
 
 #ifdef NETSIM_ANDROID_EMULATOR
@@ -36,6 +36,12 @@ class CxxServerResponseWriter {
   virtual void put_chunk(rust::Slice<const uint8_t> chunk) const = 0;
   virtual void put_ok(const std::string &mime_type,
                       const std::string &body) const = 0;
+  // Adds a header to the response, before put_ok_with_length.
+  virtual void put_header(const std::string &name,
+                          const std::string &value) const {};
+  // Returns true once the client has cancelled the call or its deadline
+  // has passed.
+  virtual bool is_cancelled() const { return false; };
 };
 
 }  // namespace frontend
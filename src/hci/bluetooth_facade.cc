@@ -19,6 +19,7 @@
 #include <cassert>
 #include <chrono>
 #include <cstdint>
+#include <functional>
 #include <iostream>
 #include <memory>
 #include <unordered_map>
@@ -38,8 +39,14 @@ using netsim::model::State;
 namespace netsim::hci::facade {
 
 int8_t SimComputeRssi(int send_id, int recv_id, int8_t tx_power);
+uint32_t FacadeId(uint32_t device_id);
 void IncrTx(uint32_t send_id, rootcanal::Phy::Type phy_type);
 void IncrRx(uint32_t receive_id, rootcanal::Phy::Type phy_type);
+void ScheduleReceive(std::chrono::milliseconds delay,
+                     const std::function<void()> &receive);
+
+// PacketType::LE_ADVERTISEMENT of the rootcanal link layer packets.
+constexpr uint8_t kLeAdvertisement = 0x0B;
 
 using namespace std::literals;
 using namespace rootcanal;
@@ -57,18 +64,43 @@ class SimPhyLayer : public PhyLayer {
   int8_t ComputeRssi(PhyDevice::Identifier sender_id,
                      PhyDevice::Identifier receiver_id,
                      int8_t tx_power) override {
-    return SimComputeRssi(sender_id, receiver_id, tx_power);
+    return SimComputeRssi(FacadeId(sender_id), FacadeId(receiver_id),
+                          tx_power);
   }
 
-  // Overrides Send in PhyLayerFactory to add Rx/Tx statistics.
+  // Overrides Send in PhyLayerFactory to add Rx/Tx statistics and the
+  // timing of advertising events.
   void Send(std::vector<uint8_t> const &packet, int8_t tx_power,
             PhyDevice::Identifier sender_id) override {
-    IncrTx(sender_id, type);
+    IncrTx(FacadeId(sender_id), type);
+    bool advertisement = type == rootcanal::Phy::Type::LOW_ENERGY &&
+                         !packet.empty() && packet[0] == kLeAdvertisement;
+    uint32_t delay = advertisement ? netsim::hci::AdvertisingDelay() : 0;
     for (const auto &device : phy_devices_) {
-      if (sender_id != device->id) {
-        IncrRx(device->id, type);
-        device->Receive(packet, type,
-                        ComputeRssi(sender_id, device->id, tx_power));
+      if (sender_id == device->id) continue;
+      // The advertising event arrives outside the scan window.
+      if (advertisement &&
+          !netsim::hci::IsListening(FacadeId(device->id), delay)) {
+        continue;
+      }
+      // The link impairment between the chips drops the packet.
+      if (netsim::processor::DropLinkPacket(FacadeId(sender_id),
+                                            FacadeId(device->id))) {
+        continue;
+      }
+      IncrRx(FacadeId(device->id), type);
+      auto rssi = ComputeRssi(sender_id, device->id, tx_power);
+      // The link impairment between the chips delays the packet.
+      uint32_t receive_delay =
+          delay + netsim::processor::LinkDelayMs(FacadeId(sender_id),
+                                                 FacadeId(device->id));
+      if (receive_delay == 0) {
+        device->Receive(packet, type, rssi);
+      } else {
+        ScheduleReceive(std::chrono::milliseconds(receive_delay),
+                        [device, packet, type = type, rssi]() {
+                          device->Receive(packet, type, rssi);
+                        });
       }
     }
   }
@@ -89,9 +121,16 @@ size_t phy_classic_index_;
 
 bool mStarted = false;
 std::shared_ptr<rootcanal::AsyncManager> mAsyncManager;
+rootcanal::AsyncUserId mReceiveUserId;
 std::unique_ptr<SimTestModel> gTestModel;
 rootcanal::ControllerProperties controller_properties_;
 
+// Delivers a delayed packet to a phy device.
+void ScheduleReceive(std::chrono::milliseconds delay,
+                     const std::function<void()> &receive) {
+  mAsyncManager->ExecAsync(mReceiveUserId, delay, receive);
+}
+
 bool ChangedState(model::State a, model::State b) {
   return (b != model::State::UNKNOWN && a != b);
 }
@@ -101,6 +140,7 @@ void Start() {
   if (mStarted) return;
 
   mAsyncManager = std::make_shared<rootcanal::AsyncManager>();
+  mReceiveUserId = mAsyncManager->GetNextUserId();
 
   gTestModel = std::make_unique<SimTestModel>(
       std::bind(&rootcanal::AsyncManager::GetNextUserId, mAsyncManager),
@@ -168,6 +208,21 @@ class ChipInfo {
 
 std::unordered_map<uint32_t, std::shared_ptr<ChipInfo>> id_to_chip_info_;
 
+// Rootcanal device ids of the facades restarted in place, and the reverse.
+// The other facades share their id with their rootcanal device.
+std::unordered_map<uint32_t, uint32_t> facade_to_device_;
+std::unordered_map<uint32_t, uint32_t> device_to_facade_;
+
+uint32_t DeviceId(uint32_t facade_id) {
+  auto it = facade_to_device_.find(facade_id);
+  return it == facade_to_device_.end() ? facade_id : it->second;
+}
+
+uint32_t FacadeId(uint32_t device_id) {
+  auto it = device_to_facade_.find(device_id);
+  return it == device_to_facade_.end() ? device_id : it->second;
+}
+
 model::Chip::Bluetooth Get(uint32_t id) {
   model::Chip::Bluetooth model;
   if (id_to_chip_info_.find(id) != id_to_chip_info_.end()) {
@@ -220,11 +275,51 @@ void Patch(uint32_t id, const model::Chip::Bluetooth &request) {
 
 void Remove(uint32_t id) {
   BtsLog("Removing HCI chip for %s");
+  auto device_id = DeviceId(id);
   id_to_chip_info_.erase(id);
-  gTestModel->RemoveDevice(id);
+  facade_to_device_.erase(id);
+  device_to_facade_.erase(device_id);
+  netsim::hci::RemoveScanner(id);
+  gTestModel->RemoveDevice(device_id);
   // rootcanal will call HciPacketTransport::Close().
 }
 
+void Restart(uint32_t id) {
+  if (id_to_chip_info_.find(id) == id_to_chip_info_.end()) {
+    BtsLog("Restart an unknown id %d", id);
+    return;
+  }
+  auto chip_info = id_to_chip_info_[id];
+  auto old_device_id = DeviceId(id);
+  device_to_facade_.erase(old_device_id);
+  netsim::hci::RemoveScanner(id);
+  // rootcanal will call HciPacketTransport::Close() on the old transport.
+  gTestModel->RemoveDevice(old_device_id);
+
+  auto transport = std::make_shared<HciPacketTransport>(mAsyncManager);
+  auto sniffer = std::static_pointer_cast<HciSniffer>(
+      rootcanal::HciSniffer::Create(transport));
+  auto hci_device =
+      std::make_shared<rootcanal::HciDevice>(sniffer, controller_properties_);
+  auto device_id = gTestModel->AddHciConnection(hci_device);
+  // The new transport keeps the facade id, so the packets of the emulator
+  // reach the new controller.
+  HciPacketTransport::Add(id, transport);
+  facade_to_device_[id] = device_id;
+  device_to_facade_[device_id] = id;
+  BtsLog("Restarted HCI facade %d as device %d", id, device_id);
+
+  chip_info->sniffer = sniffer;
+  chip_info->transport = transport;
+  // Restore the radios turned off.
+  if (chip_info->model->low_energy().state() == model::State::OFF) {
+    PatchPhy(device_id, false, true);
+  }
+  if (chip_info->model->classic().state() == model::State::OFF) {
+    PatchPhy(device_id, false, false);
+  }
+}
+
 // Rename AddChip(model::Chip, device, transport)
 
 uint32_t Add(uint32_t simulation_device) {
@@ -302,8 +397,13 @@ int8_t SimComputeRssi(int send_id, int recv_id, int8_t tx_power) {
   }
   auto a = id_to_chip_info_[send_id]->simulation_device;
   auto b = id_to_chip_info_[recv_id]->simulation_device;
-  auto distance = scene_controller::GetDistance(a, b);
-  return netsim::DistanceToRssi(tx_power, distance);
+  auto sender = scene_controller::GetPosition(a);
+  auto receiver = scene_controller::GetPosition(b);
+  propagation::RadioLink link{
+      static_cast<uint32_t>(common::ChipKind::BLUETOOTH), a, b,
+      propagation::Point{sender.x(), sender.y(), sender.z()},
+      propagation::Point{receiver.x(), receiver.y(), receiver.z()}};
+  return propagation::ComputeRssi(link, tx_power);
 }
 
 }  // namespace netsim::hci::facade
@@ -30,6 +30,7 @@ namespace netsim::hci::facade {
 
 void Reset(uint32_t);
 void Remove(uint32_t);
+void Restart(uint32_t);
 void Patch(uint32_t, const model::Chip::Bluetooth &);
 void SetPacketCapture(uint32_t id, bool isOn, std::string device_name);
 model::Chip::Bluetooth Get(uint32_t);
@@ -21,6 +21,7 @@
 #include "hci/hci_debug.h"
 #include "hci_packet.pb.h"
 #include "model/hci/hci_transport.h"
+#include "netsim-cxx/src/lib.rs.h"
 #include "packet_hub/packet_hub.h"
 #include "util/log.h"
 
@@ -96,14 +97,23 @@ void HciPacketTransport::Request(
   auto packet_callback = PacketTypeCallback(packet_type);
   if (!packet_callback) {
     BtsLog("hci_transport: unknown packet_callback");
+    netsim::transport::RecordMalformed(common::ChipKind::BLUETOOTH,
+                                       mDeviceId.value_or(0));
     return;
   }
+  uint32_t facade_id = mDeviceId.value_or(0);
   if (packet_type == HCIPacket::COMMAND) {
     auto cmd = HciCommandToString(packet->at(0), packet->at(1));
+    netsim::hci::ObserveCommand(
+        facade_id, rust::Slice<const uint8_t>(packet->data(), packet->size()));
   }
+  // The packet waits in the async manager until rootcanal handles it.
+  netsim::transport::RecordQueued(common::ChipKind::BLUETOOTH, facade_id);
   // Copy the packet bytes for rootcanal.
-  mAsyncManager->Synchronize(
-      [packet_callback, packet]() { packet_callback(packet); });
+  mAsyncManager->Synchronize([packet_callback, packet, facade_id]() {
+    netsim::transport::RecordDequeued(common::ChipKind::BLUETOOTH, facade_id);
+    packet_callback(packet);
+  });
 }
 
 void HciPacketTransport::Add(
@@ -114,8 +124,12 @@ void HciPacketTransport::Add(
 }
 
 void HciPacketTransport::Close() {
+  // A restarted facade may have registered a new transport for the id.
   if (mDeviceId.has_value()) {
-    device_to_transport_.erase(mDeviceId.value());
+    auto it = device_to_transport_.find(mDeviceId.value());
+    if (it != device_to_transport_.end() && it->second.get() == this) {
+      device_to_transport_.erase(it);
+    }
   }
 
   BtsLog("hci_packet_transport close from rootcanal");
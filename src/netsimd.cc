@@ -20,10 +20,12 @@
 
 #if defined(__linux__)
 #include <execinfo.h>
+#include <pthread.h>
 #include <signal.h>
 #include <unistd.h>
 
 #include <cstdio>
+#include <thread>
 #endif
 
 #ifndef NETSIM_ANDROID_EMULATOR
@@ -49,6 +51,16 @@ void SignalHandler(int sig) {
   backtrace_symbols_fd(buffer, size, STDERR_FILENO);
   exit(sig);
 }
+
+// Waits for a termination signal, blocked in the other threads, and exits
+// once the captures are stopped with their last packets on the disk.
+void WaitForTermination(sigset_t signals) {
+  int sig;
+  if (sigwait(&signals, &sig) != 0) return;
+  fprintf(stderr, "netsim: terminated by signal %d\n", sig);
+  netsim::pcap::ShutdownCaptures();
+  exit(0);
+}
 #endif
 
 void ArgError(char *argv[], int c) {
@@ -59,16 +71,38 @@ void ArgError(char *argv[], int c) {
 int main(int argc, char *argv[]) {
 #if defined(__linux__)
   signal(SIGSEGV, SignalHandler);
+  // A write to a pipe closed by its reader, e.g. Wireshark reading a live
+  // capture, fails with EPIPE instead of terminating netsimd.
+  signal(SIGPIPE, SIG_IGN);
+  // The threads started from here on inherit the blocked signals.
+  sigset_t termination;
+  sigemptyset(&termination);
+  sigaddset(&termination, SIGTERM);
+  sigaddset(&termination, SIGINT);
+  pthread_sigmask(SIG_BLOCK, &termination, nullptr);
+  std::thread(WaitForTermination, termination).detach();
 #endif
   const char *kShortOpt = "s:dg";
   const option kLongOptions[] = {
       {"rootcanal_default_commands_file", required_argument, 0, 'c'},
       {"rootcanal_controller_properties_file", required_argument, 0, 'p'},
+      {"fresh", no_argument, 0, 'f'},
+      {"scene", required_argument, 0, 'S'},
+      {"capture-dir", required_argument, 0, 'C'},
+      {"capture-all", no_argument, 0, 'A'},
+      {"http-host", required_argument, 0, 'H'},
+      {"http-port", required_argument, 0, 'P'},
+      {"http-cors-origins", required_argument, 0, 'O'},
+      {0, 0, 0, 0},
   };
 
   bool debug = false;
   bool grpc_startup = false;
+  bool fresh = false;
+  bool capture_all = false;
   std::string fd_startup_str;
+  std::string scene_file;
+  std::string capture_dir;
   std::string rootcanal_default_commands_file;
   std::string rootcanal_controller_properties_file;
 
@@ -98,15 +132,60 @@ int main(int argc, char *argv[]) {
         rootcanal_controller_properties_file = std::string(optarg);
         break;
 
+      case 'f':
+        fresh = true;
+        break;
+
+      case 'S':
+        scene_file = std::string(optarg);
+        break;
+
+      case 'C':
+        capture_dir = std::string(optarg);
+        break;
+
+      case 'A':
+        capture_all = true;
+        break;
+
+      // The flags of the http server override its settings.
+      case 'H':
+        if (!netsim::config::SetFlag("http.host", optarg)) return (-2);
+        break;
+
+      case 'P':
+        if (!netsim::config::SetFlag("http.port", optarg)) return (-2);
+        break;
+
+      case 'O':
+        if (!netsim::config::SetFlag("http.cors_origins", optarg)) return (-2);
+        break;
+
       default:
         ArgError(argv, c);
         return (-2);
     }
   }
 
-  // Daemon mode -- start radio managers
+  // Log at the level of the log.level setting.
+  netsim::logger::InitLogger();
+
+  // Direct the captures to a directory such as the artifacts of a CI run.
+  if (!capture_dir.empty() && !netsim::pcap::SetCaptureDir(capture_dir)) {
+    return (-2);
+  }
+
+  // Capture every chip from its first packet, e.g. the boot of a device.
+  if (capture_all) netsim::pcap::SetCaptureAll();
+
+  // Daemon mode -- restore state and start radio managers
   if (!fd_startup_str.empty() || grpc_startup) {
+    netsim::state::LoadState(fresh);
     netsim::hci::facade::Start();
+    // Import the scene before any emulator connects.
+    if (!scene_file.empty() && !netsim::scene_file::ImportScene(scene_file)) {
+      return (-2);
+    }
   }
 
 #ifdef NETSIM_ANDROID_EMULATOR
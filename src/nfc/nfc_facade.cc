@@ -0,0 +1,49 @@
+// Copyright 2023 The Android Open Source Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "nfc/nfc_facade.h"
+
+#include "model.pb.h"
+#include "netsim-cxx/src/lib.rs.h"
+#include "nfc/nfc_packet_hub.h"
+#include "rust/cxx.h"
+
+namespace netsim::nfc::facade {
+
+void Patch(uint32_t id, const model::Chip::Nfc &request) {
+  std::vector<uint8_t> message_vec(request.ByteSizeLong());
+  request.SerializeToArray(message_vec.data(), message_vec.size());
+  rust::Slice<const uint8_t> message_rust_slice(message_vec.data(),
+                                                message_vec.size());
+  nfc::facade::PatchCxx(id, message_rust_slice);
+}
+
+model::Chip::Nfc Get(uint32_t id) {
+  model::Chip::Nfc nfc;
+  auto nfc_byte_vec = nfc::facade::GetCxx(id);
+  nfc.ParseFromArray(nfc_byte_vec.data(), nfc_byte_vec.size());
+  return nfc;
+}
+
+}  // namespace netsim::nfc::facade
+
+namespace netsim::nfc {
+
+void HandleNfcRequest(uint32_t facade_id,
+                      const std::shared_ptr<std::vector<uint8_t>> &packet) {
+  rust::Slice<const uint8_t> packet_rust_slice(packet->data(), packet->size());
+  HandleNfcRequestCxx(facade_id, packet_rust_slice);
+}
+
+}  // namespace netsim::nfc
@@ -21,55 +21,230 @@
 #include "hci/hci_packet_hub.h"
 #include "hci_packet.pb.h"
 #include "netsim-cxx/src/lib.rs.h"
+#include "nfc/nfc_packet_hub.h"
+#include "uwb/uwb_packet_hub.h"
 #include "wifi/wifi_packet_hub.h"
 
 namespace netsim {
 namespace packet_hub {
 
 using netsim::common::ChipKind;
+using netsim::processor::PacketVerdict;
+using netsim::processor::ProcessedPacket;
+
+namespace {
+
+// Replaces the packet for a Modify verdict. Returns false to drop it.
+bool ApplyVerdict(const ProcessedPacket &processed,
+                  std::shared_ptr<std::vector<uint8_t>> &packet) {
+  switch (processed.verdict) {
+    case PacketVerdict::Drop:
+      return false;
+    case PacketVerdict::Modify:
+      packet = std::make_shared<std::vector<uint8_t>>(processed.packet.begin(),
+                                                      processed.packet.end());
+      return true;
+    default:
+      return true;
+  }
+}
+
+// Forwards a packet to the facade and the capture of the chip. The capture
+// records the time the packet reached the facade, in nanoseconds.
+void DeliverRequest(ChipKind kind, uint32_t facade_id,
+                    const std::shared_ptr<std::vector<uint8_t>> &packet,
+                    packet::HCIPacket_PacketType packet_type) {
+  auto timestamp = netsim::clock::NowNanos();
+  // Chips backed by an external controller bypass the built-in facades.
+  if (netsim::external::HandleRequest(kind, facade_id, *packet,
+                                      packet_type)) {
+    netsim::pcap::HandleRequest(kind, facade_id, *packet, packet_type,
+                                timestamp);
+    return;
+  }
+  if (kind == ChipKind::BLUETOOTH) {
+    netsim::hci::handle_bt_request(facade_id, packet_type, packet);
+  } else if (kind == ChipKind::WIFI) {
+    netsim::wifi::HandleWifiRequest(facade_id, packet);
+    netsim::wifi::MirrorRequest(facade_id, *packet);
+  } else if (kind == ChipKind::UWB) {
+    netsim::uwb::HandleUwbRequest(facade_id, packet);
+  } else if (kind == ChipKind::NFC) {
+    netsim::nfc::HandleNfcRequest(facade_id, packet);
+  } else if (netsim::chip_kinds::IsCustomChipKind(kind)) {
+    // The generic facade of custom chip kinds routes between the chips.
+    rust::Slice<const uint8_t> slice{packet->data(), packet->size()};
+    for (const auto &routed :
+         netsim::chip_kinds::RouteGenericRequest(kind, facade_id, slice)) {
+      HandleGenericResponse(kind, routed.facade_id,
+                            std::make_shared<std::vector<uint8_t>>(
+                                routed.packet.begin(), routed.packet.end()));
+    }
+  }
+  netsim::pcap::HandleRequest(kind, facade_id, *packet, packet_type,
+                              timestamp);
+}
+
+// Passes a packet from the transport through the processors and the MTU
+// before delivering it. The packet is shared, not copied, down to the
+// facade and the capture.
+void ForwardRequest(ChipKind kind, uint32_t facade_id,
+                    std::shared_ptr<std::vector<uint8_t>> shared_packet,
+                    packet::HCIPacket_PacketType packet_type) {
+  if (!ApplyVerdict(netsim::processor::ProcessRequest(
+                        kind, facade_id, *shared_packet, packet_type),
+                    shared_packet)) {
+    return;
+  }
+  auto fragmented =
+      netsim::processor::FragmentRequest(kind, *shared_packet, packet_type);
+  switch (fragmented.verdict) {
+    case PacketVerdict::Drop:
+      return;
+    case PacketVerdict::Modify:
+      for (const auto &fragment : fragmented.fragments) {
+        DeliverRequest(kind, facade_id,
+                       std::make_shared<std::vector<uint8_t>>(
+                           fragment.packet.begin(), fragment.packet.end()),
+                       packet_type);
+      }
+      return;
+    default:
+      DeliverRequest(kind, facade_id, shared_packet, packet_type);
+  }
+}
+
+// Passes a packet from the facade through the processors before writing it
+// to the transports and the capture of the chip.
+void ForwardResponse(ChipKind kind, uint32_t facade_id,
+                     const std::shared_ptr<std::vector<uint8_t>> &response,
+                     packet::HCIPacket_PacketType packet_type) {
+  auto timestamp = netsim::clock::NowNanos();
+  auto packet = response;
+  if (!ApplyVerdict(netsim::processor::ProcessResponse(kind, facade_id,
+                                                       *response, packet_type),
+                    packet)) {
+    return;
+  }
+  netsim::transport::RecordResponse(kind, facade_id, packet->size());
+  netsim::federation::TrunkResponse(kind, facade_id, *packet, packet_type);
+  netsim::backend::HandleResponse(kind, facade_id, *packet, packet_type);
+  netsim::fd::HandleResponse(kind, facade_id, *packet, packet_type);
+  netsim::pcap::HandleResponse(kind, facade_id, *packet, packet_type,
+                               timestamp);
+}
+
+// Forwards a packet from the facade unless the debugger holds it or a
+// link impairment delays it.
+void HandleResponse(ChipKind kind, uint32_t facade_id,
+                    const std::shared_ptr<std::vector<uint8_t>> &response,
+                    packet::HCIPacket_PacketType packet_type) {
+  if (netsim::debugger::HoldResponse(kind, facade_id, *response,
+                                     packet_type)) {
+    return;
+  }
+  if (netsim::processor::DelayResponse(kind, facade_id, *response,
+                                       packet_type)) {
+    return;
+  }
+  ForwardResponse(kind, facade_id, response, packet_type);
+}
+
+}  // namespace
 
 // forward from transport to facade via packet_hub
 void HandleRequest(ChipKind kind, uint32_t facade_id,
-                   const std::vector<uint8_t> &packet,
+                   const std::shared_ptr<std::vector<uint8_t>> &shared_request,
                    packet::HCIPacket_PacketType packet_type) {
-  // Copied
-  auto shared_packet = std::make_shared<std::vector<uint8_t>>(packet);
-  if (kind == ChipKind::BLUETOOTH) {
-    netsim::hci::handle_bt_request(facade_id, packet_type, shared_packet);
-  } else if (kind == ChipKind::WIFI) {
-    netsim::wifi::HandleWifiRequest(facade_id, shared_packet);
+  const auto &request = *shared_request;
+  netsim::session::RecordIngress(kind, facade_id, request, packet_type);
+  netsim::transport::RecordRequest(kind, facade_id, request.size());
+  netsim::watchdog::RecordRequest(kind, facade_id, request);
+  // Chips of a leaf netsimd are simulated by the hub of their trunk.
+  if (netsim::federation::TrunkRequest(kind, facade_id, request,
+                                       packet_type)) {
+    return;
+  }
+  if (!netsim::transport::AdmitRequest(kind, facade_id, request.size())) {
+    return;
+  }
+  if (!netsim::transport::ApplyBackpressure(kind, facade_id,
+                                            request.size())) {
+    return;
+  }
+  if (netsim::debugger::HoldRequest(kind, facade_id, request, packet_type)) {
+    return;
+  }
+  if (netsim::restart::HoldRequest(kind, facade_id, request, packet_type)) {
+    return;
   }
-  netsim::pcap::HandleRequest(kind, facade_id, packet, packet_type);
+  if (netsim::processor::DelayRequest(kind, facade_id, request,
+                                      packet_type)) {
+    return;
+  }
+  ForwardRequest(kind, facade_id, shared_request, packet_type);
 }
 
+// The packet of Rust is copied once, into the buffer shared by packet_hub.
 void HandleRequestCxx(uint32_t kind, uint32_t facade_id,
                       const rust::Vec<uint8_t> &packet, uint8_t packet_type) {
-  std::vector<uint8_t> buffer(packet.begin(), packet.end());
-  HandleRequest(static_cast<ChipKind>(kind), facade_id, buffer,
-                static_cast<packet::HCIPacket_PacketType>(packet_type));
+  HandleRequest(
+      static_cast<ChipKind>(kind), facade_id,
+      std::make_shared<std::vector<uint8_t>>(packet.begin(), packet.end()),
+      static_cast<packet::HCIPacket_PacketType>(packet_type));
+}
+
+// forward a packet released by the debugger or the delay queue from
+// transport to facade
+void ForwardRequestCxx(uint32_t kind, uint32_t facade_id,
+                       const rust::Vec<uint8_t> &packet, uint8_t packet_type) {
+  ForwardRequest(
+      static_cast<ChipKind>(kind), facade_id,
+      std::make_shared<std::vector<uint8_t>>(packet.begin(), packet.end()),
+      static_cast<packet::HCIPacket_PacketType>(packet_type));
+}
+
+// forward a packet released by the debugger or the delay queue from facade
+// to transport
+void ForwardResponseCxx(uint32_t kind, uint32_t facade_id,
+                        const rust::Vec<uint8_t> &packet,
+                        uint8_t packet_type) {
+  ForwardResponse(
+      static_cast<ChipKind>(kind), facade_id,
+      std::make_shared<std::vector<uint8_t>>(packet.begin(), packet.end()),
+      static_cast<packet::HCIPacket_PacketType>(packet_type));
+}
+
+// forward from an external controller to transport via packet_hub
+void HandleResponseCxx(uint32_t kind, uint32_t facade_id,
+                       const rust::Vec<uint8_t> &packet, uint8_t packet_type) {
+  HandleResponse(
+      static_cast<ChipKind>(kind), facade_id,
+      std::make_shared<std::vector<uint8_t>>(packet.begin(), packet.end()),
+      static_cast<packet::HCIPacket_PacketType>(packet_type));
 }
 
 // forward from facade to transport via packet_hub
 void HandleBtResponse(uint32_t facade_id,
                       packet::HCIPacket_PacketType packet_type,
-                      const std::shared_ptr<std::vector<uint8_t>> &packet) {
-  netsim::backend::HandleResponse(ChipKind::BLUETOOTH, facade_id, *packet,
-                                  packet_type);
-  netsim::fd::HandleResponse(ChipKind::BLUETOOTH, facade_id, *packet,
-                             packet_type);
-  netsim::pcap::HandleResponse(ChipKind::BLUETOOTH, facade_id, *packet,
-                               packet_type);
+                      const std::shared_ptr<std::vector<uint8_t>> &response) {
+  HandleResponse(ChipKind::BLUETOOTH, facade_id, response, packet_type);
 }
 
 // forward from facade to transport via packet_hub
 void HandleWifiResponse(uint32_t facade_id,
-                        const std::shared_ptr<std::vector<uint8_t>> &packet) {
-  netsim::backend::HandleResponse(ChipKind::WIFI, facade_id, *packet,
-                                  packet::HCIPacket::HCI_PACKET_UNSPECIFIED);
-  netsim::fd::HandleResponse(ChipKind::WIFI, facade_id, *packet,
-                             packet::HCIPacket::HCI_PACKET_UNSPECIFIED);
-  netsim::pcap::HandleResponse(ChipKind::WIFI, facade_id, *packet,
-                               packet::HCIPacket::HCI_PACKET_UNSPECIFIED);
+                        const std::shared_ptr<std::vector<uint8_t>> &response) {
+  HandleResponse(ChipKind::WIFI, facade_id, response,
+                 packet::HCIPacket::HCI_PACKET_UNSPECIFIED);
+}
+
+// forward from the generic facade of custom chip kinds to transport via
+// packet_hub
+void HandleGenericResponse(
+    ChipKind kind, uint32_t facade_id,
+    const std::shared_ptr<std::vector<uint8_t>> &response) {
+  HandleResponse(kind, facade_id, response,
+                 packet::HCIPacket::HCI_PACKET_UNSPECIFIED);
 }
 
 }  // namespace packet_hub
@@ -26,6 +26,9 @@
 
 #pragma once
 
+#include <memory>
+#include <vector>
+
 // Use gRPC HCI PacketType definitions so we don't expose Rootcanal's version
 // outside of the Bluetooth Facade.
 #include "common.pb.h"
@@ -39,12 +42,25 @@ namespace packet_hub {
    different transports. */
 
 void HandleRequest(common::ChipKind kind, uint32_t facade_id,
-                   const std::vector<uint8_t> &packet,
+                   const std::shared_ptr<std::vector<uint8_t>> &packet,
                    packet::HCIPacket_PacketType packet_type);
 
 void HandleRequestCxx(uint32_t kind, uint32_t facade_id,
                       const rust::Vec<uint8_t> &packet, uint8_t packet_type);
 
+/* Forward the packets held by the debugger when their delivery resumes. */
+
+void ForwardRequestCxx(uint32_t kind, uint32_t facade_id,
+                       const rust::Vec<uint8_t> &packet, uint8_t packet_type);
+
+void ForwardResponseCxx(uint32_t kind, uint32_t facade_id,
+                        const rust::Vec<uint8_t> &packet, uint8_t packet_type);
+
+/* Handle the packets of a chip backed by an external controller. */
+
+void HandleResponseCxx(uint32_t kind, uint32_t facade_id,
+                       const rust::Vec<uint8_t> &packet, uint8_t packet_type);
+
 void HandleBtResponse(uint32_t facade_id,
                       packet::HCIPacket_PacketType packet_type,
                       const std::shared_ptr<std::vector<uint8_t>> &packet);
@@ -52,5 +68,8 @@ void HandleBtResponse(uint32_t facade_id,
 void HandleWifiResponse(uint32_t facade_id,
                         const std::shared_ptr<std::vector<uint8_t>> &packet);
 
+void HandleGenericResponse(common::ChipKind kind, uint32_t facade_id,
+                           const std::shared_ptr<std::vector<uint8_t>> &packet);
+
 }  // namespace packet_hub
 }  // namespace netsim
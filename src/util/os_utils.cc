@@ -45,6 +45,17 @@ DiscoveryDir discovery {
 #endif
 };
 
+// Returns the path of a file of an instance in the discovery directory.
+std::string GetInstanceFilepath(const std::string &instance,
+                                const std::string &extension) {
+  auto discovery_dir = GetDiscoveryDirectory();
+  // Check if directory has a trailing slash.
+  if (discovery_dir.back() != netsim::filesystem::slash.back())
+    discovery_dir.append(netsim::filesystem::slash);
+  if (instance.empty()) return discovery_dir.append("netsim" + extension);
+  return discovery_dir.append("netsim_" + instance + extension);
+}
+
 }  // namespace
 
 std::string GetEnv(const std::string &name, const std::string &default_value) {
@@ -69,12 +80,18 @@ std::string GetDiscoveryDirectory() {
   return std::string(env_p) + netsim::filesystem::slash + discovery.subdir;
 }
 
+std::string GetInstanceName() { return GetEnv("NETSIM_INSTANCE", ""); }
+
+std::string GetNetsimIniFilepath(const std::string &instance) {
+  return GetInstanceFilepath(instance, ".ini");
+}
+
 std::string GetNetsimIniFilepath() {
-  auto discovery_dir = GetDiscoveryDirectory();
-  // Check if directory has a trailing slash.
-  if (discovery_dir.back() != netsim::filesystem::slash.back())
-    discovery_dir.append(netsim::filesystem::slash);
-  return discovery_dir.append("netsim.ini");
+  return GetNetsimIniFilepath(GetInstanceName());
+}
+
+std::string GetNetsimTokenFilepath() {
+  return GetInstanceFilepath(GetInstanceName(), ".token");
 }
 
 std::optional<std::string> GetServerAddress(bool frontend_server) {
@@ -89,7 +106,28 @@ std::optional<std::string> GetServerAddress(bool frontend_server) {
   }
   IniFile iniFile(filepath);
   iniFile.Read();
-  return iniFile.Get("grpc.port");
+  if (auto address = iniFile.Get("grpc.address"); address.has_value()) {
+    return address;
+  }
+  // Discovery files written by older netsimd only have the port.
+  auto port = iniFile.Get("grpc.port");
+  if (!port.has_value()) return std::nullopt;
+  return "localhost:" + port.value();
+}
+
+std::string FormatAddress(const std::string &host, int port) {
+  if (host.find(':') != std::string::npos && host.front() != '[') {
+    return "[" + host + "]:" + std::to_string(port);
+  }
+  return host + ":" + std::to_string(port);
+}
+
+std::string GetConnectHost(const std::string &listen_host) {
+  if (listen_host.empty() || listen_host == "0.0.0.0" || listen_host == "::" ||
+      listen_host == "[::]") {
+    return "localhost";
+  }
+  return listen_host;
 }
 }  // namespace osutils
 }  // namespace netsim
@@ -35,13 +35,42 @@ std::string GetEnv(const std::string &name, const std::string &default_value);
 std::string GetDiscoveryDirectory();
 
 /**
- * Return the path of netsim ini file.
+ * Return the netsimd instance name from environment variable
+ * "NETSIM_INSTANCE". The empty name selects the default instance.
+ */
+std::string GetInstanceName();
+
+/**
+ * Return the path of netsim ini file of the given instance.
+ */
+std::string GetNetsimIniFilepath(const std::string &instance);
+
+/**
+ * Return the path of netsim ini file of the instance from GetInstanceName.
  */
 std::string GetNetsimIniFilepath();
 
 /**
- * Return the frontend grpc port.
+ * Return the path of the frontend token file of the instance from
+ * GetInstanceName.
+ */
+std::string GetNetsimTokenFilepath();
+
+/**
+ * Return the frontend grpc server address like "localhost:8554" or
+ * "[::1]:8554".
  */
 std::optional<std::string> GetServerAddress(bool frontend_server = true);
+
+/**
+ * Return "host:port", enclosing IPv6 hosts in brackets like "[::1]:8554".
+ */
+std::string FormatAddress(const std::string &host, int port);
+
+/**
+ * Return the host clients use to reach a server listening on the given host.
+ * Wildcard hosts like "0.0.0.0" and "::" are reached through "localhost".
+ */
+std::string GetConnectHost(const std::string &listen_host);
 }  // namespace osutils
 }  // namespace netsim
@@ -31,6 +31,39 @@ TEST(OsUtilsTest, GetDiscoveryDir) {
   EXPECT_TRUE(netsim::filesystem::exists(dir));
 }
 
+// Test that each instance has its own netsim ini file
+TEST(OsUtilsTest, GetNetsimIniFilepath) {
+  auto default_filepath = osutils::GetNetsimIniFilepath("");
+  auto instance_filepath = osutils::GetNetsimIniFilepath("shard1");
+  EXPECT_NE(default_filepath, instance_filepath);
+  EXPECT_EQ(default_filepath.substr(default_filepath.size() - 10),
+            "netsim.ini");
+  EXPECT_EQ(instance_filepath.substr(instance_filepath.size() - 17),
+            "netsim_shard1.ini");
+}
+
+TEST(OsUtilsTest, GetNetsimTokenFilepath) {
+  auto token_filepath = osutils::GetNetsimTokenFilepath();
+  auto ini_filepath = osutils::GetNetsimIniFilepath();
+  EXPECT_EQ(token_filepath.substr(0, token_filepath.size() - 6),
+            ini_filepath.substr(0, ini_filepath.size() - 4));
+  EXPECT_EQ(token_filepath.substr(token_filepath.size() - 6), ".token");
+}
+
+TEST(OsUtilsTest, FormatAddress) {
+  EXPECT_EQ(osutils::FormatAddress("0.0.0.0", 8554), "0.0.0.0:8554");
+  EXPECT_EQ(osutils::FormatAddress("::", 8554), "[::]:8554");
+  EXPECT_EQ(osutils::FormatAddress("[::1]", 8554), "[::1]:8554");
+  EXPECT_EQ(osutils::FormatAddress("fe80::1", 0), "[fe80::1]:0");
+}
+
+TEST(OsUtilsTest, GetConnectHost) {
+  EXPECT_EQ(osutils::GetConnectHost("0.0.0.0"), "localhost");
+  EXPECT_EQ(osutils::GetConnectHost("::"), "localhost");
+  EXPECT_EQ(osutils::GetConnectHost("::1"), "::1");
+  EXPECT_EQ(osutils::GetConnectHost("10.0.0.1"), "10.0.0.1");
+}
+
 }  // namespace
 }  // namespace testing
 }  // namespace netsim
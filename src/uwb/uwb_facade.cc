@@ -17,7 +17,7 @@
 #include "model.pb.h"
 #include "netsim-cxx/src/lib.rs.h"
 #include "rust/cxx.h"
-#include "util/log.h"
+#include "uwb/uwb_packet_hub.h"
 
 namespace netsim::uwb::facade {
 
@@ -36,13 +36,14 @@ model::Chip::Radio Get(uint32_t id) {
   return radio;
 }
 
+}  // namespace netsim::uwb::facade
+
+namespace netsim::uwb {
+
 void HandleUwbRequest(uint32_t facade_id,
                       const std::shared_ptr<std::vector<uint8_t>> &packet) {
-  BtsLog("netsim::uwb::HandleUwbRequest()");
-  rust::Slice<const uint8_t> packet_rust_slice;
-  rust::Slice<const uint8_t> message_rust_slice(packet_rust_slice.data(),
-                                                packet_rust_slice.size());
+  rust::Slice<const uint8_t> packet_rust_slice(packet->data(), packet->size());
   HandleUwbRequestCxx(facade_id, packet_rust_slice);
 }
 
-}  // namespace netsim::uwb::facade
\ No newline at end of file
+}  // namespace netsim::uwb
\ No newline at end of file
@@ -3,6 +3,7 @@ import {Capture, Chip, Device as ProtoDevice, State} from './model.js';
 // URL for netsim
 const DEVICES_URL = './v1/devices';
 const CAPTURES_URL = './v1/captures';
+const WEBSOCKET_URL = './v1/websocket';
 
 /**
  * Interface for a method in notifying the subscribed observers.
@@ -285,12 +286,40 @@ class SimulationState implements Observable {
 /** Subscribed observers must register itself to the simulationState */
 export const simulationState = new SimulationState();
 
+/**
+ * Refreshes the devices or the captures on each event pushed by netsim.
+ * Resolves once the WebSocket closes, with whether it was open.
+ */
+function listen(): Promise<boolean> {
+  return new Promise(resolve => {
+    const url = new URL(WEBSOCKET_URL, window.location.href);
+    url.protocol = url.protocol === 'https:' ? 'wss:' : 'ws:';
+    const socket = new WebSocket(url);
+    let opened = false;
+    socket.onopen = () => {
+      opened = true;
+    };
+    socket.onmessage = message => {
+      const event = JSON.parse(message.data);
+      if (String(event.type).startsWith('CAPTURE_')) {
+        simulationState.invokeListCaptures();
+      } else {
+        simulationState.invokeGetDevice();
+      }
+    };
+    socket.onclose = () => resolve(opened);
+  });
+}
+
+/** Polls the simulation every second while the WebSocket is unavailable. */
 async function subscribe() {
   const delay = (ms: number) => new Promise(res => setTimeout(res, ms));
   while (true) {
     simulationState.invokeGetDevice();
     simulationState.invokeListCaptures();
-    await delay(1000);
+    if (!(await listen())) {
+      await delay(1000);
+    }
   }
 }
 